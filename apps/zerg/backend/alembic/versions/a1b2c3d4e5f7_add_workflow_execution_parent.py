@@ -0,0 +1,40 @@
+"""add_workflow_execution_parent
+
+Revision ID: a1b2c3d4e5f7
+Revises: z0a1b2c3d4e5
+Create Date: 2026-10-17 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'a1b2c3d4e5f7'
+down_revision: Union[str, Sequence[str], None] = 'z0a1b2c3d4e5'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Link executions started by a Call Workflow node to the execution and node that started them."""
+    op.add_column('workflow_executions', sa.Column('parent_execution_id', sa.Integer(), nullable=True))
+    op.add_column('workflow_executions', sa.Column('parent_node_id', sa.String(), nullable=True))
+    op.create_index('ix_workflow_executions_parent_execution_id', 'workflow_executions', ['parent_execution_id'])
+    op.create_foreign_key(
+        'fk_workflow_executions_parent_execution_id',
+        'workflow_executions',
+        'workflow_executions',
+        ['parent_execution_id'],
+        ['id'],
+        ondelete='SET NULL',
+    )
+
+
+def downgrade() -> None:
+    op.drop_constraint('fk_workflow_executions_parent_execution_id', 'workflow_executions', type_='foreignkey')
+    op.drop_index('ix_workflow_executions_parent_execution_id', table_name='workflow_executions')
+    op.drop_column('workflow_executions', 'parent_node_id')
+    op.drop_column('workflow_executions', 'parent_execution_id')
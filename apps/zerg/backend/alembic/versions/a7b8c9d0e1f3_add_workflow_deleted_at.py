@@ -0,0 +1,30 @@
+"""add_workflow_deleted_at
+
+Revision ID: a7b8c9d0e1f3
+Revises: f6a7b8c9d0e2
+Create Date: 2026-10-23 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'a7b8c9d0e1f3'
+down_revision: Union[str, Sequence[str], None] = 'f6a7b8c9d0e2'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Timestamp workflow deletes so trashed workflows can be restored until they expire."""
+    op.add_column('workflows', sa.Column('deleted_at', sa.DateTime(), nullable=True))
+    op.create_index('ix_workflows_deleted_at', 'workflows', ['deleted_at'])
+
+
+def downgrade() -> None:
+    """Drop the workflow soft-delete timestamp."""
+    op.drop_index('ix_workflows_deleted_at', table_name='workflows')
+    op.drop_column('workflows', 'deleted_at')
@@ -0,0 +1,41 @@
+"""add_environment_variables
+
+Revision ID: b2c3d4e5f6a8
+Revises: a1b2c3d4e5f7
+Create Date: 2026-10-18 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'b2c3d4e5f6a8'
+down_revision: Union[str, Sequence[str], None] = 'a1b2c3d4e5f7'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Named values (optionally secret) that nodes and agent instructions reference as {{env.NAME}}."""
+    op.create_table(
+        'environment_variables',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('owner_id', sa.Integer(), sa.ForeignKey('users.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column(
+            'workflow_id', sa.Integer(), sa.ForeignKey('workflows.id', ondelete='CASCADE'), nullable=True, index=True
+        ),
+        sa.Column('name', sa.String(length=64), nullable=False),
+        sa.Column('value', sa.Text(), nullable=False),
+        sa.Column('is_secret', sa.Boolean(), nullable=False, server_default=sa.false()),
+        sa.Column('description', sa.Text(), nullable=True),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now(), nullable=False),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now(), nullable=False),
+        sa.UniqueConstraint('owner_id', 'workflow_id', 'name', name='uix_env_var_owner_scope_name'),
+    )
+
+
+def downgrade() -> None:
+    op.drop_table('environment_variables')
@@ -0,0 +1,28 @@
+"""add_thread_model_override
+
+Revision ID: b8c9d0e1f2a4
+Revises: a7b8c9d0e1f3
+Create Date: 2026-10-24 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'b8c9d0e1f2a4'
+down_revision: Union[str, Sequence[str], None] = 'a7b8c9d0e1f3'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Let a thread run on a different model than its agent's default."""
+    op.add_column('agent_threads', sa.Column('model_override', sa.String(), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop the per-thread model override."""
+    op.drop_column('agent_threads', 'model_override')
@@ -0,0 +1,41 @@
+"""add_api_credentials
+
+Revision ID: c3d4e5f6a7b9
+Revises: b2c3d4e5f6a8
+Create Date: 2026-10-19 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'c3d4e5f6a7b9'
+down_revision: Union[str, Sequence[str], None] = 'b2c3d4e5f6a8'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Third-party API keys that HTTP nodes and MCP servers use as their auth source."""
+    op.create_table(
+        'api_credentials',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('owner_id', sa.Integer(), sa.ForeignKey('users.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('name', sa.String(length=100), nullable=False),
+        sa.Column('base_url', sa.String(length=2048), nullable=False),
+        sa.Column('auth_header', sa.String(length=255), nullable=False),
+        sa.Column('encrypted_key', sa.Text(), nullable=False),
+        sa.Column('key_hint', sa.String(length=8), nullable=False),
+        sa.Column('test_status', sa.String(length=20), nullable=False, server_default='untested'),
+        sa.Column('last_tested_at', sa.DateTime(), nullable=True),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now(), nullable=False),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now(), nullable=False),
+        sa.UniqueConstraint('owner_id', 'name', name='uix_api_credential_owner_name'),
+    )
+
+
+def downgrade() -> None:
+    op.drop_table('api_credentials')
@@ -0,0 +1,30 @@
+"""add_agent_and_workflow_tags
+
+Revision ID: c9d0e1f2a3b5
+Revises: b8c9d0e1f2a4
+Create Date: 2026-10-25 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'c9d0e1f2a3b5'
+down_revision: Union[str, Sequence[str], None] = 'b8c9d0e1f2a4'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Let agents and workflows carry free-form tags."""
+    op.add_column('agents', sa.Column('tags', sa.JSON(), nullable=True))
+    op.add_column('workflows', sa.Column('tags', sa.JSON(), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop agent and workflow tags."""
+    op.drop_column('workflows', 'tags')
+    op.drop_column('agents', 'tags')
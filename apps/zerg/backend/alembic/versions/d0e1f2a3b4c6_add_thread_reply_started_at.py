@@ -0,0 +1,28 @@
+"""add_thread_reply_started_at
+
+Revision ID: d0e1f2a3b4c6
+Revises: c9d0e1f2a3b5
+Create Date: 2026-10-26 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'd0e1f2a3b4c6'
+down_revision: Union[str, Sequence[str], None] = 'c9d0e1f2a3b5'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Record in-flight agent replies on the thread so every worker sees them."""
+    op.add_column('agent_threads', sa.Column('reply_started_at', sa.DateTime(), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop the in-flight reply marker."""
+    op.drop_column('agent_threads', 'reply_started_at')
@@ -0,0 +1,27 @@
+"""add_workflow_execution_inputs
+
+Revision ID: d4e5f6a7b8c0
+Revises: c3d4e5f6a7b9
+Create Date: 2026-10-20 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'd4e5f6a7b8c0'
+down_revision: Union[str, Sequence[str], None] = 'c3d4e5f6a7b9'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Keep the inputs a run was started with."""
+    op.add_column('workflow_executions', sa.Column('inputs', sa.JSON(), nullable=True))
+
+
+def downgrade() -> None:
+    op.drop_column('workflow_executions', 'inputs')
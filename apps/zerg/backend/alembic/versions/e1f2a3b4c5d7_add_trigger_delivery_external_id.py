@@ -0,0 +1,28 @@
+"""add_trigger_delivery_external_id
+
+Revision ID: e1f2a3b4c5d7
+Revises: d0e1f2a3b4c6
+Create Date: 2026-10-27 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'e1f2a3b4c5d7'
+down_revision: Union[str, Sequence[str], None] = 'd0e1f2a3b4c6'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Keep the sender's delivery id so redelivered events don't fire twice."""
+    op.add_column('trigger_deliveries', sa.Column('external_id', sa.String(length=64), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop the sender's delivery id."""
+    op.drop_column('trigger_deliveries', 'external_id')
@@ -0,0 +1,40 @@
+"""add_workflow_execution_start_node
+
+Revision ID: e5f6a7b8c9d1
+Revises: d4e5f6a7b8c0
+Create Date: 2026-10-21 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'e5f6a7b8c9d1'
+down_revision: Union[str, Sequence[str], None] = 'd4e5f6a7b8c0'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Record the node a partial run started from and the execution whose outputs it reused."""
+    op.add_column('workflow_executions', sa.Column('source_execution_id', sa.Integer(), nullable=True))
+    op.add_column('workflow_executions', sa.Column('start_node_id', sa.String(), nullable=True))
+    op.create_index('ix_workflow_executions_source_execution_id', 'workflow_executions', ['source_execution_id'])
+    op.create_foreign_key(
+        'fk_workflow_executions_source_execution_id',
+        'workflow_executions',
+        'workflow_executions',
+        ['source_execution_id'],
+        ['id'],
+        ondelete='SET NULL',
+    )
+
+
+def downgrade() -> None:
+    op.drop_constraint('fk_workflow_executions_source_execution_id', 'workflow_executions', type_='foreignkey')
+    op.drop_index('ix_workflow_executions_source_execution_id', table_name='workflow_executions')
+    op.drop_column('workflow_executions', 'start_node_id')
+    op.drop_column('workflow_executions', 'source_execution_id')
@@ -0,0 +1,31 @@
+"""allow_queued_runs_without_thread
+
+Revision ID: f2a3b4c5d6e8
+Revises: e1f2a3b4c5d7
+Create Date: 2026-10-27 11:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'f2a3b4c5d6e8'
+down_revision: Union[str, Sequence[str], None] = 'e1f2a3b4c5d7'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Queued runs get their thread when they start, so agent_runs.thread_id may be empty."""
+    with op.batch_alter_table('agent_runs') as batch_op:
+        batch_op.alter_column('thread_id', existing_type=sa.Integer(), nullable=True)
+
+
+def downgrade() -> None:
+    """Drop runs that never got a thread, then require one again."""
+    op.execute("DELETE FROM agent_runs WHERE thread_id IS NULL")
+    with op.batch_alter_table('agent_runs') as batch_op:
+        batch_op.alter_column('thread_id', existing_type=sa.Integer(), nullable=False)
@@ -0,0 +1,38 @@
+"""add_trigger_delivery_run
+
+Revision ID: f6a7b8c9d0e2
+Revises: e5f6a7b8c9d1
+Create Date: 2026-10-22 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'f6a7b8c9d0e2'
+down_revision: Union[str, Sequence[str], None] = 'e5f6a7b8c9d1'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Record what a trigger firing matched and the run it started."""
+    op.add_column('trigger_deliveries', sa.Column('matched', sa.String(255), nullable=True))
+    op.add_column('trigger_deliveries', sa.Column('run_id', sa.Integer(), nullable=True))
+    op.create_foreign_key(
+        'fk_trigger_deliveries_run_id',
+        'trigger_deliveries',
+        'agent_runs',
+        ['run_id'],
+        ['id'],
+        ondelete='SET NULL',
+    )
+
+
+def downgrade() -> None:
+    op.drop_constraint('fk_trigger_deliveries_run_id', 'trigger_deliveries', type_='foreignkey')
+    op.drop_column('trigger_deliveries', 'run_id')
+    op.drop_column('trigger_deliveries', 'matched')
@@ -0,0 +1,30 @@
+"""add_agent_deleted_at
+
+Revision ID: j4k5l6m7n8o9
+Revises: i3j4k5l6m7n8
+Create Date: 2026-10-14 11:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'j4k5l6m7n8o9'
+down_revision: Union[str, Sequence[str], None] = 'i3j4k5l6m7n8'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Add soft-delete timestamp so agents can be moved to the trash."""
+    op.add_column('agents', sa.Column('deleted_at', sa.DateTime(), nullable=True))
+    op.create_index('ix_agents_deleted_at', 'agents', ['deleted_at'])
+
+
+def downgrade() -> None:
+    """Drop the agent soft-delete timestamp."""
+    op.drop_index('ix_agents_deleted_at', table_name='agents')
+    op.drop_column('agents', 'deleted_at')
@@ -0,0 +1,30 @@
+"""add_thread_pinned_archived
+
+Revision ID: k5l6m7n8o9p0
+Revises: j4k5l6m7n8o9
+Create Date: 2026-10-14 12:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'k5l6m7n8o9p0'
+down_revision: Union[str, Sequence[str], None] = 'j4k5l6m7n8o9'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Add pinned/archived flags used to organise the chat sidebar."""
+    op.add_column('agent_threads', sa.Column('pinned', sa.Boolean(), nullable=False, server_default=sa.false()))
+    op.add_column('agent_threads', sa.Column('archived', sa.Boolean(), nullable=False, server_default=sa.false()))
+
+
+def downgrade() -> None:
+    """Drop thread pinned/archived flags."""
+    op.drop_column('agent_threads', 'archived')
+    op.drop_column('agent_threads', 'pinned')
@@ -0,0 +1,33 @@
+"""add_thread_message_author
+
+Revision ID: l6m7n8o9p0q1
+Revises: k5l6m7n8o9p0
+Create Date: 2026-10-14 14:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'l6m7n8o9p0q1'
+down_revision: Union[str, Sequence[str], None] = 'k5l6m7n8o9p0'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Record which user wrote each message so shared threads can show authors."""
+    op.add_column(
+        'thread_messages',
+        sa.Column('author_id', sa.Integer(), sa.ForeignKey('users.id', ondelete='SET NULL'), nullable=True),
+    )
+    op.create_index('ix_thread_messages_author_id', 'thread_messages', ['author_id'])
+
+
+def downgrade() -> None:
+    """Drop the thread message author column."""
+    op.drop_index('ix_thread_messages_author_id', table_name='thread_messages')
+    op.drop_column('thread_messages', 'author_id')
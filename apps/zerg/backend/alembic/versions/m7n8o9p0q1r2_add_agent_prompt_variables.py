@@ -0,0 +1,28 @@
+"""add_agent_prompt_variables
+
+Revision ID: m7n8o9p0q1r2
+Revises: l6m7n8o9p0q1
+Create Date: 2026-10-14 15:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'm7n8o9p0q1r2'
+down_revision: Union[str, Sequence[str], None] = 'l6m7n8o9p0q1'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Store values for {{variable}} placeholders in agent instructions."""
+    op.add_column('agents', sa.Column('prompt_variables', sa.JSON(), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop agent prompt variables."""
+    op.drop_column('agents', 'prompt_variables')
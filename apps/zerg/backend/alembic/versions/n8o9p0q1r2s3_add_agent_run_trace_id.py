@@ -0,0 +1,30 @@
+"""add_agent_run_trace_id
+
+Revision ID: n8o9p0q1r2s3
+Revises: m7n8o9p0q1r2
+Create Date: 2026-10-14 17:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'n8o9p0q1r2s3'
+down_revision: Union[str, Sequence[str], None] = 'm7n8o9p0q1r2'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Record the trace id of the request that started each run."""
+    op.add_column('agent_runs', sa.Column('trace_id', sa.String(length=64), nullable=True))
+    op.create_index('ix_agent_runs_trace_id', 'agent_runs', ['trace_id'])
+
+
+def downgrade() -> None:
+    """Drop agent run trace ids."""
+    op.drop_index('ix_agent_runs_trace_id', table_name='agent_runs')
+    op.drop_column('agent_runs', 'trace_id')
@@ -0,0 +1,37 @@
+"""add_trigger_deliveries
+
+Revision ID: o9p0q1r2s3t4
+Revises: n8o9p0q1r2s3
+Create Date: 2026-10-14 18:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'o9p0q1r2s3t4'
+down_revision: Union[str, Sequence[str], None] = 'n8o9p0q1r2s3'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create trigger_deliveries table for the webhook delivery log."""
+    op.create_table(
+        'trigger_deliveries',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('trigger_id', sa.Integer(), sa.ForeignKey('triggers.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('status_code', sa.Integer(), nullable=False),
+        sa.Column('error', sa.String(255), nullable=True),
+        sa.Column('is_test', sa.Boolean(), nullable=False, server_default=sa.false()),
+        sa.Column('payload_bytes', sa.Integer(), nullable=True),
+        sa.Column('received_at', sa.DateTime(), server_default=sa.func.now(), nullable=False),
+    )
+
+
+def downgrade() -> None:
+    """Drop trigger_deliveries table."""
+    op.drop_table('trigger_deliveries')
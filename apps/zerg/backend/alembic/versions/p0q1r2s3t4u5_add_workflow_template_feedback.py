@@ -0,0 +1,38 @@
+"""add_workflow_template_feedback
+
+Revision ID: p0q1r2s3t4u5
+Revises: o9p0q1r2s3t4
+Create Date: 2026-10-14 19:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'p0q1r2s3t4u5'
+down_revision: Union[str, Sequence[str], None] = 'o9p0q1r2s3t4'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create workflow_template_feedback table for gallery ratings and favorites."""
+    op.create_table(
+        'workflow_template_feedback',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('template_id', sa.Integer(), sa.ForeignKey('workflow_templates.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('user_id', sa.Integer(), sa.ForeignKey('users.id'), nullable=False, index=True),
+        sa.Column('rating', sa.Integer(), nullable=True),
+        sa.Column('is_favorite', sa.Boolean(), nullable=False, server_default=sa.false()),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.UniqueConstraint('template_id', 'user_id', name='uix_template_feedback_user'),
+    )
+
+
+def downgrade() -> None:
+    """Drop workflow_template_feedback table."""
+    op.drop_table('workflow_template_feedback')
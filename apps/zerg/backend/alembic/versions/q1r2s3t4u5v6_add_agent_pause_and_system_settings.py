@@ -0,0 +1,36 @@
+"""add_agent_pause_and_system_settings
+
+Revision ID: q1r2s3t4u5v6
+Revises: p0q1r2s3t4u5
+Create Date: 2026-10-14 20:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'q1r2s3t4u5v6'
+down_revision: Union[str, Sequence[str], None] = 'p0q1r2s3t4u5'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Add agents.paused_at and the system_settings table (maintenance mode)."""
+    op.add_column('agents', sa.Column('paused_at', sa.DateTime(), nullable=True))
+    op.create_table(
+        'system_settings',
+        sa.Column('key', sa.String(length=64), primary_key=True),
+        sa.Column('value', sa.JSON(), nullable=True),
+        sa.Column('updated_by', sa.Integer(), sa.ForeignKey('users.id'), nullable=True),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now()),
+    )
+
+
+def downgrade() -> None:
+    """Drop system_settings and agents.paused_at."""
+    op.drop_table('system_settings')
+    op.drop_column('agents', 'paused_at')
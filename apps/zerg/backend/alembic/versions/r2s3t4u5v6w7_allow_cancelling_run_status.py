@@ -0,0 +1,50 @@
+"""allow_cancelling_run_status
+
+Revision ID: r2s3t4u5v6w7
+Revises: q1r2s3t4u5v6
+Create Date: 2026-10-14 21:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'r2s3t4u5v6w7'
+down_revision: Union[str, Sequence[str], None] = 'q1r2s3t4u5v6'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Widen agent_runs.status for the new 'cancelling' value.
+
+    run_status_enum uses native_enum=False, so it is a VARCHAR sized to the
+    longest value; 'cancelling' is one character longer than 'cancelled'.
+    SQLite does not enforce VARCHAR lengths, so only PostgreSQL needs this.
+    """
+    if op.get_bind().dialect.name != 'postgresql':
+        return
+    op.alter_column(
+        'agent_runs',
+        'status',
+        existing_type=sa.String(length=9),
+        type_=sa.String(length=10),
+        existing_nullable=False,
+    )
+
+
+def downgrade() -> None:
+    """Fold cancelling runs into cancelled and shrink the column back."""
+    if op.get_bind().dialect.name != 'postgresql':
+        return
+    op.execute("UPDATE agent_runs SET status = 'CANCELLED' WHERE status = 'CANCELLING'")
+    op.alter_column(
+        'agent_runs',
+        'status',
+        existing_type=sa.String(length=10),
+        type_=sa.String(length=9),
+        existing_nullable=False,
+    )
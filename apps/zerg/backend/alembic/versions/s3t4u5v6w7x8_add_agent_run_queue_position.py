@@ -0,0 +1,27 @@
+"""add_agent_run_queue_position
+
+Revision ID: s3t4u5v6w7x8
+Revises: r2s3t4u5v6w7
+Create Date: 2026-10-14 22:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 's3t4u5v6w7x8'
+down_revision: Union[str, Sequence[str], None] = 'r2s3t4u5v6w7'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Add agent_runs.queue_position for runs waiting behind an active run."""
+    op.add_column('agent_runs', sa.Column('queue_position', sa.Integer(), nullable=True))
+
+
+def downgrade() -> None:
+    op.drop_column('agent_runs', 'queue_position')
@@ -0,0 +1,36 @@
+"""add_agent_shares
+
+Revision ID: t4u5v6w7x8y9
+Revises: s3t4u5v6w7x8
+Create Date: 2026-10-14 23:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 't4u5v6w7x8y9'
+down_revision: Union[str, Sequence[str], None] = 's3t4u5v6w7x8'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create agent_shares for view/run/edit grants to users or the whole workspace."""
+    op.create_table(
+        'agent_shares',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('agent_id', sa.Integer(), sa.ForeignKey('agents.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('user_id', sa.Integer(), sa.ForeignKey('users.id'), nullable=True, index=True),
+        sa.Column('permission', sa.String(), nullable=False),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.UniqueConstraint('agent_id', 'user_id', name='uix_agent_share_user'),
+    )
+
+
+def downgrade() -> None:
+    """Drop agent_shares table."""
+    op.drop_table('agent_shares')
@@ -0,0 +1,48 @@
+"""add_workflow_node_comments
+
+Revision ID: u5v6w7x8y9z0
+Revises: t4u5v6w7x8y9
+Create Date: 2026-10-15 09:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'u5v6w7x8y9z0'
+down_revision: Union[str, Sequence[str], None] = 't4u5v6w7x8y9'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create workflow_node_comments for comment threads attached to canvas nodes."""
+    op.create_table(
+        'workflow_node_comments',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column(
+            'workflow_id', sa.Integer(), sa.ForeignKey('workflows.id', ondelete='CASCADE'), nullable=False, index=True
+        ),
+        sa.Column('node_id', sa.String(), nullable=False, index=True),
+        sa.Column(
+            'parent_id',
+            sa.Integer(),
+            sa.ForeignKey('workflow_node_comments.id', ondelete='CASCADE'),
+            nullable=True,
+            index=True,
+        ),
+        sa.Column('author_id', sa.Integer(), sa.ForeignKey('users.id'), nullable=False),
+        sa.Column('body', sa.Text(), nullable=False),
+        sa.Column('mentioned_user_ids', sa.JSON(), nullable=True),
+        sa.Column('resolved_at', sa.DateTime(), nullable=True),
+        sa.Column('resolved_by_id', sa.Integer(), sa.ForeignKey('users.id'), nullable=True),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+    )
+
+
+def downgrade() -> None:
+    """Drop workflow_node_comments table."""
+    op.drop_table('workflow_node_comments')
@@ -0,0 +1,28 @@
+"""add_agent_description
+
+Revision ID: v6w7x8y9z0a1
+Revises: u5v6w7x8y9z0
+Create Date: 2026-10-15 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'v6w7x8y9z0a1'
+down_revision: Union[str, Sequence[str], None] = 'u5v6w7x8y9z0'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Store a markdown README that documents each agent for its users."""
+    op.add_column('agents', sa.Column('description', sa.Text(), nullable=True))
+
+
+def downgrade() -> None:
+    """Drop agent descriptions."""
+    op.drop_column('agents', 'description')
@@ -0,0 +1,45 @@
+"""add_agent_instruction_versions
+
+Revision ID: w7x8y9z0a1b2
+Revises: v6w7x8y9z0a1
+Create Date: 2026-10-15 12:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'w7x8y9z0a1b2'
+down_revision: Union[str, Sequence[str], None] = 'v6w7x8y9z0a1'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create agent_instruction_versions and seed version 1 from each agent's current instructions."""
+    op.create_table(
+        'agent_instruction_versions',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('agent_id', sa.Integer(), sa.ForeignKey('agents.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('version', sa.Integer(), nullable=False),
+        sa.Column('system_instructions', sa.Text(), nullable=False),
+        sa.Column('task_instructions', sa.Text(), nullable=False),
+        sa.Column('author_id', sa.Integer(), sa.ForeignKey('users.id', ondelete='SET NULL'), nullable=True),
+        sa.Column('restored_from_version', sa.Integer(), nullable=True),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.UniqueConstraint('agent_id', 'version', name='uix_agent_instruction_version'),
+    )
+    op.execute(
+        """
+        INSERT INTO agent_instruction_versions (agent_id, version, system_instructions, task_instructions, author_id)
+        SELECT id, 1, system_instructions, task_instructions, owner_id FROM agents
+        """
+    )
+
+
+def downgrade() -> None:
+    """Drop agent_instruction_versions table."""
+    op.drop_table('agent_instruction_versions')
@@ -0,0 +1,39 @@
+"""add_agent_test_cases
+
+Revision ID: x8y9z0a1b2c3
+Revises: w7x8y9z0a1b2
+Create Date: 2026-10-15 14:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'x8y9z0a1b2c3'
+down_revision: Union[str, Sequence[str], None] = 'w7x8y9z0a1b2'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create agent_test_cases for prompt checks run from the agent settings."""
+    op.create_table(
+        'agent_test_cases',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('agent_id', sa.Integer(), sa.ForeignKey('agents.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('name', sa.String(), nullable=False),
+        sa.Column('input', sa.Text(), nullable=False),
+        sa.Column('assertions', sa.JSON(), nullable=False),
+        sa.Column('last_result', sa.JSON(), nullable=True),
+        sa.Column('last_run_at', sa.DateTime(), nullable=True),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now()),
+    )
+
+
+def downgrade() -> None:
+    """Drop agent_test_cases table."""
+    op.drop_table('agent_test_cases')
@@ -0,0 +1,42 @@
+"""add_agent_knowledge_documents
+
+Revision ID: y9z0a1b2c3d4
+Revises: x8y9z0a1b2c3
+Create Date: 2026-10-16 10:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'y9z0a1b2c3d4'
+down_revision: Union[str, Sequence[str], None] = 'x8y9z0a1b2c3'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Create agent_knowledge_documents for per-agent knowledge bases."""
+    op.create_table(
+        'agent_knowledge_documents',
+        sa.Column('id', sa.Integer(), primary_key=True, autoincrement=True),
+        sa.Column('agent_id', sa.Integer(), sa.ForeignKey('agents.id', ondelete='CASCADE'), nullable=False, index=True),
+        sa.Column('name', sa.String(length=255), nullable=False),
+        sa.Column('url', sa.Text(), nullable=True),
+        sa.Column('content_type', sa.String(length=100), nullable=True),
+        sa.Column('size_bytes', sa.Integer(), nullable=True),
+        sa.Column('status', sa.String(length=20), nullable=False, server_default='indexing'),
+        sa.Column('error', sa.Text(), nullable=True),
+        sa.Column('chunks', sa.JSON(), nullable=True),
+        sa.Column('chunk_count', sa.Integer(), nullable=False, server_default='0'),
+        sa.Column('created_at', sa.DateTime(), server_default=sa.func.now()),
+        sa.Column('updated_at', sa.DateTime(), server_default=sa.func.now()),
+    )
+
+
+def downgrade() -> None:
+    """Drop agent_knowledge_documents table."""
+    op.drop_table('agent_knowledge_documents')
@@ -0,0 +1,29 @@
+"""add_agent_run_output_schema_results
+
+Revision ID: z0a1b2c3d4e5
+Revises: y9z0a1b2c3d4
+Create Date: 2026-10-16 14:00:00.000000
+
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+
+# revision identifiers, used by Alembic.
+revision: str = 'z0a1b2c3d4e5'
+down_revision: Union[str, Sequence[str], None] = 'y9z0a1b2c3d4'
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    """Add agent_runs.output and schema_errors for agents with an output schema."""
+    op.add_column('agent_runs', sa.Column('output', sa.JSON(), nullable=True))
+    op.add_column('agent_runs', sa.Column('schema_errors', sa.JSON(), nullable=True))
+
+
+def downgrade() -> None:
+    op.drop_column('agent_runs', 'schema_errors')
+    op.drop_column('agent_runs', 'output')
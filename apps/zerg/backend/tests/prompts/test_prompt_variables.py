@@ -0,0 +1,54 @@
+"""Tests for ``{{variable}}`` placeholders in agent instructions."""
+
+import pytest
+
+from zerg.prompts.variables import find_template_variables
+from zerg.prompts.variables import render_prompt_template
+from zerg.prompts.variables import undefined_template_variables
+from zerg.prompts.variables import validate_prompt_variables
+
+
+def test_find_template_variables_is_ordered_and_unique():
+    text = "Hi {{ name }}, your team is {{team}}. Bye {{name}}! {{ not valid }} {{1bad}}"
+    assert find_template_variables(text) == ["name", "team"]
+
+
+def test_render_leaves_undefined_placeholders_visible():
+    rendered = render_prompt_template("Report on {{project}} for {{ owner }}", {"project": "Zerg"})
+    assert rendered == "Report on Zerg for {{ owner }}"
+
+
+def test_undefined_variables_across_texts():
+    assert undefined_template_variables(["{{a}} {{b}}", None, "{{c}}"], {"b": "x"}) == ["a", "c"]
+
+
+@pytest.mark.parametrize(
+    "variables",
+    [{"has space": "x"}, {"1st": "x"}, {"ok": 3}],
+)
+def test_validate_rejects_bad_variables(variables):
+    with pytest.raises(ValueError):
+        validate_prompt_variables(variables)
+
+
+def test_agent_prompt_variables_render_into_new_threads(client, sample_agent):
+    response = client.put(
+        f"/api/agents/{sample_agent.id}",
+        json={
+            "system_instructions": "You support {{company}}.",
+            "prompt_variables": {"company": "Acme"},
+        },
+    )
+    assert response.status_code == 200
+    assert response.json()["prompt_variables"] == {"company": "Acme"}
+
+    thread = client.post("/api/threads", json={"agent_id": sample_agent.id, "title": "t"}).json()
+    messages = client.get(f"/api/threads/{thread['id']}/messages").json()
+    assert messages[0]["role"] == "system"
+    assert messages[0]["content"] == "You support Acme."
+
+
+def test_invalid_prompt_variable_name_is_rejected(client, sample_agent):
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"prompt_variables": {"bad name": "x"}})
+    assert response.status_code == 422
+    assert "Invalid prompt variable name" in response.json()["detail"]
@@ -2,6 +2,9 @@
 return 204 *No Content*, remove all related objects and publish the
 *agent_deleted* event without crashing the server.
 
+Deletion is two-step since agents go to the trash first: the regular DELETE
+only hides the agent, ``DELETE /permanent`` removes its threads and messages.
+
 Before commit 3e5fd10 the DELETE endpoint returned the raw SQLAlchemy model
 while still declaring a 204 response.  FastAPI tried to serialise that model,
 hit a cyclic relationship (agent -> thread -> agent …) and raised
@@ -16,13 +19,14 @@ RecursionError.  This test covers that scenario so we never regress again.
 import pytest
 from fastapi.testclient import TestClient
 
+from zerg.crud import crud
 from zerg.events import EventType
 from zerg.events import event_bus
 from tests.conftest import TEST_MODEL, TEST_WORKER_MODEL
 
 
 @pytest.mark.asyncio
-async def test_delete_agent_with_relationships(client: TestClient):
+async def test_delete_agent_with_relationships(client: TestClient, db_session):
     """Create agent → thread → message, then delete the agent."""
 
     # ------------------------------------------------------------------
@@ -78,10 +82,20 @@ async def test_delete_agent_with_relationships(client: TestClient):
     assert resp.content == b""  # Starlette sends an empty body for 204
 
     # ------------------------------------------------------------------
-    # 5. Verify the agent and its thread are gone
+    # 5. The agent and its thread are hidden, but the thread survives until
+    #    the trash is emptied
     # ------------------------------------------------------------------
     assert client.get(f"/api/agents/{agent_id}").status_code == 404
     assert client.get(f"/api/threads/{thread_id}").status_code == 404
+    assert crud.get_thread(db_session, thread_id) is not None
+
+    resp = client.delete(f"/api/agents/{agent_id}/permanent")
+    assert resp.status_code == 204
+    assert resp.content == b""
+
+    assert client.get(f"/api/threads/{thread_id}").status_code == 404
+    db_session.expire_all()
+    assert crud.get_thread(db_session, thread_id) is None
 
     # ------------------------------------------------------------------
     # 6. The *agent_deleted* event should have been published with the id
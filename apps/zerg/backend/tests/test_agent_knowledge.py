@@ -0,0 +1,65 @@
+"""Agent knowledge base: uploads, links, indexing and retrieval."""
+
+from unittest.mock import patch
+
+from fastapi.testclient import TestClient
+
+from zerg.models.models import Agent
+from zerg.services.knowledge_base import build_knowledge_context
+from zerg.services.knowledge_base import chunk_text
+from zerg.services.knowledge_base import html_to_text
+
+
+def test_chunk_text_prefers_paragraph_breaks():
+    text = ("alpha " * 150).strip() + "\n\n" + ("beta " * 150).strip()
+    chunks = chunk_text(text, size=1000, overlap=100)
+    assert chunks[0].endswith("alpha")
+    assert chunks[-1].endswith("beta")
+    assert chunk_text("   ") == []
+
+
+def test_html_to_text_drops_markup():
+    page = "<html><style>p{}</style><body><h1>Refunds</h1><p>Within&nbsp;30 days.</p><script>x()</script></body>"
+    assert html_to_text(page) == "Refunds\nWithin 30 days."
+
+
+def test_knowledge_upload_index_and_retrieve(client: TestClient, sample_agent: Agent, db_session):
+    base = f"/api/agents/{sample_agent.id}/knowledge"
+    body = "# Refund policy\n\nCustomers can request a refund within 30 days of purchase.\n\nShipping takes 5 days."
+
+    response = client.post(f"{base}/files", files={"file": ("policy.md", body.encode(), "application/octet-stream")})
+    assert response.status_code == 202
+    assert response.json()["status"] == "indexing"
+
+    # TestClient runs background tasks before returning the response
+    [document] = client.get(f"{base}/").json()
+    assert document["status"] == "ready"
+    assert document["content_type"] == "text/markdown"
+    assert document["size_bytes"] == len(body.encode())
+    assert document["chunk_count"] == 1
+
+    context = build_knowledge_context(db_session, sample_agent.id, "How do refunds work?")
+    assert 'source="policy.md"' in context
+    assert build_knowledge_context(db_session, sample_agent.id, "weather tomorrow") is None
+
+    assert client.delete(f"{base}/{document['id']}").status_code == 204
+    assert client.get(f"{base}/").json() == []
+
+
+def test_knowledge_link_fetch_failure_is_recorded(client: TestClient, sample_agent: Agent):
+    base = f"/api/agents/{sample_agent.id}/knowledge"
+
+    async def _fetch(url):
+        raise ValueError("Fetching the page failed with HTTP 404")
+
+    with patch("zerg.services.knowledge_base.fetch_url", side_effect=_fetch):
+        response = client.post(f"{base}/links", json={"url": "https://example.com/docs"})
+    assert response.status_code == 202
+    assert response.json()["name"] == "https://example.com/docs"
+
+    [document] = client.get(f"{base}/").json()
+    assert document["status"] == "failed"
+    assert "404" in document["error"]
+
+    image = client.post(f"{base}/files", files={"file": ("logo.png", b"\x89PNG", "image/png")})
+    assert image.status_code == 415
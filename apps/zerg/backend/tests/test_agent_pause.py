@@ -0,0 +1,88 @@
+"""Agent pause/resume and the global maintenance switch."""
+
+import hashlib
+import hmac
+import json
+import time
+
+import pytest
+
+from tests.conftest import TEST_MODEL
+from zerg.crud import crud
+from zerg.services.scheduler_service import scheduler_service
+
+
+@pytest.fixture
+def run_calls():
+    calls = []
+
+    async def _stub_run_agent_task(agent_id: int, trigger: str = "schedule"):  # type: ignore
+        calls.append((agent_id, trigger))
+
+    original = scheduler_service.run_agent_task
+    scheduler_service.run_agent_task = _stub_run_agent_task  # type: ignore
+    try:
+        yield calls
+    finally:
+        scheduler_service.run_agent_task = original  # type: ignore
+
+
+def _signed_headers(secret: str, body: dict) -> dict:
+    timestamp = str(int(time.time()))
+    serialised = json.dumps(body, separators=(",", ":"), sort_keys=True)
+    signature = hmac.new(secret.encode(), f"{timestamp}.{serialised}".encode(), hashlib.sha256).hexdigest()
+    return {"X-Zerg-Timestamp": timestamp, "X-Zerg-Signature": signature}
+
+
+def test_pause_and_resume_agent(client, sample_agent):
+    response = client.post(f"/api/agents/{sample_agent.id}/pause")
+    assert response.status_code == 200
+    assert response.json()["paused_at"] is not None
+
+    assert client.get(f"/api/agents/{sample_agent.id}").json()["paused_at"] is not None
+
+    response = client.post(f"/api/agents/{sample_agent.id}/resume")
+    assert response.status_code == 200
+    assert response.json()["paused_at"] is None
+
+
+def test_pause_requires_agent_owner(client, db_session, other_user):
+    agent = crud.create_agent(
+        db_session,
+        owner_id=other_user.id,
+        name="Someone else's agent",
+        system_instructions="s",
+        task_instructions="t",
+        model=TEST_MODEL,
+    )
+    assert client.post(f"/api/agents/{agent.id}/pause").status_code == 403
+
+
+def test_paused_agent_rejects_webhook_deliveries(client, sample_agent, run_calls):
+    trg = client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "webhook"}).json()
+    client.post(f"/api/agents/{sample_agent.id}/pause")
+
+    body = {"a": 1}
+    resp = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body))
+    assert resp.status_code == 503
+    assert run_calls == []
+
+    deliveries = client.get(f"/api/triggers/{trg['id']}/deliveries").json()
+    assert [(d["status_code"], d["error"]) for d in deliveries] == [(503, "Trigger suspended: agent is paused")]
+
+    client.post(f"/api/agents/{sample_agent.id}/resume")
+    resp = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body))
+    assert resp.status_code == 202
+    assert run_calls == [(sample_agent.id, "webhook")]
+
+
+def test_maintenance_mode_blocks_every_agent(client, db_session, sample_agent):
+    assert crud.automatic_run_block_reason(db_session, sample_agent) is None
+    assert client.get("/api/system/info").json()["maintenance_mode"] is False
+
+    crud.set_system_setting(db_session, crud.MAINTENANCE_MODE_KEY, True)
+    assert crud.automatic_run_block_reason(db_session, sample_agent) == "maintenance mode is on"
+    assert client.get("/api/system/info").json()["maintenance_mode"] is True
+
+    crud.set_system_setting(db_session, crud.MAINTENANCE_MODE_KEY, False)
+    assert crud.automatic_run_block_reason(db_session, sample_agent) is None
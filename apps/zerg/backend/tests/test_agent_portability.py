@@ -0,0 +1,129 @@
+"""Agent duplication and JSON export/import."""
+
+from fastapi.testclient import TestClient
+
+from zerg.crud import crud
+from zerg.models.models import Agent
+from tests.conftest import TEST_MODEL
+
+
+def _configure(db_session, agent: Agent) -> None:
+    agent.schedule = "0 9 * * *"
+    agent.allowed_tools = ["http_request"]
+    agent.prompt_variables = {"team": "ops"}
+    agent.description = "Triages **ops** tickets."
+    agent.tags = ["ops", "triage"]
+    agent.config = {"mcp_servers": [{"url": "https://mcp.example.com", "name": "docs", "auth_token": "enc:secret"}]}
+    db_session.commit()
+    crud.create_trigger(db_session, agent_id=agent.id, trigger_type="webhook")
+    crud.create_trigger(
+        db_session,
+        agent_id=agent.id,
+        trigger_type="email",
+        config={"provider": "gmail", "connector_id": 99, "history_id": 1234, "filters": {"from": "boss@example.com"}},
+    )
+
+
+def test_duplicate_agent_copies_configuration(client: TestClient, sample_agent: Agent, db_session):
+    _configure(db_session, sample_agent)
+
+    response = client.post(f"/api/agents/{sample_agent.id}/duplicate")
+    assert response.status_code == 201
+    copy = response.json()
+    assert copy["id"] != sample_agent.id
+    assert copy["name"] == "Test Agent (copy)"
+    assert copy["schedule"] == "0 9 * * *"
+    assert copy["allowed_tools"] == ["http_request"]
+    assert copy["prompt_variables"] == {"team": "ops"}
+    assert copy["description"] == "Triages **ops** tickets."
+    assert copy["tags"] == ["ops", "triage"]
+    assert copy["status"] == "idle"
+
+    source_triggers = crud.get_triggers(db_session, agent_id=sample_agent.id)
+    copied_triggers = crud.get_triggers(db_session, agent_id=copy["id"])
+    assert [t.type for t in copied_triggers] == ["webhook", "email"]
+    assert copied_triggers[0].secret != source_triggers[0].secret
+    assert "history_id" not in copied_triggers[1].config
+
+    again = client.post(f"/api/agents/{sample_agent.id}/duplicate").json()
+    assert again["name"] == "Test Agent (copy 2)"
+
+
+def test_export_strips_secrets_and_local_links(client: TestClient, sample_agent: Agent, db_session):
+    _configure(db_session, sample_agent)
+
+    response = client.get(f"/api/agents/{sample_agent.id}/export")
+    assert response.status_code == 200
+    export = response.json()
+    assert export["format"] == "zerg-agent"
+    assert export["agent"]["name"] == "Test Agent"
+    assert export["agent"]["schedule"] == "0 9 * * *"
+    assert export["agent"]["description"] == "Triages **ops** tickets."
+    assert export["agent"]["tags"] == ["ops", "triage"]
+
+    server = export["agent"]["config"]["mcp_servers"][0]
+    assert "auth_token" not in server
+    assert server["requires_auth"] is True
+
+    assert [t["type"] for t in export["triggers"]] == ["webhook", "email"]
+    assert export["triggers"][1]["config"] == {"provider": "gmail", "filters": {"from": "boss@example.com"}}
+    assert "secret" not in export["triggers"][0]
+
+
+def test_import_preview_reports_conflicts(client: TestClient, sample_agent: Agent, db_session):
+    _configure(db_session, sample_agent)
+    export = client.get(f"/api/agents/{sample_agent.id}/export").json()
+    export["agent"]["model"] = "not-a-real-model"
+
+    response = client.post("/api/agents/import/preview", json=export)
+    assert response.status_code == 200
+    preview = response.json()
+    assert preview["name_conflict"] is True
+    assert preview["suggested_name"] == "Test Agent (copy)"
+    assert preview["model_available"] is False
+    assert preview["trigger_count"] == 2
+    fields = {conflict["field"] for conflict in preview["conflicts"]}
+    assert fields == {"name", "model", "mcp_servers", "triggers"}
+
+
+def test_import_applies_resolutions(client: TestClient, sample_agent: Agent, db_session, _dev_user):
+    _configure(db_session, sample_agent)
+    connector = crud.create_connector(db_session, owner_id=_dev_user.id, type="email", provider="gmail")
+    export = client.get(f"/api/agents/{sample_agent.id}/export").json()
+
+    response = client.post(
+        "/api/agents/import",
+        json={"export": export, "name": "Imported", "include_schedule": False},
+    )
+    assert response.status_code == 201
+    imported = response.json()
+    assert imported["name"] == "Imported"
+    assert imported["schedule"] is None
+    assert imported["model"] == sample_agent.model
+    assert imported["allowed_tools"] == ["http_request"]
+
+    triggers = crud.get_triggers(db_session, agent_id=imported["id"])
+    assert [t.type for t in triggers] == ["webhook", "email"]
+    assert triggers[1].config["connector_id"] == connector.id
+
+
+def test_import_rejects_unknown_format(client: TestClient, sample_agent: Agent):
+    export = client.get(f"/api/agents/{sample_agent.id}/export").json()
+    export["format"] = "something-else"
+
+    assert client.post("/api/agents/import/preview", json=export).status_code == 422
+    assert client.post("/api/agents/import", json={"export": export}).status_code == 422
+
+
+def test_duplicate_and_export_require_ownership(client: TestClient, db_session, other_user):
+    foreign = crud.create_agent(
+        db_session,
+        owner_id=other_user.id,
+        name="Foreign",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_MODEL,
+    )
+
+    assert client.post(f"/api/agents/{foreign.id}/duplicate").status_code == 403
+    assert client.get(f"/api/agents/{foreign.id}/export").status_code == 403
@@ -0,0 +1,123 @@
+"""Sharing agents with other users or the whole workspace."""
+
+import contextlib
+
+import pytest
+
+from tests.conftest import TEST_WORKER_MODEL
+from zerg.crud import crud
+from zerg.dependencies.auth import get_current_user
+from zerg.main import app
+
+
+def _mk_user(db_session, email: str):
+    user = crud.get_user_by_email(db_session, email)
+    if user is None:
+        user = crud.create_user(db_session, email=email, provider=None, role="USER")
+    return user
+
+
+def _mk_agent(db_session, owner_id: int):
+    return crud.create_agent(
+        db_session,
+        owner_id=owner_id,
+        name="shared",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_WORKER_MODEL,
+        schedule=None,
+        config={},
+    )
+
+
+@contextlib.contextmanager
+def _as(user):
+    app.dependency_overrides[get_current_user] = lambda: user
+    try:
+        yield
+    finally:
+        with contextlib.suppress(Exception):
+            del app.dependency_overrides[get_current_user]
+
+
+@pytest.fixture
+def owner(db_session):
+    return _mk_user(db_session, "share-owner@local")
+
+
+@pytest.fixture
+def viewer(db_session):
+    return _mk_user(db_session, "share-viewer@local")
+
+
+def test_owner_grants_and_revokes_access(client, db_session, owner, viewer):
+    agent = _mk_agent(db_session, owner.id)
+
+    with _as(owner):
+        resp = client.put(f"/api/agents/{agent.id}/shares", json={"user_email": viewer.email, "permission": "view"})
+        assert resp.status_code == 200, resp.text
+        share = resp.json()
+        assert share["user_email"] == viewer.email
+        assert share["permission"] == "view"
+
+        # A second grant to the same user replaces the first
+        resp = client.put(f"/api/agents/{agent.id}/shares", json={"user_email": viewer.email, "permission": "run"})
+        assert resp.json()["id"] == share["id"]
+
+        shares = client.get(f"/api/agents/{agent.id}/shares").json()
+        assert [(s["user_email"], s["permission"]) for s in shares] == [(viewer.email, "run")]
+
+        unknown = client.put(f"/api/agents/{agent.id}/shares", json={"user_email": "nobody@x", "permission": "view"})
+        assert unknown.status_code == 404
+
+    with _as(viewer):
+        # Only the owner manages shares
+        assert client.get(f"/api/agents/{agent.id}/shares").status_code == 403
+
+    with _as(owner):
+        assert client.delete(f"/api/agents/{agent.id}/shares/{share['id']}").status_code == 204
+
+    with _as(viewer):
+        assert client.get(f"/api/agents/{agent.id}").status_code == 403
+
+
+def test_permissions_gate_view_edit_and_delete(client, db_session, owner, viewer):
+    agent = _mk_agent(db_session, owner.id)
+    crud.upsert_agent_share(db_session, agent.id, viewer.id, "view")
+
+    with _as(viewer):
+        assert client.get(f"/api/agents/{agent.id}").status_code == 200
+        assert client.put(f"/api/agents/{agent.id}", json={"name": "renamed"}).status_code == 403
+        assert client.delete(f"/api/agents/{agent.id}").status_code == 403
+
+    crud.upsert_agent_share(db_session, agent.id, viewer.id, "edit")
+    with _as(viewer):
+        resp = client.put(f"/api/agents/{agent.id}", json={"name": "renamed"})
+        assert resp.status_code == 200, resp.text
+        # Editing doesn't include deleting
+        assert client.delete(f"/api/agents/{agent.id}").status_code == 403
+
+
+def test_shared_scope_lists_workspace_and_personal_shares(client, db_session, owner, viewer):
+    workspace_agent = _mk_agent(db_session, owner.id)
+    personal_agent = _mk_agent(db_session, owner.id)
+    _mk_agent(db_session, owner.id)  # not shared
+    crud.upsert_agent_share(db_session, workspace_agent.id, None, "run")
+    crud.upsert_agent_share(db_session, personal_agent.id, None, "edit")
+    # A personal grant wins over the workspace one, even when lower
+    crud.upsert_agent_share(db_session, personal_agent.id, viewer.id, "view")
+
+    with _as(viewer):
+        resp = client.get("/api/agents/dashboard", params={"scope": "shared", "runs_limit": 0})
+        assert resp.status_code == 200, resp.text
+        body = resp.json()
+
+    assert {agent["id"] for agent in body["agents"]} == {workspace_agent.id, personal_agent.id}
+    assert {p["agent_id"]: p["permission"] for p in body["permissions"]} == {
+        workspace_agent.id: "run",
+        personal_agent.id: "view",
+    }
+
+    with _as(owner):
+        # Owners never see their own agents as "shared with me"
+        assert client.get("/api/agents", params={"scope": "shared"}).json() == []
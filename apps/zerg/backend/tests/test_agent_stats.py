@@ -0,0 +1,121 @@
+"""Tests for aggregated per-agent run statistics."""
+
+from datetime import timedelta
+
+from fastapi.testclient import TestClient
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.services.agent_stats import get_agent_stats
+from zerg.services.agent_stats import get_agent_usage
+from zerg.utils.time import utc_now_naive
+
+
+def _run(
+    db_session: Session,
+    agent_id: int,
+    thread_id: int,
+    *,
+    status: str,
+    days_ago: int = 0,
+    duration_ms=None,
+    tokens=None,
+    cost=None,
+):
+    row = crud.create_run(db_session, agent_id=agent_id, thread_id=thread_id, trigger="manual", status="queued")
+    started = utc_now_naive() - timedelta(days=days_ago)
+    crud.mark_running(db_session, row.id, started_at=started)
+    if status == "success":
+        crud.mark_finished(
+            db_session,
+            row.id,
+            finished_at=started,
+            duration_ms=duration_ms,
+            total_tokens=tokens,
+            total_cost_usd=cost,
+        )
+    elif status == "failed":
+        crud.mark_failed(db_session, row.id, finished_at=started, duration_ms=duration_ms, error="boom")
+    return row
+
+
+def test_stats_count_finished_runs_only(db_session: Session, sample_agent, sample_thread):
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", duration_ms=1000)
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", duration_ms=3000, days_ago=2)
+    _run(db_session, sample_agent.id, sample_thread.id, status="failed", duration_ms=2000, days_ago=10)
+    _run(db_session, sample_agent.id, sample_thread.id, status="running")
+
+    stats = get_agent_stats(db_session, [sample_agent.id])[sample_agent.id]
+
+    assert stats["run_count"] == 4
+    assert stats["success_count"] == 2
+    assert stats["failed_count"] == 1
+    # The running run is not counted as a failure
+    assert round(stats["success_rate"], 1) == 66.7
+    assert stats["avg_duration_ms"] == 2000.0
+
+    counts = [day["count"] for day in stats["daily_runs"]]
+    assert len(counts) == 7
+    # Today: one success + the running run; two days ago: one success; the 10-day-old run is out of range
+    assert counts[-1] == 2
+    assert counts[-3] == 1
+    assert sum(counts) == 3
+
+
+def test_stats_for_agent_without_runs(db_session: Session, sample_agent):
+    stats = get_agent_stats(db_session, [sample_agent.id])[sample_agent.id]
+    assert stats["run_count"] == 0
+    assert stats["success_rate"] is None
+    assert stats["avg_duration_ms"] is None
+    assert all(day["count"] == 0 for day in stats["daily_runs"])
+
+
+def test_stats_endpoint_and_dashboard(client: TestClient, db_session: Session, sample_agent, sample_thread):
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", duration_ms=500)
+
+    response = client.get(f"/api/agents/{sample_agent.id}/stats")
+    assert response.status_code == 200
+    assert response.json()["success_rate"] == 100.0
+
+    snapshot = client.get("/api/agents/dashboard?runs_limit=0").json()
+    entry = next(item for item in snapshot["stats"] if item["agent_id"] == sample_agent.id)
+    assert entry["run_count"] == 1
+
+
+def test_stats_endpoint_missing_agent(client: TestClient):
+    assert client.get("/api/agents/999999/stats").status_code == 404
+
+
+def test_usage_sums_the_last_week_by_model(db_session: Session, sample_agent, sample_thread):
+    override = crud.create_thread(db_session, agent_id=sample_agent.id, title="Override", active=False)
+    override.model_override = "gpt-test-large"
+    db_session.commit()
+
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", tokens=100, cost=0.01)
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", tokens=50, days_ago=3)
+    _run(db_session, sample_agent.id, override.id, status="success", tokens=400, cost=0.2)
+    # Outside the window
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", tokens=999, cost=9.0, days_ago=8)
+
+    usage = get_agent_usage(db_session, [sample_agent.id])[sample_agent.id]
+
+    assert usage["run_count"] == 3
+    assert usage["total_tokens"] == 550
+    assert round(usage["total_cost_usd"], 4) == 0.21
+    assert [item["model"] for item in usage["by_model"]] == ["gpt-test-large", sample_agent.model]
+    assert usage["by_model"][1]["run_count"] == 2
+    assert usage["by_model"][1]["total_tokens"] == 150
+
+
+def test_usage_endpoint(client: TestClient, db_session: Session, sample_agent, sample_thread):
+    _run(db_session, sample_agent.id, sample_thread.id, status="success", tokens=20)
+
+    response = client.get("/api/agents/usage?days=30")
+    assert response.status_code == 200
+    entry = next(item for item in response.json() if item["agent_id"] == sample_agent.id)
+    assert entry["days"] == 30
+    assert entry["total_tokens"] == 20
+    # No priced run yet
+    assert entry["total_cost_usd"] is None
+
+    assert client.get("/api/agents/usage?days=0").status_code == 422
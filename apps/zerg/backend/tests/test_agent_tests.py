@@ -0,0 +1,89 @@
+"""Agent test harness: saved cases, assertion checks and runs."""
+
+from unittest.mock import patch
+
+from fastapi.testclient import TestClient
+
+from zerg.models.models import Agent
+from zerg.models.models import Thread
+from zerg.models.models import ThreadMessage
+from zerg.services.agent_tests import evaluate_assertions
+
+
+def _reply(text: str):
+    async def run_thread(db, thread):
+        return [ThreadMessage(thread_id=thread.id, role="assistant", content=text, processed=True)]
+
+    return run_thread
+
+
+def test_evaluate_assertions():
+    reply = 'Here you go:\n```json\n{"status": "open", "items": [{"id": 7}]}\n```'
+    results = evaluate_assertions(
+        reply,
+        [
+            {"type": "contains", "value": "Here you go"},
+            {"type": "not_contains", "value": "sorry"},
+            {"type": "regex", "value": r'"id":\s*\d+'},
+            {"type": "json_path", "path": "$.items[0].id", "value": "7"},
+            {"type": "json_path", "path": "status", "value": "closed"},
+            {"type": "json_path", "path": "$.missing"},
+            {"type": "regex", "value": "("},
+        ],
+    )
+    assert [result["passed"] for result in results] == [True, True, True, True, False, False, False]
+    assert results[4]["actual"] == "open"
+    assert results[6]["message"].startswith("Invalid regex")
+
+
+def test_agent_test_cases_crud_and_run(client: TestClient, sample_agent: Agent, db_session):
+    base = f"/api/agents/{sample_agent.id}/tests"
+    payload = {
+        "name": "Greets politely",
+        "input": "Say hello",
+        "assertions": [{"type": "contains", "value": "Hello"}, {"type": "equals", "value": "Hello there"}],
+    }
+    response = client.post(f"{base}/", json=payload)
+    assert response.status_code == 201
+    case = response.json()
+    assert case["last_result"] is None
+
+    with patch("zerg.managers.agent_runner.AgentRunner.run_thread", side_effect=_reply("Hello there!")):
+        response = client.post(f"{base}/{case['id']}/run")
+    assert response.status_code == 200
+    result = response.json()["last_result"]
+    assert result["passed"] is False
+    assert [item["passed"] for item in result["assertions"]] == [True, False]
+    assert result["assertions"][1]["actual"] == "Hello there!"
+    assert result["output"] == "Hello there!"
+
+    # The throwaway thread is gone
+    assert db_session.query(Thread).filter(Thread.agent_id == sample_agent.id).count() == 0
+
+    # Editing the case clears the stale result; run-all reports every case
+    response = client.put(f"{base}/{case['id']}", json={"assertions": [{"type": "contains", "value": "Hello"}]})
+    assert response.json()["last_result"] is None
+    with patch("zerg.managers.agent_runner.AgentRunner.run_thread", side_effect=_reply("Hello there!")):
+        response = client.post(f"{base}/run")
+    assert [item["last_result"]["passed"] for item in response.json()] == [True]
+
+    assert client.get(f"{base}/").json()[0]["last_result"]["passed"] is True
+    assert client.delete(f"{base}/{case['id']}").status_code == 204
+    assert client.get(f"{base}/").json() == []
+
+
+def test_agent_test_run_failure_is_a_failed_result(client: TestClient, sample_agent: Agent):
+    base = f"/api/agents/{sample_agent.id}/tests"
+    case = client.post(
+        f"{base}/", json={"name": "Boom", "input": "Hi", "assertions": [{"type": "contains", "value": "x"}]}
+    ).json()
+
+    with patch("zerg.managers.agent_runner.AgentRunner.run_thread", side_effect=RuntimeError("model offline")):
+        response = client.post(f"{base}/{case['id']}/run")
+    assert response.status_code == 200
+    result = response.json()["last_result"]
+    assert result["passed"] is False
+    assert result["error"] == "model offline"
+    assert result["assertions"][0]["message"] == "Not evaluated"
+
+    assert client.post(f"{base}/", json={"name": "No checks", "input": "Hi", "assertions": []}).status_code == 422
@@ -0,0 +1,69 @@
+"""Tests for the tool playground endpoints (list + invoke a single tool)."""
+
+from tests.conftest import TEST_MODEL
+from zerg.crud import crud
+
+
+def test_list_tools_includes_parameter_schema(client, sample_agent):
+    resp = client.get(f"/api/agents/{sample_agent.id}/tools/")
+    assert resp.status_code == 200, resp.text
+    tools = {t["name"]: t for t in resp.json()}
+    assert "math_eval" in tools
+    assert "expression" in tools["math_eval"]["parameters"]["properties"]
+
+
+def test_list_tools_respects_allowlist(client, db_session, sample_agent):
+    crud.update_agent(db_session, sample_agent.id, allowed_tools=["math_eval"])
+    resp = client.get(f"/api/agents/{sample_agent.id}/tools/")
+    assert [t["name"] for t in resp.json()] == ["math_eval"]
+
+
+def test_invoke_tool_returns_result(client, sample_agent):
+    resp = client.post(
+        f"/api/agents/{sample_agent.id}/tools/math_eval/invoke",
+        json={"args": {"expression": "(10 + 5) * 2"}},
+    )
+    assert resp.status_code == 200, resp.text
+    body = resp.json()
+    assert body["ok"] is True
+    assert body["result"] == 30
+    assert body["raw"] == "30"
+    assert body["error"] is None
+
+
+def test_invoke_tool_reports_tool_errors(client, sample_agent):
+    resp = client.post(
+        f"/api/agents/{sample_agent.id}/tools/math_eval/invoke",
+        json={"args": {"expression": "1 / 0"}},
+    )
+    assert resp.status_code == 200, resp.text
+    body = resp.json()
+    assert body["ok"] is False
+    assert "Division by zero" in body["error"]
+
+
+def test_invoke_tool_rejects_invalid_args(client, sample_agent):
+    resp = client.post(f"/api/agents/{sample_agent.id}/tools/math_eval/invoke", json={"args": {}})
+    assert resp.status_code == 422
+
+
+def test_invoke_tool_outside_allowlist_is_404(client, db_session, sample_agent):
+    crud.update_agent(db_session, sample_agent.id, allowed_tools=["http_request"])
+    resp = client.post(
+        f"/api/agents/{sample_agent.id}/tools/math_eval/invoke",
+        json={"args": {"expression": "1 + 1"}},
+    )
+    assert resp.status_code == 404
+
+
+def test_invoke_tool_requires_ownership(client, db_session, other_user):
+    agent = crud.create_agent(
+        db_session,
+        owner_id=other_user.id,
+        name="Not mine",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_MODEL,
+    )
+    resp = client.post(f"/api/agents/{agent.id}/tools/math_eval/invoke", json={"args": {"expression": "1"}})
+    assert resp.status_code == 404
@@ -1,9 +1,14 @@
+from datetime import timedelta
 from unittest.mock import MagicMock
 from unittest.mock import patch
 
 from fastapi.testclient import TestClient
 
+from zerg.crud import crud
+from zerg.managers.agent_runner import AgentRunner
 from zerg.models.models import Agent
+from zerg.models.models import Thread
+from zerg.models.models import ThreadMessage
 from tests.conftest import TEST_MODEL, TEST_WORKER_MODEL
 
 
@@ -148,6 +153,95 @@ def test_update_agent_allowed_tools(client: TestClient, sample_agent: Agent):
     assert updated_agent["allowed_tools"] == ["http_*", "mcp:github/*"]
 
 
+def test_update_agent_schedule(client: TestClient, sample_agent: Agent):
+    """Schedules can be set, rejected when invalid and cleared with an empty string"""
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"schedule": "30 9 * * mon-fri"})
+    assert response.status_code == 200
+    assert response.json()["schedule"] == "30 9 * * mon-fri"
+
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"schedule": "61 * * * *"})
+    assert response.status_code == 422
+
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"schedule": ""})
+    assert response.status_code == 200
+    assert response.json()["schedule"] is None
+    assert response.json()["next_run_at"] is None
+
+
+def test_update_agent_description(client: TestClient, sample_agent: Agent):
+    """The markdown README is stored trimmed and cleared with an empty string"""
+    readme = "## Inputs\n\n- A ticket URL\n"
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"description": f"  {readme}  "})
+    assert response.status_code == 200
+    assert response.json()["description"] == readme.strip()
+
+    # Leaving it out keeps the current text
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"name": "Renamed"})
+    assert response.json()["description"] == readme.strip()
+
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"description": ""})
+    assert response.json()["description"] is None
+
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"description": "x" * 20001})
+    assert response.status_code == 422
+
+
+def test_instruction_history_and_rollback(client: TestClient, sample_agent: Agent):
+    """Each instruction change is versioned; restoring adds a version instead of rewriting history"""
+    original = sample_agent.system_instructions
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"system_instructions": "Be terse."})
+    assert response.status_code == 200
+
+    # Saves that leave the instructions alone are not versions
+    client.put(f"/api/agents/{sample_agent.id}", json={"name": "Renamed"})
+    client.put(f"/api/agents/{sample_agent.id}", json={"system_instructions": "Be terse."})
+
+    versions = client.get(f"/api/agents/{sample_agent.id}/instruction-versions").json()
+    assert [v["version"] for v in versions] == [2, 1]
+    assert versions[0]["system_instructions"] == "Be terse."
+    assert versions[0]["author_email"]
+    # The pre-history text is kept as version 1
+    assert versions[1]["system_instructions"] == original
+
+    response = client.post(f"/api/agents/{sample_agent.id}/instruction-versions/{versions[1]['id']}/restore")
+    assert response.status_code == 200
+    assert response.json()["system_instructions"] == original
+
+    versions = client.get(f"/api/agents/{sample_agent.id}/instruction-versions").json()
+    assert versions[0]["version"] == 3
+    assert versions[0]["restored_from_version"] == 1
+
+    response = client.post(f"/api/agents/{sample_agent.id}/instruction-versions/999999/restore")
+    assert response.status_code == 404
+
+
+def test_compare_models_runs_each_model_without_saving(client: TestClient, sample_agent: Agent, db_session):
+    """Model comparison replies per model, leaves the saved model alone and keeps no threads"""
+
+    async def reply(runner, db, thread):
+        runner.usage_prompt_tokens, runner.usage_completion_tokens, runner.usage_total_tokens = 10, 5, 15
+        return [ThreadMessage(thread_id=thread.id, role="assistant", content=f"Answer from {runner.model}")]
+
+    models = [TEST_MODEL, TEST_WORKER_MODEL]
+    with patch.object(AgentRunner, "run_thread", autospec=True, side_effect=reply):
+        response = client.post(f"/api/agents/{sample_agent.id}/compare", json={"models": models, "input": "Hi"})
+    assert response.status_code == 200
+    body = response.json()
+    assert body["input"] == "Hi"
+    assert [result["model"] for result in body["results"]] == models
+    assert [result["output"] for result in body["results"]] == [f"Answer from {model}" for model in models]
+    assert body["results"][0]["total_tokens"] == 15
+
+    db_session.refresh(sample_agent)
+    assert sample_agent.model == TEST_MODEL
+    assert db_session.query(Thread).filter(Thread.agent_id == sample_agent.id).count() == 0
+
+    response = client.post(f"/api/agents/{sample_agent.id}/compare", json={"models": [TEST_MODEL, TEST_MODEL]})
+    assert response.status_code == 400
+    response = client.post(f"/api/agents/{sample_agent.id}/compare", json={"models": [TEST_MODEL, "no-such-model"]})
+    assert response.status_code == 400
+
+
 def test_update_agent_not_found(client: TestClient):
     """Test the PUT /api/agents/{agent_id} endpoint with a non-existent ID"""
     update_data = {"name": "This agent doesn't exist"}
@@ -168,6 +262,78 @@ def test_delete_agent(client: TestClient, sample_agent: Agent):
     assert response.status_code == 404
 
 
+def test_deleted_agent_moves_to_trash(client: TestClient, sample_agent: Agent):
+    """Deleted agents leave the listing and show up in the trash"""
+    assert client.delete(f"/api/agents/{sample_agent.id}").status_code == 204
+
+    listed_ids = [agent["id"] for agent in client.get("/api/agents").json()]
+    assert sample_agent.id not in listed_ids
+
+    trash = client.get("/api/agents/trash").json()
+    assert [agent["id"] for agent in trash] == [sample_agent.id]
+    assert trash[0]["deleted_at"] is not None
+
+
+def test_restore_agent_from_trash(client: TestClient, sample_agent: Agent):
+    """POST /api/agents/{agent_id}/restore brings a trashed agent back"""
+    client.delete(f"/api/agents/{sample_agent.id}")
+
+    response = client.post(f"/api/agents/{sample_agent.id}/restore")
+    assert response.status_code == 200
+    assert response.json()["deleted_at"] is None
+
+    assert client.get(f"/api/agents/{sample_agent.id}").status_code == 200
+    assert client.get("/api/agents/trash").json() == []
+
+
+def test_permanent_delete_requires_trash(client: TestClient, sample_agent: Agent):
+    """Agents must be trashed before they can be deleted for good"""
+    response = client.delete(f"/api/agents/{sample_agent.id}/permanent")
+    assert response.status_code == 409
+
+    client.delete(f"/api/agents/{sample_agent.id}")
+    assert client.delete(f"/api/agents/{sample_agent.id}/permanent").status_code == 204
+    assert client.get("/api/agents/trash").json() == []
+    assert client.post(f"/api/agents/{sample_agent.id}/restore").status_code == 404
+
+
+def test_delete_reports_and_disables_dependents(client: TestClient, sample_agent: Agent, db_session):
+    """Dependents are listed before deleting; disabling them is undone by restoring"""
+    crud.create_workflow(
+        db_session,
+        owner_id=sample_agent.owner_id,
+        name="Uses agent",
+        canvas={"nodes": [{"id": "a", "type": "agent", "config": {"agent_id": sample_agent.id}}], "edges": []},
+    )
+    trigger = client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "webhook"}).json()
+
+    dependents = client.get(f"/api/agents/{sample_agent.id}/dependents").json()
+    assert [workflow["name"] for workflow in dependents["workflows"]] == ["Uses agent"]
+    assert dependents["triggers"] == [{"id": trigger["id"], "type": "webhook", "disabled": False}]
+
+    response = client.delete(f"/api/agents/{sample_agent.id}", params={"disable_dependents": True})
+    assert response.status_code == 204
+    config = crud.get_trigger(db_session, trigger["id"]).config
+    assert config["disabled"] is True
+    assert config["disabled_reason"] == crud.AGENT_DELETED
+
+    client.post(f"/api/agents/{sample_agent.id}/restore")
+    db_session.expire_all()
+    assert "disabled" not in (crud.get_trigger(db_session, trigger["id"]).config or {})
+
+
+def test_trash_purges_expired_agents(client: TestClient, sample_agent: Agent, db_session):
+    """Agents trashed longer than the retention window are purged"""
+    client.delete(f"/api/agents/{sample_agent.id}")
+    db_session.refresh(sample_agent)
+    sample_agent.deleted_at = sample_agent.deleted_at - timedelta(days=crud.AGENT_TRASH_RETENTION_DAYS + 1)
+    db_session.commit()
+
+    assert client.get("/api/agents/trash").json() == []
+    db_session.expire_all()
+    assert crud.get_agent(db_session, sample_agent.id) is None
+
+
 def test_delete_agent_not_found(client: TestClient):
     """Test the DELETE /api/agents/{agent_id} endpoint with a non-existent ID"""
     response = client.delete("/api/agents/999")
@@ -119,6 +119,26 @@ class TestCanvasAPIContract:
         assert len(workflow_data["canvas"]["nodes"]) == 1, "Node not persisted"
         assert workflow_data["canvas"]["nodes"][0]["id"] == "persist_test_node", "Wrong node persisted"
 
+    def test_current_workflow_can_be_chosen_by_id(self, client, auth_headers):
+        """?workflow_id= opens and saves a specific workflow instead of the default one."""
+
+        client.get("/api/workflows/current", headers=auth_headers)
+        payload = {"canvas": {"nodes": [], "edges": []}}
+        other = client.post("/api/workflows/", json={"name": "Second", **payload}, headers=auth_headers).json()
+
+        loaded = client.get(f"/api/workflows/current?workflow_id={other['id']}", headers=auth_headers)
+        assert loaded.status_code == 200
+        assert loaded.json()["name"] == "Second"
+
+        saved = client.patch(
+            f"/api/workflows/current/canvas?workflow_id={other['id']}", json=payload, headers=auth_headers
+        )
+        assert saved.status_code == 200
+        assert saved.json()["id"] == other["id"]
+
+        missing = client.get("/api/workflows/current?workflow_id=999999", headers=auth_headers)
+        assert missing.status_code == 404
+
 
 def test_api_routes_comprehensive_check():
     """Comprehensive check of all expected canvas-related routes."""
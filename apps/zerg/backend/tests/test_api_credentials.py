@@ -0,0 +1,153 @@
+"""API credentials: the CRUD API, test connection, and use by HTTP nodes and MCP servers."""
+
+from types import SimpleNamespace
+from unittest.mock import AsyncMock
+from unittest.mock import patch
+
+import pytest
+
+from zerg.crud import crud
+from zerg.models.models import ApiCredential
+from zerg.routers import api_credentials as api_credentials_router
+from zerg.services.api_credentials import expand_mcp_servers
+from zerg.services.api_credentials import with_credential_headers
+from tests.conftest import TEST_WORKER_MODEL
+
+BODY = {
+    "name": "Weather",
+    "base_url": "https://api.weather.test/v1/",
+    "auth_header": "X-Api-Key: {{key}}",
+    "key": "wx-1234567890",
+}
+
+
+def _create(client, auth_headers, **overrides):
+    return client.post("/api/api-credentials/", headers=auth_headers, json={**BODY, **overrides})
+
+
+def test_keys_are_write_only_and_masked(client, auth_headers, db):
+    created = _create(client, auth_headers)
+    assert created.status_code == 201, created.text
+    data = created.json()
+    assert data["base_url"] == "https://api.weather.test/v1"
+    assert data["key_hint"] == "7890"
+    assert "key" not in data and "encrypted_key" not in data
+
+    row = db.query(ApiCredential).filter_by(id=data["id"]).one()
+    assert "wx-1234567890" not in row.encrypted_key
+
+    assert _create(client, auth_headers).status_code == 409
+    assert _create(client, auth_headers, name="Other", auth_header="X-Api-Key: static").status_code == 422
+    assert _create(client, auth_headers, name="Other", base_url="api.weather.test").status_code == 422
+
+    rotated = client.patch(f"/api/api-credentials/{row.id}", headers=auth_headers, json={"key": "wx-abcdefghij"})
+    assert rotated.json()["key_hint"] == "ghij"
+
+
+def test_test_connection_sends_the_auth_header(client, auth_headers):
+    credential = _create(client, auth_headers).json()
+    seen = {}
+
+    class FakeClient:
+        def __init__(self, **kwargs):
+            pass
+
+        async def __aenter__(self):
+            return self
+
+        async def __aexit__(self, *exc):
+            return False
+
+        async def get(self, url, headers):
+            seen.update(url=url, headers=headers)
+            return SimpleNamespace(status_code=401, is_error=True)
+
+    with patch.object(api_credentials_router.httpx, "AsyncClient", FakeClient):
+        result = client.post(
+            f"/api/api-credentials/{credential['id']}/test", headers=auth_headers, json={"path": "/me"}
+        ).json()
+
+    assert seen == {"url": "https://api.weather.test/v1/me", "headers": {"X-Api-Key": "wx-1234567890"}}
+    assert result["success"] is False
+    assert "rejected the key" in result["message"]
+    listed = client.get("/api/api-credentials/", headers=auth_headers).json()
+    assert listed[0]["test_status"] == "failed"
+
+
+def test_http_nodes_get_the_credential_header(client, auth_headers, db, test_user):
+    credential = _create(client, auth_headers).json()
+
+    with patch("zerg.routers.workflows.send_request", AsyncMock(return_value={"status_code": 200})) as send:
+        client.post(
+            "/api/workflows/http-request/test",
+            headers=auth_headers,
+            json={"url": "https://api.weather.test/v1/today", "credential_id": credential["id"]},
+        )
+    assert send.await_args.args[0].headers == {"X-Api-Key": "wx-1234567890"}
+
+    config = {"url": "https://api.weather.test/v1", "credential_id": credential["id"], "headers": {"X-Trace": "1"}}
+    applied = with_credential_headers(db, test_user.id, config)
+    assert applied["headers"] == {"X-Api-Key": "wx-1234567890", "X-Trace": "1"}
+    assert config["headers"] == {"X-Trace": "1"}
+
+    with pytest.raises(ValueError, match="no longer exists"):
+        with_credential_headers(db, test_user.id + 1, config)
+
+
+def test_credential_header_only_goes_to_its_base_url(client, auth_headers, db, test_user):
+    credential = _create(client, auth_headers).json()
+
+    def try_url(url):
+        with patch("zerg.routers.workflows.send_request", AsyncMock(return_value={"status_code": 200})) as send:
+            response = client.post(
+                "/api/workflows/http-request/test",
+                headers=auth_headers,
+                json={"url": url, "credential_id": credential["id"]},
+            )
+        return response, send
+
+    foreign_urls = [
+        "https://attacker.test/collect",
+        "https://api.weather.test.attacker.test/v1",
+        "https://api.weather.test/v2",
+    ]
+    for foreign in foreign_urls:
+        response, send = try_url(foreign)
+        assert response.status_code == 422
+        assert "outside the API credential's base URL" in response.json()["detail"]
+        send.assert_not_awaited()
+
+    response, send = try_url("/today?units=metric")
+    assert response.json()["url"] == "https://api.weather.test/v1/today?units=metric"
+    assert send.await_args.args[0].headers == {"X-Api-Key": "wx-1234567890"}
+
+    # MCP servers elsewhere don't get the key either
+    server = {"url": "https://attacker.test/mcp", "name": "evil", "api_credential_id": credential["id"]}
+    assert "headers" not in expand_mcp_servers(db, test_user.id, [server])[0]
+
+
+def test_mcp_servers_reference_credentials_by_id(client, auth_headers, db, test_user):
+    credential = _create(client, auth_headers, base_url="https://mcp.weather.test").json()
+    agent = crud.create_agent(
+        db=db,
+        owner_id=test_user.id,
+        name="MCP",
+        system_instructions="",
+        task_instructions="",
+        model=TEST_WORKER_MODEL,
+        schedule=None,
+        config=None,
+    )
+
+    response = client.post(
+        f"/api/agents/{agent.id}/mcp-servers/",
+        headers=auth_headers,
+        json={"name": "weather", "api_credential_id": credential["id"]},
+    )
+    assert response.status_code == 201, response.text
+    stored = response.json()["config"]["mcp_servers"][0]
+    assert stored == {"url": "https://mcp.weather.test", "name": "weather", "api_credential_id": credential["id"]}
+
+    assert expand_mcp_servers(db, test_user.id, [stored])[0]["headers"] == {"X-Api-Key": "wx-1234567890"}
+    # Another user's agent config can't borrow the key
+    assert "headers" not in expand_mcp_servers(db, test_user.id + 1, [stored])[0]
@@ -115,3 +115,30 @@ def test_auth_guard_dev_bypass(monkeypatch, client: TestClient, db_session):
     # Ensure the dev user exists in DB
     dev_user = crud.get_user_by_email(db_session, auth_dep.DEV_EMAIL)
     assert dev_user is not None
+
+
+# ---------------------------------------------------------------------------
+# Session refresh
+# ---------------------------------------------------------------------------
+
+
+def test_refresh_extends_valid_session(unauthenticated_client: TestClient, db_session):
+    """POST /api/auth/refresh swaps a valid session cookie for a fresh one."""
+
+    user = crud.create_user(db_session, email="refresh@example.com", provider="google", provider_user_id="g-refresh")
+    old_token = auth_router._issue_access_token(user.id, user.email)
+    unauthenticated_client.cookies.set(auth_router.SESSION_COOKIE_NAME, old_token)
+
+    resp = unauthenticated_client.post("/api/auth/refresh")
+    assert resp.status_code == 200, resp.text
+    body = resp.json()
+    assert body["expires_in"] == 30 * 60
+    assert _decode_jwt(body["access_token"])["sub"] == str(user.id)
+    assert auth_router.SESSION_COOKIE_NAME in resp.cookies
+
+
+def test_refresh_rejects_missing_session(unauthenticated_client: TestClient):
+    """Without a session there is nothing to refresh."""
+
+    resp = unauthenticated_client.post("/api/auth/refresh")
+    assert resp.status_code == 401
@@ -88,3 +88,41 @@ async def test_admin_exempt_from_budgets(client, db_session, monkeypatch):
     finally:
         with contextlib.suppress(Exception):
             del app.dependency_overrides[get_current_user]
+
+
+@pytest.mark.asyncio
+async def test_agent_budget_denies_when_exhausted_even_for_admin(client, db_session, monkeypatch):
+    monkeypatch.setenv("DAILY_COST_PER_USER_CENTS", "0")
+    monkeypatch.setenv("DAILY_COST_GLOBAL_CENTS", "0")
+
+    admin = _ensure_user_role(db_session, "agent-budget@local", "ADMIN")
+    from zerg.dependencies.auth import get_current_user
+
+    app.dependency_overrides[get_current_user] = lambda: admin
+    try:
+        agent, thread = _agent_and_thread(db_session, admin.id)
+        agent.config = {"budget": {"period": "monthly", "limit_usd": 0.5}}
+        db_session.commit()
+
+        run = crud.create_run(db_session, agent_id=agent.id, thread_id=thread.id, trigger="api", status="queued")
+        crud.mark_running(db_session, run.id)
+        crud.mark_finished(db_session, run.id, total_tokens=10, total_cost_usd=0.75)
+
+        r = client.post(f"/api/threads/{thread.id}/run")
+        assert r.status_code == 429, r.text
+        assert "agent monthly budget exhausted" in r.text.lower()
+    finally:
+        with contextlib.suppress(Exception):
+            del app.dependency_overrides[get_current_user]
+
+
+def test_agent_budget_limit_ignores_invalid_config():
+    from types import SimpleNamespace
+
+    from zerg.services.quota import agent_budget_limit
+
+    assert agent_budget_limit(SimpleNamespace(config=None)) is None
+    assert agent_budget_limit(SimpleNamespace(config={"budget": {"period": "weekly", "limit_usd": 5}})) is None
+    assert agent_budget_limit(SimpleNamespace(config={"budget": {"period": "daily", "limit_usd": "abc"}})) is None
+    valid = SimpleNamespace(config={"budget": {"period": "daily", "limit_usd": "5"}})
+    assert agent_budget_limit(valid) == ("daily", 5.0)
@@ -0,0 +1,109 @@
+"""Switch and loop nodes: branch routing and bounded iteration in the workflow engine."""
+
+from unittest.mock import patch
+
+import pytest
+
+from zerg.models.models import NodeExecutionState
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowEdge
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.workflow_engine import workflow_engine
+
+
+def _node(node_id: str, node_type: str, **config) -> WorkflowNode:
+    return WorkflowNode(id=node_id, type=node_type, position=Position(x=0, y=0), config=config)
+
+
+def _edge(source: str, target: str, branch: str | None = None) -> WorkflowEdge:
+    return WorkflowEdge(from_node_id=source, to_node_id=target, config={"branch": branch} if branch else {})
+
+
+class _RecordingTools:
+    """Tool resolver whose tools return canned values and record the params they ran with."""
+
+    def __init__(self, results):
+        self.results = results
+        self.calls = []
+
+    def get_tool(self, name):
+        recorder = self
+
+        class _Tool:
+            def run(self, params):
+                recorder.calls.append((name, params))
+                return recorder.results.get(name)
+
+        return _Tool()
+
+
+async def _run(db, owner_id: int, workflow_data: WorkflowData, tools: _RecordingTools) -> WorkflowExecution:
+    workflow = Workflow(owner_id=owner_id, name="Control flow", canvas=workflow_data.model_dump(), is_active=True)
+    db.add(workflow)
+    db.commit()
+    with patch("zerg.services.node_executors.get_tool_resolver", return_value=tools):
+        execution_id = await workflow_engine.execute_workflow(workflow.id)
+    return db.query(WorkflowExecution).filter_by(id=execution_id).first()
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize("category, expected", [("billing", "billing_tool"), ("spam", "fallback_tool")])
+async def test_switch_routes_to_matching_case(db, test_user, category, expected):
+    tools = _RecordingTools({"classify": category})
+    workflow_data = WorkflowData(
+        nodes=[
+            _node("classify", "tool", tool_name="classify"),
+            _node("switch-1", "switch", expression="${classify}", cases=["billing", "support"]),
+            _node("billing", "tool", tool_name="billing_tool"),
+            _node("support", "tool", tool_name="support_tool"),
+            _node("fallback", "tool", tool_name="fallback_tool"),
+        ],
+        edges=[
+            _edge("classify", "switch-1"),
+            _edge("switch-1", "billing", "billing"),
+            _edge("switch-1", "support", "support"),
+            _edge("switch-1", "fallback", "default"),
+        ],
+    )
+
+    execution = await _run(db, test_user.id, workflow_data, tools)
+
+    assert execution.result == "success"
+    assert [name for name, _ in tools.calls] == ["classify", expected]
+
+
+@pytest.mark.asyncio
+async def test_loop_runs_body_once_per_item_within_bound(db, test_user):
+    tools = _RecordingTools({"list": ["a", "b", "c"], "echo": "ok", "finish": "done"})
+    workflow_data = WorkflowData(
+        nodes=[
+            _node("list", "tool", tool_name="list"),
+            _node("loop-1", "loop", items="${list}", max_iterations=2),
+            _node("echo", "tool", tool_name="echo", static_params={"item": "${loop-1.value.item}"}),
+            _node("finish", "tool", tool_name="finish"),
+        ],
+        edges=[
+            _edge("list", "loop-1"),
+            _edge("loop-1", "echo", "body"),
+            # The body's last node links back to the loop
+            _edge("echo", "loop-1"),
+            _edge("loop-1", "finish", "done"),
+        ],
+    )
+
+    execution = await _run(db, test_user.id, workflow_data, tools)
+
+    assert execution.result == "success"
+    assert tools.calls == [("list", {}), ("echo", {"item": "a"}), ("echo", {"item": "b"}), ("finish", {})]
+    loop_states = db.query(NodeExecutionState).filter_by(workflow_execution_id=execution.id, node_id="loop-1").all()
+    assert len(loop_states) == 3  # two passes through the body, then done
+    assert loop_states[-1].output["value"] == {
+        "index": 2,
+        "item": None,
+        "count": 2,
+        "truncated": True,
+        "branch": "done",
+    }
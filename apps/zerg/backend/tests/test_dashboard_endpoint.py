@@ -86,3 +86,23 @@ def test_dashboard_snapshot_zero_limit_returns_empty_runs(
 
     data = response.json()
     assert all(not item["runs"] for item in data["runs"])
+
+
+def test_dashboard_snapshot_reports_agent_budget(
+    client: TestClient,
+    db_session: Session,
+    sample_agent,
+    sample_thread,
+):
+    sample_agent.config = {"budget": {"period": "daily", "limit_usd": 2.0}}
+    db_session.commit()
+    _create_finished_run(db_session, sample_agent.id, sample_thread.id, tokens=10, cost=0.5)
+
+    response = client.get("/api/agents/dashboard?runs_limit=1")
+    assert response.status_code == 200
+
+    budget = next(item for item in response.json()["budgets"] if item["agent_id"] == sample_agent.id)
+    assert budget["period"] == "daily"
+    assert budget["limit_usd"] == 2.0
+    assert budget["spent_usd"] == 0.5
+    assert budget["percent"] == 25.0
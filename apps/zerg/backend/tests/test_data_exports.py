@@ -0,0 +1,118 @@
+"""Tests for the self-service account export (/users/me/exports)."""
+
+import json
+import zipfile
+from datetime import timedelta
+
+import pytest
+from fastapi.testclient import TestClient
+
+from zerg.models.models import DataExport
+from zerg.models.models import EnvironmentVariable
+from zerg.models.models import Workflow
+from zerg.services import data_export
+from zerg.utils.crypto import encrypt
+from zerg.utils.time import utc_now_naive
+
+
+@pytest.fixture(autouse=True)
+def _exports_dir(tmp_path, monkeypatch):
+    monkeypatch.setattr(data_export, "EXPORTS_DIR", tmp_path)
+    return tmp_path
+
+
+def test_export_contains_owned_data(client: TestClient, db_session, _dev_user, sample_agent, sample_thread_messages):
+    db_session.add(Workflow(owner_id=_dev_user.id, name="wf-export", canvas={"nodes": [], "edges": []}))
+    db_session.commit()
+
+    response = client.post("/api/users/me/exports")
+    assert response.status_code == 202
+    export_id = response.json()["id"]
+
+    # TestClient runs background tasks before returning the response
+    status_response = client.get(f"/api/users/me/exports/{export_id}")
+    assert status_response.json()["status"] == "success"
+    assert status_response.json()["size_bytes"] > 0
+
+    download = client.get(f"/api/users/me/exports/{export_id}/download")
+    assert download.status_code == 200
+    assert download.headers["content-type"] == "application/zip"
+
+    archive_path = data_export.EXPORTS_DIR / "download.zip"
+    archive_path.write_bytes(download.content)
+    with zipfile.ZipFile(archive_path) as archive:
+        manifest = json.loads(archive.read("manifest.json"))
+        assert manifest["counts"]["agents"] == 1
+        assert manifest["counts"]["workflows"] == 1
+        threads = json.loads(archive.read("threads.json"))
+        assert len(threads[0]["messages"]) == len(sample_thread_messages)
+
+    history = client.get("/api/users/me/exports").json()
+    assert [item["id"] for item in history] == [export_id]
+
+
+def test_export_leaves_out_secrets_and_says_so(client: TestClient, db_session, _dev_user, sample_agent):
+    trigger = client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "webhook"}).json()
+    db_session.add_all(
+        [
+            EnvironmentVariable(owner_id=_dev_user.id, name="REGION", value="eu"),
+            EnvironmentVariable(owner_id=_dev_user.id, name="API_TOKEN", value=encrypt("s3cret"), is_secret=True),
+        ]
+    )
+    db_session.commit()
+
+    export_id = client.post("/api/users/me/exports").json()["id"]
+    download = client.get(f"/api/users/me/exports/{export_id}/download")
+    archive_path = data_export.EXPORTS_DIR / "download.zip"
+    archive_path.write_bytes(download.content)
+
+    with zipfile.ZipFile(archive_path) as archive:
+        manifest = json.loads(archive.read("manifest.json"))
+        assert "api_credentials" in manifest["omitted"]
+        assert {"instruction_versions", "knowledge_documents", "trigger_deliveries"} <= set(manifest["counts"])
+        [exported_trigger] = json.loads(archive.read("triggers.json"))
+        assert exported_trigger["id"] == trigger["id"] and "secret" not in exported_trigger
+        env_vars = json.loads(archive.read("env_vars.json"))
+        assert [(var["name"], var["value"]) for var in env_vars] == [("REGION", "eu"), ("API_TOKEN", None)]
+        assert b"s3cret" not in b"".join(archive.read(name) for name in archive.namelist())
+
+
+def test_only_the_latest_archives_are_kept(client: TestClient, db_session, _dev_user, monkeypatch):
+    monkeypatch.setattr(data_export, "EXPORT_HISTORY_LIMIT", 2)
+
+    ids = [client.post("/api/users/me/exports").json()["id"] for _ in range(3)]
+
+    assert [row.id for row in db_session.query(DataExport).order_by(DataExport.id)] == ids[1:]
+    assert sorted(path.name for path in data_export.EXPORTS_DIR.iterdir()) == [
+        f"zerg-export-{_dev_user.id}-{export_id}.zip" for export_id in ids[1:]
+    ]
+
+
+def test_stale_export_no_longer_blocks_new_ones(client: TestClient, db_session, _dev_user):
+    stuck = DataExport(owner_id=_dev_user.id, status="running", created_at=utc_now_naive() - timedelta(hours=2))
+    db_session.add(stuck)
+    db_session.commit()
+
+    response = client.post("/api/users/me/exports")
+    assert response.status_code == 202
+
+    db_session.refresh(stuck)
+    assert stuck.status == "failed"
+    assert "interrupted" in stuck.error
+
+
+def test_export_rejects_concurrent_request(client: TestClient, db_session, _dev_user):
+    db_session.add(DataExport(owner_id=_dev_user.id, status="running"))
+    db_session.commit()
+
+    response = client.post("/api/users/me/exports")
+    assert response.status_code == 409
+
+
+def test_cannot_access_other_users_export(client: TestClient, db_session, other_user):
+    foreign = DataExport(owner_id=other_user.id, status="success", file_name="x.zip")
+    db_session.add(foreign)
+    db_session.commit()
+
+    assert client.get(f"/api/users/me/exports/{foreign.id}").status_code == 404
+    assert client.get(f"/api/users/me/exports/{foreign.id}/download").status_code == 404
@@ -0,0 +1,109 @@
+"""Email trigger filters: Gmail query translation, storage and preview."""
+
+import pytest
+
+from zerg.crud import crud
+from zerg.services import email_filtering
+from zerg.services import gmail_api as gmail_api_mod
+from zerg.utils import crypto
+
+
+def test_to_gmail_query_composes_terms():
+    filters = {
+        "from_contains": ["alice@example.com", "bob"],
+        "subject_contains": ["weekly report"],
+        "label_include": ["INBOX"],
+        "has_attachment": True,
+    }
+    assert (
+        email_filtering.to_gmail_query(filters)
+        == 'from:(alice@example.com OR bob) subject:"weekly report" has:attachment'
+    )
+    assert email_filtering.to_gmail_query(None) == ""
+
+
+def test_has_attachment_filter():
+    meta = {"labelIds": [], "headers": {"From": "a@b.c", "Subject": "Hi"}, "hasAttachment": False}
+    assert not email_filtering.matches(meta, {"has_attachment": True})
+    assert email_filtering.matches({**meta, "hasAttachment": True}, {"has_attachment": True})
+    assert email_filtering.matches(meta, {"has_attachment": False})
+
+
+@pytest.fixture
+def gmail_connector(db_session, _dev_user):
+    for existing in crud.get_connectors(db_session, owner_id=_dev_user.id, type="email", provider="gmail"):
+        crud.delete_connector(db_session, existing.id)
+    return crud.create_connector(
+        db_session,
+        owner_id=_dev_user.id,
+        type="email",
+        provider="gmail",
+        config={"refresh_token": crypto.encrypt("refresh"), "history_id": 0},
+    )
+
+
+def test_patch_stores_compacted_filters(client, sample_agent, gmail_connector):
+    resp = client.post(
+        "/api/triggers/",
+        json={"agent_id": sample_agent.id, "type": "email", "config": {"connector_id": gmail_connector.id}},
+    )
+    assert resp.status_code == 201, resp.text
+    trigger_id = resp.json()["id"]
+
+    resp = client.patch(
+        f"/api/triggers/{trigger_id}",
+        json={"filters": {"from_contains": [" alice@example.com ", ""], "has_attachment": True}},
+    )
+    assert resp.status_code == 200, resp.text
+    config = resp.json()["config"]
+    assert config["filters"] == {"from_contains": ["alice@example.com"], "has_attachment": True}
+    assert config["connector_id"] == gmail_connector.id
+
+    cleared = client.patch(f"/api/triggers/{trigger_id}", json={"filters": None})
+    assert "filters" not in cleared.json()["config"]
+
+    bad = client.patch(f"/api/triggers/{trigger_id}", json={"filters": {"sender": "x"}})
+    assert bad.status_code == 422
+
+
+def test_preview_returns_matching_messages(client, gmail_connector, monkeypatch):
+    seen = {}
+
+    def _list_messages(_token, *, query="", label_ids=None, max_results=20):
+        seen.update(query=query, label_ids=label_ids, max_results=max_results)
+        return ["m1", "m2"]
+
+    def _get_meta(_token, mid):
+        return {
+            "id": mid,
+            "labelIds": ["INBOX"] if mid == "m1" else ["INBOX", "SPAM"],
+            "headers": {"From": "alice@example.com", "Subject": f"Report {mid}"},
+            "snippet": "numbers",
+            "hasAttachment": True,
+        }
+
+    monkeypatch.setattr(gmail_api_mod, "exchange_refresh_token", lambda _rt: "access")
+    monkeypatch.setattr(gmail_api_mod, "list_messages", _list_messages)
+    monkeypatch.setattr(gmail_api_mod, "get_message_metadata", _get_meta)
+
+    resp = client.post(
+        "/api/triggers/email/preview",
+        json={
+            "connector_id": gmail_connector.id,
+            "filters": {"from_contains": ["alice"], "label_include": ["INBOX"], "label_exclude": ["SPAM"]},
+            "limit": 5,
+        },
+    )
+    assert resp.status_code == 200, resp.text
+    body = resp.json()
+    assert body["query"] == "from:alice"
+    assert body["scanned"] == 2
+    assert [m["id"] for m in body["messages"]] == ["m1"]
+    assert body["messages"][0]["sender"] == "alice@example.com"
+    assert seen == {"query": "from:alice", "label_ids": ["INBOX"], "max_results": 5}
+
+
+def test_preview_rejects_foreign_connector(client, db_session, other_user):
+    conn = crud.create_connector(db_session, owner_id=other_user.id, type="email", provider="gmail", config={})
+    resp = client.post("/api/triggers/email/preview", json={"connector_id": conn.id})
+    assert resp.status_code == 404
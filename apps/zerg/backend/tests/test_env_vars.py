@@ -0,0 +1,157 @@
+"""Environment variables: the CRUD API, scope precedence and {{env.NAME}} rendering."""
+
+from unittest.mock import patch
+
+import pytest
+
+from zerg.crud import crud
+from zerg.events import EventType
+from zerg.models.models import EnvironmentVariable
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.env_vars import find_env_references
+from zerg.services.env_vars import load_env
+from zerg.services.env_vars import render_agent_instructions
+from zerg.services.env_vars import render_env
+from zerg.services.execution_control import execution_controls
+from zerg.services.thread_service import ThreadService
+from zerg.services.workflow_engine import workflow_engine
+from zerg.utils.crypto import encrypt
+
+
+def _workflow(db, test_user, nodes=()):
+    canvas = WorkflowData(nodes=list(nodes), edges=[]).model_dump()
+    workflow = Workflow(owner_id=test_user.id, name="Env", canvas=canvas, is_active=True)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+def test_secret_values_are_write_only(client, auth_headers, db, test_user):
+    created = client.post(
+        "/api/env-vars/", headers=auth_headers, json={"name": "API_TOKEN", "value": "s3cret", "is_secret": True}
+    )
+    assert created.status_code == 201
+    assert created.json()["value"] is None
+
+    row = db.query(EnvironmentVariable).filter_by(id=created.json()["id"]).first()
+    assert row.value != "s3cret"
+    assert load_env(db, test_user.id) == {"API_TOKEN": "s3cret"}
+
+    listed = client.get("/api/env-vars/", headers=auth_headers).json()
+    assert [(item["name"], item["value"]) for item in listed] == [("API_TOKEN", None)]
+
+    # Renaming keeps the hidden value; un-hiding it needs a new one
+    renamed = client.patch(f"/api/env-vars/{row.id}", headers=auth_headers, json={"name": "TOKEN"})
+    assert renamed.json()["name"] == "TOKEN"
+    assert load_env(db, test_user.id) == {"TOKEN": "s3cret"}
+    reveal = client.patch(f"/api/env-vars/{row.id}", headers=auth_headers, json={"is_secret": False})
+    assert reveal.status_code == 400
+
+
+def test_names_are_unique_per_scope(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user)
+    body = {"name": "REGION", "value": "eu"}
+
+    assert client.post("/api/env-vars/", headers=auth_headers, json=body).status_code == 201
+    assert client.post("/api/env-vars/", headers=auth_headers, json=body).status_code == 409
+    scoped = client.post("/api/env-vars/", headers=auth_headers, json={**body, "workflow_id": workflow.id})
+    assert scoped.status_code == 201
+    assert scoped.json()["workflow_name"] == "Env"
+
+    bad = client.post("/api/env-vars/", headers=auth_headers, json={"name": "2FAST", "value": "x"})
+    assert bad.status_code == 422
+
+
+def test_workflow_scope_overrides_workspace(db, test_user):
+    workflow = _workflow(db, test_user)
+    db.add_all(
+        [
+            EnvironmentVariable(owner_id=test_user.id, name="REGION", value="eu"),
+            EnvironmentVariable(owner_id=test_user.id, name="REGION", value="us", workflow_id=workflow.id),
+            EnvironmentVariable(owner_id=test_user.id, name="TEAM", value="ops"),
+        ]
+    )
+    db.commit()
+
+    assert load_env(db, test_user.id) == {"REGION": "eu", "TEAM": "ops"}
+    assert load_env(db, test_user.id, workflow.id) == {"REGION": "us", "TEAM": "ops"}
+
+
+def test_render_env_leaves_undefined_references():
+    config = {"url": "https://{{ env.HOST }}/v1", "headers": ["{{env.MISSING}}"], "retries": 3}
+
+    assert find_env_references(config) == ["HOST", "MISSING"]
+    assert render_env(config, {"HOST": "api.example.com"}) == {
+        "url": "https://api.example.com/v1",
+        "headers": ["{{env.MISSING}}"],
+        "retries": 3,
+    }
+
+
+@pytest.mark.asyncio
+async def test_nodes_see_env_values(db, test_user):
+    node = WorkflowNode(
+        id="check",
+        type="conditional",
+        position=Position(x=0, y=0),
+        config={"condition": "{{env.LIMIT}} > 1", "condition_type": "expression"},
+    )
+    workflow = _workflow(db, test_user, [node])
+    db.add(EnvironmentVariable(owner_id=test_user.id, name="LIMIT", value="5", workflow_id=workflow.id))
+    db.commit()
+
+    execution_id = await workflow_engine.execute_workflow(workflow.id)
+
+    state = db.query(WorkflowExecution).filter_by(id=execution_id).first().node_states[0]
+    assert state.output["value"]["result"] is True
+
+
+@pytest.mark.asyncio
+async def test_pause_events_never_carry_secret_values(db, test_user):
+    config = {"condition": "{{env.SECRET}} > 1", "condition_type": "expression", "breakpoint": True}
+    node = WorkflowNode(id="check", type="conditional", position=Position(x=0, y=0), config=config)
+    workflow = _workflow(db, test_user, [node])
+    db.add(EnvironmentVariable(owner_id=test_user.id, name="SECRET", value=encrypt("5"), is_secret=True))
+    db.commit()
+
+    paused = []
+
+    async def record(event_type, data):
+        if event_type == EventType.EXECUTION_PAUSED:
+            paused.append(data)
+            execution_controls.get(data["execution_id"]).resume(keep_stepping=False)
+
+    with patch("zerg.services.workflow_engine.publish_event", record):
+        execution_id = await workflow_engine.execute_workflow(workflow.id)
+
+    [event] = paused
+    assert event["config"] == config
+    # The node itself still ran with the decrypted value
+    state = db.query(WorkflowExecution).filter_by(id=execution_id).first().node_states[0]
+    assert state.output["value"]["result"] is True
+
+
+def test_agent_instructions_use_workspace_values(db, sample_agent):
+    db.add(EnvironmentVariable(owner_id=sample_agent.owner_id, name="TEAM", value="ops"))
+    db.commit()
+
+    assert render_agent_instructions(db, sample_agent, "Page {{env.TEAM}} on {{ env.NOPE }}") == (
+        "Page ops on {{ env.NOPE }}"
+    )
+
+
+def test_agent_instructions_never_store_secret_values(db, sample_agent):
+    db.add(EnvironmentVariable(owner_id=sample_agent.owner_id, name="TEAM", value="ops"))
+    db.add(
+        EnvironmentVariable(owner_id=sample_agent.owner_id, name="API_TOKEN", value=encrypt("s3cret"), is_secret=True)
+    )
+    sample_agent.system_instructions = "Page {{env.TEAM}} with {{env.API_TOKEN}}"
+    db.commit()
+
+    thread = ThreadService.create_thread_with_system_message(db, sample_agent, title="Env")
+    [system] = crud.get_thread_messages(db, thread.id)
+    assert system.content == "Page ops with {{env.API_TOKEN}}"
@@ -0,0 +1,131 @@
+"""Tests for step-mode run control (services/execution_control.py)."""
+
+import asyncio
+
+import pytest
+
+from zerg.services.execution_control import ExecutionAborted
+from zerg.services.execution_control import ExecutionController
+
+
+async def _wait_until_paused(controller: ExecutionController, node_id: str):
+    for _ in range(100):
+        if controller.paused_node_id == node_id:
+            return
+        await asyncio.sleep(0.01)
+    raise AssertionError(f"{node_id} never paused")
+
+
+@pytest.mark.asyncio
+async def test_gate_is_a_no_op_outside_step_mode():
+    controller = ExecutionController(1)
+    paused = []
+
+    async def on_pause(node_id, reason):
+        paused.append((node_id, reason))
+
+    assert await controller.gate("a", {"x": 1}, on_pause) == {"x": 1}
+    assert paused == []
+
+
+@pytest.mark.asyncio
+async def test_step_releases_one_node_with_config_override():
+    controller = ExecutionController(1, step_mode=True)
+    paused = []
+
+    async def on_pause(node_id, reason):
+        paused.append((node_id, reason))
+
+    task = asyncio.create_task(controller.gate("a", {"message": "original"}, on_pause))
+    await _wait_until_paused(controller, "a")
+    assert paused == [("a", "step")]
+    assert not task.done()
+
+    controller.resume(config_override={"message": "edited"})
+    assert await task == {"message": "edited"}
+    assert controller.paused_node_id is None
+    # Still stepping: the next node parks too
+    assert controller.step_mode is True
+
+
+@pytest.mark.asyncio
+async def test_continue_runs_queued_nodes_without_pausing():
+    controller = ExecutionController(1, step_mode=True)
+
+    async def on_pause(node_id, reason):
+        pass
+
+    first = asyncio.create_task(controller.gate("a", {}, on_pause))
+    await _wait_until_paused(controller, "a")
+    # A parallel branch queues behind the parked node
+    second = asyncio.create_task(controller.gate("b", {"b": True}, on_pause))
+
+    controller.resume(keep_stepping=False)
+    assert await first == {}
+    assert await second == {"b": True}
+
+
+@pytest.mark.asyncio
+async def test_abort_fails_the_parked_node():
+    controller = ExecutionController(1, step_mode=True)
+
+    async def on_pause(node_id, reason):
+        pass
+
+    task = asyncio.create_task(controller.gate("a", {}, on_pause))
+    await _wait_until_paused(controller, "a")
+    controller.abort()
+
+    with pytest.raises(ExecutionAborted):
+        await task
+
+
+@pytest.mark.asyncio
+async def test_breakpoint_pauses_outside_step_mode():
+    controller = ExecutionController(1)
+    paused = []
+
+    async def on_pause(node_id, reason):
+        paused.append((node_id, reason))
+
+    # Nodes without a breakpoint run straight through
+    assert await controller.gate("a", {}, on_pause) == {}
+
+    task = asyncio.create_task(controller.gate("b", {"breakpoint": True}, on_pause, breakpoint=True))
+    await _wait_until_paused(controller, "b")
+    assert paused == [("b", "breakpoint")]
+
+    controller.resume(keep_stepping=False)
+    assert await task == {"breakpoint": True}
+
+
+@pytest.mark.asyncio
+async def test_aborted_execution_never_reaches_later_breakpoints():
+    controller = ExecutionController(1)
+    controller.abort()
+
+    async def on_pause(node_id, reason):
+        raise AssertionError("should not pause")
+
+    with pytest.raises(ExecutionAborted):
+        await controller.gate("a", {}, on_pause, breakpoint=True)
+
+
+def test_resume_requires_a_paused_node():
+    controller = ExecutionController(1, step_mode=True)
+    with pytest.raises(ValueError):
+        controller.resume()
+
+
+def test_step_endpoint_rejects_executions_that_are_not_paused(client, db_session, test_user):
+    from zerg.crud import crud
+
+    workflow = crud.create_workflow(db_session, owner_id=test_user.id, name="wf", description="", canvas={})
+    execution = crud.create_workflow_execution(db_session, workflow_id=workflow.id, phase="waiting")
+
+    response = client.post(f"/api/workflow-executions/{execution.id}/step")
+    assert response.status_code == 409
+
+    status = client.get(f"/api/workflow-executions/{execution.id}/status").json()
+    assert status["step_mode"] is False
+    assert status["paused_node_id"] is None
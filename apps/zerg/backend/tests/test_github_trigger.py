@@ -0,0 +1,133 @@
+"""GitHub triggers: config validation and the per-trigger webhook endpoint."""
+
+import hashlib
+import hmac
+import json
+import uuid
+
+from zerg.models.models import AccountConnectorCredential
+from zerg.services.scheduler_service import scheduler_service
+
+
+def _connect_github(db, owner_id):
+    db.add(
+        AccountConnectorCredential(
+            owner_id=owner_id,
+            connector_type="github",
+            encrypted_value="unused",
+            connector_metadata={"login": "octocat", "connected_via": "oauth"},
+        )
+    )
+    db.commit()
+
+
+def _deliver(client, trigger, event, payload, secret=None, delivery_id=None):
+    body = json.dumps(payload).encode()
+    digest = hmac.new((secret or trigger["secret"]).encode(), body, hashlib.sha256).hexdigest()
+    return client.post(
+        f"/api/github/webhooks/{trigger['id']}",
+        content=body,
+        headers={
+            "X-GitHub-Event": event,
+            "X-GitHub-Delivery": delivery_id or str(uuid.uuid4()),
+            "X-Hub-Signature-256": f"sha256={digest}",
+        },
+    )
+
+
+def _github_trigger(client, db, sample_agent, monkeypatch):
+    """A pull_request/opened trigger whose agent runs are collected instead of started."""
+    _connect_github(db, sample_agent.owner_id)
+    config = {"repo": "octocat/hello", "events": ["pull_request"], "actions": ["opened"]}
+    trigger = client.post(
+        "/api/triggers/", json={"agent_id": sample_agent.id, "type": "github", "config": config}
+    ).json()
+
+    runs = []
+
+    async def _stub_run_agent_task(agent_id: int, trigger: str = "schedule"):
+        runs.append(agent_id)
+
+    monkeypatch.setattr(scheduler_service, "run_agent_task", _stub_run_agent_task)
+    return trigger, runs
+
+
+def _pr_event(action, repo="octocat/hello"):
+    return {
+        "action": action,
+        "repository": {"full_name": repo},
+        "pull_request": {"number": 7, "title": "Fix", "labels": [{"name": "bug"}]},
+    }
+
+
+def test_github_trigger_requires_connection_repo_and_events(client, db, sample_agent):
+    body = {"agent_id": sample_agent.id, "type": "github", "config": {"repo": "octocat/hello", "events": ["push"]}}
+    assert client.post("/api/triggers/", json=body).status_code == 400
+
+    _connect_github(db, sample_agent.owner_id)
+    bad_repo = client.post("/api/triggers/", json={**body, "config": {"repo": "hello", "events": ["push"]}})
+    assert bad_repo.status_code == 422
+    bad_event = client.post("/api/triggers/", json={**body, "config": {"repo": "octocat/hello", "events": ["fork"]}})
+    assert bad_event.status_code == 422
+
+    config = {"repo": " octocat/hello ", "events": ["push", "pull_request"], "actions": ["opened", " "]}
+    created = client.post("/api/triggers/", json={**body, "config": config})
+    assert created.status_code == 201, created.text
+    assert created.json()["config"] == {
+        "repo": "octocat/hello",
+        "events": ["pull_request", "push"],
+        "actions": ["opened"],
+    }
+
+
+def test_webhook_fires_on_matching_events_and_records_deliveries(client, db, sample_agent, monkeypatch):
+    trigger, runs = _github_trigger(client, db, sample_agent, monkeypatch)
+
+    assert _deliver(client, trigger, "ping", {"zen": "hi"}).json() == {"ok": True}
+    assert _deliver(client, trigger, "pull_request", _pr_event("opened")).json()["fired"] is True
+    assert _deliver(client, trigger, "pull_request", _pr_event("closed")).json()["fired"] is False
+    assert _deliver(client, trigger, "pull_request", _pr_event("opened", "someone/else")).json()["fired"] is False
+    assert _deliver(client, trigger, "push", {"repository": {"full_name": "octocat/hello"}}).json()["fired"] is False
+    assert _deliver(client, trigger, "pull_request", _pr_event("opened"), secret="wrong").status_code == 403
+    assert runs == [sample_agent.id]
+
+    deliveries = client.get(f"/api/triggers/{trigger['id']}/deliveries").json()
+    # The unsigned attempt is refused without being recorded
+    assert [d["status_code"] for d in deliveries] == [200, 200, 200, 202, 200]
+    assert deliveries[1]["error"].startswith("Skipped: Event 'push'")
+    assert deliveries[-1]["error"] == "Ping"
+
+
+def test_unknown_and_other_triggers_look_like_bad_signatures(client, sample_agent):
+    trigger = client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "webhook"}).json()
+    other = _deliver(client, trigger, "push", {})
+    unknown = _deliver(client, {**trigger, "id": trigger["id"] + 1000}, "push", {})
+
+    assert (other.status_code, other.json()) == (unknown.status_code, unknown.json())
+    assert other.json() == {"detail": "Invalid signature"}
+    assert client.get(f"/api/triggers/{trigger['id']}/deliveries").json() == []
+
+
+def test_unsigned_bad_json_is_not_recorded(client, db, sample_agent, monkeypatch):
+    trigger, _runs = _github_trigger(client, db, sample_agent, monkeypatch)
+    response = client.post(
+        f"/api/github/webhooks/{trigger['id']}",
+        content=b"not json",
+        headers={"X-GitHub-Event": "push", "X-Hub-Signature-256": "sha256=bogus"},
+    )
+
+    assert response.status_code == 403
+    assert client.get(f"/api/triggers/{trigger['id']}/deliveries").json() == []
+
+
+def test_redeliveries_fire_once(client, db, sample_agent, monkeypatch):
+    trigger, runs = _github_trigger(client, db, sample_agent, monkeypatch)
+    guid = str(uuid.uuid4())
+
+    first = _deliver(client, trigger, "pull_request", _pr_event("opened"), delivery_id=guid)
+    again = _deliver(client, trigger, "pull_request", _pr_event("opened"), delivery_id=guid)
+
+    assert first.json()["fired"] is True
+    assert again.json() == {"ok": True, "fired": False, "duplicate": True}
+    assert runs == [sample_agent.id]
+    assert len(client.get(f"/api/triggers/{trigger['id']}/deliveries").json()) == 1
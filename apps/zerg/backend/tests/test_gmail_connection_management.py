@@ -0,0 +1,71 @@
+"""Gmail connection status, disconnect and reconnect from the profile page.
+
+Disconnecting removes the connector and disables every email trigger that
+used it; reconnecting re-enables those triggers against the new connector.
+"""
+
+from __future__ import annotations
+
+
+def _connect(client, monkeypatch):
+    from zerg.routers import auth as auth_router
+
+    monkeypatch.setattr(
+        auth_router,
+        "_exchange_google_auth_code",
+        lambda _c: {"refresh_token": "rt", "scope": "https://www.googleapis.com/auth/gmail.readonly openid"},
+    )
+    resp = client.post("/api/auth/google/gmail", json={"auth_code": "x"})
+    assert resp.status_code == 200
+    return resp.json()["connector_id"]
+
+
+def test_gmail_status_disconnect_and_reconnect(client, sample_agent, monkeypatch):
+    from zerg.services import gmail_api as gmail_api_mod
+
+    monkeypatch.setattr(gmail_api_mod, "exchange_refresh_token", lambda _rt: "access")
+    monkeypatch.setattr(gmail_api_mod, "stop_watch", lambda *, access_token: True)
+
+    assert client.get("/api/auth/google/gmail").json()["health"] == "not_connected"
+
+    connector_id = _connect(client, monkeypatch)
+    trigger = client.post(
+        "/api/triggers/",
+        json={"agent_id": sample_agent.id, "type": "email", "config": {"connector_id": connector_id}},
+    ).json()
+
+    status = client.get("/api/auth/google/gmail", params={"check": True}).json()
+    assert status["connected"] is True
+    assert status["health"] == "healthy"
+    assert status["scopes"] == ["https://www.googleapis.com/auth/gmail.readonly", "openid"]
+    assert status["triggers"] == [
+        {"id": trigger["id"], "agent_id": sample_agent.id, "agent_name": sample_agent.name, "disabled": False}
+    ]
+
+    resp = client.delete("/api/auth/google/gmail")
+    assert resp.status_code == 200
+    assert resp.json()["disabled_trigger_ids"] == [trigger["id"]]
+
+    status = client.get("/api/auth/google/gmail").json()
+    assert status["connected"] is False
+    assert status["triggers"][0]["disabled"] is True
+    assert client.delete("/api/auth/google/gmail").status_code == 404
+
+    new_connector_id = _connect(client, monkeypatch)
+    config = next(t for t in client.get(f"/api/triggers/?agent_id={sample_agent.id}").json())["config"]
+    assert config["connector_id"] == new_connector_id
+    assert "disabled" not in config
+
+
+def test_gmail_health_reports_rejected_grant(client, monkeypatch):
+    from zerg.services import gmail_api as gmail_api_mod
+
+    def _revoked(_rt):
+        raise RuntimeError("invalid_grant")
+
+    _connect(client, monkeypatch)
+    monkeypatch.setattr(gmail_api_mod, "exchange_refresh_token", _revoked)
+
+    status = client.get("/api/auth/google/gmail", params={"check": True}).json()
+    assert status["health"] == "auth_failed"
+    assert "invalid_grant" in status["health_detail"]
@@ -0,0 +1,184 @@
+"""HTTP request node: request rendering, the inspector's test endpoint and engine execution."""
+
+import asyncio
+from unittest.mock import AsyncMock
+from unittest.mock import patch
+
+import httpx
+import pytest
+
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowEdge
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.http_node import BlockedDestinationError
+from zerg.services.http_node import RenderedRequest
+from zerg.services.http_node import check_destination
+from zerg.services.http_node import render_request
+from zerg.services.http_node import send_request
+from zerg.services.workflow_engine import workflow_engine
+
+OUTPUTS = {"search": {"value": {"query": "rust", "results": [1, 2]}, "meta": {"node_type": "tool"}}}
+
+RESPONSE = {"status_code": 201, "ok": True, "headers": {}, "body": {"id": 7}, "url": "https://api.test/x"}
+
+
+def test_render_request_inserts_upstream_values():
+    request = render_request(
+        {
+            "method": "post",
+            "url": "https://api.test/search?q=${search.value.query}",
+            "headers": {"X-Count": "${search.value.results}", " ": "ignored"},
+            "body": '{"results": ${search.value.results}, "missing": "${other.value}"}',
+        },
+        OUTPUTS,
+    )
+
+    assert request.method == "POST"
+    assert request.url == "https://api.test/search?q=rust"
+    assert request.headers == {"X-Count": "[1, 2]"}
+    assert request.body == '{"results": [1, 2], "missing": "${other.value}"}'
+    assert request.unresolved == ["other.value"]
+
+
+@pytest.mark.parametrize(
+    "config, message",
+    [
+        ({"method": "TRACE", "url": "https://api.test"}, "Unsupported HTTP method TRACE"),
+        ({"url": "api.test/x"}, "URL must start with http:// or https://"),
+        ({"url": "https://api.test", "headers": ["a"]}, "Headers must be an object"),
+    ],
+)
+def test_render_request_rejects_bad_config(config, message):
+    with pytest.raises(ValueError, match=message):
+        render_request(config, {})
+
+
+def test_test_endpoint_returns_response(client, auth_headers):
+    with patch("zerg.routers.workflows.send_request", AsyncMock(return_value=RESPONSE)) as send:
+        response = client.post(
+            "/api/workflows/http-request/test",
+            headers=auth_headers,
+            json={"method": "POST", "url": "https://api.test/x", "body": '{"a": ${up.value}}'},
+        )
+
+    assert response.status_code == 200
+    data = response.json()
+    assert data["response"] == RESPONSE
+    assert data["unresolved"] == ["up.value"]
+    assert send.await_args.args[0].body == '{"a": ${up.value}}'
+
+
+def test_test_endpoint_reports_transport_errors(client, auth_headers):
+    failure = AsyncMock(side_effect=httpx.ConnectError("connection refused"))
+    with patch("zerg.routers.workflows.send_request", failure):
+        response = client.post("/api/workflows/http-request/test", headers=auth_headers, json={"url": "https://x.test"})
+
+    assert response.status_code == 200
+    assert response.json()["error"] == "Request failed: connection refused"
+
+    invalid = client.post("/api/workflows/http-request/test", headers=auth_headers, json={"url": "ftp://x.test"})
+    assert invalid.status_code == 422
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    "url",
+    [
+        "http://127.0.0.1:8000/admin",
+        "http://169.254.169.254/latest/meta-data/",
+        "http://10.0.0.5/",
+        "http://[::1]/",
+        "http://[::ffff:192.168.1.1]/",
+        "http://0.0.0.0/",
+    ],
+)
+async def test_non_public_destinations_are_blocked(url):
+    with pytest.raises(BlockedDestinationError):
+        await check_destination(url)
+
+
+@pytest.mark.asyncio
+async def test_hostnames_are_resolved_before_sending(monkeypatch):
+    async def resolve_to_loopback(host, port, **_kwargs):
+        return [(None, None, None, "", ("127.0.0.1", 0))]
+
+    monkeypatch.setattr(asyncio.get_running_loop(), "getaddrinfo", resolve_to_loopback)
+    with pytest.raises(BlockedDestinationError, match="internal.test resolves to 127.0.0.1"):
+        await check_destination("https://internal.test/")
+
+    await check_destination("https://93.184.216.34/")
+
+
+def _mock_client(handler):
+    original = httpx.AsyncClient
+    return patch(
+        "zerg.services.http_node.httpx.AsyncClient",
+        lambda **kwargs: original(transport=httpx.MockTransport(handler), **kwargs),
+    )
+
+
+@pytest.mark.asyncio
+async def test_redirect_hops_are_checked_and_drop_headers_across_origins():
+    seen = []
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        seen.append((str(request.url), request.headers.get("X-Api-Key")))
+        if request.url.path == "/start":
+            return httpx.Response(302, headers={"Location": "https://93.184.216.35/next"})
+        if request.url.path == "/next":
+            return httpx.Response(302, headers={"Location": "http://169.254.169.254/latest/meta-data/"})
+        return httpx.Response(200, json={"leaked": True})
+
+    request = RenderedRequest(method="GET", url="https://93.184.216.34/start", headers={"X-Api-Key": "secret"})
+    with _mock_client(handler), pytest.raises(BlockedDestinationError):
+        await send_request(request)
+
+    # The second origin never got the key and the metadata address was never contacted
+    assert seen == [("https://93.184.216.34/start", "secret"), ("https://93.184.216.35/next", None)]
+
+
+def test_test_endpoint_refuses_internal_addresses(client, auth_headers):
+    response = client.post(
+        "/api/workflows/http-request/test",
+        headers=auth_headers,
+        json={"url": "http://169.254.169.254/latest/meta-data/"},
+    )
+
+    assert response.status_code == 422
+    assert "not a public address" in response.json()["detail"]
+
+
+@pytest.mark.asyncio
+async def test_http_node_output_feeds_downstream_nodes(db, test_user):
+    workflow_data = WorkflowData(
+        nodes=[
+            WorkflowNode(
+                id="http-1",
+                type="http",
+                position=Position(x=0, y=0),
+                config={"method": "POST", "url": "https://api.test/x", "body": "{}"},
+            ),
+            WorkflowNode(
+                id="check",
+                type="conditional",
+                position=Position(x=200, y=0),
+                config={"condition": "${http-1.value.status_code} == 201", "condition_type": "expression"},
+            ),
+        ],
+        edges=[WorkflowEdge(from_node_id="http-1", to_node_id="check", config={})],
+    )
+    workflow = Workflow(owner_id=test_user.id, name="HTTP", canvas=workflow_data.model_dump(), is_active=True)
+    db.add(workflow)
+    db.commit()
+
+    with patch("zerg.services.node_executors.send_request", AsyncMock(return_value=RESPONSE)):
+        execution_id = await workflow_engine.execute_workflow(workflow.id)
+
+    execution = db.query(WorkflowExecution).filter_by(id=execution_id).first()
+    assert execution.result == "success"
+    states = {state.node_id: state.output for state in execution.node_states}
+    assert states["http-1"]["meta"]["status_code"] == 201
+    assert states["check"]["value"]["result"] is True
@@ -0,0 +1,91 @@
+"""Tests for the cross-agent MCP health dashboard and reconnect endpoint."""
+
+import pytest
+
+from zerg.crud import crud
+from zerg.tools.mcp_adapter import MCPClient
+from zerg.tools.mcp_adapter import MCPManager
+
+
+@pytest.fixture(autouse=True)
+def _fresh_health_history():
+    MCPManager()._health.clear()
+    yield
+    MCPManager()._health.clear()
+
+
+@pytest.fixture
+def probe(monkeypatch):
+    """Make health checks answer from a dict of url -> healthy."""
+    results = {}
+    calls = []
+
+    async def _health_check(self):
+        calls.append(self.config.url)
+        return results.get(self.config.url, False)
+
+    monkeypatch.setattr(MCPClient, "health_check", _health_check)
+    return results, calls
+
+
+def _configure(db_session, agent, servers):
+    crud.update_agent(db_session, agent.id, config={"mcp_servers": servers})
+
+
+def test_health_is_unknown_until_probed(client, db_session, sample_agent, probe):
+    _configure(db_session, sample_agent, [{"name": "files", "url": "https://files.example.com"}])
+
+    rows = client.get("/api/mcp-servers/health").json()
+    assert [(r["name"], r["status"], r["history"]) for r in rows] == [("files", "unknown", [])]
+    assert probe[1] == []
+
+
+def test_refresh_probes_each_server_once_and_builds_history(client, db_session, sample_agent, _dev_user, probe):
+    results, calls = probe
+    results["https://up.example.com"] = True
+    server_up = {"name": "up", "url": "https://up.example.com"}
+    server_down = {"name": "down", "url": "https://down.example.com"}
+    _configure(db_session, sample_agent, [server_up, server_down])
+
+    second = crud.create_agent(
+        db_session,
+        owner_id=_dev_user.id,
+        name="Shares a server",
+        system_instructions="sys",
+        task_instructions="task",
+        model=sample_agent.model,
+    )
+    _configure(db_session, second, [server_up])
+
+    client.get("/api/mcp-servers/health?refresh=true")
+    rows = client.get("/api/mcp-servers/health?refresh=true").json()
+
+    assert sorted(calls) == sorted(["https://up.example.com", "https://down.example.com"] * 2)
+    by_key = {(r["agent_id"], r["name"]): r for r in rows}
+    assert by_key[(sample_agent.id, "up")]["status"] == "online"
+    assert by_key[(sample_agent.id, "up")]["latency_ms"] is not None
+    assert len(by_key[(sample_agent.id, "up")]["history"]) == 2
+    assert by_key[(second.id, "up")]["status"] == "online"
+    assert by_key[(sample_agent.id, "down")]["status"] == "offline"
+    assert by_key[(sample_agent.id, "down")]["latency_ms"] is None
+
+
+def test_unknown_preset_reports_config_error(client, db_session, sample_agent, probe):
+    _configure(db_session, sample_agent, [{"preset": "no-such-preset"}])
+
+    rows = client.get("/api/mcp-servers/health?refresh=true").json()
+    assert rows[0]["status"] == "config_error"
+    assert "no-such-preset" in rows[0]["error"]
+
+
+def test_reconnect_returns_fresh_health(client, db_session, sample_agent, probe):
+    results, _calls = probe
+    results["https://files.example.com"] = True
+    _configure(db_session, sample_agent, [{"name": "files", "url": "https://files.example.com"}])
+
+    resp = client.post(f"/api/agents/{sample_agent.id}/mcp-servers/files/reconnect")
+    assert resp.status_code == 200, resp.text
+    assert resp.json()["status"] == "online"
+
+    missing = client.post(f"/api/agents/{sample_agent.id}/mcp-servers/nope/reconnect")
+    assert missing.status_code == 404
@@ -0,0 +1,61 @@
+"""Per-agent generation parameters: validation, effective values and LLM kwargs."""
+
+import pytest
+from fastapi.testclient import TestClient
+
+from zerg.models.models import Agent
+from zerg.services.model_params import llm_kwargs
+from zerg.services.model_params import validate_model_params
+
+
+def test_validate_model_params():
+    assert validate_model_params(None) == {}
+    assert validate_model_params({"temperature": 0, "max_tokens": 500.0, "top_p": None, "stop": ["END", "END"]}) == {
+        "temperature": 0.0,
+        "max_tokens": 500,
+        "stop": ["END"],
+    }
+
+    for params, message in [
+        ({"temperature": 2.5}, "between 0 and 2"),
+        ({"top_p": "high"}, "must be a number"),
+        ({"max_tokens": 10.5}, "whole number"),
+        ({"stop": ["a", "b", "c", "d", "e"]}, "At most 4"),
+        ({"seed": 1}, "Unknown model parameter"),
+    ]:
+        with pytest.raises(ValueError, match=message):
+            validate_model_params(params)
+
+
+def test_llm_kwargs_caps_max_tokens(monkeypatch):
+    import zerg.services.model_params as model_params
+
+    monkeypatch.setattr(model_params, "_output_cap", lambda: 1000)
+    agent = Agent(model="gpt-5-mini", config={"model_params": {"temperature": 0.2, "max_tokens": 4000}})
+    assert llm_kwargs(agent) == {"temperature": 0.2, "max_tokens": 1000}
+
+    agent.config = {}
+    assert llm_kwargs(agent) == {"max_tokens": 1000}
+
+
+def test_agent_model_params_api(client: TestClient, sample_agent: Agent):
+    base = f"/api/agents/{sample_agent.id}"
+
+    response = client.put(base, json={"config": {"model_params": {"temperature": 3}}})
+    assert response.status_code == 422
+    assert "temperature" in response.json()["detail"]
+
+    response = client.put(base, json={"config": {"model_params": {"temperature": 0.3, "stop": ["##"]}}})
+    assert response.status_code == 200
+    assert response.json()["config"]["model_params"] == {"temperature": 0.3, "stop": ["##"]}
+
+    params = client.get(f"{base}/model-params").json()
+    assert params["overrides"]["temperature"] == 0.3
+    assert params["effective"]["temperature"] == 0.3
+    assert params["effective"]["stop"] == ["##"]
+    # Untouched parameters come from config/models.json
+    assert params["effective"]["top_p"] == params["defaults"]["top_p"]
+
+    # Clearing every override removes the key
+    response = client.put(base, json={"config": {"model_params": {}}})
+    assert "model_params" not in response.json()["config"]
@@ -45,3 +45,19 @@ def test_ops_summary_ok_for_admin(client: TestClient, db_session):
     finally:
         with contextlib.suppress(Exception):
             del app.dependency_overrides[get_current_user]
+
+
+def test_status_open_to_signed_in_users(client: TestClient, db_session):
+    user = crud.create_user(db_session, email="status-viewer@local", provider=None, role="USER")
+
+    app.dependency_overrides[get_current_user] = lambda: user
+    try:
+        r = client.get("/api/ops/status")
+        assert r.status_code == 200, r.text
+        data = r.json()
+        assert data["status"] in {"ok", "degraded"}
+        assert data["database"] == "ok"
+        assert {"runs_last_hour", "errors_last_hour", "errors_last_24h", "latency_ms", "websocket"}.issubset(data)
+    finally:
+        with contextlib.suppress(Exception):
+            del app.dependency_overrides[get_current_user]
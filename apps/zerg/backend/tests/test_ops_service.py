@@ -2,6 +2,7 @@ from datetime import datetime
 from datetime import timedelta
 
 from zerg.crud import crud
+from zerg.services.ops_service import get_status
 from zerg.services.ops_service import get_summary
 from zerg.services.ops_service import get_timeseries
 from zerg.services.ops_service import get_top_agents
@@ -280,3 +281,50 @@ def test_timeseries_database_compatibility_regression(db_session):
     # Additional sanity check: Ensure no database errors occurred
     # If func.strftime() was used instead of func.extract(), this would fail on PostgreSQL
     assert True, "All timeseries queries executed successfully without database errors"
+
+
+def test_status_counts_recent_runs(db_session):
+    user = crud.create_user(db_session, email="status-user@local", provider=None, role="USER")
+    agent = crud.create_agent(
+        db_session,
+        owner_id=user.id,
+        name="status-agent",
+        system_instructions="s",
+        task_instructions="t",
+        model="gpt-mock",
+    )
+    thread = crud.create_thread(db=db_session, agent_id=agent.id, title="t1", active=True, agent_state={})
+    now = datetime.utcnow()
+
+    for minutes, status, duration in [(50, "success", 1000), (40, "success", 3000), (5, "failed", 10)]:
+        _mk_run(
+            db_session,
+            agent.id,
+            thread.id,
+            started_at=now - timedelta(minutes=minutes + 1),
+            finished_at=now - timedelta(minutes=minutes),
+            status=status,
+            duration_ms=duration,
+        )
+    # Outside the last hour but inside the day
+    _mk_run(
+        db_session,
+        agent.id,
+        thread.id,
+        started_at=now - timedelta(hours=3),
+        finished_at=now - timedelta(hours=3),
+        status="failed",
+        duration_ms=10,
+    )
+
+    status = get_status(db_session, ws_connections=2, frontend_errors_last_hour=1)
+    assert status["database"] == "ok"
+    assert status["runs_last_hour"] == 3
+    assert status["errors_last_hour"] == 1
+    assert status["errors_last_24h"] == 2
+    assert status["latency_ms"] == {"p50": 2000, "p95": 2900}
+    # One failure in three runs is over the degraded threshold
+    assert status["status"] == "degraded"
+    assert status["websocket"] == {"available": True, "active_connections": 2}
+
+    assert get_status(db_session, ws_connections=None, frontend_errors_last_hour=0)["websocket"]["available"] is False
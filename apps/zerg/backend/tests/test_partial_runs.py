@@ -0,0 +1,170 @@
+"""Running from a node: planning the subgraph, reusing cached outputs and the run-control endpoints."""
+
+from unittest.mock import patch
+
+import pytest
+
+from zerg.models.models import NodeExecutionState
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.routers import workflow_executions
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowEdge
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.execution_state import ExecutionStateMachine
+from zerg.services.partial_runs import PartialRunError
+from zerg.services.partial_runs import plan_partial_run
+from zerg.services.workflow_engine import workflow_engine
+
+
+def _node(node_id: str, **config) -> WorkflowNode:
+    return WorkflowNode(id=node_id, type="tool", position=Position(x=0, y=0), config=config)
+
+
+def _edge(source: str, target: str) -> WorkflowEdge:
+    return WorkflowEdge(from_node_id=source, to_node_id=target)
+
+
+# fetch -> summarize -> notify, with lookup also feeding notify
+WORKFLOW = WorkflowData(
+    nodes=[
+        _node("fetch", tool_name="fetch"),
+        _node("lookup", tool_name="lookup"),
+        _node("summarize", tool_name="summarize", static_params={"text": "${fetch}"}),
+        _node("notify", tool_name="notify", static_params={"who": "${lookup}"}),
+    ],
+    edges=[_edge("fetch", "summarize"), _edge("summarize", "notify"), _edge("lookup", "notify")],
+)
+
+
+def _envelope(value):
+    return {"value": value, "meta": {"node_type": "tool", "phase": "finished", "result": "success"}}
+
+
+CACHED = {node_id: _envelope(node_id) for node_id in ("fetch", "lookup", "summarize", "notify")}
+
+
+class _RecordingTools:
+    """Tool resolver whose tools return canned values and record the params they ran with."""
+
+    def __init__(self, results):
+        self.results = results
+        self.calls = []
+
+    def get_tool(self, name):
+        recorder = self
+
+        class _Tool:
+            def run(self, params):
+                recorder.calls.append((name, params))
+                return recorder.results.get(name)
+
+        return _Tool()
+
+
+def _workflow(db, test_user):
+    workflow = Workflow(owner_id=test_user.id, name="Partial", canvas=WORKFLOW.model_dump(), is_active=True)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+def _finished_execution(db, workflow, outputs):
+    execution = WorkflowExecution(workflow_id=workflow.id, phase="finished", result="success", inputs={"day": "mon"})
+    db.add(execution)
+    db.commit()
+    for node_id, output in outputs.items():
+        db.add(
+            NodeExecutionState(
+                workflow_execution_id=execution.id,
+                node_id=node_id,
+                phase="finished",
+                result="success",
+                output=output,
+            )
+        )
+    db.commit()
+    return execution
+
+
+def test_plan_runs_node_and_downstream_with_seeded_upstream():
+    plan = plan_partial_run(WORKFLOW, "summarize", CACHED)
+
+    assert sorted(node.id for node in plan.workflow.nodes) == ["notify", "summarize"]
+    assert [(e.from_node_id, e.to_node_id) for e in plan.workflow.edges] == [("summarize", "notify")]
+    assert plan.seed_outputs == {"fetch": CACHED["fetch"], "lookup": CACHED["lookup"]}
+
+
+def test_plan_requires_cached_output_for_every_feeding_node():
+    with pytest.raises(PartialRunError, match="No cached output for lookup"):
+        plan_partial_run(WORKFLOW, "summarize", {"fetch": CACHED["fetch"]})
+    with pytest.raises(PartialRunError, match="not on the canvas"):
+        plan_partial_run(WORKFLOW, "gone", CACHED)
+
+
+@pytest.mark.asyncio
+async def test_engine_reuses_cached_outputs(db, test_user):
+    workflow = _workflow(db, test_user)
+    source = _finished_execution(db, workflow, {"fetch": _envelope("report"), "lookup": _envelope("ops")})
+    execution = WorkflowExecution(workflow_id=workflow.id, source_execution_id=source.id, start_node_id="summarize")
+    db.add(execution)
+    ExecutionStateMachine.mark_running(execution)
+    db.commit()
+
+    tools = _RecordingTools({"summarize": "short", "notify": "sent"})
+    with patch("zerg.services.node_executors.get_tool_resolver", return_value=tools):
+        await workflow_engine._execute_workflow_internal(workflow.id, execution, db)
+
+    assert execution.result == "success"
+    assert [name for name, _ in tools.calls] == ["summarize", "notify"]
+    assert tools.calls[0][1]["text"] == "report"
+    states = {
+        state.node_id: state.output
+        for state in db.query(NodeExecutionState).filter_by(workflow_execution_id=execution.id).all()
+    }
+    assert sorted(states) == ["fetch", "lookup", "notify", "summarize"]
+    assert states["fetch"] == _envelope("report")
+
+
+def test_run_from_endpoint_starts_partial_execution(client, auth_headers, db, test_user, monkeypatch):
+    workflow = _workflow(db, test_user)
+    source = _finished_execution(db, workflow, CACHED)
+    started = []
+    monkeypatch.setattr(
+        workflow_executions.workflow_engine,
+        "start_workflow_in_background",
+        lambda workflow_id, execution_id: started.append(execution_id),
+    )
+
+    response = client.post(f"/api/workflow-executions/{source.id}/run-from/summarize", headers=auth_headers)
+
+    assert response.status_code == 200
+    execution = db.query(WorkflowExecution).filter_by(id=response.json()["execution_id"]).first()
+    assert (execution.source_execution_id, execution.start_node_id) == (source.id, "summarize")
+    assert execution.inputs == {"day": "mon"}
+
+
+def test_run_from_endpoint_rejects_missing_cache(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user)
+    source = _finished_execution(db, workflow, {"fetch": CACHED["fetch"]})
+
+    response = client.post(f"/api/workflow-executions/{source.id}/run-from/summarize", headers=auth_headers)
+
+    assert response.status_code == 409
+    assert "lookup" in response.json()["detail"]
+
+
+def test_node_cache_lists_latest_finished_execution(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user)
+    empty = client.get(f"/api/workflow-executions/history/{workflow.id}/node-cache", headers=auth_headers)
+    assert empty.json() == {"execution_id": None, "finished_at": None, "node_ids": []}
+
+    _finished_execution(db, workflow, CACHED)
+    latest = _finished_execution(db, workflow, {"lookup": CACHED["lookup"], "fetch": CACHED["fetch"]})
+
+    response = client.get(f"/api/workflow-executions/history/{workflow.id}/node-cache", headers=auth_headers)
+
+    assert response.status_code == 200
+    assert response.json()["execution_id"] == latest.id
+    assert response.json()["node_ids"] == ["fetch", "lookup"]
@@ -0,0 +1,72 @@
+"""Trace ids: X-Request-ID response header, error bodies, runs and logs."""
+
+import logging
+
+from fastapi import FastAPI
+
+from zerg.crud import crud
+from zerg.middleware.request_id import TraceIdLogFilter
+from zerg.middleware.request_id import bind_trace_id
+from zerg.middleware.request_id import reset_trace_id
+
+
+def test_responses_carry_a_trace_id(client):
+    first = client.get("/api/agents")
+    second = client.get("/api/agents")
+
+    assert first.headers.get("x-request-id")
+    assert first.headers["x-request-id"] != second.headers.get("x-request-id")
+
+
+def test_incoming_request_id_is_reused_when_valid(client):
+    response = client.get("/api/agents", headers={"X-Request-ID": "proxy-abc-12345"})
+    assert response.headers["x-request-id"] == "proxy-abc-12345"
+
+    response = client.get("/api/agents", headers={"X-Request-ID": "<script>"})
+    assert response.headers["x-request-id"] != "<script>"
+
+
+def test_unhandled_errors_report_the_trace_id(unauthenticated_client):
+    import zerg.main as main
+
+    app: FastAPI = main.app
+
+    @app.get("/boom-trace-test")
+    async def boom_trace_test():  # noqa: D401
+        raise RuntimeError("boom")
+
+    r = unauthenticated_client.get("/boom-trace-test", headers={"X-Request-ID": "support-trace-1"})
+    assert r.status_code == 500
+    assert r.headers.get("x-request-id") == "support-trace-1"
+    assert r.json()["trace_id"] == "support-trace-1"
+
+
+def test_runs_record_the_current_trace_id(db_session, sample_agent):
+    thread = crud.create_thread(db_session, agent_id=sample_agent.id, title="t", active=True)
+
+    token = bind_trace_id("run-trace-0001")
+    try:
+        run = crud.create_run(db_session, agent_id=sample_agent.id, thread_id=thread.id)
+    finally:
+        reset_trace_id(token)
+    assert run.trace_id == "run-trace-0001"
+
+    # Outside a request (e.g. the scheduler) a fresh id is generated
+    scheduled = crud.create_run(db_session, agent_id=sample_agent.id, thread_id=thread.id, trigger="schedule")
+    assert scheduled.trace_id and scheduled.trace_id != "run-trace-0001"
+
+
+def test_log_records_include_the_trace_id():
+    record = logging.LogRecord("zerg.test", logging.INFO, __file__, 1, "hello", None, None)
+    log_filter = TraceIdLogFilter()
+
+    log_filter.filter(record)
+    assert record.trace == ""
+
+    token = bind_trace_id("log-trace-0001")
+    try:
+        log_filter.filter(record)
+    finally:
+        reset_trace_id(token)
+    assert record.trace_id == "log-trace-0001"
+    assert record.trace == "[log-trace-0001] "
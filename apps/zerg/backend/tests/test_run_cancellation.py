@@ -0,0 +1,53 @@
+"""Cancelling agent runs (services/run_cancellation.py and POST /runs/{id}/cancel)."""
+
+import asyncio
+
+import pytest
+
+from zerg.crud import crud
+from zerg.services.run_cancellation import RunCancellationRegistry
+
+
+def _running_run(db_session, sample_agent):
+    thread = crud.create_thread(
+        db=db_session, agent_id=sample_agent.id, title="t", active=False, agent_state={}, memory_strategy="buffer"
+    )
+    run = crud.create_run(db_session, agent_id=sample_agent.id, thread_id=thread.id, trigger="manual", status="queued")
+    crud.mark_running(db_session, run.id)
+    crud.update_agent(db_session, sample_agent.id, status="running")
+    return run
+
+
+@pytest.mark.asyncio
+async def test_request_cancel_interrupts_the_registered_task():
+    registry = RunCancellationRegistry()
+    task = asyncio.ensure_future(asyncio.sleep(10))
+    registry.register(7, task)
+
+    assert registry.is_live(7)
+    assert registry.request_cancel(7) is True
+    with pytest.raises(asyncio.CancelledError):
+        await task
+    assert registry.was_requested(7)
+
+    registry.discard(7)
+    assert not registry.was_requested(7)
+    assert registry.request_cancel(7) is False
+
+
+def test_cancel_orphaned_run_marks_it_cancelled(client, db_session, sample_agent):
+    run = _running_run(db_session, sample_agent)
+
+    response = client.post(f"/api/runs/{run.id}/cancel")
+    assert response.status_code == 202, response.text
+    assert response.json()["status"] == "cancelled"
+
+    db_session.refresh(sample_agent)
+    assert sample_agent.status == "idle"
+
+    # Finished runs can't be cancelled again
+    assert client.post(f"/api/runs/{run.id}/cancel").status_code == 409
+
+
+def test_cancel_unknown_run_returns_404(client):
+    assert client.post("/api/runs/999999/cancel").status_code == 404
@@ -0,0 +1,89 @@
+"""Run inspector events: binding a run to the context and forwarding them to the agent topic."""
+
+import asyncio
+import threading
+from unittest.mock import AsyncMock
+
+import pytest
+
+from zerg.events import EventType
+from zerg.services import run_events
+from zerg.services.run_events import bind_current_run
+from zerg.services.run_events import emit_run_event
+from zerg.services.run_events import reset_current_run
+from zerg.services.run_events import set_current_run
+from zerg.websocket.manager import topic_manager
+
+
+@pytest.fixture
+def publish(monkeypatch):
+    publish = AsyncMock()
+    monkeypatch.setattr(run_events.event_bus, "publish", publish)
+    return publish
+
+
+@pytest.mark.asyncio
+async def test_emit_outside_a_run_is_a_no_op(publish):
+    await emit_run_event("message", preview="hello")
+
+    publish.assert_not_awaited()
+
+
+@pytest.mark.asyncio
+async def test_events_carry_the_bound_run(publish):
+    token = set_current_run(3, 9)
+    try:
+        await emit_run_event("tool_finished", tool_name="search", duration_ms=12, preview=None)
+    finally:
+        reset_current_run(token)
+
+    event_type, payload = publish.await_args.args
+    assert event_type == EventType.RUN_EVENT
+    assert (payload["agent_id"], payload["run_id"], payload["kind"]) == (3, 9, "tool_finished")
+    assert payload["tool_name"] == "search" and payload["duration_ms"] == 12
+    assert "preview" not in payload and payload["timestamp"]
+
+
+@pytest.mark.asyncio
+async def test_events_from_a_helper_loop_are_published_on_the_run_loop(publish):
+    helper = asyncio.new_event_loop()
+    thread = threading.Thread(target=helper.run_forever, daemon=True)
+    thread.start()
+    token = set_current_run(3, 9)
+    try:
+        # The helper loop's task doesn't inherit our context; bind_current_run carries the run over
+        future = asyncio.run_coroutine_threadsafe(bind_current_run(emit_run_event("retry", attempt=2)), helper)
+        await asyncio.wrap_future(future)
+        for _ in range(5):
+            await asyncio.sleep(0)
+    finally:
+        reset_current_run(token)
+        helper.call_soon_threadsafe(helper.stop)
+        thread.join()
+        helper.close()
+
+    _, payload = publish.await_args.args
+    assert (payload["kind"], payload["attempt"], payload["run_id"]) == ("retry", 2, 9)
+
+
+@pytest.mark.asyncio
+async def test_topic_manager_forwards_run_events_to_the_agent_topic(monkeypatch):
+    broadcast = AsyncMock()
+    monkeypatch.setattr(topic_manager, "broadcast_to_topic", broadcast)
+
+    await topic_manager._handle_run_internal_event(
+        {
+            "event_type": EventType.RUN_EVENT,
+            "agent_id": 3,
+            "run_id": 9,
+            "kind": "tool_started",
+            "tool_name": "search",
+            "timestamp": "2026-10-14T12:00:00+00:00",
+        }
+    )
+
+    topic, envelope = broadcast.await_args.args
+    assert topic == "agent:3"
+    assert envelope["type"] == "run_event"
+    assert envelope["data"]["tool_name"] == "search"
+    assert "event_type" not in envelope["data"]
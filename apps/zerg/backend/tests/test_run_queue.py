@@ -0,0 +1,103 @@
+"""Queued runs for busy agents (services/run_queue.py and /agents/{id}/queue)."""
+
+from unittest.mock import AsyncMock
+
+import pytest
+
+from zerg.crud import crud
+from zerg.models.models import AgentRun
+from zerg.services import run_queue
+from zerg.utils.time import utc_now_naive
+
+
+async def _queue_runs(db_session, agent, count):
+    crud.update_agent(db_session, agent.id, status="running")
+    return [await run_queue.enqueue_run(db_session, agent, trigger="schedule") for _ in range(count)]
+
+
+@pytest.mark.asyncio
+async def test_enqueue_appends_in_order_and_caps_the_queue(db_session, sample_agent, monkeypatch):
+    monkeypatch.setattr(run_queue, "MAX_QUEUED_RUNS", 2)
+    first, second, overflow = await _queue_runs(db_session, sample_agent, 3)
+
+    assert overflow is None
+    assert [run.id for run in crud.list_queued_runs(db_session, sample_agent.id)] == [first.id, second.id]
+    assert (first.queue_position, second.queue_position) == (1, 2)
+    assert first.status.value == "queued"
+
+    payload = run_queue.queue_payload(sample_agent.id, [first, second])
+    assert [(entry["id"], entry["position"]) for entry in payload["queue"]] == [(first.id, 1), (second.id, 2)]
+
+
+@pytest.mark.asyncio
+async def test_reorder_and_drop_queued_runs(client, db_session, sample_agent):
+    first, second, third = await _queue_runs(db_session, sample_agent, 3)
+
+    response = client.get(f"/api/agents/{sample_agent.id}/queue")
+    assert [run["id"] for run in response.json()] == [first.id, second.id, third.id]
+
+    response = client.put(f"/api/agents/{sample_agent.id}/queue", json={"run_ids": [third.id, first.id, second.id]})
+    assert response.status_code == 200, response.text
+    assert [run["id"] for run in response.json()] == [third.id, first.id, second.id]
+
+    # A stale client that misses a run is rejected
+    response = client.put(f"/api/agents/{sample_agent.id}/queue", json={"run_ids": [first.id, second.id]})
+    assert response.status_code == 409
+
+    response = client.delete(f"/api/agents/{sample_agent.id}/queue/{third.id}")
+    assert response.status_code == 200, response.text
+    assert response.json()["status"] == "cancelled"
+    assert response.json()["queue_position"] is None
+
+    queue = client.get(f"/api/agents/{sample_agent.id}/queue").json()
+    assert [(run["id"], run["queue_position"]) for run in queue] == [(first.id, 1), (second.id, 2)]
+    assert client.delete(f"/api/agents/{sample_agent.id}/queue/{third.id}").status_code == 404
+
+
+@pytest.mark.asyncio
+async def test_cancelling_a_queued_run_leaves_the_active_run_alone(client, db_session, sample_agent):
+    (queued,) = await _queue_runs(db_session, sample_agent, 1)
+
+    response = client.post(f"/api/runs/{queued.id}/cancel")
+    assert response.status_code == 202, response.text
+    assert response.json()["status"] == "cancelled"
+
+    db_session.refresh(sample_agent)
+    assert sample_agent.status == "running"
+    assert crud.list_queued_runs(db_session, sample_agent.id) == []
+
+
+@pytest.mark.asyncio
+async def test_queued_runs_get_their_thread_only_when_they_start(db_session, sample_agent):
+    threads_before = len(crud.get_threads(db_session, agent_id=sample_agent.id))
+    (queued,) = await _queue_runs(db_session, sample_agent, 1)
+    assert queued.thread_id is None
+
+    await run_queue.drop_queued_run(db_session, queued, reason="Dropped")
+    assert len(crud.get_threads(db_session, agent_id=sample_agent.id)) == threads_before
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    "block, reason",
+    [("pause", "Not started: agent is paused"), ("maintenance", "Not started: maintenance mode is on")],
+)
+async def test_blocked_agents_cancel_their_queue_instead_of_starting_it(
+    db_session, sample_agent, monkeypatch, block, reason
+):
+    queued = await _queue_runs(db_session, sample_agent, 2)
+    if block == "pause":
+        sample_agent.paused_at = utc_now_naive()
+        db_session.commit()
+    else:
+        crud.set_system_setting(db_session, crud.MAINTENANCE_MODE_KEY, True)
+
+    execute = AsyncMock()
+    monkeypatch.setattr("zerg.services.task_runner.execute_agent_task", execute)
+    await run_queue.start_next_queued_run(sample_agent.id)
+
+    execute.assert_not_awaited()
+    db_session.expire_all()
+    rows = [db_session.get(AgentRun, run.id) for run in queued]
+    assert [(row.status.value, row.cancel_reason) for row in rows] == [("cancelled", reason)] * 2
+    assert crud.list_queued_runs(db_session, sample_agent.id) == []
@@ -78,6 +78,22 @@ def test_list_agent_runs_endpoint(client: TestClient, db_session: Session, sampl
     assert payload[0]["id"] > payload[1]["id"]
 
 
+def test_list_agent_runs_pagination(client: TestClient, db_session: Session, sample_agent, sample_thread, _seed_runs):
+    """`before_id` pages through older runs without overlap."""
+
+    first_page = client.get(f"/api/agents/{sample_agent.id}/runs?limit=2").json()
+    assert len(first_page) == 2
+
+    cursor = first_page[-1]["id"]
+    second_page = client.get(f"/api/agents/{sample_agent.id}/runs?limit=2&before_id={cursor}").json()
+    assert len(second_page) == 1
+    assert second_page[0]["id"] < cursor
+
+    # Past the oldest run there is nothing left
+    empty_page = client.get(f"/api/agents/{sample_agent.id}/runs?limit=2&before_id={second_page[0]['id']}").json()
+    assert empty_page == []
+
+
 def test_get_run_endpoint(client: TestClient, db_session: Session, sample_agent, sample_thread):
     """/api/runs/{run_id} returns the row or 404."""
 
@@ -3,6 +3,8 @@ import logging
 import pytest
 
 from apscheduler.triggers.cron import CronTrigger
+from zerg.crud import crud
+from zerg.services.agent_locks import AgentAlreadyRunningError
 from zerg.services.scheduler_service import SchedulerService
 
 
@@ -203,3 +205,17 @@ async def test_handle_agent_deleted(service):
 
     # Verify the schedule was removed
     assert service.scheduler.get_job(f"agent_{agent_id}") is None
+
+
+@pytest.mark.asyncio
+async def test_run_for_busy_agent_is_queued(service, db_session, sample_agent, monkeypatch):
+    async def busy(*_args, **_kwargs):
+        raise AgentAlreadyRunningError("Agent already running")
+
+    monkeypatch.setattr("zerg.services.scheduler_service.execute_agent_task", busy)
+
+    await service.run_agent_task(sample_agent.id)
+
+    queued = crud.list_queued_runs(db_session, sample_agent.id)
+    assert len(queued) == 1
+    assert queued[0].trigger.value == "schedule"
@@ -0,0 +1,92 @@
+"""Agent output schemas and the agent contract: schema validation, checking finished runs and raw edits."""
+
+import pytest
+from fastapi.testclient import TestClient
+
+from zerg.crud import crud
+from zerg.models.models import Agent
+from zerg.services.schema_validation import validate_agent_patch
+from zerg.services.schema_validation import validate_output
+from zerg.services.schema_validation import validate_output_schema
+
+SCHEMA = {
+    "type": "object",
+    "properties": {"title": {"type": "string"}, "score": {"type": "number", "maximum": 10}},
+    "required": ["title", "score"],
+}
+
+
+def test_validate_output_schema():
+    assert validate_output_schema(SCHEMA) == SCHEMA
+    with pytest.raises(ValueError, match="must be a JSON object"):
+        validate_output_schema(["type", "object"])
+    with pytest.raises(ValueError, match="Invalid output schema at properties/score"):
+        validate_output_schema({"type": "object", "properties": {"score": {"type": "decimal"}}})
+
+
+def test_validate_output():
+    assert validate_output(SCHEMA, '```json\n{"title": "Ok", "score": 3}\n```') == ({"title": "Ok", "score": 3}, [])
+
+    value, errors = validate_output(SCHEMA, '{"title": 1, "score": 11}')
+    assert value == {"title": 1, "score": 11}
+    assert errors == ["$.score: 11 is greater than the maximum of 10", "$.title: 1 is not of type 'string'"]
+
+    value, errors = validate_output(SCHEMA, "Sure! Here you go.")
+    assert value is None
+    assert errors[0].startswith("Output is not valid JSON")
+
+
+def test_finished_run_is_checked_against_schema(client: TestClient, sample_agent: Agent, sample_thread, db_session):
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"config": {"output_schema": {"type": "nope"}}})
+    assert response.status_code == 422
+
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"config": {"output_schema": SCHEMA}})
+    assert response.status_code == 200
+    db_session.refresh(sample_agent)
+
+    crud.create_thread_message(db_session, sample_thread.id, "assistant", '{"title": "Weekly report"}')
+    run = crud.create_run(db_session, agent_id=sample_agent.id, thread_id=sample_thread.id)
+    run = crud.mark_finished(db_session, run.id)
+    assert run.output == {"title": "Weekly report"}
+    assert run.schema_errors == ["$: 'score' is a required property"]
+
+    [listed] = client.get(f"/api/agents/{sample_agent.id}/runs").json()
+    assert listed["schema_errors"] == run.schema_errors
+
+
+def test_validate_agent_patch():
+    assert validate_agent_patch({"name": "Digest", "config": {"temperature": 0.2}}) == []
+    assert validate_agent_patch(["name"]) == ["$: the edit must be a JSON object"]
+
+    errors = validate_agent_patch({"nmae": "Digest", "allowed_tools": "web_search"})
+    assert errors[0] == "$: Additional properties are not allowed ('nmae' was unexpected)"
+    assert errors[1].startswith("$.allowed_tools:")
+
+    [error] = validate_agent_patch({"config": {"output_schema": {"type": "decimal"}}})
+    assert error.startswith("$.config.output_schema: Invalid output schema")
+
+
+def test_patch_agent_applies_checked_changes(client: TestClient, sample_agent: Agent):
+    checked = client.post("/api/agents/contract/validate", json={"status": "asleep"})
+    assert checked.status_code == 200
+    assert checked.json()["errors"][0].startswith("$.status:")
+
+    url = f"/api/agents/{sample_agent.id}"
+    response = client.patch(url, json={"changes": {"name": "Renamed"}, "base": {"name": "Test Agent"}})
+    assert response.status_code == 200
+    assert response.json()["name"] == "Renamed"
+
+    invalid = client.patch(url, json={"changes": {"name": 3}})
+    assert invalid.status_code == 422
+    assert "$.name" in invalid.json()["detail"]
+
+
+def test_patch_agent_rejects_edits_from_a_stale_copy(client: TestClient, sample_agent: Agent):
+    url = f"/api/agents/{sample_agent.id}"
+    client.put(url, json={"name": "Changed elsewhere"})
+
+    response = client.patch(url, json={"changes": {"name": "Mine"}, "base": {"name": "Test Agent"}})
+
+    assert response.status_code == 409
+    assert "name" in response.json()["detail"]
+    assert client.get(url).json()["name"] == "Changed elsewhere"
@@ -0,0 +1,129 @@
+"""Slack integration: OAuth connect, Slack-event triggers and the Events API endpoint."""
+
+import hashlib
+import hmac
+import json
+import time
+from types import SimpleNamespace
+
+from zerg.config import get_settings
+from zerg.models.models import AccountConnectorCredential
+from zerg.routers import oauth as oauth_router
+from zerg.routers import slack_events as slack_events_router
+from zerg.routers.oauth import _generate_state
+from zerg.services.scheduler_service import scheduler_service
+
+SIGNING_SECRET = "slack-signing-secret"
+
+
+def _connect_slack(db, owner_id, team_id="T123"):
+    db.add(
+        AccountConnectorCredential(
+            owner_id=owner_id,
+            connector_type="slack",
+            encrypted_value="unused",
+            connector_metadata={"team_id": team_id, "connected_via": "oauth"},
+        )
+    )
+    db.commit()
+
+
+def _signed_post(client, monkeypatch, envelope, **headers):
+    settings = get_settings()
+    settings.slack_signing_secret = SIGNING_SECRET
+    monkeypatch.setattr(slack_events_router, "get_settings", lambda: settings)
+
+    body = json.dumps(envelope).encode()
+    timestamp = str(int(time.time()))
+    digest = hmac.new(SIGNING_SECRET.encode(), f"v0:{timestamp}:".encode() + body, hashlib.sha256).hexdigest()
+    signature = f"v0={digest}"
+    return client.post(
+        "/api/slack/events",
+        content=body,
+        headers={"X-Slack-Request-Timestamp": timestamp, "X-Slack-Signature": signature, **headers},
+    )
+
+
+def test_oauth_callback_stores_bot_token_and_webhook(client, db, test_user, monkeypatch):
+    settings = SimpleNamespace(slack_client_id="id", slack_client_secret="secret", app_public_url="")
+    monkeypatch.setattr(oauth_router, "_settings", settings)
+
+    def fake_post(url, data, timeout):
+        assert data["code"] == "the-code"
+        return SimpleNamespace(
+            raise_for_status=lambda: None,
+            json=lambda: {
+                "ok": True,
+                "access_token": "xoxb-1",
+                "team": {"id": "T123", "name": "Acme"},
+                "incoming_webhook": {"channel": "#alerts", "url": "https://hooks.slack.com/services/T/B/x"},
+                "scope": "chat:write,incoming-webhook",
+            },
+        )
+
+    monkeypatch.setattr(oauth_router.httpx, "post", fake_post)
+
+    resp = client.get("/api/oauth/slack/callback", params={"code": "the-code", "state": _generate_state(test_user.id)})
+    assert resp.status_code == 200
+    assert "Slack Connected!" in resp.text
+
+    cred = db.query(AccountConnectorCredential).filter_by(owner_id=test_user.id, connector_type="slack").one()
+    assert cred.display_name == "Acme #alerts"
+    assert cred.connector_metadata["team_id"] == "T123"
+
+
+def test_slack_trigger_requires_connection_and_event_type(client, db, sample_agent):
+    body = {"agent_id": sample_agent.id, "type": "slack", "config": {"event_type": "app_mention"}}
+    assert client.post("/api/triggers/", json=body).status_code == 400
+
+    _connect_slack(db, sample_agent.owner_id)
+    bad = client.post("/api/triggers/", json={**body, "config": {"event_type": "file_shared"}})
+    assert bad.status_code == 422
+
+    created = client.post("/api/triggers/", json={**body, "config": {"event_type": "app_mention", "channel": " C1 "}})
+    assert created.status_code == 201, created.text
+    assert created.json()["config"] == {
+        "event_type": "app_mention",
+        "channel": "C1",
+        "keyword": None,
+        "team_id": "T123",
+    }
+
+
+def test_events_endpoint_fires_matching_triggers(client, db, sample_agent, monkeypatch):
+    _connect_slack(db, sample_agent.owner_id)
+    config = {"event_type": "message", "channel": "C1", "keyword": "deploy"}
+    client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "slack", "config": config})
+
+    runs = []
+
+    async def _stub_run_agent_task(agent_id: int, trigger: str = "schedule"):
+        runs.append(agent_id)
+
+    monkeypatch.setattr(scheduler_service, "run_agent_task", _stub_run_agent_task)
+
+    challenge = _signed_post(client, monkeypatch, {"type": "url_verification", "challenge": "abc"})
+    assert challenge.json() == {"challenge": "abc"}
+
+    def event(**fields):
+        return {"type": "event_callback", "team_id": "T123", "event": {"type": "message", "channel": "C1", **fields}}
+
+    assert _signed_post(client, monkeypatch, event(text="please Deploy now")).json()["fired"] == 1
+    assert _signed_post(client, monkeypatch, event(text="hello")).json()["fired"] == 0
+    assert _signed_post(client, monkeypatch, event(text="deploy", bot_id="B1")).json()["fired"] == 0
+    retry = _signed_post(client, monkeypatch, event(text="deploy"), **{"X-Slack-Retry-Num": "1"})
+    assert retry.json() == {"ok": True}
+    assert runs == [sample_agent.id]
+
+
+def test_events_endpoint_rejects_bad_signatures(client, monkeypatch):
+    settings = get_settings()
+    settings.slack_signing_secret = SIGNING_SECRET
+    monkeypatch.setattr(slack_events_router, "get_settings", lambda: settings)
+
+    resp = client.post(
+        "/api/slack/events",
+        content=b"{}",
+        headers={"X-Slack-Request-Timestamp": str(int(time.time())), "X-Slack-Signature": "v0=nope"},
+    )
+    assert resp.status_code == 403
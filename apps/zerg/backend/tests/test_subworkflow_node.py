@@ -0,0 +1,119 @@
+"""Call Workflow node: input/output mapping, recursion protection and the execution drill-down."""
+
+import pytest
+
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowEdge
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.workflow_engine import workflow_engine
+
+
+def _workflow(db, test_user, name, nodes, edges=()):
+    canvas = WorkflowData(nodes=nodes, edges=list(edges)).model_dump()
+    workflow = Workflow(owner_id=test_user.id, name=name, canvas=canvas, is_active=True)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+def _call_node(workflow_id, **config):
+    return WorkflowNode(
+        id="call",
+        type="subworkflow",
+        position=Position(x=0, y=0),
+        config={"workflow_id": workflow_id, **config},
+    )
+
+
+def _child(db, test_user):
+    check = WorkflowNode(
+        id="check",
+        type="conditional",
+        position=Position(x=0, y=0),
+        config={"condition": "${input.value.count} > 1", "condition_type": "expression"},
+    )
+    return _workflow(db, test_user, "Child", [check])
+
+
+@pytest.mark.asyncio
+async def test_call_maps_inputs_and_outputs(db, test_user):
+    child = _child(db, test_user)
+    parent = _workflow(
+        db,
+        test_user,
+        "Parent",
+        [_call_node(child.id, inputs={"count": 3}, outputs={"passed": "${check.value.result}"})],
+    )
+
+    execution_id = await workflow_engine.execute_workflow(parent.id)
+
+    execution = db.query(WorkflowExecution).filter_by(id=execution_id).first()
+    output = execution.node_states[0].output
+    assert output["value"]["outputs"] == {"passed": True}
+    child_run = db.query(WorkflowExecution).filter_by(id=output["value"]["execution_id"]).first()
+    assert (child_run.workflow_id, child_run.parent_execution_id, child_run.parent_node_id) == (
+        child.id,
+        execution_id,
+        "call",
+    )
+    assert child_run.triggered_by == "workflow"
+
+
+@pytest.mark.asyncio
+async def test_call_back_into_running_workflow_fails(db, test_user):
+    workflow = _workflow(db, test_user, "Loop", [])
+    workflow.canvas = WorkflowData(nodes=[_call_node(workflow.id)], edges=[]).model_dump()
+    db.commit()
+
+    execution_id = await workflow_engine.execute_workflow(workflow.id)
+
+    state = db.query(WorkflowExecution).filter_by(id=execution_id).first().node_states[0]
+    assert state.result == "failure"
+    assert "already running in this call chain" in state.output["meta"]["error_message"]
+
+
+def test_validate_reports_call_cycles(client, auth_headers, db, test_user):
+    first = _workflow(db, test_user, "First", [])
+    second = _workflow(db, test_user, "Second", [_call_node(first.id)])
+    canvas = WorkflowData(nodes=[_call_node(second.id)], edges=[]).model_dump()
+
+    response = client.post(
+        "/api/workflows/validate", headers=auth_headers, json={"canvas": canvas, "workflow_id": first.id}
+    )
+
+    data = response.json()
+    assert data["is_valid"] is False
+    assert data["errors"][0]["code"] == "SUBWORKFLOW_RECURSION"
+    assert data["errors"][0]["node_id"] == "call"
+
+    missing = WorkflowData(nodes=[_call_node(99999)], edges=[]).model_dump()
+    response = client.post("/api/workflows/validate", headers=auth_headers, json={"canvas": missing})
+    assert response.json()["errors"][0]["code"] == "SUBWORKFLOW_NOT_FOUND"
+
+
+@pytest.mark.asyncio
+async def test_detail_lists_child_runs(client, auth_headers, db, test_user):
+    child = _child(db, test_user)
+    start = WorkflowNode(id="start", type="trigger", position=Position(x=0, y=0), config={})
+    parent = _workflow(
+        db,
+        test_user,
+        "Parent",
+        [start, _call_node(child.id, inputs={"count": 0})],
+        [WorkflowEdge(from_node_id="start", to_node_id="call", config={})],
+    )
+    execution_id = await workflow_engine.execute_workflow(parent.id)
+
+    detail = client.get(f"/api/workflow-executions/{execution_id}/detail", headers=auth_headers).json()
+
+    assert detail["workflow_name"] == "Parent"
+    assert {node["node_id"] for node in detail["nodes"]} == {"start", "call"}
+    [child_run] = detail["children"]
+    assert child_run["node_id"] == "call"
+    assert child_run["workflow_id"] == child.id
+
+    child_detail = client.get(f"/api/workflow-executions/{child_run['execution_id']}/detail", headers=auth_headers)
+    assert child_detail.json()["parent_execution_id"] == execution_id
@@ -0,0 +1,70 @@
+"""Agent and workflow tags: setting them, and renaming, merging or deleting them across rows."""
+
+import pytest
+
+from zerg.models.models import Workflow
+from zerg.services.tags import normalize_tags
+
+
+def _workflow(db, owner_id, tags=None):
+    workflow = Workflow(owner_id=owner_id, name="Tagged", canvas={"nodes": [], "edges": []}, tags=tags)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+def test_normalize_tags_trims_and_dedupes_case_insensitively():
+    assert normalize_tags(["  ops ", "Ops", "on  call", "billing"]) == ["ops", "on call", "billing"]
+    with pytest.raises(ValueError):
+        normalize_tags(["  "])
+    with pytest.raises(ValueError):
+        normalize_tags(["x" * 41])
+
+
+def test_agent_and_workflow_tags_are_set_through_updates(client, sample_agent, db, test_user):
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"tags": ["Ops", "ops", " nightly "]})
+    assert response.status_code == 200
+    assert response.json()["tags"] == ["Ops", "nightly"]
+
+    # Leaving them out keeps them; an empty list clears them
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"name": "Renamed"})
+    assert response.json()["tags"] == ["Ops", "nightly"]
+    response = client.put(f"/api/agents/{sample_agent.id}", json={"tags": []})
+    assert response.json()["tags"] is None
+
+    workflow = _workflow(db, test_user.id)
+    response = client.patch(f"/api/workflows/{workflow.id}", json={"tags": ["reports"]})
+    assert response.status_code == 200
+    assert response.json()["tags"] == ["reports"]
+
+    response = client.patch(f"/api/workflows/{workflow.id}", json={"tags": [""]})
+    assert response.status_code == 422
+
+
+def test_rename_merges_and_delete_removes_tags(client, sample_agent, db, test_user):
+    client.put(f"/api/agents/{sample_agent.id}", json={"tags": ["ops", "billing"]})
+    workflow = _workflow(db, test_user.id, tags=["Billing", "reports"])
+
+    listed = client.get("/api/tags/").json()
+    assert listed == [
+        {"name": "billing", "agent_count": 1, "workflow_count": 1},
+        {"name": "ops", "agent_count": 1, "workflow_count": 0},
+        {"name": "reports", "agent_count": 0, "workflow_count": 1},
+    ]
+
+    # "ops" onto the existing "reports" merges them
+    response = client.post("/api/tags/rename", json={"tag": "OPS", "name": "reports"})
+    assert response.status_code == 200
+    assert [entry["name"] for entry in response.json()] == ["billing", "reports"]
+    db.refresh(sample_agent)
+    assert sample_agent.tags == ["reports", "billing"]
+
+    response = client.delete("/api/tags/", params={"tag": "billing"})
+    assert response.status_code == 200
+    db.refresh(sample_agent)
+    db.refresh(workflow)
+    assert sample_agent.tags == ["reports"]
+    assert workflow.tags == ["reports"]
+
+    assert client.delete("/api/tags/", params={"tag": "missing"}).status_code == 404
+    assert client.post("/api/tags/rename", json={"tag": "reports", "name": " "}).status_code == 422
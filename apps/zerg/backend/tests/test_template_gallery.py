@@ -0,0 +1,122 @@
+"""Template gallery: publishing workflows, ratings, favorites and sorting."""
+
+from zerg.crud import crud
+
+
+def _canvas():
+    return {
+        "nodes": [{"id": "t1", "type": "trigger", "position": {"x": 0, "y": 0}, "config": {}}],
+        "edges": [],
+    }
+
+
+def _create_workflow(client, name="Gallery WF", canvas=None):
+    resp = client.post(
+        "/api/workflows/",
+        json={"name": name, "description": "wf", "canvas": canvas if canvas is not None else _canvas()},
+    )
+    assert resp.status_code in (200, 201), resp.text
+    return resp.json()["id"]
+
+
+def _publish(client, workflow_id, name, category="ops"):
+    resp = client.post(
+        "/api/templates/publish",
+        json={
+            "workflow_id": workflow_id,
+            "name": name,
+            "description": "Shared from the canvas",
+            "category": category,
+            "tags": ["demo"],
+            "preview_image_url": "data:image/svg+xml;base64,PHN2Zy8+",
+        },
+    )
+    assert resp.status_code == 201, resp.text
+    return resp.json()
+
+
+def test_publish_copies_the_workflow_canvas(client):
+    workflow_id = _create_workflow(client)
+
+    template = _publish(client, workflow_id, "Morning digest")
+
+    assert template["name"] == "Morning digest"
+    assert template["category"] == "ops"
+    assert template["preview_image_url"].startswith("data:image/svg+xml")
+    assert [node["id"] for node in template["canvas"]["nodes"]] == ["t1"]
+    assert template["rating_count"] == 0
+    assert template["is_favorite"] is False
+
+
+def test_publish_rejects_empty_and_foreign_workflows(client, db_session, other_user):
+    empty_id = _create_workflow(client, name="Empty WF", canvas={"nodes": [], "edges": []})
+    resp = client.post("/api/templates/publish", json={"workflow_id": empty_id, "name": "x", "category": "ops"})
+    assert resp.status_code == 400
+
+    foreign = crud.create_workflow(db_session, owner_id=other_user.id, name="Theirs", canvas=_canvas())
+    resp = client.post("/api/templates/publish", json={"workflow_id": foreign.id, "name": "x", "category": "ops"})
+    assert resp.status_code == 403
+
+    resp = client.post("/api/templates/publish", json={"workflow_id": 999999, "name": "x", "category": "ops"})
+    assert resp.status_code == 404
+
+
+def test_rating_and_favorite_roundtrip(client, db_session, other_user):
+    template = _publish(client, _create_workflow(client), "Rated")
+    crud.set_template_feedback(db_session, template_id=template["id"], user_id=other_user.id, rating=2)
+
+    resp = client.put(f"/api/templates/{template['id']}/rating", json={"rating": 5})
+    assert resp.status_code == 200
+    body = resp.json()
+    assert body["my_rating"] == 5
+    assert body["rating_count"] == 2
+    assert body["rating_avg"] == 3.5
+
+    resp = client.put(f"/api/templates/{template['id']}/favorite", json={"favorite": True})
+    assert resp.json()["is_favorite"] is True
+    assert resp.json()["favorite_count"] == 1
+    # Favoriting keeps the rating
+    assert resp.json()["my_rating"] == 5
+
+    resp = client.put(f"/api/templates/{template['id']}/rating", json={"rating": None})
+    assert resp.json()["my_rating"] is None
+    assert resp.json()["rating_count"] == 1
+
+    assert client.put(f"/api/templates/{template['id']}/rating", json={"rating": 6}).status_code == 422
+
+
+def test_private_templates_of_others_cannot_be_rated(client, db_session, other_user):
+    private = crud.create_workflow_template(
+        db_session, created_by=other_user.id, name="Hidden", category="ops", canvas=_canvas(), is_public=False
+    )
+
+    resp = client.put(f"/api/templates/{private.id}/rating", json={"rating": 4})
+    assert resp.status_code == 403
+    assert client.put("/api/templates/999999/favorite", json={"favorite": True}).status_code == 404
+
+
+def test_list_sorts_and_filters_by_feedback(client, db_session, other_user):
+    low = _publish(client, _create_workflow(client, name="WF low"), "Low")
+    high = _publish(client, _create_workflow(client, name="WF high"), "High")
+    loved = _publish(client, _create_workflow(client, name="WF loved"), "Loved")
+
+    client.put(f"/api/templates/{low['id']}/rating", json={"rating": 2})
+    client.put(f"/api/templates/{high['id']}/rating", json={"rating": 5})
+    client.put(f"/api/templates/{loved['id']}/favorite", json={"favorite": True})
+    crud.set_template_feedback(db_session, template_id=loved["id"], user_id=other_user.id, is_favorite=True)
+    crud.set_template_feedback(db_session, template_id=high["id"], user_id=other_user.id, is_favorite=True)
+
+    names = [t["name"] for t in client.get("/api/templates/", params={"sort": "rating"}).json()]
+    assert names.index("High") < names.index("Low") < names.index("Loved")
+
+    popular = client.get("/api/templates/", params={"sort": "popular"}).json()
+    assert [t["name"] for t in popular][:2] == ["Loved", "High"]
+
+    rated = client.get("/api/templates/", params={"min_rating": 4}).json()
+    assert [t["name"] for t in rated] == ["High"]
+
+    favorites = client.get("/api/templates/", params={"favorites": True}).json()
+    assert [t["name"] for t in favorites] == ["Loved"]
+    assert favorites[0]["is_favorite"] is True
+
+    assert client.get("/api/templates/", params={"sort": "random"}).status_code == 422
@@ -59,3 +59,50 @@ def test_read_thread_ownership_enforced(client, db_session):
     finally:
         with contextlib.suppress(Exception):
             del app.dependency_overrides[get_current_user]
+
+
+def test_thread_access_follows_live_shares(client, db_session):
+    owner = _user(db_session, "collab-owner@local", "USER")
+    viewer = _user(db_session, "collab-viewer@local", "USER")
+    runner = _user(db_session, "collab-runner@local", "USER")
+    stranger = _user(db_session, "collab-stranger@local", "USER")
+
+    agent = crud.create_agent(
+        db_session,
+        owner_id=owner.id,
+        name="collab-agent",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_WORKER_MODEL,
+        schedule=None,
+        config={},
+    )
+    thread = crud.create_thread(
+        db=db_session, agent_id=agent.id, title="t", active=True, agent_state={}, memory_strategy="buffer"
+    )
+    crud.upsert_agent_share(db_session, agent.id, viewer.id, "view")
+    runner_share = crud.upsert_agent_share(db_session, agent.id, runner.id, "run")
+
+    from zerg.dependencies.auth import get_current_user
+
+    def access_as(user):
+        app.dependency_overrides[get_current_user] = lambda: user
+        try:
+            read = client.get(f"/api/threads/{thread.id}/messages").status_code
+            post = client.post(f"/api/threads/{thread.id}/messages", json={"role": "user", "content": "hello"})
+            return read, post.status_code
+        finally:
+            with contextlib.suppress(Exception):
+                del app.dependency_overrides[get_current_user]
+
+    assert access_as(viewer) == (200, 403)
+    assert access_as(runner) == (200, 201)
+    assert access_as(stranger) == (403, 403)
+
+    # Having written in the thread grants nothing once the share is gone
+    crud.delete_agent_share(db_session, agent.id, runner_share.id)
+    assert access_as(runner) == (403, 403)
+
+    # Trashing the agent closes its threads, even to the owner
+    crud.trash_agent(db_session, agent.id)
+    assert access_as(owner) == (404, 404)
@@ -5,12 +5,19 @@ This module contains tests for the /api/threads endpoints,
 including CRUD operations for threads and messages.
 """
 
+from datetime import timedelta
+from unittest.mock import AsyncMock
+
 import pytest
 from fastapi.testclient import TestClient
 
+from zerg.crud import crud
 from zerg.models.models import Agent
 from zerg.models.models import Thread
 from zerg.models.models import ThreadMessage
+from zerg.utils.time import utc_now_naive
+from zerg.websocket.handlers import dispatch_message
+from zerg.websocket.manager import topic_manager
 
 
 @pytest.fixture
@@ -184,6 +191,26 @@ def test_update_thread(client: TestClient, sample_thread: Thread):
     assert fetched_thread["active"] == update_data["active"]
 
 
+def test_pin_and_archive_thread(client: TestClient, sample_thread: Thread):
+    """Pinned/archived flags round-trip and archiving clears the pin"""
+    assert client.get(f"/api/threads/{sample_thread.id}").json()["pinned"] is False
+
+    response = client.put(f"/api/threads/{sample_thread.id}", json={"pinned": True})
+    assert response.status_code == 200
+    assert response.json()["pinned"] is True
+    assert response.json()["archived"] is False
+
+    response = client.put(f"/api/threads/{sample_thread.id}", json={"archived": True})
+    assert response.status_code == 200
+    assert response.json()["archived"] is True
+    assert response.json()["pinned"] is False
+
+    response = client.put(f"/api/threads/{sample_thread.id}", json={"archived": False})
+    assert response.json()["archived"] is False
+    # Unrelated fields are left alone
+    assert response.json()["title"] == sample_thread.title
+
+
 def test_update_thread_not_found(client: TestClient):
     """Test the PUT /api/threads/{thread_id} endpoint with a non-existent ID"""
     update_data = {"title": "Updated Test Thread", "active": False}
@@ -304,6 +331,23 @@ def test_read_thread_messages_ordered_by_id(client: TestClient, sample_thread: T
     assert test_messages[-1]["id"] == max_id
 
 
+def test_read_thread_messages_pages_backwards(client: TestClient, sample_thread: Thread, db_session):
+    """latest/before_message_id page from the newest messages towards the oldest."""
+    for index in range(5):
+        db_session.add(ThreadMessage(thread_id=sample_thread.id, role="user", content=f"Message {index}"))
+    db_session.commit()
+
+    url = f"/api/threads/{sample_thread.id}/messages"
+    latest = client.get(url, params={"latest": True, "limit": 2}).json()
+    assert [m["content"] for m in latest] == ["Message 3", "Message 4"]
+
+    older = client.get(url, params={"before_message_id": latest[0]["id"], "limit": 2}).json()
+    assert [m["content"] for m in older] == ["Message 1", "Message 2"]
+
+    oldest = client.get(url, params={"before_message_id": older[0]["id"], "limit": 2}).json()
+    assert [m["content"] for m in oldest] == ["Message 0"]
+
+
 def test_create_thread_message(client: TestClient, sample_thread: Thread):
     """Test the POST /api/threads/{thread_id}/messages endpoint (without processing)"""
     message_data = {"role": "user", "content": "Hello, assistant"}
@@ -345,6 +389,21 @@ def test_run_thread(client: TestClient, sample_thread: Thread, db_session):
     assert message.processed is True
 
 
+def test_run_thread_records_reply_usage(client: TestClient, sample_thread: Thread, sample_agent: Agent, db_session):
+    """The final assistant message carries the run's usage for the chat footer"""
+    db_session.add(ThreadMessage(thread_id=sample_thread.id, role="user", content="Hello", processed=False))
+    db_session.commit()
+
+    assert client.post(f"/api/threads/{sample_thread.id}/run").status_code == 202
+
+    messages = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    reply = [m for m in messages if m["role"] == "assistant"][-1]
+    assert reply["usage"]["model"] == sample_agent.model
+    assert reply["usage"]["run_id"] is not None
+    assert reply["usage"]["duration_ms"] >= 0
+    assert all(m["usage"] is None for m in messages if m["role"] == "user")
+
+
 def test_run_thread_no_unprocessed_messages(client: TestClient, sample_thread: Thread):
     """Test running a thread with no unprocessed messages"""
     response = client.post(
@@ -362,6 +421,63 @@ def test_run_thread_not_found(client: TestClient):
     assert response.json()["detail"] == "Thread not found"
 
 
+def test_user_messages_record_their_author(client: TestClient, sample_thread: Thread, _dev_user):
+    """User messages carry the author's public profile for shared threads"""
+    response = client.post(f"/api/threads/{sample_thread.id}/messages", json={"role": "user", "content": "Hi"})
+    assert response.status_code == 201
+    assert response.json()["author"]["id"] == _dev_user.id
+
+    listed = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    assert listed[-1]["author"]["id"] == _dev_user.id
+
+
+def test_run_thread_rejects_concurrent_reply(client: TestClient, sample_thread: Thread, db_session):
+    """Only one agent reply may be pending per thread"""
+    message = ThreadMessage(thread_id=sample_thread.id, role="user", content="Hello", processed=False)
+    db_session.add(message)
+    db_session.commit()
+
+    # Claimed in the database, as another worker process would
+    assert crud.claim_thread_reply(db_session, sample_thread.id)
+    assert not crud.claim_thread_reply(db_session, sample_thread.id)
+    response = client.post(f"/api/threads/{sample_thread.id}/run")
+    assert response.status_code == 409
+    assert response.json()["detail"] == "The agent is already replying in this thread"
+
+    response = client.post(f"/api/threads/{sample_thread.id}/regenerate")
+    assert response.status_code == 409
+
+    db_session.refresh(message)
+    assert message.processed is False
+
+    # A claim left behind by a crashed run expires
+    sample_thread.reply_started_at = utc_now_naive() - crud.THREAD_REPLY_CLAIM_TTL - timedelta(seconds=1)
+    db_session.commit()
+    assert client.post(f"/api/threads/{sample_thread.id}/run").status_code == 202
+    db_session.refresh(sample_thread)
+    assert sample_thread.reply_started_at is None
+
+
+@pytest.mark.asyncio
+async def test_websocket_message_rejected_while_agent_replies(sample_thread: Thread, db_session, _dev_user):
+    """Posting over the socket honours the same pending-reply guard as the REST endpoints"""
+    ws = AsyncMock()
+    await topic_manager.connect("pending-reply", ws, user_id=_dev_user.id)
+    try:
+        crud.claim_thread_reply(db_session, sample_thread.id)
+        frame = {"type": "send_message", "thread_id": sample_thread.id, "content": "Hi", "message_id": "m1"}
+        await dispatch_message("pending-reply", frame, db_session)
+        [error] = [call.args[0] for call in ws.send_json.call_args_list if call.args[0]["type"] == "error"]
+        assert error["data"]["error"] == "The agent is already replying in this thread"
+        assert not any(m.content == "Hi" for m in crud.get_thread_messages(db_session, sample_thread.id))
+
+        crud.release_thread_reply(db_session, sample_thread.id)
+        await dispatch_message("pending-reply", {**frame, "message_id": "m2"}, db_session)
+        assert any(m.content == "Hi" for m in crud.get_thread_messages(db_session, sample_thread.id))
+    finally:
+        await topic_manager.disconnect("pending-reply")
+
+
 # ============================================================================
 # AUTOMATION THREADS API CONTRACT TESTS
 # ============================================================================
@@ -621,3 +737,115 @@ def test_automation_threads_separated_from_chat(client: TestClient, sample_agent
     assert len(chat_ids & scheduled_ids) == 0, "Chat threads should not appear in scheduled"
     assert len(chat_ids & manual_ids) == 0, "Chat threads should not appear in manual"
     assert len(scheduled_ids & manual_ids) == 0, "Scheduled should not appear in manual"
+
+
+def test_fork_thread_from_user_message(client: TestClient, sample_thread: Thread, sample_thread_messages):
+    """Editing a user message forks history before it and appends the new content."""
+    user_message = sample_thread_messages[1]
+
+    response = client.post(
+        f"/api/threads/{sample_thread.id}/fork",
+        json={"message_id": user_message.id, "content": "Hello again, edited"},
+    )
+    assert response.status_code == 201
+    forked = response.json()
+    assert forked["id"] != sample_thread.id
+    assert forked["title"] == "Test Thread (edited)"
+
+    messages = client.get(f"/api/threads/{forked['id']}/messages").json()
+    assert [m["role"] for m in messages] == ["system", "user"]
+    assert messages[-1]["content"] == "Hello again, edited"
+    assert messages[-1]["processed"] is False
+
+    # Original thread keeps its full history
+    original = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    assert len(original) == 3
+
+
+def test_fork_thread_rejects_assistant_message(client: TestClient, sample_thread: Thread, sample_thread_messages):
+    response = client.post(
+        f"/api/threads/{sample_thread.id}/fork",
+        json={"message_id": sample_thread_messages[2].id, "content": "nope"},
+    )
+    assert response.status_code == 400
+
+
+def test_regenerate_drops_last_assistant_turn(client: TestClient, sample_thread: Thread, sample_thread_messages):
+    response = client.post(f"/api/threads/{sample_thread.id}/regenerate")
+    assert response.status_code == 200
+    assert response.json()["id"] == sample_thread_messages[1].id
+    assert response.json()["processed"] is False
+
+    messages = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    assert [m["role"] for m in messages] == ["system", "user"]
+
+
+def test_upload_attachment_and_send_with_message(client: TestClient, sample_thread: Thread):
+    """Uploaded files are referenced from the message and returned with it."""
+    upload = client.post(
+        f"/api/threads/{sample_thread.id}/attachments",
+        files={"file": ("notes.txt", b"hello attachment", "text/plain")},
+    )
+    assert upload.status_code == 201
+    attachment = upload.json()
+    assert attachment["filename"] == "notes.txt"
+    assert attachment["size"] == len(b"hello attachment")
+    assert attachment["url"].startswith("/static/attachments/")
+
+    response = client.post(
+        f"/api/threads/{sample_thread.id}/messages",
+        json={"role": "user", "content": "See attached", "attachments": [attachment]},
+    )
+    assert response.status_code == 201
+    assert response.json()["attachments"] == [attachment]
+
+    messages = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    assert messages[-1]["attachments"] == [attachment]
+
+
+def test_attachment_rejects_unsupported_type_and_foreign_urls(client: TestClient, sample_thread: Thread):
+    upload = client.post(
+        f"/api/threads/{sample_thread.id}/attachments",
+        files={"file": ("run.exe", b"MZ", "application/x-msdownload")},
+    )
+    assert upload.status_code == 415
+
+    forged = {
+        "id": "x",
+        "filename": "evil.png",
+        "content_type": "image/png",
+        "size": 1,
+        "url": "https://example.com/evil.png",
+    }
+    response = client.post(
+        f"/api/threads/{sample_thread.id}/messages",
+        json={"role": "user", "content": "hi", "attachments": [forged]},
+    )
+    assert response.status_code == 400
+
+
+def test_bookmark_and_react_to_message(
+    client: TestClient, sample_agent: Agent, sample_thread: Thread, sample_thread_messages
+):
+    """Bookmarks land in the agent's saved list; reactions toggle per user."""
+    message = sample_thread_messages[2]
+    url = f"/api/threads/{sample_thread.id}/messages/{message.id}/metadata"
+
+    response = client.patch(url, json={"bookmarked": True, "reaction": "👍"})
+    assert response.status_code == 200
+    assert response.json()["bookmarked"] is True
+    assert response.json()["reactions"] == [{"emoji": "👍", "count": 1, "reacted": True}]
+
+    saved = client.get("/api/threads/bookmarks", params={"agent_id": sample_agent.id}).json()
+    assert [m["id"] for m in saved] == [message.id]
+
+    messages = client.get(f"/api/threads/{sample_thread.id}/messages").json()
+    assert messages[-1]["bookmarked"] is True
+
+    toggled = client.patch(url, json={"bookmarked": False, "reaction": "👍"}).json()
+    assert toggled["bookmarked"] is False
+    assert toggled["reactions"] == []
+    assert client.get("/api/threads/bookmarks", params={"agent_id": sample_agent.id}).json() == []
+
+    assert client.patch(url, json={"reaction": "🦄"}).status_code == 400
+    assert client.patch(f"/api/threads/{sample_thread.id}/messages/999999/metadata", json={}).status_code == 404
@@ -0,0 +1,42 @@
+"""Tests for the per-agent tool usage statistics endpoint."""
+
+from datetime import datetime
+from datetime import timezone
+
+from tests.conftest import TEST_MODEL
+from zerg.crud import crud
+
+
+def test_tool_usage_counts_tool_results_per_name(client, db_session, sample_agent):
+    thread = crud.create_thread(db_session, agent_id=sample_agent.id, title="t")
+    other = crud.create_thread(db_session, agent_id=sample_agent.id, title="t2", active=False)
+    early = datetime(2026, 1, 1, tzinfo=timezone.utc)
+    late = datetime(2026, 3, 1, tzinfo=timezone.utc)
+
+    def tool_result(thread_id, name, sent_at):
+        crud.create_thread_message(db_session, thread_id, "tool", "ok", tool_call_id="x", name=name, sent_at=sent_at)
+
+    tool_result(thread.id, "http_request", early)
+    tool_result(other.id, "http_request", late)
+    tool_result(thread.id, "math_eval", early)
+    # Assistant messages and tool messages without a name are ignored
+    crud.create_thread_message(db_session, thread.id, "assistant", "hi", name="http_request")
+    crud.create_thread_message(db_session, thread.id, "tool", "ok", tool_call_id="d")
+
+    resp = client.get(f"/api/agents/{sample_agent.id}/tool-usage")
+    assert resp.status_code == 200, resp.text
+    tools = resp.json()["tools"]
+    assert [(t["name"], t["calls"]) for t in tools] == [("http_request", 2), ("math_eval", 1)]
+    assert tools[0]["last_used_at"].startswith("2026-03-01")
+
+
+def test_tool_usage_requires_ownership(client, db_session, other_user):
+    agent = crud.create_agent(
+        db_session,
+        owner_id=other_user.id,
+        name="Not mine",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_MODEL,
+    )
+    assert client.get(f"/api/agents/{agent.id}/tool-usage").status_code == 403
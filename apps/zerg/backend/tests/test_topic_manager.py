@@ -129,6 +129,31 @@ class TestTopicConnectionManager:
         expected_data = {"id": agent_id, "name": "Test Agent"}
         assert sent_envelope["data"] == expected_data
 
+    async def test_agent_event_delta_to_owner_topic(self, topic_manager, mock_websocket):
+        """Full agent payloads also reach the owner's user topic as numbered deltas."""
+        client_id = "test-client-1"
+        await topic_manager.connect(client_id, mock_websocket, user_id=7)
+
+        agent = {"id": 123, "name": "Test Agent", "owner_id": 7}
+        await topic_manager._handle_agent_event({**agent, "event_type": EventType.AGENT_CREATED})
+        await topic_manager._handle_agent_event({**agent, "event_type": EventType.AGENT_DELETED})
+
+        sent = [call.args[0] for call in mock_websocket.send_json.call_args_list]
+        assert [env["type"] for env in sent] == ["agent_event", "agent_event"]
+        assert sent[0]["topic"] == "user:7"
+        assert sent[0]["data"] == {**agent, "action": "created", "seq": 1}
+        assert sent[1]["data"]["action"] == "deleted"
+        assert sent[1]["data"]["seq"] == 2
+
+    async def test_partial_agent_event_skips_owner_topic(self, topic_manager, mock_websocket):
+        """Status-only updates without an owner stay on the agent topic."""
+        await topic_manager.connect("test-client-1", mock_websocket, user_id=7)
+
+        await topic_manager._handle_agent_event({"id": 123, "status": "running", "event_type": EventType.AGENT_UPDATED})
+
+        mock_websocket.send_json.assert_not_called()
+        assert topic_manager._agent_event_seq == {}
+
     async def test_handle_thread_event(self, topic_manager, mock_websocket):
         """Test handling of thread events."""
         client_id = "test-client-1"
@@ -0,0 +1,157 @@
+"""Webhook trigger management: per-trigger secrets, rotation, deliveries, test fire."""
+
+import hashlib
+import hmac
+import json
+import time
+
+import pytest
+
+from tests.conftest import TEST_MODEL
+from zerg import constants
+from zerg.crud import crud
+from zerg.services.scheduler_service import scheduler_service
+
+
+@pytest.fixture
+def run_calls():
+    calls = []
+
+    async def _stub_run_agent_task(agent_id: int, trigger: str = "schedule"):  # type: ignore
+        calls.append((agent_id, trigger))
+
+    original = scheduler_service.run_agent_task
+    scheduler_service.run_agent_task = _stub_run_agent_task  # type: ignore
+    try:
+        yield calls
+    finally:
+        scheduler_service.run_agent_task = original  # type: ignore
+
+
+def _create_trigger(client, sample_agent):
+    resp = client.post("/api/triggers/", json={"agent_id": sample_agent.id, "type": "webhook"})
+    assert resp.status_code == 201, resp.text
+    return resp.json()
+
+
+def _signed_headers(secret: str, body: dict, *, timestamp: str = "") -> dict:
+    timestamp = timestamp or str(int(time.time()))
+    serialised = json.dumps(body, separators=(",", ":"), sort_keys=True)
+    signature = hmac.new(secret.encode(), f"{timestamp}.{serialised}".encode(), hashlib.sha256).hexdigest()
+    return {"X-Zerg-Timestamp": timestamp, "X-Zerg-Signature": signature}
+
+
+def test_per_trigger_secret_is_accepted_and_logged(client, sample_agent, run_calls):
+    trg = _create_trigger(client, sample_agent)
+    body = {"hello": "world"}
+
+    resp = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body))
+    assert resp.status_code == 202, resp.text
+    assert run_calls == [(sample_agent.id, "webhook")]
+
+    deliveries = client.get(f"/api/triggers/{trg['id']}/deliveries").json()
+    assert len(deliveries) == 1
+    assert deliveries[0]["status_code"] == 202
+    assert deliveries[0]["is_test"] is False
+    assert deliveries[0]["payload_bytes"] == len(b'{"hello":"world"}')
+
+
+def test_bad_signature_is_rejected_without_logging(client, sample_agent, run_calls):
+    trg = _create_trigger(client, sample_agent)
+    body = {"a": 1}
+
+    for secret in ("wrong", constants.TRIGGER_SIGNING_SECRET):
+        resp = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(secret, body))
+        assert resp.status_code == 403
+    assert run_calls == []
+    # Unsigned callers can't fill the delivery log
+    assert client.get(f"/api/triggers/{trg['id']}/deliveries").json() == []
+
+    # An unknown trigger looks the same as a bad signature
+    unknown = client.post("/api/triggers/999999/events", json=body, headers=_signed_headers("wrong", body))
+    assert (unknown.status_code, unknown.json()) == (resp.status_code, resp.json())
+
+
+def test_signed_replay_is_rejected_and_logged(client, sample_agent, run_calls):
+    trg = _create_trigger(client, sample_agent)
+    body = {"a": 1}
+    stale = str(int(time.time()) - 3600)
+
+    resp = client.post(
+        f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body, timestamp=stale)
+    )
+    assert resp.status_code == 400
+    assert run_calls == []
+
+    deliveries = client.get(f"/api/triggers/{trg['id']}/deliveries").json()
+    assert [(d["status_code"], d["error"]) for d in deliveries] == [(400, "Timestamp skew too large")]
+
+
+def test_rotate_secret_invalidates_old_secret(client, sample_agent, run_calls):
+    trg = _create_trigger(client, sample_agent)
+
+    resp = client.post(f"/api/triggers/{trg['id']}/rotate-secret")
+    assert resp.status_code == 200, resp.text
+    new_secret = resp.json()["secret"]
+    assert new_secret != trg["secret"]
+
+    body = {"x": "y"}
+    old = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body))
+    assert old.status_code == 403
+    new = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(new_secret, body))
+    assert new.status_code == 202
+
+
+def test_test_fire_runs_agent_and_flags_delivery(client, sample_agent, run_calls):
+    trg = _create_trigger(client, sample_agent)
+
+    resp = client.post(f"/api/triggers/{trg['id']}/test", json={"sample": True})
+    assert resp.status_code == 202, resp.text
+    assert resp.json()["delivery"]["is_test"] is True
+    assert run_calls == [(sample_agent.id, "webhook")]
+
+    deliveries = client.get(f"/api/triggers/{trg['id']}/deliveries?limit=5").json()
+    assert deliveries[0]["is_test"] is True
+    assert deliveries[0]["matched"] == "Test fire from the UI"
+    assert deliveries[0]["run_id"] is None
+
+
+def test_delivery_links_the_run_it_started(client, db_session, sample_agent):
+    thread = crud.create_thread(db_session, agent_id=sample_agent.id, title="webhook")
+    started = []
+
+    async def _stub_run_agent_task(agent_id: int, trigger: str = "schedule"):  # type: ignore
+        run = crud.create_run(db_session, agent_id=agent_id, thread_id=thread.id, trigger=trigger, status="running")
+        started.append(run.id)
+
+    original = scheduler_service.run_agent_task
+    scheduler_service.run_agent_task = _stub_run_agent_task  # type: ignore
+    try:
+        trg = _create_trigger(client, sample_agent)
+        body = {"hello": "world"}
+        resp = client.post(f"/api/triggers/{trg['id']}/events", json=body, headers=_signed_headers(trg["secret"], body))
+        assert resp.status_code == 202, resp.text
+    finally:
+        scheduler_service.run_agent_task = original  # type: ignore
+
+    delivery = client.get(f"/api/triggers/{trg['id']}/deliveries").json()[0]
+    assert delivery["matched"] == "Valid signature"
+    assert delivery["run_id"] == started[0]
+    assert delivery["run_status"] == "running"
+
+
+def test_management_endpoints_require_ownership(client, db_session, other_user, run_calls):
+    foreign_agent = crud.create_agent(
+        db_session,
+        owner_id=other_user.id,
+        name="Not mine",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_MODEL,
+    )
+    trg = crud.create_trigger(db_session, agent_id=foreign_agent.id, trigger_type="webhook")
+
+    assert client.post(f"/api/triggers/{trg.id}/rotate-secret").status_code == 403
+    assert client.get(f"/api/triggers/{trg.id}/deliveries").status_code == 403
+    assert client.post(f"/api/triggers/{trg.id}/test", json={}).status_code == 403
+    assert run_calls == []
@@ -5,7 +5,6 @@ import hmac
 import json
 import time
 
-from zerg import constants
 from zerg.services.scheduler_service import scheduler_service
 
 
@@ -50,7 +49,7 @@ def test_webhook_trigger_flow(client):
         timestamp = str(int(time.time()))
         data_to_sign = f"{timestamp}.{body_serialised}".encode()
         signature = hmac.new(
-            constants.TRIGGER_SIGNING_SECRET.encode(),
+            trg_resp.json()["secret"].encode(),
             data_to_sign,
             hashlib.sha256,
         ).hexdigest()
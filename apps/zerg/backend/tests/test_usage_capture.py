@@ -145,3 +145,59 @@ async def test_usage_missing_leaves_totals_null(client, db_session, monkeypatch)
     assert resp.status_code == 202, resp.text
     runs = crud.list_runs(db_session, agent.id, limit=1)
     assert runs and runs[0].total_tokens is None and runs[0].total_cost_usd is None
+
+
+@pytest.mark.asyncio
+async def test_thread_model_override_runs_and_records_that_model(client, db_session, monkeypatch):
+    import zerg.agents_def.zerg_react_agent as zr
+    import zerg.managers.agent_runner as ar
+
+    ar._RUNNABLE_CACHE.clear()
+    models_seen = []
+
+    class _RecordingStub(_UsageStub):
+        def __init__(self, *args, **kwargs):
+            models_seen.append(kwargs.get("model"))
+
+    monkeypatch.setattr(zr, "ChatOpenAI", _RecordingStub)
+
+    user = crud.get_user_by_email(db_session, "u3@local") or crud.create_user(
+        db_session, email="u3@local", provider=None, role="ADMIN"
+    )
+    agent = crud.create_agent(
+        db_session,
+        owner_id=user.id,
+        name="a3",
+        system_instructions="sys",
+        task_instructions="task",
+        model=TEST_MODEL,
+        schedule=None,
+        config={},
+    )
+    thread = crud.create_thread(
+        db=db_session, agent_id=agent.id, title="t3", active=True, agent_state={}, memory_strategy="buffer"
+    )
+    crud.create_thread_message(db=db_session, thread_id=thread.id, role="user", content="hi")
+
+    from zerg.dependencies.auth import get_current_user
+
+    app.dependency_overrides[get_current_user] = lambda: user
+    try:
+        assert client.put(f"/api/threads/{thread.id}", json={"model_override": "no-such-model"}).status_code == 400
+        resp = client.put(f"/api/threads/{thread.id}", json={"model_override": TEST_WORKER_MODEL})
+        assert resp.status_code == 200 and resp.json()["model_override"] == TEST_WORKER_MODEL
+        resp = client.post(f"/api/threads/{thread.id}/run")
+        assert resp.status_code == 202, resp.text
+        messages = client.get(f"/api/threads/{thread.id}/messages").json()
+        cleared = client.put(f"/api/threads/{thread.id}", json={"model_override": ""}).json()
+    finally:
+        with contextlib.suppress(Exception):
+            del app.dependency_overrides[get_current_user]
+
+    assert TEST_WORKER_MODEL in models_seen and TEST_MODEL not in models_seen
+    usage = [m["usage"] for m in messages if m["role"] == "assistant" and m.get("usage")]
+    assert usage and usage[-1]["model"] == TEST_WORKER_MODEL
+    # The agent's own model is untouched, and clearing hands the thread back to it
+    db_session.refresh(agent)
+    assert agent.model == TEST_MODEL
+    assert cleared["model_override"] is None
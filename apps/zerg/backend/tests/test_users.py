@@ -92,3 +92,84 @@ def test_user_updated_event_emitted(client: TestClient):
         # The payload must include id and display_name
         assert "id" in payload
         assert payload["display_name"] == "Bob"
+
+
+def test_get_current_user_capabilities(client: TestClient):
+    """GET /api/users/me/capabilities mirrors the dev user's role."""
+
+    resp = client.get("/api/users/me/capabilities")
+    assert resp.status_code == 200
+    data = resp.json()
+
+    if data["role"] == "ADMIN":
+        assert {"view_ops", "view_all_agents"}.issubset(data["capabilities"])
+    else:
+        assert data["capabilities"] == []
+
+
+def test_capabilities_follow_role_and_admin_emails(monkeypatch):
+    """Only admins get capabilities; database resets need ADMIN_EMAILS outside test/dev."""
+
+    from types import SimpleNamespace
+
+    from zerg.auth import capabilities as caps
+
+    settings = SimpleNamespace(auth_disabled=False, testing=False, admin_emails="root@example.com")
+    monkeypatch.setattr(caps, "get_settings", lambda: settings)
+
+    assert caps.capabilities_for(SimpleNamespace(role="USER", email="root@example.com")) == set()
+
+    admin = caps.capabilities_for(SimpleNamespace(role="ADMIN", email="ops@example.com"))
+    assert caps.Capability.VIEW_OPS in admin
+    assert caps.Capability.RESET_DATABASE not in admin
+
+    super_admin = caps.capabilities_for(SimpleNamespace(role="ADMIN", email="Root@Example.com"))
+    assert caps.Capability.RESET_DATABASE in super_admin
+
+
+def test_update_current_user_rejects_bad_known_prefs(client: TestClient):
+    """Known pref keys are validated; unknown keys pass through untouched."""
+
+    resp = client.put("/api/users/me", json={"prefs": {"theme": "pink", "timezone": "Mars/Base"}})
+    assert resp.status_code == 422
+    assert "theme: must be one of" in resp.json()["detail"]
+    assert "unknown timezone 'Mars/Base'" in resp.json()["detail"]
+
+    prefs = {"timezone": "Europe/Berlin", "quiet_hours": None, "future_setting": {"anything": 1}}
+    resp = client.put("/api/users/me", json={"prefs": prefs})
+    assert resp.status_code == 200
+    assert resp.json()["prefs"] == prefs
+
+
+def test_agent_shelf_prefs_are_validated(client: TestClient):
+    resp = client.put("/api/users/me", json={"prefs": {"shelf_favorites": ["3"], "shelf_grouping": "colour"}})
+    assert resp.status_code == 422
+    assert "shelf_favorites: must be a list of ids" in resp.json()["detail"]
+    assert "shelf_grouping: must be one of" in resp.json()["detail"]
+
+    prefs = {"shelf_favorites": [3, 7], "shelf_grouping": "owner", "shelf_collapsed_groups": ["owner:me"]}
+    resp = client.put("/api/users/me", json={"prefs": prefs})
+    assert resp.status_code == 200
+    assert resp.json()["prefs"] == prefs
+
+
+def test_workspace_defaults_are_set_by_admins_and_read_by_everyone(client: TestClient, db_session):
+    from zerg.crud import crud
+
+    assert client.get("/api/workspace/defaults").json()["theme"] is None
+
+    client.get("/api/users/me")
+    user = crud.get_user_by_email(db_session, "dev@local")
+    user.role = "ADMIN"  # type: ignore[attr-defined]
+    db_session.commit()
+
+    bad = client.put("/api/admin/workspace-defaults", json={"dashboard_scope": "everyone"})
+    assert bad.status_code == 422
+
+    resp = client.put("/api/admin/workspace-defaults", json={"theme": "light", "time_display": "relative"})
+    assert resp.status_code == 200
+
+    defaults = client.get("/api/workspace/defaults").json()
+    assert defaults["theme"] == "light"
+    assert defaults["time_display"] == "relative"
+    assert defaults["default_model"] is None
@@ -0,0 +1,116 @@
+"""Wait node: deadlines, the skip and resume endpoints, and engine execution."""
+
+import asyncio
+from datetime import timedelta
+
+import pytest
+
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.node_executors import wait_deadline
+from zerg.services.workflow_engine import workflow_engine
+from zerg.services.workflow_waits import workflow_waits
+from zerg.utils.time import utc_now_naive
+
+
+def test_wait_deadline_per_mode():
+    before = utc_now_naive()
+    assert wait_deadline({"mode": "duration", "minutes": 5}) - before >= timedelta(minutes=5)
+    assert wait_deadline({"mode": "until", "until": "2030-01-01T09:00:00+01:00"}).isoformat() == "2030-01-01T08:00:00"
+    assert wait_deadline({"mode": "event", "event": "approved"}) is None
+    assert wait_deadline({"mode": "event", "event": "approved", "timeout_minutes": 1}) > before
+
+
+@pytest.mark.parametrize(
+    "config, message",
+    [
+        ({"mode": "sleep"}, "Unknown wait mode sleep"),
+        ({"mode": "duration", "minutes": -1}, "non-negative number"),
+        ({"mode": "until", "until": "tomorrow"}, "ISO 8601"),
+        ({"mode": "event"}, "need an event name"),
+    ],
+)
+def test_wait_deadline_rejects_bad_config(config, message):
+    with pytest.raises(ValueError, match=message):
+        wait_deadline(config)
+
+
+def _workflow(db, test_user, config):
+    workflow_data = WorkflowData(
+        nodes=[WorkflowNode(id="wait-1", type="wait", position=Position(x=0, y=0), config=config)],
+        edges=[],
+    )
+    workflow = Workflow(owner_id=test_user.id, name="Wait", canvas=workflow_data.model_dump(), is_active=True)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+async def _pending_wait():
+    for _ in range(100):
+        waits = list(workflow_waits._waits.values())
+        if waits:
+            return waits[0]
+        await asyncio.sleep(0.01)
+    raise AssertionError("wait node never parked")
+
+
+@pytest.mark.asyncio
+async def test_elapsed_wait_finishes(db, test_user):
+    workflow = _workflow(db, test_user, {"mode": "duration", "minutes": 0})
+
+    execution_id = await workflow_engine.execute_workflow(workflow.id)
+
+    execution = db.query(WorkflowExecution).filter_by(id=execution_id).first()
+    assert execution.result == "success"
+    output = execution.node_states[0].output
+    assert output["value"]["outcome"] == "elapsed"
+    assert output["meta"]["mode"] == "duration"
+
+
+@pytest.mark.asyncio
+async def test_event_wait_resumes_with_payload(db, test_user):
+    workflow = _workflow(db, test_user, {"mode": "event", "event": "approved"})
+
+    run = asyncio.create_task(workflow_engine.execute_workflow(workflow.id))
+    pending = await _pending_wait()
+    assert pending.token
+    pending.release("event", {"approved_by": "ops"})
+    execution_id = await run
+
+    execution = db.query(WorkflowExecution).filter_by(id=execution_id).first()
+    value = execution.node_states[0].output["value"]
+    assert value["outcome"] == "event"
+    assert value["payload"] == {"approved_by": "ops"}
+    assert workflow_waits.get(execution_id, "wait-1") is None
+
+
+def test_skip_and_resume_endpoints(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user, {"mode": "event", "event": "approved"})
+    execution = WorkflowExecution(workflow_id=workflow.id, phase="running")
+    db.add(execution)
+    db.commit()
+
+    pending = workflow_waits.create(execution.id, "wait-1", mode="event", event="approved")
+    try:
+        assert client.post("/api/workflow-waits/not-a-token", json={}).status_code == 404
+
+        resumed = client.post(f"/api/workflow-waits/{pending.token}", json={"ok": True})
+        assert resumed.status_code == 204
+        assert (pending.outcome, pending.payload) == ("event", {"ok": True})
+    finally:
+        workflow_waits.discard(pending)
+
+    skipped = workflow_waits.create(execution.id, "wait-1", mode="duration", resume_at=utc_now_naive())
+    try:
+        response = client.post(f"/api/workflow-executions/{execution.id}/waits/wait-1/skip", headers=auth_headers)
+        assert response.status_code == 204
+        assert skipped.outcome == "skipped"
+    finally:
+        workflow_waits.discard(skipped)
+
+    missing = client.post(f"/api/workflow-executions/{execution.id}/waits/other/skip", headers=auth_headers)
+    assert missing.status_code == 409
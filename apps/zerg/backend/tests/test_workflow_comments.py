@@ -0,0 +1,98 @@
+"""Comment threads on workflow canvas nodes."""
+
+import contextlib
+from unittest.mock import AsyncMock
+
+import pytest
+
+from zerg.crud import crud
+from zerg.dependencies.auth import get_current_user
+from zerg.main import app
+from zerg.routers import workflows as workflows_router
+
+
+def _mk_user(db_session, email: str, role: str = "USER"):
+    user = crud.get_user_by_email(db_session, email)
+    if user is None:
+        user = crud.create_user(db_session, email=email, provider=None, role=role)
+    return user
+
+
+@contextlib.contextmanager
+def _as(user):
+    app.dependency_overrides[get_current_user] = lambda: user
+    try:
+        yield
+    finally:
+        with contextlib.suppress(Exception):
+            del app.dependency_overrides[get_current_user]
+
+
+@pytest.fixture
+def owner(db_session):
+    return _mk_user(db_session, "comment-owner@local.test")
+
+
+@pytest.fixture
+def workflow(db_session, owner):
+    return crud.create_workflow(db_session, owner_id=owner.id, name="Commented", canvas={"nodes": [], "edges": []})
+
+
+def test_threads_replies_and_resolution(client, owner, workflow):
+    base = f"/api/workflows/{workflow.id}/comments"
+    with _as(owner):
+        resp = client.post(base, json={"node_id": "node-1", "body": "  Should this retry?  "})
+        assert resp.status_code == 201, resp.text
+        root = resp.json()
+        assert root["body"] == "Should this retry?"
+        assert root["parent_id"] is None
+        assert root["author_name"] == owner.email
+
+        reply = client.post(base, json={"parent_id": root["id"], "body": "Yes, twice"}).json()
+        assert reply["parent_id"] == root["id"] and reply["node_id"] == "node-1"
+        # Replying to a reply joins the same thread
+        nested = client.post(base, json={"parent_id": reply["id"], "body": "Done"}).json()
+        assert nested["parent_id"] == root["id"]
+
+        assert client.post(base, json={"body": "orphan"}).status_code == 422
+        client.post(base, json={"node_id": "node-2", "body": "Elsewhere"})
+
+        listed = client.get(base, params={"node_id": "node-1"}).json()
+        assert [c["id"] for c in listed] == [root["id"], reply["id"], nested["id"]]
+
+        # Resolving through any comment resolves the thread root
+        resolved = client.patch(f"{base}/{reply['id']}", json={"resolved": True}).json()
+        assert resolved["id"] == root["id"]
+        assert resolved["resolved_at"] and resolved["resolved_by_id"] == owner.id
+
+        reopened = client.patch(f"{base}/{root['id']}", json={"resolved": False}).json()
+        assert reopened["resolved_at"] is None
+
+
+def test_mentions_notify_the_mentioned_user(client, db_session, monkeypatch, owner, workflow):
+    mentioned = _mk_user(db_session, "comment-reviewer@local.test")
+    broadcast = AsyncMock()
+    monkeypatch.setattr(workflows_router.topic_manager, "broadcast_to_topic", broadcast)
+
+    body = f"@{mentioned.email} can you check this? cc @{owner.email} and @nobody@local.test"
+    with _as(owner):
+        resp = client.post(f"/api/workflows/{workflow.id}/comments", json={"node_id": "node-1", "body": body})
+    assert resp.status_code == 201, resp.text
+    assert resp.json()["mentioned_user_ids"] == [mentioned.id]
+
+    # Only the mentioned user hears about it; the author and unknown addresses are skipped
+    broadcast.assert_awaited_once()
+    topic, envelope = broadcast.await_args.args
+    assert topic == f"user:{mentioned.id}"
+    assert envelope["type"] == "comment_mention"
+    assert envelope["data"]["workflow_id"] == workflow.id
+    assert envelope["data"]["node_id"] == "node-1"
+    assert envelope["data"]["author_name"] == owner.email
+
+
+def test_comments_are_limited_to_the_workflow_owner(client, db_session, workflow):
+    stranger = _mk_user(db_session, "comment-stranger@local.test")
+    with _as(stranger):
+        assert client.get(f"/api/workflows/{workflow.id}/comments").status_code == 404
+        resp = client.post(f"/api/workflows/{workflow.id}/comments", json={"node_id": "n", "body": "hi"})
+        assert resp.status_code == 404
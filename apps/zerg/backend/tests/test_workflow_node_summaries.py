@@ -0,0 +1,56 @@
+"""Tests for /workflow-executions/history/{workflow_id}/node-summaries."""
+
+from fastapi.testclient import TestClient
+
+from zerg.models.models import NodeExecutionState
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+
+
+def _insert_workflow(db, owner_id: int) -> Workflow:
+    wf = Workflow(owner_id=owner_id, name="wf-heat", canvas={"nodes": [], "edges": []})
+    db.add(wf)
+    db.commit()
+    db.refresh(wf)
+    return wf
+
+
+def _insert_execution(db, workflow_id: int, node_results: dict[str, str]) -> WorkflowExecution:
+    execution = WorkflowExecution(workflow_id=workflow_id, phase="finished", result="success")
+    db.add(execution)
+    db.commit()
+    for node_id, result in node_results.items():
+        db.add(
+            NodeExecutionState(
+                workflow_execution_id=execution.id,
+                node_id=node_id,
+                phase="finished",
+                result=result,
+                output={"value": None, "meta": {"execution_time_ms": 150, "total_cost_usd": 0.01}},
+            )
+        )
+    db.commit()
+    db.refresh(execution)
+    return execution
+
+
+def test_node_summaries_newest_first_with_limit(client: TestClient, db_session, _dev_user):
+    wf = _insert_workflow(db_session, _dev_user.id)
+    older = _insert_execution(db_session, wf.id, {"a": "success"})
+    newer = _insert_execution(db_session, wf.id, {"a": "failure", "b": "success"})
+
+    resp = client.get(f"/api/workflow-executions/history/{wf.id}/node-summaries?limit=1")
+    assert resp.status_code == 200
+    payload = resp.json()
+    assert [item["execution_id"] for item in payload] == [newer.id]
+    assert older.id not in [item["execution_id"] for item in payload]
+
+    nodes = {node["node_id"]: node for node in payload[0]["nodes"]}
+    assert nodes["a"]["result"] == "failure"
+    assert nodes["a"]["duration_ms"] == 150
+    assert nodes["b"]["cost_usd"] == 0.01
+
+
+def test_node_summaries_unknown_workflow_404(client: TestClient):
+    resp = client.get("/api/workflow-executions/history/999999/node-summaries")
+    assert resp.status_code == 404
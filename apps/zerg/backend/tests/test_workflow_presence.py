@@ -0,0 +1,87 @@
+from unittest.mock import AsyncMock
+
+import pytest
+
+from zerg.crud import crud
+from zerg.websocket.handlers import dispatch_message
+from zerg.websocket.manager import topic_manager
+from zerg.websocket.presence import presence_registry
+
+
+def _socket() -> AsyncMock:
+    ws = AsyncMock()
+    ws.send_json = AsyncMock()
+    ws.close = AsyncMock()
+    return ws
+
+
+def _frames(ws: AsyncMock, message_type: str) -> list[dict]:
+    return [call.args[0] for call in ws.send_json.call_args_list if call.args[0]["type"] == message_type]
+
+
+@pytest.mark.asyncio
+async def test_presence_join_update_and_leave(db_session):
+    presence_registry.reset()
+    owner = crud.create_user(db_session, email="presence-owner@local", provider=None, role="USER")
+    workflow = crud.create_workflow(db_session, owner_id=owner.id, name="Presence", canvas={"nodes": [], "edges": []})
+    topic = f"workflow:{workflow.id}:presence"
+    first, second = _socket(), _socket()
+
+    await topic_manager.connect("presence-a", first, user_id=owner.id)
+    await topic_manager.connect("presence-b", second, user_id=owner.id)
+    try:
+        await dispatch_message("presence-a", {"type": "subscribe", "topics": [topic], "message_id": "m1"}, db_session)
+        [state] = _frames(first, "presence_state")
+        assert state["data"]["self_client_id"] == "presence-a"
+        assert state["data"]["editors"] == []
+
+        # The second tab sees the first in its snapshot; the first hears about the second
+        await dispatch_message("presence-b", {"type": "subscribe", "topics": [topic], "message_id": "m2"}, db_session)
+        [state] = _frames(second, "presence_state")
+        assert [editor["client_id"] for editor in state["data"]["editors"]] == ["presence-a"]
+        assert _frames(first, "presence_update")[-1]["data"]["client_id"] == "presence-b"
+
+        update = {
+            "type": "update_presence",
+            "workflow_id": workflow.id,
+            "cursor": {"x": 12.5, "y": 40},
+            "selected_node_ids": ["node-1"],
+            "edited_node_id": "node-1",
+        }
+        await dispatch_message("presence-b", update, db_session)
+        editor = _frames(first, "presence_update")[-1]["data"]
+        assert editor["cursor"] == {"x": 12.5, "y": 40}
+        assert editor["selected_node_ids"] == ["node-1"]
+        assert editor["edited_node_id"] == "node-1" and editor["edited_at"]
+
+        await topic_manager.disconnect("presence-b")
+        left = _frames(first, "presence_left")[-1]
+        assert left["topic"] == topic
+        assert left["data"] == {"workflow_id": workflow.id, "client_id": "presence-b"}
+        assert [entry["client_id"] for entry in presence_registry.editors(workflow.id)] == ["presence-a"]
+    finally:
+        await topic_manager.disconnect("presence-a")
+        await topic_manager.disconnect("presence-b")
+
+
+@pytest.mark.asyncio
+async def test_presence_requires_access_to_the_workflow(db_session):
+    presence_registry.reset()
+    owner = crud.create_user(db_session, email="presence-owner2@local", provider=None, role="USER")
+    stranger = crud.create_user(db_session, email="presence-stranger@local", provider=None, role="USER")
+    workflow = crud.create_workflow(db_session, owner_id=owner.id, name="Private", canvas={"nodes": [], "edges": []})
+    ws = _socket()
+
+    await topic_manager.connect("presence-c", ws, user_id=stranger.id)
+    try:
+        topic = f"workflow:{workflow.id}:presence"
+        await dispatch_message("presence-c", {"type": "subscribe", "topics": [topic], "message_id": "m3"}, db_session)
+        [error] = _frames(ws, "subscribe_error")
+        assert error["data"]["error_code"] == "NOT_FOUND"
+
+        # Updates from a connection that never joined are rejected
+        await dispatch_message("presence-c", {"type": "update_presence", "workflow_id": workflow.id}, db_session)
+        assert _frames(ws, "error")
+        assert presence_registry.editors(workflow.id) == []
+    finally:
+        await topic_manager.disconnect("presence-c")
@@ -0,0 +1,113 @@
+"""Run inputs declared by the trigger: validation, the start endpoint and run history."""
+
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.routers import workflow_executions
+from zerg.schemas.workflow import Position
+from zerg.schemas.workflow import WorkflowData
+from zerg.schemas.workflow import WorkflowNode
+from zerg.services.workflow_inputs import declared_inputs
+from zerg.services.workflow_inputs import validate_run_inputs
+
+DECLARED = [
+    {"name": "email", "type": "string", "required": True},
+    {"name": "limit", "type": "integer", "default": 10},
+    {"name": "dry_run", "type": "boolean"},
+    {"name": "filters", "type": "json"},
+]
+
+
+def _workflow(db, test_user, inputs):
+    trigger = WorkflowNode(
+        id="trigger-1",
+        type="trigger",
+        position=Position(x=0, y=0),
+        config={"trigger": {"type": "manual", "config": {"enabled": True, "params": {"inputs": inputs}}}},
+    )
+    canvas = WorkflowData(nodes=[trigger], edges=[]).model_dump()
+    workflow = Workflow(owner_id=test_user.id, name="With inputs", canvas=canvas, is_active=True)
+    db.add(workflow)
+    db.commit()
+    return workflow
+
+
+def test_validate_coerces_and_fills_defaults():
+    inputs, errors = validate_run_inputs(
+        DECLARED, {"email": "a@example.com", "dry_run": "true", "filters": '{"label": "ops"}', "extra": 1}
+    )
+
+    assert errors == {}
+    assert inputs == {
+        "email": "a@example.com",
+        "limit": 10,
+        "dry_run": True,
+        "filters": {"label": "ops"},
+        "extra": 1,
+    }
+
+
+def test_validate_reports_each_bad_input():
+    _, errors = validate_run_inputs(DECLARED, {"limit": "2.5", "dry_run": "maybe", "filters": "{"})
+
+    assert errors == {
+        "email": "required",
+        "limit": "must be an integer",
+        "dry_run": "must be true or false",
+        "filters": "must be valid JSON",
+    }
+
+
+def test_workflows_without_declaration_accept_anything(db, test_user):
+    workflow = _workflow(db, test_user, [])
+
+    assert declared_inputs(workflow.canvas) is None
+    assert validate_run_inputs(None, {"anything": 1}) == ({"anything": 1}, {})
+
+
+def test_start_rejects_invalid_inputs(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user, DECLARED)
+
+    response = client.post(
+        f"/api/workflow-executions/by-workflow/{workflow.id}/start",
+        json={"inputs": {"limit": "lots"}},
+        headers=auth_headers,
+    )
+
+    assert response.status_code == 422
+    assert {issue["loc"][-1]: issue["msg"] for issue in response.json()["detail"]} == {
+        "email": "required",
+        "limit": "must be an integer",
+    }
+    assert db.query(WorkflowExecution).filter_by(workflow_id=workflow.id).count() == 0
+
+
+def test_start_keeps_inputs_on_execution(client, auth_headers, db, test_user, monkeypatch):
+    workflow = _workflow(db, test_user, DECLARED)
+    started = []
+    monkeypatch.setattr(
+        workflow_executions.workflow_engine,
+        "start_workflow_in_background",
+        lambda workflow_id, execution_id: started.append(execution_id),
+    )
+
+    response = client.post(
+        f"/api/workflow-executions/by-workflow/{workflow.id}/start",
+        json={"inputs": {"email": "a@example.com", "limit": "3"}},
+        headers=auth_headers,
+    )
+
+    assert response.status_code == 200
+    execution = db.query(WorkflowExecution).filter_by(id=response.json()["execution_id"]).first()
+    assert execution.inputs == {"email": "a@example.com", "limit": 3}
+
+
+def test_history_lists_newest_first_with_inputs(client, auth_headers, db, test_user):
+    workflow = _workflow(db, test_user, DECLARED)
+    for email in ("first@example.com", "second@example.com"):
+        db.add(WorkflowExecution(workflow_id=workflow.id, phase="finished", result="success", inputs={"email": email}))
+    db.commit()
+
+    response = client.get(f"/api/workflow-executions/history/{workflow.id}?limit=5", headers=auth_headers)
+
+    assert response.status_code == 200
+    assert [item["inputs"]["email"] for item in response.json()] == ["second@example.com", "first@example.com"]
@@ -149,9 +149,10 @@ def test_delete_workflow_success(client: TestClient, test_user: User, db: Sessio
     response = client.delete(f"/api/workflows/{wf.id}", headers=auth_headers)
     assert response.status_code == 204
 
-    # Verify the workflow is marked as inactive
+    # Verify the workflow is marked as inactive and in the trash
     db.refresh(wf)
     assert wf.is_active is False
+    assert wf.deleted_at is not None
 
 
 def test_delete_workflow_not_found(client: TestClient, test_user: User, auth_headers: dict):
@@ -193,6 +194,54 @@ def test_soft_delete_and_recreate(client: TestClient, test_user: User, db: Sessi
     assert new_wf_data["id"] != wf.id
 
 
+def test_trashed_workflow_restores_with_layout(client: TestClient, test_user: User, db: Session, auth_headers: dict):
+    """A deleted workflow is listed in the trash and comes back with its layout."""
+    wf = create_test_workflow(db, test_user.id)
+    layout = {"nodes": {"n1": {"x": 1.0, "y": 2.0}}, "viewport": {"x": 0.0, "y": 0.0, "zoom": 1.0}}
+    assert client.put(f"/api/workflows/{wf.id}/layout", headers=auth_headers, json=layout).status_code == 204
+    assert client.delete(f"/api/workflows/{wf.id}", headers=auth_headers).status_code == 204
+
+    trash = client.get("/api/workflows/trash", headers=auth_headers).json()
+    assert [item["id"] for item in trash] == [wf.id]
+    assert trash[0]["deleted_at"] is not None
+    assert wf.id not in [item["id"] for item in client.get("/api/workflows/", headers=auth_headers).json()]
+
+    response = client.post(f"/api/workflows/{wf.id}/restore", headers=auth_headers)
+    assert response.status_code == 200
+    assert response.json()["is_active"] is True
+    assert response.json()["deleted_at"] is None
+    assert client.get(f"/api/workflows/{wf.id}/layout", headers=auth_headers).json()["nodes"] == layout["nodes"]
+    assert client.get("/api/workflows/trash", headers=auth_headers).json() == []
+
+    # Restoring something that is not in the trash is a conflict
+    assert client.post(f"/api/workflows/{wf.id}/restore", headers=auth_headers).status_code == 409
+
+
+def test_restore_workflow_name_taken(client: TestClient, test_user: User, db: Session, auth_headers: dict):
+    """A trashed workflow cannot be restored over an active one with the same name."""
+    wf = create_test_workflow(db, test_user.id)
+    client.delete(f"/api/workflows/{wf.id}", headers=auth_headers)
+    create_test_workflow(db, test_user.id)
+
+    response = client.post(f"/api/workflows/{wf.id}/restore", headers=auth_headers)
+    assert response.status_code == 409
+
+
+def test_permanently_delete_workflow(client: TestClient, test_user: User, db: Session, auth_headers: dict):
+    """Permanent delete only applies to trashed workflows and removes their executions."""
+    wf = create_test_workflow(db, test_user.id)
+    execution = crud.create_workflow_execution(db, workflow_id=wf.id)
+    execution_id = execution.id
+
+    assert client.delete(f"/api/workflows/{wf.id}/permanent", headers=auth_headers).status_code == 409
+    client.delete(f"/api/workflows/{wf.id}", headers=auth_headers)
+    assert client.delete(f"/api/workflows/{wf.id}/permanent", headers=auth_headers).status_code == 204
+
+    db.expire_all()
+    assert crud.get_workflow(db, wf.id) is None
+    assert crud.get_workflow_execution(db, execution_id) is None
+
+
 def test_duplicate_workflow_name_fails(client: TestClient, test_user: User, db: Session, auth_headers: dict):
     """Test that creating a workflow with a duplicate name fails."""
     # Create an initial workflow
@@ -24,6 +24,12 @@ from zerg.config import get_settings
 # Worker context for tool event emission
 from zerg.context import get_worker_context
 
+# Per-agent generation parameters
+from zerg.services.model_params import llm_kwargs
+
+# Run inspector events (no-op outside an agent run)
+from zerg.services.run_events import emit_run_event
+
 # Centralised flags
 from zerg.tools.unified_access import get_tool_resolver
 
@@ -63,13 +69,9 @@ def _make_llm(agent_row, tools):
         "api_key": get_settings().openai_api_key,
     }
 
-    # Enforce a maximum completion length if configured (>0)
-    try:
-        max_toks = int(get_settings().max_output_tokens)
-    except Exception:  # noqa: BLE001 – defensive parsing
-        max_toks = 0
-    if max_toks and max_toks > 0:
-        kwargs["max_tokens"] = max_toks
+    # Per-agent temperature/top_p/stop overrides plus max_tokens, which is
+    # capped by MAX_OUTPUT_TOKENS when that is configured (>0)
+    kwargs.update(llm_kwargs(agent_row))
 
     # Be defensive against older stubs or versions that don't accept max_tokens
     try:
@@ -113,9 +115,15 @@ def get_runnable(agent_row):  # noqa: D401 – matches public API naming
     cfg = getattr(agent_row, "config", {}) or {}
     if "mcp_servers" in cfg:
         # Deferred import to avoid cost when MCP is unused
+        from zerg.database import db_session  # noqa: WPS433 (late import)
+        from zerg.services.api_credentials import expand_mcp_servers  # noqa: WPS433 (late import)
         from zerg.tools.mcp_adapter import load_mcp_tools_sync  # noqa: WPS433 (late import)
 
-        load_mcp_tools_sync(cfg["mcp_servers"])  # blocking – runs quickly (metadata only)
+        servers = cfg["mcp_servers"]
+        if any(server.get("api_credential_id") for server in servers):
+            with db_session() as db:
+                servers = expand_mcp_servers(db, agent_row.owner_id, servers)
+        load_mcp_tools_sync(servers)  # blocking – runs quickly (metadata only)
 
     # ------------------------------------------------------------------
     # Tool resolution using unified access
@@ -344,7 +352,8 @@ def get_runnable(agent_row):  # noqa: D401 – matches public API naming
 
         If running in a worker context (set by WorkerRunner), emits
         WORKER_TOOL_STARTED, WORKER_TOOL_COMPLETED, or WORKER_TOOL_FAILED
-        events for real-time monitoring.
+        events for real-time monitoring.  Inside an agent run the same
+        transitions are reported as run events for the run inspector.
         """
         import asyncio
         from datetime import datetime
@@ -364,6 +373,13 @@ def get_runnable(agent_row):  # noqa: D401 – matches public API naming
         # Redact sensitive fields from args for event emission
         safe_args = redact_sensitive_args(tool_args)
 
+        await emit_run_event(
+            "tool_started",
+            tool_name=tool_name,
+            tool_call_id=tool_call_id,
+            preview=safe_preview(str(safe_args)),
+        )
+
         # Emit STARTED event if in worker context
         if ctx:
             tool_record = ctx.record_tool_start(
@@ -398,6 +414,13 @@ def get_runnable(agent_row):  # noqa: D401 – matches public API naming
         # Check if tool execution failed
         result_content = str(result.content) if hasattr(result, "content") else str(result)
         is_error, error_msg = check_tool_error(result_content)
+        await emit_run_event(
+            "tool_failed" if is_error else "tool_finished",
+            tool_name=tool_name,
+            tool_call_id=tool_call_id,
+            duration_ms=duration_ms,
+            preview=safe_preview(error_msg or result_content, 500) if is_error else safe_preview(result_content),
+        )
 
         # Emit appropriate event if in worker context
         if ctx and tool_record:
@@ -491,6 +514,10 @@ def get_runnable(agent_row):  # noqa: D401 – matches public API naming
         import asyncio
 
         while isinstance(llm_response, AIMessage) and llm_response.tool_calls:
+            # Intermediate message: the model asked for tools instead of answering
+            requested = ", ".join(tc["name"] for tc in llm_response.tool_calls)
+            await emit_run_event("message", preview=safe_preview(llm_response.content or f"Calling {requested}"))
+
             # --------------------------------------------------------------
             # True *parallel* tool execution
             # --------------------------------------------------------------
@@ -0,0 +1,68 @@
+"""Capabilities – what a user may do, as one flat set.
+
+Routes still enforce permissions themselves (``require_admin`` and the
+owner checks in the routers); this module gives those rules a single name
+each so the frontend can load them once via ``/users/me/capabilities`` and
+hide or disable controls instead of sniffing 403s.
+"""
+
+from __future__ import annotations
+
+from enum import Enum
+
+from zerg.config import get_settings
+
+
+class Capability(str, Enum):
+    # Dashboard scope=all
+    VIEW_ALL_AGENTS = "view_all_agents"
+    # Edit, run, and delete agents owned by other users
+    MANAGE_ANY_AGENT = "manage_any_agent"
+    # /admin page and the /ops endpoints behind it
+    VIEW_OPS = "view_ops"
+    MANAGE_MAINTENANCE = "manage_maintenance"
+    # Workspace-wide preference defaults on the settings page
+    MANAGE_WORKSPACE_DEFAULTS = "manage_workspace_defaults"
+    RESET_DATABASE = "reset_database"
+    # Bypass ALLOWED_MODELS_NON_ADMIN
+    USE_ANY_MODEL = "use_any_model"
+
+
+ADMIN_CAPABILITIES = frozenset(
+    {
+        Capability.VIEW_ALL_AGENTS,
+        Capability.MANAGE_ANY_AGENT,
+        Capability.VIEW_OPS,
+        Capability.MANAGE_MAINTENANCE,
+        Capability.MANAGE_WORKSPACE_DEFAULTS,
+        Capability.USE_ANY_MODEL,
+    }
+)
+
+
+def is_admin(user) -> bool:
+    return getattr(user, "role", "USER") == "ADMIN"
+
+
+def is_super_admin(user) -> bool:
+    """Admins listed in ADMIN_EMAILS; any admin counts in test/dev with auth disabled."""
+
+    if not is_admin(user):
+        return False
+
+    settings = get_settings()
+    if settings.auth_disabled or settings.testing:
+        return True
+
+    admin_emails = {e.strip().lower() for e in (settings.admin_emails or "").split(",") if e.strip()}
+    return getattr(user, "email", "").lower() in admin_emails
+
+
+def capabilities_for(user) -> set[Capability]:
+    if not is_admin(user):
+        return set()
+
+    capabilities = set(ADMIN_CAPABILITIES)
+    if is_super_admin(user):
+        capabilities.add(Capability.RESET_DATABASE)
+    return capabilities
@@ -46,6 +46,13 @@ current_user_id_var: contextvars.ContextVar[Optional[int]] = contextvars.Context
     default=None,
 )
 
+# Other users following the thread (shared threads); tokens are fanned out to
+# their user topics as well.
+current_audience_ids_var: contextvars.ContextVar[tuple[int, ...]] = contextvars.ContextVar(  # noqa: E501
+    "current_audience_ids_var",
+    default=(),
+)
+
 # ---------------------------------------------------------------------------
 # Callback implementation
 # ---------------------------------------------------------------------------
@@ -118,7 +125,7 @@ class WsTokenCallback(AsyncCallbackHandler):
                 self._warned_no_context = True
             return
 
-        topic = f"user:{user_id}"
+        recipients = dict.fromkeys((user_id, *current_audience_ids_var.get()))
 
         try:
             chunk_data = StreamChunkData(
@@ -128,12 +135,14 @@ class WsTokenCallback(AsyncCallbackHandler):
                 tool_name=None,
                 tool_call_id=None,
             )
-            envelope = Envelope.create(
-                message_type="stream_chunk",
-                topic=topic,
-                data=chunk_data.model_dump(),
-            )
-            await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+            for recipient_id in recipients:
+                topic = f"user:{recipient_id}"
+                envelope = Envelope.create(
+                    message_type="stream_chunk",
+                    topic=topic,
+                    data=chunk_data.model_dump(),
+                )
+                await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
         except Exception:  # noqa: BLE001 – we log then swallow; token streaming is best-effort
             logger.exception("Error broadcasting token chunk for user %s, thread %s", user_id, thread_id)
 
@@ -178,11 +187,19 @@ def set_current_user_id(user_id: int | None):  # noqa: D401 – tiny setter help
     return current_user_id_var.set(int(user_id))
 
 
+def set_current_audience_ids(user_ids: tuple[int, ...] | list[int] | None):  # noqa: D401 – tiny setter helper
+    """Set the additional users that should receive streamed tokens."""
+
+    return current_audience_ids_var.set(tuple(int(uid) for uid in (user_ids or ())))
+
+
 __all__ = [
     "WsTokenCallback",
     "current_thread_id_var",
     "current_user_id_var",
+    "current_audience_ids_var",
     "set_current_thread_id",
     "reset_current_thread_id",
     "set_current_user_id",
+    "set_current_audience_ids",
 ]
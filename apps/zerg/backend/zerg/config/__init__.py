@@ -53,6 +53,9 @@ class Settings:  # noqa: D401 – simple data container
     google_client_secret: Any
     github_client_id: Any
     github_client_secret: Any
+    slack_client_id: Any
+    slack_client_secret: Any
+    slack_signing_secret: Any
     trigger_signing_secret: Any
 
     # Database ---------------------------------------------------------
@@ -186,6 +189,9 @@ def _load_settings() -> Settings:  # noqa: D401 – helper
         google_client_secret=os.getenv("GOOGLE_CLIENT_SECRET"),
         github_client_id=os.getenv("GITHUB_CLIENT_ID"),
         github_client_secret=os.getenv("GITHUB_CLIENT_SECRET"),
+        slack_client_id=os.getenv("SLACK_CLIENT_ID"),
+        slack_client_secret=os.getenv("SLACK_CLIENT_SECRET"),
+        slack_signing_secret=os.getenv("SLACK_SIGNING_SECRET"),
         trigger_signing_secret=os.getenv("TRIGGER_SIGNING_SECRET"),
         database_url=os.getenv("DATABASE_URL", ""),
         fernet_secret=os.getenv("FERNET_SECRET"),
@@ -33,6 +33,7 @@ CONNECTOR_TOOL_MAPPING = {
         "github_list_issues",
         "github_get_issue",
         "github_add_comment",
+        "github_add_labels",
         "github_list_pull_requests",
         "github_get_pull_request",
     ],
@@ -2,6 +2,7 @@
 # Keep stdlib ``datetime`` for type annotations; runtime *now()* comes from
 # ``utc_now``.
 from datetime import datetime
+from datetime import timedelta
 from datetime import timezone as dt_timezone
 
 # Standard library typing helpers
@@ -11,22 +12,34 @@ from typing import List
 from typing import Optional
 
 from fastapi import HTTPException
+from sqlalchemy import String
+from sqlalchemy import cast
+from sqlalchemy import func
 from sqlalchemy.orm import Session
 from sqlalchemy.orm import selectinload
 
 # Cron validation helper
 from apscheduler.triggers.cron import CronTrigger
+from zerg.middleware.request_id import get_trace_id
+from zerg.middleware.request_id import new_trace_id
 from zerg.models.enums import RunStatus
+from zerg.models.enums import SharePermission
 from zerg.models.models import Agent
+from zerg.models.models import AgentInstructionVersion
+from zerg.models.models import AgentKnowledgeDocument
 from zerg.models.models import AgentMessage
 from zerg.models.models import AgentRun
+from zerg.models.models import AgentShare
+from zerg.models.models import AgentTestCase
 
 # Canvas layout model (Phase-B)
 from zerg.models.models import CanvasLayout
 from zerg.models.models import Connector
+from zerg.models.models import SystemSetting
 from zerg.models.models import Thread
 from zerg.models.models import ThreadMessage
 from zerg.models.models import Trigger
+from zerg.models.models import TriggerDelivery
 from zerg.models.models import WorkerJob
 
 # Added for authentication
@@ -37,7 +50,12 @@ from zerg.models.models import WorkerJob
 # Python 3.13.  Using the classic ``Optional[User]`` sidesteps the issue
 # without requiring ``from __future__ import annotations``.
 from zerg.models.models import User
+from zerg.prompts.variables import validate_prompt_variables
 from zerg.schemas.schemas import RunTrigger
+from zerg.services.model_params import with_validated_model_params
+from zerg.services.schema_validation import check_run_output
+from zerg.services.schema_validation import with_validated_output_schema
+from zerg.services.tags import normalize_tags
 from zerg.utils.time import utc_now_naive
 
 
@@ -68,11 +86,13 @@ def get_agents(
     skip: int = 0,
     limit: int = 100,
     owner_id: Optional[int] = None,
+    include_deleted: bool = False,
 ):
     """Return a list of agents.
 
     If *owner_id* is provided the result is limited to agents owned by that
-    user.  Otherwise all agents are returned (paginated).
+    user.  Otherwise all agents are returned (paginated).  Agents in the trash
+    are skipped unless *include_deleted* is set.
     """
 
     # Eager-load relationships that the Pydantic ``Agent`` response model
@@ -89,6 +109,8 @@ def get_agents(
     )
     if owner_id is not None:
         query = query.filter(Agent.owner_id == owner_id)
+    if not include_deleted:
+        query = query.filter(Agent.deleted_at.is_(None))
 
     return query.offset(skip).limit(limit).all()
 
@@ -116,6 +138,9 @@ def create_agent(
     model: str,
     schedule: Optional[str] = None,
     config: Optional[Dict[str, Any]] = None,
+    prompt_variables: Optional[Dict[str, str]] = None,
+    description: Optional[str] = None,
+    tags: Optional[List[str]] = None,
 ):
     """Create a new agent.
 
@@ -125,6 +150,8 @@ def create_agent(
 
     # Validate cron expression if provided
     _validate_cron_or_raise(schedule)
+    prompt_variables = validate_prompt_variables(prompt_variables)
+    config = with_validated_output_schema(with_validated_model_params(config))
 
     # Create agent
     db_agent = Agent(
@@ -136,10 +163,15 @@ def create_agent(
         status="idle",
         schedule=schedule,
         config=config,
+        prompt_variables=prompt_variables or None,
+        description=(description or "").strip() or None,
+        tags=normalize_tags(tags or []) or None,
         next_run_at=None,
         last_run_at=None,
     )
     db.add(db_agent)
+    db.flush()
+    _add_instruction_version(db, db_agent, author_id=owner_id)
     db.commit()
     db.refresh(db_agent)
 
@@ -160,15 +192,25 @@ def update_agent(
     status: Optional[str] = None,
     schedule: Optional[str] = None,
     config: Optional[Dict[str, Any]] = None,
+    prompt_variables: Optional[Dict[str, str]] = None,
+    description: Optional[str] = None,
+    tags: Optional[List[str]] = None,
     allowed_tools: Optional[list] = None,
     next_run_at: Optional[datetime] = None,
     last_run_at: Optional[datetime] = None,
     last_error: Optional[str] = None,
+    author_id: Optional[int] = None,
+    restored_from_version: Optional[int] = None,
 ):
-    """Update an existing agent"""
+    """Update an existing agent.
+
+    A change to the system or task instructions records a new
+    :class:`AgentInstructionVersion` attributed to *author_id*.
+    """
     db_agent = db.query(Agent).filter(Agent.id == agent_id).first()
     if db_agent is None:
         return None
+    previous_instructions = (db_agent.system_instructions, db_agent.task_instructions)
 
     # Update provided fields
     if name is not None:
@@ -181,11 +223,23 @@ def update_agent(
         db_agent.model = model
     if status is not None:
         db_agent.status = status
-    if schedule is not None:
+    if schedule == "":
+        # Empty string clears the schedule (``None`` means "leave unchanged")
+        db_agent.schedule = None
+        db_agent.next_run_at = None
+    elif schedule is not None:
         _validate_cron_or_raise(schedule)
         db_agent.schedule = schedule
     if config is not None:
-        db_agent.config = config
+        db_agent.config = with_validated_output_schema(with_validated_model_params(config))
+    if prompt_variables is not None:
+        db_agent.prompt_variables = validate_prompt_variables(prompt_variables)
+    if description is not None:
+        # Empty string clears the description
+        db_agent.description = description.strip() or None
+    if tags is not None:
+        # Empty list clears the tags
+        db_agent.tags = normalize_tags(tags) or None
     if allowed_tools is not None:
         db_agent.allowed_tools = allowed_tools
     if next_run_at is not None:
@@ -195,12 +249,72 @@ def update_agent(
     if last_error is not None:
         db_agent.last_error = last_error
 
+    if (db_agent.system_instructions, db_agent.task_instructions) != previous_instructions:
+        if _latest_instruction_version(db, db_agent.id) == 0:
+            # Agents created before history existed: keep what is being replaced
+            _add_instruction_version(db, db_agent, instructions=previous_instructions)
+            db.flush()
+        _add_instruction_version(db, db_agent, author_id=author_id, restored_from_version=restored_from_version)
+
     db_agent.updated_at = utc_now_naive()
     db.commit()
     db.refresh(db_agent)
     return db_agent
 
 
+def _latest_instruction_version(db: Session, agent_id: int) -> int:
+    latest = (
+        db.query(func.max(AgentInstructionVersion.version))
+        .filter(AgentInstructionVersion.agent_id == agent_id)
+        .scalar()
+    )
+    return latest or 0
+
+
+def _add_instruction_version(
+    db: Session,
+    agent: Agent,
+    *,
+    instructions: Optional[tuple] = None,
+    author_id: Optional[int] = None,
+    restored_from_version: Optional[int] = None,
+) -> AgentInstructionVersion:
+    """Snapshot *agent*'s instructions (or the given ``(system, task)`` pair) as its next version.
+
+    The caller commits.
+    """
+    system_instructions, task_instructions = instructions or (agent.system_instructions, agent.task_instructions)
+    version = AgentInstructionVersion(
+        agent_id=agent.id,
+        version=_latest_instruction_version(db, agent.id) + 1,
+        system_instructions=system_instructions,
+        task_instructions=task_instructions,
+        author_id=author_id,
+        restored_from_version=restored_from_version,
+    )
+    db.add(version)
+    return version
+
+
+def get_instruction_versions(db: Session, agent_id: int) -> List[AgentInstructionVersion]:
+    """Instruction history for *agent_id*, newest first."""
+    return (
+        db.query(AgentInstructionVersion)
+        .options(selectinload(AgentInstructionVersion.author))
+        .filter(AgentInstructionVersion.agent_id == agent_id)
+        .order_by(AgentInstructionVersion.version.desc())
+        .all()
+    )
+
+
+def get_instruction_version(db: Session, agent_id: int, version_id: int) -> Optional[AgentInstructionVersion]:
+    return (
+        db.query(AgentInstructionVersion)
+        .filter(AgentInstructionVersion.id == version_id, AgentInstructionVersion.agent_id == agent_id)
+        .first()
+    )
+
+
 def delete_agent(db: Session, agent_id: int):
     """Delete an agent and all dependent rows.
 
@@ -217,6 +331,9 @@ def delete_agent(db: Session, agent_id: int):
         return False
 
     # Triggers are linked via backref and do not cascade by default.
+    trigger_ids = [row[0] for row in db.query(Trigger.id).filter(Trigger.agent_id == agent_id).all()]
+    if trigger_ids:
+        db.query(TriggerDelivery).filter(TriggerDelivery.trigger_id.in_(trigger_ids)).delete(synchronize_session=False)
     db.query(Trigger).filter(Trigger.agent_id == agent_id).delete(synchronize_session=False)
 
     # Runs must be deleted before threads (AgentRun.thread_id FK).
@@ -237,6 +354,14 @@ def delete_agent(db: Session, agent_id: int):
 
     # Legacy agent_messages table.
     db.query(AgentMessage).filter(AgentMessage.agent_id == agent_id).delete(synchronize_session=False)
+    db.query(AgentShare).filter(AgentShare.agent_id == agent_id).delete(synchronize_session=False)
+    db.query(AgentInstructionVersion).filter(AgentInstructionVersion.agent_id == agent_id).delete(
+        synchronize_session=False
+    )
+    db.query(AgentTestCase).filter(AgentTestCase.agent_id == agent_id).delete(synchronize_session=False)
+    db.query(AgentKnowledgeDocument).filter(AgentKnowledgeDocument.agent_id == agent_id).delete(
+        synchronize_session=False
+    )
 
     # Finally delete the agent itself.
     db.query(Agent).filter(Agent.id == agent_id).delete(synchronize_session=False)
@@ -244,6 +369,354 @@ def delete_agent(db: Session, agent_id: int):
     return True
 
 
+# Days a trashed agent stays restorable before it is deleted for good
+AGENT_TRASH_RETENTION_DAYS = 30
+
+
+def trash_agent(db: Session, agent_id: int):
+    """Move an agent to the trash (soft delete). Returns the row or ``None``."""
+    agent = db.query(Agent).filter(Agent.id == agent_id, Agent.deleted_at.is_(None)).first()
+    if agent is None:
+        return None
+    agent.deleted_at = datetime.now(dt_timezone.utc).replace(tzinfo=None)
+    db.commit()
+    db.refresh(agent)
+    return agent
+
+
+def restore_agent(db: Session, agent_id: int):
+    """Take an agent back out of the trash. Returns the row or ``None``."""
+    agent = db.query(Agent).filter(Agent.id == agent_id, Agent.deleted_at.isnot(None)).first()
+    if agent is None:
+        return None
+    agent.deleted_at = None
+    db.commit()
+    db.refresh(agent)
+    return agent
+
+
+# ``disabled_reason`` for triggers switched off along with their agent
+AGENT_DELETED = "agent_deleted"
+
+
+def workflows_using_agent(db: Session, agent) -> List[Any]:
+    """The owner's workflows with an agent node pointing at *agent*."""
+    from zerg.models.models import Workflow
+
+    def _uses(workflow) -> bool:
+        return any(
+            isinstance(node, dict)
+            and node.get("type") == "agent"
+            and str((node.get("config") or {}).get("agent_id")) == str(agent.id)
+            for node in (workflow.canvas or {}).get("nodes", [])
+        )
+
+    workflows = (
+        db.query(Workflow)
+        .filter(Workflow.owner_id == agent.owner_id, Workflow.is_active.is_(True))
+        .order_by(Workflow.id)
+        .all()
+    )
+    return [workflow for workflow in workflows if _uses(workflow)]
+
+
+def set_agent_triggers_disabled(db: Session, agent_id: int, disabled: bool) -> List[int]:
+    """Disable the agent's active triggers, or re-enable the ones deleting it disabled.
+
+    Triggers disabled for another reason (e.g. a Gmail disconnect) are left
+    alone either way. Returns the ids of the triggers that changed.
+    """
+    changed: List[int] = []
+    for trg in get_triggers(db, agent_id=agent_id):
+        cfg = dict(trg.config or {})
+        if disabled and not cfg.get("disabled"):
+            cfg.update(disabled=True, disabled_reason=AGENT_DELETED)
+        elif not disabled and cfg.get("disabled_reason") == AGENT_DELETED:
+            cfg.pop("disabled", None)
+            cfg.pop("disabled_reason", None)
+        else:
+            continue
+        # Assign a fresh dict so SQLAlchemy notices the JSON change
+        trg.config = cfg  # type: ignore[assignment]
+        changed.append(trg.id)
+    if changed:
+        db.commit()
+    return changed
+
+
+def set_agent_paused(db: Session, agent_id: int, paused: bool):
+    """Pause or resume an agent's scheduled and triggered runs. Returns the row or ``None``."""
+    agent = db.query(Agent).filter(Agent.id == agent_id, Agent.deleted_at.is_(None)).first()
+    if agent is None:
+        return None
+    if paused and agent.paused_at is None:
+        agent.paused_at = datetime.now(dt_timezone.utc).replace(tzinfo=None)
+    elif not paused:
+        agent.paused_at = None
+    db.commit()
+    db.refresh(agent)
+    return agent
+
+
+MAINTENANCE_MODE_KEY = "maintenance_mode"
+
+
+def get_system_setting(db: Session, key: str, default: Any = None) -> Any:
+    row = db.query(SystemSetting).filter(SystemSetting.key == key).first()
+    return row.value if row is not None else default
+
+
+def set_system_setting(db: Session, key: str, value: Any, *, updated_by: Optional[int] = None) -> SystemSetting:
+    row = db.query(SystemSetting).filter(SystemSetting.key == key).first()
+    if row is None:
+        row = SystemSetting(key=key)
+        db.add(row)
+    row.value = value
+    row.updated_by = updated_by
+    db.commit()
+    db.refresh(row)
+    return row
+
+
+def is_maintenance_mode(db: Session) -> bool:
+    return bool(get_system_setting(db, MAINTENANCE_MODE_KEY, False))
+
+
+# Admin-set prefs every user starts from (see services/preferences.py)
+WORKSPACE_DEFAULTS_KEY = "workspace_defaults"
+
+
+def get_workspace_defaults(db: Session) -> Dict[str, Any]:
+    return get_system_setting(db, WORKSPACE_DEFAULTS_KEY, None) or {}
+
+
+def automatic_run_block_reason(db: Session, agent) -> Optional[str]:
+    """Why a scheduled/triggered run of *agent* must be skipped, or ``None`` if it may run."""
+    if is_maintenance_mode(db):
+        return "maintenance mode is on"
+    if agent.paused_at is not None:
+        return "agent is paused"
+    return None
+
+
+def get_trashed_agents(db: Session, *, owner_id: Optional[int] = None):
+    """Return trashed agents, most recently deleted first."""
+    query = db.query(Agent).options(selectinload(Agent.owner)).filter(Agent.deleted_at.isnot(None))
+    if owner_id is not None:
+        query = query.filter(Agent.owner_id == owner_id)
+    return query.order_by(Agent.deleted_at.desc()).all()
+
+
+def purge_expired_trashed_agents(db: Session, *, owner_id: Optional[int] = None) -> int:
+    """Permanently delete agents that have been in the trash past the retention window."""
+    cutoff = datetime.now(dt_timezone.utc).replace(tzinfo=None) - timedelta(days=AGENT_TRASH_RETENTION_DAYS)
+    query = db.query(Agent.id).filter(Agent.deleted_at.isnot(None), Agent.deleted_at < cutoff)
+    if owner_id is not None:
+        query = query.filter(Agent.owner_id == owner_id)
+    expired_ids = [row[0] for row in query.all()]
+    for agent_id in expired_ids:
+        delete_agent(db, agent_id)
+    return len(expired_ids)
+
+
+# ------------------------------------------------------------
+# Agent sharing
+# ------------------------------------------------------------
+
+# Each level includes the ones before it
+SHARE_PERMISSION_ORDER = (SharePermission.VIEW, SharePermission.RUN, SharePermission.EDIT)
+
+
+def permission_allows(granted: str, required: str) -> bool:
+    order = [level.value for level in SHARE_PERMISSION_ORDER]
+    return order.index(granted) >= order.index(required)
+
+
+def get_agent_test_cases(db: Session, agent_id: int) -> List[AgentTestCase]:
+    return db.query(AgentTestCase).filter(AgentTestCase.agent_id == agent_id).order_by(AgentTestCase.id).all()
+
+
+def get_agent_test_case(db: Session, agent_id: int, case_id: int) -> Optional[AgentTestCase]:
+    return db.query(AgentTestCase).filter(AgentTestCase.id == case_id, AgentTestCase.agent_id == agent_id).first()
+
+
+def create_agent_test_case(
+    db: Session, agent_id: int, *, name: str, input: str, assertions: List[Dict[str, Any]]
+) -> AgentTestCase:
+    case = AgentTestCase(agent_id=agent_id, name=name, input=input, assertions=assertions)
+    db.add(case)
+    db.commit()
+    db.refresh(case)
+    return case
+
+
+def update_agent_test_case(
+    db: Session,
+    case: AgentTestCase,
+    *,
+    name: Optional[str] = None,
+    input: Optional[str] = None,
+    assertions: Optional[List[Dict[str, Any]]] = None,
+) -> AgentTestCase:
+    """Apply the given fields; editing the case clears its last result."""
+    if name is not None:
+        case.name = name
+    if input is not None:
+        case.input = input
+    if assertions is not None:
+        case.assertions = assertions
+    case.last_result = None
+    case.last_run_at = None
+    db.commit()
+    db.refresh(case)
+    return case
+
+
+def set_agent_test_result(db: Session, case: AgentTestCase, result: Dict[str, Any]) -> AgentTestCase:
+    case.last_result = result
+    case.last_run_at = utc_now_naive()
+    db.commit()
+    db.refresh(case)
+    return case
+
+
+def delete_agent_test_case(db: Session, case: AgentTestCase) -> None:
+    db.delete(case)
+    db.commit()
+
+
+def get_knowledge_documents(db: Session, agent_id: int) -> List[AgentKnowledgeDocument]:
+    return (
+        db.query(AgentKnowledgeDocument)
+        .filter(AgentKnowledgeDocument.agent_id == agent_id)
+        .order_by(AgentKnowledgeDocument.id)
+        .all()
+    )
+
+
+def get_knowledge_document(db: Session, agent_id: int, document_id: int) -> Optional[AgentKnowledgeDocument]:
+    return (
+        db.query(AgentKnowledgeDocument)
+        .filter(AgentKnowledgeDocument.id == document_id, AgentKnowledgeDocument.agent_id == agent_id)
+        .first()
+    )
+
+
+def create_knowledge_document(
+    db: Session,
+    agent_id: int,
+    *,
+    name: str,
+    url: Optional[str] = None,
+    content_type: Optional[str] = None,
+    size_bytes: Optional[int] = None,
+) -> AgentKnowledgeDocument:
+    """Add a document in the ``indexing`` state; the indexing task fills in its chunks."""
+    document = AgentKnowledgeDocument(
+        agent_id=agent_id,
+        name=name,
+        url=url,
+        content_type=content_type,
+        size_bytes=size_bytes,
+        status="indexing",
+        chunk_count=0,
+    )
+    db.add(document)
+    db.commit()
+    db.refresh(document)
+    return document
+
+
+def delete_knowledge_document(db: Session, document: AgentKnowledgeDocument) -> None:
+    db.delete(document)
+    db.commit()
+
+
+def get_agent_shares(db: Session, agent_id: int) -> List[AgentShare]:
+    """Grants on *agent_id*, the workspace-wide one first."""
+    return (
+        db.query(AgentShare)
+        .options(selectinload(AgentShare.user))
+        .filter(AgentShare.agent_id == agent_id)
+        .order_by(AgentShare.user_id.isnot(None), AgentShare.id)
+        .all()
+    )
+
+
+def upsert_agent_share(db: Session, agent_id: int, user_id: Optional[int], permission: str) -> AgentShare:
+    """Grant *permission* to *user_id* (``None`` = whole workspace), replacing any earlier grant."""
+    query = db.query(AgentShare).filter(AgentShare.agent_id == agent_id)
+    query = query.filter(AgentShare.user_id.is_(None) if user_id is None else AgentShare.user_id == user_id)
+    share = query.first()
+    if share is None:
+        share = AgentShare(agent_id=agent_id, user_id=user_id, permission=permission)
+        db.add(share)
+    else:
+        share.permission = permission
+    db.commit()
+    db.refresh(share)
+    return share
+
+
+def delete_agent_share(db: Session, agent_id: int, share_id: int) -> bool:
+    deleted = (
+        db.query(AgentShare)
+        .filter(AgentShare.id == share_id, AgentShare.agent_id == agent_id)
+        .delete(synchronize_session=False)
+    )
+    db.commit()
+    return bool(deleted)
+
+
+def get_shared_agent_permissions(db: Session, user_id: int) -> Dict[int, str]:
+    """Map of agent id -> permission for live agents shared with *user_id* that they don't own.
+
+    A personal grant overrides the workspace-wide one, even when it is lower.
+    """
+    rows = (
+        db.query(AgentShare.agent_id, AgentShare.user_id, AgentShare.permission)
+        .join(Agent, Agent.id == AgentShare.agent_id)
+        .filter(Agent.owner_id != user_id, Agent.deleted_at.is_(None))
+        .filter((AgentShare.user_id == user_id) | AgentShare.user_id.is_(None))
+        .all()
+    )
+    permissions: Dict[int, str] = {}
+    # Workspace grants first so personal ones overwrite them
+    for agent_id, _share_user_id, permission in sorted(rows, key=lambda row: row[1] is not None):
+        permissions[agent_id] = permission
+    return permissions
+
+
+def get_agent_permission(db: Session, agent: Agent, user_id: int) -> Optional[str]:
+    """*user_id*'s shared permission on *agent*, or ``None`` when it isn't shared with them."""
+    if agent.owner_id == user_id:
+        return None
+    rows = (
+        db.query(AgentShare.user_id, AgentShare.permission)
+        .filter(AgentShare.agent_id == agent.id)
+        .filter((AgentShare.user_id == user_id) | AgentShare.user_id.is_(None))
+        .all()
+    )
+    personal = next((permission for share_user_id, permission in rows if share_user_id is not None), None)
+    return personal or next((permission for _share_user_id, permission in rows), None)
+
+
+def get_shared_agents(db: Session, user_id: int, *, skip: int = 0, limit: int = 100):
+    """Live agents other users shared with *user_id*, directly or with the workspace."""
+    agent_ids = list(get_shared_agent_permissions(db, user_id))
+    if not agent_ids:
+        return []
+    return (
+        db.query(Agent)
+        .options(selectinload(Agent.owner), selectinload(Agent.messages))
+        .filter(Agent.id.in_(agent_ids))
+        .order_by(Agent.id)
+        .offset(skip)
+        .limit(limit)
+        .all()
+    )
+
+
 # ------------------------------------------------------------
 # User CRUD operations (Stage 1 – Auth MVP)
 # ------------------------------------------------------------
@@ -404,11 +877,112 @@ def delete_trigger(db: Session, trigger_id: int):
     trg = get_trigger(db, trigger_id)
     if trg is None:
         return False
+    db.query(TriggerDelivery).filter(TriggerDelivery.trigger_id == trigger_id).delete(synchronize_session=False)
     db.delete(trg)
     db.commit()
     return True
 
 
+def update_trigger_config(db: Session, trigger_id: int, config: Optional[Dict[str, Any]]) -> Optional[Trigger]:
+    trg = get_trigger(db, trigger_id)
+    if trg is None:
+        return None
+    # Assign a fresh dict so SQLAlchemy notices the JSON change
+    trg.config = dict(config) if config is not None else None  # type: ignore[assignment]
+    db.commit()
+    db.refresh(trg)
+    return trg
+
+
+def rotate_trigger_secret(db: Session, trigger_id: int) -> Optional[Trigger]:
+    """Replace the trigger's signing secret; the old one stops working at once."""
+
+    from uuid import uuid4
+
+    trg = get_trigger(db, trigger_id)
+    if trg is None:
+        return None
+    trg.secret = uuid4().hex
+    db.commit()
+    db.refresh(trg)
+    return trg
+
+
+# Deliveries older than the newest N per trigger are pruned on write
+TRIGGER_DELIVERY_HISTORY_LIMIT = 50
+
+
+def record_trigger_delivery(
+    db: Session,
+    *,
+    trigger_id: int,
+    status_code: int,
+    error: Optional[str] = None,
+    is_test: bool = False,
+    payload_bytes: Optional[int] = None,
+    matched: Optional[str] = None,
+    run_id: Optional[int] = None,
+    external_id: Optional[str] = None,
+) -> TriggerDelivery:
+    delivery = TriggerDelivery(
+        trigger_id=trigger_id,
+        status_code=status_code,
+        error=error[:255] if error else None,
+        is_test=is_test,
+        payload_bytes=payload_bytes,
+        matched=matched[:255] if matched else None,
+        run_id=run_id,
+        external_id=external_id[:64] if external_id else None,
+    )
+    db.add(delivery)
+    db.flush()
+
+    stale_ids = [
+        row[0]
+        for row in db.query(TriggerDelivery.id)
+        .filter(TriggerDelivery.trigger_id == trigger_id)
+        .order_by(TriggerDelivery.id.desc())
+        .offset(TRIGGER_DELIVERY_HISTORY_LIMIT)
+        .all()
+    ]
+    if stale_ids:
+        db.query(TriggerDelivery).filter(TriggerDelivery.id.in_(stale_ids)).delete(synchronize_session=False)
+
+    db.commit()
+    db.refresh(delivery)
+    return delivery
+
+
+def newest_run_id(db: Session, agent_id: int) -> Optional[int]:
+    """Id of the agent's most recent run; compare before/after firing to find the run it started."""
+    row = db.query(AgentRun.id).filter(AgentRun.agent_id == agent_id).order_by(AgentRun.id.desc()).first()
+    return row[0] if row else None
+
+
+def has_accepted_delivery(db: Session, trigger_id: int, external_id: str) -> bool:
+    """Whether the sender's delivery *external_id* was already handled; rejected attempts may be retried."""
+    return (
+        db.query(TriggerDelivery.id)
+        .filter(
+            TriggerDelivery.trigger_id == trigger_id,
+            TriggerDelivery.external_id == external_id[:64],
+            TriggerDelivery.status_code < 300,
+        )
+        .first()
+        is not None
+    )
+
+
+def list_trigger_deliveries(db: Session, trigger_id: int, limit: int = 20) -> List[TriggerDelivery]:
+    return (
+        db.query(TriggerDelivery)
+        .filter(TriggerDelivery.trigger_id == trigger_id)
+        .order_by(TriggerDelivery.id.desc())
+        .limit(limit)
+        .all()
+    )
+
+
 def get_triggers(db: Session, agent_id: Optional[int] = None) -> List[Trigger]:
     """
     Retrieve triggers, optionally filtered by agent_id.
@@ -569,6 +1143,9 @@ def update_thread(
     active: Optional[bool] = None,
     agent_state: Optional[Dict[str, Any]] = None,
     memory_strategy: Optional[str] = None,
+    pinned: Optional[bool] = None,
+    archived: Optional[bool] = None,
+    model_override: Optional[str] = None,
 ):
     """Update a thread"""
     db_thread = db.query(Thread).filter(Thread.id == thread_id).first()
@@ -589,6 +1166,16 @@ def update_thread(
         db_thread.agent_state = agent_state
     if memory_strategy is not None:
         db_thread.memory_strategy = memory_strategy
+    if pinned is not None:
+        db_thread.pinned = pinned
+    if archived is not None:
+        db_thread.archived = archived
+        # An archived thread shouldn't stay pinned to the top of the sidebar
+        if archived:
+            db_thread.pinned = False
+    if model_override is not None:
+        # Empty string clears the override (``None`` means "leave unchanged")
+        db_thread.model_override = model_override or None
 
     db_thread.updated_at = utc_now_naive()
     db.commit()
@@ -596,6 +1183,41 @@ def update_thread(
     return db_thread
 
 
+# A reply claim older than this belongs to a run whose process died; it no longer blocks the thread
+THREAD_REPLY_CLAIM_TTL = timedelta(minutes=15)
+
+
+def claim_thread_reply(db: Session, thread_id: int) -> bool:
+    """Mark an agent reply as in flight on *thread_id*; ``False`` when one already is.
+
+    A single conditional UPDATE, so concurrent requests – in any process – can't both win.
+    """
+    now = utc_now_naive()
+    claimed = (
+        db.query(Thread)
+        .filter(Thread.id == thread_id)
+        .filter(Thread.reply_started_at.is_(None) | (Thread.reply_started_at < now - THREAD_REPLY_CLAIM_TTL))
+        # Setting updated_at to itself keeps the claim from reordering the sidebar
+        .update({Thread.reply_started_at: now, Thread.updated_at: Thread.updated_at}, synchronize_session=False)
+    )
+    db.commit()
+    return claimed == 1
+
+
+def release_thread_reply(db: Session, thread_id: int) -> None:
+    db.query(Thread).filter(Thread.id == thread_id).update(
+        {Thread.reply_started_at: None, Thread.updated_at: Thread.updated_at}, synchronize_session=False
+    )
+    db.commit()
+
+
+def thread_reply_pending(db: Session, thread_id: int) -> bool:
+    cutoff = utc_now_naive() - THREAD_REPLY_CLAIM_TTL
+    return (
+        db.query(Thread.id).filter(Thread.id == thread_id, Thread.reply_started_at >= cutoff).first() is not None
+    )
+
+
 def delete_thread(db: Session, thread_id: int):
     """Delete a thread and all its messages"""
     db_thread = db.query(Thread).filter(Thread.id == thread_id).first()
@@ -606,6 +1228,25 @@ def delete_thread(db: Session, thread_id: int):
     return True
 
 
+def get_tool_usage(db: Session, agent_id: int) -> List[Dict[str, Any]]:
+    """Per-tool call counts and last use across all of an agent's threads.
+
+    Counts tool *result* messages, so a call that crashed before producing a
+    result is not included.
+    """
+
+    from sqlalchemy.sql import func
+
+    rows = (
+        db.query(ThreadMessage.name, func.count(ThreadMessage.id), func.max(ThreadMessage.sent_at))
+        .join(Thread, Thread.id == ThreadMessage.thread_id)
+        .filter(Thread.agent_id == agent_id, ThreadMessage.role == "tool", ThreadMessage.name.isnot(None))
+        .group_by(ThreadMessage.name)
+        .all()
+    )
+    return [{"name": name, "calls": calls, "last_used_at": last_used_at} for name, calls, last_used_at in rows]
+
+
 # Thread Message CRUD operations
 def get_thread_messages(db: Session, thread_id: int, skip: int = 0, limit: int = 100):
     """
@@ -633,6 +1274,21 @@ def get_thread_messages(db: Session, thread_id: int, skip: int = 0, limit: int =
     )
 
 
+def get_thread_messages_before(db: Session, thread_id: int, before_id: Optional[int] = None, limit: int = 100):
+    """Return the newest *limit* messages of a thread older than *before_id*.
+
+    Used for paging backwards through long histories: the first page passes
+    no *before_id* (the latest messages), each following page passes the id of
+    the oldest message the client already has.  Rows come back in ascending
+    id order, the same ordering guarantee as :func:`get_thread_messages`.
+    """
+    query = db.query(ThreadMessage).filter(ThreadMessage.thread_id == thread_id)
+    if before_id is not None:
+        query = query.filter(ThreadMessage.id < before_id)
+    rows = query.order_by(ThreadMessage.id.desc()).limit(limit).all()
+    return list(reversed(rows))
+
+
 def create_thread_message(
     db: Session,
     thread_id: int,
@@ -644,6 +1300,8 @@ def create_thread_message(
     processed: bool = False,
     parent_id: Optional[int] = None,
     sent_at: Optional[datetime] = None,
+    message_metadata: Optional[Dict[str, Any]] = None,
+    author_id: Optional[int] = None,
     *,
     commit: bool = True,
 ):
@@ -653,6 +1311,7 @@ def create_thread_message(
     Args:
         sent_at: Optional client-provided send timestamp. If provided, must be within ±5 minutes
                  of server time, otherwise uses server time. Timezone-aware datetime in UTC.
+        author_id: User who wrote the message (user messages only).
     """
     # Validate and normalize sent_at
     if sent_at is not None:
@@ -680,6 +1339,8 @@ def create_thread_message(
         processed=processed,
         parent_id=parent_id,
         sent_at=sent_at,
+        message_metadata=message_metadata,
+        author_id=author_id,
     )
     db.add(db_message)
 
@@ -697,6 +1358,92 @@ def create_thread_message(
     return db_message
 
 
+def fork_thread(db: Session, thread_id: int, *, before_message_id: int, title: str):
+    """Copy a thread's messages preceding *before_message_id* into a new thread.
+
+    The copy keeps the original's type and agent, marks every copied message
+    processed and remaps ``parent_id`` links onto the new rows. Returns the new
+    thread or ``None`` when the source thread does not exist.
+    """
+    source = db.query(Thread).filter(Thread.id == thread_id).first()
+    if source is None:
+        return None
+
+    forked = Thread(
+        agent_id=source.agent_id,
+        title=title,
+        active=False,
+        agent_state=source.agent_state,
+        memory_strategy=source.memory_strategy,
+        thread_type=source.thread_type,
+    )
+    db.add(forked)
+    db.flush([forked])
+
+    id_map: Dict[int, int] = {}
+    history = (
+        db.query(ThreadMessage)
+        .filter(ThreadMessage.thread_id == thread_id, ThreadMessage.id < before_message_id)
+        .order_by(ThreadMessage.id)
+        .all()
+    )
+    for message in history:
+        copy = ThreadMessage(
+            thread_id=forked.id,
+            role=message.role,
+            content=message.content,
+            tool_calls=message.tool_calls,
+            tool_call_id=message.tool_call_id,
+            name=message.name,
+            sent_at=message.sent_at,
+            processed=True,
+            # Bookmarks stay on the original so the saved list doesn't show both copies
+            message_metadata={
+                key: value for key, value in (message.message_metadata or {}).items() if key != "bookmarked_by"
+            }
+            or None,
+            parent_id=id_map.get(message.parent_id) if message.parent_id is not None else None,
+            author_id=message.author_id,
+        )
+        db.add(copy)
+        db.flush([copy])
+        id_map[message.id] = copy.id
+
+    db.commit()
+    db.refresh(forked)
+    return forked
+
+
+def truncate_thread_after_last_user_message(db: Session, thread_id: int):
+    """Drop everything after the latest user message so it can be re-run.
+
+    The user message itself is flagged unprocessed. Returns that message, or
+    ``None`` if the thread has no user messages.
+    """
+    last_user = (
+        db.query(ThreadMessage)
+        .filter(ThreadMessage.thread_id == thread_id, ThreadMessage.role == "user")
+        .order_by(ThreadMessage.id.desc())
+        .first()
+    )
+    if last_user is None:
+        return None
+
+    trailing = (
+        db.query(ThreadMessage)
+        .filter(ThreadMessage.thread_id == thread_id, ThreadMessage.id > last_user.id)
+        .order_by(ThreadMessage.id.desc())
+        .all()
+    )
+    # Children first so parent_id foreign keys never dangle mid-delete
+    for message in trailing:
+        db.delete(message)
+    last_user.processed = False
+    db.commit()
+    db.refresh(last_user)
+    return last_user
+
+
 def mark_message_processed(db: Session, message_id: int):
     """Mark a message as processed"""
     db_message = db.query(ThreadMessage).filter(ThreadMessage.id == message_id).first()
@@ -708,6 +1455,56 @@ def mark_message_processed(db: Session, message_id: int):
     return None
 
 
+# Emoji a message can be reacted with; the chat's reaction picker offers the same set
+MESSAGE_REACTIONS = ("👍", "👎", "❤️", "🎉", "😄", "🤔")
+
+
+def get_thread_message(db: Session, thread_id: int, message_id: int) -> Optional[ThreadMessage]:
+    return db.query(ThreadMessage).filter(ThreadMessage.id == message_id, ThreadMessage.thread_id == thread_id).first()
+
+
+def _save_message_metadata(db: Session, message: ThreadMessage, metadata: Dict[str, Any]) -> ThreadMessage:
+    # Reassign rather than mutate: nested lists aren't change-tracked
+    message.message_metadata = {key: value for key, value in metadata.items() if value}
+    db.commit()
+    db.refresh(message)
+    return message
+
+
+def set_message_bookmark(db: Session, message: ThreadMessage, user_id: int, bookmarked: bool) -> ThreadMessage:
+    """Star or unstar *message* for *user_id* (``bookmarked_by`` in its metadata)."""
+    metadata = dict(message.message_metadata or {})
+    users = [uid for uid in metadata.get("bookmarked_by", []) if uid != user_id]
+    if bookmarked:
+        users.append(user_id)
+    metadata["bookmarked_by"] = users
+    return _save_message_metadata(db, message, metadata)
+
+
+def toggle_message_reaction(db: Session, message: ThreadMessage, user_id: int, emoji: str) -> ThreadMessage:
+    """Add *user_id*'s *emoji* reaction to *message*, or take it back if already there."""
+    if emoji not in MESSAGE_REACTIONS:
+        raise HTTPException(status_code=400, detail=f"Unsupported reaction '{emoji}'")
+    metadata = dict(message.message_metadata or {})
+    reactions = {key: list(users) for key, users in (metadata.get("reactions") or {}).items()}
+    users = reactions.get(emoji, [])
+    reactions[emoji] = [uid for uid in users if uid != user_id] if user_id in users else [*users, user_id]
+    metadata["reactions"] = {key: users for key, users in reactions.items() if users}
+    return _save_message_metadata(db, message, metadata)
+
+
+def get_bookmarked_messages(db: Session, *, agent_id: int, user_id: int) -> List[ThreadMessage]:
+    """Messages *user_id* bookmarked in any of the agent's threads, newest first."""
+    candidates = (
+        db.query(ThreadMessage)
+        .join(Thread, Thread.id == ThreadMessage.thread_id)
+        .filter(Thread.agent_id == agent_id, cast(ThreadMessage.message_metadata, String).like('%"bookmarked_by"%'))
+        .order_by(ThreadMessage.id.desc())
+        .all()
+    )
+    return [m for m in candidates if user_id in (m.message_metadata or {}).get("bookmarked_by", [])]
+
+
 # ---------------------------------------------------------------------------
 # Bulk helpers – performance critical paths
 # ---------------------------------------------------------------------------
@@ -765,7 +1562,7 @@ def create_run(
     db: Session,
     *,
     agent_id: int,
-    thread_id: int,
+    thread_id: Optional[int],
     trigger: str = "manual",
     status: str = "queued",
 ) -> AgentRun:
@@ -788,6 +1585,8 @@ def create_run(
         thread_id=thread_id,
         trigger=trigger_enum,
         status=status_enum,
+        # Runs started outside a request (scheduler, triggers) still get an id
+        trace_id=get_trace_id() or new_trace_id(),
     )
     db.add(run_row)
     db.commit()
@@ -804,6 +1603,7 @@ def mark_running(db: Session, run_id: int, *, started_at: Optional[datetime] = N
     # Set to running status
     row.status = RunStatus.RUNNING
     row.started_at = started_at
+    row.queue_position = None
     db.commit()
     db.refresh(row)
     return row
@@ -846,6 +1646,7 @@ def mark_finished(
     row.total_tokens = total_tokens
     row.total_cost_usd = total_cost_usd
     row.summary = summary
+    check_run_output(db, row)
 
     db.commit()
     db.refresh(row)
@@ -922,23 +1723,98 @@ def mark_failed(
     row.finished_at = finished_at
     row.duration_ms = duration_ms
     row.error = error
+    row.queue_position = None
+
+    db.commit()
+    db.refresh(row)
+    return row
 
+
+def mark_cancelling(db: Session, run_id: int, *, reason: Optional[str] = None) -> Optional[AgentRun]:
+    row = db.query(AgentRun).filter(AgentRun.id == run_id).first()
+    if row is None:
+        return None
+
+    row.status = RunStatus.CANCELLING
+    row.cancel_reason = reason
     db.commit()
     db.refresh(row)
     return row
 
 
-def list_runs(db: Session, agent_id: int, *, limit: int = 20, owner_id: Optional[int] = None):
+def mark_cancelled(
+    db: Session,
+    run_id: int,
+    *,
+    finished_at: Optional[datetime] = None,
+    duration_ms: Optional[int] = None,
+    reason: Optional[str] = None,
+) -> Optional[AgentRun]:
+    row = db.query(AgentRun).filter(AgentRun.id == run_id).first()
+    if row is None:
+        return None
+
+    finished_at = finished_at or utc_now_naive()
+    if row.started_at and duration_ms is None:
+        duration_ms = int((finished_at - row.started_at).total_seconds() * 1000)
+
+    row.status = RunStatus.CANCELLED
+    row.finished_at = finished_at
+    row.duration_ms = duration_ms
+    row.queue_position = None
+    if reason is not None:
+        row.cancel_reason = reason
+
+    db.commit()
+    db.refresh(row)
+    return row
+
+
+def list_runs(
+    db: Session,
+    agent_id: int,
+    *,
+    limit: int = 20,
+    owner_id: Optional[int] = None,
+    before_id: Optional[int] = None,
+):
     """Return the most recent runs for *agent_id* ordered DESC by id.
 
     If *owner_id* is provided, the agent must be owned by that user.
+    *before_id* restricts results to runs older than that id so callers can
+    page through the full history (keyset pagination).
     """
     query = db.query(AgentRun).filter(AgentRun.agent_id == agent_id)
+    if before_id is not None:
+        query = query.filter(AgentRun.id < before_id)
     if owner_id is not None:
         query = query.join(Agent, Agent.id == AgentRun.agent_id).filter(Agent.owner_id == owner_id)
     return query.order_by(AgentRun.id.desc()).limit(limit).all()
 
 
+def list_queued_runs(db: Session, agent_id: int) -> List[AgentRun]:
+    """Runs waiting in *agent_id*'s queue, next to start first."""
+    return (
+        db.query(AgentRun)
+        .filter(AgentRun.agent_id == agent_id, AgentRun.queue_position.isnot(None))
+        .filter(AgentRun.status == RunStatus.QUEUED)
+        .order_by(AgentRun.queue_position.asc(), AgentRun.id.asc())
+        .all()
+    )
+
+
+def set_queue_order(db: Session, agent_id: int, run_ids: List[int]) -> List[AgentRun]:
+    """Renumber the queue so *run_ids* (every queued run, once) start in that order."""
+    queued = {row.id: row for row in list_queued_runs(db, agent_id)}
+    if len(run_ids) != len(set(run_ids)) or set(run_ids) != set(queued):
+        raise ValueError("run_ids must list every queued run exactly once")
+
+    for position, run_id in enumerate(run_ids, start=1):
+        queued[run_id].queue_position = position
+    db.commit()
+    return [queued[run_id] for run_id in run_ids]
+
+
 # ---------------------------------------------------------------------------
 # Canvas layout helpers (Phase-B)
 # ---------------------------------------------------------------------------
@@ -1053,6 +1929,186 @@ def get_workflow(db: Session, workflow_id: int):
     return db.query(WorkflowModel).filter_by(id=workflow_id).first()
 
 
+# Trashed workflows follow the same retention window as agents
+WORKFLOW_TRASH_RETENTION_DAYS = AGENT_TRASH_RETENTION_DAYS
+
+
+def trash_workflow(db: Session, workflow_id: int):
+    """Move a workflow to the trash (soft delete). Returns the row or ``None``.
+
+    The canvas, layouts, executions and comments are kept so a restore brings
+    the workflow back exactly as it was.
+    """
+    from zerg.models.models import Workflow as WorkflowModel
+
+    wf = db.query(WorkflowModel).filter(WorkflowModel.id == workflow_id, WorkflowModel.is_active.is_(True)).first()
+    if wf is None:
+        return None
+    wf.is_active = False
+    wf.deleted_at = utc_now_naive()
+    db.commit()
+    db.refresh(wf)
+    return wf
+
+
+def restore_workflow(db: Session, workflow_id: int):
+    """Take a workflow back out of the trash. Returns the row or ``None``.
+
+    Raises 409 when an active workflow has taken its name in the meantime.
+    """
+    from zerg.models.models import Workflow as WorkflowModel
+
+    wf = db.query(WorkflowModel).filter(WorkflowModel.id == workflow_id, WorkflowModel.deleted_at.isnot(None)).first()
+    if wf is None:
+        return None
+    clash = (
+        db.query(WorkflowModel.id)
+        .filter(
+            WorkflowModel.owner_id == wf.owner_id,
+            WorkflowModel.name == wf.name,
+            WorkflowModel.is_active.is_(True),
+        )
+        .first()
+    )
+    if clash:
+        raise HTTPException(status_code=409, detail="A workflow with this name already exists.")
+    wf.is_active = True
+    wf.deleted_at = None
+    db.commit()
+    db.refresh(wf)
+    return wf
+
+
+def get_trashed_workflows(db: Session, *, owner_id: int):
+    """Return trashed workflows, most recently deleted first."""
+    from zerg.models.models import Workflow as WorkflowModel
+
+    return (
+        db.query(WorkflowModel)
+        .filter(WorkflowModel.owner_id == owner_id, WorkflowModel.deleted_at.isnot(None))
+        .order_by(WorkflowModel.deleted_at.desc())
+        .all()
+    )
+
+
+def delete_workflow(db: Session, workflow_id: int) -> bool:
+    """Delete a workflow with its executions, layouts, variables and comments."""
+    from zerg.models.models import EnvironmentVariable
+    from zerg.models.models import NodeExecutionState
+    from zerg.models.models import Workflow as WorkflowModel
+    from zerg.models.models import WorkflowExecution
+    from zerg.models.models import WorkflowNodeComment
+
+    exists = db.query(WorkflowModel.id).filter(WorkflowModel.id == workflow_id).first()
+    if exists is None:
+        return False
+
+    execution_ids = [
+        row[0] for row in db.query(WorkflowExecution.id).filter(WorkflowExecution.workflow_id == workflow_id).all()
+    ]
+    if execution_ids:
+        db.query(NodeExecutionState).filter(NodeExecutionState.workflow_execution_id.in_(execution_ids)).delete(
+            synchronize_session=False
+        )
+        # Runs of other workflows started from these (Call Workflow, re-runs) keep their history
+        for column in (WorkflowExecution.parent_execution_id, WorkflowExecution.source_execution_id):
+            db.query(WorkflowExecution).filter(column.in_(execution_ids)).update(
+                {column: None}, synchronize_session=False
+            )
+        db.query(WorkflowExecution).filter(WorkflowExecution.id.in_(execution_ids)).delete(synchronize_session=False)
+
+    db.query(CanvasLayout).filter(CanvasLayout.workflow_id == workflow_id).delete(synchronize_session=False)
+    db.query(EnvironmentVariable).filter(EnvironmentVariable.workflow_id == workflow_id).delete(
+        synchronize_session=False
+    )
+    db.query(WorkflowNodeComment).filter(WorkflowNodeComment.workflow_id == workflow_id).delete(
+        synchronize_session=False
+    )
+    db.query(WorkflowModel).filter(WorkflowModel.id == workflow_id).delete(synchronize_session=False)
+    db.commit()
+    return True
+
+
+def purge_expired_trashed_workflows(db: Session, *, owner_id: int) -> int:
+    """Permanently delete workflows that have been in the trash past the retention window."""
+    from zerg.models.models import Workflow as WorkflowModel
+
+    cutoff = utc_now_naive() - timedelta(days=WORKFLOW_TRASH_RETENTION_DAYS)
+    expired_ids = [
+        row[0]
+        for row in db.query(WorkflowModel.id)
+        .filter(
+            WorkflowModel.owner_id == owner_id,
+            WorkflowModel.deleted_at.isnot(None),
+            WorkflowModel.deleted_at < cutoff,
+        )
+        .all()
+    ]
+    for workflow_id in expired_ids:
+        delete_workflow(db, workflow_id)
+    return len(expired_ids)
+
+
+# -------------------------------------------------------------------
+# Workflow node comments
+# -------------------------------------------------------------------
+
+
+def get_workflow_comments(db: Session, workflow_id: int, *, node_id: Optional[str] = None):
+    """Every comment on *workflow_id* (optionally one node), oldest first."""
+    from zerg.models.models import WorkflowNodeComment
+
+    query = (
+        db.query(WorkflowNodeComment)
+        .options(selectinload(WorkflowNodeComment.author))
+        .filter(WorkflowNodeComment.workflow_id == workflow_id)
+    )
+    if node_id is not None:
+        query = query.filter(WorkflowNodeComment.node_id == node_id)
+    return query.order_by(WorkflowNodeComment.created_at, WorkflowNodeComment.id).all()
+
+
+def get_workflow_comment(db: Session, workflow_id: int, comment_id: int):
+    from zerg.models.models import WorkflowNodeComment
+
+    return db.query(WorkflowNodeComment).filter_by(id=comment_id, workflow_id=workflow_id).first()
+
+
+def create_workflow_comment(
+    db: Session,
+    *,
+    workflow_id: int,
+    node_id: str,
+    author_id: int,
+    body: str,
+    parent_id: Optional[int] = None,
+    mentioned_user_ids: Optional[List[int]] = None,
+):
+    from zerg.models.models import WorkflowNodeComment
+
+    comment = WorkflowNodeComment(
+        workflow_id=workflow_id,
+        node_id=node_id,
+        parent_id=parent_id,
+        author_id=author_id,
+        body=body,
+        mentioned_user_ids=mentioned_user_ids or [],
+    )
+    db.add(comment)
+    db.commit()
+    db.refresh(comment)
+    return comment
+
+
+def set_workflow_comment_resolved(db: Session, comment, resolved_by_id: Optional[int]):
+    """Resolve the thread rooted at *comment*, or reopen it when *resolved_by_id* is None."""
+    comment.resolved_by_id = resolved_by_id
+    comment.resolved_at = None if resolved_by_id is None else utc_now_naive()
+    db.commit()
+    db.refresh(comment)
+    return comment
+
+
 def get_workflow_execution(db: Session, execution_id: int):
     from zerg.models.models import WorkflowExecution
 
@@ -1065,6 +2121,19 @@ def get_workflow_executions(db: Session, workflow_id: int, skip: int = 0, limit:
     return db.query(WorkflowExecution).filter_by(workflow_id=workflow_id).offset(skip).limit(limit).all()
 
 
+def get_recent_workflow_executions(db: Session, workflow_id: int, limit: int = 20):
+    """Return the newest *limit* executions for a workflow, most recent first."""
+    from zerg.models.models import WorkflowExecution
+
+    return (
+        db.query(WorkflowExecution)
+        .filter_by(workflow_id=workflow_id)
+        .order_by(WorkflowExecution.id.desc())
+        .limit(limit)
+        .all()
+    )
+
+
 def get_waiting_execution_for_workflow(db: Session, workflow_id: int):
     """Get the first waiting execution for a workflow, if any exists."""
     from zerg.models.models import WorkflowExecution
@@ -1136,6 +2205,29 @@ def create_workflow_template(
     return db_template
 
 
+# Accepted values for the gallery's ``sort`` query parameter
+TEMPLATE_SORTS = ("recent", "rating", "popular")
+
+
+def _template_feedback_totals(db: Session):
+    """Subquery of average rating, rating count and favorite count per template."""
+    from sqlalchemy import case
+    from sqlalchemy import func
+
+    from zerg.models.models import WorkflowTemplateFeedback as Feedback
+
+    return (
+        db.query(
+            Feedback.template_id.label("template_id"),
+            func.avg(Feedback.rating).label("rating_avg"),
+            func.count(Feedback.rating).label("rating_count"),
+            func.sum(case((Feedback.is_favorite.is_(True), 1), else_=0)).label("favorite_count"),
+        )
+        .group_by(Feedback.template_id)
+        .subquery()
+    )
+
+
 def get_workflow_templates(
     db: Session,
     *,
@@ -1144,9 +2236,19 @@ def get_workflow_templates(
     limit: int = 100,
     created_by: Optional[int] = None,
     public_only: bool = True,
+    sort: Optional[str] = None,
+    min_rating: Optional[float] = None,
+    favorites_of: Optional[int] = None,
 ):
-    """Get workflow templates with optional filtering."""
+    """Get workflow templates with optional filtering.
+
+    ``sort`` is one of TEMPLATE_SORTS ("popular" orders by favorite count);
+    ``favorites_of`` limits the list to templates that user has favorited.
+    """
+    from sqlalchemy import func
+
     from zerg.models.models import WorkflowTemplate
+    from zerg.models.models import WorkflowTemplateFeedback
 
     query = db.query(WorkflowTemplate)
 
@@ -1159,9 +2261,93 @@ def get_workflow_templates(
     if category:
         query = query.filter(WorkflowTemplate.category == category)
 
+    if favorites_of is not None:
+        query = query.join(
+            WorkflowTemplateFeedback,
+            (WorkflowTemplateFeedback.template_id == WorkflowTemplate.id)
+            & (WorkflowTemplateFeedback.user_id == favorites_of)
+            & WorkflowTemplateFeedback.is_favorite.is_(True),
+        )
+
+    if min_rating is not None or sort in ("rating", "popular"):
+        totals = _template_feedback_totals(db)
+        query = query.outerjoin(totals, totals.c.template_id == WorkflowTemplate.id)
+        if min_rating is not None:
+            query = query.filter(totals.c.rating_avg >= min_rating)
+        if sort == "rating":
+            query = query.order_by(
+                func.coalesce(totals.c.rating_avg, 0).desc(), func.coalesce(totals.c.rating_count, 0).desc()
+            )
+        elif sort == "popular":
+            query = query.order_by(func.coalesce(totals.c.favorite_count, 0).desc())
+
+    if sort is not None:
+        # Newest first, also the tie-breaker for rating/popularity
+        query = query.order_by(WorkflowTemplate.created_at.desc(), WorkflowTemplate.id.desc())
+
     return query.offset(skip).limit(limit).all()
 
 
+def get_template_feedback_summary(db: Session, template_ids: List[int], user_id: int) -> Dict[int, Dict[str, Any]]:
+    """Rating/favorite totals per template plus *user_id*'s own rating and favorite."""
+    from zerg.models.models import WorkflowTemplateFeedback as Feedback
+
+    summary: Dict[int, Dict[str, Any]] = {
+        template_id: {
+            "rating_avg": None,
+            "rating_count": 0,
+            "favorite_count": 0,
+            "my_rating": None,
+            "is_favorite": False,
+        }
+        for template_id in template_ids
+    }
+    if not template_ids:
+        return summary
+
+    totals = _template_feedback_totals(db)
+    for template_id, rating_avg, rating_count, favorite_count in (
+        db.query(totals.c.template_id, totals.c.rating_avg, totals.c.rating_count, totals.c.favorite_count)
+        .filter(totals.c.template_id.in_(template_ids))
+        .all()
+    ):
+        entry = summary[template_id]
+        entry["rating_avg"] = round(float(rating_avg), 2) if rating_avg is not None else None
+        entry["rating_count"] = int(rating_count or 0)
+        entry["favorite_count"] = int(favorite_count or 0)
+
+    for row in db.query(Feedback).filter(Feedback.template_id.in_(template_ids), Feedback.user_id == user_id).all():
+        summary[row.template_id]["my_rating"] = row.rating
+        summary[row.template_id]["is_favorite"] = bool(row.is_favorite)
+
+    return summary
+
+
+def set_template_feedback(
+    db: Session,
+    *,
+    template_id: int,
+    user_id: int,
+    rating: Optional[int] = None,
+    clear_rating: bool = False,
+    is_favorite: Optional[bool] = None,
+):
+    """Upsert one user's feedback row; fields left as None are kept."""
+    from zerg.models.models import WorkflowTemplateFeedback as Feedback
+
+    row = db.query(Feedback).filter_by(template_id=template_id, user_id=user_id).first()
+    if row is None:
+        row = Feedback(template_id=template_id, user_id=user_id, is_favorite=False)
+        db.add(row)
+    if rating is not None or clear_rating:
+        row.rating = rating
+    if is_favorite is not None:
+        row.is_favorite = is_favorite
+    db.commit()
+    db.refresh(row)
+    return row
+
+
 def get_workflow_template(db: Session, template_id: int):
     """Get a specific workflow template by ID."""
     from zerg.models.models import WorkflowTemplate
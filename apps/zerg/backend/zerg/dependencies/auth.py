@@ -15,6 +15,8 @@ from fastapi import Request
 from fastapi import status
 from sqlalchemy.orm import Session
 
+from zerg.auth.capabilities import is_admin
+from zerg.auth.capabilities import is_super_admin
 from zerg.auth.strategy import DevAuthStrategy
 from zerg.auth.strategy import JWTAuthStrategy
 from zerg.auth.strategy import _decode_jwt_fallback as _decode_jwt_fallback  # type: ignore
@@ -107,19 +109,11 @@ def require_super_admin(current_user=Depends(get_current_user)):
     """FastAPI dependency that ensures the user is in ADMIN_EMAILS list (super admin)."""
 
     # First check if they're an admin
-    if getattr(current_user, "role", "USER") != "ADMIN":
+    if not is_admin(current_user):
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Admin privileges required")
 
-    # In test/dev environments with auth disabled, any admin user is considered super admin
-    settings = get_settings()
-    if settings.auth_disabled or settings.testing:
-        return current_user
-
-    # Then check if they're a super admin (in ADMIN_EMAILS)
-    admin_emails = {e.strip().lower() for e in (settings.admin_emails or "").split(",") if e.strip()}
-    user_email = getattr(current_user, "email", "").lower()
-
-    if user_email not in admin_emails:
+    # Then check if they're a super admin (in ADMIN_EMAILS, or any admin in test/dev with auth disabled)
+    if not is_super_admin(current_user):
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Super admin privileges required")
 
     return current_user
@@ -220,6 +220,7 @@ class GmailProvider:  # noqa: D101 – obvious from context
 
         from sqlalchemy.orm.attributes import flag_modified  # type: ignore
 
+        from zerg.crud import crud
         from zerg.database import db_session
         from zerg.events import EventType
         from zerg.events import event_bus
@@ -264,8 +265,17 @@ class GmailProvider:  # noqa: D101 – obvious from context
                 except Exception as exc:  # pragma: no cover
                     logger.error("refresh-token-exchange-failed", connector_id=connector_id, error=str(exc))
                     gmail_api_error_total.inc()
+                    # Surfaced as the connection health on the profile page
+                    cfg["last_error"] = f"Token refresh failed: {exc}"[:255]
+                    conn.config = cfg  # type: ignore[assignment]
+                    flag_modified(conn, "config")
+                    session.commit()
                     return
                 self._token_cache[str(connector_id)] = (access_token, now + 55 * 60)
+                if cfg.pop("last_error", None) is not None:
+                    conn.config = cfg  # type: ignore[assignment]
+                    flag_modified(conn, "config")
+                    session.commit()
 
             # History diff at connector level
             start_hid = int(cfg.get("history_id", 0))
@@ -308,11 +318,12 @@ class GmailProvider:  # noqa: D101 – obvious from context
                 if meta:
                     meta_cache[mid] = meta
 
-            # Load triggers referencing this connector
+            # Load triggers referencing this connector (skipping ones switched
+            # off by a Gmail disconnect)
             triggers = [
                 trg
                 for trg in session.query(Trigger).filter(Trigger.type == "email").all()
-                if (trg.config or {}).get("connector_id") == connector_id
+                if (trg.config or {}).get("connector_id") == connector_id and not (trg.config or {}).get("disabled")
             ]
 
             fired_total = 0
@@ -331,7 +342,17 @@ class GmailProvider:  # noqa: D101 – obvious from context
                             "trigger_type": "webhook",
                         },
                     )
+                    run_before = crud.newest_run_id(session, trg.agent_id)
                     await scheduler_service.run_agent_task(trg.agent_id, trigger="webhook")  # type: ignore[arg-type]
+                    run_after = crud.newest_run_id(session, trg.agent_id)
+                    headers = meta.get("headers") or {}
+                    crud.record_trigger_delivery(
+                        session,
+                        trigger_id=trg.id,
+                        status_code=202,
+                        matched=f"Email from {headers.get('From', 'unknown sender')}: {headers.get('Subject', '')}",
+                        run_id=run_after if run_after != run_before else None,
+                    )
                     fired_total += 1
 
             # Update connector history id
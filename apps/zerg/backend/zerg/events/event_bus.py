@@ -28,6 +28,9 @@ class EventType(str, Enum):
     # Run events (new run history feature)
     RUN_CREATED = "run_created"
     RUN_UPDATED = "run_updated"
+    RUN_QUEUE_UPDATED = "run_queue_updated"
+    # Tool calls, intermediate messages and retries inside a run (run inspector)
+    RUN_EVENT = "run_event"
 
     # Trigger events (external webhook or other sources)
     TRIGGER_FIRED = "trigger_fired"
@@ -43,6 +46,8 @@ class EventType(str, Enum):
     EXECUTION_STARTED = "execution_started"
     NODE_STATE_CHANGED = "node_state_changed"
     WORKFLOW_PROGRESS = "workflow_progress"
+    EXECUTION_PAUSED = "execution_paused"
+    NODE_WAITING = "node_waiting"
     EXECUTION_FINISHED = "execution_finished"
     NODE_LOG = "node_log"
 
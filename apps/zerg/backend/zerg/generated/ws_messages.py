@@ -26,6 +26,7 @@ class Envelope(BaseModel):
     type: str = Field(description="Message type identifier")
     topic: str = Field(description="Topic routing string")
     req_id: Optional[str] = Field(default=None, description="Request correlation ID")
+    trace_id: Optional[str] = Field(default=None, description="Server trace ID of the request that caused this event")
     ts: int = Field(description="Timestamp in milliseconds since epoch")
     data: Dict[str, Any] = Field(description="Message payload")
 
@@ -36,6 +37,7 @@ class Envelope(BaseModel):
         topic: str,
         data: Dict[str, Any],
         req_id: Optional[str] = None,
+        trace_id: Optional[str] = None,
     ) -> "Envelope":
         """Create and validate a new envelope."""
         envelope = cls(
@@ -43,6 +45,7 @@ class Envelope(BaseModel):
             topic=topic,
             data=data,
             req_id=req_id,
+            trace_id=trace_id,
             ts=int(time.time() * 1000),
         )
         # Validate on creation for fail-fast behavior
@@ -119,6 +122,12 @@ class UnsubscribeData(BaseModel):
     topics: List[str]
     message_id: Optional[str] = None
 
+class UnsubscribeSuccessData(BaseModel):
+    """Payload for UnsubscribeSuccessData messages"""
+
+    message_id: Optional[str] = Field(default=None, description="Correlation ID matching the original unsubscribe request")
+    topics: List[str] = Field(description="Topics the client was unsubscribed from")
+
 class SendMessageData(BaseModel):
     """Payload for SendMessageData messages"""
 
@@ -145,6 +154,8 @@ class StreamStartData(BaseModel):
     """Payload for StreamStartData messages"""
 
     thread_id: int = Field(ge=1, description="")
+    started_by_id: Optional[int] = Field(default=None, ge=1, description="")
+    started_by_name: Optional[str] = None
 
 class StreamChunkData(BaseModel):
     """Payload for StreamChunkData messages"""
@@ -160,6 +171,14 @@ class StreamEndData(BaseModel):
     """Payload for StreamEndData messages"""
 
     thread_id: int = Field(ge=1, description="")
+    message_id: Optional[int] = Field(default=None, ge=1, description="")
+    run_id: Optional[int] = Field(default=None, ge=1, description="")
+    model: Optional[str] = None
+    prompt_tokens: Optional[int] = Field(default=None, ge=0, description="")
+    completion_tokens: Optional[int] = Field(default=None, ge=0, description="")
+    total_tokens: Optional[int] = Field(default=None, ge=0, description="")
+    duration_ms: Optional[int] = Field(default=None, ge=0, description="")
+    cost_usd: Optional[float] = Field(default=None, ge=0, description="")
 
 class AssistantIdData(BaseModel):
     """Payload for AssistantIdData messages"""
@@ -177,6 +196,9 @@ class AgentEventData(BaseModel):
     last_error: Optional[str] = None
     name: Optional[str] = None
     description: Optional[str] = None
+    owner_id: Optional[int] = Field(default=None, ge=1, description="")
+    action: Optional[Literal["created", "updated", "deleted"]] = Field(default=None, description="Set on user-topic deltas; the agent fields are the full row")
+    seq: Optional[int] = Field(default=None, ge=1, description="Per-owner delta counter; a gap means deltas were missed")
 
 class RunUpdateData(BaseModel):
     """Payload for RunUpdateData messages"""
@@ -184,12 +206,41 @@ class RunUpdateData(BaseModel):
     id: int = Field(ge=1, description="")
     agent_id: int = Field(ge=1, description="")
     thread_id: Optional[int] = Field(default=None, ge=1, description="")
-    status: Literal["queued", "running", "success", "failed"]
+    status: Literal["queued", "running", "cancelling", "success", "failed", "cancelled"]
     trigger: Optional[Literal["manual", "schedule", "chat", "webhook", "api"]] = None
     started_at: Optional[str] = None
     finished_at: Optional[str] = None
     duration_ms: Optional[int] = Field(default=None, ge=0, description="")
     error: Optional[str] = None
+    trace_id: Optional[str] = None
+
+class QueuedRunData(BaseModel):
+    """Payload for QueuedRunData messages"""
+
+    id: int = Field(ge=1, description="")
+    position: int = Field(ge=1, description="")
+    trigger: Literal["manual", "schedule", "chat", "webhook", "api"]
+    thread_id: Optional[int] = Field(default=None, ge=1, description="")
+    queued_at: Optional[str] = None
+
+class RunQueueUpdateData(BaseModel):
+    """Payload for RunQueueUpdateData messages"""
+
+    agent_id: int = Field(ge=1, description="")
+    queue: List[QueuedRunData] = Field(description="Queued runs, next to start first")
+
+class RunEventData(BaseModel):
+    """Payload for RunEventData messages"""
+
+    agent_id: int = Field(ge=1, description="")
+    run_id: int = Field(ge=1, description="")
+    kind: Literal["tool_started", "tool_finished", "tool_failed", "message", "retry"]
+    timestamp: str
+    tool_name: Optional[str] = None
+    tool_call_id: Optional[str] = None
+    preview: Optional[str] = Field(default=None, description="Truncated args, result, error or message text")
+    duration_ms: Optional[int] = Field(default=None, ge=0, description="")
+    attempt: Optional[int] = Field(default=None, ge=1, description="")
 
 class UserUpdateData(BaseModel):
     """Payload for UserUpdateData messages"""
@@ -199,6 +250,22 @@ class UserUpdateData(BaseModel):
     display_name: Optional[str] = None
     avatar_url: Optional[str] = None
 
+class CommentMentionData(BaseModel):
+    """Payload for CommentMentionData messages"""
+
+    workflow_id: int = Field(ge=1, description="")
+    workflow_name: str
+    node_id: str = Field(min_length=1, description="")
+    comment_id: int = Field(ge=1, description="")
+    author_name: str
+    excerpt: str = Field(description="Start of the comment body")
+
+class ExecutionStartedData(BaseModel):
+    """Payload for ExecutionStartedData messages"""
+
+    execution_id: int = Field(ge=1, description="")
+    timestamp: Optional[str] = Field(default=None, description="ISO 8601 start time")
+
 class NodeStateData(BaseModel):
     """Payload for NodeStateData messages"""
 
@@ -211,6 +278,15 @@ class NodeStateData(BaseModel):
     error_message: Optional[str] = Field(default=None, description="Detailed error message for failures")
     output: Optional[Dict[str, Any]] = None
 
+class WorkflowProgressData(BaseModel):
+    """Payload for WorkflowProgressData messages"""
+
+    execution_id: int = Field(ge=1, description="")
+    completed_nodes: List[str] = Field(description="IDs of the nodes that have finished so far")
+    node_outputs: Optional[Dict[str, Any]] = Field(default=None, description="Outputs of the completed nodes, keyed by node ID")
+    error: Optional[str] = Field(default=None, description="Error raised by the last completed node, if any")
+    timestamp: Optional[str] = None
+
 class ExecutionFinishedData(BaseModel):
     """Payload for ExecutionFinishedData messages"""
 
@@ -229,6 +305,26 @@ class NodeLogData(BaseModel):
     stream: Literal["stdout", "stderr"]
     text: str
 
+class ExecutionPausedData(BaseModel):
+    """Payload for ExecutionPausedData messages"""
+
+    execution_id: int = Field(ge=1, description="")
+    node_id: str = Field(min_length=1, description="")
+    node_type: Literal["agent", "tool", "trigger", "conditional", "switch", "loop", "http", "wait", "subworkflow"]
+    config: Dict[str, Any] = Field(description="Config the node will run with unless replaced on step")
+    node_outputs: Dict[str, Any] = Field(description="Output envelopes of the nodes that have finished so far")
+    reason: Literal["step", "breakpoint"] = Field(description="Why the execution stopped at this node")
+
+class NodeWaitingData(BaseModel):
+    """Payload for NodeWaitingData messages"""
+
+    execution_id: int = Field(ge=1, description="")
+    node_id: str = Field(min_length=1, description="")
+    mode: Literal["duration", "until", "event"]
+    resume_at: Optional[str] = Field(default=None, description="When the wait ends on its own (UTC); absent for event waits without a timeout")
+    event: Optional[str] = Field(default=None, description="External event name for event waits")
+    resume_path: Optional[str] = Field(default=None, description="Path that resumes an event wait when POSTed to")
+
 class OpsEventData(BaseModel):
     """Payload for OpsEventData messages"""
 
@@ -240,12 +336,55 @@ class OpsEventData(BaseModel):
     error: Optional[str] = None
     agent_name: Optional[str] = None
     status: Optional[str] = None
-    scope: Optional[Literal["user", "global"]] = None
+    scope: Optional[Literal["user", "global", "agent"]] = None
     percent: Optional[float] = None
     used_usd: Optional[float] = None
     limit_cents: Optional[int] = Field(default=None, ge=0, description="")
     user_email: Optional[str] = None
 
+class PresenceCursorData(BaseModel):
+    """Payload for PresenceCursorData messages"""
+
+    x: float
+    y: float
+
+class PresenceUpdateData(BaseModel):
+    """Payload for PresenceUpdateData messages"""
+
+    workflow_id: int = Field(ge=1, description="")
+    cursor: Optional[PresenceCursorData] = Field(default=None, description="Pointer position in flow coordinates; null when off the canvas")
+    selected_node_ids: Optional[List[str]] = None
+    editing_node_id: Optional[str] = Field(default=None, description="Node open in the sender's inspector")
+    edited_node_id: Optional[str] = Field(default=None, description="Node the sender just changed")
+
+class PresenceEditorData(BaseModel):
+    """Payload for PresenceEditorData messages"""
+
+    workflow_id: int = Field(ge=1, description="")
+    client_id: str = Field(min_length=1, description="Connection id; one user may have several tabs open")
+    user_id: int = Field(ge=1, description="")
+    display_name: Optional[str] = None
+    avatar_url: Optional[str] = None
+    cursor: Optional[PresenceCursorData] = None
+    selected_node_ids: List[str]
+    editing_node_id: Optional[str] = None
+    edited_node_id: Optional[str] = None
+    edited_at: Optional[str] = None
+    updated_at: str
+
+class PresenceStateData(BaseModel):
+    """Payload for PresenceStateData messages"""
+
+    workflow_id: int = Field(ge=1, description="")
+    self_client_id: str = Field(min_length=1, description="The receiving connection's own client_id")
+    editors: List[PresenceEditorData]
+
+class PresenceLeftData(BaseModel):
+    """Payload for PresenceLeftData messages"""
+
+    workflow_id: int = Field(ge=1, description="")
+    client_id: str = Field(min_length=1, description="")
+
 class MessageType(str, Enum):
     """Enumeration of all WebSocket message types."""
 
@@ -256,6 +395,7 @@ class MessageType(str, Enum):
     SUBSCRIBE_ACK = "subscribe_ack"
     SUBSCRIBE_ERROR = "subscribe_error"
     UNSUBSCRIBE = "unsubscribe"
+    UNSUBSCRIBE_SUCCESS = "unsubscribe_success"
     SEND_MESSAGE = "send_message"
     THREAD_MESSAGE = "thread_message"
     THREAD_EVENT = "thread_event"
@@ -265,11 +405,22 @@ class MessageType(str, Enum):
     ASSISTANT_ID = "assistant_id"
     AGENT_EVENT = "agent_event"
     RUN_UPDATE = "run_update"
+    RUN_QUEUE_UPDATE = "run_queue_update"
+    RUN_EVENT = "run_event"
     USER_UPDATE = "user_update"
+    COMMENT_MENTION = "comment_mention"
+    EXECUTION_STARTED = "execution_started"
     NODE_STATE = "node_state"
+    WORKFLOW_PROGRESS = "workflow_progress"
     EXECUTION_FINISHED = "execution_finished"
     NODE_LOG = "node_log"
+    EXECUTION_PAUSED = "execution_paused"
+    NODE_WAITING = "node_waiting"
     OPS_EVENT = "ops_event"
+    UPDATE_PRESENCE = "update_presence"
+    PRESENCE_STATE = "presence_state"
+    PRESENCE_UPDATE = "presence_update"
+    PRESENCE_LEFT = "presence_left"
 
 
 # Typed emitter for contract enforcement
@@ -343,6 +494,7 @@ ENVELOPE_SCHEMA = {
         "type": {"type": "string"},
         "topic": {"type": "string"},
         "req_id": {"type": ["string", "null"]},
+        "trace_id": {"type": ["string", "null"]},
         "ts": {"type": "integer"},
         "data": {"type": "object"}
     }
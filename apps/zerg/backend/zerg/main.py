@@ -47,29 +47,41 @@ from zerg.routers.account_connectors import router as account_connectors_router
 from zerg.routers.admin import router as admin_router
 from zerg.routers.agent_config import router as agent_config_router
 from zerg.routers.agent_connectors import router as agent_connectors_router
+from zerg.routers.agent_knowledge import router as agent_knowledge_router
+from zerg.routers.agent_tests import router as agent_tests_router
+from zerg.routers.agent_tools import router as agent_tools_router
 from zerg.routers.agents import router as agents_router
+from zerg.routers.api_credentials import router as api_credentials_router
 from zerg.routers.auth import router as auth_router
 from zerg.routers.connectors import router as connectors_router
+from zerg.routers.data_exports import router as data_exports_router
 from zerg.routers.email_webhooks import router as email_webhook_router
+from zerg.routers.env_vars import router as env_vars_router
 from zerg.routers.email_webhooks_pubsub import router as pubsub_webhook_router
 from zerg.routers.funnel import router as funnel_router
+from zerg.routers.github_webhooks import router as github_webhooks_router
 from zerg.routers.graph_layout import router as graph_router
 from zerg.routers.jarvis import router as jarvis_router
+from zerg.routers.mcp_servers import health_router as mcp_health_router
 from zerg.routers.mcp_servers import router as mcp_servers_router
 from zerg.routers.metrics import router as metrics_router
 from zerg.routers.models import router as models_router
 from zerg.routers.oauth import router as oauth_router
 from zerg.routers.ops import beacon_router as ops_beacon_router
 from zerg.routers.ops import router as ops_router
+from zerg.routers.ops import status_router as ops_status_router
 from zerg.routers.runs import router as runs_router
+from zerg.routers.slack_events import router as slack_events_router
 from zerg.routers.sync import router as sync_router
 from zerg.routers.system import router as system_router
+from zerg.routers.tags import router as tags_router
 from zerg.routers.templates import router as templates_router
 from zerg.routers.threads import router as threads_router
 from zerg.routers.triggers import router as triggers_router
 from zerg.routers.users import router as users_router
 from zerg.routers.websocket import router as websocket_router
 from zerg.routers.workflow_executions import router as workflow_executions_router
+from zerg.routers.workflow_executions import wait_resume_router as workflow_wait_resume_router
 from zerg.routers.workflows import router as workflows_router
 
 # Email trigger polling service (stub for now)
@@ -81,6 +93,9 @@ from zerg.routers.workflows import router as workflows_router
 # unless they are stopped explicitly.  To make the entire test-suite
 # friction-free we skip service start-up when the environment variable
 # ``TESTING`` is truthy (set automatically by `backend/tests/conftest.py`).
+from zerg.middleware.request_id import TRACE_ID_HEADER  # noqa: E402
+from zerg.middleware.request_id import RequestIDMiddleware  # noqa: E402
+from zerg.middleware.request_id import TraceIdLogFilter  # noqa: E402
 from zerg.services.ops_events import ops_events_bridge  # noqa: E402
 from zerg.services.scheduler_service import scheduler_service  # noqa: E402
 
@@ -94,7 +109,9 @@ except AttributeError:
     _log_level = logging.INFO
 else:
     pass
-logging.basicConfig(level=_log_level, format="%(levelname)s - %(message)s", handlers=[logging.StreamHandler()])
+_log_handler = logging.StreamHandler()
+_log_handler.addFilter(TraceIdLogFilter())
+logging.basicConfig(level=_log_level, format="%(levelname)s - %(trace)s%(message)s", handlers=[_log_handler])
 
 # Suppress verbose INFO logs from known-noisy modules (e.g., websocket connects)
 for _noisy_mod in ("zerg.routers.websocket", "zerg.websocket.manager"):
@@ -361,12 +378,16 @@ from fastapi.responses import JSONResponse
 @app.exception_handler(Exception)
 async def ensure_cors_on_errors(request: Request, exc: Exception):
     """Ensure CORS headers are included even in error responses."""
-    # Log the actual error for debugging
-    logger.error(f"Unhandled exception: {exc}", exc_info=True)
+    trace_id = getattr(request.state, "trace_id", None)
+    # Log the actual error for debugging (outside the request context, so
+    # the trace id is not picked up by the log filter)
+    logger.error(f"Unhandled exception (trace {trace_id}): {exc}", exc_info=True)
 
     # Determine allowed origin strictly (no wildcard fallback in prod).
     origin = request.headers.get("origin")
     headers = {"Vary": "Origin"}
+    if trace_id:
+        headers[TRACE_ID_HEADER] = trace_id
     if origin and ("*" in cors_origins or origin in cors_origins):
         headers.update(
             {
@@ -378,7 +399,11 @@ async def ensure_cors_on_errors(request: Request, exc: Exception):
             }
         )
 
-    return JSONResponse(status_code=500, content={"detail": "Internal server error"}, headers=headers)
+    return JSONResponse(
+        status_code=500,
+        content={"detail": "Internal server error", "trace_id": trace_id},
+        headers=headers,
+    )
 
 
 app.add_middleware(
@@ -410,9 +435,14 @@ except Exception:  # pragma: no cover – keep startup resilient
     # Defer logging until *logger* is available (defined right below).
     pass
 
+# Outermost so every response – including CORS preflights and errors raised by
+# inner middleware – carries the trace id.
+app.add_middleware(RequestIDMiddleware)
+
 # Include our API routers with centralized prefixes
 app.include_router(agents_router, prefix=f"{API_PREFIX}{AGENTS_PREFIX}")
 app.include_router(mcp_servers_router, prefix=f"{API_PREFIX}")  # MCP servers nested under agents
+app.include_router(mcp_health_router, prefix=f"{API_PREFIX}")  # Cross-agent MCP health dashboard
 app.include_router(threads_router, prefix=f"{API_PREFIX}{THREADS_PREFIX}")
 app.include_router(models_router, prefix=f"{API_PREFIX}{MODELS_PREFIX}")
 app.include_router(websocket_router, prefix=API_PREFIX)
@@ -424,9 +454,13 @@ app.include_router(triggers_router, prefix=f"{API_PREFIX}")
 app.include_router(runs_router, prefix=f"{API_PREFIX}")
 app.include_router(workflows_router, prefix=f"{API_PREFIX}")
 app.include_router(workflow_executions_router, prefix=f"{API_PREFIX}")
+app.include_router(workflow_wait_resume_router, prefix=f"{API_PREFIX}")  # Public wait resume (token auth)
 app.include_router(auth_router, prefix=f"{API_PREFIX}")
 app.include_router(oauth_router, prefix=f"{API_PREFIX}")  # OAuth for third-party connectors
+app.include_router(slack_events_router, prefix=f"{API_PREFIX}")  # Slack Events API (signing-secret auth)
+app.include_router(github_webhooks_router, prefix=f"{API_PREFIX}")  # GitHub webhooks (per-trigger secret)
 app.include_router(users_router, prefix=f"{API_PREFIX}")
+app.include_router(data_exports_router, prefix=f"{API_PREFIX}")
 app.include_router(templates_router, prefix=f"{API_PREFIX}")
 app.include_router(graph_router, prefix=f"{API_PREFIX}")
 app.include_router(jarvis_router)  # Jarvis integration - includes /api/jarvis prefix
@@ -435,9 +469,16 @@ app.include_router(system_router, prefix=API_PREFIX)
 app.include_router(metrics_router)  # no prefix – Prometheus expects /metrics
 app.include_router(ops_router, prefix=f"{API_PREFIX}")
 app.include_router(ops_beacon_router, prefix=f"{API_PREFIX}")  # Public beacon (no auth)
+app.include_router(ops_status_router, prefix=f"{API_PREFIX}")  # Status page (any signed-in user)
 app.include_router(agent_config_router, prefix=f"{API_PREFIX}")
 app.include_router(agent_connectors_router, prefix=f"{API_PREFIX}")  # Agent connector credentials
+app.include_router(agent_tools_router, prefix=f"{API_PREFIX}")  # Tool playground
+app.include_router(agent_tests_router, prefix=f"{API_PREFIX}")  # Prompt test cases
+app.include_router(agent_knowledge_router, prefix=f"{API_PREFIX}")  # Knowledge base documents
 app.include_router(account_connectors_router, prefix=f"{API_PREFIX}")  # Account-level connector credentials
+app.include_router(env_vars_router, prefix=f"{API_PREFIX}")  # Environment variables and secrets
+app.include_router(tags_router, prefix=f"{API_PREFIX}")  # Agent and workflow tags
+app.include_router(api_credentials_router, prefix=f"{API_PREFIX}")  # API keys for HTTP nodes and MCP servers
 app.include_router(funnel_router, prefix=f"{API_PREFIX}")  # Funnel tracking
 
 # ---------------------------------------------------------------------------
@@ -47,27 +47,47 @@ from zerg.models.models import ThreadMessage as ThreadMessageModel
 
 # Static connector protocols for system prompt
 from zerg.prompts.connector_protocols import get_connector_protocols
+from zerg.services.knowledge_base import build_knowledge_context
 from zerg.services.thread_service import ThreadService
 
 logger = logging.getLogger(__name__)
 
 # ---------------------------------------------------------------------------
 # Local in-memory cache for compiled LangGraph runnables.
-# Keyed by (agent_id, agent_updated_at, stream_flag) so that any edit to the
-# agent definition automatically busts the cache.  The cache is deliberately
+# Keyed by (agent_id, agent_updated_at, stream_flag, model) so that any edit
+# to the agent definition automatically busts the cache.  The cache is deliberately
 # **process-local** – workers in a multi-process Gunicorn deployment will each
 # compile their own runnable once on first use which is acceptable given the
 # small cost (~100 ms).
 # ---------------------------------------------------------------------------
 
-_RUNNABLE_CACHE: Dict[Tuple[int, str, bool], Any] = {}
+_RUNNABLE_CACHE: Dict[Tuple[int, str, bool, str], Any] = {}
+
+
+class _ModelOverride:
+    """Read-only view of an agent row that reports a different ``model``."""
+
+    def __init__(self, agent_row: AgentModel, model: str):
+        self._agent_row = agent_row
+        self.model = model
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self._agent_row, name)
 
 
 class AgentRunner:  # noqa: D401 – naming follows project conventions
     """Run one agent turn (async)."""
 
-    def __init__(self, agent_row: AgentModel, *, thread_service: ThreadService | None = None):
+    def __init__(
+        self,
+        agent_row: AgentModel,
+        *,
+        thread_service: ThreadService | None = None,
+        model_override: str | None = None,
+    ):
+        """``model_override`` runs the agent on another model without saving it (model comparison)."""
         self.agent = agent_row
+        self.model = model_override or agent_row.model
         self.thread_service = thread_service or ThreadService
         # Aggregated usage for the last run (provider metadata only)
         self.usage_prompt_tokens: int | None = None
@@ -96,13 +116,14 @@ class AgentRunner:  # noqa: D401 – naming follows project conventions
         # ------------------------------------------------------------------
 
         updated_at_str = agent_row.updated_at.isoformat() if getattr(agent_row, "updated_at", None) else "0"
-        cache_key = (agent_row.id, updated_at_str, self.enable_token_stream)
+        cache_key = (agent_row.id, updated_at_str, self.enable_token_stream, self.model)
 
         if cache_key in _RUNNABLE_CACHE:
             self._runnable = _RUNNABLE_CACHE[cache_key]
             logger.debug("AgentRunner: using cached runnable for agent %s", agent_row.id)
         else:
-            self._runnable = zerg_react_agent.get_runnable(agent_row)
+            definition = agent_row if self.model == agent_row.model else _ModelOverride(agent_row, self.model)
+            self._runnable = zerg_react_agent.get_runnable(definition)
             _RUNNABLE_CACHE[cache_key] = self._runnable
             logger.debug("AgentRunner: compiled & cached runnable for agent %s", agent_row.id)
 
@@ -186,6 +207,31 @@ class AgentRunner:  # noqa: D401 – naming follows project conventions
                 exc_info=True,
             )
 
+        # ------------------------------------------------------------------
+        # Knowledge base: excerpts matching the latest user message, injected
+        # the same ephemeral way as the connector context (never saved)
+        # ------------------------------------------------------------------
+        latest_user_text = next(
+            (str(msg.content) for msg in reversed(original_msgs) if getattr(msg, "type", None) == "human"),
+            "",
+        )
+        try:
+            knowledge_text = build_knowledge_context(db, self.agent.id, latest_user_text)
+            if knowledge_text:
+                from langchain_core.messages import AIMessage as LCAIMessage
+                from langchain_core.messages import HumanMessage
+
+                knowledge_injection = [
+                    HumanMessage(content=knowledge_text),
+                    LCAIMessage(content="Understood. I'll use these excerpts where they help."),
+                ]
+                has_system = original_msgs and getattr(original_msgs[0], "type", None) == "system"
+                insert_at = 1 if has_system else 0
+                original_msgs = original_msgs[:insert_at] + knowledge_injection + original_msgs[insert_at:]
+                logger.debug("[AgentRunner] Injected knowledge base excerpts for agent %s", self.agent.id)
+        except Exception as e:
+            logger.warning("[AgentRunner] Failed to inject knowledge base excerpts: %s", e, exc_info=True)
+
         unprocessed_rows = crud.get_unprocessed_messages(db, thread.id)
         logger.info(f"[AgentRunner] Found {len(unprocessed_rows)} unprocessed messages")
 
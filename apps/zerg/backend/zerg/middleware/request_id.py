@@ -0,0 +1,102 @@
+"""Per-request trace IDs shared by API responses, WebSocket events and logs.
+
+Every HTTP request gets a short *trace id* – taken from an incoming
+``X-Request-ID`` header when a proxy already assigned one, otherwise freshly
+generated.  The id lives in a ``contextvars.ContextVar`` so anything running
+on behalf of the request (including background tasks spawned from it, which
+copy the context) can stamp it onto WebSocket envelopes, ``AgentRun`` rows
+and log records.  It is echoed back in the ``X-Request-ID`` response header
+so the UI can show users a single id to hand to support.
+
+WebSocket connections are long-lived, so the dispatcher binds a new trace id
+per inbound message instead of reusing the handshake's.
+"""
+
+from __future__ import annotations
+
+import contextvars
+import logging
+import re
+import secrets
+from typing import Optional
+
+from starlette.types import ASGIApp
+from starlette.types import Message
+from starlette.types import Receive
+from starlette.types import Scope
+from starlette.types import Send
+
+TRACE_ID_HEADER = "X-Request-ID"
+
+# Accept ids from upstream proxies only when they look like ids; anything
+# else is replaced so arbitrary header content never reaches logs or the UI.
+_VALID_TRACE_ID = re.compile(r"^[A-Za-z0-9._-]{8,64}$")
+
+current_trace_id: contextvars.ContextVar[Optional[str]] = contextvars.ContextVar("current_trace_id", default=None)
+
+
+def new_trace_id() -> str:
+    return secrets.token_hex(8)
+
+
+def get_trace_id() -> Optional[str]:
+    return current_trace_id.get()
+
+
+def bind_trace_id(trace_id: Optional[str] = None) -> contextvars.Token:
+    """Set the trace id for the current context and return the reset token."""
+
+    return current_trace_id.set(trace_id or new_trace_id())
+
+
+def reset_trace_id(token: contextvars.Token) -> None:
+    current_trace_id.reset(token)
+
+
+def _incoming_trace_id(scope: Scope) -> Optional[str]:
+    header_name = TRACE_ID_HEADER.lower().encode()
+    for raw_name, raw_value in scope.get("headers", []):
+        if raw_name.lower() == header_name:
+            value = raw_value.decode("latin-1").strip()
+            return value if _VALID_TRACE_ID.match(value) else None
+    return None
+
+
+class RequestIDMiddleware:  # noqa: D401 – ASGI middleware
+    """Bind a trace id to every HTTP request and echo it in the response."""
+
+    def __init__(self, app: ASGIApp) -> None:  # noqa: D401 – ASGI signature
+        self.app = app
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:  # noqa: D401 – ASGI
+        if scope.get("type") != "http":
+            await self.app(scope, receive, send)
+            return
+
+        trace_id = _incoming_trace_id(scope) or new_trace_id()
+        # Also expose it on request.state: the catch-all 500 handler runs in
+        # ServerErrorMiddleware, outside this middleware's context.
+        scope.setdefault("state", {})["trace_id"] = trace_id
+
+        async def send_with_trace_id(message: Message) -> None:
+            if message["type"] == "http.response.start":
+                headers = list(message.get("headers", []))
+                headers.append((TRACE_ID_HEADER.lower().encode(), trace_id.encode()))
+                message = {**message, "headers": headers}
+            await send(message)
+
+        token = bind_trace_id(trace_id)
+        try:
+            await self.app(scope, receive, send_with_trace_id)
+        finally:
+            reset_trace_id(token)
+
+
+class TraceIdLogFilter(logging.Filter):
+    """Expose the current trace id to log formatters as ``%(trace)s``."""
+
+    def filter(self, record: logging.LogRecord) -> bool:  # noqa: D401 – logging API
+        trace_id = current_trace_id.get()
+        record.trace_id = trace_id
+        record.trace = f"[{trace_id}] " if trace_id else ""
+        return True
@@ -5,6 +5,7 @@ from .models import AgentMessage
 from .models import Thread
 from .models import ThreadMessage
 from .models import Trigger
+from .models import TriggerDelivery
 from .sync import SyncOperation
 from .trigger_config import TriggerConfig
 
@@ -15,5 +16,6 @@ __all__ = [
     "Thread",
     "ThreadMessage",
     "Trigger",
+    "TriggerDelivery",
     "TriggerConfig",
 ]
@@ -17,6 +17,32 @@ class UserRole(str, Enum):
     ADMIN = "ADMIN"
 
 
+class SharePermission(str, Enum):
+    """Access granted on someone else's agent; each level includes the ones before it."""
+
+    VIEW = "view"
+    RUN = "run"
+    EDIT = "edit"
+
+
+class AgentTestAssertionType(str, Enum):
+    """How an agent test case checks the reply."""
+
+    CONTAINS = "contains"
+    NOT_CONTAINS = "not_contains"
+    EQUALS = "equals"
+    REGEX = "regex"
+    JSON_PATH = "json_path"
+
+
+class KnowledgeDocumentStatus(str, Enum):
+    """Indexing state of an agent knowledge-base document."""
+
+    INDEXING = "indexing"
+    READY = "ready"
+    FAILED = "failed"
+
+
 class AgentStatus(str, Enum):
     IDLE = "idle"
     RUNNING = "running"
@@ -27,6 +53,8 @@ class AgentStatus(str, Enum):
 class RunStatus(str, Enum):
     QUEUED = "queued"
     RUNNING = "running"
+    # Cancel requested; the run stops at its next await point
+    CANCELLING = "cancelling"
     SUCCESS = "success"
     FAILED = "failed"
     CANCELLED = "cancelled"
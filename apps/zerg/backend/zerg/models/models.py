@@ -154,6 +154,13 @@ class Agent(Base):
     schedule = Column(String, nullable=True)  # CRON expression or interval
     model = Column(String, nullable=False)  # Model to use (no default)
     config = Column(MutableDict.as_mutable(JSON), nullable=True)  # Additional configuration as JSON
+    # Values for ``{{name}}`` placeholders in the system/task instructions
+    prompt_variables = Column(MutableDict.as_mutable(JSON), nullable=True)
+    # Markdown README for people: what the agent does, its inputs, caveats.
+    # Never sent to the model.
+    description = Column(Text, nullable=True)
+    # Free-form labels for organising agents (see zerg.services.tags)
+    tags = Column(MutableList.as_mutable(JSON), nullable=True)
 
     # -------------------------------------------------------------------
     # Tool allowlist – controls which tools this agent can use
@@ -185,6 +192,13 @@ class Agent(Base):
     # service will run the agent.  A NULL / empty schedule means "disabled".
     # --------------------------------------------------
     last_error = Column(Text, nullable=True)  # Store the last error message
+    # Soft delete – set when the agent is moved to the trash.  Trashed agents
+    # are hidden from listings and unscheduled, and purged after
+    # ``AGENT_TRASH_RETENTION_DAYS``.
+    deleted_at = Column(DateTime, nullable=True, index=True)
+    # Set while the agent is paused – scheduled runs and trigger firings are
+    # skipped, manual runs still go through.
+    paused_at = Column(DateTime, nullable=True)
 
     # Define relationship with AgentMessage
     messages = relationship("AgentMessage", back_populates="agent", cascade="all, delete-orphan")
@@ -193,6 +207,118 @@ class Agent(Base):
 
     # Relationship to execution runs (added in the *Run History* feature).
     runs = relationship("AgentRun", back_populates="agent", cascade="all, delete-orphan")
+    shares = relationship("AgentShare", back_populates="agent", cascade="all, delete-orphan")
+    instruction_versions = relationship(
+        "AgentInstructionVersion",
+        back_populates="agent",
+        cascade="all, delete-orphan",
+        order_by="AgentInstructionVersion.version",
+    )
+    test_cases = relationship(
+        "AgentTestCase",
+        back_populates="agent",
+        cascade="all, delete-orphan",
+        order_by="AgentTestCase.id",
+    )
+    knowledge_documents = relationship(
+        "AgentKnowledgeDocument",
+        back_populates="agent",
+        cascade="all, delete-orphan",
+        order_by="AgentKnowledgeDocument.id",
+    )
+
+
+class AgentInstructionVersion(Base):
+    """Snapshot of an agent's system/task instructions, one per save that changed them.
+
+    ``version`` counts up per agent from 1.  Rolling back never rewrites
+    history: it records a new version with ``restored_from_version`` set.
+    """
+
+    __tablename__ = "agent_instruction_versions"
+    __table_args__ = (UniqueConstraint("agent_id", "version", name="uix_agent_instruction_version"),)
+
+    id = Column(Integer, primary_key=True, index=True)
+    agent_id = Column(Integer, ForeignKey("agents.id", ondelete="CASCADE"), nullable=False, index=True)
+    version = Column(Integer, nullable=False)
+    system_instructions = Column(Text, nullable=False)
+    task_instructions = Column(Text, nullable=False)
+    author_id = Column(Integer, ForeignKey("users.id", ondelete="SET NULL"), nullable=True)
+    restored_from_version = Column(Integer, nullable=True)
+    created_at = Column(DateTime, server_default=func.now())
+
+    agent = relationship("Agent", back_populates="instruction_versions")
+    author = relationship("User")
+
+
+class AgentTestCase(Base):
+    """A saved prompt check: send ``input`` to the agent and assert on the reply.
+
+    ``assertions`` is a list of ``{"type", "value", "path"}`` dicts (see
+    :class:`zerg.models.enums.AgentTestAssertionType`).  ``last_result`` keeps the
+    outcome of the most recent run so the UI can show it after a reload.
+    """
+
+    __tablename__ = "agent_test_cases"
+
+    id = Column(Integer, primary_key=True, index=True)
+    agent_id = Column(Integer, ForeignKey("agents.id", ondelete="CASCADE"), nullable=False, index=True)
+    name = Column(String, nullable=False)
+    input = Column(Text, nullable=False)
+    assertions = Column(MutableList.as_mutable(JSON), nullable=False, default=list)
+    last_result = Column(JSON, nullable=True)
+    last_run_at = Column(DateTime, nullable=True)
+    created_at = Column(DateTime, server_default=func.now())
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
+
+    agent = relationship("Agent", back_populates="test_cases")
+
+
+class AgentKnowledgeDocument(Base):
+    """A document in an agent's knowledge base, uploaded or fetched from ``url``.
+
+    The text is kept only as ``chunks`` (see :mod:`zerg.services.knowledge_base`);
+    ``status`` moves from ``indexing`` to ``ready`` or ``failed`` once the
+    background task is done.
+    """
+
+    __tablename__ = "agent_knowledge_documents"
+
+    id = Column(Integer, primary_key=True, index=True)
+    agent_id = Column(Integer, ForeignKey("agents.id", ondelete="CASCADE"), nullable=False, index=True)
+    name = Column(String(255), nullable=False)
+    # Set for linked pages; uploads have none
+    url = Column(Text, nullable=True)
+    content_type = Column(String(100), nullable=True)
+    size_bytes = Column(Integer, nullable=True)
+    status = Column(String(20), nullable=False, default="indexing")  # indexing, ready, failed
+    error = Column(Text, nullable=True)
+    chunks = Column(JSON, nullable=True)
+    chunk_count = Column(Integer, nullable=False, default=0)
+    created_at = Column(DateTime, server_default=func.now())
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
+
+    agent = relationship("Agent", back_populates="knowledge_documents")
+
+
+class AgentShare(Base):
+    """Access to an agent granted by its owner.
+
+    ``user_id`` NULL shares with the whole workspace; a per-user grant takes
+    precedence over the workspace one.
+    """
+
+    __tablename__ = "agent_shares"
+    __table_args__ = (UniqueConstraint("agent_id", "user_id", name="uix_agent_share_user"),)
+
+    id = Column(Integer, primary_key=True, index=True)
+    agent_id = Column(Integer, ForeignKey("agents.id", ondelete="CASCADE"), nullable=False, index=True)
+    user_id = Column(Integer, ForeignKey("users.id"), nullable=True, index=True)
+    permission = Column(String, nullable=False)
+    created_at = Column(DateTime, server_default=func.now())
+
+    agent = relationship("Agent", back_populates="shares")
+    user = relationship("User")
 
 
 # ---------------------------------------------------------------------------
@@ -289,6 +415,13 @@ class Thread(Base):
         default=ThreadType.CHAT.value,
         nullable=False,
     )  # Types: chat, scheduled, manual
+    # Sidebar organisation – pinned threads sort first, archived ones are tucked away
+    pinned = Column(Boolean, default=False, nullable=False)
+    archived = Column(Boolean, default=False, nullable=False)
+    # Runs this thread on another model than the agent's; NULL = agent default
+    model_override = Column(String, nullable=True)
+    # Set while an agent reply is being generated; only one runs per thread at a time
+    reply_started_at = Column(DateTime, nullable=True)
     created_at = Column(DateTime, server_default=func.now())
     updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
 
@@ -360,6 +493,37 @@ class Trigger(Base):
     agent = relationship("Agent", backref="triggers")
 
 
+class TriggerDelivery(Base):
+    """One firing of a trigger (webhook call, Slack or GitHub event, matching
+    email), kept so users can debug their senders.
+
+    Rows are written for accepted *and* rejected deliveries (bad signature,
+    stale timestamp); test fires from the UI are flagged with ``is_test``.
+    """
+
+    __tablename__ = "trigger_deliveries"
+
+    id = Column(Integer, primary_key=True, index=True)
+    trigger_id = Column(Integer, ForeignKey("triggers.id", ondelete="CASCADE"), nullable=False, index=True)
+    status_code = Column(Integer, nullable=False)
+    error = Column(String(255), nullable=True)
+    is_test = Column(Boolean, nullable=False, default=False)
+    payload_bytes = Column(Integer, nullable=True)
+    received_at = Column(DateTime, server_default=func.now(), nullable=False)
+    # What the event matched (filter, Slack event, GitHub action) and the run
+    # it started; both empty for rejected deliveries
+    matched = Column(String(255), nullable=True)
+    run_id = Column(Integer, ForeignKey("agent_runs.id", ondelete="SET NULL"), nullable=True)
+    # Sender's own id for the delivery (GitHub's X-GitHub-Delivery), so redeliveries can be spotted
+    external_id = Column(String(64), nullable=True)
+
+    run = relationship("AgentRun")
+
+    @property
+    def run_status(self):
+        return getattr(self.run.status, "value", self.run.status) if self.run is not None else None
+
+
 class ThreadMessage(Base):
     __tablename__ = "thread_messages"
 
@@ -375,9 +539,12 @@ class ThreadMessage(Base):
     processed = Column(Boolean, default=False, nullable=False)  # Track if message has been processed by agent
     message_metadata = Column(MutableDict.as_mutable(JSON), nullable=True)  # Store additional metadata
     parent_id = Column(Integer, ForeignKey("thread_messages.id"), nullable=True)
+    # User who wrote a "user" message; NULL for agent output and older rows
+    author_id = Column(Integer, ForeignKey("users.id", ondelete="SET NULL"), nullable=True, index=True)
 
     # Define relationship with Thread
     thread = relationship("Thread", back_populates="messages")
+    author = relationship("User", lazy="joined")
 
 
 # ---------------------------------------------------------------------------
@@ -401,7 +568,8 @@ class AgentRun(Base):
 
     # Foreign keys -------------------------------------------------------
     agent_id = Column(Integer, ForeignKey("agents.id"), nullable=False)
-    thread_id = Column(Integer, ForeignKey("agent_threads.id"), nullable=False)
+    # Empty while the run is queued; its thread is created when it starts
+    thread_id = Column(Integer, ForeignKey("agent_threads.id"), nullable=True)
 
     # Lifecycle ----------------------------------------------------------
     status = Column(
@@ -414,6 +582,8 @@ class AgentRun(Base):
         default=RunTrigger.MANUAL.value,
         nullable=False,
     )  # manual / schedule / api
+    # Place in the agent's run queue (1 = next); set only while status is queued
+    queue_position = Column(Integer, nullable=True)
 
     # Timing -------------------------------------------------------------
     started_at = Column(DateTime, nullable=True)
@@ -428,10 +598,20 @@ class AgentRun(Base):
     error = Column(Text, nullable=True)
     cancel_reason = Column(Text, nullable=True)
 
+    # Tracing ------------------------------------------------------------
+    # Trace id of the request (or scheduler tick) that started the run
+    trace_id = Column(String(64), nullable=True, index=True)
+
     # Summary ------------------------------------------------------------
     # Brief summary of the run for Jarvis Task Inbox (first assistant response or truncated output)
     summary = Column(Text, nullable=True)
 
+    # Structured output --------------------------------------------------
+    # Final reply parsed as JSON; set only when the agent has an output schema
+    output = Column(JSON, nullable=True)
+    # Schema violations of the final reply ([] = valid, NULL = not checked)
+    schema_errors = Column(JSON, nullable=True)
+
     # Timestamps ---------------------------------------------------------
     # Note: nullable=True for SQLite compatibility with existing tables
     # New rows will have defaults, existing rows backfilled by migration
@@ -456,12 +636,42 @@ class Workflow(Base):
     name = Column(String, nullable=False)
     description = Column(Text, nullable=True)
     canvas = Column(MutableDict.as_mutable(JSON), nullable=False)
+    # Free-form labels, shared with agent tags (see zerg.services.tags)
+    tags = Column(MutableList.as_mutable(JSON), nullable=True)
     is_active = Column(Boolean, default=True, nullable=False)
+    # Set along with ``is_active = False`` when the workflow is moved to the
+    # trash; purged after ``WORKFLOW_TRASH_RETENTION_DAYS``.
+    deleted_at = Column(DateTime, nullable=True, index=True)
     created_at = Column(DateTime, server_default=func.now())
     updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
 
     # ORM relationship to User
     owner = relationship("User", backref="workflows")
+    comments = relationship("WorkflowNodeComment", back_populates="workflow", cascade="all, delete-orphan")
+
+
+class WorkflowNodeComment(Base):
+    """A comment on one canvas node.
+
+    Comments with no ``parent_id`` start a thread; replies point at the thread
+    root, and only the root carries ``resolved_at``.
+    """
+
+    __tablename__ = "workflow_node_comments"
+
+    id = Column(Integer, primary_key=True, index=True)
+    workflow_id = Column(Integer, ForeignKey("workflows.id", ondelete="CASCADE"), nullable=False, index=True)
+    node_id = Column(String, nullable=False, index=True)
+    parent_id = Column(Integer, ForeignKey("workflow_node_comments.id", ondelete="CASCADE"), nullable=True, index=True)
+    author_id = Column(Integer, ForeignKey("users.id"), nullable=False)
+    body = Column(Text, nullable=False)
+    mentioned_user_ids = Column(JSON, nullable=True, default=lambda: [])
+    resolved_at = Column(DateTime, nullable=True)
+    resolved_by_id = Column(Integer, ForeignKey("users.id"), nullable=True)
+    created_at = Column(DateTime, server_default=func.now())
+
+    workflow = relationship("Workflow", back_populates="comments")
+    author = relationship("User", foreign_keys=[author_id])
 
 
 class WorkflowTemplate(Base):
@@ -481,6 +691,24 @@ class WorkflowTemplate(Base):
 
     # ORM relationships
     creator = relationship("User", backref="created_templates")
+    feedback = relationship("WorkflowTemplateFeedback", cascade="all, delete-orphan", back_populates="template")
+
+
+class WorkflowTemplateFeedback(Base):
+    """A user's rating (1-5) and/or favorite mark on a gallery template."""
+
+    __tablename__ = "workflow_template_feedback"
+    __table_args__ = (UniqueConstraint("template_id", "user_id", name="uix_template_feedback_user"),)
+
+    id = Column(Integer, primary_key=True, index=True)
+    template_id = Column(Integer, ForeignKey("workflow_templates.id", ondelete="CASCADE"), nullable=False, index=True)
+    user_id = Column(Integer, ForeignKey("users.id"), nullable=False, index=True)
+    rating = Column(Integer, nullable=True)
+    is_favorite = Column(Boolean, nullable=False, default=False)
+    created_at = Column(DateTime, server_default=func.now())
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
+
+    template = relationship("WorkflowTemplate", back_populates="feedback")
 
 
 class WorkflowExecution(Base):
@@ -513,6 +741,21 @@ class WorkflowExecution(Base):
     created_at = Column(DateTime, server_default=func.now())
     updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
 
+    # Set when a Call Workflow node started this execution
+    parent_execution_id = Column(
+        Integer, ForeignKey("workflow_executions.id", ondelete="SET NULL"), nullable=True, index=True
+    )
+    parent_node_id = Column(String, nullable=True)
+
+    # Run inputs, readable by nodes as ${input.value.<name>}
+    inputs = Column(JSON, nullable=True)
+
+    # Set on runs started from a node: upstream outputs were reused from the source execution
+    source_execution_id = Column(
+        Integer, ForeignKey("workflow_executions.id", ondelete="SET NULL"), nullable=True, index=True
+    )
+    start_node_id = Column(String, nullable=True)
+
     # ORM relationships
     workflow = relationship("Workflow", backref="executions")
     node_states = relationship("NodeExecutionState", back_populates="workflow_execution", cascade="all, delete-orphan")
@@ -686,6 +929,72 @@ class AccountConnectorCredential(Base):
     owner = relationship("User", backref="account_connector_credentials")
 
 
+# ---------------------------------------------------------------------------
+# EnvironmentVariable – named values referenced as {{env.NAME}}
+# ---------------------------------------------------------------------------
+
+
+class EnvironmentVariable(Base):
+    """A named value nodes and agent instructions reference as ``{{env.NAME}}``.
+
+    Rows with no ``workflow_id`` apply workspace-wide (every workflow and agent
+    the owner has); a row scoped to a workflow overrides a workspace one of the
+    same name.  Secret values are encrypted at rest and never sent back to the
+    browser.
+    """
+
+    __tablename__ = "environment_variables"
+    __table_args__ = (UniqueConstraint("owner_id", "workflow_id", "name", name="uix_env_var_owner_scope_name"),)
+
+    id = Column(Integer, primary_key=True, index=True)
+    owner_id = Column(Integer, ForeignKey("users.id", ondelete="CASCADE"), nullable=False, index=True)
+    workflow_id = Column(Integer, ForeignKey("workflows.id", ondelete="CASCADE"), nullable=True, index=True)
+    name = Column(String(64), nullable=False)
+    # Plain text, or a Fernet token when is_secret
+    value = Column(Text, nullable=False)
+    is_secret = Column(Boolean, nullable=False, default=False)
+    description = Column(Text, nullable=True)
+    created_at = Column(DateTime, server_default=func.now(), nullable=False)
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now(), nullable=False)
+
+    owner = relationship("User", backref="environment_variables")
+    workflow = relationship("Workflow")
+
+
+# ---------------------------------------------------------------------------
+# ApiCredential – third-party REST API keys for HTTP nodes and MCP servers
+# ---------------------------------------------------------------------------
+
+
+class ApiCredential(Base):
+    """A named API key with the base URL and auth header it's sent with.
+
+    HTTP Request nodes and MCP server entries reference a credential by id, so
+    rotating the key here updates every node and server that uses it.
+    """
+
+    __tablename__ = "api_credentials"
+    __table_args__ = (UniqueConstraint("owner_id", "name", name="uix_api_credential_owner_name"),)
+
+    id = Column(Integer, primary_key=True, index=True)
+    owner_id = Column(Integer, ForeignKey("users.id", ondelete="CASCADE"), nullable=False, index=True)
+    name = Column(String(100), nullable=False)
+    base_url = Column(String(2048), nullable=False)
+    # "Header-Name: value", where {{key}} marks the key, e.g. "Authorization: Bearer {{key}}"
+    auth_header = Column(String(255), nullable=False)
+    # Fernet token of the key
+    encrypted_key = Column(Text, nullable=False)
+    # Last four characters, shown masked
+    key_hint = Column(String(8), nullable=False)
+
+    test_status = Column(String(20), nullable=False, default="untested")
+    last_tested_at = Column(DateTime, nullable=True)
+    created_at = Column(DateTime, server_default=func.now(), nullable=False)
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now(), nullable=False)
+
+    owner = relationship("User", backref="api_credentials")
+
+
 # ---------------------------------------------------------------------------
 # Worker Jobs – Background task execution for supervisor agents
 # ---------------------------------------------------------------------------
@@ -728,3 +1037,47 @@ class WorkerJob(Base):
 
     # Relationships
     owner = relationship("User", backref="worker_jobs")
+
+
+# ---------------------------------------------------------------------------
+# DataExport – self-service export of everything a user owns
+# ---------------------------------------------------------------------------
+
+
+class DataExport(Base):
+    """Archive of everything a user owns apart from credentials and secrets.
+
+    Rows are created by ``POST /users/me/exports`` and filled in by a
+    background task; the archive itself lives on disk under ``data/exports``.
+    """
+
+    __tablename__ = "data_exports"
+
+    id = Column(Integer, primary_key=True, index=True)
+    owner_id = Column(Integer, ForeignKey("users.id"), nullable=False, index=True)
+
+    status = Column(String(20), nullable=False, default="queued")  # queued, running, success, failed
+    file_name = Column(String(255), nullable=True)  # Set once the archive has been written
+    size_bytes = Column(Integer, nullable=True)
+    error = Column(Text, nullable=True)
+
+    created_at = Column(DateTime, server_default=func.now(), nullable=False)
+    finished_at = Column(DateTime, nullable=True)
+
+    owner = relationship("User", backref="data_exports")
+
+
+# ---------------------------------------------------------------------------
+# SystemSetting – deployment-wide switches toggled at runtime by admins
+# ---------------------------------------------------------------------------
+
+
+class SystemSetting(Base):
+    """Key/value row for settings that must survive restarts (e.g. maintenance mode)."""
+
+    __tablename__ = "system_settings"
+
+    key = Column(String(64), primary_key=True)
+    value = Column(JSON, nullable=True)
+    updated_by = Column(Integer, ForeignKey("users.id"), nullable=True)
+    updated_at = Column(DateTime, server_default=func.now(), onupdate=func.now())
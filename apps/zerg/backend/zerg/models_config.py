@@ -31,6 +31,7 @@ class ModelConfig:
         is_default: bool = False,
         tier: Optional[str] = None,
         description: Optional[str] = None,
+        default_params: Optional[Dict] = None,
     ):
         self.id = id
         self.display_name = display_name
@@ -38,6 +39,8 @@ class ModelConfig:
         self.is_default = is_default
         self.tier = tier
         self.description = description
+        # Generation parameters used when the agent doesn't override them
+        self.default_params = default_params or {}
 
     def to_dict(self) -> Dict:
         """Convert to dictionary for API responses"""
@@ -46,6 +49,7 @@ class ModelConfig:
             "display_name": self.display_name,
             "provider": self.provider,
             "is_default": self.is_default,
+            "default_params": self.default_params,
         }
 
 
@@ -220,6 +224,9 @@ def _build_models_cache() -> None:
     _DEFAULT_WORKER_MODEL_ID = _TIERS[_DEFAULTS["worker"]]  # type: ignore[index]
     _TEST_MODEL_ID = _TIERS[_DEFAULTS["test"]]  # type: ignore[index]
 
+    # Text-wide defaults, refined per model under models.<id>.defaultParams
+    shared_params = _TEXT_CONFIG.get("defaultParams", {})  # type: ignore[union-attr]
+
     _AVAILABLE_MODELS = []
     for model_id, model_info in _MODELS.items():  # type: ignore[union-attr]
         provider = ModelProvider(model_info["provider"])
@@ -232,6 +239,7 @@ def _build_models_cache() -> None:
                 is_default=is_default,
                 tier=model_info.get("tier"),
                 description=model_info.get("description"),
+                default_params={**shared_params, **model_info.get("defaultParams", {})},
             )
         )
 
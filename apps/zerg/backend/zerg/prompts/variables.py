@@ -0,0 +1,67 @@
+"""``{{variable}}`` placeholders in agent system/task instructions.
+
+Agents store a flat ``name -> value`` mapping in ``Agent.prompt_variables``.
+Placeholders are substituted when the instructions are copied into a thread;
+references to variables that aren't defined are left untouched so a typo is
+visible in the transcript instead of silently becoming an empty string.
+"""
+
+import re
+from typing import Any
+from typing import Dict
+from typing import Iterable
+from typing import List
+from typing import Mapping
+from typing import Optional
+
+VARIABLE_PATTERN = re.compile(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}")
+_NAME_PATTERN = re.compile(r"[A-Za-z_][A-Za-z0-9_]*")
+
+MAX_PROMPT_VARIABLES = 50
+MAX_VARIABLE_VALUE_LENGTH = 10_000
+
+
+def find_template_variables(text: Optional[str]) -> List[str]:
+    """Return the distinct variable names referenced in *text*, in order."""
+
+    if not text:
+        return []
+    return list(dict.fromkeys(match.group(1) for match in VARIABLE_PATTERN.finditer(text)))
+
+
+def render_prompt_template(text: Optional[str], variables: Optional[Mapping[str, str]]) -> str:
+    """Substitute defined ``{{name}}`` placeholders in *text*."""
+
+    if not text:
+        return text or ""
+    if not variables:
+        return text
+    return VARIABLE_PATTERN.sub(lambda match: variables.get(match.group(1), match.group(0)), text)
+
+
+def undefined_template_variables(texts: Iterable[Optional[str]], variables: Optional[Mapping[str, str]]) -> List[str]:
+    """Names referenced in any of *texts* that have no value in *variables*."""
+
+    defined = variables or {}
+    referenced = dict.fromkeys(name for text in texts for name in find_template_variables(text))
+    return [name for name in referenced if name not in defined]
+
+
+def validate_prompt_variables(variables: Optional[Mapping[str, Any]]) -> Dict[str, str]:
+    """Return a clean copy of *variables* or raise ``ValueError``."""
+
+    if not variables:
+        return {}
+    if len(variables) > MAX_PROMPT_VARIABLES:
+        raise ValueError(f"At most {MAX_PROMPT_VARIABLES} prompt variables are allowed")
+
+    cleaned: Dict[str, str] = {}
+    for name, value in variables.items():
+        if not isinstance(name, str) or not _NAME_PATTERN.fullmatch(name):
+            raise ValueError(f"Invalid prompt variable name: {name!r}")
+        if not isinstance(value, str):
+            raise ValueError(f"Prompt variable {name!r} must be a string")
+        if len(value) > MAX_VARIABLE_VALUE_LENGTH:
+            raise ValueError(f"Prompt variable {name!r} is too long")
+        cleaned[name] = value
+    return cleaned
@@ -266,8 +266,13 @@ def test_configured_connector(
     cred.test_status = "success" if result["success"] else "failed"
     cred.last_tested_at = datetime.utcnow()
 
-    # Always update metadata: if test failed or returned no metadata, clear it.
-    cred.connector_metadata = result.get("metadata")
+    # Always update metadata: if test failed or returned no metadata, clear it –
+    # except what an OAuth install recorded (e.g. the Slack workspace id that
+    # Slack events are routed by).
+    metadata = result.get("metadata")
+    if (cred.connector_metadata or {}).get("connected_via") == "oauth":
+        metadata = {**cred.connector_metadata, **(metadata or {})}
+    cred.connector_metadata = metadata
 
     db.commit()
 
@@ -10,18 +10,23 @@ from fastapi import FastAPI as _FastAPI
 from fastapi import HTTPException
 from fastapi.responses import JSONResponse
 from pydantic import BaseModel
+from sqlalchemy.orm import Session
 
 # Centralised settings
 from zerg.config import get_settings
 
 # Database helpers
+from zerg.crud import crud
 from zerg.database import Base
+from zerg.database import get_db
 from zerg.database import get_session_factory
 
 # Auth dependency
 from zerg.dependencies.auth import get_current_user
 from zerg.dependencies.auth import require_admin
 from zerg.dependencies.auth import require_super_admin
+from zerg.schemas.schemas import WorkspaceDefaults
+from zerg.services.preferences import preference_errors
 
 router = APIRouter(
     prefix="/admin",
@@ -494,6 +499,49 @@ async def reset_database(request: DatabaseResetRequest, current_user=Depends(req
         return JSONResponse(status_code=500, content={"detail": f"Failed to reset database: {str(e)}"})
 
 
+class MaintenanceModeRequest(BaseModel):
+    enabled: bool
+
+
+class MaintenanceModeResponse(BaseModel):
+    """While enabled, scheduled runs and trigger firings are skipped for every agent."""
+
+    enabled: bool
+
+
+@router.get("/maintenance")
+def get_maintenance_mode(db: Session = Depends(get_db)) -> MaintenanceModeResponse:
+    return MaintenanceModeResponse(enabled=crud.is_maintenance_mode(db))
+
+
+@router.put("/maintenance")
+def set_maintenance_mode(
+    request: MaintenanceModeRequest,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+) -> MaintenanceModeResponse:
+    crud.set_system_setting(db, crud.MAINTENANCE_MODE_KEY, request.enabled, updated_by=current_user.id)
+    logger.warning(f"Maintenance mode {'enabled' if request.enabled else 'disabled'} by user {current_user.id}")
+    return MaintenanceModeResponse(enabled=request.enabled)
+
+
+@router.put("/workspace-defaults")
+def set_workspace_defaults(
+    request: WorkspaceDefaults,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+) -> WorkspaceDefaults:
+    """Replace the workspace defaults; unset fields fall back to the built-in defaults."""
+
+    values = request.model_dump(exclude_none=True)
+    errors = preference_errors(values)
+    if errors:
+        raise HTTPException(status_code=422, detail="; ".join(errors))
+    crud.set_system_setting(db, crud.WORKSPACE_DEFAULTS_KEY, values, updated_by=current_user.id)
+    logger.info(f"Workspace defaults updated by user {current_user.id}: {sorted(values)}")
+    return WorkspaceDefaults(**values)
+
+
 # ---------------------------------------------------------------------------
 # Backwards-compatibility route (no /api prefix) so legacy Playwright specs
 # that still call ``POST /admin/reset-database`` continue to work.  We simply
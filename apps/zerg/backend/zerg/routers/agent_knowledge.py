@@ -0,0 +1,152 @@
+"""Agent knowledge base API.
+
+Lets an agent's owner manage the documents the agent draws on:
+- Upload text files (plain text, Markdown, CSV, JSON, HTML)
+- Link a web page, fetched and indexed in the background
+- List documents with size and indexing status, and delete them
+
+Relevant excerpts are added to the agent's context on every turn.
+"""
+
+from __future__ import annotations
+
+import logging
+from datetime import datetime
+from typing import Any
+from typing import List
+from typing import Optional
+
+from fastapi import APIRouter
+from fastapi import BackgroundTasks
+from fastapi import Depends
+from fastapi import File
+from fastapi import HTTPException
+from fastapi import Path
+from fastapi import Response
+from fastapi import UploadFile
+from fastapi import status
+from pydantic import AnyHttpUrl
+from pydantic import BaseModel
+from pydantic import ConfigDict
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.models import Agent
+from zerg.services import knowledge_base
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(
+    prefix="/agents/{agent_id}/knowledge",
+    tags=["agent-knowledge"],
+)
+
+
+class KnowledgeLinkCreate(BaseModel):
+    url: AnyHttpUrl
+    # Defaults to the URL
+    name: Optional[str] = None
+
+
+class KnowledgeDocumentOut(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    agent_id: int
+    name: str
+    url: Optional[str] = None
+    content_type: Optional[str] = None
+    size_bytes: Optional[int] = None
+    status: str
+    error: Optional[str] = None
+    chunk_count: int
+    created_at: Optional[datetime] = None
+    updated_at: Optional[datetime] = None
+
+
+# ---------------------------------------------------------------------------
+# Helper functions
+# ---------------------------------------------------------------------------
+
+
+def _get_agent_or_404(db: Session, agent_id: int, current_user: Any) -> Agent:
+    """Get agent and verify ownership, raise 404 if not found/owned."""
+    agent = db.query(Agent).filter(Agent.id == agent_id, Agent.deleted_at.is_(None)).first()
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if not agent or (agent.owner_id != current_user.id and not is_admin):
+        raise HTTPException(status_code=404, detail="Agent not found")
+    return agent
+
+
+def _assert_has_room(db: Session, agent: Agent) -> None:
+    if len(crud.get_knowledge_documents(db, agent.id)) >= knowledge_base.MAX_DOCUMENTS_PER_AGENT:
+        raise HTTPException(
+            status_code=status.HTTP_409_CONFLICT,
+            detail=f"An agent can have at most {knowledge_base.MAX_DOCUMENTS_PER_AGENT} knowledge documents",
+        )
+
+
+# ---------------------------------------------------------------------------
+# Endpoints
+# ---------------------------------------------------------------------------
+
+
+@router.get("/", response_model=List[KnowledgeDocumentOut])
+def list_knowledge_documents(
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    return crud.get_knowledge_documents(db, agent.id)
+
+
+@router.post("/files", response_model=KnowledgeDocumentOut, status_code=status.HTTP_202_ACCEPTED)
+def upload_knowledge_file(
+    background_tasks: BackgroundTasks,
+    agent_id: int = Path(..., gt=0),
+    file: UploadFile = File(..., description="Text, Markdown, CSV, JSON or HTML document ≤5 MB"),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    """Store the document and index it in the background."""
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    _assert_has_room(db, agent)
+    name, content_type, raw = knowledge_base.read_upload(file)
+    document = crud.create_knowledge_document(db, agent.id, name=name, content_type=content_type, size_bytes=len(raw))
+    background_tasks.add_task(knowledge_base.run_knowledge_indexing, document.id, raw)
+    return document
+
+
+@router.post("/links", response_model=KnowledgeDocumentOut, status_code=status.HTTP_202_ACCEPTED)
+def add_knowledge_link(
+    payload: KnowledgeLinkCreate,
+    background_tasks: BackgroundTasks,
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    """Fetch the page in the background and index its text."""
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    _assert_has_room(db, agent)
+    url = str(payload.url)
+    document = crud.create_knowledge_document(db, agent.id, name=(payload.name or "").strip()[:255] or url, url=url)
+    background_tasks.add_task(knowledge_base.run_knowledge_indexing, document.id)
+    return document
+
+
+@router.delete("/{document_id}", status_code=status.HTTP_204_NO_CONTENT)
+def delete_knowledge_document(
+    agent_id: int = Path(..., gt=0),
+    document_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    document = crud.get_knowledge_document(db, agent.id, document_id)
+    if document is None:
+        raise HTTPException(status_code=404, detail="Document not found")
+    crud.delete_knowledge_document(db, document)
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
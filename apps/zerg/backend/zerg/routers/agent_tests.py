@@ -0,0 +1,212 @@
+"""Agent test harness API.
+
+Lets an agent's owner keep prompt checks next to the agent:
+- Save test cases (an input message plus assertions on the reply)
+- Run one case, or all of them in order, against the real agent
+- Read back each case's last result, including the reply it was judged on
+
+Runs count against the same daily run cap and agent budget as chat.
+"""
+
+from __future__ import annotations
+
+import logging
+from datetime import datetime
+from typing import Any
+from typing import List
+from typing import Optional
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Path
+from fastapi import Response
+from fastapi import status
+from pydantic import BaseModel
+from pydantic import ConfigDict
+from pydantic import Field
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.enums import AgentTestAssertionType
+from zerg.models.models import Agent
+from zerg.services import agent_tests
+from zerg.services.quota import assert_agent_within_budget
+from zerg.services.quota import assert_can_start_run
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(
+    prefix="/agents/{agent_id}/tests",
+    tags=["agent-tests"],
+)
+
+
+class AgentTestAssertion(BaseModel):
+    """One check on the reply; ``path`` is only used by ``json_path``."""
+
+    type: AgentTestAssertionType
+    value: str = ""
+    path: Optional[str] = None
+
+
+class AgentTestCaseCreate(BaseModel):
+    name: str = Field(..., min_length=1, max_length=200)
+    input: str = Field(..., min_length=1)
+    assertions: List[AgentTestAssertion] = Field(..., min_length=1)
+
+
+class AgentTestCaseUpdate(BaseModel):
+    name: Optional[str] = Field(None, min_length=1, max_length=200)
+    input: Optional[str] = Field(None, min_length=1)
+    assertions: Optional[List[AgentTestAssertion]] = Field(None, min_length=1)
+
+
+class AgentTestAssertionResult(AgentTestAssertion):
+    passed: bool
+    # The part of the reply the assertion looked at, for the diff view
+    actual: Optional[str] = None
+    message: str
+
+
+class AgentTestResult(BaseModel):
+    passed: bool
+    output: str
+    # Set when the agent failed to run; assertions are then not evaluated
+    error: Optional[str] = None
+    assertions: List[AgentTestAssertionResult]
+    duration_ms: int
+    ran_at: datetime
+
+
+class AgentTestCaseOut(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    agent_id: int
+    name: str
+    input: str
+    assertions: List[AgentTestAssertion]
+    last_result: Optional[AgentTestResult] = None
+    last_run_at: Optional[datetime] = None
+    created_at: Optional[datetime] = None
+    updated_at: Optional[datetime] = None
+
+
+# ---------------------------------------------------------------------------
+# Helper functions
+# ---------------------------------------------------------------------------
+
+
+def _get_agent_or_404(db: Session, agent_id: int, current_user: Any) -> Agent:
+    """Get agent and verify ownership, raise 404 if not found/owned."""
+    agent = db.query(Agent).filter(Agent.id == agent_id, Agent.deleted_at.is_(None)).first()
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if not agent or (agent.owner_id != current_user.id and not is_admin):
+        raise HTTPException(status_code=404, detail="Agent not found")
+    return agent
+
+
+def _get_case_or_404(db: Session, agent: Agent, case_id: int):
+    case = crud.get_agent_test_case(db, agent.id, case_id)
+    if case is None:
+        raise HTTPException(status_code=404, detail="Test case not found")
+    return case
+
+
+def _assert_can_run(db: Session, agent: Agent, current_user: Any) -> None:
+    assert_can_start_run(db, user=current_user)
+    assert_agent_within_budget(db, agent)
+
+
+# ---------------------------------------------------------------------------
+# Endpoints
+# ---------------------------------------------------------------------------
+
+
+@router.get("/", response_model=List[AgentTestCaseOut])
+def list_agent_tests(
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    return crud.get_agent_test_cases(db, agent.id)
+
+
+@router.post("/", response_model=AgentTestCaseOut, status_code=status.HTTP_201_CREATED)
+def create_agent_test(
+    payload: AgentTestCaseCreate,
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    return crud.create_agent_test_case(
+        db,
+        agent.id,
+        name=payload.name.strip(),
+        input=payload.input,
+        assertions=[assertion.model_dump(mode="json") for assertion in payload.assertions],
+    )
+
+
+@router.put("/{case_id}", response_model=AgentTestCaseOut)
+def update_agent_test(
+    payload: AgentTestCaseUpdate,
+    agent_id: int = Path(..., gt=0),
+    case_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    case = _get_case_or_404(db, agent, case_id)
+    assertions = payload.assertions
+    return crud.update_agent_test_case(
+        db,
+        case,
+        name=payload.name.strip() if payload.name is not None else None,
+        input=payload.input,
+        assertions=[assertion.model_dump(mode="json") for assertion in assertions] if assertions is not None else None,
+    )
+
+
+@router.delete("/{case_id}", status_code=status.HTTP_204_NO_CONTENT)
+def delete_agent_test(
+    agent_id: int = Path(..., gt=0),
+    case_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    crud.delete_agent_test_case(db, _get_case_or_404(db, agent, case_id))
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+
+@router.post("/run", response_model=List[AgentTestCaseOut])
+async def run_all_agent_tests(
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    """Run every case one after another and return them with fresh results."""
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    cases = crud.get_agent_test_cases(db, agent.id)
+    if cases:
+        _assert_can_run(db, agent, current_user)
+    return [await agent_tests.run_test_case(db, agent, case) for case in cases]
+
+
+@router.post("/{case_id}/run", response_model=AgentTestCaseOut)
+async def run_agent_test(
+    agent_id: int = Path(..., gt=0),
+    case_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+):
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    case = _get_case_or_404(db, agent, case_id)
+    _assert_can_run(db, agent, current_user)
+    return await agent_tests.run_test_case(db, agent, case)
@@ -0,0 +1,187 @@
+"""Agent tool playground API.
+
+Lets an agent's owner call one of its tools directly with hand-written
+arguments, without running the agent:
+- List the tools the agent may use, with their parameter JSON schemas
+- Invoke a single tool and return its raw result
+
+Tools run with the same credential context the AgentRunner provides, so
+connector tools see the agent's configured credentials.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import time
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Path
+from fastapi import status
+from fastapi.encoders import jsonable_encoder
+from langchain_core.tools import BaseTool
+from pydantic import BaseModel
+from pydantic import Field
+from pydantic import ValidationError
+from sqlalchemy.orm import Session
+
+from zerg.connectors.context import reset_credential_resolver
+from zerg.connectors.context import set_credential_resolver
+from zerg.connectors.resolver import CredentialResolver
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.models import Agent
+from zerg.tools.result_utils import check_tool_error
+from zerg.tools.unified_access import get_tool_resolver
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(
+    prefix="/agents/{agent_id}/tools",
+    tags=["agent-tools"],
+)
+
+
+class ToolDefinitionResponse(BaseModel):
+    """A tool the agent may call, with the JSON schema of its arguments."""
+
+    name: str
+    description: str
+    parameters: Dict[str, Any]
+
+
+class ToolInvokeRequest(BaseModel):
+    """Arguments to pass to the tool, keyed by parameter name."""
+
+    args: Dict[str, Any] = Field(default_factory=dict)
+
+
+class ToolInvokeResponse(BaseModel):
+    """Outcome of a single playground invocation."""
+
+    tool: str
+    ok: bool
+    result: Any = None
+    raw: str
+    error: Optional[str] = None
+    duration_ms: int
+
+
+# ---------------------------------------------------------------------------
+# Helper functions
+# ---------------------------------------------------------------------------
+
+
+def _get_agent_or_404(db: Session, agent_id: int, current_user: Any) -> Agent:
+    """Get agent and verify ownership, raise 404 if not found/owned."""
+    agent = db.query(Agent).filter(Agent.id == agent_id).first()
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if not agent or (agent.owner_id != current_user.id and not is_admin):
+        raise HTTPException(status_code=404, detail="Agent not found")
+    return agent
+
+
+def _agent_tools(agent: Agent) -> List[BaseTool]:
+    """Tools the agent's allowlist lets it call (all tools when unset)."""
+    return get_tool_resolver().filter_by_allowlist(agent.allowed_tools)
+
+
+def _parameters_schema(tool: BaseTool) -> Dict[str, Any]:
+    """JSON schema of a tool's arguments, whatever form the tool declared it in."""
+    schema = tool.args_schema
+    if isinstance(schema, dict):
+        return schema
+    if schema is not None and hasattr(schema, "model_json_schema"):
+        return schema.model_json_schema()
+    return {"type": "object", "properties": dict(tool.args)}
+
+
+def _decode_result(result: Any) -> Any:
+    """Parse JSON string results so the client can pretty-print them."""
+    if isinstance(result, str):
+        try:
+            return json.loads(result)
+        except ValueError:
+            return result
+    return jsonable_encoder(result)
+
+
+# ---------------------------------------------------------------------------
+# Endpoints
+# ---------------------------------------------------------------------------
+
+
+@router.get("/", response_model=List[ToolDefinitionResponse])
+def list_agent_tools(
+    agent_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+) -> List[ToolDefinitionResponse]:
+    """List the tools this agent can call, sorted by name."""
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    tools = sorted(_agent_tools(agent), key=lambda t: t.name)
+    return [
+        ToolDefinitionResponse(name=t.name, description=t.description or "", parameters=_parameters_schema(t))
+        for t in tools
+    ]
+
+
+@router.post("/{tool_name}/invoke", response_model=ToolInvokeResponse)
+async def invoke_agent_tool(
+    request: ToolInvokeRequest,
+    agent_id: int = Path(..., gt=0),
+    tool_name: str = Path(...),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+) -> ToolInvokeResponse:
+    """Call one tool with the given arguments and return what it produced.
+
+    Argument validation failures are reported as 422. Errors raised or
+    returned by the tool itself come back as ``ok: false`` so the playground
+    can show them next to the raw output.
+    """
+    agent = _get_agent_or_404(db, agent_id, current_user)
+    tool = next((t for t in _agent_tools(agent) if t.name == tool_name), None)
+    if tool is None:
+        raise HTTPException(status_code=404, detail=f"Tool '{tool_name}' is not available to this agent")
+
+    resolver = CredentialResolver(agent_id=agent.id, db=db, owner_id=agent.owner_id)
+    cred_token = set_credential_resolver(resolver)
+    start = time.perf_counter()
+    try:
+        # asyncio.to_thread copies the context, so the tool sees the resolver
+        result = await asyncio.to_thread(tool.invoke, request.args)
+        error = None
+    except ValidationError as exc:
+        raise HTTPException(
+            status_code=status.HTTP_422_UNPROCESSABLE_ENTITY,
+            detail=jsonable_encoder(exc.errors(include_url=False)),
+        )
+    except Exception as exc:  # noqa: BLE001
+        logger.info("Playground call to %s on agent %s failed: %s", tool_name, agent.id, exc)
+        result = None
+        error = str(exc)
+    finally:
+        reset_credential_resolver(cred_token)
+    duration_ms = int((time.perf_counter() - start) * 1000)
+
+    if error is None:
+        is_error, error = check_tool_error(result)
+    else:
+        is_error = True
+
+    return ToolInvokeResponse(
+        tool=tool_name,
+        ok=not is_error,
+        result=_decode_result(result),
+        raw="" if result is None else str(result),
+        error=error,
+        duration_ms=duration_ms,
+    )
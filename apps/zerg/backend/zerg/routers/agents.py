@@ -1,4 +1,5 @@
 import logging
+from dataclasses import asdict
 from time import perf_counter
 from typing import Any
 from typing import List
@@ -15,6 +16,7 @@ from fastapi import HTTPException
 from fastapi import Query
 from fastapi import Response
 from fastapi import status
+from fastapi.encoders import jsonable_encoder
 
 # Instantiate OpenAI client with API key from central settings
 from sqlalchemy.orm import Session
@@ -29,14 +31,47 @@ from zerg.metrics import dashboard_snapshot_agents_returned
 from zerg.metrics import dashboard_snapshot_latency_seconds
 from zerg.metrics import dashboard_snapshot_requests_total
 from zerg.metrics import dashboard_snapshot_runs_returned
+from zerg.models.enums import SharePermission
 from zerg.schemas.schemas import Agent
+from zerg.schemas.schemas import AgentBudgetStatus
 from zerg.schemas.schemas import AgentCreate
+from zerg.schemas.schemas import AgentDependents
 from zerg.schemas.schemas import AgentDetails
+from zerg.schemas.schemas import AgentExport
+from zerg.schemas.schemas import AgentImportPreview
+from zerg.schemas.schemas import AgentImportRequest
+from zerg.schemas.schemas import AgentInstructionVersionOut
+from zerg.schemas.schemas import AgentModelParams
+from zerg.schemas.schemas import AgentPatch
+from zerg.schemas.schemas import AgentPatchValidation
+from zerg.schemas.schemas import AgentPermission
 from zerg.schemas.schemas import AgentRunsBundle
+from zerg.schemas.schemas import AgentShareOut
+from zerg.schemas.schemas import AgentShareUpsert
+from zerg.schemas.schemas import AgentStats
 from zerg.schemas.schemas import AgentUpdate
+from zerg.schemas.schemas import AgentUsage
 from zerg.schemas.schemas import DashboardSnapshot
 from zerg.schemas.schemas import MessageCreate
 from zerg.schemas.schemas import MessageResponse
+from zerg.schemas.schemas import ModelComparison
+from zerg.schemas.schemas import ModelComparisonRequest
+from zerg.schemas.schemas import ToolUsageResponse
+from zerg.services import agent_portability
+from zerg.services import run_queue
+from zerg.services.agent_stats import USAGE_DAYS
+from zerg.services.agent_stats import get_agent_stats
+from zerg.services.agent_stats import get_agent_usage
+from zerg.services.env_vars import render_agent_instructions
+from zerg.services.model_params import agent_model_params
+from zerg.services.model_params import effective_model_params
+from zerg.services.model_params import model_default_params
+from zerg.services.quota import agent_budget_status
+from zerg.services.quota import assert_agent_within_budget
+from zerg.services.quota import assert_can_start_run
+from zerg.services.schema_validation import agent_contract_schema
+from zerg.services.schema_validation import validate_agent_patch
+from zerg.services.scratch_run import run_scratch_message
 from zerg.utils.time import utc_now_naive
 
 load_dotenv()
@@ -115,6 +150,8 @@ def _get_agents_for_scope(
 ):
     if scope == "my":
         return crud.get_agents(db, skip=skip, limit=limit, owner_id=current_user.id)
+    if scope == "shared":
+        return crud.get_shared_agents(db, current_user.id, skip=skip, limit=limit)
 
     from zerg.dependencies.auth import AUTH_DISABLED  # local import to avoid cycle
 
@@ -132,7 +169,7 @@ def _check_idempotency_cache(key: str, user_id: int, db: Session) -> Optional[Ag
     if cache_key in IDEMPOTENCY_CACHE:
         agent_id = IDEMPOTENCY_CACHE[cache_key]
         agent = crud.get_agent(db, agent_id)
-        if agent:
+        if agent and agent.deleted_at is None:
             logger.info(f"Idempotency: Returning existing agent {agent_id} for key {key}")
             return agent
     return None
@@ -153,7 +190,7 @@ def _store_idempotency_cache(key: str, user_id: int, agent_id: int) -> None:
 @router.get("", response_model=List[Agent])
 def read_agents(
     *,
-    scope: str = Query("my", pattern="^(my|all)$"),
+    scope: str = Query("my", pattern="^(my|all|shared)$"),
     skip: int = 0,
     limit: int = 100,
     db: Session = Depends(get_db),
@@ -165,7 +202,7 @@ def read_agents(
 @router.get("/dashboard", response_model=DashboardSnapshot)
 def read_dashboard_snapshot(
     *,
-    scope: str = Query("my", pattern="^(my|all)$"),
+    scope: str = Query("my", pattern="^(my|all|shared)$"),
     runs_limit: int = Query(50, ge=0, le=500),
     skip: int = 0,
     limit: int = 100,
@@ -189,6 +226,16 @@ def read_dashboard_snapshot(
             bundles = [AgentRunsBundle(agent_id=agent.id, runs=[]) for agent in agents]
 
         total_runs = sum(len(bundle.runs) for bundle in bundles)
+        budgets = [
+            AgentBudgetStatus(**budget)
+            for budget in (agent_budget_status(db, agent) for agent in agents)
+            if budget is not None
+        ]
+        stats = list(get_agent_stats(db, [agent.id for agent in agents]).values())
+        permissions = []
+        if scope == "shared":
+            shared = crud.get_shared_agent_permissions(db, current_user.id)
+            permissions = [AgentPermission(agent_id=agent.id, permission=shared[agent.id]) for agent in agents]
 
         logger.info(
             "Dashboard snapshot fetched (scope=%s, runs_limit=%s, agents=%s, total_runs=%s)",
@@ -204,6 +251,9 @@ def read_dashboard_snapshot(
             runs_limit=runs_limit,
             agents=agents,
             runs=bundles,
+            budgets=budgets,
+            stats=stats,
+            permissions=permissions,
         )
     except Exception:
         status_label = "error"
@@ -216,6 +266,48 @@ def read_dashboard_snapshot(
         dashboard_snapshot_runs_returned.observe(float(total_runs))
 
 
+@router.get("/usage", response_model=List[AgentUsage])
+def read_agents_usage(
+    *,
+    scope: str = Query("my", pattern="^(my|all|shared)$"),
+    days: int = Query(USAGE_DAYS, ge=1, le=90),
+    skip: int = 0,
+    limit: int = 100,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Tokens and cost per agent over the last ``days`` days; same scope and paging as ``/dashboard``."""
+
+    agents = _get_agents_for_scope(db, current_user, scope, skip=skip, limit=limit)
+    return list(get_agent_usage(db, [agent.id for agent in agents], days=days).values())
+
+
+@router.get("/trash", response_model=List[Agent])
+def read_trashed_agents(
+    *,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Agents the current user moved to the trash, newest first.
+
+    Expired entries are purged lazily here rather than by a separate job.
+    """
+    crud.purge_expired_trashed_agents(db, owner_id=current_user.id)
+    return crud.get_trashed_agents(db, owner_id=current_user.id)
+
+
+@router.get("/contract", response_model=dict[str, Any])
+def read_agent_contract(current_user=Depends(get_current_user)):
+    """JSON Schema of the fields ``PATCH /agents/{id}`` accepts."""
+    return agent_contract_schema()
+
+
+@router.post("/contract/validate", response_model=AgentPatchValidation)
+def validate_agent_edit(patch: Any = Body(...), current_user=Depends(get_current_user)):
+    """Check a raw agent edit against the contract without applying it."""
+    return AgentPatchValidation(errors=validate_agent_patch(patch))
+
+
 @router.post("/", response_model=Agent, status_code=status.HTTP_201_CREATED)
 @router.post("", response_model=Agent, status_code=status.HTTP_201_CREATED)
 @publish_event(EventType.AGENT_CREATED)
@@ -245,6 +337,9 @@ async def create_agent(
             model=model_to_use,
             schedule=agent.schedule,
             config=agent.config,
+            prompt_variables=agent.prompt_variables,
+            description=agent.description,
+            tags=agent.tags,
         )
 
         # Store in idempotency cache
@@ -258,13 +353,7 @@ async def create_agent(
 
 @router.get("/{agent_id}", response_model=Agent)
 def read_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
-    row = crud.get_agent(db, agent_id)
-    if row is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and row.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
-    return row
+    return _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
 
 
 @router.put("/{agent_id}", response_model=Agent)
@@ -275,6 +364,39 @@ async def update_agent(
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
     ):
+    return _apply_agent_update(db, agent_id, agent, current_user)
+
+
+@router.patch("/{agent_id}", response_model=Agent)
+@publish_event(EventType.AGENT_UPDATED)
+async def patch_agent(
+    agent_id: int,
+    patch: AgentPatch,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Apply a raw edit from the JSON editor.
+
+    The changes are checked against the agent contract first, and any changed
+    field whose current value no longer matches ``base`` is a 409 so an edit
+    made from a stale copy can't clobber someone else's.
+    """
+    errors = validate_agent_patch(patch.changes)
+    if errors:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail="; ".join(errors))
+
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.EDIT)
+    current = jsonable_encoder(Agent.model_validate(row))
+    stale = sorted(key for key in patch.changes if key in patch.base and current.get(key) != patch.base[key])
+    if stale:
+        raise HTTPException(
+            status_code=status.HTTP_409_CONFLICT,
+            detail=f"Changed since you loaded it: {', '.join(stale)}. Reload and try again.",
+        )
+    return _apply_agent_update(db, agent_id, AgentUpdate.model_validate(patch.changes), current_user)
+
+
+def _apply_agent_update(db: Session, agent_id: int, agent: AgentUpdate, current_user):
     if agent.model is not None:
         _validate_model_or_400(agent.model)
         # Enforce role-based allowlist for non-admin users when updating model
@@ -282,13 +404,8 @@ async def update_agent(
     else:
         agent_model_validated = None
 
-    # Authorization: only owner or admin may update an agent
-    existing = crud.get_agent(db, agent_id)
-    if existing is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and existing.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    # Authorization: owner, admin, or a user the agent was shared with for editing
+    _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.EDIT)
 
     try:
         row = crud.update_agent(
@@ -301,7 +418,11 @@ async def update_agent(
             status=agent.status.value if agent.status else None,
             schedule=agent.schedule,
             config=agent.config,
+            prompt_variables=agent.prompt_variables,
+            description=agent.description,
+            tags=agent.tags,
             allowed_tools=agent.allowed_tools,
+            author_id=current_user.id,
         )
     except ValueError as exc:
         raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc)) from exc
@@ -311,6 +432,112 @@ async def update_agent(
     return row
 
 
+# ---------------------------------------------------------------------------
+# Model comparison
+# ---------------------------------------------------------------------------
+
+
+@router.post("/{agent_id}/compare", response_model=ModelComparison)
+async def compare_models(
+    agent_id: int,
+    request: ModelComparisonRequest,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Run the same message on each requested model, one after another, for a side-by-side view.
+
+    The agent's saved model is untouched and the runs are not kept in its history.
+    """
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.RUN)
+    for model in request.models:
+        _validate_model_or_400(model)
+    models = [_enforce_model_allowlist_or_422(model, current_user) for model in request.models]
+    if len(set(models)) != len(models):
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Pick different models to compare")
+
+    message = (request.input or "").strip() or render_agent_instructions(db, row, row.task_instructions)
+    if not message.strip():
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST, detail="Nothing to send: no input or task instructions"
+        )
+
+    assert_can_start_run(db, user=current_user)
+    assert_agent_within_budget(db, row)
+
+    results = []
+    for model in models:
+        run = await run_scratch_message(db, row, message, title=f"Model comparison – {model}", model=model)
+        results.append(asdict(run))
+    return ModelComparison(agent_id=row.id, input=message, results=results)
+
+
+# ---------------------------------------------------------------------------
+# Model parameters
+# ---------------------------------------------------------------------------
+
+
+@router.get("/{agent_id}/model-params", response_model=AgentModelParams)
+def read_model_params(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Model defaults, the agent's overrides and the parameters its runs end up using."""
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
+    return AgentModelParams(
+        agent_id=row.id,
+        model=row.model,
+        defaults=model_default_params(row.model),
+        overrides=agent_model_params(row),
+        effective=effective_model_params(row),
+    )
+
+
+# ---------------------------------------------------------------------------
+# Instruction history
+# ---------------------------------------------------------------------------
+
+
+def _instruction_version_out(version) -> AgentInstructionVersionOut:
+    return AgentInstructionVersionOut(
+        id=version.id,
+        agent_id=version.agent_id,
+        version=version.version,
+        system_instructions=version.system_instructions,
+        task_instructions=version.task_instructions,
+        author_id=version.author_id,
+        author_email=version.author.email if version.author is not None else None,
+        restored_from_version=version.restored_from_version,
+        created_at=version.created_at,
+    )
+
+
+@router.get("/{agent_id}/instruction-versions", response_model=List[AgentInstructionVersionOut])
+def read_instruction_versions(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Every saved revision of the system/task instructions, newest first."""
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
+    return [_instruction_version_out(version) for version in crud.get_instruction_versions(db, row.id)]
+
+
+@router.post("/{agent_id}/instruction-versions/{version_id}/restore", response_model=Agent)
+@publish_event(EventType.AGENT_UPDATED)
+async def restore_instruction_version(
+    agent_id: int,
+    version_id: int,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Roll the instructions back to *version_id*, recorded as a new version."""
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.EDIT)
+    version = crud.get_instruction_version(db, row.id, version_id)
+    if version is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Version not found")
+    return crud.update_agent(
+        db,
+        row.id,
+        system_instructions=version.system_instructions,
+        task_instructions=version.task_instructions,
+        author_id=current_user.id,
+        restored_from_version=version.version,
+    )
+
+
 # ---------------------------------------------------------------------------
 # Details
 # ---------------------------------------------------------------------------
@@ -324,12 +551,7 @@ def read_agent_details(
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
 ):
-    row = crud.get_agent(db, agent_id)
-    if row is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and row.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
 
     include_set: set[str] = set(p.strip().lower() for p in include.split(",")) if include else set()
     payload: dict[str, Any] = {"agent": row}
@@ -338,33 +560,320 @@ def read_agent_details(
     if "runs" in include_set:
         payload["runs"] = crud.list_runs(db, agent_id)  # type: ignore[assignment]
     if "stats" in include_set:
-        payload["stats"] = {}
+        payload["stats"] = get_agent_stats(db, [agent_id])[agent_id]
     return payload
 
 
+@router.get("/{agent_id}/stats", response_model=AgentStats)
+def read_agent_stats(
+    agent_id: int,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Success rate, average duration and runs per day for the last week."""
+
+    row = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
+    return get_agent_stats(db, [agent_id])[agent_id]
+
+
 # ---------------------------------------------------------------------------
 # Delete & aux
 # ---------------------------------------------------------------------------
 
 
+def _agent_event_payload(row) -> dict[str, Any]:
+    return {c.name: getattr(row, c.name) for c in row.__table__.columns}
+
+
+@router.get("/{agent_id}/dependents", response_model=AgentDependents)
+def read_agent_dependents(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Workflows, triggers, schedule and queued runs that rely on the agent, checked before deleting it."""
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+    return {
+        "agent_id": row.id,
+        "workflows": crud.workflows_using_agent(db, row),
+        "triggers": [
+            {"id": trg.id, "type": trg.type, "disabled": bool((trg.config or {}).get("disabled"))}
+            for trg in crud.get_triggers(db, agent_id=row.id)
+        ],
+        "schedule": row.schedule,
+        "next_run_at": row.next_run_at,
+        "queued_run_ids": [run.id for run in crud.list_queued_runs(db, row.id)],
+    }
+
+
 @router.delete("/{agent_id}", status_code=status.HTTP_204_NO_CONTENT)
-async def delete_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+async def delete_agent(
+    agent_id: int,
+    disable_dependents: bool = Query(False, description="Also disable the agent's triggers and drop its queued runs"),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Move an agent to the trash.
+
+    The agent disappears from listings and is unscheduled, but keeps its
+    threads and runs so it can be restored for ``AGENT_TRASH_RETENTION_DAYS``.
+    With ``disable_dependents`` its triggers are disabled too (restoring the
+    agent turns them back on) and its queued runs are cancelled.
+    """
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+
+    if disable_dependents:
+        crud.set_agent_triggers_disabled(db, agent_id, True)
+        for run in crud.list_queued_runs(db, agent_id):
+            await run_queue.drop_queued_run(db, run, reason="Agent was deleted")
+
+    row = crud.trash_agent(db, agent_id)
+    if row is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+
+    # Subscribers (scheduler, websocket clients) treat trashed agents as gone
+    await event_bus.publish(EventType.AGENT_DELETED, _agent_event_payload(row))
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+
+def _get_trashed_agent_or_404(db: Session, agent_id: int, current_user):
     row = crud.get_agent(db, agent_id)
     if row is None:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
     is_admin = getattr(current_user, "role", "USER") == "ADMIN"
     if not is_admin and row.owner_id != current_user.id:
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    if row.deleted_at is None:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="Agent is not in the trash")
+    return row
+
+
+@router.post("/{agent_id}/restore", response_model=Agent)
+async def restore_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    _get_trashed_agent_or_404(db, agent_id, current_user)
+    row = crud.restore_agent(db, agent_id)
+    if row is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+    crud.set_agent_triggers_disabled(db, agent_id, False)
+
+    # Same event as a fresh agent so the scheduler picks its schedule back up
+    await event_bus.publish(EventType.AGENT_CREATED, _agent_event_payload(row))
+    return row
+
+
+@router.delete("/{agent_id}/permanent", status_code=status.HTTP_204_NO_CONTENT)
+async def permanently_delete_agent(
+    agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)
+):
+    """Hard-delete a trashed agent together with its threads, runs and triggers."""
+    row = _get_trashed_agent_or_404(db, agent_id, current_user)
+    payload = _agent_event_payload(row)
 
     if not crud.delete_agent(db, agent_id):
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
 
-    payload = {c.name: getattr(row, c.name) for c in row.__table__.columns}
-    payload.pop("_sa_instance_state", None)
     await event_bus.publish(EventType.AGENT_DELETED, payload)
     return Response(status_code=status.HTTP_204_NO_CONTENT)
 
 
+# ---------------------------------------------------------------------------
+# Pause & resume
+# ---------------------------------------------------------------------------
+
+
+async def _set_paused(db: Session, agent_id: int, current_user, paused: bool):
+    _get_owned_agent_or_404(db, agent_id, current_user)
+    row = crud.set_agent_paused(db, agent_id, paused)
+    if row is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+
+    await event_bus.publish(EventType.AGENT_UPDATED, _agent_event_payload(row))
+    return row
+
+
+@router.post("/{agent_id}/pause", response_model=Agent)
+async def pause_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Suspend scheduled runs and trigger firings. Manual runs still work."""
+    return await _set_paused(db, agent_id, current_user, True)
+
+
+@router.post("/{agent_id}/resume", response_model=Agent)
+async def resume_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    return await _set_paused(db, agent_id, current_user, False)
+
+
+# ---------------------------------------------------------------------------
+# Duplicate, export & import
+# ---------------------------------------------------------------------------
+
+
+def _get_owned_agent_or_404(db: Session, agent_id: int, current_user):
+    row = crud.get_agent(db, agent_id)
+    if row is None or row.deleted_at is not None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if not is_admin and row.owner_id != current_user.id:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    return row
+
+
+def _get_accessible_agent_or_404(db: Session, agent_id: int, current_user, required: SharePermission):
+    """Like ``_get_owned_agent_or_404`` but also admits users holding a share of at least *required*."""
+    row = crud.get_agent(db, agent_id)
+    if row is None or row.deleted_at is not None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if is_admin or row.owner_id == current_user.id:
+        return row
+    granted = crud.get_agent_permission(db, row, current_user.id)
+    if granted is None or not crud.permission_allows(granted, required.value):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    return row
+
+
+def _model_available(model_id: str, current_user) -> bool:
+    try:
+        _validate_model_or_400(model_id)
+        _enforce_model_allowlist_or_422(model_id, current_user)
+    except HTTPException:
+        return False
+    return True
+
+
+def _check_export_format(export: AgentExport) -> None:
+    if export.format != agent_portability.EXPORT_FORMAT or export.version > agent_portability.EXPORT_VERSION:
+        raise HTTPException(
+            status_code=status.HTTP_422_UNPROCESSABLE_ENTITY,
+            detail=f"Unsupported export file (format '{export.format}', version {export.version})",
+        )
+
+
+@router.post("/{agent_id}/duplicate", response_model=Agent, status_code=status.HTTP_201_CREATED)
+async def duplicate_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Copy an agent with its configuration and triggers; the copy starts idle."""
+    source = _get_owned_agent_or_404(db, agent_id, current_user)
+    # The copy belongs to whoever duplicated it, so their model allowlist applies
+    _enforce_model_allowlist_or_422(source.model, current_user)
+
+    try:
+        row = agent_portability.duplicate_agent(db, source, owner_id=current_user.id)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc)) from exc
+
+    await event_bus.publish(EventType.AGENT_CREATED, _agent_event_payload(row))
+    return row
+
+
+@router.get("/{agent_id}/export", response_model=AgentExport)
+def export_agent(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Portable JSON for recreating the agent in another environment.
+
+    Secrets (MCP auth tokens, webhook secrets) and deployment-specific links
+    such as Gmail connectors are left out.
+    """
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+    return agent_portability.export_agent(db, row)
+
+
+@router.get("/{agent_id}/tool-usage", response_model=ToolUsageResponse)
+def read_tool_usage(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """How often each tool has been called by this agent, most used first."""
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+    usage = sorted(crud.get_tool_usage(db, row.id), key=lambda stat: (-stat["calls"], stat["name"]))
+    return ToolUsageResponse(agent_id=row.id, tools=usage)
+
+
+# ---------------------------------------------------------------------------
+# Sharing
+# ---------------------------------------------------------------------------
+
+
+def _share_out(share) -> AgentShareOut:
+    return AgentShareOut(
+        id=share.id,
+        agent_id=share.agent_id,
+        user_id=share.user_id,
+        user_email=share.user.email if share.user is not None else None,
+        permission=share.permission,
+        created_at=share.created_at,
+    )
+
+
+@router.get("/{agent_id}/shares", response_model=List[AgentShareOut])
+def read_agent_shares(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Who the agent is shared with; only the owner (or an admin) may look."""
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+    return [_share_out(share) for share in crud.get_agent_shares(db, row.id)]
+
+
+@router.put("/{agent_id}/shares", response_model=AgentShareOut)
+def upsert_agent_share(
+    agent_id: int,
+    payload: AgentShareUpsert,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Grant view/run/edit access to one user by email, or to the whole workspace without one."""
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+
+    user_id = None
+    if payload.user_email:
+        user = crud.get_user_by_email(db, payload.user_email.strip())
+        if user is None:
+            raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="No user with that email")
+        if user.id == row.owner_id:
+            raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="The owner already has full access")
+        user_id = user.id
+
+    share = crud.upsert_agent_share(db, row.id, user_id, payload.permission.value)
+    return _share_out(share)
+
+
+@router.delete("/{agent_id}/shares/{share_id}", status_code=status.HTTP_204_NO_CONTENT)
+def delete_agent_share(
+    agent_id: int,
+    share_id: int,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    row = _get_owned_agent_or_404(db, agent_id, current_user)
+    if not crud.delete_agent_share(db, row.id, share_id):
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Share not found")
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+
+@router.post("/import/preview", response_model=AgentImportPreview)
+def preview_agent_import(
+    export: AgentExport = Body(...),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Conflicts the import wizard should ask the user to resolve."""
+    _check_export_format(export)
+    return agent_portability.preview_import(
+        db,
+        export,
+        owner_id=current_user.id,
+        model_available=_model_available(export.agent.model, current_user),
+    )
+
+
+@router.post("/import", response_model=Agent, status_code=status.HTTP_201_CREATED)
+async def import_agent(
+    request: AgentImportRequest = Body(...),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    _check_export_format(request.export)
+    model = request.model or request.export.agent.model
+    _validate_model_or_400(model)
+    model = _enforce_model_allowlist_or_422(model, current_user)
+
+    try:
+        row = agent_portability.import_agent(db, request, owner_id=current_user.id, model=model)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc)) from exc
+
+    await event_bus.publish(EventType.AGENT_CREATED, _agent_event_payload(row))
+    return row
+
+
 @router.get("/{agent_id}/messages", response_model=List[MessageResponse])
 def read_agent_messages(
     agent_id: int,
@@ -373,12 +882,7 @@ def read_agent_messages(
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
 ):
-    agent = crud.get_agent(db, agent_id)
-    if agent is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.VIEW)
     return crud.get_agent_messages(db, agent_id=agent_id, skip=skip, limit=limit) or []
 
 
@@ -389,32 +893,22 @@ def create_agent_message(
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
 ):
-    agent = crud.get_agent(db, agent_id)
-    if agent is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.EDIT)
     return crud.create_agent_message(db=db, agent_id=agent_id, role=message.role, content=message.content)
 
 
 @router.post("/{agent_id}/task", status_code=status.HTTP_202_ACCEPTED)
 async def run_agent_task(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
-    agent = crud.get_agent(db, agent_id)
-    if agent is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-
-    # Authorization: only owner or admin may run an agent's task
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    # Authorization: owner, admin, or a user the agent was shared with for running
+    agent = _get_accessible_agent_or_404(db, agent_id, current_user, SharePermission.RUN)
 
+    from zerg.services.agent_locks import AgentAlreadyRunningError
     from zerg.services.task_runner import execute_agent_task
 
     try:
         thread = await execute_agent_task(db, agent, thread_type="manual")
+    except AgentAlreadyRunningError as exc:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="Agent already running") from exc
     except ValueError as exc:
-        if "already running" in str(exc).lower():
-            raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="Agent already running") from exc
         raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail=str(exc)) from exc
     return {"thread_id": thread.id}
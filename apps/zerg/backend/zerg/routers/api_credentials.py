@@ -0,0 +1,218 @@
+"""API credentials router – third-party REST API keys for HTTP nodes and MCP servers.
+
+Keys are write-only: they're encrypted on save and responses only carry the
+last four characters.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import List
+from typing import Optional
+
+import httpx
+from fastapi import APIRouter
+from fastapi import Body
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Response
+from fastapi import status
+from pydantic import BaseModel
+from pydantic import Field
+from sqlalchemy.orm import Session
+
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.models import ApiCredential
+from zerg.services.api_credentials import DEFAULT_AUTH_HEADER
+from zerg.services.api_credentials import auth_headers
+from zerg.services.api_credentials import get_owned_credential
+from zerg.services.api_credentials import key_hint
+from zerg.services.api_credentials import parse_auth_header
+from zerg.utils.crypto import encrypt
+from zerg.utils.time import utc_now_naive
+
+router = APIRouter(
+    prefix="/api-credentials",
+    tags=["api-credentials"],
+    dependencies=[Depends(get_current_user)],
+)
+
+TEST_TIMEOUT_SECONDS = 10.0
+
+
+class ApiCredentialCreate(BaseModel):
+    name: str = Field(min_length=1, max_length=100)
+    base_url: str = Field(max_length=2048)
+    auth_header: str = DEFAULT_AUTH_HEADER
+    key: str = Field(min_length=1, max_length=4096)
+
+
+class ApiCredentialUpdate(BaseModel):
+    name: Optional[str] = Field(None, min_length=1, max_length=100)
+    base_url: Optional[str] = Field(None, max_length=2048)
+    auth_header: Optional[str] = None
+    # Omit to keep the current key
+    key: Optional[str] = Field(None, min_length=1, max_length=4096)
+
+
+class ApiCredentialOut(BaseModel):
+    id: int
+    name: str
+    base_url: str
+    auth_header: str
+    key_hint: str
+    test_status: str
+    last_tested_at: Optional[datetime] = None
+    updated_at: Optional[datetime] = None
+
+
+class ApiCredentialTestResult(BaseModel):
+    success: bool
+    message: str
+    status_code: Optional[int] = None
+
+
+def _to_out(credential: ApiCredential) -> ApiCredentialOut:
+    return ApiCredentialOut(
+        id=credential.id,
+        name=credential.name,
+        base_url=credential.base_url,
+        auth_header=credential.auth_header,
+        key_hint=credential.key_hint,
+        test_status=credential.test_status,
+        last_tested_at=credential.last_tested_at,
+        updated_at=credential.updated_at,
+    )
+
+
+def _get_own_credential(db: Session, credential_id: int, current_user) -> ApiCredential:
+    credential = get_owned_credential(db, current_user.id, credential_id)
+    if credential is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="API credential not found")
+    return credential
+
+
+def _clean_base_url(base_url: str) -> str:
+    base_url = base_url.strip().rstrip("/")
+    if not base_url.startswith(("http://", "https://")):
+        raise HTTPException(
+            status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail="Base URL must start with http:// or https://"
+        )
+    return base_url
+
+
+def _clean_auth_header(template: str) -> str:
+    try:
+        name, value = parse_auth_header(template)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc))
+    return f"{name}: {value}"
+
+
+def _ensure_unique(db: Session, owner_id: int, name: str, exclude_id: Optional[int] = None):
+    query = db.query(ApiCredential).filter(ApiCredential.owner_id == owner_id, ApiCredential.name == name)
+    if exclude_id is not None:
+        query = query.filter(ApiCredential.id != exclude_id)
+    if query.first() is not None:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail=f"An API credential named {name} exists")
+
+
+@router.get("/", response_model=List[ApiCredentialOut])
+def list_api_credentials(db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    credentials = (
+        db.query(ApiCredential).filter(ApiCredential.owner_id == current_user.id).order_by(ApiCredential.name).all()
+    )
+    return [_to_out(credential) for credential in credentials]
+
+
+@router.post("/", response_model=ApiCredentialOut, status_code=status.HTTP_201_CREATED)
+def create_api_credential(
+    payload: ApiCredentialCreate, db: Session = Depends(get_db), current_user=Depends(get_current_user)
+):
+    name = payload.name.strip()
+    _ensure_unique(db, current_user.id, name)
+    credential = ApiCredential(
+        owner_id=current_user.id,
+        name=name,
+        base_url=_clean_base_url(payload.base_url),
+        auth_header=_clean_auth_header(payload.auth_header),
+        encrypted_key=encrypt(payload.key.strip()),
+        key_hint=key_hint(payload.key.strip()),
+    )
+    db.add(credential)
+    db.commit()
+    db.refresh(credential)
+    return _to_out(credential)
+
+
+@router.patch("/{credential_id}", response_model=ApiCredentialOut)
+def update_api_credential(
+    credential_id: int,
+    payload: ApiCredentialUpdate,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    credential = _get_own_credential(db, credential_id, current_user)
+
+    if payload.name is not None:
+        name = payload.name.strip()
+        _ensure_unique(db, current_user.id, name, exclude_id=credential.id)
+        credential.name = name
+    if payload.base_url is not None:
+        credential.base_url = _clean_base_url(payload.base_url)
+    if payload.auth_header is not None:
+        credential.auth_header = _clean_auth_header(payload.auth_header)
+    if payload.key is not None:
+        credential.encrypted_key = encrypt(payload.key.strip())
+        credential.key_hint = key_hint(payload.key.strip())
+    if payload.model_fields_set - {"name"}:
+        # A new key, URL or header hasn't been tested yet
+        credential.test_status = "untested"
+
+    db.commit()
+    db.refresh(credential)
+    return _to_out(credential)
+
+
+@router.delete("/{credential_id}", status_code=status.HTTP_204_NO_CONTENT)
+def delete_api_credential(credential_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    credential = _get_own_credential(db, credential_id, current_user)
+    db.delete(credential)
+    db.commit()
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+
+@router.post("/{credential_id}/test", response_model=ApiCredentialTestResult)
+async def test_api_credential(
+    credential_id: int,
+    path: str = Body("", embed=True),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """GET the base URL (plus *path*) with the auth header and report whether the key was accepted."""
+
+    credential = _get_own_credential(db, credential_id, current_user)
+    url = credential.base_url + ("/" + path.lstrip("/") if path.strip() else "")
+    try:
+        async with httpx.AsyncClient(follow_redirects=True, timeout=TEST_TIMEOUT_SECONDS) as client:
+            response = await client.get(url, headers=auth_headers(credential))
+    except httpx.HTTPError as exc:
+        result = ApiCredentialTestResult(success=False, message=f"Could not reach {url}: {exc}")
+    except ValueError as exc:  # key can't be decrypted
+        result = ApiCredentialTestResult(success=False, message=str(exc))
+    else:
+        if response.status_code in (401, 403):
+            message = f"The API rejected the key (HTTP {response.status_code})"
+        elif response.is_error:
+            message = f"Reached the API, but GET {url} returned HTTP {response.status_code}"
+        else:
+            message = f"Connected – GET {url} returned HTTP {response.status_code}"
+        result = ApiCredentialTestResult(
+            success=not response.is_error, message=message, status_code=response.status_code
+        )
+
+    credential.test_status = "success" if result.success else "failed"
+    credential.last_tested_at = utc_now_naive()
+    db.commit()
+    return result
@@ -11,6 +11,7 @@ from dotenv import load_dotenv
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import Query
 from fastapi import Request
 from fastapi import Response
 from fastapi import status
@@ -20,6 +21,9 @@ from zerg.config import get_settings
 from zerg.crud import crud
 from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
+from zerg.schemas.schemas import GmailConnectionOut
+from zerg.schemas.schemas import GmailDependentTrigger
+from zerg.schemas.schemas import GmailDisconnectOut
 from zerg.schemas.schemas import TokenOut
 
 load_dotenv()
@@ -58,6 +62,9 @@ SESSION_COOKIE_PATH = "/"
 # Secure=True only in production (HTTPS); False in dev for http://localhost
 SESSION_COOKIE_SECURE = not _settings.auth_disabled and not _settings.testing
 
+# Lifetime of access tokens and the session cookie that carries them
+ACCESS_TOKEN_TTL = timedelta(minutes=30)
+
 
 def _set_session_cookie(response: Response, token: str, max_age: int) -> None:
     """Set the session cookie with proper security flags.
@@ -124,7 +131,7 @@ def _issue_access_token(
     *,
     display_name: Optional[str] = None,
     avatar_url: Optional[str] = None,
-    expires_delta: timedelta = timedelta(minutes=30),
+    expires_delta: timedelta = ACCESS_TOKEN_TTL,
 ) -> str:
     """Return signed HS256 access token including *optional* profile fields.
 
@@ -248,6 +255,43 @@ def _exchange_google_auth_code(auth_code: str, *, redirect_uri: Optional[str] =
     return payload  # Contains refresh_token, access_token, expires_in, scope, …
 
 
+def _session_user(request: Request, db: Session):
+    """Return the active user behind the request's session, or raise 401."""
+    from zerg.auth.strategy import _decode_jwt_fallback
+
+    # Try to extract token: prefer cookie, fall back to bearer
+    token: str | None = None
+
+    # 1. Check cookie first (browser auth)
+    token = request.cookies.get(SESSION_COOKIE_NAME)
+
+    # 2. Fall back to Authorization header (API clients)
+    if not token:
+        auth_header = request.headers.get("Authorization", "")
+        if auth_header.startswith("Bearer "):
+            token = auth_header[7:]
+
+    if not token:
+        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="No session")
+
+    # Validate the token (checks signature and expiry)
+    try:
+        payload = _decode_jwt_fallback(token, JWT_SECRET)
+    except Exception:
+        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Invalid session")
+
+    # Extract user_id and verify user exists and is active
+    try:
+        user_id = int(payload.get("sub"))
+    except (TypeError, ValueError):
+        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Invalid session")
+
+    user = crud.get_user(db, user_id)
+    if user is None or not getattr(user, "is_active", True):
+        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="User not found or inactive")
+    return user
+
+
 # ---------------------------------------------------------------------------
 # Routes
 # ---------------------------------------------------------------------------
@@ -272,7 +316,7 @@ def dev_login(response: Response, db: Session = Depends(get_db)) -> TokenOut:
         user = crud.create_user(db, email="dev@local", provider="dev", provider_user_id="dev-user-1", role="ADMIN")
 
     # Issue platform JWT
-    expires_in = 30 * 60  # 30 minutes
+    expires_in = int(ACCESS_TOKEN_TTL.total_seconds())
     access_token = _issue_access_token(
         user.id,
         user.email,
@@ -347,7 +391,7 @@ def google_sign_in(response: Response, body: dict[str, str], db: Session = Depen
                 pass
 
     # 3. Issue platform JWT
-    expires_in = 30 * 60  # 30 minutes
+    expires_in = int(ACCESS_TOKEN_TTL.total_seconds())
     access_token = _issue_access_token(
         user.id,
         user.email,
@@ -378,40 +422,31 @@ def verify_session(request: Request, db: Session = Depends(get_db)):
     - User existence in database
     - User is_active status
     """
-    from zerg.auth.strategy import _decode_jwt_fallback
-
-    # Try to extract token: prefer cookie, fall back to bearer
-    token: str | None = None
-
-    # 1. Check cookie first (browser auth)
-    token = request.cookies.get(SESSION_COOKIE_NAME)
+    _session_user(request, db)
 
-    # 2. Fall back to Authorization header (API clients)
-    if not token:
-        auth_header = request.headers.get("Authorization", "")
-        if auth_header.startswith("Bearer "):
-            token = auth_header[7:]
+    # Valid token and user - 204 response handled by status_code
 
-    if not token:
-        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="No session")
 
-    # Validate the token (checks signature and expiry)
-    try:
-        payload = _decode_jwt_fallback(token, JWT_SECRET)
-    except Exception:
-        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Invalid session")
+@router.post("/refresh", response_model=TokenOut)
+def refresh_session(request: Request, response: Response, db: Session = Depends(get_db)) -> TokenOut:
+    """Swap a still-valid session for a fresh one.
 
-    # Extract user_id and verify user exists and is active
-    try:
-        user_id = int(payload.get("sub"))
-    except (TypeError, ValueError):
-        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="Invalid session")
+    The browser calls this shortly before the cookie expires while the user
+    is active, so working sessions slide forward without a new Google
+    sign-in.  Expired sessions get 401 and must sign in again.
+    """
+    user = _session_user(request, db)
 
-    user = crud.get_user(db, user_id)
-    if user is None or not getattr(user, "is_active", True):
-        raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail="User not found or inactive")
+    expires_in = int(ACCESS_TOKEN_TTL.total_seconds())
+    access_token = _issue_access_token(
+        user.id,
+        user.email,
+        display_name=user.display_name,
+        avatar_url=user.avatar_url,
+    )
+    _set_session_cookie(response, access_token, expires_in)
 
-    # Valid token and user - 204 response handled by status_code
+    return TokenOut(access_token=access_token, expires_in=expires_in)
 
 
 @router.post("/logout", status_code=status.HTTP_204_NO_CONTENT, response_class=Response)
@@ -452,6 +487,7 @@ def connect_gmail(
     # Exchange code for tokens (patched to stub out in unit-tests)
     token_payload = _exchange_google_auth_code(auth_code)
     refresh_token: str = token_payload["refresh_token"]
+    scopes = str(token_payload.get("scope") or "").split()
 
     # Create or update connector for this user
     from zerg.utils import crypto  # lazy import
@@ -464,9 +500,11 @@ def connect_gmail(
         conn = existing[0]
         cfg = dict(conn.config or {})
         cfg["refresh_token"] = enc
+        cfg["scopes"] = scopes
         # Clear watch meta; will be re-initialized below when possible
         cfg.pop("history_id", None)
         cfg.pop("watch_expiry", None)
+        cfg.pop("last_error", None)
         conn = crud.update_connector(db, conn.id, config=cfg)  # type: ignore[assignment]
         connector_id = conn.id if conn else None
     else:
@@ -476,7 +514,7 @@ def connect_gmail(
                 owner_id=current_user.id,
                 type="email",
                 provider="gmail",
-                config={"refresh_token": enc},
+                config={"refresh_token": enc, "scopes": scopes},
             )
             connector_id = conn.id
         except Exception:
@@ -487,6 +525,8 @@ def connect_gmail(
             conn = existing[0]
             connector_id = conn.id
 
+    _reenable_gmail_triggers(db, current_user.id, int(connector_id))
+
     # Optionally start a Gmail watch immediately (best effort)
     try:
         # Derive/validate callback URL server-side for security
@@ -554,3 +594,150 @@ def connect_gmail(
         pass
 
     return {"status": "connected", "connector_id": int(connector_id)}
+
+
+# Reason stored on email triggers switched off by a Gmail disconnect so a
+# later reconnect can switch them back on.
+GMAIL_DISCONNECTED = "gmail_disconnected"
+
+
+def _gmail_connector(db: Session, owner_id: int):
+    existing = crud.get_connectors(db, owner_id=owner_id, type="email", provider="gmail")
+    return existing[0] if existing else None
+
+
+def _email_triggers(db: Session, owner_id: int) -> list:
+    from zerg.models.models import Agent
+    from zerg.models.models import Trigger
+
+    return (
+        db.query(Trigger)
+        .join(Agent, Agent.id == Trigger.agent_id)
+        .filter(Agent.owner_id == owner_id, Trigger.type == "email")
+        .order_by(Trigger.id)
+        .all()
+    )
+
+
+def _set_trigger_config(db: Session, trg, cfg: dict) -> None:
+    from sqlalchemy.orm.attributes import flag_modified
+
+    trg.config = cfg
+    flag_modified(trg, "config")
+    db.add(trg)
+
+
+def _reenable_gmail_triggers(db: Session, owner_id: int, connector_id: int) -> None:
+    """Point triggers disabled by a disconnect at the new connector and enable them."""
+
+    changed = False
+    for trg in _email_triggers(db, owner_id):
+        cfg = dict(trg.config or {})
+        if cfg.get("disabled_reason") != GMAIL_DISCONNECTED:
+            continue
+        cfg.pop("disabled", None)
+        cfg.pop("disabled_reason", None)
+        cfg["connector_id"] = connector_id
+        _set_trigger_config(db, trg, cfg)
+        changed = True
+    if changed:
+        db.commit()
+
+
+def _gmail_health(cfg: dict) -> tuple[str, Optional[str]]:
+    if not cfg.get("refresh_token"):
+        return "auth_failed", "No refresh token stored; reconnect Gmail."
+    if cfg.get("last_error"):
+        return "auth_failed", str(cfg["last_error"])
+    expiry = cfg.get("watch_expiry")
+    if expiry and int(expiry) < int(datetime.utcnow().timestamp() * 1000):
+        return "watch_expired", "Gmail stopped sending change notifications; reconnect to renew the watch."
+    return "healthy", None
+
+
+@router.get("/google/gmail", response_model=GmailConnectionOut)
+def read_gmail_connection(
+    check: bool = Query(False, description="Refresh the access token now to verify the grant"),
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+) -> GmailConnectionOut:
+    """Return the connected Gmail account, its scopes, health and the email triggers using it."""
+
+    conn = _gmail_connector(db, current_user.id)
+    triggers = [
+        GmailDependentTrigger(
+            id=trg.id,
+            agent_id=trg.agent_id,
+            agent_name=trg.agent.name,
+            disabled=bool((trg.config or {}).get("disabled")),
+        )
+        for trg in _email_triggers(db, current_user.id)
+    ]
+    if conn is None:
+        return GmailConnectionOut(connected=False, health="not_connected", triggers=triggers)
+
+    cfg = dict(conn.config or {})
+    if check and cfg.get("refresh_token"):
+        from zerg.services import gmail_api
+        from zerg.utils import crypto
+
+        try:
+            gmail_api.exchange_refresh_token(crypto.decrypt(cfg["refresh_token"]))
+            cfg.pop("last_error", None)
+        except Exception as exc:
+            cfg["last_error"] = f"Google rejected the stored grant: {exc}"[:255]
+        if cfg != (conn.config or {}):
+            crud.update_connector(db, conn.id, config=cfg)
+
+    health, detail = _gmail_health(cfg)
+    return GmailConnectionOut(
+        connected=True,
+        connector_id=conn.id,
+        email_address=cfg.get("emailAddress"),
+        scopes=list(cfg.get("scopes") or []),
+        connected_at=conn.updated_at or conn.created_at,
+        watch_expiry=cfg.get("watch_expiry"),
+        health=health,
+        health_detail=detail,
+        triggers=triggers,
+    )
+
+
+@router.delete("/google/gmail", response_model=GmailDisconnectOut)
+def disconnect_gmail(
+    db: Session = Depends(get_db),
+    current_user: Any = Depends(get_current_user),
+) -> GmailDisconnectOut:
+    """Remove the Gmail connector and disable every email trigger that used it.
+
+    The watch is stopped best-effort; the stored grant is dropped regardless.
+    Disabled triggers keep their filters and come back on reconnect.
+    """
+
+    conn = _gmail_connector(db, current_user.id)
+    if conn is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Gmail is not connected")
+
+    disabled: list[int] = []
+    for trg in _email_triggers(db, current_user.id):
+        cfg = dict(trg.config or {})
+        if cfg.get("connector_id") != conn.id or cfg.get("disabled"):
+            continue
+        cfg["disabled"] = True
+        cfg["disabled_reason"] = GMAIL_DISCONNECTED
+        _set_trigger_config(db, trg, cfg)
+        disabled.append(trg.id)
+    db.commit()
+
+    enc = (conn.config or {}).get("refresh_token")
+    if enc:
+        try:
+            from zerg.services import gmail_api
+            from zerg.utils import crypto
+
+            gmail_api.stop_watch(access_token=gmail_api.exchange_refresh_token(crypto.decrypt(enc)))
+        except Exception:  # pragma: no cover – best-effort; the grant may already be revoked
+            pass
+
+    crud.delete_connector(db, conn.id)
+    return GmailDisconnectOut(status="disconnected", disabled_trigger_ids=disabled)
@@ -0,0 +1,88 @@
+"""Data exports router – self-service download of everything a user owns."""
+
+from __future__ import annotations
+
+from typing import List
+
+from fastapi import APIRouter
+from fastapi import BackgroundTasks
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import status
+from fastapi.responses import FileResponse
+from sqlalchemy.orm import Session
+
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.models import DataExport
+from zerg.schemas.schemas import DataExportOut
+from zerg.services.data_export import EXPORT_HISTORY_LIMIT
+from zerg.services.data_export import export_path
+from zerg.services.data_export import fail_stale_exports
+from zerg.services.data_export import run_data_export
+
+router = APIRouter(
+    tags=["users"],
+    dependencies=[Depends(get_current_user)],
+)
+
+def _get_own_export(db: Session, export_id: int, current_user) -> DataExport:
+    export = db.query(DataExport).filter(DataExport.id == export_id, DataExport.owner_id == current_user.id).first()
+    if export is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Export not found")
+    return export
+
+
+@router.post("/users/me/exports", response_model=DataExportOut, status_code=status.HTTP_202_ACCEPTED)
+def request_data_export(
+    background_tasks: BackgroundTasks,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Queue a full export of everything the caller owns (see services/data_export.py)."""
+
+    # An export whose worker died must not block new ones forever
+    fail_stale_exports(db, current_user.id)
+    in_progress = (
+        db.query(DataExport)
+        .filter(DataExport.owner_id == current_user.id, DataExport.status.in_(("queued", "running")))
+        .first()
+    )
+    if in_progress is not None:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="An export is already in progress")
+
+    export = DataExport(owner_id=current_user.id, status="queued")
+    db.add(export)
+    db.commit()
+    db.refresh(export)
+
+    background_tasks.add_task(run_data_export, export.id)
+    return export
+
+
+@router.get("/users/me/exports", response_model=List[DataExportOut])
+def list_data_exports(db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Return the caller's most recent exports, newest first."""
+
+    fail_stale_exports(db, current_user.id)
+    return (
+        db.query(DataExport)
+        .filter(DataExport.owner_id == current_user.id)
+        .order_by(DataExport.id.desc())
+        .limit(EXPORT_HISTORY_LIMIT)
+        .all()
+    )
+
+
+@router.get("/users/me/exports/{export_id}", response_model=DataExportOut)
+def get_data_export(export_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    return _get_own_export(db, export_id, current_user)
+
+
+@router.get("/users/me/exports/{export_id}/download")
+def download_data_export(export_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    export = _get_own_export(db, export_id, current_user)
+    path = export_path(export)
+    if export.status != "success" or path is None or not path.is_file():
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="Export is not ready")
+    return FileResponse(path, media_type="application/zip", filename=export.file_name)
@@ -0,0 +1,185 @@
+"""Environment variables router – named values and secrets referenced as ``{{env.NAME}}``.
+
+Secret values are write-only: they're encrypted on save and responses only
+say that a value is set.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import List
+from typing import Optional
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Response
+from fastapi import status
+from pydantic import BaseModel
+from pydantic import Field
+from sqlalchemy.orm import Session
+
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.models.models import EnvironmentVariable
+from zerg.models.models import Workflow
+from zerg.services.env_vars import MAX_ENV_VALUE_LENGTH
+from zerg.services.env_vars import plain_value
+from zerg.services.env_vars import validate_env_name
+from zerg.utils.crypto import encrypt
+
+router = APIRouter(
+    prefix="/env-vars",
+    tags=["env-vars"],
+    dependencies=[Depends(get_current_user)],
+)
+
+
+class EnvVarCreate(BaseModel):
+    name: str
+    value: str = Field(max_length=MAX_ENV_VALUE_LENGTH)
+    is_secret: bool = False
+    description: Optional[str] = None
+    # None for a workspace-wide variable
+    workflow_id: Optional[int] = None
+
+
+class EnvVarUpdate(BaseModel):
+    name: Optional[str] = None
+    # Omit to keep the current value (the only way to keep a secret's value)
+    value: Optional[str] = Field(None, max_length=MAX_ENV_VALUE_LENGTH)
+    is_secret: Optional[bool] = None
+    description: Optional[str] = None
+
+
+class EnvVarOut(BaseModel):
+    id: int
+    name: str
+    workflow_id: Optional[int] = None
+    workflow_name: Optional[str] = None
+    is_secret: bool
+    # Always None for secrets
+    value: Optional[str] = None
+    description: Optional[str] = None
+    updated_at: Optional[datetime] = None
+
+
+def _to_out(variable: EnvironmentVariable) -> EnvVarOut:
+    return EnvVarOut(
+        id=variable.id,
+        name=variable.name,
+        workflow_id=variable.workflow_id,
+        workflow_name=variable.workflow.name if variable.workflow else None,
+        is_secret=variable.is_secret,
+        value=None if variable.is_secret else variable.value,
+        description=variable.description,
+        updated_at=variable.updated_at,
+    )
+
+
+def _get_own_variable(db: Session, variable_id: int, current_user) -> EnvironmentVariable:
+    variable = (
+        db.query(EnvironmentVariable)
+        .filter(EnvironmentVariable.id == variable_id, EnvironmentVariable.owner_id == current_user.id)
+        .first()
+    )
+    if variable is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Variable not found")
+    return variable
+
+
+def _clean_name(name: str) -> str:
+    try:
+        return validate_env_name(name)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc))
+
+
+def _ensure_unique(db: Session, owner_id: int, workflow_id: Optional[int], name: str, exclude_id: Optional[int] = None):
+    # SQLite treats NULL workflow ids as distinct, so workspace names are checked here too
+    in_scope = (
+        EnvironmentVariable.workflow_id.is_(None)
+        if workflow_id is None
+        else EnvironmentVariable.workflow_id == workflow_id
+    )
+    query = db.query(EnvironmentVariable).filter(
+        EnvironmentVariable.owner_id == owner_id, in_scope, EnvironmentVariable.name == name
+    )
+    if exclude_id is not None:
+        query = query.filter(EnvironmentVariable.id != exclude_id)
+    if query.first() is not None:
+        scope = "this workflow" if workflow_id is not None else "the workspace"
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail=f"{name} is already defined for {scope}")
+
+
+@router.get("/", response_model=List[EnvVarOut])
+def list_env_vars(db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Every variable the caller owns, workspace-wide ones first."""
+
+    variables = (
+        db.query(EnvironmentVariable)
+        .filter(EnvironmentVariable.owner_id == current_user.id)
+        .order_by(EnvironmentVariable.workflow_id.isnot(None), EnvironmentVariable.name)
+        .all()
+    )
+    return [_to_out(variable) for variable in variables]
+
+
+@router.post("/", response_model=EnvVarOut, status_code=status.HTTP_201_CREATED)
+def create_env_var(payload: EnvVarCreate, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    name = _clean_name(payload.name)
+    if payload.workflow_id is not None:
+        workflow = db.query(Workflow).filter_by(id=payload.workflow_id, owner_id=current_user.id).first()
+        if workflow is None:
+            raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Workflow not found")
+    _ensure_unique(db, current_user.id, payload.workflow_id, name)
+
+    variable = EnvironmentVariable(
+        owner_id=current_user.id,
+        workflow_id=payload.workflow_id,
+        name=name,
+        value=encrypt(payload.value) if payload.is_secret else payload.value,
+        is_secret=payload.is_secret,
+        description=payload.description,
+    )
+    db.add(variable)
+    db.commit()
+    db.refresh(variable)
+    return _to_out(variable)
+
+
+@router.patch("/{variable_id}", response_model=EnvVarOut)
+def update_env_var(
+    variable_id: int, payload: EnvVarUpdate, db: Session = Depends(get_db), current_user=Depends(get_current_user)
+):
+    variable = _get_own_variable(db, variable_id, current_user)
+
+    if payload.name is not None:
+        name = _clean_name(payload.name)
+        _ensure_unique(db, current_user.id, variable.workflow_id, name, exclude_id=variable.id)
+        variable.name = name
+
+    is_secret = variable.is_secret if payload.is_secret is None else payload.is_secret
+    if variable.is_secret and not is_secret and payload.value is None:
+        # Turning a secret into a plain value would reveal it
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST, detail="Enter a new value to stop treating this as a secret"
+        )
+    value = plain_value(variable) if payload.value is None else payload.value
+    variable.value = encrypt(value) if is_secret else value
+    variable.is_secret = is_secret
+
+    if payload.description is not None:
+        variable.description = payload.description or None
+
+    db.commit()
+    db.refresh(variable)
+    return _to_out(variable)
+
+
+@router.delete("/{variable_id}", status_code=status.HTTP_204_NO_CONTENT)
+def delete_env_var(variable_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    variable = _get_own_variable(db, variable_id, current_user)
+    db.delete(variable)
+    db.commit()
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
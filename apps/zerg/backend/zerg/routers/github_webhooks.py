@@ -0,0 +1,108 @@
+"""GitHub webhook endpoint – fires ``github`` triggers.
+
+Each trigger's payload URL is ``https://<host>/api/github/webhooks/{trigger_id}``
+with content type ``application/json``.  Requests are authenticated with the
+trigger's secret (GitHub's ``X-Hub-Signature-256``) rather than a user session.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Path
+from fastapi import Request
+from fastapi import status
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.database import get_db
+from zerg.routers.triggers import _fire_webhook
+from zerg.routers.triggers import _payload_size
+from zerg.routers.triggers import _reject_delivery
+from zerg.services.github_events import match_summary
+from zerg.services.github_events import skip_reason
+from zerg.services.github_events import summarize_payload
+from zerg.services.github_events import verify_github_signature
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(prefix="/github", tags=["github"])
+
+
+@router.post("/webhooks/{trigger_id}", status_code=status.HTTP_202_ACCEPTED)
+async def github_webhook(
+    request: Request,
+    trigger_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+):
+    """Verify a delivery and run the agent when the event matches the trigger.
+
+    Pings and events the trigger doesn't listen for are still acknowledged so
+    GitHub doesn't flag the hook as failing; they show up in the trigger's
+    delivery history as skipped.
+
+    Unknown triggers, non-GitHub triggers and bad signatures all get the same
+    403 and are not recorded, as for plain webhooks.  A redelivery of an event
+    that was already handled (same ``X-GitHub-Delivery``) is acknowledged
+    without firing again.
+    """
+
+    body = await request.body()
+    trg = crud.get_trigger(db, trigger_id)
+    if (
+        trg is None
+        or trg.type != "github"
+        or not verify_github_signature(trg.secret, body, request.headers.get("X-Hub-Signature-256", ""))
+    ):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Invalid signature")
+
+    try:
+        payload = json.loads(body) if body else {}
+    except ValueError:
+        payload = None
+    if not isinstance(payload, dict):
+        _reject_delivery(db, trg, {}, status.HTTP_400_BAD_REQUEST, "Body must be a JSON object")
+
+    delivery_id = request.headers.get("X-GitHub-Delivery") or None
+    if delivery_id and crud.has_accepted_delivery(db, trg.id, delivery_id):
+        logger.info("GitHub delivery %s for trigger %s already handled", delivery_id, trg.id)
+        return {"ok": True, "fired": False, "duplicate": True}
+
+    event = request.headers.get("X-GitHub-Event", "")
+    if event == "ping":
+        crud.record_trigger_delivery(
+            db,
+            trigger_id=trg.id,
+            status_code=status.HTTP_200_OK,
+            error="Ping",
+            payload_bytes=_payload_size(payload),
+            external_id=delivery_id,
+        )
+        return {"ok": True}
+
+    reason = skip_reason(trg.config, event, payload)
+    if reason:
+        crud.record_trigger_delivery(
+            db,
+            trigger_id=trg.id,
+            status_code=status.HTTP_200_OK,
+            error=f"Skipped: {reason}",
+            payload_bytes=_payload_size(payload),
+            external_id=delivery_id,
+        )
+        return {"ok": True, "fired": False}
+
+    logger.info("GitHub %s delivery %s fired trigger %s", event, delivery_id, trg.id)
+    await _fire_webhook(
+        db,
+        trg,
+        summarize_payload(event, payload),
+        trigger_type="github",
+        matched=match_summary(event, payload),
+        external_id=delivery_id,
+    )
+    return {"ok": True, "fired": True}
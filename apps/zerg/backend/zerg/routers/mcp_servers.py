@@ -1,13 +1,18 @@
 """MCP server management routes."""
 
+import asyncio
 import logging
+from datetime import datetime
 from typing import Any
 from typing import Dict
 from typing import List
+from typing import Optional
+from typing import Tuple
 
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import Query
 from fastapi import status
 from pydantic import BaseModel
 from pydantic import Field
@@ -17,9 +22,12 @@ from zerg.crud import crud
 from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
 from zerg.schemas.schemas import Agent
+from zerg.services.api_credentials import expand_mcp_servers
+from zerg.services.api_credentials import get_owned_credential
 
 # MCP manager singleton – needed by several endpoints
 from zerg.tools.mcp_adapter import MCPManager  # noqa: E402 – placed after stdlib imports
+from zerg.tools.mcp_adapter import MCPServerHealth
 from zerg.tools.mcp_exceptions import MCPAuthenticationError
 from zerg.tools.mcp_exceptions import MCPConfigurationError
 from zerg.tools.mcp_exceptions import MCPConnectionError
@@ -36,6 +44,13 @@ router = APIRouter(
     dependencies=[Depends(get_current_user)],
 )
 
+# Cross-agent view (health dashboard) – not nested under a single agent
+health_router = APIRouter(
+    prefix="/mcp-servers",
+    tags=["mcp-servers"],
+    dependencies=[Depends(get_current_user)],
+)
+
 
 # Pydantic models for request/response
 class MCPServerAddRequest(BaseModel):
@@ -50,6 +65,9 @@ class MCPServerAddRequest(BaseModel):
 
     # Common fields
     auth_token: str = Field(None, description="Authentication token for the MCP server")
+    api_credential_id: int = Field(
+        None, description="API credential whose auth header is sent (custom servers default to its base URL)"
+    )
     allowed_tools: List[str] = Field(None, description="List of allowed tools (None means all)")
 
     # Custom validation
@@ -57,7 +75,7 @@ class MCPServerAddRequest(BaseModel):
         """Validate that either preset or (url, name) is provided."""
         if self.preset and (self.url or self.name):
             raise ValueError("Cannot specify both 'preset' and custom server fields")
-        if not self.preset and not (self.url and self.name):
+        if not self.preset and not (self.name and (self.url or self.api_credential_id)):
             raise ValueError("Must specify either 'preset' or both 'url' and 'name'")
 
 
@@ -79,7 +97,92 @@ class MCPTestConnectionResponse(BaseModel):
     tools: List[str] = Field(default_factory=list)
 
 
+class MCPHealthSampleResponse(BaseModel):
+    """One health probe result."""
+
+    status: str
+    checked_at: datetime
+    latency_ms: Optional[float] = None
+    error: Optional[str] = None
+
+
+class MCPServerHealthResponse(BaseModel):
+    """Health of one configured MCP server on one agent."""
+
+    agent_id: int
+    agent_name: str
+    name: str
+    url: str
+    preset: Optional[str] = None
+    tool_count: int = 0
+    status: str = "unknown"  # online, offline, auth_error, config_error, unknown (never checked)
+    latency_ms: Optional[float] = None
+    last_checked: Optional[datetime] = None
+    error: Optional[str] = None
+    history: List[MCPHealthSampleResponse] = Field(default_factory=list)
+
+
 # Helper functions
+def _server_identity(server_config: Dict[str, Any]) -> Tuple[str, str]:
+    """(name, url) for a stored server entry; unknown presets keep their key."""
+    if "preset" in server_config:
+        preset_name = server_config["preset"]
+        preset = PRESET_MCP_SERVERS.get(preset_name)
+        if preset:
+            return preset.name, preset.url
+        return preset_name, "unknown"
+    return server_config.get("name", "unknown"), server_config.get("url", "unknown")
+
+
+def _server_tools(name: str) -> List[str]:
+    tool_prefix = f"mcp_{name}_"
+    return [tool.name for tool in get_tool_resolver().get_all_tools() if tool.name.startswith(tool_prefix)]
+
+
+def _health_response(
+    agent, server_config: Dict[str, Any], health: Optional[MCPServerHealth]
+) -> MCPServerHealthResponse:
+    name, url = _server_identity(server_config)
+    latest = health.latest if health else None
+    return MCPServerHealthResponse(
+        agent_id=agent.id,
+        agent_name=agent.name,
+        name=name,
+        url=url,
+        preset=server_config.get("preset"),
+        tool_count=len(_server_tools(name)),
+        status=latest.status if latest else "unknown",
+        latency_ms=latest.latency_ms if latest else None,
+        last_checked=latest.checked_at if latest else None,
+        error=latest.error if latest else None,
+        history=[MCPHealthSampleResponse(**vars(sample)) for sample in (health.samples if health else [])],
+    )
+
+
+def _build_server_config(db: Session, agent, request: MCPServerAddRequest) -> Dict[str, Any]:
+    """The entry stored on the agent; the auth token is left for the caller to encrypt."""
+    credential = None
+    if request.api_credential_id is not None:
+        credential = get_owned_credential(db, agent.owner_id, request.api_credential_id)
+        if credential is None:
+            raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="API credential not found")
+
+    if request.preset:
+        server_config: Dict[str, Any] = {"preset": request.preset}
+    else:
+        server_config = {"url": request.url or credential.base_url, "name": request.name}
+    if credential is not None:
+        server_config["api_credential_id"] = credential.id
+    if request.allowed_tools:
+        server_config["allowed_tools"] = request.allowed_tools
+    return server_config
+
+
+def _connectable(db: Session, agent, server_config: Dict[str, Any]) -> Dict[str, Any]:
+    """*server_config* with its API credential's header, ready for the MCP manager."""
+    return expand_mcp_servers(db, agent.owner_id, [server_config])[0]
+
+
 def _get_mcp_servers_from_config(config: Dict[str, Any]) -> List[Dict[str, Any]]:
     """Extract MCP server configurations from agent config."""
     if not config:
@@ -116,28 +219,14 @@ async def list_mcp_servers(
 
     # Build response with tool information
     response = []
-    resolver = get_tool_resolver()
 
     for server_config in mcp_servers:
-        if "preset" in server_config:
-            preset_name = server_config["preset"]
-            if preset_name in PRESET_MCP_SERVERS:
-                preset = PRESET_MCP_SERVERS[preset_name]
-                name = preset.name
-                url = preset.url
-            else:
-                # Unknown preset – still include it in the list so the UI
-                # can show *offline* status and allow the user to troubleshoot
-                # or remove the entry.
-                name = preset_name
-                url = "unknown"
-        else:
-            name = server_config.get("name", "unknown")
-            url = server_config.get("url", "unknown")
+        # Unknown presets are still listed so the UI can show *offline*
+        # status and allow the user to troubleshoot or remove the entry.
+        name, url = _server_identity(server_config)
 
         # Get tools for this server
-        tool_prefix = f"mcp_{name}_"
-        tools = [tool.name for tool in resolver.get_all_tools() if tool.name.startswith(tool_prefix)]
+        tools = _server_tools(name)
 
         response.append(
             MCPServerResponse(
@@ -184,30 +273,15 @@ async def add_mcp_server(
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized to modify this agent")
 
     # Build MCP server config
-    if request.preset:
-        server_config: Dict[str, Any] = {
-            "preset": request.preset,
-        }
-        # Encrypt auth token if provided
-        if request.auth_token:
-            server_config["auth_token"] = crypto.encrypt(request.auth_token)
-    else:
-        # Validate HTTPS URL for security
-        if not request.url.startswith("https://"):
-            raise HTTPException(
-                status_code=status.HTTP_400_BAD_REQUEST, detail="MCP server URL must use HTTPS for security"
-            )
-
-        server_config = {
-            "url": request.url,
-            "name": request.name,
-        }
-        # Encrypt auth token if provided
-        if request.auth_token:
-            server_config["auth_token"] = crypto.encrypt(request.auth_token)
-
-    if request.allowed_tools:
-        server_config["allowed_tools"] = request.allowed_tools
+    server_config = _build_server_config(db, agent, request)
+    # Validate HTTPS URL for security
+    if not request.preset and not server_config["url"].startswith("https://"):
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST, detail="MCP server URL must use HTTPS for security"
+        )
+    # Encrypt auth token if provided
+    if request.auth_token:
+        server_config["auth_token"] = crypto.encrypt(request.auth_token)
 
     # Add to agent config
     current_config = agent.config or {}
@@ -220,16 +294,16 @@ async def add_mcp_server(
                 status_code=status.HTTP_400_BAD_REQUEST,
                 detail=f"Preset '{request.preset}' is already configured for this agent",
             )
-        elif not request.preset and existing.get("url") == request.url:
+        elif not request.preset and existing.get("url") == server_config["url"]:
             raise HTTPException(
                 status_code=status.HTTP_400_BAD_REQUEST,
-                detail=f"Server URL '{request.url}' is already configured for this agent",
+                detail=f"Server URL '{server_config['url']}' is already configured for this agent",
             )
 
     # Try to connect to the server
     try:
         manager = MCPManager()
-        manager.add_server(server_config)
+        manager.add_server(_connectable(db, agent, server_config))
     except MCPAuthenticationError as e:
         raise HTTPException(status_code=status.HTTP_401_UNAUTHORIZED, detail=str(e))
     except MCPConnectionError as e:
@@ -313,25 +387,12 @@ async def test_mcp_connection(
         )
 
     # Build MCP server config
-    if request.preset:
-        server_config: Dict[str, Any] = {
-            "preset": request.preset,
-            "auth_token": request.auth_token,
-        }
-    else:
-        server_config = {
-            "url": request.url,
-            "name": request.name,
-            "auth_token": request.auth_token,
-        }
-
-    if request.allowed_tools:
-        server_config["allowed_tools"] = request.allowed_tools
+    server_config = {**_build_server_config(db, agent, request), "auth_token": request.auth_token}
 
     # Try to connect to the server
     try:
         manager = MCPManager()
-        manager.add_server(server_config)
+        manager.add_server(_connectable(db, agent, server_config))
 
         # Get tools that were registered
         resolver = get_tool_resolver()
@@ -411,3 +472,67 @@ async def get_available_tools(
         "builtin": builtin_tools,
         "mcp": mcp_tools,
     }
+
+
+@router.post("/{server_name}/reconnect", response_model=MCPServerHealthResponse)
+async def reconnect_mcp_server(
+    agent_id: int,
+    server_name: str,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Re-establish the connection to an MCP server and re-register its tools."""
+    agent = crud.get_agent(db, agent_id=agent_id)
+    if not agent:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+
+    if agent.owner_id != current_user.id and current_user.role != "ADMIN":
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized to modify this agent")
+
+    server_config = next(
+        (
+            cfg
+            for cfg in _get_mcp_servers_from_config(agent.config)
+            if cfg.get("preset") == server_name or cfg.get("name") == server_name
+        ),
+        None,
+    )
+    if server_config is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail=f"MCP server '{server_name}' not found")
+
+    manager = MCPManager()
+    connectable = _connectable(db, agent, server_config)
+    try:
+        health = await manager.reconnect_async(connectable)
+    except MCPConfigurationError as e:
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail=str(e))
+    except MCPAuthenticationError:
+        # Recorded as an auth_error sample; the dashboard shows it inline
+        health = await manager.check_health_async(connectable)
+    except MCPConnectionError:
+        health = await manager.check_health_async(connectable)
+
+    return _health_response(agent, server_config, health)
+
+
+@health_router.get("/health", response_model=List[MCPServerHealthResponse])
+async def list_mcp_server_health(
+    refresh: bool = Query(False, description="Probe every server now instead of returning the last results"),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Health of every MCP server configured on the current user's agents.
+
+    Servers shared by several agents are probed once per refresh.
+    """
+    agents = crud.get_agents(db, owner_id=current_user.id, limit=1000)
+    entries = [(agent, cfg) for agent in agents for cfg in _get_mcp_servers_from_config(agent.config)]
+
+    manager = MCPManager()
+    if refresh:
+        unique: Dict[Tuple[str, str], Dict[str, Any]] = {}
+        for agent, cfg in entries:
+            unique.setdefault(_server_identity(cfg), _connectable(db, agent, cfg))
+        await asyncio.gather(*(manager.check_health_async(cfg) for cfg in unique.values()))
+
+    return [_health_response(agent, cfg, manager.get_health(cfg)) for agent, cfg in entries]
@@ -1,6 +1,6 @@
 """OAuth routes for third-party connector authorization.
 
-Implements OAuth 2.0 Authorization Code flow for services like GitHub.
+Implements OAuth 2.0 Authorization Code flow for services like GitHub and Slack.
 Users click "Connect" -> redirect to provider -> callback with code -> exchange for token.
 """
 
@@ -48,6 +48,21 @@ GITHUB_TOKEN_URL = "https://github.com/login/oauth/access_token"
 # - read:user: Read user profile data
 GITHUB_SCOPES = "repo read:user"
 
+# ---------------------------------------------------------------------------
+# Slack OAuth Configuration
+# ---------------------------------------------------------------------------
+
+SLACK_AUTHORIZE_URL = "https://slack.com/oauth/v2/authorize"
+SLACK_TOKEN_URL = "https://slack.com/api/oauth.v2.access"
+
+# Bot scopes for the Slack integration
+# - chat:write / incoming-webhook: Send to Slack (channel posts and the picked webhook channel)
+# - channels:read: list channels for pickers
+# - app_mentions:read, channels:history, reactions:read: Slack-event triggers
+SLACK_SCOPES = "chat:write,incoming-webhook,channels:read,app_mentions:read,channels:history,reactions:read"
+
+_PROVIDER_NAMES = {"github": "GitHub", "slack": "Slack"}
+
 
 # ---------------------------------------------------------------------------
 # State management (in-memory for simplicity, use Redis in production)
@@ -223,54 +238,204 @@ def github_callback(
         scope_list = []
 
     # Store credentials in database
-    credentials = {"token": access_token}
+    _store_oauth_credential(
+        db,
+        user_id,
+        "github",
+        credentials={"token": access_token},
+        display_name=f"@{github_login}",
+        metadata={
+            "login": github_login,
+            "name": github_name,
+            "scopes": scope_list,
+            "connected_via": "oauth",
+        },
+    )
+
+    return _oauth_result_page(
+        success=True,
+        provider="github",
+        username=github_login,
+    )
+
+
+# ---------------------------------------------------------------------------
+# Slack OAuth Routes
+# ---------------------------------------------------------------------------
+
+
+def _slack_callback_url(request: Request | None = None) -> str:
+    if _settings.app_public_url:
+        return f"{_settings.app_public_url.rstrip('/')}/api/oauth/slack/callback"
+    if request is not None:
+        return str(request.url_for("slack_callback"))
+    return "http://localhost:8000/api/oauth/slack/callback"
+
+
+@router.get("/slack/authorize")
+def slack_authorize(
+    request: Request,
+    current_user: User = Depends(get_current_user),
+) -> RedirectResponse:
+    """Initiate Slack OAuth flow (installs the Zerg app into a workspace).
+
+    The user picks a workspace and a channel for the incoming webhook, then
+    Slack redirects back to /oauth/slack/callback with an authorization code.
+    """
+    if not _settings.slack_client_id:
+        raise HTTPException(
+            status_code=status.HTTP_500_INTERNAL_SERVER_ERROR,
+            detail="Slack OAuth not configured (missing SLACK_CLIENT_ID)",
+        )
+
+    params = {
+        "client_id": _settings.slack_client_id,
+        "redirect_uri": _slack_callback_url(request),
+        "scope": SLACK_SCOPES,
+        "state": _generate_state(current_user.id),
+    }
+
+    logger.info("Redirecting user %d to Slack OAuth", current_user.id)
+    return RedirectResponse(url=f"{SLACK_AUTHORIZE_URL}?{urlencode(params)}", status_code=302)
+
+
+@router.get("/slack/callback")
+def slack_callback(
+    code: str | None = Query(None),
+    state: str = Query(...),
+    error: str | None = Query(None),
+    db: Session = Depends(get_db),
+) -> HTMLResponse:
+    """Handle Slack OAuth callback.
+
+    Exchanges the code for a bot token and stores it, together with the
+    incoming webhook URL Slack created for the chosen channel, as the
+    account's Slack credential.  The workspace id is kept in the metadata so
+    Slack events can be routed back to this account.
+    """
+    if error or not code:
+        logger.warning("Slack OAuth error: %s", error)
+        return _oauth_result_page(success=False, provider="slack", error=error or "Authorization was cancelled")
+
+    user_id = _validate_state(state)
+    if user_id is None:
+        logger.warning("Invalid or expired OAuth state token")
+        return _oauth_result_page(
+            success=False,
+            provider="slack",
+            error="Invalid or expired authorization request. Please try again.",
+        )
+
+    if not _settings.slack_client_id or not _settings.slack_client_secret:
+        logger.error("Slack OAuth credentials not configured")
+        return _oauth_result_page(success=False, provider="slack", error="Slack OAuth not configured on server")
+
+    try:
+        token_response = httpx.post(
+            SLACK_TOKEN_URL,
+            data={
+                "client_id": _settings.slack_client_id,
+                "client_secret": _settings.slack_client_secret,
+                "code": code,
+                "redirect_uri": _slack_callback_url(),
+            },
+            timeout=10.0,
+        )
+        token_response.raise_for_status()
+        token_data = token_response.json()
+    except httpx.HTTPError as e:
+        logger.exception("Slack token exchange failed")
+        return _oauth_result_page(
+            success=False,
+            provider="slack",
+            error=f"Failed to exchange code for token: {str(e)}",
+        )
+
+    # Slack reports errors with HTTP 200 and ok=false
+    if not token_data.get("ok") or not token_data.get("access_token"):
+        logger.warning("Slack token error: %s", token_data.get("error"))
+        return _oauth_result_page(
+            success=False,
+            provider="slack",
+            error=token_data.get("error") or "Slack did not return an access token",
+        )
+
+    team = token_data.get("team") or {}
+    webhook = token_data.get("incoming_webhook") or {}
+    team_name = team.get("name") or "Slack"
+
+    credentials = {"bot_token": token_data["access_token"]}
+    if webhook.get("url"):
+        credentials["webhook_url"] = webhook["url"]
+
+    _store_oauth_credential(
+        db,
+        user_id,
+        "slack",
+        credentials=credentials,
+        display_name=f"{team_name} {webhook['channel']}" if webhook.get("channel") else team_name,
+        metadata={
+            "team": team_name,
+            "team_id": team.get("id"),
+            "channel": webhook.get("channel"),
+            "bot_user_id": token_data.get("bot_user_id"),
+            "scopes": [s for s in (token_data.get("scope") or "").split(",") if s],
+            "connected_via": "oauth",
+        },
+    )
+
+    return _oauth_result_page(success=True, provider="slack", username=team_name)
+
+
+# ---------------------------------------------------------------------------
+# Helper: credential upsert
+# ---------------------------------------------------------------------------
+
+
+def _store_oauth_credential(
+    db: Session,
+    user_id: int,
+    connector_type: str,
+    *,
+    credentials: dict,
+    display_name: str,
+    metadata: dict,
+) -> None:
+    """Create or replace the account-level credential an OAuth flow produced."""
     encrypted = encrypt(json.dumps(credentials))
+    name = _PROVIDER_NAMES.get(connector_type, connector_type)
 
-    # Upsert: check if credential exists for this user
     existing = (
         db.query(AccountConnectorCredential)
         .filter(
             AccountConnectorCredential.owner_id == user_id,
-            AccountConnectorCredential.connector_type == "github",
+            AccountConnectorCredential.connector_type == connector_type,
         )
         .first()
     )
 
-    metadata = {
-        "login": github_login,
-        "name": github_name,
-        "scopes": scope_list,
-        "connected_via": "oauth",
-    }
-
     if existing:
         existing.encrypted_value = encrypted
-        existing.display_name = f"@{github_login}"
+        existing.display_name = display_name
         existing.test_status = "success"
         existing.last_tested_at = datetime.utcnow()
         existing.connector_metadata = metadata
-        logger.info("Updated GitHub credentials for user %d via OAuth", user_id)
+        logger.info("Updated %s credentials for user %d via OAuth", name, user_id)
     else:
         cred = AccountConnectorCredential(
             owner_id=user_id,
-            connector_type="github",
+            connector_type=connector_type,
             encrypted_value=encrypted,
-            display_name=f"@{github_login}",
+            display_name=display_name,
             test_status="success",
             last_tested_at=datetime.utcnow(),
             connector_metadata=metadata,
         )
         db.add(cred)
-        logger.info("Created GitHub credentials for user %d via OAuth", user_id)
+        logger.info("Created %s credentials for user %d via OAuth", name, user_id)
 
     db.commit()
 
-    return _oauth_result_page(
-        success=True,
-        provider="github",
-        username=github_login,
-    )
-
 
 # ---------------------------------------------------------------------------
 # Helper: OAuth result page
@@ -298,6 +463,8 @@ def _oauth_result_page(
         result["error"] = error
 
     result_json = json.dumps(result)
+    provider_name = _PROVIDER_NAMES.get(provider, provider)
+    connected_as = f"@{username}" if provider == "github" else username
 
     html = f"""
     <!DOCTYPE html>
@@ -339,8 +506,8 @@ def _oauth_result_page(
     <body>
         <div class="container">
             <div class="icon">{"✓" if success else "✗"}</div>
-            <h1>{"GitHub Connected!" if success else "Connection Failed"}</h1>
-            <p>{f"Connected as @{username}" if success and username else error or "This window will close automatically."}</p>
+            <h1>{f"{provider_name} Connected!" if success else "Connection Failed"}</h1>
+            <p>{f"Connected as {connected_as}" if success and username else error or "This window will close automatically."}</p>
         </div>
         <script>
             // Send result to parent window
@@ -1,8 +1,9 @@
-"""Admin-only Ops Dashboard APIs."""
+"""Ops Dashboard APIs (admin-only), plus the status page summary any signed-in user can read."""
 
 from __future__ import annotations
 
 from datetime import datetime
+from datetime import timedelta
 from typing import Any
 
 from fastapi import APIRouter
@@ -13,11 +14,14 @@ from fastapi import Request
 from sqlalchemy.orm import Session
 
 from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
 from zerg.dependencies.auth import require_admin
 from zerg.models.models import User as UserModel
 from zerg.schemas.ops import OpsSummary
+from zerg.schemas.ops import SystemStatus
 from zerg.schemas.ops import TimeSeriesResponse
 from zerg.schemas.ops import TopAgentsResponse
+from zerg.services.ops_service import get_status as svc_get_status
 from zerg.services.ops_service import get_summary as svc_get_summary
 from zerg.services.ops_service import get_timeseries as svc_get_timeseries
 from zerg.services.ops_service import get_top_agents as svc_get_top_agents
@@ -46,6 +50,36 @@ async def error_beacon(request: Request):
     return {}
 
 
+# ---------------------------------------------------------------------------
+# Status page (any signed-in user)
+# ---------------------------------------------------------------------------
+status_router = APIRouter(prefix="/ops", tags=["ops"], dependencies=[Depends(get_current_user)])
+
+
+def _frontend_errors_since(since: datetime) -> int:
+    cutoff = since.isoformat()
+    # Beacon timestamps are naive UTC ISO strings, so they sort lexically
+    return sum(1 for error in _frontend_errors if str(error.get("ts", "")) >= cutoff)
+
+
+def _ws_connection_count() -> int | None:
+    try:
+        from zerg.websocket.manager import topic_manager
+    except Exception:  # pragma: no cover – ws manager missing in minimal builds
+        return None
+    return len(topic_manager.active_connections)
+
+
+@status_router.get("/status", response_model=SystemStatus)
+def get_status(db: Session = Depends(get_db)):
+    """System health summary for the status page and wallboards."""
+    return svc_get_status(
+        db,
+        ws_connections=_ws_connection_count(),
+        frontend_errors_last_hour=_frontend_errors_since(datetime.utcnow() - timedelta(hours=1)),
+    )
+
+
 @router.get("/errors")
 def get_frontend_errors(current_user: UserModel = Depends(require_admin)):
     """Admin-only: view recent frontend errors captured via beacon."""
@@ -1,13 +1,15 @@
-"""Runs router – read-only access to AgentRun rows."""
+"""Runs router – read access to AgentRun rows, run cancellation and the per-agent run queue."""
 
 from __future__ import annotations
 
 from typing import List
+from typing import Optional
 
 # FastAPI helpers
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import status
 from sqlalchemy.orm import Session
 
 from zerg.crud import crud
@@ -15,11 +17,17 @@ from zerg.database import get_db
 
 # Auth dependency
 from zerg.dependencies.auth import get_current_user
+from zerg.events import EventType
+from zerg.events.event_bus import event_bus
+from zerg.models.enums import RunStatus
 from zerg.models.models import Agent as AgentModel
 from zerg.models.models import AgentRun as AgentRunModel
 
 # Schemas
 from zerg.schemas.schemas import AgentRunOut
+from zerg.schemas.schemas import RunQueueOrder
+from zerg.services import run_queue
+from zerg.services.run_cancellation import run_cancellations
 
 router = APIRouter(
     tags=["runs"],
@@ -31,11 +39,21 @@ router = APIRouter(
 def list_agent_runs(
     agent_id: int,
     limit: int = 20,
+    before_id: Optional[int] = None,
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
 ):
-    """Return latest *limit* runs for the given agent (descending)."""
+    """Return latest *limit* runs for the given agent (descending).
 
+    Pass the smallest id from the previous page as *before_id* to fetch the
+    next (older) page.
+    """
+
+    _get_owned_agent(db, agent_id, current_user)
+    return crud.list_runs(db, agent_id, limit=limit, before_id=before_id)
+
+
+def _get_owned_agent(db: Session, agent_id: int, current_user) -> AgentModel:
     agent = crud.get_agent(db, agent_id)
     if agent is None:
         raise HTTPException(status_code=404, detail="Agent not found")
@@ -44,12 +62,10 @@ def list_agent_runs(
     is_admin = getattr(current_user, "role", "USER") == "ADMIN"
     if not is_admin and agent.owner_id != current_user.id:
         raise HTTPException(status_code=403, detail="Forbidden: not agent owner")
+    return agent
 
-    return crud.list_runs(db, agent_id, limit=limit)
 
-
-@router.get("/runs/{run_id}", response_model=AgentRunOut)
-def get_run(run_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+def _get_owned_run(db: Session, run_id: int, current_user) -> AgentRunModel:
     row = (
         db.query(AgentRunModel)
         .join(AgentModel, AgentModel.id == AgentRunModel.agent_id)
@@ -64,3 +80,88 @@ def get_run(run_id: int, db: Session = Depends(get_db), current_user=Depends(get
     if not is_admin and row.agent.owner_id != current_user.id:
         raise HTTPException(status_code=403, detail="Forbidden: not agent owner")
     return row
+
+
+@router.get("/runs/{run_id}", response_model=AgentRunOut)
+def get_run(run_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    return _get_owned_run(db, run_id, current_user)
+
+
+@router.post("/runs/{run_id}/cancel", response_model=AgentRunOut, status_code=status.HTTP_202_ACCEPTED)
+async def cancel_run(run_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Ask a queued/running run to stop.
+
+    The run flips to ``cancelling`` straight away and to ``cancelled`` once
+    the agent reaches its next await point. Runs with no live task in this
+    process (e.g. orphaned by a restart) are marked ``cancelled`` directly.
+    """
+    row = _get_owned_run(db, run_id, current_user)
+    if row.status not in {RunStatus.QUEUED, RunStatus.RUNNING, RunStatus.CANCELLING}:
+        raise HTTPException(status_code=409, detail=f"Run already {row.status.value}")
+
+    reason = f"Cancelled by user {current_user.id}"
+    if row.queue_position is not None:
+        return await run_queue.drop_queued_run(db, row, reason=reason)
+    if run_cancellations.is_live(run_id):
+        row = crud.mark_cancelling(db, run_id, reason=reason)
+        run_cancellations.request_cancel(run_id)
+        event = {"status": "cancelling"}
+    else:
+        row = crud.mark_cancelled(db, run_id, reason=reason)
+        event = {"status": "cancelled", "finished_at": row.finished_at.isoformat(), "duration_ms": row.duration_ms}
+        # Nothing will flip the agent back once its run is gone
+        if row.agent.status == "running":
+            crud.update_agent(db, row.agent_id, status="idle")
+            await event_bus.publish(
+                EventType.AGENT_UPDATED, {"event_type": "agent_updated", "id": row.agent_id, "status": "idle"}
+            )
+
+    await event_bus.publish(
+        EventType.RUN_UPDATED,
+        {"event_type": "run_updated", "agent_id": row.agent_id, "run_id": row.id, "thread_id": row.thread_id, **event},
+    )
+    return row
+
+
+# ---------------------------------------------------------------------------
+# Run queue – runs waiting for the agent's active run to finish
+# ---------------------------------------------------------------------------
+
+
+@router.get("/agents/{agent_id}/queue", response_model=List[AgentRunOut])
+def get_run_queue(agent_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Queued runs for the agent, next to start first."""
+    _get_owned_agent(db, agent_id, current_user)
+    return crud.list_queued_runs(db, agent_id)
+
+
+@router.put("/agents/{agent_id}/queue", response_model=List[AgentRunOut])
+async def reorder_run_queue(
+    agent_id: int,
+    order: RunQueueOrder,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Reorder the queue. ``run_ids`` must name every queued run exactly once."""
+    _get_owned_agent(db, agent_id, current_user)
+    try:
+        crud.set_queue_order(db, agent_id, order.run_ids)
+    except ValueError as exc:
+        # Usually a run started or was dropped since the client loaded the queue
+        raise HTTPException(status_code=409, detail=str(exc)) from exc
+    return await run_queue.publish_queue(db, agent_id)
+
+
+@router.delete("/agents/{agent_id}/queue/{run_id}", response_model=AgentRunOut)
+async def drop_queued_run(
+    agent_id: int,
+    run_id: int,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Remove a run from the queue; it is recorded as ``cancelled``."""
+    _get_owned_agent(db, agent_id, current_user)
+    row = next((run for run in crud.list_queued_runs(db, agent_id) if run.id == run_id), None)
+    if row is None:
+        raise HTTPException(status_code=404, detail="Run is not queued")
+    return await run_queue.drop_queued_run(db, row, reason=f"Dropped from queue by user {current_user.id}")
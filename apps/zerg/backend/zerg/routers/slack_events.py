@@ -0,0 +1,82 @@
+"""Slack Events API endpoint – fires ``slack`` triggers.
+
+Configure ``https://<host>/api/slack/events`` as the Request URL of the Slack
+app's Event Subscriptions.  Requests are authenticated with Slack's signing
+secret (``SLACK_SIGNING_SECRET``) rather than a user session.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import time
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Request
+from fastapi import status
+from sqlalchemy.orm import Session
+
+from zerg import constants
+from zerg.config import get_settings
+from zerg.database import get_db
+from zerg.routers.triggers import _fire_webhook
+from zerg.services.slack_events import match_summary
+from zerg.services.slack_events import matching_triggers
+from zerg.services.slack_events import verify_slack_signature
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(prefix="/slack", tags=["slack"])
+
+
+@router.post("/events")
+async def slack_events(request: Request, db: Session = Depends(get_db)):
+    """Handle URL verification and ``event_callback`` deliveries.
+
+    Slack expects an answer within three seconds and redelivers otherwise, so
+    retries (``X-Slack-Retry-Num``) are acknowledged without firing again.
+    """
+
+    settings = get_settings()
+    if not settings.slack_signing_secret:
+        raise HTTPException(status_code=status.HTTP_503_SERVICE_UNAVAILABLE, detail="Slack events not configured")
+
+    body = await request.body()
+    timestamp = request.headers.get("X-Slack-Request-Timestamp", "")
+    try:
+        skew = abs(int(time.time()) - int(timestamp))
+    except ValueError:
+        raise HTTPException(status_code=400, detail="Invalid X-Slack-Request-Timestamp header")
+    if skew > constants.TRIGGER_TIMESTAMP_TOLERANCE_S:
+        raise HTTPException(status_code=400, detail="Timestamp skew too large")
+    if not verify_slack_signature(
+        settings.slack_signing_secret, timestamp, body, request.headers.get("X-Slack-Signature", "")
+    ):
+        raise HTTPException(status_code=403, detail="Invalid signature")
+
+    try:
+        envelope = json.loads(body)
+    except ValueError:
+        raise HTTPException(status_code=400, detail="Invalid JSON body")
+
+    if envelope.get("type") == "url_verification":
+        return {"challenge": envelope.get("challenge")}
+
+    if envelope.get("type") != "event_callback" or request.headers.get("X-Slack-Retry-Num"):
+        return {"ok": True}
+
+    team_id = envelope.get("team_id")
+    event = envelope.get("event") or {}
+    fired = 0
+    for trg in matching_triggers(db, team_id, event):
+        payload = {"team_id": team_id, "event_id": envelope.get("event_id"), "event": event}
+        try:
+            await _fire_webhook(db, trg, payload, trigger_type="slack", matched=match_summary(trg.config, event))
+            fired += 1
+        except HTTPException as exc:
+            # Suspended agents are logged as a rejected delivery; keep going
+            logger.info("Slack event skipped for trigger %s: %s", trg.id, exc.detail)
+
+    return {"ok": True, "fired": fired}
@@ -10,10 +10,14 @@ from typing import Any
 from typing import Dict
 
 from fastapi import APIRouter
+from fastapi import Depends
 from fastapi import status
 from sqlalchemy import text
+from sqlalchemy.orm import Session
 
 from zerg.config import get_settings
+from zerg.crud import crud
+from zerg.database import get_db
 from zerg.database import get_session_factory
 
 try:  # optional – ws manager may not be present in minimal builds
@@ -27,7 +31,7 @@ _settings = get_settings()
 
 
 @router.get("/info", status_code=status.HTTP_200_OK)
-def system_info() -> Dict[str, Any]:
+def system_info(db: Session = Depends(get_db)) -> Dict[str, Any]:
     """Return non-sensitive runtime switches used by the SPA at startup."""
 
     return {
@@ -35,6 +39,8 @@ def system_info() -> Dict[str, Any]:
         "google_client_id": _settings.google_client_id,
         # Surface public URL so frontend can compute callback routes when needed
         "app_public_url": _settings.app_public_url,
+        # Admin-controlled: schedules and triggers are suspended while on
+        "maintenance_mode": crud.is_maintenance_mode(db),
     }
 
 
@@ -0,0 +1,74 @@
+"""Tags router – list, rename, merge and delete the caller's agent and workflow tags.
+
+Tags themselves are set through the agent and workflow update endpoints;
+this router works across every row that carries one.
+"""
+
+from __future__ import annotations
+
+from typing import List
+
+from fastapi import APIRouter
+from fastapi import Depends
+from fastapi import HTTPException
+from fastapi import Query
+from fastapi import status
+from pydantic import BaseModel
+from sqlalchemy.orm import Session
+
+from zerg.database import get_db
+from zerg.dependencies.auth import get_current_user
+from zerg.services import tags as tag_service
+
+router = APIRouter(
+    prefix="/tags",
+    tags=["tags"],
+    dependencies=[Depends(get_current_user)],
+)
+
+
+class TagOut(BaseModel):
+    name: str
+    agent_count: int
+    workflow_count: int
+
+
+class TagRename(BaseModel):
+    tag: str
+    # Renaming onto an existing tag merges the two
+    name: str
+
+
+def _own_tags(db: Session, owner_id: int) -> List[TagOut]:
+    return [TagOut(**entry) for entry in tag_service.list_tags(db, owner_id)]
+
+
+@router.get("/", response_model=List[TagOut])
+def list_tags(db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Every tag on the caller's agents and workflows, with usage counts."""
+
+    return _own_tags(db, current_user.id)
+
+
+@router.post("/rename", response_model=List[TagOut])
+def rename_tag(payload: TagRename, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Rename a tag everywhere it is used, or merge it into another; returns the updated tag list."""
+
+    try:
+        changed = tag_service.rename_tag(db, current_user.id, payload.tag, payload.name)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc))
+    if changed == 0:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Tag not found")
+    return _own_tags(db, current_user.id)
+
+
+@router.delete("/", response_model=List[TagOut])
+def delete_tag(
+    tag: str = Query(..., min_length=1), db: Session = Depends(get_db), current_user=Depends(get_current_user)
+):
+    """Remove a tag from every agent and workflow; returns the updated tag list."""
+
+    if tag_service.delete_tag(db, current_user.id, tag) == 0:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Tag not found")
+    return _own_tags(db, current_user.id)
@@ -1,9 +1,11 @@
 from typing import List
+from typing import Literal
 from typing import Optional
 
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import Query
 from sqlalchemy.orm import Session
 
 from zerg.crud import crud
@@ -11,6 +13,9 @@ from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
 from zerg.models.models import User
 from zerg.schemas.schemas import TemplateDeployRequest
+from zerg.schemas.schemas import TemplateFavoriteRequest
+from zerg.schemas.schemas import TemplatePublishRequest
+from zerg.schemas.schemas import TemplateRatingRequest
 from zerg.schemas.schemas import Workflow
 from zerg.schemas.schemas import WorkflowTemplate
 from zerg.schemas.schemas import WorkflowTemplateCreate
@@ -23,6 +28,23 @@ router = APIRouter(
 )
 
 
+def _with_feedback(db: Session, templates, user_id: int) -> List[WorkflowTemplate]:
+    """Attach rating/favorite totals and the caller's own feedback."""
+    summary = crud.get_template_feedback_summary(db, [template.id for template in templates], user_id)
+    return [
+        WorkflowTemplate.model_validate(template).model_copy(update=summary[template.id]) for template in templates
+    ]
+
+
+def _get_visible_template(db: Session, template_id: int, user: User):
+    template = crud.get_workflow_template(db=db, template_id=template_id)
+    if not template:
+        raise HTTPException(status_code=404, detail="Template not found")
+    if not template.is_public and template.created_by != user.id:
+        raise HTTPException(status_code=403, detail="Access denied to this template")
+    return template
+
+
 @router.post("/", response_model=WorkflowTemplate)
 def create_template(
     *,
@@ -53,6 +75,43 @@ def create_template(
     return template
 
 
+@router.post("/publish", response_model=WorkflowTemplate, status_code=201)
+def publish_workflow(
+    *,
+    db: Session = Depends(get_db),
+    publish_in: TemplatePublishRequest,
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Publish one of your workflows as a gallery template.
+    """
+    workflow = crud.get_workflow(db, publish_in.workflow_id)
+    if workflow is None or not workflow.is_active:
+        raise HTTPException(status_code=404, detail="Workflow not found")
+    if workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=403, detail="Only the workflow owner can publish it")
+
+    try:
+        canvas = WorkflowData(**(workflow.canvas or {})).model_dump(by_alias=True)
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=f"Workflow canvas cannot be published: {e}")
+    if not canvas.get("nodes"):
+        raise HTTPException(status_code=400, detail="Cannot publish an empty workflow")
+
+    template = crud.create_workflow_template(
+        db=db,
+        created_by=current_user.id,
+        name=publish_in.name,
+        description=publish_in.description,
+        category=publish_in.category,
+        canvas=canvas,
+        tags=publish_in.tags,
+        preview_image_url=publish_in.preview_image_url,
+        is_public=publish_in.is_public,
+    )
+    return _with_feedback(db, [template], current_user.id)[0]
+
+
 @router.get("/", response_model=List[WorkflowTemplate])
 def list_templates(
     db: Session = Depends(get_db),
@@ -60,18 +119,30 @@ def list_templates(
     skip: int = 0,
     limit: int = 100,
     my_templates: bool = False,
+    sort: Optional[Literal["recent", "rating", "popular"]] = None,
+    min_rating: Optional[float] = Query(None, ge=1, le=5),
+    favorites: bool = False,
     current_user: User = Depends(get_current_user),
 ):
     """
     List workflow templates. By default shows public templates.
     Set my_templates=true to see your own templates (public and private).
+    sort orders by recency, average rating or favorite count; favorites=true
+    keeps only templates you have favorited.
     """
+    filters = {
+        "category": category,
+        "skip": skip,
+        "limit": limit,
+        "sort": sort,
+        "min_rating": min_rating,
+        "favorites_of": current_user.id if favorites else None,
+    }
     if my_templates:
-        return crud.get_workflow_templates(
-            db=db, category=category, skip=skip, limit=limit, created_by=current_user.id, public_only=False
-        )
+        templates = crud.get_workflow_templates(db=db, created_by=current_user.id, public_only=False, **filters)
     else:
-        return crud.get_workflow_templates(db=db, category=category, skip=skip, limit=limit, public_only=True)
+        templates = crud.get_workflow_templates(db=db, public_only=True, **filters)
+    return _with_feedback(db, templates, current_user.id)
 
 
 @router.get("/categories", response_model=List[str])
@@ -94,15 +165,44 @@ def get_template(
     """
     Get a specific template by ID.
     """
-    template = crud.get_workflow_template(db=db, template_id=template_id)
-    if not template:
-        raise HTTPException(status_code=404, detail="Template not found")
+    template = _get_visible_template(db, template_id, current_user)
+    return _with_feedback(db, [template], current_user.id)[0]
 
-    # Check access permissions
-    if not template.is_public and template.created_by != current_user.id:
-        raise HTTPException(status_code=403, detail="Access denied to this template")
 
-    return template
+@router.put("/{template_id}/rating", response_model=WorkflowTemplate)
+def rate_template(
+    template_id: int,
+    rating_in: TemplateRatingRequest,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Rate a template from 1 to 5 stars; a null rating clears yours.
+    """
+    template = _get_visible_template(db, template_id, current_user)
+    crud.set_template_feedback(
+        db,
+        template_id=template.id,
+        user_id=current_user.id,
+        rating=rating_in.rating,
+        clear_rating=rating_in.rating is None,
+    )
+    return _with_feedback(db, [template], current_user.id)[0]
+
+
+@router.put("/{template_id}/favorite", response_model=WorkflowTemplate)
+def favorite_template(
+    template_id: int,
+    favorite_in: TemplateFavoriteRequest,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Add or remove a template from your favorites.
+    """
+    template = _get_visible_template(db, template_id, current_user)
+    crud.set_template_feedback(db, template_id=template.id, user_id=current_user.id, is_favorite=favorite_in.favorite)
+    return _with_feedback(db, [template], current_user.id)[0]
 
 
 @router.post("/deploy", response_model=Workflow)
@@ -12,12 +12,16 @@ from typing import Optional
 # FastAPI helpers
 from fastapi import APIRouter
 from fastapi import Depends
+from fastapi import File
 from fastapi import HTTPException
+from fastapi import Query
+from fastapi import UploadFile
 from fastapi import status
 from sqlalchemy.orm import Session
 
 # New higher-level ThreadService façade
 # Auth dependency
+from zerg.callbacks.token_stream import set_current_audience_ids
 from zerg.callbacks.token_stream import set_current_user_id
 
 # DB/CRUD helpers
@@ -30,11 +34,23 @@ from zerg.generated.ws_messages import StreamChunkData
 from zerg.generated.ws_messages import StreamEndData
 from zerg.generated.ws_messages import StreamStartData
 from zerg.managers.agent_runner import AgentRunner
+from zerg.models.enums import SharePermission
+from zerg.routers.agents import _enforce_model_allowlist_or_422
+from zerg.routers.agents import _validate_model_or_400
+from zerg.schemas.schemas import ChatAttachment
+from zerg.schemas.schemas import MessageAuthor
+from zerg.schemas.schemas import MessageMetadataUpdate
+from zerg.schemas.schemas import MessageReaction
+from zerg.schemas.schemas import MessageUsage
 from zerg.schemas.schemas import Thread
 from zerg.schemas.schemas import ThreadCreate
+from zerg.schemas.schemas import ThreadForkRequest
 from zerg.schemas.schemas import ThreadMessageCreate
 from zerg.schemas.schemas import ThreadMessageResponse
 from zerg.schemas.schemas import ThreadUpdate
+from zerg.services.attachment_service import is_stored_attachment_url
+from zerg.services.attachment_service import store_attachment
+from zerg.services.quota import assert_agent_within_budget
 from zerg.services.quota import assert_can_start_run
 from zerg.services.run_history import execute_thread_run_with_history
 
@@ -52,7 +68,6 @@ router = APIRouter(
     dependencies=[Depends(get_current_user)],
 )
 
-
 @router.get("/", response_model=List[Thread])
 @router.get("", response_model=List[Thread])
 def read_threads(
@@ -69,6 +84,22 @@ def read_threads(
     return threads
 
 
+@router.get("/bookmarks", response_model=List[ThreadMessageResponse])
+def read_bookmarked_messages(
+    agent_id: int = Query(..., description="Saved messages across this agent's threads"),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Messages the current user bookmarked in the agent's threads, newest first."""
+    agent = crud.get_agent(db, agent_id=agent_id)
+    if agent is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+    if not _may_use_agent(db, agent, current_user, SharePermission.VIEW):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
+    messages = crud.get_bookmarked_messages(db, agent_id=agent_id, user_id=current_user.id)
+    return [_to_message_response(m, viewer_id=current_user.id) for m in messages]
+
+
 @router.post("/", response_model=Thread, status_code=status.HTTP_201_CREATED)
 @router.post("", response_model=Thread, status_code=status.HTTP_201_CREATED)
 def create_thread(thread: ThreadCreate, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
@@ -78,9 +109,8 @@ def create_thread(thread: ThreadCreate, db: Session = Depends(get_db), current_u
     if agent_row is None:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
 
-    # Authorization: only owner (or admin) can create a thread for an agent
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent_row.owner_id != current_user.id:
+    # Authorization: the owner, an admin or someone the agent is shared with for running
+    if not _may_use_agent(db, agent_row, current_user, SharePermission.RUN):
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not agent owner")
 
     # Delegate creation to ThreadService so the mandatory system message is
@@ -110,20 +140,21 @@ def create_thread(thread: ThreadCreate, db: Session = Depends(get_db), current_u
 @router.get("/{thread_id}", response_model=Thread)
 def read_thread(thread_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
     """Get a specific thread by ID"""
-    db_thread = crud.get_thread(db, thread_id=thread_id)
-    if db_thread is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
-    # Authorization: only owner or admin can read
-    agent = crud.get_agent(db, agent_id=db_thread.agent_id)
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not thread owner")
-    return db_thread
+    return _get_accessible_thread(db, thread_id, current_user, SharePermission.VIEW)
 
 
 @router.put("/{thread_id}", response_model=Thread)
-def update_thread(thread_id: int, thread: ThreadUpdate, db: Session = Depends(get_db)):
+def update_thread(
+    thread_id: int,
+    thread: ThreadUpdate,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
     """Update a thread"""
+    if thread.model_override:
+        # Same registry and allowlist checks as choosing the agent's own model
+        _validate_model_or_400(thread.model_override)
+        _enforce_model_allowlist_or_422(thread.model_override, current_user)
     db_thread = crud.update_thread(
         db,
         thread_id=thread_id,
@@ -131,6 +162,9 @@ def update_thread(thread_id: int, thread: ThreadUpdate, db: Session = Depends(ge
         active=thread.active,
         agent_state=thread.agent_state,
         memory_strategy=thread.memory_strategy,
+        pinned=thread.pinned,
+        archived=thread.archived,
+        model_override=thread.model_override,
     )
     if db_thread is None:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
@@ -150,6 +184,8 @@ def read_thread_messages(
     thread_id: int,
     skip: int = 0,
     limit: int = 100,
+    before_message_id: Optional[int] = Query(None, ge=1),
+    latest: bool = False,
     db: Session = Depends(get_db),
     current_user=Depends(get_current_user),
 ):
@@ -160,56 +196,90 @@ def read_thread_messages(
     This provides deterministic ordering regardless of timestamp precision or creation time.
     The client MUST NOT sort these messages client-side; the server ordering is authoritative.
 
+    Paging: ``latest=true`` returns the newest *limit* messages instead of the
+    oldest, and ``before_message_id`` returns the newest *limit* messages older
+    than that id, so long threads can be loaded from the end backwards.  A page
+    shorter than *limit* means there is nothing older.
+
     See crud.get_thread_messages() for implementation details on the .order_by(ThreadMessage.id) guarantee.
     """
-    # First check if thread exists
-    db_thread = crud.get_thread(db, thread_id=thread_id)
-    if not db_thread:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
-
-    # Authorization: only owner or admin can read messages
-    agent = crud.get_agent(db, agent_id=db_thread.agent_id)
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not thread owner")
+    _get_accessible_thread(db, thread_id, current_user, SharePermission.VIEW)
 
     # Fetch ORM messages and map to response schema including tool metadata
-    orm_msgs = crud.get_thread_messages(db, thread_id=thread_id, skip=skip, limit=limit)
+    if latest or before_message_id is not None:
+        orm_msgs = crud.get_thread_messages_before(db, thread_id=thread_id, before_id=before_message_id, limit=limit)
+    else:
+        orm_msgs = crud.get_thread_messages(db, thread_id=thread_id, skip=skip, limit=limit)
     if not orm_msgs:
         return []
-    result: List[ThreadMessageResponse] = []
-    for m in orm_msgs:
-        # Determine message_type based on role
-        if m.role == "tool":
-            message_type = "tool_output"
-            tool_name = m.name
-        elif m.role == "assistant":
-            message_type = "assistant_message"
-            tool_name = None
-        elif m.role == "user":
-            message_type = "user_message"
-            tool_name = None
-        else:
-            # Fallback to raw role for unknown types
-            message_type = f"{m.role}_message"
-            tool_name = None
-        result.append(
-            ThreadMessageResponse(
-                id=m.id,
-                thread_id=m.thread_id,
-                role=m.role,
-                content=m.content,
-                tool_calls=m.tool_calls,
-                tool_call_id=m.tool_call_id,
-                name=m.name,
-                sent_at=m.sent_at,
-                processed=m.processed,
-                parent_id=m.parent_id,
-                message_type=message_type,
-                tool_name=tool_name,
-            )
-        )
-    return result
+    return [_to_message_response(m, viewer_id=current_user.id) for m in orm_msgs]
+
+
+def _to_message_response(m, viewer_id: Optional[int] = None) -> ThreadMessageResponse:
+    """Map an ORM message to the response schema including tool metadata.
+
+    Bookmarks and reactions are reported from *viewer_id*'s point of view.
+    """
+    metadata = m.message_metadata or {}
+
+    # Determine message_type based on role
+    if m.role == "tool":
+        message_type = "tool_output"
+        tool_name = m.name
+    elif m.role == "assistant":
+        message_type = "assistant_message"
+        tool_name = None
+    elif m.role == "user":
+        message_type = "user_message"
+        tool_name = None
+    else:
+        # Fallback to raw role for unknown types
+        message_type = f"{m.role}_message"
+        tool_name = None
+    return ThreadMessageResponse(
+        id=m.id,
+        thread_id=m.thread_id,
+        role=m.role,
+        content=m.content,
+        tool_calls=m.tool_calls,
+        tool_call_id=m.tool_call_id,
+        name=m.name,
+        sent_at=m.sent_at,
+        processed=m.processed,
+        parent_id=m.parent_id,
+        message_type=message_type,
+        tool_name=tool_name,
+        attachments=metadata.get("attachments", []),
+        author=MessageAuthor.model_validate(m.author) if m.author is not None else None,
+        usage=metadata.get("usage"),
+        bookmarked=viewer_id is not None and viewer_id in metadata.get("bookmarked_by", []),
+        reactions=[
+            MessageReaction(emoji=emoji, count=len(users), reacted=viewer_id in users)
+            for emoji in crud.MESSAGE_REACTIONS
+            if (users := (metadata.get("reactions") or {}).get(emoji))
+        ],
+    )
+
+
+@router.patch("/{thread_id}/messages/{message_id}/metadata", response_model=ThreadMessageResponse)
+def update_message_metadata(
+    thread_id: int,
+    message_id: int,
+    update: MessageMetadataUpdate,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Bookmark or unbookmark a message and/or toggle the caller's reaction on it."""
+    _get_accessible_thread(db, thread_id, current_user, SharePermission.VIEW)
+
+    message = crud.get_thread_message(db, thread_id, message_id)
+    if message is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Message not found")
+    if update.bookmarked is not None:
+        message = crud.set_message_bookmark(db, message, current_user.id, update.bookmarked)
+    if update.reaction is not None:
+        message = crud.toggle_message_reaction(db, message, current_user.id, update.reaction)
+    return _to_message_response(message, viewer_id=current_user.id)
 
 
 @router.post(
@@ -223,17 +293,14 @@ def create_thread_message(
     """Create a new message in a thread"""
     logger.info(f"Creating message in thread {thread_id}: role={message.role}, content={message.content}")
 
-    # First check if thread exists
-    db_thread = crud.get_thread(db, thread_id=thread_id)
-    if not db_thread:
-        logger.warning(f"Thread {thread_id} not found when creating message")
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
+    _get_accessible_thread(db, thread_id, current_user, SharePermission.RUN)
 
-    # Authorization: only owner or admin can post messages
-    agent = crud.get_agent(db, agent_id=db_thread.agent_id)
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not thread owner")
+    message_metadata = None
+    if message.attachments:
+        # Only files previously stored through the upload endpoint may be referenced
+        if any(not is_stored_attachment_url(att.url) for att in message.attachments):
+            raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Unknown attachment")
+        message_metadata = {"attachments": [att.model_dump() for att in message.attachments]}
 
     # Create the message (note: by default, processed=False for user messages)
     new_message = crud.create_thread_message(
@@ -242,10 +309,134 @@ def create_thread_message(
         role=message.role,
         content=message.content,
         sent_at=message.sent_at,
+        message_metadata=message_metadata,
+        author_id=current_user.id if message.role == "user" else None,
     )
     logger.info(f"Created message with ID {new_message.id} in thread {thread_id}, processed={new_message.processed}")
 
-    return new_message
+    return _to_message_response(new_message, viewer_id=current_user.id)
+
+
+@router.post(
+    "/{thread_id}/attachments",
+    response_model=ChatAttachment,
+    status_code=status.HTTP_201_CREATED,
+)
+def upload_thread_attachment(
+    thread_id: int,
+    file: UploadFile = File(..., description="Attachment (image, PDF or text ≤10 MB)"),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Handle *multipart/form-data* upload of a file to attach to the next message."""
+
+    _get_accessible_thread(db, thread_id, current_user, SharePermission.RUN)
+    return store_attachment(file)
+
+
+def _may_use_agent(db: Session, agent, current_user, required: SharePermission) -> bool:
+    """Owner, admin, or shared with the caller at *required* or above."""
+    if getattr(current_user, "role", "USER") == "ADMIN" or agent.owner_id == current_user.id:
+        return True
+    granted = crud.get_agent_permission(db, agent, current_user.id)
+    return granted is not None and crud.permission_allows(granted, required.value)
+
+
+def _get_accessible_thread(db: Session, thread_id: int, current_user, required: SharePermission):
+    """Fetch a thread or raise 404/403 unless the caller may use its agent at *required*.
+
+    Access follows the agent as it is now, so revoking a share or trashing the
+    agent closes its threads too.
+    """
+    db_thread = crud.get_thread(db, thread_id=thread_id)
+    if db_thread is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
+    agent = crud.get_agent(db, agent_id=db_thread.agent_id)
+    if agent is None or agent.deleted_at is not None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
+    if not _may_use_agent(db, agent, current_user, required):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not thread owner")
+    return db_thread
+    if not crud.is_thread_participant(db, thread_id, current_user.id):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not a thread participant")
+    return db_thread
+
+
+@router.post("/{thread_id}/fork", response_model=Thread, status_code=status.HTTP_201_CREATED)
+def fork_thread(
+    thread_id: int, payload: ThreadForkRequest, db: Session = Depends(get_db), current_user=Depends(get_current_user)
+):
+    """Edit-and-resend a user message by forking the thread.
+
+    History before ``message_id`` is copied into a new thread and the edited
+    content is appended as an unprocessed user message; the original thread is
+    left untouched. Call ``/run`` on the returned thread to get a response.
+    """
+    source = _get_accessible_thread(db, thread_id, current_user, SharePermission.RUN)
+
+    target = next((m for m in source.messages if m.id == payload.message_id), None)
+    if target is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Message not found in thread")
+    if target.role != "user":
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Only user messages can be edited")
+
+    forked = crud.fork_thread(
+        db,
+        thread_id,
+        before_message_id=payload.message_id,
+        title=f"{source.title} (edited)",
+    )
+    crud.create_thread_message(
+        db=db, thread_id=forked.id, role="user", content=payload.content, author_id=current_user.id
+    )
+    db.refresh(forked)
+    return forked
+
+
+@router.post("/{thread_id}/regenerate", response_model=ThreadMessageResponse)
+def prepare_regenerate(thread_id: int, db: Session = Depends(get_db), current_user=Depends(get_current_user)):
+    """Discard the latest assistant turn so the last user message can be re-run.
+
+    Returns the user message that will be answered again; follow up with
+    ``/run`` to stream the new response.
+    """
+    _get_accessible_thread(db, thread_id, current_user, SharePermission.RUN)
+    _assert_no_pending_reply(db, thread_id)
+
+    user_message = crud.truncate_thread_after_last_user_message(db, thread_id)
+    if user_message is None:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail="Thread has no user message to regenerate")
+    return ThreadMessageResponse(
+        id=user_message.id,
+        thread_id=user_message.thread_id,
+        role=user_message.role,
+        content=user_message.content,
+        sent_at=user_message.sent_at,
+        processed=user_message.processed,
+        parent_id=user_message.parent_id,
+        message_type="user_message",
+        author=MessageAuthor.model_validate(user_message.author) if user_message.author is not None else None,
+    )
+
+
+def _pending_reply_conflict() -> HTTPException:
+    return HTTPException(status_code=status.HTTP_409_CONFLICT, detail="The agent is already replying in this thread")
+
+
+def _assert_no_pending_reply(db: Session, thread_id: int) -> None:
+    """Only one agent reply is generated at a time, so collaborators posting to
+    the same thread cannot interleave agent turns."""
+    if crud.thread_reply_pending(db, thread_id):
+        raise _pending_reply_conflict()
+
+
+def _thread_audience(thread, agent, current_user) -> list[int]:
+    """Users whose sessions should see a run streamed: the caller first, then
+    the agent owner and everyone else who has written in the thread."""
+
+    audience = [current_user.id, agent.owner_id]
+    audience.extend(m.author_id for m in thread.messages if m.author_id is not None)
+    return list(dict.fromkeys(uid for uid in audience if uid is not None))
 
 
 @router.post("/{thread_id}/run", status_code=status.HTTP_202_ACCEPTED)
@@ -255,40 +446,46 @@ async def run_thread(thread_id: int, db: Session = Depends(get_db), current_user
     # Enforce per-user daily run cap (non-admins are restricted)
     assert_can_start_run(db, user=current_user)
 
-    # Validate thread & agent and ownership
-    thread = crud.get_thread(db, thread_id=thread_id)
-    if thread is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Thread not found")
+    # Validate thread & agent and access
+    thread = _get_accessible_thread(db, thread_id, current_user, SharePermission.RUN)
     agent = crud.get_agent(db, agent_id=thread.agent_id)
-    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
-    if not is_admin and agent and agent.owner_id != current_user.id:
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Forbidden: not thread owner")
+    assert_agent_within_budget(db, agent)
+    _assert_no_pending_reply(db, thread_id)
 
     messages = crud.get_unprocessed_messages(db, thread_id=thread_id)
     if not messages:
         return {"status": "No unprocessed messages"}
 
-    if agent is None:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Agent not found")
-
-    runner = AgentRunner(agent)
+    # The thread's model override applies to this thread only; the agent keeps its default
+    runner = AgentRunner(agent, model_override=thread.model_override)
 
-    # User-scoped topic for ALL streaming events
+    # Streaming events go to the user-scoped topic of everyone in the thread
     user_id = current_user.id
-    topic = f"user:{user_id}"
+    audience = _thread_audience(thread, agent, current_user)
+    topics = [f"user:{uid}" for uid in audience]
+
+    async def broadcast(message_type: str, data) -> None:
+        for topic in topics:
+            envelope = Envelope.create(message_type=message_type, topic=topic, data=data.model_dump())
+            await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+
+    # Checked again atomically: another request may have started a reply since
+    if not crud.claim_thread_reply(db, thread_id):
+        raise _pending_reply_conflict()
 
     # Set user_id context for token streaming
     set_current_user_id(user_id)
+    set_current_audience_ids(audience[1:])
 
     try:
-        # Notify start of (non token) stream
-        stream_start_data = StreamStartData(thread_id=thread_id)
-        envelope = Envelope.create(
-            message_type="stream_start",
-            topic=topic,
-            data=stream_start_data.model_dump(),
+        # Notify start of (non token) stream; collaborators use started_by_* to
+        # tell a reply they triggered from one somebody else did
+        stream_start_data = StreamStartData(
+            thread_id=thread_id,
+            started_by_id=user_id,
+            started_by_name=getattr(current_user, "display_name", None) or getattr(current_user, "email", None),
         )
-        await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+        await broadcast("stream_start", stream_start_data)
 
         # Execute the agent turn and record run history/events
         created_rows = await execute_thread_run_with_history(
@@ -304,16 +501,7 @@ async def run_thread(thread_id: int, db: Session = Depends(get_db), current_user
                     # Phase-2: emit the new *assistant_id* frame so the frontend
                     # can link upcoming tool_output chunks to this assistant
                     # bubble while streaming is still in progress.
-                    assistant_id_data = AssistantIdData(
-                        thread_id=thread_id,
-                        message_id=row.id,
-                    )
-                    envelope = Envelope.create(
-                        message_type="assistant_id",
-                        topic=topic,
-                        data=assistant_id_data.model_dump(),
-                    )
-                    await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+                    await broadcast("assistant_id", AssistantIdData(thread_id=thread_id, message_id=row.id))
                 else:
                     # Non-token mode: keep sending the full assistant_message
                     chunk_data = StreamChunkData(
@@ -324,12 +512,7 @@ async def run_thread(thread_id: int, db: Session = Depends(get_db), current_user
                         tool_call_id=None,
                         message_id=row.id,
                     )
-                    envelope = Envelope.create(
-                        message_type="stream_chunk",
-                        topic=topic,
-                        data=chunk_data.model_dump(),
-                    )
-                    await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+                    await broadcast("stream_chunk", chunk_data)
 
             elif row.role == "tool":
                 chunk_data = StreamChunkData(
@@ -340,23 +523,22 @@ async def run_thread(thread_id: int, db: Session = Depends(get_db), current_user
                     tool_call_id=getattr(row, "tool_call_id", None),
                     message_id=row.id,
                 )
-                envelope = Envelope.create(
-                    message_type="stream_chunk",
-                    topic=topic,
-                    data=chunk_data.model_dump(),
-                )
-                await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
-
-        # Close the stream sequence at the end
-        stream_end_data = StreamEndData(thread_id=thread_id)
-        envelope = Envelope.create(
-            message_type="stream_end",
-            topic=topic,
-            data=stream_end_data.model_dump(),
+                await broadcast("stream_chunk", chunk_data)
+
+        # Close the stream sequence at the end; the usage summary feeds the
+        # token/cost footer under the final assistant bubble
+        final_assistant = next((row for row in reversed(created_rows) if row.role == "assistant"), None)
+        usage = (final_assistant.message_metadata or {}).get("usage") if final_assistant else None
+        stream_end_data = StreamEndData(
+            thread_id=thread_id,
+            message_id=final_assistant.id if final_assistant else None,
+            **MessageUsage.model_validate(usage or {}).model_dump(),
         )
-        await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+        await broadcast("stream_end", stream_end_data)
 
         return {"status": "ok"}
     finally:
         # Always clean up user context
+        crud.release_thread_reply(db, thread_id)
         set_current_user_id(None)
+        set_current_audience_ids(None)
@@ -1,6 +1,8 @@
 """API router for Triggers (milestone M1).
 
-Currently only supports simple *webhook* triggers that, when invoked, publish
+Supports signed *webhook* triggers, Gmail *email* triggers, *slack* event
+triggers (fired from ``routers/slack_events.py``) and *github* repository
+triggers (fired from ``routers/github_webhooks.py``).  Webhooks, when invoked, publish
 an EventType.TRIGGER_FIRED event.  The SchedulerService listens for that event
 and executes the associated agent immediately.
 """
@@ -15,6 +17,7 @@ import logging
 import time
 from typing import Dict
 from typing import List
+from typing import NoReturn
 from typing import Optional
 
 # FastAPI helpers
@@ -41,9 +44,22 @@ from zerg.events import event_bus
 from zerg.metrics import trigger_fired_total
 
 # Schemas
+from zerg.schemas.schemas import EmailFilterPreviewOut
+from zerg.schemas.schemas import EmailFilterPreviewRequest
+from zerg.schemas.schemas import EmailPreviewMessage
+from zerg.schemas.schemas import EmailTriggerFilters
 from zerg.schemas.schemas import Trigger as TriggerSchema
 from zerg.schemas.schemas import TriggerCreate
+from zerg.schemas.schemas import TriggerDeliveryOut
+from zerg.schemas.schemas import TriggerUpdate
+from zerg.services import email_filtering
+from zerg.services import gmail_api
+from zerg.services.github_events import GITHUB_EVENT_TYPES
+from zerg.services.github_events import REPO_PATTERN
+from zerg.services.github_events import github_connection
 from zerg.services.scheduler_service import scheduler_service
+from zerg.services.slack_events import SLACK_EVENT_TYPES
+from zerg.services.slack_events import slack_connection
 
 logger = logging.getLogger(__name__)
 
@@ -96,7 +112,7 @@ async def create_trigger(trigger_in: TriggerCreate, db: Session = Depends(get_db
         raise HTTPException(status_code=404, detail="Agent not found")
 
     # Validate trigger type against allowlist
-    if trigger_in.type not in {"webhook", "email"}:
+    if trigger_in.type not in {"webhook", "email", "slack", "github"}:
         raise HTTPException(status_code=400, detail="Invalid trigger type")
 
     # Email triggers must reference a connector (validate before persist)
@@ -116,7 +132,13 @@ async def create_trigger(trigger_in: TriggerCreate, db: Session = Depends(get_db
             raise HTTPException(status_code=403, detail="Connector belongs to different user")
         # Normalise provider field to connector provider
         cfg["provider"] = conn.provider
+        if cfg.get("filters") is not None:
+            cfg["filters"] = _validated_filters(cfg["filters"])
         new_config = cfg
+    elif trigger_in.type == "slack":
+        new_config = _validated_slack_config(db, agent, trigger_in.config)
+    elif trigger_in.type == "github":
+        new_config = _validated_github_config(db, agent, trigger_in.config)
 
     # Persist trigger -----------------------------------------------------
     trg = crud.create_trigger(
@@ -129,6 +151,137 @@ async def create_trigger(trigger_in: TriggerCreate, db: Session = Depends(get_db
     return trg
 
 
+def _validated_filters(raw) -> Dict:
+    try:
+        return EmailTriggerFilters.model_validate(raw).for_storage()
+    except ValueError as exc:
+        raise HTTPException(status_code=422, detail=f"Invalid email filters: {exc}") from exc
+
+
+def _validated_slack_config(db: Session, agent, raw) -> Dict:
+    cfg = dict(raw or {})
+    if cfg.get("event_type") not in SLACK_EVENT_TYPES:
+        raise HTTPException(
+            status_code=422, detail=f"Slack triggers need an event_type: one of {', '.join(SLACK_EVENT_TYPES)}"
+        )
+    connection = slack_connection(db, agent.owner_id)
+    if connection is None:
+        raise HTTPException(status_code=400, detail="Connect Slack in Integrations before adding a Slack trigger")
+    return {
+        "event_type": cfg["event_type"],
+        "channel": (cfg.get("channel") or "").strip() or None,
+        "keyword": (cfg.get("keyword") or "").strip() or None,
+        "team_id": connection.connector_metadata["team_id"],
+    }
+
+
+def _validated_github_config(db: Session, agent, raw) -> Dict:
+    cfg = dict(raw or {})
+    repo = (cfg.get("repo") or "").strip()
+    if not REPO_PATTERN.match(repo):
+        raise HTTPException(status_code=422, detail="GitHub triggers need a repo in owner/name form")
+    events = cfg.get("events") or []
+    if not events or not set(events) <= set(GITHUB_EVENT_TYPES):
+        raise HTTPException(
+            status_code=422, detail=f"GitHub triggers need events from: {', '.join(GITHUB_EVENT_TYPES)}"
+        )
+    if github_connection(db, agent.owner_id) is None:
+        raise HTTPException(status_code=400, detail="Connect GitHub in Integrations before adding a GitHub trigger")
+    return {
+        "repo": repo,
+        "events": [event for event in GITHUB_EVENT_TYPES if event in events],
+        "actions": [action.strip() for action in cfg.get("actions") or [] if action.strip()],
+    }
+
+
+def _signature_for(secret: str, timestamp: str, payload: Dict) -> str:
+    # We must use the *raw* body as delivered on the wire, not the already-
+    # parsed `payload` dict.  FastAPI gives us access via request.body() but
+    # we'd need Request object; instead re-serialise deterministically.
+    body_serialised = json.dumps(payload, separators=(",", ":"), sort_keys=True)
+    data_to_sign = f"{timestamp}.{body_serialised}".encode()
+    return hmac.new(secret.encode(), data_to_sign, hashlib.sha256).hexdigest()
+
+
+def _payload_size(payload: Dict) -> int:
+    return len(json.dumps(payload, separators=(",", ":"), sort_keys=True).encode())
+
+
+def _reject_delivery(db: Session, trg, payload: Dict, status_code: int, detail: str) -> NoReturn:
+    crud.record_trigger_delivery(
+        db,
+        trigger_id=trg.id,
+        status_code=status_code,
+        error=detail,
+        payload_bytes=_payload_size(payload),
+    )
+    raise HTTPException(status_code=status_code, detail=detail)
+
+
+async def _fire_webhook(
+    db: Session,
+    trg,
+    payload: Dict,
+    *,
+    is_test: bool = False,
+    trigger_type: str = "webhook",
+    matched: Optional[str] = None,
+    external_id: Optional[str] = None,
+):
+    """Publish, count and run – shared by signed deliveries, test fires, Slack and GitHub events.
+
+    *matched* says what the event matched for the delivery history; plain
+    webhooks default to the signature check that let them through.
+    """
+
+    if (trg.config or {}).get("disabled"):
+        _reject_delivery(db, trg, payload, status.HTTP_503_SERVICE_UNAVAILABLE, "Trigger is disabled")
+
+    agent = crud.get_agent(db, trg.agent_id)
+    block_reason = crud.automatic_run_block_reason(db, agent) if agent is not None else None
+    if block_reason:
+        _reject_delivery(db, trg, payload, status.HTTP_503_SERVICE_UNAVAILABLE, f"Trigger suspended: {block_reason}")
+
+    await event_bus.publish(
+        EventType.TRIGGER_FIRED,
+        {"trigger_id": trg.id, "agent_id": trg.agent_id, "payload": payload, "trigger_type": trigger_type},
+    )
+
+    # Metrics -----------------------------------------------------------
+    try:
+        trigger_fired_total.inc()
+    except Exception:  # pragma: no cover – guard against misconfig
+        pass
+
+    # Fire agent immediately (non-blocking)
+    run_before = crud.newest_run_id(db, trg.agent_id)
+    await scheduler_service.run_agent_task(trg.agent_id, trigger="webhook")  # type: ignore[arg-type]
+    run_after = crud.newest_run_id(db, trg.agent_id)
+
+    return crud.record_trigger_delivery(
+        db,
+        trigger_id=trg.id,
+        status_code=status.HTTP_202_ACCEPTED,
+        is_test=is_test,
+        payload_bytes=_payload_size(payload),
+        matched=matched or ("Test fire from the UI" if is_test else "Valid signature"),
+        run_id=run_after if run_after != run_before else None,
+        external_id=external_id,
+    )
+
+
+def _get_owned_trigger(db: Session, trigger_id: int, current_user):
+    trg = crud.get_trigger(db, trigger_id)
+    if trg is None:
+        raise HTTPException(status_code=404, detail="Trigger not found")
+
+    agent = crud.get_agent(db, trg.agent_id)
+    is_admin = getattr(current_user, "role", "USER") == "ADMIN"
+    if agent is None or (not is_admin and agent.owner_id != current_user.id):
+        raise HTTPException(status_code=403, detail="Forbidden: not trigger owner")
+    return trg
+
+
 @router.post("/{trigger_id}/events", status_code=status.HTTP_202_ACCEPTED)
 async def fire_trigger_event(
     *,
@@ -140,7 +293,8 @@ async def fire_trigger_event(
 ):
     """Webhook endpoint that fires a trigger event.
 
-    Security: the caller must sign the request body using HMAC-SHA256.
+    Security: the caller must sign the request body using HMAC-SHA256 with
+    the trigger's own ``secret``; rotating it revokes the old one at once.
 
     Signature string to hash:
         "{timestamp}.{raw_body}"
@@ -148,53 +302,167 @@ async def fire_trigger_event(
     where *timestamp* is the same value sent in `X-Zerg-Timestamp` header and
     *raw_body* is the exact JSON body (no whitespace changes).  The hex-encoded
     digest is provided via `X-Zerg-Signature` header.
+
+    Unknown triggers and bad signatures get the same 403, so trigger ids can't
+    be probed, and are not recorded.  Signed attempts – accepted or rejected
+    (e.g. a replayed timestamp) – are recorded as deliveries so owners can
+    debug their integration.
     """
 
-    # 1) Validate timestamp (prevents replay attacks)
+    # 1) Recompute HMAC with the trigger's secret and compare (constant-time)
+    trg = crud.get_trigger(db, trigger_id)
+    if trg is None or not hmac.compare_digest(
+        _signature_for(trg.secret, x_zerg_timestamp, payload).encode(), x_zerg_signature.encode()
+    ):
+        raise HTTPException(status_code=403, detail="Invalid signature")
+
+    # 2) Validate timestamp (prevents replay attacks)
     try:
         ts_int = int(x_zerg_timestamp)
     except ValueError:
-        raise HTTPException(status_code=400, detail="Invalid X-Zerg-Timestamp header")
+        _reject_delivery(db, trg, payload, 400, "Invalid X-Zerg-Timestamp header")
 
     now = int(time.time())
     if abs(now - ts_int) > constants.TRIGGER_TIMESTAMP_TOLERANCE_S:
-        raise HTTPException(status_code=400, detail="Timestamp skew too large")
+        _reject_delivery(db, trg, payload, 400, "Timestamp skew too large")
 
-    # 2) Recompute HMAC and compare (constant-time)
-    signing_secret = constants.TRIGGER_SIGNING_SECRET.encode()
+    # 3) Publish event, run the agent and log the delivery
+    await _fire_webhook(db, trg, payload)
 
-    # We must use the *raw* body as delivered on the wire, not the already-
-    # parsed `payload` dict.  FastAPI gives us access via request.body() but
-    # we'd need Request object; instead re-serialise deterministically.
-    body_serialised = json.dumps(payload, separators=(",", ":"), sort_keys=True)
+    return {"status": "accepted"}
 
-    data_to_sign = f"{x_zerg_timestamp}.{body_serialised}".encode()
-    expected_sig = hmac.new(signing_secret, data_to_sign, hashlib.sha256).hexdigest()
 
-    if not hmac.compare_digest(expected_sig, x_zerg_signature):
-        raise HTTPException(status_code=403, detail="Invalid signature")
+@router.post("/{trigger_id}/test", status_code=status.HTTP_202_ACCEPTED)
+async def test_trigger(
+    *,
+    trigger_id: int = Path(..., gt=0),
+    payload: Dict = Body(default={}),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Fire a webhook trigger with a sample payload, skipping signature checks.
 
-    # 3) Check trigger exists
-    trg = crud.get_trigger(db, trigger_id)
-    if trg is None:
-        raise HTTPException(status_code=404, detail="Trigger not found")
+    Authenticated owners use this from the UI to check the wiring end to end;
+    the resulting delivery is flagged ``is_test``.
+    """
 
-    # 4) Publish event on internal bus
-    await event_bus.publish(
-        EventType.TRIGGER_FIRED,
-        {"trigger_id": trg.id, "agent_id": trg.agent_id, "payload": payload, "trigger_type": "webhook"},
-    )
+    trg = _get_owned_trigger(db, trigger_id, current_user)
+    if trg.type != "webhook":
+        raise HTTPException(status_code=400, detail="Only webhook triggers can be test-fired")
 
-    # Metrics -----------------------------------------------------------
-    try:
-        trigger_fired_total.inc()
-    except Exception:  # pragma: no cover – guard against misconfig
-        pass
+    delivery = await _fire_webhook(db, trg, payload, is_test=True)
+    return {"status": "accepted", "delivery": TriggerDeliveryOut.model_validate(delivery)}
 
-    # 5) Fire agent immediately (non-blocking)
-    await scheduler_service.run_agent_task(trg.agent_id, trigger="webhook")  # type: ignore[arg-type]
 
-    return {"status": "accepted"}
+@router.post("/{trigger_id}/rotate-secret", response_model=TriggerSchema)
+def rotate_trigger_secret(
+    *,
+    trigger_id: int = Path(..., gt=0),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Issue a new signing secret; the previous one is rejected immediately."""
+
+    _get_owned_trigger(db, trigger_id, current_user)
+    return crud.rotate_trigger_secret(db, trigger_id)
+
+
+@router.get("/{trigger_id}/deliveries", response_model=List[TriggerDeliveryOut])
+def list_trigger_deliveries(
+    *,
+    trigger_id: int = Path(..., gt=0),
+    limit: int = Query(20, ge=1, le=crud.TRIGGER_DELIVERY_HISTORY_LIMIT),
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Most recent deliveries for a trigger, newest first."""
+
+    _get_owned_trigger(db, trigger_id, current_user)
+    return crud.list_trigger_deliveries(db, trigger_id, limit=limit)
+
+
+@router.patch("/{trigger_id}", response_model=TriggerSchema)
+def update_trigger(
+    *,
+    trigger_id: int = Path(..., gt=0),
+    update: TriggerUpdate,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """Replace the e-mail filter of an *email* trigger."""
+
+    trg = _get_owned_trigger(db, trigger_id, current_user)
+    if trg.type != "email":
+        raise HTTPException(status_code=400, detail="Only email triggers have filters")
+
+    cfg = dict(trg.config or {})
+    filters = update.filters.for_storage() if update.filters is not None else {}
+    if filters:
+        cfg["filters"] = filters
+    else:
+        cfg.pop("filters", None)
+    return crud.update_trigger_config(db, trigger_id, cfg)
+
+
+@router.post("/email/preview", response_model=EmailFilterPreviewOut)
+async def preview_email_filter(
+    preview: EmailFilterPreviewRequest,
+    db: Session = Depends(get_db),
+    current_user=Depends(get_current_user),
+):
+    """List recent Gmail messages that an email trigger with *filters* would match.
+
+    Candidates come from Gmail search and are re-checked with the same
+    matcher the trigger uses, so the preview never shows a message the
+    trigger would ignore.
+    """
+
+    from zerg.utils import crypto
+
+    conn = crud.get_connector(db, preview.connector_id)
+    if conn is None or conn.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Connector not found")
+    if conn.provider != "gmail":
+        raise HTTPException(status_code=400, detail="Preview is only available for Gmail connectors")
+
+    enc_token = (conn.config or {}).get("refresh_token")
+    if not enc_token:
+        raise HTTPException(status_code=400, detail="Gmail connector is not authorised")
+
+    try:
+        access_token = await gmail_api.async_exchange_refresh_token(crypto.decrypt(enc_token))
+    except Exception as exc:
+        logger.warning("gmail preview token refresh failed for connector %s: %s", conn.id, exc)
+        raise HTTPException(status_code=502, detail="Could not reach Gmail") from exc
+
+    filters = preview.filters.for_storage()
+    query = email_filtering.to_gmail_query(filters)
+    message_ids = await gmail_api.async_list_messages(
+        access_token,
+        query=query,
+        label_ids=filters.get("label_include"),
+        max_results=preview.limit,
+    )
+
+    messages: List[EmailPreviewMessage] = []
+    for message_id in message_ids:
+        meta = await gmail_api.async_get_message_metadata(access_token, message_id)
+        if not meta or not email_filtering.matches(meta, filters):
+            continue
+        headers = meta.get("headers", {})
+        messages.append(
+            EmailPreviewMessage(
+                id=str(meta.get("id") or message_id),
+                sender=headers.get("From"),
+                subject=headers.get("Subject"),
+                date=headers.get("Date"),
+                snippet=meta.get("snippet"),
+                label_ids=meta.get("labelIds") or [],
+                has_attachment=bool(meta.get("hasAttachment")),
+            )
+        )
+
+    return EmailFilterPreviewOut(query=query, scanned=len(message_ids), messages=messages)
 
 
 @router.get("/", response_model=List[TriggerSchema])
@@ -18,6 +18,7 @@ from fastapi import status
 from pydantic import BaseModel, ValidationError
 from sqlalchemy.orm import Session
 
+from zerg.auth.capabilities import capabilities_for
 from zerg.crud import crud
 from zerg.database import get_db
 
@@ -25,12 +26,15 @@ from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
 from zerg.events import EventType
 from zerg.events.decorators import publish_event
+from zerg.schemas.schemas import CapabilitiesOut
 from zerg.schemas.schemas import UserOut
 from zerg.schemas.schemas import UserUpdate
+from zerg.schemas.schemas import WorkspaceDefaults
 from zerg.schemas.user_context import UserContext
 
 # Avatar helper
 from zerg.services.avatar_service import store_avatar_for_user
+from zerg.services.preferences import preference_errors
 
 router = APIRouter(tags=["users"], dependencies=[Depends(get_current_user)])
 
@@ -96,6 +100,16 @@ def read_current_user(current_user=Depends(get_current_user)):
     return current_user  # SQLAlchemy row – FastAPI will use attrs to dict
 
 
+@router.get("/users/me/capabilities", response_model=CapabilitiesOut)
+def read_current_user_capabilities(current_user=Depends(get_current_user)):
+    """Return what the current user may do so the UI can gate controls up front."""
+
+    return CapabilitiesOut(
+        role=getattr(current_user, "role", "USER"),
+        capabilities=sorted(capability.value for capability in capabilities_for(current_user)),
+    )
+
+
 # ---------------------------------------------------------------------------
 # /users/me – partial update
 # ---------------------------------------------------------------------------
@@ -110,6 +124,11 @@ async def update_current_user(
 ):
     """Patch the authenticated user's profile (display name, avatar, prefs)."""
 
+    if patch.prefs is not None:
+        errors = preference_errors(patch.prefs)
+        if errors:
+            raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail="; ".join(errors))
+
     updated = crud.update_user(
         db,
         current_user.id,
@@ -125,6 +144,18 @@ async def update_current_user(
     return updated
 
 
+# ---------------------------------------------------------------------------
+# /workspace/defaults – admin-set prefs, readable by everyone
+# ---------------------------------------------------------------------------
+
+
+@router.get("/workspace/defaults", response_model=WorkspaceDefaults)
+def read_workspace_defaults(db: Session = Depends(get_db)):
+    """Preferences that apply until the user sets their own (see PUT /admin/workspace-defaults)."""
+
+    return WorkspaceDefaults(**crud.get_workspace_defaults(db))
+
+
 # ---------------------------------------------------------------------------
 # /users/me/avatar – upload user avatar
 # ---------------------------------------------------------------------------
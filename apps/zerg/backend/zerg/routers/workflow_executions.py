@@ -1,11 +1,14 @@
+import asyncio
 from datetime import datetime
 from datetime import timezone
 
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import Request
 from fastapi import Response
 from pydantic import BaseModel
+from pydantic import ConfigDict
 from pydantic import Field
 from sqlalchemy.orm import Session
 
@@ -13,10 +16,19 @@ from zerg.crud import crud
 from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
 from zerg.models.models import User
+from zerg.models.models import WorkflowExecution
 from zerg.schemas.workflow import ExecutionLogsResponse
 from zerg.schemas.workflow import ExecutionStatusResponse
+from zerg.schemas.workflow import WorkflowData
+from zerg.services.execution_control import execution_controls
+from zerg.services.execution_state import ExecutionStateMachine
+from zerg.services.partial_runs import cached_outputs
+from zerg.services.partial_runs import plan_partial_run
 from zerg.services.workflow_engine import workflow_engine
+from zerg.services.workflow_inputs import declared_inputs
+from zerg.services.workflow_inputs import validate_run_inputs
 from zerg.services.workflow_scheduler import workflow_scheduler
+from zerg.services.workflow_waits import workflow_waits
 from zerg.utils.time import utc_now_naive
 
 router = APIRouter(
@@ -25,6 +37,9 @@ router = APIRouter(
     dependencies=[Depends(get_current_user)],
 )
 
+# Event waits are resumed by external systems that only hold the wait's token
+wait_resume_router = APIRouter(prefix="/workflow-waits", tags=["workflow-executions"])
+
 
 # ---------------------------------------------------------------------------
 # Models
@@ -40,6 +55,83 @@ class ScheduleWorkflowPayload(BaseModel):
     trigger_config: dict = Field(default_factory=dict)
 
 
+class StartExecutionPayload(BaseModel):
+    # Checked against the inputs the workflow's trigger declares
+    inputs: dict | None = None
+
+
+class StepPayload(BaseModel):
+    # Replaces the paused node's config for this run only
+    config: dict | None = None
+
+
+class ExecutionNodeDetail(BaseModel):
+    node_id: str
+    phase: str
+    result: str | None = None
+    error_message: str | None = None
+    output: dict | None = None
+
+
+class ChildExecutionSummary(BaseModel):
+    execution_id: int
+    workflow_id: int
+    # Call Workflow node in the parent that started it
+    node_id: str | None = None
+    phase: str
+    result: str | None = None
+
+
+class ExecutionDetail(BaseModel):
+    execution_id: int
+    workflow_id: int
+    workflow_name: str
+    phase: str
+    result: str | None = None
+    error_message: str | None = None
+    parent_execution_id: int | None = None
+    parent_node_id: str | None = None
+    canvas: dict
+    nodes: list[ExecutionNodeDetail]
+    children: list[ChildExecutionSummary]
+
+
+class ExecutionHistoryItem(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    workflow_id: int
+    phase: str
+    result: str | None = None
+    error_message: str | None = None
+    triggered_by: str | None = None
+    started_at: datetime | None = None
+    finished_at: datetime | None = None
+    inputs: dict | None = None
+    # Set on runs started from a node
+    source_execution_id: int | None = None
+    start_node_id: str | None = None
+
+
+class NodeOutputCache(BaseModel):
+    # Latest finished execution; a run from a node reuses its outputs
+    execution_id: int | None = None
+    finished_at: datetime | None = None
+    # Nodes that succeeded in it
+    node_ids: list[str] = Field(default_factory=list)
+
+
+def _execution_status(execution) -> ExecutionStatusResponse:
+    controller = execution_controls.get(execution.id)
+    return ExecutionStatusResponse(
+        execution_id=execution.id,
+        phase=execution.phase,
+        result=execution.result,
+        step_mode=bool(controller and controller.step_mode),
+        paused_node_id=controller.paused_node_id if controller else None,
+    )
+
+
 @router.post("/by-workflow/{workflow_id}/reserve", response_model=ExecutionStatusResponse)
 async def reserve_workflow_execution(
     workflow_id: int,
@@ -67,17 +159,32 @@ async def reserve_workflow_execution(
 @router.post("/by-workflow/{workflow_id}/start", response_model=ExecutionStatusResponse)
 async def start_workflow_execution(
     workflow_id: int,
+    step_mode: bool = False,
+    payload: StartExecutionPayload | None = None,
     db: Session = Depends(get_db),
     current_user: User = Depends(get_current_user),
 ):
     """
     Start a new execution of a workflow using LangGraph engine.
     Non-blocking: returns immediately with phase=running.
+
+    With ``step_mode`` the run pauses before every node until
+    ``/{execution_id}/step`` or ``/{execution_id}/continue`` is called.
+
+    ``inputs`` in the body are validated against the parameters the
+    workflow's trigger declares (422 with one issue per input) and kept on the
+    execution; nodes read them as ``${input.value.<name>}``.
     """
     workflow = crud.get_workflow(db, workflow_id)
     if not workflow or workflow.owner_id != current_user.id:
         raise HTTPException(status_code=404, detail="Workflow not found")
 
+    inputs, input_errors = validate_run_inputs(declared_inputs(workflow.canvas), payload.inputs if payload else None)
+    if input_errors:
+        # Same shape as FastAPI's own validation errors, so clients can map them to fields
+        detail = [{"loc": ["body", "inputs", name], "msg": msg} for name, msg in input_errors.items()]
+        raise HTTPException(status_code=422, detail=detail)
+
     # Create execution record and start in background (truly non-blocking)
     try:
         from zerg.models.models import WorkflowExecution
@@ -89,6 +196,7 @@ async def start_workflow_execution(
             workflow_id=workflow_id,
             started_at=utc_now_naive(),
             triggered_by="manual",
+            inputs=inputs or None,
         )
         db.add(execution)
         db.commit()
@@ -100,6 +208,8 @@ async def start_workflow_execution(
 
         # Return immediately so frontend can subscribe BEFORE workflow starts
         execution_id = execution.id
+        if step_mode:
+            execution_controls.create(execution_id, step_mode=True)
 
         # Start workflow in background with small delay to allow subscription
         import asyncio
@@ -112,7 +222,8 @@ async def start_workflow_execution(
         return ExecutionStatusResponse(
             execution_id=execution_id,
             phase="running",
-            result=None
+            result=None,
+            step_mode=step_mode,
         )
     except Exception as e:
         # Log the full error for debugging
@@ -166,6 +277,59 @@ async def start_reserved_execution(
     )
 
 
+@router.post("/{execution_id}/run-from/{node_id}", response_model=ExecutionStatusResponse)
+async def run_from_node(
+    execution_id: int,
+    node_id: str,
+    step_mode: bool = False,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Start a new run at *node_id*, reusing *execution_id*'s outputs for everything else.
+
+    Only the node and what follows it on the current canvas run; the new
+    execution keeps the source's inputs. 409 while the source is still running
+    or when a node feeding the subgraph has no cached output.
+    """
+    source = crud.get_workflow_execution(db, execution_id)
+    if not source or source.workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Execution not found")
+    if source.phase != "finished":
+        raise HTTPException(status_code=409, detail="Execution is still running")
+
+    try:
+        plan_partial_run(WorkflowData(**(source.workflow.canvas or {})), node_id, cached_outputs(db, source.id))
+    except ValueError as e:
+        raise HTTPException(status_code=409, detail=str(e))
+
+    execution = WorkflowExecution(
+        workflow_id=source.workflow_id,
+        started_at=utc_now_naive(),
+        triggered_by="manual",
+        inputs=source.inputs,
+        source_execution_id=source.id,
+        start_node_id=node_id,
+    )
+    db.add(execution)
+    db.commit()
+    db.refresh(execution)
+    ExecutionStateMachine.mark_running(execution)
+    db.commit()
+
+    new_execution_id = execution.id
+    if step_mode:
+        execution_controls.create(new_execution_id, step_mode=True)
+
+    async def delayed_start():
+        await asyncio.sleep(0.1)  # Same subscription grace as /start
+        workflow_engine.start_workflow_in_background(source.workflow_id, new_execution_id)
+
+    asyncio.create_task(delayed_start())
+
+    return ExecutionStatusResponse(execution_id=new_execution_id, phase="running", result=None, step_mode=step_mode)
+
+
 # Backward compatibility - DEPRECATED (place after specific routes to avoid conflicts)
 @router.post("/{workflow_id}/start")
 async def start_workflow_execution_deprecated(
@@ -194,7 +358,7 @@ async def start_workflow_execution_deprecated(
         return {"execution_id": waiting.id, "phase": waiting.phase, "result": waiting.result}
 
     # Fall back to creating new execution
-    return await start_workflow_execution(workflow_id, db, current_user)
+    return await start_workflow_execution(workflow_id, db=db, current_user=current_user)
 
 
 @router.get("/{execution_id}/status", response_model=ExecutionStatusResponse)
@@ -209,11 +373,82 @@ def get_execution_status(
     execution = crud.get_workflow_execution(db, execution_id)
     if not execution or execution.workflow.owner_id != current_user.id:
         raise HTTPException(status_code=404, detail="Execution not found")
-    return ExecutionStatusResponse(
-        execution_id=execution.id,
-        phase=execution.phase,
-        result=execution.result
-    )
+    return _execution_status(execution)
+
+
+def _get_paused_controller(db: Session, execution_id: int, current_user: User):
+    execution = crud.get_workflow_execution(db, execution_id)
+    if not execution or execution.workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Execution not found")
+    controller = execution_controls.get(execution_id)
+    if controller is None or controller.paused_node_id is None:
+        raise HTTPException(status_code=409, detail="Execution is not paused")
+    return execution, controller
+
+
+@router.post("/{execution_id}/step", response_model=ExecutionStatusResponse)
+def step_execution(
+    execution_id: int,
+    payload: StepPayload | None = None,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Run the node a step-mode execution is paused at, then pause again before the next one.
+    """
+    execution, controller = _get_paused_controller(db, execution_id, current_user)
+    controller.resume(config_override=payload.config if payload else None)
+    return _execution_status(execution)
+
+
+@router.post("/{execution_id}/continue", response_model=ExecutionStatusResponse)
+def continue_execution(
+    execution_id: int,
+    payload: StepPayload | None = None,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Leave step mode: run the paused node and everything after it without stopping.
+    """
+    execution, controller = _get_paused_controller(db, execution_id, current_user)
+    controller.resume(config_override=payload.config if payload else None, keep_stepping=False)
+    return _execution_status(execution)
+
+
+@router.post("/{execution_id}/waits/{node_id}/skip", status_code=204)
+def skip_wait(
+    execution_id: int,
+    node_id: str,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    End a wait node's wait now; the workflow carries on from that node.
+    """
+    execution = crud.get_workflow_execution(db, execution_id)
+    if not execution or execution.workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Execution not found")
+    pending = workflow_waits.get(execution_id, node_id)
+    if pending is None:
+        raise HTTPException(status_code=409, detail="Node is not waiting")
+    pending.release("skipped")
+    return Response(status_code=204)
+
+
+@wait_resume_router.post("/{token}", status_code=204)
+async def resume_wait(token: str, request: Request):
+    """Resume an event wait. No auth: the token is the credential. A JSON body becomes the node's payload."""
+    pending = workflow_waits.by_token(token)
+    if pending is None:
+        raise HTTPException(status_code=404, detail="Wait not found")
+    body = await request.body()
+    try:
+        payload = await request.json() if body else None
+    except ValueError:
+        payload = body.decode(errors="replace")
+    pending.release("event", payload)
+    return Response(status_code=204)
 
 
 @router.post("/{execution_id}/await")
@@ -267,19 +502,162 @@ def get_execution_logs(
     return ExecutionLogsResponse(logs=execution.log or "")
 
 
-@router.get("/history/{workflow_id}")
+@router.get("/{execution_id}/detail", response_model=ExecutionDetail)
+def get_execution_detail(
+    execution_id: int,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    An execution with its workflow's canvas, per-node states and the child runs its Call Workflow nodes started.
+    """
+    execution = crud.get_workflow_execution(db, execution_id)
+    if not execution or execution.workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Execution not found")
+
+    children = (
+        db.query(WorkflowExecution)
+        .filter(WorkflowExecution.parent_execution_id == execution.id)
+        .order_by(WorkflowExecution.id)
+        .all()
+    )
+    return ExecutionDetail(
+        execution_id=execution.id,
+        workflow_id=execution.workflow_id,
+        workflow_name=execution.workflow.name,
+        phase=execution.phase,
+        result=execution.result,
+        error_message=execution.error_message,
+        parent_execution_id=execution.parent_execution_id,
+        parent_node_id=execution.parent_node_id,
+        canvas=execution.workflow.canvas or {},
+        nodes=[
+            ExecutionNodeDetail(
+                node_id=state.node_id,
+                phase=state.phase,
+                result=state.result,
+                error_message=state.error_message,
+                output=state.output,
+            )
+            for state in execution.node_states
+        ],
+        children=[
+            ChildExecutionSummary(
+                execution_id=child.id,
+                workflow_id=child.workflow_id,
+                node_id=child.parent_node_id,
+                phase=child.phase,
+                result=child.result,
+            )
+            for child in children
+        ],
+    )
+
+
+@router.get("/history/{workflow_id}", response_model=list[ExecutionHistoryItem])
 def get_execution_history(
     workflow_id: int,
+    limit: int = 100,
     db: Session = Depends(get_db),
     current_user: User = Depends(get_current_user),
 ):
     """
-    Get the execution history of a workflow.
+    The last *limit* executions of a workflow, newest first.
+
+    Each carries the inputs it was started with, so the canvas can re-run it.
     """
     workflow = crud.get_workflow(db, workflow_id)
     if not workflow or workflow.owner_id != current_user.id:
         raise HTTPException(status_code=404, detail="Workflow not found")
-    return crud.get_workflow_executions(db, workflow_id)
+    limit = max(1, min(limit, 500))
+    return crud.get_recent_workflow_executions(db, workflow_id, limit=limit)
+
+
+def _node_duration_ms(node_state) -> int | None:
+    meta = (node_state.output or {}).get("meta") or {}
+    if isinstance(meta.get("execution_time_ms"), (int, float)):
+        return int(meta["execution_time_ms"])
+    # Fallback: the row is created on start and updated on finish
+    if node_state.created_at and node_state.updated_at:
+        return max(0, int((node_state.updated_at - node_state.created_at).total_seconds() * 1000))
+    return None
+
+
+def _node_cost_usd(node_state) -> float | None:
+    meta = (node_state.output or {}).get("meta") or {}
+    cost = meta.get("total_cost_usd")
+    return float(cost) if isinstance(cost, (int, float)) else None
+
+
+@router.get("/history/{workflow_id}/node-summaries")
+def get_execution_node_summaries(
+    workflow_id: int,
+    limit: int = 20,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Per-node outcome, duration and cost for the last *limit* executions.
+
+    The canvas aggregates these into its execution heat overlay.
+    """
+    workflow = crud.get_workflow(db, workflow_id)
+    if not workflow or workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Workflow not found")
+
+    limit = max(1, min(limit, 200))
+    executions = crud.get_recent_workflow_executions(db, workflow_id, limit=limit)
+
+    return [
+        {
+            "execution_id": execution.id,
+            "phase": execution.phase,
+            "result": execution.result,
+            "started_at": execution.started_at,
+            "finished_at": execution.finished_at,
+            "nodes": [
+                {
+                    "node_id": state.node_id,
+                    "phase": state.phase,
+                    "result": state.result,
+                    "duration_ms": _node_duration_ms(state),
+                    "cost_usd": _node_cost_usd(state),
+                }
+                for state in execution.node_states
+            ],
+        }
+        for execution in executions
+    ]
+
+
+@router.get("/history/{workflow_id}/node-cache", response_model=NodeOutputCache)
+def get_node_output_cache(
+    workflow_id: int,
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Nodes with a cached output in the workflow's latest finished execution.
+
+    The canvas marks them and offers to run from any node they feed.
+    """
+    workflow = crud.get_workflow(db, workflow_id)
+    if not workflow or workflow.owner_id != current_user.id:
+        raise HTTPException(status_code=404, detail="Workflow not found")
+
+    latest = (
+        db.query(WorkflowExecution)
+        .filter(WorkflowExecution.workflow_id == workflow_id, WorkflowExecution.phase == "finished")
+        .order_by(WorkflowExecution.id.desc())
+        .first()
+    )
+    if latest is None:
+        return NodeOutputCache()
+    return NodeOutputCache(
+        execution_id=latest.id,
+        finished_at=latest.finished_at,
+        node_ids=sorted(cached_outputs(db, latest.id)),
+    )
 
 
 @router.get("/{execution_id}/export")
@@ -328,8 +706,27 @@ def cancel_execution(
 
     ExecutionStateMachine.mark_cancelled(execution, reason=payload.reason)
     execution.finished_at = utc_now_naive()
+    # Runs started by this execution's Call Workflow nodes stop with it
+    cancelled_ids = [execution.id]
+    pending_children = db.query(WorkflowExecution).filter(WorkflowExecution.parent_execution_id == execution.id).all()
+    while pending_children:
+        child = pending_children.pop()
+        if child.phase != "finished":
+            ExecutionStateMachine.mark_cancelled(child, reason=payload.reason)
+            child.finished_at = utc_now_naive()
+            cancelled_ids.append(child.id)
+        pending_children.extend(
+            db.query(WorkflowExecution).filter(WorkflowExecution.parent_execution_id == child.id).all()
+        )
     db.commit()
 
+    # A step-mode run parked on a node would otherwise wait forever
+    for cancelled_id in cancelled_ids:
+        controller = execution_controls.get(cancelled_id)
+        if controller is not None:
+            controller.abort()
+        workflow_waits.cancel_execution(cancelled_id)
+
     # Emit EXECUTION_FINISHED event with cancelled status so UI updates
     from zerg.events import EventType  # local import to avoid cycles
     from zerg.events.publisher import publish_event_fire_and_forget
@@ -1,28 +1,45 @@
+import re
 from typing import Any
 from typing import Dict
 from typing import List
+from typing import Optional
 
+import httpx
 from fastapi import APIRouter
 from fastapi import Depends
 from fastapi import HTTPException
+from fastapi import Query
 from fastapi import Request
 from fastapi import Response
 from fastapi import status
 from pydantic import BaseModel
 from sqlalchemy.orm import Session
 
+from zerg.auth.capabilities import is_admin
 from zerg.crud import crud
 from zerg.database import get_db
 from zerg.dependencies.auth import get_current_user
+from zerg.generated.ws_messages import CommentMentionData
+from zerg.generated.ws_messages import Envelope
 from zerg.middleware.rate_limiter import check_workflow_creation_rate_limit
 from zerg.models.models import User
 
 # Canvas layout helper models reused from graph router to avoid duplication.
 from zerg.routers.graph_layout import LayoutUpdate
 from zerg.schemas.schemas import Workflow
+from zerg.schemas.schemas import WorkflowCommentCreate
+from zerg.schemas.schemas import WorkflowCommentOut
+from zerg.schemas.schemas import WorkflowCommentUpdate
 from zerg.schemas.schemas import WorkflowCreate
 from zerg.schemas.schemas import WorkflowUpdate
 from zerg.schemas.workflow import WorkflowData
+from zerg.services.api_credentials import with_credential_headers
+from zerg.services.http_node import BlockedDestinationError
+from zerg.services.http_node import render_request
+from zerg.services.http_node import send_request
+from zerg.services.subworkflows import validate_subworkflow_calls
+from zerg.services.tags import normalize_tags
+from zerg.websocket.manager import topic_manager
 
 router = APIRouter(
     prefix="/workflows",
@@ -37,6 +54,11 @@ class CanvasUpdate(BaseModel):
     canvas: WorkflowData
 
 
+class CanvasValidate(CanvasUpdate):
+    # The canvas's own workflow, so Call Workflow nodes that lead back to it are caught
+    workflow_id: Optional[int] = None
+
+
 class ValidationResponse(BaseModel):
     """Response for workflow validation."""
 
@@ -48,7 +70,8 @@ class ValidationResponse(BaseModel):
 @router.post("/validate", response_model=ValidationResponse)
 def validate_workflow(
     *,
-    payload: CanvasUpdate,
+    payload: CanvasValidate,
+    db: Session = Depends(get_db),
     current_user: User = Depends(get_current_user),
 ):
     """
@@ -58,8 +81,9 @@ def validate_workflow(
         # Direct pydantic validation of WorkflowData
         payload.canvas  # This triggers validation
 
-        # If we get here, validation passed
-        return ValidationResponse(is_valid=True, errors=[], warnings=[])
+        # Call Workflow targets must exist and must not recurse or nest too deeply
+        errors = validate_subworkflow_calls(db, current_user.id, payload.canvas.nodes, payload.workflow_id)
+        return ValidationResponse(is_valid=not errors, errors=errors, warnings=[])
 
     except Exception as e:
         # Validation error
@@ -70,6 +94,63 @@ def validate_workflow(
         )
 
 
+class HttpRequestTest(BaseModel):
+    """An HTTP node's config, sent from the node inspector before saving."""
+
+    method: str = "GET"
+    url: str
+    headers: Dict[str, str] = {}
+    body: Optional[str] = None
+    # API credential whose auth header is added (see routers/api_credentials.py)
+    credential_id: Optional[int] = None
+    # Resolve ${...} references against this execution's node outputs
+    execution_id: Optional[int] = None
+
+
+class HttpRequestTestResult(BaseModel):
+    method: str
+    url: str
+    unresolved: List[str]
+    response: Optional[Dict[str, Any]] = None
+    error: Optional[str] = None
+
+
+@router.post("/http-request/test", response_model=HttpRequestTestResult)
+async def test_http_request(
+    *,
+    db: Session = Depends(get_db),
+    payload: HttpRequestTest,
+    current_user: User = Depends(get_current_user),
+):
+    """
+    Send the request an HTTP node would make and return the response.
+
+    Upstream references resolve against ``execution_id``'s outputs when given;
+    anything left unresolved is reported and sent as written.
+    """
+    node_outputs: Dict[str, Any] = {}
+    if payload.execution_id is not None:
+        execution = crud.get_workflow_execution(db, payload.execution_id)
+        if not execution or execution.workflow.owner_id != current_user.id:
+            raise HTTPException(status_code=404, detail="Execution not found")
+        node_outputs = {state.node_id: state.output for state in execution.node_states if state.output}
+
+    try:
+        config = with_credential_headers(db, current_user.id, payload.model_dump(exclude={"execution_id"}))
+        request = render_request(config, node_outputs)
+    except ValueError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc))
+
+    result = HttpRequestTestResult(method=request.method, url=request.url, unresolved=request.unresolved)
+    try:
+        result.response = await send_request(request)
+    except BlockedDestinationError as exc:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(exc))
+    except httpx.HTTPError as exc:
+        result.error = f"Request failed: {exc}"
+    return result
+
+
 @router.post("/", response_model=Workflow, status_code=status.HTTP_201_CREATED)
 def create_workflow(
     *,
@@ -146,15 +227,14 @@ def create_workflow(
         )
 
 
-@router.get("/current", response_model=Workflow)
-def get_current_workflow(
-    db: Session = Depends(get_db),
-    current_user: User = Depends(get_current_user),
-):
-    """
-    Get the user's current working workflow.
-    Creates a default workflow if none exists.
-    """
+def _current_workflow(db: Session, current_user: User, workflow_id: Optional[int]):
+    """The workflow the canvas edits: *workflow_id* if given, else the user's first one."""
+    if workflow_id is not None:
+        wf = crud.get_workflow(db, workflow_id)
+        if wf is None or wf.owner_id != current_user.id or not wf.is_active:
+            raise HTTPException(status_code=404, detail="workflow not found")
+        return wf
+
     # Get most recent workflow
     workflows = crud.get_workflows(db, owner_id=current_user.id, skip=0, limit=1)
 
@@ -162,14 +242,26 @@ def get_current_workflow(
         return workflows[0]
 
     # Create default workflow if none exists
-    workflow = crud.create_workflow(
+    return crud.create_workflow(
         db=db,
         owner_id=current_user.id,
         name="My Workflow",
         description="",
         canvas={"nodes": [], "edges": []},
     )
-    return workflow
+
+
+@router.get("/current", response_model=Workflow)
+def get_current_workflow(
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+    workflow_id: Optional[int] = Query(None, description="Open this workflow instead of the default one"),
+):
+    """
+    Get the user's current working workflow.
+    Creates a default workflow if none exists.
+    """
+    return _current_workflow(db, current_user, workflow_id)
 
 
 @router.patch("/current/canvas", response_model=Workflow)
@@ -178,25 +270,13 @@ def update_current_workflow_canvas(
     db: Session = Depends(get_db),
     payload: CanvasUpdate,
     current_user: User = Depends(get_current_user),
+    workflow_id: Optional[int] = Query(None, description="Save to this workflow instead of the default one"),
 ):
     """
     Update the canvas for the user's current workflow.
     Creates a default workflow if none exists.
     """
-    # Get most recent workflow
-    workflows = crud.get_workflows(db, owner_id=current_user.id, skip=0, limit=1)
-
-    if workflows:
-        workflow = workflows[0]
-    else:
-        # Create default workflow if none exists
-        workflow = crud.create_workflow(
-            db=db,
-            owner_id=current_user.id,
-            name="My Workflow",
-            description="",
-            canvas={"nodes": [], "edges": []},
-        )
+    workflow = _current_workflow(db, current_user, workflow_id)
 
     # Validate and store directly
     try:
@@ -223,7 +303,20 @@ def read_workflows(
     return crud.get_workflows(db, owner_id=current_user.id, skip=skip, limit=limit)
 
 
-# Rename workflow
+@router.get("/trash", response_model=List[Workflow])
+def read_trashed_workflows(
+    db: Session = Depends(get_db),
+    current_user: User = Depends(get_current_user),
+):
+    """Workflows the current user moved to the trash, newest first.
+
+    Expired entries are purged lazily here, as for trashed agents.
+    """
+    crud.purge_expired_trashed_workflows(db, owner_id=current_user.id)
+    return crud.get_trashed_workflows(db, owner_id=current_user.id)
+
+
+# Rename workflow or change its description and tags
 @router.patch("/{workflow_id}", response_model=Workflow)
 def rename_workflow(
     *,
@@ -240,12 +333,17 @@ def rename_workflow(
         wf.name = payload.name
     if payload.description is not None:
         wf.description = payload.description
+    if payload.tags is not None:
+        try:
+            wf.tags = normalize_tags(payload.tags) or None
+        except ValueError as exc:
+            raise HTTPException(status_code=422, detail=str(exc))
     db.commit()
     db.refresh(wf)
     return wf
 
 
-# Delete workflow (soft delete by flag)
+# Delete workflow (moves it to the trash)
 @router.delete("/{workflow_id}", status_code=status.HTTP_204_NO_CONTENT)
 def delete_workflow(
     *,
@@ -253,12 +351,50 @@ def delete_workflow(
     workflow_id: int,
     current_user: User = Depends(get_current_user),
 ):
+    """Move a workflow to the trash, restorable for ``WORKFLOW_TRASH_RETENTION_DAYS``."""
+    wf = crud.get_workflow(db, workflow_id)
+    if wf is None or wf.owner_id != current_user.id or not wf.is_active:
+        raise HTTPException(status_code=404, detail="workflow not found")
+
+    crud.trash_workflow(db, workflow_id)
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+
+def _get_trashed_workflow_or_404(db: Session, workflow_id: int, current_user: User):
     wf = crud.get_workflow(db, workflow_id)
     if wf is None or wf.owner_id != current_user.id:
         raise HTTPException(status_code=404, detail="workflow not found")
+    if wf.deleted_at is None:
+        raise HTTPException(status_code=409, detail="Workflow is not in the trash")
+    return wf
 
-    wf.is_active = False
-    db.commit()
+
+@router.post("/{workflow_id}/restore", response_model=Workflow)
+def restore_workflow(
+    *,
+    db: Session = Depends(get_db),
+    workflow_id: int,
+    current_user: User = Depends(get_current_user),
+):
+    """Bring a trashed workflow back with its canvas, layout and history."""
+    _get_trashed_workflow_or_404(db, workflow_id, current_user)
+    wf = crud.restore_workflow(db, workflow_id)
+    if wf is None:
+        raise HTTPException(status_code=404, detail="workflow not found")
+    return wf
+
+
+@router.delete("/{workflow_id}/permanent", status_code=status.HTTP_204_NO_CONTENT)
+def permanently_delete_workflow(
+    *,
+    db: Session = Depends(get_db),
+    workflow_id: int,
+    current_user: User = Depends(get_current_user),
+):
+    """Hard-delete a trashed workflow together with its executions and layouts."""
+    _get_trashed_workflow_or_404(db, workflow_id, current_user)
+    if not crud.delete_workflow(db, workflow_id):
+        raise HTTPException(status_code=404, detail="workflow not found")
     return Response(status_code=status.HTTP_204_NO_CONTENT)
 
 
@@ -317,3 +453,140 @@ def get_workflow_layout(
         return Response(status_code=status.HTTP_204_NO_CONTENT)
 
     return {"nodes": layout.nodes_json, "viewport": layout.viewport}
+
+
+# ---------------------------------------------------------------------------
+# Comment threads on canvas nodes
+# ---------------------------------------------------------------------------
+
+# ``@someone@example.com``; the leading @ must not follow another word character
+_MENTION_RE = re.compile(r"(?<![\w@])@([\w.+-]+@[\w-]+(?:\.[\w-]+)+)")
+_MENTION_EXCERPT_CHARS = 140
+
+
+def _get_commentable_workflow_or_404(db: Session, workflow_id: int, current_user: User):
+    # Same audience as the canvas itself: the owner, plus admins
+    wf = crud.get_workflow(db, workflow_id)
+    if wf is None or not wf.is_active or (wf.owner_id != current_user.id and not is_admin(current_user)):
+        raise HTTPException(status_code=404, detail="workflow not found")
+    return wf
+
+
+def _comment_out(comment) -> WorkflowCommentOut:
+    author = comment.author
+    return WorkflowCommentOut(
+        id=comment.id,
+        workflow_id=comment.workflow_id,
+        node_id=comment.node_id,
+        parent_id=comment.parent_id,
+        author_id=comment.author_id,
+        author_name=(author.display_name or author.email) if author is not None else f"User {comment.author_id}",
+        author_avatar_url=getattr(author, "avatar_url", None),
+        body=comment.body,
+        mentioned_user_ids=comment.mentioned_user_ids or [],
+        resolved_at=comment.resolved_at,
+        resolved_by_id=comment.resolved_by_id,
+        created_at=comment.created_at,
+    )
+
+
+def _mentioned_users(db: Session, body: str, author_id: int) -> List[User]:
+    """Users named by ``@email`` in *body*; unknown addresses and the author are skipped."""
+    users: Dict[int, User] = {}
+    for email in _MENTION_RE.findall(body):
+        user = crud.get_user_by_email(db, email.rstrip("."))
+        if user is not None and user.id != author_id:
+            users[user.id] = user
+    return list(users.values())
+
+
+@router.get("/{workflow_id}/comments", response_model=List[WorkflowCommentOut])
+def read_workflow_comments(
+    *,
+    db: Session = Depends(get_db),
+    workflow_id: int,
+    node_id: Optional[str] = Query(None),
+    current_user: User = Depends(get_current_user),
+):
+    """All comments on the workflow (or one node), oldest first; clients group them by ``parent_id``."""
+
+    _get_commentable_workflow_or_404(db, workflow_id, current_user)
+    return [_comment_out(comment) for comment in crud.get_workflow_comments(db, workflow_id, node_id=node_id)]
+
+
+@router.post("/{workflow_id}/comments", response_model=WorkflowCommentOut, status_code=status.HTTP_201_CREATED)
+async def create_workflow_comment(
+    *,
+    db: Session = Depends(get_db),
+    workflow_id: int,
+    payload: WorkflowCommentCreate,
+    current_user: User = Depends(get_current_user),
+):
+    """Start a thread on a node or reply to one, notifying anyone ``@``-mentioned."""
+
+    wf = _get_commentable_workflow_or_404(db, workflow_id, current_user)
+    body = payload.body.strip()
+    if not body:
+        raise HTTPException(status_code=422, detail="Comment cannot be empty")
+
+    node_id = payload.node_id
+    parent_id = None
+    if payload.parent_id is not None:
+        parent = crud.get_workflow_comment(db, workflow_id, payload.parent_id)
+        if parent is None:
+            raise HTTPException(status_code=404, detail="comment not found")
+        # Threads are one level deep; replying to a reply joins its thread
+        parent_id = parent.parent_id or parent.id
+        node_id = parent.node_id
+    if not node_id:
+        raise HTTPException(status_code=422, detail="node_id is required to start a thread")
+
+    mentioned = _mentioned_users(db, body, current_user.id)
+    comment = crud.create_workflow_comment(
+        db,
+        workflow_id=workflow_id,
+        node_id=node_id,
+        author_id=current_user.id,
+        body=body,
+        parent_id=parent_id,
+        mentioned_user_ids=[user.id for user in mentioned],
+    )
+    out = _comment_out(comment)
+
+    excerpt = body if len(body) <= _MENTION_EXCERPT_CHARS else body[: _MENTION_EXCERPT_CHARS - 1] + "…"
+    for user in mentioned:
+        topic = f"user:{user.id}"
+        data = CommentMentionData(
+            workflow_id=wf.id,
+            workflow_name=wf.name,
+            node_id=node_id,
+            comment_id=comment.id,
+            author_name=out.author_name,
+            excerpt=excerpt,
+        )
+        envelope = Envelope.create(message_type="comment_mention", topic=topic, data=data.model_dump())
+        await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+
+    return out
+
+
+@router.patch("/{workflow_id}/comments/{comment_id}", response_model=WorkflowCommentOut)
+def update_workflow_comment(
+    *,
+    db: Session = Depends(get_db),
+    workflow_id: int,
+    comment_id: int,
+    payload: WorkflowCommentUpdate,
+    current_user: User = Depends(get_current_user),
+):
+    """Resolve or reopen the thread that *comment_id* belongs to."""
+
+    _get_commentable_workflow_or_404(db, workflow_id, current_user)
+    comment = crud.get_workflow_comment(db, workflow_id, comment_id)
+    if comment is None:
+        raise HTTPException(status_code=404, detail="comment not found")
+    if comment.parent_id is not None:
+        comment = crud.get_workflow_comment(db, workflow_id, comment.parent_id)
+
+    comment = crud.set_workflow_comment_resolved(db, comment, current_user.id if payload.resolved else None)
+    return _comment_out(comment)
@@ -8,6 +8,7 @@ Replaces inconsistent output formats with a clean value/meta separation.
 from datetime import datetime
 from typing import Any
 from typing import Dict
+from typing import List
 from typing import Literal
 from typing import Optional
 from typing import Union
@@ -21,7 +22,9 @@ class NodeMetadata(BaseModel):
     """Standard metadata for all node executions."""
 
     # Required fields for all nodes
-    node_type: Literal["tool", "agent", "trigger", "conditional"] = Field(description="Type of node that was executed")
+    node_type: Literal[
+        "tool", "agent", "trigger", "conditional", "switch", "loop", "http", "wait", "subworkflow"
+    ] = Field(description="Type of node that was executed")
     phase: Literal["waiting", "running", "finished"] = Field(description="Current execution phase")
     result: Optional[Literal["success", "failure", "cancelled"]] = Field(
         None, description="Execution outcome (when phase=finished)"
@@ -75,6 +78,59 @@ class ConditionalNodeMetadata(NodeMetadata):
     evaluation_method: Optional[str] = Field(None, description="Method used for evaluation (e.g., ast_safe)")
 
 
+class SwitchNodeMetadata(NodeMetadata):
+    """Metadata specific to switch node executions."""
+
+    node_type: Literal["switch"] = "switch"
+
+    # Switch-specific metadata
+    expression: Optional[str] = Field(None, description="Original switch expression")
+    cases: Optional[List[str]] = Field(None, description="Case labels in match order")
+
+
+class LoopNodeMetadata(NodeMetadata):
+    """Metadata specific to loop node executions."""
+
+    node_type: Literal["loop"] = "loop"
+
+    # Loop-specific metadata
+    max_iterations: Optional[int] = Field(None, description="Upper bound on body iterations")
+    condition: Optional[str] = Field(None, description="Continue-while condition, if any")
+
+
+class HttpNodeMetadata(NodeMetadata):
+    """Metadata specific to HTTP request node executions."""
+
+    node_type: Literal["http"] = "http"
+
+    # HTTP-specific metadata
+    method: Optional[str] = Field(None, description="HTTP method")
+    url: Optional[str] = Field(None, description="Request URL after variable resolution")
+    status_code: Optional[int] = Field(None, description="Response status code")
+
+
+class WaitNodeMetadata(NodeMetadata):
+    """Metadata specific to wait node executions."""
+
+    node_type: Literal["wait"] = "wait"
+
+    # Wait-specific metadata
+    mode: Optional[str] = Field(None, description="Wait mode (duration, until or event)")
+    resume_at: Optional[str] = Field(None, description="When the wait was due to end (ISO 8601, UTC)")
+    event: Optional[str] = Field(None, description="External event name for event waits")
+    outcome: Optional[str] = Field(None, description="How the wait ended (elapsed, skipped or event)")
+
+
+class SubworkflowNodeMetadata(NodeMetadata):
+    """Metadata specific to Call Workflow node executions."""
+
+    node_type: Literal["subworkflow"] = "subworkflow"
+
+    # Subworkflow-specific metadata
+    workflow_id: Optional[int] = Field(None, description="Workflow that was called")
+    child_execution_id: Optional[int] = Field(None, description="Execution of the called workflow")
+
+
 class TriggerNodeMetadata(NodeMetadata):
     """Metadata specific to trigger node executions."""
 
@@ -96,9 +152,18 @@ class NodeOutputEnvelope(BaseModel):
     model_config = ConfigDict(json_encoders={datetime: lambda v: v.isoformat() if v else None})
 
     value: Any = Field(description="Primary result value from node execution")
-    meta: Union[ToolNodeMetadata, AgentNodeMetadata, ConditionalNodeMetadata, TriggerNodeMetadata, NodeMetadata] = (
-        Field(description="Execution metadata and context")
-    )
+    meta: Union[
+        ToolNodeMetadata,
+        AgentNodeMetadata,
+        ConditionalNodeMetadata,
+        SwitchNodeMetadata,
+        LoopNodeMetadata,
+        HttpNodeMetadata,
+        WaitNodeMetadata,
+        SubworkflowNodeMetadata,
+        TriggerNodeMetadata,
+        NodeMetadata,
+    ] = Field(description="Execution metadata and context")
 
 
 # Convenience functions for creating envelopes
@@ -227,6 +292,215 @@ def create_conditional_envelope(
     return NodeOutputEnvelope(value=value, meta=metadata)
 
 
+def create_switch_envelope(
+    value: Any,
+    *,
+    phase: Literal["waiting", "running", "finished"] = "finished",
+    result: Optional[Literal["success", "failure", "cancelled"]] = "success",
+    expression: Optional[str] = None,
+    cases: Optional[List[str]] = None,
+    execution_time_ms: Optional[int] = None,
+    error_message: Optional[str] = None,
+    **kwargs,
+) -> NodeOutputEnvelope:
+    """
+    Create a standardized switch node output envelope.
+
+    Args:
+        value: Switched-on value and the chosen branch
+        phase: Current execution phase
+        result: Execution outcome (when phase=finished)
+        expression: Original switch expression
+        cases: Case labels in match order
+        execution_time_ms: Execution time in milliseconds
+        error_message: Error message if result=failure
+        **kwargs: Additional metadata fields
+
+    Returns:
+        NodeOutputEnvelope with switch metadata
+    """
+    metadata = SwitchNodeMetadata(
+        phase=phase,
+        result=result,
+        expression=expression,
+        cases=cases,
+        execution_time_ms=execution_time_ms,
+        error_message=error_message,
+        **kwargs,
+    )
+
+    return NodeOutputEnvelope(value=value, meta=metadata)
+
+
+def create_loop_envelope(
+    value: Any,
+    *,
+    phase: Literal["waiting", "running", "finished"] = "finished",
+    result: Optional[Literal["success", "failure", "cancelled"]] = "success",
+    max_iterations: Optional[int] = None,
+    condition: Optional[str] = None,
+    execution_time_ms: Optional[int] = None,
+    error_message: Optional[str] = None,
+    **kwargs,
+) -> NodeOutputEnvelope:
+    """
+    Create a standardized loop node output envelope.
+
+    Args:
+        value: Current iteration (index, item) and the chosen branch
+        phase: Current execution phase
+        result: Execution outcome (when phase=finished)
+        max_iterations: Upper bound on body iterations
+        condition: Continue-while condition, if any
+        execution_time_ms: Execution time in milliseconds
+        error_message: Error message if result=failure
+        **kwargs: Additional metadata fields
+
+    Returns:
+        NodeOutputEnvelope with loop metadata
+    """
+    metadata = LoopNodeMetadata(
+        phase=phase,
+        result=result,
+        max_iterations=max_iterations,
+        condition=condition,
+        execution_time_ms=execution_time_ms,
+        error_message=error_message,
+        **kwargs,
+    )
+
+    return NodeOutputEnvelope(value=value, meta=metadata)
+
+
+def create_http_envelope(
+    value: Any,
+    *,
+    phase: Literal["waiting", "running", "finished"] = "finished",
+    result: Optional[Literal["success", "failure", "cancelled"]] = "success",
+    method: Optional[str] = None,
+    url: Optional[str] = None,
+    status_code: Optional[int] = None,
+    execution_time_ms: Optional[int] = None,
+    error_message: Optional[str] = None,
+    **kwargs,
+) -> NodeOutputEnvelope:
+    """
+    Create a standardized HTTP request node output envelope.
+
+    Args:
+        value: Response status, headers and body
+        phase: Current execution phase
+        result: Execution outcome (when phase=finished)
+        method: HTTP method
+        url: Request URL after variable resolution
+        status_code: Response status code
+        execution_time_ms: Execution time in milliseconds
+        error_message: Error message if result=failure
+        **kwargs: Additional metadata fields
+
+    Returns:
+        NodeOutputEnvelope with HTTP metadata
+    """
+    metadata = HttpNodeMetadata(
+        phase=phase,
+        result=result,
+        method=method,
+        url=url,
+        status_code=status_code,
+        execution_time_ms=execution_time_ms,
+        error_message=error_message,
+        **kwargs,
+    )
+
+    return NodeOutputEnvelope(value=value, meta=metadata)
+
+
+def create_wait_envelope(
+    value: Any,
+    *,
+    phase: Literal["waiting", "running", "finished"] = "finished",
+    result: Optional[Literal["success", "failure", "cancelled"]] = "success",
+    mode: Optional[str] = None,
+    resume_at: Optional[str] = None,
+    event: Optional[str] = None,
+    outcome: Optional[str] = None,
+    execution_time_ms: Optional[int] = None,
+    error_message: Optional[str] = None,
+    **kwargs,
+) -> NodeOutputEnvelope:
+    """
+    Create a standardized wait node output envelope.
+
+    Args:
+        value: How the wait ended and any event payload
+        phase: Current execution phase
+        result: Execution outcome (when phase=finished)
+        mode: Wait mode (duration, until or event)
+        resume_at: When the wait was due to end (ISO 8601, UTC)
+        event: External event name for event waits
+        outcome: How the wait ended (elapsed, skipped or event)
+        execution_time_ms: Execution time in milliseconds
+        error_message: Error message if result=failure
+        **kwargs: Additional metadata fields
+
+    Returns:
+        NodeOutputEnvelope with wait metadata
+    """
+    metadata = WaitNodeMetadata(
+        phase=phase,
+        result=result,
+        mode=mode,
+        resume_at=resume_at,
+        event=event,
+        outcome=outcome,
+        execution_time_ms=execution_time_ms,
+        error_message=error_message,
+        **kwargs,
+    )
+
+    return NodeOutputEnvelope(value=value, meta=metadata)
+
+
+def create_subworkflow_envelope(
+    value: Any,
+    *,
+    phase: Literal["waiting", "running", "finished"] = "finished",
+    result: Optional[Literal["success", "failure", "cancelled"]] = "success",
+    workflow_id: Optional[int] = None,
+    child_execution_id: Optional[int] = None,
+    execution_time_ms: Optional[int] = None,
+    error_message: Optional[str] = None,
+    **kwargs,
+) -> NodeOutputEnvelope:
+    """
+    Create a standardized Call Workflow node output envelope.
+
+    Args:
+        value: The child run's id, result and mapped outputs
+        phase: Current execution phase
+        result: Execution outcome (when phase=finished)
+        workflow_id: Workflow that was called
+        child_execution_id: Execution of the called workflow
+        execution_time_ms: Execution time in milliseconds
+        error_message: Error message if result=failure
+        **kwargs: Additional metadata fields
+
+    Returns:
+        NodeOutputEnvelope with subworkflow metadata
+    """
+    metadata = SubworkflowNodeMetadata(
+        phase=phase,
+        result=result,
+        workflow_id=workflow_id,
+        child_execution_id=child_execution_id,
+        execution_time_ms=execution_time_ms,
+        error_message=error_message,
+        **kwargs,
+    )
+
+    return NodeOutputEnvelope(value=value, meta=metadata)
+
+
 def create_trigger_envelope(
     value: Any,
     *,
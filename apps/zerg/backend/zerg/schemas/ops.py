@@ -57,3 +57,23 @@ class TimeSeriesResponse(BaseModel):
 class TopAgentsResponse(BaseModel):
     """Response containing top agents list."""
     top_agents: List[OpsTopAgent]
+
+
+class StatusWebSocket(BaseModel):
+    """WebSocket server state."""
+    available: bool
+    active_connections: int
+
+
+class SystemStatus(BaseModel):
+    """System health for the status page – aggregate counts only, no per-user data."""
+    status: str  # "ok" or "degraded"
+    checked_at: str
+    database: str  # "ok" or "error"
+    websocket: StatusWebSocket
+    runs_last_hour: int
+    errors_last_hour: int
+    errors_last_24h: int
+    error_rate_last_hour: Optional[float]
+    frontend_errors_last_hour: int
+    latency_ms: LatencyStats
@@ -3,6 +3,7 @@ from enum import Enum
 from typing import Any
 from typing import Dict
 from typing import List
+from typing import Literal
 from typing import Optional
 
 # ---------------------------------------------------------------------------
@@ -10,12 +11,18 @@ from typing import Optional
 # ---------------------------------------------------------------------------
 from pydantic import BaseModel
 from pydantic import ConfigDict
+from pydantic import Field
+from pydantic import field_validator
 
 from zerg.models.enums import AgentStatus
 from zerg.models.enums import RunStatus
+from zerg.models.enums import SharePermission
 from zerg.schemas.workflow import WorkflowData
 
 
+AGENT_DESCRIPTION_MAX_CHARS = 20000
+
+
 # Agent schemas
 class AgentBase(BaseModel):
     name: str
@@ -24,6 +31,9 @@ class AgentBase(BaseModel):
     model: str
     schedule: Optional[str] = None
     config: Optional[Dict[str, Any]] = None
+    prompt_variables: Optional[Dict[str, str]] = None
+    description: Optional[str] = None
+    tags: Optional[List[str]] = None
     last_error: Optional[str] = None
     allowed_tools: Optional[List[str]] = None
 
@@ -36,6 +46,9 @@ class AgentCreate(BaseModel):
     model: str
     schedule: Optional[str] = None
     config: Optional[Dict[str, Any]] = None
+    prompt_variables: Optional[Dict[str, str]] = None
+    description: Optional[str] = Field(None, max_length=AGENT_DESCRIPTION_MAX_CHARS)
+    tags: Optional[List[str]] = None
     allowed_tools: Optional[List[str]] = None
 
 
@@ -47,10 +60,28 @@ class AgentUpdate(BaseModel):
     status: Optional[AgentStatus] = None
     schedule: Optional[str] = None
     config: Optional[Dict[str, Any]] = None
+    prompt_variables: Optional[Dict[str, str]] = None
+    # Markdown; an empty string clears it
+    description: Optional[str] = Field(None, max_length=AGENT_DESCRIPTION_MAX_CHARS)
+    # Replaces the whole list; an empty list clears it
+    tags: Optional[List[str]] = None
     last_error: Optional[str] = None
     allowed_tools: Optional[List[str]] = None
 
 
+class AgentPatch(BaseModel):
+    """Raw edit from the JSON editor: the changed ``AgentUpdate`` fields."""
+
+    changes: Dict[str, Any]
+    # Values of the changed fields when the editor loaded them; a field that
+    # has moved on since is a conflict instead of a silent overwrite
+    base: Dict[str, Any] = Field(default_factory=dict)
+
+
+class AgentPatchValidation(BaseModel):
+    errors: List[str]
+
+
 # ---------------------------------------------------------------------------
 # Pydantic 2.x quirk: on some Python versions ForwardRef resolution fails when
 # schema is imported *before* all referenced types are defined.  Calling
@@ -99,6 +130,13 @@ class UserOut(BaseModel):
     gmail_connected: bool = False
 
 
+class CapabilitiesOut(BaseModel):
+    """Permission set for the current user (see zerg.auth.capabilities)."""
+
+    role: str
+    capabilities: List[str]
+
+
 # User profile update schema (partial)
 class UserUpdate(BaseModel):
     display_name: Optional[str] = None
@@ -106,6 +144,17 @@ class UserUpdate(BaseModel):
     prefs: Optional[Dict[str, Any]] = None
 
 
+class WorkspaceDefaults(BaseModel):
+    """Preferences that apply to every user until they choose their own (admin-managed)."""
+
+    theme: Optional[str] = None
+    timezone: Optional[str] = None
+    time_display: Optional[str] = None
+    default_model: Optional[str] = None
+    dashboard_scope: Optional[str] = None
+    dashboard_sort: Optional[Dict[str, Any]] = None
+
+
 class TokenOut(BaseModel):
     access_token: str
     token_type: str = "bearer"
@@ -121,8 +170,62 @@ class ThreadMessageBase(BaseModel):
     name: Optional[str] = None
 
 
+class ChatAttachment(BaseModel):
+    """File uploaded via ``POST /threads/{id}/attachments``, referenced by a message."""
+
+    id: str
+    filename: str
+    content_type: str
+    size: int
+    url: str
+
+
 class ThreadMessageCreate(ThreadMessageBase):
     sent_at: Optional[datetime] = None  # Client can provide send timestamp; server validates ±5min, or uses server time
+    attachments: Optional[List[ChatAttachment]] = None
+
+
+class ThreadForkRequest(BaseModel):
+    """Edit-and-resend: fork before *message_id* and post *content* instead."""
+
+    message_id: int
+    content: str = Field(..., min_length=1)
+
+
+class MessageAuthor(BaseModel):
+    """Public profile of the user who wrote a message in a shared thread."""
+
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    display_name: Optional[str] = None
+    avatar_url: Optional[str] = None
+
+
+class MessageUsage(BaseModel):
+    """Token usage, latency and cost of the run that produced an assistant reply."""
+
+    run_id: Optional[int] = None
+    model: Optional[str] = None
+    prompt_tokens: Optional[int] = None
+    completion_tokens: Optional[int] = None
+    total_tokens: Optional[int] = None
+    duration_ms: Optional[int] = None
+    cost_usd: Optional[float] = None
+
+
+class MessageReaction(BaseModel):
+    emoji: str
+    count: int
+    # Whether the requesting user is one of the reactors
+    reacted: bool = False
+
+
+class MessageMetadataUpdate(BaseModel):
+    """Bookmark a message and/or toggle one of the caller's reactions on it."""
+
+    bookmarked: Optional[bool] = None
+    reaction: Optional[str] = None
 
 
 class ThreadMessageResponse(ThreadMessageBase):
@@ -136,6 +239,13 @@ class ThreadMessageResponse(ThreadMessageBase):
     # Fields for message type and tool display
     message_type: Optional[str] = None
     tool_name: Optional[str] = None
+    attachments: List[ChatAttachment] = []
+    author: Optional[MessageAuthor] = None
+    # Only set on the final assistant message of a chat run
+    usage: Optional[MessageUsage] = None
+    # Bookmarks and reactions are per user; these reflect the requesting user
+    bookmarked: bool = False
+    reactions: List[MessageReaction] = []
 
 
 # Thread schemas
@@ -157,6 +267,10 @@ class ThreadUpdate(BaseModel):
     memory_strategy: Optional[str] = None
     active: Optional[bool] = None
     thread_type: Optional[str] = None
+    pinned: Optional[bool] = None
+    archived: Optional[bool] = None
+    # Empty string clears the override so the thread follows the agent's model again
+    model_override: Optional[str] = None
 
 
 class Thread(ThreadBase):
@@ -164,6 +278,9 @@ class Thread(ThreadBase):
 
     id: int
     agent_id: int
+    pinned: bool = False
+    archived: bool = False
+    model_override: Optional[str] = None
     created_at: datetime
     updated_at: datetime
     messages: List[ThreadMessageResponse] = []
@@ -183,6 +300,10 @@ class Agent(AgentBase):
     next_run_at: Optional[datetime] = None
     last_run_at: Optional[datetime] = None
     last_error: Optional[str] = None
+    # Set while the agent is in the trash
+    deleted_at: Optional[datetime] = None
+    # Set while scheduled runs and trigger firings are suspended
+    paused_at: Optional[datetime] = None
 
 
 # ---------------------------------------------------------------------------
@@ -249,6 +370,99 @@ class Trigger(TriggerBase):
     created_at: datetime
 
 
+class EmailTriggerFilters(BaseModel):
+    """Structured e-mail filter stored under ``Trigger.config["filters"]``.
+
+    Keys mirror :mod:`zerg.services.email_filtering`; empty values mean
+    "no constraint" and are dropped when persisted.
+    """
+
+    model_config = ConfigDict(extra="forbid")
+
+    from_contains: List[str] = Field(default_factory=list, max_length=20)
+    subject_contains: List[str] = Field(default_factory=list, max_length=20)
+    label_include: List[str] = Field(default_factory=list, max_length=20)
+    label_exclude: List[str] = Field(default_factory=list, max_length=20)
+    has_attachment: bool = False
+    query: Optional[str] = Field(None, max_length=500)
+
+    @field_validator("from_contains", "subject_contains", "label_include", "label_exclude")
+    @classmethod
+    def _strip_terms(cls, value: List[str]) -> List[str]:
+        return [term.strip() for term in value if term and term.strip()]
+
+    def for_storage(self) -> Dict[str, Any]:
+        return {key: value for key, value in self.model_dump().items() if value}
+
+
+class TriggerUpdate(BaseModel):
+    # Replaces the e-mail filter; ``null`` clears it
+    filters: Optional[EmailTriggerFilters] = None
+
+
+class EmailFilterPreviewRequest(BaseModel):
+    connector_id: int
+    filters: EmailTriggerFilters = Field(default_factory=EmailTriggerFilters)
+    limit: int = Field(10, ge=1, le=25)
+
+
+class EmailPreviewMessage(BaseModel):
+    id: str
+    sender: Optional[str] = None
+    subject: Optional[str] = None
+    date: Optional[str] = None
+    snippet: Optional[str] = None
+    label_ids: List[str] = Field(default_factory=list)
+    has_attachment: bool = False
+
+
+class EmailFilterPreviewOut(BaseModel):
+    # Gmail search string the preview ran, shown so users can reproduce it
+    query: str
+    scanned: int
+    messages: List[EmailPreviewMessage]
+
+
+class GmailDependentTrigger(BaseModel):
+    id: int
+    agent_id: int
+    agent_name: str
+    disabled: bool = False
+
+
+class GmailConnectionOut(BaseModel):
+    connected: bool
+    connector_id: Optional[int] = None
+    email_address: Optional[str] = None
+    scopes: List[str] = Field(default_factory=list)
+    connected_at: Optional[datetime] = None
+    watch_expiry: Optional[int] = None  # epoch-ms
+    # "healthy", "watch_expired", "auth_failed" or "not_connected"
+    health: str
+    health_detail: Optional[str] = None
+    triggers: List[GmailDependentTrigger] = Field(default_factory=list)
+
+
+class GmailDisconnectOut(BaseModel):
+    status: str
+    disabled_trigger_ids: List[int]
+
+
+class TriggerDeliveryOut(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    trigger_id: int
+    status_code: int
+    error: Optional[str] = None
+    is_test: bool = False
+    payload_bytes: Optional[int] = None
+    received_at: datetime
+    matched: Optional[str] = None
+    run_id: Optional[int] = None
+    run_status: Optional[str] = None
+
+
 # ------------------------------------------------------------
 # AgentRun output schema (read-only, hence *Out* suffix)
 # ------------------------------------------------------------
@@ -270,15 +484,109 @@ class AgentRunOut(BaseModel):
 
     id: int
     agent_id: int
-    thread_id: int
+    thread_id: Optional[int] = None
     status: RunStatus
     trigger: RunTrigger
+    queue_position: Optional[int] = None
     started_at: Optional[datetime] = None
     finished_at: Optional[datetime] = None
     duration_ms: Optional[int] = None
     total_tokens: Optional[int] = None
     total_cost_usd: Optional[float] = None
     error: Optional[str] = None
+    trace_id: Optional[str] = None
+    # Only for agents with an output schema
+    output: Optional[Any] = None
+    schema_errors: Optional[List[str]] = None
+
+
+class RunQueueOrder(BaseModel):
+    """New order for an agent's queued runs: every queued run id, next first."""
+
+    run_ids: List[int]
+
+
+# ---------------------------------------------------------------------------
+# Account data export
+# ---------------------------------------------------------------------------
+
+
+class DataExportOut(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    status: str  # queued, running, success, failed
+    size_bytes: Optional[int] = None
+    error: Optional[str] = None
+    created_at: datetime
+    finished_at: Optional[datetime] = None
+
+
+# ---------------------------------------------------------------------------
+# Agent export / import (see zerg.services.agent_portability)
+# ---------------------------------------------------------------------------
+
+
+class AgentExportConfig(BaseModel):
+    name: str
+    system_instructions: str
+    task_instructions: str
+    model: str
+    schedule: Optional[str] = None
+    config: Optional[Dict[str, Any]] = None
+    allowed_tools: Optional[List[str]] = None
+    prompt_variables: Optional[Dict[str, str]] = None
+    description: Optional[str] = None
+    tags: Optional[List[str]] = None
+
+
+class AgentExportTrigger(BaseModel):
+    type: str
+    config: Optional[Dict[str, Any]] = None
+
+
+class AgentExport(BaseModel):
+    format: str = "zerg-agent"
+    version: int = 1
+    exported_at: Optional[datetime] = None
+    agent: AgentExportConfig
+    triggers: List[AgentExportTrigger] = []
+
+
+class AgentImportConflict(BaseModel):
+    field: str  # name, model, mcp_servers, triggers
+    message: str
+
+
+class ToolUsageStat(BaseModel):
+    name: str
+    calls: int
+    last_used_at: Optional[datetime] = None
+
+
+class ToolUsageResponse(BaseModel):
+    agent_id: int
+    tools: List[ToolUsageStat]
+
+
+class AgentImportPreview(BaseModel):
+    name: str
+    suggested_name: str
+    name_conflict: bool
+    model: str
+    model_available: bool
+    has_schedule: bool
+    trigger_count: int
+    conflicts: List[AgentImportConflict] = []
+
+
+class AgentImportRequest(BaseModel):
+    export: AgentExport
+    # Resolutions from the import wizard; unset values keep the exported ones
+    name: Optional[str] = None
+    model: Optional[str] = None
+    include_schedule: bool = True
+    include_triggers: bool = True
 
 
 # ---------------------------------------------------------------------------
@@ -291,12 +599,176 @@ class AgentRunsBundle(BaseModel):
     runs: List[AgentRunOut] = []
 
 
+class AgentDailyRunCount(BaseModel):
+    date: str  # YYYY-MM-DD (UTC)
+    count: int
+
+
+class AgentStats(BaseModel):
+    """Run totals over an agent's whole history plus a short daily series."""
+
+    agent_id: int
+    run_count: int
+    success_count: int
+    failed_count: int
+    # Percentage of finished runs that succeeded; None until one finishes
+    success_rate: Optional[float] = None
+    avg_duration_ms: Optional[float] = None
+    daily_runs: List[AgentDailyRunCount] = []
+
+
+class AgentModelUsage(BaseModel):
+    model: str
+    run_count: int
+    total_tokens: int
+    # None when the model has no known price
+    total_cost_usd: Optional[float] = None
+
+
+class AgentUsage(BaseModel):
+    """Tokens and cost of an agent's runs over the last ``days`` days, split by model."""
+
+    agent_id: int
+    days: int
+    run_count: int
+    total_tokens: int
+    # None when no run in the window has a known cost
+    total_cost_usd: Optional[float] = None
+    by_model: List[AgentModelUsage] = []
+
+
+class AgentDependentWorkflow(BaseModel):
+    id: int
+    name: str
+
+
+class AgentDependentTrigger(BaseModel):
+    id: int
+    type: str
+    disabled: bool = False
+
+
+class AgentDependents(BaseModel):
+    """What still relies on an agent, listed before it is deleted."""
+
+    agent_id: int
+    # Workflows with an agent node pointing at it; they fail there while it is trashed
+    workflows: List[AgentDependentWorkflow] = []
+    triggers: List[AgentDependentTrigger] = []
+    schedule: Optional[str] = None
+    next_run_at: Optional[datetime] = None
+    queued_run_ids: List[int] = []
+
+
+class AgentBudgetStatus(BaseModel):
+    """Spend against a per-agent cap for the current UTC day or month."""
+
+    agent_id: int
+    period: Literal["daily", "monthly"]
+    limit_usd: float
+    spent_usd: float
+    percent: float
+
+
+class AgentShareUpsert(BaseModel):
+    """Grant *permission* to the user with *user_email*, or to everyone when it is omitted."""
+
+    user_email: Optional[str] = None
+    permission: SharePermission
+
+
+class AgentShareOut(BaseModel):
+    model_config = ConfigDict(from_attributes=True)
+
+    id: int
+    agent_id: int
+    # None for a workspace-wide share
+    user_id: Optional[int] = None
+    user_email: Optional[str] = None
+    permission: SharePermission
+    created_at: Optional[datetime] = None
+
+
+class ModelComparisonRequest(BaseModel):
+    """Run the agent once per model on the same message."""
+
+    models: List[str] = Field(..., min_length=2, max_length=4)
+    # Defaults to the agent's task instructions
+    input: Optional[str] = None
+
+
+class ModelComparisonResult(BaseModel):
+    model: str
+    output: str
+    error: Optional[str] = None
+    duration_ms: int
+    prompt_tokens: Optional[int] = None
+    completion_tokens: Optional[int] = None
+    total_tokens: Optional[int] = None
+    # None when the model has no known pricing
+    cost_usd: Optional[float] = None
+
+
+class ModelComparison(BaseModel):
+    agent_id: int
+    input: str
+    # One entry per requested model, in request order
+    results: List[ModelComparisonResult]
+
+
+class ModelParams(BaseModel):
+    temperature: Optional[float] = None
+    top_p: Optional[float] = None
+    max_tokens: Optional[int] = None
+    stop: List[str] = []
+
+
+class AgentModelParams(BaseModel):
+    """Generation parameters of an agent's runs and where each one comes from."""
+
+    agent_id: int
+    model: str
+    # From config/models.json for the agent's model
+    defaults: ModelParams
+    # The agent's own settings (``config.model_params``)
+    overrides: ModelParams
+    # What the next run uses, after MAX_OUTPUT_TOKENS is applied
+    effective: ModelParams
+
+
+class AgentInstructionVersionOut(BaseModel):
+    """One saved revision of an agent's system/task instructions."""
+
+    id: int
+    agent_id: int
+    version: int
+    system_instructions: str
+    task_instructions: str
+    author_id: Optional[int] = None
+    author_email: Optional[str] = None
+    # Set when this version was created by rolling back to an earlier one
+    restored_from_version: Optional[int] = None
+    created_at: Optional[datetime] = None
+
+
+class AgentPermission(BaseModel):
+    """The caller's access to an agent they don't own."""
+
+    agent_id: int
+    permission: SharePermission
+
+
 class DashboardSnapshot(BaseModel):
     scope: str
     fetched_at: datetime
     runs_limit: int
     agents: List[Agent]
     runs: List[AgentRunsBundle]
+    # Only agents with a budget configured appear here
+    budgets: List[AgentBudgetStatus] = []
+    stats: List[AgentStats] = []
+    # Only agents shared with the caller appear here (scope=shared)
+    permissions: List[AgentPermission] = []
 
 
 # ------------------------------------------------------------
@@ -318,6 +790,8 @@ class WorkflowCreate(WorkflowBase):
 class WorkflowUpdate(BaseModel):
     name: Optional[str] = None
     description: Optional[str] = None
+    # Replaces the whole list; an empty list clears it
+    tags: Optional[List[str]] = None
 
 
 class Workflow(WorkflowBase):
@@ -329,6 +803,38 @@ class Workflow(WorkflowBase):
     created_at: datetime
     updated_at: datetime
     canvas: WorkflowData
+    tags: Optional[List[str]] = None
+    # Set while the workflow is in the trash
+    deleted_at: Optional[datetime] = None
+
+
+class WorkflowCommentCreate(BaseModel):
+    """Start a thread on *node_id*, or reply to the thread rooted at *parent_id*."""
+
+    node_id: Optional[str] = None
+    parent_id: Optional[int] = None
+    # ``@user@example.com`` mentions notify that user
+    body: str = Field(..., min_length=1, max_length=5000)
+
+
+class WorkflowCommentUpdate(BaseModel):
+    resolved: bool
+
+
+class WorkflowCommentOut(BaseModel):
+    id: int
+    workflow_id: int
+    node_id: str
+    # None for a thread root
+    parent_id: Optional[int] = None
+    author_id: int
+    author_name: str
+    author_avatar_url: Optional[str] = None
+    body: str
+    mentioned_user_ids: List[int] = []
+    resolved_at: Optional[datetime] = None
+    resolved_by_id: Optional[int] = None
+    created_at: Optional[datetime] = None
 
 
 # Template Gallery schemas
@@ -356,6 +862,34 @@ class WorkflowTemplate(WorkflowTemplateBase):
     is_public: bool
     created_at: datetime
     updated_at: datetime
+    # Gallery feedback; the my_/is_ fields are for the requesting user
+    rating_avg: Optional[float] = None
+    rating_count: int = 0
+    favorite_count: int = 0
+    my_rating: Optional[int] = None
+    is_favorite: bool = False
+
+
+class TemplatePublishRequest(BaseModel):
+    """Publish one of the caller's workflows to the gallery."""
+
+    workflow_id: int
+    name: str = Field(..., min_length=1, max_length=200)
+    description: Optional[str] = None
+    category: str = Field(..., min_length=1, max_length=100)
+    tags: List[str] = []
+    # Usually a data: URL rendered from the canvas by the frontend
+    preview_image_url: Optional[str] = None
+    is_public: bool = True
+
+
+class TemplateRatingRequest(BaseModel):
+    # None clears the caller's rating
+    rating: Optional[int] = Field(None, ge=1, le=5)
+
+
+class TemplateFavoriteRequest(BaseModel):
+    favorite: bool
 
 
 class TemplateDeployRequest(BaseModel):
@@ -24,6 +24,9 @@ class ExecutionStatusResponse(BaseModel):
     execution_id: int
     phase: str
     result: Optional[Any] = None
+    # Run control (only meaningful while the execution is running)
+    step_mode: bool = False
+    paused_node_id: Optional[str] = None
 
 
 class ExecutionLogsResponse(BaseModel):
@@ -39,12 +42,12 @@ class Position(BaseModel):
 
 
 class WorkflowNode(BaseModel):
-    """A workflow node (agent, tool, trigger, or conditional)."""
+    """A workflow node: agent, tool, trigger, HTTP request, wait, workflow call or control flow (if/switch/loop)."""
 
     model_config = ConfigDict(extra="forbid")
 
     id: str
-    type: Literal["agent", "tool", "trigger", "conditional"]
+    type: Literal["agent", "tool", "trigger", "conditional", "switch", "loop", "http", "wait", "subworkflow"]
     position: Position
     config: Dict[str, Any] = Field(default_factory=dict)
 
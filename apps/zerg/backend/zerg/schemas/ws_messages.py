@@ -26,6 +26,7 @@ class Envelope(BaseModel):
     type: str  # Message type in SCREAMING_SNAKE format
     topic: str  # Topic string like "thread:123", "agent:456"
     req_id: Optional[str] = None  # Echoes client's message_id when applicable
+    trace_id: Optional[str] = None  # Server trace id of the originating request
     ts: int  # Timestamp in milliseconds since epoch, monotonic on server
     data: Dict[str, Any]  # Payload schema depends on type
 
@@ -36,6 +37,7 @@ class Envelope(BaseModel):
         topic: str,
         data: Dict[str, Any],
         req_id: Optional[str] = None,
+        trace_id: Optional[str] = None,
     ) -> "Envelope":
         """Create a new envelope with current timestamp."""
         return cls(
@@ -43,6 +45,7 @@ class Envelope(BaseModel):
             topic=topic,
             data=data,
             req_id=req_id,
+            trace_id=trace_id,
             ts=int(time.time() * 1000),  # Current time in milliseconds
         )
 
@@ -18,6 +18,10 @@ from sqlalchemy.orm import Session
 logger = logging.getLogger(__name__)
 
 
+class AgentAlreadyRunningError(ValueError):
+    """Another run holds the agent's lock."""
+
+
 class AgentLockManager:
     """
     Manages agent concurrency using PostgreSQL advisory locks.
@@ -131,7 +135,7 @@ class AgentLockManager:
                     pass
                 else:
                     # Agent is already running
-                    raise ValueError("Agent already running")
+                    raise AgentAlreadyRunningError("Agent already running")
 
         The lock is automatically released when the context exits,
         even if an exception occurs.
@@ -0,0 +1,236 @@
+"""Duplicate agents and move their configuration between environments.
+
+An *agent export* is a small JSON document with everything needed to
+recreate an agent elsewhere: instructions, model, schedule, prompt
+variables, tool allowlist, MCP servers and triggers.  Anything tied to this
+deployment is stripped on the way out – encrypted MCP auth tokens, webhook
+secrets, the Gmail connector an email trigger listens on and its sync
+cursor – and reported as a conflict on import so the user can re-enter or
+re-link it.
+
+Duplicating an agent stays within the same deployment, so it copies the
+configuration verbatim and only issues new webhook secrets.
+"""
+
+from __future__ import annotations
+
+import copy
+from datetime import datetime
+from datetime import timezone
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.models.models import Agent
+from zerg.schemas.schemas import AgentExport
+from zerg.schemas.schemas import AgentExportConfig
+from zerg.schemas.schemas import AgentExportTrigger
+from zerg.schemas.schemas import AgentImportConflict
+from zerg.schemas.schemas import AgentImportPreview
+from zerg.schemas.schemas import AgentImportRequest
+
+EXPORT_FORMAT = "zerg-agent"
+EXPORT_VERSION = 1
+
+# Trigger config keys that only make sense in the deployment that wrote them
+_LOCAL_TRIGGER_KEYS = ("connector_id", "history_id", "watch_expiry")
+
+
+def _portable_config(config: Optional[Dict[str, Any]]) -> Optional[Dict[str, Any]]:
+    """Copy of *config* with MCP auth tokens and credentials replaced by a ``requires_auth`` flag."""
+
+    if not config:
+        return None
+    portable = copy.deepcopy(dict(config))
+    servers = []
+    for server in portable.get("mcp_servers") or []:
+        server = dict(server)
+        # API credentials are local to this deployment, like the token
+        has_credential = server.pop("api_credential_id", None) is not None
+        if server.pop("auth_token", None) or has_credential:
+            server["requires_auth"] = True
+        servers.append(server)
+    if "mcp_servers" in portable:
+        portable["mcp_servers"] = servers
+    return portable
+
+
+def _portable_trigger_config(config: Optional[Dict[str, Any]]) -> Optional[Dict[str, Any]]:
+    if not config:
+        return None
+    return {key: value for key, value in config.items() if key not in _LOCAL_TRIGGER_KEYS} or None
+
+
+def unique_agent_name(db: Session, owner_id: int, base: str) -> str:
+    """*base*, or the first free ``"<base> (copy N)"`` among the owner's agents."""
+
+    taken = {agent.name for agent in crud.get_agents(db, owner_id=owner_id, limit=10_000)}
+    if base not in taken:
+        return base
+    candidate = f"{base} (copy)"
+    suffix = 2
+    while candidate in taken:
+        candidate = f"{base} (copy {suffix})"
+        suffix += 1
+    return candidate
+
+
+def export_agent(db: Session, agent: Agent) -> AgentExport:
+    triggers = [
+        AgentExportTrigger(type=trigger.type, config=_portable_trigger_config(trigger.config))
+        for trigger in crud.get_triggers(db, agent_id=agent.id)
+    ]
+    return AgentExport(
+        format=EXPORT_FORMAT,
+        version=EXPORT_VERSION,
+        exported_at=datetime.now(timezone.utc),
+        agent=AgentExportConfig(
+            name=agent.name,
+            system_instructions=agent.system_instructions,
+            task_instructions=agent.task_instructions,
+            model=agent.model,
+            schedule=agent.schedule,
+            config=_portable_config(agent.config),
+            allowed_tools=list(agent.allowed_tools) if agent.allowed_tools is not None else None,
+            prompt_variables=dict(agent.prompt_variables) if agent.prompt_variables else None,
+            description=agent.description,
+            tags=list(agent.tags) if agent.tags else None,
+        ),
+        triggers=triggers,
+    )
+
+
+def duplicate_agent(db: Session, agent: Agent, *, owner_id: int) -> Agent:
+    """Create a copy of *agent* (and its triggers) owned by *owner_id*."""
+
+    clone = crud.create_agent(
+        db,
+        owner_id=owner_id,
+        name=unique_agent_name(db, owner_id, agent.name),
+        system_instructions=agent.system_instructions,
+        task_instructions=agent.task_instructions,
+        model=agent.model,
+        schedule=agent.schedule,
+        config=copy.deepcopy(dict(agent.config)) if agent.config else None,
+        prompt_variables=dict(agent.prompt_variables) if agent.prompt_variables else None,
+        description=agent.description,
+        tags=list(agent.tags) if agent.tags else None,
+    )
+    if agent.allowed_tools is not None:
+        crud.update_agent(db, clone.id, allowed_tools=list(agent.allowed_tools))
+
+    # Copies of the same mailbox watch are fine; only the sync cursor is reset
+    for trigger in crud.get_triggers(db, agent_id=agent.id):
+        config = dict(trigger.config) if trigger.config else None
+        if config:
+            config.pop("history_id", None)
+            config.pop("watch_expiry", None)
+        crud.create_trigger(db, agent_id=clone.id, trigger_type=trigger.type, config=config)
+
+    db.refresh(clone)
+    return clone
+
+
+def _resolve_triggers(
+    db: Session, owner_id: int, triggers: List[AgentExportTrigger]
+) -> Tuple[List[Tuple[str, Optional[Dict[str, Any]]]], List[AgentImportConflict]]:
+    """Map exported triggers onto this deployment.
+
+    Webhook triggers are recreated with new secrets.  Email triggers are
+    attached to the importing user's first connector for the same provider;
+    without one they are skipped.
+    """
+
+    resolved: List[Tuple[str, Optional[Dict[str, Any]]]] = []
+    conflicts: List[AgentImportConflict] = []
+    for trigger in triggers:
+        if trigger.type == "webhook":
+            resolved.append(("webhook", trigger.config))
+        elif trigger.type == "email":
+            provider = (trigger.config or {}).get("provider", "gmail")
+            connectors = crud.get_connectors(db, owner_id=owner_id, type="email", provider=provider)
+            if not connectors:
+                conflicts.append(
+                    AgentImportConflict(
+                        field="triggers",
+                        message=f"No {provider} account is connected, so the email trigger will be skipped",
+                    )
+                )
+                continue
+            resolved.append(("email", {**(trigger.config or {}), "connector_id": connectors[0].id}))
+        else:
+            conflicts.append(
+                AgentImportConflict(field="triggers", message=f"Unsupported trigger type '{trigger.type}' will be skipped")
+            )
+    return resolved, conflicts
+
+
+def preview_import(db: Session, export: AgentExport, *, owner_id: int, model_available: bool) -> AgentImportPreview:
+    """Report what needs the user's attention before *export* is imported."""
+
+    conflicts: List[AgentImportConflict] = []
+    suggested_name = unique_agent_name(db, owner_id, export.agent.name)
+    name_conflict = suggested_name != export.agent.name
+    if name_conflict:
+        conflicts.append(AgentImportConflict(field="name", message=f"You already have an agent named '{export.agent.name}'"))
+    if not model_available:
+        conflicts.append(
+            AgentImportConflict(field="model", message=f"Model '{export.agent.model}' is not available here; pick another")
+        )
+    for server in (export.agent.config or {}).get("mcp_servers") or []:
+        if server.get("requires_auth"):
+            label = server.get("name") or server.get("preset") or server.get("url")
+            conflicts.append(
+                AgentImportConflict(
+                    field="mcp_servers",
+                    message=f"MCP server '{label}' needs its auth token re-entered after import",
+                )
+            )
+    conflicts.extend(_resolve_triggers(db, owner_id, export.triggers)[1])
+
+    return AgentImportPreview(
+        name=export.agent.name,
+        suggested_name=suggested_name,
+        name_conflict=name_conflict,
+        model=export.agent.model,
+        model_available=model_available,
+        has_schedule=bool(export.agent.schedule),
+        trigger_count=len(export.triggers),
+        conflicts=conflicts,
+    )
+
+
+def import_agent(db: Session, request: AgentImportRequest, *, owner_id: int, model: str) -> Agent:
+    """Create an agent from *request*; *model* has already been validated."""
+
+    exported = request.export.agent
+    name = (request.name or "").strip() or unique_agent_name(db, owner_id, exported.name)
+
+    agent = crud.create_agent(
+        db,
+        owner_id=owner_id,
+        name=name,
+        system_instructions=exported.system_instructions,
+        task_instructions=exported.task_instructions,
+        model=model,
+        schedule=exported.schedule if request.include_schedule else None,
+        config=exported.config,
+        prompt_variables=exported.prompt_variables,
+        description=exported.description,
+        tags=exported.tags,
+    )
+    if exported.allowed_tools is not None:
+        crud.update_agent(db, agent.id, allowed_tools=list(exported.allowed_tools))
+
+    if request.include_triggers:
+        triggers, _ = _resolve_triggers(db, owner_id, request.export.triggers)
+        for trigger_type, config in triggers:
+            crud.create_trigger(db, agent_id=agent.id, trigger_type=trigger_type, config=config)
+
+    db.refresh(agent)
+    return agent
@@ -0,0 +1,187 @@
+"""Aggregated run statistics per agent.
+
+The dashboard used to derive success rates from the handful of runs it had
+loaded; these helpers count over the full run history instead and add a
+per-day run count for the row sparkline. ``get_agent_usage`` sums tokens and
+cost over a recent window for the dashboard's optional usage columns.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from datetime import timedelta
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Sequence
+
+from sqlalchemy import case
+from sqlalchemy import func
+from sqlalchemy.orm import Session
+
+from zerg.models.models import Agent as AgentModel
+from zerg.models.models import AgentRun as AgentRunModel
+from zerg.models.models import Thread as ThreadModel
+from zerg.utils.time import utc_now_naive
+
+# Length of the daily run-count series
+STATS_DAYS = 7
+
+# Window of the dashboard's token and cost columns
+USAGE_DAYS = 7
+
+_FINISHED = ("success", "failed")
+
+
+def _empty_stats(agent_id: int, day_keys: List[str]) -> Dict[str, Any]:
+    return {
+        "agent_id": agent_id,
+        "run_count": 0,
+        "success_count": 0,
+        "failed_count": 0,
+        "success_rate": None,
+        "avg_duration_ms": None,
+        "daily_runs": [{"date": key, "count": 0} for key in day_keys],
+    }
+
+
+def get_agent_stats(
+    db: Session,
+    agent_ids: Sequence[int],
+    *,
+    days: int = STATS_DAYS,
+    now: Optional[datetime] = None,
+) -> Dict[int, Dict[str, Any]]:
+    """Return stats keyed by agent id; agents without runs get zeroed entries.
+
+    ``success_rate`` is over finished runs only (queued/running runs are not
+    failures yet) and is None when nothing has finished. ``daily_runs`` covers
+    the last ``days`` UTC days by start time, oldest first.
+    """
+
+    if not agent_ids:
+        return {}
+
+    today = (now or utc_now_naive()).date()
+    start_day = today - timedelta(days=days - 1)
+    day_keys = [(start_day + timedelta(days=offset)).isoformat() for offset in range(days)]
+    stats = {agent_id: _empty_stats(agent_id, day_keys) for agent_id in agent_ids}
+
+    is_success = case((AgentRunModel.status == "success", 1), else_=0)
+    is_failed = case((AgentRunModel.status == "failed", 1), else_=0)
+    finished_duration = case((AgentRunModel.status.in_(_FINISHED), AgentRunModel.duration_ms), else_=None)
+
+    totals = (
+        db.query(
+            AgentRunModel.agent_id,
+            func.count(AgentRunModel.id),
+            func.sum(is_success),
+            func.sum(is_failed),
+            func.avg(finished_duration),
+        )
+        .filter(AgentRunModel.agent_id.in_(agent_ids))
+        .group_by(AgentRunModel.agent_id)
+        .all()
+    )
+    for agent_id, run_count, success_count, failed_count, avg_duration in totals:
+        entry = stats[agent_id]
+        entry["run_count"] = int(run_count or 0)
+        entry["success_count"] = int(success_count or 0)
+        entry["failed_count"] = int(failed_count or 0)
+        finished = entry["success_count"] + entry["failed_count"]
+        if finished:
+            entry["success_rate"] = entry["success_count"] / finished * 100.0
+        entry["avg_duration_ms"] = float(avg_duration) if avg_duration is not None else None
+
+    start_date = func.date(AgentRunModel.started_at)
+    daily = (
+        db.query(AgentRunModel.agent_id, start_date, func.count(AgentRunModel.id))
+        .filter(
+            AgentRunModel.agent_id.in_(agent_ids),
+            AgentRunModel.started_at.isnot(None),
+            start_date >= start_day,
+        )
+        .group_by(AgentRunModel.agent_id, start_date)
+        .all()
+    )
+    index_of = {key: position for position, key in enumerate(day_keys)}
+    for agent_id, day_value, count in daily:
+        # SQLite returns the date as text, PostgreSQL as a date
+        key = day_value.isoformat() if hasattr(day_value, "isoformat") else str(day_value)
+        position = index_of.get(key)
+        if position is not None:
+            stats[agent_id]["daily_runs"][position]["count"] = int(count)
+
+    return stats
+
+
+def get_agent_usage(
+    db: Session,
+    agent_ids: Sequence[int],
+    *,
+    days: int = USAGE_DAYS,
+    now: Optional[datetime] = None,
+) -> Dict[int, Dict[str, Any]]:
+    """Return token and cost totals for runs started in the last ``days`` days.
+
+    Keyed by agent id; agents without runs get zeroed entries. Costs are None
+    when no run in the window has a known price. ``by_model`` splits the
+    totals by the model each run's thread used (its override, else the agent's
+    current model), most expensive first.
+    """
+
+    if not agent_ids:
+        return {}
+
+    since = (now or utc_now_naive()) - timedelta(days=days)
+    usage: Dict[int, Dict[str, Any]] = {
+        agent_id: {
+            "agent_id": agent_id,
+            "days": days,
+            "run_count": 0,
+            "total_tokens": 0,
+            "total_cost_usd": None,
+            "by_model": [],
+        }
+        for agent_id in agent_ids
+    }
+
+    model = func.coalesce(ThreadModel.model_override, AgentModel.model)
+    rows = (
+        db.query(
+            AgentRunModel.agent_id,
+            model,
+            func.count(AgentRunModel.id),
+            func.sum(AgentRunModel.total_tokens),
+            func.sum(AgentRunModel.total_cost_usd),
+        )
+        .join(ThreadModel, ThreadModel.id == AgentRunModel.thread_id)
+        .join(AgentModel, AgentModel.id == AgentRunModel.agent_id)
+        .filter(
+            AgentRunModel.agent_id.in_(agent_ids),
+            AgentRunModel.started_at.isnot(None),
+            AgentRunModel.started_at >= since,
+        )
+        .group_by(AgentRunModel.agent_id, model)
+        .all()
+    )
+    for agent_id, model_id, run_count, tokens, cost in rows:
+        entry = usage[agent_id]
+        tokens = int(tokens or 0)
+        cost = float(cost) if cost is not None else None
+        entry["run_count"] += int(run_count or 0)
+        entry["total_tokens"] += tokens
+        if cost is not None:
+            entry["total_cost_usd"] = (entry["total_cost_usd"] or 0.0) + cost
+        entry["by_model"].append(
+            {"model": model_id, "run_count": int(run_count or 0), "total_tokens": tokens, "total_cost_usd": cost}
+        )
+
+    def most_expensive(item: Dict[str, Any]):
+        return (-(item["total_cost_usd"] or 0.0), -item["total_tokens"], item["model"])
+
+    for entry in usage.values():
+        entry["by_model"].sort(key=most_expensive)
+
+    return usage
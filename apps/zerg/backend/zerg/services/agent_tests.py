@@ -0,0 +1,140 @@
+"""Agent test harness – run saved test cases and check the replies.
+
+A test case sends one user message to the agent (see
+:mod:`zerg.services.scratch_run`) and evaluates its assertions against the
+final assistant reply; only the outcome is kept on the case.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.models.enums import AgentTestAssertionType
+from zerg.models.models import Agent
+from zerg.models.models import AgentTestCase
+from zerg.services.scratch_run import run_scratch_message
+from zerg.utils.time import utc_now
+
+# Replies are stored on the case; keep the row small for chatty agents
+MAX_STORED_OUTPUT_CHARS = 20000
+
+_MISSING = object()
+_PATH_TOKEN = re.compile(r"\.?([^.\[\]]+)|\[(\d+)\]")
+_JSON_FENCE = re.compile(r"```(?:json)?\s*\n(.*?)```", re.DOTALL)
+
+
+def parse_json_reply(output: str) -> Any:
+    """The reply as JSON, also accepting a single fenced ```json block."""
+    try:
+        return json.loads(output)
+    except ValueError:
+        pass
+    match = _JSON_FENCE.search(output)
+    if match:
+        return json.loads(match.group(1))
+    raise ValueError("Reply is not valid JSON")
+
+
+def resolve_json_path(document: Any, path: str) -> Any:
+    """Follow ``$.items[0].name`` (or ``items.0.name``) into *document*; ``_MISSING`` when absent."""
+    current = document
+    remainder = path.strip()
+    if remainder.startswith("$"):
+        remainder = remainder[1:]
+    for match in _PATH_TOKEN.finditer(remainder):
+        key, index = match.group(1), match.group(2)
+        if index is not None or (key is not None and key.isdigit() and isinstance(current, list)):
+            position = int(index if index is not None else key)
+            if not isinstance(current, list) or position >= len(current):
+                return _MISSING
+            current = current[position]
+        elif isinstance(current, dict) and key in current:
+            current = current[key]
+        else:
+            return _MISSING
+    return current
+
+
+def _json_matches(actual: Any, expected: str) -> bool:
+    """Compare as JSON when *expected* parses, else against the plain string."""
+    try:
+        return actual == json.loads(expected)
+    except ValueError:
+        return isinstance(actual, str) and actual == expected
+
+
+def _check(output: str, assertion: Dict[str, Any]) -> Tuple[bool, Optional[str], str]:
+    """Evaluate one assertion -> (passed, actual value shown in the diff, message)."""
+    kind = assertion.get("type")
+    expected = assertion.get("value") or ""
+
+    if kind == AgentTestAssertionType.CONTAINS.value:
+        passed = expected in output
+        return passed, None, "Reply contains the text" if passed else "Text not found in reply"
+    if kind == AgentTestAssertionType.NOT_CONTAINS.value:
+        passed = expected not in output
+        return passed, None, "Text is absent" if passed else "Reply contains forbidden text"
+    if kind == AgentTestAssertionType.EQUALS.value:
+        passed = output.strip() == expected.strip()
+        return passed, output, "Reply matches exactly" if passed else "Reply differs from expected"
+    if kind == AgentTestAssertionType.REGEX.value:
+        try:
+            match = re.search(expected, output, re.MULTILINE)
+        except re.error as exc:
+            return False, None, f"Invalid regex: {exc}"
+        return bool(match), match.group(0) if match else None, "Pattern matched" if match else "Pattern not found"
+    if kind == AgentTestAssertionType.JSON_PATH.value:
+        path = assertion.get("path") or "$"
+        try:
+            document = parse_json_reply(output)
+        except ValueError as exc:
+            return False, None, str(exc)
+        actual = resolve_json_path(document, path)
+        if actual is _MISSING:
+            return False, None, f"{path} not found"
+        shown = actual if isinstance(actual, str) else json.dumps(actual)
+        # Without an expected value the assertion only checks that the path exists
+        if not expected:
+            return True, shown, f"{path} exists"
+        passed = _json_matches(actual, expected)
+        return passed, shown, f"{path} matches" if passed else f"{path} differs from expected"
+    return False, None, f"Unknown assertion type '{kind}'"
+
+
+def evaluate_assertions(output: str, assertions: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    results = []
+    for assertion in assertions:
+        passed, actual, message = _check(output, assertion)
+        results.append({**assertion, "passed": passed, "actual": actual, "message": message})
+    return results
+
+
+async def run_test_case(db: Session, agent: Agent, case: AgentTestCase) -> AgentTestCase:
+    """Send *case.input* to *agent*, evaluate the reply and store the result on the case."""
+    run = await run_scratch_message(db, agent, case.input, title=f"Test – {case.name}")
+
+    if run.error is None:
+        assertion_results = evaluate_assertions(run.output, case.assertions or [])
+    else:
+        assertion_results = [
+            {**assertion, "passed": False, "actual": None, "message": "Not evaluated"} for assertion in case.assertions
+        ]
+
+    result = {
+        "passed": run.error is None and all(item["passed"] for item in assertion_results),
+        "output": run.output[:MAX_STORED_OUTPUT_CHARS],
+        "error": run.error,
+        "assertions": assertion_results,
+        "duration_ms": run.duration_ms,
+        "ran_at": utc_now().isoformat(),
+    }
+    return crud.set_agent_test_result(db, case, result)
@@ -0,0 +1,95 @@
+"""API credentials: third-party REST API keys used as an auth source.
+
+A credential is a base URL, an auth header template such as
+``Authorization: Bearer {{key}}`` and an encrypted key.  HTTP Request nodes
+reference one with ``credential_id`` in their config and MCP server entries
+with ``api_credential_id``; the header is rendered when the request is made,
+so the key itself never lands in node or agent config.  The header only goes
+to URLs under the credential's base URL.
+"""
+
+import logging
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+from sqlalchemy.orm import Session
+
+from zerg.models.models import ApiCredential
+from zerg.services.http_node import CREDENTIAL_BASE_URL_KEY
+from zerg.services.http_node import under_base_url
+from zerg.utils.crypto import decrypt
+
+logger = logging.getLogger(__name__)
+
+KEY_PLACEHOLDER = "{{key}}"
+DEFAULT_AUTH_HEADER = "Authorization: Bearer {{key}}"
+
+
+def parse_auth_header(template: str) -> Tuple[str, str]:
+    """Split ``Name: value`` into its parts, or raise ``ValueError``."""
+    name, sep, value = (template or "").partition(":")
+    name, value = name.strip(), value.strip()
+    if not sep or not name or " " in name:
+        raise ValueError("Write the auth header as Name: value, e.g. Authorization: Bearer {{key}}")
+    if KEY_PLACEHOLDER not in value:
+        raise ValueError("The auth header value must contain {{key}} where the API key goes")
+    return name, value
+
+
+def key_hint(key: str) -> str:
+    return key[-4:] if len(key) > 8 else ""
+
+
+def auth_headers(credential: ApiCredential) -> Dict[str, str]:
+    """The header to send, with the decrypted key filled in."""
+    name, value = parse_auth_header(credential.auth_header)
+    return {name: value.replace(KEY_PLACEHOLDER, decrypt(credential.encrypted_key))}
+
+
+def get_owned_credential(db: Session, owner_id: int, credential_id: int) -> Optional[ApiCredential]:
+    return db.query(ApiCredential).filter(ApiCredential.id == credential_id, ApiCredential.owner_id == owner_id).first()
+
+
+def with_credential_headers(db: Session, owner_id: int, config: Dict) -> Dict:
+    """*config* with the credential's header merged into ``headers``; headers the node sets itself win.
+
+    The credential's base URL goes along so render_request can keep the
+    rendered URL under it.
+    """
+    credential_id = config.get("credential_id")
+    if not credential_id:
+        return config
+    credential = get_owned_credential(db, owner_id, int(credential_id))
+    if credential is None:
+        raise ValueError(f"API credential {credential_id} no longer exists")
+    headers = config.get("headers") if isinstance(config.get("headers"), dict) else {}
+    return {
+        **config,
+        "headers": {**auth_headers(credential), **headers},
+        CREDENTIAL_BASE_URL_KEY: credential.base_url,
+    }
+
+
+def expand_mcp_servers(db: Session, owner_id: int, servers: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    """MCP server entries with each ``api_credential_id`` turned into ``headers``.
+
+    A credential that's gone (or isn't *owner_id*'s), or whose base URL doesn't
+    cover the server's URL, adds nothing, so the server answers 401 and shows up
+    as an auth error rather than failing the run.
+    """
+    expanded = []
+    for server in servers or []:
+        credential_id = server.get("api_credential_id")
+        credential = get_owned_credential(db, owner_id, int(credential_id)) if credential_id else None
+        if credential is not None and not under_base_url(str(server.get("url") or ""), credential.base_url):
+            logger.warning("MCP server %s is outside API credential %s's base URL", server.get("name"), credential.id)
+        elif credential is not None:
+            try:
+                server = {**server, "headers": {**(server.get("headers") or {}), **auth_headers(credential)}}
+            except ValueError:
+                logger.warning("Could not use API credential %s for MCP server %s", credential.id, server.get("name"))
+        expanded.append(server)
+    return expanded
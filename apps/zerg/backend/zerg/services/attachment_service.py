@@ -0,0 +1,110 @@
+"""Chat attachment storage helper.
+
+Files uploaded from the chat composer are validated here and written to
+``static/attachments`` next to the avatars, so they are served by the same
+``/static`` mount.  The router only deals with HTTP and ownership checks.
+
+The returned dict is what gets stored under ``message_metadata["attachments"]``
+once the user actually sends the message.
+"""
+
+from __future__ import annotations
+
+# Standard library
+import mimetypes
+import uuid
+from pathlib import Path
+from typing import Any
+from typing import Dict
+from typing import Final
+
+# Third-party
+from fastapi import UploadFile
+from fastapi import status
+from fastapi.exceptions import HTTPException
+
+# Constants ------------------------------------------------------------------
+
+MAX_ATTACHMENT_BYTES: Final[int] = 10 * 1024 * 1024  # 10 MiB per file
+IMAGE_MIME: Final[set[str]] = {"image/png", "image/jpeg", "image/webp", "image/gif"}
+ALLOWED_MIME: Final[set[str]] = IMAGE_MIME | {
+    "application/pdf",
+    "application/json",
+    "text/plain",
+    "text/markdown",
+    "text/csv",
+}
+
+ATTACHMENTS_DIR = Path(__file__).resolve().parent.parent.parent / "static" / "attachments"
+ATTACHMENTS_DIR.mkdir(parents=True, exist_ok=True)
+
+
+# Helper functions -----------------------------------------------------------
+
+
+def _resolve_content_type(upload: UploadFile) -> str:
+    """Browsers send ``application/octet-stream`` for e.g. ``.md`` – fall back to the extension."""
+
+    content_type = (upload.content_type or "").split(";")[0].strip().lower()
+    if content_type in ALLOWED_MIME:
+        return content_type
+    guessed, _ = mimetypes.guess_type(upload.filename or "")
+    if guessed in ALLOWED_MIME:
+        return guessed
+    if (upload.filename or "").lower().endswith(".md"):
+        return "text/markdown"
+    return content_type
+
+
+def _safe_filename(filename: str | None) -> str:
+    # Only the display name is kept; the stored file always gets a random name
+    name = Path(filename or "").name.strip()
+    return name[:255] or "attachment"
+
+
+# Public API -----------------------------------------------------------------
+
+
+def store_attachment(upload: UploadFile) -> Dict[str, Any]:
+    """Validate *upload*, persist it under ./static/attachments and describe it."""
+
+    content_type = _resolve_content_type(upload)
+    if content_type not in ALLOWED_MIME:
+        raise HTTPException(status_code=status.HTTP_415_UNSUPPORTED_MEDIA_TYPE, detail="Unsupported file type")
+
+    # Read one byte past the limit so oversized uploads fail without loading them fully
+    raw = upload.file.read(MAX_ATTACHMENT_BYTES + 1)
+    if len(raw) > MAX_ATTACHMENT_BYTES:
+        raise HTTPException(status_code=status.HTTP_413_REQUEST_ENTITY_TOO_LARGE, detail="File too large")
+    if not raw:
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Empty file")
+
+    filename = _safe_filename(upload.filename)
+    attachment_id = uuid.uuid4().hex
+    ext = mimetypes.guess_extension(content_type) or Path(filename).suffix or ".bin"
+    dest_path: Path = ATTACHMENTS_DIR / f"{attachment_id}{ext}"
+    try:
+        dest_path.write_bytes(raw)
+    except Exception as exc:  # pragma: no cover
+        raise HTTPException(
+            status_code=status.HTTP_500_INTERNAL_SERVER_ERROR,
+            detail=f"Failed to store attachment: {exc}",
+        ) from exc
+
+    return {
+        "id": attachment_id,
+        "filename": filename,
+        "content_type": content_type,
+        "size": len(raw),
+        "url": f"/static/attachments/{dest_path.name}",
+    }
+
+
+def is_stored_attachment_url(url: str) -> bool:
+    """Guard against clients attaching arbitrary URLs to their messages."""
+
+    prefix = "/static/attachments/"
+    if not url.startswith(prefix):
+        return False
+    name = url[len(prefix) :]
+    return "/" not in name and (ATTACHMENTS_DIR / name).is_file()
@@ -0,0 +1,246 @@
+"""Self-service account export.
+
+Builds a zip archive with everything a user owns – agents with their
+instruction history and knowledge documents, workflows, threads (including
+messages), runs, triggers with their delivery logs, and environment variable
+names – so it can be downloaded from the profile page.  Credentials and secret
+values are deliberately left out: they are encrypted with a server-side key
+and useless outside this deployment.  The manifest lists what is not exported.
+
+``run_data_export`` is meant to be scheduled as a background task right after
+the ``DataExport`` row is created; it opens its own DB session.  Only the
+latest ``EXPORT_HISTORY_LIMIT`` exports (and their archives) are kept, and an
+export still queued or running after ``EXPORT_STALE_AFTER`` is taken to have
+died with its worker and marked failed.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import zipfile
+from datetime import datetime
+from datetime import timedelta
+from datetime import timezone
+from enum import Enum
+from pathlib import Path
+from typing import Any
+from typing import Dict
+from typing import List
+
+from sqlalchemy.orm import Session
+
+from zerg.database import db_session
+from zerg.models.models import Agent
+from zerg.models.models import AgentInstructionVersion
+from zerg.models.models import AgentKnowledgeDocument
+from zerg.models.models import AgentRun
+from zerg.models.models import DataExport
+from zerg.models.models import EnvironmentVariable
+from zerg.models.models import Thread
+from zerg.models.models import ThreadMessage
+from zerg.models.models import Trigger
+from zerg.models.models import TriggerDelivery
+from zerg.models.models import Workflow
+from zerg.utils.time import utc_now_naive
+
+logger = logging.getLogger(__name__)
+
+# Archives are private, so unlike avatars they are *not* under ./static
+EXPORTS_DIR = Path(__file__).resolve().parent.parent.parent / "data" / "exports"
+
+EXPORT_FORMAT_VERSION = 2
+
+# Older exports and their archives are deleted once a newer one succeeds
+EXPORT_HISTORY_LIMIT = 10
+
+# No export takes this long; one still unfinished lost its worker
+EXPORT_STALE_AFTER = timedelta(hours=1)
+
+# Owned data the archive leaves out, and why; written to the manifest
+OMITTED_FROM_EXPORT = {
+    "connector_credentials": "Encrypted with a server-side key; reconnect the services instead",
+    "api_credentials": "Encrypted with a server-side key; add the keys again instead",
+    "secret_values": "Secret environment variable values and trigger signing secrets",
+    "workflow_executions": "Workflow run history and per-node outputs",
+    "agent_test_cases": "Saved prompt checks and their last results",
+    "agent_shares": "Who the agents are shared with",
+}
+
+
+def _json_default(value: Any) -> Any:
+    if isinstance(value, datetime):
+        return value.isoformat()
+    if isinstance(value, Enum):
+        return value.value
+    return str(value)
+
+
+def _row_to_dict(row: Any, exclude: tuple[str, ...] = ()) -> Dict[str, Any]:
+    return {col.name: getattr(row, col.name) for col in row.__table__.columns if col.name not in exclude}
+
+
+def collect_user_data(db: Session, user_id: int) -> Dict[str, List[Dict[str, Any]]]:
+    """Return every exportable row owned by *user_id*, grouped by section."""
+
+    agents = db.query(Agent).filter(Agent.owner_id == user_id).order_by(Agent.id).all()
+    agent_ids = [agent.id for agent in agents]
+
+    threads: List[Dict[str, Any]] = []
+    runs: List[Dict[str, Any]] = []
+    instruction_versions: List[AgentInstructionVersion] = []
+    knowledge_documents: List[AgentKnowledgeDocument] = []
+    triggers: List[Trigger] = []
+    deliveries: List[TriggerDelivery] = []
+    if agent_ids:
+        for thread in db.query(Thread).filter(Thread.agent_id.in_(agent_ids)).order_by(Thread.id).all():
+            data = _row_to_dict(thread)
+            data["messages"] = [
+                _row_to_dict(message)
+                for message in db.query(ThreadMessage)
+                .filter(ThreadMessage.thread_id == thread.id)
+                .order_by(ThreadMessage.id)
+                .all()
+            ]
+            threads.append(data)
+        runs = [
+            _row_to_dict(run)
+            for run in db.query(AgentRun).filter(AgentRun.agent_id.in_(agent_ids)).order_by(AgentRun.id).all()
+        ]
+        instruction_versions = (
+            db.query(AgentInstructionVersion)
+            .filter(AgentInstructionVersion.agent_id.in_(agent_ids))
+            .order_by(AgentInstructionVersion.id)
+            .all()
+        )
+        knowledge_documents = (
+            db.query(AgentKnowledgeDocument)
+            .filter(AgentKnowledgeDocument.agent_id.in_(agent_ids))
+            .order_by(AgentKnowledgeDocument.id)
+            .all()
+        )
+        triggers = db.query(Trigger).filter(Trigger.agent_id.in_(agent_ids)).order_by(Trigger.id).all()
+        if triggers:
+            deliveries = (
+                db.query(TriggerDelivery)
+                .filter(TriggerDelivery.trigger_id.in_([trigger.id for trigger in triggers]))
+                .order_by(TriggerDelivery.id)
+                .all()
+            )
+
+    workflows = db.query(Workflow).filter(Workflow.owner_id == user_id).order_by(Workflow.id).all()
+    env_vars = (
+        db.query(EnvironmentVariable)
+        .filter(EnvironmentVariable.owner_id == user_id)
+        .order_by(EnvironmentVariable.id)
+        .all()
+    )
+
+    return {
+        "agents": [_row_to_dict(agent) for agent in agents],
+        "instruction_versions": [_row_to_dict(version) for version in instruction_versions],
+        "knowledge_documents": [_row_to_dict(document) for document in knowledge_documents],
+        "workflows": [_row_to_dict(workflow) for workflow in workflows],
+        "threads": threads,
+        "runs": runs,
+        "triggers": [_row_to_dict(trigger, exclude=("secret",)) for trigger in triggers],
+        "trigger_deliveries": [_row_to_dict(delivery) for delivery in deliveries],
+        # Secret values stay behind; plain ones come along with the names
+        "env_vars": [
+            {**_row_to_dict(var, exclude=("value",)), "value": None if var.is_secret else var.value}
+            for var in env_vars
+        ],
+    }
+
+
+def write_export_archive(data: Dict[str, List[Dict[str, Any]]], dest: Path, *, user_id: int) -> int:
+    """Write *data* as one JSON file per section plus a manifest; return the archive size."""
+
+    dest.parent.mkdir(parents=True, exist_ok=True)
+    manifest = {
+        "format_version": EXPORT_FORMAT_VERSION,
+        "user_id": user_id,
+        "exported_at": datetime.now(timezone.utc).isoformat(),
+        "counts": {section: len(rows) for section, rows in data.items()},
+        "omitted": OMITTED_FROM_EXPORT,
+    }
+    with zipfile.ZipFile(dest, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+        archive.writestr("manifest.json", json.dumps(manifest, indent=2))
+        for section, rows in data.items():
+            archive.writestr(f"{section}.json", json.dumps(rows, indent=2, default=_json_default))
+    return dest.stat().st_size
+
+
+def export_path(export: DataExport) -> Path | None:
+    if not export.file_name:
+        return None
+    return EXPORTS_DIR / export.file_name
+
+
+def fail_stale_exports(db: Session, owner_id: int) -> None:
+    """Mark *owner_id*'s exports that outlived ``EXPORT_STALE_AFTER`` unfinished as failed."""
+
+    cutoff = utc_now_naive() - EXPORT_STALE_AFTER
+    stale = (
+        db.query(DataExport)
+        .filter(
+            DataExport.owner_id == owner_id,
+            DataExport.status.in_(("queued", "running")),
+            DataExport.created_at < cutoff,
+        )
+        .all()
+    )
+    for export in stale:
+        logger.warning("Data export %s never finished; marking it failed", export.id)
+        export.status = "failed"
+        export.error = "The export was interrupted; request a new one"
+        export.finished_at = utc_now_naive()
+    if stale:
+        db.commit()
+
+
+def prune_old_exports(db: Session, owner_id: int) -> None:
+    """Delete *owner_id*'s exports beyond the newest ``EXPORT_HISTORY_LIMIT``, archives included."""
+
+    old = (
+        db.query(DataExport)
+        .filter(DataExport.owner_id == owner_id)
+        .order_by(DataExport.id.desc())
+        .offset(EXPORT_HISTORY_LIMIT)
+        .all()
+    )
+    for export in old:
+        path = export_path(export)
+        if path is not None:
+            path.unlink(missing_ok=True)
+        db.delete(export)
+    if old:
+        db.commit()
+
+
+def run_data_export(export_id: int) -> None:
+    """Background task: build the archive for *export_id* and record the outcome."""
+
+    with db_session() as db:
+        export = db.query(DataExport).filter(DataExport.id == export_id).first()
+        if export is None:
+            logger.warning("Data export %s vanished before it could run", export_id)
+            return
+        export.status = "running"
+        db.commit()
+
+        try:
+            data = collect_user_data(db, export.owner_id)
+            file_name = f"zerg-export-{export.owner_id}-{export.id}.zip"
+            export.size_bytes = write_export_archive(data, EXPORTS_DIR / file_name, user_id=export.owner_id)
+            export.file_name = file_name
+            export.status = "success"
+        except Exception as exc:  # noqa: BLE001 – recorded on the row for the user to see
+            logger.exception("Data export %s failed", export_id)
+            export.status = "failed"
+            export.error = str(exc)
+        export.finished_at = datetime.now(timezone.utc).replace(tzinfo=None)
+        db.commit()
+
+        if export.status == "success":
+            prune_old_exports(db, export.owner_id)
@@ -12,6 +12,8 @@ Supported filter keys (all optional):
 • ``subject_contains``   – List[str]; same for *Subject*.
 • ``label_include``      – List[str]; message must contain *all* labels.
 • ``label_exclude``      – List[str]; message must **not** contain *any*.
+• ``has_attachment``     – bool; when true the message must carry an
+                           attachment (``hasAttachment`` in the metadata).
 
 If a key is provided but the relevant header or attribute is *missing* we
 consider it **non-matching** – this makes the matcher fail-safe.
@@ -73,6 +75,9 @@ def matches(msg_meta: Dict[str, Any], filters: Dict[str, Any] | None) -> bool:
         if not _contains_any(headers.get("Subject"), filters["subject_contains"]):
             return False
 
+    if filters.get("has_attachment") and not msg_meta.get("hasAttachment"):
+        return False
+
     # ------------------------------------------------------------------
     # Simple text query – treat space-separated terms as AND set
     # ------------------------------------------------------------------
@@ -84,3 +89,37 @@ def matches(msg_meta: Dict[str, Any], filters: Dict[str, Any] | None) -> bool:
             return False
 
     return True
+
+
+def _quote(term: str) -> str:
+    term = term.replace('"', "")
+    return f'"{term}"' if any(ch.isspace() for ch in term) else term
+
+
+def to_gmail_query(filters: Dict[str, Any] | None) -> str:
+    """Translate *filters* into a Gmail search string for previews.
+
+    Labels are not part of the query – Gmail search expects label *names*
+    while filters store label *ids*, so callers pass ``label_include`` via the
+    API's ``labelIds`` parameter and re-check every hit with :func:`matches`.
+    """
+
+    if not filters:
+        return ""
+
+    parts: List[str] = []
+    for key, operator in (("from_contains", "from"), ("subject_contains", "subject")):
+        terms = [_quote(t) for t in filters.get(key) or [] if t.strip()]
+        if len(terms) == 1:
+            parts.append(f"{operator}:{terms[0]}")
+        elif terms:
+            parts.append(f"{operator}:({' OR '.join(terms)})")
+
+    if filters.get("has_attachment"):
+        parts.append("has:attachment")
+
+    query = (filters.get("query") or "").strip()
+    if query:
+        parts.append(query)
+
+    return " ".join(parts)
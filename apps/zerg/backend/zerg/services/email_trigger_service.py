@@ -140,6 +140,9 @@ class EmailTriggerService:
             return
 
         for trg_data in trigger_data:
+            # Switched off when the user disconnected the mailbox
+            if trg_data["config"].get("disabled"):
+                continue
             provider_name = trg_data["config"].get("provider", "gmail")
 
             # ------------------------------------------------------------------
@@ -0,0 +1,114 @@
+"""``{{env.NAME}}`` references to the owner's environment variables.
+
+Workflow nodes have every ``{{env.NAME}}`` in their config filled in as they
+run, using the workflow's own variables over workspace-wide ones.  Only the
+executing copy is rendered, so the config the canvas and pause events see
+keeps the references as written.
+Agent system and task instructions use the workspace-wide variables when
+they're copied into a thread, except secrets: thread messages are stored and
+readable by everyone in the thread, so secret references there stay as
+written.  As with prompt variables, references to names that aren't defined
+are left as written so the typo shows up in the output.
+"""
+
+import logging
+import re
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+from sqlalchemy import or_
+from sqlalchemy.orm import Session
+
+from zerg.models.models import Agent
+from zerg.models.models import EnvironmentVariable
+from zerg.prompts.variables import render_prompt_template
+from zerg.schemas.workflow import WorkflowData
+from zerg.utils.crypto import decrypt
+
+logger = logging.getLogger(__name__)
+
+ENV_REFERENCE_PATTERN = re.compile(r"\{\{\s*env\.([A-Za-z_][A-Za-z0-9_]*)\s*\}\}")
+ENV_NAME_PATTERN = re.compile(r"[A-Za-z_][A-Za-z0-9_]*")
+
+MAX_ENV_NAME_LENGTH = 64
+MAX_ENV_VALUE_LENGTH = 10_000
+
+
+def validate_env_name(name: str) -> str:
+    """Return *name* stripped, or raise ``ValueError``."""
+    name = (name or "").strip()
+    if not ENV_NAME_PATTERN.fullmatch(name) or len(name) > MAX_ENV_NAME_LENGTH:
+        raise ValueError("Names use letters, digits and underscores, and can't start with a digit")
+    return name
+
+
+def plain_value(variable: EnvironmentVariable) -> str:
+    return decrypt(variable.value) if variable.is_secret else variable.value
+
+
+def load_env(
+    db: Session, owner_id: int, workflow_id: Optional[int] = None, *, include_secrets: bool = True
+) -> Dict[str, str]:
+    """Values visible to *workflow_id* (or workspace-wide only), workflow scope winning."""
+    scopes = [EnvironmentVariable.workflow_id.is_(None)]
+    if workflow_id is not None:
+        scopes.append(EnvironmentVariable.workflow_id == workflow_id)
+    query = db.query(EnvironmentVariable).filter(EnvironmentVariable.owner_id == owner_id, or_(*scopes))
+    if not include_secrets:
+        query = query.filter(EnvironmentVariable.is_secret.is_(False))
+    rows = query.all()
+
+    env: Dict[str, str] = {}
+    # Workspace rows first so workflow rows overwrite them
+    for row in sorted(rows, key=lambda row: row.workflow_id is not None):
+        try:
+            env[row.name] = plain_value(row)
+        except ValueError:  # a bad token (e.g. rotated FERNET_SECRET) shouldn't break every run
+            logger.warning("Could not decrypt environment variable %s (id=%s)", row.name, row.id)
+    return env
+
+
+def find_env_references(data: Any) -> List[str]:
+    """Distinct ``{{env.NAME}}`` names referenced anywhere in *data*, in order."""
+    names: Dict[str, None] = {}
+    if isinstance(data, str):
+        names.update(dict.fromkeys(match.group(1) for match in ENV_REFERENCE_PATTERN.finditer(data)))
+    elif isinstance(data, dict):
+        for value in data.values():
+            names.update(dict.fromkeys(find_env_references(value)))
+    elif isinstance(data, list):
+        for item in data:
+            names.update(dict.fromkeys(find_env_references(item)))
+    return list(names)
+
+
+def render_env(data: Any, env: Dict[str, str]) -> Any:
+    """*data* with each defined ``{{env.NAME}}`` replaced; dicts and lists are walked."""
+    if isinstance(data, str):
+        if "{{" not in data:
+            return data
+        return ENV_REFERENCE_PATTERN.sub(lambda match: env.get(match.group(1), match.group(0)), data)
+    if isinstance(data, dict):
+        return {key: render_env(value, env) for key, value in data.items()}
+    if isinstance(data, list):
+        return [render_env(item, env) for item in data]
+    return data
+
+
+def warn_undefined_env(workflow_data: WorkflowData, env: Dict[str, str]) -> None:
+    missing = [name for name in find_env_references([node.config for node in workflow_data.nodes]) if name not in env]
+    if missing:
+        logger.warning("Workflow references undefined environment variables: %s", ", ".join(missing))
+
+
+def render_agent_instructions(db: Session, agent: Agent, text: Optional[str]) -> str:
+    """Agent instructions with prompt variables and workspace-wide env references filled in.
+
+    The result is saved as a thread message, so secret values are never substituted.
+    """
+    rendered = render_prompt_template(text, agent.prompt_variables)
+    if not ENV_REFERENCE_PATTERN.search(rendered):
+        return rendered
+    return render_env(rendered, load_env(db, agent.owner_id, include_secrets=False))
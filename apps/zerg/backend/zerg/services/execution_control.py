@@ -0,0 +1,118 @@
+"""
+Run control for in-flight workflow executions.
+
+Executions started in *step mode*, or whose canvas has breakpoints, get a
+controller that the engine consults before every node. While stepping, and
+at every breakpointed node, the node is parked until the user advances
+(optionally replacing the node's config for this run) or continues to the
+next breakpoint.
+
+State is in-process only: a controller lives as long as its background task.
+"""
+
+import asyncio
+import logging
+from typing import Any
+from typing import Awaitable
+from typing import Callable
+from typing import Dict
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+
+class ExecutionAborted(Exception):
+    """Raised inside a parked node when its execution is cancelled."""
+
+
+class ExecutionController:
+    """Gate between the workflow engine and one execution's nodes."""
+
+    def __init__(self, execution_id: int, *, step_mode: bool = False):
+        self.execution_id = execution_id
+        self.step_mode = step_mode
+        self.paused_node_id: Optional[str] = None
+        # Parallel branches queue up here so only one node is parked at a time
+        self._lock = asyncio.Lock()
+        self._resume = asyncio.Event()
+        self._config_override: Optional[Dict[str, Any]] = None
+        self._aborted = False
+        self._loop: Optional[asyncio.AbstractEventLoop] = None
+
+    async def gate(
+        self,
+        node_id: str,
+        config: Dict[str, Any],
+        on_pause: Callable[[str, str], Awaitable[None]],
+        *,
+        breakpoint: bool = False,
+    ) -> Dict[str, Any]:
+        """Wait for permission to run *node_id* and return the config it should run with.
+
+        ``on_pause`` receives the node id and why it stopped (``step`` or ``breakpoint``).
+        """
+        if self._aborted:
+            raise ExecutionAborted(f"Execution {self.execution_id} cancelled before {node_id}")
+        if not (self.step_mode or breakpoint):
+            return config
+
+        async with self._lock:
+            # The user may have continued while this branch was queued
+            if not (self.step_mode or breakpoint):
+                return config
+            self._loop = asyncio.get_running_loop()
+            self.paused_node_id = node_id
+            self._resume.clear()
+            self._config_override = None
+            await on_pause(node_id, "step" if self.step_mode else "breakpoint")
+            await self._resume.wait()
+            self.paused_node_id = None
+
+            if self._aborted:
+                raise ExecutionAborted(f"Execution {self.execution_id} cancelled while paused at {node_id}")
+            override, self._config_override = self._config_override, None
+            return override if override is not None else config
+
+    def resume(self, *, config_override: Optional[Dict[str, Any]] = None, keep_stepping: bool = True) -> None:
+        """Release the parked node. ``keep_stepping=False`` runs on until the next breakpoint."""
+        if self.paused_node_id is None:
+            raise ValueError("Execution is not paused")
+        self._config_override = config_override
+        self.step_mode = keep_stepping
+        self._wake()
+
+    def abort(self) -> None:
+        """Fail the parked node (if any) and stop pausing."""
+        self._aborted = True
+        self.step_mode = False
+        self._wake()
+
+    def _wake(self) -> None:
+        # Sync endpoints call in from a worker thread, not the engine's loop
+        if self._loop is not None and not self._loop.is_closed():
+            self._loop.call_soon_threadsafe(self._resume.set)
+        else:
+            self._resume.set()
+
+
+class ExecutionControlRegistry:
+    """Controllers for executions running in this process, keyed by execution id."""
+
+    def __init__(self):
+        self._controllers: Dict[int, ExecutionController] = {}
+
+    def create(self, execution_id: int, *, step_mode: bool = False) -> ExecutionController:
+        controller = ExecutionController(execution_id, step_mode=step_mode)
+        self._controllers[execution_id] = controller
+        logger.info(f"[ExecutionControl] Controller created – execution_id={execution_id}, step_mode={step_mode}")
+        return controller
+
+    def get(self, execution_id: int) -> Optional[ExecutionController]:
+        return self._controllers.get(execution_id)
+
+    def discard(self, execution_id: int) -> None:
+        self._controllers.pop(execution_id, None)
+
+
+# Singleton instance
+execution_controls = ExecutionControlRegistry()
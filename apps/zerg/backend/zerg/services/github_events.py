@@ -0,0 +1,92 @@
+"""GitHub webhook helpers for GitHub triggers.
+
+Each ``github`` trigger has its own payload URL,
+``/api/github/webhooks/{trigger_id}``, which is added to the repository's
+webhook settings with the trigger's secret.  The config names the repository
+and the events that run the agent, optionally narrowed to some actions:
+
+    {"repo": "octocat/hello-world", "events": ["pull_request"], "actions": ["opened"]}
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+import re
+from typing import Any
+from typing import Dict
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from zerg.models.models import AccountConnectorCredential
+
+GITHUB_EVENT_TYPES = ("issues", "pull_request", "push")
+
+REPO_PATTERN = re.compile(r"^[A-Za-z0-9_.-]+/[A-Za-z0-9_.-]+$")
+
+
+def verify_github_signature(secret: str, body: bytes, signature: str) -> bool:
+    """Check GitHub's ``X-Hub-Signature-256`` (``sha256=`` HMAC-SHA256 of the raw body)."""
+    expected = "sha256=" + hmac.new(secret.encode(), body, hashlib.sha256).hexdigest()
+    return hmac.compare_digest(expected, signature or "")
+
+
+def github_connection(db: Session, owner_id: int) -> Optional[AccountConnectorCredential]:
+    """The owner's GitHub credential, whether connected via OAuth or a token."""
+    return (
+        db.query(AccountConnectorCredential)
+        .filter(
+            AccountConnectorCredential.owner_id == owner_id,
+            AccountConnectorCredential.connector_type == "github",
+        )
+        .first()
+    )
+
+
+def skip_reason(config: Optional[Dict[str, Any]], event: str, payload: Dict[str, Any]) -> Optional[str]:
+    """Why a delivery should not run the agent, or ``None`` when it should."""
+    cfg = config or {}
+    if event not in (cfg.get("events") or []):
+        return f"Event '{event}' is not enabled for this trigger"
+    repo = (payload.get("repository") or {}).get("full_name") or ""
+    if repo.lower() != (cfg.get("repo") or "").lower():
+        return f"Delivery is for {repo or 'an unknown repository'}, not {cfg.get('repo')}"
+    actions = cfg.get("actions") or []
+    if actions and event != "push" and payload.get("action") not in actions:
+        return f"Action '{payload.get('action')}' is not enabled for this trigger"
+    return None
+
+
+def match_summary(event: str, payload: Dict[str, Any]) -> str:
+    """What a delivery that passed ``skip_reason`` matched, for the delivery history."""
+    action = payload.get("action")
+    repo = (payload.get("repository") or {}).get("full_name")
+    return f"{event}.{action} on {repo}" if action and event != "push" else f"{event} on {repo}"
+
+
+def summarize_payload(event: str, payload: Dict[str, Any]) -> Dict[str, Any]:
+    """The parts of a delivery an agent needs, instead of GitHub's full payload."""
+    summary: Dict[str, Any] = {
+        "event": event,
+        "action": payload.get("action"),
+        "repo": (payload.get("repository") or {}).get("full_name"),
+        "sender": (payload.get("sender") or {}).get("login"),
+    }
+    item = payload.get("pull_request") or payload.get("issue")
+    if item:
+        summary.update(
+            number=item.get("number"),
+            title=item.get("title"),
+            body=item.get("body"),
+            url=item.get("html_url"),
+            labels=[label.get("name") for label in item.get("labels") or []],
+        )
+    if event == "push":
+        summary.update(
+            ref=payload.get("ref"),
+            commits=[
+                {"id": commit.get("id"), "message": commit.get("message")} for commit in payload.get("commits") or []
+            ],
+        )
+    return summary
@@ -242,6 +242,13 @@ async def async_list_history(access_token: str, start_history_id: int):  # noqa:
     return await asyncio.to_thread(list_history, access_token, start_history_id)
 
 
+@async_retry(provider="gmail")
+async def async_list_messages(access_token: str, **kwargs):  # noqa: D401
+    """Async wrapper for :func:`list_messages` with retry."""
+
+    return await asyncio.to_thread(list_messages, access_token, **kwargs)
+
+
 @async_retry(provider="gmail")
 async def async_get_message_metadata(access_token: str, msg_id: str):  # noqa: D401
     """Async wrapper for :func:`get_message_metadata` with retry."""
@@ -319,7 +326,7 @@ def get_message_metadata(access_token: str, msg_id: str) -> Dict[str, Any]:  # n
 
     url = (
         "https://gmail.googleapis.com/gmail/v1/users/me/messages/"
-        f"{msg_id}?format=metadata&metadataHeaders=From&metadataHeaders=Subject"
+        f"{msg_id}?format=metadata&metadataHeaders=From&metadataHeaders=Subject&metadataHeaders=Date"
     )
 
     try:
@@ -337,4 +344,38 @@ def get_message_metadata(access_token: str, msg_id: str) -> Dict[str, Any]:  # n
         "id": payload.get("id"),
         "labelIds": payload.get("labelIds", []),
         "headers": headers_dict,
+        "snippet": payload.get("snippet"),
+        # Metadata responses omit parts; a mixed multipart top-level body is
+        # how Gmail represents "has attachments"
+        "hasAttachment": payload.get("payload", {}).get("mimeType") == "multipart/mixed",
     }
+
+
+def list_messages(
+    access_token: str,
+    *,
+    query: str = "",
+    label_ids: List[str] | None = None,
+    max_results: int = 20,
+) -> List[str]:  # noqa: D401 – helper
+    """Return ids of the newest messages matching a Gmail search *query*.
+
+    Network errors yield an **empty list**, same as :func:`list_history`.
+    """
+
+    params: List[tuple[str, str]] = [("maxResults", str(max_results))]
+    if query:
+        params.append(("q", query))
+    for label in label_ids or []:
+        params.append(("labelIds", label))
+
+    url = "https://gmail.googleapis.com/gmail/v1/users/me/messages?" + urllib.parse.urlencode(params)
+
+    try:
+        with _make_request(url, access_token) as resp:  # type: ignore[attr-defined]
+            payload: Dict[str, Any] = json.loads(resp.read().decode())
+    except Exception as exc:  # pragma: no cover – offline
+        logger.warning("list_messages network failure: %s", exc)
+        return []
+
+    return [str(m["id"]) for m in payload.get("messages", []) if m.get("id")]
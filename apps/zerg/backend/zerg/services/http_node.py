@@ -0,0 +1,224 @@
+"""HTTP request node: render a request from node config and send it.
+
+Config keys: ``method``, ``url``, ``headers`` (name -> value) and ``body``, a
+text template. Each may reference upstream outputs with ``${node.value...}``;
+structured values are inserted as JSON, so a body such as
+``{"items": ${search.value.results}}`` stays valid JSON and is sent as JSON.
+
+Any response, whatever its status, is the node's output so a following
+If/Else can branch on ``${node_id.value.status_code}``; only transport errors
+(DNS, refused connection, timeout) fail the node.
+
+A node using an API credential only sends to the credential's base URL: a
+relative ``url`` is joined onto it and an absolute one elsewhere is refused,
+so the key can't be pointed at another host.
+
+Requests only go to public addresses: the host of the URL, and of every
+redirect hop, is resolved first and loopback, private and link-local targets
+(the metadata service at 169.254.169.254, other containers) are refused.
+"""
+
+import asyncio
+import ipaddress
+import json
+import logging
+import re
+import socket
+import time
+from dataclasses import dataclass
+from dataclasses import field
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+import httpx
+
+from zerg.services.variable_resolver import VariableResolutionError
+from zerg.services.variable_resolver import resolve_variable_path
+
+logger = logging.getLogger(__name__)
+
+HTTP_METHODS = ("GET", "POST", "PUT", "PATCH", "DELETE", "HEAD")
+REQUEST_TIMEOUT_SECONDS = 30.0
+# Response bodies are stored with the node's output; keep them bounded
+MAX_RESPONSE_CHARS = 10_000
+MAX_REDIRECTS = 5
+
+# Set by api_credentials.with_credential_headers next to the credential's header
+CREDENTIAL_BASE_URL_KEY = "credential_base_url"
+
+_VARIABLE_PATTERN = re.compile(r"\$\{([^}]+)\}")
+
+
+class BlockedDestinationError(ValueError):
+    """The URL's host is, or resolves to, a non-public address."""
+
+
+@dataclass
+class RenderedRequest:
+    method: str
+    url: str
+    headers: Dict[str, str]
+    body: Optional[str] = None
+    # Variable paths that didn't resolve and were left as written
+    unresolved: List[str] = field(default_factory=list)
+
+
+def render_template(text: str, node_outputs: Dict[str, Any], unresolved: List[str]) -> str:
+    """Substitute ``${...}`` references; strings go in as-is, anything else as JSON."""
+
+    def replace(match: re.Match) -> str:
+        path = match.group(1)
+        try:
+            value = resolve_variable_path(path, node_outputs)
+        except VariableResolutionError:
+            unresolved.append(path)
+            return match.group(0)
+        return value if isinstance(value, str) else json.dumps(value)
+
+    return _VARIABLE_PATTERN.sub(replace, text)
+
+
+def under_base_url(url: str, base_url: str) -> bool:
+    """Whether *url* is *base_url* or a path below it (``https://api.x`` doesn't cover ``https://api.x.evil``)."""
+    base = base_url.rstrip("/")
+    return url == base or (url.startswith(base) and url[len(base)] in "/?#")
+
+
+def resolve_credential_url(url: str, base_url: str) -> str:
+    """*url* joined onto *base_url* when relative; raises ValueError when it points anywhere else."""
+    base = base_url.rstrip("/")
+    if not url.startswith(("http://", "https://")):
+        return f"{base}/{url.lstrip('/')}" if url else base
+    if not under_base_url(url, base):
+        raise ValueError(f"URL {url!r} is outside the API credential's base URL {base}")
+    return url
+
+
+def render_request(config: Dict[str, Any], node_outputs: Dict[str, Any]) -> RenderedRequest:
+    """Build the request for an HTTP node; raises ValueError for an unusable config."""
+    unresolved: List[str] = []
+
+    method = str(config.get("method") or "GET").upper()
+    if method not in HTTP_METHODS:
+        raise ValueError(f"Unsupported HTTP method {method}")
+
+    url = render_template(str(config.get("url") or "").strip(), node_outputs, unresolved)
+    base_url = config.get(CREDENTIAL_BASE_URL_KEY)
+    if base_url:
+        url = resolve_credential_url(url, str(base_url))
+    if not url.startswith(("http://", "https://")):
+        raise ValueError(f"URL must start with http:// or https://, got {url!r}")
+
+    raw_headers = config.get("headers") or {}
+    if not isinstance(raw_headers, dict):
+        raise ValueError("Headers must be an object of name/value pairs")
+    headers = {
+        str(name).strip(): render_template(str(value), node_outputs, unresolved)
+        for name, value in raw_headers.items()
+        if str(name).strip()
+    }
+
+    body = config.get("body")
+    rendered_body = render_template(body, node_outputs, unresolved) if isinstance(body, str) and body.strip() else None
+
+    return RenderedRequest(method=method, url=url, headers=headers, body=rendered_body, unresolved=unresolved)
+
+
+def _is_public(address: ipaddress.IPv4Address | ipaddress.IPv6Address) -> bool:
+    if isinstance(address, ipaddress.IPv6Address) and address.ipv4_mapped is not None:
+        address = address.ipv4_mapped
+    return not (
+        address.is_private
+        or address.is_loopback
+        or address.is_link_local
+        or address.is_multicast
+        or address.is_reserved
+        or address.is_unspecified
+    )
+
+
+async def check_destination(url: str) -> None:
+    """Raise BlockedDestinationError unless every address *url*'s host resolves to is public.
+
+    A host that doesn't resolve raises httpx.ConnectError, like any other
+    transport failure.
+    """
+    host = httpx.URL(url).host
+    if not host:
+        raise BlockedDestinationError(f"URL {url!r} has no host")
+    try:
+        addresses = [ipaddress.ip_address(host)]
+    except ValueError:
+        try:
+            infos = await asyncio.get_running_loop().getaddrinfo(host, None, type=socket.SOCK_STREAM)
+        except socket.gaierror as exc:
+            raise httpx.ConnectError(f"Could not resolve {host}: {exc}") from exc
+        # Scoped IPv6 results carry a %zone suffix
+        addresses = [ipaddress.ip_address(str(info[4][0]).split("%")[0]) for info in infos]
+    blocked = next((address for address in addresses if not _is_public(address)), None)
+    if blocked is not None:
+        raise BlockedDestinationError(f"{host} resolves to {blocked}, which is not a public address")
+
+
+def _same_origin(left: httpx.URL, right: httpx.URL) -> bool:
+    return (left.scheme, left.host, left.port) == (right.scheme, right.host, right.port)
+
+
+def _response_value(response: httpx.Response, elapsed_ms: int) -> Dict[str, Any]:
+    body: Any = response.text
+    if "application/json" in response.headers.get("content-type", "").lower():
+        try:
+            body = response.json()
+        except ValueError:
+            pass
+
+    value = {
+        "status_code": response.status_code,
+        "ok": response.is_success,
+        "headers": dict(response.headers),
+        "body": body,
+        "url": str(response.url),
+        "elapsed_ms": elapsed_ms,
+    }
+    if isinstance(body, str) and len(body) > MAX_RESPONSE_CHARS:
+        value["body"] = body[:MAX_RESPONSE_CHARS]
+        value["truncated"] = True
+    return value
+
+
+async def send_request(request: RenderedRequest, timeout: float = REQUEST_TIMEOUT_SECONDS) -> Dict[str, Any]:
+    """Send *request*; raises httpx.HTTPError when no response arrives.
+
+    Redirects are followed by hand so each hop's host is checked as well;
+    headers from the node config (credentials included) are not sent on to
+    another origin. Raises BlockedDestinationError for a non-public target.
+    """
+    headers = {"User-Agent": "Zerg-Workflow/1.0", **request.headers}
+    content: Dict[str, Any] = {}
+    if request.body is not None and request.method not in ("GET", "HEAD"):
+        try:
+            content["json"] = json.loads(request.body)
+        except ValueError:
+            content["content"] = request.body
+
+    started = time.monotonic()
+    await check_destination(request.url)
+    async with httpx.AsyncClient(follow_redirects=False, timeout=timeout) as client:
+        response = await client.request(request.method, request.url, headers=headers, **content)
+        hops = 0
+        while response.next_request is not None:
+            if hops == MAX_REDIRECTS:
+                raise httpx.TooManyRedirects(f"More than {MAX_REDIRECTS} redirects", request=response.request)
+            hops += 1
+            next_request = response.next_request
+            await check_destination(str(next_request.url))
+            if not _same_origin(next_request.url, response.request.url):
+                for name in request.headers:
+                    next_request.headers.pop(name, None)
+            response = await client.send(next_request)
+    elapsed_ms = int((time.monotonic() - started) * 1000)
+
+    logger.info(f"[HttpNode] {request.method} {request.url} -> {response.status_code} in {elapsed_ms}ms")
+    return _response_value(response, elapsed_ms)
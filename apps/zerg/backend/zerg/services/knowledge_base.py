@@ -0,0 +1,244 @@
+"""Per-agent knowledge base – documents an agent can draw on while it runs.
+
+Owners upload text files or link web pages in the agent settings.  Each
+document is split into overlapping chunks by a background task; at run time
+the chunks that best match the latest user message are injected as
+ephemeral context (see :meth:`AgentRunner.run_thread`).  Matching is plain
+term overlap – no embeddings – which keeps the feature dependency-free and
+good enough for the handful of documents an agent usually has.
+"""
+
+from __future__ import annotations
+
+import html
+import logging
+import math
+import mimetypes
+import re
+from collections import Counter
+from pathlib import Path
+from typing import Final
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+import httpx
+from fastapi import UploadFile
+from fastapi import status
+from fastapi.exceptions import HTTPException
+from sqlalchemy.orm import Session
+
+from zerg.database import db_session
+from zerg.models.enums import KnowledgeDocumentStatus
+from zerg.models.models import AgentKnowledgeDocument
+
+logger = logging.getLogger(__name__)
+
+# Constants ------------------------------------------------------------------
+
+MAX_KNOWLEDGE_FILE_BYTES: Final[int] = 5 * 1024 * 1024  # 5 MiB per document
+MAX_DOCUMENTS_PER_AGENT: Final[int] = 50
+TEXT_MIME: Final[set[str]] = {
+    "text/plain",
+    "text/markdown",
+    "text/csv",
+    "text/html",
+    "application/json",
+}
+
+CHUNK_CHARS: Final[int] = 1200
+CHUNK_OVERLAP: Final[int] = 200
+# Chunks injected per turn; together well under the model's context window
+MAX_CONTEXT_CHUNKS: Final[int] = 4
+URL_FETCH_TIMEOUT_SECONDS: Final[float] = 15.0
+
+_TERM = re.compile(r"[a-z0-9]{3,}")
+_HTML_DROP = re.compile(r"<(script|style|noscript)\b.*?</\1>", re.IGNORECASE | re.DOTALL)
+_HTML_TAG = re.compile(r"<[^>]+>")
+_BLANK_LINES = re.compile(r"\n\s*\n\s*\n+")
+
+
+# Text extraction ------------------------------------------------------------
+
+
+def _resolve_content_type(content_type: Optional[str], filename: str) -> str:
+    resolved = (content_type or "").split(";")[0].strip().lower()
+    if resolved in TEXT_MIME:
+        return resolved
+    if filename.lower().endswith(".md"):
+        return "text/markdown"
+    guessed, _ = mimetypes.guess_type(filename)
+    return guessed if guessed in TEXT_MIME else resolved
+
+
+def html_to_text(markup: str) -> str:
+    text = _HTML_DROP.sub(" ", markup)
+    text = re.sub(r"<(br|/p|/div|/li|/h[1-6])\b[^>]*>", "\n", text, flags=re.IGNORECASE)
+    text = html.unescape(_HTML_TAG.sub(" ", text))
+    lines = [" ".join(line.split()) for line in text.splitlines()]
+    return _BLANK_LINES.sub("\n\n", "\n".join(lines)).strip()
+
+
+def extract_text(raw: bytes, content_type: str) -> str:
+    text = raw.decode("utf-8", errors="replace")
+    return html_to_text(text) if content_type == "text/html" else text.strip()
+
+
+def read_upload(upload: UploadFile) -> Tuple[str, str, bytes]:
+    """Validate *upload* -> ``(name, content_type, raw bytes)``."""
+
+    name = Path(upload.filename or "").name.strip()[:255] or "document"
+    content_type = _resolve_content_type(upload.content_type, name)
+    if content_type not in TEXT_MIME:
+        raise HTTPException(
+            status_code=status.HTTP_415_UNSUPPORTED_MEDIA_TYPE,
+            detail="Only text, Markdown, CSV, JSON and HTML documents are supported",
+        )
+
+    # Read one byte past the limit so oversized uploads fail without loading them fully
+    raw = upload.file.read(MAX_KNOWLEDGE_FILE_BYTES + 1)
+    if len(raw) > MAX_KNOWLEDGE_FILE_BYTES:
+        raise HTTPException(status_code=status.HTTP_413_REQUEST_ENTITY_TOO_LARGE, detail="File too large")
+    if not raw:
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Empty file")
+    return name, content_type, raw
+
+
+async def fetch_url(url: str) -> Tuple[str, bytes]:
+    """Download *url* -> ``(content_type, raw bytes)``; raises ``ValueError`` with a readable reason."""
+
+    try:
+        async with httpx.AsyncClient(timeout=URL_FETCH_TIMEOUT_SECONDS, follow_redirects=True) as client:
+            async with client.stream("GET", url) as response:
+                if response.status_code >= 400:
+                    raise ValueError(f"Fetching the page failed with HTTP {response.status_code}")
+                raw = b""
+                async for piece in response.aiter_bytes():
+                    raw += piece
+                    if len(raw) > MAX_KNOWLEDGE_FILE_BYTES:
+                        raise ValueError("Page is larger than 5 MB")
+                content_type = _resolve_content_type(response.headers.get("content-type"), url)
+    except httpx.HTTPError as exc:
+        raise ValueError(f"Could not fetch the page: {exc}") from exc
+    if content_type not in TEXT_MIME:
+        raise ValueError(f"Unsupported content type '{content_type or 'unknown'}'")
+    return content_type, raw
+
+
+# Chunking & search ----------------------------------------------------------
+
+
+def chunk_text(text: str, size: int = CHUNK_CHARS, overlap: int = CHUNK_OVERLAP) -> List[str]:
+    """Split *text* into ~*size* character chunks, preferring paragraph and sentence breaks."""
+
+    text = text.strip()
+    chunks: List[str] = []
+    start = 0
+    while start < len(text):
+        end = min(start + size, len(text))
+        if end < len(text):
+            window = text[start:end]
+            # Break at the last paragraph, line or sentence end in the second half of the window
+            for separator in ("\n\n", "\n", ". "):
+                cut = window.rfind(separator)
+                if cut > size // 2:
+                    end = start + cut + len(separator)
+                    break
+        chunk = text[start:end].strip()
+        if chunk:
+            chunks.append(chunk)
+        if end >= len(text):
+            break
+        start = max(end - overlap, start + 1)
+    return chunks
+
+
+def _stem(term: str) -> str:
+    # Just enough to match "refunds" with "refund"
+    return term[:-1] if len(term) > 4 and term.endswith("s") and not term.endswith("ss") else term
+
+
+def _terms(text: str) -> Counter:
+    return Counter(_stem(term) for term in _TERM.findall(text.lower()))
+
+
+def search_chunks(documents: List[AgentKnowledgeDocument], query: str, limit: int = MAX_CONTEXT_CHUNKS):
+    """Best matching ``(document, chunk)`` pairs for *query*, most relevant first."""
+
+    query_terms = set(_terms(query))
+    if not query_terms:
+        return []
+
+    candidates = [(document, chunk, _terms(chunk)) for document in documents for chunk in document.chunks or []]
+    # Rare terms count for more, like a bare-bones BM25
+    document_frequency = Counter(term for _, _, terms in candidates for term in query_terms & set(terms))
+    scored = []
+    for document, chunk, terms in candidates:
+        score = sum(
+            (1 + math.log(terms[term])) * math.log(1 + len(candidates) / document_frequency[term])
+            for term in query_terms
+            if term in terms
+        )
+        if score > 0:
+            scored.append((score, document, chunk))
+    scored.sort(key=lambda item: item[0], reverse=True)
+    return [(document, chunk) for _, document, chunk in scored[:limit]]
+
+
+def build_knowledge_context(db: Session, agent_id: int, query: str) -> Optional[str]:
+    """Context message with the excerpts relevant to *query*, or None when nothing matches."""
+
+    documents = (
+        db.query(AgentKnowledgeDocument)
+        .filter(
+            AgentKnowledgeDocument.agent_id == agent_id,
+            AgentKnowledgeDocument.status == KnowledgeDocumentStatus.READY.value,
+        )
+        .all()
+    )
+    matches = search_chunks(documents, query) if documents else []
+    if not matches:
+        return None
+    excerpts = "\n\n".join(f'<excerpt source="{document.name}">\n{chunk}\n</excerpt>' for document, chunk in matches)
+    return (
+        "Excerpts from your knowledge base that may help with the next message. "
+        "Use them when relevant and mention the source when you rely on one.\n\n" + excerpts
+    )
+
+
+# Indexing -------------------------------------------------------------------
+
+
+def index_text(db: Session, document: AgentKnowledgeDocument, text: str) -> None:
+    chunks = chunk_text(text)
+    if chunks:
+        document.chunks = chunks
+        document.chunk_count = len(chunks)
+        document.status = KnowledgeDocumentStatus.READY.value
+        document.error = None
+    else:
+        document.status = KnowledgeDocumentStatus.FAILED.value
+        document.error = "No text found in the document"
+    db.commit()
+
+
+async def run_knowledge_indexing(document_id: int, raw: Optional[bytes] = None) -> None:
+    """Background task: chunk an uploaded document, or fetch and chunk a linked one."""
+
+    with db_session() as db:
+        document = db.query(AgentKnowledgeDocument).filter(AgentKnowledgeDocument.id == document_id).first()
+        if document is None:
+            logger.warning("Knowledge document %s was deleted before it could be indexed", document_id)
+            return
+        try:
+            content_type = document.content_type
+            if raw is None:
+                content_type, raw = await fetch_url(document.url)
+                document.content_type = content_type
+                document.size_bytes = len(raw)
+            index_text(db, document, extract_text(raw, content_type))
+        except Exception as exc:  # noqa: BLE001 – recorded on the row for the user to see
+            logger.warning("Indexing knowledge document %s failed: %s", document_id, exc)
+            document.status = KnowledgeDocumentStatus.FAILED.value
+            document.error = str(exc)
+            db.commit()
@@ -0,0 +1,120 @@
+"""Per-agent generation parameters (temperature, top_p, max tokens, stop).
+
+Overrides live under ``Agent.config["model_params"]``.  Anything the agent
+doesn't set falls back to the model's ``defaultParams`` in config/models.json;
+only the overrides are sent to the provider, so a model keeps its own
+behaviour for parameters nobody touched.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+from typing import Dict
+from typing import Mapping
+from typing import Optional
+
+from zerg.config import get_settings
+from zerg.models_config import get_model_by_id
+
+# Key in Agent.config holding the overrides: {"temperature": 0.2, "stop": ["END"]}
+AGENT_MODEL_PARAMS_KEY = "model_params"
+
+# Inclusive bounds, matching what the OpenAI chat API accepts
+MODEL_PARAM_RANGES: Dict[str, tuple[float, float]] = {
+    "temperature": (0.0, 2.0),
+    "top_p": (0.0, 1.0),
+    "max_tokens": (1, 128_000),
+}
+MAX_STOP_SEQUENCES = 4
+MAX_STOP_SEQUENCE_LENGTH = 100
+
+
+def _number(name: str, value: Any) -> float | int:
+    low, high = MODEL_PARAM_RANGES[name]
+    if isinstance(value, bool) or not isinstance(value, (int, float)):
+        raise ValueError(f"'{name}' must be a number")
+    if name == "max_tokens" and int(value) != value:
+        raise ValueError("'max_tokens' must be a whole number")
+    if not low <= value <= high:
+        raise ValueError(f"'{name}' must be between {low:g} and {high:g}")
+    return int(value) if name == "max_tokens" else float(value)
+
+
+def validate_model_params(params: Optional[Mapping[str, Any]]) -> Dict[str, Any]:
+    """Return a clean copy of *params* (``None`` values dropped) or raise ``ValueError``."""
+
+    if not params:
+        return {}
+    if not isinstance(params, Mapping):
+        raise ValueError("'model_params' must be an object")
+
+    unknown = sorted(set(params) - set(MODEL_PARAM_RANGES) - {"stop"})
+    if unknown:
+        raise ValueError(f"Unknown model parameter(s): {', '.join(unknown)}")
+
+    cleaned: Dict[str, Any] = {}
+    for name in MODEL_PARAM_RANGES:
+        if params.get(name) is not None:
+            cleaned[name] = _number(name, params[name])
+
+    stop = params.get("stop")
+    if stop:
+        if not isinstance(stop, list) or not all(isinstance(item, str) and item for item in stop):
+            raise ValueError("'stop' must be a list of non-empty strings")
+        if len(stop) > MAX_STOP_SEQUENCES:
+            raise ValueError(f"At most {MAX_STOP_SEQUENCES} stop sequences are allowed")
+        if any(len(item) > MAX_STOP_SEQUENCE_LENGTH for item in stop):
+            raise ValueError(f"Stop sequences must be at most {MAX_STOP_SEQUENCE_LENGTH} characters")
+        cleaned["stop"] = list(dict.fromkeys(stop))
+    return cleaned
+
+
+def with_validated_model_params(config: Optional[Dict[str, Any]]) -> Optional[Dict[str, Any]]:
+    """*config* with its ``model_params`` validated; an empty override set is removed."""
+
+    if not config or AGENT_MODEL_PARAMS_KEY not in config:
+        return config
+    params = validate_model_params(config[AGENT_MODEL_PARAMS_KEY])
+    cleaned = {key: value for key, value in config.items() if key != AGENT_MODEL_PARAMS_KEY}
+    if params:
+        cleaned[AGENT_MODEL_PARAMS_KEY] = params
+    return cleaned
+
+
+def agent_model_params(agent: Any) -> Dict[str, Any]:
+    """The agent's own overrides; invalid stored values are ignored rather than failing the run."""
+
+    try:
+        return validate_model_params((agent.config or {}).get(AGENT_MODEL_PARAMS_KEY))
+    except ValueError:
+        return {}
+
+
+def model_default_params(model_id: str) -> Dict[str, Any]:
+    model = get_model_by_id(model_id)
+    return dict(model.default_params) if model else {}
+
+
+def _output_cap() -> int:
+    """Deployment-wide MAX_OUTPUT_TOKENS; 0 disables the cap."""
+
+    try:
+        return max(int(get_settings().max_output_tokens), 0)
+    except Exception:  # noqa: BLE001 – defensive parsing
+        return 0
+
+
+def llm_kwargs(agent: Any) -> Dict[str, Any]:
+    """Keyword arguments for the chat model: the overrides, with max_tokens capped by the deployment."""
+
+    kwargs = agent_model_params(agent)
+    cap = _output_cap()
+    if cap:
+        kwargs["max_tokens"] = min(kwargs.get("max_tokens", cap), cap)
+    return kwargs
+
+
+def effective_model_params(agent: Any) -> Dict[str, Any]:
+    """What a run of *agent* actually uses: model defaults overlaid with the agent's settings."""
+
+    return {**model_default_params(agent.model), **llm_kwargs(agent)}
@@ -6,36 +6,66 @@ No backward compatibility. Clean, direct implementation.
 """
 
 import logging
+from datetime import datetime
+from datetime import timedelta
+from datetime import timezone
 from typing import Any
+from typing import Callable
 from typing import Dict
+from typing import Optional
+
+import httpx
 
 from zerg.callbacks.token_stream import set_current_user_id
 from zerg.crud import crud
 from zerg.database import get_session_factory
+from zerg.events import EventType
+from zerg.events.publisher import publish_event
 from zerg.managers.agent_runner import AgentRunner
 from zerg.models.enums import FailureKind
 from zerg.models.models import Agent
 from zerg.models.models import NodeExecutionState
+from zerg.models.models import WorkflowExecution
 from zerg.schemas.node_output import create_agent_envelope
 from zerg.schemas.node_output import create_conditional_envelope
+from zerg.schemas.node_output import create_http_envelope
+from zerg.schemas.node_output import create_loop_envelope
+from zerg.schemas.node_output import create_subworkflow_envelope
+from zerg.schemas.node_output import create_switch_envelope
 from zerg.schemas.node_output import create_tool_envelope
 from zerg.schemas.node_output import create_trigger_envelope
+from zerg.schemas.node_output import create_wait_envelope
 from zerg.services.execution_state import ExecutionStateMachine
 from zerg.services.expression_evaluator import safe_evaluator
+from zerg.services.http_node import render_request
+from zerg.services.http_node import send_request
+from zerg.services.subworkflows import check_call_allowed
+from zerg.services.subworkflows import map_outputs
+from zerg.services.subworkflows import subworkflow_target
 from zerg.services.variable_resolver import resolve_variables
+from zerg.services.workflow_waits import workflow_waits
 from zerg.tools.unified_access import get_tool_resolver
+from zerg.utils.time import utc_now_naive
 
 logger = logging.getLogger(__name__)
 
+# Fills in a node's {{env.NAME}} references and credential headers right before it runs
+ConfigRenderer = Callable[[Any, Any], Dict[str, Any]]
+
+# Loop nodes always have an upper bound so a workflow can't spin forever
+DEFAULT_LOOP_ITERATIONS = 10
+MAX_LOOP_ITERATIONS = 100
+
 
 class BaseNodeExecutor:
     """Base class for node executors. Envelope format only."""
 
-    def __init__(self, node, publish_event_callback, node_type: str):
+    def __init__(self, node, publish_event_callback, node_type: str, render_config: Optional[ConfigRenderer] = None):
         self.node = node
         self.node_id = node.id
         self.publish_event = publish_event_callback
         self.node_type = node_type
+        self.render_config = render_config
 
     async def execute(self, state: Dict[str, Any], config: Dict[str, Any] = None) -> Dict[str, Any]:
         """Execute the node and return updated state.
@@ -64,7 +94,7 @@ class BaseNodeExecutor:
             await self.publish_event(execution_id=execution_id, node_id=self.node_id, node_state=node_state, output=None)
 
             try:
-                output = await self._execute_node_logic(db, state, execution_id)
+                output = await self._execute_rendered(db, state, execution_id)
 
                 # Mark as successful using state machine
                 ExecutionStateMachine.mark_success(node_state)
@@ -110,6 +140,21 @@ class BaseNodeExecutor:
                     "error": f"{self.node.type} node {self.node_id} failed: {error_msg}",
                 }
 
+    async def _execute_rendered(self, db, state, execution_id: int):
+        """Run the node logic against a copy of the node with its config rendered.
+
+        ``self.node`` otherwise keeps the config as written, so pause events and
+        anything else describing the node never carry secret values.
+        """
+        if self.render_config is None:
+            return await self._execute_node_logic(db, state, execution_id)
+        written = self.node
+        self.node = written.model_copy(update={"config": self.render_config(db, written)})
+        try:
+            return await self._execute_node_logic(db, state, execution_id)
+        finally:
+            self.node = written
+
     async def _execute_node_logic(self, db, state, execution_id: int):
         """Override this in subclasses.
 
@@ -128,6 +173,16 @@ class BaseNodeExecutor:
             return create_agent_envelope(value, **kwargs)
         elif node_type == "conditional":
             return create_conditional_envelope(value, **kwargs)
+        elif node_type == "switch":
+            return create_switch_envelope(value, **kwargs)
+        elif node_type == "loop":
+            return create_loop_envelope(value, **kwargs)
+        elif node_type == "http":
+            return create_http_envelope(value, **kwargs)
+        elif node_type == "wait":
+            return create_wait_envelope(value, **kwargs)
+        elif node_type == "subworkflow":
+            return create_subworkflow_envelope(value, **kwargs)
         elif node_type == "trigger":
             return create_trigger_envelope(value, **kwargs)
         else:
@@ -311,46 +366,315 @@ class ConditionalNodeExecutor(BaseNodeExecutor):
         )
 
     def _evaluate_condition(self, condition: str, condition_type: str, node_outputs: Dict[str, Any]) -> bool:
-        """Evaluate condition. Clean, direct evaluation."""
+        return evaluate_condition(condition, condition_type, node_outputs)
 
-        if condition_type == "expression":
-            try:
-                # Resolve variables in condition
-                resolved_condition = resolve_variables(condition, node_outputs)
 
-                # If condition resolved to a single value, check truthiness
-                if not isinstance(resolved_condition, str):
-                    return bool(resolved_condition)
+class SwitchNodeExecutor(BaseNodeExecutor):
+    """Executes switch nodes: routes to the case matching the expression's value. Envelope format only."""
 
-                # Evaluate the resolved expression
-                result = safe_evaluator.evaluate(resolved_condition, {})
-                return bool(result)
+    async def _execute_node_logic(self, db, state, execution_id: int):
+        node_outputs = state.get("node_outputs", {})
+        expression = self.node.config.get("expression", "")
+        cases = [str(case) for case in self.node.config.get("cases") or []]
 
-            except Exception as e:
-                logger.error(f"Failed to evaluate condition '{condition}': {e}")
-                return False
+        if not expression:
+            raise ValueError(f"Switch node {self.node_id} missing expression")
 
-        elif condition_type == "exists":
-            try:
-                resolve_variables(f"${{{condition}}}", node_outputs)
-                return True
-            except Exception:
-                return False
+        value = _switch_value(resolve_variables(expression, node_outputs))
+        key = _case_key(value)
+        branch = key if key in cases else "default"
 
-        else:
-            raise ValueError(f"Unsupported condition type: {condition_type}")
+        logger.info(f"[SwitchNode] Expression '{expression}' evaluated to {value!r}, branch '{branch}'")
+
+        return self._create_envelope_output(
+            value={"value": value, "branch": branch},
+            node_type="switch",
+            phase="finished",
+            result="success",
+            expression=expression,
+            cases=cases,
+        )
+
+
+class LoopNodeExecutor(BaseNodeExecutor):
+    """Executes loop nodes. Envelope format only.
+
+    The loop node is entered once from upstream and again after every pass
+    through its body (the body's last node links back to it).  Each entry
+    advances the index and routes to ``body`` while items remain, the bound
+    isn't reached and the optional continue-while condition holds, otherwise to
+    ``done``.  Body nodes read the current item as ``${loop_id.value.item}``.
+    """
+
+    async def _execute_node_logic(self, db, state, execution_id: int):
+        node_outputs = state.get("node_outputs", {})
+        config = self.node.config
+
+        previous = (node_outputs.get(self.node_id) or {}).get("value") or {}
+        index = previous["index"] + 1 if previous.get("branch") == "body" else 0
+
+        max_iterations = loop_max_iterations(config)
+        items = None
+        if config.get("items"):
+            items = resolve_variables(config["items"], node_outputs)
+            if not isinstance(items, list):
+                raise ValueError(f"Loop node {self.node_id}: items must resolve to a list, got {type(items).__name__}")
+        count = min(len(items), max_iterations) if items is not None else max_iterations
+
+        condition = config.get("condition") or None
+        proceed = index < count
+        if proceed and condition:
+            proceed = evaluate_condition(condition, "expression", node_outputs)
+
+        branch = "body" if proceed else "done"
+        logger.info(f"[LoopNode] Node {self.node_id} iteration {index + 1}/{count}: {branch}")
+
+        return self._create_envelope_output(
+            value={
+                "index": index,
+                "item": items[index] if items is not None and proceed else None,
+                "count": count,
+                "truncated": items is not None and len(items) > max_iterations,
+                "branch": branch,
+            },
+            node_type="loop",
+            phase="finished",
+            result="success",
+            max_iterations=max_iterations,
+            condition=condition,
+        )
+
+
+class HttpRequestNodeExecutor(BaseNodeExecutor):
+    """Executes HTTP request nodes. Envelope format only.
+
+    Every response is a success, whatever its status code; see services/http_node.py.
+    """
+
+    async def _execute_node_logic(self, db, state, execution_id: int):
+        request = render_request(self.node.config, state.get("node_outputs", {}))
+        if request.unresolved:
+            logger.warning(f"[HttpNode] {self.node_id}: unresolved variables {request.unresolved}")
+
+        logger.info(f"[HttpNode] Starting execution – node_id={self.node_id}, {request.method} {request.url}")
+        try:
+            response = await send_request(request)
+        except httpx.HTTPError as exc:
+            raise ValueError(f"{request.method} {request.url} failed: {exc}") from exc
+
+        return self._create_envelope_output(
+            value=response,
+            node_type="http",
+            phase="finished",
+            result="success",
+            method=request.method,
+            url=request.url,
+            status_code=response["status_code"],
+        )
+
+
+WAIT_MODES = ("duration", "until", "event")
+
+
+def wait_deadline(config: Dict[str, Any]) -> Optional[datetime]:
+    """When a wait node should resume on its own (naive UTC), or None to wait for its event indefinitely."""
+    mode = config.get("mode", "duration")
+    if mode not in WAIT_MODES:
+        raise ValueError(f"Unknown wait mode {mode}")
+
+    if mode == "duration":
+        minutes = config.get("minutes")
+        if not isinstance(minutes, (int, float)) or minutes < 0:
+            raise ValueError("Wait minutes must be a non-negative number")
+        return utc_now_naive() + timedelta(minutes=minutes)
+
+    if mode == "until":
+        try:
+            until = datetime.fromisoformat(str(config.get("until", "")).replace("Z", "+00:00"))
+        except ValueError as exc:
+            raise ValueError("Wait until must be an ISO 8601 time") from exc
+        if until.tzinfo is not None:
+            until = until.astimezone(timezone.utc).replace(tzinfo=None)
+        return until
+
+    if not config.get("event"):
+        raise ValueError("Event waits need an event name")
+    timeout = config.get("timeout_minutes")
+    return utc_now_naive() + timedelta(minutes=timeout) if timeout else None
+
+
+class WaitNodeExecutor(BaseNodeExecutor):
+    """Executes wait nodes: parks the branch until the deadline, the event, or a manual skip."""
+
+    async def _execute_node_logic(self, db, state, execution_id: int):
+        config = resolve_variables(self.node.config, state.get("node_outputs", {}))
+        mode = config.get("mode", "duration")
+        resume_at = wait_deadline(config)
+        event = config.get("event") if mode == "event" else None
+
+        pending = workflow_waits.create(execution_id, self.node_id, mode=mode, resume_at=resume_at, event=event)
+        resume_iso = resume_at.isoformat() + "Z" if resume_at else None
+        started = utc_now_naive()
+        try:
+            await publish_event(
+                EventType.NODE_WAITING,
+                {
+                    "execution_id": execution_id,
+                    "node_id": self.node_id,
+                    "mode": mode,
+                    "resume_at": resume_iso,
+                    "event": event,
+                    "resume_path": f"/api/workflow-waits/{pending.token}" if pending.token else None,
+                    "event_type": EventType.NODE_WAITING,
+                },
+            )
+            outcome, payload = await pending.wait()
+        finally:
+            workflow_waits.discard(pending)
+
+        waited_ms = int((utc_now_naive() - started).total_seconds() * 1000)
+        logger.info(f"[WaitNode] {self.node_id} resumed – outcome={outcome}, waited_ms={waited_ms}")
+        return self._create_envelope_output(
+            value={"outcome": outcome, "waited_ms": waited_ms, "payload": payload},
+            node_type="wait",
+            phase="finished",
+            result="success",
+            mode=mode,
+            resume_at=resume_iso,
+            event=event,
+            outcome=outcome,
+        )
+
+
+class SubworkflowNodeExecutor(BaseNodeExecutor):
+    """Executes Call Workflow nodes: runs the target workflow as a child execution and waits for it."""
+
+    async def _execute_node_logic(self, db, state, execution_id: int):
+        # Imported here: the engine builds these executors
+        from zerg.services.workflow_engine import workflow_engine
+
+        target_id = subworkflow_target(self.node.config)
+        if target_id is None:
+            raise ValueError(f"Call workflow node {self.node_id} has no workflow_id")
+
+        parent = db.query(WorkflowExecution).filter_by(id=execution_id).first()
+        check_call_allowed(db, parent, target_id)
+        node_outputs = state.get("node_outputs", {})
+        inputs = resolve_variables(self.node.config.get("inputs") or {}, node_outputs)
+
+        logger.info(f"[SubworkflowNode] {self.node_id} calling workflow {target_id} from execution {execution_id}")
+        child_id = await workflow_engine.execute_workflow(
+            target_id,
+            trigger_type="workflow",
+            inputs=inputs,
+            parent_execution_id=execution_id,
+            parent_node_id=self.node_id,
+        )
+
+        child = db.query(WorkflowExecution).filter_by(id=child_id).first()
+        if child.result != "success":
+            raise ValueError(f"Called workflow {target_id} {child.result}: {child.error_message or 'no details'}")
+        failed = [node_state.node_id for node_state in child.node_states if node_state.result == "failure"]
+        if failed:
+            raise ValueError(f"Called workflow {target_id} failed at {', '.join(failed)} (execution {child_id})")
+
+        child_outputs = {node_state.node_id: node_state.output for node_state in child.node_states}
+        return self._create_envelope_output(
+            value={
+                "workflow_id": target_id,
+                "execution_id": child_id,
+                "result": child.result,
+                "outputs": map_outputs(self.node.config.get("outputs") or {}, child_outputs),
+            },
+            node_type="subworkflow",
+            phase="finished",
+            result="success",
+            workflow_id=target_id,
+            child_execution_id=child_id,
+        )
+
+
+def evaluate_condition(condition: str, condition_type: str, node_outputs: Dict[str, Any]) -> bool:
+    """Evaluate a conditional/loop condition. Clean, direct evaluation."""
+
+    if condition_type == "expression":
+        try:
+            # Resolve variables in condition
+            resolved_condition = resolve_variables(condition, node_outputs)
+
+            # If condition resolved to a single value, check truthiness
+            if not isinstance(resolved_condition, str):
+                return bool(resolved_condition)
+
+            # Evaluate the resolved expression
+            result = safe_evaluator.evaluate(resolved_condition, {})
+            return bool(result)
+
+        except Exception as e:
+            logger.error(f"Failed to evaluate condition '{condition}': {e}")
+            return False
+
+    elif condition_type == "exists":
+        try:
+            resolve_variables(f"${{{condition}}}", node_outputs)
+            return True
+        except Exception:
+            return False
+
+    else:
+        raise ValueError(f"Unsupported condition type: {condition_type}")
+
+
+def loop_max_iterations(config: Dict[str, Any]) -> int:
+    try:
+        requested = int(config.get("max_iterations") or DEFAULT_LOOP_ITERATIONS)
+    except (TypeError, ValueError):
+        requested = DEFAULT_LOOP_ITERATIONS
+    return max(1, min(requested, MAX_LOOP_ITERATIONS))
+
+
+def _switch_value(resolved: Any) -> Any:
+    """Evaluate *resolved* as an expression when it is one (``${a} > 5``), else keep it as-is."""
+
+    if not isinstance(resolved, str):
+        return resolved
+    try:
+        return safe_evaluator.evaluate(resolved, {})
+    except Exception:
+        return resolved.strip()
+
+
+def _case_key(value: Any) -> str:
+    # Case labels are strings; booleans match "true"/"false" like JSON
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if value is None:
+        return "null"
+    return str(value)
 
 
-def create_node_executor(node, publish_event_callback) -> BaseNodeExecutor:
+def create_node_executor(
+    node, publish_event_callback, render_config: Optional[ConfigRenderer] = None
+) -> BaseNodeExecutor:
     """Factory function to create node executor. Envelope format only."""
     if node.type == "agent":
-        return AgentNodeExecutor(node, publish_event_callback, "agent")
+        return AgentNodeExecutor(node, publish_event_callback, "agent", render_config)
     elif node.type == "tool":
-        return ToolNodeExecutor(node, publish_event_callback, "tool")
+        return ToolNodeExecutor(node, publish_event_callback, "tool", render_config)
     elif node.type == "trigger":
-        return TriggerNodeExecutor(node, publish_event_callback, "trigger")
+        return TriggerNodeExecutor(node, publish_event_callback, "trigger", render_config)
     elif node.type == "conditional":
-        return ConditionalNodeExecutor(node, publish_event_callback, "conditional")
+        return ConditionalNodeExecutor(node, publish_event_callback, "conditional", render_config)
+    elif node.type == "switch":
+        return SwitchNodeExecutor(node, publish_event_callback, "switch", render_config)
+    elif node.type == "loop":
+        return LoopNodeExecutor(node, publish_event_callback, "loop", render_config)
+    elif node.type == "http":
+        return HttpRequestNodeExecutor(node, publish_event_callback, "http", render_config)
+    elif node.type == "wait":
+        return WaitNodeExecutor(node, publish_event_callback, "wait", render_config)
+    elif node.type == "subworkflow":
+        return SubworkflowNodeExecutor(node, publish_event_callback, "subworkflow", render_config)
     else:
         # Placeholder for unknown types
         class PlaceholderExecutor(BaseNodeExecutor):
@@ -360,4 +684,4 @@ def create_node_executor(node, publish_event_callback) -> BaseNodeExecutor:
                     value={"skipped": True}, node_type="placeholder", phase="finished", result="success"
                 )
 
-        return PlaceholderExecutor(node, publish_event_callback, "placeholder")
+        return PlaceholderExecutor(node, publish_event_callback, "placeholder", render_config)
@@ -84,12 +84,15 @@ class OpsEventsBridge:
 
     async def _handle_budget_denied(self, data: Dict[str, Any]) -> None:
         # Data expected: { scope, percent, used_usd, limit_cents, user_email }
+        # plus agent_id/agent_name for scope="agent"
         scope = data.get("scope")
         if not scope:
             return
         payload = OpsEventData(
             type="budget_denied",
             scope=scope,
+            agent_id=data.get("agent_id"),
+            agent_name=data.get("agent_name"),
             percent=data.get("percent"),
             used_usd=data.get("used_usd"),
             limit_cents=data.get("limit_cents"),
@@ -175,6 +175,60 @@ def get_summary(db: Session, current_user: UserModel) -> Dict[str, Any]:
     }
 
 
+# Share of the last hour's finished runs that failed before the status page reports "degraded"
+DEGRADED_ERROR_RATE = 0.25
+
+
+def get_status(db: Session, *, ws_connections: Optional[int], frontend_errors_last_hour: int) -> Dict[str, Any]:
+    """System health for the status page: recent run outcomes and latency, system-wide.
+
+    *ws_connections* is ``None`` when the WebSocket manager isn't loaded.
+    Counts are aggregated across all users, so nothing identifies an agent
+    or owner and any signed-in user may see them.
+    """
+    now = _now_utc()
+    since_1h = now - timedelta(hours=1)
+    since_24h = now - timedelta(hours=24)
+
+    database = "ok"
+    runs_last_hour = errors_last_hour = errors_last_24h = 0
+    durations: List[int] = []
+    try:
+        finished = db.query(func.count(AgentRunModel.id)).filter(AgentRunModel.finished_at.isnot(None))
+        runs_last_hour = int(finished.filter(AgentRunModel.finished_at >= since_1h).scalar() or 0)
+        failed = finished.filter(AgentRunModel.status == "failed")
+        errors_last_hour = int(failed.filter(AgentRunModel.finished_at >= since_1h).scalar() or 0)
+        errors_last_24h = int(failed.filter(AgentRunModel.finished_at >= since_24h).scalar() or 0)
+        durations = [
+            int(row[0])
+            for row in db.query(AgentRunModel.duration_ms)
+            .filter(
+                AgentRunModel.duration_ms.isnot(None),
+                AgentRunModel.finished_at.isnot(None),
+                AgentRunModel.finished_at >= since_1h,
+                AgentRunModel.status == "success",
+            )
+            .all()
+        ]
+    except Exception:  # noqa: BLE001 – report the outage rather than fail the status page
+        database = "error"
+
+    error_rate = errors_last_hour / runs_last_hour if runs_last_hour else None
+    degraded = database != "ok" or ws_connections is None or (error_rate or 0) >= DEGRADED_ERROR_RATE
+    return {
+        "status": "degraded" if degraded else "ok",
+        "checked_at": now.isoformat(),
+        "database": database,
+        "websocket": {"available": ws_connections is not None, "active_connections": ws_connections or 0},
+        "runs_last_hour": runs_last_hour,
+        "errors_last_hour": errors_last_hour,
+        "errors_last_24h": errors_last_24h,
+        "error_rate_last_hour": error_rate,
+        "frontend_errors_last_hour": frontend_errors_last_hour,
+        "latency_ms": {"p50": _percentile(durations, 50) or 0, "p95": _percentile(durations, 95) or 0},
+    }
+
+
 def get_timeseries(db: Session, metric: str, window: str = "today") -> List[Dict[str, Any]]:
     """Return simple time-series suitable for small sparklines.
 
@@ -0,0 +1,108 @@
+"""
+Run a workflow from one node, reusing an earlier execution's node outputs.
+
+Every node's output is kept on its ``NodeExecutionState`` row, so a finished
+execution doubles as a cache of what each node produced. A partial run
+executes the chosen node and everything downstream of it; every other node
+is skipped and its cached output is handed to the graph as if it had just
+run, so ``${node_id.value}`` references keep resolving.
+
+The reused outputs are copied onto the new execution, which makes it a full
+cache in its turn: running from a later node of a partial run still finds
+everything upstream.
+"""
+
+from dataclasses import dataclass
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Set
+
+from zerg.models.models import NodeExecutionState
+from zerg.schemas.workflow import WorkflowData
+
+
+class PartialRunError(ValueError):
+    """The run can't start from the requested node."""
+
+
+@dataclass
+class PartialRunPlan:
+    # The chosen node and its downstream subgraph
+    workflow: WorkflowData
+    # Cached outputs of the skipped nodes, keyed by node id
+    seed_outputs: Dict[str, Dict[str, Any]]
+
+
+def cached_outputs(db, execution_id: int) -> Dict[str, Dict[str, Any]]:
+    """Outputs of the nodes that succeeded in *execution_id*."""
+    states = (
+        db.query(NodeExecutionState)
+        .filter(NodeExecutionState.workflow_execution_id == execution_id, NodeExecutionState.result == "success")
+        .order_by(NodeExecutionState.id)
+        .all()
+    )
+    # Loop bodies run more than once; the last iteration wins
+    return {state.node_id: state.output for state in states if state.output is not None}
+
+
+def downstream_of(workflow_data: WorkflowData, node_id: str) -> Set[str]:
+    """*node_id* and every node reachable from it."""
+    targets: Dict[str, List[str]] = {}
+    for edge in workflow_data.edges:
+        targets.setdefault(edge.from_node_id, []).append(edge.to_node_id)
+    reached = {node_id}
+    pending = [node_id]
+    while pending:
+        for target in targets.get(pending.pop(), []):
+            if target not in reached:
+                reached.add(target)
+                pending.append(target)
+    return reached
+
+
+def plan_partial_run(
+    workflow_data: WorkflowData, start_node_id: str, cached: Dict[str, Dict[str, Any]]
+) -> PartialRunPlan:
+    """The subgraph to run from *start_node_id* and the cached outputs it needs.
+
+    Raises ``PartialRunError`` when the node isn't on the canvas or a node
+    feeding the subgraph has no cached output.
+    """
+    node_ids = {node.id for node in workflow_data.nodes}
+    if start_node_id not in node_ids:
+        raise PartialRunError(f"Node {start_node_id} is not on the canvas")
+
+    run_ids = downstream_of(workflow_data, start_node_id)
+    feeding = {
+        edge.from_node_id
+        for edge in workflow_data.edges
+        if edge.to_node_id in run_ids and edge.from_node_id not in run_ids
+    }
+    missing = sorted(feeding - cached.keys())
+    if missing:
+        raise PartialRunError(f"No cached output for {', '.join(missing)}; run the whole workflow first")
+
+    subgraph = WorkflowData(
+        nodes=[node for node in workflow_data.nodes if node.id in run_ids],
+        edges=[edge for edge in workflow_data.edges if edge.from_node_id in run_ids and edge.to_node_id in run_ids],
+    )
+    seed_outputs = {
+        node_id: output for node_id, output in cached.items() if node_id in node_ids and node_id not in run_ids
+    }
+    return PartialRunPlan(workflow=subgraph, seed_outputs=seed_outputs)
+
+
+def record_reused_outputs(db, execution_id: int, outputs: Dict[str, Dict[str, Any]]) -> None:
+    """Copy the reused outputs onto *execution_id* as finished node states."""
+    for node_id, output in outputs.items():
+        db.add(
+            NodeExecutionState(
+                workflow_execution_id=execution_id,
+                node_id=node_id,
+                phase="finished",
+                result="success",
+                output=dict(output),
+            )
+        )
+    db.commit()
@@ -0,0 +1,120 @@
+"""Validation for the known keys of ``user.prefs`` and the workspace defaults.
+
+Prefs are a free-form JSON object owned by the frontend; keys this module
+doesn't know pass through untouched so new settings can be stored before the
+backend learns about them. Workspace defaults are a subset of the same keys,
+set by an admin, that apply until a user picks their own value.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+from typing import Callable
+from typing import Dict
+from typing import List
+from typing import Optional
+from zoneinfo import ZoneInfo
+from zoneinfo import ZoneInfoNotFoundError
+
+from zerg.models_config import get_model_by_id
+
+THEMES = ("dark", "light", "high-contrast")
+TIME_DISPLAY_MODES = ("absolute", "relative")
+DASHBOARD_SCOPES = ("my", "all")
+DASHBOARD_SORT_KEYS = ("name", "status", "created_at", "last_run", "next_run", "success")
+SHELF_GROUPINGS = ("none", "owner", "tag")
+# Stored timezone meaning "use the browser's"
+AUTO_TIMEZONE = "auto"
+
+# Keys an admin can set for the whole workspace
+WORKSPACE_DEFAULT_KEYS = ("theme", "timezone", "time_display", "default_model", "dashboard_scope", "dashboard_sort")
+
+_TIME_OF_DAY = re.compile(r"^([01]\d|2[0-3]):[0-5]\d$")
+
+
+def _one_of(choices: tuple) -> Callable[[Any], Optional[str]]:
+    def check(value: Any) -> Optional[str]:
+        return None if value in choices else f"must be one of {', '.join(choices)}"
+
+    return check
+
+
+def _check_timezone(value: Any) -> Optional[str]:
+    if value == AUTO_TIMEZONE:
+        return None
+    if not isinstance(value, str) or not value:
+        return "must be a timezone name"
+    try:
+        ZoneInfo(value)
+    except (ZoneInfoNotFoundError, ValueError):
+        return f"unknown timezone {value!r}"
+    return None
+
+
+def _check_bool(value: Any) -> Optional[str]:
+    return None if isinstance(value, bool) else "must be true or false"
+
+
+def _check_quiet_hours(value: Any) -> Optional[str]:
+    if not isinstance(value, dict) or set(value) != {"start", "end"}:
+        return "must have start and end"
+    if not all(isinstance(value[key], str) and _TIME_OF_DAY.match(value[key]) for key in ("start", "end")):
+        return "start and end must be HH:MM"
+    if value["start"] == value["end"]:
+        return "start and end must differ"
+    return None
+
+
+def _check_model(value: Any) -> Optional[str]:
+    if not isinstance(value, str) or get_model_by_id(value) is None:
+        return f"unknown model {value!r}"
+    return None
+
+
+def _check_dashboard_sort(value: Any) -> Optional[str]:
+    if not isinstance(value, dict) or value.get("key") not in DASHBOARD_SORT_KEYS:
+        return f"key must be one of {', '.join(DASHBOARD_SORT_KEYS)}"
+    if not isinstance(value.get("ascending"), bool):
+        return "ascending must be true or false"
+    return None
+
+
+def _check_id_list(value: Any) -> Optional[str]:
+    if not isinstance(value, list) or not all(isinstance(item, int) and not isinstance(item, bool) for item in value):
+        return "must be a list of ids"
+    return None
+
+
+def _check_str_list(value: Any) -> Optional[str]:
+    if not isinstance(value, list) or not all(isinstance(item, str) for item in value):
+        return "must be a list of strings"
+    return None
+
+
+_CHECKS: Dict[str, Callable[[Any], Optional[str]]] = {
+    "theme": _one_of(THEMES),
+    "timezone": _check_timezone,
+    "time_display": _one_of(TIME_DISPLAY_MODES),
+    "desktop_notifications": _check_bool,
+    "quiet_hours": _check_quiet_hours,
+    "default_model": _check_model,
+    "dashboard_scope": _one_of(DASHBOARD_SCOPES),
+    "dashboard_sort": _check_dashboard_sort,
+    "shelf_favorites": _check_id_list,
+    "shelf_grouping": _one_of(SHELF_GROUPINGS),
+    "shelf_collapsed_groups": _check_str_list,
+}
+
+
+def preference_errors(prefs: Dict[str, Any]) -> List[str]:
+    """One message per known key with a bad value; null always clears a key."""
+
+    errors = []
+    for key, check in _CHECKS.items():
+        if prefs.get(key) is None:
+            continue
+        message = check(prefs[key])
+        if message:
+            errors.append(f"{key}: {message}")
+    return errors
@@ -1,4 +1,4 @@
-"""Quota helpers for per-user daily run caps.
+"""Quota helpers for per-user daily run caps and per-agent budgets.
 
 Centralised, reusable checks to keep routers/services lean.
 """
@@ -8,6 +8,9 @@ from __future__ import annotations
 import logging
 from datetime import datetime
 from datetime import timezone
+from typing import Any
+from typing import Dict
+from typing import Optional
 
 from fastapi import HTTPException
 from fastapi import status
@@ -21,6 +24,11 @@ from zerg.models.models import Agent as AgentModel
 from zerg.models.models import AgentRun as AgentRunModel
 from zerg.models.models import User as UserModel
 from zerg.services.ops_discord import send_budget_alert
+from zerg.utils.time import utc_now_naive
+
+# Key in Agent.config holding the per-agent cap: {"period": "daily"|"monthly", "limit_usd": 5.0}
+AGENT_BUDGET_KEY = "budget"
+AGENT_BUDGET_PERIODS = ("daily", "monthly")
 
 
 def _is_admin(user: UserModel | None) -> bool:
@@ -162,3 +170,96 @@ def assert_can_start_run(db: Session, *, user: UserModel) -> None:
 
     _check_budget(user_cost_usd, user_budget_cents, "user")
     _check_budget(global_cost_usd, global_budget_cents, "global")
+
+
+# ---------------------------------------------------------------------------
+# Per-agent budgets
+# ---------------------------------------------------------------------------
+
+
+def agent_budget_limit(agent: AgentModel) -> Optional[tuple[str, float]]:
+    """Return ``(period, limit_usd)`` from the agent config, or None when uncapped."""
+
+    budget = (agent.config or {}).get(AGENT_BUDGET_KEY)
+    if not isinstance(budget, dict):
+        return None
+    period = budget.get("period")
+    try:
+        limit_usd = float(budget.get("limit_usd"))
+    except (TypeError, ValueError):
+        return None
+    if period not in AGENT_BUDGET_PERIODS or limit_usd <= 0:
+        return None
+    return period, limit_usd
+
+
+def _period_start(period: str, now: datetime) -> datetime:
+    start = now.replace(hour=0, minute=0, second=0, microsecond=0)
+    return start.replace(day=1) if period == "monthly" else start
+
+
+def agent_budget_status(db: Session, agent: AgentModel, now: Optional[datetime] = None) -> Optional[Dict[str, Any]]:
+    """Spend so far in the agent's budget period (UTC calendar day or month)."""
+
+    limit = agent_budget_limit(agent)
+    if limit is None:
+        return None
+    period, limit_usd = limit
+    since = _period_start(period, now or utc_now_naive())
+    spent_usd = float(
+        db.query(func.coalesce(func.sum(AgentRunModel.total_cost_usd), 0.0))
+        .filter(
+            AgentRunModel.agent_id == agent.id,
+            AgentRunModel.finished_at.isnot(None),
+            AgentRunModel.finished_at >= since,
+        )
+        .scalar()
+        or 0.0
+    )
+    return {
+        "agent_id": agent.id,
+        "period": period,
+        "limit_usd": limit_usd,
+        "spent_usd": spent_usd,
+        "percent": (spent_usd / limit_usd) * 100.0,
+    }
+
+
+def assert_agent_within_budget(db: Session, agent: AgentModel) -> None:
+    """Raise 429 when the agent has used up its own budget.
+
+    Unlike the user/global budgets this applies to admins too: the cap is
+    something the owner set on purpose for this agent.
+    """
+
+    budget = agent_budget_status(db, agent)
+    if budget is None or budget["spent_usd"] < budget["limit_usd"]:
+        return
+
+    try:
+        import asyncio
+
+        frame = {
+            "scope": "agent",
+            "agent_id": agent.id,
+            "agent_name": agent.name,
+            "percent": budget["percent"],
+            "used_usd": budget["spent_usd"],
+            "limit_cents": int(round(budget["limit_usd"] * 100)),
+        }
+
+        async def _emit():  # pragma: no cover - tiny helper
+            await event_bus.publish(EventType.BUDGET_DENIED, frame)
+
+        asyncio.create_task(_emit())
+    except Exception:  # pragma: no cover – robustness (no running loop)
+        pass
+
+    raise HTTPException(
+        status_code=status.HTTP_429_TOO_MANY_REQUESTS,
+        detail=(
+            f"Agent {budget['period']} budget exhausted "
+            f"(${budget['spent_usd']:.2f}/${budget['limit_usd']:.2f}). "
+            "Raise the cap in the agent's settings or wait for the next period."
+        ),
+    )
@@ -0,0 +1,54 @@
+"""
+Cancellation for in-flight agent task runs.
+
+``execute_agent_task`` runs the agent inside its own asyncio task and
+registers it here; ``POST /runs/{id}/cancel`` flags the run and cancels the
+task, which stops at its next await point (LLM call, tool call) and is then
+recorded as ``cancelled`` instead of ``failed``.
+
+State is in-process only, like the run itself.
+"""
+
+import asyncio
+import logging
+from typing import Dict
+from typing import Set
+
+logger = logging.getLogger(__name__)
+
+
+class RunCancellationRegistry:
+    """Live agent-run tasks keyed by run id."""
+
+    def __init__(self):
+        self._tasks: Dict[int, asyncio.Task] = {}
+        self._requested: Set[int] = set()
+
+    def register(self, run_id: int, task: asyncio.Task) -> None:
+        self._tasks[run_id] = task
+
+    def discard(self, run_id: int) -> None:
+        self._tasks.pop(run_id, None)
+        self._requested.discard(run_id)
+
+    def is_live(self, run_id: int) -> bool:
+        task = self._tasks.get(run_id)
+        return task is not None and not task.done()
+
+    def was_requested(self, run_id: int) -> bool:
+        return run_id in self._requested
+
+    def request_cancel(self, run_id: int) -> bool:
+        """Cancel the run's task. Returns ``False`` when no live task is known."""
+        task = self._tasks.get(run_id)
+        if task is None or task.done():
+            return False
+        self._requested.add(run_id)
+        # Thread-safe: sync endpoints may call in from a worker thread
+        task.get_loop().call_soon_threadsafe(task.cancel)
+        logger.info(f"[RunCancellation] Cancel requested – run_id={run_id}")
+        return True
+
+
+# Singleton instance
+run_cancellations = RunCancellationRegistry()
@@ -0,0 +1,124 @@
+"""Internal events of an agent run, streamed to the run inspector.
+
+While a run executes, the task runner and the thread run path bind it to the
+current context so the ReAct loop can report what it is doing – tool calls
+starting and finishing, the intermediate assistant messages that request them
+and retried MCP requests – without threading ids through every call.
+
+Events are published on the event bus as ``RUN_EVENT`` and the topic manager
+forwards them to ``agent:{agent_id}`` as ``run_event``.  Nothing is stored: a
+client that subscribes mid-run only sees what happens from then on.
+
+Usage around a run:
+    token = set_current_run(agent.id, run_row.id)
+    try:
+        await runner.run_thread(db, thread)
+    finally:
+        reset_current_run(token)
+"""
+
+from __future__ import annotations
+
+import asyncio
+import contextvars
+import logging
+from dataclasses import dataclass
+from datetime import datetime
+from datetime import timezone
+from typing import Any
+from typing import Awaitable
+from typing import Optional
+from typing import TypeVar
+
+from zerg.events import EventType
+from zerg.events import event_bus
+
+logger = logging.getLogger(__name__)
+
+T = TypeVar("T")
+
+# Kinds carried in RunEventData.kind
+RUN_EVENT_KINDS = ("tool_started", "tool_finished", "tool_failed", "message", "retry")
+
+
+@dataclass(frozen=True)
+class RunContext:
+    agent_id: int
+    run_id: int
+    # Loop the run executes on; events raised on helper loops are handed back to it
+    loop: asyncio.AbstractEventLoop
+
+
+_current_run_var: contextvars.ContextVar[Optional[RunContext]] = contextvars.ContextVar(
+    "_current_run_var",
+    default=None,
+)
+
+
+def get_current_run() -> Optional[RunContext]:
+    """The run being executed in this context, or None outside a run."""
+    return _current_run_var.get()
+
+
+def set_current_run(agent_id: int, run_id: int) -> contextvars.Token:
+    """Bind the run to the current context; must be called on the run's event loop."""
+    return _current_run_var.set(RunContext(agent_id=agent_id, run_id=run_id, loop=asyncio.get_running_loop()))
+
+
+def reset_current_run(token: contextvars.Token) -> None:
+    """Restore the context from before ``set_current_run``."""
+    _current_run_var.reset(token)
+
+
+def bind_current_run(coro: Awaitable[T]) -> Awaitable[T]:
+    """Wrap *coro* so it still sees the current run when awaited in another loop's task."""
+    run = _current_run_var.get()
+
+    async def _bound() -> T:
+        token = _current_run_var.set(run)
+        try:
+            return await coro
+        finally:
+            _current_run_var.reset(token)
+
+    return _bound()
+
+
+async def emit_run_event(kind: str, **fields: Any) -> None:
+    """Publish a *kind* event for the current run; a no-op outside a run.
+
+    *fields* are optional RunEventData properties (``tool_name``,
+    ``tool_call_id``, ``preview``, ``duration_ms``, ``attempt``); None values
+    are left out.  Failures are logged and never reach the run.
+    """
+    run = _current_run_var.get()
+    if run is None:
+        return
+
+    payload = {
+        "event_type": EventType.RUN_EVENT,
+        "agent_id": run.agent_id,
+        "run_id": run.run_id,
+        "kind": kind,
+        "timestamp": datetime.now(timezone.utc).isoformat(),
+        **{key: value for key, value in fields.items() if value is not None},
+    }
+    try:
+        if asyncio.get_running_loop() is run.loop:
+            await event_bus.publish(EventType.RUN_EVENT, payload)
+        else:
+            # WebSocket sends must happen on the loop that owns the connections
+            asyncio.run_coroutine_threadsafe(event_bus.publish(EventType.RUN_EVENT, payload), run.loop)
+    except Exception:
+        logger.warning("Failed to emit run event %s for run %s", kind, run.run_id, exc_info=True)
+
+
+__all__ = [
+    "RUN_EVENT_KINDS",
+    "RunContext",
+    "bind_current_run",
+    "emit_run_event",
+    "get_current_run",
+    "reset_current_run",
+    "set_current_run",
+]
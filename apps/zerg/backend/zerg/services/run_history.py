@@ -14,6 +14,8 @@ from zerg.events.event_bus import event_bus
 from zerg.managers.agent_runner import AgentRunner
 from zerg.models.models import Agent as AgentModel
 from zerg.models.models import Thread as ThreadModel
+from zerg.services.run_events import reset_current_run
+from zerg.services.run_events import set_current_run
 
 
 async def execute_thread_run_with_history(
@@ -46,6 +48,7 @@ async def execute_thread_run_with_history(
             "run_id": run_row.id,
             "status": run_row.status,
             "thread_id": thread.id,
+            "trace_id": run_row.trace_id,
         },
     )
 
@@ -65,6 +68,7 @@ async def execute_thread_run_with_history(
     )
 
     # Execute the agent turn
+    run_token = set_current_run(agent.id, run_row.id)
     try:
         created_rows = await runner.run_thread(db, thread)
     except Exception as exc:
@@ -86,6 +90,8 @@ async def execute_thread_run_with_history(
         },
     )
         raise
+    finally:
+        reset_current_run(run_token)
 
     # Success path
     end_ts = datetime.now(timezone.utc)
@@ -99,13 +105,30 @@ async def execute_thread_run_with_history(
     ):
         from zerg.pricing import get_usd_prices_per_1k
 
-        prices = get_usd_prices_per_1k(agent.model)
+        prices = get_usd_prices_per_1k(runner.model)
         if prices is not None:
             in_price, out_price = prices
             total_cost_usd = (
                 (runner.usage_prompt_tokens * in_price) + (runner.usage_completion_tokens * out_price)
             ) / 1000.0
 
+    # The reply's usage is kept with its final assistant message so the chat
+    # can show per-message footers and thread totals after a reload
+    final_assistant = next((row for row in reversed(created_rows) if row.role == "assistant"), None)
+    if final_assistant is not None:
+        final_assistant.message_metadata = {
+            **(final_assistant.message_metadata or {}),
+            "usage": {
+                "run_id": run_row.id,
+                "model": runner.model,
+                "prompt_tokens": getattr(runner, "usage_prompt_tokens", None),
+                "completion_tokens": getattr(runner, "usage_completion_tokens", None),
+                "total_tokens": total_tokens,
+                "duration_ms": duration_ms,
+                "cost_usd": total_cost_usd,
+            },
+        }
+
     # Mark run as finished (summary auto-extracted)
     finished_run = crud.mark_finished(
         db,
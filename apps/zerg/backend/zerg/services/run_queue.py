@@ -0,0 +1,173 @@
+"""
+Queue for agent runs that arrive while the agent is busy.
+
+Agents run one task at a time (``execute_agent_task`` holds an advisory lock
+for the whole run). When a schedule or trigger fires during an active run,
+the run is recorded as ``queued`` with a ``queue_position`` instead of being
+skipped, and the head of the queue starts as soon as the active run ends.
+The run's thread is only created when it starts, so a queued run that never
+starts leaves nothing behind. If the agent has been paused, or maintenance
+mode is on, by the time the queue is due, the queued runs are cancelled with
+the reason recorded, as a fresh schedule or trigger firing would be skipped.
+Users can reorder or drop queued runs; every change broadcasts the agent's
+full queue as ``run_queue_updated``.
+"""
+
+import asyncio
+import logging
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.database import db_session
+from zerg.events import EventType
+from zerg.events.event_bus import event_bus
+from zerg.models.enums import RunStatus
+from zerg.models.models import Agent as AgentModel
+from zerg.models.models import AgentRun as AgentRunModel
+from zerg.services.agent_locks import AgentAlreadyRunningError
+
+logger = logging.getLogger(__name__)
+
+# Further firings are dropped once this many runs are waiting
+MAX_QUEUED_RUNS = 10
+
+
+def queue_payload(agent_id: int, runs: List[AgentRunModel]) -> Dict[str, Any]:
+    return {
+        "event_type": "run_queue_updated",
+        "agent_id": agent_id,
+        "queue": [
+            {
+                "id": run.id,
+                "position": position,
+                "trigger": run.trigger.value,
+                "queued_at": run.created_at.isoformat() if run.created_at else None,
+            }
+            for position, run in enumerate(runs, start=1)
+        ],
+    }
+
+
+async def publish_queue(db: Session, agent_id: int) -> List[AgentRunModel]:
+    runs = crud.list_queued_runs(db, agent_id)
+    await event_bus.publish(EventType.RUN_QUEUE_UPDATED, queue_payload(agent_id, runs))
+    return runs
+
+
+async def enqueue_run(db: Session, agent: AgentModel, *, trigger: str) -> Optional[AgentRunModel]:
+    """Queue a run behind the active one. Returns ``None`` when the queue is full."""
+    queued = crud.list_queued_runs(db, agent.id)
+    if len(queued) >= MAX_QUEUED_RUNS:
+        logger.info("Run queue full for agent %s – dropping %s run", agent.id, trigger)
+        return None
+
+    run_row = crud.create_run(db, agent_id=agent.id, thread_id=None, trigger=trigger, status="queued")
+    run_row.queue_position = (queued[-1].queue_position if queued else 0) + 1
+    db.commit()
+
+    await event_bus.publish(
+        EventType.RUN_CREATED,
+        {
+            "event_type": "run_created",
+            "agent_id": agent.id,
+            "run_id": run_row.id,
+            "status": "queued",
+            "trigger": trigger,
+            "trace_id": run_row.trace_id,
+        },
+    )
+    await publish_queue(db, agent.id)
+    logger.info("Queued %s run %s for busy agent %s", trigger, run_row.id, agent.id)
+    return run_row
+
+
+async def drop_queued_run(db: Session, run: AgentRunModel, *, reason: str) -> AgentRunModel:
+    """Take *run* out of the queue and record it as ``cancelled``."""
+    row = await _cancel_queued_run(db, run, reason=reason)
+    remaining = crud.list_queued_runs(db, row.agent_id)
+    crud.set_queue_order(db, row.agent_id, [queued.id for queued in remaining])
+    await publish_queue(db, row.agent_id)
+    return row
+
+
+async def _cancel_queued_run(db: Session, run: AgentRunModel, *, reason: str) -> AgentRunModel:
+    # Never started, so there is no duration to record and no thread yet
+    row = crud.mark_cancelled(db, run.id, reason=reason)
+    await event_bus.publish(
+        EventType.RUN_UPDATED,
+        {
+            "event_type": "run_updated",
+            "agent_id": row.agent_id,
+            "run_id": row.id,
+            "status": "cancelled",
+            "finished_at": row.finished_at.isoformat(),
+        },
+    )
+    return row
+
+
+def start_next_if_queued(db: Session, agent_id: int) -> None:
+    """Schedule the head of the queue once the caller's run has released the agent."""
+    if not crud.list_queued_runs(db, agent_id):
+        return
+    # Runs after the caller returns, i.e. once the advisory lock is released
+    asyncio.get_running_loop().create_task(start_next_queued_run(agent_id))
+
+
+async def start_next_queued_run(agent_id: int) -> None:
+    from zerg.services.task_runner import execute_agent_task
+
+    with db_session() as db:
+        queued = crud.list_queued_runs(db, agent_id)
+        if not queued:
+            return
+        run_row = queued[0]
+        agent = crud.get_agent(db, agent_id)
+
+        block_reason = crud.automatic_run_block_reason(db, agent) if agent is not None else None
+        if block_reason:
+            logger.info("Cancelling %d queued runs for agent %s: %s", len(queued), agent_id, block_reason)
+            for run in queued:
+                await _cancel_queued_run(db, run, reason=f"Not started: {block_reason}")
+            await publish_queue(db, agent_id)
+            return
+
+        try:
+            if agent is None or agent.deleted_at is not None:
+                raise ValueError("Agent no longer exists")
+            await execute_agent_task(
+                db, agent, thread_type="schedule", trigger=run_row.trigger.value, queued_run=run_row
+            )
+        except AgentAlreadyRunningError:
+            # Another run got the lock first; it will start the queue when it ends
+            return
+        except Exception as exc:
+            # Once the run has started, execute_agent_task records the failure itself
+            if run_row.status == RunStatus.QUEUED:
+                await _fail_queued_run(db, run_row, str(exc))
+            else:
+                logger.info("Queued run %s for agent %s failed: %s", run_row.id, agent_id, exc)
+
+
+async def _fail_queued_run(db: Session, run_row: AgentRunModel, error: str) -> None:
+    """The run could not start (budget, kill switch…); fail it and move on to the next."""
+    logger.info("Queued run %s could not start: %s", run_row.id, error)
+    row = crud.mark_failed(db, run_row.id, error=error)
+    await event_bus.publish(
+        EventType.RUN_UPDATED,
+        {
+            "event_type": "run_updated",
+            "agent_id": row.agent_id,
+            "run_id": row.id,
+            "status": "failed",
+            "finished_at": row.finished_at.isoformat(),
+            "error": error,
+        },
+    )
+    await publish_queue(db, row.agent_id)
+    start_next_if_queued(db, row.agent_id)
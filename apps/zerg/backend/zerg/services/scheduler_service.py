@@ -21,6 +21,8 @@ from zerg.database import default_session_factory
 # EventBus remains for UI notifications
 from zerg.events import EventType
 from zerg.events.event_bus import event_bus
+from zerg.services import run_queue
+from zerg.services.agent_locks import AgentAlreadyRunningError
 
 # New unified task runner helper
 from zerg.services.task_runner import execute_agent_task
@@ -138,7 +140,9 @@ class SchedulerService:
                 # Query as plain tuples so ORM instances are never leaked outside
                 # this helper – allows us to close the session safely.
                 agent_rows: list[tuple[int, str]] = (
-                    db.query(crud.Agent.id, crud.Agent.schedule).filter(crud.Agent.schedule.isnot(None)).all()
+                    db.query(crud.Agent.id, crud.Agent.schedule)
+                    .filter(crud.Agent.schedule.isnot(None), crud.Agent.deleted_at.is_(None))
+                    .all()
                 )
         except Exception as exc:  # noqa: BLE001
             logger.error("Error loading scheduled agents: %s", exc)
@@ -226,20 +230,26 @@ class SchedulerService:
                 if agent is None:
                     logger.error("Agent %s not found", agent_id)
                     return
+                if agent.deleted_at is not None:
+                    logger.info("Skipping run for trashed agent %s", agent_id)
+                    return
+                # Paused agents and maintenance mode suspend schedules and triggers
+                block_reason = crud.automatic_run_block_reason(db, agent)
+                if block_reason:
+                    logger.info("Skipping %s run for agent %s – %s", trigger, agent_id, block_reason)
+                    return
 
                 # ------------------------------------------------------------------
                 # Delegate to shared helper (handles status flips & events).
-                # Scheduler runs silently skip if agent is already running.
+                # If the agent is already running the run waits in its queue.
                 # ------------------------------------------------------------------
                 logger.info("Running task for agent %s with trigger=%s", agent_id, trigger)
                 # Pass explicit trigger type to distinguish schedule vs webhook
                 try:
                     thread = await execute_agent_task(db, agent, thread_type="schedule", trigger=trigger)
-                except ValueError as exc:
-                    if "already running" in str(exc).lower():
-                        logger.info("Skipping scheduled run for agent %s - already running", agent_id)
-                        return
-                    raise
+                except AgentAlreadyRunningError:
+                    await run_queue.enqueue_run(db, agent, trigger=trigger)
+                    return
 
                 # ------------------------------------------------------------------
                 # Update *next_run_at* after successful run so dashboards show when
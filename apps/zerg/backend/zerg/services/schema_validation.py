@@ -0,0 +1,145 @@
+"""Expected JSON output for an agent, described as a JSON Schema.
+
+The schema lives under ``Agent.config["output_schema"]``.  When a run of such
+an agent finishes, its final reply is parsed as JSON and checked against the
+schema; the parsed value and any violations are stored on the run so the UI
+can render structured results and flag bad ones in the run history.
+
+The same machinery checks raw edits of an agent (the settings drawer's JSON
+editor) against the agent contract: the JSON Schema of ``AgentUpdate``.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from functools import lru_cache
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+from jsonschema import Draft202012Validator
+from jsonschema.exceptions import SchemaError
+from sqlalchemy.orm import Session
+
+from zerg.models.models import AgentRun
+from zerg.models.models import ThreadMessage
+from zerg.schemas.schemas import AgentUpdate
+
+# Key in Agent.config holding the schema
+AGENT_OUTPUT_SCHEMA_KEY = "output_schema"
+
+# Violations stored per run; the rest are summarised in one line
+MAX_SCHEMA_ERRORS = 10
+
+# Models like to wrap JSON in a Markdown code fence
+_CODE_FENCE = re.compile(r"^```[a-zA-Z0-9_-]*\s*\n(.*?)\n?```$", re.DOTALL)
+
+
+def validate_output_schema(schema: Any) -> Dict[str, Any]:
+    """Return *schema* if it is a valid JSON Schema object, else raise ``ValueError``."""
+
+    if not isinstance(schema, dict):
+        raise ValueError("'output_schema' must be a JSON object")
+    try:
+        Draft202012Validator.check_schema(schema)
+    except SchemaError as exc:
+        location = "/".join(str(part) for part in exc.path)
+        raise ValueError(f"Invalid output schema{f' at {location}' if location else ''}: {exc.message}") from exc
+    return schema
+
+
+def with_validated_output_schema(config: Optional[Dict[str, Any]]) -> Optional[Dict[str, Any]]:
+    """*config* with its ``output_schema`` validated; an empty schema is removed."""
+
+    if not config or AGENT_OUTPUT_SCHEMA_KEY not in config:
+        return config
+    schema = config[AGENT_OUTPUT_SCHEMA_KEY]
+    cleaned = {key: value for key, value in config.items() if key != AGENT_OUTPUT_SCHEMA_KEY}
+    if schema:
+        cleaned[AGENT_OUTPUT_SCHEMA_KEY] = validate_output_schema(schema)
+    return cleaned
+
+
+def agent_output_schema(agent: Any) -> Optional[Dict[str, Any]]:
+    config = getattr(agent, "config", None) or {}
+    schema = config.get(AGENT_OUTPUT_SCHEMA_KEY)
+    return schema if isinstance(schema, dict) and schema else None
+
+
+def parse_json_output(text: str) -> Tuple[Any, Optional[str]]:
+    """Parse *text* as JSON -> ``(value, None)`` or ``(None, reason)``."""
+
+    stripped = text.strip()
+    fenced = _CODE_FENCE.match(stripped)
+    if fenced:
+        stripped = fenced.group(1).strip()
+    try:
+        return json.loads(stripped), None
+    except json.JSONDecodeError as exc:
+        return None, f"Output is not valid JSON: {exc.msg} (line {exc.lineno}, column {exc.colno})"
+
+
+def validate_output(schema: Dict[str, Any], text: str) -> Tuple[Any, List[str]]:
+    """Check *text* against *schema* -> ``(parsed value or None, violations)``."""
+
+    value, parse_error = parse_json_output(text)
+    if parse_error:
+        return None, [parse_error]
+
+    return value, _violations(schema, value)
+
+
+def _violations(schema: Dict[str, Any], value: Any) -> List[str]:
+    errors = sorted(Draft202012Validator(schema).iter_errors(value), key=lambda error: list(error.path))
+    messages = [f"{error.json_path}: {error.message}" for error in errors[:MAX_SCHEMA_ERRORS]]
+    if len(errors) > MAX_SCHEMA_ERRORS:
+        messages.append(f"…and {len(errors) - MAX_SCHEMA_ERRORS} more")
+    return messages
+
+
+@lru_cache(maxsize=1)
+def agent_contract_schema() -> Dict[str, Any]:
+    """JSON Schema of the fields an agent edit may set; unknown keys are rejected."""
+
+    return {**AgentUpdate.model_json_schema(), "additionalProperties": False}
+
+
+def validate_agent_patch(patch: Any) -> List[str]:
+    """Violations of the agent contract in *patch*, including a bad ``config.output_schema``."""
+
+    if not isinstance(patch, dict):
+        return ["$: the edit must be a JSON object"]
+    messages = _violations(agent_contract_schema(), patch)
+    config = patch.get("config")
+    if not messages and isinstance(config, dict) and config.get(AGENT_OUTPUT_SCHEMA_KEY):
+        try:
+            validate_output_schema(config[AGENT_OUTPUT_SCHEMA_KEY])
+        except ValueError as exc:
+            messages.append(f"$.config.{AGENT_OUTPUT_SCHEMA_KEY}: {exc}")
+    return messages
+
+
+def _final_reply(db: Session, thread_id: int) -> Optional[str]:
+    row = (
+        db.query(ThreadMessage)
+        .filter(ThreadMessage.thread_id == thread_id, ThreadMessage.role == "assistant")
+        .order_by(ThreadMessage.id.desc())
+        .first()
+    )
+    return row.content if row is not None and isinstance(row.content, str) else None
+
+
+def check_run_output(db: Session, run: AgentRun) -> None:
+    """Record the structured output and schema violations of a finished *run* (no commit)."""
+
+    schema = agent_output_schema(run.agent)
+    if schema is None:
+        return
+    reply = _final_reply(db, run.thread_id)
+    if reply is None:
+        run.output, run.schema_errors = None, ["The run produced no reply"]
+        return
+    run.output, run.schema_errors = validate_output(schema, reply)
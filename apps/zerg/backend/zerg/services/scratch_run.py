@@ -0,0 +1,90 @@
+"""Run an agent on one message without leaving a trace in its history.
+
+Used by the test harness and model comparison: the message goes to a
+throwaway thread that is deleted as soon as the reply is in, and no
+``AgentRun`` row is written.  The agent still runs for real – tools
+included – so any side effects those tools have still happen.
+"""
+
+from __future__ import annotations
+
+import logging
+import time
+from dataclasses import dataclass
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from zerg.crud import crud
+from zerg.managers.agent_runner import AgentRunner
+from zerg.models.models import Agent
+from zerg.pricing import get_usd_prices_per_1k
+from zerg.services.thread_service import ThreadService
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class ScratchRunResult:
+    model: str
+    output: str
+    # Set when the agent failed to produce a reply
+    error: Optional[str]
+    duration_ms: int
+    prompt_tokens: Optional[int] = None
+    completion_tokens: Optional[int] = None
+    total_tokens: Optional[int] = None
+    # None when the model has no known pricing
+    cost_usd: Optional[float] = None
+
+
+def _cost_usd(model: str, runner: AgentRunner) -> Optional[float]:
+    if runner.usage_prompt_tokens is None or runner.usage_completion_tokens is None:
+        return None
+    prices = get_usd_prices_per_1k(model)
+    if prices is None:
+        return None
+    in_price, out_price = prices
+    return (runner.usage_prompt_tokens * in_price + runner.usage_completion_tokens * out_price) / 1000.0
+
+
+async def run_scratch_message(
+    db: Session,
+    agent: Agent,
+    message: str,
+    *,
+    title: str,
+    model: Optional[str] = None,
+) -> ScratchRunResult:
+    """Send *message* to *agent* (optionally on another *model*) and return its final reply."""
+    model_name = model or agent.model
+    started = time.monotonic()
+    thread = ThreadService.create_thread_with_system_message(
+        db, agent, title=title, thread_type="manual", active=False
+    )
+    crud.create_thread_message(db=db, thread_id=thread.id, role="user", content=message, processed=False)
+
+    runner: Optional[AgentRunner] = None
+    output = ""
+    error = None
+    try:
+        runner = AgentRunner(agent, model_override=model)
+        created = await runner.run_thread(db, thread)
+        replies = [row.content for row in created if row.role == "assistant" and row.content]
+        output = replies[-1] if replies else ""
+    except Exception as exc:  # noqa: BLE001 – reported to the caller, not raised
+        logger.warning("Scratch run of agent %s on %s failed: %s", agent.id, model_name, exc)
+        error = str(exc) or exc.__class__.__name__
+    finally:
+        crud.delete_thread(db, thread.id)
+
+    return ScratchRunResult(
+        model=model_name,
+        output=output,
+        error=error,
+        duration_ms=int((time.monotonic() - started) * 1000),
+        prompt_tokens=runner.usage_prompt_tokens if runner else None,
+        completion_tokens=runner.usage_completion_tokens if runner else None,
+        total_tokens=runner.usage_total_tokens if runner else None,
+        cost_usd=_cost_usd(model_name, runner) if runner else None,
+    )
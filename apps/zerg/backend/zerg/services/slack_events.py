@@ -0,0 +1,105 @@
+"""Slack Events API helpers for Slack-event triggers.
+
+The connected Slack app (see ``routers/oauth.py``) delivers workspace events
+to ``/api/slack/events``.  Each delivery is matched against the ``slack``
+triggers of every account connected to that workspace; a trigger's config
+picks the event type and, optionally, a channel and a keyword:
+
+    {"event_type": "app_mention", "channel": "C0123456", "keyword": "deploy"}
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from zerg.models.models import AccountConnectorCredential
+from zerg.models.models import Agent
+from zerg.models.models import Trigger
+
+SLACK_EVENT_TYPES = ("message", "app_mention", "reaction_added")
+
+
+def verify_slack_signature(secret: str, timestamp: str, body: bytes, signature: str) -> bool:
+    """Check Slack's ``X-Slack-Signature`` (``v0=`` HMAC-SHA256 of ``v0:{timestamp}:{body}``)."""
+    base = b"v0:" + timestamp.encode() + b":" + body
+    expected = "v0=" + hmac.new(secret.encode(), base, hashlib.sha256).hexdigest()
+    return hmac.compare_digest(expected, signature or "")
+
+
+def slack_connection(db: Session, owner_id: int) -> Optional[AccountConnectorCredential]:
+    """The owner's Slack credential when it came from the OAuth app install."""
+    cred = (
+        db.query(AccountConnectorCredential)
+        .filter(
+            AccountConnectorCredential.owner_id == owner_id,
+            AccountConnectorCredential.connector_type == "slack",
+        )
+        .first()
+    )
+    if cred is None or not (cred.connector_metadata or {}).get("team_id"):
+        return None
+    return cred
+
+
+def owners_for_team(db: Session, team_id: str) -> List[int]:
+    creds = db.query(AccountConnectorCredential).filter(AccountConnectorCredential.connector_type == "slack").all()
+    return [cred.owner_id for cred in creds if (cred.connector_metadata or {}).get("team_id") == team_id]
+
+
+def _event_channel(event: Dict[str, Any]) -> Optional[str]:
+    if event.get("type") == "reaction_added":
+        return (event.get("item") or {}).get("channel")
+    return event.get("channel")
+
+
+def is_actionable(event: Dict[str, Any]) -> bool:
+    """Skip bot posts (including our own, which would loop) and edits/deletes."""
+    if event.get("bot_id") or event.get("subtype"):
+        return False
+    return event.get("type") in SLACK_EVENT_TYPES
+
+
+def trigger_matches(config: Optional[Dict[str, Any]], event: Dict[str, Any]) -> bool:
+    cfg = config or {}
+    if cfg.get("event_type") != event.get("type"):
+        return False
+    channel = cfg.get("channel")
+    if channel and channel != _event_channel(event):
+        return False
+    keyword = (cfg.get("keyword") or "").strip().lower()
+    if keyword:
+        text = event.get("reaction") if event.get("type") == "reaction_added" else event.get("text")
+        if keyword not in (text or "").lower():
+            return False
+    return True
+
+
+def match_summary(config: Optional[Dict[str, Any]], event: Dict[str, Any]) -> str:
+    """What a matching event satisfied, for the trigger's delivery history."""
+    cfg = config or {}
+    parts = [str(event.get("type"))]
+    if cfg.get("channel"):
+        parts.append(f"in {cfg['channel']}")
+    if cfg.get("keyword"):
+        parts.append(f"containing '{cfg['keyword']}'")
+    return " ".join(parts)
+
+
+def matching_triggers(db: Session, team_id: str, event: Dict[str, Any]) -> List[Trigger]:
+    owner_ids = owners_for_team(db, team_id) if team_id else []
+    if not owner_ids or not is_actionable(event):
+        return []
+    triggers = (
+        db.query(Trigger)
+        .join(Agent, Agent.id == Trigger.agent_id)
+        .filter(Trigger.type == "slack", Agent.owner_id.in_(owner_ids), Agent.deleted_at.is_(None))
+        .all()
+    )
+    return [trg for trg in triggers if trigger_matches(trg.config, event)]
@@ -0,0 +1,135 @@
+"""
+Call Workflow ("subworkflow") nodes.
+
+A subworkflow node runs another of the owner's workflows as a child
+execution. The node's ``inputs`` map is resolved against upstream outputs and
+handed to the child, whose nodes read it as ``${input.value.<name>}``; the
+``outputs`` map is resolved against the child's node outputs once it finishes.
+
+Calls are bounded: a workflow can't (indirectly) call itself, and chains stop
+at ``MAX_SUBWORKFLOW_DEPTH``. The canvas validator checks the saved call graph
+and the executor re-checks the live parent chain before starting a child.
+"""
+
+from typing import Any
+from typing import Dict
+from typing import Iterable
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+from sqlalchemy.orm import Session
+
+from zerg.models.models import Workflow
+from zerg.models.models import WorkflowExecution
+from zerg.services.variable_resolver import resolve_variables
+
+# Child workflows read the node's mapped inputs as ${input.value.<name>}
+WORKFLOW_INPUT_KEY = "input"
+
+# Executions nested below a top-level run, counting the run itself
+MAX_SUBWORKFLOW_DEPTH = 5
+
+
+def subworkflow_target(config: Dict[str, Any]) -> Optional[int]:
+    target = config.get("workflow_id")
+    try:
+        return int(target) if target not in (None, "") else None
+    except (TypeError, ValueError):
+        return None
+
+
+def subworkflow_calls(nodes: Iterable[Any]) -> List[Tuple[str, Optional[int]]]:
+    """(node id, target workflow id) for each subworkflow node; accepts node dicts or models."""
+    calls = []
+    for node in nodes:
+        data = node if isinstance(node, dict) else node.model_dump()
+        if data.get("type") == "subworkflow":
+            calls.append((data.get("id"), subworkflow_target(data.get("config") or {})))
+    return calls
+
+
+def input_envelope(inputs: Dict[str, Any]) -> Dict[str, Any]:
+    """Seed output the child's nodes resolve ${input.value...} against."""
+    return {"value": inputs, "meta": {"node_type": "trigger", "phase": "finished", "result": "success"}}
+
+
+def _owned_workflow(db: Session, owner_id: int, workflow_id: int) -> Optional[Workflow]:
+    return db.query(Workflow).filter_by(id=workflow_id, owner_id=owner_id, is_active=True).first()
+
+
+def _find_bad_chain(db: Session, owner_id: int, workflow_id: int, path: List[int]) -> Optional[Tuple[str, List[int]]]:
+    path = path + [workflow_id]
+    if workflow_id in path[:-1]:
+        return "SUBWORKFLOW_RECURSION", path
+    if len(path) > MAX_SUBWORKFLOW_DEPTH:
+        return "SUBWORKFLOW_TOO_DEEP", path
+
+    workflow = _owned_workflow(db, owner_id, workflow_id)
+    if workflow is None:
+        return None
+    for _, target in subworkflow_calls((workflow.canvas or {}).get("nodes", [])):
+        if target is not None:
+            problem = _find_bad_chain(db, owner_id, target, path)
+            if problem:
+                return problem
+    return None
+
+
+def validate_subworkflow_calls(
+    db: Session, owner_id: int, nodes: Iterable[Any], workflow_id: Optional[int] = None
+) -> List[Dict[str, Any]]:
+    """Errors for subworkflow nodes on a canvas, in the /workflows/validate error format."""
+    errors = []
+    for node_id, target in subworkflow_calls(nodes):
+        if target is None:
+            errors.append(_error("SUBWORKFLOW_MISSING_TARGET", "Choose a workflow to call", node_id))
+            continue
+        if _owned_workflow(db, owner_id, target) is None:
+            errors.append(_error("SUBWORKFLOW_NOT_FOUND", f"Workflow {target} not found", node_id))
+            continue
+
+        problem = _find_bad_chain(db, owner_id, target, [workflow_id] if workflow_id is not None else [])
+        if problem:
+            code, chain = problem
+            route = " → ".join(str(workflow) for workflow in chain)
+            message = (
+                f"Workflow calls itself: {route}"
+                if code == "SUBWORKFLOW_RECURSION"
+                else f"Workflow calls nest deeper than {MAX_SUBWORKFLOW_DEPTH} levels: {route}"
+            )
+            errors.append(_error(code, message, node_id))
+    return errors
+
+
+def _error(code: str, message: str, node_id: str) -> Dict[str, Any]:
+    return {"code": code, "message": message, "node_id": node_id, "severity": "error"}
+
+
+def check_call_allowed(db: Session, execution: WorkflowExecution, target_id: int) -> Workflow:
+    """The workflow *execution* may start as a child, or ValueError explaining why not."""
+    owner_id = execution.workflow.owner_id
+    target = _owned_workflow(db, owner_id, target_id)
+    if target is None:
+        raise ValueError(f"Workflow {target_id} not found")
+
+    ancestors = [execution.workflow_id]
+    current = execution
+    while current.parent_execution_id is not None:
+        current = db.query(WorkflowExecution).filter_by(id=current.parent_execution_id).first()
+        if current is None:
+            break
+        ancestors.append(current.workflow_id)
+
+    if target_id in ancestors:
+        raise ValueError(f"Workflow {target_id} is already running in this call chain")
+    if len(ancestors) >= MAX_SUBWORKFLOW_DEPTH:
+        raise ValueError(f"Workflow calls can nest at most {MAX_SUBWORKFLOW_DEPTH} levels")
+    return target
+
+
+def map_outputs(outputs: Dict[str, Any], child_outputs: Dict[str, Any]) -> Dict[str, Any]:
+    """Resolve the node's output map against the child's node outputs; unmapped calls return every node's value."""
+    if not outputs:
+        return {node_id: output.get("value") for node_id, output in child_outputs.items() if isinstance(output, dict)}
+    return resolve_variables(outputs, child_outputs)
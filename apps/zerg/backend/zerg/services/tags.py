@@ -0,0 +1,93 @@
+"""Free-form tags on agents and workflows.
+
+Tags are plain strings kept on each row (``agents.tags``, ``workflows.tags``)
+rather than in a table of their own, so a tag exists for as long as something
+carries it. Tags compare case-insensitively; the first spelling wins. Renaming
+a tag onto one that already exists merges the two.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Dict
+from typing import Iterable
+from typing import List
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from zerg.models.models import Agent
+from zerg.models.models import Workflow
+
+MAX_TAG_LENGTH = 40
+MAX_TAGS = 20
+
+_WHITESPACE = re.compile(r"\s+")
+
+
+def normalize_tag(tag: str) -> str:
+    """*tag* trimmed with inner whitespace collapsed, or raise ``ValueError``."""
+    tag = _WHITESPACE.sub(" ", tag or "").strip()
+    if not tag:
+        raise ValueError("Tags can't be empty")
+    if len(tag) > MAX_TAG_LENGTH:
+        raise ValueError(f"Tags are at most {MAX_TAG_LENGTH} characters")
+    return tag
+
+
+def normalize_tags(tags: Iterable[str]) -> List[str]:
+    """Normalised tags in their original order, without case-insensitive duplicates."""
+    seen = set()
+    result = []
+    for tag in tags:
+        tag = normalize_tag(tag)
+        if tag.lower() in seen:
+            continue
+        seen.add(tag.lower())
+        result.append(tag)
+    if len(result) > MAX_TAGS:
+        raise ValueError(f"At most {MAX_TAGS} tags")
+    return result
+
+
+def _tagged_rows(db: Session, owner_id: int):
+    # Trashed rows keep their tags so they come back tagged when restored
+    yield from db.query(Agent).filter(Agent.owner_id == owner_id).all()
+    yield from db.query(Workflow).filter(Workflow.owner_id == owner_id).all()
+
+
+def list_tags(db: Session, owner_id: int) -> List[Dict[str, object]]:
+    """The owner's tags with how many live agents and workflows carry each, by name."""
+    counts: Dict[str, Dict[str, object]] = {}
+    for row in _tagged_rows(db, owner_id):
+        if row.deleted_at is not None:
+            continue
+        kind = "agent_count" if isinstance(row, Agent) else "workflow_count"
+        for tag in row.tags or []:
+            entry = counts.setdefault(tag.lower(), {"name": tag, "agent_count": 0, "workflow_count": 0})
+            entry[kind] += 1
+    return sorted(counts.values(), key=lambda entry: str(entry["name"]).lower())
+
+
+def _retag(db: Session, owner_id: int, tag: str, replacement: Optional[str]) -> int:
+    key = tag.strip().lower()
+    changed = 0
+    for row in _tagged_rows(db, owner_id):
+        current = list(row.tags or [])
+        if not any(existing.lower() == key for existing in current):
+            continue
+        swapped = [replacement if existing.lower() == key else existing for existing in current]
+        row.tags = normalize_tags(existing for existing in swapped if existing is not None) or None
+        changed += 1
+    db.commit()
+    return changed
+
+
+def rename_tag(db: Session, owner_id: int, tag: str, new_name: str) -> int:
+    """Rename *tag* on every agent and workflow of the owner; returns how many changed."""
+    return _retag(db, owner_id, tag, normalize_tag(new_name))
+
+
+def delete_tag(db: Session, owner_id: int, tag: str) -> int:
+    """Remove *tag* from every agent and workflow of the owner; returns how many changed."""
+    return _retag(db, owner_id, tag, None)
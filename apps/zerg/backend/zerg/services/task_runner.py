@@ -21,6 +21,7 @@ SchedulerService) and removes the last dependency on the legacy
 
 from __future__ import annotations
 
+import asyncio
 import logging
 from datetime import datetime
 from datetime import timezone
@@ -34,8 +35,15 @@ from zerg.events import EventType
 from zerg.events.event_bus import event_bus
 from zerg.managers.agent_runner import AgentRunner
 from zerg.models.models import Agent as AgentModel
+from zerg.models.models import AgentRun as AgentRunModel
 from zerg.models.models import Thread as ThreadModel
+from zerg.services.env_vars import render_agent_instructions
+from zerg.services.quota import assert_agent_within_budget
 from zerg.services.quota import assert_can_start_run
+from zerg.services import run_queue
+from zerg.services.run_cancellation import run_cancellations
+from zerg.services.run_events import reset_current_run
+from zerg.services.run_events import set_current_run
 from zerg.services.thread_service import ThreadService
 
 logger = logging.getLogger(__name__)
@@ -46,7 +54,12 @@ logger = logging.getLogger(__name__)
 
 
 async def execute_agent_task(
-    db: Session, agent: AgentModel, *, thread_type: str = "manual", trigger: str | None = None
+    db: Session,
+    agent: AgentModel,
+    *,
+    thread_type: str = "manual",
+    trigger: str | None = None,
+    queued_run: AgentRunModel | None = None,
 ) -> ThreadModel:
     """Run *agent.task_instructions* exactly once and return the created thread.
 
@@ -62,6 +75,9 @@ async def execute_agent_task(
     trigger
         Optional explicit trigger type. If not provided, inferred from thread_type.
         One of: "manual", "schedule", "chat", "webhook", "api".
+    queued_run
+        A run from the agent's queue (see :pymod:`zerg.services.run_queue`) to
+        start instead of creating a new run row; its thread is created now.
 
     Raises
     ------
@@ -85,6 +101,9 @@ async def execute_agent_task(
     if owner:
         assert_can_start_run(db, user=owner)
 
+    # Per-agent spend cap (applies to everyone, admins included)
+    assert_agent_within_budget(db, agent)
+
     # ------------------------------------------------------------------
     # Validate pre-conditions
     # ------------------------------------------------------------------
@@ -98,12 +117,13 @@ async def execute_agent_task(
     use_advisory = bool(getattr(db.bind, "dialect", None) and db.bind.dialect.name == "postgresql")
 
     if use_advisory:
+        from zerg.services.agent_locks import AgentAlreadyRunningError
         from zerg.services.agent_locks import AgentLockManager
 
         # Hold the advisory lock for the entire run window.
         with AgentLockManager.agent_lock(db, agent.id) as acquired:
             if not acquired:
-                raise ValueError("Agent already running")
+                raise AgentAlreadyRunningError("Agent already running")
 
             # Persist status for UI/telemetry while the advisory lock enforces exclusivity
             crud.update_agent(db, agent.id, status="running")
@@ -115,7 +135,8 @@ async def execute_agent_task(
 
             # Proceed with execution inside the lock scope
             # ------------------------------------------------------------------
-            # Create the new thread + seed messages.
+            # Create the new thread + seed messages.  Queued runs get theirs
+            # here too, at start time, so dropped runs leave no empty thread.
             # ------------------------------------------------------------------
             timestamp_str = datetime.now(timezone.utc).strftime("%Y-%m-%d %H:%M:%S")
             title = f"Task Run – {timestamp_str}"
@@ -133,7 +154,7 @@ async def execute_agent_task(
                 db=db,
                 thread_id=thread.id,
                 role="user",
-                content=agent.task_instructions,
+                content=render_agent_instructions(db, agent, agent.task_instructions),
                 processed=False,
             )
 
@@ -142,28 +163,36 @@ async def execute_agent_task(
             # ------------------------------------------------------------------
             # Use explicit trigger if provided, otherwise infer from thread_type
             run_trigger = trigger if trigger else (thread_type if thread_type in {"manual", "schedule"} else "api")
-            run_row = crud.create_run(
-                db,
-                agent_id=agent.id,
-                thread_id=thread.id,
-                trigger=run_trigger,
-                status="queued",
-            )
+            if queued_run is not None:
+                run_row = queued_run
+                run_row.thread_id = thread.id
+                db.commit()
+            else:
+                run_row = crud.create_run(
+                    db,
+                    agent_id=agent.id,
+                    thread_id=thread.id,
+                    trigger=run_trigger,
+                    status="queued",
+                )
 
-            await event_bus.publish(
-                EventType.RUN_CREATED,
-                {
-                    "event_type": "run_created",
-                    "agent_id": agent.id,
-                    "run_id": run_row.id,
-                    "status": "queued",
-                    "thread_id": thread.id,
-                },
-            )
+                await event_bus.publish(
+                    EventType.RUN_CREATED,
+                    {
+                        "event_type": "run_created",
+                        "agent_id": agent.id,
+                        "run_id": run_row.id,
+                        "status": "queued",
+                        "thread_id": thread.id,
+                        "trace_id": run_row.trace_id,
+                    },
+                )
 
-            # Immediately mark as running (no async queue yet)
+            # Mark as running straight away; mark_running also takes a queued run off the queue
             start_ts = datetime.now(timezone.utc)
             crud.mark_running(db, run_row.id, started_at=start_ts)
+            if queued_run is not None:
+                await run_queue.publish_queue(db, agent.id)
             await event_bus.publish(
                 EventType.RUN_UPDATED,
                 {
@@ -185,8 +214,48 @@ async def execute_agent_task(
             set_current_user_id(agent.owner_id)
 
             try:
+                # Own task so POST /runs/{id}/cancel can interrupt it between steps
+                # The task runs in a copy of the context, so the run only needs binding while it's created
+                run_token = set_current_run(agent.id, run_row.id)
                 try:
-                    await runner.run_thread(db, thread)
+                    run_task = asyncio.ensure_future(runner.run_thread(db, thread))
+                finally:
+                    reset_current_run(run_token)
+                run_cancellations.register(run_row.id, run_task)
+                try:
+                    await run_task
+
+                except asyncio.CancelledError:
+                    if not run_cancellations.was_requested(run_row.id):
+                        raise
+                    end_ts = datetime.now(timezone.utc)
+                    duration_ms = int((end_ts - start_ts).total_seconds() * 1000)
+                    crud.mark_cancelled(db, run_row.id, finished_at=end_ts, duration_ms=duration_ms)
+
+                    await event_bus.publish(
+                        EventType.RUN_UPDATED,
+                        {
+                            "event_type": "run_updated",
+                            "agent_id": agent.id,
+                            "run_id": run_row.id,
+                            "status": "cancelled",
+                            "finished_at": end_ts.isoformat(),
+                            "duration_ms": duration_ms,
+                            "thread_id": thread.id,
+                        },
+                    )
+
+                    # A cancelled run is not an agent error
+                    crud.update_agent(db, agent.id, status="idle")
+                    db.commit()
+
+                    await event_bus.publish(
+                        EventType.AGENT_UPDATED,
+                        {"event_type": "agent_updated", "id": agent.id, "status": "idle"},
+                    )
+
+                    logger.info("Task run %s cancelled for agent %s", run_row.id, agent.id)
+                    return thread
 
                 except Exception as exc:
                     # Persist run failure first
@@ -293,8 +362,13 @@ async def execute_agent_task(
 
                 return thread
             finally:
+                run_cancellations.discard(run_row.id)
                 # Always clean up user context
                 set_current_user_id(None)
+                try:
+                    run_queue.start_next_if_queued(db, agent.id)
+                except Exception:
+                    logger.exception("Could not start the next queued run for agent %s", agent.id)
 
     # If we are here, the database is not PostgreSQL; this app requires
     # PostgreSQL for advisory locks. Simplify by failing fast.
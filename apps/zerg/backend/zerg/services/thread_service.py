@@ -31,6 +31,7 @@ from zerg.crud import crud
 from zerg.models.models import Agent as AgentModel
 from zerg.models.models import Thread as ThreadModel
 from zerg.models.models import ThreadMessage as ThreadMessageModel
+from zerg.services.env_vars import render_agent_instructions
 
 logger = logging.getLogger(__name__)
 
@@ -69,6 +70,11 @@ def _db_to_langchain(msg_row: ThreadMessageModel) -> BaseMessage:  # pragma: no
     if role == "system":
         return SystemMessage(content=content)
     if role == "user":
+        # The model can't open uploads, but it should know they were sent
+        attachments = (getattr(msg_row, "message_metadata", None) or {}).get("attachments") or []
+        if attachments:
+            names = ", ".join(f"{att.get('filename')} ({att.get('content_type')})" for att in attachments)
+            content = f"{content}\n\n[Attachments: {names}]"
         return HumanMessage(content=content)
     if role == "assistant":
         # Assistant messages may or may not include tool calls.
@@ -207,7 +213,7 @@ class ThreadService:
             db=db,
             thread_id=thread.id,
             role="system",
-            content=agent.system_instructions,
+            content=render_agent_instructions(db, agent, agent.system_instructions),
             processed=True,  # System messages are implicitly processed
         )
 
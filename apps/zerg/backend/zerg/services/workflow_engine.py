@@ -28,8 +28,20 @@ from zerg.models.enums import FailureKind
 from zerg.models.models import Workflow
 from zerg.models.models import WorkflowExecution
 from zerg.schemas.workflow import WorkflowData
+from zerg.services.api_credentials import with_credential_headers
+from zerg.services.execution_control import ExecutionController
+from zerg.services.execution_control import execution_controls
 from zerg.services.execution_state import ExecutionStateMachine
+from zerg.services.env_vars import load_env
+from zerg.services.env_vars import render_env
+from zerg.services.env_vars import warn_undefined_env
 from zerg.services.node_executors import create_node_executor
+from zerg.services.node_executors import loop_max_iterations
+from zerg.services.partial_runs import cached_outputs
+from zerg.services.partial_runs import plan_partial_run
+from zerg.services.partial_runs import record_reused_outputs
+from zerg.services.subworkflows import WORKFLOW_INPUT_KEY
+from zerg.services.subworkflows import input_envelope
 from zerg.utils.time import utc_now_naive
 from zerg.websocket.langgraph_mapper import LangGraphMapper
 
@@ -46,6 +58,40 @@ def first_error(left: Union[str, None], right: Union[str, None]) -> Union[str, N
     return left if left is not None else right
 
 
+def _node_config_renderer(owner_id: int, env: Dict[str, str]):
+    """Config renderer for node executors: env references, then an HTTP node's credential header."""
+
+    def render(db, node) -> Dict[str, Any]:
+        config = render_env(node.config, env)
+        return with_credential_headers(db, owner_id, config) if node.type == "http" else config
+
+    return render
+
+
+# Branch taken by an outgoing edge of a control-flow node that doesn't name one
+DEFAULT_BRANCHES = {"conditional": "true", "switch": "default", "loop": "done"}
+
+
+def make_branch_router(source_node_id: str, targets_by_branch: Dict[str, List[str]]):
+    """Route to every target of the branch the control-flow node chose (envelope ``value.branch``)."""
+
+    def branch_router(state):
+        output = state["node_outputs"].get(source_node_id)
+        value = output.get("value") if isinstance(output, dict) else None
+        branch = value.get("branch") if isinstance(value, dict) else None
+        targets = targets_by_branch.get(str(branch)) if branch is not None else None
+        return targets or END  # End this path if the chosen branch isn't connected
+
+    return branch_router
+
+
+def recursion_limit(workflow_data: WorkflowData) -> int:
+    """LangGraph step budget: every loop may re-run the whole graph once per iteration."""
+
+    iterations = sum(loop_max_iterations(node.config) for node in workflow_data.nodes if node.type == "loop")
+    return max(25, (len(workflow_data.nodes) + 1) * (iterations + 1))
+
+
 class WorkflowState(TypedDict):
     """State passed between nodes in the workflow.
 
@@ -69,8 +115,20 @@ class WorkflowEngine:
         """Initialize the workflow engine with task tracking."""
         self._running_tasks: Dict[int, asyncio.Task] = {}
 
-    async def execute_workflow(self, workflow_id: int, trigger_type: str = "manual") -> int:
-        """Execute workflow and return execution ID."""
+    async def execute_workflow(
+        self,
+        workflow_id: int,
+        trigger_type: str = "manual",
+        *,
+        inputs: Optional[Dict[str, Any]] = None,
+        parent_execution_id: Optional[int] = None,
+        parent_node_id: Optional[str] = None,
+    ) -> int:
+        """Execute workflow and return execution ID.
+
+        ``inputs`` are readable by every node as ``${input.value.<name>}``; Call Workflow
+        nodes pass them along with the parent execution and node that started the run.
+        """
         logger.info(f"[WorkflowEngine] Starting execution – workflow_id={workflow_id}")
 
         session_factory = get_session_factory()
@@ -80,6 +138,9 @@ class WorkflowEngine:
                 workflow_id=workflow_id,
                 started_at=utc_now_naive(),
                 triggered_by=trigger_type,
+                parent_execution_id=parent_execution_id,
+                parent_node_id=parent_node_id,
+                inputs=inputs,
             )
             db.add(execution)
             db.commit()
@@ -89,7 +150,7 @@ class WorkflowEngine:
                 ExecutionStateMachine.mark_running(execution)
                 db.commit()
 
-                await self._execute_workflow_internal(workflow_id, execution, db)
+                await self._execute_workflow_internal(workflow_id, execution, db, inputs=inputs)
                 return execution.id
             except Exception as e:
                 # Mark as failed using state machine only if not already finished
@@ -103,8 +164,12 @@ class WorkflowEngine:
                 )
                 logger.exception(f"[WorkflowEngine] Execution failed – execution_id={execution.id}")
                 raise
+            finally:
+                execution_controls.discard(execution.id)
 
-    async def _execute_workflow_internal(self, workflow_id: int, execution: WorkflowExecution, db):
+    async def _execute_workflow_internal(
+        self, workflow_id: int, execution: WorkflowExecution, db, inputs: Optional[Dict[str, Any]] = None
+    ):
         """Load and execute workflow."""
         # Load workflow
         workflow_model = db.query(Workflow).filter_by(id=workflow_id, is_active=True).first()
@@ -117,6 +182,12 @@ class WorkflowEngine:
         except Exception as e:
             raise ValueError(f"Invalid workflow data: {e}")
 
+        # {{env.NAME}} references and API credential headers are filled in as each
+        # node runs; the nodes themselves (and pause events) keep them as written
+        env = load_env(db, workflow_model.owner_id, workflow_id)
+        warn_undefined_env(workflow_data, env)
+        render_config = _node_config_renderer(workflow_model.owner_id, env)
+
         # Handle empty workflows
         if not workflow_data.nodes:
             logger.info(f"[WorkflowEngine] Empty workflow {workflow_id} - completing immediately")
@@ -134,25 +205,51 @@ class WorkflowEngine:
             )
             return
 
+        # Runs started from a node skip everything else and reuse the source execution's outputs
+        entry_node_ids = None
+        seed_outputs: Dict[str, Any] = {}
+        if execution.start_node_id:
+            plan = plan_partial_run(
+                workflow_data, execution.start_node_id, cached_outputs(db, execution.source_execution_id)
+            )
+            workflow_data, seed_outputs = plan.workflow, plan.seed_outputs
+            entry_node_ids = [execution.start_node_id]
+            record_reused_outputs(db, execution.id, seed_outputs)
+
         # Build and execute graph
-        graph = self._build_langgraph(workflow_data, execution.id)
-        await self._execute_graph(graph, execution, db, workflow_id)
+        graph = self._build_langgraph(
+            workflow_data, execution.id, entry_node_ids=entry_node_ids, render_config=render_config
+        )
+        await self._execute_graph(graph, execution, db, workflow_id, inputs=inputs, seed_outputs=seed_outputs)
+
+    def _build_langgraph(
+        self,
+        workflow_data: WorkflowData,
+        execution_id: int,
+        entry_node_ids: Optional[List[str]] = None,
+        render_config=None,
+    ):
+        """Build LangGraph from WorkflowData.
 
-    def _build_langgraph(self, workflow_data: WorkflowData, execution_id: int):
-        """Build LangGraph from WorkflowData."""
+        The graph starts at the nodes without incoming edges, or at *entry_node_ids* when given.
+        """
         graph = StateGraph(WorkflowState)
+        controller = execution_controls.get(execution_id)
+        if controller is None and any(node.config.get("breakpoint") for node in workflow_data.nodes):
+            controller = execution_controls.create(execution_id)
 
         # Add nodes (execution_id will be passed via config, not state)
         for node in workflow_data.nodes:
-            executor = create_node_executor(node, self._publish_node_event)
-            graph.add_node(node.id, executor.execute)
+            executor = create_node_executor(node, self._publish_node_event, render_config)
+            run = executor.execute if controller is None else self._gated(executor, controller)
+            graph.add_node(node.id, run)
             logger.info(f"[WorkflowEngine] Added node: {node.id} (type: {node.type})")
 
         # Find start and end nodes
         target_nodes = {edge.to_node_id for edge in workflow_data.edges}
         source_nodes = {edge.from_node_id for edge in workflow_data.edges}
 
-        start_nodes = [node.id for node in workflow_data.nodes if node.id not in target_nodes]
+        start_nodes = entry_node_ids or [node.id for node in workflow_data.nodes if node.id not in target_nodes]
         end_nodes = [node.id for node in workflow_data.nodes if node.id not in source_nodes]
 
         logger.info(f"[WorkflowEngine] Start nodes: {start_nodes}, End nodes: {end_nodes}")
@@ -170,50 +267,24 @@ class WorkflowEngine:
                 edges_by_source[edge.from_node_id] = []
             edges_by_source[edge.from_node_id].append(edge)
 
-        # Add edges with conditional routing support
+        # Add edges with branch routing for control-flow nodes
         for source_node_id, edges in edges_by_source.items():
             from_node = next((n for n in workflow_data.nodes if n.id == source_node_id), None)
 
-            if from_node and from_node.type == "conditional":
-                # For conditional nodes, create a router that handles all outgoing edges
-                true_targets = [e.to_node_id for e in edges if e.config.get("branch", "true") == "true"]
-                false_targets = [e.to_node_id for e in edges if e.config.get("branch", "true") == "false"]
-
-                def make_conditional_router(true_list, false_list):
-                    def conditional_router(state):
-                        """Route based on conditional node result."""
-                        if source_node_id in state["node_outputs"]:
-                            conditional_result = state["node_outputs"][source_node_id]
-
-                            # Envelope format only
-                            if isinstance(conditional_result, dict) and "value" in conditional_result:
-                                result_value = conditional_result["value"]
-                                if isinstance(result_value, dict) and "branch" in result_value:
-                                    branch = result_value["branch"]
-                                else:
-                                    branch = "false"
-                            else:
-                                branch = "false"
-
-                            if branch == "true" and true_list:
-                                return true_list[0]  # Take first true target
-                            elif branch == "false" and false_list:
-                                return false_list[0]  # Take first false target
-                        return END  # End workflow if no valid route
-
-                    return conditional_router
-
-                # Build the routing map
-                route_map = {}
-                if true_targets:
-                    route_map[true_targets[0]] = true_targets[0]
-                if false_targets:
-                    route_map[false_targets[0]] = false_targets[0]
-                route_map[END] = END
+            if from_node and from_node.type in DEFAULT_BRANCHES:
+                # Each outgoing edge belongs to a branch (edge.config.branch); the router picks one
+                default_branch = DEFAULT_BRANCHES[from_node.type]
+                targets_by_branch: Dict[str, List[str]] = {}
+                for edge in edges:
+                    branch = str(edge.config.get("branch", default_branch))
+                    targets_by_branch.setdefault(branch, []).append(edge.to_node_id)
 
+                route_map = {edge.to_node_id: edge.to_node_id for edge in edges}
+                route_map[END] = END
                 graph.add_conditional_edges(
-                    source_node_id, make_conditional_router(true_targets, false_targets), route_map
+                    source_node_id, make_branch_router(source_node_id, targets_by_branch), route_map
                 )
+                logger.info(f"[WorkflowEngine] Connected {source_node_id} branches {targets_by_branch}")
             else:
                 # Regular edges - add them normally
                 for edge in edges:
@@ -225,13 +296,47 @@ class WorkflowEngine:
             logger.info(f"[WorkflowEngine] Connected {end_node} -> END")
 
         checkpointer = MemorySaver()
-        return graph.compile(checkpointer=checkpointer)
+        return graph.compile(checkpointer=checkpointer).with_config(recursion_limit=recursion_limit(workflow_data))
+
+    def _gated(self, executor, controller: ExecutionController):
+        """Wrap a node executor so run control can park it before it starts."""
+
+        async def run(state: Dict[str, Any], config: Dict[str, Any] = None) -> Dict[str, Any]:
+            async def announce_pause(node_id: str, reason: str):
+                await self._publish_execution_paused(
+                    execution_id=controller.execution_id,
+                    node=executor.node,
+                    node_outputs=state.get("node_outputs", {}),
+                    reason=reason,
+                )
 
-    async def _execute_graph(self, graph, execution: WorkflowExecution, db, workflow_id: int):
-        """Execute the compiled graph."""
+            # The user may replace the node's config for this run while it is parked
+            executor.node.config = await controller.gate(
+                executor.node_id,
+                executor.node.config,
+                announce_pause,
+                breakpoint=bool(executor.node.config.get("breakpoint")),
+            )
+            return await executor.execute(state, config)
+
+        return run
+
+    async def _execute_graph(
+        self,
+        graph,
+        execution: WorkflowExecution,
+        db,
+        workflow_id: int,
+        inputs: Optional[Dict[str, Any]] = None,
+        seed_outputs: Optional[Dict[str, Any]] = None,
+    ):
+        """Execute the compiled graph; *seed_outputs* stand in for nodes that aren't run."""
+        node_outputs = dict(seed_outputs or {})
+        if inputs is not None:
+            node_outputs[WORKFLOW_INPUT_KEY] = input_envelope(inputs)
         # Remove execution_id from state - it's immutable metadata, passed via config
         initial_state = {
-            "node_outputs": {},
+            "node_outputs": node_outputs,
             "completed_nodes": [],
             "error": None,
         }
@@ -302,6 +407,20 @@ class WorkflowEngine:
             },
         )
 
+    async def _publish_execution_paused(self, *, execution_id: int, node, node_outputs: Dict[str, Any], reason: str):
+        await publish_event(
+            EventType.EXECUTION_PAUSED,
+            {
+                "execution_id": execution_id,
+                "node_id": node.id,
+                "node_type": node.type,
+                "config": node.config,
+                "node_outputs": node_outputs,
+                "reason": reason,
+                "event_type": EventType.EXECUTION_PAUSED,
+            },
+        )
+
     async def _publish_streaming_progress(
         self, *, execution_id: int, completed_nodes: List[str], node_outputs: Dict[str, Any], error: str
     ):
@@ -343,7 +462,8 @@ class WorkflowEngine:
 
         Args:
             workflow_id: ID of the workflow to execute
-            execution_id: ID of the pre-created execution record
+            execution_id: ID of the pre-created execution record; its ``inputs`` seed the run, and
+                a ``start_node_id`` runs only that node and what follows it
         """
 
         async def run_workflow():
@@ -357,7 +477,7 @@ class WorkflowEngine:
                     return
 
                 try:
-                    await self._execute_workflow_internal(workflow_id, execution, db)
+                    await self._execute_workflow_internal(workflow_id, execution, db, inputs=execution.inputs)
                     logger.info(f"[WorkflowEngine] Background execution completed for execution_id={execution_id}")
                 except Exception as e:
                     msg = f"[WorkflowEngine] Background execution failed for execution_id={execution_id}: {e}"
@@ -365,6 +485,7 @@ class WorkflowEngine:
                 finally:
                     # Clean up task tracking
                     self._running_tasks.pop(execution_id, None)
+                    execution_controls.discard(execution_id)
 
         # Create and track the task
         task = asyncio.create_task(run_workflow())
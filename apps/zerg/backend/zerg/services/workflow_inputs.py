@@ -0,0 +1,119 @@
+"""
+Run inputs declared by a workflow's manual trigger.
+
+The manual trigger may list the parameters a run started by hand expects in
+``config.trigger.config.params.inputs``::
+
+    [{"name": "email", "type": "string", "required": true, "description": "..."},
+     {"name": "limit", "type": "integer", "default": 10}]
+
+The canvas renders a form from the declaration and the start endpoint checks
+the submitted values against it before the run begins; nodes read them as
+``${input.value.<name>}``. Workflows without a declaration accept any inputs.
+"""
+
+import json
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+INPUT_TYPES = ("string", "number", "integer", "boolean", "json")
+
+
+def _trigger_params(node: Dict[str, Any]) -> Dict[str, Any]:
+    trigger = (node.get("config") or {}).get("trigger") or {}
+    params = (trigger.get("config") or {}).get("params")
+    return params if isinstance(params, dict) else {}
+
+
+def _manual_trigger(canvas: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+    for node in canvas.get("nodes") or []:
+        if not isinstance(node, dict) or node.get("type") != "trigger":
+            continue
+        if ((node.get("config") or {}).get("trigger") or {}).get("type") == "manual":
+            return node
+    return None
+
+
+def declared_inputs(canvas: Optional[Dict[str, Any]]) -> Optional[List[Dict[str, Any]]]:
+    """Input declarations of the workflow's manual trigger, or None when it declares none."""
+    node = _manual_trigger(canvas or {})
+    if node is None:
+        return None
+    declared = _trigger_params(node).get("inputs")
+    if not isinstance(declared, list):
+        return None
+    inputs = [
+        item for item in declared if isinstance(item, dict) and isinstance(item.get("name"), str) and item["name"]
+    ]
+    return inputs or None
+
+
+def _coerce(kind: str, value: Any) -> Any:
+    """Value converted to *kind*; raises ValueError when it doesn't fit."""
+    if kind == "boolean":
+        if isinstance(value, bool):
+            return value
+        if isinstance(value, str) and value.lower() in ("true", "false"):
+            return value.lower() == "true"
+        raise ValueError("must be true or false")
+    if kind in ("number", "integer"):
+        message = "must be an integer" if kind == "integer" else "must be a number"
+        if isinstance(value, str):
+            try:
+                value = float(value)
+            except ValueError:
+                raise ValueError(message)
+        if isinstance(value, bool) or not isinstance(value, (int, float)):
+            raise ValueError(message)
+        if kind == "integer":
+            if value != int(value):
+                raise ValueError(message)
+            return int(value)
+        return value
+    if kind == "json":
+        if isinstance(value, str):
+            try:
+                return json.loads(value)
+            except ValueError:
+                raise ValueError("must be valid JSON")
+        return value
+    if not isinstance(value, str):
+        raise ValueError("must be text")
+    return value
+
+
+def validate_run_inputs(
+    declared: Optional[List[Dict[str, Any]]], values: Optional[Dict[str, Any]]
+) -> Tuple[Dict[str, Any], Dict[str, str]]:
+    """Check *values* against *declared*; returns (coerced inputs, error per input name).
+
+    Defaults fill in missing values and required inputs must be present.
+    Values that aren't declared are passed through unchanged.
+    """
+    values = dict(values or {})
+    if not declared:
+        return values, {}
+
+    errors: Dict[str, str] = {}
+    inputs = dict(values)
+    for item in declared:
+        name = item["name"]
+        kind = item.get("type") if item.get("type") in INPUT_TYPES else "string"
+        value = values.get(name)
+        if value is None or value == "":
+            if item.get("default") is not None:
+                value = item["default"]
+            elif item.get("required"):
+                errors[name] = "required"
+                continue
+            else:
+                inputs.pop(name, None)
+                continue
+        try:
+            inputs[name] = _coerce(kind, value)
+        except ValueError as exc:
+            errors[name] = str(exc)
+    return inputs, errors
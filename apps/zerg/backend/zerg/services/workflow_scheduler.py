@@ -97,6 +97,11 @@ class WorkflowScheduler:
         """
         logger.info(f"Executing scheduled workflow {workflow_id}")
 
+        with self.session_factory() as db:
+            if crud.is_maintenance_mode(db):
+                logger.info(f"Skipping scheduled workflow {workflow_id} – maintenance mode is on")
+                return
+
         try:
             # Add trigger context to indicate this is a scheduled execution
             # This allows the workflow engine to track the execution source
@@ -347,9 +347,13 @@ class WorkflowValidator:
         """Simple cycle detection using iterative DFS."""
         # Build adjacency list
         graph = {node.node_id: [] for node in canvas.nodes}
+        # Edges back into a loop node are how loops are drawn, not accidental cycles
+        loop_ids = {
+            node.node_id for node in canvas.nodes if NodeTypeHelper.parse_node_type(node.node_type)[0] == "loop"
+        }
 
         for edge in canvas.edges:
-            if edge.from_node_id in graph:
+            if edge.from_node_id in graph and edge.to_node_id not in loop_ids:
                 graph[edge.from_node_id].append(edge.to_node_id)
 
         # DFS cycle detection
@@ -0,0 +1,114 @@
+"""
+Pending waits for workflow Wait nodes.
+
+A Wait node parks its branch until a deadline passes (``duration`` and
+``until`` modes) or an external event arrives (``event`` mode, optionally with
+a deadline). Any wait can be skipped from the execution sidebar. Event waits
+get a random resume token; posting to ``/api/workflow-waits/{token}`` wakes
+the node and hands it the request body.
+
+Like execution control, state is in-process only: a wait lives as long as the
+execution's background task.
+"""
+
+import asyncio
+import logging
+import secrets
+from dataclasses import dataclass
+from dataclasses import field
+from datetime import datetime
+from typing import Any
+from typing import Dict
+from typing import Optional
+from typing import Tuple
+
+from zerg.services.execution_control import ExecutionAborted
+from zerg.utils.time import utc_now_naive
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class PendingWait:
+    execution_id: int
+    node_id: str
+    mode: str
+    resume_at: Optional[datetime] = None
+    event: Optional[str] = None
+    token: Optional[str] = None
+    outcome: Optional[str] = None
+    payload: Any = None
+    _released: asyncio.Event = field(default_factory=asyncio.Event, repr=False)
+    _loop: Optional[asyncio.AbstractEventLoop] = field(default=None, repr=False)
+
+    async def wait(self) -> Tuple[str, Any]:
+        """Block until released or the deadline passes; returns (outcome, payload)."""
+        self._loop = asyncio.get_running_loop()
+        timeout = None
+        if self.resume_at is not None:
+            timeout = max(0.0, (self.resume_at - utc_now_naive()).total_seconds())
+        try:
+            await asyncio.wait_for(self._released.wait(), timeout)
+        except asyncio.TimeoutError:
+            return "elapsed", None
+
+        if self.outcome == "cancelled":
+            raise ExecutionAborted(f"Execution {self.execution_id} cancelled while {self.node_id} was waiting")
+        return self.outcome or "skipped", self.payload
+
+    def release(self, outcome: str, payload: Any = None) -> None:
+        if self.outcome is not None:
+            return
+        self.outcome = outcome
+        self.payload = payload
+        # Endpoints may call in from a worker thread, not the engine's loop
+        if self._loop is not None and not self._loop.is_closed():
+            self._loop.call_soon_threadsafe(self._released.set)
+        else:
+            self._released.set()
+
+
+class WaitRegistry:
+    """Waits parked in this process, keyed by (execution id, node id)."""
+
+    def __init__(self):
+        self._waits: Dict[Tuple[int, str], PendingWait] = {}
+
+    def create(
+        self,
+        execution_id: int,
+        node_id: str,
+        *,
+        mode: str,
+        resume_at: Optional[datetime] = None,
+        event: Optional[str] = None,
+    ) -> PendingWait:
+        token = secrets.token_urlsafe(24) if mode == "event" else None
+        pending = PendingWait(execution_id, node_id, mode, resume_at=resume_at, event=event, token=token)
+        self._waits[(execution_id, node_id)] = pending
+        logger.info(f"[WorkflowWaits] {node_id} waiting – execution_id={execution_id}, mode={mode}")
+        return pending
+
+    def get(self, execution_id: int, node_id: str) -> Optional[PendingWait]:
+        return self._waits.get((execution_id, node_id))
+
+    def by_token(self, token: str) -> Optional[PendingWait]:
+        for pending in self._waits.values():
+            if pending.token and secrets.compare_digest(pending.token, token):
+                return pending
+        return None
+
+    def for_execution(self, execution_id: int) -> list[PendingWait]:
+        return [pending for (exec_id, _), pending in self._waits.items() if exec_id == execution_id]
+
+    def cancel_execution(self, execution_id: int) -> None:
+        for pending in self.for_execution(execution_id):
+            pending.release("cancelled")
+
+    def discard(self, pending: PendingWait) -> None:
+        if self._waits.get((pending.execution_id, pending.node_id)) is pending:
+            del self._waits[(pending.execution_id, pending.node_id)]
+
+
+# Singleton instance
+workflow_waits = WaitRegistry()
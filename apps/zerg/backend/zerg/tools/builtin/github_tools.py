@@ -516,6 +516,61 @@ def github_add_comment(
     return result
 
 
+def github_add_labels(
+    owner: str,
+    repo: str,
+    issue_number: int,
+    labels: List[str],
+    token: Optional[str] = None,
+) -> Dict[str, Any]:
+    """Add labels to a GitHub issue or pull request.
+
+    Labels that don't exist in the repository yet are created by GitHub.
+
+    Args:
+        owner: Repository owner (username or organization)
+        repo: Repository name
+        issue_number: Issue or PR number to label
+        labels: Label names to add (existing labels are kept)
+        token: GitHub Personal Access Token (optional, can be configured in Agent Settings)
+
+    Returns:
+        Dictionary containing:
+        - success: Boolean indicating if the operation succeeded
+        - data: All labels now on the issue or PR (if successful)
+        - error: Error message (if failed)
+
+    Example:
+        >>> github_add_labels(owner="octocat", repo="hello-world", issue_number=42, labels=["needs-review"])
+        {'success': True, 'data': {'labels': ['bug', 'needs-review']}}
+    """
+    resolved_token, error = _resolve_github_token(token)
+    if error:
+        return error
+
+    names = [label.strip() for label in labels or [] if isinstance(label, str) and label.strip()]
+    if not names:
+        return tool_error(
+            error_type=ErrorType.VALIDATION_ERROR,
+            user_message="At least one label is required",
+            connector="github",
+        )
+
+    if not isinstance(issue_number, int) or issue_number < 1:
+        return tool_error(
+            error_type=ErrorType.VALIDATION_ERROR,
+            user_message="Issue number must be a positive integer",
+            connector="github",
+        )
+
+    endpoint = f"/repos/{owner}/{repo}/issues/{issue_number}/labels"
+    result = _make_github_request(resolved_token, "POST", endpoint, data={"labels": names})
+
+    if result.get("ok") and "data" in result:
+        return tool_success({"labels": [label.get("name") for label in result["data"]]})
+
+    return result
+
 def github_list_pull_requests(
     owner: str,
     repo: str,
@@ -686,6 +741,11 @@ TOOLS: List[StructuredTool] = [
         name="github_add_comment",
         description="Add a comment to an existing GitHub issue or pull request. Token can be provided or configured in Agent Settings -> Connectors.",
     ),
+    StructuredTool.from_function(
+        func=github_add_labels,
+        name="github_add_labels",
+        description="Add labels to an existing GitHub issue or pull request, keeping its current labels. Token can be provided or configured in Agent Settings -> Connectors.",
+    ),
     StructuredTool.from_function(
         func=github_list_pull_requests,
         name="github_list_pull_requests",
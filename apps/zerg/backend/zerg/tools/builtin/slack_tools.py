@@ -1,4 +1,4 @@
-"""Slack-related tools for sending messages via webhooks."""
+"""Slack-related tools for sending messages via webhooks or the connected Slack app."""
 
 import logging
 from typing import Any
@@ -212,6 +212,122 @@ def send_slack_webhook(
         )
 
 
+SLACK_POST_MESSAGE_URL = "https://slack.com/api/chat.postMessage"
+
+
+def send_slack_message(
+    channel: str,
+    text: str,
+    thread_ts: Optional[str] = None,
+    blocks: Optional[List[Dict[str, Any]]] = None,
+) -> Dict[str, Any]:
+    """Post a message to a Slack channel as the connected Slack app.
+
+    Needs Slack connected with "Connect Slack" (OAuth) in Integrations, which
+    stores a bot token. Unlike the webhook tool, any channel the app has been
+    invited to can be targeted.
+
+    Args:
+        channel: Channel name (``#alerts``) or ID (``C0123456``)
+        text: Message text (also used as the notification fallback with blocks)
+        thread_ts: Optional timestamp of a message to reply to in its thread
+        blocks: Optional list of Block Kit blocks for rich formatting
+
+    Returns:
+        Dictionary containing ``channel`` and ``ts`` of the posted message on
+        success, or an error envelope.
+    """
+    resolver = get_credential_resolver()
+    creds = resolver.get(ConnectorType.SLACK) if resolver else None
+    bot_token = (creds or {}).get("bot_token")
+    if not bot_token:
+        return tool_error(
+            error_type=ErrorType.CONNECTOR_NOT_CONFIGURED,
+            user_message="Slack is not connected. Use Connect Slack in Settings → Integrations to post to channels.",
+            connector="slack",
+            setup_url="/settings/integrations",
+        )
+
+    channel = (channel or "").strip()
+    if not channel:
+        return tool_error(
+            error_type=ErrorType.VALIDATION_ERROR,
+            user_message="channel cannot be empty",
+            connector="slack",
+        )
+    if not text or not text.strip():
+        return tool_error(
+            error_type=ErrorType.VALIDATION_ERROR,
+            user_message="text cannot be empty",
+            connector="slack",
+        )
+
+    payload: Dict[str, Any] = {"channel": channel, "text": text}
+    if thread_ts:
+        payload["thread_ts"] = thread_ts
+    if blocks is not None:
+        if not isinstance(blocks, list):
+            return tool_error(
+                error_type=ErrorType.VALIDATION_ERROR,
+                user_message="blocks must be a list",
+                connector="slack",
+            )
+        payload["blocks"] = blocks
+
+    try:
+        with httpx.Client() as client:
+            response = client.post(
+                SLACK_POST_MESSAGE_URL,
+                json=payload,
+                headers={"Authorization": f"Bearer {bot_token}", "User-Agent": "Zerg-Agent/1.0"},
+                timeout=10.0,
+            )
+    except httpx.TimeoutException:
+        logger.error("Slack chat.postMessage timeout")
+        return tool_error(
+            error_type=ErrorType.EXECUTION_ERROR,
+            user_message="Request timed out after 10 seconds",
+            connector="slack",
+        )
+    except httpx.RequestError as e:
+        logger.error(f"Slack chat.postMessage request error: {e}")
+        return tool_error(
+            error_type=ErrorType.EXECUTION_ERROR,
+            user_message=f"Request failed: {str(e)}",
+            connector="slack",
+        )
+
+    if response.status_code == 429:
+        retry_after = response.headers.get("Retry-After")
+        return tool_error(
+            error_type=ErrorType.RATE_LIMITED,
+            user_message="Rate limit exceeded" + (f" (retry after {retry_after} seconds)" if retry_after else ""),
+            connector="slack",
+        )
+
+    # The Web API answers 200 with ok=false for most failures
+    try:
+        body = response.json()
+    except ValueError:
+        body = {}
+    if response.status_code != 200 or not body.get("ok"):
+        error_code = body.get("error") or f"HTTP {response.status_code}"
+        logger.warning(f"Slack chat.postMessage failed: {error_code}")
+        error_type = (
+            ErrorType.INVALID_CREDENTIALS
+            if error_code in {"invalid_auth", "not_authed", "token_revoked", "account_inactive"}
+            else ErrorType.EXECUTION_ERROR
+        )
+        hint = " – invite the Zerg app to the channel first" if error_code == "not_in_channel" else ""
+        return tool_error(
+            error_type=error_type,
+            user_message=f"Slack rejected the message: {error_code}{hint}",
+            connector="slack",
+        )
+
+    return tool_success({"channel": body.get("channel"), "ts": body.get("ts")})
+
+
 TOOLS: List[StructuredTool] = [
     StructuredTool.from_function(
         func=send_slack_webhook,
@@ -223,4 +339,13 @@ TOOLS: List[StructuredTool] = [
             "Use this to send notifications, alerts, or status updates to Slack channels."
         ),
     ),
+    StructuredTool.from_function(
+        func=send_slack_message,
+        name="send_slack_message",
+        description=(
+            "Post a message to a specific Slack channel (name like #alerts or ID) as the connected Slack app. "
+            "Requires Slack to be connected via OAuth in Integrations. "
+            "Can reply in a thread with thread_ts."
+        ),
+    ),
 ]
@@ -18,6 +18,7 @@ class ToolName(str, Enum):
     # Notification tools
     SEND_DISCORD_WEBHOOK = "send_discord_webhook"
     SEND_SLACK_WEBHOOK = "send_slack_webhook"
+    SEND_SLACK_MESSAGE = "send_slack_message"
     SEND_EMAIL = "send_email"
     SEND_SMS = "send_sms"
     # Connector meta-tools
@@ -28,6 +29,7 @@ class ToolName(str, Enum):
     GITHUB_LIST_ISSUES = "github_list_issues"
     GITHUB_GET_ISSUE = "github_get_issue"
     GITHUB_ADD_COMMENT = "github_add_comment"
+    GITHUB_ADD_LABELS = "github_add_labels"
     GITHUB_LIST_PULL_REQUESTS = "github_list_pull_requests"
     GITHUB_GET_PULL_REQUEST = "github_get_pull_request"
     JIRA_CREATE_ISSUE = "jira_create_issue"
@@ -97,6 +99,7 @@ TOOL_SERVER_MAPPING: Dict[ToolName, ServerName] = {
     # Notification tools
     ToolName.SEND_DISCORD_WEBHOOK: ServerName.DISCORD,
     ToolName.SEND_SLACK_WEBHOOK: ServerName.SLACK,
+    ToolName.SEND_SLACK_MESSAGE: ServerName.SLACK,
     ToolName.SEND_EMAIL: ServerName.EMAIL,
     ToolName.SEND_SMS: ServerName.SMS,
     # GitHub tools
@@ -105,6 +108,7 @@ TOOL_SERVER_MAPPING: Dict[ToolName, ServerName] = {
     ToolName.GITHUB_LIST_ISSUES: ServerName.GITHUB,
     ToolName.GITHUB_GET_ISSUE: ServerName.GITHUB,
     ToolName.GITHUB_ADD_COMMENT: ServerName.GITHUB,
+    ToolName.GITHUB_ADD_LABELS: ServerName.GITHUB,
     ToolName.GITHUB_LIST_PULL_REQUESTS: ServerName.GITHUB,
     ToolName.GITHUB_GET_PULL_REQUEST: ServerName.GITHUB,
     # Jira tools
@@ -25,9 +25,15 @@ See `docs/mcp_integration_requirements.md` for the end-to-end design.
 import asyncio
 import logging
 import threading
+import time
+from collections import deque
 from dataclasses import dataclass
+from dataclasses import field
+from datetime import datetime
+from datetime import timezone
 from typing import Any
 from typing import Callable
+from typing import Deque
 from typing import Dict
 from typing import List
 from typing import Optional
@@ -36,12 +42,15 @@ from typing import Tuple
 import httpx
 import jsonschema
 
+from zerg.services.run_events import bind_current_run
+from zerg.services.run_events import emit_run_event
 from zerg.tools.mcp_config_schema import normalize_config
 from zerg.tools.mcp_exceptions import MCPAuthenticationError
 from zerg.tools.mcp_exceptions import MCPConfigurationError
 from zerg.tools.mcp_exceptions import MCPConnectionError
 from zerg.tools.mcp_exceptions import MCPToolExecutionError
 from zerg.tools.mcp_exceptions import MCPValidationError
+from zerg.tools.result_utils import safe_preview
 
 # from zerg.tools.registry import register_tool
 
@@ -66,6 +75,39 @@ class MCPServerConfig:
     allowed_tools: Optional[List[str]] = None
     timeout: float = 30.0
     max_retries: int = 3
+    # Extra request headers, e.g. an API credential's auth header
+    headers: Optional[Dict[str, str]] = None
+
+    @property
+    def cache_key(self) -> Tuple[str, str, Tuple[Tuple[str, str], ...]]:
+        return self.url, self.auth_token or "", tuple(sorted((self.headers or {}).items()))
+
+
+# Samples kept per server – enough for a sparkline, small enough to hold in memory
+MCP_HEALTH_HISTORY_SIZE = 30
+
+
+@dataclass
+class MCPHealthSample:
+    """Outcome of a single health probe against an MCP server."""
+
+    status: str  # online | offline | auth_error | config_error
+    checked_at: datetime
+    latency_ms: Optional[float] = None
+    error: Optional[str] = None
+
+
+@dataclass
+class MCPServerHealth:
+    """Rolling probe history for one server (newest sample last)."""
+
+    name: str
+    url: str
+    samples: Deque[MCPHealthSample] = field(default_factory=lambda: deque(maxlen=MCP_HEALTH_HISTORY_SIZE))
+
+    @property
+    def latest(self) -> Optional[MCPHealthSample]:
+        return self.samples[-1] if self.samples else None
 
 
 class MCPClient:
@@ -127,6 +169,7 @@ class MCPClient:
         headers = {"Accept": "application/json", "Content-Type": "application/json"}
         if self.config.auth_token:
             headers["Authorization"] = f"Bearer {self.config.auth_token}"
+        headers.update(self.config.headers or {})
         return headers
 
     async def _request_with_retry(self, method: str, path: str, **kwargs) -> httpx.Response:
@@ -164,6 +207,11 @@ class MCPClient:
                 logger.debug(
                     f"Retrying request to {url} in {wait_time}s (attempt {attempt + 1}/{self.config.max_retries})"
                 )
+                await emit_run_event(
+                    "retry",
+                    attempt=attempt + 1,
+                    preview=safe_preview(f"MCP {self.config.name} {path}: {last_exception}; retrying in {wait_time}s"),
+                )
                 await asyncio.sleep(wait_time)
 
         raise MCPConnectionError(self.config.name, self.config.url, last_exception)
@@ -304,7 +352,8 @@ class MCPToolAdapter:
 
         # Synchronous wrapper that uses the shared event loop
         def _sync_tool_wrapper(**kwargs):  # noqa: D401 – wrapper
-            return MCPManager().run_in_loop(_async_tool_wrapper(**kwargs))
+            # The shared loop runs it in its own task, so carry the run over for retry events
+            return MCPManager().run_in_loop(bind_current_run(_async_tool_wrapper(**kwargs)))
 
         _sync_tool_wrapper.__name__ = f"{self.tool_prefix}{tool_name}"
         _sync_tool_wrapper.__doc__ = tool_spec.get("description", "")
@@ -351,7 +400,8 @@ class MCPManager:
         with cls._lock:
             if cls._instance is None:
                 cls._instance = super().__new__(cls)
-                cls._instance._adapters: Dict[Tuple[str, str], MCPToolAdapter] = {}
+                cls._instance._adapters: Dict[Tuple[str, str, Tuple[Tuple[str, str], ...]], MCPToolAdapter] = {}
+                cls._instance._health: Dict[Tuple[str, str], MCPServerHealth] = {}
         return cls._instance
 
     def run_in_loop(self, coro):
@@ -373,7 +423,7 @@ class MCPManager:
     # ------------------------------------------------------------------
 
     async def _init_adapter(self, cfg: MCPServerConfig):
-        key = (cfg.url, cfg.auth_token or "")
+        key = cfg.cache_key
         if key in self._adapters:
             return  # Already initialised
 
@@ -411,8 +461,8 @@ class MCPManager:
         except Exception:  # pragma: no cover – best-effort refresh
             pass
 
-    async def add_server_async(self, cfg_dict: Dict[str, Any]):
-        """Add a server configuration (async version)."""
+    def resolve_config(self, cfg_dict: Dict[str, Any]) -> MCPServerConfig:
+        """Turn a stored server entry (preset or custom) into a connection config."""
         # Normalize and validate configuration
         try:
             normalized_config = normalize_config(cfg_dict)
@@ -447,6 +497,7 @@ class MCPManager:
                 allowed_tools=normalized_config.get("allowed_tools", base_cfg.allowed_tools),
                 timeout=normalized_config.get("timeout", base_cfg.timeout),
                 max_retries=normalized_config.get("max_retries", base_cfg.max_retries),
+                headers=normalized_config.get("headers"),
             )
         else:  # type == "custom"
             try:
@@ -470,11 +521,77 @@ class MCPManager:
                     allowed_tools=normalized_config.get("allowed_tools"),
                     timeout=normalized_config.get("timeout", 30.0),
                     max_retries=normalized_config.get("max_retries", 3),
+                    headers=normalized_config.get("headers"),
                 )
             except (KeyError, TypeError) as exc:
                 raise MCPConfigurationError(f"Invalid configuration: {exc}")
 
+        return cfg
+
+    async def add_server_async(self, cfg_dict: Dict[str, Any]):
+        """Add a server configuration (async version)."""
+        await self._init_adapter(self.resolve_config(cfg_dict))
+
+    # ------------------------------------------------------------------
+    # Health monitoring
+    # ------------------------------------------------------------------
+
+    async def check_health_async(self, cfg_dict: Dict[str, Any]) -> MCPServerHealth:
+        """Probe the server's ``/health`` endpoint and record the outcome.
+
+        Never raises – configuration and auth problems become samples with
+        the matching status so a dashboard can show them side by side.
+        """
+
+        try:
+            cfg = self.resolve_config(cfg_dict)
+        except MCPAuthenticationError as exc:
+            return self._record_health(_entry_identity(cfg_dict), "auth_error", None, str(exc))
+        except MCPConfigurationError as exc:
+            return self._record_health(_entry_identity(cfg_dict), "config_error", None, str(exc))
+
+        started = time.perf_counter()
+        try:
+            async with MCPClient(cfg) as client:
+                healthy = await client.health_check()
+            status, error = ("online", None) if healthy else ("offline", "Health check failed")
+        except MCPAuthenticationError as exc:
+            status, error = "auth_error", str(exc)
+        except Exception as exc:  # pragma: no cover – health_check swallows most errors
+            status, error = "offline", str(exc)
+        latency_ms = round((time.perf_counter() - started) * 1000, 1)
+
+        return self._record_health((cfg.name, cfg.url), status, latency_ms if status == "online" else None, error)
+
+    def get_health(self, cfg_dict: Dict[str, Any]) -> Optional[MCPServerHealth]:
+        """Last recorded probe history for a stored server entry, if any."""
+
+        try:
+            cfg = self.resolve_config(cfg_dict)
+            key = (cfg.name, cfg.url)
+        except (MCPAuthenticationError, MCPConfigurationError):
+            key = _entry_identity(cfg_dict)
+        return self._health.get(key)
+
+    async def reconnect_async(self, cfg_dict: Dict[str, Any]) -> MCPServerHealth:
+        """Drop the cached adapter, re-register its tools and probe again."""
+
+        cfg = self.resolve_config(cfg_dict)
+        self._adapters.pop(cfg.cache_key, None)
         await self._init_adapter(cfg)
+        return await self.check_health_async(cfg_dict)
+
+    def _record_health(
+        self, key: Tuple[str, str], status: str, latency_ms: Optional[float], error: Optional[str]
+    ) -> MCPServerHealth:
+        health = self._health.get(key)
+        if health is None:
+            health = self._health[key] = MCPServerHealth(name=key[0], url=key[1])
+        health.samples.append(
+            MCPHealthSample(status=status, checked_at=datetime.now(timezone.utc), latency_ms=latency_ms, error=error)
+        )
+        return health
+
 
     def add_server(self, cfg_dict: Dict[str, Any]):
         """Synchronous wrapper around :pyfunc:`add_server_async`."""
@@ -496,6 +613,14 @@ class MCPManager:
 
 
 
+def _entry_identity(cfg_dict: Dict[str, Any]) -> Tuple[str, str]:
+    """Best-effort (name, url) for an entry that could not be resolved."""
+
+    if cfg_dict.get("preset"):
+        return str(cfg_dict["preset"]), "unknown"
+    return str(cfg_dict.get("name") or "unknown"), str(cfg_dict.get("url") or "unknown")
+
+
 # ---------------------------------------------------------------------------
 #  Public helpers – bulk-loaders
 # ---------------------------------------------------------------------------
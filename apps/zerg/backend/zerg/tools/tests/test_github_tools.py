@@ -4,6 +4,7 @@ from unittest.mock import Mock
 from unittest.mock import patch
 
 from zerg.tools.builtin.github_tools import github_add_comment
+from zerg.tools.builtin.github_tools import github_add_labels
 from zerg.tools.builtin.github_tools import github_create_issue
 from zerg.tools.builtin.github_tools import github_get_issue
 from zerg.tools.builtin.github_tools import github_get_pull_request
@@ -290,6 +291,44 @@ class TestGitHubAddComment:
         assert result["data"]["body"] == "Test comment"
 
 
+class TestGitHubAddLabels:
+    """Tests for github_add_labels function."""
+
+    def test_blank_labels(self):
+        """Test that an empty label list is rejected."""
+        result = github_add_labels(
+            token="ghp_test_token",
+            owner="octocat",
+            repo="hello-world",
+            issue_number=42,
+            labels=[" ", ""]
+        )
+        assert result["ok"] is False
+        assert "At least one label" in result["user_message"]
+
+    @patch("zerg.tools.builtin.github_tools.httpx.Client")
+    def test_successful_add_labels(self, mock_client):
+        """Test labels are posted trimmed and the full label set is returned."""
+        mock_response = Mock()
+        mock_response.status_code = 200
+        mock_response.json.return_value = [{"name": "bug"}, {"name": "needs-review"}]
+        mock_request = mock_client.return_value.__enter__.return_value.request
+        mock_request.return_value = mock_response
+
+        result = github_add_labels(
+            token="ghp_test_token",
+            owner="octocat",
+            repo="hello-world",
+            issue_number=42,
+            labels=[" needs-review "]
+        )
+
+        assert result["ok"] is True
+        assert result["data"]["labels"] == ["bug", "needs-review"]
+        assert mock_request.call_args.kwargs["json"] == {"labels": ["needs-review"]}
+        assert mock_request.call_args.kwargs["url"].endswith("/repos/octocat/hello-world/issues/42/labels")
+
+
 class TestGitHubListPullRequests:
     """Tests for github_list_pull_requests function."""
 
@@ -3,6 +3,7 @@
 from unittest.mock import Mock
 from unittest.mock import patch
 
+from zerg.tools.builtin.slack_tools import send_slack_message
 from zerg.tools.builtin.slack_tools import send_slack_webhook
 
 
@@ -132,3 +133,44 @@ class TestSendSlackWebhook:
 
         assert result["ok"] is False
         assert result["error_type"] == "execution_error"
+
+
+class TestSendSlackMessage:
+    """Tests for send_slack_message (posts with the connected app's bot token)."""
+
+    @patch("zerg.tools.builtin.slack_tools.get_credential_resolver", return_value=None)
+    def test_requires_connected_app(self, _resolver):
+        result = send_slack_message(channel="#alerts", text="Test message")
+        assert result["ok"] is False
+        assert result["error_type"] == "connector_not_configured"
+
+    @patch("zerg.tools.builtin.slack_tools.httpx.Client")
+    @patch("zerg.tools.builtin.slack_tools.get_credential_resolver")
+    def test_successful_post(self, mock_resolver, mock_client):
+        mock_resolver.return_value.get.return_value = {"bot_token": "xoxb-1"}
+        mock_response = Mock()
+        mock_response.status_code = 200
+        mock_response.json.return_value = {"ok": True, "channel": "C1", "ts": "1.2"}
+        mock_post = mock_client.return_value.__enter__.return_value.post
+        mock_post.return_value = mock_response
+
+        result = send_slack_message(channel="#alerts", text="Test message")
+
+        assert result["ok"] is True
+        assert result["data"] == {"channel": "C1", "ts": "1.2"}
+        assert mock_post.call_args.kwargs["headers"]["Authorization"] == "Bearer xoxb-1"
+        assert mock_post.call_args.kwargs["json"] == {"channel": "#alerts", "text": "Test message"}
+
+    @patch("zerg.tools.builtin.slack_tools.httpx.Client")
+    @patch("zerg.tools.builtin.slack_tools.get_credential_resolver")
+    def test_not_in_channel(self, mock_resolver, mock_client):
+        mock_resolver.return_value.get.return_value = {"bot_token": "xoxb-1"}
+        mock_response = Mock()
+        mock_response.status_code = 200
+        mock_response.json.return_value = {"ok": False, "error": "not_in_channel"}
+        mock_client.return_value.__enter__.return_value.post.return_value = mock_response
+
+        result = send_slack_message(channel="#alerts", text="Test message")
+
+        assert result["ok"] is False
+        assert "invite the Zerg app" in result["user_message"]
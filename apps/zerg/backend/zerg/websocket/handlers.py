@@ -9,10 +9,12 @@ from typing import Any
 from typing import Dict
 from typing import Optional
 
+from fastapi import HTTPException
 from pydantic import BaseModel
 from pydantic import ValidationError
 from sqlalchemy.orm import Session
 
+from zerg.auth.capabilities import is_admin
 from zerg.crud import crud
 from zerg.dependencies.auth import DEV_EMAIL  # noqa: F401  # may be used in future gating
 from zerg.generated.ws_messages import AgentEventData
@@ -25,12 +27,22 @@ from zerg.generated.ws_messages import ErrorData
 from zerg.generated.ws_messages import MessageType
 from zerg.generated.ws_messages import PingData
 from zerg.generated.ws_messages import PongData
+from zerg.generated.ws_messages import PresenceEditorData
+from zerg.generated.ws_messages import PresenceStateData
+from zerg.generated.ws_messages import PresenceUpdateData
 from zerg.generated.ws_messages import SendMessageData
 from zerg.generated.ws_messages import SubscribeData
 from zerg.generated.ws_messages import ThreadMessageData
 from zerg.generated.ws_messages import UnsubscribeData
+from zerg.generated.ws_messages import UnsubscribeSuccessData
 from zerg.generated.ws_messages import UserUpdateData
+from zerg.middleware.request_id import bind_trace_id
+from zerg.middleware.request_id import get_trace_id
+from zerg.middleware.request_id import reset_trace_id
 from zerg.websocket.manager import topic_manager
+from zerg.websocket.presence import parse_presence_topic
+from zerg.websocket.presence import presence_registry
+from zerg.websocket.presence import presence_topic
 
 # Import simple subscription helpers
 from zerg.websocket.subscription_helpers import send_subscribe_ack
@@ -128,6 +140,35 @@ async def handle_workflow_subscription(client_id: str, execution_id: int, messag
     await send_subscribe_ack(client_id, message_id, [topic], send_to_client)
 
 
+async def handle_workflow_presence_subscription(client_id: str, workflow_id: int, message_id: str, db: Session) -> None:
+    """Join a workflow's editors: send who is already there, then announce the newcomer."""
+    topic = presence_topic(workflow_id)
+    user_id = topic_manager.client_users.get(client_id)
+    user = crud.get_user(db, int(user_id)) if user_id else None
+    if user is None:
+        return await send_subscribe_error(
+            client_id, message_id, "Unauthorized", [topic], send_to_client, "UNAUTHORIZED"
+        )
+
+    workflow = crud.get_workflow(db, workflow_id)
+    if workflow is None or (workflow.owner_id != user.id and not is_admin(user)):
+        return await send_subscribe_error(
+            client_id, message_id, f"Workflow {workflow_id} not found", [topic], send_to_client, "NOT_FOUND"
+        )
+
+    others = [entry for entry in presence_registry.editors(workflow_id) if entry["client_id"] != client_id]
+    entry = presence_registry.join(workflow_id, client_id, user)
+    state = PresenceStateData(
+        workflow_id=workflow_id,
+        self_client_id=client_id,
+        editors=[PresenceEditorData(**other) for other in others],
+    )
+    await subscribe_and_send_state(client_id, topic, message_id, state, "presence_state", send_to_client)
+
+    envelope = Envelope.create("presence_update", topic, PresenceEditorData(**entry).model_dump())
+    await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+
+
 async def handle_ops_subscription(client_id: str, message_id: str, db: Session) -> None:
     """Subscribe to ops events (admin-only)."""
     topic = "ops:events"
@@ -243,6 +284,10 @@ async def send_to_client(
             )
             payload = envelope.model_dump()
 
+        trace_id = get_trace_id()
+        if trace_id and not payload.get("trace_id"):
+            payload = {**payload, "trace_id": trace_id}
+
         await topic_manager.active_connections[client_id].send_json(payload)  # type: ignore[arg-type]
         return True
     except Exception as e:  # noqa: BLE001 – log & swallow
@@ -365,6 +410,11 @@ async def handle_subscribe(client_id: str, envelope: Envelope, db: Session) -> N
                     await handle_user_subscription(client_id, int(topic_id), message_id, db)
                 elif prefix == "workflow_execution":
                     await handle_workflow_subscription(client_id, int(topic_id), message_id, db)
+                elif prefix == "workflow":
+                    workflow_id = parse_presence_topic(topic)
+                    if workflow_id is None:
+                        raise ValueError(topic)
+                    await handle_workflow_presence_subscription(client_id, workflow_id, message_id, db)
                 elif prefix == "ops" and topic_id == "events":
                     await handle_ops_subscription(client_id, message_id, db)
                 elif prefix == "thread":
@@ -402,27 +452,48 @@ async def handle_unsubscribe(client_id: str, message: Dict[str, Any], _: Session
         message_id = message.get("message_id", "")
         for topic in message.get("topics", []):
             await topic_manager.unsubscribe_from_topic(client_id, topic)
+            workflow_id = parse_presence_topic(topic)
+            if workflow_id is not None:
+                await topic_manager.leave_presence(client_id, workflow_id)
 
         # Send confirmation message back to client
-        await send_to_client(
-            client_id,
-            {
-                "type": "unsubscribe_success",
-                "message_id": message_id,
-                "topics": message.get("topics", []),
-            },
+        confirmation = UnsubscribeSuccessData(message_id=message_id, topics=message.get("topics", []))
+        envelope = Envelope.create(
+            message_type=MessageType.UNSUBSCRIBE_SUCCESS,
+            topic="system",
+            data=confirmation.model_dump(),
+            req_id=message_id,
         )
+        await send_to_client(client_id, envelope.model_dump())
     except Exception as e:
         logger.error(f"Error handling unsubscribe: {str(e)}")
         await send_error(client_id, "Failed to process unsubscribe", message.get("message_id", ""))
 
 
+async def handle_update_presence(client_id: str, message: Dict[str, Any], _: Session) -> None:
+    """Relay a change to the sender's cursor, selection or editing state to the workflow's editors."""
+    try:
+        update = PresenceUpdateData.model_validate(message.get("data", message))
+        changes = update.model_dump(exclude_unset=True)
+        entry = presence_registry.update(update.workflow_id, client_id, changes)
+        topic = presence_topic(update.workflow_id)
+        if entry is None:
+            await send_error(client_id, f"Not subscribed to {topic}", message.get("message_id"))
+            return
+
+        envelope = Envelope.create("presence_update", topic, PresenceEditorData(**entry).model_dump())
+        await topic_manager.broadcast_to_topic(topic, envelope.model_dump())
+    except ValidationError as e:
+        await send_error(client_id, f"Invalid presence update: {e}", message.get("message_id"))
+
+
 # Message handler dispatcher
 MESSAGE_HANDLERS = {
     "ping": handle_ping,
     "pong": handle_pong,
     "subscribe": handle_subscribe,
     "unsubscribe": handle_unsubscribe,
+    "update_presence": handle_update_presence,
     # Thread‑specific handlers used by higher‑level chat API
     "subscribe_thread": None,  # populated below
     "send_message": None,  # populated below
@@ -442,6 +513,7 @@ _INBOUND_SCHEMA_MAP: Dict[str, type[BaseModel]] = {
     "subscribe": SubscribeData,
     "unsubscribe": UnsubscribeData,
     "send_message": SendMessageData,
+    "update_presence": PresenceUpdateData,
     # Note: All messages now validated as envelope + payload data
 }
 
@@ -491,13 +563,24 @@ async def handle_send_message(client_id: str, message: Dict[str, Any], db: Sessi
             await send_error(client_id, f"Agent for thread {thread_id} not found", message_id)
             return
 
+        # Same guard as the REST endpoints: no new turns while the agent is replying
+        from zerg.routers.threads import _assert_no_pending_reply
+
+        try:
+            _assert_no_pending_reply(db, thread_id)
+        except HTTPException as exc:
+            await send_error(client_id, exc.detail, message_id)
+            return
+
         # Persist the message
+        author_id = topic_manager.client_users.get(client_id)
         db_msg = crud.create_thread_message(
             db,
             thread_id=thread_id,
             role="user",
             content=content,
             processed=False,
+            author_id=int(author_id) if author_id else None,
         )
 
         msg_dict = {
@@ -550,6 +633,9 @@ async def dispatch_message(client_id: str, message: Dict[str, Any], db: Session)
         message: The message to dispatch (raw dict or envelope)
         db: Database session
     """
+    # The socket outlives any single action, so each inbound message gets
+    # its own trace id (inherited by tasks it spawns, e.g. agent replies).
+    trace_token = bind_trace_id()
     try:
         # Handle both envelope and legacy format messages
         if "type" in message and "data" in message and "topic" in message:
@@ -610,6 +696,8 @@ async def dispatch_message(client_id: str, message: Dict[str, Any], db: Session)
     except Exception as e:
         logger.error(f"Error dispatching message: {str(e)}")
         await send_error(client_id, "Failed to process message")
+    finally:
+        reset_trace_id(trace_token)
 
 
 __all__ = ["dispatch_message"]
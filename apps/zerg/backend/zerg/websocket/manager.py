@@ -23,7 +23,10 @@ from zerg.events import event_bus
 from zerg.generated.ws_messages import Envelope
 from zerg.metrics import websocket_run_update_latency_seconds
 from zerg.metrics import websocket_run_updates_total
+from zerg.middleware.request_id import get_trace_id
 from zerg.utils.time import utc_now
+from zerg.websocket.presence import presence_registry
+from zerg.websocket.presence import presence_topic
 
 logger = logging.getLogger(__name__)
 
@@ -79,6 +82,11 @@ class TopicConnectionManager:
         # no further messages are broadcast on the subscribed topic.
         self._cleanup_task: asyncio.Task | None = None
 
+        # Per-owner sequence number for ``agent_event`` deltas on *user:*
+        # topics.  Clients that see a gap (or a reset after a restart) know
+        # they missed a delta and refetch their agent list.
+        self._agent_event_seq: Dict[int, int] = {}
+
         # Register for relevant events
         self._setup_event_handlers()
 
@@ -117,6 +125,8 @@ class TopicConnectionManager:
         # Run events
         event_bus.subscribe(EventType.RUN_CREATED, self._handle_run_event)
         event_bus.subscribe(EventType.RUN_UPDATED, self._handle_run_event)
+        event_bus.subscribe(EventType.RUN_QUEUE_UPDATED, self._handle_run_queue_event)
+        event_bus.subscribe(EventType.RUN_EVENT, self._handle_run_internal_event)
 
         # Workflow execution events
         print("🔥 About to subscribe to EXECUTION_STARTED", flush=True)
@@ -125,6 +135,8 @@ class TopicConnectionManager:
         event_bus.subscribe(EventType.NODE_STATE_CHANGED, self._handle_node_state_event)
         print("🔥 About to subscribe to WORKFLOW_PROGRESS", flush=True)
         event_bus.subscribe(EventType.WORKFLOW_PROGRESS, self._handle_workflow_progress)
+        event_bus.subscribe(EventType.EXECUTION_PAUSED, self._handle_execution_paused)
+        event_bus.subscribe(EventType.NODE_WAITING, self._handle_node_waiting)
         print("🔥 About to subscribe to EXECUTION_FINISHED", flush=True)
         event_bus.subscribe(EventType.EXECUTION_FINISHED, self._handle_execution_finished)
         print("🔥 About to subscribe to NODE_LOG", flush=True)
@@ -270,6 +282,24 @@ class TopicConnectionManager:
             # connection record has been cleared by another path).
             self._last_pong.pop(client_id, None)
 
+        await self.leave_presence(client_id)
+
+    async def leave_presence(self, client_id: str, workflow_id: int | None = None) -> None:
+        """Drop *client_id* from one (or every) workflow's editors and tell the others."""
+        if workflow_id is None:
+            left = presence_registry.leave_all(client_id)
+        else:
+            left = [workflow_id] if presence_registry.leave(workflow_id, client_id) else []
+
+        for wf_id in left:
+            topic = presence_topic(wf_id)
+            envelope = Envelope.create(
+                message_type="presence_left",
+                topic=topic,
+                data={"workflow_id": wf_id, "client_id": client_id},
+            )
+            await self.broadcast_to_topic(topic, envelope.model_dump())
+
     # ------------------------------------------------------------------
     # Heart-beat helpers – client must respond with *pong*
     # ------------------------------------------------------------------
@@ -462,6 +492,11 @@ class TopicConnectionManager:
             raise ValueError("Message must be in envelope format")
 
         final_message = message
+        # Tag the event with the trace id of the request that caused it so
+        # the UI can correlate what it saw with backend logs.
+        trace_id = get_trace_id()
+        if trace_id and not message.get("trace_id"):
+            final_message = {**message, "trace_id": trace_id}
 
         # Queue / immediately send the message for each client
         for client_id, queue in client_queues.items():
@@ -522,6 +557,36 @@ class TopicConnectionManager:
         envelope = Envelope.create(message_type=event_type, topic=topic, data=serialized_data)
         await self.broadcast_to_topic(topic, envelope.model_dump())
 
+        await self._send_agent_delta(event_type, serialized_data)
+
+    async def _send_agent_delta(self, event_type: str, agent: Dict[str, Any]) -> None:
+        """Send an ``agent_event`` delta to the owner's *user:* topic.
+
+        Per-agent topics only reach agents a client already knows about, so
+        creations (and deletions from another tab) travel on the owner's
+        personal topic instead.  Partial updates without ``owner_id`` (e.g.
+        status flips from the task runner) are left to the *agent:* topic.
+        """
+        owner_id = agent.get("owner_id")
+        action = {
+            EventType.AGENT_CREATED: "created",
+            EventType.AGENT_UPDATED: "updated",
+            EventType.AGENT_DELETED: "deleted",
+        }.get(event_type)
+        if owner_id is None or action is None:
+            return
+
+        seq = self._agent_event_seq.get(owner_id, 0) + 1
+        self._agent_event_seq[owner_id] = seq
+
+        topic = f"user:{owner_id}"
+        envelope = Envelope.create(
+            message_type="agent_event",
+            topic=topic,
+            data={**agent, "action": action, "seq": seq},
+        )
+        await self.broadcast_to_topic(topic, envelope.model_dump())
+
     async def _handle_thread_event(self, data: Dict[str, Any]) -> None:
         """Handle thread-related events from the event bus."""
         if "thread_id" not in data:
@@ -596,6 +661,26 @@ class TopicConnectionManager:
         envelope = Envelope.create(message_type="run_update", topic=topic, data=serialized_data)
         await self.broadcast_to_topic(topic, envelope.model_dump())
 
+    async def _handle_run_queue_event(self, data: Dict[str, Any]) -> None:
+        """Forward an agent's run queue snapshot to its *agent:* topic."""
+        topic = f"agent:{data['agent_id']}"
+
+        clean_data = {k: v for k, v in data.items() if k != "event_type"}
+        serialized_data = jsonable_encoder(clean_data)
+
+        envelope = Envelope.create(message_type="run_queue_update", topic=topic, data=serialized_data)
+        await self.broadcast_to_topic(topic, envelope.model_dump())
+
+    async def _handle_run_internal_event(self, data: Dict[str, Any]) -> None:
+        """Forward a run's tool calls, messages and retries to its *agent:* topic (run inspector)."""
+        topic = f"agent:{data['agent_id']}"
+
+        clean_data = {k: v for k, v in data.items() if k != "event_type"}
+        serialized_data = jsonable_encoder(clean_data)
+
+        envelope = Envelope.create(message_type="run_event", topic=topic, data=serialized_data)
+        await self.broadcast_to_topic(topic, envelope.model_dump())
+
     async def _handle_user_event(self, data: Dict[str, Any]) -> None:
         """Forward user events to `user:{id}` topic so other tabs update."""
         user_id = data["id"]
@@ -662,6 +747,28 @@ class TopicConnectionManager:
         await self.broadcast_to_topic(topic, envelope.model_dump())
         logger.debug(f"Broadcasted workflow_progress for execution {execution_id} to topic {topic}")
 
+    async def _handle_execution_paused(self, data: Dict[str, Any]) -> None:
+        """Broadcast that a step-mode execution is parked before a node."""
+        execution_id = data["execution_id"]
+        topic = f"workflow_execution:{execution_id}"
+
+        clean_data = {k: v for k, v in data.items() if k != "event_type"}
+        serialized_data = jsonable_encoder(clean_data)
+
+        envelope = Envelope.create(message_type="execution_paused", topic=topic, data=serialized_data)
+        await self.broadcast_to_topic(topic, envelope.model_dump())
+
+    async def _handle_node_waiting(self, data: Dict[str, Any]) -> None:
+        """Broadcast that a wait node is parked until its deadline, event or a manual skip."""
+        execution_id = data["execution_id"]
+        topic = f"workflow_execution:{execution_id}"
+
+        clean_data = {k: v for k, v in data.items() if k != "event_type"}
+        serialized_data = jsonable_encoder(clean_data)
+
+        envelope = Envelope.create(message_type="node_waiting", topic=topic, data=serialized_data)
+        await self.broadcast_to_topic(topic, envelope.model_dump())
+
     # ------------------------------------------------------------------
     # Execution finished
     # ------------------------------------------------------------------
@@ -0,0 +1,100 @@
+"""Who has a workflow open on the canvas.
+
+Every connection subscribed to ``workflow:{id}:presence`` has one entry per
+workflow holding its cursor (in flow coordinates), selection and the node it
+is editing.  Entries live in memory only – they describe open tabs, so a
+restart that drops every socket also correctly drops every entry.
+
+Canvas saves stay last-write-wins; the ``edited_node_id``/``edited_at``
+pair lets other editors warn before they overwrite a change someone else
+just made to the same node.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+from typing import Dict
+from typing import List
+from typing import Optional
+
+from zerg.utils.time import utc_now
+
+PRESENCE_TOPIC_SUFFIX = ":presence"
+
+# Fields a client may set about itself; identity fields come from the server
+_CLIENT_FIELDS = ("cursor", "selected_node_ids", "editing_node_id")
+
+
+def presence_topic(workflow_id: int) -> str:
+    return f"workflow:{workflow_id}{PRESENCE_TOPIC_SUFFIX}"
+
+
+def parse_presence_topic(topic: str) -> Optional[int]:
+    """``workflow:12:presence`` -> 12, None for anything else."""
+    if not topic.startswith("workflow:") or not topic.endswith(PRESENCE_TOPIC_SUFFIX):
+        return None
+    raw_id = topic[len("workflow:") : -len(PRESENCE_TOPIC_SUFFIX)]
+    return int(raw_id) if raw_id.isdigit() else None
+
+
+class WorkflowPresence:
+    """In-memory editor registry keyed by workflow then client id."""
+
+    def __init__(self) -> None:
+        self._editors: Dict[int, Dict[str, Dict[str, Any]]] = {}
+
+    def join(self, workflow_id: int, client_id: str, user) -> Dict[str, Any]:
+        entry = {
+            "workflow_id": workflow_id,
+            "client_id": client_id,
+            "user_id": user.id,
+            "display_name": getattr(user, "display_name", None) or user.email,
+            "avatar_url": getattr(user, "avatar_url", None),
+            "cursor": None,
+            "selected_node_ids": [],
+            "editing_node_id": None,
+            "edited_node_id": None,
+            "edited_at": None,
+            "updated_at": utc_now().isoformat(),
+        }
+        self._editors.setdefault(workflow_id, {})[client_id] = entry
+        return entry
+
+    def update(self, workflow_id: int, client_id: str, changes: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+        """Apply a client's presence changes; None when it never joined."""
+        entry = self._editors.get(workflow_id, {}).get(client_id)
+        if entry is None:
+            return None
+        for field in _CLIENT_FIELDS:
+            if field in changes:
+                entry[field] = changes[field]
+        now = utc_now().isoformat()
+        if changes.get("edited_node_id"):
+            entry["edited_node_id"] = changes["edited_node_id"]
+            entry["edited_at"] = now
+        entry["updated_at"] = now
+        return entry
+
+    def leave(self, workflow_id: int, client_id: str) -> bool:
+        editors = self._editors.get(workflow_id)
+        if not editors or editors.pop(client_id, None) is None:
+            return False
+        if not editors:
+            del self._editors[workflow_id]
+        return True
+
+    def leave_all(self, client_id: str) -> List[int]:
+        """Drop *client_id* everywhere; returns the workflows it left."""
+        left = [workflow_id for workflow_id, editors in self._editors.items() if client_id in editors]
+        for workflow_id in left:
+            self.leave(workflow_id, client_id)
+        return left
+
+    def editors(self, workflow_id: int) -> List[Dict[str, Any]]:
+        return list(self._editors.get(workflow_id, {}).values())
+
+    def reset(self) -> None:
+        self._editors.clear()
+
+
+presence_registry = WorkflowPresence()
@@ -0,0 +1,47 @@
+import { Page } from '@playwright/test';
+
+/**
+ * Wrappers around the app's `window.__zergTest` hooks (frontend-web
+ * src/lib/testHooks.ts), installed whenever Playwright sets __TEST_WORKER_ID__.
+ * Prefer these to fixed sleeps: wait for quiescence after an action, and
+ * restore a snapshot instead of rebuilding state through the UI.
+ */
+
+export interface AppSnapshot {
+  version: number;
+  path: string;
+  queries: { key: unknown[]; data: unknown }[];
+}
+
+export interface QuiescenceOptions {
+  timeoutMs?: number;
+  settleMs?: number;
+}
+
+async function waitForHooks(page: Page): Promise<void> {
+  await page.waitForFunction(() => (window as any).__zergTest !== undefined, undefined, { timeout: 10000 });
+}
+
+/**
+ * Wait until nothing is fetching, mutating or waiting to be saved
+ */
+export async function waitForAppQuiescence(page: Page, options: QuiescenceOptions = {}): Promise<void> {
+  await waitForHooks(page);
+  await page.evaluate((opts) => (window as any).__zergTest.waitForQuiescence(opts), options);
+}
+
+/**
+ * Capture every cached query plus the current route
+ */
+export async function snapshotAppState(page: Page): Promise<AppSnapshot> {
+  await waitForHooks(page);
+  return page.evaluate(() => (window as any).__zergTest.snapshot());
+}
+
+/**
+ * Load a snapshot taken with snapshotAppState; returns the number of queries restored
+ */
+export async function restoreAppState(page: Page, snapshot: AppSnapshot): Promise<number> {
+  await waitForHooks(page);
+  return page.evaluate((value) => (window as any).__zergTest.restore(value), snapshot);
+}
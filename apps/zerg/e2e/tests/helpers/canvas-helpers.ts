@@ -1,4 +1,5 @@
 import { Page, expect, Locator } from '@playwright/test';
+import { waitForAppQuiescence } from './app-state';
 
 /**
  * Canvas-specific helper functions for E2E tests
@@ -24,7 +25,7 @@ export interface ExecutionMonitor {
  */
 export async function navigateToCanvas(page: Page): Promise<void> {
   await page.getByTestId('global-canvas-tab').click();
-  await page.waitForTimeout(2000);
+  await waitForAppQuiescence(page);
 
   // Verify canvas loaded
   await expect(page.locator('#canvas-container')).toBeVisible({ timeout: 10000 });
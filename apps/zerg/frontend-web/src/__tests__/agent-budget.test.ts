@@ -0,0 +1,41 @@
+import { describe, expect, it } from "vitest";
+import { budgetFromConfig, budgetTone, isBudgetExhausted, withBudget } from "../lib/agentBudget";
+
+const status = (spent: number, limit = 10) => ({
+  agent_id: 1,
+  period: "daily" as const,
+  limit_usd: limit,
+  spent_usd: spent,
+  percent: (spent / limit) * 100,
+});
+
+describe("agent budget config", () => {
+  it("reads valid caps and ignores anything else", () => {
+    expect(budgetFromConfig({ budget: { period: "monthly", limit_usd: 25 } })).toEqual({
+      period: "monthly",
+      limit_usd: 25,
+    });
+    expect(budgetFromConfig({ budget: { period: "weekly", limit_usd: 25 } })).toBeNull();
+    expect(budgetFromConfig({ budget: { period: "daily", limit_usd: 0 } })).toBeNull();
+    expect(budgetFromConfig(null)).toBeNull();
+  });
+
+  it("merges into the existing config instead of replacing it", () => {
+    const config = { temperature: 0.2, budget: { period: "daily", limit_usd: 1 } };
+    expect(withBudget(config, { period: "monthly", limit_usd: 5 })).toEqual({
+      temperature: 0.2,
+      budget: { period: "monthly", limit_usd: 5 },
+    });
+    expect(withBudget(config, null)).toEqual({ temperature: 0.2 });
+  });
+});
+
+describe("agent budget status", () => {
+  it("warns from 80% and blocks at the cap", () => {
+    expect(budgetTone(status(5))).toBe("ok");
+    expect(budgetTone(status(8))).toBe("warning");
+    expect(budgetTone(status(10))).toBe("exhausted");
+    expect(isBudgetExhausted(status(9.99))).toBe(false);
+    expect(isBudgetExhausted(undefined)).toBe(false);
+  });
+});
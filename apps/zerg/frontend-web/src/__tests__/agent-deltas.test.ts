@@ -0,0 +1,61 @@
+import { describe, expect, it } from "vitest";
+import { AgentDeltaSequence, applyAgentDelta, parseAgentDelta } from "../lib/agentDeltas";
+import type { AgentSummary, DashboardSnapshot } from "../services/api";
+
+const alpha = { id: 1, name: "Alpha", status: "idle", owner_id: 7, owner: { id: 7 } } as unknown as AgentSummary;
+
+function snapshot(agents: AgentSummary[]): DashboardSnapshot {
+  return {
+    scope: "my",
+    fetchedAt: "2026-01-01T00:00:00.000Z",
+    runsLimit: 50,
+    agents,
+    runs: agents.map((agent) => ({ agentId: agent.id, runs: [] })),
+    budgets: [],
+    stats: [],
+  } as unknown as DashboardSnapshot;
+}
+
+describe("agent deltas", () => {
+  it("parses user-topic deltas and ignores plain status events", () => {
+    const delta = parseAgentDelta({ id: 2, name: "Beta", owner_id: 7, action: "created", seq: 3 });
+    expect(delta).toEqual({ action: "created", seq: 3, agent: { id: 2, name: "Beta", owner_id: 7 } });
+    expect(parseAgentDelta({ id: 2, status: "running" })).toBeNull();
+    expect(parseAgentDelta(null)).toBeNull();
+  });
+
+  it("inserts created agents and drops deleted ones with their runs", () => {
+    const base = snapshot([alpha]);
+    const beta = { id: 2, name: "Beta", status: "idle", owner_id: 7 } as unknown as AgentSummary;
+
+    const created = applyAgentDelta(base, { action: "created", seq: 1, agent: beta });
+    expect(created.agents.map((agent) => agent.id)).toEqual([1, 2]);
+
+    const deleted = applyAgentDelta(created, { action: "deleted", seq: 2, agent: alpha });
+    expect(deleted.agents.map((agent) => agent.id)).toEqual([2]);
+    expect(deleted.runs.map((bundle) => bundle.agentId)).toEqual([]);
+    expect(applyAgentDelta(deleted, { action: "deleted", seq: 3, agent: alpha })).toBe(deleted);
+  });
+
+  it("patches only the updated row and keeps fields the event lacks", () => {
+    const beta = { id: 2, name: "Beta", status: "idle", owner_id: 7 } as unknown as AgentSummary;
+    const base = snapshot([alpha, beta]);
+    const renamed = { id: 1, name: "Renamed", status: "idle", owner_id: 7 } as unknown as AgentSummary;
+
+    const next = applyAgentDelta(base, { action: "updated", seq: 1, agent: renamed });
+    expect(next.agents[0]).toMatchObject({ name: "Renamed", owner: { id: 7 } });
+    expect(next.agents[1]).toBe(beta);
+    expect(applyAgentDelta(next, { action: "updated", seq: 2, agent: renamed })).toBe(next);
+  });
+
+  it("flags gaps and restarts in the sequence", () => {
+    const sequence = new AgentDeltaSequence();
+    expect(sequence.accept(4)).toBe(true);
+    expect(sequence.accept(5)).toBe(true);
+    expect(sequence.accept(7)).toBe(false);
+    expect(sequence.accept(8)).toBe(true);
+    expect(sequence.accept(1)).toBe(false);
+    sequence.reset();
+    expect(sequence.accept(9)).toBe(true);
+  });
+});
@@ -0,0 +1,41 @@
+import { describe, expect, it } from "vitest";
+import { canDisableDependents, describeDependents, hasDependents } from "../lib/agentDependents";
+import type { AgentDependents } from "../services/api";
+
+const none: AgentDependents = { agent_id: 1, workflows: [], triggers: [], schedule: null, queued_run_ids: [] };
+
+describe("agent dependents", () => {
+  it("ignores triggers that are already disabled", () => {
+    const dependents = { ...none, triggers: [{ id: 1, type: "email", disabled: true }] };
+    expect(hasDependents(none)).toBe(false);
+    expect(hasDependents(dependents)).toBe(false);
+    expect(describeDependents(dependents)).toEqual([]);
+  });
+
+  it("describes each kind of dependent", () => {
+    const dependents: AgentDependents = {
+      ...none,
+      workflows: [
+        { id: 1, name: "Triage" },
+        { id: 2, name: "Digest" },
+      ],
+      triggers: [
+        { id: 1, type: "webhook", disabled: false },
+        { id: 2, type: "webhook", disabled: false },
+      ],
+      schedule: "0 9 * * *",
+      queued_run_ids: [5],
+    };
+    expect(describeDependents(dependents)).toEqual([
+      "Used by 2 workflows (Triage, Digest); their agent steps fail while it is deleted.",
+      "2 active triggers (webhook) will stop running it.",
+      "Its schedule (0 9 * * *) stops.",
+      "1 queued run waiting to start.",
+    ]);
+  });
+
+  it("offers disabling only when there are triggers or queued runs to act on", () => {
+    expect(canDisableDependents({ ...none, workflows: [{ id: 1, name: "Triage" }], schedule: "@daily" })).toBe(false);
+    expect(canDisableDependents({ ...none, queued_run_ids: [3] })).toBe(true);
+  });
+});
@@ -0,0 +1,32 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { confirmRunWhilePaused, isAgentPaused, pausedRunWarning } from "../lib/agentPause";
+
+const active = { name: "Digest", paused_at: null };
+const paused = { name: "Nightly", paused_at: "2026-10-14T08:00:00Z" };
+
+describe("agent pause", () => {
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it("treats a set paused_at as paused", () => {
+    expect(isAgentPaused(paused)).toBe(true);
+    expect(isAgentPaused(active)).toBe(false);
+    expect(isAgentPaused(undefined)).toBe(false);
+  });
+
+  it("only warns about the paused agents", () => {
+    expect(pausedRunWarning([active])).toBeNull();
+    expect(pausedRunWarning([active, paused])).toMatch(/^"Nightly" is paused\./);
+    expect(pausedRunWarning([paused, { ...paused, name: "Other" }])).toMatch(/^"Nightly", "Other" are paused\./);
+  });
+
+  it("asks before running a paused agent", () => {
+    const confirm = vi.spyOn(window, "confirm").mockReturnValue(false);
+    expect(confirmRunWhilePaused([active])).toBe(true);
+    expect(confirm).not.toHaveBeenCalled();
+
+    expect(confirmRunWhilePaused([paused])).toBe(false);
+    expect(confirm).toHaveBeenCalledOnce();
+  });
+});
@@ -0,0 +1,35 @@
+import { describe, expect, it } from "vitest";
+import { hasReadme, readmeSummary } from "../lib/agentReadme";
+
+describe("agent README summary", () => {
+  it("uses the first paragraph after headings and strips inline markdown", () => {
+    const markdown = [
+      "# Inbox triage",
+      "",
+      "Sorts **new mail** into [labels](https://example.com) using `gmail`",
+      "and drafts replies.",
+      "",
+      "## Inputs",
+      "- A Gmail connector",
+    ].join("\n");
+    expect(readmeSummary(markdown)).toBe("Sorts new mail into labels using gmail and drafts replies.");
+  });
+
+  it("skips code blocks and list markers", () => {
+    expect(readmeSummary("```bash\nrun me\n```\n\n- First caveat\n- Second")).toBe("First caveat Second");
+    expect(readmeSummary("> Quoted _note_")).toBe("Quoted note");
+  });
+
+  it("truncates long paragraphs with an ellipsis", () => {
+    const summary = readmeSummary("word ".repeat(100), 20);
+    expect(summary.length).toBeLessThanOrEqual(20);
+    expect(summary.endsWith("…")).toBe(true);
+  });
+
+  it("treats blank descriptions as missing", () => {
+    expect(readmeSummary(null)).toBe("");
+    expect(readmeSummary("## Only a heading")).toBe("");
+    expect(hasReadme("  \n ")).toBe(false);
+    expect(hasReadme("Does things")).toBe(true);
+  });
+});
@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest";
+import { accessAllows, agentAccess, sharedPermissionMap } from "../lib/agentSharing";
+
+describe("agent sharing", () => {
+  const shared = sharedPermissionMap([
+    { agent_id: 2, permission: "view" },
+    { agent_id: 3, permission: "edit" },
+  ]);
+
+  it("resolves owner, shared and missing access", () => {
+    expect(agentAccess({ id: 1, owner_id: 5 }, 5, shared, false)).toBe("owner");
+    expect(agentAccess({ id: 2, owner_id: 9 }, 5, shared, false)).toBe("view");
+    expect(agentAccess({ id: 4, owner_id: 9 }, 5, shared, false)).toBeNull();
+    expect(agentAccess({ id: 4, owner_id: 9 }, 5, shared, true)).toBe("owner");
+    expect(agentAccess({ id: 1, owner_id: 5 }, null, shared, false)).toBeNull();
+  });
+
+  it("orders permissions view < run < edit < owner", () => {
+    expect(accessAllows("view", "view")).toBe(true);
+    expect(accessAllows("view", "run")).toBe(false);
+    expect(accessAllows("edit", "run")).toBe(true);
+    expect(accessAllows("edit", "owner")).toBe(false);
+    expect(accessAllows("owner", "edit")).toBe(true);
+    expect(accessAllows(null, "view")).toBe(false);
+  });
+});
@@ -0,0 +1,87 @@
+import { describe, expect, it } from "vitest";
+import type { AgentSummary } from "../services/api";
+import {
+  collapsedGroupsFromPrefs,
+  favoritesFromPrefs,
+  groupShelfAgents,
+  groupingFromPrefs,
+  toggleInList,
+} from "../lib/agentShelf";
+
+function agent(id: number, name: string, ownerId: number, ownerName?: string): AgentSummary {
+  return {
+    id,
+    name,
+    owner_id: ownerId,
+    owner: ownerName ? { id: ownerId, email: `${ownerName.toLowerCase()}@example.com`, display_name: ownerName } : null,
+  } as unknown as AgentSummary;
+}
+
+const agents = [
+  agent(1, "Writer", 1),
+  agent(2, "Archivist", 2, "Zoe"),
+  agent(3, "Researcher", 3, "Amir"),
+  agent(4, "Digest", 1),
+];
+
+describe("agent shelf", () => {
+  it("lists matching agents alphabetically without grouping", () => {
+    const groups = groupShelfAgents(agents, { search: "er" });
+    expect(groups).toHaveLength(1);
+    expect(groups[0].key).toBe("all");
+    expect(groups[0].agents.map((a) => a.name)).toEqual(["Researcher", "Writer"]);
+  });
+
+  it("pins favorites to their own group", () => {
+    const groups = groupShelfAgents(agents, { favorites: [3] });
+    expect(groups.map((group) => [group.key, group.label])).toEqual([
+      ["favorites", "Favorites"],
+      ["all", "All agents"],
+    ]);
+    expect(groups[1].agents.map((a) => a.id)).toEqual([2, 4, 1]);
+  });
+
+  it("groups by owner with the current user first", () => {
+    const groups = groupShelfAgents(agents, { grouping: "owner", favorites: [4], currentUserId: 1 });
+    expect(groups.map((group) => group.label)).toEqual(["Favorites", "Mine", "Amir", "Zoe"]);
+    expect(groups[1].agents.map((a) => a.id)).toEqual([1]);
+  });
+
+  it("drops empty groups", () => {
+    expect(groupShelfAgents(agents, { search: "nothing", favorites: [1] })).toEqual([]);
+  });
+
+  it("reads shelf prefs defensively", () => {
+    const prefs = { shelf_favorites: [1, "2", 3], shelf_grouping: "colour", shelf_collapsed_groups: ["owner:me", 4] };
+    expect(favoritesFromPrefs(prefs)).toEqual([1, 3]);
+    expect(groupingFromPrefs(prefs)).toBe("none");
+    expect(groupingFromPrefs({ shelf_grouping: "owner" })).toBe("owner");
+    expect(collapsedGroupsFromPrefs(prefs)).toEqual(["owner:me"]);
+    expect(favoritesFromPrefs(null)).toEqual([]);
+  });
+
+  it("toggles list membership", () => {
+    expect(toggleInList([1, 2], 3)).toEqual([1, 2, 3]);
+    expect(toggleInList([1, 2], 1)).toEqual([2]);
+  });
+
+  it("groups by tag with untagged agents last and filters by tag", () => {
+    const tagged = [
+      { ...agents[0], tags: ["Ops"] },
+      { ...agents[1], tags: ["ops", "Archive"] },
+      agents[2],
+      { ...agents[3], tags: ["Archive"] },
+    ] as AgentSummary[];
+    const groups = groupShelfAgents(tagged, { grouping: "tag" });
+    expect(groups.map((group) => [group.key, group.label])).toEqual([
+      ["tag:archive", "Archive"],
+      ["tag:ops", "Ops"],
+      ["untagged", "Untagged"],
+    ]);
+    expect(groups[0].agents.map((a) => a.id)).toEqual([2, 4]);
+    expect(groups[1].agents.map((a) => a.id)).toEqual([2, 1]);
+
+    const filtered = groupShelfAgents(tagged, { tags: ["OPS", "archive"] });
+    expect(filtered[0].agents.map((a) => a.id)).toEqual([2]);
+  });
+});
@@ -0,0 +1,68 @@
+import { describe, expect, it } from "vitest";
+import {
+  compareSuccess,
+  formatAverageDuration,
+  sparklineHeights,
+  summarizeRuns,
+  withAgentStats,
+} from "../lib/agentStats";
+import type { AgentRun, AgentStats } from "../services/api";
+
+const run = (id: number, status: AgentRun["status"]): AgentRun => ({
+  id,
+  agent_id: 1,
+  thread_id: 1,
+  status,
+  trigger: "manual",
+  started_at: null,
+  finished_at: null,
+  duration_ms: null,
+  total_tokens: null,
+  total_cost_usd: null,
+  error: null,
+});
+
+const stats = (agentId: number, runCount: number): AgentStats => ({
+  agent_id: agentId,
+  run_count: runCount,
+  success_count: runCount,
+  failed_count: 0,
+  success_rate: runCount ? 100 : null,
+  avg_duration_ms: null,
+  daily_runs: [],
+});
+
+describe("agent stats", () => {
+  it("leaves unfinished runs out of the success rate", () => {
+    const summary = summarizeRuns([run(1, "success"), run(2, "failed"), run(3, "running")]);
+    expect(summary.rate).toBe(50);
+    expect(summary.display).toBe("50.0% (3)");
+    expect(summarizeRuns([run(4, "queued")]).display).toBe("– (1)");
+    expect(summarizeRuns(undefined).rate).toBeNull();
+  });
+
+  it("sorts unknown rates below any known rate", () => {
+    const none = summarizeRuns([]);
+    const perfect = summarizeRuns([run(1, "success")]);
+    expect(compareSuccess(none, perfect)).toBeLessThan(0);
+    expect(compareSuccess(perfect, summarizeRuns([run(1, "success"), run(2, "success")]))).toBeLessThan(0);
+  });
+
+  it("formats average durations by magnitude", () => {
+    expect(formatAverageDuration(null)).toBeNull();
+    expect(formatAverageDuration(420)).toBe("420 ms");
+    expect(formatAverageDuration(2500)).toBe("2.5 s");
+    expect(formatAverageDuration(125_000)).toBe("2 m 05 s");
+  });
+
+  it("scales sparkline bars to the busiest day", () => {
+    expect(sparklineHeights([{ date: "a", count: 2 }, { date: "b", count: 4 }])).toEqual([0.5, 1]);
+    expect(sparklineHeights([{ date: "a", count: 0 }])).toEqual([0]);
+  });
+
+  it("replaces or appends a refreshed entry", () => {
+    const list = [stats(1, 3), stats(2, 5)];
+    expect(withAgentStats(list, stats(2, 6)).map((entry) => entry.run_count)).toEqual([3, 6]);
+    expect(withAgentStats(list, stats(3, 1))).toHaveLength(3);
+  });
+});
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+import type { AgentRun, AgentSummary } from "../services/api";
+import { agentTopicChanges, wantedAgentIds } from "../lib/agentSubscriptions";
+
+const agent = (id: number, status: AgentSummary["status"] = "idle") => ({ id, status }) as AgentSummary;
+const run = (status: AgentRun["status"]) => ({ id: 1, status }) as AgentRun;
+
+describe("wantedAgentIds", () => {
+  const agents = [agent(1), agent(2), agent(3, "running"), agent(4), agent(5)];
+
+  it("subscribes to every agent until visibility is known", () => {
+    expect(wantedAgentIds(agents, { visibleIds: null, expandedAgentId: null, runsByAgent: {} })).toEqual(
+      new Set([1, 2, 3, 4, 5])
+    );
+  });
+
+  it("keeps visible, expanded and busy agents", () => {
+    const wanted = wantedAgentIds(agents, {
+      visibleIds: new Set([1, 9]),
+      expandedAgentId: 5,
+      runsByAgent: { 2: [run("success")], 4: [run("queued")] },
+    });
+    expect([...wanted].sort()).toEqual([1, 3, 4, 5]);
+  });
+});
+
+describe("agentTopicChanges", () => {
+  it("skips pending subscriptions and drops agents that are no longer wanted", () => {
+    expect(agentTopicChanges(new Set([1, 2, 3]), new Set([1, 4]), new Set([2]))).toEqual({
+      subscribe: [3],
+      unsubscribe: [4],
+    });
+  });
+});
@@ -0,0 +1,35 @@
+import { describe, expect, it } from "vitest";
+import { assertionError, emptyAssertion, summarizeTestRun } from "../lib/agentTests";
+import type { AgentTestCase } from "../services/api";
+
+function testCase(id: number, passed: boolean | null): AgentTestCase {
+  return {
+    id,
+    agent_id: 1,
+    name: `Case ${id}`,
+    input: "Hi",
+    assertions: [{ type: "contains", value: "Hello" }],
+    last_result:
+      passed === null
+        ? null
+        : { passed, output: "", assertions: [], duration_ms: 5, ran_at: "2026-10-15T10:00:00Z" },
+  };
+}
+
+describe("agent tests", () => {
+  it("summarizes the last run of each case", () => {
+    const cases = [testCase(1, true), testCase(2, false), testCase(3, null), testCase(4, true)];
+    expect(summarizeTestRun(cases)).toEqual({ passed: 2, failed: 1, notRun: 1 });
+  });
+
+  it("requires a value, or a path for JSON path assertions", () => {
+    expect(assertionError(emptyAssertion())).toBe("Value is required");
+    expect(assertionError({ type: "regex", value: "(?P<id>\\d+)" })).toBeNull();
+
+    const jsonPath = emptyAssertion("json_path");
+    expect(jsonPath.path).toBe("$.");
+    // An empty value only checks that the path exists
+    expect(assertionError(jsonPath)).toBeNull();
+    expect(assertionError({ ...jsonPath, path: "  " })).toBe("Path is required");
+  });
+});
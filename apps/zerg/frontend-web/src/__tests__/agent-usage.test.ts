@@ -0,0 +1,55 @@
+import { describe, expect, it } from "vitest";
+import type { AgentUsage } from "../services/api";
+import {
+  compareUsage,
+  costBreakdownTitle,
+  formatTokenCount,
+  formatUsageCost,
+  parseUsageColumns,
+  toggleUsageColumn,
+} from "../lib/agentUsage";
+
+function usage(agentId: number, tokens: number, cost: number | null, byModel: AgentUsage["by_model"] = []): AgentUsage {
+  return { agent_id: agentId, days: 7, run_count: 1, total_tokens: tokens, total_cost_usd: cost, by_model: byModel };
+}
+
+describe("agent usage columns", () => {
+  it("reads and toggles the shown columns in table order", () => {
+    expect(parseUsageColumns(null)).toEqual([]);
+    expect(parseUsageColumns("cost, bogus,tokens")).toEqual(["tokens", "cost"]);
+    expect(toggleUsageColumn(["cost"], "tokens")).toEqual(["tokens", "cost"]);
+    expect(toggleUsageColumn(["tokens", "cost"], "tokens")).toEqual(["cost"]);
+  });
+
+  it("formats token counts and costs", () => {
+    expect(formatTokenCount(950)).toBe("950");
+    expect(formatTokenCount(12_340)).toBe("12.3k");
+    expect(formatTokenCount(4_000_000)).toBe("4M");
+    expect(formatTokenCount(null)).toBe("—");
+    expect(formatUsageCost(0)).toBe("$0.00");
+    expect(formatUsageCost(12.5)).toBe("$12.50");
+    expect(formatUsageCost(0.042)).toBe("$0.042");
+    expect(formatUsageCost(0.0012)).toBe("$0.0012");
+    expect(formatUsageCost(undefined)).toBe("—");
+  });
+
+  it("breaks the cost down by model", () => {
+    const entry = usage(1, 1500, 0.3, [
+      { model: "gpt-big", run_count: 1, total_tokens: 1000, total_cost_usd: 0.3 },
+      { model: "local", run_count: 2, total_tokens: 500, total_cost_usd: null },
+    ]);
+    expect(costBreakdownTitle(entry)?.split("\n")).toEqual([
+      "gpt-big: $0.300 · 1k tokens (1 run)",
+      "local: — · 500 tokens (2 runs)",
+      "— = no pricing for that model",
+    ]);
+    expect(costBreakdownTitle(usage(1, 0, null))).toBeUndefined();
+    expect(costBreakdownTitle(undefined)).toBeUndefined();
+  });
+
+  it("sorts missing and unpriced usage first", () => {
+    expect(compareUsage(usage(1, 10, null), usage(2, 5, 0), "cost")).toBeLessThan(0);
+    expect(compareUsage(usage(1, 10, null), usage(2, 5, 0), "tokens")).toBeGreaterThan(0);
+    expect(compareUsage(undefined, usage(2, 0, null), "tokens")).toBeLessThan(0);
+  });
+});
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+import { agentStatusAnnouncement, runFinishedAnnouncement, shouldAnnounce } from "../lib/announcements";
+
+const agent = { id: 7, name: "Digest", last_error: "Timeout" };
+
+describe("status announcements", () => {
+  it("announces transitions to running and error only", () => {
+    expect(agentStatusAnnouncement(agent, "idle", "running")).toEqual({
+      message: "Digest is running",
+      politeness: "polite",
+      key: "agent-7-running",
+    });
+    expect(agentStatusAnnouncement(agent, "running", "error")).toMatchObject({
+      message: "Digest error: Timeout",
+      politeness: "assertive",
+    });
+    expect(agentStatusAnnouncement(agent, "running", "idle")).toBeNull();
+    expect(agentStatusAnnouncement(agent, "running", "running")).toBeNull();
+  });
+
+  it("describes finished runs", () => {
+    expect(runFinishedAnnouncement(agent, 12, "success").message).toBe("Digest run 12 completed");
+    expect(runFinishedAnnouncement(agent, 12, "failed")).toMatchObject({ politeness: "assertive" });
+  });
+
+  it("reads a failed run and the agent's error state out once", () => {
+    const recent = new Map<string, number>();
+    expect(shouldAnnounce(recent, runFinishedAnnouncement(agent, 12, "failed"), 1000)).toBe(true);
+    const errorState = agentStatusAnnouncement(agent, "running", "error");
+    expect(errorState && shouldAnnounce(recent, errorState, 2000)).toBe(false);
+    expect(errorState && shouldAnnounce(recent, errorState, 7000)).toBe(true);
+    expect(shouldAnnounce(recent, { message: "Saved", politeness: "polite" }, 7000)).toBe(true);
+  });
+});
@@ -0,0 +1,33 @@
+import { describe, expect, it } from "vitest";
+import { authHeaderError, isBaseUrl, maskedKey, previewAuthHeader } from "../lib/apiCredentials";
+import { applyDraft, draftFromNode } from "../lib/nodeInspector";
+
+describe("API credentials", () => {
+  it("accepts header templates the backend accepts", () => {
+    expect(authHeaderError("Authorization: Bearer {{key}}")).toBeNull();
+    expect(authHeaderError("X-Api-Key: {{key}}")).toBeNull();
+    expect(authHeaderError("Api Key: {{key}}")).not.toBeNull();
+    expect(authHeaderError("X-Api-Key: static")).toMatch("{{key}}");
+    expect(authHeaderError("{{key}}")).not.toBeNull();
+  });
+
+  it("checks base URLs", () => {
+    expect(isBaseUrl("https://api.example.com/v1")).toBe(true);
+    expect(isBaseUrl("api.example.com")).toBe(false);
+  });
+
+  it("masks the key", () => {
+    expect(maskedKey({ key_hint: "7890" })).toBe("••••7890");
+    expect(maskedKey({ key_hint: "" })).toBe("••••••••");
+    expect(previewAuthHeader({ auth_header: "X-Api-Key: {{key}}", key_hint: "7890" })).toBe("X-Api-Key: ••••7890");
+  });
+
+  it("stores an HTTP node's auth source as credential_id", () => {
+    const config = { method: "GET", url: "https://api.example.com", credential_id: 4 };
+    const draft = draftFromNode("Fetch", config);
+    expect(draft.credentialId).toBe("4");
+
+    expect(applyDraft("http", config, draft).credential_id).toBe(4);
+    expect(applyDraft("http", config, { ...draft, credentialId: "" })).not.toHaveProperty("credential_id");
+  });
+});
@@ -0,0 +1,60 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { apiCircuits } from "../lib/apiResilience";
+import { ApiError, apiErrorKind, fetchAgent, isApiError } from "../services/api";
+
+describe("typed api errors", () => {
+  beforeEach(() => {
+    vi.spyOn(console, "error").mockImplementation(() => {});
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+    vi.unstubAllGlobals();
+    apiCircuits.reset();
+  });
+
+  it("classifies statuses", () => {
+    expect([401, 403, 404, 409, 422, 429, 503, 418, 0].map(apiErrorKind)).toEqual([
+      "unauthorized",
+      "forbidden",
+      "not_found",
+      "conflict",
+      "validation",
+      "rate_limited",
+      "server",
+      "client",
+      "network",
+    ]);
+  });
+
+  it("exposes validation field errors and the server detail", () => {
+    const error = new ApiError({
+      url: "/api/agents",
+      status: 422,
+      body: { detail: [{ loc: ["body", "config", "temperature"], msg: "must be <= 2" }] },
+    });
+    expect(isApiError(error, "validation")).toBe(true);
+    expect(isApiError(error, "conflict")).toBe(false);
+    expect(error.fieldErrors).toEqual([{ field: "config.temperature", message: "must be <= 2" }]);
+
+    const conflict = new ApiError({
+      url: "/api/agents/1/task",
+      status: 409,
+      body: { detail: "Agent already running" },
+    });
+    expect(conflict.detailMessage).toBe("Agent already running");
+    expect(conflict.fieldErrors).toEqual([]);
+  });
+
+  it("reports Retry-After on rate limits and wraps network failures", async () => {
+    const headers = new Headers({ "content-type": "application/json", "Retry-After": "30" });
+    const json = () => Promise.resolve({ detail: "Slow down" });
+    vi.stubGlobal("fetch", vi.fn().mockResolvedValue({ ok: false, status: 429, headers, json }));
+    const limited = await fetchAgent(1).catch((error: unknown) => error);
+    expect(isApiError(limited, "rate_limited") && limited.retryAfter).toBe(30);
+
+    vi.stubGlobal("fetch", vi.fn().mockRejectedValue(new TypeError("Failed to fetch")));
+    const offline = await fetchAgent(2).catch((error: unknown) => error);
+    expect(isApiError(offline, "network")).toBe(true);
+  });
+});
@@ -0,0 +1,124 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import {
+  CircuitBreakers,
+  CircuitOpenError,
+  backoffDelay,
+  endpointGroup,
+  isRetryableRequest,
+  resilientFetch,
+} from "../lib/apiResilience";
+
+const response = (status: number) => ({ status, headers: new Headers() }) as Response;
+const ok = () => response(200);
+const unavailable = () => response(503);
+const noSleep = () => Promise.resolve();
+
+describe("api resilience", () => {
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it("groups endpoints and only retries safe requests", () => {
+    expect(endpointGroup("/agents/3/runs?limit=5")).toBe("agents");
+    expect(endpointGroup("models")).toBe("models");
+    expect(isRetryableRequest()).toBe(true);
+    expect(isRetryableRequest({ method: "head" })).toBe(true);
+    expect(isRetryableRequest({ method: "PUT" })).toBe(false);
+    expect(isRetryableRequest({ method: "DELETE" })).toBe(false);
+    expect(isRetryableRequest({ method: "POST" })).toBe(false);
+    expect(isRetryableRequest({ method: "POST", headers: { "Idempotency-Key": "k" } })).toBe(false);
+  });
+
+  it("backs off exponentially up to the cap and honours Retry-After", () => {
+    const policy = { maxAttempts: 5, baseDelayMs: 100, maxDelayMs: 1000 };
+    expect(backoffDelay(1, policy, null, () => 1)).toBe(100);
+    expect(backoffDelay(3, policy, null, () => 1)).toBe(400);
+    expect(backoffDelay(3, policy, null, () => 0)).toBe(200);
+    expect(backoffDelay(8, policy, null, () => 1)).toBe(1000);
+    expect(backoffDelay(1, policy, "2", () => 1)).toBe(1000);
+    expect(backoffDelay(1, policy, "0.25", () => 1)).toBe(250);
+  });
+
+  it("retries transient failures for idempotent requests", async () => {
+    const circuits = new CircuitBreakers();
+    const send = vi.fn().mockResolvedValueOnce(unavailable()).mockRejectedValueOnce(new TypeError("offline"));
+    send.mockResolvedValueOnce(ok());
+
+    const result = await resilientFetch("agents", send, { retry: true, circuits, sleep: noSleep });
+    expect(result.status).toBe(200);
+    expect(send).toHaveBeenCalledTimes(3);
+    expect(circuits.state("agents")).toBe("closed");
+  });
+
+  it("returns the failure straight away when retrying is unsafe", async () => {
+    const circuits = new CircuitBreakers();
+    const send = vi.fn().mockResolvedValue(unavailable());
+
+    const result = await resilientFetch("runs", send, { retry: false, circuits, sleep: noSleep });
+    expect(result.status).toBe(503);
+    expect(send).toHaveBeenCalledTimes(1);
+  });
+
+  it("opens after repeated failures, fails fast, then probes and closes", async () => {
+    vi.useFakeTimers();
+    const circuits = new CircuitBreakers({ failureThreshold: 2, cooldownMs: 1000 });
+    const send = vi.fn().mockResolvedValue(unavailable());
+    const listener = vi.fn();
+    circuits.subscribe(listener);
+
+    await resilientFetch("workflows", send, { retry: true, circuits, sleep: noSleep });
+    expect(send).toHaveBeenCalledTimes(2);
+    expect(circuits.getStatuses()).toEqual([{ group: "workflows", state: "open" }]);
+    expect(() => circuits.beforeRequest("workflows")).toThrow(CircuitOpenError);
+    // Other groups are unaffected
+    expect(() => circuits.beforeRequest("agents")).not.toThrow();
+
+    send.mockResolvedValue(ok());
+    await vi.advanceTimersByTimeAsync(1000);
+    expect(send).toHaveBeenCalledTimes(3);
+    expect(circuits.state("workflows")).toBe("closed");
+    expect(circuits.getStatuses()).toEqual([]);
+    expect(listener).toHaveBeenCalled();
+  });
+
+  it("reopens when the half-open probe fails", async () => {
+    vi.useFakeTimers();
+    const circuits = new CircuitBreakers({ failureThreshold: 1, cooldownMs: 1000 });
+    const send = vi.fn().mockResolvedValue(unavailable());
+
+    await resilientFetch("agents", send, { retry: true, circuits, sleep: noSleep });
+    expect(circuits.state("agents")).toBe("open");
+
+    await vi.advanceTimersByTimeAsync(1000);
+    expect(circuits.state("agents")).toBe("open");
+    expect(send).toHaveBeenCalledTimes(2);
+  });
+
+  it("never replays a failed mutation as the probe", async () => {
+    vi.useFakeTimers();
+    const circuits = new CircuitBreakers({ failureThreshold: 2, cooldownMs: 1000 });
+    const write = vi.fn().mockResolvedValue(unavailable());
+
+    await resilientFetch("runs", write, { retry: isRetryableRequest({ method: "DELETE" }), circuits, sleep: noSleep });
+    await resilientFetch("runs", write, { retry: isRetryableRequest({ method: "PUT" }), circuits, sleep: noSleep });
+    expect(circuits.state("runs")).toBe("open");
+
+    await vi.advanceTimersByTimeAsync(1000);
+    expect(write).toHaveBeenCalledTimes(2);
+    expect(circuits.state("runs")).toBe("half-open");
+  });
+
+  it("lets one real request probe when there's nothing to replay", async () => {
+    vi.useFakeTimers();
+    const circuits = new CircuitBreakers({ failureThreshold: 1, cooldownMs: 1000 });
+
+    await resilientFetch("runs", vi.fn().mockResolvedValue(unavailable()), { retry: false, circuits });
+    await vi.advanceTimersByTimeAsync(1000);
+    expect(circuits.state("runs")).toBe("half-open");
+
+    circuits.beforeRequest("runs");
+    expect(() => circuits.beforeRequest("runs")).toThrow(CircuitOpenError);
+    circuits.recordSuccess("runs");
+    expect(circuits.state("runs")).toBe("closed");
+  });
+});
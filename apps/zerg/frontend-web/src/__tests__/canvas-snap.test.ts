@@ -0,0 +1,76 @@
+import { describe, expect, it } from "vitest";
+import {
+  alignBoxes,
+  alignToNeighbours,
+  distributeHorizontally,
+  resolveDropPlacement,
+  snapToGrid,
+  suggestEdgeSource,
+} from "../lib/canvasSnap";
+
+const size = { width: 160, height: 48 };
+const nodeA = { id: "a", x: 0, y: 0, ...size };
+
+describe("canvas drop placement", () => {
+  it("snaps to the nearest grid point", () => {
+    expect(snapToGrid({ x: 37, y: 11 }, 24)).toEqual({ x: 48, y: 0 });
+  });
+
+  it("aligns with a nearby node's edge and reports a guide spanning both", () => {
+    const result = alignToNeighbours({ x: 103, y: 300, ...size }, [{ x: 100, y: 0, ...size }]);
+    expect(result.position).toEqual({ x: 100, y: 300 });
+    expect(result.guides).toEqual([{ orientation: "vertical", position: 100, start: 0, end: 348 }]);
+  });
+
+  it("leaves the box alone when nothing is within the threshold", () => {
+    const result = alignToNeighbours({ x: 120, y: 300, ...size }, [{ x: 100, y: 0, ...size }]);
+    expect(result.position).toEqual({ x: 120, y: 300 });
+    expect(result.guides).toEqual([]);
+  });
+
+  it("suggests an edge from the node whose output handle is close", () => {
+    expect(suggestEdgeSource({ x: 200, y: 10, ...size }, [nodeA])).toBe("a");
+    expect(suggestEdgeSource({ x: 400, y: 10, ...size }, [nodeA])).toBeNull();
+  });
+
+  it("applies grid snap before alignment", () => {
+    const snapped = resolveDropPlacement({ x: 205, y: 5 }, size, [nodeA], { grid: 24 });
+    expect(snapped.position).toEqual({ x: 216, y: 0 });
+    expect(snapped.guides.map((guide) => guide.orientation)).toEqual(["horizontal"]);
+    expect(snapped.suggestedSourceId).toBe("a");
+
+    const free = resolveDropPlacement({ x: 205, y: 5 }, size, [nodeA], { grid: null });
+    expect(free.position).toEqual({ x: 205, y: 0 });
+  });
+});
+
+describe("selection arrange commands", () => {
+  const boxes = [
+    { id: "a", x: 0, y: 40, width: 100, height: 48 },
+    { id: "b", x: 50, y: 10, width: 160, height: 48 },
+    { id: "c", x: 400, y: 90, width: 100, height: 48 },
+  ];
+
+  it("aligns on the outermost edge", () => {
+    const left = alignBoxes(boxes, "left");
+    expect([...left.values()].map((p) => p.x)).toEqual([0, 0, 0]);
+
+    const right = alignBoxes(boxes, "right");
+    expect(right.get("b")).toEqual({ x: 340, y: 10 });
+
+    const top = alignBoxes(boxes, "top");
+    expect([...top.values()].map((p) => p.y)).toEqual([10, 10, 10]);
+  });
+
+  it("spaces boxes evenly between the outer two", () => {
+    const positions = distributeHorizontally([boxes[2], boxes[0], boxes[1]]);
+    expect(positions.get("a")).toEqual({ x: 0, y: 40 });
+    expect(positions.get("b")).toEqual({ x: 170, y: 10 });
+    expect(positions.get("c")).toEqual({ x: 400, y: 90 });
+  });
+
+  it("needs enough nodes to do anything", () => {
+    expect(alignBoxes(boxes.slice(0, 1), "left").size).toBe(0);
+    expect(distributeHorizontally(boxes.slice(0, 2)).size).toBe(0);
+  });
+});
@@ -0,0 +1,16 @@
+import { describe, expect, it } from "vitest";
+import { Capability, capabilityChecker } from "../lib/capabilities";
+
+describe("capabilities", () => {
+  it("grants only what the server listed", () => {
+    const can = capabilityChecker(["view_ops", "manage_maintenance"]);
+    expect(can(Capability.ViewOps)).toBe(true);
+    expect(can(Capability.ManageMaintenance)).toBe(true);
+    expect(can(Capability.ResetDatabase)).toBe(false);
+  });
+
+  it("denies everything until the set has loaded", () => {
+    const can = capabilityChecker(undefined);
+    expect(Object.values(Capability).some((capability) => can(capability))).toBe(false);
+  });
+});
@@ -0,0 +1,70 @@
+import { describe, expect, it } from "vitest";
+import { bucketRunsByDay, labelStride, nearestIndex, niceTicks, seriesMax, stackSeries } from "../lib/chart";
+
+const box = { width: 100, height: 50, top: 0, right: 0, bottom: 0, left: 0 };
+
+describe("niceTicks", () => {
+  it("rounds the top tick up to a nice step", () => {
+    expect(niceTicks(10)).toEqual([0, 5, 10]);
+    expect(niceTicks(7)).toEqual([0, 2, 4, 6, 8]);
+    expect(niceTicks(0.03)).toEqual([0, 0.01, 0.02, 0.03]);
+  });
+
+  it("falls back to a unit axis for empty data", () => {
+    expect(niceTicks(0)).toEqual([0, 1]);
+  });
+});
+
+describe("stackSeries", () => {
+  it("accumulates layers and treats nulls as zero", () => {
+    const [bottom, top] = stackSeries([
+      [1, 2, null],
+      [3, null, 4],
+    ]);
+    expect(bottom).toEqual({ lower: [0, 0, 0], upper: [1, 2, 0] });
+    expect(top).toEqual({ lower: [1, 2, 0], upper: [4, 2, 4] });
+  });
+
+  it("uses the stacked total for the axis maximum", () => {
+    const series = [
+      { key: "a", label: "A", tone: "success" as const, values: [1, 2] },
+      { key: "b", label: "B", tone: "error" as const, values: [3, null] },
+    ];
+    expect(seriesMax(series, true)).toBe(4);
+    expect(seriesMax(series, false)).toBe(3);
+  });
+});
+
+describe("hover and labels", () => {
+  it("snaps to the nearest point and clamps outside the plot", () => {
+    expect(nearestIndex(box, 0, 5)).toBe(0);
+    expect(nearestIndex(box, 60, 5)).toBe(2);
+    expect(nearestIndex(box, 140, 5)).toBe(4);
+  });
+
+  it("thins x labels to a maximum count", () => {
+    expect(labelStride(24, 8)).toBe(3);
+    expect(labelStride(5, 8)).toBe(1);
+  });
+});
+
+describe("bucketRunsByDay", () => {
+  it("counts finished runs per day in the given time zone", () => {
+    const now = new Date("2025-03-14T12:00:00Z");
+    const buckets = bucketRunsByDay(
+      [
+        { started_at: "2025-03-14T08:00:00Z", status: "success" },
+        { started_at: "2025-03-13T23:30:00Z", status: "failed" },
+        { started_at: "2025-03-13T10:00:00Z", status: "running" },
+        { started_at: "2025-03-01T10:00:00Z", status: "success" },
+      ],
+      3,
+      now,
+      "Asia/Tokyo"
+    );
+    expect(buckets.labels).toEqual(["2025-03-12", "2025-03-13", "2025-03-14"]);
+    // 23:30Z on the 13th is already the 14th in Tokyo
+    expect(buckets.succeeded).toEqual([0, 0, 1]);
+    expect(buckets.failed).toEqual([0, 0, 1]);
+  });
+});
@@ -0,0 +1,54 @@
+import { describe, expect, it } from "vitest";
+import {
+  formatCostUsd,
+  formatLatency,
+  formatTokenCount,
+  summarizeThreadUsage,
+} from "../components/chat/chatUtils";
+import type { ThreadMessage } from "../services/api";
+
+function message(role: string, usage: ThreadMessage["usage"] = null): ThreadMessage {
+  return {
+    id: Math.floor(Math.random() * 1000) + 1,
+    thread_id: 1,
+    role,
+    content: "",
+    sent_at: new Date(0).toISOString(),
+    processed: true,
+    usage,
+  };
+}
+
+describe("chat usage formatting", () => {
+  it("abbreviates token counts", () => {
+    expect(formatTokenCount(950)).toBe("950");
+    expect(formatTokenCount(1234)).toBe("1.2k");
+    expect(formatTokenCount(45_600)).toBe("46k");
+    expect(formatTokenCount(2_500_000)).toBe("2.5M");
+  });
+
+  it("keeps sub-cent costs readable", () => {
+    expect(formatCostUsd(0)).toBe("$0.00");
+    expect(formatCostUsd(0.00042)).toBe("$0.0004");
+    expect(formatCostUsd(1.5)).toBe("$1.50");
+  });
+
+  it("formats latency", () => {
+    expect(formatLatency(420)).toBe("420ms");
+    expect(formatLatency(2345)).toBe("2.3s");
+  });
+
+  it("sums usage from assistant replies only", () => {
+    const totals = summarizeThreadUsage([
+      message("user"),
+      message("assistant", { total_tokens: 100, cost_usd: 0.01 }),
+      message("assistant", { total_tokens: 50, cost_usd: null }),
+      message("assistant"),
+    ]);
+    expect(totals).toEqual({ replies: 2, totalTokens: 150, costUsd: 0.01 });
+  });
+
+  it("leaves cost unknown when no reply was priced", () => {
+    expect(summarizeThreadUsage([message("assistant", { total_tokens: 10 })]).costUsd).toBeNull();
+  });
+});
@@ -0,0 +1,44 @@
+import { describe, expect, it } from "vitest";
+import {
+  branchHandles,
+  branchLabel,
+  controlNodeSummary,
+  edgeBranch,
+  loopMaxIterations,
+  parseSwitchCases,
+} from "../lib/controlFlow";
+
+describe("control flow nodes", () => {
+  it("gives each branch its own handle", () => {
+    expect(branchHandles("conditional", {}).map((handle) => handle.id)).toEqual(["true", "false"]);
+    expect(branchHandles("switch", { cases: ["billing", "support"] }).map((handle) => handle.id)).toEqual([
+      "billing",
+      "support",
+      "default",
+    ]);
+    expect(branchHandles("loop", {}).map((handle) => handle.label)).toEqual(["Each", "Done"]);
+    expect(branchHandles("tool", {})).toEqual([]);
+  });
+
+  it("falls back to the engine's default branch for edges without one", () => {
+    expect(edgeBranch("conditional", {})).toBe("true");
+    expect(edgeBranch("switch", { branch: "" })).toBe("default");
+    expect(edgeBranch("loop", { branch: "body" })).toBe("body");
+    expect(edgeBranch("agent", { branch: "body" })).toBeNull();
+    expect(branchLabel("loop", {}, "done")).toBe("Done");
+    expect(branchLabel("switch", { cases: [] }, "gone")).toBe("gone");
+  });
+
+  it("parses switch cases one per line", () => {
+    expect(parseSwitchCases(" billing \n\nsupport\nbilling\ndefault")).toEqual(["billing", "support"]);
+  });
+
+  it("bounds loop iterations", () => {
+    expect(loopMaxIterations({ max_iterations: 5 })).toBe(5);
+    expect(loopMaxIterations({ max_iterations: 5000 })).toBe(100);
+    expect(loopMaxIterations({ max_iterations: 0 })).toBe(10);
+    expect(loopMaxIterations(undefined)).toBe(10);
+    expect(controlNodeSummary("loop", { items: "${list}", max_iterations: 3 })).toBe("${list} (max 3)");
+    expect(controlNodeSummary("loop", {})).toBe("Repeat, max 10");
+  });
+});
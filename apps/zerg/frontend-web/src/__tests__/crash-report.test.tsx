@@ -0,0 +1,63 @@
+import { act, render, screen } from "@testing-library/react";
+import { afterEach, describe, expect, it } from "vitest";
+import { CrashOverlay } from "../components/CrashOverlay";
+import {
+  RECOVERY_STORAGE_KEY,
+  buildDiagnosticReport,
+  dismissCrash,
+  getCrash,
+  persistRecoverableState,
+  reportCrash,
+  setRecoverableState,
+  takeRecoveredState,
+} from "../lib/crashReport";
+
+afterEach(() => {
+  act(() => dismissCrash());
+  setRecoverableState("canvas", null);
+  window.sessionStorage.clear();
+});
+
+describe("crash reporting", () => {
+  it("shows the overlay for the first crash until it is dismissed", () => {
+    render(<CrashOverlay />);
+    expect(screen.queryByTestId("crash-overlay")).toBeNull();
+
+    act(() => reportCrash("window", new Error("boom")));
+    act(() => reportCrash("window", new Error("second")));
+    expect(screen.getByRole("alertdialog")).toHaveTextContent("boom");
+    expect(getCrash()?.message).toBe("boom");
+
+    act(() => screen.getByRole("button", { name: "Dismiss" }).click());
+    expect(screen.queryByTestId("crash-overlay")).toBeNull();
+  });
+
+  it("builds a report with the error, recent errors and WebSocket frames", () => {
+    const report = buildDiagnosticReport(
+      { message: "boom", stack: "Error: boom\n    at Canvas", source: "react", ts: 0 },
+      {
+        errors: [{ ts: 0, source: "rejection", message: "fetch failed" }],
+        frames: [{ seq: 1, ts: 0, direction: "in", type: "run_update", topic: "agent:3", size: 42, payload: {} }],
+        recoverableKeys: ["canvas"],
+        now: new Date(0),
+      }
+    );
+
+    expect(report).toContain("Generated: 1970-01-01T00:00:00.000Z");
+    expect(report).toContain("Unsaved state: canvas");
+    expect(report).toContain("Error (react): boom\nError: boom\n    at Canvas");
+    expect(report).toContain("1970-01-01T00:00:00.000Z rejection: fetch failed");
+    expect(report).toContain("<- run_update [agent:3] 42B");
+  });
+
+  it("carries unsaved state across a soft reload once", () => {
+    const canvas = { nodes: [{ id: "n1" }], edges: [] };
+    setRecoverableState("canvas", canvas);
+    persistRecoverableState();
+
+    expect(JSON.parse(window.sessionStorage.getItem(RECOVERY_STORAGE_KEY)!)).toEqual({ canvas });
+    expect(takeRecoveredState("canvas")).toEqual(canvas);
+    expect(takeRecoveredState("canvas")).toBeNull();
+    expect(window.sessionStorage.getItem(RECOVERY_STORAGE_KEY)).toBeNull();
+  });
+});
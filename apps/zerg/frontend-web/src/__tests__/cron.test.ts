@@ -0,0 +1,62 @@
+import { describe, expect, it } from "vitest";
+import { buildCronExpression, describeCron, detectCronPreset, nextRunTimes, parseCron } from "../lib/cron";
+
+describe("cron helpers", () => {
+  it("parses lists, ranges, steps and names", () => {
+    const result = parseCron("*/15 9-17 * jan,jul mon-fri");
+    expect(result.ok).toBe(true);
+    if (!result.ok) return;
+    expect(result.schedule.minutes).toEqual([0, 15, 30, 45]);
+    expect(result.schedule.hours).toEqual([9, 10, 11, 12, 13, 14, 15, 16, 17]);
+    expect(result.schedule.months).toEqual([1, 7]);
+    expect(result.schedule.weekdays).toEqual([0, 1, 2, 3, 4]);
+    expect(result.warnings).toEqual([]);
+  });
+
+  it("rejects malformed expressions with a readable error", () => {
+    expect(parseCron("* * *")).toEqual({ ok: false, error: expect.stringContaining("Expected 5 fields") });
+    expect(parseCron("61 * * * *")).toEqual({ ok: false, error: "Minute must be between 0 and 59" });
+    expect(parseCron("0 9 * * funday")).toEqual({ ok: false, error: expect.stringContaining("funday") });
+    expect(parseCron("0 17-9 * * *")).toEqual({ ok: false, error: expect.stringContaining("backwards") });
+  });
+
+  it("warns about numeric weekdays", () => {
+    const result = parseCron("0 9 * * 1");
+    expect(result.ok && result.warnings).toHaveLength(1);
+  });
+
+  it("describes common schedules", () => {
+    expect(describeCron("* * * * *")).toBe("Every minute");
+    expect(describeCron("*/5 * * * *")).toBe("Every 5 minutes");
+    expect(describeCron("0 * * * *")).toBe("Every hour");
+    expect(describeCron("30 9 * * mon-fri")).toBe("At 09:30 on weekdays");
+    expect(describeCron("0 8 1 * *")).toBe("At 08:00 on day 1 of the month");
+    expect(describeCron("0 8,18 * * sat,sun")).toBe("At 08:00 and 18:00 on weekends");
+    expect(describeCron("nope")).toBeNull();
+  });
+
+  it("previews the next run times in UTC", () => {
+    // Friday 2026-10-16, after that day's run
+    const runs = nextRunTimes("30 9 * * mon-fri", new Date("2026-10-16T10:00:00Z"));
+    expect(runs.map((run) => run.toISOString())).toEqual([
+      "2026-10-19T09:30:00.000Z",
+      "2026-10-20T09:30:00.000Z",
+      "2026-10-21T09:30:00.000Z",
+      "2026-10-22T09:30:00.000Z",
+      "2026-10-23T09:30:00.000Z",
+    ]);
+    expect(nextRunTimes("0 * * * *", new Date("2026-10-16T10:00:00Z"), 2).map((run) => run.toISOString())).toEqual([
+      "2026-10-16T11:00:00.000Z",
+      "2026-10-16T12:00:00.000Z",
+    ]);
+    expect(nextRunTimes("bad")).toEqual([]);
+  });
+
+  it("round-trips presets", () => {
+    const weekly = buildCronExpression("weekly", { minute: 5, hour: 7, weekday: 2 });
+    expect(weekly).toBe("5 7 * * wed");
+    expect(detectCronPreset(weekly)).toEqual({ preset: "weekly", minute: 5, hour: 7, weekday: 2 });
+    expect(detectCronPreset("0 9 * * mon-fri")?.preset).toBe("weekdays");
+    expect(detectCronPreset("0 9 1 * *")).toBeNull();
+  });
+});
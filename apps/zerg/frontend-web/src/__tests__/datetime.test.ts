@@ -0,0 +1,53 @@
+import { describe, expect, it } from "vitest";
+import {
+  compareTimestamps,
+  formatDateTimeShort,
+  formatRelativeTime,
+  formatTimeShort,
+  formatUtcTooltip,
+  parseTimestamp,
+  timePreferencesFromPrefs,
+} from "../lib/datetime";
+
+const NBSP = "\u00A0";
+
+describe("datetime helpers", () => {
+  it("reads offset-less backend timestamps as UTC", () => {
+    expect(parseTimestamp("2025-09-24T10:00:00")?.toISOString()).toBe("2025-09-24T10:00:00.000Z");
+    expect(parseTimestamp("2025-09-24T10:00:00+02:00")?.toISOString()).toBe("2025-09-24T08:00:00.000Z");
+    expect(parseTimestamp("not a date")).toBeNull();
+    expect(parseTimestamp(null)).toBeNull();
+  });
+
+  it("formats in the requested timezone", () => {
+    expect(formatDateTimeShort("2025-09-24T23:30:00Z", "UTC")).toBe(`2025-09-24${NBSP}23:30`);
+    expect(formatDateTimeShort("2025-09-24T23:30:00Z", "Asia/Tokyo")).toBe(`2025-09-25${NBSP}08:30`);
+    expect(formatTimeShort("2025-09-24T00:05:00Z", "America/New_York")).toBe("20:05");
+    expect(formatUtcTooltip("2025-09-24T23:30:15Z")).toBe("2025-09-24 23:30:15 UTC");
+    expect(formatDateTimeShort(null)).toBe("-");
+  });
+
+  it("describes times relative to now", () => {
+    const now = Date.parse("2025-09-24T12:00:00Z");
+    expect(formatRelativeTime("2025-09-24T11:59:40Z", { now })).toBe("just now");
+    expect(formatRelativeTime("2025-09-24T11:57:00Z", { now })).toBe("3 min ago");
+    expect(formatRelativeTime("2025-09-24T14:30:00Z", { now })).toBe("in 2 h");
+    expect(formatRelativeTime("2025-09-21T12:00:00Z", { now })).toBe("3 days ago");
+    expect(formatRelativeTime("2025-06-01T08:00:00Z", { now, timeZone: "UTC" })).toBe(`2025-06-01${NBSP}08:00`);
+  });
+
+  it("falls back to the browser timezone for missing or unknown prefs", () => {
+    expect(timePreferencesFromPrefs({ timezone: "Europe/Berlin", time_display: "relative" })).toEqual({
+      timeZone: "Europe/Berlin",
+      mode: "relative",
+    });
+    const fallback = timePreferencesFromPrefs({ timezone: "Mars/Olympus" });
+    expect(fallback.mode).toBe("absolute");
+    expect(fallback.timeZone).not.toBe("Mars/Olympus");
+  });
+
+  it("sorts missing timestamps first", () => {
+    const values = ["2025-09-24T10:00:00", null, "2025-09-23T10:00:00"];
+    expect([...values].sort(compareTimestamps)).toEqual([null, "2025-09-23T10:00:00", "2025-09-24T10:00:00"]);
+  });
+});
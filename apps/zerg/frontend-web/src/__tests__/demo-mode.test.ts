@@ -0,0 +1,149 @@
+import { describe, expect, it, vi } from "vitest";
+import type { AgentRun, ThreadMessage } from "../services/api";
+import { createDemoSeed } from "../lib/demoData";
+import {
+  DEMO_RUN_MS,
+  DEMO_STORAGE_KEY,
+  DemoBackend,
+  createDemoFetch,
+  demoApiPath,
+  isDemoMode,
+  type DemoEvent,
+} from "../lib/demoMode";
+
+const NOW = new Date("2025-03-10T12:00:00Z");
+
+// Runs scheduled callbacks on demand instead of on a timer
+function manualSchedule() {
+  const pending: { callback: () => void; at: number }[] = [];
+  let clock = 0;
+  return {
+    schedule: (callback: () => void, ms: number) => pending.push({ callback, at: clock + ms }),
+    advance(ms: number) {
+      clock += ms;
+      pending
+        .filter((task) => task.at <= clock)
+        .sort((a, b) => a.at - b.at)
+        .forEach((task) => {
+          pending.splice(pending.indexOf(task), 1);
+          task.callback();
+        });
+    },
+  };
+}
+
+function get(backend: DemoBackend, path: string, query = "") {
+  return backend.handle("GET", path, new URLSearchParams(query), undefined);
+}
+
+describe("demo mode switch", () => {
+  it("turns on with ?demo=1 for the rest of the tab and off with ?demo=0", () => {
+    const storage = window.sessionStorage;
+    storage.removeItem(DEMO_STORAGE_KEY);
+    expect(isDemoMode("", storage)).toBe(false);
+    expect(isDemoMode("?demo=1", storage)).toBe(true);
+    expect(isDemoMode("?tab=runs", storage)).toBe(true);
+    expect(isDemoMode("?demo=0", storage)).toBe(false);
+    expect(isDemoMode("", storage)).toBe(false);
+  });
+
+  it("only intercepts API paths", () => {
+    expect(demoApiPath("/api/agents/3")).toBe("/agents/3");
+    expect(demoApiPath("/api")).toBe("/");
+    expect(demoApiPath("/assets/index.js")).toBeNull();
+  });
+});
+
+describe("demo seed", () => {
+  it("is identical for the same clock", () => {
+    expect(createDemoSeed(NOW)).toEqual(createDemoSeed(NOW));
+  });
+});
+
+describe("DemoBackend", () => {
+  it("serves the seeded dashboard, threads and ops numbers", () => {
+    const backend = new DemoBackend(() => NOW, manualSchedule().schedule);
+    const dashboard = get(backend, "/agents/dashboard", "scope=my&runs_limit=3").body as {
+      agents: unknown[];
+      runs: { agent_id: number; runs: AgentRun[] }[];
+    };
+    expect(dashboard.agents.length).toBeGreaterThan(0);
+    expect(dashboard.runs[0].runs).toHaveLength(3);
+
+    const messages = get(backend, "/threads/1/messages").body as ThreadMessage[];
+    expect(messages.map((message) => message.role)).toEqual(["user", "assistant", "user", "assistant"]);
+    expect(get(backend, "/ops/timeseries", "metric=runs_by_hour&window=today").body).toMatchObject({
+      series: expect.arrayContaining([{ hour_iso: "00:00Z", value: expect.any(Number) }]),
+    });
+    expect(get(backend, "/templates/").body).toEqual([]);
+    expect(get(backend, "/agents/999").status).toBe(404);
+    expect(backend.handle("POST", "/users/me/exports", new URLSearchParams(), undefined).status).toBe(501);
+  });
+
+  it("runs an agent and publishes its status changes", () => {
+    const timers = manualSchedule();
+    const backend = new DemoBackend(() => NOW, timers.schedule);
+    const events: DemoEvent[] = [];
+    backend.subscribe((event) => events.push(event));
+
+    expect(backend.handle("POST", "/agents/3/task", new URLSearchParams(), undefined).status).toBe(202);
+    expect(events.map((event) => [event.type, event.data.status])).toEqual([
+      ["agent_state", "running"],
+      ["run_update", "running"],
+    ]);
+
+    timers.advance(DEMO_RUN_MS);
+    expect(events.slice(2).map((event) => [event.type, event.data.status])).toEqual([
+      ["run_update", "success"],
+      ["agent_state", "idle"],
+    ]);
+    const runs = get(backend, "/agents/3/runs", "limit=1").body as AgentRun[];
+    expect(runs[0]).toMatchObject({ status: "success", trigger: "manual" });
+  });
+
+  it("streams a chat reply and then stores it", () => {
+    const timers = manualSchedule();
+    const backend = new DemoBackend(() => NOW, timers.schedule);
+    const events: DemoEvent[] = [];
+    backend.subscribe((event) => events.push(event));
+    const body = { role: "user", content: "Hello" };
+    backend.handle("POST", "/threads/1/messages", new URLSearchParams(), body);
+    backend.handle("POST", "/threads/1/run", new URLSearchParams(), undefined);
+
+    timers.advance(60_000);
+    const types = events
+      .map((event) => event.type)
+      .filter((type) => type.startsWith("stream_") || type === "assistant_id");
+    expect(types[0]).toBe("stream_start");
+    expect(types[1]).toBe("assistant_id");
+    expect(types.at(-1)).toBe("stream_end");
+    const streamed = events
+      .filter((event) => event.type === "stream_chunk")
+      .map((event) => event.data.content)
+      .join("");
+
+    const messages = get(backend, "/threads/1/messages").body as ThreadMessage[];
+    const reply = messages.at(-1)!;
+    expect(reply).toMatchObject({ role: "assistant", content: streamed });
+    expect(reply.id).toBe(events.find((event) => event.type === "assistant_id")!.data.message_id);
+    expect(messages.at(-2)).toMatchObject({ role: "user", content: "Hello" });
+  });
+});
+
+describe("createDemoFetch", () => {
+  it("answers API calls and passes other requests through", async () => {
+    const passthrough = vi.fn(async () => new Response("asset"));
+    const demoFetch = createDemoFetch(new DemoBackend(() => NOW, manualSchedule().schedule), passthrough);
+
+    const response = await demoFetch("/api/users/me", { credentials: "include" });
+    expect(response.status).toBe(200);
+    expect(await response.json()).toMatchObject({ email: "demo@example.com" });
+
+    const created = await demoFetch("/api/agents", { method: "POST", body: JSON.stringify({ name: "Scout" }) });
+    expect(created.status).toBe(201);
+    expect(await created.json()).toMatchObject({ name: "Scout", status: "idle" });
+
+    await demoFetch("/config.js");
+    expect(passthrough).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,51 @@
+import { describe, expect, it } from "vitest";
+import {
+  desktopNotificationPrefs,
+  isWithinQuietHours,
+  runFinishedNotification,
+  shouldNotifyDesktop,
+} from "../lib/desktopNotifications";
+
+const overnight = { start: "22:00", end: "07:00" };
+
+describe("desktop notification helpers", () => {
+  it("reads prefs and drops malformed quiet hours", () => {
+    expect(desktopNotificationPrefs({ desktop_notifications: true, quiet_hours: overnight })).toEqual({
+      enabled: true,
+      quietHours: overnight,
+    });
+    const malformed = { desktop_notifications: "yes", quiet_hours: { start: "25:00", end: "07:00" } };
+    expect(desktopNotificationPrefs(malformed)).toEqual({ enabled: false, quietHours: null });
+    expect(desktopNotificationPrefs(null)).toEqual({ enabled: false, quietHours: null });
+  });
+
+  it("handles quiet hours that wrap past midnight, in the user's timezone", () => {
+    expect(isWithinQuietHours(new Date("2026-05-01T23:30:00Z"), overnight, "UTC")).toBe(true);
+    expect(isWithinQuietHours(new Date("2026-05-01T06:59:00Z"), overnight, "UTC")).toBe(true);
+    expect(isWithinQuietHours(new Date("2026-05-01T07:00:00Z"), overnight, "UTC")).toBe(false);
+    // 23:30 UTC is 19:30 in New York (EDT)
+    expect(isWithinQuietHours(new Date("2026-05-01T23:30:00Z"), overnight, "America/New_York")).toBe(false);
+    expect(isWithinQuietHours(new Date("2026-05-01T13:00:00Z"), { start: "12:00", end: "14:00" }, "UTC")).toBe(true);
+    expect(isWithinQuietHours(new Date("2026-05-01T13:00:00Z"), null, "UTC")).toBe(false);
+  });
+
+  it("only notifies for hidden tabs with permission outside quiet hours", () => {
+    const base = {
+      prefs: { enabled: true, quietHours: overnight },
+      permission: "granted" as const,
+      hidden: true,
+      now: new Date("2026-05-01T12:00:00Z"),
+      timeZone: "UTC",
+    };
+    expect(shouldNotifyDesktop(base)).toBe(true);
+    expect(shouldNotifyDesktop({ ...base, hidden: false })).toBe(false);
+    expect(shouldNotifyDesktop({ ...base, permission: "denied" })).toBe(false);
+    expect(shouldNotifyDesktop({ ...base, prefs: { enabled: false, quietHours: null } })).toBe(false);
+    expect(shouldNotifyDesktop({ ...base, now: new Date("2026-05-01T23:00:00Z") })).toBe(false);
+  });
+
+  it("describes the outcome", () => {
+    expect(runFinishedNotification("Digest", "success").title).toBe("Digest finished");
+    expect(runFinishedNotification("Digest", "failed", "Timeout")).toEqual({ title: "Digest failed", body: "Timeout" });
+  });
+});
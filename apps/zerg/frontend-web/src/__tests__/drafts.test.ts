@@ -0,0 +1,41 @@
+import { afterEach, describe, expect, it } from "vitest";
+import {
+  DRAFT_MAX_AGE_MS,
+  DRAFT_STORAGE_PREFIX,
+  chatDraftKey,
+  draftSlice,
+  draftWriter,
+  readDraft,
+} from "../lib/drafts";
+import { PersistenceQueue } from "../lib/persistence";
+
+const key = chatDraftKey(7);
+
+describe("drafts", () => {
+  afterEach(() => {
+    window.localStorage.clear();
+  });
+
+  it("writes through the persistence queue and removes the draft when cleared", () => {
+    const queue = new PersistenceQueue();
+    queue.register(draftSlice(key), draftWriter(key));
+
+    queue.schedule(draftSlice(key), { value: "Half a thought", savedAt: 1000 });
+    expect(readDraft(key, window.localStorage, 2000)).toBeNull();
+    queue.flush();
+    expect(readDraft(key, window.localStorage, 2000)).toEqual({ value: "Half a thought", savedAt: 1000 });
+
+    queue.schedule(draftSlice(key), null);
+    queue.flush();
+    expect(window.localStorage.getItem(DRAFT_STORAGE_PREFIX + key)).toBeNull();
+  });
+
+  it("ignores expired and malformed drafts", () => {
+    window.localStorage.setItem(DRAFT_STORAGE_PREFIX + key, JSON.stringify({ value: "old", savedAt: 0 }));
+    expect(readDraft(key, window.localStorage, DRAFT_MAX_AGE_MS + 1)).toBeNull();
+    expect(window.localStorage.getItem(DRAFT_STORAGE_PREFIX + key)).toBeNull();
+
+    window.localStorage.setItem(DRAFT_STORAGE_PREFIX + key, "{not json");
+    expect(readDraft(key)).toBeNull();
+  });
+});
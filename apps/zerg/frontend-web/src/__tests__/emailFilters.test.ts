@@ -0,0 +1,33 @@
+import { describe, expect, it } from "vitest";
+import { describeEmailFilters, filtersToForm, formToFilters } from "../lib/emailFilters";
+
+describe("email trigger filters", () => {
+  it("composes a compact filter object from the form", () => {
+    const filters = formToFilters({
+      from: " alice@example.com, bob ,",
+      subject: "",
+      label: "INBOX",
+      hasAttachment: true,
+    });
+    expect(filters).toEqual({
+      from_contains: ["alice@example.com", "bob"],
+      label_include: ["INBOX"],
+      has_attachment: true,
+    });
+    expect(formToFilters({ from: "", subject: "", label: "", hasAttachment: false })).toEqual({});
+  });
+
+  it("round-trips and keeps keys the builder does not edit", () => {
+    const stored = { subject_contains: ["invoice"], label_exclude: ["SPAM"], query: "urgent" };
+    const form = filtersToForm(stored);
+    expect(form).toEqual({ from: "", subject: "invoice", label: "", hasAttachment: false });
+    expect(formToFilters(form, stored)).toEqual(stored);
+  });
+
+  it("describes filters in plain words", () => {
+    expect(describeEmailFilters(null)).toBe("Every new email");
+    expect(describeEmailFilters({ from_contains: ["alice"], subject_contains: ["report"], has_attachment: true })).toBe(
+      'Emails from alice, subject contains "report", with an attachment'
+    );
+  });
+});
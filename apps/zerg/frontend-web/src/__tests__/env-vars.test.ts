@@ -0,0 +1,40 @@
+import { describe, expect, it } from "vitest";
+import { findEnvReferences, isValidEnvName, resolveEnvReference } from "../lib/envVars";
+import type { EnvVar } from "../services/api";
+
+const vars: EnvVar[] = [
+  { id: 1, name: "REGION", workflow_id: null, is_secret: false, value: "eu" },
+  { id: 2, name: "REGION", workflow_id: 7, is_secret: false, value: "us" },
+  { id: 3, name: "API_TOKEN", workflow_id: null, is_secret: true, value: null },
+];
+
+describe("environment variables", () => {
+  it("finds distinct references anywhere in a draft", () => {
+    const draft = {
+      url: "https://{{ env.HOST }}/{{env.REGION}}",
+      headers: ["Authorization: Bearer {{env.API_TOKEN}}", "{{env.HOST}}"],
+      retries: 3,
+    };
+    expect(findEnvReferences(draft)).toEqual(["HOST", "REGION", "API_TOKEN"]);
+    expect(findEnvReferences("{{ env.2BAD }} {{input.value}}")).toEqual([]);
+  });
+
+  it("prefers the workflow's own value over the workspace one", () => {
+    expect(resolveEnvReference("REGION", vars, 7)).toEqual({ name: "REGION", scope: "workflow", secret: false });
+    expect(resolveEnvReference("REGION", vars, 8)).toEqual({ name: "REGION", scope: "workspace", secret: false });
+    expect(resolveEnvReference("API_TOKEN", vars, null)).toEqual({
+      name: "API_TOKEN",
+      scope: "workspace",
+      secret: true,
+    });
+    expect(resolveEnvReference("MISSING", vars, 7).scope).toBeNull();
+  });
+
+  it("validates names", () => {
+    expect(isValidEnvName("API_TOKEN")).toBe(true);
+    expect(isValidEnvName("_private2")).toBe(true);
+    expect(isValidEnvName("2FAST")).toBe(false);
+    expect(isValidEnvName("has-dash")).toBe(false);
+    expect(isValidEnvName("A".repeat(65))).toBe(false);
+  });
+});
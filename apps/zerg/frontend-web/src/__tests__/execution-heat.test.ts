@@ -0,0 +1,40 @@
+import { describe, expect, it } from "vitest";
+import { aggregateNodeStats, computeNodeHeat } from "../lib/executionHeat";
+import type { ExecutionNodeSummary } from "../services/api";
+
+function execution(id: number, nodes: ExecutionNodeSummary["nodes"]): ExecutionNodeSummary {
+  return { execution_id: id, phase: "finished", result: "success", started_at: null, finished_at: null, nodes };
+}
+
+const summaries: ExecutionNodeSummary[] = [
+  execution(2, [
+    { node_id: "a", phase: "finished", result: "failure", duration_ms: 400, cost_usd: 0.02 },
+    { node_id: "b", phase: "finished", result: "success", duration_ms: 100, cost_usd: null },
+  ]),
+  execution(1, [
+    { node_id: "a", phase: "finished", result: "success", duration_ms: 200, cost_usd: 0.04 },
+    { node_id: "b", phase: "running", result: null, duration_ms: null, cost_usd: null },
+  ]),
+];
+
+describe("execution heat", () => {
+  it("aggregates finished node runs only", () => {
+    const stats = aggregateNodeStats(summaries);
+    expect(stats.get("a")).toEqual({ runs: 2, failures: 1, failureRate: 0.5, avgDurationMs: 300, avgCostUsd: 0.03 });
+    expect(stats.get("b")?.runs).toBe(1);
+    expect(stats.get("b")?.avgCostUsd).toBeNull();
+  });
+
+  it("normalises duration against the slowest node", () => {
+    const { heat, max } = computeNodeHeat(aggregateNodeStats(summaries), "avg_duration");
+    expect(max).toBe(300);
+    expect(heat.get("a")?.intensity).toBe(1);
+    expect(heat.get("b")?.intensity).toBeCloseTo(1 / 3);
+  });
+
+  it("skips nodes without data for the chosen metric", () => {
+    const { heat } = computeNodeHeat(aggregateNodeStats(summaries), "cost");
+    expect(heat.has("a")).toBe(true);
+    expect(heat.has("b")).toBe(false);
+  });
+});
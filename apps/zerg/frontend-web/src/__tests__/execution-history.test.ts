@@ -0,0 +1,80 @@
+import { beforeEach, describe, expect, it } from "vitest";
+import {
+  DEFAULT_EXECUTION_RETENTION,
+  EMPTY_EXECUTION_FILTER,
+  EXECUTION_RETENTION_STORAGE_KEY,
+  executionDurationLabel,
+  executionOutcome,
+  executionPinsStorageKey,
+  filterExecutions,
+  pruneExecutions,
+  readExecutionPins,
+  readExecutionRetention,
+  togglePin,
+} from "../lib/executionHistory";
+import type { WorkflowExecution } from "../services/api";
+
+const NOW = Date.parse("2026-10-14T12:00:00Z");
+const daysAgo = (days: number) => new Date(NOW - days * 24 * 60 * 60 * 1000).toISOString().replace("Z", "");
+
+function run(id: number, overrides: Partial<WorkflowExecution> = {}): WorkflowExecution {
+  return { id, workflow_id: 1, phase: "finished", result: "success", started_at: daysAgo(0), ...overrides };
+}
+
+describe("execution history", () => {
+  beforeEach(() => localStorage.clear());
+
+  it("derives the outcome from phase and result", () => {
+    expect(executionOutcome(run(1, { phase: "running", result: null }))).toBe("running");
+    expect(executionOutcome(run(1, { result: "cancelled" }))).toBe("cancelled");
+    expect(executionOutcome(run(1, { result: null }))).toBe("failure");
+    expect(executionDurationLabel(run(1, { started_at: daysAgo(0), finished_at: daysAgo(0) }))).toBe("0ms");
+    expect(executionDurationLabel(run(1, { finished_at: null }))).toBeNull();
+  });
+
+  it("filters by status, id and start date with pins first", () => {
+    const runs = [run(12, { result: "failure" }), run(11), run(10, { started_at: daysAgo(10) }), run(3)];
+    const pinned = new Set([3]);
+
+    expect(filterExecutions(runs, EMPTY_EXECUTION_FILTER, pinned).map((r) => r.id)).toEqual([3, 12, 11, 10]);
+    const failed = filterExecutions(runs, { ...EMPTY_EXECUTION_FILTER, status: "failure" }, pinned);
+    expect(failed.map((r) => r.id)).toEqual([12]);
+    const byId = filterExecutions(runs, { ...EMPTY_EXECUTION_FILTER, query: "#1" }, pinned);
+    expect(byId.map((r) => r.id)).toEqual([12, 11, 10]);
+
+    // Date inputs hold local dates
+    const started = new Date(`${daysAgo(10)}Z`);
+    const pad = (value: number) => String(value).padStart(2, "0");
+    const day = `${started.getFullYear()}-${pad(started.getMonth() + 1)}-${pad(started.getDate())}`;
+    const onDay = filterExecutions(runs, { ...EMPTY_EXECUTION_FILTER, from: day, to: day }, pinned);
+    expect(onDay.map((r) => r.id)).toEqual([10]);
+  });
+
+  it("prunes by age and count, keeping pinned and unfinished runs", () => {
+    const runs = [
+      run(6, { phase: "running", result: null }),
+      run(5),
+      run(4),
+      run(3),
+      run(2, { started_at: daysAgo(40) }),
+      run(1, { started_at: daysAgo(60) }),
+    ];
+    const kept = pruneExecutions(runs, { maxCount: 2, maxAgeDays: 30 }, new Set([1]), NOW);
+    expect(kept.map((r) => r.id)).toEqual([6, 5, 4, 1]);
+    expect(pruneExecutions(runs, { maxCount: null, maxAgeDays: null }, new Set(), NOW)).toHaveLength(6);
+  });
+
+  it("reads pins and retention from storage, ignoring bad values", () => {
+    expect(togglePin(togglePin(new Set([1]), 2), 1)).toEqual(new Set([2]));
+
+    localStorage.setItem(executionPinsStorageKey(7), JSON.stringify([4, "x", 9]));
+    expect(readExecutionPins(7)).toEqual(new Set([4, 9]));
+    expect(readExecutionPins(8)).toEqual(new Set());
+
+    expect(readExecutionRetention()).toEqual(DEFAULT_EXECUTION_RETENTION);
+    localStorage.setItem(EXECUTION_RETENTION_STORAGE_KEY, JSON.stringify({ maxCount: null, maxAgeDays: 7 }));
+    expect(readExecutionRetention()).toEqual({ maxCount: null, maxAgeDays: 7 });
+    localStorage.setItem(EXECUTION_RETENTION_STORAGE_KEY, JSON.stringify({ maxCount: -1, maxAgeDays: 7 }));
+    expect(readExecutionRetention()).toEqual(DEFAULT_EXECUTION_RETENTION);
+  });
+});
@@ -0,0 +1,36 @@
+import { describe, expect, it } from "vitest";
+import { LOG_LEVELS, filterLogs, getLogLevel, serializeLogs, type LogEntry } from "../components/ExecutionLogStream";
+
+const logs: LogEntry[] = [
+  { timestamp: 0, type: "execution", message: "EXECUTION STARTED [ID: 1]" },
+  { timestamp: 1000, type: "node", message: "NODE a → RUNNING", nodeId: "a" },
+  { timestamp: 2000, type: "output", level: "stdout", message: "hello world", nodeId: "a" },
+  { timestamp: 3000, type: "output", level: "stderr", message: "warning: slow", nodeId: "b" },
+  { timestamp: 4000, type: "error", message: "NODE b → FAILED", nodeId: "b" },
+];
+
+describe("execution log filters", () => {
+  it("derives levels from entry type when not explicit", () => {
+    expect(logs.map(getLogLevel)).toEqual(["info", "info", "stdout", "stderr", "error"]);
+  });
+
+  it("combines search, node and level filters", () => {
+    const allLevels = new Set(LOG_LEVELS);
+    expect(filterLogs(logs, { search: "", nodeId: "b", levels: allLevels })).toHaveLength(2);
+    expect(filterLogs(logs, { search: "HELLO", nodeId: null, levels: allLevels })).toEqual([logs[2]]);
+    expect(filterLogs(logs, { search: "", nodeId: null, levels: new Set(["stderr", "error"]) })).toEqual([
+      logs[3],
+      logs[4],
+    ]);
+  });
+
+  it("serializes NDJSON one object per line", () => {
+    const lines = serializeLogs(logs.slice(2, 4), "ndjson").trim().split("\n");
+    expect(lines).toHaveLength(2);
+    expect(JSON.parse(lines[1])).toMatchObject({ level: "stderr", node_id: "b", message: "warning: slow" });
+  });
+
+  it("serializes plain text with level and node", () => {
+    expect(serializeLogs([logs[3]], "text")).toBe("1970-01-01T00:00:03.000Z STDERR [b] warning: slow\n");
+  });
+});
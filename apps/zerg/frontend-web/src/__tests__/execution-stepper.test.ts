@@ -0,0 +1,86 @@
+import { describe, expect, it } from "vitest";
+import {
+  configOverride,
+  hasBreakpoint,
+  initialStepState,
+  outputValue,
+  parseConfigDraft,
+  stepReducer,
+  toggleBreakpoint,
+} from "../lib/executionStepper";
+
+const paused = (nodeId: string, outputs: Record<string, unknown> = {}, reason: "step" | "breakpoint" = "step") =>
+  ({
+    type: "paused",
+    data: {
+      execution_id: 1,
+      node_id: nodeId,
+      node_type: "tool",
+      config: { tool_name: "x" },
+      node_outputs: outputs,
+      reason,
+    },
+  }) as const;
+
+describe("step-mode reducer", () => {
+  it("tracks the paused node and outputs so far", () => {
+    const state = stepReducer(initialStepState, paused("a", { trigger: { value: 1 } }));
+    expect(state.paused).toEqual({ nodeId: "a", nodeType: "tool", config: { tool_name: "x" }, reason: "step" });
+    expect(state.nodeOutputs).toEqual({ trigger: { value: 1 } });
+  });
+
+  it("ignores a late resume for a node that is no longer paused", () => {
+    let state = stepReducer(initialStepState, paused("a"));
+    state = stepReducer(state, { type: "resume_requested" });
+    expect(state.resuming).toBe(true);
+    // The next pause arrives before the step request resolves
+    state = stepReducer(state, paused("b"));
+    state = stepReducer(state, { type: "resumed", nodeId: "a" });
+    expect(state.paused?.nodeId).toBe("b");
+
+    state = stepReducer(state, { type: "resumed", nodeId: "b" });
+    expect(state.paused).toBeNull();
+  });
+
+  it("keeps the pause when resuming fails", () => {
+    let state = stepReducer(initialStepState, paused("a"));
+    state = stepReducer(state, { type: "resume_requested" });
+    state = stepReducer(state, { type: "resume_failed" });
+    expect(state.paused?.nodeId).toBe("a");
+    expect(state.resuming).toBe(false);
+  });
+});
+
+describe("breakpoints", () => {
+  it("records why the run stopped", () => {
+    const state = stepReducer(initialStepState, paused("a", {}, "breakpoint"));
+    expect(state.paused?.reason).toBe("breakpoint");
+  });
+
+  it("toggles the flag without storing false", () => {
+    const on = toggleBreakpoint({ tool_name: "x" });
+    expect(on).toEqual({ tool_name: "x", breakpoint: true });
+    expect(hasBreakpoint(on)).toBe(true);
+    expect(toggleBreakpoint(on)).toEqual({ tool_name: "x" });
+    expect(hasBreakpoint(undefined)).toBe(false);
+  });
+});
+
+describe("node input drafts", () => {
+  it("rejects anything but a JSON object", () => {
+    expect(parseConfigDraft("[1]").ok).toBe(false);
+    expect(parseConfigDraft("{").ok).toBe(false);
+    expect(parseConfigDraft('{"a": 1}')).toEqual({ ok: true, config: { a: 1 } });
+  });
+
+  it("only sends an override when the input changed", () => {
+    expect(configOverride({ a: 1 }, '{\n  "a": 1\n}')).toBeUndefined();
+    expect(configOverride({ a: 1 }, '{"a": 2}')).toEqual({ a: 2 });
+    expect(configOverride({ a: 1 }, "not json")).toBeUndefined();
+  });
+
+  it("unwraps output envelopes", () => {
+    expect(outputValue({ value: { status: 200 }, meta: {} })).toEqual({ status: 200 });
+    expect(outputValue("raw")).toBe("raw");
+  });
+});
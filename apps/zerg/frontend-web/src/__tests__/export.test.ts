@@ -0,0 +1,77 @@
+import { describe, expect, it } from "vitest";
+import type { AgentRun, ThreadMessage } from "../services/api";
+import { exportFilename, opsActivityToCsv, runReportHtml, runReportMessages, toCsv } from "../lib/export";
+
+const run = {
+  id: 7,
+  agent_id: 1,
+  thread_id: 3,
+  status: "success",
+  trigger: "schedule",
+  started_at: "2025-01-01T10:00:00Z",
+  finished_at: "2025-01-01T10:00:05Z",
+  duration_ms: 5000,
+  total_tokens: 300,
+  total_cost_usd: 0.004,
+  error: null,
+} as AgentRun;
+
+function message(id: number, role: string, content: string, sentAt: string, extra: Partial<ThreadMessage> = {}) {
+  return { id, thread_id: 3, role, content, sent_at: sentAt, processed: true, ...extra } as ThreadMessage;
+}
+
+describe("report exports", () => {
+  it("builds CSV from columns", () => {
+    const csv = toCsv(
+      [
+        { header: "name", value: (row: { name: string; tags: string[] }) => row.name },
+        { header: "tags", value: (row: { name: string; tags: string[] }) => row.tags },
+      ],
+      [{ name: "a, b", tags: ["x"] }]
+    );
+    expect(csv).toBe('name,tags\r\n"a, b","[""x""]"\r\n');
+  });
+
+  it("names files after the subject and date", () => {
+    expect(exportFilename("Nightly Digest!", "runs", "csv", new Date("2025-01-31T12:00:00Z"))).toBe(
+      "nightly-digest-runs-2025-01-31.csv"
+    );
+  });
+
+  it("exports ops buckets with a total row", () => {
+    const csv = opsActivityToCsv({
+      labels: ["2025-01-01", "2025-01-02"],
+      runs: [4, 0],
+      errors: [1, 0],
+      cost: [0.5, 0],
+    });
+    expect(csv.trim().split("\r\n")).toEqual([
+      "bucket,runs,errors,error_rate,cost_usd",
+      "2025-01-01,4,1,0.2500,0.500000",
+      "2025-01-02,0,0,,0.000000",
+      "total,4,1,0.2500,0.500000",
+    ]);
+  });
+
+  it("keeps only the run's own messages", () => {
+    const messages = [
+      message(1, "user", "earlier chat", "2025-01-01T09:00:00Z"),
+      message(2, "user", "Summarise the inbox", "2025-01-01T10:00:00Z"),
+      message(3, "assistant", "Done", "2025-01-01T10:00:04Z", { usage: { run_id: 7, total_tokens: 300 } }),
+      message(4, "assistant", "Other run", "2025-01-01T10:00:03Z", { usage: { run_id: 8 } }),
+    ];
+    expect(runReportMessages(run, messages).map((m) => m.id)).toEqual([2, 3]);
+  });
+
+  it("renders an escaped printable report", () => {
+    const html = runReportHtml({
+      run: { ...run, error: "<script>alert(1)</script>" },
+      agentName: "Inbox & Co",
+      messages: [message(2, "user", "Hello <b>", "2025-01-01T10:00:00Z")],
+    });
+    expect(html).toContain("<title>Run #7 – Inbox &amp; Co</title>");
+    expect(html).toContain("&lt;script&gt;alert(1)&lt;/script&gt;");
+    expect(html).toContain("Hello &lt;b&gt;");
+    expect(html).not.toContain("<script>");
+  });
+});
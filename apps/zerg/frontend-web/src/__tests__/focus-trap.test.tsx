@@ -0,0 +1,81 @@
+import { useState, type ReactNode } from "react";
+import { fireEvent, render, screen } from "@testing-library/react";
+import { describe, expect, it, vi } from "vitest";
+import { nextTrapFocus, useFocusTrap } from "../lib/useFocusTrap";
+
+function Dialog({ label, onClose, children }: { label: string; onClose: () => void; children?: ReactNode }) {
+  const ref = useFocusTrap(true, onClose);
+  return (
+    <div ref={ref} role="dialog" aria-label={label} tabIndex={-1}>
+      <button type="button">{label} first</button>
+      <button type="button">{label} last</button>
+      {children}
+    </div>
+  );
+}
+
+function Harness({ onClose = vi.fn() }: { onClose?: () => void }) {
+  const [open, setOpen] = useState(false);
+  return (
+    <>
+      <button type="button" onClick={() => setOpen(true)}>
+        Open
+      </button>
+      {open && (
+        <Dialog
+          label="Outer"
+          onClose={() => {
+            onClose();
+            setOpen(false);
+          }}
+        />
+      )}
+    </>
+  );
+}
+
+describe("useFocusTrap", () => {
+  it("wraps Tab at either end and leaves the middle to the browser", () => {
+    const a = document.createElement("button");
+    const b = document.createElement("button");
+    const c = document.createElement("button");
+    expect(nextTrapFocus([a, b, c], c, false)).toBe(a);
+    expect(nextTrapFocus([a, b, c], a, true)).toBe(c);
+    expect(nextTrapFocus([a, b, c], b, false)).toBeNull();
+    expect(nextTrapFocus([a, b, c], document.body, false)).toBe(a);
+    expect(nextTrapFocus([], a, false)).toBeNull();
+  });
+
+  it("moves focus in, cycles with Tab and restores focus on Escape", () => {
+    const onClose = vi.fn();
+    render(<Harness onClose={onClose} />);
+    const opener = screen.getByText("Open");
+    opener.focus();
+    fireEvent.click(opener);
+
+    expect(screen.getByText("Outer first")).toHaveFocus();
+    screen.getByText("Outer last").focus();
+    fireEvent.keyDown(document.activeElement as Element, { key: "Tab" });
+    expect(screen.getByText("Outer first")).toHaveFocus();
+    fireEvent.keyDown(document.activeElement as Element, { key: "Tab", shiftKey: true });
+    expect(screen.getByText("Outer last")).toHaveFocus();
+
+    fireEvent.keyDown(document.activeElement as Element, { key: "Escape" });
+    expect(onClose).toHaveBeenCalledTimes(1);
+    expect(screen.queryByRole("dialog")).not.toBeInTheDocument();
+    expect(opener).toHaveFocus();
+  });
+
+  it("only lets the topmost dialog handle Escape", () => {
+    const outerClose = vi.fn();
+    const innerClose = vi.fn();
+    render(
+      <Dialog label="Outer" onClose={outerClose}>
+        <Dialog label="Inner" onClose={innerClose} />
+      </Dialog>
+    );
+    fireEvent.keyDown(document.activeElement as Element, { key: "Escape" });
+    expect(innerClose).toHaveBeenCalledTimes(1);
+    expect(outerClose).not.toHaveBeenCalled();
+  });
+});
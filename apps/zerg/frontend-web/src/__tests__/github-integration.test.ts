@@ -0,0 +1,60 @@
+import { describe, expect, it } from "vitest";
+import { describeGitHubTrigger, isGitHubConnected, isGitHubRepo, parseActions } from "../lib/githubTrigger";
+import { defaultGitHubConfig, githubNodeSummary } from "../lib/githubNode";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+describe("GitHub triggers", () => {
+  it("summarises the repository, events and actions", () => {
+    expect(describeGitHubTrigger({ repo: "octocat/hello", events: ["pull_request", "push"], actions: [] })).toBe(
+      "Pull requests, Pushes in octocat/hello"
+    );
+    expect(describeGitHubTrigger({ repo: "octocat/hello", events: ["issues"], actions: ["opened", "labeled"] })).toBe(
+      "Issues (opened, labeled) in octocat/hello"
+    );
+    expect(describeGitHubTrigger({ repo: "octocat/hello", events: ["fork"] })).toBe("Unknown GitHub trigger");
+  });
+
+  it("validates the form inputs", () => {
+    expect(isGitHubRepo(" octocat/hello ")).toBe(true);
+    expect(isGitHubRepo("hello")).toBe(false);
+    expect(parseActions("opened, , labeled ")).toEqual(["opened", "labeled"]);
+    expect(isGitHubConnected([{ type: "github", configured: true }])).toBe(true);
+    expect(isGitHubConnected([{ type: "github", configured: false }])).toBe(false);
+  });
+});
+
+describe("GitHub PR action node", () => {
+  it("comments, then switches to labelling the same PR", () => {
+    const config = { ...defaultGitHubConfig(), tool_type: "github-pr-action" };
+    expect(githubNodeSummary(config)).toBe("No repository");
+
+    const draft = draftFromNode("Review", config);
+    expect(validateDraft("tool", draft, [])).toMatchObject({
+      githubRepo: "Enter the repository as owner/name.",
+      githubNumber: "Enter the issue or pull request number.",
+      githubText: "Enter the comment.",
+    });
+
+    const commented = applyDraft("tool", config, {
+      ...draft,
+      githubRepo: "octocat/hello",
+      githubNumber: "7",
+      githubText: "Looks good",
+    });
+    expect(commented.tool_name).toBe("github_add_comment");
+    expect(commented.static_params).toEqual({ owner: "octocat", repo: "hello", issue_number: 7, body: "Looks good" });
+    expect(githubNodeSummary(commented)).toBe("Comments on octocat/hello#7");
+
+    const relabel = { ...draftFromNode("Review", commented), githubAction: "label" as const, githubText: "bot, ok " };
+    relabel.githubNumber = "${trigger.value.number}";
+    const labelled = applyDraft("tool", commented, relabel);
+    expect(labelled.tool_name).toBe("github_add_labels");
+    expect(labelled.static_params).toEqual({
+      owner: "octocat",
+      repo: "hello",
+      issue_number: "${trigger.value.number}",
+      labels: ["bot", "ok"],
+    });
+    expect(githubNodeSummary(labelled)).toBe("Labels octocat/hello#${trigger.value.number}");
+  });
+});
@@ -0,0 +1,33 @@
+import { describe, expect, it } from "vitest";
+import { GMAIL_SCOPE, disconnectWarning, needsReconnect, scopeLabel } from "../lib/gmail";
+import type { GmailConnection } from "../services/api";
+
+const trigger = (id: number, agentName: string, disabled = false) => ({
+  id,
+  agent_id: id * 10,
+  agent_name: agentName,
+  disabled,
+});
+
+describe("gmail connection", () => {
+  it("names the agents whose triggers a disconnect disables", () => {
+    expect(disconnectWarning([])).toBe("Disconnect Gmail? No email triggers use this connection.");
+    const triggers = [trigger(1, "Inbox triage"), trigger(2, "Inbox triage"), trigger(3, "Old", true)];
+    const warning = disconnectWarning(triggers);
+    expect(warning).toContain("2 email triggers will be disabled");
+    expect(warning).toContain("stop running Inbox triage.");
+    expect(warning).not.toContain("Old");
+  });
+
+  it("labels known scopes and shortens unknown ones", () => {
+    expect(scopeLabel(GMAIL_SCOPE)).toBe("Read email and settings");
+    expect(scopeLabel("https://www.googleapis.com/auth/calendar")).toBe("calendar");
+  });
+
+  it("asks for a reconnect only for broken connections", () => {
+    const connection: GmailConnection = { connected: true, health: "healthy", scopes: [], triggers: [] };
+    expect(needsReconnect(connection)).toBe(false);
+    expect(needsReconnect({ ...connection, health: "auth_failed" })).toBe(true);
+    expect(needsReconnect({ ...connection, connected: false, health: "not_connected" })).toBe(false);
+  });
+});
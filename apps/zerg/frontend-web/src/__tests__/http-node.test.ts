@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest";
+import { formatHeaderLines, httpNodeSummary, isRequestUrl, parseHeaderLines, statusTone } from "../lib/httpNode";
+
+describe("http node", () => {
+  it("round-trips headers through the editor text", () => {
+    const headers = { Authorization: "Bearer ${auth.value.token}", "Content-Type": "application/json" };
+    const text = formatHeaderLines(headers);
+    expect(text).toBe("Authorization: Bearer ${auth.value.token}\nContent-Type: application/json");
+    expect(parseHeaderLines(`${text}\n\n`)).toEqual({ headers, badLine: null });
+    expect(parseHeaderLines("Accept: */*\nno separator").badLine).toBe(2);
+    expect(parseHeaderLines(": value").badLine).toBe(1);
+  });
+
+  it("accepts absolute or templated URLs", () => {
+    expect(isRequestUrl("https://api.example.com/items")).toBe(true);
+    expect(isRequestUrl("${config.value.base_url}/items")).toBe(true);
+    expect(isRequestUrl("api.example.com")).toBe(false);
+    expect(isRequestUrl("https://")).toBe(false);
+  });
+
+  it("summarises the request and its status", () => {
+    expect(httpNodeSummary({ method: "POST", url: "https://x.test" })).toBe("POST https://x.test");
+    expect(httpNodeSummary({})).toBe("GET No URL");
+    expect([statusTone(204), statusTone(302), statusTone(500)]).toEqual(["success", "warning", "error"]);
+  });
+});
@@ -0,0 +1,36 @@
+import { describe, expect, it } from "vitest";
+import { isHttpUrl, isKnowledgeIndexing, knowledgeDocumentMeta, knowledgeFileError } from "../lib/knowledgeBase";
+import type { KnowledgeDocument } from "../services/api";
+
+const makeDocument = (overrides: Partial<KnowledgeDocument> = {}): KnowledgeDocument => ({
+  id: 1,
+  agent_id: 1,
+  name: "policy.md",
+  status: "ready",
+  chunk_count: 3,
+  size_bytes: 2048,
+  ...overrides,
+});
+
+describe("knowledge base", () => {
+  it("accepts text documents within the size limit", () => {
+    expect(knowledgeFileError({ name: "notes.md", size: 100, type: "" })).toBeNull();
+    expect(knowledgeFileError({ name: "data", size: 100, type: "application/json" })).toBeNull();
+    expect(knowledgeFileError({ name: "slides.pdf", size: 100, type: "application/pdf" })).toMatch(/only text/);
+    expect(knowledgeFileError({ name: "big.txt", size: 6 * 1024 * 1024, type: "text/plain" })).toMatch(/5 MB/);
+    expect(knowledgeFileError({ name: "empty.txt", size: 0, type: "text/plain" })).toMatch(/empty/);
+  });
+
+  it("describes documents and detects pending indexing", () => {
+    expect(knowledgeDocumentMeta(makeDocument())).toBe("2.0 KB · 3 chunks");
+    expect(knowledgeDocumentMeta(makeDocument({ status: "indexing", size_bytes: null }))).toBe("");
+    expect(isKnowledgeIndexing([makeDocument(), makeDocument({ status: "indexing" })])).toBe(true);
+    expect(isKnowledgeIndexing([makeDocument({ status: "failed" })])).toBe(false);
+  });
+
+  it("only links http(s) pages", () => {
+    expect(isHttpUrl("https://example.com/docs")).toBe(true);
+    expect(isHttpUrl("ftp://example.com")).toBe(false);
+    expect(isHttpUrl("not a url")).toBe(false);
+  });
+});
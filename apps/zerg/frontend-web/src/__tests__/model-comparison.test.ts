@@ -0,0 +1,24 @@
+import { describe, expect, it } from "vitest";
+import { bestResultIndex, relativeToBest } from "../lib/modelComparison";
+import type { ModelComparisonResult } from "../services/api";
+
+function result(model: string, overrides: Partial<ModelComparisonResult> = {}): ModelComparisonResult {
+  return { model, output: "ok", duration_ms: 1000, total_tokens: 100, cost_usd: 0.01, ...overrides };
+}
+
+describe("model comparison", () => {
+  it("picks the lowest value per metric", () => {
+    const results = [result("a", { duration_ms: 1500, cost_usd: 0.002 }), result("b", { duration_ms: 1000 })];
+    expect(bestResultIndex(results, "duration_ms")).toBe(1);
+    expect(bestResultIndex(results, "cost_usd")).toBe(0);
+    expect(relativeToBest(results, 0, "duration_ms")).toBe("+50%");
+    expect(relativeToBest(results, 1, "duration_ms")).toBeNull();
+  });
+
+  it("has no winner on ties, unknown values or failed runs", () => {
+    expect(bestResultIndex([result("a"), result("b")], "total_tokens")).toBeNull();
+    expect(bestResultIndex([result("a", { cost_usd: null }), result("b")], "cost_usd")).toBeNull();
+    const failed = result("b", { duration_ms: 5, error: "timeout" });
+    expect(bestResultIndex([result("a"), failed], "duration_ms")).toBeNull();
+  });
+});
@@ -0,0 +1,42 @@
+import { describe, expect, it } from "vitest";
+import {
+  MODEL_PARAM_FIELDS,
+  draftErrors,
+  draftFromParams,
+  formatModelParam,
+  modelParamError,
+  paramsFromDraft,
+  withModelParams,
+} from "../lib/modelParams";
+
+const field = (key: string) => MODEL_PARAM_FIELDS.find((item) => item.key === key)!;
+
+describe("model params", () => {
+  it("round-trips overrides through the form draft", () => {
+    const draft = draftFromParams({ temperature: 0.2, stop: ["END", "###"] });
+    expect(draft).toEqual({ temperature: "0.2", top_p: "", max_tokens: "", stop: "END\n###" });
+    expect(paramsFromDraft({ ...draft, max_tokens: "800", stop: "END\n\nEND\n" })).toEqual({
+      temperature: 0.2,
+      max_tokens: 800,
+      stop: ["END"],
+    });
+  });
+
+  it("validates ranges like the backend", () => {
+    expect(modelParamError(field("temperature"), "")).toBeNull();
+    expect(modelParamError(field("temperature"), "2.5")).toMatch(/between 0 and 2/);
+    expect(modelParamError(field("top_p"), "abc")).toMatch(/between 0 and 1/);
+    expect(modelParamError(field("max_tokens"), "10.5")).toMatch(/whole number/);
+    expect(draftErrors({ temperature: "1", top_p: "", max_tokens: "", stop: "a\nb\nc\nd\ne" })).toEqual([
+      "At most 4 stop sequences are allowed",
+    ]);
+  });
+
+  it("merges into the config and drops an empty override set", () => {
+    const config = { budget: { period: "daily", limit_usd: 1 }, model_params: { temperature: 1 } };
+    expect(withModelParams(config, { top_p: 0.5 })).toEqual({ budget: config.budget, model_params: { top_p: 0.5 } });
+    expect(withModelParams(config, {})).toEqual({ budget: config.budget });
+    expect(formatModelParam("max_tokens", {})).toBe("model default");
+    expect(formatModelParam("stop", { stop: ["END"] })).toBe('"END"');
+  });
+});
@@ -0,0 +1,64 @@
+import { describe, expect, it } from "vitest";
+import type { WorkflowComment } from "../services/api";
+import { groupCommentThreads, mentionNotification, splitMentions, summarizeNodeComments } from "../lib/nodeComments";
+
+function comment(id: number, overrides: Partial<WorkflowComment> = {}): WorkflowComment {
+  return {
+    id,
+    workflow_id: 3,
+    node_id: "node-1",
+    parent_id: null,
+    author_id: 1,
+    author_name: "ada@example.com",
+    body: `comment ${id}`,
+    mentioned_user_ids: [],
+    resolved_at: null,
+    created_at: "2026-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+describe("node comments", () => {
+  it("groups replies under their thread and lists open threads first", () => {
+    const threads = groupCommentThreads([
+      comment(1, { resolved_at: "2026-01-02T00:00:00Z" }),
+      comment(2),
+      comment(3, { parent_id: 1 }),
+      comment(4, { parent_id: 2 }),
+      comment(5, { parent_id: 2 }),
+      comment(6, { node_id: "node-2" }),
+    ]);
+
+    const first = threads.get("node-1")!;
+    expect(first.map((thread) => thread.root.id)).toEqual([2, 1]);
+    expect(first[0].replies.map((reply) => reply.id)).toEqual([4, 5]);
+    expect(first[1].resolved).toBe(true);
+
+    expect(Object.fromEntries(summarizeNodeComments(threads))).toEqual({
+      "node-1": { open: 1, total: 2 },
+      "node-2": { open: 1, total: 1 },
+    });
+  });
+
+  it("highlights email mentions but not bare addresses", () => {
+    expect(splitMentions("ping @grace@example.com. Mail ada@example.com too")).toEqual([
+      { text: "ping " },
+      { text: "@grace@example.com", mention: "grace@example.com" },
+      { text: ". Mail ada@example.com too" },
+    ]);
+    expect(splitMentions("no mentions")).toEqual([{ text: "no mentions" }]);
+  });
+
+  it("turns a mention event into a notification", () => {
+    const entry = mentionNotification({
+      workflow_id: 3,
+      workflow_name: "Triage",
+      node_id: "node-1",
+      comment_id: 9,
+      author_name: "Ada",
+      excerpt: "Can you look?",
+    });
+    expect(entry).toMatchObject({ id: "comment-mention-9", source: "mention", href: "/canvas" });
+    expect(entry.message).toBe('Ada mentioned you on "Triage": Can you look?');
+  });
+});
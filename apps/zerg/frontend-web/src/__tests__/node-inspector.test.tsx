@@ -0,0 +1,113 @@
+import { render, screen } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { describe, expect, it, vi } from "vitest";
+import NodeInspector from "../components/NodeInspector";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+describe("node inspector model", () => {
+  it("maps shelf tool types to engine tool names", () => {
+    const draft = draftFromNode("HTTP Request", { tool_type: "http-request" });
+    expect(draft.toolName).toBe("http_request");
+    expect(draft.params).toBe("{}");
+  });
+
+  it("validates per node type", () => {
+    const base = draftFromNode("Node", {});
+    expect(validateDraft("agent", base, [1])).toEqual({ agentId: "Choose an agent." });
+    expect(validateDraft("agent", { ...base, agentId: "9" }, [1]).agentId).toBe("This agent no longer exists.");
+    expect(validateDraft("tool", { ...base, toolName: "x", params: "[1]" }, []).params).toBe(
+      "Parameters must be a JSON object."
+    );
+    expect(validateDraft("tool", { ...base, toolName: "x", params: "{" }, []).params).toMatch(/^Invalid JSON/);
+    expect(validateDraft("trigger", { ...base, triggerType: "schedule", cron: "nope" }, []).cron).toBeTruthy();
+    expect(validateDraft("conditional", { ...base, label: " " }, [])).toEqual({
+      label: "Give the node a label.",
+      condition: "Enter a condition.",
+    });
+  });
+
+  it("bounds loops and stores switch cases", () => {
+    const loop = draftFromNode("Loop", { max_iterations: 5, condition: "x" });
+    expect(validateDraft("loop", { ...loop, maxIterations: "500" }, []).maxIterations).toBe(
+      "Enter a whole number from 1 to 100."
+    );
+    expect(applyDraft("loop", { condition: "x" }, { ...loop, items: " ${list} ", condition: "" })).toEqual({
+      text: "Loop",
+      items: "${list}",
+      max_iterations: 5,
+    });
+    const draft = { ...draftFromNode("Route", {}), expression: "${classify}", cases: "billing\n\nsupport" };
+    expect(applyDraft("switch", {}, draft)).toMatchObject({ expression: "${classify}", cases: ["billing", "support"] });
+  });
+
+  it("validates and stores HTTP requests", () => {
+    const draft = draftFromNode("Call", { method: "POST", url: "https://x.test", headers: { Accept: "json" } });
+    expect(draft.headers).toBe("Accept: json");
+    expect(validateDraft("http", { ...draft, url: "x.test", headers: "Accept" }, [])).toEqual({
+      url: "The URL must start with http:// or https://.",
+      headers: "Line 1: write each header as Name: value.",
+    });
+    expect(applyDraft("http", {}, { ...draft, headers: "Accept: json\nX-Id: ${a.value}", body: "{}" })).toEqual({
+      text: "Call",
+      method: "POST",
+      url: "https://x.test",
+      headers: { Accept: "json", "X-Id": "${a.value}" },
+      body: "{}",
+    });
+  });
+
+  it("keeps unrelated trigger settings when applying a schedule", () => {
+    const config = {
+      text: "Start",
+      trigger: { type: "manual", config: { enabled: true, params: { keep: 1 }, filters: ["a"] } },
+    };
+    const draft = { ...draftFromNode("Start", config), triggerType: "schedule" as const, cron: "0 9 * * *" };
+    expect(applyDraft("trigger", config, draft).trigger).toEqual({
+      type: "schedule",
+      config: { enabled: true, params: { keep: 1, cron: "0 9 * * *" }, filters: ["a"] },
+    });
+  });
+});
+
+describe("NodeInspector", () => {
+  it("applies agent binding and message only once the form is valid", async () => {
+    const onApply = vi.fn();
+    render(
+      <NodeInspector
+        node={{ id: "agent-1", type: "agent", label: "Agent", config: { text: "Agent", extra: true } }}
+        agents={[{ id: 3, name: "Researcher" }]}
+        onApply={onApply}
+        onClose={vi.fn()}
+      />
+    );
+
+    const apply = screen.getByRole("button", { name: "Apply" });
+    expect(apply).toBeDisabled();
+    expect(screen.getByRole("alert")).toHaveTextContent("Choose an agent.");
+
+    await userEvent.selectOptions(screen.getByLabelText("Agent"), "3");
+    await userEvent.type(screen.getByLabelText("Message"), "Summarise the news");
+    await userEvent.click(apply);
+
+    expect(onApply).toHaveBeenCalledWith("agent-1", {
+      label: "Agent",
+      config: { text: "Agent", extra: true, agent_id: 3, message: "Summarise the news" },
+    });
+  });
+
+  it("discards edits on Escape", async () => {
+    const onClose = vi.fn();
+    render(
+      <NodeInspector
+        node={{ id: "c1", type: "conditional", label: "Check", config: { condition: "1 == 1" } }}
+        agents={[]}
+        onApply={vi.fn()}
+        onClose={onClose}
+      />
+    );
+    const input = screen.getByLabelText("Condition");
+    await userEvent.type(input, " and more{Escape}");
+    expect(onClose).toHaveBeenCalled();
+    expect(input).toHaveValue("1 == 1");
+  });
+});
@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+import { downstreamNodeIds, runFromBlocker, uncachedInputs } from "../lib/nodeOutputCache";
+
+// fetch -> summarize -> notify, with lookup also feeding notify
+const EDGES = [
+  { source: "fetch", target: "summarize" },
+  { source: "summarize", target: "notify" },
+  { source: "lookup", target: "notify" },
+];
+
+describe("node output cache", () => {
+  it("re-runs the node and everything downstream of it", () => {
+    expect(downstreamNodeIds(EDGES, "summarize")).toEqual(new Set(["summarize", "notify"]));
+    expect(downstreamNodeIds(EDGES, "notify")).toEqual(new Set(["notify"]));
+    // Loop bodies point back at the loop without running forever
+    const loop = [
+      { source: "loop", target: "body" },
+      { source: "body", target: "loop" },
+    ];
+    expect(downstreamNodeIds(loop, "body")).toEqual(new Set(["body", "loop"]));
+  });
+
+  it("needs a cached output for every node feeding the re-run part", () => {
+    expect(uncachedInputs(EDGES, "summarize", new Set(["fetch"]))).toEqual(["lookup"]);
+    expect(uncachedInputs(EDGES, "summarize", new Set(["fetch", "lookup"]))).toEqual([]);
+    expect(uncachedInputs(EDGES, "fetch", new Set())).toEqual(["lookup"]);
+  });
+
+  it("explains why a node can't be run from", () => {
+    expect(runFromBlocker(undefined, EDGES, "summarize")).toBe("Run the whole workflow once first");
+    expect(runFromBlocker({ execution_id: null, node_ids: [] }, EDGES, "summarize")).toBe(
+      "Run the whole workflow once first"
+    );
+    const cache = { execution_id: 7, node_ids: ["fetch"] };
+    expect(runFromBlocker(cache, EDGES, "summarize", (id) => id.toUpperCase())).toBe("No cached output for LOOKUP");
+    expect(runFromBlocker({ ...cache, node_ids: ["fetch", "lookup"] }, EDGES, "summarize")).toBeNull();
+  });
+});
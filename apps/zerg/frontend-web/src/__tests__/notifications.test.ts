@@ -0,0 +1,83 @@
+import { describe, expect, it } from "vitest";
+import {
+  addNotification,
+  createNotification,
+  filterNotifications,
+  loadNotifications,
+  markAllRead,
+  markRead,
+  notificationFromToast,
+  unreadCount,
+  withoutUndo,
+  NOTIFICATIONS_STORAGE_KEY,
+} from "../lib/notifications";
+
+const now = new Date("2026-05-01T12:00:00Z");
+
+function entry(id: string, overrides: Partial<Parameters<typeof createNotification>[0]> = {}) {
+  return createNotification({ id, kind: "info", source: "toast", message: id, ...overrides }, now);
+}
+
+describe("notification center model", () => {
+  it("keeps newest first, replaces repeated ids and caps the list", () => {
+    let list = addNotification([], entry("a"));
+    list = addNotification(list, entry("b"));
+    list = addNotification(list, entry("a", { message: "again" }));
+    expect(list.map((item) => [item.id, item.message])).toEqual([
+      ["a", "again"],
+      ["b", "b"],
+    ]);
+    expect(addNotification(list, entry("c"), 2).map((item) => item.id)).toEqual(["c", "a"]);
+  });
+
+  it("tracks unread state", () => {
+    const list = [entry("a"), entry("b"), entry("c")];
+    expect(unreadCount(list)).toBe(3);
+    expect(unreadCount(markRead(list, "b"))).toBe(2);
+    const allRead = markAllRead(list);
+    expect(unreadCount(allRead)).toBe(0);
+    expect(markAllRead(allRead)).toBe(allRead);
+  });
+
+  it("filters by read state, severity and source", () => {
+    const list = [
+      entry("ok", { kind: "success" }),
+      entry("boom", { kind: "error" }),
+      entry("run", { kind: "error", source: "run" }),
+      entry("budget", { kind: "error", source: "budget" }),
+    ];
+    const withRead = markRead(list, "ok");
+    expect(filterNotifications(withRead, "unread").map((item) => item.id)).toEqual(["boom", "run", "budget"]);
+    expect(filterNotifications(list, "errors")).toHaveLength(3);
+    expect(filterNotifications(list, "runs").map((item) => item.id)).toEqual(["run", "budget"]);
+    expect(filterNotifications(list, "all")).toBe(list);
+  });
+
+  it("records plain-text toasts but not loading spinners", () => {
+    expect(notificationFromToast({ id: "1", type: "error", message: "Failed" })).toEqual({
+      id: "toast-1",
+      kind: "error",
+      source: "toast",
+      message: "Failed",
+    });
+    expect(notificationFromToast({ id: "2", type: "blank", message: "Hi" })?.kind).toBe("info");
+    expect(notificationFromToast({ id: "3", type: "loading", message: "Saving…" })).toBeNull();
+    expect(notificationFromToast({ id: "4", type: "success", message: { jsx: true } })).toBeNull();
+  });
+
+  it("drops an entry's undo once used", () => {
+    const list = [entry("deleted", { source: "trash", undo: { kind: "restore-agent", id: 4 } }), entry("other")];
+    const next = withoutUndo(list, "deleted");
+    expect(next[0]).not.toHaveProperty("undo");
+    expect(next[0].message).toBe("deleted");
+    expect(next[1]).toBe(list[1]);
+  });
+
+  it("loads saved history and ignores corrupt storage", () => {
+    const saved = [entry("a"), { junk: true }];
+    const storage = { getItem: (key: string) => (key === NOTIFICATIONS_STORAGE_KEY ? JSON.stringify(saved) : null) };
+    expect(loadNotifications(storage).map((item) => item.id)).toEqual(["a"]);
+    expect(loadNotifications({ getItem: () => "{not json" })).toEqual([]);
+    expect(loadNotifications(undefined)).toEqual([]);
+  });
+});
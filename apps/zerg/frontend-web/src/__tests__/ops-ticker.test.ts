@@ -0,0 +1,42 @@
+import { describe, expect, it } from "vitest";
+import { describeOpsEvent, opsEventLink, opsEventTone, prependEntries, type TickerEntry } from "../lib/opsTicker";
+
+const entry = (id: number): TickerEntry => ({
+  id,
+  receivedAt: "2025-03-14T12:00:00Z",
+  event: { type: "run_started", agent_id: 1, run_id: id },
+});
+
+describe("opsEventLink", () => {
+  it("links run events to the run and agent events to the agent", () => {
+    expect(opsEventLink({ type: "run_failed", agent_id: 3, run_id: 9 })).toBe("/dashboard?agent=3&run=9");
+    expect(opsEventLink({ type: "agent_created", agent_id: 3 })).toBe("/dashboard?agent=3");
+  });
+
+  it("has nothing to open for events without an agent", () => {
+    expect(opsEventLink({ type: "budget_denied", scope: "global" })).toBeNull();
+    expect(opsEventLink({ type: "thread_message_created", thread_id: 4 })).toBeNull();
+  });
+});
+
+describe("describeOpsEvent", () => {
+  it("names the agent and includes failure details", () => {
+    const failed = { type: "run_failed" as const, agent_id: 2, agent_name: "Digest", run_id: 5, error: "timeout" };
+    expect(describeOpsEvent(failed)).toBe("Digest failed run #5: timeout");
+    expect(describeOpsEvent({ type: "budget_denied", scope: "user", user_email: "a@b.c", percent: 100 })).toBe(
+      "Budget for a@b.c exhausted (100%)"
+    );
+  });
+
+  it("maps kinds to tones", () => {
+    expect(opsEventTone("run_failed")).toBe("error");
+    expect(opsEventTone("budget_denied")).toBe("warning");
+  });
+});
+
+describe("prependEntries", () => {
+  it("keeps the newest entries first and drops the oldest past the limit", () => {
+    const merged = prependEntries([entry(2), entry(1)], [entry(4), entry(3)], 3);
+    expect(merged.map((item) => item.id)).toEqual([4, 3, 2]);
+  });
+});
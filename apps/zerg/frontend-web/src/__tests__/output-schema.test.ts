@@ -0,0 +1,54 @@
+import { describe, expect, it } from "vitest";
+import {
+  formatScalar,
+  hasSchemaViolations,
+  outputSchemaFromConfig,
+  parseSchemaDraft,
+  schemaViolationTitle,
+  tableColumns,
+  withOutputSchema,
+} from "../lib/outputSchema";
+
+describe("output schema", () => {
+  it("parses the editor text", () => {
+    expect(parseSchemaDraft("  ")).toEqual({ schema: null, error: null });
+    expect(parseSchemaDraft('{"type": "object"}')).toEqual({ schema: { type: "object" }, error: null });
+    expect(parseSchemaDraft('{"type": ["string", "null"]}').error).toBeNull();
+    expect(parseSchemaDraft("{type: object}").error).toMatch(/^Invalid JSON/);
+    expect(parseSchemaDraft("[1, 2]").error).toBe("The schema must be a JSON object");
+    expect(parseSchemaDraft('{"type": "decimal"}').error).toBe('Unknown type "decimal"');
+  });
+
+  it("merges the schema into the agent config", () => {
+    const config = { budget: { limit_usd: 5 } };
+    const next = withOutputSchema(config, { type: "object" });
+    expect(next).toEqual({ budget: { limit_usd: 5 }, output_schema: { type: "object" } });
+    expect(outputSchemaFromConfig(next)).toBe('{\n  "type": "object"\n}');
+    expect(withOutputSchema(next, null)).toEqual(config);
+    expect(outputSchemaFromConfig(null)).toBe("");
+  });
+
+  it("flags runs with violations", () => {
+    expect(hasSchemaViolations({ schema_errors: ["$.score: 'x' is not of type 'number'"] })).toBe(true);
+    expect(hasSchemaViolations({ schema_errors: [] })).toBe(false);
+    expect(hasSchemaViolations({ schema_errors: null })).toBe(false);
+    const run = { schema_errors: ["$: 'a' is a required property", "$.b: 1 is not of type 'string'"] };
+    expect(schemaViolationTitle(run)).toBe(
+      "Output doesn't match the schema:\n$: 'a' is a required property\n$.b: 1 is not of type 'string'"
+    );
+  });
+
+  it("renders arrays of objects as tables", () => {
+    expect(tableColumns([{ name: "a" }, { name: "b", size: 2 }])).toEqual(["name", "size"]);
+    expect(tableColumns([{ name: "a" }, "b"])).toBeNull();
+    expect(tableColumns([])).toBeNull();
+    expect(tableColumns({ name: "a" })).toBeNull();
+  });
+
+  it("formats scalars", () => {
+    expect(formatScalar("text")).toBe("text");
+    expect(formatScalar(3)).toBe("3");
+    expect(formatScalar(false)).toBe("false");
+    expect(formatScalar(null)).toBe("—");
+  });
+});
@@ -0,0 +1,48 @@
+import { afterEach, describe, expect, it } from "vitest";
+import {
+  RollingSamples,
+  isPerfHudEnabled,
+  measuredReducer,
+  percentile,
+  perfSummary,
+  recordTiming,
+  setPerfHudEnabled,
+} from "../lib/perfMetrics";
+
+afterEach(() => setPerfHudEnabled(false));
+
+describe("perf metrics", () => {
+  it("computes nearest-rank percentiles over a rolling window", () => {
+    expect(percentile([], 0.5)).toBe(0);
+    expect(percentile([1, 2, 3, 4], 0.5)).toBe(3);
+
+    const samples = new RollingSamples(3);
+    [50, 1, 2, 3].forEach((ms) => samples.add(ms));
+    expect(samples.summary()).toEqual({ count: 3, p50: 2, p95: 3, max: 3 });
+  });
+
+  it("records nothing until the HUD is enabled and counts frames over budget", () => {
+    recordTiming("frame", 40);
+    expect(perfSummary().frame.count).toBe(0);
+
+    setPerfHudEnabled(true);
+    expect(isPerfHudEnabled()).toBe(true);
+    expect(window.localStorage.getItem("zerg:perf-hud")).toBe("1");
+    recordTiming("frame", 10);
+    recordTiming("frame", 40);
+    expect(perfSummary().frame).toMatchObject({ count: 2, max: 40 });
+    expect(perfSummary().overBudgetFrames).toBe(1);
+
+    // Turning it off drops what was collected
+    setPerfHudEnabled(false);
+    expect(perfSummary().frame.count).toBe(0);
+    expect(window.localStorage.getItem("zerg:perf-hud")).toBeNull();
+  });
+
+  it("times reducer dispatches without changing their result", () => {
+    setPerfHudEnabled(true);
+    const reducer = measuredReducer((state: number, action: number) => state + action);
+    expect(reducer(1, 2)).toBe(3);
+    expect(perfSummary().reducer.count).toBe(1);
+  });
+});
@@ -0,0 +1,86 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { PersistenceQueue, installPersistenceFlush } from "../lib/persistence";
+
+describe("PersistenceQueue", () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it("coalesces bursts per slice and skips unchanged values", () => {
+    const queue = new PersistenceQueue({ delayMs: 100, maxWaitMs: 1000 });
+    const workflow = vi.fn();
+    const prefs = vi.fn();
+    queue.register("workflow", workflow);
+    queue.register("prefs", prefs);
+    queue.markPersisted("workflow", { nodes: 0 });
+
+    queue.schedule("workflow", { nodes: 1 });
+    queue.schedule("workflow", { nodes: 2 });
+    vi.advanceTimersByTime(100);
+    expect(workflow).toHaveBeenCalledTimes(1);
+    expect(workflow).toHaveBeenCalledWith({ nodes: 2 }, { keepalive: false });
+    expect(prefs).not.toHaveBeenCalled();
+
+    // Same value as the last write, or edited back to it before the write
+    queue.schedule("workflow", { nodes: 2 });
+    queue.schedule("prefs", { theme: "dark" });
+    queue.schedule("prefs", null);
+    queue.markPersisted("prefs", null);
+    vi.advanceTimersByTime(100);
+    expect(workflow).toHaveBeenCalledTimes(1);
+    expect(prefs).not.toHaveBeenCalled();
+  });
+
+  it("writes a steadily edited slice once the max wait is reached", () => {
+    const queue = new PersistenceQueue({ delayMs: 100, maxWaitMs: 250 });
+    const writer = vi.fn();
+    queue.register("workflow", writer);
+
+    // Every edit lands inside the 100ms quiet period, so only the max wait forces a write
+    for (let tick = 1; tick <= 5; tick += 1) {
+      queue.schedule("workflow", tick);
+      vi.advanceTimersByTime(60);
+    }
+    expect(writer).toHaveBeenCalledTimes(1);
+    expect(writer).toHaveBeenCalledWith(5, { keepalive: false });
+  });
+
+  it("retries a value whose write failed", async () => {
+    const queue = new PersistenceQueue({ delayMs: 10 });
+    const writer = vi.fn().mockRejectedValueOnce(new Error("offline")).mockResolvedValue(undefined);
+    queue.register("workflow", writer);
+
+    queue.schedule("workflow", "v1");
+    queue.flush("workflow");
+    expect(writer).toHaveBeenCalledTimes(1);
+    await Promise.resolve();
+    await Promise.resolve();
+
+    queue.schedule("workflow", "v1");
+    queue.flush("workflow");
+    expect(writer).toHaveBeenCalledTimes(2);
+  });
+
+  it("flushes pending slices with keepalive when the page unloads and on unregister", () => {
+    const queue = new PersistenceQueue({ delayMs: 1000 });
+    const workflow = vi.fn();
+    const grid = vi.fn();
+    queue.register("workflow", workflow);
+    const unregisterGrid = queue.register("grid", grid);
+    const uninstall = installPersistenceFlush(queue);
+
+    queue.schedule("grid", 48);
+    unregisterGrid();
+    expect(grid).toHaveBeenCalledWith(48, { keepalive: false });
+
+    queue.schedule("workflow", { nodes: 3 });
+    window.dispatchEvent(new Event("beforeunload"));
+    expect(workflow).toHaveBeenCalledWith({ nodes: 3 }, { keepalive: true });
+    expect(queue.hasPending()).toBe(false);
+    uninstall();
+  });
+});
@@ -0,0 +1,70 @@
+import { describe, expect, it } from "vitest";
+import {
+  dashboardSortFromPrefs,
+  draftFromPrefs,
+  effectivePrefs,
+  prefsFromDraft,
+  validatePreferencesDraft,
+  workspaceDefaultsFromDraft,
+} from "../lib/preferences";
+
+describe("preferences", () => {
+  it("layers stored prefs over the workspace defaults", () => {
+    const merged = effectivePrefs(
+      { theme: "light", timezone: null },
+      { theme: "dark", timezone: "Europe/Berlin", default_model: "gpt-4o" }
+    );
+    expect(merged).toEqual({ theme: "light", timezone: "Europe/Berlin", default_model: "gpt-4o" });
+    expect(effectivePrefs(undefined, null)).toEqual({});
+  });
+
+  it("round-trips settings-page keys and keeps unrelated prefs", () => {
+    const stored = {
+      theme: "high-contrast",
+      timezone: "America/New_York",
+      quiet_hours: { start: "23:00", end: "06:30" },
+      desktop_notifications: true,
+      dashboard_sort: { key: "last_run", ascending: false },
+      shelf_collapsed: true,
+    };
+    const draft = draftFromPrefs(stored);
+    expect(draft).toMatchObject({ theme: "high-contrast", quietHoursEnabled: true, dashboardSort: "last_run" });
+
+    const saved = prefsFromDraft(stored, draft);
+    expect(saved).toMatchObject(stored);
+    expect(saved.default_model).toBeNull();
+    expect(dashboardSortFromPrefs(saved)).toEqual({ key: "last_run", ascending: false });
+  });
+
+  it("clears a key when the field is reset to the default", () => {
+    const stored = { theme: "light", dashboard_scope: "all" };
+    const saved = prefsFromDraft(stored, { ...draftFromPrefs(stored), theme: "", dashboardScope: "" });
+    expect(saved.theme).toBeNull();
+    expect(saved.dashboard_scope).toBeNull();
+  });
+
+  it("flags invalid timezones, empty quiet hours and retired models", () => {
+    const draft = {
+      ...draftFromPrefs({}),
+      timeZone: "Mars/Olympus",
+      quietHoursEnabled: true,
+      quietHours: { start: "08:00", end: "08:00" },
+      defaultModel: "gpt-2",
+    };
+    const errors = validatePreferencesDraft(draft, ["gpt-4o"]);
+    expect(Object.keys(errors).sort()).toEqual(["defaultModel", "quietHours", "timeZone"]);
+    expect(validatePreferencesDraft(draftFromPrefs({ timezone: "auto" }), [])).toEqual({});
+  });
+
+  it("builds workspace defaults from the admin draft", () => {
+    const draft = { ...draftFromPrefs({}), theme: "light", dashboardSort: "name", dashboardAscending: false };
+    expect(workspaceDefaultsFromDraft(draft)).toEqual({
+      theme: "light",
+      timezone: null,
+      time_display: null,
+      default_model: null,
+      dashboard_scope: null,
+      dashboard_sort: { key: "name", ascending: false },
+    });
+  });
+});
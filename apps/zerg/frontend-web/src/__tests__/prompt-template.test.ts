@@ -0,0 +1,35 @@
+import { describe, expect, it } from "vitest";
+import {
+  findTemplateVariables,
+  isValidVariableName,
+  renderPromptTemplate,
+  undefinedTemplateVariables,
+} from "../lib/promptTemplate";
+
+describe("prompt templates", () => {
+  it("finds distinct placeholders in order", () => {
+    expect(findTemplateVariables("Hi {{ name }}, welcome to {{company}}. Bye {{name}}")).toEqual(["name", "company"]);
+    expect(findTemplateVariables("{{ 1bad }} {single}")).toEqual([]);
+    expect(findTemplateVariables(null)).toEqual([]);
+  });
+
+  it("renders defined placeholders and leaves the rest intact", () => {
+    expect(renderPromptTemplate("You support {{ company }} in {{region}}.", { company: "Acme" })).toBe(
+      "You support Acme in {{region}}."
+    );
+    expect(renderPromptTemplate("{{x}}", { x: "$& literal" })).toBe("$& literal");
+    expect(renderPromptTemplate("", { x: "1" })).toBe("");
+  });
+
+  it("reports placeholders without a value across all texts", () => {
+    expect(undefinedTemplateVariables(["{{a}} {{b}}", "{{c}} {{a}}"], { b: "" })).toEqual(["a", "c"]);
+    expect(undefinedTemplateVariables(["{{toString}}"], {})).toEqual(["toString"]);
+  });
+
+  it("validates variable names", () => {
+    expect(isValidVariableName("company_name")).toBe(true);
+    expect(isValidVariableName("_x1")).toBe(true);
+    expect(isValidVariableName("1x")).toBe(false);
+    expect(isValidVariableName("with-dash")).toBe(false);
+  });
+});
@@ -0,0 +1,71 @@
+import { describe, expect, it } from "vitest";
+import {
+  buildQuickSwitchIndex,
+  fuzzyScore,
+  matchPositions,
+  quickSwitchPath,
+  quickSwitchTargets,
+  searchQuickSwitch,
+  splitTagQuery,
+} from "../lib/quickSwitcher";
+
+const index = buildQuickSwitchIndex(
+  [
+    { id: 1, name: "Daily Digest" },
+    { id: 2, name: "Deploy Guard" },
+    { id: 3, name: "Inbox triage" },
+  ],
+  [{ id: 9, name: "Nightly deploy" }]
+);
+
+describe("quick switcher", () => {
+  it("matches subsequences and rejects the rest", () => {
+    expect(fuzzyScore("dd", "daily digest")).toBeGreaterThan(0);
+    expect(fuzzyScore("xyz", "daily digest")).toBe(-1);
+    expect(fuzzyScore("daily digests", "daily digest")).toBe(-1);
+  });
+
+  it("ranks prefixes and word starts above scattered matches", () => {
+    const names = searchQuickSwitch(index, "dep").map((match) => match.entry.name);
+    expect(names).toEqual(["Deploy Guard", "Nightly deploy"]);
+    expect(searchQuickSwitch(index, "tri")[0].entry.name).toBe("Inbox triage");
+  });
+
+  it("keeps only the best matches and lists the index for an empty query", () => {
+    const many = buildQuickSwitchIndex(Array.from({ length: 3000 }, (_, id) => ({ id, name: `agent ${id}` })), []);
+    const top = searchQuickSwitch(many, "agent 12", 5);
+    expect(top).toHaveLength(5);
+    expect(top[0].entry.name).toBe("agent 12");
+    expect(searchQuickSwitch(index, "  ", 2).map((match) => match.entry.id)).toEqual([1, 2]);
+  });
+
+  it("highlights the matched characters", () => {
+    expect(matchPositions("dg", "deploy guard")).toEqual([0, 7]);
+    expect(matchPositions("zz", "deploy guard")).toEqual([]);
+  });
+
+  it("links agents to chat or settings and workflows to the canvas", () => {
+    const [agent] = index;
+    const workflow = index[index.length - 1];
+    expect(quickSwitchTargets(agent)).toEqual(["chat", "config"]);
+    expect(quickSwitchPath(agent, "chat")).toBe("/agent/1/thread/?name=Daily%20Digest");
+    expect(quickSwitchPath(agent, "config")).toBe("/dashboard?agent=1&settings=1");
+    expect(quickSwitchPath(workflow, "canvas")).toBe("/canvas?workflow=9");
+  });
+
+  it("filters by #tag prefixes before matching the name", () => {
+    const tagged = buildQuickSwitchIndex(
+      [
+        { id: 1, name: "Daily Digest", tags: ["Reports"] },
+        { id: 2, name: "Deploy Guard", tags: ["ops", "reports"] },
+        { id: 3, name: "Inbox triage" },
+      ],
+      [{ id: 9, name: "Nightly deploy", tags: ["Ops"] }]
+    );
+    expect(splitTagQuery("#ops  deploy #Rep")).toEqual({ text: "deploy", tags: ["ops", "rep"] });
+    expect(searchQuickSwitch(tagged, "#ops").map((match) => match.entry.id)).toEqual([2, 9]);
+    expect(searchQuickSwitch(tagged, "#rep #op").map((match) => match.entry.id)).toEqual([2]);
+    expect(searchQuickSwitch(tagged, "#ops nightly").map((match) => match.entry.id)).toEqual([9]);
+    expect(searchQuickSwitch(tagged, "#nope")).toEqual([]);
+  });
+});
@@ -0,0 +1,60 @@
+import { describe, expect, it } from "vitest";
+import {
+  AGENT_CONTRACT_FIELDS,
+  agentContractJson,
+  agentEditPatch,
+  parseRawAgentDraft,
+  stableStringify,
+} from "../lib/rawAgentJson";
+import type { Agent } from "../services/api";
+
+const agent = {
+  id: 4,
+  owner_id: 1,
+  name: "Digest",
+  model: "gpt-4o-mini",
+  status: "idle",
+  system_instructions: "Be brief",
+  task_instructions: "Summarise",
+  config: { temperature: 0.2, output_schema: { type: "object" } },
+  created_at: "2026-10-14T12:00:00Z",
+  updated_at: "2026-10-14T12:00:00Z",
+} as unknown as Agent;
+
+describe("raw agent JSON", () => {
+  it("lists every contract field and nothing else", () => {
+    const fields = JSON.parse(agentContractJson(agent));
+    expect(Object.keys(fields)).toEqual([...AGENT_CONTRACT_FIELDS]);
+    expect(fields.name).toBe("Digest");
+    expect(fields.schedule).toBeNull();
+    expect(fields.id).toBeUndefined();
+  });
+
+  it("rejects text that isn't a JSON object", () => {
+    expect(parseRawAgentDraft('{"name": "x"}')).toEqual({ value: { name: "x" }, error: null });
+    expect(parseRawAgentDraft("[1]").error).toBe("The agent must be a JSON object");
+    expect(parseRawAgentDraft('{"name": ').error).toMatch(/^Invalid JSON/);
+  });
+
+  it("sends only changed fields with their loaded values as the base", () => {
+    const original = JSON.parse(agentContractJson(agent));
+    const edited = {
+      ...original,
+      name: "Daily digest",
+      // Same config with its keys reordered isn't a change
+      config: { output_schema: { type: "object" }, temperature: 0.2 },
+      extra_field: true,
+    };
+    delete edited.description;
+
+    expect(agentEditPatch(original, edited)).toEqual({
+      changes: { name: "Daily digest", extra_field: true },
+      base: { name: "Digest" },
+    });
+    expect(agentEditPatch(original, original).changes).toEqual({});
+  });
+
+  it("stringifies objects with sorted keys", () => {
+    expect(stableStringify({ b: [1, { d: 1, c: 2 }], a: null })).toBe('{"a":null,"b":[1,{"c":2,"d":1}]}');
+  });
+});
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+import { activeRun, isRunCancellable, markRunCancelling } from "../lib/runCancellation";
+
+describe("run cancellation helpers", () => {
+  it("only queued and running runs can be cancelled", () => {
+    expect(isRunCancellable({ status: "running" })).toBe(true);
+    expect(isRunCancellable({ status: "queued" })).toBe(true);
+    expect(isRunCancellable({ status: "cancelling" })).toBe(false);
+    expect(isRunCancellable({ status: "success" })).toBe(false);
+    expect(isRunCancellable(null)).toBe(false);
+  });
+
+  it("picks the running run before queued ones", () => {
+    const runs = [
+      { id: 3, status: "success" as const },
+      { id: 2, status: "running" as const },
+      { id: 1, status: "running" as const },
+    ];
+    expect(activeRun(runs)?.id).toBe(2);
+    // A run waiting in the queue is newer than the one holding the agent
+    expect(activeRun([{ id: 4, status: "queued" as const }, ...runs])?.id).toBe(2);
+    expect(activeRun([{ id: 1, status: "failed" as const }])).toBeNull();
+    expect(activeRun(undefined)).toBeNull();
+  });
+
+  it("marks only the targeted active run as cancelling", () => {
+    const runs = [
+      { id: 2, status: "running" as const },
+      { id: 1, status: "failed" as const },
+    ];
+    expect(markRunCancelling(runs, 2).map((run) => run.status)).toEqual(["cancelling", "failed"]);
+    expect(markRunCancelling(runs, 1)).toEqual(runs);
+  });
+});
@@ -0,0 +1,54 @@
+import { describe, expect, it } from "vitest";
+import {
+  appendRunEvents,
+  describeRunEvent,
+  isRunLive,
+  runEventFor,
+  runEventJson,
+  runEventTone,
+  type RunEventEntry,
+} from "../lib/runEvents";
+import type { RunEventData } from "../generated/ws-messages";
+
+const event = (overrides: Partial<RunEventData> = {}): RunEventData => ({
+  agent_id: 1,
+  run_id: 7,
+  kind: "tool_started",
+  timestamp: "2026-10-14T12:00:00Z",
+  tool_name: "web_search",
+  ...overrides,
+});
+
+const entry = (id: number): RunEventEntry => ({ id, event: event() });
+
+describe("run events", () => {
+  it("picks out run_event frames for the run being inspected", () => {
+    expect(runEventFor({ type: "run_event", data: event() }, 7)).toEqual(event());
+    expect(runEventFor({ type: "run_event", data: event({ run_id: 8 }) }, 7)).toBeNull();
+    expect(runEventFor({ type: "run_update", data: { id: 7 } }, 7)).toBeNull();
+  });
+
+  it("treats queued, running and cancelling runs as live", () => {
+    expect(isRunLive({ status: "running" })).toBe(true);
+    expect(isRunLive({ status: "cancelling" })).toBe(true);
+    expect(isRunLive({ status: "success" })).toBe(false);
+  });
+
+  it("describes each kind with its tool, timing and attempt", () => {
+    expect(describeRunEvent(event())).toBe("Calling web_search");
+    expect(describeRunEvent(event({ kind: "tool_finished", duration_ms: 40 }))).toBe("web_search returned in 40ms");
+    expect(describeRunEvent(event({ kind: "tool_failed", tool_name: undefined }))).toBe("tool failed");
+    expect(describeRunEvent(event({ kind: "retry", attempt: 2 }))).toBe("Retrying (attempt 2)");
+    expect(runEventTone("tool_failed")).toBe("error");
+  });
+
+  it("copies the payload as pretty JSON", () => {
+    expect(JSON.parse(runEventJson(event()))).toEqual(event());
+    expect(runEventJson(event())).toContain('\n  "run_id": 7');
+  });
+
+  it("appends in arrival order and keeps the newest events", () => {
+    const kept = appendRunEvents([entry(1), entry(2)], [entry(3), entry(4)], 3);
+    expect(kept.map((e) => e.id)).toEqual([2, 3, 4]);
+  });
+});
@@ -0,0 +1,65 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { AgentRun } from "../services/api";
+
+const apiMocks = vi.hoisted(() => ({
+  fetchAgentRuns: vi.fn(),
+}));
+
+vi.mock("../services/api", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../services/api")>();
+  return { ...actual, ...apiMocks };
+});
+
+import { fetchAllAgentRuns, runsToCsv, runsToJsonl } from "../lib/runExport";
+
+function makeRun(id: number, overrides: Partial<AgentRun> = {}): AgentRun {
+  return {
+    id,
+    agent_id: 1,
+    thread_id: 10,
+    status: "success",
+    trigger: "manual",
+    started_at: "2025-01-01T00:00:00Z",
+    finished_at: "2025-01-01T00:00:02Z",
+    duration_ms: 2000,
+    total_tokens: 120,
+    total_cost_usd: 0.0012,
+    error: null,
+    ...overrides,
+  } as AgentRun;
+}
+
+describe("run history export", () => {
+  beforeEach(() => {
+    apiMocks.fetchAgentRuns.mockReset();
+  });
+
+  it("pages with before_id until a short page is returned", async () => {
+    apiMocks.fetchAgentRuns
+      .mockResolvedValueOnce([makeRun(5), makeRun(4)])
+      .mockResolvedValueOnce([makeRun(3), makeRun(2)])
+      .mockResolvedValueOnce([makeRun(1)]);
+    const onProgress = vi.fn();
+
+    const runs = await fetchAllAgentRuns(1, { pageSize: 2, onProgress });
+
+    expect(runs.map((run) => run.id)).toEqual([5, 4, 3, 2, 1]);
+    expect(apiMocks.fetchAgentRuns).toHaveBeenNthCalledWith(1, 1, 2, undefined);
+    expect(apiMocks.fetchAgentRuns).toHaveBeenNthCalledWith(2, 1, 2, 4);
+    expect(apiMocks.fetchAgentRuns).toHaveBeenNthCalledWith(3, 1, 2, 2);
+    expect(onProgress).toHaveBeenLastCalledWith(5);
+  });
+
+  it("quotes CSV values containing separators", () => {
+    const csv = runsToCsv([makeRun(1, { status: "failed", error: 'Tool "x" failed, retrying' })]);
+    const [header, row] = csv.trim().split("\r\n");
+    expect(header).toContain("total_cost_usd");
+    expect(row).toContain('"Tool ""x"" failed, retrying"');
+  });
+
+  it("writes one JSON object per line", () => {
+    const lines = runsToJsonl([makeRun(2), makeRun(1)]).trim().split("\n");
+    expect(lines).toHaveLength(2);
+    expect(JSON.parse(lines[0])).toMatchObject({ id: 2, total_tokens: 120, error: null });
+  });
+});
@@ -0,0 +1,39 @@
+import { describe, expect, it } from "vitest";
+import { applyQueueUpdate, moveInQueue, queueBadgeLabel, queuedRuns } from "../lib/runQueue";
+
+const runs = [
+  { id: 5, status: "queued" as const, queue_position: 2 },
+  { id: 4, status: "queued" as const, queue_position: 1 },
+  { id: 3, status: "running" as const, queue_position: null },
+];
+
+describe("run queue helpers", () => {
+  it("lists queued runs in start order", () => {
+    expect(queuedRuns(runs).map((run) => run.id)).toEqual([4, 5]);
+    expect(queuedRuns(undefined)).toEqual([]);
+  });
+
+  it("applies positions from a queue update and clears runs that left", () => {
+    const next = applyQueueUpdate(runs, { queue: [{ id: 5, position: 1, trigger: "schedule" }] });
+    expect(next.map((run) => run.queue_position)).toEqual([1, null, null]);
+    const unchanged = applyQueueUpdate(runs, {
+      queue: [
+        { id: 4, position: 1, trigger: "schedule" },
+        { id: 5, position: 2, trigger: "webhook" },
+      ],
+    });
+    expect(unchanged).toBe(runs);
+  });
+
+  it("moves a run within the queue, clamped to the ends", () => {
+    expect(moveInQueue([1, 2, 3], 3, -1)).toEqual([1, 3, 2]);
+    expect(moveInQueue([1, 2, 3], 1, -1)).toEqual([1, 2, 3]);
+    expect(moveInQueue([1, 2, 3], 1, 5)).toEqual([2, 3, 1]);
+    expect(moveInQueue([1, 2, 3], 9, 1)).toEqual([1, 2, 3]);
+  });
+
+  it("labels the badge", () => {
+    expect(queueBadgeLabel(1)).toBe("1 queued run");
+    expect(queueBadgeLabel(3)).toBe("3 queued runs");
+  });
+});
@@ -0,0 +1,67 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { QueryClient } from "@tanstack/react-query";
+import {
+  parseTrace,
+  recordSessionMessage,
+  replaySession,
+  sanitize,
+  startSessionRecording,
+  stopSessionRecording,
+  traceToJson,
+} from "../lib/sessionRecorder";
+
+describe("session recording", () => {
+  afterEach(() => {
+    stopSessionRecording();
+  });
+
+  it("redacts credentials and shortens long strings", () => {
+    const clean = sanitize({
+      name: "Weather",
+      auth_token: "secret-value",
+      nested: { Authorization: "Bearer abc", headers: [{ api_key: "k" }] },
+      body: "x".repeat(3000),
+      empty_token: "",
+    }) as Record<string, unknown>;
+    expect(clean.name).toBe("Weather");
+    expect(clean.auth_token).toBe("[redacted]");
+    expect(clean.nested).toEqual({ Authorization: "[redacted]", headers: [{ api_key: "[redacted]" }] });
+    expect(String(clean.body)).toMatch(/… \[3000 chars\]$/);
+    expect(clean.empty_token).toBe("");
+  });
+
+  it("records cache updates and messages only while on", () => {
+    const client = new QueryClient();
+    client.setQueryData(["agents"], [{ id: 1 }]);
+    recordSessionMessage("in", { type: "agent_updated" });
+
+    startSessionRecording(client);
+    client.setQueryData(["agents"], [{ id: 1, status: "running" }]);
+    recordSessionMessage("in", { type: "run_update", data: { token: "t" } });
+    const trace = stopSessionRecording()!;
+
+    expect(trace.events.map((event) => event.kind)).toEqual(["query", "ws-in"]);
+    expect(trace.events[0]).toMatchObject({ seq: 1, key: ["agents"], payload: [{ id: 1, status: "running" }] });
+    expect(trace.events[1]).toMatchObject({ type: "run_update", payload: { data: { token: "[redacted]" } } });
+    expect(stopSessionRecording()).toBeNull();
+  });
+
+  it("replays a trace onto a fresh cache, optionally up to a step", () => {
+    const recorded = new QueryClient();
+    startSessionRecording(recorded);
+    recorded.setQueryData(["agents"], [{ id: 1, status: "idle" }]);
+    recorded.setQueryData(["threads", 1], []);
+    recorded.setQueryData(["agents"], [{ id: 1, status: "error" }]);
+    const trace = parseTrace(traceToJson(stopSessionRecording()!));
+
+    const fresh = new QueryClient();
+    expect(replaySession(trace, fresh)).toBe(3);
+    expect(fresh.getQueryData(["agents"])).toEqual([{ id: 1, status: "error" }]);
+
+    replaySession(trace, fresh, { upTo: 2 });
+    expect(fresh.getQueryData(["agents"])).toEqual([{ id: 1, status: "idle" }]);
+    expect(fresh.getQueryData(["threads", 1])).toEqual([]);
+
+    expect(() => parseTrace('{"version": 99, "events": []}')).toThrow("session trace");
+  });
+});
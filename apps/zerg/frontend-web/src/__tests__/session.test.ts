@@ -0,0 +1,59 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import {
+  SESSION_ACTIVE_WINDOW_MS,
+  clearSession,
+  getSession,
+  markSessionExpired,
+  refreshSession,
+  sessionAction,
+  setSessionExpiry,
+  subscribeSession,
+} from "../lib/session";
+
+const MINUTE = 60 * 1000;
+
+describe("session lifetime", () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+    clearSession();
+  });
+
+  it("refreshes active users and warns idle ones before expiry", () => {
+    const now = 1_000_000_000;
+    const idle = now - SESSION_ACTIVE_WINDOW_MS - 1;
+    expect(sessionAction(now, null, now)).toBe("wait");
+    expect(sessionAction(now, now + 20 * MINUTE, now)).toBe("wait");
+    expect(sessionAction(now, now + 4 * MINUTE, now)).toBe("refresh");
+    expect(sessionAction(now, now + 4 * MINUTE, idle)).toBe("wait");
+    expect(sessionAction(now, now + 1 * MINUTE, idle)).toBe("warn");
+    expect(sessionAction(now, now - 1, now)).toBe("expire");
+  });
+
+  it("tracks expiry and notifies subscribers", () => {
+    const listener = vi.fn();
+    const unsubscribe = subscribeSession(listener);
+
+    setSessionExpiry(1800, 0);
+    expect(getSession()).toEqual({ expiresAt: 1_800_000, warning: false, expired: false });
+    markSessionExpired();
+    markSessionExpired();
+    expect(getSession().expired).toBe(true);
+    expect(listener).toHaveBeenCalledTimes(2);
+
+    // Signing back in clears the expired flag
+    setSessionExpiry(1800, 0);
+    expect(getSession().expired).toBe(false);
+    unsubscribe();
+  });
+
+  it("extends the session on refresh and expires it when the server refuses", async () => {
+    const json = () => Promise.resolve({ access_token: "t", expires_in: 1800 });
+    vi.stubGlobal("fetch", vi.fn().mockResolvedValue({ ok: true, status: 200, json }));
+    await expect(refreshSession()).resolves.toBe(true);
+    expect(getSession().expiresAt).toBeGreaterThan(Date.now() + 29 * MINUTE);
+
+    vi.stubGlobal("fetch", vi.fn().mockResolvedValue({ ok: false, status: 401 }));
+    await expect(refreshSession()).resolves.toBe(false);
+    expect(getSession().expired).toBe(true);
+  });
+});
@@ -0,0 +1,64 @@
+import { describe, expect, it } from "vitest";
+import {
+  SHORTCUTS_PREF,
+  actionForEvent,
+  bindingOverrides,
+  bindingsFromPrefs,
+  chordFromEvent,
+  chordKeys,
+  defaultBindings,
+  findConflicts,
+  normalizeChord,
+} from "../lib/shortcuts";
+
+type Modifiers = Partial<Record<"ctrlKey" | "altKey" | "shiftKey" | "metaKey", boolean>>;
+
+function keydown(key: string, code: string, modifiers: Modifiers = {}) {
+  return { key, code, ctrlKey: false, altKey: false, shiftKey: false, metaKey: false, ...modifiers };
+}
+
+describe("shortcut registry", () => {
+  it("builds chords from physical keys in a fixed modifier order", () => {
+    expect(chordFromEvent(keydown("n", "KeyN"))).toBe("N");
+    expect(chordFromEvent(keydown("?", "Slash", { shiftKey: true }))).toBe("Shift+/");
+    // AZERTY "a" key still reports KeyQ
+    expect(chordFromEvent(keydown("a", "KeyQ", { ctrlKey: true, shiftKey: true }))).toBe("Ctrl+Shift+Q");
+    expect(chordFromEvent(keydown("ArrowDown", "ArrowDown"))).toBe("ArrowDown");
+    expect(chordFromEvent(keydown("Shift", "ShiftLeft", { shiftKey: true }))).toBeNull();
+  });
+
+  it("normalizes stored chords and rejects junk", () => {
+    expect(normalizeChord("shift+ctrl+k")).toBe("Ctrl+Shift+K");
+    expect(normalizeChord("Shift++")).toBe("Shift++");
+    expect(normalizeChord("Hyper+K")).toBeNull();
+    expect(normalizeChord(42)).toBeNull();
+    expect(chordKeys("Shift+ArrowUp")).toEqual(["Shift", "↑"]);
+  });
+
+  it("applies valid overrides from prefs and stores only the differences", () => {
+    const prefs = { [SHORTCUTS_PREF]: { "dashboard.createAgent": "ctrl+n", "canvas.toggleSnap": 7 } };
+    const bindings = bindingsFromPrefs(prefs);
+    expect(bindings["dashboard.createAgent"]).toBe("Ctrl+N");
+    expect(bindings["canvas.toggleSnap"]).toBe("Shift+S");
+    expect(bindingOverrides(bindings)).toEqual({ "dashboard.createAgent": "Ctrl+N" });
+    expect(bindingsFromPrefs(null)).toEqual(defaultBindings());
+  });
+
+  it("flags clashes only between actions that can fire on the same page", () => {
+    const defaults = defaultBindings();
+    // Both help actions use Shift+/ but live on different pages
+    expect(findConflicts(defaults)).toEqual({});
+    const clashing = { ...defaults, "dashboard.runAgent": "N" };
+    expect(findConflicts(clashing)).toEqual({
+      "dashboard.createAgent": ["dashboard.runAgent"],
+      "dashboard.runAgent": ["dashboard.createAgent"],
+    });
+  });
+
+  it("resolves a keydown to the action bound in the current scope", () => {
+    const bindings = defaultBindings();
+    expect(actionForEvent(keydown("?", "Slash", { shiftKey: true }), bindings, "canvas")).toBe("canvas.showHelp");
+    expect(actionForEvent(keydown("?", "Slash", { shiftKey: true }), bindings, "dashboard")).toBe("dashboard.showHelp");
+    expect(actionForEvent(keydown("s", "KeyS", { shiftKey: true }), bindings, "dashboard")).toBeNull();
+  });
+});
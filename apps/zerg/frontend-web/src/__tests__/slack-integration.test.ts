@@ -0,0 +1,40 @@
+import { describe, expect, it } from "vitest";
+import { describeSlackTrigger, isSlackAppConnected } from "../lib/slackTrigger";
+import { defaultSlackConfig, slackNodeSummary } from "../lib/slackNode";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+describe("Slack triggers", () => {
+  it("summarises what a trigger listens for", () => {
+    expect(describeSlackTrigger({ event_type: "app_mention" })).toBe("The app is @mentioned in any channel");
+    expect(describeSlackTrigger({ event_type: "message", channel: "C1", keyword: "deploy" })).toBe(
+      "A message is posted in C1 containing “deploy”"
+    );
+    expect(describeSlackTrigger({ event_type: "reaction_added", keyword: "eyes" })).toBe(
+      "A reaction is added in any channel with :eyes:"
+    );
+    expect(describeSlackTrigger({ event_type: "file_shared" })).toBe("Unknown Slack event");
+  });
+
+  it("needs the OAuth app install, not just a webhook", () => {
+    expect(isSlackAppConnected([{ type: "slack", metadata: { connected_via: "oauth", team_id: "T1" } }])).toBe(true);
+    expect(isSlackAppConnected([{ type: "slack", metadata: null }])).toBe(false);
+    expect(isSlackAppConnected([])).toBe(false);
+  });
+});
+
+describe("Send to Slack node", () => {
+  it("edits channel and message in the tool's params", () => {
+    const config = { ...defaultSlackConfig(), tool_type: "slack-message" };
+    expect(slackNodeSummary(config)).toBe("No channel");
+
+    const draft = draftFromNode("Notify", config);
+    expect(validateDraft("tool", draft, [])).toMatchObject({
+      slackChannel: "Enter a channel, e.g. #alerts.",
+      slackText: "Enter the message to send.",
+    });
+
+    const next = applyDraft("tool", config, { ...draft, slackChannel: " #alerts ", slackText: "Done: ${run.value}" });
+    expect(next.static_params).toEqual({ channel: "#alerts", text: "Done: ${run.value}" });
+    expect(slackNodeSummary(next)).toBe("Posts to #alerts");
+  });
+});
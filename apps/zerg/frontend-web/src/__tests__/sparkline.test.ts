@@ -0,0 +1,22 @@
+import { describe, expect, it } from "vitest";
+import { sparklineGeometry } from "../lib/sparkline";
+
+describe("sparklineGeometry", () => {
+  it("scales values into the box with the largest at the top", () => {
+    const { segments, gaps, max } = sparklineGeometry([10, 20, 30], 22, 12);
+    expect(segments).toEqual(["1,11 11,6 21,1"]);
+    expect(gaps).toEqual([]);
+    expect(max).toBe(30);
+  });
+
+  it("breaks the line at missing values", () => {
+    const { segments, gaps } = sparklineGeometry([5, null, 10, 15], 32, 12);
+    expect(segments).toEqual(["1,11", "21,6 31,1"]);
+    expect(gaps).toEqual([11]);
+  });
+
+  it("centres a flat series and handles empty input", () => {
+    expect(sparklineGeometry([7, 7], 10, 10).segments).toEqual(["1,5 9,5"]);
+    expect(sparklineGeometry([], 10, 10)).toEqual({ segments: [], gaps: [], max: 0 });
+  });
+});
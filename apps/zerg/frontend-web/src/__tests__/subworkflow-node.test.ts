@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+import { formatMapping, parseMapping, subworkflowNodeSummary, subworkflowTarget } from "../lib/subworkflowNode";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+describe("Call Workflow node", () => {
+  it("summarises the call target", () => {
+    expect(subworkflowNodeSummary({ workflow_id: 4 }, "Enrich lead")).toBe("Calls Enrich lead");
+    expect(subworkflowNodeSummary({ workflow_id: "4" })).toBe("Calls workflow #4");
+    expect(subworkflowNodeSummary({ workflow_id: null })).toBe("No workflow chosen");
+    expect(subworkflowTarget({ workflow_id: "abc" })).toBeNull();
+  });
+
+  it("round-trips mapping lines", () => {
+    const text = formatMapping({ customer: "${fetch.value.email}", limit: 3 });
+    expect(text).toBe("customer = ${fetch.value.email}\nlimit = 3");
+    expect(parseMapping(`${text}\n\n`)).toEqual({ mapping: { customer: "${fetch.value.email}", limit: "3" } });
+    expect(parseMapping("= nameless")).toEqual({ error: "Line 1: write each mapping as name = value." });
+    expect(parseMapping("a = 1\na = 2")).toEqual({ error: "Line 2: a is mapped twice." });
+  });
+
+  it("edits the target and maps through the inspector", () => {
+    const config = { text: "Enrich", workflow_id: null, inputs: {}, outputs: {} };
+    const draft = draftFromNode("Enrich", config);
+    expect(validateDraft("subworkflow", draft, []).workflowId).toBe("Choose a workflow to call.");
+
+    const next = { ...draft, workflowId: "7", inputs: "email = ${trigger.value.email}", outputs: "bad line" };
+    expect(validateDraft("subworkflow", next, []).outputs).toBe("Line 1: write each mapping as name = value.");
+
+    const valid = { ...next, outputs: "" };
+    expect(validateDraft("subworkflow", valid, [])).toEqual({});
+    expect(applyDraft("subworkflow", config, valid)).toEqual({
+      text: "Enrich",
+      workflow_id: 7,
+      inputs: { email: "${trigger.value.email}" },
+      outputs: {},
+    });
+  });
+});
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+import type { SystemStatus } from "../services/api";
+import { formatErrorRate, overallStatus } from "../lib/systemStatus";
+import { ConnectionStatus } from "../lib/useWebSocket";
+
+const healthy: SystemStatus = {
+  status: "ok",
+  checked_at: "2025-01-01T00:00:00Z",
+  database: "ok",
+  websocket: { available: true, active_connections: 3 },
+  runs_last_hour: 10,
+  errors_last_hour: 0,
+  errors_last_24h: 2,
+  error_rate_last_hour: 0,
+  frontend_errors_last_hour: 0,
+  latency_ms: { p50: 800, p95: 2400 },
+};
+
+describe("system status", () => {
+  it("rolls the API summary and this browser's connection into one status", () => {
+    const inputs = { status: healthy, apiReachable: true, connection: ConnectionStatus.CONNECTED };
+    expect(overallStatus(inputs)).toBe("operational");
+    expect(overallStatus({ ...inputs, status: { ...healthy, status: "degraded" } })).toBe("degraded");
+    expect(overallStatus({ ...inputs, connection: ConnectionStatus.RECONNECTING })).toBe("degraded");
+    // Stale numbers don't hide an outage
+    expect(overallStatus({ ...inputs, apiReachable: false })).toBe("down");
+  });
+
+  it("formats the error rate", () => {
+    expect(formatErrorRate(null)).toBe("No runs");
+    expect(formatErrorRate(0.25)).toBe("25%");
+    expect(formatErrorRate(0.025)).toBe("2.5%");
+  });
+});
@@ -0,0 +1,28 @@
+import { describe, expect, it } from "vitest";
+import { MAX_TAGS, addTag, collectTags, hasTag, matchesTags, normalizeTag, removeTag } from "../lib/tags";
+
+describe("tags", () => {
+  it("normalizes whitespace and compares case-insensitively", () => {
+    expect(normalizeTag("  on   call ")).toBe("on call");
+    expect(hasTag(["Ops"], "ops")).toBe(true);
+    expect(hasTag(null, "ops")).toBe(false);
+  });
+
+  it("adds and removes tags without duplicates", () => {
+    expect(addTag(["ops"], " Triage ")).toEqual(["ops", "Triage"]);
+    expect(addTag(["ops"], "OPS")).toEqual(["ops"]);
+    expect(addTag(["ops"], "   ")).toEqual(["ops"]);
+    expect(addTag(["ops"], "x".repeat(41))).toEqual(["ops"]);
+    const full = Array.from({ length: MAX_TAGS }, (_, i) => `t${i}`);
+    expect(addTag(full, "one more")).toHaveLength(MAX_TAGS);
+    expect(removeTag(["ops", "Triage"], "triage")).toEqual(["ops"]);
+  });
+
+  it("collects tags once each and matches every selected tag", () => {
+    const items = [{ tags: ["ops", "Beta"] }, { tags: ["Ops"] }, { tags: null }, {}];
+    expect(collectTags(items)).toEqual(["Beta", "ops"]);
+    expect(matchesTags(items[0], ["OPS", "beta"])).toBe(true);
+    expect(matchesTags(items[1], ["ops", "beta"])).toBe(false);
+    expect(matchesTags(items[2], [])).toBe(true);
+  });
+});
@@ -0,0 +1,70 @@
+import { describe, expect, it } from "vitest";
+import { canvasPreviewDataUrl, formatRating, parseTags, replaceTemplate } from "../lib/templateGallery";
+import type { WorkflowTemplate } from "../services/api";
+
+const template = (id: number, overrides: Partial<WorkflowTemplate> = {}) =>
+  ({
+    id,
+    name: `T${id}`,
+    rating_avg: null,
+    rating_count: 0,
+    favorite_count: 0,
+    my_rating: null,
+    is_favorite: false,
+    ...overrides,
+  }) as WorkflowTemplate;
+
+function decode(url: string): string {
+  return atob(url.replace("data:image/svg+xml;base64,", ""));
+}
+
+describe("template gallery helpers", () => {
+  it("draws one rect per node and one line per edge", () => {
+    const svg = decode(
+      canvasPreviewDataUrl({
+        nodes: [
+          { id: "a", type: "trigger", position: { x: 0, y: 0 } },
+          { id: "b", type: "agent", position: { x: 400, y: 200 } },
+        ],
+        edges: [
+          { from_node_id: "a", to_node_id: "b" },
+          { from_node_id: "a", to_node_id: "missing" },
+        ],
+      })
+    );
+    // Background plus two nodes
+    expect(svg.match(/<rect/g)).toHaveLength(3);
+    expect(svg.match(/<line/g)).toHaveLength(1);
+    expect(svg).toContain('width="240"');
+  });
+
+  it("keeps nodes inside the thumbnail", () => {
+    const svg = decode(
+      canvasPreviewDataUrl({
+        nodes: [
+          { id: "a", type: "tool", position: { x: -500, y: -500 } },
+          { id: "b", type: "tool", position: { x: 5000, y: 3000 } },
+        ],
+        edges: [],
+      })
+    );
+    const xs = [...svg.matchAll(/<rect x="([\d.-]+)" y="([\d.-]+)"/g)].map((match) => Number(match[1]));
+    expect(Math.min(...xs)).toBeGreaterThanOrEqual(0);
+    expect(Math.max(...xs) + 36).toBeLessThanOrEqual(240);
+  });
+
+  it("summarises ratings", () => {
+    expect(formatRating(template(1))).toBe("No ratings yet");
+    expect(formatRating(template(1, { rating_avg: 4.25, rating_count: 4 }))).toBe("4.3 ★ (4 ratings)");
+  });
+
+  it("drops unfavorited templates from a favorites-only list", () => {
+    const list = [template(1, { is_favorite: true }), template(2, { is_favorite: true })];
+    expect(replaceTemplate(list, template(2, { is_favorite: false }), true).map((t) => t.id)).toEqual([1]);
+    expect(replaceTemplate(list, template(2, { my_rating: 3 }))[1].my_rating).toBe(3);
+  });
+
+  it("parses comma separated tags", () => {
+    expect(parseTags(" email, daily,,email ")).toEqual(["email", "daily"]);
+  });
+});
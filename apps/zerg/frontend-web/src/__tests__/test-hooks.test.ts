@@ -0,0 +1,75 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { QueryClient } from "@tanstack/react-query";
+import { PersistenceQueue } from "../lib/persistence";
+import {
+  busyState,
+  parseSnapshot,
+  restoreAppState,
+  snapshotAppState,
+  waitForQuiescence,
+  type BusyState,
+} from "../lib/testHooks";
+
+const IDLE: BusyState = { fetching: 0, mutating: 0, requests: 0, unsaved: false };
+
+describe("app state snapshots", () => {
+  it("round-trips cached queries through JSON", () => {
+    const source = new QueryClient();
+    source.setQueryData(["agents"], [{ id: 1, status: "idle" }]);
+    source.setQueryData(["threads", 1], [{ id: 7, title: "Chat" }]);
+    const snapshot = parseSnapshot(JSON.stringify(snapshotAppState(source, "/canvas")));
+    expect(snapshot.path).toBe("/canvas");
+
+    const target = new QueryClient();
+    target.setQueryData(["stale"], true);
+    expect(restoreAppState({ ...snapshot, path: "" }, target)).toBe(2);
+    expect(target.getQueryData(["agents"])).toEqual([{ id: 1, status: "idle" }]);
+    expect(target.getQueryData(["threads", 1])).toEqual([{ id: 7, title: "Chat" }]);
+    expect(target.getQueryData(["stale"])).toBeUndefined();
+  });
+
+  it("rejects anything that isn't a snapshot", () => {
+    expect(() => parseSnapshot(JSON.stringify({ events: [] }))).toThrow(/app snapshot/);
+  });
+
+  it("counts unsaved persistence slices as busy", () => {
+    const queue = new PersistenceQueue();
+    queue.register("workflow", vi.fn());
+    expect(busyState(new QueryClient(), queue)).toEqual(IDLE);
+    queue.schedule("workflow", { nodes: 1 });
+    expect(busyState(new QueryClient(), queue).unsaved).toBe(true);
+  });
+});
+
+describe("waitForQuiescence", () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it("resolves after staying idle for the settle period", async () => {
+    let state: BusyState = { ...IDLE, fetching: 1 };
+    let settled = false;
+    waitForQuiescence(() => state, { settleMs: 100 }).then(() => {
+      settled = true;
+    });
+
+    await vi.advanceTimersByTimeAsync(200);
+    expect(settled).toBe(false);
+    state = IDLE;
+    await vi.advanceTimersByTimeAsync(50);
+    expect(settled).toBe(false);
+    await vi.advanceTimersByTimeAsync(100);
+    expect(settled).toBe(true);
+  });
+
+  it("reports what is still busy on timeout", async () => {
+    const waiting = waitForQuiescence(() => ({ ...IDLE, requests: 2, unsaved: true }), { timeoutMs: 500 });
+    const assertion = expect(waiting).rejects.toThrow("Still busy after 500 ms: 2 requests, unsaved changes");
+    await vi.advanceTimersByTimeAsync(600);
+    await assertion;
+  });
+});
@@ -0,0 +1,71 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import {
+  clearTextMeasureCache,
+  estimateNodeSize,
+  getTextMetricsVersion,
+  layoutLabel,
+  measureTextWidth,
+  subscribeToTextMetrics,
+  wrapText,
+} from "../lib/textMeasure";
+
+// jsdom has no canvas, so widths come from the 0.6em-per-glyph fallback:
+// at 10px every character is 6px wide
+const FONT = "500 10px Inter";
+
+describe("textMeasure", () => {
+  afterEach(() => {
+    clearTextMeasureCache();
+  });
+
+  it("measures and caches per text/font pair", () => {
+    expect(measureTextWidth("abcd", FONT)).toBe(24);
+    expect(measureTextWidth("abcd", "500 20px Inter")).toBe(48);
+  });
+
+  it("wraps on word boundaries and breaks overlong words", () => {
+    expect(wrapText("alpha beta gamma", FONT, 66)).toEqual(["alpha beta", "gamma"]);
+    expect(wrapText("abcdefghij", FONT, 30)).toEqual(["abcde", "fghij"]);
+  });
+
+  it("sizes the label box to the widest line within bounds", () => {
+    expect(layoutLabel("Hi", FONT, { minWidth: 40, maxWidth: 120 })).toEqual({ lines: ["Hi"], width: 40 });
+    expect(layoutLabel("Summarise inbox", FONT, { minWidth: 0, maxWidth: 120 })).toEqual({
+      lines: ["Summarise inbox"],
+      width: 90,
+    });
+  });
+
+  it("truncates the last line once maxLines is exceeded", () => {
+    const layout = layoutLabel("one two three four", FONT, { minWidth: 0, maxWidth: 30, maxLines: 2 });
+    expect(layout.lines).toEqual(["one", "two…"]);
+  });
+
+  it("reuses a cached layout until metrics change", () => {
+    const layout = layoutLabel("Summarise inbox", FONT);
+    expect(layoutLabel("Summarise inbox", FONT)).toBe(layout);
+    expect(layoutLabel("Summarise inbox", FONT, { maxWidth: 60 })).not.toBe(layout);
+
+    clearTextMeasureCache();
+    expect(layoutLabel("Summarise inbox", FONT)).not.toBe(layout);
+  });
+
+  it("estimates node size from the wrapped label", () => {
+    // 96px minimum label box plus 64px of chrome; one 13px line sits within the 20px icon height
+    expect(estimateNodeSize("Hi", FONT)).toEqual({ width: 160, height: 46 });
+    // Three lines (the last truncated), 210px at the widest
+    expect(estimateNodeSize("word ".repeat(40), FONT)).toEqual({ width: 274, height: 65 });
+  });
+
+  it("notifies subscribers when the cache is invalidated", () => {
+    const listener = vi.fn();
+    const unsubscribe = subscribeToTextMetrics(listener);
+    const before = getTextMetricsVersion();
+
+    clearTextMeasureCache();
+
+    expect(listener).toHaveBeenCalledTimes(1);
+    expect(getTextMetricsVersion()).toBe(before + 1);
+    unsubscribe();
+  });
+});
@@ -0,0 +1,28 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { THEME_STORAGE_KEY, applyTheme, cachedTheme, themeFromPrefs } from "../lib/theme";
+
+describe("theme helpers", () => {
+  afterEach(() => {
+    window.localStorage.clear();
+    delete document.documentElement.dataset.theme;
+  });
+
+  it("reads the theme pref and ignores unknown values", () => {
+    expect(themeFromPrefs({ theme: "light" })).toBe("light");
+    expect(themeFromPrefs({ theme: "high-contrast" })).toBe("high-contrast");
+    expect(themeFromPrefs({ theme: "solarized" })).toBeNull();
+    expect(themeFromPrefs(null)).toBeNull();
+  });
+
+  it("falls back to dark when nothing valid is cached", () => {
+    expect(cachedTheme({ getItem: () => "light" })).toBe("light");
+    expect(cachedTheme({ getItem: () => "neon" })).toBe("dark");
+    expect(cachedTheme(undefined)).toBe("dark");
+  });
+
+  it("sets the root attribute and caches the choice", () => {
+    applyTheme("high-contrast");
+    expect(document.documentElement.dataset.theme).toBe("high-contrast");
+    expect(window.localStorage.getItem(THEME_STORAGE_KEY)).toBe("high-contrast");
+  });
+});
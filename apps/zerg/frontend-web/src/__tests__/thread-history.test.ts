@@ -0,0 +1,29 @@
+import { describe, expect, it } from "vitest";
+import type { ThreadMessage } from "../services/api";
+import { mergeLatestPage, oldestMessageId, prependOlderPage } from "../lib/threadHistory";
+
+const message = (id: number, content = `#${id}`) => ({ id, role: "user", content }) as ThreadMessage;
+const idsOf = (messages: ThreadMessage[]) => messages.map((msg) => msg.id);
+
+describe("thread history pages", () => {
+  it("keeps paged-in history when the latest page is refetched", () => {
+    const cached = [message(1), message(2), message(3), message(4), message(-99)];
+    const page = [message(3), message(4, "edited"), message(5)];
+    const merged = mergeLatestPage(cached, page);
+    expect(idsOf(merged)).toEqual([1, 2, 3, 4, 5]);
+    expect(merged[3].content).toBe("edited");
+  });
+
+  it("drops history that no longer connects to the latest page", () => {
+    const page = [message(40), message(41)];
+    expect(mergeLatestPage([message(1), message(2)], page)).toBe(page);
+    expect(mergeLatestPage(undefined, page)).toBe(page);
+  });
+
+  it("prepends older pages without duplicates", () => {
+    const current = [message(-5), message(4), message(5)];
+    expect(oldestMessageId(current)).toBe(4);
+    expect(idsOf(prependOlderPage(current, [message(2), message(3), message(4)]))).toEqual([2, 3, -5, 4, 5]);
+    expect(prependOlderPage(current, [message(4)])).toBe(current);
+  });
+});
@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest";
+import { isToolAllowed, setToolAllowed, unusedAllowedTools, withoutUnusedTools } from "../lib/toolAllowlist";
+
+const available = ["http_request", "http_get", "math_eval", "get_current_time"];
+
+describe("tool allowlist helpers", () => {
+  it("treats an empty list as allow-all and honours wildcards", () => {
+    expect(isToolAllowed("math_eval", [])).toBe(true);
+    expect(isToolAllowed("http_get", ["http_*"])).toBe(true);
+    expect(isToolAllowed("math_eval", ["http_*"])).toBe(false);
+  });
+
+  it("toggles single tools without losing the rest", () => {
+    expect(setToolAllowed("math_eval", false, [], available)).toEqual(["http_request", "http_get", "get_current_time"]);
+    expect(setToolAllowed("math_eval", true, ["http_request"], available)).toEqual(["http_request", "math_eval"]);
+    expect(setToolAllowed("math_eval", true, ["math_eval"], available)).toEqual(["math_eval"]);
+    expect(setToolAllowed("http_get", false, ["http_*", "math_eval"], available)).toEqual(["math_eval", "http_request"]);
+  });
+
+  it("finds and drops unused tools", () => {
+    const used = new Set(["http_request"]);
+    expect(unusedAllowedTools([], available, used)).toEqual(["http_get", "math_eval", "get_current_time"]);
+    expect(withoutUnusedTools([], available, used)).toEqual(["http_request"]);
+    expect(withoutUnusedTools([], available, new Set())).toBeNull();
+  });
+});
@@ -0,0 +1,56 @@
+import { describe, expect, it } from "vitest";
+import { argErrorsFromDetail, buildArgs, initialValues, schemaFields } from "../lib/toolForm";
+
+const schema = {
+  type: "object",
+  properties: {
+    url: { type: "string", title: "Url", description: "Where to send it" },
+    method: { type: "string", enum: ["GET", "POST"], default: "GET" },
+    timeout: { anyOf: [{ type: "integer" }, { type: "null" }], default: 30 },
+    verbose: { type: "boolean" },
+    headers: { anyOf: [{ type: "object" }, { type: "null" }] },
+  },
+  required: ["url"],
+};
+
+describe("tool form helpers", () => {
+  it("derives fields from a pydantic-style schema", () => {
+    const fields = schemaFields(schema);
+    expect(fields.map((field) => [field.name, field.kind, field.required])).toEqual([
+      ["url", "string", true],
+      ["method", "string", false],
+      ["timeout", "integer", false],
+      ["verbose", "boolean", false],
+      ["headers", "json", false],
+    ]);
+    expect(fields[0].label).toBe("Url");
+    expect(fields[1].options).toEqual(["GET", "POST"]);
+    expect(fields[4].placeholder).toBe("{}");
+  });
+
+  it("prefills defaults", () => {
+    expect(initialValues(schema)).toEqual({ url: "", method: "GET", timeout: "30", verbose: "", headers: "" });
+  });
+
+  it("converts entered values and omits blank optional fields", () => {
+    const fields = schemaFields(schema);
+    expect(buildArgs(fields, { url: "https://x.test", timeout: "5", verbose: "true", headers: '{"a": 1}' })).toEqual({
+      ok: true,
+      args: { url: "https://x.test", timeout: 5, verbose: true, headers: { a: 1 } },
+    });
+  });
+
+  it("reports per-field problems", () => {
+    const fields = schemaFields(schema);
+    expect(buildArgs(fields, { timeout: "1.5", headers: "{oops" })).toEqual({
+      ok: false,
+      errors: { url: "Required", timeout: "Must be a whole number", headers: "Must be valid JSON" },
+    });
+  });
+
+  it("maps server validation errors onto fields", () => {
+    const detail = [{ loc: ["expression"], msg: "Field required" }, { loc: ["body", "args"], msg: "ignored" }];
+    expect(argErrorsFromDetail(detail)).toEqual({ expression: "Field required" });
+    expect(argErrorsFromDetail("nope")).toEqual({});
+  });
+});
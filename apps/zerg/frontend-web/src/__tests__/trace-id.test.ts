@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+import { ApiError } from "../services/api";
+import { appendTraceId, extractTraceId, getLastTraceId, traceIdFromResponse } from "../lib/traceId";
+
+describe("trace ids", () => {
+  it("reads and remembers the X-Request-ID header", () => {
+    const response = { headers: new Headers({ "X-Request-ID": "a1b2c3d4e5f60718" }) };
+    expect(traceIdFromResponse(response)).toBe("a1b2c3d4e5f60718");
+    expect(getLastTraceId()).toBe("a1b2c3d4e5f60718");
+
+    // Responses without the header don't clobber the last known id
+    expect(traceIdFromResponse({ headers: new Headers() })).toBeNull();
+    expect(getLastTraceId()).toBe("a1b2c3d4e5f60718");
+  });
+
+  it("round-trips the id through an error message", () => {
+    const message = appendTraceId("Something broke", "req-42.abc");
+    expect(message).toBe("Something broke (trace ID: req-42.abc)");
+    expect(extractTraceId(message)).toBe("req-42.abc");
+    expect(appendTraceId("Something broke", null)).toBe("Something broke");
+    expect(extractTraceId("Something broke")).toBeNull();
+    expect(extractTraceId(undefined)).toBeNull();
+  });
+
+  it("includes the trace id in API error messages", () => {
+    const error = new ApiError({ url: "/api/agents", status: 500, body: { detail: "boom" }, traceId: "deadbeef00112233" });
+    expect(error.traceId).toBe("deadbeef00112233");
+    expect(error.message).toBe("Request to /api/agents failed with status 500: boom (trace ID: deadbeef00112233)");
+    expect(extractTraceId(error.message)).toBe("deadbeef00112233");
+  });
+});
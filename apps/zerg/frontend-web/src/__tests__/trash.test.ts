@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+import { mergeTrashed } from "../lib/trash";
+
+describe("trash", () => {
+  it("merges agents and workflows newest deletion first", () => {
+    const agents = [
+      { id: 1, name: "Old agent", deleted_at: "2026-01-01T00:00:00Z" },
+      { id: 2, name: "New agent", deleted_at: "2026-01-03T00:00:00Z" },
+    ];
+    const workflows = [{ id: 1, name: "Sync", deleted_at: "2026-01-02T00:00:00Z" }];
+
+    expect(mergeTrashed(agents, workflows).map((item) => `${item.kind}:${item.name}`)).toEqual([
+      "agent:New agent",
+      "workflow:Sync",
+      "agent:Old agent",
+    ]);
+    expect(mergeTrashed(agents, workflows, 1)).toEqual([
+      { kind: "agent", id: 2, name: "New agent", deletedAt: "2026-01-03T00:00:00Z" },
+    ]);
+  });
+});
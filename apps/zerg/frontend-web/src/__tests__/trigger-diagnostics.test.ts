@@ -0,0 +1,54 @@
+import { describe, expect, it } from "vitest";
+import { diagnoseTrigger, parseSamplePayload } from "../lib/triggerDiagnostics";
+
+const failing = (diagnosis: ReturnType<typeof diagnoseTrigger>) =>
+  diagnosis.checks.filter((check) => !check.passed).map((check) => check.label);
+
+describe("trigger diagnostics", () => {
+  it("rejects samples that are not JSON objects", () => {
+    expect(parseSamplePayload("{")).toHaveProperty("error");
+    expect(parseSamplePayload("[1]")).toEqual({ error: "Sample payload must be a JSON object" });
+    expect(parseSamplePayload('{"a": 1}')).toEqual({ sample: { a: 1 } });
+  });
+
+  it("reports which email filters a message misses", () => {
+    const trigger = {
+      type: "email",
+      config: { filters: { from_contains: ["@vendor.com"], subject_contains: ["invoice"], label_include: ["INBOX"] } },
+    };
+    const sample = { labelIds: ["INBOX"], headers: { From: "alice@example.com", Subject: "Invoice #42" } };
+    const diagnosis = diagnoseTrigger(trigger, sample);
+    expect(diagnosis.fires).toBe(false);
+    expect(failing(diagnosis)).toEqual(["From contains @vendor.com"]);
+    expect(diagnoseTrigger(trigger, { ...sample, headers: { ...sample.headers, From: "b@vendor.com" } }).fires).toBe(
+      true,
+    );
+  });
+
+  it("checks Slack events like the backend, including the envelope and bot posts", () => {
+    const trigger = { type: "slack", config: { event_type: "app_mention", channel: "C1", keyword: "deploy" } };
+    const event = { type: "app_mention", channel: "C1", text: "please Deploy" };
+    expect(diagnoseTrigger(trigger, { type: "event_callback", event }).fires).toBe(true);
+    expect(failing(diagnoseTrigger(trigger, { ...event, bot_id: "B1", channel: "C2" }))).toEqual([
+      "Posted by a person",
+      "In channel C1",
+    ]);
+  });
+
+  it("takes the GitHub event from the header and skips actions for pushes", () => {
+    const config = { repo: "Octo/Hello", events: ["pull_request", "push"], actions: ["opened"] };
+    const trigger = { type: "github", config };
+    const payload = { action: "closed", repository: { full_name: "octo/hello" } };
+    expect(failing(diagnoseTrigger(trigger, payload, { githubEvent: "pull_request" }))).toEqual([
+      "Action is one of opened",
+    ]);
+    expect(diagnoseTrigger(trigger, payload, { githubEvent: "push" }).fires).toBe(true);
+    expect(diagnoseTrigger(trigger, payload, { githubEvent: "issues" }).fires).toBe(false);
+  });
+
+  it("never fires a disabled trigger", () => {
+    const diagnosis = diagnoseTrigger({ type: "email", config: { disabled: true } }, {});
+    expect(diagnosis.fires).toBe(false);
+    expect(failing(diagnosis)).toEqual(["Trigger is enabled"]);
+  });
+});
@@ -0,0 +1,64 @@
+import { describe, expect, it } from "vitest";
+import {
+  formatRemaining,
+  fromLocalInput,
+  pendingWaitFromMessage,
+  toLocalInput,
+  waitNodeSummary,
+} from "../lib/waitNode";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+describe("wait node", () => {
+  it("summarises each mode", () => {
+    expect(waitNodeSummary({ mode: "duration", minutes: 15 })).toBe("For 15 min");
+    expect(waitNodeSummary({ mode: "event", event: "approved" })).toBe("On event “approved”");
+    expect(waitNodeSummary({ mode: "until" })).toBe("No time set");
+    expect(waitNodeSummary({})).toBe("No duration");
+  });
+
+  it("counts down to the deadline", () => {
+    const now = Date.parse("2026-10-14T09:00:00Z");
+    expect(formatRemaining(now + 3_725_000, now)).toBe("1h 2m left");
+    expect(formatRemaining(now + 65_000, now)).toBe("1m 5s left");
+    expect(formatRemaining(now + 400, now)).toBe("1s left");
+    expect(formatRemaining(now - 1, now)).toBe("Resuming…");
+    expect(formatRemaining(null, now)).toBe("Until the event arrives");
+  });
+
+  it("reads node_waiting payloads", () => {
+    const wait = pendingWaitFromMessage({
+      execution_id: 3,
+      node_id: "wait-1",
+      mode: "event",
+      event: "approved",
+      resume_path: "/api/workflow-waits/abc",
+    });
+    expect(wait).toEqual({
+      nodeId: "wait-1",
+      mode: "event",
+      resumeAt: null,
+      event: "approved",
+      resumePath: "/api/workflow-waits/abc",
+    });
+    const resumeAt = "2030-01-01T00:00:00Z";
+    const until = pendingWaitFromMessage({ execution_id: 3, node_id: "w", mode: "until", resume_at: resumeAt });
+    expect(until.resumeAt).toBe(Date.parse(resumeAt));
+  });
+
+  it("round-trips datetime-local values", () => {
+    const iso = fromLocalInput("2030-01-01T09:30");
+    expect(toLocalInput(iso)).toBe("2030-01-01T09:30");
+    expect(fromLocalInput("")).toBe("");
+    expect(toLocalInput("not a date")).toBe("");
+  });
+
+  it("keeps only the selected mode's settings", () => {
+    const config = { text: "Hold", mode: "duration", minutes: 5 };
+    const draft = { ...draftFromNode("Hold", config), waitMode: "event" as const, event: " approved " };
+
+    expect(validateDraft("wait", draft, [])).toEqual({});
+    expect(applyDraft("wait", config, draft)).toEqual({ text: "Hold", mode: "event", event: "approved" });
+    expect(validateDraft("wait", { ...draft, event: "" }, []).event).toBe("Name the event to wait for.");
+    expect(validateDraft("wait", { ...draft, waitMode: "duration", minutes: "-1" }, []).minutes).toBeDefined();
+  });
+});
@@ -0,0 +1,37 @@
+import { describe, expect, it } from "vitest";
+import { diffStats, diffWords } from "../lib/wordDiff";
+
+function rebuild(segments: ReturnType<typeof diffWords>, side: "before" | "after"): string {
+  const skip = side === "before" ? "added" : "removed";
+  return segments
+    .filter((segment) => segment.type !== skip)
+    .map((segment) => segment.text)
+    .join("");
+}
+
+describe("word diff", () => {
+  it("marks changed words and keeps the rest", () => {
+    const segments = diffWords("You are a helpful assistant.", "You are a terse assistant.");
+    expect(segments).toEqual([
+      { type: "equal", text: "You are a " },
+      { type: "removed", text: "helpful" },
+      { type: "added", text: "terse" },
+      { type: "equal", text: " assistant." },
+    ]);
+    expect(diffStats(segments)).toEqual({ added: 1, removed: 1 });
+  });
+
+  it("reassembles both sides exactly, whitespace included", () => {
+    const before = "Summarise the inbox.\n\nSkip newsletters  and promos.";
+    const after = "Summarise the inbox daily.\n\nSkip promos.\nFlag invoices.";
+    const segments = diffWords(before, after);
+    expect(rebuild(segments, "before")).toBe(before);
+    expect(rebuild(segments, "after")).toBe(after);
+  });
+
+  it("handles empty sides", () => {
+    expect(diffWords("", "")).toEqual([]);
+    expect(diffWords("", "New text")).toEqual([{ type: "added", text: "New text" }]);
+    expect(diffStats(diffWords("Old words here", ""))).toEqual({ added: 0, removed: 3 });
+  });
+});
@@ -0,0 +1,50 @@
+import { describe, expect, it, vi } from "vitest";
+import { LAYOUT_COLUMN_GAP, LAYOUT_ROW_GAP, layeredLayout, validateGraph } from "../lib/workflowGraph";
+import { runWorkflowTask } from "../lib/workerBridge";
+
+const node = (id: string, type = "agent") => ({ id, type, width: 100, height: 40 });
+const edge = (source: string, target: string) => ({ source, target });
+
+describe("workflow graph", () => {
+  it("lays nodes out in columns after their furthest predecessor", () => {
+    const nodes = [node("c"), node("b"), node("a", "trigger")];
+    const positions = layeredLayout(nodes, [edge("a", "b"), edge("b", "c"), edge("a", "c")]);
+    const column = 100 + LAYOUT_COLUMN_GAP;
+    expect(positions).toEqual({ a: { x: 0, y: 0 }, b: { x: column, y: 0 }, c: { x: 2 * column, y: 0 } });
+  });
+
+  it("stacks siblings by predecessor order and parks cyclic nodes in a last column", () => {
+    const nodes = [node("root"), node("left"), node("right"), node("x"), node("y")];
+    const edges = [edge("root", "left"), edge("root", "right"), edge("x", "y"), edge("y", "x")];
+    const positions = layeredLayout(nodes, edges);
+    expect(positions.left).toEqual({ x: 180, y: 0 });
+    expect(positions.right).toEqual({ x: 180, y: 40 + LAYOUT_ROW_GAP });
+    expect([positions.x.x, positions.y.x]).toEqual([360, 360]);
+  });
+
+  it("reports structural problems", () => {
+    const issues = validateGraph(
+      [node("a"), node("b"), node("c"), node("lonely")],
+      [edge("a", "b"), edge("b", "a"), edge("c", "c"), edge("c", "ghost")]
+    );
+    expect(issues.map((issue) => [issue.severity, issue.message])).toEqual([
+      ["error", "1 edge(s) point at missing nodes"],
+      ["error", "Node connected to itself"],
+      ["error", "Workflow contains a cycle"],
+      ["warning", "No trigger node; the workflow only runs manually"],
+      ["warning", "1 node(s) not connected"],
+    ]);
+    expect(issues[2].nodeIds).toEqual(["a", "b"]);
+    expect(validateGraph([node("t", "trigger"), node("a")], [edge("t", "a")])).toEqual([]);
+  });
+
+  it("runs tasks inline when Web Workers are unavailable", async () => {
+    const onProgress = vi.fn();
+    const positions = await runWorkflowTask("layout", { nodes: [node("a")], edges: [] }, { onProgress });
+    expect(positions).toEqual({ a: { x: 0, y: 0 } });
+    expect(onProgress).toHaveBeenLastCalledWith(1);
+
+    await expect(runWorkflowTask("parseJson", '{"ok": true}')).resolves.toEqual({ ok: true });
+    await expect(runWorkflowTask("parseJson", "{")).rejects.toThrow();
+  });
+});
@@ -0,0 +1,95 @@
+import { describe, expect, it } from "vitest";
+import {
+  declaredRunInputs,
+  formatInputSpecs,
+  initialRunInputValues,
+  parseInputSpecs,
+  runInputFieldErrors,
+  validateRunInputs,
+  type RunInputSpec,
+} from "../lib/workflowInputs";
+import { applyDraft, draftFromNode, validateDraft } from "../lib/nodeInspector";
+
+const SPECS: RunInputSpec[] = [
+  { name: "email", type: "string", required: true, description: "Who to notify" },
+  { name: "limit", type: "integer", default: 10 },
+  { name: "dry_run", type: "boolean" },
+  { name: "filters", type: "json" },
+];
+
+const manualTrigger = (inputs: unknown) => ({
+  type: "trigger",
+  config: { trigger: { type: "manual", config: { enabled: true, params: { inputs } } } },
+});
+
+describe("workflow run inputs", () => {
+  it("reads the declaration from the manual trigger only", () => {
+    const schedule = {
+      type: "trigger",
+      config: { trigger: { type: "schedule", config: { params: { inputs: [{ name: "ignored" }] } } } },
+    };
+    expect(declaredRunInputs([schedule, manualTrigger(SPECS)])).toEqual(SPECS);
+    expect(declaredRunInputs([schedule])).toEqual([]);
+    expect(declaredRunInputs([manualTrigger([{ name: "" }, { name: "x", type: "date" }])])).toEqual([
+      { name: "x", type: "string" },
+    ]);
+  });
+
+  it("round-trips inspector lines", () => {
+    const text = formatInputSpecs(SPECS);
+    expect(text).toBe(
+      "email: string required # Who to notify\nlimit: integer = 10\ndry_run: boolean\nfilters: json"
+    );
+    expect(parseInputSpecs(`${text}\n\n`)).toEqual({ specs: SPECS });
+    expect(parseInputSpecs("email")).toEqual({
+      error: "Line 1: write each input as name: type, e.g. email: string required.",
+    });
+    expect(parseInputSpecs("when: date")).toEqual({
+      error: "Line 1: the type must be one of string, number, integer, boolean, json.",
+    });
+    expect(parseInputSpecs("a: string\na: number")).toEqual({ error: "Line 2: a is declared twice." });
+    expect(parseInputSpecs("n: integer = lots")).toEqual({ error: "Line 1: the default must be an integer." });
+  });
+
+  it("validates and converts form values", () => {
+    const values = initialRunInputValues(SPECS);
+    expect(values).toEqual({ email: "", limit: "10", dry_run: false, filters: "" });
+
+    expect(validateRunInputs(SPECS, { ...values, limit: "2.5", filters: "{" }).errors).toEqual({
+      email: "required",
+      limit: "must be an integer",
+      filters: "must be valid JSON",
+    });
+    expect(validateRunInputs(SPECS, { ...values, email: "a@example.com", filters: '{"label": "ops"}' })).toEqual({
+      inputs: { email: "a@example.com", limit: 10, dry_run: false, filters: { label: "ops" } },
+      errors: {},
+    });
+  });
+
+  it("maps the server's per-input errors back to fields", () => {
+    expect(
+      runInputFieldErrors([
+        { field: "inputs.email", message: "required" },
+        { field: "name", message: "too long" },
+      ])
+    ).toEqual({ email: "required" });
+    expect(runInputFieldErrors([{ field: "name", message: "too long" }])).toBeNull();
+  });
+
+  it("edits the declaration through the trigger inspector", () => {
+    const config = manualTrigger([]).config;
+    const draft = { ...draftFromNode("Start", config), runInputs: "limit: integer = ten" };
+    expect(validateDraft("trigger", draft, []).runInputs).toBe("Line 1: the default must be an integer.");
+
+    const valid = { ...draft, runInputs: "email: string required" };
+    expect(validateDraft("trigger", valid, [])).toEqual({});
+    const applied = applyDraft("trigger", config, valid);
+    expect(declaredRunInputs([{ type: "trigger", config: applied }])).toEqual([
+      { name: "email", type: "string", required: true },
+    ]);
+    // Switching away from manual drops the declaration
+    const scheduled = applyDraft("trigger", applied, { ...valid, triggerType: "schedule", cron: "0 9 * * *" });
+    expect(scheduled.trigger).toMatchObject({ config: { params: { cron: "0 9 * * *" } } });
+    expect(JSON.stringify(scheduled.trigger)).not.toContain("inputs");
+  });
+});
@@ -0,0 +1,76 @@
+import { describe, expect, it } from "vitest";
+import type { PresenceEditorData } from "../generated/ws-messages";
+import {
+  CONFLICT_WINDOW_MS,
+  EMPTY_PRESENCE,
+  applyPresenceMessage,
+  conflictingEditors,
+  presenceInitials,
+  remoteSelections,
+  uniqueEditors,
+} from "../lib/workflowPresence";
+
+function editor(clientId: string, overrides: Partial<PresenceEditorData> = {}): PresenceEditorData {
+  return {
+    workflow_id: 7,
+    client_id: clientId,
+    user_id: 1,
+    display_name: "Ada Lovelace",
+    cursor: null,
+    selected_node_ids: [],
+    editing_node_id: null,
+    edited_node_id: null,
+    edited_at: null,
+    updated_at: "2026-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+describe("workflow presence", () => {
+  it("tracks remote editors and ignores our own connection", () => {
+    let state = applyPresenceMessage(EMPTY_PRESENCE, {
+      type: "presence_state",
+      data: { workflow_id: 7, self_client_id: "me", editors: [editor("me"), editor("other")] },
+    });
+    expect([...state.editors.keys()]).toEqual(["other"]);
+
+    const afterSelf = applyPresenceMessage(state, { type: "presence_update", data: editor("me") });
+    expect(afterSelf).toBe(state);
+
+    state = applyPresenceMessage(state, { type: "presence_update", data: editor("third", { user_id: 2 }) });
+    expect(state.editors.size).toBe(2);
+
+    state = applyPresenceMessage(state, { type: "presence_left", data: { workflow_id: 7, client_id: "other" } });
+    expect([...state.editors.keys()]).toEqual(["third"]);
+    expect(applyPresenceMessage(state, { type: "agent_updated", data: {} })).toBe(state);
+  });
+
+  it("flags editors that have the node open or changed it recently", () => {
+    const now = Date.parse("2026-01-01T00:10:00Z");
+    const editors = [
+      editor("open", { editing_node_id: "n1" }),
+      editor("recent", { edited_node_id: "n1", edited_at: new Date(now - 1000).toISOString() }),
+      editor("stale", { edited_node_id: "n1", edited_at: new Date(now - CONFLICT_WINDOW_MS - 1).toISOString() }),
+      editor("elsewhere", { editing_node_id: "n2" }),
+    ];
+    expect(conflictingEditors(editors, "n1", now).map((e) => e.client_id)).toEqual(["open", "recent"]);
+  });
+
+  it("groups selections by node and avatars by user", () => {
+    const editors = [
+      editor("a", { selected_node_ids: ["n1", "n2"] }),
+      editor("b", { selected_node_ids: ["n1"] }),
+      editor("c", { user_id: 2 }),
+    ];
+    const selections = remoteSelections(editors);
+    expect(selections.get("n1")?.map((e) => e.client_id)).toEqual(["a", "b"]);
+    expect(selections.get("n2")?.map((e) => e.client_id)).toEqual(["a"]);
+    expect(uniqueEditors(editors).map((e) => e.client_id)).toEqual(["a", "c"]);
+  });
+
+  it("derives initials from names and emails", () => {
+    expect(presenceInitials("Ada Lovelace")).toBe("AL");
+    expect(presenceInitials("grace.hopper@example.com")).toBe("GH");
+    expect(presenceInitials("linus")).toBe("LI");
+  });
+});
@@ -0,0 +1,49 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { FrameRingBuffer, recordFrame, WS_DEBUG_STORAGE_KEY, wsFrameLog } from "../lib/wsFrameLog";
+
+const frame = (type: string) => ({ ts: 0, direction: "in" as const, type, topic: null, size: 2, payload: {} });
+
+describe("FrameRingBuffer", () => {
+  it("keeps only the newest frames once full", () => {
+    const buffer = new FrameRingBuffer(3);
+    ["a", "b", "c", "d", "e"].forEach((type) => buffer.push(frame(type)));
+
+    expect(buffer.size).toBe(3);
+    expect(buffer.snapshot().map((entry) => entry.type)).toEqual(["c", "d", "e"]);
+    expect(buffer.snapshot().map((entry) => entry.seq)).toEqual([3, 4, 5]);
+  });
+
+  it("returns a stable snapshot until the buffer changes", () => {
+    const buffer = new FrameRingBuffer(2);
+    buffer.push(frame("a"));
+    const first = buffer.snapshot();
+    expect(buffer.snapshot()).toBe(first);
+
+    buffer.clear();
+    expect(buffer.snapshot()).not.toBe(first);
+    expect(buffer.snapshot()).toEqual([]);
+  });
+});
+
+describe("recordFrame", () => {
+  afterEach(() => {
+    window.localStorage.removeItem(WS_DEBUG_STORAGE_KEY);
+    wsFrameLog.clear();
+  });
+
+  it("records nothing unless WebSocket debugging is enabled", () => {
+    recordFrame("in", '{"type":"ping"}', { type: "ping" });
+    expect(wsFrameLog.size).toBe(0);
+  });
+
+  it("records frames and exposes them on window when enabled", () => {
+    window.localStorage.setItem(WS_DEBUG_STORAGE_KEY, "1");
+    recordFrame("out", '{"type":"subscribe","topic":"agent:1"}', { type: "subscribe", topic: "agent:1" });
+
+    const [recorded] = wsFrameLog.snapshot();
+    expect(recorded).toMatchObject({ direction: "out", type: "subscribe", topic: "agent:1", size: 38 });
+    expect(window.__zergWsDebug?.getFrameCount()).toBe(1);
+    window.__zergWsDebug?.clearFrames();
+    expect(wsFrameLog.size).toBe(0);
+  });
+});
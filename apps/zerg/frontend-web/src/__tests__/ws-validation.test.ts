@@ -0,0 +1,92 @@
+import { describe, expect, it, vi } from "vitest";
+import { SchemaMismatchLog, checkIncomingEnvelope, validateEnvelope } from "../lib/wsValidation";
+
+const envelope = (type: string, data: unknown) => ({ v: 1, type, topic: "agent:3", ts: 1700000000000, data });
+const run = { id: 9, agent_id: 3, status: "running" };
+
+describe("validateEnvelope", () => {
+  it("accepts messages that match the protocol", () => {
+    expect(validateEnvelope(envelope("run_update", run))).toEqual([]);
+    expect(validateEnvelope({ ...envelope("agent_state", { id: 3, last_error: null }), req_id: null })).toEqual([]);
+  });
+
+  it("points at each field that doesn't match", () => {
+    expect(validateEnvelope(envelope("run_update", { ...run, status: "exploded", agent_id: "3" }))).toEqual([
+      { path: "data.agent_id", message: "should be integer, got string" },
+      { path: "data.status", message: expect.stringContaining("should be one of queued, running") },
+    ]);
+    expect(validateEnvelope({ ...envelope("run_update", { id: 9, status: "running" }), v: 2 })).toEqual([
+      { path: "envelope.v", message: "should be 1" },
+      { path: "data.agent_id", message: "is required" },
+    ]);
+  });
+
+  it("accepts every message type the backend sends", () => {
+    const at = (topic: string, type: string, data: unknown) => ({ ...envelope(type, data), topic });
+    const startedAt = "2026-01-05T10:00:00.000000";
+    const sent = [
+      at("system", "pong", { timestamp: 1700000000000 }),
+      at("system", "error", { error: "Unknown topic", details: null }),
+      at("system", "subscribe_ack", { message_id: "sub-1", topics: ["agent:3"] }),
+      at("system", "subscribe_error", { message_id: "sub-1", error: "Forbidden", error_code: null }),
+      at("system", "unsubscribe_success", { message_id: "unsub-1", topics: ["thread:4"] }),
+      at("thread:4", "thread_message", { thread_id: 4, message: { id: 12, role: "user", content: "hi" } }),
+      at("thread:4", "stream_start", { thread_id: 4 }),
+      at("thread:4", "stream_chunk", { thread_id: 4, chunk_type: "assistant_token", content: "Hel" }),
+      at("thread:4", "stream_end", { thread_id: 4 }),
+      at("thread:4", "assistant_id", { thread_id: 4, message_id: 13 }),
+      at("agent:3", "agent_event", { id: 3, status: "running" }),
+      at("agent:3", "run_update", run),
+      at("agent:3", "run_queue_update", { agent_id: 3, queue: [] }),
+      at("agent:3", "run_event", { agent_id: 3, run_id: 9, kind: "tool_started", timestamp: startedAt }),
+      at("user:1", "user_update", { id: 1, display_name: "Ada" }),
+      at("user:1", "comment_mention", {
+        workflow_id: 7,
+        workflow_name: "Digest",
+        node_id: "node-1",
+        comment_id: 2,
+        author_name: "Ada",
+        excerpt: "@you look",
+      }),
+      at("workflow_execution:5", "execution_started", { execution_id: 5, timestamp: startedAt }),
+      at("workflow_execution:5", "node_state", { execution_id: 5, node_id: "node-1", phase: "running" }),
+      at("workflow_execution:5", "workflow_progress", {
+        execution_id: 5,
+        completed_nodes: ["node-1"],
+        node_outputs: { "node-1": { value: 42 } },
+        error: null,
+        timestamp: startedAt,
+      }),
+      at("workflow_execution:5", "node_log", { execution_id: 5, node_id: "node-1", stream: "stdout", text: "ok" }),
+      at("workflow_execution:5", "execution_finished", { execution_id: 5, result: "success", duration_ms: 120 }),
+      at("ops:events", "ops_event", { type: "run_started", agent_id: 3, run_id: 9 }),
+      at("workflow:7:presence", "presence_left", { workflow_id: 7, client_id: "c-2" }),
+    ];
+    sent.forEach((message) => expect([message.type, validateEnvelope(message)]).toEqual([message.type, []]));
+  });
+
+  it("flags message types the protocol doesn't define", () => {
+    expect(validateEnvelope(envelope("mystery", {}))).toEqual([
+      { path: "envelope.type", message: '"mystery" is not a message type in the protocol' },
+    ]);
+  });
+});
+
+describe("checkIncomingEnvelope", () => {
+  it("counts mismatches per message type", () => {
+    const warn = vi.spyOn(console, "warn").mockImplementation(() => {});
+    const log = new SchemaMismatchLog();
+    expect(checkIncomingEnvelope(envelope("run_update", run), { log, panic: false })).toBe(true);
+    checkIncomingEnvelope(envelope("run_update", {}), { log, panic: false });
+    checkIncomingEnvelope(envelope("run_update", { ...run, id: 0 }), { log, panic: false });
+    checkIncomingEnvelope(envelope("mystery", {}), { log, panic: false });
+
+    expect(log.total).toBe(3);
+    expect(log.snapshot().map((entry) => [entry.type, entry.count])).toEqual([
+      ["run_update", 2],
+      ["mystery", 1],
+    ]);
+    expect(log.snapshot()[0].lastIssues[0]).toEqual({ path: "data.id", message: "should be at least 1" });
+    warn.mockRestore();
+  });
+});
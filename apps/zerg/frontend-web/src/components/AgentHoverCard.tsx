@@ -0,0 +1,257 @@
+import { useCallback, useEffect, useLayoutEffect, useRef, useState, type ReactNode } from "react";
+import { createPortal } from "react-dom";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { useNavigate } from "react-router-dom";
+import toast from "react-hot-toast";
+import clsx from "clsx";
+import { runAgent } from "../services/api";
+import { useAgentQuickStats } from "../hooks/useAgentQuickStats";
+import { isImeComposing } from "../lib/ime";
+import { MessageCircleIcon, PlayIcon, EyeIcon } from "./icons";
+import "../styles/agent-hover-card.css";
+
+const OPEN_DELAY_MS = 300;
+const CLOSE_DELAY_MS = 150;
+const CARD_WIDTH = 280;
+const VIEWPORT_MARGIN = 8;
+
+interface AgentHoverCardProps {
+  agentId: number;
+  // Fallback name while agent details are loading
+  agentName?: string;
+  children: ReactNode;
+  className?: string;
+  // Override the "Open" action; defaults to the agent's dashboard row
+  onOpen?: (agentId: number) => void;
+}
+
+type CardPosition = { top: number; left: number };
+
+function formatRelativeTime(value: string | null): string {
+  if (!value) {
+    return "Never";
+  }
+  const timestamp = new Date(value).getTime();
+  if (Number.isNaN(timestamp)) {
+    return "-";
+  }
+  const seconds = Math.round((Date.now() - timestamp) / 1000);
+  if (seconds < 60) return "just now";
+  if (seconds < 3600) return `${Math.floor(seconds / 60)}m ago`;
+  if (seconds < 86400) return `${Math.floor(seconds / 3600)}h ago`;
+  return `${Math.floor(seconds / 86400)}d ago`;
+}
+
+function Sparkline({ points }: { points: boolean[] }) {
+  if (points.length === 0) {
+    return <span className="agent-hover-card__muted">No finished runs</span>;
+  }
+  const barWidth = 6;
+  const gap = 2;
+  const width = points.length * (barWidth + gap) - gap;
+  return (
+    <svg
+      className="agent-hover-card__sparkline"
+      width={width}
+      height={16}
+      viewBox={`0 0 ${width} 16`}
+      role="img"
+      aria-label={`${points.filter(Boolean).length} of ${points.length} recent runs succeeded`}
+    >
+      {points.map((success, index) => (
+        <rect
+          key={index}
+          x={index * (barWidth + gap)}
+          // Failures render as short bars so the pattern reads without colour
+          y={success ? 0 : 8}
+          width={barWidth}
+          height={success ? 16 : 8}
+          rx={1}
+          className={success ? "spark-success" : "spark-failed"}
+        />
+      ))}
+    </svg>
+  );
+}
+
+/**
+ * Wraps an agent name and shows a quick-stats card on hover or keyboard focus.
+ *
+ * The card is portaled to <body> so it escapes table cells and the canvas
+ * transform; events inside it are stopped so they don't reach row handlers.
+ */
+export function AgentHoverCard({ agentId, agentName, children, className, onOpen }: AgentHoverCardProps) {
+  const navigate = useNavigate();
+  const queryClient = useQueryClient();
+  const triggerRef = useRef<HTMLSpanElement | null>(null);
+  const openTimerRef = useRef<number | null>(null);
+  const closeTimerRef = useRef<number | null>(null);
+  const [isOpen, setIsOpen] = useState(false);
+  const [position, setPosition] = useState<CardPosition | null>(null);
+
+  const { stats, isLoadingAgent, isLoadingRuns } = useAgentQuickStats(agentId, isOpen);
+
+  const clearTimers = useCallback(() => {
+    if (openTimerRef.current !== null) {
+      window.clearTimeout(openTimerRef.current);
+      openTimerRef.current = null;
+    }
+    if (closeTimerRef.current !== null) {
+      window.clearTimeout(closeTimerRef.current);
+      closeTimerRef.current = null;
+    }
+  }, []);
+
+  useEffect(() => clearTimers, [clearTimers]);
+
+  const scheduleOpen = useCallback(() => {
+    clearTimers();
+    openTimerRef.current = window.setTimeout(() => setIsOpen(true), OPEN_DELAY_MS);
+  }, [clearTimers]);
+
+  const scheduleClose = useCallback(() => {
+    clearTimers();
+    closeTimerRef.current = window.setTimeout(() => setIsOpen(false), CLOSE_DELAY_MS);
+  }, [clearTimers]);
+
+  useLayoutEffect(() => {
+    if (!isOpen || !triggerRef.current) {
+      return;
+    }
+    const rect = triggerRef.current.getBoundingClientRect();
+    const maxLeft = window.innerWidth - CARD_WIDTH - VIEWPORT_MARGIN;
+    setPosition({
+      top: rect.bottom + 6,
+      left: Math.max(VIEWPORT_MARGIN, Math.min(rect.left, maxLeft)),
+    });
+  }, [isOpen]);
+
+  useEffect(() => {
+    if (!isOpen) {
+      return;
+    }
+    // Scrolling moves the trigger away from the fixed-position card
+    const close = () => setIsOpen(false);
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.key === "Escape" && !isImeComposing(event)) {
+        close();
+      }
+    };
+    window.addEventListener("scroll", close, true);
+    window.addEventListener("keydown", handleKeyDown);
+    return () => {
+      window.removeEventListener("scroll", close, true);
+      window.removeEventListener("keydown", handleKeyDown);
+    };
+  }, [isOpen]);
+
+  const runMutation = useMutation({
+    mutationFn: () => runAgent(agentId),
+    onSuccess: () => {
+      toast.success("Agent run started");
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+      queryClient.invalidateQueries({ queryKey: ["agent-runs", agentId] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to run agent: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const displayName = stats.agent?.name ?? agentName ?? `Agent ${agentId}`;
+
+  const handleChat = () => {
+    setIsOpen(false);
+    navigate(`/agent/${agentId}/thread/?name=${encodeURIComponent(displayName)}`);
+  };
+
+  const handleOpen = () => {
+    setIsOpen(false);
+    if (onOpen) {
+      onOpen(agentId);
+    } else {
+      navigate(`/dashboard?agent=${agentId}`);
+    }
+  };
+
+  const status = stats.status?.toLowerCase() ?? null;
+
+  const card =
+    isOpen && position
+      ? createPortal(
+          <div
+            className="agent-hover-card"
+            role="dialog"
+            aria-label={`${displayName} quick stats`}
+            style={{ top: position.top, left: position.left, width: CARD_WIDTH }}
+            onMouseEnter={clearTimers}
+            onMouseLeave={scheduleClose}
+            // Portal events still bubble through the React tree (e.g. to table rows)
+            onClick={(event) => event.stopPropagation()}
+            onMouseDown={(event) => event.stopPropagation()}
+            onKeyDown={(event) => event.stopPropagation()}
+            data-testid={`agent-hover-card-${agentId}`}
+          >
+            <div className="agent-hover-card__header">
+              <span className="agent-hover-card__name">{displayName}</span>
+              {status ? (
+                <span className={`status-indicator status-${status}`}>{stats.status}</span>
+              ) : isLoadingAgent ? (
+                <span className="agent-hover-card__muted">Loading…</span>
+              ) : null}
+            </div>
+
+            <dl className="agent-hover-card__stats">
+              <div>
+                <dt>Last run</dt>
+                <dd>{isLoadingAgent ? "…" : formatRelativeTime(stats.lastRunAt)}</dd>
+              </div>
+              <div>
+                <dt>Success</dt>
+                <dd>{stats.successRate === null ? "-" : `${stats.successRate.toFixed(0)}%`}</dd>
+              </div>
+              <div>
+                <dt>Owner</dt>
+                <dd>{stats.ownerLabel ?? "-"}</dd>
+              </div>
+            </dl>
+
+            <div className="agent-hover-card__trend">
+              {isLoadingRuns ? <span className="agent-hover-card__muted">Loading runs…</span> : <Sparkline points={stats.sparkline} />}
+            </div>
+
+            <div className="agent-hover-card__actions">
+              <button
+                type="button"
+                onClick={() => runMutation.mutate()}
+                disabled={runMutation.isPending || status === "running"}
+              >
+                <PlayIcon width={14} height={14} /> Run
+              </button>
+              <button type="button" onClick={handleChat}>
+                <MessageCircleIcon width={14} height={14} /> Chat
+              </button>
+              <button type="button" onClick={handleOpen}>
+                <EyeIcon width={14} height={14} /> Open
+              </button>
+            </div>
+          </div>,
+          document.body
+        )
+      : null;
+
+  return (
+    <span
+      ref={triggerRef}
+      className={clsx("agent-hover-trigger", className)}
+      onMouseEnter={scheduleOpen}
+      onMouseLeave={scheduleClose}
+      onFocus={scheduleOpen}
+      onBlur={scheduleClose}
+    >
+      {children}
+      {card}
+    </span>
+  );
+}
+
+export default AgentHoverCard;
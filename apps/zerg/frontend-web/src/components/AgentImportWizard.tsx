@@ -0,0 +1,199 @@
+import { useState, type ChangeEvent } from "react";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import { runWorkflowTask } from "../lib/workerBridge";
+import {
+  importAgent,
+  previewAgentImport,
+  type AgentExport,
+  type AgentImportPreview,
+  type AgentSummary,
+  type ModelConfig,
+} from "../services/api";
+
+interface AgentImportWizardProps {
+  models: ModelConfig[];
+  onClose: () => void;
+  onImported: (agent: AgentSummary) => void;
+}
+
+function errorMessage(error: unknown, fallback: string) {
+  return error instanceof Error ? error.message : fallback;
+}
+
+/**
+ * Import an agent from a JSON export: pick a file, review what doesn't carry
+ * over to this environment, then choose a name/model and whether to keep the
+ * schedule and triggers.
+ */
+export function AgentImportWizard({ models, onClose, onImported }: AgentImportWizardProps) {
+  const [agentExport, setAgentExport] = useState<AgentExport | null>(null);
+  const [preview, setPreview] = useState<AgentImportPreview | null>(null);
+  const [name, setName] = useState("");
+  const [model, setModel] = useState("");
+  const [includeSchedule, setIncludeSchedule] = useState(true);
+  const [includeTriggers, setIncludeTriggers] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  const drawerRef = useFocusTrap<HTMLElement>(true, onClose);
+
+  const handleFile = async (event: ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    if (!file) return;
+    setError(null);
+    setPreview(null);
+
+    let parsed: AgentExport;
+    try {
+      // Exports with long histories can be large; parse off the main thread
+      parsed = (await runWorkflowTask("parseJson", await file.text())) as AgentExport;
+    } catch {
+      setError("That file isn't valid JSON.");
+      return;
+    }
+
+    setBusy(true);
+    try {
+      const result = await previewAgentImport(parsed);
+      setAgentExport(parsed);
+      setPreview(result);
+      setName(result.suggested_name);
+      setModel(
+        result.model_available ? result.model : (models.find((m) => m.is_default) ?? models[0])?.id ?? ""
+      );
+    } catch (err) {
+      setError(errorMessage(err, "Couldn't read the export file."));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleImport = async () => {
+    if (!agentExport || !preview) return;
+    setBusy(true);
+    setError(null);
+    try {
+      const agent = await importAgent(agentExport, {
+        name: name.trim() || undefined,
+        model: model || undefined,
+        include_schedule: includeSchedule,
+        include_triggers: includeTriggers,
+      });
+      onImported(agent);
+    } catch (err) {
+      setError(errorMessage(err, "Import failed."));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div
+      className="agent-settings-backdrop open"
+      onClick={(event) => {
+        if (event.target === event.currentTarget) {
+          onClose();
+        }
+      }}
+      role="presentation"
+    >
+      <aside
+        ref={drawerRef}
+        className="agent-settings-drawer open"
+        role="dialog"
+        aria-modal="true"
+        aria-label="Import agent"
+        tabIndex={-1}
+        data-testid="agent-import-wizard"
+      >
+        <header className="agent-settings-header">
+          <div>
+            <h2>Import agent</h2>
+            <p>Create an agent from a JSON export</p>
+          </div>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close import">
+            ×
+          </button>
+        </header>
+
+        <section className="agent-settings-section">
+          <h3>1. Choose a file</h3>
+          <p className="section-description">
+            Exports leave out secrets: MCP auth tokens and webhook secrets have to be set up again.
+          </p>
+          <input type="file" accept="application/json,.json" aria-label="Agent export file" onChange={handleFile} />
+        </section>
+
+        {preview && (
+          <section className="agent-settings-section">
+            <h3>2. Resolve conflicts</h3>
+            {preview.conflicts.length === 0 ? (
+              <p className="muted">Nothing to resolve – this agent imports as-is.</p>
+            ) : (
+              <ul className="import-conflicts" data-testid="import-conflicts">
+                {preview.conflicts.map((conflict, index) => (
+                  <li key={`${conflict.field}-${index}`}>{conflict.message}</li>
+                ))}
+              </ul>
+            )}
+
+            <div className="import-fields">
+              <label>
+                Name
+                <input type="text" value={name} onChange={(event) => setName(event.target.value)} />
+              </label>
+              <label>
+                Model
+                <select value={model} onChange={(event) => setModel(event.target.value)}>
+                  {!preview.model_available && <option value="">Select a model</option>}
+                  {models.map((option) => (
+                    <option key={option.id} value={option.id}>
+                      {option.display_name}
+                    </option>
+                  ))}
+                </select>
+              </label>
+              {preview.has_schedule && (
+                <label className="import-checkbox">
+                  <input
+                    type="checkbox"
+                    checked={includeSchedule}
+                    onChange={(event) => setIncludeSchedule(event.target.checked)}
+                  />
+                  Keep the schedule
+                </label>
+              )}
+              {preview.trigger_count > 0 && (
+                <label className="import-checkbox">
+                  <input
+                    type="checkbox"
+                    checked={includeTriggers}
+                    onChange={(event) => setIncludeTriggers(event.target.checked)}
+                  />
+                  Recreate {preview.trigger_count === 1 ? "the trigger" : `${preview.trigger_count} triggers`}
+                </label>
+              )}
+            </div>
+          </section>
+        )}
+
+        {error && (
+          <p className="import-error" role="alert">
+            {error}
+          </p>
+        )}
+
+        <footer className="agent-settings-footer">
+          <button type="button" className="btn-secondary" onClick={onClose}>
+            Cancel
+          </button>
+          <button type="button" className="btn-primary" onClick={handleImport} disabled={!preview || !model || busy}>
+            {busy && preview ? "Importing…" : "Import"}
+          </button>
+        </footer>
+      </aside>
+    </div>
+  );
+}
+
+export default AgentImportWizard;
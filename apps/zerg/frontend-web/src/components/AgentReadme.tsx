@@ -0,0 +1,46 @@
+import { useId, useState } from "react";
+import clsx from "clsx";
+import { MarkdownMessage } from "./chat/MarkdownMessage";
+import { hasReadme, readmeSummary } from "../lib/agentReadme";
+
+interface AgentReadmeProps {
+  description: string | null | undefined;
+  className?: string;
+  defaultExpanded?: boolean;
+}
+
+/**
+ * Collapsible panel for an agent's markdown README. Collapsed it shows the
+ * first paragraph as a one-line teaser; renders nothing without a README.
+ */
+export function AgentReadme({ description, className, defaultExpanded = false }: AgentReadmeProps) {
+  const [expanded, setExpanded] = useState(defaultExpanded);
+  const contentId = useId();
+  if (!hasReadme(description)) return null;
+
+  return (
+    <section
+      className={clsx("agent-readme", expanded && "agent-readme--expanded", className)}
+      data-testid="agent-readme"
+    >
+      <button
+        type="button"
+        className="agent-readme-toggle"
+        aria-expanded={expanded}
+        aria-controls={contentId}
+        onClick={() => setExpanded((current) => !current)}
+      >
+        <span className="agent-readme-title">About this agent</span>
+        {!expanded && <span className="agent-readme-summary">{readmeSummary(description)}</span>}
+        <span className="agent-readme-chevron" aria-hidden="true">
+          {expanded ? "▾" : "▸"}
+        </span>
+      </button>
+      {expanded && (
+        <div id={contentId} className="agent-readme-body">
+          <MarkdownMessage content={description} />
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,30 @@
+import { useMemo } from "react";
+import { TimeSeriesChart } from "./TimeSeriesChart";
+import { bucketRunsByDay } from "../lib/chart";
+import { useTimePreferences } from "../lib/useTimePreferences";
+import type { AgentRun } from "../services/api";
+
+const CHART_DAYS = 7;
+
+// "2025-03-14" -> "03-14"; the year only adds noise on a one-week axis
+const shortDay = (label: string) => label.slice(5);
+
+/** Stacked daily succeeded/failed runs for the expanded dashboard row. */
+export default function AgentRunChart({ runs }: { runs: AgentRun[] }) {
+  const { timeZone } = useTimePreferences();
+  const buckets = useMemo(() => bucketRunsByDay(runs, CHART_DAYS, new Date(), timeZone), [runs, timeZone]);
+
+  return (
+    <TimeSeriesChart
+      ariaLabel={`Runs per day over the last ${CHART_DAYS} days`}
+      labels={buckets.labels}
+      formatLabel={shortDay}
+      height={140}
+      stacked
+      series={[
+        { key: "succeeded", label: "Succeeded", tone: "success", values: buckets.succeeded },
+        { key: "failed", label: "Failed", tone: "error", values: buckets.failed },
+      ]}
+    />
+  );
+}
@@ -0,0 +1,166 @@
+import { useState, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import {
+  fetchAgentShares,
+  isApiError,
+  removeAgentShare,
+  shareAgent,
+  type AgentShare,
+  type AgentSummary,
+  type SharePermission,
+} from "../services/api";
+import { SHARE_PERMISSION_LABELS } from "../lib/agentSharing";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import "../styles/css/agent-share.css";
+
+interface AgentShareDialogProps {
+  agent: Pick<AgentSummary, "id" | "name">;
+  onClose: () => void;
+}
+
+const PERMISSIONS = Object.keys(SHARE_PERMISSION_LABELS) as SharePermission[];
+
+function shareLabel(share: AgentShare): string {
+  return share.user_email ?? "Everyone in the workspace";
+}
+
+/** Owner-only dialog for granting view/run/edit access to users or the whole workspace. */
+export function AgentShareDialog({ agent, onClose }: AgentShareDialogProps) {
+  const queryClient = useQueryClient();
+  const dialogRef = useFocusTrap<HTMLDivElement>(true, onClose);
+  const [email, setEmail] = useState("");
+  const [wholeWorkspace, setWholeWorkspace] = useState(false);
+  const [permission, setPermission] = useState<SharePermission>("view");
+  const sharesKey = ["agent-shares", agent.id];
+
+  const { data: shares = [], isLoading } = useQuery({
+    queryKey: sharesKey,
+    queryFn: () => fetchAgentShares(agent.id),
+  });
+
+  const saveShare = useMutation({
+    mutationFn: (next: { permission: SharePermission; email: string | null }) =>
+      shareAgent(agent.id, next.permission, next.email),
+    onSuccess: () => {
+      setEmail("");
+      queryClient.invalidateQueries({ queryKey: sharesKey });
+    },
+    onError: (error: Error) => {
+      toast.error(isApiError(error, "not_found") ? "No user with that email" : `Could not share: ${error.message}`);
+    },
+  });
+
+  const revokeShare = useMutation({
+    mutationFn: (shareId: number) => removeAgentShare(agent.id, shareId),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: sharesKey }),
+    onError: (error: Error) => toast.error(`Could not remove access: ${error.message}`),
+  });
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    const target = wholeWorkspace ? null : email.trim();
+    if (target === "") return;
+    saveShare.mutate({ permission, email: target });
+  };
+
+  return (
+    <div className="connector-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        ref={dialogRef}
+        className="connector-modal agent-share-dialog"
+        onClick={(event) => event.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="agent-share-title"
+        tabIndex={-1}
+        data-testid="agent-share-dialog"
+      >
+        <header className="connector-modal-header">
+          <h3 id="agent-share-title">Share {agent.name}</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <form onSubmit={handleSubmit}>
+          <div className="connector-modal-body">
+            <div className="agent-share-form">
+              <input
+                type="email"
+                aria-label="User email"
+                placeholder="teammate@example.com"
+                value={email}
+                disabled={wholeWorkspace}
+                onChange={(event) => setEmail(event.target.value)}
+              />
+              <select
+                aria-label="Permission"
+                value={permission}
+                onChange={(event) => setPermission(event.target.value as SharePermission)}
+              >
+                {PERMISSIONS.map((level) => (
+                  <option key={level} value={level}>
+                    {SHARE_PERMISSION_LABELS[level]}
+                  </option>
+                ))}
+              </select>
+              <button type="submit" className="btn-primary" disabled={saveShare.isPending}>
+                Share
+              </button>
+            </div>
+            <label className="agent-share-workspace">
+              <input
+                type="checkbox"
+                checked={wholeWorkspace}
+                onChange={(event) => setWholeWorkspace(event.target.checked)}
+              />
+              Share with everyone in the workspace
+            </label>
+
+            {isLoading ? (
+              <p className="connector-description">Loading…</p>
+            ) : shares.length === 0 ? (
+              <p className="connector-description">Only you can see this agent.</p>
+            ) : (
+              <ul className="agent-share-list">
+                {shares.map((share) => (
+                  <li key={share.id} className="agent-share-row">
+                    <span className="agent-share-who">{shareLabel(share)}</span>
+                    <select
+                      aria-label={`Permission for ${shareLabel(share)}`}
+                      value={share.permission}
+                      onChange={(event) =>
+                        saveShare.mutate({
+                          permission: event.target.value as SharePermission,
+                          email: share.user_email ?? null,
+                        })
+                      }
+                    >
+                      {PERMISSIONS.map((level) => (
+                        <option key={level} value={level}>
+                          {SHARE_PERMISSION_LABELS[level]}
+                        </option>
+                      ))}
+                    </select>
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      aria-label={`Remove access for ${shareLabel(share)}`}
+                      disabled={revokeShare.isPending}
+                      onClick={() => revokeShare.mutate(share.id)}
+                    >
+                      Remove
+                    </button>
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
+
+export default AgentShareDialog;
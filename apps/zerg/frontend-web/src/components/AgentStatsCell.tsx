@@ -0,0 +1,52 @@
+import { formatAverageDuration, sparklineHeights, type SuccessSummary } from "../lib/agentStats";
+import type { AgentStats } from "../services/api";
+
+const BAR_WIDTH = 6;
+const BAR_GAP = 2;
+const SPARK_HEIGHT = 16;
+
+type AgentStatsCellProps = {
+  summary: SuccessSummary;
+  stats?: AgentStats;
+};
+
+/** Success rate and run count, plus average duration and a 7-day sparkline once server stats are in. */
+export default function AgentStatsCell({ summary, stats }: AgentStatsCellProps) {
+  const average = formatAverageDuration(stats?.avg_duration_ms);
+  const daily = stats?.daily_runs ?? [];
+  const heights = sparklineHeights(daily);
+  const total = daily.reduce((sum, day) => sum + day.count, 0);
+  const sparkLabel = `${total} run${total === 1 ? "" : "s"} in the last ${daily.length} days`;
+
+  return (
+    <div className="agent-stats-cell">
+      <span className="agent-stats-rate">{summary.display}</span>
+      {average && <span className="agent-stats-duration">avg {average}</span>}
+      {daily.length > 0 && (
+        <svg
+          className="agent-stats-sparkline"
+          width={daily.length * (BAR_WIDTH + BAR_GAP) - BAR_GAP}
+          height={SPARK_HEIGHT}
+          role="img"
+          aria-label={sparkLabel}
+        >
+          <title>{daily.map((day) => `${day.date}: ${day.count}`).join("\n")}</title>
+          {heights.map((height, index) => {
+            // Keep a 1px stub so empty days still read as days
+            const barHeight = Math.max(1, Math.round(height * SPARK_HEIGHT));
+            return (
+              <rect
+                key={daily[index].date}
+                className={height === 0 ? "agent-stats-bar empty" : "agent-stats-bar"}
+                x={index * (BAR_WIDTH + BAR_GAP)}
+                y={SPARK_HEIGHT - barHeight}
+                width={BAR_WIDTH}
+                height={barHeight}
+              />
+            );
+          })}
+        </svg>
+      )}
+    </div>
+  );
+}
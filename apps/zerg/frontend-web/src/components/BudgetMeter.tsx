@@ -0,0 +1,24 @@
+import clsx from "clsx";
+import { budgetTone, formatBudget } from "../lib/agentBudget";
+import type { AgentBudgetStatus } from "../services/api";
+
+/** Spend-vs-cap bar shown under an agent's name on the dashboard. */
+export default function BudgetMeter({ status }: { status: AgentBudgetStatus }) {
+  const percent = Math.min(100, Math.max(0, Math.round(status.percent)));
+  const label = formatBudget(status);
+  return (
+    <div className={clsx("budget-meter", `budget-meter--${budgetTone(status)}`)} title={label}>
+      <div
+        className="budget-meter-track"
+        role="progressbar"
+        aria-valuemin={0}
+        aria-valuemax={100}
+        aria-valuenow={percent}
+        aria-label={`Budget used: ${label}`}
+      >
+        <span className="budget-meter-fill" style={{ width: `${percent}%` }} />
+      </div>
+      <span className="budget-meter-label">{label}</span>
+    </div>
+  );
+}
@@ -0,0 +1,63 @@
+import type { PresenceEditorData } from "../generated/ws-messages";
+import { presenceColor, presenceInitials, presenceName, uniqueEditors } from "../lib/workflowPresence";
+
+const MAX_AVATARS = 4;
+
+/** Stacked avatars of the other people on this workflow. */
+export function PresenceAvatars({ editors }: { editors: Iterable<PresenceEditorData> }) {
+  const people = uniqueEditors(editors);
+  if (people.length === 0) return null;
+  const shown = people.slice(0, MAX_AVATARS);
+  const hidden = people.length - shown.length;
+  const names = people.map(presenceName).join(", ");
+
+  return (
+    <div className="presence-avatars" data-testid="presence-avatars" aria-label={`Also editing: ${names}`} role="group">
+      {shown.map((editor) => {
+        const name = presenceName(editor);
+        return (
+          <span
+            key={editor.user_id}
+            className="presence-avatar"
+            style={{ ["--presence-color" as string]: presenceColor(editor.user_id) }}
+            title={name}
+          >
+            {editor.avatar_url ? <img src={editor.avatar_url} alt="" /> : presenceInitials(name)}
+          </span>
+        );
+      })}
+      {hidden > 0 && (
+        <span className="presence-avatar presence-avatar--more" title={names}>
+          +{hidden}
+        </span>
+      )}
+    </div>
+  );
+}
+
+/** Other editors' pointers; render inside a ViewportPortal so positions are in flow coordinates. */
+export function RemoteCursors({ editors }: { editors: Iterable<PresenceEditorData> }) {
+  return (
+    <>
+      {[...editors].map((editor) =>
+        editor.cursor ? (
+          <div
+            key={editor.client_id}
+            className="presence-cursor"
+            data-testid="presence-cursor"
+            style={{
+              transform: `translate(${editor.cursor.x}px, ${editor.cursor.y}px)`,
+              ["--presence-color" as string]: presenceColor(editor.user_id),
+            }}
+            aria-hidden="true"
+          >
+            <svg width="16" height="16" viewBox="0 0 16 16">
+              <path d="M1 1l5 14 2-6 6-2z" />
+            </svg>
+            <span className="presence-cursor__label">{presenceName(editor)}</span>
+          </div>
+        ) : null
+      )}
+    </>
+  );
+}
@@ -0,0 +1,66 @@
+import { useSyncExternalStore } from "react";
+import { toast } from "react-hot-toast";
+import { copyToClipboard } from "../lib/clipboard";
+import {
+  buildDiagnosticReport,
+  dismissCrash,
+  getCrash,
+  softReload,
+  subscribeCrash,
+  type Crash,
+} from "../lib/crashReport";
+import { useFocusTrap } from "../lib/useFocusTrap";
+
+export async function copyDiagnosticReport(crash: Crash | null) {
+  try {
+    await copyToClipboard(buildDiagnosticReport(crash));
+    toast.success("Diagnostic report copied");
+  } catch {
+    toast.error("Could not copy the report");
+  }
+}
+
+/** Full-screen notice for uncaught exceptions outside a route's ErrorBoundary. */
+export function CrashOverlay() {
+  const crash = useSyncExternalStore(subscribeCrash, getCrash);
+  const panelRef = useFocusTrap<HTMLDivElement>(!!crash, dismissCrash);
+
+  if (!crash) return null;
+
+  return (
+    <div className="crash-overlay" data-testid="crash-overlay">
+      <div
+        ref={panelRef}
+        className="error-fallback crash-overlay-panel"
+        role="alertdialog"
+        aria-labelledby="crash-overlay-title"
+        aria-describedby="crash-overlay-message"
+        tabIndex={-1}
+      >
+        <div className="error-fallback__icon">⚠️</div>
+        <h2 id="crash-overlay-title" className="error-fallback__title">
+          Something went wrong
+        </h2>
+        <p id="crash-overlay-message" className="error-fallback__message">
+          {crash.message || "An unexpected error occurred."} Reloading keeps your unsaved canvas changes.
+        </p>
+        <div className="error-fallback__actions">
+          <button onClick={softReload} className="error-fallback__button error-fallback__button--primary">
+            Reload
+          </button>
+          <button
+            onClick={() => copyDiagnosticReport(crash)}
+            className="error-fallback__button error-fallback__button--secondary"
+          >
+            Copy diagnostic report
+          </button>
+          <button onClick={dismissCrash} className="error-fallback__button error-fallback__button--secondary">
+            Dismiss
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default CrashOverlay;
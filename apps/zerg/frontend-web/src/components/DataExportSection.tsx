@@ -0,0 +1,164 @@
+import { useEffect, useRef } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import {
+  downloadDataExport,
+  fetchDataExports,
+  requestDataExport,
+  type DataExport,
+} from "../services/api";
+import { downloadBlob } from "../lib/download";
+import { formatFileSize } from "./chat/chatUtils";
+
+const POLL_INTERVAL_MS = 2000;
+
+const STATUS_LABELS: Record<DataExport["status"], string> = {
+  queued: "Queued",
+  running: "Preparing…",
+  success: "Ready",
+  failed: "Failed",
+};
+
+function isPendingStatus(status: DataExport["status"] | undefined) {
+  return status === "queued" || status === "running";
+}
+
+function isPending(item: DataExport) {
+  return isPendingStatus(item.status);
+}
+
+// Shown when the tab is in the background; the toast covers the foreground case
+function notifyExportReady() {
+  if (typeof Notification === "undefined" || Notification.permission !== "granted" || !document.hidden) {
+    return;
+  }
+  new Notification("Your data export is ready", { body: "Return to your profile to download it." });
+}
+
+/**
+ * Self-service export of everything the user owns except credentials and secrets.
+ *
+ * The backend builds the archive in a background job; the list is polled
+ * while any export is still in progress.
+ */
+export function DataExportSection() {
+  const queryClient = useQueryClient();
+  // Export id → last seen status, to detect the transition to "success"
+  const seenStatuses = useRef<Map<number, DataExport["status"]> | null>(null);
+
+  const exportsQuery = useQuery<DataExport[]>({
+    queryKey: ["data-exports"],
+    queryFn: fetchDataExports,
+    refetchInterval: (query) => (query.state.data?.some(isPending) ? POLL_INTERVAL_MS : false),
+  });
+
+  const exports = exportsQuery.data ?? [];
+  const hasPending = exports.some(isPending);
+
+  useEffect(() => {
+    if (!exportsQuery.data) return;
+    const previous = seenStatuses.current;
+    const next = new Map(exportsQuery.data.map((item) => [item.id, item.status]));
+    // The first load only records state; exports finished earlier aren't news
+    if (previous) {
+      for (const item of exportsQuery.data) {
+        const before = previous.get(item.id);
+        if (isPendingStatus(before) && item.status === "success") {
+          toast.success("Your data export is ready to download");
+          notifyExportReady();
+        } else if (isPendingStatus(before) && item.status === "failed") {
+          toast.error(`Data export failed: ${item.error ?? "unknown error"}`, { duration: 6000 });
+        }
+      }
+    }
+    seenStatuses.current = next;
+  }, [exportsQuery.data]);
+
+  const requestMutation = useMutation({
+    mutationFn: requestDataExport,
+    onSuccess: (created) => {
+      seenStatuses.current?.set(created.id, created.status);
+      queryClient.setQueryData<DataExport[]>(["data-exports"], (old) => [created, ...(old ?? [])]);
+      queryClient.invalidateQueries({ queryKey: ["data-exports"] });
+      toast.success("Export started – we'll let you know when it's ready");
+      if (typeof Notification !== "undefined" && Notification.permission === "default") {
+        Notification.requestPermission().catch(() => undefined);
+      }
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to start export: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const downloadMutation = useMutation({
+    mutationFn: async (item: DataExport) => {
+      const blob = await downloadDataExport(item.id);
+      downloadBlob(`zerg-export-${item.id}.zip`, blob);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to download export: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <div className="form-section data-export-section">
+      <h3>Export Your Data</h3>
+      <p className="data-export-description">
+        Download an archive of your agents, workflows, chat threads, run history, triggers and environment
+        variables. Credentials and secret values are not included; the archive's manifest lists what is left out.
+      </p>
+      <button
+        type="button"
+        className="btn-primary"
+        onClick={() => requestMutation.mutate()}
+        disabled={requestMutation.isPending || hasPending}
+      >
+        {hasPending ? "Export in progress…" : "Request export"}
+      </button>
+
+      {exportsQuery.isLoading ? (
+        <div className="data-export-empty">Loading export history…</div>
+      ) : exports.length === 0 ? (
+        <div className="data-export-empty">No exports yet</div>
+      ) : (
+        <table className="data-export-history">
+          <thead>
+            <tr>
+              <th>Requested</th>
+              <th>Status</th>
+              <th>Size</th>
+              <th aria-label="Actions" />
+            </tr>
+          </thead>
+          <tbody>
+            {exports.map((item) => (
+              <tr key={item.id}>
+                <td>{new Date(item.created_at).toLocaleString()}</td>
+                <td>
+                  <span className={`data-export-status data-export-status--${item.status}`} title={item.error ?? undefined}>
+                    {STATUS_LABELS[item.status]}
+                  </span>
+                </td>
+                <td>{item.size_bytes != null ? formatFileSize(item.size_bytes) : "-"}</td>
+                <td>
+                  {item.status === "success" && (
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      onClick={() => downloadMutation.mutate(item)}
+                      disabled={downloadMutation.isPending && downloadMutation.variables?.id === item.id}
+                    >
+                      Download
+                    </button>
+                  )}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+export default DataExportSection;
@@ -0,0 +1,79 @@
+import { canDisableDependents, describeDependents } from "../lib/agentDependents";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import type { AgentDependents, AgentSummary } from "../services/api";
+
+interface DeleteAgentDialogProps {
+  agent: Pick<AgentSummary, "id" | "name">;
+  // null when the dependency check itself failed
+  dependents: AgentDependents | null;
+  onCancel: () => void;
+  onConfirm: (disableDependents: boolean) => void;
+}
+
+/**
+ * Confirmation shown before trashing an agent that other things rely on:
+ * lists the dependents and offers to disable its triggers and queued runs
+ * along with it.
+ */
+export function DeleteAgentDialog({ agent, dependents, onCancel, onConfirm }: DeleteAgentDialogProps) {
+  const dialogRef = useFocusTrap<HTMLDivElement>(true, onCancel);
+  const lines = dependents ? describeDependents(dependents) : [];
+
+  return (
+    <div className="connector-modal-backdrop" onClick={onCancel} role="presentation">
+      <div
+        ref={dialogRef}
+        className="connector-modal delete-agent-dialog"
+        onClick={(event) => event.stopPropagation()}
+        role="alertdialog"
+        aria-modal="true"
+        aria-labelledby="delete-agent-title"
+        tabIndex={-1}
+        data-testid="delete-agent-dialog"
+      >
+        <header className="connector-modal-header">
+          <h3 id="delete-agent-title">Delete {agent.name}?</h3>
+          <button type="button" className="close-btn" onClick={onCancel} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <div className="connector-modal-body">
+          {dependents ? (
+            <>
+              <p className="connector-description">Other things still rely on this agent:</p>
+              <ul className="delete-agent-dependents">
+                {lines.map((line) => (
+                  <li key={line}>{line}</li>
+                ))}
+              </ul>
+            </>
+          ) : (
+            <p className="connector-description">
+              Couldn&apos;t check what relies on this agent. Its workflows and triggers may stop working.
+            </p>
+          )}
+          <p className="connector-description">
+            It moves to the trash and can be restored from there or from the notification center.
+          </p>
+        </div>
+
+        <footer className="connector-modal-footer">
+          <button type="button" className="btn-secondary" onClick={onCancel}>
+            Cancel
+          </button>
+          {dependents && canDisableDependents(dependents) && (
+            <button type="button" className="btn-secondary" onClick={() => onConfirm(true)}>
+              Disable triggers and delete
+            </button>
+          )}
+          <button type="button" className="btn-danger" onClick={() => onConfirm(false)}>
+            Delete
+          </button>
+        </footer>
+      </div>
+    </div>
+  );
+}
+
+export default DeleteAgentDialog;
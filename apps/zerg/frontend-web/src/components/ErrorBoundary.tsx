@@ -1,4 +1,6 @@
 import React from 'react';
+import { markErrorHandled, noteError, softReload } from '../lib/crashReport';
+import { copyDiagnosticReport } from './CrashOverlay';
 
 interface ErrorBoundaryState {
   hasError: boolean;
@@ -8,130 +10,50 @@ interface ErrorBoundaryState {
 
 interface ErrorBoundaryProps {
   children: React.ReactNode;
-  fallback?: React.ComponentType<{ error?: Error; retry: () => void }>;
+  fallback?: React.ComponentType<{ error?: Error; retry: () => void; componentStack?: string }>;
 }
 
 // Default error fallback component
 function DefaultErrorFallback({
   error,
   retry,
+  componentStack,
 }: {
   error?: Error;
   retry: () => void;
+  componentStack?: string;
 }) {
+  const handleCopyReport = () =>
+    copyDiagnosticReport({
+      message: error?.message ?? 'Unknown error',
+      stack: error?.stack,
+      componentStack,
+      source: 'react',
+      ts: Date.now(),
+    });
+
   return (
-    <div style={{
-      display: 'flex',
-      flexDirection: 'column',
-      alignItems: 'center',
-      justifyContent: 'center',
-      minHeight: '400px',
-      padding: '32px',
-      backgroundColor: 'var(--dark-card, #2a2a3a)',
-      border: '1px solid var(--border-color, #3d3d5c)',
-      borderRadius: 'var(--radius-lg, 8px)',
-      margin: '32px',
-      textAlign: 'center',
-    }}>
-      <div style={{
-        fontSize: '48px',
-        marginBottom: '16px',
-        opacity: 0.6,
-      }}>
-        ⚠️
-      </div>
-      <h2 style={{
-        color: '#ef4444',
-        fontSize: '20px',
-        fontWeight: '600',
-        margin: '0 0 12px 0',
-      }}>
-        Something went wrong
-      </h2>
-      <p style={{
-        color: 'var(--text-secondary, #e0e0e0)',
-        fontSize: '16px',
-        margin: '0 0 24px 0',
-        maxWidth: '500px',
-        lineHeight: 1.5,
-      }}>
+    <div className="error-fallback">
+      <div className="error-fallback__icon">⚠️</div>
+      <h2 className="error-fallback__title">Something went wrong</h2>
+      <p className="error-fallback__message">
         {error?.message || 'An unexpected error occurred. Please try refreshing the page or contact support if the problem persists.'}
       </p>
-      <div style={{ display: 'flex', gap: '12px' }}>
-        <button
-          onClick={retry}
-          style={{
-            background: 'linear-gradient(135deg, #10b981 0%, #059669 100%)',
-            border: 'none',
-            color: 'white',
-            padding: '10px 20px',
-            borderRadius: 'var(--radius-sm, 4px)',
-            fontSize: '14px',
-            fontWeight: '500',
-            cursor: 'pointer',
-            transition: 'all 0.2s ease',
-          }}
-          onMouseOver={(e) => {
-            e.currentTarget.style.background = 'linear-gradient(135deg, #059669 0%, #047857 100%)';
-            e.currentTarget.style.transform = 'translateY(-1px)';
-          }}
-          onMouseOut={(e) => {
-            e.currentTarget.style.background = 'linear-gradient(135deg, #10b981 0%, #059669 100%)';
-            e.currentTarget.style.transform = 'translateY(0)';
-          }}
-        >
+      <div className="error-fallback__actions">
+        <button onClick={retry} className="error-fallback__button error-fallback__button--primary">
           Try Again
         </button>
-        <button
-          onClick={() => window.location.reload()}
-          style={{
-            background: 'var(--dark-lighter, #33334a)',
-            border: '1px solid var(--border-color, #3d3d5c)',
-            color: 'var(--text-secondary, #e0e0e0)',
-            padding: '10px 20px',
-            borderRadius: 'var(--radius-sm, 4px)',
-            fontSize: '14px',
-            fontWeight: '500',
-            cursor: 'pointer',
-            transition: 'all 0.2s ease',
-          }}
-          onMouseOver={(e) => {
-            e.currentTarget.style.background = 'var(--dark-card, #2a2a3a)';
-            e.currentTarget.style.color = 'var(--text, #ffffff)';
-          }}
-          onMouseOut={(e) => {
-            e.currentTarget.style.background = 'var(--dark-lighter, #33334a)';
-            e.currentTarget.style.color = 'var(--text-secondary, #e0e0e0)';
-          }}
-        >
+        <button onClick={softReload} className="error-fallback__button error-fallback__button--secondary">
           Reload Page
         </button>
+        <button onClick={handleCopyReport} className="error-fallback__button error-fallback__button--secondary">
+          Copy diagnostic report
+        </button>
       </div>
       {import.meta.env.MODE === 'development' && error && (
-        <details style={{
-          marginTop: '24px',
-          padding: '16px',
-          background: 'rgba(0, 0, 0, 0.3)',
-          borderRadius: 'var(--radius-sm, 4px)',
-          border: '1px solid var(--border-color, #3d3d5c)',
-          fontSize: '12px',
-          fontFamily: 'Monaco, Menlo, monospace',
-          color: 'var(--text-secondary, #e0e0e0)',
-          textAlign: 'left',
-          maxWidth: '600px',
-        }}>
-          <summary style={{ cursor: 'pointer', marginBottom: '8px' }}>
-            Error Details (Development Mode)
-          </summary>
-          <pre style={{
-            margin: 0,
-            whiteSpace: 'pre-wrap',
-            wordBreak: 'break-word',
-            maxHeight: '200px',
-            overflow: 'auto',
-          }}>
-            {error.stack}
-          </pre>
+        <details className="error-fallback__details">
+          <summary>Error Details (Development Mode)</summary>
+          <pre>{error.stack}</pre>
         </details>
       )}
     </div>
@@ -153,6 +75,8 @@ export class ErrorBoundary extends React.Component<ErrorBoundaryProps, ErrorBoun
 
   componentDidCatch(error: Error, errorInfo: React.ErrorInfo) {
     console.error('ErrorBoundary caught an error:', error, errorInfo);
+    markErrorHandled(error);
+    noteError('react', error.message);
 
     // Log error to external service in production
     if (import.meta.env.MODE === 'production') {
@@ -179,7 +103,13 @@ export class ErrorBoundary extends React.Component<ErrorBoundaryProps, ErrorBoun
   render() {
     if (this.state.hasError) {
       const FallbackComponent = this.props.fallback || DefaultErrorFallback;
-      return <FallbackComponent error={this.state.error} retry={this.handleRetry} />;
+      return (
+        <FallbackComponent
+          error={this.state.error}
+          retry={this.handleRetry}
+          componentStack={this.state.errorInfo?.componentStack ?? undefined}
+        />
+      );
     }
 
     return this.props.children;
@@ -189,7 +119,7 @@ export class ErrorBoundary extends React.Component<ErrorBoundaryProps, ErrorBoun
 // Higher-order component for easy error boundary wrapping
 export function withErrorBoundary<P extends object>(
   Component: React.ComponentType<P>,
-  fallback?: React.ComponentType<{ error?: Error; retry: () => void }>
+  fallback?: React.ComponentType<{ error?: Error; retry: () => void; componentStack?: string }>
 ) {
   const WrappedComponent = (props: P) => (
     <ErrorBoundary fallback={fallback}>
@@ -0,0 +1,256 @@
+import { useEffect, useMemo, useRef, useState } from "react";
+import { useQuery } from "@tanstack/react-query";
+import clsx from "clsx";
+import { getExecutionHistory, type WorkflowExecution } from "../services/api";
+import {
+  EMPTY_EXECUTION_FILTER,
+  EXECUTION_RETENTION_STORAGE_KEY,
+  EXECUTION_STATUS_LABELS,
+  RETENTION_AGE_OPTIONS,
+  RETENTION_COUNT_OPTIONS,
+  executionDurationLabel,
+  executionHistoryQueryKey,
+  executionOutcome,
+  executionPinsStorageKey,
+  filterExecutions,
+  pruneExecutions,
+  readExecutionPins,
+  readExecutionRetention,
+  togglePin,
+  type ExecutionFilter,
+  type ExecutionRetention,
+  type ExecutionStatusFilter,
+} from "../lib/executionHistory";
+import { localStorageWriter, persistence } from "../lib/persistence";
+import { Timestamp } from "./Timestamp";
+
+// Persistence slices (see lib/persistence.ts)
+const RETENTION_SLICE = "ui:execution-retention";
+const pinsSlice = (workflowId: number) => `ui:execution-pins:${workflowId}`;
+
+interface ExecutionHistorySidebarProps {
+  workflowId: number;
+  // Run currently shown in the logs panel
+  activeExecutionId?: number | null;
+  // False while a run is in progress
+  canRerun: boolean;
+  onRerun: (execution: WorkflowExecution) => void;
+  onClose: () => void;
+}
+
+const limitLabel = (value: number | null, unit: string) => (value === null ? "Forever" : `${value} ${unit}`);
+
+/** Recent runs of the workflow on the canvas, with filters, pins, retention and re-run. */
+export default function ExecutionHistorySidebar({
+  workflowId,
+  activeExecutionId = null,
+  canRerun,
+  onRerun,
+  onClose,
+}: ExecutionHistorySidebarProps) {
+  const [filter, setFilter] = useState<ExecutionFilter>(EMPTY_EXECUTION_FILTER);
+  const [pinned, setPinned] = useState<ReadonlySet<number>>(() => readExecutionPins(workflowId));
+  const [retention, setRetention] = useState<ExecutionRetention>(() => readExecutionRetention());
+  const [showSettings, setShowSettings] = useState(false);
+  // Read inside the query so pinning doesn't refetch; pins only protect runs already listed
+  const pinnedRef = useRef(pinned);
+  pinnedRef.current = pinned;
+
+  useEffect(() => persistence.register(RETENTION_SLICE, localStorageWriter(EXECUTION_RETENTION_STORAGE_KEY)), []);
+  useEffect(
+    () => persistence.register(pinsSlice(workflowId), localStorageWriter(executionPinsStorageKey(workflowId))),
+    [workflowId]
+  );
+  useEffect(() => {
+    persistence.schedule(RETENTION_SLICE, retention);
+  }, [retention]);
+  useEffect(() => {
+    persistence.schedule(pinsSlice(workflowId), [...pinned]);
+  }, [pinned, workflowId]);
+
+  const { data: executions = [], isLoading, error } = useQuery({
+    queryKey: [...executionHistoryQueryKey(workflowId), retention],
+    // Pruned before it reaches the cache, so dropped runs don't linger in memory
+    queryFn: async () => pruneExecutions(await getExecutionHistory(workflowId), retention, pinnedRef.current),
+  });
+
+  const visible = useMemo(() => filterExecutions(executions, filter, pinned), [executions, filter, pinned]);
+  const isFiltered = filter.status !== "all" || filter.from !== "" || filter.to !== "" || filter.query.trim() !== "";
+  const updateFilter = (patch: Partial<ExecutionFilter>) => setFilter((current) => ({ ...current, ...patch }));
+
+  return (
+    <aside
+      className="execution-history-panel"
+      aria-labelledby="execution-history-title"
+      data-testid="execution-history"
+    >
+      <header className="node-inspector-header">
+        <h3 id="execution-history-title">Execution history</h3>
+        <div className="execution-history-header-actions">
+          <button
+            type="button"
+            className="execution-history-icon-btn"
+            onClick={() => setShowSettings((open) => !open)}
+            aria-expanded={showSettings}
+            aria-controls="execution-history-retention"
+            title="Retention settings"
+            aria-label="Retention settings"
+          >
+            ⚙️
+          </button>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close execution history">
+            ×
+          </button>
+        </div>
+      </header>
+
+      {showSettings && (
+        <fieldset id="execution-history-retention" className="execution-history-retention">
+          <legend>Keep in this list</legend>
+          <label>
+            Runs
+            <select
+              value={String(retention.maxCount)}
+              onChange={(e) => setRetention((current) => ({ ...current, maxCount: Number(e.target.value) || null }))}
+            >
+              {RETENTION_COUNT_OPTIONS.map((option) => (
+                <option key={String(option)} value={String(option)}>
+                  {option === null ? "All" : `Last ${option}`}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label>
+            Age
+            <select
+              value={String(retention.maxAgeDays)}
+              onChange={(e) =>
+                setRetention((current) => ({ ...current, maxAgeDays: Number(e.target.value) || null }))
+              }
+            >
+              {RETENTION_AGE_OPTIONS.map((option) => (
+                <option key={String(option)} value={String(option)}>
+                  {limitLabel(option, option === 1 ? "day" : "days")}
+                </option>
+              ))}
+            </select>
+          </label>
+          <span className="node-inspector-hint">Pinned and running executions are always kept.</span>
+        </fieldset>
+      )}
+
+      <div className="execution-history-filters" role="search">
+        <input
+          type="search"
+          value={filter.query}
+          onChange={(e) => updateFilter({ query: e.target.value })}
+          placeholder="Search by id, e.g. #42"
+          aria-label="Search executions by id"
+        />
+        <select
+          value={filter.status}
+          onChange={(e) => updateFilter({ status: e.target.value as ExecutionStatusFilter })}
+          aria-label="Filter by status"
+        >
+          {(Object.keys(EXECUTION_STATUS_LABELS) as ExecutionStatusFilter[]).map((status) => (
+            <option key={status} value={status}>
+              {EXECUTION_STATUS_LABELS[status]}
+            </option>
+          ))}
+        </select>
+        <div className="execution-history-dates">
+          <input
+            type="date"
+            value={filter.from}
+            max={filter.to || undefined}
+            onChange={(e) => updateFilter({ from: e.target.value })}
+            aria-label="Started on or after"
+          />
+          <span aria-hidden="true">–</span>
+          <input
+            type="date"
+            value={filter.to}
+            min={filter.from || undefined}
+            onChange={(e) => updateFilter({ to: e.target.value })}
+            aria-label="Started on or before"
+          />
+        </div>
+        {isFiltered && (
+          <button type="button" className="execution-history-clear" onClick={() => setFilter(EMPTY_EXECUTION_FILTER)}>
+            Clear filters
+          </button>
+        )}
+      </div>
+
+      {isLoading && <p className="node-inspector-hint execution-history-empty">Loading executions…</p>}
+      {error && (
+        <p className="execution-history-empty execution-history-error" role="alert">
+          Couldn&apos;t load executions: {(error as Error).message}
+        </p>
+      )}
+      {!isLoading && !error && visible.length === 0 && (
+        <p className="node-inspector-hint execution-history-empty">
+          {executions.length === 0 ? "This workflow hasn't run yet." : "No executions match these filters."}
+        </p>
+      )}
+
+      <ul className="execution-history-list">
+        {visible.map((execution) => {
+          const outcome = executionOutcome(execution);
+          const isPinned = pinned.has(execution.id);
+          const duration = executionDurationLabel(execution);
+          const inputCount = Object.keys(execution.inputs ?? {}).length;
+          return (
+            <li
+              key={execution.id}
+              className={clsx("execution-history-item", execution.id === activeExecutionId && "is-active")}
+              data-testid="execution-history-item"
+            >
+              <div className="execution-history-row">
+                <span className="execution-history-id">#{execution.id}</span>
+                <span className={clsx("execution-history-status", `is-${outcome}`)}>
+                  {EXECUTION_STATUS_LABELS[outcome]}
+                </span>
+                <button
+                  type="button"
+                  className={clsx("execution-history-icon-btn", isPinned && "is-pinned")}
+                  onClick={() => setPinned((current) => togglePin(current, execution.id))}
+                  aria-pressed={isPinned}
+                  aria-label={isPinned ? `Unpin execution ${execution.id}` : `Pin execution ${execution.id}`}
+                  title={isPinned ? "Unpin" : "Pin: keep this execution and list it first"}
+                >
+                  📌
+                </button>
+              </div>
+              <div className="execution-history-meta">
+                <Timestamp value={execution.started_at} />
+                {duration && <span>{duration}</span>}
+                {execution.triggered_by && <span>{execution.triggered_by}</span>}
+                {execution.start_node_id && (
+                  <span title={`Reused the other nodes' outputs from #${execution.source_execution_id ?? "?"}`}>
+                    from {execution.start_node_id}
+                  </span>
+                )}
+                {inputCount > 0 && (
+                  <span title={JSON.stringify(execution.inputs, null, 2)}>
+                    {inputCount} input{inputCount === 1 ? "" : "s"}
+                  </span>
+                )}
+              </div>
+              {execution.error_message && <p className="execution-history-error">{execution.error_message}</p>}
+              <button
+                type="button"
+                className="execution-history-rerun"
+                onClick={() => onRerun(execution)}
+                disabled={!canRerun}
+                title={canRerun ? "Start a new run with the same inputs" : "Wait for the current run to finish"}
+              >
+                ↻ Re-run with same inputs
+              </button>
+            </li>
+          );
+        })}
+      </ul>
+    </aside>
+  );
+}
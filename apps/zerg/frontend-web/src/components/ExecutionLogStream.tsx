@@ -1,28 +1,120 @@
-import { useEffect, useRef } from 'react';
+import { useEffect, useMemo, useRef, useState } from 'react';
+import { downloadTextFile } from '../lib/download';
 import '../styles/execution-log-stream.css';
 
+export type LogLevel = 'stdout' | 'stderr' | 'info' | 'error';
+
+export const LOG_LEVELS: LogLevel[] = ['info', 'stdout', 'stderr', 'error'];
+
 export interface LogEntry {
   timestamp: number;
   type: 'execution' | 'node' | 'output' | 'error';
   message: string;
+  // Node the entry belongs to; execution-level entries leave this unset
+  nodeId?: string;
+  // Explicit level (e.g. stdout/stderr from node_log); derived from type otherwise
+  level?: LogLevel;
   metadata?: Record<string, unknown>;
 }
 
+export interface LogFilters {
+  search: string;
+  nodeId: string | null;
+  levels: Set<LogLevel>;
+}
+
+export type LogExportFormat = 'text' | 'ndjson';
+
 interface ExecutionLogStreamProps {
   logs: LogEntry[];
   isRunning: boolean;
+  // Used to name downloaded log files
+  executionId?: number | null;
+}
+
+export function getLogLevel(entry: LogEntry): LogLevel {
+  if (entry.level) return entry.level;
+  switch (entry.type) {
+    case 'error':
+      return 'error';
+    case 'output':
+      return 'stdout';
+    default:
+      return 'info';
+  }
 }
 
-export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps) {
+export function filterLogs(logs: LogEntry[], filters: LogFilters): LogEntry[] {
+  const needle = filters.search.trim().toLowerCase();
+  return logs.filter((entry) => {
+    if (!filters.levels.has(getLogLevel(entry))) return false;
+    if (filters.nodeId !== null && entry.nodeId !== filters.nodeId) return false;
+    if (needle && !entry.message.toLowerCase().includes(needle)) return false;
+    return true;
+  });
+}
+
+export function serializeLogs(logs: LogEntry[], format: LogExportFormat): string {
+  if (format === 'ndjson') {
+    return logs
+      .map((entry) =>
+        JSON.stringify({
+          timestamp: new Date(entry.timestamp).toISOString(),
+          level: getLogLevel(entry),
+          type: entry.type,
+          node_id: entry.nodeId ?? null,
+          message: entry.message,
+        })
+      )
+      .join('\n') + (logs.length > 0 ? '\n' : '');
+  }
+  return logs
+    .map((entry) => {
+      const node = entry.nodeId ? ` [${entry.nodeId}]` : '';
+      return `${new Date(entry.timestamp).toISOString()} ${getLogLevel(entry).toUpperCase().padEnd(6)}${node} ${entry.message}`;
+    })
+    .join('\n') + (logs.length > 0 ? '\n' : '');
+}
+
+function downloadLogs(logs: LogEntry[], format: LogExportFormat, executionId?: number | null) {
+  const extension = format === 'ndjson' ? 'ndjson' : 'log';
+  downloadTextFile(
+    `execution-${executionId ?? 'logs'}.${extension}`,
+    serializeLogs(logs, format),
+    format === 'ndjson' ? 'application/x-ndjson' : 'text/plain'
+  );
+}
+
+export function ExecutionLogStream({ logs, isRunning, executionId }: ExecutionLogStreamProps) {
   const containerRef = useRef<HTMLDivElement>(null);
   const shouldAutoScroll = useRef(true);
+  const [autoScroll, setAutoScroll] = useState(true);
+  const [search, setSearch] = useState('');
+  const [nodeId, setNodeId] = useState<string | null>(null);
+  const [levels, setLevels] = useState<Set<LogLevel>>(() => new Set(LOG_LEVELS));
+  const [exportFormat, setExportFormat] = useState<LogExportFormat>('text');
+
+  const nodeIds = useMemo(() => {
+    const ids = new Set<string>();
+    logs.forEach((entry) => {
+      if (entry.nodeId) ids.add(entry.nodeId);
+    });
+    return Array.from(ids).sort();
+  }, [logs]);
+
+  const visibleLogs = useMemo(
+    () => filterLogs(logs, { search, nodeId, levels }),
+    [logs, search, nodeId, levels]
+  );
+
+  const isFiltered = search.trim() !== '' || nodeId !== null || levels.size !== LOG_LEVELS.length;
 
   // Auto-scroll to bottom when new logs arrive
   useEffect(() => {
-    if (shouldAutoScroll.current && containerRef.current) {
+    if (autoScroll && shouldAutoScroll.current && containerRef.current) {
       containerRef.current.scrollTop = containerRef.current.scrollHeight;
     }
-  }, [logs]);
+  }, [visibleLogs, autoScroll]);
 
   // Detect if user has scrolled up manually
   const handleScroll = () => {
@@ -32,6 +124,26 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
     shouldAutoScroll.current = isAtBottom;
   };
 
+  const toggleAutoScroll = () => {
+    setAutoScroll((prev) => {
+      // Re-enabling should jump back to the tail even if the user scrolled up
+      if (!prev) shouldAutoScroll.current = true;
+      return !prev;
+    });
+  };
+
+  const toggleLevel = (level: LogLevel) => {
+    setLevels((prev) => {
+      const next = new Set(prev);
+      if (next.has(level)) {
+        next.delete(level);
+      } else {
+        next.add(level);
+      }
+      return next;
+    });
+  };
+
   const formatTimestamp = (timestamp: number) => {
     const date = new Date(timestamp);
     return date.toLocaleTimeString('en-US', {
@@ -44,6 +156,7 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
   };
 
   const getLogPrefix = (entry: LogEntry) => {
+    if (entry.level === 'stderr') return '!';
     switch (entry.type) {
       case 'execution':
         return '>';
@@ -60,7 +173,8 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
 
   const getLogClass = (entry: LogEntry) => {
     const baseClass = 'log-entry';
-    return `${baseClass} log-entry--${entry.type}`;
+    const levelClass = entry.level === 'stderr' ? ` ${baseClass}--stderr` : '';
+    return `${baseClass} ${baseClass}--${entry.type}${levelClass}`;
   };
 
   return (
@@ -69,6 +183,69 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
         <span className="log-stream-title">EXECUTION STREAM</span>
         {isRunning && <span className="log-stream-indicator">●</span>}
       </div>
+      <div className="log-stream-toolbar">
+        <input
+          type="search"
+          className="log-search"
+          placeholder="Search logs…"
+          value={search}
+          onChange={(event) => setSearch(event.target.value)}
+          aria-label="Search logs"
+        />
+        <select
+          className="log-node-filter"
+          value={nodeId ?? ''}
+          onChange={(event) => setNodeId(event.target.value || null)}
+          aria-label="Filter by node"
+        >
+          <option value="">All nodes</option>
+          {nodeIds.map((id) => (
+            <option key={id} value={id}>
+              {id}
+            </option>
+          ))}
+        </select>
+        <div className="log-level-filters" role="group" aria-label="Log levels">
+          {LOG_LEVELS.map((level) => (
+            <button
+              key={level}
+              type="button"
+              className={`log-level-toggle log-level-toggle--${level}${levels.has(level) ? ' active' : ''}`}
+              aria-pressed={levels.has(level)}
+              onClick={() => toggleLevel(level)}
+            >
+              {level}
+            </button>
+          ))}
+        </div>
+        <button
+          type="button"
+          className={`log-autoscroll-toggle${autoScroll ? ' active' : ''}`}
+          aria-pressed={autoScroll}
+          onClick={toggleAutoScroll}
+          title="Follow new log lines"
+        >
+          Auto-scroll
+        </button>
+        <div className="log-download">
+          <select
+            value={exportFormat}
+            onChange={(event) => setExportFormat(event.target.value as LogExportFormat)}
+            aria-label="Download format"
+          >
+            <option value="text">Text</option>
+            <option value="ndjson">NDJSON</option>
+          </select>
+          <button
+            type="button"
+            onClick={() => downloadLogs(visibleLogs, exportFormat, executionId)}
+            disabled={visibleLogs.length === 0}
+            title={isFiltered ? 'Download filtered logs' : 'Download logs'}
+          >
+            Download
+          </button>
+        </div>
+      </div>
       <div
         ref={containerRef}
         className="log-stream-container"
@@ -79,8 +256,12 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
             <span className="cursor-blink">_</span>
             <span className="log-hint">Waiting for execution...</span>
           </div>
+        ) : visibleLogs.length === 0 ? (
+          <div className="log-empty">
+            <span className="log-hint">No log lines match the current filters</span>
+          </div>
         ) : (
-          logs.map((entry, index) => (
+          visibleLogs.map((entry, index) => (
             <div key={index} className={getLogClass(entry)}>
               <span className="log-timestamp">{formatTimestamp(entry.timestamp)}</span>
               <span className="log-prefix">{getLogPrefix(entry)}</span>
@@ -88,12 +269,17 @@ export function ExecutionLogStream({ logs, isRunning }: ExecutionLogStreamProps)
             </div>
           ))
         )}
-        {isRunning && logs.length > 0 && (
+        {isRunning && visibleLogs.length > 0 && (
           <div className="log-entry">
             <span className="cursor-blink">_</span>
           </div>
         )}
       </div>
+      {isFiltered && logs.length > 0 && (
+        <div className="log-stream-footer">
+          Showing {visibleLogs.length} of {logs.length} lines
+        </div>
+      )}
     </div>
   );
 }
@@ -0,0 +1,97 @@
+import { useEffect, useState } from "react";
+import { configOverride, outputValue, parseConfigDraft, type StepState } from "../lib/executionStepper";
+
+interface ExecutionStepPanelProps {
+  state: StepState;
+  // Canvas labels keyed by node id
+  nodeLabels: Record<string, string>;
+  onStep: (config?: Record<string, unknown>) => void;
+  onContinue: (config?: Record<string, unknown>) => void;
+  onAbort: () => void;
+}
+
+/**
+ * Run controls shown in the execution sidebar while stepping or stopped at
+ * a breakpoint: the node the run is parked at, its (editable) input config
+ * and the outputs produced so far.
+ */
+export function ExecutionStepPanel({ state, nodeLabels, onStep, onContinue, onAbort }: ExecutionStepPanelProps) {
+  const { paused, nodeOutputs, resuming } = state;
+  const [draft, setDraft] = useState("");
+
+  useEffect(() => {
+    setDraft(paused ? JSON.stringify(paused.config, null, 2) : "");
+  }, [paused]);
+
+  const parsed = paused ? parseConfigDraft(draft) : null;
+  const error = parsed && !parsed.ok ? parsed.error : null;
+  const override = paused ? configOverride(paused.config, draft) : undefined;
+  const outputEntries = Object.entries(nodeOutputs);
+  const labelFor = (nodeId: string) => nodeLabels[nodeId] ?? nodeId;
+
+  return (
+    <section className="execution-step-panel" aria-label="Step-through controls" data-testid="execution-step-panel">
+      {paused ? (
+        <>
+          <p className="execution-step-status" role="status">
+            {paused.reason === "breakpoint" ? "Breakpoint hit at " : "Paused before "}
+            <strong>{labelFor(paused.nodeId)}</strong> ({paused.nodeType})
+          </p>
+          <label htmlFor="execution-step-input">Node input</label>
+          <textarea
+            id="execution-step-input"
+            className="execution-step-input"
+            value={draft}
+            onChange={(event) => setDraft(event.target.value)}
+            rows={6}
+            spellCheck={false}
+            aria-invalid={error ? true : undefined}
+          />
+          {error && (
+            <span className="execution-step-error" role="alert">
+              {error}
+            </span>
+          )}
+          {override && <span className="execution-step-hint">Edited input applies to this run only.</span>}
+          <div className="execution-step-actions">
+            <button
+              type="button"
+              className="btn-primary"
+              onClick={() => onStep(override)}
+              disabled={resuming || !!error}
+            >
+              Step
+            </button>
+            <button
+              type="button"
+              className="btn-secondary"
+              onClick={() => onContinue(override)}
+              disabled={resuming || !!error}
+            >
+              Continue
+            </button>
+            <button type="button" className="btn-danger" onClick={onAbort} disabled={resuming}>
+              Abort
+            </button>
+          </div>
+        </>
+      ) : (
+        <p className="execution-step-status" role="status">
+          Running next node…
+        </p>
+      )}
+
+      {outputEntries.length > 0 && (
+        <div className="execution-step-outputs">
+          <h5>Outputs so far</h5>
+          {outputEntries.map(([nodeId, output]) => (
+            <details key={nodeId}>
+              <summary>{labelFor(nodeId)}</summary>
+              <pre>{JSON.stringify(outputValue(output), null, 2)}</pre>
+            </details>
+          ))}
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,203 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import config from "../lib/config";
+import {
+  GMAIL_CONNECTION_QUERY_KEY,
+  GMAIL_HEALTH_LABELS,
+  activeTriggers,
+  disconnectWarning,
+  needsReconnect,
+  requestGmailAuthCode,
+  scopeLabel,
+} from "../lib/gmail";
+import { connectGmail, disconnectGmail, fetchGmailConnection, type GmailConnection } from "../services/api";
+import Timestamp from "./Timestamp";
+
+/**
+ * The user's Gmail connection: which account, what it may access, whether
+ * Google still honours the grant, and the email triggers that depend on it.
+ */
+export function GmailConnectionSection() {
+  const queryClient = useQueryClient();
+  const connectionQuery = useQuery<GmailConnection>({
+    queryKey: GMAIL_CONNECTION_QUERY_KEY,
+    queryFn: () => fetchGmailConnection(),
+  });
+  const connection = connectionQuery.data;
+
+  // Triggers and connector pickers elsewhere read from these
+  const refreshDependents = () => {
+    queryClient.invalidateQueries({ queryKey: GMAIL_CONNECTION_QUERY_KEY });
+    queryClient.invalidateQueries({ queryKey: ["email-connectors"] });
+    queryClient.invalidateQueries({ queryKey: ["triggers"] });
+  };
+
+  const connectMutation = useMutation({
+    mutationFn: async () => connectGmail(await requestGmailAuthCode(config.googleClientId)),
+    onSuccess: () => {
+      toast.success("Gmail connected");
+      refreshDependents();
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to connect Gmail: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const checkMutation = useMutation({
+    mutationFn: () => fetchGmailConnection(true),
+    onSuccess: (checked) => {
+      queryClient.setQueryData(GMAIL_CONNECTION_QUERY_KEY, checked);
+      if (checked.health === "healthy") toast.success("Gmail connection is healthy");
+      else toast.error(checked.health_detail ?? GMAIL_HEALTH_LABELS[checked.health], { duration: 6000 });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to check Gmail: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const disconnectMutation = useMutation({
+    mutationFn: disconnectGmail,
+    onSuccess: ({ disabled_trigger_ids }) => {
+      const count = disabled_trigger_ids.length;
+      const triggers = count === 1 ? "1 email trigger" : `${count} email triggers`;
+      toast.success(count === 0 ? "Gmail disconnected" : `Gmail disconnected; ${triggers} disabled`);
+      refreshDependents();
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to disconnect Gmail: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDisconnect = () => {
+    if (connection && window.confirm(disconnectWarning(connection.triggers))) {
+      disconnectMutation.mutate();
+    }
+  };
+
+  const busy = connectMutation.isPending || disconnectMutation.isPending;
+  const disabledTriggers = connection ? connection.triggers.filter((trigger) => trigger.disabled) : [];
+
+  return (
+    <div className="form-section gmail-connection-section">
+      <h3>Integrations</h3>
+      <h4>Gmail</h4>
+
+      {connectionQuery.isLoading ? (
+        <small>Loading Gmail connection…</small>
+      ) : connectionQuery.isError ? (
+        <p className="gmail-warning">Could not load the Gmail connection.</p>
+      ) : !connection?.connected ? (
+        <>
+          <p className="form-help">Connect Gmail to run agents when matching email arrives.</p>
+          {disabledTriggers.length > 0 && (
+            <p className="gmail-warning" role="status">
+              {disabledTriggers.length} email trigger{disabledTriggers.length === 1 ? " was" : "s were"} disabled
+              when Gmail was disconnected. Reconnecting turns {disabledTriggers.length === 1 ? "it" : "them"} back on.
+            </p>
+          )}
+          <button type="button" className="btn-primary" onClick={() => connectMutation.mutate()} disabled={busy}>
+            {connectMutation.isPending ? "Waiting for Google…" : "Connect Gmail"}
+          </button>
+        </>
+      ) : (
+        <>
+          <div className="info-grid">
+            <div className="info-item">
+              <span className="info-label">Account:</span>
+              <span className="info-value">{connection.email_address ?? "Unknown address"}</span>
+            </div>
+            <div className="info-item">
+              <span className="info-label">Status:</span>
+              <span
+                className={`info-value gmail-health gmail-health--${connection.health}`}
+                title={connection.health_detail ?? undefined}
+              >
+                {GMAIL_HEALTH_LABELS[connection.health]}
+              </span>
+            </div>
+            <div className="info-item">
+              <span className="info-label">Connected:</span>
+              <span className="info-value">
+                <Timestamp value={connection.connected_at} />
+              </span>
+            </div>
+            <div className="info-item">
+              <span className="info-label">Notifications until:</span>
+              <span className="info-value">
+                <Timestamp
+                  value={connection.watch_expiry ? new Date(connection.watch_expiry).toISOString() : null}
+                  fallback="No watch registered"
+                />
+              </span>
+            </div>
+          </div>
+
+          {connection.health_detail && (
+            <p className="gmail-warning" role="status">
+              {connection.health_detail}
+            </p>
+          )}
+
+          <div className="form-group">
+            <span className="form-label">Granted access</span>
+            {connection.scopes.length === 0 ? (
+              <small>Scopes weren&apos;t recorded for this connection. Reconnect to see them.</small>
+            ) : (
+              <ul className="gmail-scopes">
+                {connection.scopes.map((scope) => (
+                  <li key={scope} title={scope}>
+                    {scopeLabel(scope)}
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+
+          <div className="form-group">
+            <span className="form-label">Used by</span>
+            {connection.triggers.length === 0 ? (
+              <small>No email triggers yet. Add one from an agent&apos;s settings.</small>
+            ) : (
+              <ul className="gmail-triggers">
+                {connection.triggers.map((trigger) => (
+                  <li key={trigger.id}>
+                    <a href={`/dashboard?agent=${trigger.agent_id}&settings=1`}>{trigger.agent_name}</a>
+                    {trigger.disabled && <span className="muted"> (disabled)</span>}
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+
+          <div className="form-actions">
+            <button
+              type="button"
+              className="btn-secondary"
+              onClick={() => checkMutation.mutate()}
+              disabled={busy || checkMutation.isPending}
+            >
+              {checkMutation.isPending ? "Checking…" : "Check connection"}
+            </button>
+            <button
+              type="button"
+              className={needsReconnect(connection) ? "btn-primary" : "btn-secondary"}
+              onClick={() => connectMutation.mutate()}
+              disabled={busy}
+            >
+              {connectMutation.isPending ? "Waiting for Google…" : "Reconnect"}
+            </button>
+            <button type="button" className="btn-danger" onClick={handleDisconnect} disabled={busy}>
+              {disconnectMutation.isPending
+                ? "Disconnecting…"
+                : activeTriggers(connection.triggers).length > 0
+                  ? "Disconnect and disable triggers"
+                  : "Disconnect"}
+            </button>
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
+
+export default GmailConnectionSection;
@@ -0,0 +1,86 @@
+import { useMutation } from "@tanstack/react-query";
+import clsx from "clsx";
+import { testHttpRequest, type HttpRequestTestResult } from "../services/api";
+import { parseHeaderLines, statusTone } from "../lib/httpNode";
+import type { NodeDraft } from "../lib/nodeInspector";
+
+type HttpRequestTestProps = {
+  draft: Pick<NodeDraft, "method" | "url" | "headers" | "body" | "credentialId">;
+  // Latest execution on the canvas; upstream references resolve against its outputs
+  executionId?: number | null;
+  disabled: boolean;
+};
+
+function formatBody(body: unknown): string {
+  return typeof body === "string" ? body : JSON.stringify(body, null, 2);
+}
+
+/** "Test request" for the HTTP node inspector: sends the unsaved draft and shows the response. */
+export default function HttpRequestTest({ draft, executionId, disabled }: HttpRequestTestProps) {
+  const mutation = useMutation<HttpRequestTestResult, Error>({
+    mutationFn: () =>
+      testHttpRequest({
+        method: draft.method,
+        url: draft.url.trim(),
+        headers: parseHeaderLines(draft.headers).headers,
+        body: draft.body.trim() ? draft.body : null,
+        execution_id: executionId ?? null,
+        credential_id: draft.credentialId ? Number(draft.credentialId) : null,
+      }),
+  });
+  const result = mutation.data;
+  const response = result?.response;
+
+  return (
+    <div className="http-request-test" data-testid="http-request-test">
+      <button
+        type="button"
+        className="btn-secondary"
+        onClick={() => mutation.mutate()}
+        disabled={disabled || mutation.isPending}
+      >
+        {mutation.isPending ? "Sending…" : "Test request"}
+      </button>
+      {!executionId && (
+        <span className="node-inspector-hint">Run the workflow once to fill in references to earlier nodes.</span>
+      )}
+
+      {mutation.isError && (
+        <p className="http-request-test-error" role="alert">
+          {mutation.error.message}
+        </p>
+      )}
+      {result && (
+        <div className="http-request-test-result" aria-live="polite">
+          <div className="http-request-test-line">
+            {result.method} {result.url}
+          </div>
+          {result.unresolved.length > 0 && (
+            <p className="http-request-test-warning">Sent unresolved: {result.unresolved.join(", ")}</p>
+          )}
+          {result.error && (
+            <p className="http-request-test-error" role="alert">
+              {result.error}
+            </p>
+          )}
+          {response && (
+            <>
+              <div className="http-request-test-status">
+                <span className={clsx("http-status", `http-status--${statusTone(response.status_code)}`)}>
+                  {response.status_code}
+                </span>
+                {response.elapsed_ms != null && <span>{response.elapsed_ms} ms</span>}
+                {response.truncated && <span>body truncated</span>}
+              </div>
+              <details>
+                <summary>Headers</summary>
+                <pre className="node-inspector-code">{formatBody(response.headers)}</pre>
+              </details>
+              <pre className="node-inspector-code http-request-test-body">{formatBody(response.body)}</pre>
+            </>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
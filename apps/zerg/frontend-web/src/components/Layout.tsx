@@ -1,11 +1,27 @@
 import clsx from "clsx";
-import type { PropsWithChildren } from "react";
-import { useLocation, useNavigate } from "react-router-dom";
+import { useState, useSyncExternalStore, type PropsWithChildren } from "react";
+import { Link, useLocation, useNavigate } from "react-router-dom";
 import { useAuth } from "../lib/auth";
 import { useShelf } from "../lib/useShelfState";
 import { useWebSocket, ConnectionStatusIndicator } from "../lib/useWebSocket";
+import { isWsDebugEnabled } from "../lib/wsFrameLog";
+import { wsSchemaMismatches } from "../lib/wsValidation";
+import { isDemoActive } from "../lib/demoMode";
+import { isPerfHudEnabled, setPerfHudEnabled } from "../lib/perfMetrics";
+import { apiCircuits } from "../lib/apiResilience";
+import { Capability, useCapabilities } from "../lib/capabilities";
+import { useShortcuts } from "../lib/useShortcuts";
+import { useNotify } from "../lib/useNotifications";
+import { mentionNotification } from "../lib/nodeComments";
+import type { CommentMentionData } from "../generated/ws-messages";
 import "../styles/layout.css";
 import { MenuIcon } from "./icons";
+import { NotificationCenter } from "./NotificationCenter";
+import { PerfHud } from "./PerfHud";
+import { QuickSwitcher } from "./QuickSwitcher";
+import { SessionRecorderControl } from "./SessionRecorderControl";
+import { ThemeSwitcher } from "./ThemeSwitcher";
+import { WsDebugPanel } from "./WsDebugPanel";
 
 const STATUS_ITEMS = [
   { label: "Runs", value: "0" },
@@ -76,6 +92,11 @@ function WelcomeHeader() {
         <h1 id="header-title">Swarmlet</h1>
       </div>
       <div className="user-menu-container">
+        <ThemeSwitcher />
+        <Link to="/settings/preferences" className="theme-switcher header-settings-link" title="Settings">
+          Settings
+        </Link>
+        <NotificationCenter />
         <div
           className="avatar-badge"
           aria-label="User avatar"
@@ -104,38 +125,120 @@ function WelcomeHeader() {
   );
 }
 
+// Shown while any endpoint group's circuit breaker is open or probing
+function ApiCircuitIndicator() {
+  const circuits = useSyncExternalStore(apiCircuits.subscribe, apiCircuits.getStatuses);
+  if (circuits.length === 0) return null;
+
+  const probing = circuits.every((circuit) => circuit.state === "half-open");
+  const groups = circuits.map((circuit) => circuit.group).join(", ");
+  return (
+    <span
+      className={clsx("circuit-indicator", probing && "circuit-indicator--probing")}
+      data-testid="api-circuit-indicator"
+      title={`Requests to ${groups} are paused after repeated server errors and will resume automatically`}
+    >
+      {probing ? "Reconnecting to API…" : "API unavailable"}: {groups}
+    </span>
+  );
+}
+
 function StatusFooter() {
-  // Use a background WebSocket connection for general status monitoring
+  const notify = useNotify();
+  // Use a background WebSocket connection for general status monitoring; it is
+  // also the one listener on the personal topic wherever the user is in the app
   const { connectionStatus } = useWebSocket(true, {
     includeAuth: true,
     // Don't invalidate any queries from the layout level
     invalidateQueries: [],
+    onMessage: (message) => {
+      if (message.type === "comment_mention" && message.data) {
+        notify(mentionNotification(message.data as CommentMentionData));
+      }
+    },
   });
+  const [showWsDebug, setShowWsDebug] = useState(false);
+  const schemaMismatches = useSyncExternalStore(
+    (listener) => wsSchemaMismatches.subscribe(listener),
+    () => wsSchemaMismatches.total
+  );
 
   return (
     <footer className="status-bar" data-testid="status-footer" aria-live="polite">
-      <div className="packet-counter">
+      {/* Hidden frame viewer: double-click the connection status when WS debugging is enabled */}
+      <div className="packet-counter" onDoubleClick={() => isWsDebugEnabled() && setShowWsDebug(true)}>
         <ConnectionStatusIndicator status={connectionStatus} />
       </div>
+      {schemaMismatches > 0 && isWsDebugEnabled() && (
+        <button
+          type="button"
+          className="ws-schema-indicator"
+          onClick={() => setShowWsDebug(true)}
+          title="Incoming WebSocket messages that don't match the protocol schema"
+          data-testid="ws-schema-indicator"
+        >
+          ⚠ {schemaMismatches} schema {schemaMismatches === 1 ? "mismatch" : "mismatches"}
+        </button>
+      )}
+      <ApiCircuitIndicator />
+      {isDemoActive() && (
+        <a
+          href="/?demo=0"
+          className="demo-mode-badge"
+          title="Sample data that lives in this browser only – click to leave demo mode"
+        >
+          Demo mode · exit
+        </a>
+      )}
+      <Link to="/status" className="status-bar-link">
+        System status
+      </Link>
+      <SessionRecorderControl />
+      {showWsDebug && <WsDebugPanel onClose={() => setShowWsDebug(false)} />}
     </footer>
   );
 }
 
+// Opt-in timings overlay; the choice persists in localStorage across reloads
+function PerfHudToggle() {
+  const [visible, setVisible] = useState(isPerfHudEnabled);
+  const setHud = (next: boolean) => {
+    setPerfHudEnabled(next);
+    setVisible(next);
+  };
+
+  useShortcuts("global", { "global.togglePerfHud": () => setHud(!visible) });
+
+  return visible ? <PerfHud onClose={() => setHud(false)} /> : null;
+}
+
+// Ctrl+P from anywhere in the app; the switcher only fetches its index while open
+function QuickSwitcherLauncher() {
+  const [open, setOpen] = useState(false);
+
+  useShortcuts("global", { "global.quickSwitcher": () => setOpen((current) => !current) });
+
+  return open ? <QuickSwitcher onClose={() => setOpen(false)} /> : null;
+}
+
 export default function Layout({ children }: PropsWithChildren) {
   const navigate = useNavigate();
   const location = useLocation();
-  const { user } = useAuth();
+  const { can } = useCapabilities();
 
   const isDashboardRoute =
     location.pathname === "/" || location.pathname.startsWith("/dashboard");
   const isCanvasRoute = location.pathname.startsWith("/canvas");
   const isProfileRoute = location.pathname.startsWith("/profile");
-  const isIntegrationsRoute = location.pathname.startsWith("/settings/integrations");
+  const isIntegrationsRoute =
+    location.pathname.startsWith("/settings/integrations") ||
+    location.pathname.startsWith("/settings/mcp") ||
+    location.pathname.startsWith("/settings/variables") ||
+    location.pathname.startsWith("/settings/api-credentials");
   const isAdminRoute = location.pathname.startsWith("/admin");
   const isChatRoute = location.pathname.startsWith("/chat");
 
-  // Check if user has admin access using authoritative role field
-  const isAdmin = user?.role === 'ADMIN';
+  const canViewOps = can(Capability.ViewOps);
 
   const handleTabClick = (path: string) => {
     navigate(path);
@@ -180,7 +283,7 @@ export default function Layout({ children }: PropsWithChildren) {
         >
           Integrations
         </button>
-        {isAdmin && (
+        {canViewOps && (
           <button
             id="global-admin-tab"
             type="button"
@@ -200,6 +303,8 @@ export default function Layout({ children }: PropsWithChildren) {
         {children}
       </div>
       <StatusFooter />
+      <PerfHudToggle />
+      <QuickSwitcherLauncher />
     </>
   );
 }
@@ -0,0 +1,220 @@
+import { createContext, useContext, useState, type FormEvent, type KeyboardEvent } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import clsx from "clsx";
+import { createWorkflowComment, resolveWorkflowComment, type WorkflowComment } from "../services/api";
+import { splitMentions, type CommentThread, type NodeCommentSummary } from "../lib/nodeComments";
+import { CommentIcon } from "./icons";
+import { Timestamp } from "./Timestamp";
+
+export const workflowCommentsKey = (workflowId: number) => ["workflow-comments", workflowId] as const;
+
+// Lets canvas node components open the panel without threading a callback through node data
+export const NodeCommentsContext = createContext<((nodeId: string) => void) | null>(null);
+
+/** Speech-bubble badge on a node that has comment threads. */
+export function CommentBadge({ nodeId, summary }: { nodeId: string; summary?: NodeCommentSummary }) {
+  const openComments = useContext(NodeCommentsContext);
+  if (!summary || summary.total === 0) return null;
+  const label =
+    summary.open > 0
+      ? `${summary.open} open comment thread${summary.open === 1 ? "" : "s"}`
+      : "All comment threads resolved";
+  return (
+    <button
+      type="button"
+      className={clsx("node-comment-badge", "nodrag", summary.open === 0 && "node-comment-badge--resolved")}
+      data-testid="node-comment-badge"
+      title={label}
+      aria-label={label}
+      onClick={(event) => {
+        event.stopPropagation();
+        openComments?.(nodeId);
+      }}
+    >
+      <CommentIcon width={12} height={12} />
+      {summary.open > 0 && <span>{summary.open}</span>}
+    </button>
+  );
+}
+
+function CommentBody({ body }: { body: string }) {
+  return (
+    <p className="node-comment-body">
+      {splitMentions(body).map((segment, index) =>
+        segment.mention ? (
+          <span key={index} className="node-comment-mention">
+            {segment.text}
+          </span>
+        ) : (
+          <span key={index}>{segment.text}</span>
+        )
+      )}
+    </p>
+  );
+}
+
+function CommentEntry({ comment }: { comment: WorkflowComment }) {
+  return (
+    <li className="node-comment" data-testid="node-comment">
+      <div className="node-comment-meta">
+        <strong>{comment.author_name}</strong>
+        <Timestamp value={comment.created_at} />
+      </div>
+      <CommentBody body={comment.body} />
+    </li>
+  );
+}
+
+interface ComposerProps {
+  placeholder: string;
+  submitLabel: string;
+  pending: boolean;
+  onSubmit: (body: string) => Promise<unknown>;
+}
+
+function CommentComposer({ placeholder, submitLabel, pending, onSubmit }: ComposerProps) {
+  const [body, setBody] = useState("");
+
+  const submit = () => {
+    if (!body.trim() || pending) return;
+    onSubmit(body.trim()).then(() => setBody(""), () => undefined);
+  };
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    submit();
+  };
+
+  // Cmd/Ctrl+Enter posts, plain Enter adds a line
+  const handleKeyDown = (event: KeyboardEvent<HTMLTextAreaElement>) => {
+    if (event.key === "Enter" && (event.metaKey || event.ctrlKey)) {
+      event.preventDefault();
+      submit();
+    }
+  };
+
+  return (
+    <form className="node-comment-composer" onSubmit={handleSubmit}>
+      <textarea
+        value={body}
+        onChange={(event) => setBody(event.target.value)}
+        onKeyDown={handleKeyDown}
+        placeholder={placeholder}
+        aria-label={placeholder}
+        rows={2}
+      />
+      <button type="submit" className="btn-primary" disabled={!body.trim() || pending}>
+        {submitLabel}
+      </button>
+    </form>
+  );
+}
+
+interface NodeCommentsPanelProps {
+  workflowId: number;
+  nodeId: string;
+  nodeLabel: string;
+  threads: CommentThread[];
+  onClose: () => void;
+}
+
+/**
+ * Side panel with one node's comment threads: read, reply, resolve and
+ * reopen, or start a new thread. Mention someone with `@their@email`.
+ */
+export default function NodeCommentsPanel({ workflowId, nodeId, nodeLabel, threads, onClose }: NodeCommentsPanelProps) {
+  const queryClient = useQueryClient();
+  const [showResolved, setShowResolved] = useState(false);
+  const refresh = () => queryClient.invalidateQueries({ queryKey: workflowCommentsKey(workflowId) });
+
+  const postComment = useMutation({
+    mutationFn: (payload: { body: string; parentId?: number }) =>
+      createWorkflowComment(workflowId, payload.parentId ? payload : { ...payload, nodeId }),
+    onSuccess: refresh,
+    onError: (error: Error) => toast.error(`Could not post comment: ${error.message}`),
+  });
+
+  const setResolved = useMutation({
+    mutationFn: (payload: { commentId: number; resolved: boolean }) =>
+      resolveWorkflowComment(workflowId, payload.commentId, payload.resolved),
+    onSuccess: refresh,
+    onError: (error: Error) => toast.error(`Could not update thread: ${error.message}`),
+  });
+
+  const resolvedCount = threads.filter((thread) => thread.resolved).length;
+  const visible = showResolved ? threads : threads.filter((thread) => !thread.resolved);
+
+  const handleKeyDown = (event: KeyboardEvent) => {
+    if (event.key === "Escape") {
+      event.stopPropagation();
+      onClose();
+    }
+  };
+
+  return (
+    <aside
+      className="node-comments-panel"
+      aria-labelledby="node-comments-title"
+      data-testid="node-comments-panel"
+      onKeyDown={handleKeyDown}
+    >
+      <header className="node-inspector-header">
+        <h3 id="node-comments-title">Comments · {nodeLabel}</h3>
+        <button type="button" className="close-btn" onClick={onClose} aria-label="Close comments">
+          ×
+        </button>
+      </header>
+
+      <div className="node-comments-body">
+        {resolvedCount > 0 && (
+          <label className="node-inspector-toggle">
+            <input type="checkbox" checked={showResolved} onChange={(e) => setShowResolved(e.target.checked)} />
+            Show {resolvedCount} resolved
+          </label>
+        )}
+        {visible.length === 0 && <p className="node-inspector-hint">No open comments on this node.</p>}
+
+        {visible.map((thread) => (
+          <section
+            key={thread.root.id}
+            className={clsx("node-comment-thread", thread.resolved && "node-comment-thread--resolved")}
+            aria-label={`Thread by ${thread.root.author_name}`}
+          >
+            <ul className="node-comment-list">
+              <CommentEntry comment={thread.root} />
+              {thread.replies.map((reply) => (
+                <CommentEntry key={reply.id} comment={reply} />
+              ))}
+            </ul>
+            <div className="node-comment-thread-actions">
+              <button
+                type="button"
+                className="btn-secondary"
+                disabled={setResolved.isPending}
+                onClick={() => setResolved.mutate({ commentId: thread.root.id, resolved: !thread.resolved })}
+              >
+                {thread.resolved ? "Reopen" : "Resolve"}
+              </button>
+            </div>
+            {!thread.resolved && (
+              <CommentComposer
+                placeholder="Reply…"
+                submitLabel="Reply"
+                pending={postComment.isPending}
+                onSubmit={(body) => postComment.mutateAsync({ body, parentId: thread.root.id })}
+              />
+            )}
+          </section>
+        ))}
+
+        <CommentComposer
+          placeholder="Start a thread (mention with @email)…"
+          submitLabel="Comment"
+          pending={postComment.isPending}
+          onSubmit={(body) => postComment.mutateAsync({ body })}
+        />
+      </div>
+    </aside>
+  );
+}
@@ -0,0 +1,699 @@
+import { useEffect, useMemo, useState, type FormEvent, type KeyboardEvent } from "react";
+import { Link } from "react-router-dom";
+import {
+  TRIGGER_TYPE_LABELS,
+  applyDraft,
+  draftFromNode,
+  isDraftDirty,
+  validateDraft,
+  type InspectableNodeType,
+  type NodeConfig,
+  type NodeDraft,
+  type TriggerType,
+} from "../lib/nodeInspector";
+import { MAX_LOOP_ITERATIONS } from "../lib/controlFlow";
+import { envReferenceText, findEnvReferences, resolveEnvReference } from "../lib/envVars";
+import { describeCron } from "../lib/cron";
+import { githubActionFor, type GitHubAction } from "../lib/githubNode";
+import { HTTP_METHODS, type HttpMethod } from "../lib/httpNode";
+import { SLACK_TOOL_NAME } from "../lib/slackNode";
+import { WAIT_MODES, type WaitMode } from "../lib/waitNode";
+import HttpRequestTest from "./HttpRequestTest";
+import type { ApiCredential, EnvVar } from "../services/api";
+
+export type InspectedNode = {
+  id: string;
+  type: InspectableNodeType;
+  label: string;
+  config: NodeConfig;
+};
+
+type NodeInspectorProps = {
+  node: InspectedNode;
+  agents: { id: number; name: string }[];
+  // Workflows a Call Workflow node can target (the current one excluded)
+  workflows?: { id: number; name: string }[];
+  onApply: (nodeId: string, update: { label: string; config: NodeConfig }) => void;
+  onClose: () => void;
+  // Latest execution on the canvas, for the HTTP node's test request
+  executionId?: number | null;
+  // For showing where each {{env.NAME}} in the draft resolves from
+  envVars?: EnvVar[];
+  workflowId?: number | null;
+  // Auth sources an HTTP node can use
+  apiCredentials?: ApiCredential[];
+};
+
+const ENV_SCOPE_LABELS = {
+  workflow: "From this workflow",
+  workspace: "From workspace",
+} as const;
+
+const TYPE_TITLES: Record<InspectableNodeType, string> = {
+  agent: "Agent node",
+  tool: "Tool node",
+  trigger: "Trigger node",
+  conditional: "If / Else node",
+  switch: "Switch node",
+  loop: "Loop node",
+  http: "HTTP request node",
+  wait: "Wait node",
+  subworkflow: "Call Workflow node",
+};
+
+/**
+ * Right-hand panel for the selected canvas node. Edits stay local until
+ * Apply; Cancel (or Escape) discards them.
+ */
+export default function NodeInspector({
+  node,
+  agents,
+  workflows = [],
+  onApply,
+  onClose,
+  executionId,
+  envVars = [],
+  workflowId = null,
+  apiCredentials = [],
+}: NodeInspectorProps) {
+  const original = useMemo(() => draftFromNode(node.label, node.config), [node.label, node.config]);
+  const [draft, setDraft] = useState<NodeDraft>(original);
+
+  // Switching nodes (or an external edit) resets the form
+  useEffect(() => {
+    setDraft(original);
+  }, [node.id, original]);
+
+  const agentIds = useMemo(() => agents.map((agent) => agent.id), [agents]);
+  const errors = validateDraft(node.type, draft, agentIds);
+  const hasErrors = Object.keys(errors).length > 0;
+  const dirty = isDraftDirty(original, draft);
+
+  const update = <K extends keyof NodeDraft>(key: K, value: NodeDraft[K]) =>
+    setDraft((current) => ({ ...current, [key]: value }));
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    if (hasErrors || !dirty) return;
+    const config = applyDraft(node.type, node.config, draft);
+    onApply(node.id, { label: draft.label.trim(), config });
+  };
+
+  const handleCancel = () => {
+    setDraft(original);
+    onClose();
+  };
+
+  const handleKeyDown = (event: KeyboardEvent) => {
+    if (event.key === "Escape") {
+      event.stopPropagation();
+      handleCancel();
+    }
+  };
+
+  const fieldError = (key: keyof NodeDraft) =>
+    errors[key] ? (
+      <span className="node-inspector-error" id={`node-inspector-${key}-error`} role="alert">
+        {errors[key]}
+      </span>
+    ) : null;
+  // id, plus error wiring when the field is invalid
+  const controlProps = (key: keyof NodeDraft) => ({
+    id: `node-inspector-${key}`,
+    ...(errors[key] ? { "aria-invalid": true, "aria-describedby": `node-inspector-${key}-error` } : {}),
+  });
+
+  const envResolutions = useMemo(
+    () => findEnvReferences(draft).map((name) => resolveEnvReference(name, envVars, workflowId)),
+    [draft, envVars, workflowId]
+  );
+
+  const isSlackTool = node.type === "tool" && draft.toolName === SLACK_TOOL_NAME;
+  const isGitHubTool = node.type === "tool" && githubActionFor(draft.toolName) !== null;
+
+  const cronDescription = draft.triggerType === "schedule" && !errors.cron ? describeCron(draft.cron) : null;
+
+  return (
+    <aside
+      className="node-inspector"
+      aria-labelledby="node-inspector-title"
+      data-testid="node-inspector"
+      onKeyDown={handleKeyDown}
+    >
+      <header className="node-inspector-header">
+        <h3 id="node-inspector-title">
+          {isSlackTool ? "Send to Slack node" : isGitHubTool ? "GitHub PR action node" : TYPE_TITLES[node.type]}
+        </h3>
+        <button type="button" className="close-btn" onClick={handleCancel} aria-label="Close inspector">
+          ×
+        </button>
+      </header>
+
+      <form className="node-inspector-form" onSubmit={handleSubmit}>
+        <div className="node-inspector-field">
+          <label htmlFor="node-inspector-label">Label</label>
+          <input value={draft.label} onChange={(e) => update("label", e.target.value)} {...controlProps("label")} />
+          {fieldError("label")}
+        </div>
+
+        {node.type === "agent" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-agentId">Agent</label>
+              <select
+                value={draft.agentId}
+                onChange={(e) => update("agentId", e.target.value)}
+                {...controlProps("agentId")}
+              >
+                <option value="">Select an agent…</option>
+                {agents.map((agent) => (
+                  <option key={agent.id} value={agent.id}>
+                    {agent.name}
+                  </option>
+                ))}
+              </select>
+              {fieldError("agentId")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-message">Message</label>
+              <textarea
+                id="node-inspector-message"
+                value={draft.message}
+                onChange={(e) => update("message", e.target.value)}
+                rows={4}
+                placeholder="Execute this task"
+              />
+              <span className="node-inspector-hint">
+                Sent to the agent when the node runs. Reference earlier nodes with {"${node_id.value}"}.
+              </span>
+            </div>
+          </>
+        )}
+
+        {isSlackTool && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-slackChannel">Channel</label>
+              <input
+                value={draft.slackChannel}
+                onChange={(e) => update("slackChannel", e.target.value)}
+                placeholder="#alerts"
+                {...controlProps("slackChannel")}
+              />
+              {fieldError("slackChannel")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-slackText">Message</label>
+              <textarea
+                value={draft.slackText}
+                onChange={(e) => update("slackText", e.target.value)}
+                rows={4}
+                placeholder={"Deploy finished: ${deploy.value.status}"}
+                {...controlProps("slackText")}
+              />
+              {fieldError("slackText")}
+              <span className="node-inspector-hint">
+                Posted as the connected Slack app; invite it to the channel first. Reference earlier nodes with{" "}
+                {"${node_id.value}"}.
+              </span>
+            </div>
+          </>
+        )}
+
+        {isGitHubTool && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-githubAction">Action</label>
+              <select
+                value={draft.githubAction}
+                onChange={(e) => update("githubAction", e.target.value as GitHubAction)}
+                {...controlProps("githubAction")}
+              >
+                <option value="comment">Comment</option>
+                <option value="label">Add labels</option>
+              </select>
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-githubRepo">Repository</label>
+              <input
+                value={draft.githubRepo}
+                onChange={(e) => update("githubRepo", e.target.value)}
+                placeholder="owner/name"
+                {...controlProps("githubRepo")}
+              />
+              {fieldError("githubRepo")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-githubNumber">Issue or PR number</label>
+              <input
+                value={draft.githubNumber}
+                onChange={(e) => update("githubNumber", e.target.value)}
+                placeholder={"42 or ${trigger.value.number}"}
+                {...controlProps("githubNumber")}
+              />
+              {fieldError("githubNumber")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-githubText">{draft.githubAction === "label" ? "Labels" : "Comment"}</label>
+              {draft.githubAction === "label" ? (
+                <input
+                  value={draft.githubText}
+                  onChange={(e) => update("githubText", e.target.value)}
+                  placeholder="needs-review, bot"
+                  {...controlProps("githubText")}
+                />
+              ) : (
+                <textarea
+                  value={draft.githubText}
+                  onChange={(e) => update("githubText", e.target.value)}
+                  rows={4}
+                  placeholder={"Review summary: ${review.value}"}
+                  {...controlProps("githubText")}
+                />
+              )}
+              {fieldError("githubText")}
+              <span className="node-inspector-hint">
+                {draft.githubAction === "label" ? "Comma-separated; existing labels are kept. " : ""}
+                Uses your GitHub connector. Reference earlier nodes with {"${node_id.value}"}.
+              </span>
+            </div>
+          </>
+        )}
+
+        {node.type === "tool" && !isSlackTool && !isGitHubTool && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-toolName">Tool</label>
+              <input
+                value={draft.toolName}
+                onChange={(e) => update("toolName", e.target.value)}
+                placeholder="http_request"
+                {...controlProps("toolName")}
+              />
+              {fieldError("toolName")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-params">Parameters (JSON)</label>
+              <textarea
+                className="node-inspector-code"
+                value={draft.params}
+                onChange={(e) => update("params", e.target.value)}
+                rows={6}
+                spellCheck={false}
+                {...controlProps("params")}
+              />
+              {fieldError("params")}
+            </div>
+          </>
+        )}
+
+        {node.type === "trigger" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-triggerType">Trigger type</label>
+              <select
+                id="node-inspector-triggerType"
+                value={draft.triggerType}
+                onChange={(e) => update("triggerType", e.target.value as TriggerType)}
+              >
+                {(Object.keys(TRIGGER_TYPE_LABELS) as TriggerType[]).map((type) => (
+                  <option key={type} value={type}>
+                    {TRIGGER_TYPE_LABELS[type]}
+                  </option>
+                ))}
+              </select>
+            </div>
+            {draft.triggerType === "schedule" && (
+              <div className="node-inspector-field">
+                <label htmlFor="node-inspector-cron">Cron expression</label>
+                <input
+                  className="node-inspector-code"
+                  value={draft.cron}
+                  onChange={(e) => update("cron", e.target.value)}
+                  placeholder="0 9 * * 1-5"
+                  {...controlProps("cron")}
+                />
+                {fieldError("cron")}
+                {cronDescription && <span className="node-inspector-hint">{cronDescription}</span>}
+              </div>
+            )}
+            {draft.triggerType === "manual" && (
+              <div className="node-inspector-field">
+                <label htmlFor="node-inspector-runInputs">Run inputs</label>
+                <textarea
+                  className="node-inspector-code"
+                  value={draft.runInputs}
+                  onChange={(e) => update("runInputs", e.target.value)}
+                  rows={3}
+                  spellCheck={false}
+                  placeholder={"email: string required\nlimit: integer = 10"}
+                  {...controlProps("runInputs")}
+                />
+                {fieldError("runInputs")}
+                <span className="node-inspector-hint">
+                  One name: type per line (string, number, integer, boolean or json), optionally followed by
+                  required or = default. Running the workflow asks for these; nodes read each as{" "}
+                  {"${input.value.name}"}.
+                </span>
+              </div>
+            )}
+            <label className="node-inspector-toggle">
+              <input type="checkbox" checked={draft.enabled} onChange={(e) => update("enabled", e.target.checked)} />
+              <span>Enabled</span>
+            </label>
+          </>
+        )}
+
+        {node.type === "conditional" && (
+          <div className="node-inspector-field">
+            <label htmlFor="node-inspector-condition">Condition</label>
+            <input
+              className="node-inspector-code"
+              value={draft.condition}
+              onChange={(e) => update("condition", e.target.value)}
+              placeholder="${node_id.value.status} == 200"
+              {...controlProps("condition")}
+            />
+            {fieldError("condition")}
+          </div>
+        )}
+
+        {node.type === "switch" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-expression">Switch on</label>
+              <input
+                className="node-inspector-code"
+                value={draft.expression}
+                onChange={(e) => update("expression", e.target.value)}
+                placeholder="${node_id.value.category}"
+                {...controlProps("expression")}
+              />
+              {fieldError("expression")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-cases">Cases</label>
+              <textarea
+                id="node-inspector-cases"
+                className="node-inspector-code"
+                value={draft.cases}
+                onChange={(e) => update("cases", e.target.value)}
+                rows={4}
+                spellCheck={false}
+                placeholder={"billing\nsupport"}
+              />
+              <span className="node-inspector-hint">
+                One value per line, each with its own output. Anything else takes the Default output.
+              </span>
+            </div>
+          </>
+        )}
+
+        {node.type === "loop" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-items">For each item in</label>
+              <input
+                id="node-inspector-items"
+                className="node-inspector-code"
+                value={draft.items}
+                onChange={(e) => update("items", e.target.value)}
+                placeholder="${node_id.value.results}"
+              />
+              <span className="node-inspector-hint">
+                Leave empty to repeat until the limit. The body reads the current item as {"${loop_id.value.item}"}
+                and links back to this node; Done runs once the loop ends.
+              </span>
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-maxIterations">Max iterations</label>
+              <input
+                type="number"
+                min={1}
+                max={MAX_LOOP_ITERATIONS}
+                value={draft.maxIterations}
+                onChange={(e) => update("maxIterations", e.target.value)}
+                {...controlProps("maxIterations")}
+              />
+              {fieldError("maxIterations")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-condition">Continue while (optional)</label>
+              <input
+                className="node-inspector-code"
+                value={draft.condition}
+                onChange={(e) => update("condition", e.target.value)}
+                placeholder="${node_id.value.has_more} == True"
+                {...controlProps("condition")}
+              />
+            </div>
+          </>
+        )}
+
+        {node.type === "http" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-method">Method</label>
+              <select
+                id="node-inspector-method"
+                value={draft.method}
+                onChange={(e) => update("method", e.target.value as HttpMethod)}
+              >
+                {HTTP_METHODS.map((method) => (
+                  <option key={method} value={method}>
+                    {method}
+                  </option>
+                ))}
+              </select>
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-url">URL</label>
+              <input
+                className="node-inspector-code"
+                value={draft.url}
+                onChange={(e) => update("url", e.target.value)}
+                placeholder="https://api.example.com/items/${node_id.value.id}"
+                {...controlProps("url")}
+              />
+              {fieldError("url")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-credentialId">Auth</label>
+              <select
+                id="node-inspector-credentialId"
+                value={draft.credentialId}
+                onChange={(e) => update("credentialId", e.target.value)}
+              >
+                <option value="">None</option>
+                {apiCredentials.map((credential) => (
+                  <option key={credential.id} value={String(credential.id)}>
+                    {credential.name} ({credential.auth_header.split(":")[0]})
+                  </option>
+                ))}
+                {draft.credentialId && !apiCredentials.some((c) => String(c.id) === draft.credentialId) && (
+                  <option value={draft.credentialId}>Deleted credential</option>
+                )}
+              </select>
+              <span className="node-inspector-hint">
+                Adds the credential&apos;s auth header; a header set below with the same name wins.{" "}
+                <Link to="/settings/api-credentials">Manage API credentials</Link>
+              </span>
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-headers">Headers</label>
+              <textarea
+                className="node-inspector-code"
+                value={draft.headers}
+                onChange={(e) => update("headers", e.target.value)}
+                rows={3}
+                spellCheck={false}
+                placeholder="Authorization: Bearer ${node_id.value.token}"
+                {...controlProps("headers")}
+              />
+              {fieldError("headers")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-body">Body</label>
+              <textarea
+                id="node-inspector-body"
+                className="node-inspector-code"
+                value={draft.body}
+                onChange={(e) => update("body", e.target.value)}
+                rows={6}
+                spellCheck={false}
+                placeholder={'{"summary": "${node_id.value}"}'}
+              />
+              <span className="node-inspector-hint">
+                A body that renders to valid JSON is sent as JSON. Objects and lists from earlier nodes are inserted as
+                JSON.
+              </span>
+            </div>
+            <HttpRequestTest draft={draft} executionId={executionId} disabled={Boolean(errors.url || errors.headers)} />
+          </>
+        )}
+
+        {node.type === "wait" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-waitMode">Wait</label>
+              <select
+                id="node-inspector-waitMode"
+                value={draft.waitMode}
+                onChange={(e) => update("waitMode", e.target.value as WaitMode)}
+              >
+                {WAIT_MODES.map((mode) => (
+                  <option key={mode.value} value={mode.value}>
+                    {mode.label}
+                  </option>
+                ))}
+              </select>
+            </div>
+            {draft.waitMode === "duration" && (
+              <div className="node-inspector-field">
+                <label htmlFor="node-inspector-minutes">Minutes</label>
+                <input
+                  type="number"
+                  min={0}
+                  step="any"
+                  value={draft.minutes}
+                  onChange={(e) => update("minutes", e.target.value)}
+                  {...controlProps("minutes")}
+                />
+                {fieldError("minutes")}
+              </div>
+            )}
+            {draft.waitMode === "until" && (
+              <div className="node-inspector-field">
+                <label htmlFor="node-inspector-until">Resume at</label>
+                <input
+                  type="datetime-local"
+                  value={draft.until}
+                  onChange={(e) => update("until", e.target.value)}
+                  {...controlProps("until")}
+                />
+                {fieldError("until")}
+                <span className="node-inspector-hint">In your local time. A time already past resumes at once.</span>
+              </div>
+            )}
+            {draft.waitMode === "event" && (
+              <>
+                <div className="node-inspector-field">
+                  <label htmlFor="node-inspector-event">Event name</label>
+                  <input
+                    value={draft.event}
+                    onChange={(e) => update("event", e.target.value)}
+                    placeholder="approval_received"
+                    {...controlProps("event")}
+                  />
+                  {fieldError("event")}
+                  <span className="node-inspector-hint">
+                    Each run gets its own resume URL, shown in the execution sidebar while the node waits. A JSON body
+                    POSTed to it becomes {"${wait_id.value.payload}"}.
+                  </span>
+                </div>
+                <div className="node-inspector-field">
+                  <label htmlFor="node-inspector-timeoutMinutes">Give up after (minutes, optional)</label>
+                  <input
+                    type="number"
+                    min={0}
+                    step="any"
+                    value={draft.timeoutMinutes}
+                    onChange={(e) => update("timeoutMinutes", e.target.value)}
+                    {...controlProps("timeoutMinutes")}
+                  />
+                  {fieldError("timeoutMinutes")}
+                </div>
+              </>
+            )}
+          </>
+        )}
+
+        {node.type === "subworkflow" && (
+          <>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-workflowId">Workflow</label>
+              <select
+                value={draft.workflowId}
+                onChange={(e) => update("workflowId", e.target.value)}
+                {...controlProps("workflowId")}
+              >
+                <option value="">Select a workflow…</option>
+                {workflows.map((workflow) => (
+                  <option key={workflow.id} value={String(workflow.id)}>
+                    {workflow.name}
+                  </option>
+                ))}
+                {draft.workflowId && !workflows.some((workflow) => String(workflow.id) === draft.workflowId) && (
+                  <option value={draft.workflowId}>Workflow #{draft.workflowId} (unavailable)</option>
+                )}
+              </select>
+              {fieldError("workflowId")}
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-inputs">Inputs</label>
+              <textarea
+                className="node-inspector-code"
+                value={draft.inputs}
+                onChange={(e) => update("inputs", e.target.value)}
+                rows={4}
+                spellCheck={false}
+                placeholder={"customer = ${node_id.value.email}"}
+                {...controlProps("inputs")}
+              />
+              {fieldError("inputs")}
+              <span className="node-inspector-hint">
+                One name = value per line. The called workflow reads each as {"${input.value.name}"}.
+              </span>
+            </div>
+            <div className="node-inspector-field">
+              <label htmlFor="node-inspector-outputs">Outputs</label>
+              <textarea
+                className="node-inspector-code"
+                value={draft.outputs}
+                onChange={(e) => update("outputs", e.target.value)}
+                rows={4}
+                spellCheck={false}
+                placeholder={"summary = ${child_node_id.value}"}
+                {...controlProps("outputs")}
+              />
+              {fieldError("outputs")}
+              <span className="node-inspector-hint">
+                Resolved against the called workflow&apos;s nodes when it finishes, then available as{" "}
+                {"${this_node.value.outputs.name}"}. Leave blank to return every node&apos;s output.
+              </span>
+            </div>
+          </>
+        )}
+
+        {envResolutions.length > 0 && (
+          <div className="node-inspector-field" data-testid="node-inspector-env">
+            <span className="node-inspector-label">Variables</span>
+            <ul className="node-inspector-env">
+              {envResolutions.map((resolution) => (
+                <li key={resolution.name} className={resolution.scope ? "resolved" : "missing"}>
+                  <code>{envReferenceText(resolution.name)}</code>
+                  <span>
+                    {resolution.scope ? ENV_SCOPE_LABELS[resolution.scope] : "Not defined – left as written"}
+                    {resolution.secret && " · secret"}
+                  </span>
+                </li>
+              ))}
+            </ul>
+            <Link className="node-inspector-hint" to="/settings/variables">
+              Manage secrets &amp; variables
+            </Link>
+          </div>
+        )}
+
+        <div className="node-inspector-actions">
+          <button type="button" className="btn-secondary" onClick={handleCancel}>
+            Cancel
+          </button>
+          <button type="submit" className="btn-primary" disabled={hasErrors || !dirty}>
+            Apply
+          </button>
+        </div>
+      </form>
+    </aside>
+  );
+}
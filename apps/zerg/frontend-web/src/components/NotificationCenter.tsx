@@ -0,0 +1,162 @@
+import { useEffect, useRef, useState } from "react";
+import clsx from "clsx";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { useNavigate } from "react-router-dom";
+import { toast } from "react-hot-toast";
+import { useNotifications } from "../lib/useNotifications";
+import { filterNotifications, type AppNotification, type NotificationFilter } from "../lib/notifications";
+import { isApiError } from "../services/api";
+import { invalidateTrashLists, restoreTrashed } from "../lib/trash";
+import { BellIcon } from "./icons";
+import { Timestamp } from "./Timestamp";
+
+const FILTERS: { value: NotificationFilter; label: string }[] = [
+  { value: "all", label: "All" },
+  { value: "unread", label: "Unread" },
+  { value: "errors", label: "Errors" },
+  { value: "runs", label: "Runs" },
+];
+
+/**
+ * Header bell with an unread badge; opens the list of past toasts and run
+ * events. Entries carrying an undo (e.g. a deleted agent) offer it here
+ * after their toast is gone.
+ */
+export function NotificationCenter() {
+  const { notifications, unread, persist, markRead, markAllRead, clearUndo, clear, setPersist } = useNotifications();
+  const queryClient = useQueryClient();
+  const [isOpen, setIsOpen] = useState(false);
+  const [filter, setFilter] = useState<NotificationFilter>("all");
+  const containerRef = useRef<HTMLDivElement>(null);
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const handlePointerDown = (event: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(event.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.key === "Escape") setIsOpen(false);
+    };
+    document.addEventListener("mousedown", handlePointerDown);
+    document.addEventListener("keydown", handleKeyDown);
+    return () => {
+      document.removeEventListener("mousedown", handlePointerDown);
+      document.removeEventListener("keydown", handleKeyDown);
+    };
+  }, [isOpen]);
+
+  const visible = filterNotifications(notifications, filter);
+
+  // Every undo so far takes an agent or workflow back out of the trash
+  const undoMutation = useMutation({
+    mutationFn: (entry: AppNotification) =>
+      restoreTrashed({ kind: entry.undo!.kind === "restore-workflow" ? "workflow" : "agent", id: entry.undo!.id }),
+    onSuccess: (restored, entry) => {
+      clearUndo(entry.id);
+      toast.success(`Restored ${restored.name}`);
+      invalidateTrashLists(queryClient);
+    },
+    onError: (error: Error, entry) => {
+      // Already restored or purged from the trash – nothing left to undo
+      if (isApiError(error, "conflict") || isApiError(error, "not_found")) clearUndo(entry.id);
+      toast.error(`Failed to restore: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleSelect = (entry: AppNotification) => {
+    markRead(entry.id);
+    if (entry.href) {
+      setIsOpen(false);
+      navigate(entry.href);
+    }
+  };
+
+  return (
+    <div className="notification-center" ref={containerRef}>
+      <button
+        type="button"
+        className="notification-bell"
+        aria-label={unread > 0 ? `Notifications (${unread} unread)` : "Notifications"}
+        aria-haspopup="dialog"
+        aria-expanded={isOpen}
+        onClick={() => setIsOpen((open) => !open)}
+        data-testid="notification-bell"
+      >
+        <BellIcon />
+        {unread > 0 && (
+          <span className="notification-badge" data-testid="notification-badge">
+            {unread > 99 ? "99+" : unread}
+          </span>
+        )}
+      </button>
+
+      {isOpen && (
+        <div className="notification-panel" role="dialog" aria-label="Notifications">
+          <header className="notification-panel-header">
+            <h2>Notifications</h2>
+            <button type="button" className="btn-tertiary" onClick={markAllRead} disabled={unread === 0}>
+              Mark all read
+            </button>
+          </header>
+
+          <div className="notification-filters" role="tablist">
+            {FILTERS.map((option) => (
+              <button
+                key={option.value}
+                type="button"
+                role="tab"
+                aria-selected={filter === option.value}
+                className={clsx("notification-filter", { active: filter === option.value })}
+                onClick={() => setFilter(option.value)}
+              >
+                {option.label}
+              </button>
+            ))}
+          </div>
+
+          {visible.length === 0 ? (
+            <p className="notification-empty">Nothing here yet.</p>
+          ) : (
+            <ul className="notification-list" data-testid="notification-list">
+              {visible.map((entry) => (
+                <li key={entry.id} className={clsx({ "notification-with-undo": entry.undo })}>
+                  <button
+                    type="button"
+                    className={clsx("notification-item", `notification-${entry.kind}`, { unread: !entry.read })}
+                    onClick={() => handleSelect(entry)}
+                  >
+                    <span className="notification-message">{entry.message}</span>
+                    <Timestamp value={entry.createdAt} className="notification-time" />
+                  </button>
+                  {entry.undo && (
+                    <button
+                      type="button"
+                      className="btn-tertiary notification-undo"
+                      onClick={() => undoMutation.mutate(entry)}
+                      disabled={undoMutation.isPending}
+                    >
+                      Undo
+                    </button>
+                  )}
+                </li>
+              ))}
+            </ul>
+          )}
+
+          <footer className="notification-panel-footer">
+            <label>
+              <input type="checkbox" checked={persist} onChange={(event) => setPersist(event.target.checked)} />
+              <span>Keep history after reload</span>
+            </label>
+            <button type="button" className="btn-tertiary" onClick={clear} disabled={notifications.length === 0}>
+              Clear
+            </button>
+          </footer>
+        </div>
+      )}
+    </div>
+  );
+}
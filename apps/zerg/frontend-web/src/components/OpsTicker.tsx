@@ -0,0 +1,142 @@
+import { useCallback, useEffect, useRef, useState } from "react";
+import { useNavigate } from "react-router-dom";
+import clsx from "clsx";
+import { ConnectionStatus, useWebSocket } from "../lib/useWebSocket";
+import {
+  OPS_EVENT_KINDS,
+  describeOpsEvent,
+  opsEventLink,
+  opsEventTone,
+  prependEntries,
+  type OpsEventKind,
+  type TickerEntry,
+} from "../lib/opsTicker";
+import type { OpsEventData } from "../generated/ws-messages";
+import Timestamp from "./Timestamp";
+
+const OPS_TOPIC = "ops:events";
+
+/**
+ * Live feed of the `ops:events` topic. Chips filter by event kind; pausing
+ * holds new events aside so the list stops moving while someone reads it.
+ */
+export default function OpsTicker() {
+  const navigate = useNavigate();
+  const [entries, setEntries] = useState<TickerEntry[]>([]);
+  const [held, setHeld] = useState<TickerEntry[]>([]);
+  const [paused, setPaused] = useState(false);
+  // Empty means every kind is shown
+  const [kinds, setKinds] = useState<Set<OpsEventKind>>(new Set());
+  const pausedRef = useRef(paused);
+  const nextIdRef = useRef(0);
+
+  useEffect(() => {
+    pausedRef.current = paused;
+  }, [paused]);
+
+  const handleMessage = useCallback((message: { type: string; data?: unknown }) => {
+    if (message.type !== "ops_event" || !message.data) return;
+    nextIdRef.current += 1;
+    const entry: TickerEntry = {
+      id: nextIdRef.current,
+      receivedAt: new Date().toISOString(),
+      event: message.data as OpsEventData,
+    };
+    if (pausedRef.current) {
+      setHeld((current) => prependEntries(current, [entry]));
+    } else {
+      setEntries((current) => prependEntries(current, [entry]));
+    }
+  }, []);
+
+  const { connectionStatus, sendMessage } = useWebSocket(true, { onMessage: handleMessage });
+
+  // Subscriptions don't survive a reconnect, so resubscribe on every connect
+  useEffect(() => {
+    if (connectionStatus !== ConnectionStatus.CONNECTED) return;
+    sendMessage({ type: "subscribe", topics: [OPS_TOPIC], message_id: `ops-ticker-${Date.now()}` });
+  }, [connectionStatus, sendMessage]);
+
+  const togglePaused = () => {
+    if (paused) {
+      setEntries((current) => prependEntries(current, held));
+      setHeld([]);
+    }
+    setPaused(!paused);
+  };
+
+  const toggleKind = (kind: OpsEventKind) => {
+    setKinds((current) => {
+      const next = new Set(current);
+      if (next.has(kind)) next.delete(kind);
+      else next.add(kind);
+      return next;
+    });
+  };
+
+  const visible = kinds.size === 0 ? entries : entries.filter((entry) => kinds.has(entry.event.type));
+
+  return (
+    <div className="ops-ticker" data-testid="ops-ticker">
+      <div className="ops-ticker-toolbar">
+        <div className="ops-ticker-chips" role="group" aria-label="Filter events by kind">
+          <button
+            type="button"
+            className={clsx("ops-ticker-chip", { active: kinds.size === 0 })}
+            aria-pressed={kinds.size === 0}
+            onClick={() => setKinds(new Set())}
+          >
+            All
+          </button>
+          {OPS_EVENT_KINDS.map(({ kind, label, tone }) => (
+            <button
+              key={kind}
+              type="button"
+              className={clsx("ops-ticker-chip", `ops-tone-${tone}`, { active: kinds.has(kind) })}
+              aria-pressed={kinds.has(kind)}
+              onClick={() => toggleKind(kind)}
+            >
+              {label}
+            </button>
+          ))}
+        </div>
+        <button type="button" className="ops-ticker-pause" aria-pressed={paused} onClick={togglePaused}>
+          {paused ? `Resume${held.length > 0 ? ` (${held.length} new)` : ""}` : "Pause"}
+        </button>
+      </div>
+
+      {connectionStatus !== ConnectionStatus.CONNECTED && (
+        <div className="ops-ticker-status">Live feed {connectionStatus}…</div>
+      )}
+
+      {visible.length === 0 ? (
+        <div className="empty-state">
+          {entries.length === 0 ? "Waiting for events…" : "No events match the selected filters."}
+        </div>
+      ) : (
+        <ul className="ops-ticker-list" aria-live={paused ? "off" : "polite"}>
+          {visible.map(({ id, receivedAt, event }) => {
+            const link = opsEventLink(event);
+            const content = (
+              <>
+                <Timestamp value={receivedAt} format="time" className="ops-ticker-time" />
+                <span className="ops-ticker-text">{describeOpsEvent(event)}</span>
+              </>
+            );
+            return (
+              <li key={id} className={clsx("ops-ticker-item", `ops-tone-${opsEventTone(event.type)}`)}>
+                {link ? (
+                  <button type="button" className="ops-ticker-link" onClick={() => navigate(link)}>
+                    {content}
+                  </button>
+                ) : (
+                  <div className="ops-ticker-link">{content}</div>
+                )}
+              </li>
+            );
+          })}
+        </ul>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,72 @@
+import { useEffect, useState } from "react";
+import toast from "react-hot-toast";
+import { formatRemaining, resumeUrl, type PendingWait } from "../lib/waitNode";
+
+interface PendingWaitsPanelProps {
+  waits: PendingWait[];
+  // Canvas labels keyed by node id
+  nodeLabels: Record<string, string>;
+  onSkip: (nodeId: string) => void;
+  // Node whose skip request is in flight
+  skippingNodeId: string | null;
+}
+
+/**
+ * Wait nodes the current run is parked on, shown in the execution sidebar:
+ * time left, the resume URL for event waits, and a button to skip the wait.
+ */
+export function PendingWaitsPanel({ waits, nodeLabels, onSkip, skippingNodeId }: PendingWaitsPanelProps) {
+  const [now, setNow] = useState(() => Date.now());
+
+  useEffect(() => {
+    const timer = window.setInterval(() => setNow(Date.now()), 1000);
+    return () => window.clearInterval(timer);
+  }, []);
+
+  const copyResumeUrl = async (url: string) => {
+    try {
+      await navigator.clipboard.writeText(url);
+      toast.success("Resume URL copied");
+    } catch {
+      toast.error("Couldn't copy the resume URL");
+    }
+  };
+
+  return (
+    <section className="pending-waits-panel" aria-label="Waiting nodes" data-testid="pending-waits-panel">
+      {waits.map((wait) => {
+        const url = wait.resumePath ? resumeUrl(wait.resumePath, window.location.origin) : null;
+        return (
+          <div key={wait.nodeId} className="pending-wait">
+            <div className="pending-wait-header">
+              <span className="pending-wait-icon" aria-hidden="true">
+                ⏳
+              </span>
+              <strong>{nodeLabels[wait.nodeId] ?? wait.nodeId}</strong>
+              <span className="pending-wait-remaining" role="timer">
+                {formatRemaining(wait.resumeAt, now)}
+              </span>
+            </div>
+            {wait.event && <div className="pending-wait-event">Waiting for “{wait.event}”</div>}
+            {url && (
+              <div className="pending-wait-url">
+                <code title={url}>POST {url}</code>
+                <button type="button" className="btn-secondary" onClick={() => copyResumeUrl(url)}>
+                  Copy
+                </button>
+              </div>
+            )}
+            <button
+              type="button"
+              className="btn-secondary pending-wait-skip"
+              onClick={() => onSkip(wait.nodeId)}
+              disabled={skippingNodeId === wait.nodeId}
+            >
+              {skippingNodeId === wait.nodeId ? "Skipping…" : "Skip wait"}
+            </button>
+          </div>
+        );
+      })}
+    </section>
+  );
+}
@@ -0,0 +1,76 @@
+import { useEffect, useState } from "react";
+import {
+  FRAME_BUDGET_MS,
+  PERF_METRIC_LABELS,
+  perfSummary,
+  resetPerfMetrics,
+  startFrameSampler,
+  type PerfMetric,
+} from "../lib/perfMetrics";
+import "../styles/css/perf-hud.css";
+
+const METRICS: PerfMetric[] = ["frame", "reducer", "ws", "api"];
+const REFRESH_MS = 500;
+
+const formatMs = (ms: number) => (ms >= 100 ? ms.toFixed(0) : ms.toFixed(1));
+
+interface PerfHudProps {
+  onClose: () => void;
+}
+
+/** Rolling p50/p95 timings; the frame row turns red once p95 exceeds the 60 Hz budget. */
+export function PerfHud({ onClose }: PerfHudProps) {
+  const [summary, setSummary] = useState(perfSummary);
+
+  useEffect(() => {
+    const stopSampler = startFrameSampler();
+    const interval = window.setInterval(() => setSummary(perfSummary()), REFRESH_MS);
+    return () => {
+      stopSampler();
+      window.clearInterval(interval);
+    };
+  }, []);
+
+  const overBudget = summary.frame.count > 0 && summary.frame.p95 > FRAME_BUDGET_MS;
+
+  return (
+    <section className="perf-hud" aria-label="Performance HUD" data-testid="perf-hud">
+      <header className="perf-hud-header">
+        <span>Perf (ms)</span>
+        <button type="button" onClick={resetPerfMetrics} aria-label="Reset timings">
+          ↺
+        </button>
+        <button type="button" onClick={onClose} aria-label="Close performance HUD">
+          ×
+        </button>
+      </header>
+      <table>
+        <thead>
+          <tr>
+            <th scope="col" />
+            <th scope="col">p50</th>
+            <th scope="col">p95</th>
+            <th scope="col">n</th>
+          </tr>
+        </thead>
+        <tbody>
+          {METRICS.map((metric) => (
+            <tr key={metric} className={metric === "frame" && overBudget ? "perf-hud-over" : undefined}>
+              <th scope="row">{PERF_METRIC_LABELS[metric]}</th>
+              <td>{formatMs(summary[metric].p50)}</td>
+              <td>{formatMs(summary[metric].p95)}</td>
+              <td>{summary[metric].count}</td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+      {summary.overBudgetFrames > 0 && (
+        <p className={overBudget ? "perf-hud-warning" : "perf-hud-note"} role={overBudget ? "alert" : undefined}>
+          {summary.overBudgetFrames} frames over the {formatMs(FRAME_BUDGET_MS)} ms budget
+        </p>
+      )}
+    </section>
+  );
+}
+
+export default PerfHud;
@@ -0,0 +1,178 @@
+import { useMemo, useState, type KeyboardEvent as ReactKeyboardEvent } from "react";
+import { useNavigate } from "react-router-dom";
+import { useQuery } from "@tanstack/react-query";
+import clsx from "clsx";
+import { fetchAgents, fetchWorkflows } from "../services/api";
+import { RecentlyDeleted } from "./RecentlyDeleted";
+import { isImeComposing } from "../lib/ime";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import { actionForEvent } from "../lib/shortcuts";
+import { useShortcutBindings } from "../lib/useShortcuts";
+import {
+  QUICK_SWITCH_TARGET_LABELS,
+  buildQuickSwitchIndex,
+  matchPositions,
+  normalizeQuickSwitchQuery,
+  quickSwitchPath,
+  quickSwitchTargets,
+  searchQuickSwitch,
+  splitTagQuery,
+  type QuickSwitchEntry,
+  type QuickSwitchTarget,
+} from "../lib/quickSwitcher";
+import "../styles/css/shortcuts.css";
+
+// Enough for the whole list of a heavy user; the switcher is only as good as its index
+const INDEX_LIMIT = 5000;
+
+function HighlightedName({ entry, query }: { entry: QuickSwitchEntry; query: string }) {
+  const positions = new Set(matchPositions(query, entry.key));
+  if (positions.size === 0) return <>{entry.name}</>;
+  return (
+    <>
+      {Array.from(entry.name, (char, index) => (positions.has(index) ? <mark key={index}>{char}</mark> : char))}
+    </>
+  );
+}
+
+/**
+ * Ctrl+P jump list over agent and workflow names. Enter opens an agent's chat
+ * or a workflow's canvas; Shift+Enter opens an agent's settings instead.
+ * "#tag" narrows the list to agents and workflows with that tag. Before
+ * anything is typed, recently deleted ones are listed for restoring.
+ */
+export function QuickSwitcher({ onClose }: { onClose: () => void }) {
+  const navigate = useNavigate();
+  const bindings = useShortcutBindings();
+  const dialogRef = useFocusTrap(true, onClose);
+  const [query, setQuery] = useState("");
+  const [activeIndex, setActiveIndex] = useState(0);
+
+  const { data: agents = [], isLoading: agentsLoading } = useQuery({
+    queryKey: ["quick-switcher", "agents"],
+    queryFn: () => fetchAgents({ scope: "my", limit: INDEX_LIMIT }),
+    staleTime: 60000,
+  });
+  const { data: workflows = [], isLoading: workflowsLoading } = useQuery({
+    queryKey: ["quick-switcher", "workflows"],
+    queryFn: () => fetchWorkflows(INDEX_LIMIT),
+    staleTime: 60000,
+  });
+
+  const index = useMemo(() => buildQuickSwitchIndex(agents, workflows), [agents, workflows]);
+  const matches = useMemo(() => searchQuickSwitch(index, query), [index, query]);
+  const needle = normalizeQuickSwitchQuery(splitTagQuery(query).text);
+  const typed = query.trim() !== "";
+  const active = Math.min(activeIndex, Math.max(matches.length - 1, 0));
+
+  const open = (entry: QuickSwitchEntry, target: QuickSwitchTarget) => {
+    onClose();
+    navigate(quickSwitchPath(entry, target));
+  };
+
+  const handleKeyDown = (event: ReactKeyboardEvent<HTMLInputElement>) => {
+    if (isImeComposing(event)) return;
+    // Global shortcuts skip text fields, so toggling closed is handled here
+    if (actionForEvent(event.nativeEvent, bindings, "global") === "global.quickSwitcher") {
+      event.preventDefault();
+      onClose();
+    } else if (event.key === "ArrowDown" || event.key === "ArrowUp") {
+      event.preventDefault();
+      if (matches.length === 0) return;
+      const step = event.key === "ArrowDown" ? 1 : -1;
+      setActiveIndex((active + step + matches.length) % matches.length);
+    } else if (event.key === "Enter") {
+      const entry = matches[active]?.entry;
+      if (!entry) return;
+      event.preventDefault();
+      const [primary, secondary] = quickSwitchTargets(entry);
+      open(entry, event.shiftKey && secondary ? secondary : primary);
+    }
+  };
+
+  const loading = agentsLoading || workflowsLoading;
+
+  return (
+    <div
+      ref={dialogRef}
+      className="shortcut-help-overlay quick-switcher-overlay"
+      role="dialog"
+      aria-modal="true"
+      aria-label="Quick switcher"
+      tabIndex={-1}
+      onClick={(event) => {
+        if (event.target === event.currentTarget) onClose();
+      }}
+    >
+      <div className="shortcut-help-panel quick-switcher-panel">
+        <input
+          type="text"
+          className="quick-switcher-input"
+          placeholder="Jump to an agent or workflow… (#tag to filter)"
+          value={query}
+          onChange={(event) => {
+            setQuery(event.target.value);
+            setActiveIndex(0);
+          }}
+          onKeyDown={handleKeyDown}
+          role="combobox"
+          aria-expanded="true"
+          aria-controls="quick-switcher-results"
+          aria-activedescendant={matches.length > 0 ? `quick-switcher-option-${active}` : undefined}
+          autoComplete="off"
+          spellCheck={false}
+          autoFocus
+        />
+        {matches.length === 0 ? (
+          <p className="muted quick-switcher-empty">
+            {loading ? "Loading…" : typed ? `Nothing matches “${query.trim()}”` : "No agents or workflows yet"}
+          </p>
+        ) : (
+          <ul id="quick-switcher-results" className="quick-switcher-list" role="listbox">
+            {matches.map(({ entry }, position) => (
+              <li
+                key={`${entry.kind}-${entry.id}`}
+                id={`quick-switcher-option-${position}`}
+                role="option"
+                aria-selected={position === active}
+                className={clsx("quick-switcher-item", { active: position === active })}
+                onMouseMove={() => setActiveIndex(position)}
+              >
+                <span className="quick-switcher-kind">{entry.kind === "agent" ? "Agent" : "Workflow"}</span>
+                <span className="quick-switcher-name">
+                  <HighlightedName entry={entry} query={needle} />
+                </span>
+                {entry.tags.length > 0 && (
+                  <span className="tag-list">
+                    {entry.tags.map((tag) => (
+                      <span key={tag} className="tag-chip">
+                        {tag}
+                      </span>
+                    ))}
+                  </span>
+                )}
+                {quickSwitchTargets(entry).map((target) => (
+                  <button
+                    key={target}
+                    type="button"
+                    className="btn-tertiary"
+                    tabIndex={-1}
+                    onClick={() => open(entry, target)}
+                  >
+                    {QUICK_SWITCH_TARGET_LABELS[target]}
+                  </button>
+                ))}
+              </li>
+            ))}
+          </ul>
+        )}
+        {!typed && <RecentlyDeleted limit={3} onNavigate={onClose} />}
+        <p className="shortcut-help-hint">
+          ↑↓ to move · Enter to open · Shift+Enter for agent settings · #tag to filter · Esc to close
+        </p>
+      </div>
+    </div>
+  );
+}
+
+export default QuickSwitcher;
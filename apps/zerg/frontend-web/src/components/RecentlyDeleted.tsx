@@ -0,0 +1,81 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import { toast } from "react-hot-toast";
+import clsx from "clsx";
+import { fetchTrashedAgents, fetchTrashedWorkflows } from "../services/api";
+import {
+  TRASHED_KIND_LABELS,
+  daysUntilPurge,
+  invalidateTrashLists,
+  mergeTrashed,
+  restoreTrashed,
+  type TrashedItem,
+} from "../lib/trash";
+
+interface RecentlyDeletedProps {
+  limit?: number;
+  className?: string;
+  // Called before following the trash link, e.g. to close the quick switcher
+  onNavigate?: () => void;
+}
+
+/**
+ * The newest few trashed agents and workflows with a one-click restore.
+ * Renders nothing while the trash is empty.
+ */
+export function RecentlyDeleted({ limit = 5, className, onNavigate }: RecentlyDeletedProps) {
+  const queryClient = useQueryClient();
+  const { data: agents = [] } = useQuery({ queryKey: ["agents-trash"], queryFn: fetchTrashedAgents });
+  const { data: workflows = [] } = useQuery({ queryKey: ["workflows-trash"], queryFn: fetchTrashedWorkflows });
+
+  const restoreMutation = useMutation({
+    mutationFn: (item: TrashedItem) => restoreTrashed(item),
+    onSuccess: (_data, item) => {
+      invalidateTrashLists(queryClient);
+      toast.success(`Restored ${item.name}`);
+    },
+    onError: (error: Error, item) => {
+      toast.error(`Failed to restore ${item.kind}: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const items = mergeTrashed(agents, workflows, limit);
+  if (items.length === 0) return null;
+
+  return (
+    <section className={clsx("recently-deleted", className)} aria-labelledby="recently-deleted-title">
+      <header className="recently-deleted-header">
+        <h3 id="recently-deleted-title">Recently deleted</h3>
+        <Link to="/trash" onClick={onNavigate}>
+          View trash
+        </Link>
+      </header>
+      <ul className="recently-deleted-list">
+        {items.map((item) => {
+          const daysLeft = daysUntilPurge(item.deletedAt);
+          const pending =
+            restoreMutation.isPending &&
+            restoreMutation.variables?.kind === item.kind &&
+            restoreMutation.variables.id === item.id;
+          return (
+            <li key={`${item.kind}-${item.id}`} data-testid={`recently-deleted-${item.kind}-${item.id}`}>
+              <span className="recently-deleted-kind">{TRASHED_KIND_LABELS[item.kind]}</span>
+              <span className="recently-deleted-name">{item.name}</span>
+              <span className="recently-deleted-expiry">{daysLeft === 1 ? "1 day left" : `${daysLeft} days left`}</span>
+              <button
+                type="button"
+                className="btn-tertiary"
+                onClick={() => restoreMutation.mutate(item)}
+                disabled={pending}
+              >
+                Restore
+              </button>
+            </li>
+          );
+        })}
+      </ul>
+    </section>
+  );
+}
+
+export default RecentlyDeleted;
@@ -0,0 +1,216 @@
+import { useState } from "react";
+import toast from "react-hot-toast";
+import { exportFilename, openPrintableReport, runReportHtml, runReportMessages } from "../lib/export";
+import { hasSchemaViolations } from "../lib/outputSchema";
+import { isRunCancellable } from "../lib/runCancellation";
+import { isRunLive } from "../lib/runEvents";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import { fetchThreadMessages, type AgentRun } from "../services/api";
+import { formatCostUsd, formatLatency, formatTokenCount } from "./chat/chatUtils";
+import { RunLiveEvents } from "./RunLiveEvents";
+import { StructuredOutput } from "./StructuredOutput";
+import { TraceId } from "./TraceId";
+import Timestamp from "./Timestamp";
+
+interface RunDetailDrawerProps {
+  run: AgentRun;
+  agentName: string;
+  onClose: () => void;
+  // Shown for queued/running runs when provided
+  onCancel?: () => void;
+}
+
+type RunDrawerTab = "details" | "live";
+
+/**
+ * Details of a single agent run, opened from the dashboard run history.
+ * Active runs get a Live tab streaming their internal events.
+ * Shares the agent settings drawer chrome.
+ */
+export function RunDetailDrawer({ run, agentName, onClose, onCancel }: RunDetailDrawerProps) {
+  const drawerRef = useFocusTrap<HTMLElement>(true, onClose);
+  const [isExporting, setIsExporting] = useState(false);
+  const [tab, setTab] = useState<RunDrawerTab>("details");
+  // The tab stays once opened so the log outlives the run finishing
+  const showTabs = isRunLive(run) || tab === "live";
+
+  // Prompts and outputs come from the run's thread, so they're fetched on demand
+  const handleExportReport = async () => {
+    const threadId = run.thread_id;
+    if (threadId == null) {
+      return;
+    }
+    setIsExporting(true);
+    try {
+      const messages = runReportMessages(run, await fetchThreadMessages(threadId));
+      const html = runReportHtml({ run, agentName, messages });
+      if (!openPrintableReport(html, exportFilename(agentName, `run-${run.id}`, "html"))) {
+        toast("Pop-ups are blocked, so the report was downloaded instead.");
+      }
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to export report: ${message}`, { duration: 6000 });
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  return (
+    <div
+      className="agent-settings-backdrop open"
+      onClick={(event) => {
+        if (event.target === event.currentTarget) {
+          onClose();
+        }
+      }}
+      role="presentation"
+    >
+      <aside
+        ref={drawerRef}
+        className="agent-settings-drawer open"
+        role="dialog"
+        aria-modal="true"
+        aria-label={`Run ${run.id} details`}
+        tabIndex={-1}
+        data-testid="run-detail-drawer"
+      >
+        <header className="agent-settings-header">
+          <div>
+            <h2>Run #{run.id}</h2>
+            <p>{agentName}</p>
+          </div>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close run details">
+            ×
+          </button>
+        </header>
+
+        {showTabs && (
+          <div className="description-tabs run-detail-tabs" role="tablist" aria-label="Run views">
+            <button
+              type="button"
+              role="tab"
+              aria-selected={tab === "details"}
+              className={tab === "details" ? "btn-primary" : "btn-secondary"}
+              onClick={() => setTab("details")}
+            >
+              Details
+            </button>
+            <button
+              type="button"
+              role="tab"
+              aria-selected={tab === "live"}
+              className={tab === "live" ? "btn-primary" : "btn-secondary"}
+              onClick={() => setTab("live")}
+              data-testid="run-detail-live-tab"
+            >
+              Live
+            </button>
+          </div>
+        )}
+
+        {/* Mounted with the tabs so events keep collecting while Details is shown */}
+        {showTabs && <RunLiveEvents run={run} hidden={tab !== "live"} />}
+
+        {tab === "details" && (
+          <>
+            <section className="agent-settings-section">
+              <dl className="policy-grid">
+                <div>
+                  <dt>Status</dt>
+                  <dd>{run.status}</dd>
+                </div>
+                <div>
+                  <dt>Trigger</dt>
+                  <dd>{run.trigger}</dd>
+                </div>
+                <div>
+                  <dt>Started</dt>
+                  <dd>
+                    <Timestamp value={run.started_at} />
+                  </dd>
+                </div>
+                <div>
+                  <dt>Finished</dt>
+                  <dd>
+                    <Timestamp value={run.finished_at} />
+                  </dd>
+                </div>
+                <div>
+                  <dt>Duration</dt>
+                  <dd>{run.duration_ms != null ? formatLatency(run.duration_ms) : "-"}</dd>
+                </div>
+                <div>
+                  <dt>Tokens</dt>
+                  <dd>{run.total_tokens != null ? formatTokenCount(run.total_tokens) : "-"}</dd>
+                </div>
+                <div>
+                  <dt>Cost</dt>
+                  <dd>{run.total_cost_usd != null ? formatCostUsd(run.total_cost_usd) : "-"}</dd>
+                </div>
+              </dl>
+              <button
+                type="button"
+                className="btn-secondary"
+                onClick={handleExportReport}
+                disabled={isExporting || run.thread_id == null}
+                title={run.thread_id == null ? "Nothing to report until the run starts" : undefined}
+                data-testid="run-detail-export"
+              >
+                {isExporting ? "Preparing report…" : "Export report"}
+              </button>
+              {onCancel && (isRunCancellable(run) || run.status === "cancelling") && (
+                <button
+                  type="button"
+                  className="btn-danger"
+                  onClick={onCancel}
+                  disabled={run.status === "cancelling"}
+                  data-testid="run-detail-cancel"
+                >
+                  {run.status === "cancelling" ? "Cancelling…" : "Cancel run"}
+                </button>
+              )}
+            </section>
+
+            {run.error && (
+              <section className="agent-settings-section">
+                <h3>Error</h3>
+                <pre className="run-detail-error">{run.error}</pre>
+              </section>
+            )}
+
+            {hasSchemaViolations(run) && (
+              <section className="agent-settings-section" data-testid="run-schema-errors">
+                <h3>Schema violations</h3>
+                <p className="section-description">
+                  The final reply doesn&apos;t match the agent&apos;s output schema.
+                </p>
+                <ul className="run-schema-errors">
+                  {run.schema_errors?.map((error) => (
+                    <li key={error}>{error}</li>
+                  ))}
+                </ul>
+              </section>
+            )}
+
+            {run.output != null && (
+              <section className="agent-settings-section">
+                <h3>Output</h3>
+                <div className="structured-output">
+                  <StructuredOutput value={run.output} />
+                </div>
+              </section>
+            )}
+
+            <section className="agent-settings-section">
+              <h3>Trace ID</h3>
+              <p className="section-description">Share this ID with support to find the run in the server logs.</p>
+              {run.trace_id ? <TraceId traceId={run.trace_id} /> : <p className="muted">Not recorded for this run.</p>}
+            </section>
+          </>
+        )}
+      </aside>
+    </div>
+  );
+}
+
+export default RunDetailDrawer;
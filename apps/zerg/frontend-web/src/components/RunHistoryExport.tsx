@@ -0,0 +1,85 @@
+import { useEffect, useRef, useState } from "react";
+import toast from "react-hot-toast";
+import { downloadTextFile } from "../lib/download";
+import { exportFilename } from "../lib/export";
+import { fetchAllAgentRuns, serializeRuns, type RunExportFormat } from "../lib/runExport";
+
+interface RunHistoryExportProps {
+  agentId: number;
+  agentName: string;
+}
+
+/**
+ * Export an agent's complete run history as CSV or JSONL.
+ *
+ * The dashboard only loads the latest runs, so the export pages through the
+ * API itself and reports progress while it does.
+ */
+export function RunHistoryExport({ agentId, agentName }: RunHistoryExportProps) {
+  const [format, setFormat] = useState<RunExportFormat>("csv");
+  const [fetchedCount, setFetchedCount] = useState<number | null>(null);
+  const abortRef = useRef<AbortController | null>(null);
+
+  useEffect(() => () => abortRef.current?.abort(), []);
+
+  const isExporting = fetchedCount !== null;
+
+  const handleExport = async () => {
+    const controller = new AbortController();
+    abortRef.current = controller;
+    setFetchedCount(0);
+    try {
+      const runs = await fetchAllAgentRuns(agentId, {
+        signal: controller.signal,
+        onProgress: setFetchedCount,
+      });
+      downloadTextFile(
+        exportFilename(agentName, "runs", format),
+        serializeRuns(runs, format),
+        format === "csv" ? "text/csv" : "application/x-ndjson"
+      );
+      toast.success(`Exported ${runs.length} runs`);
+    } catch (error) {
+      if (error instanceof DOMException && error.name === "AbortError") {
+        return;
+      }
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to export runs: ${message}`, { duration: 6000 });
+    } finally {
+      if (abortRef.current === controller) {
+        abortRef.current = null;
+        setFetchedCount(null);
+      }
+    }
+  };
+
+  return (
+    <div className="run-history-export" data-testid={`run-export-${agentId}`}>
+      <select
+        value={format}
+        onChange={(event) => setFormat(event.target.value as RunExportFormat)}
+        disabled={isExporting}
+        aria-label="Export format"
+      >
+        <option value="csv">CSV</option>
+        <option value="jsonl">JSONL</option>
+      </select>
+      {isExporting ? (
+        <>
+          <span className="run-export-progress" role="status">
+            Fetched {fetchedCount} runs…
+          </span>
+          <button type="button" className="run-export-btn" onClick={() => abortRef.current?.abort()}>
+            Cancel
+          </button>
+        </>
+      ) : (
+        <button type="button" className="run-export-btn" onClick={handleExport}>
+          Export all runs
+        </button>
+      )}
+    </div>
+  );
+}
+
+export default RunHistoryExport;
@@ -0,0 +1,165 @@
+import { useEffect, useState, type FormEvent } from "react";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import {
+  initialRunInputValues,
+  validateRunInputs,
+  type RunInputSpec,
+  type RunInputValues,
+} from "../lib/workflowInputs";
+
+interface RunInputsModalProps {
+  specs: RunInputSpec[];
+  stepMode?: boolean;
+  // Values to start from, e.g. those of the run being repeated
+  initialInputs?: Record<string, unknown>;
+  // Per-input messages from the server's own check, keyed by input name
+  serverErrors?: Record<string, string>;
+  isStarting: boolean;
+  onRun: (inputs: Record<string, unknown>) => void;
+  onClose: () => void;
+}
+
+// Placeholders hint the expected shape of free-text types
+const PLACEHOLDERS: Partial<Record<RunInputSpec["type"], string>> = {
+  number: "e.g. 1.5",
+  integer: "e.g. 10",
+  json: '{"key": "value"}',
+};
+const INPUT_MODES: Partial<Record<RunInputSpec["type"], "numeric" | "decimal">> = {
+  number: "decimal",
+  integer: "numeric",
+};
+
+/** Form generated from the manual trigger's declared run inputs; submitting starts the run. */
+export function RunInputsModal({
+  specs,
+  stepMode = false,
+  initialInputs,
+  serverErrors,
+  isStarting,
+  onRun,
+  onClose,
+}: RunInputsModalProps) {
+  const dialogRef = useFocusTrap<HTMLDivElement>(true, onClose);
+  const [values, setValues] = useState<RunInputValues>(() => initialRunInputValues(specs, initialInputs));
+  const [errors, setErrors] = useState<Record<string, string>>({});
+
+  // Rejected by the server: show its messages until the fields are edited
+  useEffect(() => {
+    if (serverErrors) setErrors(serverErrors);
+  }, [serverErrors]);
+
+  const update = (name: string, value: string | boolean) => {
+    setValues((current) => ({ ...current, [name]: value }));
+    setErrors(({ [name]: _cleared, ...rest }) => rest);
+  };
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    const result = validateRunInputs(specs, values);
+    setErrors(result.errors);
+    if (Object.keys(result.errors).length === 0) onRun(result.inputs);
+  };
+
+  return (
+    <div className="connector-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        ref={dialogRef}
+        className="connector-modal run-inputs-modal"
+        onClick={(event) => event.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="run-inputs-title"
+        tabIndex={-1}
+        data-testid="run-inputs-modal"
+      >
+        <header className="connector-modal-header">
+          <h3 id="run-inputs-title">{stepMode ? "Run step by step" : "Run workflow"}</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <form onSubmit={handleSubmit} noValidate>
+          <div className="connector-modal-body">
+            <p className="connector-description">This workflow asks for these inputs before it runs.</p>
+            <div className="connector-fields">
+              {specs.map((spec) => {
+                const id = `run-input-${spec.name}`;
+                const error = errors[spec.name];
+                const described = [spec.description && `${id}-hint`, error && `${id}-error`].filter(Boolean);
+                const controlProps = {
+                  id,
+                  "aria-invalid": error ? true : undefined,
+                  "aria-describedby": described.length > 0 ? described.join(" ") : undefined,
+                };
+                return (
+                  <div key={spec.name} className="form-field run-input-field">
+                    {spec.type === "boolean" ? (
+                      <label className="run-input-toggle">
+                        <input
+                          type="checkbox"
+                          checked={values[spec.name] === true}
+                          onChange={(event) => update(spec.name, event.target.checked)}
+                          {...controlProps}
+                        />
+                        <span>{spec.name}</span>
+                      </label>
+                    ) : (
+                      <>
+                        <label htmlFor={id}>
+                          {spec.name}
+                          {spec.required && <span className="required">*</span>}
+                          <span className="run-input-type">{spec.type}</span>
+                        </label>
+                        {spec.type === "json" ? (
+                          <textarea
+                            className="run-input-code"
+                            value={String(values[spec.name] ?? "")}
+                            onChange={(event) => update(spec.name, event.target.value)}
+                            rows={4}
+                            spellCheck={false}
+                            placeholder={PLACEHOLDERS.json}
+                            {...controlProps}
+                          />
+                        ) : (
+                          <input
+                            type="text"
+                            inputMode={INPUT_MODES[spec.type]}
+                            value={String(values[spec.name] ?? "")}
+                            onChange={(event) => update(spec.name, event.target.value)}
+                            placeholder={PLACEHOLDERS[spec.type]}
+                            {...controlProps}
+                          />
+                        )}
+                      </>
+                    )}
+                    {spec.description && (
+                      <span id={`${id}-hint`} className="run-input-hint">
+                        {spec.description}
+                      </span>
+                    )}
+                    {error && (
+                      <span id={`${id}-error`} className="run-input-error" role="alert">
+                        {spec.name} {error}
+                      </span>
+                    )}
+                  </div>
+                );
+              })}
+            </div>
+          </div>
+
+          <footer className="connector-modal-footer">
+            <button type="button" className="btn-secondary" onClick={onClose}>
+              Cancel
+            </button>
+            <button type="submit" className="btn-primary" disabled={isStarting}>
+              {isStarting ? "Starting…" : "Run"}
+            </button>
+          </footer>
+        </form>
+      </div>
+    </div>
+  );
+}
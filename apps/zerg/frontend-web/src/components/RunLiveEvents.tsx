@@ -0,0 +1,130 @@
+import { useCallback, useEffect, useRef, useState } from "react";
+import clsx from "clsx";
+import toast from "react-hot-toast";
+import { copyToClipboard } from "../lib/clipboard";
+import {
+  appendRunEvents,
+  describeRunEvent,
+  isRunLive,
+  runEventFor,
+  runEventJson,
+  runEventLabel,
+  runEventTone,
+  type RunEventEntry,
+} from "../lib/runEvents";
+import { ConnectionStatus, useWebSocket } from "../lib/useWebSocket";
+import type { RunEventData } from "../generated/ws-messages";
+import type { AgentRun } from "../services/api";
+import Timestamp from "./Timestamp";
+
+async function copyEventJson(event: RunEventData) {
+  try {
+    await copyToClipboard(runEventJson(event));
+    toast.success("Event JSON copied");
+  } catch {
+    toast.error("Could not copy event JSON", { duration: 6000 });
+  }
+}
+
+/**
+ * Live tab of the run drawer: the run's internal events as they stream in on
+ * its agent topic. Pausing holds new events aside so the log stops moving
+ * while someone reads it.
+ */
+export function RunLiveEvents({ run, hidden = false }: { run: AgentRun; hidden?: boolean }) {
+  const [entries, setEntries] = useState<RunEventEntry[]>([]);
+  const [held, setHeld] = useState<RunEventEntry[]>([]);
+  const [paused, setPaused] = useState(false);
+  const pausedRef = useRef(paused);
+  const nextIdRef = useRef(0);
+  const live = isRunLive(run);
+
+  useEffect(() => {
+    pausedRef.current = paused;
+  }, [paused]);
+
+  const handleMessage = useCallback(
+    (message: { type: string; data?: unknown }) => {
+      const event = runEventFor(message, run.id);
+      if (!event) return;
+      nextIdRef.current += 1;
+      const entry: RunEventEntry = { id: nextIdRef.current, event };
+      if (pausedRef.current) {
+        setHeld((current) => appendRunEvents(current, [entry]));
+      } else {
+        setEntries((current) => appendRunEvents(current, [entry]));
+      }
+    },
+    [run.id]
+  );
+
+  // Only listen while the run can still produce events
+  const { connectionStatus, sendMessage } = useWebSocket(live, { onMessage: handleMessage });
+
+  // Subscriptions don't survive a reconnect, so resubscribe on every connect
+  useEffect(() => {
+    if (connectionStatus !== ConnectionStatus.CONNECTED) return;
+    const topic = `agent:${run.agent_id}`;
+    sendMessage({ type: "subscribe", topics: [topic], message_id: `run-live-${run.id}-${Date.now()}` });
+  }, [connectionStatus, sendMessage, run.agent_id, run.id]);
+
+  const togglePaused = () => {
+    if (paused) {
+      setEntries((current) => appendRunEvents(current, held));
+      setHeld([]);
+    }
+    setPaused(!paused);
+  };
+
+  return (
+    <section className="agent-settings-section run-live" role="tabpanel" hidden={hidden} data-testid="run-live-events">
+      <div className="run-live-toolbar">
+        <p className="section-description">
+          {live
+            ? "Tool calls, intermediate messages and retries as the run produces them."
+            : "The run has finished; no more events will arrive."}
+        </p>
+        <button
+          type="button"
+          className="btn-secondary"
+          aria-pressed={paused}
+          onClick={togglePaused}
+          disabled={!live && held.length === 0}
+        >
+          {paused ? `Resume${held.length > 0 ? ` (${held.length} new)` : ""}` : "Pause"}
+        </button>
+      </div>
+
+      {live && connectionStatus !== ConnectionStatus.CONNECTED && (
+        <p className="muted">Live stream {connectionStatus}…</p>
+      )}
+
+      {entries.length === 0 ? (
+        <p className="muted">{live ? "Waiting for events…" : "No events were received while this tab was open."}</p>
+      ) : (
+        <ol className="run-live-list" aria-live={paused ? "off" : "polite"}>
+          {entries.map(({ id, event }) => (
+            <li key={id} className={clsx("run-live-item", `run-live-tone-${runEventTone(event.kind)}`)}>
+              <div className="run-live-row">
+                <Timestamp value={event.timestamp} format="time" className="run-live-time" />
+                <span className="run-live-kind">{runEventLabel(event.kind)}</span>
+                <span className="run-live-text">{describeRunEvent(event)}</span>
+                <button
+                  type="button"
+                  className="btn-tertiary"
+                  onClick={() => copyEventJson(event)}
+                  aria-label={`Copy ${runEventLabel(event.kind).toLowerCase()} event as JSON`}
+                >
+                  Copy JSON
+                </button>
+              </div>
+              {event.preview && <pre className="run-live-preview">{event.preview}</pre>}
+            </li>
+          ))}
+        </ol>
+      )}
+    </section>
+  );
+}
+
+export default RunLiveEvents;
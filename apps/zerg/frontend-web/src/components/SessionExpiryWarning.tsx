@@ -0,0 +1,56 @@
+import { useEffect, useState } from "react";
+import { useFocusTrap } from "../lib/useFocusTrap";
+
+interface SessionExpiryWarningProps {
+  expiresAt: number;
+  onStay: () => void;
+  onSignOut: () => void;
+}
+
+function formatCountdown(ms: number): string {
+  const seconds = Math.max(0, Math.ceil(ms / 1000));
+  return `${Math.floor(seconds / 60)}:${String(seconds % 60).padStart(2, "0")}`;
+}
+
+/** Shown to idle users shortly before their session lapses. */
+export function SessionExpiryWarning({ expiresAt, onStay, onSignOut }: SessionExpiryWarningProps) {
+  const [now, setNow] = useState(() => Date.now());
+  const panelRef = useFocusTrap<HTMLDivElement>(true, onStay);
+
+  useEffect(() => {
+    const handle = setInterval(() => setNow(Date.now()), 1000);
+    return () => clearInterval(handle);
+  }, []);
+
+  return (
+    <div className="crash-overlay" data-testid="session-expiry-warning">
+      <div
+        ref={panelRef}
+        className="error-fallback crash-overlay-panel"
+        role="alertdialog"
+        aria-labelledby="session-expiry-title"
+        aria-describedby="session-expiry-message"
+        tabIndex={-1}
+      >
+        <h2 id="session-expiry-title" className="error-fallback__title">
+          Still there?
+        </h2>
+        <p id="session-expiry-message" className="error-fallback__message">
+          You'll be signed out in{" "}
+          <span className="session-expiry-countdown">{formatCountdown(expiresAt - now)}</span>. Unsaved changes
+          are kept either way.
+        </p>
+        <div className="error-fallback__actions">
+          <button onClick={onStay} className="error-fallback__button error-fallback__button--primary">
+            Stay signed in
+          </button>
+          <button onClick={onSignOut} className="error-fallback__button error-fallback__button--secondary">
+            Sign out
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default SessionExpiryWarning;
@@ -0,0 +1,67 @@
+import { useEffect, useSyncExternalStore } from "react";
+import { useQueryClient } from "@tanstack/react-query";
+import { useLocation } from "react-router-dom";
+import toast from "react-hot-toast";
+import { downloadTextFile } from "../lib/download";
+import {
+  isRecordingSession,
+  recordSessionNavigation,
+  recordedEventCount,
+  startSessionRecording,
+  stopSessionRecording,
+  subscribeSessionRecording,
+  traceToJson,
+} from "../lib/sessionRecorder";
+
+/**
+ * Status footer switch for session recording: start, then stop to download
+ * the sanitized trace and attach it to a bug report.
+ */
+export function SessionRecorderControl() {
+  const queryClient = useQueryClient();
+  const location = useLocation();
+  const recording = useSyncExternalStore(subscribeSessionRecording, isRecordingSession);
+  const eventCount = useSyncExternalStore(subscribeSessionRecording, recordedEventCount);
+
+  useEffect(() => {
+    recordSessionNavigation(location.pathname);
+  }, [location.pathname]);
+
+  const handleStart = () => {
+    startSessionRecording(queryClient);
+    recordSessionNavigation(location.pathname);
+    toast("Recording this session. Reproduce the problem, then stop to download the trace.");
+  };
+
+  const handleStop = () => {
+    const trace = stopSessionRecording();
+    if (!trace) return;
+    const stamp = trace.started_at.replace(/[:.]/g, "-");
+    downloadTextFile(`zerg-session-${stamp}.json`, traceToJson(trace), "application/json");
+    toast.success(`Downloaded ${trace.events.length} recorded events`);
+  };
+
+  return recording ? (
+    <button
+      type="button"
+      className="session-recorder session-recorder--active"
+      onClick={handleStop}
+      title="Stop recording and download the trace"
+      data-testid="session-recorder-stop"
+    >
+      ● Recording ({eventCount}) – stop &amp; download
+    </button>
+  ) : (
+    <button
+      type="button"
+      className="session-recorder"
+      onClick={handleStart}
+      title="Record app state changes and live messages (credentials removed) to attach to a bug report"
+      data-testid="session-recorder-start"
+    >
+      Record session
+    </button>
+  );
+}
+
+export default SessionRecorderControl;
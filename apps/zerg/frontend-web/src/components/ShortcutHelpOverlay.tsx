@@ -0,0 +1,57 @@
+import { useFocusTrap } from "../lib/useFocusTrap";
+import { useShortcutBindings } from "../lib/useShortcuts";
+import { SCOPE_LABELS, chordKeys, shortcutsForScope, type ShortcutScope } from "../lib/shortcuts";
+import "../styles/css/shortcuts.css";
+
+export function ShortcutChord({ chord }: { chord: string }) {
+  return (
+    <span className="shortcut-chord">
+      {chordKeys(chord).map((key, index) => (
+        <kbd key={index}>{key}</kbd>
+      ))}
+    </span>
+  );
+}
+
+interface ShortcutHelpOverlayProps {
+  scope: ShortcutScope;
+  onClose: () => void;
+}
+
+/** Lists the shortcuts available on a page, generated from the registry. */
+export function ShortcutHelpOverlay({ scope, onClose }: ShortcutHelpOverlayProps) {
+  const bindings = useShortcutBindings();
+  const dialogRef = useFocusTrap(true, onClose);
+
+  return (
+    <div
+      ref={dialogRef}
+      className="shortcut-help-overlay"
+      role="dialog"
+      aria-modal="true"
+      aria-labelledby="shortcut-help-title"
+      tabIndex={-1}
+      onClick={(event) => {
+        if (event.target === event.currentTarget) onClose();
+      }}
+    >
+      <div className="shortcut-help-panel">
+        <div className="shortcut-help-header">
+          <h3 id="shortcut-help-title">{SCOPE_LABELS[scope]} Shortcuts</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close shortcuts">
+            ×
+          </button>
+        </div>
+        <ul className="shortcut-help-list">
+          {shortcutsForScope(scope).map((action) => (
+            <li key={action.id}>
+              <span>{action.label}</span>
+              <ShortcutChord chord={bindings[action.id]} />
+            </li>
+          ))}
+        </ul>
+        <p className="shortcut-help-hint">Rebind keys from your profile. Press Esc to close.</p>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,103 @@
+import { useState, type KeyboardEvent as ReactKeyboardEvent } from "react";
+import clsx from "clsx";
+import { ShortcutChord } from "./ShortcutHelpOverlay";
+import {
+  SCOPE_LABELS,
+  SHORTCUT_ACTIONS,
+  chordFromEvent,
+  findConflicts,
+  type ShortcutActionId,
+  type ShortcutBindings,
+} from "../lib/shortcuts";
+import "../styles/css/shortcuts.css";
+
+interface ShortcutSettingsProps {
+  bindings: ShortcutBindings;
+  onChange: (bindings: ShortcutBindings) => void;
+}
+
+const labelFor = (id: ShortcutActionId) => SHORTCUT_ACTIONS.find((action) => action.id === id)?.label ?? id;
+
+/**
+ * Rebinding table for the profile page. Click a chord, then press the new
+ * key combination; Escape cancels. Clashing chords are flagged inline.
+ */
+export function ShortcutSettings({ bindings, onChange }: ShortcutSettingsProps) {
+  const [recording, setRecording] = useState<ShortcutActionId | null>(null);
+  const conflicts = findConflicts(bindings);
+
+  const handleRecordKeyDown = (event: ReactKeyboardEvent<HTMLButtonElement>, id: ShortcutActionId) => {
+    if (recording !== id) return;
+    // Tab still moves focus; everything else is captured
+    if (event.key === "Tab") {
+      setRecording(null);
+      return;
+    }
+    event.preventDefault();
+    event.stopPropagation();
+    if (event.key === "Escape") {
+      setRecording(null);
+      return;
+    }
+    const chord = chordFromEvent(event.nativeEvent);
+    if (!chord) return;
+    onChange({ ...bindings, [id]: chord });
+    setRecording(null);
+  };
+
+  return (
+    <table className="shortcut-settings" data-testid="shortcut-settings">
+      <thead>
+        <tr>
+          <th scope="col">Action</th>
+          <th scope="col">Where</th>
+          <th scope="col">Keys</th>
+          <th scope="col">
+            <span className="sr-only">Reset</span>
+          </th>
+        </tr>
+      </thead>
+      <tbody>
+        {SHORTCUT_ACTIONS.map((action) => {
+          const clashing = conflicts[action.id];
+          const isRecording = recording === action.id;
+          return (
+            <tr key={action.id} className={clsx({ "shortcut-row--conflict": clashing })}>
+              <td>{action.label}</td>
+              <td>{SCOPE_LABELS[action.scope]}</td>
+              <td>
+                <button
+                  type="button"
+                  className="shortcut-rebind-btn"
+                  aria-pressed={isRecording}
+                  aria-label={`${action.label}: ${bindings[action.id]}. Press to rebind`}
+                  onClick={() => setRecording(isRecording ? null : action.id)}
+                  onKeyDown={(event) => handleRecordKeyDown(event, action.id)}
+                  onBlur={() => isRecording && setRecording(null)}
+                >
+                  {isRecording ? "Press keys…" : <ShortcutChord chord={bindings[action.id]} />}
+                </button>
+                {clashing && (
+                  <span className="shortcut-conflict" role="alert">
+                    Also bound to {clashing.map(labelFor).join(", ")}
+                  </span>
+                )}
+              </td>
+              <td>
+                {bindings[action.id] !== action.defaultChord && (
+                  <button
+                    type="button"
+                    className="shortcut-reset-btn"
+                    onClick={() => onChange({ ...bindings, [action.id]: action.defaultChord })}
+                  >
+                    Reset
+                  </button>
+                )}
+              </td>
+            </tr>
+          );
+        })}
+      </tbody>
+    </table>
+  );
+}
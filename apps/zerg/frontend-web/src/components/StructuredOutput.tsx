@@ -0,0 +1,72 @@
+import { formatScalar, isPlainObject, tableColumns } from "../lib/outputSchema";
+
+type StructuredOutputProps = {
+  value: unknown;
+};
+
+/**
+ * JSON run output as a tree: objects become key/value lists, arrays of
+ * objects become tables and nested values are rendered recursively.
+ */
+export function StructuredOutput({ value }: StructuredOutputProps) {
+  const columns = tableColumns(value);
+  if (columns) {
+    const rows = value as Record<string, unknown>[];
+    return (
+      <table className="structured-output-table">
+        <thead>
+          <tr>
+            {columns.map((column) => (
+              <th key={column}>{column}</th>
+            ))}
+          </tr>
+        </thead>
+        <tbody>
+          {rows.map((row, index) => (
+            <tr key={index}>
+              {columns.map((column) => (
+                <td key={column}>
+                  <StructuredOutput value={row[column]} />
+                </td>
+              ))}
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    );
+  }
+
+  if (Array.isArray(value)) {
+    if (value.length === 0) return <span className="structured-output-empty">empty list</span>;
+    return (
+      <ol className="structured-output-list">
+        {value.map((item, index) => (
+          <li key={index}>
+            <StructuredOutput value={item} />
+          </li>
+        ))}
+      </ol>
+    );
+  }
+
+  if (isPlainObject(value)) {
+    const entries = Object.entries(value);
+    if (entries.length === 0) return <span className="structured-output-empty">empty object</span>;
+    return (
+      <dl className="structured-output-object">
+        {entries.map(([key, item]) => (
+          <div key={key}>
+            <dt>{key}</dt>
+            <dd>
+              <StructuredOutput value={item} />
+            </dd>
+          </div>
+        ))}
+      </dl>
+    );
+  }
+
+  return <span className={`structured-output-scalar ${typeof value}`}>{formatScalar(value)}</span>;
+}
+
+export default StructuredOutput;
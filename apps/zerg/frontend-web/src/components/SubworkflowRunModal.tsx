@@ -0,0 +1,132 @@
+import { createContext, useState } from "react";
+import { useQuery } from "@tanstack/react-query";
+import clsx from "clsx";
+import { getExecutionDetail, type ExecutionDetail } from "../services/api";
+import { useFocusTrap } from "../lib/useFocusTrap";
+
+// Opens the child run started by the Call Workflow node with the given id
+export const SubworkflowRunContext = createContext<((nodeId: string) => void) | null>(null);
+
+interface SubworkflowRunModalProps {
+  // Run on the canvas and the Call Workflow node to drill into
+  executionId: number;
+  nodeId: string;
+  onClose: () => void;
+}
+
+// Latest child run a call node started (loops can call more than once)
+function childRunId(detail: ExecutionDetail | undefined, nodeId: string): number | null {
+  const runs = detail?.children.filter((child) => child.node_id === nodeId) ?? [];
+  return runs.length > 0 ? runs[runs.length - 1].execution_id : null;
+}
+
+function nodeLabel(detail: ExecutionDetail, nodeId: string): string {
+  const node = detail.canvas.nodes.find((candidate) => candidate.id === nodeId);
+  const text = (node?.config as Record<string, unknown> | undefined)?.text;
+  return typeof text === "string" && text ? text : nodeId;
+}
+
+/**
+ * Read-only view of a called workflow's run: its node states, with nested
+ * Call Workflow nodes opening their own child runs. The breadcrumb walks back
+ * up the call chain.
+ */
+export function SubworkflowRunModal({ executionId, nodeId, onClose }: SubworkflowRunModalProps) {
+  const dialogRef = useFocusTrap<HTMLDivElement>(true, onClose);
+  // Runs drilled into below the first child, outermost first
+  const [trail, setTrail] = useState<number[]>([]);
+
+  const { data: parent } = useQuery({
+    queryKey: ["execution-detail", executionId],
+    queryFn: () => getExecutionDetail(executionId),
+    // The child run is created just after the node starts
+    refetchInterval: (query) => (childRunId(query.state.data, nodeId) == null ? 1000 : false),
+  });
+  const rootRunId = childRunId(parent, nodeId);
+  const stack = rootRunId != null ? [rootRunId, ...trail] : [];
+  const currentId = stack.length > 0 ? stack[stack.length - 1] : null;
+
+  const { data: detail, isLoading } = useQuery({
+    queryKey: ["execution-detail", currentId],
+    queryFn: () => getExecutionDetail(currentId!),
+    enabled: currentId != null,
+    // Keep following the run while it's in progress
+    refetchInterval: (query) => (query.state.data?.phase === "finished" ? false : 2000),
+  });
+
+  const openChild = (childNodeId: string) => {
+    const child = childRunId(detail, childNodeId);
+    if (child != null) setTrail((current) => [...current, child]);
+  };
+
+  return (
+    <div className="connector-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        ref={dialogRef}
+        className="connector-modal subworkflow-run-modal"
+        onClick={(event) => event.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="subworkflow-run-title"
+        tabIndex={-1}
+        data-testid="subworkflow-run-modal"
+      >
+        <header className="connector-modal-header">
+          <h3 id="subworkflow-run-title">{detail ? detail.workflow_name : "Called workflow"}</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <nav className="subworkflow-run-breadcrumb" aria-label="Call chain">
+          <button type="button" className="subworkflow-run-crumb" onClick={onClose}>
+            {parent?.workflow_name ?? "This workflow"}
+          </button>
+          {stack.map((runId, index) => (
+            <span key={runId}>
+              {" › "}
+              {index === stack.length - 1 ? (
+                <span aria-current="page">Run #{runId}</span>
+              ) : (
+                <button
+                  type="button"
+                  className="subworkflow-run-crumb"
+                  onClick={() => setTrail(trail.slice(0, index))}
+                >
+                  Run #{runId}
+                </button>
+              )}
+            </span>
+          ))}
+        </nav>
+
+        {parent && rootRunId == null && (
+          <p className="subworkflow-run-empty">This node hasn&apos;t started a run yet.</p>
+        )}
+        {isLoading && <p className="subworkflow-run-empty">Loading run…</p>}
+        {detail && (
+          <>
+            <p className="subworkflow-run-status">
+              {detail.phase === "finished" ? `Finished: ${detail.result ?? "unknown"}` : `Status: ${detail.phase}`}
+              {detail.error_message && ` — ${detail.error_message}`}
+            </p>
+            <ul className="subworkflow-run-nodes">
+              {detail.nodes.map((node) => (
+                <li key={node.node_id} className={clsx("subworkflow-run-node", node.result && `is-${node.result}`)}>
+                  <span className="subworkflow-run-node-label">{nodeLabel(detail, node.node_id)}</span>
+                  <span className="subworkflow-run-node-state">{node.result ?? node.phase}</span>
+                  {childRunId(detail, node.node_id) != null && (
+                    <button type="button" className="btn-secondary" onClick={() => openChild(node.node_id)}>
+                      Open run
+                    </button>
+                  )}
+                  {node.error_message && <div className="subworkflow-run-node-error">{node.error_message}</div>}
+                </li>
+              ))}
+            </ul>
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,86 @@
+import { useId, useState, type KeyboardEvent as ReactKeyboardEvent } from "react";
+import { isImeComposing } from "../lib/ime";
+import { MAX_TAG_LENGTH, MAX_TAGS, addTag, hasTag, removeTag } from "../lib/tags";
+
+interface TagEditorProps {
+  tags: readonly string[];
+  onChange: (tags: string[]) => void;
+  // Existing tags offered while typing
+  suggestions?: readonly string[];
+  label: string;
+  disabled?: boolean;
+}
+
+/**
+ * Tags as removable chips plus an input for new ones. Enter or a comma adds
+ * the typed tag; Backspace in the empty input removes the last chip.
+ */
+export function TagEditor({ tags, onChange, suggestions = [], label, disabled = false }: TagEditorProps) {
+  const listId = useId();
+  const [draft, setDraft] = useState("");
+  const full = tags.length >= MAX_TAGS;
+
+  const commit = () => {
+    const next = addTag(tags, draft);
+    setDraft("");
+    if (next.length !== tags.length) onChange(next);
+  };
+
+  const handleKeyDown = (event: ReactKeyboardEvent<HTMLInputElement>) => {
+    if (isImeComposing(event)) return;
+    if (event.key === "Enter" || event.key === ",") {
+      event.preventDefault();
+      commit();
+    } else if (event.key === "Backspace" && draft === "" && tags.length > 0) {
+      event.preventDefault();
+      onChange(tags.slice(0, -1));
+    }
+  };
+
+  return (
+    <div className="tag-editor" role="group" aria-label={label} data-testid="tag-editor">
+      {tags.map((tag) => (
+        <span key={tag} className="tag-chip">
+          {tag}
+          {!disabled && (
+            <button
+              type="button"
+              className="tag-chip-remove"
+              onClick={() => onChange(removeTag(tags, tag))}
+              aria-label={`Remove tag ${tag}`}
+            >
+              ×
+            </button>
+          )}
+        </span>
+      ))}
+      {!disabled && (
+        <>
+          <input
+            type="text"
+            className="tag-editor-input"
+            value={draft}
+            onChange={(event) => setDraft(event.target.value)}
+            onKeyDown={handleKeyDown}
+            onBlur={() => draft.trim() && commit()}
+            placeholder={full ? `Up to ${MAX_TAGS} tags` : tags.length > 0 ? "Add tag" : "Add tags…"}
+            maxLength={MAX_TAG_LENGTH}
+            disabled={full}
+            list={listId}
+            aria-label={`Add ${label.toLowerCase()}`}
+            data-testid="tag-editor-input"
+          />
+          <datalist id={listId}>
+            {suggestions
+              .filter((suggestion) => !hasTag(tags, suggestion))
+              .map((suggestion) => (
+                <option key={suggestion} value={suggestion} />
+              ))}
+          </datalist>
+        </>
+      )}
+    </div>
+  );
+}
+
+export default TagEditor;
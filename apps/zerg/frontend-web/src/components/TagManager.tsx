@@ -0,0 +1,123 @@
+import { useState, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { deleteTag, fetchTags, renameTag, type TagSummary } from "../services/api";
+import { MAX_TAG_LENGTH, TAGS_QUERY_KEY, normalizeTag, sameTag } from "../lib/tags";
+
+function usageLabel(tag: TagSummary): string {
+  const parts = [];
+  if (tag.agent_count > 0) parts.push(`${tag.agent_count} agent${tag.agent_count === 1 ? "" : "s"}`);
+  if (tag.workflow_count > 0) parts.push(`${tag.workflow_count} workflow${tag.workflow_count === 1 ? "" : "s"}`);
+  return parts.join(", ") || "Only on trashed items";
+}
+
+/**
+ * Rename, merge and delete the tags on the user's agents and workflows.
+ * Renaming a tag onto an existing one merges the two.
+ */
+export function TagManager() {
+  const queryClient = useQueryClient();
+  const [editing, setEditing] = useState<string | null>(null);
+  const [draft, setDraft] = useState("");
+
+  const { data: tags = [], isLoading } = useQuery({ queryKey: TAGS_QUERY_KEY, queryFn: fetchTags });
+
+  const applied = (updated: TagSummary[]) => {
+    queryClient.setQueryData(TAGS_QUERY_KEY, updated);
+    // Tags show on the dashboard, the shelf, the quick switcher and the canvas
+    for (const queryKey of [["dashboard"], ["agents"], ["agent"], ["workflows"], ["workflow"], ["quick-switcher"]]) {
+      queryClient.invalidateQueries({ queryKey });
+    }
+  };
+
+  const renameMutation = useMutation({
+    mutationFn: ({ tag, name }: { tag: string; name: string }) => renameTag(tag, name),
+    onSuccess: (updated) => {
+      applied(updated);
+      setEditing(null);
+    },
+    onError: (error: Error) => toast.error(`Failed to rename tag: ${error.message}`, { duration: 6000 }),
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: (tag: string) => deleteTag(tag),
+    onSuccess: applied,
+    onError: (error: Error) => toast.error(`Failed to delete tag: ${error.message}`, { duration: 6000 }),
+  });
+
+  const handleRename = (event: FormEvent, tag: string) => {
+    event.preventDefault();
+    const name = normalizeTag(draft);
+    if (!name || name === tag) {
+      setEditing(null);
+      return;
+    }
+    const target = tags.find((other) => other.name !== tag && sameTag(other.name, name));
+    const merge = `Merge “${tag}” into “${target?.name}”? Everything tagged “${tag}” gets that tag.`;
+    if (target && !window.confirm(merge)) return;
+    renameMutation.mutate({ tag, name });
+  };
+
+  const handleDelete = (tag: TagSummary) => {
+    if (window.confirm(`Remove the tag “${tag.name}” from ${usageLabel(tag).toLowerCase()}?`)) {
+      deleteMutation.mutate(tag.name);
+    }
+  };
+
+  if (isLoading) return <p className="muted">Loading tags…</p>;
+  if (tags.length === 0) {
+    return <p className="muted">No tags yet. Add them from an agent&apos;s config or the canvas toolbar.</p>;
+  }
+
+  return (
+    <ul className="tag-manager" data-testid="tag-manager">
+      {tags.map((tag) => (
+        <li key={tag.name} className="tag-manager-row" data-testid={`tag-row-${tag.name}`}>
+          {editing === tag.name ? (
+            <form className="tag-manager-rename" onSubmit={(event) => handleRename(event, tag.name)}>
+              <input
+                type="text"
+                value={draft}
+                onChange={(event) => setDraft(event.target.value)}
+                maxLength={MAX_TAG_LENGTH}
+                aria-label={`New name for ${tag.name}`}
+                autoFocus
+              />
+              <button type="submit" className="btn-primary" disabled={renameMutation.isPending}>
+                Save
+              </button>
+              <button type="button" className="btn-secondary" onClick={() => setEditing(null)}>
+                Cancel
+              </button>
+            </form>
+          ) : (
+            <>
+              <span className="tag-chip">{tag.name}</span>
+              <span className="muted tag-manager-usage">{usageLabel(tag)}</span>
+              <button
+                type="button"
+                className="btn-tertiary"
+                onClick={() => {
+                  setEditing(tag.name);
+                  setDraft(tag.name);
+                }}
+              >
+                Rename
+              </button>
+              <button
+                type="button"
+                className="btn-tertiary"
+                onClick={() => handleDelete(tag)}
+                disabled={deleteMutation.isPending}
+              >
+                Delete
+              </button>
+            </>
+          )}
+        </li>
+      ))}
+    </ul>
+  );
+}
+
+export default TagManager;
@@ -0,0 +1,25 @@
+import { useTheme } from "../lib/useTheme";
+import { THEMES, THEME_LABELS, isTheme } from "../lib/theme";
+
+/** Compact theme picker for the header user menu. */
+export function ThemeSwitcher() {
+  const { theme, setTheme } = useTheme();
+
+  return (
+    <select
+      className="theme-switcher"
+      aria-label="Color theme"
+      title="Color theme"
+      value={theme}
+      onChange={(e) => {
+        if (isTheme(e.target.value)) setTheme(e.target.value);
+      }}
+    >
+      {THEMES.map((value) => (
+        <option key={value} value={value}>
+          {THEME_LABELS[value]}
+        </option>
+      ))}
+    </select>
+  );
+}
@@ -0,0 +1,208 @@
+import { useMemo, useState, type KeyboardEvent, type MouseEvent } from "react";
+import clsx from "clsx";
+import {
+  labelStride,
+  nearestIndex,
+  niceTicks,
+  seriesMax,
+  stackSeries,
+  xPosition,
+  yPosition,
+  type ChartBox,
+  type ChartSeries,
+} from "../lib/chart";
+import "../styles/css/chart.css";
+
+interface TimeSeriesChartProps {
+  // One label per point (timestamp or bucket name), shown on the x axis and in the tooltip
+  labels: string[];
+  series: ChartSeries[];
+  stacked?: boolean;
+  height?: number;
+  ariaLabel: string;
+  formatValue?: (value: number) => string;
+  formatLabel?: (label: string) => string;
+  maxXLabels?: number;
+}
+
+// Drawn in a fixed coordinate space and scaled to the container width
+const VIEW_WIDTH = 600;
+const MARGIN = { top: 12, right: 12, bottom: 24, left: 44 };
+
+const defaultFormat = (value: number) => (Number.isInteger(value) ? String(value) : value.toFixed(2));
+const identity = (label: string) => label;
+
+function linePath(box: ChartBox, values: (number | null)[], max: number): string {
+  let path = "";
+  let penDown = false;
+  values.forEach((value, index) => {
+    if (value === null) {
+      penDown = false;
+      return;
+    }
+    const x = xPosition(box, index, values.length).toFixed(1);
+    const y = yPosition(box, value, max).toFixed(1);
+    path += `${penDown ? "L" : "M"}${x},${y} `;
+    penDown = true;
+  });
+  return path.trim();
+}
+
+function areaPath(box: ChartBox, lower: number[], upper: number[], max: number): string {
+  const count = upper.length;
+  const point = (value: number, index: number) =>
+    `${xPosition(box, index, count).toFixed(1)},${yPosition(box, value, max).toFixed(1)}`;
+  const top = upper.map(point);
+  const bottom = lower.map(point).reverse();
+  return `M${top.join(" L")} L${bottom.join(" L")} Z`;
+}
+
+/**
+ * Small SVG line/area chart with a y axis, gridlines, x labels and a hover
+ * (or arrow-key) tooltip. Stacked mode draws each series as a layer on top
+ * of the previous ones, e.g. successful runs under failed runs.
+ */
+export function TimeSeriesChart({
+  labels,
+  series,
+  stacked = false,
+  height = 180,
+  ariaLabel,
+  formatValue = defaultFormat,
+  formatLabel = identity,
+  maxXLabels = 8,
+}: TimeSeriesChartProps) {
+  const [hoverIndex, setHoverIndex] = useState<number | null>(null);
+  const box: ChartBox = useMemo(() => ({ width: VIEW_WIDTH, height, ...MARGIN }), [height]);
+  const count = labels.length;
+
+  const ticks = useMemo(() => niceTicks(seriesMax(series, stacked)), [series, stacked]);
+  const max = ticks[ticks.length - 1];
+  const layers = useMemo(() => (stacked ? stackSeries(series.map((entry) => entry.values)) : null), [series, stacked]);
+  const stride = labelStride(count, maxXLabels);
+
+  const handleMouseMove = (event: MouseEvent<SVGSVGElement>) => {
+    const rect = event.currentTarget.getBoundingClientRect();
+    if (!rect.width || count === 0) return;
+    const x = ((event.clientX - rect.left) / rect.width) * VIEW_WIDTH;
+    setHoverIndex(nearestIndex(box, x, count));
+  };
+
+  const handleKeyDown = (event: KeyboardEvent<SVGSVGElement>) => {
+    if (count === 0) return;
+    if (event.key === "ArrowRight" || event.key === "ArrowLeft") {
+      event.preventDefault();
+      const delta = event.key === "ArrowRight" ? 1 : -1;
+      setHoverIndex((current) => Math.min(count - 1, Math.max(0, (current ?? (delta > 0 ? -1 : count)) + delta)));
+    } else if (event.key === "Escape") {
+      setHoverIndex(null);
+    }
+  };
+
+  if (count === 0) {
+    return <div className="ts-chart ts-chart--empty">No data for this period.</div>;
+  }
+
+  const hoverX = hoverIndex !== null ? xPosition(box, hoverIndex, count) : null;
+
+  return (
+    <figure className="ts-chart" aria-label={ariaLabel}>
+      <svg
+        viewBox={`0 0 ${VIEW_WIDTH} ${height}`}
+        className="ts-chart__svg"
+        role="img"
+        aria-label={ariaLabel}
+        tabIndex={0}
+        onMouseMove={handleMouseMove}
+        onMouseLeave={() => setHoverIndex(null)}
+        onKeyDown={handleKeyDown}
+        onBlur={() => setHoverIndex(null)}
+      >
+        {ticks.map((tick) => {
+          const y = yPosition(box, tick, max);
+          return (
+            <g key={tick} className="ts-chart__grid">
+              <line x1={box.left} x2={VIEW_WIDTH - box.right} y1={y} y2={y} />
+              <text x={box.left - 6} y={y} dy="0.32em" textAnchor="end" className="ts-chart__tick">
+                {formatValue(tick)}
+              </text>
+            </g>
+          );
+        })}
+
+        {labels.map((label, index) =>
+          index % stride === 0 || index === count - 1 ? (
+            <text
+              key={`${label}-${index}`}
+              x={xPosition(box, index, count)}
+              y={height - 6}
+              textAnchor="middle"
+              className="ts-chart__tick"
+            >
+              {formatLabel(label)}
+            </text>
+          ) : null
+        )}
+
+        {series.map((entry, seriesIndex) => {
+          const layer = layers?.[seriesIndex];
+          return (
+            <g key={entry.key} className={clsx("ts-chart__series", `chart-tone-${entry.tone}`)}>
+              {layer && <path className="ts-chart__area" d={areaPath(box, layer.lower, layer.upper, max)} />}
+              <path className="ts-chart__line" d={linePath(box, layer ? layer.upper : entry.values, max)} />
+            </g>
+          );
+        })}
+
+        {hoverIndex !== null && hoverX !== null && (
+          <g className="ts-chart__hover">
+            <line x1={hoverX} x2={hoverX} y1={box.top} y2={height - box.bottom} />
+            {series.map((entry, seriesIndex) => {
+              const value = layers ? layers[seriesIndex].upper[hoverIndex] : entry.values[hoverIndex];
+              if (value === null || value === undefined) return null;
+              return (
+                <circle
+                  key={entry.key}
+                  className={`chart-tone-${entry.tone}`}
+                  cx={hoverX}
+                  cy={yPosition(box, value, max)}
+                  r={3.5}
+                />
+              );
+            })}
+          </g>
+        )}
+      </svg>
+
+      {hoverIndex !== null && hoverX !== null && (
+        <div
+          className={clsx("ts-chart__tooltip", { "ts-chart__tooltip--left": hoverX > VIEW_WIDTH / 2 })}
+          style={{ left: `${(hoverX / VIEW_WIDTH) * 100}%` }}
+          role="status"
+        >
+          <div className="ts-chart__tooltip-label">{formatLabel(labels[hoverIndex])}</div>
+          {series.map((entry) => {
+            const value = entry.values[hoverIndex];
+            return (
+              <div key={entry.key} className={clsx("ts-chart__tooltip-row", `chart-tone-${entry.tone}`)}>
+                <span className="ts-chart__swatch" />
+                {entry.label}: {value === null ? "—" : formatValue(value)}
+              </div>
+            );
+          })}
+        </div>
+      )}
+
+      {series.length > 1 && (
+        <figcaption className="ts-chart__legend">
+          {series.map((entry) => (
+            <span key={entry.key} className={clsx("ts-chart__legend-item", `chart-tone-${entry.tone}`)}>
+              <span className="ts-chart__swatch" />
+              {entry.label}
+            </span>
+          ))}
+        </figcaption>
+      )}
+    </figure>
+  );
+}
@@ -0,0 +1,44 @@
+import {
+  formatDateTimeShort,
+  formatRelativeTime,
+  formatTimeShort,
+  formatUtcTooltip,
+  parseTimestamp,
+} from "../lib/datetime";
+import { useNow, useTimePreferences } from "../lib/useTimePreferences";
+
+interface TimestampProps {
+  value: string | null | undefined;
+  // "time" shows only HH:MM (chat messages, thread lists)
+  format?: "datetime" | "time";
+  fallback?: string;
+  className?: string;
+}
+
+/**
+ * A timestamp in the user's timezone (or relative, if they prefer), with
+ * the UTC time on hover.
+ */
+export function Timestamp({ value, format = "datetime", fallback = "-", className }: TimestampProps) {
+  const { timeZone, mode } = useTimePreferences();
+  const relative = mode === "relative";
+  const now = useNow(relative);
+  const date = parseTimestamp(value);
+
+  if (!date) {
+    return <span className={className}>{fallback}</span>;
+  }
+
+  const absolute = format === "time" ? formatTimeShort(date, timeZone) : formatDateTimeShort(date, timeZone);
+  const utc = formatUtcTooltip(date);
+  const text = relative ? formatRelativeTime(date, { now, timeZone }) : absolute;
+  const title = relative ? `${formatDateTimeShort(date, timeZone)} ${timeZone}\n${utc}` : utc;
+
+  return (
+    <time className={className} dateTime={date.toISOString()} title={title}>
+      {text}
+    </time>
+  );
+}
+
+export default Timestamp;
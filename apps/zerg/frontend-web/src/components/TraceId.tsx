@@ -0,0 +1,43 @@
+import { useState } from "react";
+import { copyToClipboard } from "../lib/clipboard";
+
+interface TraceIdProps {
+  traceId: string;
+  // Hide the id itself and only show the copy button (e.g. in toasts, where
+  // the message text already includes it)
+  compact?: boolean;
+}
+
+/**
+ * Backend trace id with a copy button, so users can hand support a single id
+ * that matches the server logs.
+ */
+export function TraceId({ traceId, compact = false }: TraceIdProps) {
+  const [copied, setCopied] = useState(false);
+
+  const handleCopy = () => {
+    copyToClipboard(traceId)
+      .then(() => {
+        setCopied(true);
+        window.setTimeout(() => setCopied(false), 2000);
+      })
+      .catch(() => undefined);
+  };
+
+  return (
+    <span className="trace-id" data-testid="trace-id">
+      {!compact && <code className="trace-id-value">{traceId}</code>}
+      <button
+        type="button"
+        className="trace-id-copy"
+        onClick={handleCopy}
+        aria-label={`Copy trace ID ${traceId}`}
+        title="Copy trace ID"
+      >
+        {copied ? "Copied" : compact ? "Copy trace ID" : "Copy"}
+      </button>
+    </span>
+  );
+}
+
+export default TraceId;
@@ -0,0 +1,79 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { fetchTags, updateWorkflow, type Workflow } from "../services/api";
+import { TAGS_QUERY_KEY } from "../lib/tags";
+import { TagEditor } from "./TagEditor";
+
+/**
+ * Canvas toolbar button for the open workflow's tags. The chips save as they
+ * change; the quick switcher and the tag settings pick them up from there.
+ */
+export function WorkflowTagsButton({ workflow }: { workflow: Workflow | undefined }) {
+  const queryClient = useQueryClient();
+  const [open, setOpen] = useState(false);
+  const [tags, setTags] = useState<string[] | null>(null);
+  const shown = tags ?? workflow?.tags ?? [];
+
+  const { data: known = [] } = useQuery({
+    queryKey: TAGS_QUERY_KEY,
+    queryFn: fetchTags,
+    enabled: open,
+    staleTime: 60000,
+  });
+
+  const saveMutation = useMutation({
+    mutationFn: ({ id, next }: { id: number; next: string[] }) => updateWorkflow(id, { tags: next }),
+    onSuccess: (updated) => {
+      setTags(null);
+      queryClient.setQueriesData<Workflow>({ queryKey: ["workflow", "current"] }, (current) =>
+        current && current.id === updated.id ? { ...current, tags: updated.tags } : current
+      );
+      queryClient.invalidateQueries({ queryKey: ["workflows"] });
+      queryClient.invalidateQueries({ queryKey: ["quick-switcher", "workflows"] });
+      queryClient.invalidateQueries({ queryKey: TAGS_QUERY_KEY });
+    },
+    onError: (error: Error) => {
+      setTags(null);
+      toast.error(`Failed to save workflow tags: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <span className="workflow-tags">
+      <button
+        type="button"
+        className="canvas-toggle-btn"
+        onClick={() => setOpen((current) => !current)}
+        aria-expanded={open}
+        aria-label="Workflow tags"
+        title={shown.length > 0 ? `Tags: ${shown.join(", ")}` : "Tag this workflow"}
+        disabled={!workflow?.id}
+        data-testid="workflow-tags-toggle"
+      >
+        🏷️
+      </button>
+      {open && workflow && (
+        <div className="workflow-tags-popover" role="dialog" aria-label={`Tags for ${workflow.name}`}>
+          <div className="workflow-tags-popover-header">
+            <span>Tags</span>
+            <button type="button" className="btn-tertiary" onClick={() => setOpen(false)} aria-label="Close tags">
+              ×
+            </button>
+          </div>
+          <TagEditor
+            tags={shown}
+            onChange={(next) => {
+              setTags(next);
+              saveMutation.mutate({ id: workflow.id, next });
+            }}
+            suggestions={known.map((tag) => tag.name)}
+            label="Workflow tags"
+          />
+        </div>
+      )}
+    </span>
+  );
+}
+
+export default WorkflowTagsButton;
@@ -0,0 +1,122 @@
+import { useSyncExternalStore } from "react";
+import { toast } from "react-hot-toast";
+import { copyToClipboard } from "../lib/clipboard";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import { framesToJson, wsFrameLog } from "../lib/wsFrameLog";
+import { wsSchemaMismatches, type SchemaMismatch } from "../lib/wsValidation";
+import "../styles/css/ws-debug.css";
+
+interface WsDebugPanelProps {
+  onClose: () => void;
+}
+
+function formatTime(ts: number): string {
+  const date = new Date(ts);
+  return `${date.toLocaleTimeString([], { hour12: false })}.${String(date.getMilliseconds()).padStart(3, "0")}`;
+}
+
+// Incoming messages that didn't match the generated protocol schema, by type
+function SchemaMismatches({ mismatches }: { mismatches: SchemaMismatch[] }) {
+  return (
+    <section className="ws-debug-mismatches" data-testid="ws-schema-mismatches">
+      <header className="ws-debug-mismatches-header">
+        <h4>Schema mismatches ({mismatches.reduce((total, entry) => total + entry.count, 0)})</h4>
+        <button type="button" onClick={() => wsSchemaMismatches.clear()}>
+          Reset
+        </button>
+      </header>
+      <ul>
+        {mismatches.map((entry) => (
+          <li key={entry.type}>
+            <details>
+              <summary>
+                <span className="ws-debug-type">{entry.type}</span>
+                <span className="ws-debug-time">last {formatTime(entry.lastSeen)}</span>
+                <span className="ws-debug-size">×{entry.count}</span>
+              </summary>
+              <ul className="ws-debug-issues">
+                {entry.lastIssues.map((issue) => (
+                  <li key={`${issue.path} ${issue.message}`}>
+                    <code>{issue.path}</code> {issue.message}
+                  </li>
+                ))}
+              </ul>
+              <pre>{JSON.stringify(entry.lastMessage, null, 2)}</pre>
+            </details>
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
+
+/** Recent WebSocket frames, newest first. Opened by double-clicking the footer connection status. */
+export function WsDebugPanel({ onClose }: WsDebugPanelProps) {
+  const frames = useSyncExternalStore(
+    (listener) => wsFrameLog.subscribe(listener),
+    () => wsFrameLog.snapshot()
+  );
+  const mismatches = useSyncExternalStore(
+    (listener) => wsSchemaMismatches.subscribe(listener),
+    () => wsSchemaMismatches.snapshot()
+  );
+  const panelRef = useFocusTrap<HTMLElement>(true, onClose);
+
+  const handleCopy = async () => {
+    try {
+      await copyToClipboard(framesToJson(frames));
+      toast.success(`Copied ${frames.length} frames`);
+    } catch {
+      toast.error("Could not copy frames");
+    }
+  };
+
+  return (
+    <aside
+      ref={panelRef}
+      className="ws-debug-panel"
+      role="dialog"
+      aria-label="WebSocket frames"
+      tabIndex={-1}
+      data-testid="ws-debug-panel"
+    >
+      <header className="ws-debug-header">
+        <h3>WebSocket frames ({frames.length})</h3>
+        <button type="button" onClick={handleCopy} disabled={frames.length === 0}>
+          Copy JSON
+        </button>
+        <button type="button" onClick={() => wsFrameLog.clear()} disabled={frames.length === 0}>
+          Clear
+        </button>
+        <button type="button" className="close-btn" onClick={onClose} aria-label="Close WebSocket frames">
+          ×
+        </button>
+      </header>
+      {mismatches.length > 0 && <SchemaMismatches mismatches={mismatches} />}
+      {frames.length === 0 ? (
+        <p className="ws-debug-empty">No frames recorded yet.</p>
+      ) : (
+        <ol className="ws-debug-list" reversed>
+          {[...frames].reverse().map((frame) => (
+            <li key={frame.seq} className={`ws-debug-frame ws-debug-${frame.direction}`}>
+              <details>
+                <summary>
+                  <span className="ws-debug-time">{formatTime(frame.ts)}</span>
+                  <span className="ws-debug-direction" aria-label={frame.direction === "in" ? "Received" : "Sent"}>
+                    {frame.direction === "in" ? "↓" : "↑"}
+                  </span>
+                  <span className="ws-debug-type">{frame.type}</span>
+                  {frame.topic && <span className="ws-debug-topic">{frame.topic}</span>}
+                  <span className="ws-debug-size">{frame.size} B</span>
+                </summary>
+                <pre>{JSON.stringify(frame.payload, null, 2)}</pre>
+              </details>
+            </li>
+          ))}
+        </ol>
+      )}
+    </aside>
+  );
+}
+
+export default WsDebugPanel;
@@ -1,5 +1,6 @@
 import { useCallback, useEffect, useMemo, useState, type FormEvent } from "react";
 import clsx from "clsx";
+import { useQuery } from "@tanstack/react-query";
 import {
   useAddMcpServer,
   useAgentDetails,
@@ -18,9 +19,25 @@ import {
 } from "../../hooks/useAgentConnectors";
 import { useAccountConnectors } from "../../hooks/useAccountConnectors";
 import { useAuth } from "../../lib/auth";
-import type { McpServerAddRequest, McpServerResponse } from "../../services/api";
+import { useFocusTrap } from "../../lib/useFocusTrap";
+import { fetchApiCredentials, type McpServerAddRequest, type McpServerResponse } from "../../services/api";
 import { TOOL_GROUPS, UTILITY_TOOLS } from "../../constants/toolGroups";
 import { ConnectorConfigModal, type ConfigModalState } from "./ConnectorConfigModal";
+import { PromptTemplateSection } from "./PromptTemplateSection";
+import { DescriptionSection } from "./DescriptionSection";
+import { TagsSection } from "./TagsSection";
+import { InstructionHistorySection } from "./InstructionHistorySection";
+import { ModelSection } from "./ModelSection";
+import { OutputSchemaSection } from "./OutputSchemaSection";
+import { RawJsonSection } from "./RawJsonSection";
+import { KnowledgeSection } from "./KnowledgeSection";
+import { AgentTestsSection } from "./AgentTestsSection";
+import { ModelCompareSection } from "./ModelCompareSection";
+import { ScheduleSection } from "./ScheduleSection";
+import { BudgetSection } from "./BudgetSection";
+import { TriggersSection } from "./TriggersSection";
+import { ToolUsagePanel } from "./ToolUsagePanel";
+import { ToolPlaygroundModal } from "./ToolPlaygroundModal";
 import type { ConnectorStatus } from "../../types/connectors";
 import { Link } from "react-router-dom";
 
@@ -47,6 +64,12 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
   const addMcpServer = useAddMcpServer(isOpen ? agentId : null);
   const removeMcpServer = useRemoveMcpServer(isOpen ? agentId : null);
   const testMcpServer = useTestMcpServer(isOpen ? agentId : null);
+  const { data: apiCredentials = [] } = useQuery({
+    queryKey: ["api-credentials"],
+    queryFn: fetchApiCredentials,
+    enabled: isOpen,
+    staleTime: 60000,
+  });
 
   // Connector Hooks
   const { data: connectors } = useAgentConnectors(isOpen ? agentId : null);
@@ -72,9 +95,17 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
   const [customName, setCustomName] = useState("");
   const [customUrl, setCustomUrl] = useState("");
   const [authToken, setAuthToken] = useState("");
+  // Saved API credential used for auth instead of a token; "" for none
+  const [credentialId, setCredentialId] = useState("");
   const [formAllowedTools, setFormAllowedTools] = useState("");
   const [isTesting, setIsTesting] = useState(false);
 
+  // Tool playground: open flag plus the tool to preselect
+  const [playground, setPlayground] = useState<{ isOpen: boolean; tool: string | null }>({
+    isOpen: false,
+    tool: null,
+  });
+
   // Connector Config Modal State
   const [connectorModal, setConnectorModal] = useState<ConfigModalState>({
     isOpen: false,
@@ -129,21 +160,8 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
     }
   }, [debouncedUpdateAllowedTools.isError, agent?.allowed_tools]);
 
-  // ESC key handler
-  useEffect(() => {
-    if (!isOpen) {
-      return;
-    }
-    const handleKeyDown = (event: KeyboardEvent) => {
-      if (event.key === "Escape") {
-        handleClose();
-      }
-    };
-    window.addEventListener("keydown", handleKeyDown);
-    return () => {
-      window.removeEventListener("keydown", handleKeyDown);
-    };
-  }, [isOpen, handleClose]);
+  // Traps Tab inside the drawer and closes it on Escape
+  const drawerRef = useFocusTrap<HTMLElement>(isOpen, handleClose);
 
   // --- Tool Logic ---
 
@@ -161,6 +179,11 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
     });
   };
 
+  const replaceAllowedTools = (next: string[]) => {
+    setSelectedTools(new Set(next));
+    debouncedUpdateAllowedTools.mutate(next);
+  };
+
   const handleAddCustomTool = () => {
     const trimmed = customTool.trim();
     if (!trimmed) {
@@ -270,25 +293,29 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
     setCustomName("");
     setCustomUrl("");
     setAuthToken("");
+    setCredentialId("");
     setFormAllowedTools("");
     setFormMode("preset");
   };
 
+  const buildServerPayload = (): McpServerAddRequest => {
+    const auth = credentialId
+      ? { api_credential_id: Number(credentialId) }
+      : { auth_token: authToken.trim() || undefined };
+    return formMode === "preset"
+      ? { preset: presetName.trim(), ...auth, allowed_tools: parseAllowedTools(formAllowedTools) }
+      : {
+          name: customName.trim(),
+          // Blank uses the credential's base URL
+          url: customUrl.trim() || undefined,
+          ...auth,
+          allowed_tools: parseAllowedTools(formAllowedTools),
+        };
+  };
+
   const handleSubmitServer = (event: FormEvent<HTMLFormElement>) => {
     event.preventDefault();
-    const payload: McpServerAddRequest =
-      formMode === "preset"
-        ? {
-            preset: presetName.trim(),
-            auth_token: authToken.trim() || undefined,
-            allowed_tools: parseAllowedTools(formAllowedTools),
-          }
-        : {
-            name: customName.trim(),
-            url: customUrl.trim(),
-            auth_token: authToken.trim() || undefined,
-            allowed_tools: parseAllowedTools(formAllowedTools),
-          };
+    const payload = buildServerPayload();
 
     addMcpServer.mutate(payload, {
       onSuccess: () => {
@@ -299,19 +326,7 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
 
   const handleTestServer = () => {
     setIsTesting(true);
-    const payload: McpServerAddRequest =
-      formMode === "preset"
-        ? {
-            preset: presetName.trim(),
-            auth_token: authToken.trim() || undefined,
-            allowed_tools: parseAllowedTools(formAllowedTools),
-          }
-        : {
-            name: customName.trim(),
-            url: customUrl.trim(),
-            auth_token: authToken.trim() || undefined,
-            allowed_tools: parseAllowedTools(formAllowedTools),
-          };
+    const payload = buildServerPayload();
 
     testMcpServer.mutate(payload, {
       onSettled: () => setIsTesting(false),
@@ -340,10 +355,18 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
       }}
       role="presentation"
     >
-      <aside className={clsx("agent-settings-drawer", { open: isOpen })}>
+      <aside
+        ref={drawerRef}
+        className={clsx("agent-settings-drawer", { open: isOpen })}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="agent-settings-title"
+        aria-hidden={!isOpen}
+        tabIndex={-1}
+      >
         <header className="agent-settings-header">
           <div>
-            <h2>Agent Config</h2>
+            <h2 id="agent-settings-title">Agent Config</h2>
             <p>{agent?.name}</p>
           </div>
           <button type="button" className="close-btn" onClick={handleClose} aria-label="Close settings">
@@ -351,6 +374,19 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
           </button>
         </header>
 
+        {agent && <DescriptionSection agent={agent} canEdit={isOwner} />}
+        {agent && <TagsSection agent={agent} canEdit={isOwner} />}
+        {agent && <PromptTemplateSection agent={agent} canEdit={isOwner} />}
+        {agent && <InstructionHistorySection agent={agent} canEdit={isOwner} />}
+        {agent && <ModelSection agent={agent} canEdit={isOwner} />}
+        {agent && <OutputSchemaSection agent={agent} canEdit={isOwner} />}
+        {agent && isOwner && <KnowledgeSection agent={agent} canEdit={isOwner} />}
+        {agent && isOwner && <AgentTestsSection agent={agent} canEdit={isOwner} />}
+        {agent && <ModelCompareSection agent={agent} />}
+        {agent && <ScheduleSection agent={agent} canEdit={isOwner} />}
+        {agent && <BudgetSection agent={agent} canEdit={isOwner} />}
+        {agent && <TriggersSection agent={agent} canEdit={isOwner} />}
+
         <section className="agent-settings-section">
           <h3>Container Execution</h3>
           <p className="section-description">
@@ -406,11 +442,21 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
                 </Link>
               </p>
             </div>
-            {debouncedUpdateAllowedTools.isPending && (
-              <span className="saving-indicator" title="Saving changes…">
-                ●
-              </span>
-            )}
+            <div className="section-header-actions">
+              {debouncedUpdateAllowedTools.isPending && (
+                <span className="saving-indicator" title="Saving changes…">
+                  ●
+                </span>
+              )}
+              <button
+                type="button"
+                className="btn-secondary"
+                onClick={() => setPlayground({ isOpen: true, tool: null })}
+                data-testid="open-tool-playground"
+              >
+                Try a tool
+              </button>
+            </div>
           </div>
 
           {/* High-Level Integrations */}
@@ -558,6 +604,15 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
               </div>
           </details>
 
+          <ToolUsagePanel
+            agentId={agentId}
+            toolOptions={toolOptions}
+            allowedTools={Array.from(selectedTools)}
+            canEdit={isOwner}
+            onChange={replaceAllowedTools}
+            onTry={(tool) => setPlayground({ isOpen: true, tool })}
+          />
+
         </section>
 
         <section className="agent-settings-section">
@@ -565,7 +620,10 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
             <div>
               <h3>MCP Servers</h3>
               <p className="section-description">
-                Connect Model Context Protocol servers to expose additional tools to this agent.
+                Connect Model Context Protocol servers to expose additional tools to this agent.{" "}
+                <Link to="/settings/mcp" onClick={onClose}>
+                  Health of all servers
+                </Link>
               </p>
             </div>
             <button
@@ -666,22 +724,38 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
                       type="url"
                       value={customUrl}
                       onChange={(event) => setCustomUrl(event.target.value)}
-                      placeholder="https://example.com/mcp"
-                      required
+                      placeholder={credentialId ? "The API credential's base URL" : "https://example.com/mcp"}
+                      required={!credentialId}
                     />
                   </label>
                 </>
               )}
 
-              <label className="form-field">
-                Auth token
-                <input
-                  type="password"
-                  value={authToken}
-                  onChange={(event) => setAuthToken(event.target.value)}
-                  placeholder="Optional"
-                />
-              </label>
+              {isOwner && apiCredentials.length > 0 && (
+                <label className="form-field">
+                  API credential
+                  <select value={credentialId} onChange={(event) => setCredentialId(event.target.value)}>
+                    <option value="">None – use an auth token</option>
+                    {apiCredentials.map((credential) => (
+                      <option key={credential.id} value={String(credential.id)}>
+                        {credential.name}
+                      </option>
+                    ))}
+                  </select>
+                </label>
+              )}
+
+              {!credentialId && (
+                <label className="form-field">
+                  Auth token
+                  <input
+                    type="password"
+                    value={authToken}
+                    onChange={(event) => setAuthToken(event.target.value)}
+                    placeholder="Optional"
+                  />
+                </label>
+              )}
 
               <label className="form-field">
                 Allowed tools (comma separated)
@@ -723,6 +797,8 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
           )}
         </section>
 
+        {agent && <RawJsonSection agent={agent} canEdit={isOwner} />}
+
         <footer className="agent-settings-footer">
           <button type="button" className="btn-primary" onClick={handleClose}>
             Close
@@ -748,6 +824,13 @@ export function AgentSettingsDrawer({ agentId, isOpen, onClose }: AgentSettingsD
         isSaving={configureConnector.isPending}
         isTesting={testBeforeSave.isPending}
       />
+
+      <ToolPlaygroundModal
+        agentId={agentId}
+        isOpen={playground.isOpen}
+        initialTool={playground.tool}
+        onClose={() => setPlayground((prev) => ({ ...prev, isOpen: false }))}
+      />
     </div>
   );
 }
@@ -0,0 +1,379 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import {
+  createAgentTest,
+  deleteAgentTest,
+  fetchAgentTests,
+  runAgentTest,
+  runAllAgentTests,
+  updateAgentTest,
+  type Agent,
+  type AgentTestAssertion,
+  type AgentTestAssertionResult,
+  type AgentTestAssertionType,
+  type AgentTestCase,
+  type AgentTestCasePayload,
+} from "../../services/api";
+import {
+  ASSERTION_TYPES,
+  assertionError,
+  assertionLabel,
+  emptyAssertion,
+  summarizeTestRun,
+} from "../../lib/agentTests";
+import { diffWords } from "../../lib/wordDiff";
+import Timestamp from "../Timestamp";
+import { WordDiff } from "./WordDiff";
+
+type AgentTestsSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+type Draft = AgentTestCasePayload & { id: number | null };
+
+const NEW_DRAFT: Draft = { id: null, name: "", input: "", assertions: [emptyAssertion()] };
+
+type CaseStatus = "running" | "passed" | "failed" | "idle";
+
+const STATUS_LABELS: Record<CaseStatus, string> = { running: "Running", passed: "Pass", failed: "Fail", idle: "—" };
+
+function caseStatus(testCase: AgentTestCase, running: boolean): CaseStatus {
+  if (running) return "running";
+  if (!testCase.last_result) return "idle";
+  return testCase.last_result.passed ? "passed" : "failed";
+}
+
+function AssertionResultRow({ result }: { result: AgentTestAssertionResult }) {
+  const target = result.type === "json_path" ? `${result.path ?? "$"} ${result.value ? `= ${result.value}` : ""}` : "";
+  return (
+    <li className={result.passed ? "agent-test-assertion passed" : "agent-test-assertion failed"}>
+      <span className="agent-test-assertion-status">{result.passed ? "✓" : "✗"}</span>
+      <span className="agent-test-assertion-label">
+        {assertionLabel(result.type)} <code>{target || result.value}</code>
+      </span>
+      <span className="agent-test-assertion-message">{result.message}</span>
+      {!result.passed && result.type === "equals" && result.actual != null && (
+        <WordDiff segments={diffWords(result.value, result.actual)} />
+      )}
+      {!result.passed && result.type !== "equals" && result.actual != null && (
+        <div className="agent-test-actual">
+          Got: <code>{result.actual}</code>
+        </div>
+      )}
+    </li>
+  );
+}
+
+function TestCaseEditor({
+  draft,
+  onChange,
+  onSave,
+  onCancel,
+  saving,
+}: {
+  draft: Draft;
+  onChange: (draft: Draft) => void;
+  onSave: () => void;
+  onCancel: () => void;
+  saving: boolean;
+}) {
+  const errors = draft.assertions.map(assertionError);
+  const invalid = !draft.name.trim() || !draft.input.trim() || draft.assertions.length === 0 || errors.some(Boolean);
+
+  const setAssertion = (index: number, patch: Partial<AgentTestAssertion>) => {
+    onChange({
+      ...draft,
+      assertions: draft.assertions.map((assertion, i) => (i === index ? { ...assertion, ...patch } : assertion)),
+    });
+  };
+
+  return (
+    <div className="agent-test-editor" data-testid="agent-test-editor">
+      <label className="prompt-template-field">
+        <span>Name</span>
+        <input type="text" value={draft.name} onChange={(event) => onChange({ ...draft, name: event.target.value })} />
+      </label>
+      <label className="prompt-template-field">
+        <span>Input message</span>
+        <textarea
+          value={draft.input}
+          rows={3}
+          onChange={(event) => onChange({ ...draft, input: event.target.value })}
+        />
+      </label>
+
+      <div className="prompt-template-preview-label">Assertions</div>
+      {draft.assertions.map((assertion, index) => (
+        <div key={index} className="agent-test-assertion-row">
+          <select
+            value={assertion.type}
+            aria-label="Assertion type"
+            onChange={(event) => {
+              const type = event.target.value as AgentTestAssertionType;
+              setAssertion(index, { type, path: type === "json_path" ? assertion.path || "$." : null });
+            }}
+          >
+            {ASSERTION_TYPES.map((entry) => (
+              <option key={entry.type} value={entry.type}>
+                {entry.label}
+              </option>
+            ))}
+          </select>
+          {assertion.type === "json_path" && (
+            <input
+              type="text"
+              className="agent-test-path"
+              value={assertion.path ?? ""}
+              placeholder="$.items[0].id"
+              aria-label="JSON path"
+              onChange={(event) => setAssertion(index, { path: event.target.value })}
+            />
+          )}
+          <input
+            type="text"
+            value={assertion.value}
+            placeholder={ASSERTION_TYPES.find((entry) => entry.type === assertion.type)?.placeholder}
+            aria-label="Expected value"
+            aria-invalid={errors[index] ? true : undefined}
+            onChange={(event) => setAssertion(index, { value: event.target.value })}
+          />
+          <button
+            type="button"
+            className="btn-secondary"
+            aria-label={`Remove assertion ${index + 1}`}
+            disabled={draft.assertions.length === 1}
+            onClick={() => onChange({ ...draft, assertions: draft.assertions.filter((_, i) => i !== index) })}
+          >
+            ×
+          </button>
+          {errors[index] && <div className="prompt-variable-error">{errors[index]}</div>}
+        </div>
+      ))}
+      <div className="agent-test-editor-actions">
+        <button
+          type="button"
+          className="btn-secondary"
+          onClick={() => onChange({ ...draft, assertions: [...draft.assertions, emptyAssertion()] })}
+        >
+          Add assertion
+        </button>
+        <span className="spacer" />
+        <button type="button" className="btn-secondary" onClick={onCancel}>
+          Cancel
+        </button>
+        <button type="button" className="btn-primary" onClick={onSave} disabled={invalid || saving}>
+          {saving ? "Saving…" : "Save test"}
+        </button>
+      </div>
+    </div>
+  );
+}
+
+/**
+ * Saved prompt checks: each case sends one message to the agent and asserts
+ * on the reply. Runs use the real agent, tools included.
+ */
+export function AgentTestsSection({ agent, canEdit }: AgentTestsSectionProps) {
+  const queryClient = useQueryClient();
+  const queryKey = ["agent", agent.id, "tests"];
+  const [draft, setDraft] = useState<Draft | null>(null);
+  const [expandedId, setExpandedId] = useState<number | null>(null);
+  const [runningIds, setRunningIds] = useState<ReadonlySet<number>>(new Set());
+
+  const testsQuery = useQuery({
+    queryKey,
+    queryFn: () => fetchAgentTests(agent.id),
+  });
+  const cases = testsQuery.data ?? [];
+  const summary = summarizeTestRun(cases);
+
+  const replaceCase = (updated: AgentTestCase) => {
+    queryClient.setQueryData<AgentTestCase[]>(queryKey, (current) =>
+      current?.some((item) => item.id === updated.id)
+        ? current.map((item) => (item.id === updated.id ? updated : item))
+        : [...(current ?? []), updated]
+    );
+  };
+
+  const saveMutation = useMutation({
+    mutationFn: ({ id, ...payload }: Draft) =>
+      id == null ? createAgentTest(agent.id, payload) : updateAgentTest(agent.id, id, payload),
+    onSuccess: (saved) => {
+      replaceCase(saved);
+      setDraft(null);
+      toast.success("Test saved");
+    },
+    onError: (error: Error) => toast.error(`Failed to save test: ${error.message}`, { duration: 6000 }),
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: (caseId: number) => deleteAgentTest(agent.id, caseId),
+    onSuccess: (_data, caseId) => {
+      queryClient.setQueryData<AgentTestCase[]>(queryKey, (current) => current?.filter((item) => item.id !== caseId));
+    },
+    onError: (error: Error) => toast.error(`Failed to delete test: ${error.message}`),
+  });
+
+  const runMutation = useMutation({
+    // null runs every case
+    mutationFn: async (caseIds: number[] | null) =>
+      caseIds?.length === 1 ? [await runAgentTest(agent.id, caseIds[0])] : runAllAgentTests(agent.id),
+    onMutate: (caseIds) => setRunningIds(new Set(caseIds ?? cases.map((item) => item.id))),
+    onSuccess: (results) => {
+      results.forEach(replaceCase);
+      const failed = results.filter((item) => !item.last_result?.passed).length;
+      if (results.length > 1) {
+        if (failed) toast.error(`${failed} of ${results.length} tests failed`);
+        else toast.success(`All ${results.length} tests passed`);
+      }
+      if (failed === 1) setExpandedId(results.find((item) => !item.last_result?.passed)?.id ?? null);
+    },
+    onError: (error: Error) => toast.error(`Test run failed: ${error.message}`, { duration: 6000 }),
+    onSettled: () => setRunningIds(new Set()),
+  });
+
+  const handleDelete = (testCase: AgentTestCase) => {
+    if (window.confirm(`Delete the test "${testCase.name}"?`)) deleteMutation.mutate(testCase.id);
+  };
+
+  return (
+    <section className="agent-settings-section agent-tests-section">
+      <h3>Tests</h3>
+      <p className="section-description">
+        Send a message to the agent and check the reply. Tests run the real agent, so tools it calls take effect.
+      </p>
+
+      <div className="agent-tests-toolbar">
+        <span className="agent-tests-summary" data-testid="agent-tests-summary">
+          {cases.length === 0
+            ? "No tests yet"
+            : `${summary.passed} passed · ${summary.failed} failed · ${summary.notRun} not run`}
+        </span>
+        {canEdit && (
+          <button type="button" className="btn-secondary" onClick={() => setDraft(NEW_DRAFT)} disabled={draft != null}>
+            New test
+          </button>
+        )}
+        <button
+          type="button"
+          className="btn-primary"
+          onClick={() => runMutation.mutate(null)}
+          disabled={cases.length === 0 || runMutation.isPending}
+        >
+          {runMutation.isPending && runningIds.size > 1 ? "Running…" : "Run all"}
+        </button>
+      </div>
+
+      {draft && draft.id == null && (
+        <TestCaseEditor
+          draft={draft}
+          onChange={setDraft}
+          onSave={() => saveMutation.mutate(draft)}
+          onCancel={() => setDraft(null)}
+          saving={saveMutation.isPending}
+        />
+      )}
+
+      {testsQuery.isError && <p className="muted">Unable to load tests.</p>}
+      <ul className="agent-tests-list" data-testid="agent-tests-list">
+        {cases.map((testCase) => {
+          const result = testCase.last_result;
+          const status = caseStatus(testCase, runningIds.has(testCase.id));
+          const expanded = expandedId === testCase.id;
+
+          if (draft?.id === testCase.id) {
+            return (
+              <li key={testCase.id} className="agent-test-case">
+                <TestCaseEditor
+                  draft={draft}
+                  onChange={setDraft}
+                  onSave={() => saveMutation.mutate(draft)}
+                  onCancel={() => setDraft(null)}
+                  saving={saveMutation.isPending}
+                />
+              </li>
+            );
+          }
+
+          return (
+            <li key={testCase.id} className={`agent-test-case ${status}`} data-testid={`agent-test-${testCase.id}`}>
+              <div className="agent-test-header">
+                <span className={`agent-test-status ${status}`}>
+                  {STATUS_LABELS[status]}
+                </span>
+                <button
+                  type="button"
+                  className="agent-test-name"
+                  aria-expanded={expanded}
+                  onClick={() => setExpandedId(expanded ? null : testCase.id)}
+                >
+                  {testCase.name}
+                </button>
+                <button
+                  type="button"
+                  className="btn-secondary"
+                  onClick={() => runMutation.mutate([testCase.id])}
+                  disabled={runMutation.isPending}
+                >
+                  Run
+                </button>
+                {canEdit && (
+                  <>
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      onClick={() =>
+                        setDraft({
+                          id: testCase.id,
+                          name: testCase.name,
+                          input: testCase.input,
+                          assertions: testCase.assertions,
+                        })
+                      }
+                      disabled={draft != null}
+                    >
+                      Edit
+                    </button>
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      onClick={() => handleDelete(testCase)}
+                      aria-label={`Delete test ${testCase.name}`}
+                    >
+                      ×
+                    </button>
+                  </>
+                )}
+              </div>
+
+              {expanded && (
+                <div className="agent-test-details">
+                  <div className="prompt-template-preview-label">Input</div>
+                  <pre>{testCase.input}</pre>
+                  {!result && <p className="muted">Not run since it was last edited.</p>}
+                  {result && (
+                    <>
+                      <div className="prompt-template-preview-label">
+                        Last run <Timestamp value={result.ran_at} /> · {result.duration_ms} ms
+                      </div>
+                      {result.error && <div className="prompt-variable-error">Run failed: {result.error}</div>}
+                      <ul className="agent-test-assertions">
+                        {result.assertions.map((item, index) => (
+                          <AssertionResultRow key={index} result={item} />
+                        ))}
+                      </ul>
+                      <div className="prompt-template-preview-label">Reply</div>
+                      <pre>{result.output || "(empty)"}</pre>
+                    </>
+                  )}
+                </div>
+              )}
+            </li>
+          );
+        })}
+      </ul>
+    </section>
+  );
+}
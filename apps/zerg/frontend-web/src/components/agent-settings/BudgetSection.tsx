@@ -0,0 +1,123 @@
+import { useEffect, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { updateAgent, type Agent, type AgentUpdatePayload } from "../../services/api";
+import {
+  BUDGET_PERIOD_LABELS,
+  budgetFromConfig,
+  withBudget,
+  type BudgetPeriod,
+} from "../../lib/agentBudget";
+
+type BudgetSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+type BudgetMode = "none" | BudgetPeriod;
+
+function initialState(agent: Agent) {
+  const budget = budgetFromConfig(agent.config as Record<string, unknown> | null);
+  return {
+    mode: (budget?.period ?? "none") as BudgetMode,
+    limit: budget ? String(budget.limit_usd) : "",
+  };
+}
+
+/**
+ * Daily or monthly USD cap for this agent. Runs are refused once the cap is
+ * reached; spend resets at midnight UTC or on the first of the month.
+ */
+export function BudgetSection({ agent, canEdit }: BudgetSectionProps) {
+  const queryClient = useQueryClient();
+  const [state, setState] = useState(() => initialState(agent));
+
+  useEffect(() => {
+    setState(initialState(agent));
+  }, [agent]);
+
+  const { mode, limit } = state;
+  const limitUsd = Number(limit);
+  const limitValid = mode === "none" || (limit.trim() !== "" && Number.isFinite(limitUsd) && limitUsd > 0);
+  const current = budgetFromConfig(agent.config as Record<string, unknown> | null);
+  const isDirty =
+    mode === "none" ? current !== null : current === null || current.period !== mode || current.limit_usd !== limitUsd;
+
+  const saveMutation = useMutation({
+    // The backend replaces config wholesale, so merge into what's there
+    mutationFn: () =>
+      updateAgent(agent.id, {
+        // Generated as Record<string, never>; the column is free-form JSON
+        config: withBudget(
+          agent.config as Record<string, unknown> | null,
+          mode === "none" ? null : { period: mode, limit_usd: limitUsd }
+        ) as AgentUpdatePayload["config"],
+      }),
+    onSuccess: () => {
+      toast.success(mode === "none" ? "Budget removed" : "Budget saved");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save budget: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const canSave = canEdit && isDirty && limitValid && !saveMutation.isPending;
+
+  return (
+    <section className="agent-settings-section budget-section">
+      <h3>Budget</h3>
+      <p className="section-description">
+        Cap what this agent may spend on model calls. Runs are blocked once the cap is reached (UTC day or month).
+      </p>
+
+      <div className="schedule-builder">
+        <label>
+          <span>Limit</span>
+          <select
+            value={mode}
+            onChange={(event) => setState((prev) => ({ ...prev, mode: event.target.value as BudgetMode }))}
+            disabled={!canEdit}
+            aria-label="Budget period"
+          >
+            <option value="none">No limit</option>
+            {(Object.keys(BUDGET_PERIOD_LABELS) as BudgetPeriod[]).map((period) => (
+              <option key={period} value={period}>
+                {BUDGET_PERIOD_LABELS[period]}
+              </option>
+            ))}
+          </select>
+        </label>
+
+        {mode !== "none" && (
+          <label>
+            <span>Cap (USD)</span>
+            <input
+              type="number"
+              min={0}
+              step="0.01"
+              value={limit}
+              onChange={(event) => setState((prev) => ({ ...prev, limit: event.target.value }))}
+              aria-invalid={!limitValid || undefined}
+              disabled={!canEdit}
+              aria-label="Budget cap in USD"
+            />
+          </label>
+        )}
+      </div>
+
+      {!limitValid && (
+        <div className="schedule-error" role="alert">
+          Enter a cap greater than $0.
+        </div>
+      )}
+
+      {canEdit && (
+        <button type="button" className="btn-primary" onClick={() => saveMutation.mutate()} disabled={!canSave}>
+          {saveMutation.isPending ? "Saving…" : "Save budget"}
+        </button>
+      )}
+    </section>
+  );
+}
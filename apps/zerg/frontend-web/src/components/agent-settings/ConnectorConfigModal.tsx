@@ -1,5 +1,6 @@
 import type { FormEvent } from "react";
 import type { ConnectorStatus, CredentialField } from "../../types/connectors";
+import { useFocusTrap } from "../../lib/useFocusTrap";
 
 export type ConfigModalState = {
   isOpen: boolean;
@@ -29,13 +30,24 @@ export function ConnectorConfigModal({
   isSaving,
   isTesting,
 }: ConnectorConfigModalProps) {
-  if (!modal.isOpen || !modal.connector) return null;
+  const isOpen = modal.isOpen && modal.connector !== null;
+  const dialogRef = useFocusTrap(isOpen, onClose);
+
+  if (!isOpen || !modal.connector) return null;
 
   return (
     <div className="connector-modal-backdrop" onClick={onClose} role="presentation">
-      <div className="connector-modal" onClick={(e) => e.stopPropagation()}>
+      <div
+        ref={dialogRef}
+        className="connector-modal"
+        onClick={(e) => e.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="connector-config-title"
+        tabIndex={-1}
+      >
         <header className="connector-modal-header">
-          <h3>Configure {modal.connector.name}</h3>
+          <h3 id="connector-config-title">Configure {modal.connector.name}</h3>
           <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
             ×
           </button>
@@ -0,0 +1,109 @@
+import { useEffect, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { updateAgent, type Agent } from "../../services/api";
+import { MarkdownMessage } from "../chat/MarkdownMessage";
+
+// Mirrors AGENT_DESCRIPTION_MAX_CHARS on the backend
+export const DESCRIPTION_MAX_CHARS = 20000;
+
+type DescriptionSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+/**
+ * Markdown README for the people running the agent: what it does, the inputs
+ * it expects and known caveats. Shown on the dashboard and in the chat
+ * header; never sent to the model.
+ */
+export function DescriptionSection({ agent, canEdit }: DescriptionSectionProps) {
+  const queryClient = useQueryClient();
+  const [description, setDescription] = useState(agent.description ?? "");
+  const [previewing, setPreviewing] = useState(false);
+
+  // Rehydrate when the server copy changes (initial load, save, another tab)
+  useEffect(() => {
+    setDescription(agent.description ?? "");
+  }, [agent.id, agent.description]);
+
+  const dirty = description.trim() !== (agent.description ?? "").trim();
+  const tooLong = description.length > DESCRIPTION_MAX_CHARS;
+
+  const saveMutation = useMutation({
+    mutationFn: () => updateAgent(agent.id, { description }),
+    onSuccess: () => {
+      toast.success("README saved");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save README: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <section className="agent-settings-section description-section">
+      <h3>README</h3>
+      <p className="section-description">
+        Markdown shown next to the run button on the dashboard and in chat. Document what the agent does, the inputs it
+        expects and any known caveats. It is not sent to the model.
+      </p>
+
+      <div className="description-tabs" role="tablist" aria-label="README editor">
+        <button
+          type="button"
+          role="tab"
+          aria-selected={!previewing}
+          className={previewing ? "btn-secondary" : "btn-primary"}
+          onClick={() => setPreviewing(false)}
+        >
+          Write
+        </button>
+        <button
+          type="button"
+          role="tab"
+          aria-selected={previewing}
+          className={previewing ? "btn-primary" : "btn-secondary"}
+          onClick={() => setPreviewing(true)}
+        >
+          Preview
+        </button>
+      </div>
+
+      {previewing ? (
+        <div className="description-preview" data-testid="description-preview">
+          {description.trim() ? <MarkdownMessage content={description} /> : <p className="muted">Nothing to preview</p>}
+        </div>
+      ) : (
+        <label className="prompt-template-field">
+          <span>Description</span>
+          <textarea
+            value={description}
+            onChange={(event) => setDescription(event.target.value)}
+            rows={8}
+            placeholder={"## What it does\n\n## Inputs\n\n## Known caveats"}
+            aria-invalid={tooLong ? true : undefined}
+            disabled={!canEdit}
+          />
+        </label>
+      )}
+      {tooLong && (
+        <div className="prompt-variable-error" role="alert">
+          {description.length.toLocaleString()} / {DESCRIPTION_MAX_CHARS.toLocaleString()} characters
+        </div>
+      )}
+
+      {canEdit && (
+        <button
+          type="button"
+          className="btn-primary"
+          onClick={() => saveMutation.mutate()}
+          disabled={!dirty || tooLong || saveMutation.isPending}
+        >
+          {saveMutation.isPending ? "Saving…" : "Save README"}
+        </button>
+      )}
+    </section>
+  );
+}
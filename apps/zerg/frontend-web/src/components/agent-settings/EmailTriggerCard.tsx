@@ -0,0 +1,204 @@
+import { useEffect, useMemo, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import {
+  deleteTrigger,
+  previewEmailFilter,
+  updateTriggerFilters,
+  type EmailFilterPreview,
+  type Trigger,
+} from "../../services/api";
+import {
+  describeEmailFilters,
+  filtersToForm,
+  formToFilters,
+  type EmailFilterForm,
+  type EmailTriggerFilters,
+} from "../../lib/emailFilters";
+import { TriggerDeliveries, TriggerDiagnostics } from "./TriggerFields";
+
+type EmailTriggerCardProps = {
+  trigger: Trigger;
+  canEdit: boolean;
+};
+
+function storedFilters(trigger: Trigger): EmailTriggerFilters | null {
+  const filters = trigger.config?.filters;
+  return filters && typeof filters === "object" ? (filters as EmailTriggerFilters) : null;
+}
+
+/**
+ * Filter builder for a Gmail trigger: compose the rules emails must match,
+ * preview which recent messages they pick up, then save them on the trigger.
+ */
+export function EmailTriggerCard({ trigger, canEdit }: EmailTriggerCardProps) {
+  const queryClient = useQueryClient();
+  const saved = storedFilters(trigger);
+  const [form, setForm] = useState<EmailFilterForm>(() => filtersToForm(saved));
+  const [preview, setPreview] = useState<EmailFilterPreview | null>(null);
+  const connectorId = Number(trigger.config?.connector_id);
+
+  useEffect(() => {
+    setForm(filtersToForm(storedFilters(trigger)));
+    setPreview(null);
+  }, [trigger]);
+
+  const filters = useMemo(() => formToFilters(form, saved), [form, saved]);
+  const isDirty = JSON.stringify(filters) !== JSON.stringify(formToFilters(filtersToForm(saved), saved));
+
+  const update = (patch: Partial<EmailFilterForm>) => {
+    setForm((current) => ({ ...current, ...patch }));
+    setPreview(null);
+  };
+
+  const saveMutation = useMutation({
+    mutationFn: () => updateTriggerFilters(trigger.id, Object.keys(filters).length ? filters : null),
+    onSuccess: () => {
+      toast.success("Email filter saved");
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save filter: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const previewMutation = useMutation({
+    mutationFn: () => previewEmailFilter(connectorId, filters),
+    onSuccess: (result) => setPreview(result),
+    onError: (error: Error) => {
+      toast.error(`Preview failed: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: () => deleteTrigger(trigger.id),
+    onSuccess: () => {
+      toast.success("Trigger deleted");
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to delete trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDelete = () => {
+    if (window.confirm("Delete this Gmail trigger? New emails will no longer run the agent.")) {
+      deleteMutation.mutate();
+    }
+  };
+
+  return (
+    <div className="trigger-card" data-testid={`email-trigger-${trigger.id}`}>
+      <p className="email-filter-summary">{describeEmailFilters(filters)}</p>
+      {trigger.config?.disabled === true && (
+        <p className="email-trigger-disabled" role="status">
+          Disabled because Gmail was disconnected. <a href="/profile">Reconnect Gmail</a> to turn it back on.
+        </p>
+      )}
+
+      <div className="email-filter-builder">
+        <label>
+          <span>From contains</span>
+          <input
+            type="text"
+            value={form.from}
+            onChange={(event) => update({ from: event.target.value })}
+            placeholder="alice@example.com, @vendor.com"
+            disabled={!canEdit}
+          />
+        </label>
+        <label>
+          <span>Subject contains</span>
+          <input
+            type="text"
+            value={form.subject}
+            onChange={(event) => update({ subject: event.target.value })}
+            placeholder="invoice, receipt"
+            disabled={!canEdit}
+          />
+        </label>
+        <label>
+          <span>Label</span>
+          <input
+            type="text"
+            value={form.label}
+            onChange={(event) => update({ label: event.target.value })}
+            placeholder="INBOX"
+            disabled={!canEdit}
+          />
+        </label>
+        <label className="email-filter-checkbox">
+          <input
+            type="checkbox"
+            checked={form.hasAttachment}
+            onChange={(event) => update({ hasAttachment: event.target.checked })}
+            disabled={!canEdit}
+          />
+          <span>Has attachment</span>
+        </label>
+      </div>
+      <p className="muted">Separate several values with commas; any of them may match.</p>
+
+      {canEdit && (
+        <div className="email-filter-actions">
+          <button
+            type="button"
+            className="btn-secondary"
+            onClick={() => previewMutation.mutate()}
+            disabled={previewMutation.isPending || !Number.isFinite(connectorId)}
+            data-testid={`preview-email-filter-${trigger.id}`}
+          >
+            {previewMutation.isPending ? "Searching…" : "Preview matching emails"}
+          </button>
+          <button
+            type="button"
+            className="btn-primary"
+            onClick={() => saveMutation.mutate()}
+            disabled={!isDirty || saveMutation.isPending}
+          >
+            {saveMutation.isPending ? "Saving…" : "Save filter"}
+          </button>
+          <button type="button" className="btn-tertiary" onClick={handleDelete} disabled={deleteMutation.isPending}>
+            Delete trigger
+          </button>
+        </div>
+      )}
+
+      {preview && (
+        <div className="email-filter-preview" data-testid="email-filter-preview">
+          <h4>
+            {preview.messages.length === 0
+              ? "No recent emails match"
+              : `${preview.messages.length} of the latest ${preview.scanned} candidate emails match`}
+          </h4>
+          {preview.query && (
+            <p className="muted">
+              Gmail search: <code>{preview.query}</code>
+            </p>
+          )}
+          <ul>
+            {preview.messages.map((message) => (
+              <li key={message.id}>
+                <strong>{message.subject || "(no subject)"}</strong>
+                <span className="muted">
+                  {" "}
+                  — {message.sender ?? "unknown sender"}
+                  {message.date ? ` · ${message.date}` : ""}
+                  {message.has_attachment ? " · 📎" : ""}
+                </span>
+                {message.snippet && <div className="email-filter-snippet">{message.snippet}</div>}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+
+      {canEdit && (
+        <>
+          <TriggerDeliveries triggerId={trigger.id} agentId={trigger.agent_id} />
+          <TriggerDiagnostics trigger={trigger} />
+        </>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,142 @@
+import { useState, type FormEvent } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { createGitHubTrigger, deleteTrigger, githubWebhookUrl, type Trigger } from "../../services/api";
+import {
+  GITHUB_EVENT_LABELS,
+  describeGitHubTrigger,
+  isGitHubRepo,
+  parseActions,
+  type GitHubEventType,
+} from "../../lib/githubTrigger";
+import { TriggerDeliveries, TriggerDiagnostics, TriggerSecretField, TriggerUrlField } from "./TriggerFields";
+
+/**
+ * A GitHub trigger: what it listens for, the payload URL and secret to paste
+ * into the repository's webhook settings, its recent firings and a
+ * diagnostic for deliveries that did not run the agent.
+ */
+export function GitHubTriggerCard({ trigger, canEdit }: { trigger: Trigger; canEdit: boolean }) {
+  const queryClient = useQueryClient();
+
+  const deleteMutation = useMutation({
+    mutationFn: () => deleteTrigger(trigger.id),
+    onSuccess: () => {
+      toast.success("Trigger deleted");
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to delete trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDelete = () => {
+    if (window.confirm("Delete this GitHub trigger? Remove the webhook from the repository as well.")) {
+      deleteMutation.mutate();
+    }
+  };
+
+  return (
+    <div className="trigger-card github-trigger-card" data-testid={`github-trigger-${trigger.id}`}>
+      <span>{describeGitHubTrigger(trigger.config)}</span>
+      <TriggerUrlField label="Payload URL" url={githubWebhookUrl(trigger.id)} />
+
+      {canEdit && (
+        <>
+          <TriggerSecretField trigger={trigger} label="Webhook secret" />
+
+          <details className="trigger-signing-help">
+            <summary>How to connect the repository</summary>
+            <p>
+              In the repository&apos;s Settings → Webhooks, add a webhook with the payload URL above, content
+              type <code>application/json</code> and the webhook secret, then pick the same events. GitHub sends a
+              ping that shows up below once it arrives.
+            </p>
+          </details>
+
+          <TriggerDeliveries triggerId={trigger.id} agentId={trigger.agent_id} />
+          <TriggerDiagnostics trigger={trigger} />
+
+          <button type="button" className="btn-tertiary" onClick={handleDelete} disabled={deleteMutation.isPending}>
+            Delete trigger
+          </button>
+        </>
+      )}
+    </div>
+  );
+}
+
+/** Pick the repository and events that run the agent. Offered once GitHub is connected. */
+export function GitHubTriggerForm({ agentId }: { agentId: number }) {
+  const queryClient = useQueryClient();
+  const [repo, setRepo] = useState("");
+  const [events, setEvents] = useState<GitHubEventType[]>(["pull_request"]);
+  const [actions, setActions] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  const createMutation = useMutation({
+    mutationFn: () => createGitHubTrigger(agentId, { repo: repo.trim(), events, actions: parseActions(actions) }),
+    onSuccess: () => {
+      toast.success("GitHub trigger created");
+      setRepo("");
+      setActions("");
+      queryClient.invalidateQueries({ queryKey: ["triggers", agentId] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to create trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const toggleEvent = (event: GitHubEventType) => {
+    setEvents((current) => (current.includes(event) ? current.filter((e) => e !== event) : [...current, event]));
+  };
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    if (!isGitHubRepo(repo)) {
+      setError("Enter the repository as owner/name.");
+      return;
+    }
+    if (events.length === 0) {
+      setError("Choose at least one event.");
+      return;
+    }
+    setError(null);
+    createMutation.mutate();
+  };
+
+  return (
+    <form className="github-trigger-form" onSubmit={handleSubmit} data-testid="github-trigger-form">
+      <label>
+        <span>Repository</span>
+        <input type="text" value={repo} onChange={(event) => setRepo(event.target.value)} placeholder="owner/name" />
+      </label>
+      <fieldset>
+        <legend>Run on</legend>
+        {(Object.keys(GITHUB_EVENT_LABELS) as GitHubEventType[]).map((type) => (
+          <label key={type}>
+            <input type="checkbox" checked={events.includes(type)} onChange={() => toggleEvent(type)} />
+            {GITHUB_EVENT_LABELS[type]}
+          </label>
+        ))}
+      </fieldset>
+      <label>
+        <span>Actions</span>
+        <input
+          type="text"
+          value={actions}
+          onChange={(event) => setActions(event.target.value)}
+          placeholder="Any action (e.g. opened, labeled)"
+        />
+      </label>
+      {error && (
+        <div className="schedule-error" role="alert">
+          {error}
+        </div>
+      )}
+      <button type="submit" className="btn-secondary" disabled={createMutation.isPending}>
+        {createMutation.isPending ? "Creating…" : "Add GitHub trigger"}
+      </button>
+    </form>
+  );
+}
@@ -0,0 +1,137 @@
+import { useMemo, useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import {
+  fetchInstructionVersions,
+  restoreInstructionVersion,
+  type Agent,
+  type AgentInstructionVersion,
+} from "../../services/api";
+import { diffStats, diffWords, type DiffSegment } from "../../lib/wordDiff";
+import Timestamp from "../Timestamp";
+import { WordDiff } from "./WordDiff";
+
+type InstructionHistorySectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+function DiffView({ label, segments }: { label: string; segments: DiffSegment[] }) {
+  const { added, removed } = diffStats(segments);
+  return (
+    <div className="instruction-diff">
+      <div className="prompt-template-preview-label">
+        {label}
+        {added + removed === 0 ? " · unchanged" : ` · +${added} −${removed} words`}
+      </div>
+      <WordDiff segments={segments} />
+    </div>
+  );
+}
+
+function versionLabel(version: AgentInstructionVersion): string {
+  const restored = version.restored_from_version != null ? ` (restored v${version.restored_from_version})` : "";
+  return `v${version.version}${restored}`;
+}
+
+/**
+ * Every saved revision of the system/task instructions with a word-level
+ * diff against the revision before it and one-click rollback.
+ */
+export function InstructionHistorySection({ agent, canEdit }: InstructionHistorySectionProps) {
+  const queryClient = useQueryClient();
+  const [selectedId, setSelectedId] = useState<number | null>(null);
+
+  const versionsQuery = useQuery({
+    queryKey: ["agent", agent.id, "instruction-versions"],
+    queryFn: () => fetchInstructionVersions(agent.id),
+  });
+  const versions = useMemo(() => versionsQuery.data ?? [], [versionsQuery.data]);
+  const selectedIndex = versions.findIndex((version) => version.id === selectedId);
+  const selected = selectedIndex >= 0 ? versions[selectedIndex] : null;
+  // Versions are newest first, so the one before is the next entry
+  const previous = selectedIndex >= 0 ? versions[selectedIndex + 1] : undefined;
+
+  const diffs = useMemo(() => {
+    if (!selected) return null;
+    return {
+      system: diffWords(previous?.system_instructions ?? "", selected.system_instructions),
+      task: diffWords(previous?.task_instructions ?? "", selected.task_instructions),
+    };
+  }, [selected, previous]);
+
+  const restoreMutation = useMutation({
+    mutationFn: (version: AgentInstructionVersion) => restoreInstructionVersion(agent.id, version.id),
+    onSuccess: (_agent, version) => {
+      toast.success(`Restored instructions from v${version.version}`);
+      setSelectedId(null);
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to restore instructions: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleRestore = (version: AgentInstructionVersion) => {
+    const confirmed = window.confirm(
+      `Replace the current instructions with v${version.version}? The current text stays in the history.`
+    );
+    if (confirmed) restoreMutation.mutate(version);
+  };
+
+  return (
+    <section className="agent-settings-section instruction-history-section">
+      <h3>History</h3>
+      <p className="section-description">
+        A version is saved each time the system or task instructions change. Select one to see what changed.
+      </p>
+
+      {versionsQuery.isLoading && <p className="muted">Loading history…</p>}
+      {versionsQuery.isError && <p className="muted">Unable to load instruction history.</p>}
+      {!versionsQuery.isLoading && versions.length === 0 && <p className="muted">No versions saved yet.</p>}
+
+      {versions.length > 0 && (
+        <ul className="instruction-history-list" data-testid="instruction-history">
+          {versions.map((version, index) => (
+            <li
+              key={version.id}
+              className={version.id === selectedId ? "instruction-history-item selected" : "instruction-history-item"}
+            >
+              <button
+                type="button"
+                className="instruction-history-select"
+                aria-expanded={version.id === selectedId}
+                onClick={() => setSelectedId((current) => (current === version.id ? null : version.id))}
+              >
+                <span className="instruction-history-version">{versionLabel(version)}</span>
+                {index === 0 && <span className="instruction-history-current">Current</span>}
+                <span className="instruction-history-meta">
+                  <Timestamp value={version.created_at} />
+                  {version.author_email && ` · ${version.author_email}`}
+                </span>
+              </button>
+              {canEdit && index > 0 && (
+                <button
+                  type="button"
+                  className="btn-secondary"
+                  onClick={() => handleRestore(version)}
+                  disabled={restoreMutation.isPending}
+                >
+                  Restore
+                </button>
+              )}
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {selected && diffs && (
+        <div className="prompt-template-preview" data-testid="instruction-diff">
+          <h4>{previous ? `Changes from v${previous.version} to v${selected.version}` : "First version"}</h4>
+          <DiffView label="System" segments={diffs.system} />
+          <DiffView label="Task" segments={diffs.task} />
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,190 @@
+import { useRef, useState, type ChangeEvent, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import clsx from "clsx";
+import {
+  addKnowledgeLink,
+  deleteKnowledgeDocument,
+  fetchKnowledgeDocuments,
+  uploadKnowledgeFile,
+  type Agent,
+  type KnowledgeDocument,
+} from "../../services/api";
+import {
+  KNOWLEDGE_ACCEPT,
+  KNOWLEDGE_STATUS_LABELS,
+  isHttpUrl,
+  isKnowledgeIndexing,
+  knowledgeDocumentMeta,
+  knowledgeFileError,
+} from "../../lib/knowledgeBase";
+
+type KnowledgeSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+type PendingUpload = {
+  localId: number;
+  name: string;
+  progress: number;
+};
+
+const POLL_INTERVAL_MS = 2000;
+
+let nextUploadId = 0;
+
+/**
+ * Documents the agent can draw on. Uploads and links are indexed in the
+ * background; the list is polled while anything is still indexing.
+ */
+export function KnowledgeSection({ agent, canEdit }: KnowledgeSectionProps) {
+  const queryClient = useQueryClient();
+  const fileInputRef = useRef<HTMLInputElement>(null);
+  const [uploads, setUploads] = useState<PendingUpload[]>([]);
+  const [linkUrl, setLinkUrl] = useState("");
+
+  const queryKey = ["agent-knowledge", agent.id];
+  const { data: documents = [], isLoading } = useQuery({
+    queryKey,
+    queryFn: () => fetchKnowledgeDocuments(agent.id),
+    refetchInterval: (query) => (isKnowledgeIndexing(query.state.data) ? POLL_INTERVAL_MS : false),
+  });
+
+  const refresh = () => queryClient.invalidateQueries({ queryKey });
+
+  const uploadOne = async (file: File) => {
+    const localId = nextUploadId++;
+    setUploads((prev) => [...prev, { localId, name: file.name, progress: 0 }]);
+    try {
+      await uploadKnowledgeFile(agent.id, file, {
+        onProgress: (progress) =>
+          setUploads((prev) => prev.map((item) => (item.localId === localId ? { ...item, progress } : item))),
+      });
+    } catch (error) {
+      toast.error(`Failed to upload ${file.name}: ${(error as Error).message}`, { duration: 6000 });
+    } finally {
+      setUploads((prev) => prev.filter((item) => item.localId !== localId));
+      refresh();
+    }
+  };
+
+  const handleFiles = (event: ChangeEvent<HTMLInputElement>) => {
+    const files = Array.from(event.target.files ?? []);
+    event.target.value = "";
+    for (const file of files) {
+      const error = knowledgeFileError(file);
+      if (error) toast.error(error, { duration: 6000 });
+      else void uploadOne(file);
+    }
+  };
+
+  const linkMutation = useMutation({
+    mutationFn: () => addKnowledgeLink(agent.id, linkUrl.trim()),
+    onSuccess: () => {
+      setLinkUrl("");
+      refresh();
+    },
+    onError: (error: Error) => toast.error(`Failed to add link: ${error.message}`, { duration: 6000 }),
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: (doc: KnowledgeDocument) => deleteKnowledgeDocument(agent.id, doc.id),
+    onSuccess: (_, doc) => {
+      toast.success(`Removed ${doc.name}`);
+      refresh();
+    },
+    onError: (error: Error) => toast.error(`Failed to remove document: ${error.message}`),
+  });
+
+  const handleAddLink = (event: FormEvent) => {
+    event.preventDefault();
+    if (isHttpUrl(linkUrl)) linkMutation.mutate();
+  };
+
+  return (
+    <section className="agent-settings-section knowledge-section">
+      <h3>Knowledge</h3>
+      <p className="section-description">
+        Documents the agent can look things up in. Passages relevant to each message are added to its context.
+      </p>
+
+      {canEdit && (
+        <div className="knowledge-toolbar">
+          <button type="button" className="btn-secondary" onClick={() => fileInputRef.current?.click()}>
+            Upload files
+          </button>
+          <input
+            ref={fileInputRef}
+            type="file"
+            accept={KNOWLEDGE_ACCEPT}
+            multiple
+            hidden
+            onChange={handleFiles}
+            aria-label="Upload knowledge documents"
+          />
+          <form className="knowledge-link-form" onSubmit={handleAddLink}>
+            <input
+              type="url"
+              value={linkUrl}
+              onChange={(event) => setLinkUrl(event.target.value)}
+              placeholder="https://example.com/docs"
+              aria-label="Link a web page"
+            />
+            <button type="submit" className="btn-secondary" disabled={!isHttpUrl(linkUrl) || linkMutation.isPending}>
+              Add link
+            </button>
+          </form>
+        </div>
+      )}
+
+      <ul className="knowledge-list">
+        {uploads.map((upload) => (
+          <li key={`upload-${upload.localId}`} className="knowledge-doc">
+            <span className="knowledge-doc-name">{upload.name}</span>
+            <span className="knowledge-doc-meta">Uploading {Math.round(upload.progress * 100)}%</span>
+            <span
+              className="knowledge-upload-progress"
+              style={{ width: `${Math.round(upload.progress * 100)}%` }}
+              role="progressbar"
+              aria-valuemin={0}
+              aria-valuemax={100}
+              aria-valuenow={Math.round(upload.progress * 100)}
+            />
+          </li>
+        ))}
+        {documents.map((doc) => (
+          <li key={doc.id} className="knowledge-doc" title={doc.error ?? undefined}>
+            <span className="knowledge-doc-name">
+              {doc.url ? (
+                <a href={doc.url} target="_blank" rel="noreferrer">
+                  {doc.name}
+                </a>
+              ) : (
+                doc.name
+              )}
+            </span>
+            <span className="knowledge-doc-meta">{knowledgeDocumentMeta(doc)}</span>
+            <span className={clsx("knowledge-status", doc.status)}>{KNOWLEDGE_STATUS_LABELS[doc.status]}</span>
+            {canEdit && (
+              <button
+                type="button"
+                className="knowledge-remove"
+                onClick={() => deleteMutation.mutate(doc)}
+                disabled={deleteMutation.isPending}
+                aria-label={`Remove ${doc.name}`}
+              >
+                ✕
+              </button>
+            )}
+            {doc.status === "failed" && doc.error && <span className="prompt-variable-error">{doc.error}</span>}
+          </li>
+        ))}
+      </ul>
+
+      {!isLoading && documents.length === 0 && uploads.length === 0 && (
+        <p className="knowledge-empty">No documents yet.</p>
+      )}
+    </section>
+  );
+}
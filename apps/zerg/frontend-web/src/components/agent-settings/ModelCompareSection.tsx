@@ -0,0 +1,112 @@
+import { useState } from "react";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import clsx from "clsx";
+import { compareModels, fetchModels, type Agent, type ModelComparisonResult } from "../../services/api";
+import { COMPARISON_METRICS, bestResultIndex, relativeToBest, type ComparisonMetric } from "../../lib/modelComparison";
+import { formatCostUsd, formatLatency, formatTokenCount } from "../chat/chatUtils";
+
+type ModelCompareSectionProps = {
+  agent: Agent;
+};
+
+function formatMetric(result: ModelComparisonResult, metric: ComparisonMetric): string {
+  const value = result[metric];
+  if (value == null) return "—";
+  if (metric === "duration_ms") return formatLatency(value);
+  if (metric === "cost_usd") return formatCostUsd(value);
+  return formatTokenCount(value);
+}
+
+export function ModelCompareSection({ agent }: ModelCompareSectionProps) {
+  const { data: models = [] } = useQuery({ queryKey: ["models"], queryFn: fetchModels, staleTime: 60 * 60 * 1000 });
+  const [left, setLeft] = useState<string>(agent.model);
+  const [right, setRight] = useState<string>("");
+  const [input, setInput] = useState("");
+
+  // Default the second column to the first model that isn't the agent's own
+  const rightModel = right || models.find((model) => model.id !== left)?.id || "";
+
+  const comparison = useMutation({
+    mutationFn: () => compareModels(agent.id, [left, rightModel], input.trim() || undefined),
+    onError: (error: Error) => toast.error(error.message || "Comparison failed"),
+  });
+
+  const results = comparison.data?.results ?? [];
+  const canCompare = Boolean(left && rightModel) && left !== rightModel && !comparison.isPending;
+
+  const renderSelect = (value: string, onChange: (value: string) => void, label: string) => (
+    <label className="prompt-template-field">
+      <span>{label}</span>
+      <select value={value} onChange={(event) => onChange(event.target.value)}>
+        {models.map((model) => (
+          <option key={model.id} value={model.id}>
+            {model.display_name || model.id}
+          </option>
+        ))}
+      </select>
+    </label>
+  );
+
+  return (
+    <section className="agent-settings-section model-compare-section">
+      <h3>Compare Models</h3>
+      <p className="section-description">
+        Run the same message on two models and compare the replies. Nothing is saved to the agent or its
+        threads, but tools still run for real.
+      </p>
+
+      <div className="model-compare-pickers">
+        {renderSelect(left, setLeft, "Model A")}
+        {renderSelect(rightModel, setRight, "Model B")}
+      </div>
+
+      <label className="prompt-template-field">
+        <span>Message</span>
+        <textarea
+          value={input}
+          onChange={(event) => setInput(event.target.value)}
+          rows={3}
+          placeholder="Leave empty to use the agent's task instructions"
+        />
+      </label>
+
+      <div className="agent-tests-toolbar">
+        {left === rightModel && <span className="prompt-variable-error">Pick two different models</span>}
+        <span className="spacer" />
+        <button type="button" className="btn-primary" onClick={() => comparison.mutate()} disabled={!canCompare}>
+          {comparison.isPending ? "Comparing…" : "Compare"}
+        </button>
+      </div>
+
+      {results.length > 0 && (
+        <div className="model-compare-results">
+          {results.map((result, index) => (
+            <div key={result.model} className={clsx("model-compare-column", result.error && "failed")}>
+              <div className="model-compare-model">{result.model}</div>
+              <dl className="model-compare-metrics">
+                {COMPARISON_METRICS.map(({ key, label }) => {
+                  const delta = relativeToBest(results, index, key);
+                  return (
+                    <div key={key} className={clsx(bestResultIndex(results, key) === index && "best")}>
+                      <dt>{label}</dt>
+                      <dd>
+                        {formatMetric(result, key)}
+                        {delta && <span className="model-compare-delta">{delta}</span>}
+                      </dd>
+                    </div>
+                  );
+                })}
+              </dl>
+              {result.error ? (
+                <p className="prompt-variable-error">{result.error}</p>
+              ) : (
+                <pre className="model-compare-output">{result.output || "(empty reply)"}</pre>
+              )}
+            </div>
+          ))}
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,175 @@
+import { useEffect, useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import {
+  fetchAgentModelParams,
+  fetchModels,
+  updateAgent,
+  type Agent,
+  type AgentUpdatePayload,
+  type ModelParams,
+} from "../../services/api";
+import {
+  MODEL_PARAM_FIELDS,
+  draftErrors,
+  draftFromParams,
+  formatModelParam,
+  isOverridden,
+  modelParamError,
+  modelParamsFromConfig,
+  paramsFromDraft,
+  withModelParams,
+  type ModelParamsDraft,
+} from "../../lib/modelParams";
+
+type ModelSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+const EFFECTIVE_ROWS: { key: keyof ModelParams; label: string }[] = [
+  ...MODEL_PARAM_FIELDS.map(({ key, label }) => ({ key, label })),
+  { key: "stop", label: "Stop sequences" },
+];
+
+function initialState(agent: Agent) {
+  return {
+    model: agent.model,
+    draft: draftFromParams(modelParamsFromConfig(agent.config as Record<string, unknown> | null)),
+  };
+}
+
+/**
+ * Model id plus temperature / top_p / max tokens / stop overrides. Blank
+ * fields use the model's defaults; the effective values (after the
+ * deployment's output cap) are shown underneath.
+ */
+export function ModelSection({ agent, canEdit }: ModelSectionProps) {
+  const queryClient = useQueryClient();
+  const [state, setState] = useState(() => initialState(agent));
+
+  useEffect(() => {
+    setState(initialState(agent));
+  }, [agent]);
+
+  const { data: models = [] } = useQuery({ queryKey: ["models"], queryFn: fetchModels, staleTime: 60 * 60 * 1000 });
+  const { data: params } = useQuery({
+    queryKey: ["agent-model-params", agent.id],
+    queryFn: () => fetchAgentModelParams(agent.id),
+  });
+
+  const { model, draft } = state;
+  const defaults = models.find((item) => item.id === model)?.default_params ?? {};
+  const errors = draftErrors(draft);
+  const initial = initialState(agent);
+  const isDirty = model !== initial.model || JSON.stringify(draft) !== JSON.stringify(initial.draft);
+
+  const setField = (key: keyof ModelParamsDraft, value: string) =>
+    setState((prev) => ({ ...prev, draft: { ...prev.draft, [key]: value } }));
+
+  const saveMutation = useMutation({
+    // The backend replaces config wholesale, so merge into what's there
+    mutationFn: () =>
+      updateAgent(agent.id, {
+        model: model !== agent.model ? model : undefined,
+        config: withModelParams(
+          agent.config as Record<string, unknown> | null,
+          paramsFromDraft(draft)
+        ) as AgentUpdatePayload["config"],
+      }),
+    onSuccess: () => {
+      toast.success("Model settings saved");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["agent-model-params", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save model settings: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const canSave = canEdit && isDirty && errors.length === 0 && !saveMutation.isPending;
+
+  return (
+    <section className="agent-settings-section model-section">
+      <h3>Model</h3>
+      <p className="section-description">
+        Which model the agent runs on and how it generates. Leave a field blank to use the model&apos;s default.
+      </p>
+
+      <div className="schedule-builder">
+        <label>
+          <span>Model</span>
+          <select
+            value={model}
+            onChange={(event) => setState((prev) => ({ ...prev, model: event.target.value }))}
+            disabled={!canEdit}
+          >
+            {!models.some((item) => item.id === model) && <option value={model}>{model}</option>}
+            {models.map((item) => (
+              <option key={item.id} value={item.id}>
+                {item.display_name || item.id}
+              </option>
+            ))}
+          </select>
+        </label>
+
+        {MODEL_PARAM_FIELDS.map((field) => (
+          <label key={field.key}>
+            <span>{field.label}</span>
+            <input
+              type="number"
+              min={field.min}
+              max={field.max}
+              step={field.step}
+              value={draft[field.key]}
+              placeholder={formatModelParam(field.key, defaults)}
+              onChange={(event) => setField(field.key, event.target.value)}
+              aria-invalid={modelParamError(field, draft[field.key]) ? true : undefined}
+              disabled={!canEdit}
+            />
+          </label>
+        ))}
+      </div>
+
+      <label className="prompt-template-field">
+        <span>Stop sequences (one per line)</span>
+        <textarea
+          value={draft.stop}
+          onChange={(event) => setField("stop", event.target.value)}
+          rows={2}
+          disabled={!canEdit}
+        />
+      </label>
+
+      {errors.map((error) => (
+        <div key={error} className="schedule-error" role="alert">
+          {error}
+        </div>
+      ))}
+
+      {canEdit && (
+        <button type="button" className="btn-primary" onClick={() => saveMutation.mutate()} disabled={!canSave}>
+          {saveMutation.isPending ? "Saving…" : "Save model settings"}
+        </button>
+      )}
+
+      {params && (
+        <div className="model-params-effective">
+          <div className="prompt-template-preview-label">Effective parameters ({params.model})</div>
+          <dl>
+            {EFFECTIVE_ROWS.map(({ key, label }) => (
+              <div key={key}>
+                <dt>{label}</dt>
+                <dd>
+                  {formatModelParam(key, params.effective)}
+                  {isOverridden(key, params.overrides) && <span className="model-params-source">agent</span>}
+                </dd>
+              </div>
+            ))}
+          </dl>
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,95 @@
+import { useEffect, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { updateAgent, type Agent, type AgentUpdatePayload } from "../../services/api";
+import {
+  EXAMPLE_OUTPUT_SCHEMA,
+  outputSchemaFromConfig,
+  parseSchemaDraft,
+  withOutputSchema,
+} from "../../lib/outputSchema";
+
+type OutputSchemaSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+/**
+ * JSON Schema the agent's final reply should match. Conforming run outputs
+ * are shown as structured data in the run details; violations are flagged
+ * in the run history.
+ */
+export function OutputSchemaSection({ agent, canEdit }: OutputSchemaSectionProps) {
+  const queryClient = useQueryClient();
+  const config = agent.config as Record<string, unknown> | null;
+  const [text, setText] = useState(() => outputSchemaFromConfig(config));
+
+  useEffect(() => {
+    setText(outputSchemaFromConfig(agent.config as Record<string, unknown> | null));
+  }, [agent.id, agent.config]);
+
+  const { schema, error } = parseSchemaDraft(text);
+  const dirty = text.trim() !== outputSchemaFromConfig(config).trim();
+
+  const saveMutation = useMutation({
+    // The backend replaces config wholesale, so merge into what's there
+    mutationFn: () =>
+      updateAgent(agent.id, { config: withOutputSchema(config, schema) as AgentUpdatePayload["config"] }),
+    onSuccess: () => {
+      toast.success(schema ? "Output schema saved" : "Output schema removed");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (saveError: Error) => {
+      toast.error(`Failed to save output schema: ${saveError.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <section className="agent-settings-section output-schema-section">
+      <h3>Output schema</h3>
+      <p className="section-description">
+        JSON Schema the agent&apos;s final reply should follow. Matching runs are shown as structured data; runs that
+        don&apos;t match are flagged in the run history. Leave blank for free-form replies.
+      </p>
+
+      <label className="prompt-template-field">
+        <span>JSON Schema</span>
+        <textarea
+          className="output-schema-editor"
+          value={text}
+          onChange={(event) => setText(event.target.value)}
+          rows={10}
+          spellCheck={false}
+          placeholder={EXAMPLE_OUTPUT_SCHEMA}
+          aria-invalid={error ? true : undefined}
+          disabled={!canEdit}
+        />
+      </label>
+
+      {error && (
+        <div className="schedule-error" role="alert">
+          {error}
+        </div>
+      )}
+
+      {canEdit && (
+        <div className="agent-tests-toolbar">
+          <button
+            type="button"
+            className="btn-primary"
+            onClick={() => saveMutation.mutate()}
+            disabled={!dirty || Boolean(error) || saveMutation.isPending}
+          >
+            {saveMutation.isPending ? "Saving…" : "Save schema"}
+          </button>
+          {!text.trim() && (
+            <button type="button" className="btn-secondary" onClick={() => setText(EXAMPLE_OUTPUT_SCHEMA)}>
+              Start from example
+            </button>
+          )}
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,280 @@
+import { useEffect, useMemo, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { updateAgent, type Agent } from "../../services/api";
+import {
+  isValidVariableName,
+  renderPromptTemplate,
+  undefinedTemplateVariables,
+  type PromptVariables,
+} from "../../lib/promptTemplate";
+import { agentDraftKey, draftSlice, readDraft } from "../../lib/drafts";
+import { persistence } from "../../lib/persistence";
+import { useDraftAutosave } from "../../lib/useDraftAutosave";
+
+type VariableRow = {
+  key: number;
+  name: string;
+  value: string;
+};
+
+type InstructionsDraft = Pick<Agent, "system_instructions" | "task_instructions">;
+
+type PromptTemplateSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+let nextRowKey = 0;
+
+function toRows(variables: Agent["prompt_variables"]): VariableRow[] {
+  return Object.entries(variables ?? {}).map(([name, value]) => ({ key: nextRowKey++, name, value }));
+}
+
+// A stored draft worth restoring over the server copy of *agent*
+function restorableDraft(agent: Agent): InstructionsDraft | null {
+  const key = agentDraftKey(agent.id);
+  // A change typed moments ago may still be waiting in the queue
+  persistence.flush(draftSlice(key));
+  const draft = readDraft<Partial<InstructionsDraft>>(key)?.value;
+  if (typeof draft?.system_instructions !== "string" || typeof draft.task_instructions !== "string") return null;
+  if (
+    draft.system_instructions === agent.system_instructions &&
+    draft.task_instructions === agent.task_instructions
+  ) {
+    return null;
+  }
+  return { system_instructions: draft.system_instructions, task_instructions: draft.task_instructions };
+}
+
+/**
+ * System/task instructions with ``{{variable}}`` placeholders, the variables
+ * that fill them and a live preview of the rendered prompt.
+ */
+export function PromptTemplateSection({ agent, canEdit }: PromptTemplateSectionProps) {
+  const queryClient = useQueryClient();
+  const [systemInstructions, setSystemInstructions] = useState(agent.system_instructions);
+  const [taskInstructions, setTaskInstructions] = useState(agent.task_instructions);
+  const [rows, setRows] = useState<VariableRow[]>(() => toRows(agent.prompt_variables));
+  // Agent the edited instructions belong to; always changes together with them
+  const [draftAgentId, setDraftAgentId] = useState<number | null>(null);
+  const [draftRestored, setDraftRestored] = useState(false);
+
+  const unsavedInstructions = useMemo<InstructionsDraft | null>(
+    () =>
+      draftAgentId === agent.id &&
+      (systemInstructions !== agent.system_instructions || taskInstructions !== agent.task_instructions)
+        ? { system_instructions: systemInstructions, task_instructions: taskInstructions }
+        : null,
+    [agent, draftAgentId, systemInstructions, taskInstructions]
+  );
+  const { discard: discardDraft } = useDraftAutosave(
+    draftAgentId === null ? null : agentDraftKey(draftAgentId),
+    unsavedInstructions
+  );
+
+  // Rehydrate when the server copy changes (initial load, save, another tab),
+  // keeping unsaved edits from an earlier visit on top of it
+  useEffect(() => {
+    const draft = canEdit ? restorableDraft(agent) : null;
+    setSystemInstructions(draft?.system_instructions ?? agent.system_instructions);
+    setTaskInstructions(draft?.task_instructions ?? agent.task_instructions);
+    setDraftRestored(draft !== null);
+    setDraftAgentId(agent.id);
+    setRows(toRows(agent.prompt_variables));
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [agent.id, agent.system_instructions, agent.task_instructions, agent.prompt_variables]);
+
+  const handleDiscardDraft = () => {
+    setSystemInstructions(agent.system_instructions);
+    setTaskInstructions(agent.task_instructions);
+    setDraftRestored(false);
+    discardDraft();
+  };
+
+  const variables = useMemo<PromptVariables>(() => {
+    const result: PromptVariables = {};
+    for (const row of rows) {
+      const name = row.name.trim();
+      if (name) result[name] = row.value;
+    }
+    return result;
+  }, [rows]);
+
+  const rowErrors = useMemo(() => {
+    const seen = new Set<string>();
+    return rows.map((row) => {
+      const name = row.name.trim();
+      if (!name) return row.value ? "Name is required" : null;
+      if (!isValidVariableName(name)) return "Use letters, digits and underscores";
+      if (seen.has(name)) return "Duplicate name";
+      seen.add(name);
+      return null;
+    });
+  }, [rows]);
+
+  const hasRowErrors = rowErrors.some(Boolean);
+  const undefinedNames = undefinedTemplateVariables([systemInstructions, taskInstructions], variables);
+  const renderedSystem = renderPromptTemplate(systemInstructions, variables);
+  const renderedTask = renderPromptTemplate(taskInstructions, variables);
+
+  const saveMutation = useMutation({
+    mutationFn: () =>
+      updateAgent(agent.id, {
+        system_instructions: systemInstructions,
+        task_instructions: taskInstructions,
+        prompt_variables: variables,
+      }),
+    onSuccess: () => {
+      discardDraft();
+      setDraftRestored(false);
+      toast.success("Instructions saved");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save instructions: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const updateRow = (key: number, patch: Partial<Omit<VariableRow, "key">>) => {
+    setRows((current) => current.map((row) => (row.key === key ? { ...row, ...patch } : row)));
+  };
+
+  const handleSave = () => {
+    if (hasRowErrors) return;
+    if (undefinedNames.length > 0) {
+      const confirmed = window.confirm(
+        `These placeholders have no value and will be sent as-is: ${undefinedNames.join(", ")}. Save anyway?`
+      );
+      if (!confirmed) return;
+    }
+    saveMutation.mutate();
+  };
+
+  return (
+    <section className="agent-settings-section prompt-template-section">
+      <h3>Instructions</h3>
+      <p className="section-description">
+        Use <code>{"{{name}}"}</code> placeholders to reuse values across the system and task instructions.
+      </p>
+
+      {draftRestored && (
+        <div className="prompt-template-draft" role="status" data-testid="instructions-draft-restored">
+          <span>Draft restored: these instructions have unsaved changes from an earlier visit.</span>
+          <button type="button" className="btn-tertiary" onClick={handleDiscardDraft}>
+            Discard
+          </button>
+        </div>
+      )}
+
+      <label className="prompt-template-field">
+        <span>System instructions</span>
+        <textarea
+          value={systemInstructions}
+          onChange={(event) => setSystemInstructions(event.target.value)}
+          rows={6}
+          disabled={!canEdit}
+        />
+      </label>
+      <label className="prompt-template-field">
+        <span>Task instructions</span>
+        <textarea
+          value={taskInstructions}
+          onChange={(event) => setTaskInstructions(event.target.value)}
+          rows={4}
+          disabled={!canEdit}
+        />
+      </label>
+
+      <table className="prompt-variables-table">
+        <thead>
+          <tr>
+            <th>Variable</th>
+            <th>Value</th>
+            <th aria-label="Actions" />
+          </tr>
+        </thead>
+        <tbody>
+          {rows.length === 0 && (
+            <tr>
+              <td colSpan={3} className="muted">
+                No variables defined
+              </td>
+            </tr>
+          )}
+          {rows.map((row, index) => (
+            <tr key={row.key}>
+              <td>
+                <input
+                  type="text"
+                  value={row.name}
+                  onChange={(event) => updateRow(row.key, { name: event.target.value })}
+                  placeholder="company"
+                  aria-label="Variable name"
+                  aria-invalid={rowErrors[index] ? true : undefined}
+                  disabled={!canEdit}
+                />
+                {rowErrors[index] && <div className="prompt-variable-error">{rowErrors[index]}</div>}
+              </td>
+              <td>
+                <input
+                  type="text"
+                  value={row.value}
+                  onChange={(event) => updateRow(row.key, { value: event.target.value })}
+                  aria-label="Variable value"
+                  disabled={!canEdit}
+                />
+              </td>
+              <td>
+                {canEdit && (
+                  <button
+                    type="button"
+                    className="btn-tertiary"
+                    onClick={() => setRows((current) => current.filter((item) => item.key !== row.key))}
+                    aria-label={`Remove variable ${row.name || index + 1}`}
+                  >
+                    ×
+                  </button>
+                )}
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+      {canEdit && (
+        <button
+          type="button"
+          className="btn-secondary"
+          onClick={() => setRows((current) => [...current, { key: nextRowKey++, name: "", value: "" }])}
+        >
+          Add variable
+        </button>
+      )}
+
+      {undefinedNames.length > 0 && (
+        <div className="prompt-template-warning" role="status" data-testid="undefined-variables-warning">
+          Undefined variables: {undefinedNames.map((name) => `{{${name}}}`).join(", ")}
+        </div>
+      )}
+
+      <div className="prompt-template-preview" data-testid="prompt-preview">
+        <h4>Preview</h4>
+        <div className="prompt-template-preview-label">System</div>
+        <pre>{renderedSystem || "(empty)"}</pre>
+        <div className="prompt-template-preview-label">Task</div>
+        <pre>{renderedTask || "(empty)"}</pre>
+      </div>
+
+      {canEdit && (
+        <button
+          type="button"
+          className="btn-primary"
+          onClick={handleSave}
+          disabled={hasRowErrors || saveMutation.isPending}
+        >
+          {saveMutation.isPending ? "Saving…" : "Save instructions"}
+        </button>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,164 @@
+import { useEffect, useMemo, useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { patchAgent, validateAgentEdit, type Agent, type AgentPatch } from "../../services/api";
+import { agentContractJson, agentEditPatch, parseRawAgentDraft, stableStringify } from "../../lib/rawAgentJson";
+import { SyntaxHighlighter, oneDark } from "../../lib/syntaxHighlighter";
+import { useDebounce } from "../../lib/usePerformance";
+
+type RawJsonSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+// Highlighted text and the textarea on top of it must line up exactly
+const HIGHLIGHT_STYLE = {
+  margin: 0,
+  padding: "var(--space-3)",
+  background: "transparent",
+  fontFamily: "var(--font-family-mono)",
+  fontSize: "var(--font-size-xs)",
+  lineHeight: 1.5,
+};
+const CODE_STYLE = { style: { fontFamily: "inherit", fontSize: "inherit", lineHeight: "inherit" } };
+
+// The trailing newline keeps the last line's height when the text ends with one
+function HighlightedJson({ text }: { text: string }) {
+  return (
+    <SyntaxHighlighter
+      style={oneDark}
+      language="json"
+      PreTag="pre"
+      customStyle={HIGHLIGHT_STYLE}
+      codeTagProps={CODE_STYLE}
+    >
+      {`${text}\n`}
+    </SyntaxHighlighter>
+  );
+}
+
+/**
+ * The agent's contract fields as raw JSON. Read-only until "Edit"; edits are
+ * checked against the contract as you type and applied with a PATCH that
+ * only sends the changed fields.
+ */
+export function RawJsonSection({ agent, canEdit }: RawJsonSectionProps) {
+  const queryClient = useQueryClient();
+  const loaded = useMemo(() => agentContractJson(agent), [agent]);
+  const [editing, setEditing] = useState(false);
+  const [text, setText] = useState(loaded);
+  // What the edit is diffed against; kept while editing so a refetch can't move it
+  const [original, setOriginal] = useState(loaded);
+
+  useEffect(() => {
+    if (!editing) {
+      setText(loaded);
+      setOriginal(loaded);
+    }
+  }, [loaded, editing]);
+
+  const { value, error: parseError } = useMemo(() => parseRawAgentDraft(text), [text]);
+  const patch = useMemo(
+    () => (value ? agentEditPatch(JSON.parse(original) as Record<string, unknown>, value) : null),
+    [value, original]
+  );
+  const changedFields = patch ? Object.keys(patch.changes) : [];
+  const changesKey = patch ? stableStringify(patch.changes) : "";
+  const debouncedChangesKey = useDebounce(changesKey, 400);
+
+  const contractQuery = useQuery({
+    queryKey: ["agent-contract-validate", debouncedChangesKey],
+    queryFn: () => validateAgentEdit(JSON.parse(debouncedChangesKey)),
+    enabled: editing && debouncedChangesKey !== "" && debouncedChangesKey !== "{}",
+    staleTime: Infinity,
+  });
+  // Results for an older draft don't count
+  const validation = debouncedChangesKey === changesKey ? contractQuery.data : undefined;
+  const checked = validation !== undefined;
+  const contractErrors = validation?.errors ?? [];
+
+  const applyMutation = useMutation({
+    mutationFn: (body: AgentPatch) => patchAgent(agent.id, body),
+    onSuccess: () => {
+      toast.success(`Updated ${changedFields.join(", ")}`);
+      setEditing(false);
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (applyError: Error) => {
+      toast.error(`Failed to apply JSON: ${applyError.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleApply = () => {
+    const summary = changedFields.join(", ");
+    if (patch && window.confirm(`Apply raw changes to ${summary}? Fields are saved as typed, bypassing the forms.`)) {
+      applyMutation.mutate(patch);
+    }
+  };
+
+  const canApply =
+    changedFields.length > 0 && !parseError && checked && contractErrors.length === 0 && !applyMutation.isPending;
+
+  return (
+    <section className="agent-settings-section raw-json-section">
+      <h3>Raw JSON</h3>
+      <p className="section-description">
+        Every editable field of the agent, including ones the forms above don&apos;t cover yet. Edits are checked
+        against the agent contract before they can be applied.
+      </p>
+
+      {editing ? (
+        <div className="raw-json-editor">
+          <div aria-hidden="true">
+            <HighlightedJson text={text} />
+          </div>
+          <textarea
+            value={text}
+            onChange={(event) => setText(event.target.value)}
+            spellCheck={false}
+            wrap="off"
+            aria-label="Agent JSON"
+            aria-invalid={parseError || contractErrors.length > 0 ? true : undefined}
+          />
+        </div>
+      ) : (
+        <div className="raw-json-view" data-testid="raw-json-view">
+          <HighlightedJson text={loaded} />
+        </div>
+      )}
+
+      {editing && (parseError || contractErrors.length > 0) && (
+        <ul className="run-schema-errors" role="alert">
+          {parseError ? <li>{parseError}</li> : contractErrors.map((message) => <li key={message}>{message}</li>)}
+        </ul>
+      )}
+
+      {canEdit && (
+        <div className="agent-tests-toolbar">
+          {editing ? (
+            <>
+              <button type="button" className="btn-primary" onClick={handleApply} disabled={!canApply}>
+                {applyMutation.isPending ? "Applying…" : "Apply"}
+              </button>
+              <button type="button" className="btn-secondary" onClick={() => setEditing(false)}>
+                Discard
+              </button>
+              <span className="muted">
+                {changedFields.length === 0
+                  ? "No changes"
+                  : `${changedFields.length} field${changedFields.length === 1 ? "" : "s"} changed${
+                      checked || parseError ? "" : " · checking…"
+                    }`}
+              </span>
+            </>
+          ) : (
+            <button type="button" className="btn-secondary" onClick={() => setEditing(true)}>
+              Edit JSON
+            </button>
+          )}
+        </div>
+      )}
+    </section>
+  );
+}
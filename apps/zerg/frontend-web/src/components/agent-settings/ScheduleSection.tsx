@@ -0,0 +1,236 @@
+import { useEffect, useMemo, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { updateAgent, type Agent } from "../../services/api";
+import {
+  WEEKDAY_NAMES,
+  buildCronExpression,
+  describeCron,
+  detectCronPreset,
+  nextRunTimes,
+  parseCron,
+  type CronPreset,
+} from "../../lib/cron";
+
+type ScheduleMode = "none" | CronPreset | "custom";
+
+type ScheduleSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+const MODE_LABELS: Record<ScheduleMode, string> = {
+  none: "Not scheduled",
+  hourly: "Every hour",
+  daily: "Every day",
+  weekdays: "Weekdays",
+  weekly: "Once a week",
+  custom: "Custom (cron)",
+};
+
+const runTimeFormat = new Intl.DateTimeFormat(undefined, {
+  timeZone: "UTC",
+  weekday: "short",
+  year: "numeric",
+  month: "short",
+  day: "numeric",
+  hour: "2-digit",
+  minute: "2-digit",
+});
+
+function pad(value: number) {
+  return String(value).padStart(2, "0");
+}
+
+function initialState(schedule: string | null | undefined) {
+  const preset = schedule ? detectCronPreset(schedule) : null;
+  return {
+    mode: (schedule ? preset?.preset ?? "custom" : "none") as ScheduleMode,
+    minute: preset?.minute ?? 0,
+    hour: preset?.hour ?? 9,
+    weekday: preset?.weekday ?? 0,
+    expression: schedule ?? "",
+  };
+}
+
+/**
+ * Cron schedule builder: presets for the common cases, a raw expression for
+ * everything else, and a preview of the next runs.
+ */
+export function ScheduleSection({ agent, canEdit }: ScheduleSectionProps) {
+  const queryClient = useQueryClient();
+  const [state, setState] = useState(() => initialState(agent.schedule));
+
+  useEffect(() => {
+    setState(initialState(agent.schedule));
+  }, [agent.id, agent.schedule]);
+
+  const { mode, minute, hour, weekday, expression } = state;
+  const parsed = useMemo(() => (mode === "none" ? null : parseCron(expression)), [mode, expression]);
+  const description = parsed?.ok ? describeCron(expression) : null;
+  const upcoming = useMemo(() => (parsed?.ok ? nextRunTimes(expression) : []), [parsed, expression]);
+
+  const applyPreset = (patch: Partial<typeof state>) => {
+    setState((current) => {
+      const next = { ...current, ...patch };
+      if (next.mode !== "none" && next.mode !== "custom") {
+        next.expression = buildCronExpression(next.mode, next);
+      }
+      return next;
+    });
+  };
+
+  const handleTimeChange = (value: string) => {
+    const [hours, minutes] = value.split(":").map(Number);
+    if (Number.isNaN(hours) || Number.isNaN(minutes)) return;
+    applyPreset({ hour: hours, minute: minutes });
+  };
+
+  const handleExpressionChange = (value: string) => {
+    const preset = detectCronPreset(value);
+    setState((current) => ({
+      ...current,
+      ...(preset ?? {}),
+      mode: preset?.preset ?? "custom",
+      expression: value,
+    }));
+  };
+
+  const saveMutation = useMutation({
+    // An empty string clears the schedule server-side
+    mutationFn: () => updateAgent(agent.id, { schedule: mode === "none" ? "" : expression.trim() }),
+    onSuccess: () => {
+      toast.success(mode === "none" ? "Schedule removed" : "Schedule saved");
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save schedule: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const isDirty = (mode === "none" ? "" : expression.trim()) !== (agent.schedule ?? "");
+  const canSave = canEdit && isDirty && (mode === "none" || parsed?.ok === true) && !saveMutation.isPending;
+  const showsTime = mode === "daily" || mode === "weekdays" || mode === "weekly";
+
+  return (
+    <section className="agent-settings-section schedule-section">
+      <h3>Schedule</h3>
+      <p className="section-description">Run this agent automatically. Times are in UTC.</p>
+
+      <div className="schedule-builder">
+        <label>
+          <span>Repeat</span>
+          <select
+            value={mode}
+            onChange={(event) => applyPreset({ mode: event.target.value as ScheduleMode })}
+            disabled={!canEdit}
+            aria-label="Schedule preset"
+          >
+            {(Object.keys(MODE_LABELS) as ScheduleMode[]).map((option) => (
+              <option key={option} value={option}>
+                {MODE_LABELS[option]}
+              </option>
+            ))}
+          </select>
+        </label>
+
+        {mode === "hourly" && (
+          <label>
+            <span>Minute past the hour</span>
+            <input
+              type="number"
+              min={0}
+              max={59}
+              value={minute}
+              onChange={(event) => applyPreset({ minute: Math.min(59, Math.max(0, Number(event.target.value))) })}
+              disabled={!canEdit}
+            />
+          </label>
+        )}
+
+        {mode === "weekly" && (
+          <label>
+            <span>Day</span>
+            <select
+              value={weekday}
+              onChange={(event) => applyPreset({ weekday: Number(event.target.value) })}
+              disabled={!canEdit}
+            >
+              {WEEKDAY_NAMES.map((name, index) => (
+                <option key={name} value={index}>
+                  {name}
+                </option>
+              ))}
+            </select>
+          </label>
+        )}
+
+        {showsTime && (
+          <label>
+            <span>Time (UTC)</span>
+            <input
+              type="time"
+              value={`${pad(hour)}:${pad(minute)}`}
+              onChange={(event) => handleTimeChange(event.target.value)}
+              disabled={!canEdit}
+            />
+          </label>
+        )}
+      </div>
+
+      {mode !== "none" && (
+        <>
+          <label className="schedule-expression">
+            <span>Cron expression</span>
+            <input
+              type="text"
+              value={expression}
+              onChange={(event) => handleExpressionChange(event.target.value)}
+              placeholder="minute hour day month weekday"
+              spellCheck={false}
+              aria-invalid={parsed && !parsed.ok ? true : undefined}
+              disabled={!canEdit}
+            />
+          </label>
+
+          {parsed && !parsed.ok && (
+            <div className="schedule-error" role="alert">
+              {parsed.error}
+            </div>
+          )}
+          {description && (
+            <p className="schedule-description" data-testid="schedule-description">
+              {description} (UTC)
+            </p>
+          )}
+          {parsed?.ok &&
+            parsed.warnings.map((warning) => (
+              <p key={warning} className="schedule-warning">
+                {warning}
+              </p>
+            ))}
+
+          {upcoming.length > 0 && (
+            <div className="schedule-preview" data-testid="schedule-preview">
+              <h4>Next runs</h4>
+              <ol>
+                {upcoming.map((run) => (
+                  <li key={run.toISOString()}>
+                    <time dateTime={run.toISOString()}>{runTimeFormat.format(run)} UTC</time>
+                  </li>
+                ))}
+              </ol>
+            </div>
+          )}
+        </>
+      )}
+
+      {canEdit && (
+        <button type="button" className="btn-primary" onClick={() => saveMutation.mutate()} disabled={!canSave}>
+          {saveMutation.isPending ? "Saving…" : "Save schedule"}
+        </button>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,108 @@
+import { useState, type FormEvent } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { createSlackTrigger, deleteTrigger, type Trigger } from "../../services/api";
+import { SLACK_EVENT_LABELS, describeSlackTrigger, type SlackEventType } from "../../lib/slackTrigger";
+import { TriggerDeliveries, TriggerDiagnostics } from "./TriggerFields";
+
+/** A Slack-event trigger: what it listens for, its recent firings and a delete button. */
+export function SlackTriggerCard({ trigger, canEdit }: { trigger: Trigger; canEdit: boolean }) {
+  const queryClient = useQueryClient();
+
+  const deleteMutation = useMutation({
+    mutationFn: () => deleteTrigger(trigger.id),
+    onSuccess: () => {
+      toast.success("Trigger deleted");
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to delete trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDelete = () => {
+    if (window.confirm("Delete this Slack trigger? Slack events will no longer run the agent.")) {
+      deleteMutation.mutate();
+    }
+  };
+
+  return (
+    <div className="trigger-card slack-trigger-card" data-testid={`slack-trigger-${trigger.id}`}>
+      <span>{describeSlackTrigger(trigger.config)}</span>
+      {canEdit && (
+        <>
+          <TriggerDeliveries triggerId={trigger.id} agentId={trigger.agent_id} />
+          <TriggerDiagnostics trigger={trigger} />
+          <button type="button" className="btn-tertiary" onClick={handleDelete} disabled={deleteMutation.isPending}>
+            Delete trigger
+          </button>
+        </>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Choose the Slack event that runs the agent, optionally narrowed to one
+ * channel and a keyword. Only offered once the Slack app is connected.
+ */
+export function SlackTriggerForm({ agentId }: { agentId: number }) {
+  const queryClient = useQueryClient();
+  const [eventType, setEventType] = useState<SlackEventType>("app_mention");
+  const [channel, setChannel] = useState("");
+  const [keyword, setKeyword] = useState("");
+
+  const createMutation = useMutation({
+    mutationFn: () => createSlackTrigger(agentId, { event_type: eventType, channel, keyword }),
+    onSuccess: () => {
+      toast.success("Slack trigger created");
+      setChannel("");
+      setKeyword("");
+      queryClient.invalidateQueries({ queryKey: ["triggers", agentId] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to create trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    createMutation.mutate();
+  };
+
+  return (
+    <form className="slack-trigger-form" onSubmit={handleSubmit} data-testid="slack-trigger-form">
+      <label>
+        <span>Run when</span>
+        <select value={eventType} onChange={(event) => setEventType(event.target.value as SlackEventType)}>
+          {(Object.keys(SLACK_EVENT_LABELS) as SlackEventType[]).map((type) => (
+            <option key={type} value={type}>
+              {SLACK_EVENT_LABELS[type]}
+            </option>
+          ))}
+        </select>
+      </label>
+      <label>
+        <span>Channel ID</span>
+        <input
+          type="text"
+          value={channel}
+          onChange={(event) => setChannel(event.target.value)}
+          placeholder="Any channel (e.g. C0123456)"
+        />
+      </label>
+      <label>
+        <span>{eventType === "reaction_added" ? "Emoji" : "Contains"}</span>
+        <input
+          type="text"
+          value={keyword}
+          onChange={(event) => setKeyword(event.target.value)}
+          placeholder={eventType === "reaction_added" ? "eyes" : "Optional keyword"}
+        />
+      </label>
+      <button type="submit" className="btn-secondary" disabled={createMutation.isPending}>
+        {createMutation.isPending ? "Creating…" : "Add Slack trigger"}
+      </button>
+    </form>
+  );
+}
@@ -0,0 +1,67 @@
+import { useEffect, useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { fetchTags, updateAgent, type Agent } from "../../services/api";
+import { TAGS_QUERY_KEY } from "../../lib/tags";
+import { TagEditor } from "../TagEditor";
+
+type TagsSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+/**
+ * Free-form tags for filtering the dashboard and the canvas shelf. Each
+ * change is saved straight away.
+ */
+export function TagsSection({ agent, canEdit }: TagsSectionProps) {
+  const queryClient = useQueryClient();
+  const [tags, setTags] = useState<string[]>(agent.tags ?? []);
+
+  useEffect(() => {
+    setTags(agent.tags ?? []);
+  }, [agent.id, agent.tags]);
+
+  const { data: known = [] } = useQuery({
+    queryKey: TAGS_QUERY_KEY,
+    queryFn: fetchTags,
+    enabled: canEdit,
+    staleTime: 60000,
+  });
+
+  const saveMutation = useMutation({
+    mutationFn: (next: string[]) => updateAgent(agent.id, { tags: next }),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["agent", agent.id] });
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+      queryClient.invalidateQueries({ queryKey: ["agents"] });
+      queryClient.invalidateQueries({ queryKey: TAGS_QUERY_KEY });
+    },
+    onError: (error: Error) => {
+      setTags(agent.tags ?? []);
+      toast.error(`Failed to save tags: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleChange = (next: string[]) => {
+    setTags(next);
+    saveMutation.mutate(next);
+  };
+
+  return (
+    <section className="agent-settings-section tags-section">
+      <h3>Tags</h3>
+      <p className="section-description">
+        Group and filter agents on the dashboard and the canvas shelf. Rename or merge tags in Settings.
+      </p>
+      <TagEditor
+        tags={tags}
+        onChange={handleChange}
+        suggestions={known.map((tag) => tag.name)}
+        label="Agent tags"
+        disabled={!canEdit}
+      />
+      {!canEdit && tags.length === 0 && <p className="muted">No tags</p>}
+    </section>
+  );
+}
@@ -0,0 +1,259 @@
+import { useEffect, useMemo, useState, type FormEvent } from "react";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import { fetchAgentTools, invokeTool, isApiError, type ToolInvokeResult } from "../../services/api";
+import {
+  argErrorsFromDetail,
+  buildArgs,
+  formatToolResult,
+  initialValues,
+  schemaFields,
+  type ToolField,
+} from "../../lib/toolForm";
+import { useFocusTrap } from "../../lib/useFocusTrap";
+
+type ToolPlaygroundModalProps = {
+  agentId: number;
+  isOpen: boolean;
+  initialTool?: string | null;
+  onClose: () => void;
+};
+
+type ToolFieldInputProps = {
+  field: ToolField;
+  value: string;
+  error?: string;
+  onChange: (value: string) => void;
+};
+
+function ToolFieldInput({ field, value, error, onChange }: ToolFieldInputProps) {
+  const id = `tool-arg-${field.name}`;
+  let control;
+  if (field.kind === "boolean") {
+    control = (
+      <input id={id} type="checkbox" checked={value === "true"} onChange={(e) => onChange(String(e.target.checked))} />
+    );
+  } else if (field.options) {
+    control = (
+      <select id={id} value={value} onChange={(e) => onChange(e.target.value)}>
+        {!field.required && <option value="">—</option>}
+        {field.options.map((option) => (
+          <option key={option} value={option}>
+            {option}
+          </option>
+        ))}
+      </select>
+    );
+  } else if (field.kind === "json") {
+    control = (
+      <textarea
+        id={id}
+        value={value}
+        rows={3}
+        spellCheck={false}
+        placeholder={field.placeholder}
+        onChange={(e) => onChange(e.target.value)}
+      />
+    );
+  } else {
+    control = (
+      <input
+        id={id}
+        type={field.kind === "string" ? "text" : "number"}
+        step={field.kind === "integer" ? 1 : "any"}
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+      />
+    );
+  }
+
+  return (
+    <div className="form-field tool-playground-field">
+      <label htmlFor={id}>
+        {field.label}
+        {field.required && <span className="required">*</span>}
+        <span className="tool-playground-kind">{field.kind}</span>
+      </label>
+      {control}
+      {field.description && <span className="muted">{field.description}</span>}
+      {error && (
+        <span className="schedule-error" role="alert">
+          {error}
+        </span>
+      )}
+    </div>
+  );
+}
+
+/**
+ * "Try tool" playground: call one of the agent's tools with hand-entered
+ * arguments and inspect what it returns, without running the agent.
+ */
+export function ToolPlaygroundModal({ agentId, isOpen, initialTool, onClose }: ToolPlaygroundModalProps) {
+  const [toolName, setToolName] = useState("");
+  const [values, setValues] = useState<Record<string, string>>({});
+  const [fieldErrors, setFieldErrors] = useState<Record<string, string>>({});
+  const [result, setResult] = useState<ToolInvokeResult | null>(null);
+  const [view, setView] = useState<"pretty" | "raw">("pretty");
+
+  const { data: tools = [], isLoading } = useQuery({
+    queryKey: ["agent", agentId, "tools"],
+    queryFn: () => fetchAgentTools(agentId),
+    enabled: isOpen,
+  });
+
+  const tool = tools.find((candidate) => candidate.name === toolName);
+  const fields = useMemo(() => schemaFields(tool?.parameters), [tool]);
+
+  useEffect(() => {
+    if (isOpen && initialTool) setToolName(initialTool);
+  }, [isOpen, initialTool]);
+
+  useEffect(() => {
+    if (tools.length > 0 && !tools.some((candidate) => candidate.name === toolName)) {
+      setToolName(tools[0].name);
+    }
+  }, [tools, toolName]);
+
+  useEffect(() => {
+    setValues(initialValues(tool?.parameters));
+    setFieldErrors({});
+    setResult(null);
+  }, [tool]);
+
+  const invokeMutation = useMutation({
+    mutationFn: (args: Record<string, unknown>) => invokeTool(agentId, toolName, args),
+    onSuccess: (response) => setResult(response),
+    onError: (error: Error) => {
+      if (isApiError(error, "validation")) {
+        setFieldErrors(argErrorsFromDetail(error.detail));
+        return;
+      }
+      toast.error(`Tool call failed: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const dialogRef = useFocusTrap(isOpen, onClose);
+
+  if (!isOpen) return null;
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    const built = buildArgs(fields, values);
+    if (!built.ok) {
+      setFieldErrors(built.errors);
+      return;
+    }
+    setFieldErrors({});
+    invokeMutation.mutate(built.args);
+  };
+
+  return (
+    <div className="connector-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        className="connector-modal tool-playground"
+        onClick={(e) => e.stopPropagation()}
+        ref={dialogRef}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="tool-playground-title"
+        tabIndex={-1}
+      >
+        <header className="connector-modal-header">
+          <h3 id="tool-playground-title">Try a tool</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <form onSubmit={handleSubmit}>
+          <div className="connector-modal-body">
+            {isLoading ? (
+              <p className="muted">Loading tools…</p>
+            ) : tools.length === 0 ? (
+              <p className="muted">This agent has no tools enabled.</p>
+            ) : (
+              <>
+                <div className="form-field">
+                  <label htmlFor="tool-playground-select">Tool</label>
+                  <select
+                    id="tool-playground-select"
+                    value={toolName}
+                    onChange={(e) => setToolName(e.target.value)}
+                    data-testid="tool-playground-select"
+                  >
+                    {tools.map((candidate) => (
+                      <option key={candidate.name} value={candidate.name}>
+                        {candidate.name}
+                      </option>
+                    ))}
+                  </select>
+                </div>
+                {tool?.description && <p className="connector-description">{tool.description}</p>}
+
+                {fields.length === 0 ? (
+                  <p className="muted">This tool takes no parameters.</p>
+                ) : (
+                  fields.map((field) => (
+                    <ToolFieldInput
+                      key={field.name}
+                      field={field}
+                      value={values[field.name] ?? ""}
+                      error={fieldErrors[field.name]}
+                      onChange={(value) => setValues((current) => ({ ...current, [field.name]: value }))}
+                    />
+                  ))
+                )}
+              </>
+            )}
+
+            {result && (
+              <div className="tool-playground-result" data-testid="tool-playground-result">
+                <div className="tool-playground-result-header">
+                  <span className={`delivery-status ${result.ok ? "delivery-ok" : "delivery-failed"}`}>
+                    {result.ok ? "OK" : "Error"}
+                  </span>
+                  <span className="muted">{result.duration_ms} ms</span>
+                  <div className="tool-playground-view">
+                    <button
+                      type="button"
+                      className={view === "pretty" ? "btn-secondary" : "btn-tertiary"}
+                      onClick={() => setView("pretty")}
+                      aria-pressed={view === "pretty"}
+                    >
+                      Pretty
+                    </button>
+                    <button
+                      type="button"
+                      className={view === "raw" ? "btn-secondary" : "btn-tertiary"}
+                      onClick={() => setView("raw")}
+                      aria-pressed={view === "raw"}
+                    >
+                      Raw
+                    </button>
+                  </div>
+                </div>
+                {result.error && <p className="schedule-error">{result.error}</p>}
+                <pre>{view === "pretty" ? formatToolResult(result.result) : result.raw}</pre>
+              </div>
+            )}
+          </div>
+
+          <footer className="connector-modal-footer">
+            <button type="button" className="btn-secondary" onClick={onClose}>
+              Close
+            </button>
+            <button
+              type="submit"
+              className="btn-primary"
+              disabled={!tool || invokeMutation.isPending}
+              data-testid="tool-playground-run"
+            >
+              {invokeMutation.isPending ? "Running…" : "Run tool"}
+            </button>
+          </footer>
+        </form>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,150 @@
+import { useMemo, useState } from "react";
+import { useQuery } from "@tanstack/react-query";
+import { fetchToolUsage } from "../../services/api";
+import { isToolAllowed, setToolAllowed, unusedAllowedTools, withoutUnusedTools } from "../../lib/toolAllowlist";
+import { Timestamp } from "../Timestamp";
+
+type ToolOption = {
+  name: string;
+  label: string;
+  source: string;
+};
+
+type ToolUsagePanelProps = {
+  agentId: number;
+  toolOptions: ToolOption[];
+  allowedTools: string[];
+  canEdit: boolean;
+  onChange: (next: string[]) => void;
+  onTry?: (toolName: string) => void;
+};
+
+type SortKey = "calls" | "name";
+
+/**
+ * Every available tool with how often this agent has called it, a per-tool
+ * switch and a bulk action to drop the ones it never uses.
+ */
+export function ToolUsagePanel({ agentId, toolOptions, allowedTools, canEdit, onChange, onTry }: ToolUsagePanelProps) {
+  const [sortKey, setSortKey] = useState<SortKey>("calls");
+  const { data, isLoading } = useQuery({
+    queryKey: ["agent", agentId, "tool-usage"],
+    queryFn: () => fetchToolUsage(agentId),
+  });
+
+  const usageByName = useMemo(() => new Map((data?.tools ?? []).map((stat) => [stat.name, stat])), [data]);
+  const usedNames = useMemo(() => new Set(usageByName.keys()), [usageByName]);
+  const available = useMemo(() => toolOptions.map((option) => option.name), [toolOptions]);
+  const unused = unusedAllowedTools(allowedTools, available, usedNames);
+
+  const rows = useMemo(() => {
+    const sorted = [...toolOptions];
+    sorted.sort((a, b) => {
+      if (sortKey === "calls") {
+        const diff = (usageByName.get(b.name)?.calls ?? 0) - (usageByName.get(a.name)?.calls ?? 0);
+        if (diff !== 0) return diff;
+      }
+      return a.name.localeCompare(b.name);
+    });
+    return sorted;
+  }, [toolOptions, usageByName, sortKey]);
+
+  const handleDisableUnused = () => {
+    const next = withoutUnusedTools(allowedTools, available, usedNames);
+    if (!next) return;
+    if (window.confirm(`Disable ${unused.length} tool${unused.length === 1 ? "" : "s"} this agent has never used?`)) {
+      onChange(next);
+    }
+  };
+
+  return (
+    <details className="advanced-tools tool-usage-panel" data-testid="tool-usage-panel">
+      <summary>Tool usage</summary>
+
+      <div className="tool-usage-toolbar">
+        <label>
+          <span>Sort by</span>
+          <select value={sortKey} onChange={(event) => setSortKey(event.target.value as SortKey)}>
+            <option value="calls">Most used</option>
+            <option value="name">Name</option>
+          </select>
+        </label>
+        {canEdit && (
+          <button
+            type="button"
+            className="btn-secondary"
+            onClick={handleDisableUnused}
+            disabled={unused.length === 0 || usedNames.size === 0}
+            title={usedNames.size === 0 ? "This agent has not called any tools yet" : undefined}
+            data-testid="disable-unused-tools"
+          >
+            Disable all unused tools ({unused.length})
+          </button>
+        )}
+      </div>
+
+      {allowedTools.length === 0 && (
+        <p className="muted">No allowlist set, so this agent can use every tool below.</p>
+      )}
+
+      {isLoading ? (
+        <p className="muted">Loading usage…</p>
+      ) : (
+        <table className="tool-usage-table">
+          <thead>
+            <tr>
+              <th>Tool</th>
+              <th>Calls</th>
+              <th>Last used</th>
+              <th>Enabled</th>
+              {onTry && <th aria-label="Actions" />}
+            </tr>
+          </thead>
+          <tbody>
+            {rows.map((option) => {
+              const stat = usageByName.get(option.name);
+              const enabled = isToolAllowed(option.name, allowedTools);
+              const id = `tool-usage-${option.name}`;
+              return (
+                <tr key={option.name} className={stat ? undefined : "tool-unused"}>
+                  <td>
+                    <label htmlFor={id}>{option.label}</label>
+                    {option.source !== "builtin" && <span className="tool-badge">{option.source}</span>}
+                  </td>
+                  <td>{stat?.calls ?? 0}</td>
+                  <td>
+                    <Timestamp value={stat?.last_used_at} fallback="Never" />
+                  </td>
+                  <td>
+                    <input
+                      id={id}
+                      type="checkbox"
+                      checked={enabled}
+                      disabled={!canEdit}
+                      onChange={(event) =>
+                        onChange(setToolAllowed(option.name, event.target.checked, allowedTools, available))
+                      }
+                    />
+                  </td>
+                  {onTry && (
+                    <td>
+                      <button
+                        type="button"
+                        className="btn-tertiary"
+                        onClick={() => onTry(option.name)}
+                        disabled={!enabled}
+                        title={enabled ? undefined : "Enable this tool to try it"}
+                      >
+                        Try
+                      </button>
+                    </td>
+                  )}
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      )}
+    </details>
+  );
+}
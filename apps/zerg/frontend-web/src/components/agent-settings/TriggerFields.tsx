@@ -0,0 +1,233 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import toast from "react-hot-toast";
+import {
+  fetchTriggerDeliveries,
+  rotateTriggerSecret,
+  type Trigger,
+  type TriggerDelivery,
+} from "../../services/api";
+import { copyToClipboard } from "../../lib/clipboard";
+import {
+  SAMPLE_PAYLOADS,
+  diagnoseTrigger,
+  parseSamplePayload,
+  type TriggerDiagnosis,
+} from "../../lib/triggerDiagnostics";
+import { Timestamp } from "../Timestamp";
+
+// Pieces shared by the trigger cards: signed-delivery fields, firing history and diagnostics
+
+function maskSecret(secret: string) {
+  return `${secret.slice(0, 4)}${"•".repeat(Math.max(0, secret.length - 4))}`;
+}
+
+export async function copyWithToast(text: string, label: string) {
+  try {
+    await copyToClipboard(text);
+    toast.success(`${label} copied`);
+  } catch {
+    toast.error(`Could not copy ${label.toLowerCase()}`, { duration: 6000 });
+  }
+}
+
+export function TriggerUrlField({ label, url }: { label: string; url: string }) {
+  return (
+    <div className="trigger-field">
+      <span className="trigger-field-label">{label}</span>
+      <code className="trigger-field-value">{url}</code>
+      <button type="button" className="btn-tertiary" onClick={() => copyWithToast(url, label)}>
+        Copy
+      </button>
+    </div>
+  );
+}
+
+/** The trigger's secret, masked until revealed, with copy and rotate. */
+export function TriggerSecretField({ trigger, label = "Signing secret" }: { trigger: Trigger; label?: string }) {
+  const queryClient = useQueryClient();
+  const [revealed, setRevealed] = useState(false);
+
+  const rotateMutation = useMutation({
+    mutationFn: () => rotateTriggerSecret(trigger.id),
+    onSuccess: () => {
+      toast.success(`${label} rotated`);
+      setRevealed(true);
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to rotate secret: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleRotate = () => {
+    if (window.confirm("Rotate the signing secret? Callers using the current secret will be rejected immediately.")) {
+      rotateMutation.mutate();
+    }
+  };
+
+  return (
+    <div className="trigger-field">
+      <span className="trigger-field-label">{label}</span>
+      <code className="trigger-field-value" data-testid="trigger-secret">
+        {revealed ? trigger.secret : maskSecret(trigger.secret)}
+      </code>
+      <button type="button" className="btn-tertiary" onClick={() => setRevealed((value) => !value)}>
+        {revealed ? "Hide" : "Reveal"}
+      </button>
+      <button type="button" className="btn-tertiary" onClick={() => copyWithToast(trigger.secret, "Secret")}>
+        Copy
+      </button>
+      <button type="button" className="btn-secondary" onClick={handleRotate} disabled={rotateMutation.isPending}>
+        {rotateMutation.isPending ? "Rotating…" : "Rotate"}
+      </button>
+    </div>
+  );
+}
+
+/** What a firing led to: the run's status when it started one, else the delivery result. */
+function firingOutcome(delivery: TriggerDelivery): string {
+  if (delivery.run_status) return delivery.run_status;
+  if (delivery.error) return delivery.error;
+  return delivery.status_code < 300 ? "Accepted" : "Rejected";
+}
+
+/**
+ * Recent firings, accepted or rejected, newest first: what each matched and
+ * the run it started.
+ */
+export function TriggerDeliveries({ triggerId, agentId }: { triggerId: number; agentId: number }) {
+  const { data: deliveries = [], isLoading } = useQuery({
+    queryKey: ["trigger-deliveries", triggerId],
+    queryFn: () => fetchTriggerDeliveries(triggerId),
+  });
+
+  return (
+    <div className="trigger-deliveries">
+      <h4>Recent firings</h4>
+      {isLoading ? (
+        <p className="muted">Loading…</p>
+      ) : deliveries.length === 0 ? (
+        <p className="muted">No firings yet.</p>
+      ) : (
+        <table data-testid="trigger-deliveries">
+          <thead>
+            <tr>
+              <th>Received</th>
+              <th>Status</th>
+              <th>Matched</th>
+              <th>Run</th>
+              <th>Outcome</th>
+            </tr>
+          </thead>
+          <tbody>
+            {deliveries.map((delivery) => (
+              <tr key={delivery.id}>
+                <td>
+                  <Timestamp value={delivery.received_at} />
+                </td>
+                <td>
+                  <span className={`delivery-status ${delivery.status_code < 300 ? "delivery-ok" : "delivery-failed"}`}>
+                    {delivery.status_code}
+                  </span>
+                </td>
+                <td>
+                  {delivery.is_test && <span className="delivery-test-badge">test</span>}
+                  {delivery.matched ?? (delivery.payload_bytes != null ? `${delivery.payload_bytes} bytes` : "—")}
+                </td>
+                <td>
+                  {delivery.run_id != null ? (
+                    <Link to={`/dashboard?agent=${agentId}&run=${delivery.run_id}`}>#{delivery.run_id}</Link>
+                  ) : (
+                    <span className="muted">No run</span>
+                  )}
+                </td>
+                <td className={delivery.error ? "delivery-failed" : undefined}>{firingOutcome(delivery)}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+/**
+ * "Why didn't it fire?": paste a sample event and see which of the trigger's
+ * conditions it passes, evaluated in the browser without running the agent.
+ */
+export function TriggerDiagnostics({ trigger }: { trigger: Trigger }) {
+  const [sampleText, setSampleText] = useState(() =>
+    JSON.stringify(SAMPLE_PAYLOADS[trigger.type] ?? SAMPLE_PAYLOADS.webhook, null, 2),
+  );
+  const githubEvents = Array.isArray(trigger.config?.events) ? (trigger.config.events as string[]) : [];
+  const [githubEvent, setGithubEvent] = useState(githubEvents[0] ?? "pull_request");
+  const [result, setResult] = useState<TriggerDiagnosis | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleCheck = () => {
+    const parsed = parseSamplePayload(sampleText);
+    if ("error" in parsed) {
+      setError(parsed.error);
+      setResult(null);
+      return;
+    }
+    setError(null);
+    setResult(diagnoseTrigger(trigger, parsed.sample, { githubEvent }));
+  };
+
+  return (
+    <details className="trigger-diagnostics" data-testid={`trigger-diagnostics-${trigger.id}`}>
+      <summary>Why didn&apos;t it fire?</summary>
+      <label htmlFor={`trigger-diagnostic-sample-${trigger.id}`}>Sample payload</label>
+      <textarea
+        id={`trigger-diagnostic-sample-${trigger.id}`}
+        value={sampleText}
+        onChange={(event) => {
+          setSampleText(event.target.value);
+          setResult(null);
+        }}
+        rows={6}
+        spellCheck={false}
+        aria-invalid={error ? true : undefined}
+      />
+      {trigger.type === "github" && (
+        <label className="trigger-diagnostic-event">
+          <span>X-GitHub-Event</span>
+          <select value={githubEvent} onChange={(event) => setGithubEvent(event.target.value)}>
+            {["issues", "pull_request", "push"].map((event) => (
+              <option key={event} value={event}>
+                {event}
+              </option>
+            ))}
+          </select>
+        </label>
+      )}
+      {error && (
+        <div className="schedule-error" role="alert">
+          {error}
+        </div>
+      )}
+      <button type="button" className="btn-secondary" onClick={handleCheck}>
+        Check sample
+      </button>
+
+      {result && (
+        <div className="trigger-diagnostic-result" data-testid="trigger-diagnostic-result">
+          <p className={result.fires ? "delivery-ok" : "delivery-failed"}>
+            {result.fires ? "This payload would fire the trigger." : "This payload would not fire the trigger."}
+          </p>
+          <ul>
+            {result.checks.map((check) => (
+              <li key={check.label} className={check.passed ? "diagnostic-pass" : "diagnostic-fail"}>
+                <span aria-hidden="true">{check.passed ? "✓" : "✗"}</span> {check.label}
+                {check.detail && <span className="muted"> — {check.detail}</span>}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+    </details>
+  );
+}
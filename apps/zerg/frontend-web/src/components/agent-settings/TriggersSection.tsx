@@ -0,0 +1,275 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import toast from "react-hot-toast";
+import {
+  createEmailTrigger,
+  createWebhookTrigger,
+  deleteTrigger,
+  fetchEmailConnectors,
+  fetchTriggers,
+  testTrigger,
+  triggerWebhookUrl,
+  type Agent,
+  type Trigger,
+} from "../../services/api";
+import { EmailTriggerCard } from "./EmailTriggerCard";
+import { GitHubTriggerCard, GitHubTriggerForm } from "./GitHubTriggerCard";
+import { SlackTriggerCard, SlackTriggerForm } from "./SlackTriggerCard";
+import { TriggerDeliveries, TriggerDiagnostics, TriggerSecretField, TriggerUrlField } from "./TriggerFields";
+import { useAccountConnectors } from "../../hooks/useAccountConnectors";
+import { isSlackAppConnected } from "../../lib/slackTrigger";
+import { isGitHubConnected } from "../../lib/githubTrigger";
+
+type TriggersSectionProps = {
+  agent: Agent;
+  canEdit: boolean;
+};
+
+const SAMPLE_PAYLOAD = JSON.stringify({ event: "test", message: "Hello from Zerg" }, null, 2);
+
+function WebhookTriggerCard({ trigger, canEdit }: { trigger: Trigger; canEdit: boolean }) {
+  const queryClient = useQueryClient();
+  const [payloadText, setPayloadText] = useState(SAMPLE_PAYLOAD);
+  const [payloadError, setPayloadError] = useState<string | null>(null);
+  const webhookUrl = triggerWebhookUrl(trigger.id);
+
+  const testMutation = useMutation({
+    mutationFn: (payload: Record<string, unknown>) => testTrigger(trigger.id, payload),
+    onSuccess: () => {
+      toast.success("Test event sent");
+    },
+    onError: (error: Error) => {
+      toast.error(`Test fire failed: ${error.message}`, { duration: 6000 });
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: ["trigger-deliveries", trigger.id] });
+    },
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: () => deleteTrigger(trigger.id),
+    onSuccess: () => {
+      toast.success("Trigger deleted");
+      queryClient.invalidateQueries({ queryKey: ["triggers", trigger.agent_id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to delete trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDelete = () => {
+    if (window.confirm("Delete this webhook trigger? Its URL will stop working.")) {
+      deleteMutation.mutate();
+    }
+  };
+
+  const handleTestFire = () => {
+    let payload: unknown;
+    try {
+      payload = payloadText.trim() ? JSON.parse(payloadText) : {};
+    } catch (error) {
+      setPayloadError(`Invalid JSON: ${(error as Error).message}`);
+      return;
+    }
+    if (payload === null || typeof payload !== "object" || Array.isArray(payload)) {
+      setPayloadError("Payload must be a JSON object");
+      return;
+    }
+    setPayloadError(null);
+    testMutation.mutate(payload as Record<string, unknown>);
+  };
+
+  return (
+    <div className="trigger-card" data-testid={`webhook-trigger-${trigger.id}`}>
+      <TriggerUrlField label="Webhook URL" url={webhookUrl} />
+
+      {canEdit && (
+        <>
+          <TriggerSecretField trigger={trigger} />
+
+          <details className="trigger-signing-help">
+            <summary>How to sign requests</summary>
+            <p>
+              Send a JSON body with an <code>X-Zerg-Timestamp</code> header (Unix seconds) and an{" "}
+              <code>X-Zerg-Signature</code> header holding the hex HMAC-SHA256 of{" "}
+              <code>{"{timestamp}.{body}"}</code>, keyed with the signing secret. The body is hashed as compact JSON
+              with sorted keys.
+            </p>
+          </details>
+
+          <div className="trigger-test-fire">
+            <label htmlFor={`trigger-test-payload-${trigger.id}`}>Sample payload</label>
+            <textarea
+              id={`trigger-test-payload-${trigger.id}`}
+              value={payloadText}
+              onChange={(event) => setPayloadText(event.target.value)}
+              rows={4}
+              spellCheck={false}
+              aria-invalid={payloadError ? true : undefined}
+            />
+            {payloadError && (
+              <div className="schedule-error" role="alert">
+                {payloadError}
+              </div>
+            )}
+            <button
+              type="button"
+              className="btn-primary"
+              onClick={handleTestFire}
+              disabled={testMutation.isPending}
+              data-testid={`test-trigger-${trigger.id}`}
+            >
+              {testMutation.isPending ? "Sending…" : "Send test event"}
+            </button>
+          </div>
+
+          <TriggerDeliveries triggerId={trigger.id} agentId={trigger.agent_id} />
+          <TriggerDiagnostics trigger={trigger} />
+
+          <button type="button" className="btn-tertiary" onClick={handleDelete} disabled={deleteMutation.isPending}>
+            Delete trigger
+          </button>
+        </>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Webhook triggers (the URL to call, its signing secret, recent deliveries and
+ * a test-fire button), Gmail triggers with their filter builder, Slack event
+ * triggers and GitHub repository triggers.
+ */
+export function TriggersSection({ agent, canEdit }: TriggersSectionProps) {
+  const queryClient = useQueryClient();
+  const { data: triggers = [], isLoading } = useQuery({
+    queryKey: ["triggers", agent.id],
+    queryFn: () => fetchTriggers(agent.id),
+  });
+  const webhooks = triggers.filter((trigger) => trigger.type === "webhook");
+  const emailTriggers = triggers.filter((trigger) => trigger.type === "email");
+  const { data: emailConnectors = [] } = useQuery({
+    queryKey: ["email-connectors"],
+    queryFn: fetchEmailConnectors,
+    enabled: canEdit,
+  });
+  const gmailConnector = emailConnectors.find((connector) => connector.provider === "gmail");
+  const slackTriggers = triggers.filter((trigger) => trigger.type === "slack");
+  const { data: accountConnectors = [] } = useAccountConnectors();
+  const slackConnected = isSlackAppConnected(accountConnectors);
+  const githubTriggers = triggers.filter((trigger) => trigger.type === "github");
+  const githubConnected = isGitHubConnected(accountConnectors);
+
+  const createMutation = useMutation({
+    mutationFn: () => createWebhookTrigger(agent.id),
+    onSuccess: () => {
+      toast.success("Webhook trigger created");
+      queryClient.invalidateQueries({ queryKey: ["triggers", agent.id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to create trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const createEmailMutation = useMutation({
+    mutationFn: (connectorId: number) => createEmailTrigger(agent.id, connectorId),
+    onSuccess: () => {
+      toast.success("Gmail trigger created");
+      queryClient.invalidateQueries({ queryKey: ["triggers", agent.id] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to create trigger: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <section className="agent-settings-section triggers-section">
+      <h3>Triggers</h3>
+      <p className="section-description">
+        Run this agent when an external service posts a signed event, a matching email arrives or something
+        happens in Slack or on GitHub.
+      </p>
+
+      <h4 className="triggers-subheading">Webhooks</h4>
+
+      {isLoading ? (
+        <p className="muted">Loading triggers…</p>
+      ) : webhooks.length === 0 ? (
+        <p className="muted">No webhook triggers.</p>
+      ) : (
+        webhooks.map((trigger) => <WebhookTriggerCard key={trigger.id} trigger={trigger} canEdit={canEdit} />)
+      )}
+
+      {canEdit && (
+        <button
+          type="button"
+          className="btn-secondary"
+          onClick={() => createMutation.mutate()}
+          disabled={createMutation.isPending}
+          data-testid="add-webhook-trigger"
+        >
+          {createMutation.isPending ? "Creating…" : "Add webhook trigger"}
+        </button>
+      )}
+
+      <h4 className="triggers-subheading">Gmail</h4>
+      {isLoading ? null : emailTriggers.length === 0 ? (
+        <p className="muted">No Gmail triggers.</p>
+      ) : (
+        emailTriggers.map((trigger) => <EmailTriggerCard key={trigger.id} trigger={trigger} canEdit={canEdit} />)
+      )}
+
+      {canEdit &&
+        (gmailConnector ? (
+          <button
+            type="button"
+            className="btn-secondary"
+            onClick={() => createEmailMutation.mutate(gmailConnector.id)}
+            disabled={createEmailMutation.isPending}
+            data-testid="add-email-trigger"
+          >
+            {createEmailMutation.isPending ? "Creating…" : "Add Gmail trigger"}
+          </button>
+        ) : (
+          <p className="muted">
+            <a href="/profile">Connect Gmail</a> in your profile to trigger this agent from incoming email.
+          </p>
+        ))}
+
+      <h4 className="triggers-subheading">Slack</h4>
+      {isLoading ? null : slackTriggers.length === 0 ? (
+        <p className="muted">No Slack triggers.</p>
+      ) : (
+        slackTriggers.map((trigger) => <SlackTriggerCard key={trigger.id} trigger={trigger} canEdit={canEdit} />)
+      )}
+
+      {canEdit &&
+        (slackConnected ? (
+          <SlackTriggerForm agentId={agent.id} />
+        ) : (
+          <p className="muted">
+            <Link to="/settings/integrations">Connect Slack</Link> to trigger this agent from Slack messages,
+            mentions and reactions.
+          </p>
+        ))}
+
+      <h4 className="triggers-subheading">GitHub</h4>
+      {isLoading ? null : githubTriggers.length === 0 ? (
+        <p className="muted">No GitHub triggers.</p>
+      ) : (
+        githubTriggers.map((trigger) => <GitHubTriggerCard key={trigger.id} trigger={trigger} canEdit={canEdit} />)
+      )}
+
+      {canEdit &&
+        (githubConnected ? (
+          <GitHubTriggerForm agentId={agent.id} />
+        ) : (
+          <p className="muted">
+            <Link to="/settings/integrations">Connect GitHub</Link> to trigger this agent from issues, pull requests
+            and pushes.
+          </p>
+        ))}
+    </section>
+  );
+}
@@ -0,0 +1,23 @@
+import type { DiffSegment } from "../../lib/wordDiff";
+
+/** Inline word diff: insertions and deletions highlighted in the running text. */
+export function WordDiff({ segments }: { segments: DiffSegment[] }) {
+  return (
+    <pre className="word-diff">
+      {segments.length === 0 && "(empty)"}
+      {segments.map((segment, index) =>
+        segment.type === "equal" ? (
+          <span key={index}>{segment.text}</span>
+        ) : segment.type === "added" ? (
+          <ins key={index} className="word-diff-added">
+            {segment.text}
+          </ins>
+        ) : (
+          <del key={index} className="word-diff-removed">
+            {segment.text}
+          </del>
+        )
+      )}
+    </pre>
+  );
+}
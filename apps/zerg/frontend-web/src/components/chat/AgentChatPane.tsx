@@ -0,0 +1,173 @@
+import { useEffect, useState, type FormEvent } from "react";
+import { useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { createThread } from "../../services/api";
+import { useChatData } from "../../hooks/chat/useChatData";
+import { useChatActions } from "../../hooks/chat/useChatActions";
+import { useThreadStreaming } from "../../hooks/chat/useThreadStreaming";
+import { useAuth } from "../../lib/auth";
+import { isImeComposing } from "../../lib/ime";
+import AgentSettingsDrawer from "../agent-settings/AgentSettingsDrawer";
+import { ChatMessageList } from "./ChatMessageList";
+
+// Title of the thread created when an agent has no chat yet
+const TEST_THREAD_TITLE = "Canvas test chat";
+
+export interface AgentChatOption {
+  agentId: number;
+  label: string;
+}
+
+interface AgentChatPaneProps {
+  agentId: number;
+  // Agents placed on the canvas; picking one selects its node
+  agentOptions: AgentChatOption[];
+  onSelectAgent: (agentId: number) => void;
+  onClose: () => void;
+}
+
+/**
+ * Chat with one agent next to the canvas, for trying out instruction changes
+ * without leaving the workflow. Uses the agent's most recent chat thread.
+ */
+export function AgentChatPane({ agentId, agentOptions, onSelectAgent, onClose }: AgentChatPaneProps) {
+  const queryClient = useQueryClient();
+  const { user } = useAuth();
+  const [threadId, setThreadId] = useState<number | null>(null);
+  const [draft, setDraft] = useState("");
+  const [isSettingsOpen, setIsSettingsOpen] = useState(false);
+  const [isCreatingThread, setIsCreatingThread] = useState(false);
+
+  const { agent, chatThreads, chatThreadsQuery, messages, hasOlderMessages, isLoadingOlder, loadOlderMessages } =
+    useChatData({ agentId, effectiveThreadId: threadId });
+  const { sendMutation, failedSends, retryFailedSend, discardFailedSend } = useChatActions({
+    agentId,
+    effectiveThreadId: threadId,
+  });
+  const { streamingMessages, streamingMessageId, pendingTokenBuffer, streamingToolOutputs, isAgentReplying } =
+    useThreadStreaming({ agentId, effectiveThreadId: threadId });
+
+  // Follow the agent's latest thread; switching agents starts over
+  useEffect(() => {
+    setThreadId(null);
+  }, [agentId]);
+
+  useEffect(() => {
+    if (threadId == null && chatThreads.length > 0) {
+      setThreadId(chatThreads[0].id);
+    }
+  }, [threadId, chatThreads]);
+
+  const ensureThread = async (): Promise<number | null> => {
+    if (threadId != null) return threadId;
+    setIsCreatingThread(true);
+    try {
+      const thread = await createThread(agentId, TEST_THREAD_TITLE);
+      await queryClient.invalidateQueries({ queryKey: ["threads", agentId, "chat"] });
+      setThreadId(thread.id);
+      return thread.id;
+    } catch (error) {
+      toast.error(`Failed to start a chat: ${(error as Error).message}`, { duration: 6000 });
+      return null;
+    } finally {
+      setIsCreatingThread(false);
+    }
+  };
+
+  const handleSend = async (event?: FormEvent) => {
+    event?.preventDefault();
+    const content = draft.trim();
+    if (!content || sendMutation.isPending || isAgentReplying) return;
+    const targetThreadId = await ensureThread();
+    if (targetThreadId == null) return;
+    setDraft("");
+    try {
+      await sendMutation.mutateAsync({ threadId: targetThreadId, content });
+    } catch {
+      // Error handling is done in the mutation's onError callback
+    }
+  };
+
+  const isBusy = sendMutation.isPending || isAgentReplying || isCreatingThread;
+
+  return (
+    <aside className="agent-chat-pane" aria-label="Agent chat" data-testid="agent-chat-pane">
+      <header className="agent-chat-pane-header">
+        <select
+          value={agentId}
+          onChange={(event) => onSelectAgent(Number(event.target.value))}
+          aria-label="Agent to chat with"
+          data-testid="agent-chat-pane-agent"
+        >
+          {!agentOptions.some((option) => option.agentId === agentId) && (
+            <option value={agentId}>{agent?.name ?? `Agent ${agentId}`}</option>
+          )}
+          {agentOptions.map((option) => (
+            <option key={option.agentId} value={option.agentId}>
+              {option.label}
+            </option>
+          ))}
+        </select>
+        <button
+          type="button"
+          className="agent-chat-pane-btn"
+          onClick={() => setIsSettingsOpen(true)}
+          title="Edit this agent's instructions"
+        >
+          Instructions
+        </button>
+        <button type="button" className="agent-chat-pane-btn" onClick={onClose} aria-label="Close chat">
+          ×
+        </button>
+      </header>
+
+      {chatThreadsQuery.isLoading ? (
+        <p className="muted agent-chat-pane-empty">Loading chat…</p>
+      ) : threadId == null ? (
+        <p className="muted agent-chat-pane-empty">Send a message to start a test chat with this agent.</p>
+      ) : (
+        <ChatMessageList
+          key={threadId}
+          messages={messages}
+          hasOlderMessages={hasOlderMessages}
+          isLoadingOlder={isLoadingOlder}
+          onLoadOlder={loadOlderMessages}
+          streamingMessages={streamingMessages}
+          streamingMessageId={streamingMessageId}
+          pendingTokenBuffer={pendingTokenBuffer}
+          streamingToolOutputs={streamingToolOutputs}
+          onCopyMessage={(message) => void navigator.clipboard?.writeText(message.content)}
+          currentUserId={user?.id ?? null}
+          failedSends={failedSends.filter((failure) => failure.threadId === threadId)}
+          onRetryFailed={retryFailedSend}
+          onDiscardFailed={discardFailedSend}
+          isBusy={isBusy}
+        />
+      )}
+
+      <form className="agent-chat-pane-composer" onSubmit={handleSend}>
+        <textarea
+          value={draft}
+          onChange={(event) => setDraft(event.target.value)}
+          onKeyDown={(event) => {
+            if (isImeComposing(event)) return;
+            if (event.key === "Enter" && !event.shiftKey) {
+              event.preventDefault();
+              void handleSend();
+            }
+          }}
+          placeholder={`Message ${agent?.name ?? "agent"}…`}
+          aria-label="Message"
+          data-testid="agent-chat-pane-input"
+        />
+        <button type="submit" className="primary" disabled={!draft.trim() || isBusy}>
+          Send
+        </button>
+      </form>
+
+      <AgentSettingsDrawer agentId={agentId} isOpen={isSettingsOpen} onClose={() => setIsSettingsOpen(false)} />
+    </aside>
+  );
+}
+
+export default AgentChatPane;
@@ -0,0 +1,95 @@
+import clsx from "clsx";
+import type { ChatAttachment } from "../../services/api";
+import type { PendingAttachment } from "../../hooks/chat/useChatAttachments";
+import { FileTextIcon } from "../icons";
+import { formatFileSize, isImageType } from "./chatUtils";
+
+interface PendingAttachmentChipsProps {
+  attachments: PendingAttachment[];
+  onRemove: (localId: string) => void;
+}
+
+// Staged files above the composer input, with upload progress
+export function PendingAttachmentChips({ attachments, onRemove }: PendingAttachmentChipsProps) {
+  if (attachments.length === 0) return null;
+
+  return (
+    <ul className="composer-attachments" aria-label="Attachments">
+      {attachments.map((item) => (
+        <li
+          key={item.localId}
+          className={clsx("attachment-chip", `attachment-chip--${item.status}`)}
+          title={item.error ?? item.file.name}
+        >
+          {item.previewUrl ? (
+            <img className="attachment-chip-thumb" src={item.previewUrl} alt="" />
+          ) : (
+            <FileTextIcon width={14} height={14} />
+          )}
+          <span className="attachment-chip-name">{item.file.name}</span>
+          <span className="attachment-chip-meta">
+            {item.status === "uploading"
+              ? `${Math.round(item.progress * 100)}%`
+              : item.status === "error"
+                ? "Failed"
+                : formatFileSize(item.file.size)}
+          </span>
+          <button
+            type="button"
+            className="attachment-chip-remove"
+            onClick={() => onRemove(item.localId)}
+            aria-label={`Remove ${item.file.name}`}
+          >
+            ✕
+          </button>
+          {item.status === "uploading" && (
+            <span
+              className="attachment-chip-progress"
+              style={{ width: `${Math.round(item.progress * 100)}%` }}
+              role="progressbar"
+              aria-valuemin={0}
+              aria-valuemax={100}
+              aria-valuenow={Math.round(item.progress * 100)}
+            />
+          )}
+        </li>
+      ))}
+    </ul>
+  );
+}
+
+// Attachments on a sent message: thumbnails for images, chips for everything else
+export function MessageAttachments({ attachments }: { attachments?: ChatAttachment[] | null }) {
+  if (!attachments || attachments.length === 0) return null;
+
+  const images = attachments.filter((att) => isImageType(att.content_type));
+  const files = attachments.filter((att) => !isImageType(att.content_type));
+
+  return (
+    <div className="message-attachments">
+      {images.length > 0 && (
+        <div className="message-attachment-images">
+          {images.map((att) => (
+            <a key={att.id} href={att.url} target="_blank" rel="noopener noreferrer" title={att.filename}>
+              <img className="message-attachment-thumb" src={att.url} alt={att.filename} loading="lazy" />
+            </a>
+          ))}
+        </div>
+      )}
+      {files.map((att) => (
+        <a
+          key={att.id}
+          className="attachment-chip attachment-chip--ready"
+          href={att.url}
+          target="_blank"
+          rel="noopener noreferrer"
+          download={att.filename}
+        >
+          <FileTextIcon width={14} height={14} />
+          <span className="attachment-chip-name">{att.filename}</span>
+          <span className="attachment-chip-meta">{formatFileSize(att.size)}</span>
+        </a>
+      ))}
+    </div>
+  );
+}
@@ -1,7 +1,12 @@
-import { type FormEvent, useRef, useEffect } from "react";
+import { type ClipboardEvent, type DragEvent, type FormEvent, useRef, useEffect, useState } from "react";
 import clsx from "clsx";
 import { Workflow } from "../../services/api";
-import { WrenchIcon, FileTextIcon } from "../icons";
+import type { PendingAttachment } from "../../hooks/chat/useChatAttachments";
+import { isImeComposing } from "../../lib/ime";
+import { WrenchIcon, FileTextIcon, PaperclipIcon } from "../icons";
+import { PendingAttachmentChips } from "./ChatAttachments";
+
+const ACCEPTED_FILE_TYPES = "image/png,image/jpeg,image/webp,image/gif,application/pdf,application/json,text/plain,text/markdown,text/csv,.md";
 
 interface ChatComposerProps {
   draft: string;
@@ -21,6 +26,17 @@ interface ChatComposerProps {
   isExecutingWorkflow: boolean;
   messagesCount: number;
   onExportChat: () => void;
+  attachments: PendingAttachment[];
+  isUploading: boolean;
+  onAddFiles: (files: File[]) => void;
+  onRemoveAttachment: (localId: string) => void;
+  // Set while the agent answers a message from another participant
+  replyPendingNotice?: string | null;
+  // One agent reply at a time per thread
+  isAgentReplying?: boolean;
+  // The draft text came from local storage rather than this visit
+  draftRestored?: boolean;
+  onDiscardDraft?: () => void;
 }
 
 export function ChatComposer({
@@ -38,8 +54,28 @@ export function ChatComposer({
   isExecutingWorkflow,
   messagesCount,
   onExportChat,
+  attachments,
+  isUploading,
+  onAddFiles,
+  onRemoveAttachment,
+  replyPendingNotice = null,
+  isAgentReplying = false,
+  draftRestored = false,
+  onDiscardDraft,
 }: ChatComposerProps) {
   const textareaRef = useRef<HTMLTextAreaElement>(null);
+  // Tracked separately from the event flags because some browsers clear them early
+  const isComposingRef = useRef(false);
+  const fileInputRef = useRef<HTMLInputElement>(null);
+  const [isDragOver, setIsDragOver] = useState(false);
+
+  const hasReadyAttachment = attachments.some((item) => item.status === "ready");
+  const canSend =
+    Boolean(effectiveThreadId) &&
+    !isSending &&
+    !isAgentReplying &&
+    !isUploading &&
+    (draft.trim().length > 0 || hasReadyAttachment);
 
   // Auto-resize textarea
   useEffect(() => {
@@ -50,6 +86,10 @@ export function ChatComposer({
   }, [draft]);
 
   const handleKeyDown = (e: React.KeyboardEvent) => {
+    // Enter while composing confirms the IME candidate; it must not send
+    if (isComposingRef.current || isImeComposing(e)) {
+      return;
+    }
     if (e.key === "Enter" && !e.shiftKey) {
       e.preventDefault();
       // Submit form
@@ -57,10 +97,78 @@ export function ChatComposer({
     }
   };
 
+  const handleDragOver = (e: DragEvent) => {
+    if (!effectiveThreadId || !e.dataTransfer.types.includes("Files")) return;
+    e.preventDefault();
+    setIsDragOver(true);
+  };
+
+  const handleDrop = (e: DragEvent) => {
+    setIsDragOver(false);
+    if (!effectiveThreadId || e.dataTransfer.files.length === 0) return;
+    e.preventDefault();
+    onAddFiles(Array.from(e.dataTransfer.files));
+  };
+
+  // Pasted screenshots arrive as files on the clipboard
+  const handlePaste = (e: ClipboardEvent) => {
+    const files = Array.from(e.clipboardData.files);
+    if (files.length > 0) {
+      e.preventDefault();
+      onAddFiles(files);
+    }
+  };
+
   return (
-    <div className="chat-composer-container">
+    <div
+      className={clsx("chat-composer-container", { "drag-over": isDragOver })}
+      onDragOver={handleDragOver}
+      onDragLeave={(e) => {
+        if (!e.currentTarget.contains(e.relatedTarget as Node | null)) setIsDragOver(false);
+      }}
+      onDrop={handleDrop}
+    >
+      {replyPendingNotice && (
+        <div className="chat-reply-pending" role="status" data-testid="reply-pending-notice">
+          {replyPendingNotice}
+        </div>
+      )}
+      {draftRestored && (
+        <div className="chat-draft-restored" role="status" data-testid="draft-restored">
+          <span>Draft restored</span>
+          {onDiscardDraft && (
+            <button type="button" onClick={onDiscardDraft}>
+              Discard
+            </button>
+          )}
+        </div>
+      )}
+      <PendingAttachmentChips attachments={attachments} onRemove={onRemoveAttachment} />
       <form className="chat-input-form" onSubmit={onSend}>
         <div className="chat-input-tools-left">
+          <button
+            type="button"
+            className="icon-tool-btn"
+            onClick={() => fileInputRef.current?.click()}
+            disabled={!effectiveThreadId}
+            title="Attach files"
+            data-testid="attach-file-btn"
+          >
+            <PaperclipIcon width={18} height={18} />
+          </button>
+          <input
+            ref={fileInputRef}
+            type="file"
+            multiple
+            accept={ACCEPTED_FILE_TYPES}
+            hidden
+            data-testid="attach-file-input"
+            onChange={(evt) => {
+              onAddFiles(Array.from(evt.target.files ?? []));
+              // Allow picking the same file again after removing it
+              evt.target.value = "";
+            }}
+          />
           <button
             type="button"
             className="icon-tool-btn"
@@ -74,7 +182,7 @@ export function ChatComposer({
             className="icon-tool-btn"
             onClick={onExportChat}
             disabled={messagesCount === 0}
-            title="Export Chat History"
+            title="Export thread as Markdown"
           >
             <FileTextIcon width={18} height={18} />
           </button>
@@ -90,12 +198,19 @@ export function ChatComposer({
             data-testid="chat-input"
             disabled={!effectiveThreadId}
             onKeyDown={handleKeyDown}
+            onCompositionStart={() => {
+              isComposingRef.current = true;
+            }}
+            onCompositionEnd={() => {
+              isComposingRef.current = false;
+            }}
+            onPaste={handlePaste}
             rows={1}
           />
           <button
             type="submit"
             className={clsx("chat-send-btn", { disabled: !effectiveThreadId })}
-            disabled={isSending || !draft.trim() || !effectiveThreadId}
+            disabled={!canSend}
             data-testid="send-message-btn"
             title="Send Message"
           >
@@ -107,6 +222,8 @@ export function ChatComposer({
         </div>
       </form>
 
+      {isDragOver && <div className="composer-drop-hint">Drop files to attach</div>}
+
       {/* Workflow Execution Panel */}
       {showWorkflowPanel && (
         <div className="workflow-panel-popover">
@@ -1,77 +1,302 @@
-import { useEffect, useRef, useState } from "react";
+import { useEffect, useLayoutEffect, useRef, useState } from "react";
 import clsx from "clsx";
-import ReactMarkdown from 'react-markdown';
-import remarkGfm from 'remark-gfm';
-import { SyntaxHighlighter, oneDark } from '../../lib/syntaxHighlighter';
-import { ThreadMessage } from "../../services/api";
-import { formatTimestamp } from "./chatUtils";
+import { MESSAGE_REACTIONS, ThreadMessage, type MessageAuthor, type MessageUsage } from "../../services/api";
+import type { FailedSend } from "../../hooks/chat/useChatActions";
+import { MessageAttachments } from "./ChatAttachments";
+import { isImeComposing } from "../../lib/ime";
+import { formatCostUsd, formatLatency, formatTokenCount } from "./chatUtils";
+import Timestamp from "../Timestamp";
+import { MarkdownMessage } from "./MarkdownMessage";
 import { ToolMessage } from "./ToolMessage";
+import { extractToolInvocations, type StreamedToolOutput } from "./toolCalls";
+import {
+  resolveWindow,
+  revealNewer,
+  revealOlder,
+  TAIL_WINDOW,
+  windowAround,
+  type MessageWindow,
+} from "./messageWindow";
+
+// Sentinels start growing the window this far before they scroll into view
+const SENTINEL_MARGIN = "400px 0px";
+// How long a message jumped to from the saved list stays highlighted
+const FOCUS_HIGHLIGHT_MS = 2000;
+
+// Tokens, model, latency and cost of the run that produced a reply
+function MessageUsageFooter({ usage }: { usage: MessageUsage }) {
+  const parts = [
+    usage.total_tokens != null ? `${formatTokenCount(usage.total_tokens)} tokens` : null,
+    usage.model ?? null,
+    usage.duration_ms != null ? formatLatency(usage.duration_ms) : null,
+    usage.cost_usd != null ? formatCostUsd(usage.cost_usd) : null,
+  ].filter((part): part is string => part != null);
+  if (parts.length === 0) return null;
+
+  const title =
+    usage.prompt_tokens != null && usage.completion_tokens != null
+      ? `${usage.prompt_tokens} prompt + ${usage.completion_tokens} completion tokens`
+      : undefined;
+  return (
+    <div className="message-usage" data-testid="message-usage" title={title}>
+      {parts.join(" · ")}
+    </div>
+  );
+}
 
 interface ChatMessageListProps {
   messages: ThreadMessage[];
   streamingMessages: Map<number, string>;
   streamingMessageId: number | null;
   pendingTokenBuffer: string;
+  // Tool results received over the websocket for the active run
+  streamingToolOutputs?: StreamedToolOutput[];
   onCopyMessage: (message: ThreadMessage) => void;
+  failedSends?: FailedSend[];
+  onRetryFailed?: (key: number) => void;
+  onDiscardFailed?: (key: number) => void;
+  onEditMessage?: (message: ThreadMessage, content: string) => void;
+  onRegenerate?: () => void;
+  // Disables edit/regenerate while a send or run is in flight
+  isBusy?: boolean;
+  // Used to label the viewer's own messages in shared threads
+  currentUserId?: number | null;
+  // Older history still on the server, fetched when the top of the list is reached
+  hasOlderMessages?: boolean;
+  isLoadingOlder?: boolean;
+  onLoadOlder?: () => void;
+  onToggleBookmark?: (message: ThreadMessage) => void;
+  onToggleReaction?: (message: ThreadMessage, emoji: string) => void;
+  // Scrolled into view and highlighted, paging in older history if needed
+  focusMessageId?: number | null;
+  onFocusHandled?: () => void;
 }
 
-// Custom Code Block Component with Copy Button
-const CodeBlock = ({ inline, className, children, ...props }: any) => {
-  const [copied, setCopied] = useState(false);
-  const match = /language-(\w+)/.exec(className || '');
-
-  if (inline) {
-    return <code className={className} {...props}>{children}</code>;
-  }
-
-  const codeString = String(children).replace(/\n$/, '');
-
-  const handleCopy = () => {
-    navigator.clipboard.writeText(codeString);
-    setCopied(true);
-    setTimeout(() => setCopied(false), 2000);
-  };
-
+function MessageReactions({
+  message,
+  onToggle,
+}: {
+  message: ThreadMessage;
+  onToggle?: (message: ThreadMessage, emoji: string) => void;
+}) {
+  const reactions = message.reactions ?? [];
+  if (reactions.length === 0) return null;
   return (
-    <div className="code-block-wrapper">
-      <div className="code-block-header">
-        <span className="code-language">{match ? match[1] : 'text'}</span>
+    <div className="message-reactions" data-testid="message-reactions">
+      {reactions.map((reaction) => (
         <button
-          className="code-copy-btn"
-          onClick={handleCopy}
-          title="Copy code"
+          key={reaction.emoji}
+          type="button"
+          className={clsx("message-reaction", { "message-reaction--mine": reaction.reacted })}
+          onClick={() => onToggle?.(message, reaction.emoji)}
+          disabled={!onToggle}
+          aria-pressed={reaction.reacted}
+          title={reaction.reacted ? "Remove your reaction" : "React"}
         >
-          {copied ? '✓ Copied' : '📋 Copy'}
+          {reaction.emoji} {reaction.count}
         </button>
-      </div>
-      <SyntaxHighlighter
-        style={oneDark}
-        language={match ? match[1] : 'text'}
-        PreTag="div"
-        customStyle={{ margin: 0, borderTopLeftRadius: 0, borderTopRightRadius: 0 }}
-        {...props}
-      >
-        {codeString}
-      </SyntaxHighlighter>
+      ))}
+    </div>
+  );
+}
+
+function MessageAuthorBadge({ author, isSelf }: { author: MessageAuthor; isSelf: boolean }) {
+  const name = author.display_name || `User ${author.id}`;
+  const initials = name
+    .split(/\s+/)
+    .map((part) => part[0])
+    .join("")
+    .slice(0, 2)
+    .toUpperCase();
+  return (
+    <div className="message-author" data-testid="message-author">
+      <span className="message-author-avatar" aria-hidden="true">
+        {author.avatar_url ? <img src={author.avatar_url} alt="" /> : initials}
+      </span>
+      <span className="message-author-name">{isSelf ? "You" : name}</span>
     </div>
   );
-};
+}
 
 export function ChatMessageList({
   messages,
   streamingMessages,
   streamingMessageId,
   pendingTokenBuffer,
+  streamingToolOutputs = [],
   onCopyMessage,
+  failedSends = [],
+  onRetryFailed,
+  onDiscardFailed,
+  onEditMessage,
+  onRegenerate,
+  isBusy = false,
+  currentUserId = null,
+  hasOlderMessages = false,
+  isLoadingOlder = false,
+  onLoadOlder,
+  onToggleBookmark,
+  onToggleReaction,
+  focusMessageId = null,
+  onFocusHandled,
 }: ChatMessageListProps) {
   const messagesContainerRef = useRef<HTMLDivElement>(null);
+  const topSentinelRef = useRef<HTMLDivElement>(null);
+  const bottomSentinelRef = useRef<HTMLDivElement>(null);
+  const [messageWindow, setMessageWindow] = useState<MessageWindow>(TAIL_WINDOW);
+  const [edgesInView, setEdgesInView] = useState({ top: false, bottom: false });
+  // Message that must stay put on screen while the window changes above or below it
+  const scrollAnchorRef = useRef<{ id: number; offset: number } | null>(null);
+  const [editingMessageId, setEditingMessageId] = useState<number | null>(null);
+  const [editingContent, setEditingContent] = useState("");
+  const [reactionPickerId, setReactionPickerId] = useState<number | null>(null);
+  const [highlightedId, setHighlightedId] = useState<number | null>(null);
+
+  const failedByMessageId = new Map(
+    failedSends.filter((failure) => failure.messageId != null).map((failure) => [failure.messageId!, failure])
+  );
+  const unsentFailures = failedSends.filter((failure) => failure.messageId == null);
+
+  // Authors are only worth showing once someone other than the viewer writes
+  const authorIds = new Set(messages.map((msg) => msg.author?.id).filter((id): id is number => id != null));
+  const showAuthors = authorIds.size > 1 || [...authorIds].some((id) => id !== currentUserId);
+
+  // Only the newest assistant reply can be regenerated
+  const visibleMessages = messages.filter((msg) => msg.role !== "system" && msg.role !== "tool");
+  const lastVisible = visibleMessages[visibleMessages.length - 1];
+  const regenerableMessageId = lastVisible?.role === "assistant" && !streamingMessageId ? lastVisible.id : null;
+
+  // Only a window of the thread is mounted, so 5k-message threads stay cheap to render
+  const visibleIds = visibleMessages.map((msg) => msg.id);
+  const range = resolveWindow(visibleIds, messageWindow);
+  const renderedMessages = visibleMessages.slice(range.start, range.end);
+  const canShowOlder = range.start > 0 || hasOlderMessages;
+
+  const captureScrollAnchor = (id: number | undefined) => {
+    const container = messagesContainerRef.current;
+    const element = id === undefined ? null : container?.querySelector(`[data-message-id="${id}"]`);
+    if (!container || !element || id === undefined) return;
+    scrollAnchorRef.current = {
+      id,
+      offset: element.getBoundingClientRect().top - container.getBoundingClientRect().top,
+    };
+  };
+
+  const showOlder = () => {
+    if (range.start > 0) {
+      captureScrollAnchor(visibleIds[range.start]);
+      setMessageWindow((current) => revealOlder(visibleIds, current));
+    } else if (hasOlderMessages && !isLoadingOlder) {
+      // Prepended pages don't shift the window (it is anchored by id); the next pass reveals them
+      onLoadOlder?.();
+    }
+  };
+
+  const showNewer = () => {
+    captureScrollAnchor(visibleIds[range.end - 1]);
+    setMessageWindow((current) => revealNewer(visibleIds, current));
+  };
+
+  // Keep the anchored message where it was after rows mount or unmount around it
+  useLayoutEffect(() => {
+    const anchor = scrollAnchorRef.current;
+    const container = messagesContainerRef.current;
+    if (!anchor || !container) return;
+    scrollAnchorRef.current = null;
+    const element = container.querySelector(`[data-message-id="${anchor.id}"]`);
+    if (!element) return;
+    const offset = element.getBoundingClientRect().top - container.getBoundingClientRect().top;
+    container.scrollTop += offset - anchor.offset;
+  });
+
+  useEffect(() => {
+    const container = messagesContainerRef.current;
+    if (!container || typeof IntersectionObserver === "undefined") return;
+    const observer = new IntersectionObserver(
+      (entries) => {
+        setEdgesInView((current) => {
+          const next = { ...current };
+          entries.forEach((entry) => {
+            if (entry.target === topSentinelRef.current) next.top = entry.isIntersecting;
+            if (entry.target === bottomSentinelRef.current) next.bottom = entry.isIntersecting;
+          });
+          return next.top === current.top && next.bottom === current.bottom ? current : next;
+        });
+      },
+      { root: container, rootMargin: SENTINEL_MARGIN }
+    );
+    [topSentinelRef.current, bottomSentinelRef.current].forEach((sentinel) => sentinel && observer.observe(sentinel));
+    return () => observer.disconnect();
+  }, [messageWindow.atTail]);
+
+  // Re-run after every change so a sentinel that stays in view keeps growing the window
+  useEffect(() => {
+    if (edgesInView.top && canShowOlder) showOlder();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [edgesInView.top, range.start, hasOlderMessages, isLoadingOlder]);
 
-  // Scroll to bottom when messages change
   useEffect(() => {
-    if (messagesContainerRef.current) {
+    if (edgesInView.bottom && !messageWindow.atTail) showNewer();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [edgesInView.bottom, range.end, messageWindow.atTail]);
+
+  // Jump to a saved message: page older history in until it is loaded, then
+  // move the window onto it, scroll it into view and flash it
+  useEffect(() => {
+    if (focusMessageId == null) return;
+    const focusIndex = visibleIds.indexOf(focusMessageId);
+    if (focusIndex < 0) {
+      if (hasOlderMessages) {
+        if (!isLoadingOlder) onLoadOlder?.();
+      } else if (messages.length > 0) {
+        onFocusHandled?.();
+      }
+      return;
+    }
+    if (focusIndex < range.start || focusIndex >= range.end) {
+      setMessageWindow(windowAround(visibleIds, focusMessageId));
+      return;
+    }
+    const element = messagesContainerRef.current?.querySelector(`[data-message-id="${focusMessageId}"]`);
+    element?.scrollIntoView?.({ block: "center" });
+    setHighlightedId(focusMessageId);
+    onFocusHandled?.();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [focusMessageId, visibleIds.length, range.start, range.end, hasOlderMessages, isLoadingOlder]);
+
+  useEffect(() => {
+    if (highlightedId == null) return;
+    const timer = setTimeout(() => setHighlightedId(null), FOCUS_HIGHLIGHT_MS);
+    return () => clearTimeout(timer);
+  }, [highlightedId]);
+
+  const startEditing = (message: ThreadMessage) => {
+    setEditingMessageId(message.id);
+    setEditingContent(message.content);
+  };
+
+  const cancelEditing = () => {
+    setEditingMessageId(null);
+    setEditingContent("");
+  };
+
+  const submitEdit = (message: ThreadMessage) => {
+    const trimmed = editingContent.trim();
+    if (!trimmed || trimmed === message.content.trim()) {
+      cancelEditing();
+      return;
+    }
+    onEditMessage?.(message, trimmed);
+    cancelEditing();
+  };
+
+  // Scroll to bottom when a message arrives or streams, unless the user is reading further up
+  const tailMessageId = lastVisible?.id;
+  useEffect(() => {
+    if (messagesContainerRef.current && messageWindow.atTail && focusMessageId == null) {
       messagesContainerRef.current.scrollTop = messagesContainerRef.current.scrollHeight;
     }
-  }, [messages, pendingTokenBuffer, streamingMessages]);
+  }, [tailMessageId, pendingTokenBuffer, streamingMessages, failedSends, messageWindow.atTail]);
 
   // Group tool messages by parent_id for rendering under assistant messages
   const toolMessagesByParent = new Map<number, ThreadMessage[]>();
@@ -86,87 +311,315 @@ export function ChatMessageList({
   // Get orphaned tool messages (no parent_id)
   const orphanedToolMessages = messages.filter(m => m.role === "tool" && m.parent_id == null);
 
+  // Streamed results are dropped once the persisted tool message shows up
+  const persistedToolIds = new Set(messages.filter((m) => m.role === "tool").map((m) => m.id));
+  const liveToolOutputs = streamingToolOutputs.filter(
+    (output) => output.messageId == null || !persistedToolIds.has(output.messageId)
+  );
+  const isRunActive = streamingMessageId != null || isBusy;
+
+  // Pair each requested tool call with its result; results without a matching
+  // call (older rows, or ids the model didn't echo) are listed after them
+  const renderToolCalls = (msg: ThreadMessage) => {
+    const results = toolMessagesByParent.get(msg.id) ?? [];
+    const liveResults = liveToolOutputs.filter((output) => output.parentId === msg.id);
+    const invocations = extractToolInvocations(msg);
+    const matchedResultIds = new Set<number>();
+    const matchedLiveIds = new Set<string>();
+
+    const bubbles = invocations.map((invocation) => {
+      const result = results.find((toolMsg) => toolMsg.tool_call_id === invocation.id);
+      const live = result ? undefined : liveResults.find((output) => output.toolCallId === invocation.id);
+      if (result) matchedResultIds.add(result.id);
+      if (live?.toolCallId) matchedLiveIds.add(live.toolCallId);
+      return (
+        <ToolMessage
+          key={`call-${invocation.id}`}
+          toolName={invocation.name}
+          toolCallId={invocation.id}
+          args={invocation.args}
+          output={result?.content ?? live?.content ?? null}
+          isRunning={isRunActive}
+        />
+      );
+    });
+
+    results
+      .filter((toolMsg) => !matchedResultIds.has(toolMsg.id))
+      .forEach((toolMsg) => {
+        bubbles.push(
+          <ToolMessage
+            key={toolMsg.id}
+            toolName={toolMsg.tool_name || toolMsg.name || "tool"}
+            toolCallId={toolMsg.tool_call_id}
+            output={toolMsg.content}
+          />
+        );
+      });
+    liveResults
+      .filter((output) => !output.toolCallId || !matchedLiveIds.has(output.toolCallId))
+      .forEach((output, index) => {
+        bubbles.push(
+          <ToolMessage
+            key={`live-${output.toolCallId ?? index}`}
+            toolName={output.toolName || "tool"}
+            toolCallId={output.toolCallId}
+            output={output.content}
+          />
+        );
+      });
+
+    return bubbles;
+  };
+
   return (
     <section className="conversation-area">
       <div className="messages-container" data-testid="messages-container" ref={messagesContainerRef}>
-        {messages
-          .filter(msg => msg.role !== "system" && msg.role !== "tool")
-          .map((msg, index) => {
-            const isLastUserMessage = msg.role === "user" && index === messages.length - 1;
-            const toolMessages = toolMessagesByParent.get(msg.id);
-
-            // Check if this message is currently streaming
-            const streamingContent = streamingMessages.get(msg.id);
-            const isStreaming = streamingMessageId === msg.id && streamingContent !== undefined;
-            const displayContent = streamingContent !== undefined ? streamingContent : msg.content;
-
-            // Skip rendering empty assistant messages (they only have tool calls)
-            if (msg.role === "assistant" && msg.content.trim() === "" && !isStreaming) {
-              return (
-                <div key={msg.id}>
-                  {toolMessages?.map(toolMsg => (
-                    <ToolMessage key={toolMsg.id} message={toolMsg} />
-                  ))}
-                </div>
-              );
-            }
+        <div className="messages-sentinel" ref={topSentinelRef}>
+          {canShowOlder && (
+            <button
+              type="button"
+              className="messages-load-older"
+              onClick={showOlder}
+              disabled={range.start === 0 && isLoadingOlder}
+              data-testid="messages-load-older"
+            >
+              {range.start === 0 && isLoadingOlder ? "Loading earlier messages…" : "Show earlier messages"}
+            </button>
+          )}
+        </div>
+        {renderedMessages.map((msg, index) => {
+          const isLastUserMessage = msg.role === "user" && range.start + index === messages.length - 1;
+
+          // Check if this message is currently streaming
+          const streamingContent = streamingMessages.get(msg.id);
+          const isStreaming = streamingMessageId === msg.id && streamingContent !== undefined;
+          const displayContent = streamingContent !== undefined ? streamingContent : msg.content;
 
+          // Skip rendering empty assistant messages (they only have tool calls)
+          if (msg.role === "assistant" && msg.content.trim() === "" && !isStreaming) {
             return (
-              <div key={msg.id}>
-                <div className="chat-row">
-                  <article
-                    className={clsx("message", {
-                      "user-message": msg.role === "user",
-                      "assistant-message": msg.role === "assistant",
-                      "streaming": isStreaming,
-                    })}
-                    data-testid={isLastUserMessage ? "chat-message" : undefined}
-                    data-role={`chat-message-${msg.role}`}
-                    data-streaming={isStreaming ? "true" : undefined}
-                  >
-                    <div className="message-content">
-                      {msg.role === "assistant" ? (
-                        <>
-                          <ReactMarkdown
-                            remarkPlugins={[remarkGfm]}
-                            components={{
-                              code: CodeBlock
-                            }}
-                          >
-                            {displayContent || ""}
-                          </ReactMarkdown>
-                          {isStreaming && <span className="streaming-cursor">▋</span>}
-                        </>
-                      ) : (
-                         // User messages rendered as plain text but preserving whitespace
-                        <div className="preserve-whitespace">
-                           {displayContent || msg.content}
+              <div key={msg.id} data-message-id={msg.id}>
+                {renderToolCalls(msg)}
+              </div>
+            );
+          }
+
+          const failure = failedByMessageId.get(msg.id);
+          const isEditing = editingMessageId === msg.id;
+
+          return (
+            <div key={msg.id} data-message-id={msg.id}>
+              <div className="chat-row">
+                <article
+                  className={clsx("message", {
+                    "user-message": msg.role === "user",
+                    "assistant-message": msg.role === "assistant",
+                    "streaming": isStreaming,
+                    "message--failed": failure != null,
+                    "message--highlighted": highlightedId === msg.id,
+                    "message--bookmarked": msg.bookmarked,
+                  })}
+                  data-testid={isLastUserMessage ? "chat-message" : undefined}
+                  data-role={`chat-message-${msg.role}`}
+                  data-streaming={isStreaming ? "true" : undefined}
+                >
+                  {showAuthors && msg.role === "user" && msg.author && (
+                    <MessageAuthorBadge author={msg.author} isSelf={msg.author.id === currentUserId} />
+                  )}
+                  <div className="message-content">
+                    {msg.role === "assistant" ? (
+                      <>
+                        <MarkdownMessage content={displayContent || ""} isStreaming={isStreaming} />
+                        {isStreaming && <span className="streaming-cursor">▋</span>}
+                      </>
+                    ) : isEditing ? (
+                      <div className="message-edit">
+                        <textarea
+                          className="message-edit-input"
+                          value={editingContent}
+                          onChange={(event) => setEditingContent(event.target.value)}
+                          onKeyDown={(event) => {
+                            if (isImeComposing(event)) return;
+                            if (event.key === "Escape") {
+                              event.preventDefault();
+                              cancelEditing();
+                            } else if (event.key === "Enter" && (event.metaKey || event.ctrlKey)) {
+                              event.preventDefault();
+                              submitEdit(msg);
+                            }
+                          }}
+                          aria-label="Edit message"
+                          autoFocus
+                        />
+                        <div className="message-edit-actions">
+                          <button type="button" onClick={cancelEditing}>
+                            Cancel
+                          </button>
+                          <button type="button" className="primary" onClick={() => submitEdit(msg)}>
+                            Save &amp; resend
+                          </button>
                         </div>
-                      )}
+                        <div className="message-edit-hint">Saving starts a new thread from this message.</div>
+                      </div>
+                    ) : (
+                       // User messages rendered as plain text but preserving whitespace
+                      <>
+                        {(displayContent || msg.content) && (
+                          <div className="preserve-whitespace">
+                             {displayContent || msg.content}
+                          </div>
+                        )}
+                        <MessageAttachments attachments={msg.attachments} />
+                      </>
+                    )}
+                  </div>
+                  {!isStreaming && <MessageReactions message={msg} onToggle={onToggleReaction} />}
+                  {failure && (
+                    <div className="message-failure" role="alert">
+                      <span>No response: the run failed.</span>
+                      <button type="button" onClick={() => onRetryFailed?.(failure.key)}>
+                        Retry
+                      </button>
                     </div>
-                    <div className="message-footer">
-                      <div className="message-time">{formatTimestamp(msg.created_at)}</div>
-                      <div className="message-actions">
+                  )}
+                  <div className="message-footer">
+                    <div className="message-time">
+                      <Timestamp value={msg.created_at} format="time" fallback="" />
+                    </div>
+                    {msg.role === "assistant" && msg.usage && !isStreaming && <MessageUsageFooter usage={msg.usage} />}
+                    <div className="message-actions">
+                      <button
+                        type="button"
+                        className="message-action-btn"
+                        onClick={() => onCopyMessage(msg)}
+                        title="Copy message"
+                      >
+                        📋
+                      </button>
+                      {onToggleBookmark && msg.id > 0 && !isStreaming && (
+                        <button
+                          type="button"
+                          className={clsx("message-action-btn", { "message-action-btn--active": msg.bookmarked })}
+                          onClick={() => onToggleBookmark(msg)}
+                          aria-pressed={msg.bookmarked ?? false}
+                          title={msg.bookmarked ? "Remove from saved messages" : "Save message"}
+                          data-testid="message-bookmark"
+                        >
+                          {msg.bookmarked ? "★" : "☆"}
+                        </button>
+                      )}
+                      {onToggleReaction && msg.id > 0 && !isStreaming && (
+                        <span className="message-reaction-picker">
+                          <button
+                            type="button"
+                            className="message-action-btn"
+                            onClick={() => setReactionPickerId(reactionPickerId === msg.id ? null : msg.id)}
+                            aria-expanded={reactionPickerId === msg.id}
+                            title="Add reaction"
+                            data-testid="message-react"
+                          >
+                            😊
+                          </button>
+                          {reactionPickerId === msg.id && (
+                            <span className="message-reaction-options" role="menu">
+                              {MESSAGE_REACTIONS.map((emoji) => (
+                                <button
+                                  key={emoji}
+                                  type="button"
+                                  role="menuitem"
+                                  onClick={() => {
+                                    setReactionPickerId(null);
+                                    onToggleReaction(msg, emoji);
+                                  }}
+                                  aria-label={`React with ${emoji}`}
+                                >
+                                  {emoji}
+                                </button>
+                              ))}
+                            </span>
+                          )}
+                        </span>
+                      )}
+                      {msg.role === "user" && onEditMessage && msg.id > 0 && !isEditing &&
+                        (msg.author == null || msg.author.id === currentUserId) && (
                         <button
                           type="button"
                           className="message-action-btn"
-                          onClick={() => onCopyMessage(msg)}
-                          title="Copy message"
+                          onClick={() => startEditing(msg)}
+                          disabled={isBusy}
+                          title="Edit and resend"
                         >
-                          📋
+                          ✏️
                         </button>
-                      </div>
+                      )}
+                      {msg.id === regenerableMessageId && onRegenerate && (
+                        <button
+                          type="button"
+                          className="message-action-btn"
+                          onClick={onRegenerate}
+                          disabled={isBusy}
+                          title="Regenerate response"
+                        >
+                          🔄
+                        </button>
+                      )}
                     </div>
-                  </article>
-                </div>
-                {msg.role === "assistant" && toolMessages?.map(toolMsg => (
-                  <ToolMessage key={toolMsg.id} message={toolMsg} />
-                ))}
+                  </div>
+                </article>
               </div>
-            );
-          })}
+              {msg.role === "assistant" && renderToolCalls(msg)}
+            </div>
+          );
+        })}
+        {!messageWindow.atTail && (
+          <div className="messages-sentinel" ref={bottomSentinelRef}>
+            <button
+              type="button"
+              className="messages-jump-latest"
+              onClick={() => setMessageWindow(TAIL_WINDOW)}
+              data-testid="messages-jump-latest"
+            >
+              Jump to latest
+            </button>
+          </div>
+        )}
         {orphanedToolMessages.map(toolMsg => (
-          <ToolMessage key={toolMsg.id} message={toolMsg} />
+          <ToolMessage
+            key={toolMsg.id}
+            toolName={toolMsg.tool_name || toolMsg.name || "tool"}
+            toolCallId={toolMsg.tool_call_id}
+            output={toolMsg.content}
+          />
+        ))}
+        {liveToolOutputs
+          .filter((output) => output.parentId == null || !messages.some((m) => m.id === output.parentId))
+          .map((output, index) => (
+            <ToolMessage
+              key={`live-${output.toolCallId ?? index}`}
+              toolName={output.toolName || "tool"}
+              toolCallId={output.toolCallId}
+              output={output.content}
+            />
+          ))}
+        {unsentFailures.map((failure) => (
+          <div className="chat-row" key={`failed-${failure.key}`}>
+            <article className="message user-message message--failed" data-role="chat-message-failed">
+              <div className="message-content">
+                {failure.content && <div className="preserve-whitespace">{failure.content}</div>}
+                <MessageAttachments attachments={failure.attachments} />
+              </div>
+              <div className="message-failure" role="alert">
+                <span>Not sent.</span>
+                <button type="button" onClick={() => onRetryFailed?.(failure.key)}>
+                  Retry
+                </button>
+                <button type="button" onClick={() => onDiscardFailed?.(failure.key)}>
+                  Discard
+                </button>
+              </div>
+            </article>
+          </div>
         ))}
         {/* Show pending buffer as temporary assistant message at END of messages */}
         {pendingTokenBuffer && (
@@ -177,14 +630,7 @@ export function ChatMessageList({
                 data-streaming="true"
               >
                 <div className="message-content">
-                  <ReactMarkdown
-                    remarkPlugins={[remarkGfm]}
-                    components={{
-                      code: CodeBlock
-                    }}
-                  >
-                    {pendingTokenBuffer}
-                  </ReactMarkdown>
+                  <MarkdownMessage content={pendingTokenBuffer} isStreaming />
                   <span className="streaming-cursor">▋</span>
                 </div>
               </article>
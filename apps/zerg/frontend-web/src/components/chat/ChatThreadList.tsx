@@ -1,10 +1,14 @@
 import { useState } from "react";
 import clsx from "clsx";
 import { Thread } from "../../services/api";
-import { formatTimestamp, truncateText } from "./chatUtils";
+import { truncateText } from "./chatUtils";
+import Timestamp from "../Timestamp";
+import { isImeComposing } from "../../lib/ime";
+import { TRANSCRIPT_FORMAT_LABELS, type TranscriptFormat } from "./transcriptExport";
 
 interface ChatThreadListProps {
   chatThreads: Thread[];
+  archivedThreads: Thread[];
   automationThreads: Thread[];
   effectiveThreadId: number | null;
   editingThreadId: number | null;
@@ -14,6 +18,9 @@ interface ChatThreadListProps {
   onSaveThreadTitle: (threadId: number) => void;
   onCancelEdit: () => void;
   onTitleChange: (title: string) => void;
+  onTogglePin: (thread: Thread) => void;
+  onToggleArchive: (thread: Thread) => void;
+  onExportThread?: (thread: Thread, format: TranscriptFormat) => void;
   isRenamingPending: boolean;
   onCreateThread: () => void;
   isShelfOpen?: boolean;
@@ -22,6 +29,7 @@ interface ChatThreadListProps {
 
 export function ChatThreadList({
   chatThreads,
+  archivedThreads,
   automationThreads,
   effectiveThreadId,
   editingThreadId,
@@ -31,12 +39,164 @@ export function ChatThreadList({
   onSaveThreadTitle,
   onCancelEdit,
   onTitleChange,
+  onTogglePin,
+  onToggleArchive,
+  onExportThread,
   isRenamingPending,
   onCreateThread,
   isShelfOpen,
   streamingThreadIds,
 }: ChatThreadListProps) {
   const [isAutomationCollapsed, setIsAutomationCollapsed] = useState(true);
+  const [isArchivedCollapsed, setIsArchivedCollapsed] = useState(true);
+  const [exportMenuThreadId, setExportMenuThreadId] = useState<number | null>(null);
+
+  const renderThreadRow = (thread: Thread) => {
+    const threadMessages = (thread.messages || []).filter(m => m.role !== "system");
+    const lastMessage = threadMessages[threadMessages.length - 1];
+    const messagePreview = lastMessage
+      ? truncateText(lastMessage.content, 50)
+      : "No messages";
+    const isWriting = streamingThreadIds.includes(thread.id);
+
+    return (
+      <div
+        key={thread.id}
+        className={clsx("thread-item", {
+          selected: thread.id === effectiveThreadId,
+          writing: isWriting,
+          pinned: thread.pinned,
+          archived: thread.archived,
+        })}
+        data-testid={`thread-row-${thread.id}`}
+        data-id={thread.id}
+        data-thread-id={thread.id}
+        role="button"
+        tabIndex={0}
+        onClick={() => onSelectThread(thread)}
+        onKeyDown={(event) => {
+          if (event.key === "Enter" || event.key === " ") {
+            event.preventDefault();
+            onSelectThread(thread);
+          }
+        }}
+      >
+        {editingThreadId === thread.id ? (
+          <div className="thread-edit-form" onClick={(e) => e.stopPropagation()}>
+            <input
+              type="text"
+              value={editingTitle}
+              onChange={(e) => onTitleChange(e.target.value)}
+              onKeyDown={(e) => {
+                // Keep typing (incl. IME keystrokes) from reaching the row's Enter/Space handler
+                e.stopPropagation();
+                if (isImeComposing(e)) return;
+                if (e.key === "Enter") {
+                  onSaveThreadTitle(thread.id);
+                } else if (e.key === "Escape") {
+                  onCancelEdit();
+                }
+              }}
+              autoFocus
+              className="thread-title-input"
+              aria-label="Thread title"
+              disabled={isRenamingPending}
+            />
+          </div>
+        ) : (
+          <>
+            <div className="thread-item-title" onDoubleClick={(e) => onEditThreadTitle(thread, e)}>
+              {thread.pinned && <span className="thread-pin-indicator" aria-label="Pinned">📌 </span>}
+              {isWriting && <span className="writing-indicator" title="AI is writing...">✍️ </span>}
+              {thread.title}
+            </div>
+            <div className="thread-item-time">
+              <Timestamp value={thread.updated_at || thread.created_at} format="time" fallback="" />
+            </div>
+            <div className="thread-item-actions">
+              {!thread.archived && (
+                <button
+                  type="button"
+                  className={clsx("thread-action-button thread-pin-button", { active: thread.pinned })}
+                  data-testid={`pin-thread-${thread.id}`}
+                  onClick={(e) => {
+                    e.stopPropagation();
+                    onTogglePin(thread);
+                  }}
+                  aria-label={thread.pinned ? "Unpin thread" : "Pin thread"}
+                  title={thread.pinned ? "Unpin thread" : "Pin thread"}
+                  aria-pressed={thread.pinned}
+                >
+                  📌
+                </button>
+              )}
+              <button
+                type="button"
+                className="thread-edit-button"
+                data-testid={`edit-thread-${thread.id}`}
+                onClick={(e) => onEditThreadTitle(thread, e)}
+                aria-label="Edit thread title"
+                title="Edit thread title"
+                disabled={isRenamingPending}
+              >
+                ✎
+              </button>
+              <button
+                type="button"
+                className="thread-action-button thread-archive-button"
+                data-testid={`archive-thread-${thread.id}`}
+                onClick={(e) => {
+                  e.stopPropagation();
+                  onToggleArchive(thread);
+                }}
+                aria-label={thread.archived ? "Unarchive thread" : "Archive thread"}
+                title={thread.archived ? "Unarchive thread" : "Archive thread"}
+              >
+                {thread.archived ? "↩" : "🗄"}
+              </button>
+              {onExportThread && (
+                <span className="thread-export-menu">
+                  <button
+                    type="button"
+                    className="thread-action-button thread-export-button"
+                    data-testid={`export-thread-${thread.id}`}
+                    onClick={(e) => {
+                      e.stopPropagation();
+                      setExportMenuThreadId(exportMenuThreadId === thread.id ? null : thread.id);
+                    }}
+                    aria-label="Export thread"
+                    title="Export thread"
+                    aria-expanded={exportMenuThreadId === thread.id}
+                  >
+                    ⤓
+                  </button>
+                  {exportMenuThreadId === thread.id && (
+                    <span className="thread-export-options" role="menu">
+                      {(Object.keys(TRANSCRIPT_FORMAT_LABELS) as TranscriptFormat[]).map((format) => (
+                        <button
+                          key={format}
+                          type="button"
+                          role="menuitem"
+                          onClick={(e) => {
+                            e.stopPropagation();
+                            setExportMenuThreadId(null);
+                            onExportThread(thread, format);
+                          }}
+                        >
+                          {TRANSCRIPT_FORMAT_LABELS[format]}
+                        </button>
+                      ))}
+                    </span>
+                  )}
+                </span>
+              )}
+            </div>
+            <div className="thread-item-preview">{messagePreview}</div>
+          </>
+        )}
+      </div>
+    );
+  };
 
   return (
     <aside className={clsx("thread-sidebar", { active: isShelfOpen })}>
@@ -52,83 +212,34 @@ export function ChatThreadList({
         </button>
       </div>
       <div className="thread-list">
-        {chatThreads.map((thread) => {
-          const threadMessages = (thread.messages || []).filter(m => m.role !== "system");
-          const lastMessage = threadMessages[threadMessages.length - 1];
-          const messagePreview = lastMessage
-            ? truncateText(lastMessage.content, 50)
-            : "No messages";
-          const isWriting = streamingThreadIds.includes(thread.id);
-
-          return (
-            <div
-              key={thread.id}
-              className={clsx("thread-item", {
-                selected: thread.id === effectiveThreadId,
-                writing: isWriting
-              })}
-              data-testid={`thread-row-${thread.id}`}
-              data-id={thread.id}
-              data-thread-id={thread.id}
-              role="button"
-              tabIndex={0}
-              onClick={() => onSelectThread(thread)}
-              onKeyDown={(event) => {
-                if (event.key === "Enter" || event.key === " ") {
-                  event.preventDefault();
-                  onSelectThread(thread);
-                }
-              }}
-            >
-              {editingThreadId === thread.id ? (
-                <div className="thread-edit-form" onClick={(e) => e.stopPropagation()}>
-                  <input
-                    type="text"
-                    value={editingTitle}
-                    onChange={(e) => onTitleChange(e.target.value)}
-                    onKeyDown={(e) => {
-                      if (e.key === "Enter") {
-                        onSaveThreadTitle(thread.id);
-                      } else if (e.key === "Escape") {
-                        onCancelEdit();
-                      }
-                    }}
-                    autoFocus
-                    className="thread-title-input"
-                    disabled={isRenamingPending}
-                  />
-                </div>
-              ) : (
-                <>
-                  <div className="thread-item-title">
-                    {isWriting && <span className="writing-indicator" title="AI is writing...">✍️ </span>}
-                    {thread.title}
-                  </div>
-                  <div className="thread-item-time">
-                    {formatTimestamp(thread.updated_at || thread.created_at)}
-                  </div>
-                  <button
-                    type="button"
-                    className="thread-edit-button"
-                    data-testid={`edit-thread-${thread.id}`}
-                    onClick={(e) => onEditThreadTitle(thread, e)}
-                    aria-label="Edit thread title"
-                    title="Edit thread title"
-                    disabled={isRenamingPending}
-                  >
-                    ✎
-                  </button>
-                  <div className="thread-item-preview">{messagePreview}</div>
-                </>
-              )}
-            </div>
-          );
-        })}
+        {chatThreads.map(renderThreadRow)}
         {chatThreads.length === 0 && (
           <div className="thread-list-empty">No threads found</div>
         )}
       </div>
 
+      {/* Archived threads stay reachable but out of the way */}
+      {archivedThreads.length > 0 && (
+        <div className="archived-threads" data-testid="archived-threads">
+          <button
+            type="button"
+            className="archived-threads-header"
+            data-testid="archived-threads-header"
+            onClick={() => setIsArchivedCollapsed(!isArchivedCollapsed)}
+            aria-expanded={!isArchivedCollapsed}
+          >
+            <span className={clsx("automation-collapse-icon", { collapsed: isArchivedCollapsed })}>▼</span>
+            Archived
+            <span className="automation-history-count">{archivedThreads.length}</span>
+          </button>
+          {!isArchivedCollapsed && (
+            <div className="archived-threads-list" data-testid="archived-threads-list">
+              {archivedThreads.map(renderThreadRow)}
+            </div>
+          )}
+        </div>
+      )}
+
       {/* Automation History Section */}
       {automationThreads.length > 0 && (
         <div className="automation-history" data-testid="automation-history">
@@ -184,7 +295,7 @@ export function ChatThreadList({
               >
                 <div className="automation-run-title">{thread.title}</div>
                 <div className="automation-run-time">
-                  {formatTimestamp(thread.created_at)}
+                  <Timestamp value={thread.created_at} format="time" fallback="" />
                 </div>
                 <div className="automation-run-type">
                   <span
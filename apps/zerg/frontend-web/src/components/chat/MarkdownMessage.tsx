@@ -0,0 +1,158 @@
+import { memo, useMemo, useState, isValidElement, type ReactElement, type ReactNode } from "react";
+import ReactMarkdown, { type Components } from "react-markdown";
+import remarkGfm from "remark-gfm";
+import { SyntaxHighlighter, oneDark } from "../../lib/syntaxHighlighter";
+import { copyToClipboard } from "../../lib/clipboard";
+
+const FENCE_PATTERN = /^ {0,3}(`{3,}|~{3,})/;
+
+/**
+ * Split markdown into top-level blocks at blank lines that sit outside fenced
+ * code. While a response streams only the last block changes, so the earlier
+ * ones can stay memoized instead of re-parsing the whole message per token.
+ */
+export function splitMarkdownBlocks(content: string): string[] {
+  const blocks: string[] = [];
+  let current: string[] = [];
+  let openFence: string | null = null;
+
+  for (const line of content.split("\n")) {
+    const fence = FENCE_PATTERN.exec(line);
+    if (fence) {
+      const marker = fence[1];
+      if (openFence === null) {
+        openFence = marker;
+      } else if (marker[0] === openFence[0] && marker.length >= openFence.length) {
+        openFence = null;
+      }
+    }
+
+    if (openFence === null && line.trim() === "" && current.length > 0) {
+      blocks.push(current.join("\n"));
+      current = [];
+      continue;
+    }
+    current.push(line);
+  }
+
+  if (current.length > 0) {
+    blocks.push(current.join("\n"));
+  }
+  return blocks;
+}
+
+/**
+ * Close a fence that hasn't received its closing marker yet so partial code
+ * renders as a code block rather than flashing as paragraph text.
+ */
+export function closeOpenFence(block: string): string {
+  let openFence: string | null = null;
+  for (const line of block.split("\n")) {
+    const fence = FENCE_PATTERN.exec(line);
+    if (!fence) continue;
+    const marker = fence[1];
+    if (openFence === null) {
+      openFence = marker;
+    } else if (marker[0] === openFence[0] && marker.length >= openFence.length) {
+      openFence = null;
+    }
+  }
+  return openFence === null ? block : `${block}\n${openFence}`;
+}
+
+// Fenced code block with language label and copy button
+function CodeBlock({ language, code }: { language: string; code: string }) {
+  const [copied, setCopied] = useState(false);
+
+  const handleCopy = () => {
+    copyToClipboard(code)
+      .then(() => {
+        setCopied(true);
+        setTimeout(() => setCopied(false), 2000);
+      })
+      .catch((error) => console.error("Failed to copy code:", error));
+  };
+
+  return (
+    <div className="code-block-wrapper">
+      <div className="code-block-header">
+        <span className="code-language">{language}</span>
+        <button type="button" className="code-copy-btn" onClick={handleCopy} title="Copy code">
+          {copied ? "✓ Copied" : "📋 Copy"}
+        </button>
+      </div>
+      <SyntaxHighlighter
+        style={oneDark}
+        language={language}
+        PreTag="div"
+        customStyle={{ margin: 0, borderTopLeftRadius: 0, borderTopRightRadius: 0 }}
+      >
+        {code}
+      </SyntaxHighlighter>
+    </div>
+  );
+}
+
+function extractText(node: ReactNode): string {
+  if (typeof node === "string" || typeof node === "number") return String(node);
+  if (Array.isArray(node)) return node.map(extractText).join("");
+  if (isValidElement(node)) return extractText((node.props as { children?: ReactNode }).children);
+  return "";
+}
+
+// react-markdown no longer flags inline code, so fenced blocks are detected
+// at the <pre> level and inline `code` keeps the default rendering.
+const markdownComponents: Components = {
+  pre: ({ children }) => {
+    const child = Array.isArray(children) ? children[0] : children;
+    if (!isValidElement(child)) {
+      return <pre>{children}</pre>;
+    }
+    const codeElement = child as ReactElement<{ className?: string; children?: ReactNode }>;
+    const match = /language-([\w+-]+)/.exec(codeElement.props.className || "");
+    const code = extractText(codeElement.props.children).replace(/\n$/, "");
+    return <CodeBlock language={match ? match[1] : "text"} code={code} />;
+  },
+  a: ({ children, href, ...props }) => (
+    <a href={href} target="_blank" rel="noopener noreferrer" {...props}>
+      {children}
+    </a>
+  ),
+};
+
+const MarkdownBlock = memo(function MarkdownBlock({ content }: { content: string }) {
+  return (
+    <ReactMarkdown remarkPlugins={[remarkGfm]} components={markdownComponents}>
+      {content}
+    </ReactMarkdown>
+  );
+});
+
+interface MarkdownMessageProps {
+  content: string;
+  isStreaming?: boolean;
+}
+
+/**
+ * Render assistant markdown. Finished messages are parsed as one document so
+ * reference links and loose lists behave; streaming messages are rendered
+ * block-by-block so each token only re-parses the tail.
+ */
+export function MarkdownMessage({ content, isStreaming = false }: MarkdownMessageProps) {
+  const blocks = useMemo(() => (isStreaming ? splitMarkdownBlocks(content) : null), [content, isStreaming]);
+
+  if (!blocks) {
+    return <MarkdownBlock content={content} />;
+  }
+
+  return (
+    <>
+      {blocks.map((block, index) => (
+        <MarkdownBlock
+          key={index}
+          content={index === blocks.length - 1 ? closeOpenFence(block) : block}
+        />
+      ))}
+    </>
+  );
+}
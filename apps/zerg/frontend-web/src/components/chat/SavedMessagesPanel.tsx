@@ -0,0 +1,73 @@
+import { useQuery } from "@tanstack/react-query";
+import { fetchBookmarkedMessages, type Thread, type ThreadMessage } from "../../services/api";
+import Timestamp from "../Timestamp";
+
+// Characters of a saved message shown in the list
+const SNIPPET_LENGTH = 140;
+
+interface SavedMessagesPanelProps {
+  agentId: number;
+  // Used to label each message with the thread it came from
+  threads: Thread[];
+  onJump: (message: ThreadMessage) => void;
+  onRemove: (message: ThreadMessage) => void;
+  onClose: () => void;
+}
+
+function snippet(content: string): string {
+  const flat = content.replace(/\s+/g, " ").trim();
+  return flat.length > SNIPPET_LENGTH ? `${flat.slice(0, SNIPPET_LENGTH)}…` : flat;
+}
+
+/**
+ * Messages the viewer bookmarked across this agent's threads, newest first.
+ * Picking one opens its thread scrolled to the message.
+ */
+export function SavedMessagesPanel({ agentId, threads, onJump, onRemove, onClose }: SavedMessagesPanelProps) {
+  const { data: saved = [], isLoading } = useQuery({
+    queryKey: ["message-bookmarks", agentId],
+    queryFn: () => fetchBookmarkedMessages(agentId),
+  });
+  const threadTitle = (threadId: number) => threads.find((thread) => thread.id === threadId)?.title ?? `#${threadId}`;
+
+  return (
+    <aside className="saved-messages-panel" aria-labelledby="saved-messages-title" data-testid="saved-messages-panel">
+      <header className="saved-messages-header">
+        <h3 id="saved-messages-title">Saved messages</h3>
+        <button type="button" className="saved-messages-close" onClick={onClose} aria-label="Close saved messages">
+          ×
+        </button>
+      </header>
+      {isLoading ? (
+        <p className="muted">Loading…</p>
+      ) : saved.length === 0 ? (
+        <p className="saved-messages-empty">Star a message to keep it here.</p>
+      ) : (
+        <ul className="saved-messages-list">
+          {saved.map((message) => (
+            <li key={message.id} data-testid={`saved-message-${message.id}`}>
+              <button type="button" className="saved-message-jump" onClick={() => onJump(message)}>
+                <span className="saved-message-meta">
+                  {message.role === "assistant" ? "Agent" : (message.author?.display_name ?? "You")} ·{" "}
+                  {threadTitle(message.thread_id)} ·{" "}
+                  <Timestamp value={message.sent_at} fallback="" />
+                </span>
+                <span className="saved-message-snippet">{snippet(message.content)}</span>
+              </button>
+              <button
+                type="button"
+                className="saved-message-remove"
+                onClick={() => onRemove(message)}
+                title="Remove from saved messages"
+              >
+                ★
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+    </aside>
+  );
+}
+
+export default SavedMessagesPanel;
@@ -0,0 +1,42 @@
+import type { ModelConfig } from "../../services/api";
+
+interface ThreadModelPickerProps {
+  models: ModelConfig[];
+  agentModel: string | undefined;
+  // The thread's override, or null to follow the agent
+  value: string | null | undefined;
+  onChange: (model: string) => void;
+  disabled?: boolean;
+}
+
+/**
+ * Model dropdown in the chat header. Picking one overrides the agent's model
+ * for this thread only; "Agent default" clears the override.
+ */
+export function ThreadModelPicker({ models, agentModel, value, onChange, disabled }: ThreadModelPickerProps) {
+  const selected = value ?? "";
+  const label = (id: string) => models.find((model) => model.id === id)?.display_name ?? id;
+
+  return (
+    <label className="thread-model-picker" title="Model for this thread only">
+      <span className="sr-only">Thread model</span>
+      <select
+        value={selected}
+        onChange={(event) => onChange(event.target.value)}
+        disabled={disabled}
+        data-testid="thread-model-picker"
+        className={selected ? "thread-model-picker--override" : undefined}
+      >
+        <option value="">Agent default{agentModel ? ` (${label(agentModel)})` : ""}</option>
+        {selected && !models.some((model) => model.id === selected) && <option value={selected}>{selected}</option>}
+        {models.map((model) => (
+          <option key={model.id} value={model.id}>
+            {model.display_name}
+          </option>
+        ))}
+      </select>
+    </label>
+  );
+}
+
+export default ThreadModelPicker;
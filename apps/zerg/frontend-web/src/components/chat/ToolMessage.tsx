@@ -1,67 +1,84 @@
-import { useState } from "react";
+import { useId, useState } from "react";
 import clsx from "clsx";
 import { SyntaxHighlighter, oneDark } from '../../lib/syntaxHighlighter';
-import { ThreadMessage } from "../../services/api";
+import { formatToolPayload, isToolErrorOutput } from "./toolCalls";
+import { truncateText } from "./chatUtils";
 
 interface ToolMessageProps {
-  message: ThreadMessage;
+  toolName: string;
+  toolCallId?: string | null;
+  // Arguments from the assistant's tool call, when known
+  args?: unknown;
+  // null while the tool is still running (or never reported back)
+  output: string | null;
+  isRunning?: boolean;
 }
 
-export function ToolMessage({ message }: ToolMessageProps) {
-  const [isOpen, setIsOpen] = useState(false);
-  const toolName = message.tool_name || "tool";
-  const toolCallId = message.tool_call_id || "";
+function ToolPayload({ label, value }: { label: string; value: unknown }) {
+  const { text, isJson } = formatToolPayload(value);
+  return (
+    <div className="tool-section">
+      <div className="tool-section-header">{label}</div>
+      <div className="tool-code-block">
+        <SyntaxHighlighter
+          language={isJson ? "json" : "text"}
+          style={oneDark}
+          customStyle={{ margin: 0, borderRadius: '4px', fontSize: '12px' }}
+          wrapLongLines={true}
+        >
+          {text || "(empty)"}
+        </SyntaxHighlighter>
+      </div>
+    </div>
+  );
+}
 
-  // Determine status based on content (if content is empty, it might be processing)
-  const isProcessing = !message.content && !message.name; // simplistic check
+// Collapsible bubble for a single tool call: name + status, arguments and output
+export function ToolMessage({ toolName, toolCallId, args, output, isRunning = false }: ToolMessageProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const detailsId = useId();
 
-  const toggleOpen = () => setIsOpen(!isOpen);
+  const hasOutput = output != null;
+  const isError = isToolErrorOutput(output);
+  const status = isError ? "error" : hasOutput ? "completed" : isRunning ? "running" : "no-output";
+  const statusLabel = {
+    error: "Failed",
+    completed: "Completed",
+    running: "Running…",
+    "no-output": "No output",
+  }[status];
+  const argsPreview = args != null ? truncateText(formatToolPayload(args).text.replace(/\s+/g, " "), 60) : "";
 
   return (
-    <div className="tool-message-container" data-tool-call-id={toolCallId}>
-      <div
+    <div
+      className={clsx("tool-message-container", `tool-message--${status}`)}
+      data-tool-call-id={toolCallId ?? undefined}
+      data-testid="tool-call-bubble"
+    >
+      <button
+        type="button"
         className={clsx("tool-summary", { "is-open": isOpen })}
-        onClick={toggleOpen}
+        onClick={() => setIsOpen((open) => !open)}
+        aria-expanded={isOpen}
+        aria-controls={detailsId}
       >
-        <div className="tool-icon">🛠️</div>
+        <span className="tool-icon" aria-hidden="true">🛠️</span>
         <span className="tool-name">Used <strong>{toolName}</strong></span>
-        <span className="tool-status-indicator">
-           {isProcessing ? "Running..." : "Completed"}
-        </span>
-        <div className={clsx("chevron", { "open": isOpen })}>▶</div>
-      </div>
+        {argsPreview && <code className="tool-args-preview">{argsPreview}</code>}
+        <span className={clsx("tool-status-indicator", `tool-status--${status}`)}>{statusLabel}</span>
+        <span className={clsx("chevron", { open: isOpen })} aria-hidden="true">▶</span>
+      </button>
 
       {isOpen && (
-        <div className="tool-details">
-          {message.name && (
-            <div className="tool-section">
-              <div className="tool-section-header">Input</div>
-              <div className="tool-code-block">
-                <SyntaxHighlighter
-                   language="json"
-                   style={oneDark}
-                   customStyle={{ margin: 0, borderRadius: '4px', fontSize: '12px' }}
-                   wrapLongLines={true}
-                >
-                   {message.name}
-                </SyntaxHighlighter>
-              </div>
+        <div className="tool-details" id={detailsId}>
+          {args != null && <ToolPayload label="Arguments" value={args} />}
+          {hasOutput ? (
+            <ToolPayload label={isError ? "Error" : "Output"} value={isError ? output.replace("<tool-error>", "").trim() : output} />
+          ) : (
+            <div className="tool-section tool-section--empty">
+              {isRunning ? "Waiting for the tool to finish…" : "The tool did not return any output."}
             </div>
           )}
-
-          <div className="tool-section">
-            <div className="tool-section-header">Output</div>
-             <div className="tool-code-block">
-                <SyntaxHighlighter
-                   language="json"
-                   style={oneDark}
-                   customStyle={{ margin: 0, borderRadius: '4px', fontSize: '12px' }}
-                   wrapLongLines={true}
-                >
-                   {message.content || "(No output)"}
-                </SyntaxHighlighter>
-            </div>
-          </div>
         </div>
       )}
     </div>
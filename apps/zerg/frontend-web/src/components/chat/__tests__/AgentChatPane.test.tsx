@@ -0,0 +1,100 @@
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { Thread } from "../../../services/api";
+
+const apiMocks = vi.hoisted(() => ({
+  fetchAgent: vi.fn(),
+  fetchThreads: vi.fn(),
+  fetchThreadMessagesPage: vi.fn(),
+  fetchWorkflows: vi.fn().mockResolvedValue([]),
+  postThreadMessage: vi.fn(),
+  runThread: vi.fn().mockResolvedValue(undefined),
+  createThread: vi.fn(),
+}));
+
+vi.mock("../../../services/api", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../../../services/api")>();
+  return { ...actual, ...apiMocks };
+});
+
+vi.mock("../../agent-settings/AgentSettingsDrawer", () => ({ default: () => null }));
+
+import { AgentChatPane } from "../AgentChatPane";
+
+const now = new Date().toISOString();
+
+function thread(id: number, agentId: number): Thread {
+  return {
+    id,
+    agent_id: agentId,
+    title: `Thread ${id}`,
+    agent_state: null,
+    memory_strategy: "buffer",
+    active: true,
+    thread_type: "chat",
+    created_at: now,
+    updated_at: now,
+    messages: [],
+  } as Thread;
+}
+
+function renderPane(onSelectAgent = vi.fn()) {
+  const queryClient = new QueryClient({ defaultOptions: { queries: { retry: false } } });
+  render(
+    <QueryClientProvider client={queryClient}>
+      <AgentChatPane
+        agentId={1}
+        agentOptions={[
+          { agentId: 1, label: "Researcher" },
+          { agentId: 2, label: "Writer" },
+        ]}
+        onSelectAgent={onSelectAgent}
+        onClose={vi.fn()}
+      />
+    </QueryClientProvider>
+  );
+  return { onSelectAgent };
+}
+
+describe("AgentChatPane", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    apiMocks.fetchAgent.mockImplementation((id: number) => Promise.resolve({ id, name: `Agent ${id}` }));
+    apiMocks.fetchThreadMessagesPage.mockResolvedValue([
+      { id: 5, thread_id: 10, role: "assistant", content: "Ready when you are", sent_at: now, processed: true },
+    ]);
+    apiMocks.postThreadMessage.mockImplementation((threadId: number, content: string) =>
+      Promise.resolve({ id: 6, thread_id: threadId, role: "user", content, sent_at: now, processed: false })
+    );
+  });
+
+  it("opens the agent's latest chat thread and selects agents on the canvas", async () => {
+    apiMocks.fetchThreads.mockResolvedValue([thread(10, 1)]);
+    const { onSelectAgent } = renderPane();
+
+    expect(await screen.findByText("Ready when you are")).toBeInTheDocument();
+    expect(apiMocks.fetchThreadMessagesPage).toHaveBeenCalledWith(10, expect.anything());
+
+    await userEvent.setup().selectOptions(screen.getByTestId("agent-chat-pane-agent"), "2");
+    expect(onSelectAgent).toHaveBeenCalledWith(2);
+  });
+
+  it("starts a test thread when the agent has none", async () => {
+    apiMocks.fetchThreads.mockResolvedValue([]);
+    apiMocks.createThread.mockResolvedValue(thread(11, 1));
+    renderPane();
+
+    const user = userEvent.setup();
+    await screen.findByText(/Send a message to start a test chat/);
+    await user.type(screen.getByTestId("agent-chat-pane-input"), "Hi there");
+    await user.click(screen.getByRole("button", { name: "Send" }));
+
+    await waitFor(() => {
+      expect(apiMocks.createThread).toHaveBeenCalledWith(1, "Canvas test chat");
+      expect(apiMocks.postThreadMessage).toHaveBeenCalledWith(11, "Hi there", []);
+      expect(apiMocks.runThread).toHaveBeenCalledWith(11);
+    });
+  });
+});
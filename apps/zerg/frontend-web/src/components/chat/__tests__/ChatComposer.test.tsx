@@ -0,0 +1,72 @@
+import { fireEvent, render, screen } from "@testing-library/react";
+import { describe, expect, it, vi } from "vitest";
+import { ChatComposer } from "../ChatComposer";
+import { isImeComposing } from "../../../lib/ime";
+
+function renderComposer(onSend = vi.fn()) {
+  render(
+    <ChatComposer
+      draft="こんにちは"
+      onDraftChange={vi.fn()}
+      onSend={onSend}
+      effectiveThreadId={1}
+      isSending={false}
+      showWorkflowPanel={false}
+      onToggleWorkflowPanel={vi.fn()}
+      workflowsQuery={{ data: [], isLoading: false }}
+      selectedWorkflow={null}
+      onSelectWorkflow={vi.fn()}
+      onExecuteWorkflow={vi.fn()}
+      isExecutingWorkflow={false}
+      messagesCount={0}
+      onExportChat={vi.fn()}
+      attachments={[]}
+      isUploading={false}
+      onAddFiles={vi.fn()}
+      onRemoveAttachment={vi.fn()}
+    />
+  );
+  return { onSend, input: screen.getByTestId("chat-input") };
+}
+
+describe("ChatComposer IME handling", () => {
+  it("does not send on the Enter that confirms a composition", () => {
+    const { onSend, input } = renderComposer();
+
+    fireEvent.compositionStart(input);
+    fireEvent.keyDown(input, { key: "Enter", isComposing: true });
+    fireEvent.compositionEnd(input);
+    expect(onSend).not.toHaveBeenCalled();
+
+    // A fresh Enter after the composition is confirmed sends
+    fireEvent.keyDown(input, { key: "Enter" });
+    expect(onSend).toHaveBeenCalledTimes(1);
+  });
+
+  it("ignores Safari's post-compositionend Enter (keyCode 229)", () => {
+    const { onSend, input } = renderComposer();
+
+    fireEvent.compositionStart(input);
+    fireEvent.compositionEnd(input);
+    fireEvent.keyDown(input, { key: "Enter", keyCode: 229 });
+
+    expect(onSend).not.toHaveBeenCalled();
+  });
+
+  it("keeps the composition flag even if the event omits isComposing", () => {
+    const { onSend, input } = renderComposer();
+
+    fireEvent.compositionStart(input);
+    fireEvent.keyDown(input, { key: "Enter" });
+
+    expect(onSend).not.toHaveBeenCalled();
+  });
+});
+
+describe("isImeComposing", () => {
+  it("detects composing keystrokes for global shortcut handlers", () => {
+    expect(isImeComposing(new KeyboardEvent("keydown", { key: "g", isComposing: true }))).toBe(true);
+    expect(isImeComposing(new KeyboardEvent("keydown", { key: "Process" }))).toBe(true);
+    expect(isImeComposing(new KeyboardEvent("keydown", { key: "g" }))).toBe(false);
+  });
+});
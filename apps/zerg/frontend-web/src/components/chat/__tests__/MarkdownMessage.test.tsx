@@ -0,0 +1,31 @@
+import { render, screen } from "@testing-library/react";
+import { describe, expect, it } from "vitest";
+import { MarkdownMessage, closeOpenFence, splitMarkdownBlocks } from "../MarkdownMessage";
+
+describe("splitMarkdownBlocks", () => {
+  it("splits on blank lines but keeps fenced code intact", () => {
+    const content = "Intro\n\n```py\nx = 1\n\ny = 2\n```\n\n- a\n- b";
+    expect(splitMarkdownBlocks(content)).toEqual(["Intro", "```py\nx = 1\n\ny = 2\n```", "- a\n- b"]);
+  });
+
+  it("closes an unterminated fence while streaming", () => {
+    expect(closeOpenFence("```ts\nconst a")).toBe("```ts\nconst a\n```");
+    expect(closeOpenFence("```ts\nconst a\n```")).toBe("```ts\nconst a\n```");
+  });
+});
+
+describe("MarkdownMessage", () => {
+  it("renders tables, links and copyable code blocks", () => {
+    const content = "| a | b |\n| - | - |\n| 1 | 2 |\n\n[docs](https://example.com)\n\n```js\nconsole.log(1)\n```";
+    render(<MarkdownMessage content={content} />);
+
+    expect(screen.getByRole("table")).toBeInTheDocument();
+    expect(screen.getByRole("link", { name: "docs" })).toHaveAttribute("target", "_blank");
+    expect(screen.getByTitle("Copy code")).toBeInTheDocument();
+  });
+
+  it("renders a partial code block while streaming", () => {
+    render(<MarkdownMessage content={"Here:\n\n```js\nconst x"} isStreaming />);
+    expect(screen.getByText("js")).toBeInTheDocument();
+  });
+});
@@ -0,0 +1,111 @@
+import { render, screen, within } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { describe, expect, it, vi } from "vitest";
+import { ChatMessageList } from "../ChatMessageList";
+import { extractToolInvocations, formatToolPayload } from "../toolCalls";
+import type { ThreadMessage } from "../../../services/api";
+
+function buildMessage(overrides: Partial<ThreadMessage> & Pick<ThreadMessage, "id" | "role" | "content">): ThreadMessage {
+  return {
+    thread_id: 1,
+    sent_at: "2025-01-01T00:00:00Z",
+    processed: true,
+    tool_calls: null,
+    tool_call_id: null,
+    name: null,
+    parent_id: null,
+    message_type: null,
+    tool_name: null,
+    attachments: [],
+    ...overrides,
+  };
+}
+
+function renderList(messages: ThreadMessage[], extra: Partial<Parameters<typeof ChatMessageList>[0]> = {}) {
+  return render(
+    <ChatMessageList
+      messages={messages}
+      streamingMessages={new Map()}
+      streamingMessageId={null}
+      pendingTokenBuffer=""
+      onCopyMessage={vi.fn()}
+      {...extra}
+    />
+  );
+}
+
+describe("tool call bubbles", () => {
+  const assistant = buildMessage({
+    id: 10,
+    role: "assistant",
+    content: "",
+    tool_calls: [{ id: "call_1", name: "http_request", args: { url: "https://example.com", method: "GET" } }] as never,
+  });
+
+  it("pairs tool calls with their output and pretty-prints both", async () => {
+    const toolResult = buildMessage({
+      id: 11,
+      role: "tool",
+      content: '{"status":200}',
+      tool_call_id: "call_1",
+      tool_name: "http_request",
+      parent_id: 10,
+    });
+
+    renderList([assistant, toolResult]);
+
+    const bubble = screen.getByTestId("tool-call-bubble");
+    expect(within(bubble).getByText("http_request")).toBeInTheDocument();
+    expect(within(bubble).getByText("Completed")).toBeInTheDocument();
+
+    await userEvent.click(within(bubble).getByRole("button", { expanded: false }));
+
+    expect(within(bubble).getByText("Arguments")).toBeInTheDocument();
+    expect(within(bubble).getByText("Output")).toBeInTheDocument();
+    expect(bubble.textContent).toContain('"status": 200');
+  });
+
+  it("marks pending calls as running while the run is active and flags tool errors", () => {
+    const failing = buildMessage({
+      id: 12,
+      role: "assistant",
+      content: "",
+      tool_calls: [{ id: "call_2", name: "web_search", args: {} }] as never,
+    });
+    const failure = buildMessage({
+      id: 13,
+      role: "tool",
+      content: "<tool-error> quota exceeded",
+      tool_call_id: "call_2",
+      tool_name: "web_search",
+      parent_id: 12,
+    });
+
+    renderList([assistant, failing, failure], { isBusy: true });
+
+    const [pending, failed] = screen.getAllByTestId("tool-call-bubble");
+    expect(within(pending).getByText("Running…")).toBeInTheDocument();
+    expect(within(failed).getByText("Failed")).toBeInTheDocument();
+  });
+
+  it("shows streamed tool output before the thread is refetched", () => {
+    renderList([assistant], {
+      streamingToolOutputs: [
+        { messageId: 99, parentId: 10, toolCallId: "call_1", toolName: "http_request", content: "ok" },
+      ],
+    });
+
+    expect(screen.getByText("Completed")).toBeInTheDocument();
+  });
+});
+
+describe("toolCalls helpers", () => {
+  it("accepts OpenAI-style function calls with JSON-encoded arguments", () => {
+    const [invocation] = extractToolInvocations({
+      tool_calls: [{ id: "x", function: { name: "get_time", arguments: '{"tz":"UTC"}' } }] as never,
+    });
+    expect(invocation).toEqual({ id: "x", name: "get_time", args: '{"tz":"UTC"}' });
+    expect(formatToolPayload(invocation.args)).toEqual({ text: '{\n  "tz": "UTC"\n}', isJson: true });
+    expect(formatToolPayload("plain text")).toEqual({ text: "plain text", isJson: false });
+  });
+});
@@ -0,0 +1,44 @@
+import { describe, expect, it } from "vitest";
+import { resolveWindow, revealNewer, revealOlder, TAIL_WINDOW, windowAround } from "../messageWindow";
+
+const ids = (count: number, first = 1) => Array.from({ length: count }, (_, index) => first + index);
+
+describe("message window", () => {
+  it("follows the newest messages", () => {
+    const window = { ...TAIL_WINDOW, size: 3 };
+    expect(resolveWindow(ids(10), window)).toEqual({ start: 7, end: 10 });
+    expect(resolveWindow(ids(12), window)).toEqual({ start: 9, end: 12 });
+    expect(resolveWindow(ids(2), window)).toEqual({ start: 0, end: 2 });
+  });
+
+  it("grows upwards and leaves the tail once past the cap", () => {
+    const thread = ids(20);
+    const grown = revealOlder(thread, { ...TAIL_WINDOW, size: 4 }, 4, 10);
+    expect(grown).toEqual({ startId: null, size: 8, atTail: true });
+
+    const capped = revealOlder(thread, grown, 4, 10);
+    expect(capped).toEqual({ startId: 9, size: 10, atTail: false });
+    expect(resolveWindow(thread, capped)).toEqual({ start: 8, end: 18 });
+  });
+
+  it("stays on the same messages when older history is prepended", () => {
+    const window = { startId: 50, size: 10, atTail: false };
+    expect(resolveWindow(ids(100, 1), window)).toEqual({ start: 49, end: 59 });
+    expect(resolveWindow(ids(150, -49), window)).toEqual({ start: 99, end: 109 });
+  });
+
+  it("slides back down to the tail", () => {
+    const thread = ids(20);
+    const window = revealNewer(thread, { startId: 5, size: 10, atTail: false }, 4, 10);
+    expect(window).toEqual({ startId: 9, size: 10, atTail: false });
+    expect(revealNewer(thread, window, 4, 10)).toEqual({ startId: null, size: 10, atTail: true });
+  });
+
+  it("centres a jump target and clamps at either end", () => {
+    const thread = ids(100);
+    expect(windowAround(thread, 50, 10)).toEqual({ startId: 45, size: 10, atTail: false });
+    expect(windowAround(thread, 2, 10)).toEqual({ startId: 1, size: 10, atTail: false });
+    expect(windowAround(thread, 99, 10)).toEqual({ startId: null, size: 10, atTail: true });
+    expect(windowAround(thread, 500, 10)).toEqual(TAIL_WINDOW);
+  });
+});
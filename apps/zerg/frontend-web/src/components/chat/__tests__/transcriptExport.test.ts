@@ -0,0 +1,92 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { ThreadMessage } from "../../../services/api";
+
+const apiMocks = vi.hoisted(() => ({
+  fetchThreadMessagesPage: vi.fn(),
+}));
+
+vi.mock("../../../services/api", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../../../services/api")>();
+  return { ...actual, ...apiMocks };
+});
+
+import {
+  buildTranscript,
+  fetchAllThreadMessages,
+  transcriptToJson,
+  transcriptToMarkdown,
+} from "../transcriptExport";
+
+function message(id: number, role: string, content: string, extra: Partial<ThreadMessage> = {}): ThreadMessage {
+  return {
+    id,
+    thread_id: 3,
+    role,
+    content,
+    sent_at: `2025-01-01T10:00:0${id}Z`,
+    processed: true,
+    ...extra,
+  } as ThreadMessage;
+}
+
+const thread = [
+  message(1, "system", "You are helpful"),
+  message(2, "user", "Weather in Paris?"),
+  message(3, "assistant", "", { tool_calls: [{ id: "call-a", name: "get_weather", args: { city: "Paris" } }] }),
+  message(4, "tool", '{"temp": 21}', { tool_call_id: "call-a", parent_id: 3 }),
+  message(5, "assistant", "It is 21°C."),
+];
+
+const subject = { threadId: 3, title: "Trip", agentName: "Planner" };
+const exportedAt = new Date("2025-01-02T00:00:00Z");
+
+describe("transcript export", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("drops system frames and folds tool results into their call", () => {
+    const entries = buildTranscript(thread);
+    expect(entries.map((entry) => entry.id)).toEqual([2, 3, 5]);
+    expect(entries[1].tool_calls).toEqual([
+      { id: "call-a", name: "get_weather", args: { city: "Paris" }, output: '{"temp": 21}' },
+    ]);
+  });
+
+  it("keeps tool results it cannot match as their own entries", () => {
+    const entries = buildTranscript([message(2, "user", "hi"), message(4, "tool", "orphan", { name: "search" })]);
+    expect(entries.map((entry) => entry.role)).toEqual(["user", "tool"]);
+  });
+
+  it("renders Markdown with timestamps and tool calls", () => {
+    const markdown = transcriptToMarkdown(subject, buildTranscript(thread), exportedAt);
+    expect(markdown).toContain("# Trip");
+    expect(markdown).toContain("- Agent: Planner");
+    expect(markdown).toContain("### User · 2025-01-01T10:00:02Z");
+    expect(markdown).toContain("**Tool call:** `get_weather`");
+    expect(markdown).toContain('```json\n{\n  "temp": 21\n}\n```');
+    expect(markdown).not.toContain("You are helpful");
+  });
+
+  it("widens fences around content that contains backticks", () => {
+    const entries = buildTranscript([message(4, "tool", "use ```code```")]);
+    expect(transcriptToMarkdown(subject, entries, exportedAt)).toContain("````\nuse ```code```\n````");
+  });
+
+  it("serialises JSON with thread details", () => {
+    const parsed = JSON.parse(transcriptToJson(subject, buildTranscript(thread), exportedAt));
+    expect(parsed.thread).toEqual({ id: 3, title: "Trip", agent: "Planner" });
+    expect(parsed.exported_at).toBe("2025-01-02T00:00:00.000Z");
+    expect(parsed.messages).toHaveLength(3);
+  });
+
+  it("pages back through the whole thread", async () => {
+    apiMocks.fetchThreadMessagesPage
+      .mockResolvedValueOnce([message(3, "user", "c"), message(4, "assistant", "d")])
+      .mockResolvedValueOnce([message(1, "user", "a"), message(2, "assistant", "b")])
+      .mockResolvedValueOnce([]);
+    const messages = await fetchAllThreadMessages(3, 2);
+    expect(messages.map((m) => m.id)).toEqual([1, 2, 3, 4]);
+    expect(apiMocks.fetchThreadMessagesPage).toHaveBeenLastCalledWith(3, { beforeMessageId: 1, limit: 2 });
+  });
+});
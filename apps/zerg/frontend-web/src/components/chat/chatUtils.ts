@@ -1,14 +1,54 @@
-export function formatTimestamp(sentAt?: string | null): string {
-  if (!sentAt) return "";
-  try {
-    const date = new Date(sentAt);
-    return date.toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' });
-  } catch {
-    return "";
-  }
-}
+import type { MessageUsage, ThreadMessage } from "../../services/api";
 
 export function truncateText(text: string, maxLength: number): string {
   if (text.length <= maxLength) return text;
   return text.substring(0, maxLength) + "...";
 }
+
+export function formatFileSize(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+export function isImageType(contentType: string): boolean {
+  return contentType.startsWith("image/");
+}
+
+export function formatTokenCount(tokens: number): string {
+  if (tokens < 1000) return `${tokens}`;
+  if (tokens < 1_000_000) return `${(tokens / 1000).toFixed(tokens < 10_000 ? 1 : 0)}k`;
+  return `${(tokens / 1_000_000).toFixed(1)}M`;
+}
+
+export function formatCostUsd(cost: number): string {
+  if (cost === 0) return "$0.00";
+  // Single replies usually cost fractions of a cent
+  if (cost < 0.01) return `$${cost.toFixed(4)}`;
+  return `$${cost.toFixed(2)}`;
+}
+
+export function formatLatency(durationMs: number): string {
+  if (durationMs < 1000) return `${durationMs}ms`;
+  return `${(durationMs / 1000).toFixed(1)}s`;
+}
+
+export interface ThreadUsageTotals {
+  replies: number;
+  totalTokens: number;
+  costUsd: number | null;
+}
+
+// Sum the per-reply usage recorded on assistant messages; cost stays null
+// when no reply has a price (e.g. models missing from the pricing table)
+export function summarizeThreadUsage(messages: ThreadMessage[]): ThreadUsageTotals {
+  const usages = messages
+    .map((msg) => (msg.role === "assistant" ? msg.usage : null))
+    .filter((usage): usage is MessageUsage => usage != null);
+  const priced = usages.filter((usage) => usage.cost_usd != null);
+  return {
+    replies: usages.length,
+    totalTokens: usages.reduce((sum, usage) => sum + (usage.total_tokens ?? 0), 0),
+    costUsd: priced.length > 0 ? priced.reduce((sum, usage) => sum + (usage.cost_usd ?? 0), 0) : null,
+  };
+}
@@ -0,0 +1,62 @@
+/**
+ * The slice of a thread's messages that is actually rendered. Long threads
+ * only mount a bounded window; sentinels at either end grow or slide it as
+ * the user scrolls. The window is anchored by message id rather than by
+ * index so paging older history in at the front doesn't move it.
+ */
+
+export const INITIAL_WINDOW = 60;
+export const WINDOW_STEP = 40;
+export const MAX_WINDOW = 160;
+
+export interface MessageWindow {
+  // First rendered message; ignored while following the tail
+  startId: number | null;
+  size: number;
+  // Stick to the newest message as new ones arrive
+  atTail: boolean;
+}
+
+export const TAIL_WINDOW: MessageWindow = { startId: null, size: INITIAL_WINDOW, atTail: true };
+
+export interface WindowRange {
+  start: number;
+  end: number;
+}
+
+/** Index range of *window* within *ids* (end exclusive). */
+export function resolveWindow(ids: number[], window: MessageWindow): WindowRange {
+  const count = ids.length;
+  if (window.atTail || window.startId === null) {
+    return { start: Math.max(0, count - window.size), end: count };
+  }
+  const start = Math.max(0, ids.indexOf(window.startId));
+  return { start, end: Math.min(count, start + window.size) };
+}
+
+function windowFor(ids: number[], start: number, end: number): MessageWindow {
+  const atTail = end >= ids.length;
+  return { startId: atTail ? null : (ids[start] ?? null), size: end - start, atTail };
+}
+
+/** Render *step* more messages above, dropping from the bottom once past *max*. */
+export function revealOlder(ids: number[], window: MessageWindow, step = WINDOW_STEP, max = MAX_WINDOW): MessageWindow {
+  const { start, end } = resolveWindow(ids, window);
+  const nextStart = Math.max(0, start - step);
+  return windowFor(ids, nextStart, Math.min(end, nextStart + max));
+}
+
+/** Render *step* more messages below, dropping from the top once past *max*. */
+export function revealNewer(ids: number[], window: MessageWindow, step = WINDOW_STEP, max = MAX_WINDOW): MessageWindow {
+  const { start, end } = resolveWindow(ids, window);
+  const nextEnd = Math.min(ids.length, end + step);
+  return windowFor(ids, Math.max(start, nextEnd - max), nextEnd);
+}
+
+/** A window with *id* roughly in the middle, for jumping to a message out of view. */
+export function windowAround(ids: number[], id: number, size = INITIAL_WINDOW): MessageWindow {
+  const index = ids.indexOf(id);
+  if (index < 0) return TAIL_WINDOW;
+  const start = Math.max(0, Math.min(index - Math.floor(size / 2), ids.length - size));
+  return windowFor(ids, start, Math.min(ids.length, start + size));
+}
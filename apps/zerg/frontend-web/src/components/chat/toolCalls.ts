@@ -0,0 +1,68 @@
+import type { ThreadMessage } from "../../services/api";
+
+// One tool invocation requested by an assistant message
+export interface ToolInvocation {
+  id: string;
+  name: string;
+  args: unknown;
+}
+
+// A tool result that arrived over the websocket before the thread refetch
+export interface StreamedToolOutput {
+  messageId: number | null;
+  parentId: number | null;
+  toolCallId: string | null;
+  toolName: string | null;
+  content: string;
+}
+
+/**
+ * Normalise ``tool_calls`` from an assistant message. LangChain stores
+ * ``{ id, name, args }``; OpenAI-style ``{ id, function: { name, arguments } }``
+ * (with JSON-encoded arguments) is accepted as well.
+ */
+export function extractToolInvocations(message: Pick<ThreadMessage, "tool_calls">): ToolInvocation[] {
+  const rawCalls = (message.tool_calls ?? []) as Array<Record<string, unknown>>;
+  return rawCalls.flatMap((call, index) => {
+    if (!call || typeof call !== "object") return [];
+    const fn = call.function as { name?: string; arguments?: unknown } | undefined;
+    const name = (call.name as string | undefined) ?? fn?.name;
+    if (!name) return [];
+    return [
+      {
+        id: (call.id as string | undefined) ?? `call-${index}`,
+        name,
+        args: call.args ?? fn?.arguments ?? null,
+      },
+    ];
+  });
+}
+
+/**
+ * Pretty-print tool arguments/output. Strings holding JSON are parsed first so
+ * they get indented too; anything else is shown verbatim.
+ */
+export function formatToolPayload(value: unknown): { text: string; isJson: boolean } {
+  if (value == null || value === "") return { text: "", isJson: false };
+  if (typeof value === "string") {
+    const trimmed = value.trim();
+    if (trimmed.startsWith("{") || trimmed.startsWith("[")) {
+      try {
+        return { text: JSON.stringify(JSON.parse(trimmed), null, 2), isJson: true };
+      } catch {
+        // Not JSON after all – fall through to plain text
+      }
+    }
+    return { text: value, isJson: false };
+  }
+  try {
+    return { text: JSON.stringify(value, null, 2), isJson: true };
+  } catch {
+    return { text: String(value), isJson: false };
+  }
+}
+
+// The agent runtime prefixes failed tool observations with "<tool-error>"
+export function isToolErrorOutput(output: string | null | undefined): boolean {
+  return output != null && output.trimStart().startsWith("<tool-error>");
+}
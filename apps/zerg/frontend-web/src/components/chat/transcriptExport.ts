@@ -0,0 +1,168 @@
+import { fetchThreadMessagesPage, THREAD_MESSAGES_PAGE_SIZE, type ThreadMessage } from "../../services/api";
+import { exportFilename } from "../../lib/export";
+import { downloadTextFile } from "../../lib/download";
+import { extractToolInvocations, formatToolPayload } from "./toolCalls";
+
+export type TranscriptFormat = "markdown" | "json";
+
+export const TRANSCRIPT_FORMAT_LABELS: Record<TranscriptFormat, string> = {
+  markdown: "Markdown",
+  json: "JSON",
+};
+
+export interface TranscriptToolCall {
+  id: string;
+  name: string;
+  args: unknown;
+  output: string | null;
+}
+
+export interface TranscriptEntry {
+  id: number;
+  role: string;
+  author: string | null;
+  sent_at: string | null;
+  content: string;
+  attachments: string[];
+  tool_calls: TranscriptToolCall[];
+}
+
+export interface TranscriptSubject {
+  threadId: number;
+  title: string;
+  agentName?: string;
+}
+
+/**
+ * Page a thread's history in from the newest message back, so the export
+ * covers older messages the chat view hasn't loaded.
+ */
+export async function fetchAllThreadMessages(
+  threadId: number,
+  pageSize = THREAD_MESSAGES_PAGE_SIZE
+): Promise<ThreadMessage[]> {
+  let messages: ThreadMessage[] = [];
+  let beforeMessageId: number | undefined;
+
+  for (;;) {
+    const page = await fetchThreadMessagesPage(threadId, { beforeMessageId, limit: pageSize });
+    messages = [...page, ...messages];
+    if (page.length < pageSize) {
+      break;
+    }
+    beforeMessageId = page[0].id;
+  }
+
+  return messages;
+}
+
+/**
+ * What a reader of the conversation saw: system frames are dropped, and each
+ * tool result is folded into the assistant message that requested it.
+ * Results whose call can't be matched are kept as their own "tool" entries.
+ */
+export function buildTranscript(messages: ThreadMessage[]): TranscriptEntry[] {
+  const toolResults = messages.filter((message) => message.role === "tool");
+  const claimed = new Set<number>();
+
+  const entries = messages
+    .filter((message) => message.role !== "system" && message.role !== "tool")
+    .map((message): TranscriptEntry => {
+      const toolCalls = extractToolInvocations(message).map((invocation) => {
+        const result = toolResults.find((tool) => !claimed.has(tool.id) && tool.tool_call_id === invocation.id);
+        if (result) claimed.add(result.id);
+        return { ...invocation, output: result?.content ?? null };
+      });
+      return toEntry(message, toolCalls);
+    });
+
+  const unmatched = toolResults.filter((tool) => !claimed.has(tool.id)).map((tool) => toEntry(tool, []));
+  return [...entries, ...unmatched].sort((left, right) => left.id - right.id);
+}
+
+function toEntry(message: ThreadMessage, toolCalls: TranscriptToolCall[]): TranscriptEntry {
+  return {
+    id: message.id,
+    role: message.role,
+    author: message.role === "user" ? (message.author?.display_name ?? null) : null,
+    sent_at: message.sent_at ?? null,
+    content: message.content,
+    attachments: (message.attachments ?? []).map((attachment) => attachment.filename),
+    tool_calls: toolCalls,
+  };
+}
+
+function speaker(entry: TranscriptEntry): string {
+  if (entry.role === "tool") return "Tool result";
+  if (entry.role === "assistant") return "Assistant";
+  return entry.author ?? "User";
+}
+
+function fenced(text: string, language = ""): string {
+  // A fence longer than any run of backticks inside keeps the block intact
+  const longestRun = Math.max(2, ...(text.match(/`+/g) ?? []).map((run) => run.length));
+  const fence = "`".repeat(longestRun + 1);
+  return `${fence}${language}\n${text}\n${fence}`;
+}
+
+export function transcriptToMarkdown(
+  subject: TranscriptSubject,
+  entries: TranscriptEntry[],
+  exportedAt = new Date()
+): string {
+  const header = [
+    `# ${subject.title}`,
+    "",
+    ...(subject.agentName ? [`- Agent: ${subject.agentName}`] : []),
+    `- Thread: #${subject.threadId}`,
+    `- Exported: ${exportedAt.toISOString()}`,
+  ];
+
+  const body = entries.map((entry) => {
+    const lines = [`### ${speaker(entry)}${entry.sent_at ? ` · ${entry.sent_at}` : ""}`, ""];
+    if (entry.content.trim()) {
+      lines.push(entry.role === "tool" ? fenced(formatToolPayload(entry.content).text) : entry.content.trim(), "");
+    }
+    if (entry.attachments.length > 0) {
+      lines.push(`Attachments: ${entry.attachments.join(", ")}`, "");
+    }
+    for (const call of entry.tool_calls) {
+      const args = formatToolPayload(call.args);
+      lines.push(`**Tool call:** \`${call.name}\``, "");
+      if (args.text) lines.push(fenced(args.text, args.isJson ? "json" : ""), "");
+      if (call.output != null) {
+        const output = formatToolPayload(call.output);
+        lines.push("Output:", "", fenced(output.text, output.isJson ? "json" : ""), "");
+      }
+    }
+    return lines.join("\n").trimEnd();
+  });
+
+  return [header.join("\n"), ...body].join("\n\n---\n\n") + "\n";
+}
+
+export function transcriptToJson(
+  subject: TranscriptSubject,
+  entries: TranscriptEntry[],
+  exportedAt = new Date()
+): string {
+  const document = {
+    thread: { id: subject.threadId, title: subject.title, agent: subject.agentName ?? null },
+    exported_at: exportedAt.toISOString(),
+    messages: entries,
+  };
+  return JSON.stringify(document, null, 2) + "\n";
+}
+
+/** Build the transcript of *messages* in *format* and download it. */
+export function downloadTranscript(subject: TranscriptSubject, messages: ThreadMessage[], format: TranscriptFormat) {
+  const entries = buildTranscript(messages);
+  const name = subject.title || `thread-${subject.threadId}`;
+  if (format === "json") {
+    const filename = exportFilename(name, "transcript", "json");
+    downloadTextFile(filename, transcriptToJson(subject, entries), "application/json");
+  } else {
+    const filename = exportFilename(name, "transcript", "md");
+    downloadTextFile(filename, transcriptToMarkdown(subject, entries), "text/markdown");
+  }
+}
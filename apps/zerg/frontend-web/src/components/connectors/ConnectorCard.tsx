@@ -6,7 +6,7 @@
 import type { ConnectorStatus, AccountConnectorStatus } from "../../types/connectors";
 
 // Connectors that support OAuth flow
-const OAUTH_CONNECTORS = ["github"] as const;
+const OAUTH_CONNECTORS = ["github", "slack"] as const;
 type OAuthConnector = (typeof OAUTH_CONNECTORS)[number];
 
 function isOAuthConnector(type: string): type is OAuthConnector {
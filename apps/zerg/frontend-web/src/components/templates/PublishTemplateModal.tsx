@@ -0,0 +1,151 @@
+import { useMemo, useState, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import toast from "react-hot-toast";
+import {
+  fetchTemplateCategories,
+  publishWorkflowTemplate,
+  updateWorkflowCanvas,
+  type Workflow,
+  type WorkflowDataInput,
+} from "../../services/api";
+import { useFocusTrap } from "../../lib/useFocusTrap";
+import { canvasPreviewDataUrl, parseTags } from "../../lib/templateGallery";
+import "../../styles/css/template-gallery.css";
+
+type PublishTemplateModalProps = {
+  isOpen: boolean;
+  onClose: () => void;
+  workflow: Workflow;
+  // The canvas as currently shown, which may be ahead of the last autosave
+  canvas: WorkflowDataInput;
+};
+
+/** Publish the current workflow to the template gallery with a thumbnail of its canvas. */
+export function PublishTemplateModal({ isOpen, onClose, workflow, canvas }: PublishTemplateModalProps) {
+  const dialogRef = useFocusTrap(isOpen, onClose);
+  const queryClient = useQueryClient();
+  const [name, setName] = useState(workflow.name);
+  const [description, setDescription] = useState(workflow.description ?? "");
+  const [category, setCategory] = useState("");
+  const [tags, setTags] = useState("");
+  const [isPublic, setIsPublic] = useState(true);
+
+  const { data: categories = [] } = useQuery({
+    queryKey: ["template-categories"],
+    queryFn: fetchTemplateCategories,
+    enabled: isOpen,
+  });
+  const preview = useMemo(() => canvasPreviewDataUrl(canvas), [canvas]);
+
+  const publishMutation = useMutation({
+    mutationFn: async () => {
+      // The server publishes its stored canvas, so flush any pending edits first
+      await updateWorkflowCanvas(canvas);
+      return publishWorkflowTemplate({
+        workflow_id: workflow.id,
+        name: name.trim(),
+        description: description.trim() || null,
+        category: category.trim(),
+        tags: parseTags(tags),
+        preview_image_url: preview,
+        is_public: isPublic,
+      });
+    },
+    onSuccess: (template) => {
+      toast.success(`Published "${template.name}"`);
+      queryClient.invalidateQueries({ queryKey: ["templates"] });
+      queryClient.invalidateQueries({ queryKey: ["template-categories"] });
+      onClose();
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to publish template: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  if (!isOpen) return null;
+
+  const isEmpty = canvas.nodes.length === 0;
+  const canSubmit = !isEmpty && name.trim() !== "" && category.trim() !== "" && !publishMutation.isPending;
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    if (canSubmit) publishMutation.mutate();
+  };
+
+  return (
+    <div className="template-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        ref={dialogRef}
+        className="template-modal"
+        onClick={(e) => e.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="publish-template-title"
+        tabIndex={-1}
+      >
+        <header className="template-modal-header">
+          <h3 id="publish-template-title">Publish as Template</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <form className="template-publish-form" onSubmit={handleSubmit}>
+          <img className="template-preview" src={preview} alt="Preview of the workflow canvas" />
+          {isEmpty && (
+            <p className="template-publish-warning" role="alert">
+              Add at least one node before publishing.
+            </p>
+          )}
+
+          <label className="form-field">
+            Name
+            <input type="text" value={name} onChange={(e) => setName(e.target.value)} maxLength={200} required />
+          </label>
+          <label className="form-field">
+            Description
+            <textarea value={description} onChange={(e) => setDescription(e.target.value)} rows={3} />
+          </label>
+          <label className="form-field">
+            Category
+            <input
+              type="text"
+              value={category}
+              onChange={(e) => setCategory(e.target.value)}
+              list="template-category-options"
+              maxLength={100}
+              required
+            />
+            <datalist id="template-category-options">
+              {categories.map((option) => (
+                <option key={option} value={option} />
+              ))}
+            </datalist>
+          </label>
+          <label className="form-field">
+            Tags
+            <input
+              type="text"
+              value={tags}
+              onChange={(e) => setTags(e.target.value)}
+              placeholder="Comma separated, e.g. email, daily"
+            />
+          </label>
+          <label className="template-gallery-toggle">
+            <input type="checkbox" checked={isPublic} onChange={(e) => setIsPublic(e.target.checked)} />
+            <span>Visible to everyone</span>
+          </label>
+
+          <div className="template-modal-actions">
+            <button type="button" className="btn-secondary" onClick={onClose}>
+              Cancel
+            </button>
+            <button type="submit" className="btn-primary" disabled={!canSubmit}>
+              {publishMutation.isPending ? "Publishing…" : "Publish"}
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,210 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import clsx from "clsx";
+import toast from "react-hot-toast";
+import {
+  favoriteTemplate,
+  fetchTemplateCategories,
+  fetchTemplates,
+  rateTemplate,
+  type TemplateListParams,
+  type TemplateSort,
+  type WorkflowTemplate,
+} from "../../services/api";
+import { useFocusTrap } from "../../lib/useFocusTrap";
+import { MAX_RATING, TEMPLATE_SORT_LABELS, formatRating, replaceTemplate } from "../../lib/templateGallery";
+import "../../styles/css/template-gallery.css";
+
+type TemplateGalleryModalProps = {
+  isOpen: boolean;
+  onClose: () => void;
+  onUseTemplate: (template: WorkflowTemplate) => void;
+};
+
+/**
+ * Browse published workflow templates. Ratings and favorites are per user;
+ * the list can be narrowed by category, minimum rating or favorites.
+ */
+export function TemplateGalleryModal({ isOpen, onClose, onUseTemplate }: TemplateGalleryModalProps) {
+  const dialogRef = useFocusTrap(isOpen, onClose);
+  const queryClient = useQueryClient();
+  const [filters, setFilters] = useState<TemplateListParams>({ sort: "recent" });
+  const queryKey = ["templates", filters] as const;
+
+  const { data: templates = [], isLoading, error } = useQuery({
+    queryKey,
+    queryFn: () => fetchTemplates(filters),
+    enabled: isOpen,
+  });
+  const { data: categories = [] } = useQuery({
+    queryKey: ["template-categories"],
+    queryFn: fetchTemplateCategories,
+    enabled: isOpen,
+  });
+
+  const applyUpdate = (updated: WorkflowTemplate) => {
+    queryClient.setQueryData<WorkflowTemplate[]>(queryKey, (current) =>
+      current ? replaceTemplate(current, updated, filters.favoritesOnly) : current
+    );
+    // Other filter combinations (and this one's sort order) are now stale
+    queryClient.invalidateQueries({ queryKey: ["templates"], refetchType: "none" });
+  };
+
+  const rateMutation = useMutation({
+    mutationFn: ({ id, rating }: { id: number; rating: number | null }) => rateTemplate(id, rating),
+    onSuccess: applyUpdate,
+    onError: (err: Error) => toast.error(`Failed to rate template: ${err.message}`, { duration: 6000 }),
+  });
+  const favoriteMutation = useMutation({
+    mutationFn: ({ id, favorite }: { id: number; favorite: boolean }) => favoriteTemplate(id, favorite),
+    onSuccess: applyUpdate,
+    onError: (err: Error) => toast.error(`Failed to update favorites: ${err.message}`, { duration: 6000 }),
+  });
+
+  if (!isOpen) return null;
+
+  const updateFilters = (patch: Partial<TemplateListParams>) => setFilters((current) => ({ ...current, ...patch }));
+
+  return (
+    <div className="template-modal-backdrop" onClick={onClose} role="presentation">
+      <div
+        ref={dialogRef}
+        className="template-modal template-gallery"
+        onClick={(e) => e.stopPropagation()}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="template-gallery-title"
+        tabIndex={-1}
+      >
+        <header className="template-modal-header">
+          <h3 id="template-gallery-title">Template Gallery</h3>
+          <button type="button" className="close-btn" onClick={onClose} aria-label="Close">
+            ×
+          </button>
+        </header>
+
+        <div className="template-gallery-filters">
+          <label>
+            <span>Category</span>
+            <select
+              value={filters.category ?? ""}
+              onChange={(e) => updateFilters({ category: e.target.value || null })}
+            >
+              <option value="">All categories</option>
+              {categories.map((category) => (
+                <option key={category} value={category}>
+                  {category}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label>
+            <span>Sort</span>
+            <select
+              value={filters.sort}
+              onChange={(e) => updateFilters({ sort: e.target.value as TemplateSort })}
+            >
+              {(Object.keys(TEMPLATE_SORT_LABELS) as TemplateSort[]).map((sort) => (
+                <option key={sort} value={sort}>
+                  {TEMPLATE_SORT_LABELS[sort]}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label>
+            <span>Rating</span>
+            <select
+              value={filters.minRating ?? ""}
+              onChange={(e) => updateFilters({ minRating: e.target.value ? Number(e.target.value) : null })}
+            >
+              <option value="">Any rating</option>
+              {[4, 3, 2].map((stars) => (
+                <option key={stars} value={stars}>
+                  {stars}★ and up
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="template-gallery-toggle">
+            <input
+              type="checkbox"
+              checked={!!filters.favoritesOnly}
+              onChange={(e) => updateFilters({ favoritesOnly: e.target.checked })}
+            />
+            <span>Favorites only</span>
+          </label>
+        </div>
+
+        <div className="template-gallery-body">
+          {isLoading ? (
+            <div className="empty-state">Loading templates…</div>
+          ) : error ? (
+            <div className="empty-state" role="alert">
+              Failed to load templates: {(error as Error).message}
+            </div>
+          ) : templates.length === 0 ? (
+            <div className="empty-state">No templates match these filters.</div>
+          ) : (
+            <ul className="template-grid">
+              {templates.map((template) => (
+                <li key={template.id} className="template-card" data-testid={`template-card-${template.id}`}>
+                  {template.preview_image_url ? (
+                    <img className="template-preview" src={template.preview_image_url} alt="" />
+                  ) : (
+                    <div className="template-preview template-preview--empty" aria-hidden="true" />
+                  )}
+                  <div className="template-card-body">
+                    <div className="template-card-title">
+                      <h4>{template.name}</h4>
+                      <button
+                        type="button"
+                        className={clsx("template-favorite", { active: template.is_favorite })}
+                        aria-pressed={template.is_favorite}
+                        aria-label={template.is_favorite ? "Remove from favorites" : "Add to favorites"}
+                        title={`${template.favorite_count} favorite${template.favorite_count === 1 ? "" : "s"}`}
+                        onClick={() => favoriteMutation.mutate({ id: template.id, favorite: !template.is_favorite })}
+                      >
+                        {template.is_favorite ? "♥" : "♡"}
+                      </button>
+                    </div>
+                    <span className="template-category">{template.category}</span>
+                    {template.description && <p className="template-description">{template.description}</p>}
+                    <div className="template-rating">
+                      <div className="template-stars" role="group" aria-label="Your rating">
+                        {Array.from({ length: MAX_RATING }, (_, index) => index + 1).map((stars) => {
+                          const filled = (template.my_rating ?? 0) >= stars;
+                          return (
+                            <button
+                              key={stars}
+                              type="button"
+                              className={clsx("template-star", { filled })}
+                              aria-pressed={template.my_rating === stars}
+                              aria-label={`Rate ${stars} star${stars === 1 ? "" : "s"}`}
+                              onClick={() =>
+                                rateMutation.mutate({
+                                  id: template.id,
+                                  // Clicking your current rating clears it
+                                  rating: template.my_rating === stars ? null : stars,
+                                })
+                              }
+                            >
+                              {filled ? "★" : "☆"}
+                            </button>
+                          );
+                        })}
+                      </div>
+                      <span className="template-rating-summary">{formatRating(template)}</span>
+                    </div>
+                    <button type="button" className="btn-primary" onClick={() => onUseTemplate(template)}>
+                      Use template
+                    </button>
+                  </div>
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,110 @@
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { TemplateGalleryModal } from "../TemplateGalleryModal";
+import {
+  favoriteTemplate,
+  fetchTemplateCategories,
+  fetchTemplates,
+  rateTemplate,
+  type WorkflowTemplate,
+} from "../../../services/api";
+
+vi.mock("../../../services/api", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../../../services/api")>();
+  return {
+    ...actual,
+    fetchTemplates: vi.fn(),
+    fetchTemplateCategories: vi.fn(),
+    rateTemplate: vi.fn(),
+    favoriteTemplate: vi.fn(),
+  };
+});
+
+const fetchTemplatesMock = fetchTemplates as unknown as vi.MockedFunction<typeof fetchTemplates>;
+const fetchCategoriesMock = fetchTemplateCategories as unknown as vi.MockedFunction<typeof fetchTemplateCategories>;
+const rateTemplateMock = rateTemplate as unknown as vi.MockedFunction<typeof rateTemplate>;
+const favoriteTemplateMock = favoriteTemplate as unknown as vi.MockedFunction<typeof favoriteTemplate>;
+
+function buildTemplate(overrides: Partial<WorkflowTemplate> = {}): WorkflowTemplate {
+  return {
+    id: 1,
+    name: "Inbox triage",
+    description: "Sort new mail",
+    category: "email",
+    canvas: { nodes: [], edges: [] },
+    tags: [],
+    preview_image_url: null,
+    created_by: 2,
+    is_public: true,
+    created_at: "2026-10-01T00:00:00Z",
+    updated_at: "2026-10-01T00:00:00Z",
+    rating_avg: null,
+    rating_count: 0,
+    favorite_count: 0,
+    my_rating: null,
+    is_favorite: false,
+    ...overrides,
+  };
+}
+
+function renderGallery(onUseTemplate = vi.fn()) {
+  const queryClient = new QueryClient({ defaultOptions: { queries: { retry: false }, mutations: { retry: false } } });
+  render(
+    <QueryClientProvider client={queryClient}>
+      <TemplateGalleryModal isOpen onClose={vi.fn()} onUseTemplate={onUseTemplate} />
+    </QueryClientProvider>
+  );
+  return onUseTemplate;
+}
+
+describe("TemplateGalleryModal", () => {
+  beforeEach(() => {
+    fetchTemplatesMock.mockReset();
+    fetchCategoriesMock.mockReset();
+    fetchCategoriesMock.mockResolvedValue(["email", "ops"]);
+    rateTemplateMock.mockReset();
+    favoriteTemplateMock.mockReset();
+  });
+
+  it("passes sort and filter choices to the API", async () => {
+    fetchTemplatesMock.mockResolvedValue([buildTemplate()]);
+    renderGallery();
+    await screen.findByText("Inbox triage");
+    expect(fetchTemplatesMock).toHaveBeenLastCalledWith({ sort: "recent" });
+
+    await userEvent.selectOptions(screen.getByLabelText("Sort"), "rating");
+    await userEvent.selectOptions(screen.getByLabelText("Rating"), "4");
+    await userEvent.click(screen.getByLabelText("Favorites only"));
+
+    await waitFor(() =>
+      expect(fetchTemplatesMock).toHaveBeenLastCalledWith({ sort: "rating", minRating: 4, favoritesOnly: true })
+    );
+  });
+
+  it("rates and favorites a template and shows the server's totals", async () => {
+    fetchTemplatesMock.mockResolvedValue([buildTemplate()]);
+    rateTemplateMock.mockResolvedValue(buildTemplate({ my_rating: 4, rating_avg: 4, rating_count: 1 }));
+    favoriteTemplateMock.mockResolvedValue(
+      buildTemplate({ my_rating: 4, rating_avg: 4, rating_count: 1, is_favorite: true, favorite_count: 1 })
+    );
+    const onUseTemplate = renderGallery();
+    await screen.findByText("No ratings yet");
+
+    await userEvent.click(screen.getByRole("button", { name: "Rate 4 stars" }));
+    expect(rateTemplateMock).toHaveBeenCalledWith(1, 4);
+    expect(await screen.findByText("4.0 ★ (1 rating)")).toBeInTheDocument();
+    expect(screen.getByRole("button", { name: "Rate 4 stars" })).toHaveAttribute("aria-pressed", "true");
+
+    await userEvent.click(screen.getByRole("button", { name: "Add to favorites" }));
+    expect(favoriteTemplateMock).toHaveBeenCalledWith(1, true);
+    expect(await screen.findByRole("button", { name: "Remove from favorites" })).toHaveAttribute(
+      "aria-pressed",
+      "true"
+    );
+
+    await userEvent.click(screen.getByRole("button", { name: "Use template" }));
+    expect(onUseTemplate).toHaveBeenCalledWith(expect.objectContaining({ id: 1 }));
+  });
+});
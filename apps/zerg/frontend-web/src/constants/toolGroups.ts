@@ -4,6 +4,7 @@ export const TOOL_GROUPS: Record<string, string[]> = {
     "github_list_issues",
     "github_get_issue",
     "github_add_comment",
+    "github_add_labels",
     "github_list_pull_requests",
     "github_get_pull_request",
   ],
@@ -15,7 +16,7 @@ export const TOOL_GROUPS: Record<string, string[]> = {
     "jira_transition_issue",
     "jira_update_issue",
   ],
-  slack: ["send_slack_webhook"],
+  slack: ["send_slack_webhook", "send_slack_message"],
   discord: ["send_discord_webhook"],
   email: ["send_email"],
   sms: ["send_sms"],
@@ -57,6 +57,26 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/agents/usage": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Read Agents Usage
+         * @description Tokens and cost per agent over the last ``days`` days; same scope and paging as ``/dashboard``.
+         */
+        get: operations["read_agents_usage_api_agents_usage_get"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/agents/{agent_id}": {
         parameters: {
             query?: never;
@@ -73,7 +93,11 @@ export interface paths {
         delete: operations["delete_agent_api_agents__agent_id__delete"];
         options?: never;
         head?: never;
-        patch?: never;
+        /**
+         * Patch Agent
+         * @description Apply a raw edit from the JSON editor.
+         */
+        patch: operations["patch_agent_api_agents__agent_id__patch"];
         trace?: never;
     };
     "/api/agents/{agent_id}/details": {
@@ -618,6 +642,9 @@ export interface paths {
         /**
          * List Agent Runs
          * @description Return latest *limit* runs for the given agent (descending).
+         *
+         *     Pass the smallest id from the previous page as *before_id* to fetch the
+         *     next (older) page.
          */
         get: operations["list_agent_runs_api_agents__agent_id__runs_get"];
         put?: never;
@@ -840,6 +867,30 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/workflow-executions/{execution_id}/run-from/{node_id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Run From Node
+         * @description Start a new run at *node_id*, reusing *execution_id*'s outputs for everything else.
+         *
+         *     Only the node and what follows it on the current canvas run; the new
+         *     execution keeps the source's inputs. 409 while the source is still running
+         *     or when a node feeding the subgraph has no cached output.
+         */
+        get?: never;
+        put?: never;
+        post: operations["run_from_node_api_workflow_executions__execution_id__run_from__node_id__post"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/workflow-executions/{workflow_id}/start": {
         parameters: {
             query?: never;
@@ -940,7 +991,9 @@ export interface paths {
         };
         /**
          * Get Execution History
-         * @description Get the execution history of a workflow.
+         * @description The last *limit* executions of a workflow, newest first.
+         *
+         *     Each carries the inputs it was started with, so the canvas can re-run it.
          */
         get: operations["get_execution_history_api_workflow_executions_history__workflow_id__get"];
         put?: never;
@@ -951,6 +1004,28 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/workflow-executions/history/{workflow_id}/node-cache": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get Node Output Cache
+         * @description Nodes with a cached output in the workflow's latest finished execution.
+         *
+         *     The canvas marks them and offers to run from any node they feed.
+         */
+        get: operations["get_node_output_cache_api_workflow_executions_history__workflow_id__node_cache_get"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/workflow-executions/{execution_id}/export": {
         parameters: {
             query?: never;
@@ -1138,6 +1213,26 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/users/me/capabilities": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Read Current User Capabilities
+         * @description Return what the current user may do so the UI can gate controls up front.
+         */
+        get: operations["read_current_user_capabilities_api_users_me_capabilities_get"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/users/me/avatar": {
         parameters: {
             query?: never;
@@ -1634,6 +1729,14 @@ export interface components {
             last_error?: string | null;
             /** Allowed Tools */
             allowed_tools?: string[] | null;
+            /** Prompt Variables */
+            prompt_variables?: {
+                [key: string]: string;
+            } | null;
+            /** Description */
+            description?: string | null;
+            /** Tags */
+            tags?: string[] | null;
             /** Id */
             id: number;
             /** Owner Id */
@@ -1660,6 +1763,29 @@ export interface components {
             next_run_at?: string | null;
             /** Last Run At */
             last_run_at?: string | null;
+            /** Deleted At */
+            deleted_at?: string | null;
+            /** Paused At */
+            paused_at?: string | null;
+        };
+        /**
+         * AgentBudgetStatus
+         * @description Spend against a per-agent cap for the current UTC day or month.
+         */
+        AgentBudgetStatus: {
+            /** Agent Id */
+            agent_id: number;
+            /**
+             * Period
+             * @enum {string}
+             */
+            period: "daily" | "monthly";
+            /** Limit Usd */
+            limit_usd: number;
+            /** Spent Usd */
+            spent_usd: number;
+            /** Percent */
+            percent: number;
         };
         /**
          * AgentCreate
@@ -1678,6 +1804,21 @@ export interface components {
             config?: Record<string, never> | null;
             /** Allowed Tools */
             allowed_tools?: string[] | null;
+            /** Prompt Variables */
+            prompt_variables?: {
+                [key: string]: string;
+            } | null;
+            /** Description */
+            description?: string | null;
+            /** Tags */
+            tags?: string[] | null;
+        };
+        /** AgentDailyRunCount */
+        AgentDailyRunCount: {
+            /** Date */
+            date: string;
+            /** Count */
+            count: number;
         };
         /**
          * AgentDetails
@@ -1720,9 +1861,11 @@ export interface components {
             /** Agent Id */
             agent_id: number;
             /** Thread Id */
-            thread_id: number;
+            thread_id?: number | null;
             status: components["schemas"]["RunStatus"];
             trigger: components["schemas"]["RunTrigger"];
+            /** Queue Position */
+            queue_position?: number | null;
             /** Started At */
             started_at?: string | null;
             /** Finished At */
@@ -1735,6 +1878,88 @@ export interface components {
             total_cost_usd?: number | null;
             /** Error */
             error?: string | null;
+            /** Trace Id */
+            trace_id?: string | null;
+            /** Output */
+            output?: unknown;
+            /** Schema Errors */
+            schema_errors?: string[] | null;
+        };
+        /**
+         * AgentInstructionVersionOut
+         * @description One saved revision of an agent's system/task instructions.
+         */
+        AgentInstructionVersionOut: {
+            /** Id */
+            id: number;
+            /** Agent Id */
+            agent_id: number;
+            /** Version */
+            version: number;
+            /** System Instructions */
+            system_instructions: string;
+            /** Task Instructions */
+            task_instructions: string;
+            /** Author Id */
+            author_id?: number | null;
+            /** Author Email */
+            author_email?: string | null;
+            /** Restored From Version */
+            restored_from_version?: number | null;
+            /** Created At */
+            created_at?: string | null;
+        };
+        /**
+         * AgentModelParams
+         * @description Generation parameters of an agent's runs and where each one comes from.
+         */
+        AgentModelParams: {
+            /** Agent Id */
+            agent_id: number;
+            /** Model */
+            model: string;
+            defaults: components["schemas"]["ModelParams"];
+            overrides: components["schemas"]["ModelParams"];
+            effective: components["schemas"]["ModelParams"];
+        };
+        /** AgentModelUsage */
+        AgentModelUsage: {
+            /** Model */
+            model: string;
+            /** Run Count */
+            run_count: number;
+            /** Total Tokens */
+            total_tokens: number;
+            /** Total Cost Usd */
+            total_cost_usd?: number | null;
+        };
+        /**
+         * AgentPatch
+         * @description Raw edit from the JSON editor: the changed ``AgentUpdate`` fields.
+         */
+        AgentPatch: {
+            /** Changes */
+            changes: {
+                [key: string]: unknown;
+            };
+            /** Base */
+            base?: {
+                [key: string]: unknown;
+            };
+        };
+        /** AgentPatchValidation */
+        AgentPatchValidation: {
+            /** Errors */
+            errors: string[];
+        };
+        /**
+         * AgentPermission
+         * @description The caller's access to an agent they don't own.
+         */
+        AgentPermission: {
+            /** Agent Id */
+            agent_id: number;
+            permission: components["schemas"]["SharePermission"];
         };
         /** AgentRunsBundle */
         AgentRunsBundle: {
@@ -1746,6 +1971,52 @@ export interface components {
              */
             runs: components["schemas"]["AgentRunOut"][];
         };
+        /** AgentShareOut */
+        AgentShareOut: {
+            /** Id */
+            id: number;
+            /** Agent Id */
+            agent_id: number;
+            /** User Id */
+            user_id?: number | null;
+            /** User Email */
+            user_email?: string | null;
+            permission: components["schemas"]["SharePermission"];
+            /** Created At */
+            created_at?: string | null;
+        };
+        /**
+         * AgentShareUpsert
+         * @description Grant *permission* to the user with *user_email*, or to everyone when it is omitted.
+         */
+        AgentShareUpsert: {
+            /** User Email */
+            user_email?: string | null;
+            permission: components["schemas"]["SharePermission"];
+        };
+        /**
+         * AgentStats
+         * @description Run totals over an agent's whole history plus a short daily series.
+         */
+        AgentStats: {
+            /** Agent Id */
+            agent_id: number;
+            /** Run Count */
+            run_count: number;
+            /** Success Count */
+            success_count: number;
+            /** Failed Count */
+            failed_count: number;
+            /** Success Rate */
+            success_rate?: number | null;
+            /** Avg Duration Ms */
+            avg_duration_ms?: number | null;
+            /**
+             * Daily Runs
+             * @default []
+             */
+            daily_runs: components["schemas"]["AgentDailyRunCount"][];
+        };
         /**
          * AgentStatus
          * @enum {string}
@@ -1770,6 +2041,35 @@ export interface components {
             last_error?: string | null;
             /** Allowed Tools */
             allowed_tools?: string[] | null;
+            /** Prompt Variables */
+            prompt_variables?: {
+                [key: string]: string;
+            } | null;
+            /** Description */
+            description?: string | null;
+            /** Tags */
+            tags?: string[] | null;
+        };
+        /**
+         * AgentUsage
+         * @description Tokens and cost of an agent's runs over the last ``days`` days, split by model.
+         */
+        AgentUsage: {
+            /** Agent Id */
+            agent_id: number;
+            /** Days */
+            days: number;
+            /** Run Count */
+            run_count: number;
+            /** Total Tokens */
+            total_tokens: number;
+            /** Total Cost Usd */
+            total_cost_usd?: number | null;
+            /**
+             * By Model
+             * @default []
+             */
+            by_model: components["schemas"]["AgentModelUsage"][];
         };
         /** Body_upload_current_user_avatar_api_users_me_avatar_post */
         Body_upload_current_user_avatar_api_users_me_avatar_post: {
@@ -1804,6 +2104,38 @@ export interface components {
         CanvasUpdate: {
             canvas: components["schemas"]["WorkflowData-Input"];
         };
+        /** CanvasValidate */
+        CanvasValidate: {
+            canvas: components["schemas"]["WorkflowData-Input"];
+            /** Workflow Id */
+            workflow_id?: number | null;
+        };
+        /**
+         * CapabilitiesOut
+         * @description Permission set for the current user (see zerg.auth.capabilities).
+         */
+        CapabilitiesOut: {
+            /** Role */
+            role: string;
+            /** Capabilities */
+            capabilities: string[];
+        };
+        /**
+         * ChatAttachment
+         * @description File uploaded via ``POST /threads/{id}/attachments``, referenced by a message.
+         */
+        ChatAttachment: {
+            /** Id */
+            id: string;
+            /** Filename */
+            filename: string;
+            /** Content Type */
+            content_type: string;
+            /** Size */
+            size: number;
+            /** Url */
+            url: string;
+        };
         /**
          * ContainerPolicyResponse
          * @description Response model describing container execution policy.
@@ -1841,6 +2173,21 @@ export interface components {
             agents: components["schemas"]["Agent"][];
             /** Runs */
             runs: components["schemas"]["AgentRunsBundle"][];
+            /**
+             * Budgets
+             * @default []
+             */
+            budgets: components["schemas"]["AgentBudgetStatus"][];
+            /**
+             * Stats
+             * @default []
+             */
+            stats: components["schemas"]["AgentStats"][];
+            /**
+             * Permissions
+             * @default []
+             */
+            permissions: components["schemas"]["AgentPermission"][];
         };
         /**
          * DatabaseResetRequest
@@ -1852,6 +2199,31 @@ export interface components {
             /** @default clear_data */
             reset_type: components["schemas"]["ResetType"];
         };
+        /** ExecutionHistoryItem */
+        ExecutionHistoryItem: {
+            /** Id */
+            id: number;
+            /** Workflow Id */
+            workflow_id: number;
+            /** Phase */
+            phase: string;
+            /** Result */
+            result?: string | null;
+            /** Error Message */
+            error_message?: string | null;
+            /** Triggered By */
+            triggered_by?: string | null;
+            /** Started At */
+            started_at?: string | null;
+            /** Finished At */
+            finished_at?: string | null;
+            /** Inputs */
+            inputs?: Record<string, never> | null;
+            /** Source Execution Id */
+            source_execution_id?: number | null;
+            /** Start Node Id */
+            start_node_id?: string | null;
+        };
         /**
          * ExecutionLogsResponse
          * @description Response for workflow execution logs.
@@ -1871,6 +2243,13 @@ export interface components {
             phase: string;
             /** Result */
             result?: unknown | null;
+            /**
+             * Step Mode
+             * @default false
+             */
+            step_mode?: boolean;
+            /** Paused Node Id */
+            paused_node_id?: string | null;
         };
         /** HTTPValidationError */
         HTTPValidationError: {
@@ -2035,6 +2414,11 @@ export interface components {
              * @description Authentication token for the MCP server
              */
             auth_token?: string;
+            /**
+             * Api Credential Id
+             * @description Saved API credential whose auth header is sent to the server
+             */
+            api_credential_id?: number;
             /**
              * Allowed Tools
              * @description List of allowed tools (None means all)
@@ -2075,6 +2459,18 @@ export interface components {
             /** Tools */
             tools?: string[];
         };
+        /**
+         * MessageAuthor
+         * @description Public profile of the user who wrote a message in a shared thread.
+         */
+        MessageAuthor: {
+            /** Id */
+            id: number;
+            /** Display Name */
+            display_name?: string | null;
+            /** Avatar Url */
+            avatar_url?: string | null;
+        };
         /** MessageCreate */
         MessageCreate: {
             /** Role */
@@ -2082,6 +2478,28 @@ export interface components {
             /** Content */
             content: string;
         };
+        /**
+         * MessageMetadataUpdate
+         * @description Bookmark a message and/or toggle one of the caller's reactions on it.
+         */
+        MessageMetadataUpdate: {
+            /** Bookmarked */
+            bookmarked?: boolean | null;
+            /** Reaction */
+            reaction?: string | null;
+        };
+        /** MessageReaction */
+        MessageReaction: {
+            /** Emoji */
+            emoji: string;
+            /** Count */
+            count: number;
+            /**
+             * Reacted
+             * @default false
+             */
+            reacted: boolean;
+        };
         /** MessageResponse */
         MessageResponse: {
             /** Id */
@@ -2098,6 +2516,87 @@ export interface components {
              */
             timestamp: string;
         };
+        /** ModelComparison */
+        ModelComparison: {
+            /** Agent Id */
+            agent_id: number;
+            /** Input */
+            input: string;
+            /** Results */
+            results: components["schemas"]["ModelComparisonResult"][];
+        };
+        /**
+         * ModelComparisonRequest
+         * @description Run the agent once per model on the same message.
+         */
+        ModelComparisonRequest: {
+            /** Models */
+            models: string[];
+            /** Input */
+            input?: string | null;
+        };
+        /** ModelComparisonResult */
+        ModelComparisonResult: {
+            /** Model */
+            model: string;
+            /** Output */
+            output: string;
+            /** Error */
+            error?: string | null;
+            /** Duration Ms */
+            duration_ms: number;
+            /** Prompt Tokens */
+            prompt_tokens?: number | null;
+            /** Completion Tokens */
+            completion_tokens?: number | null;
+            /** Total Tokens */
+            total_tokens?: number | null;
+            /** Cost Usd */
+            cost_usd?: number | null;
+        };
+        /** ModelParams */
+        ModelParams: {
+            /** Temperature */
+            temperature?: number | null;
+            /** Top P */
+            top_p?: number | null;
+            /** Max Tokens */
+            max_tokens?: number | null;
+            /**
+             * Stop
+             * @default []
+             */
+            stop?: string[];
+        };
+        /**
+         * MessageUsage
+         * @description Token usage, latency and cost of the run that produced an assistant reply.
+         */
+        MessageUsage: {
+            /** Run Id */
+            run_id?: number | null;
+            /** Model */
+            model?: string | null;
+            /** Prompt Tokens */
+            prompt_tokens?: number | null;
+            /** Completion Tokens */
+            completion_tokens?: number | null;
+            /** Total Tokens */
+            total_tokens?: number | null;
+            /** Duration Ms */
+            duration_ms?: number | null;
+            /** Cost Usd */
+            cost_usd?: number | null;
+        };
+        /** NodeOutputCache */
+        NodeOutputCache: {
+            /** Execution Id */
+            execution_id?: number | null;
+            /** Finished At */
+            finished_at?: string | null;
+            /** Node Ids */
+            node_ids?: string[];
+        };
         /** NodePos */
         NodePos: {
             /** X */
@@ -2176,12 +2675,18 @@ export interface components {
          * RunStatus
          * @enum {string}
          */
-        RunStatus: "queued" | "running" | "success" | "failed";
+        RunStatus: "queued" | "running" | "cancelling" | "success" | "failed" | "cancelled";
         /**
          * RunTrigger
          * @enum {string}
          */
         RunTrigger: "manual" | "schedule" | "chat" | "webhook" | "api";
+        /**
+         * SharePermission
+         * @description Access granted on someone else's agent; each level includes the ones before it.
+         * @enum {string}
+         */
+        SharePermission: "view" | "run" | "edit";
         /** ScheduleWorkflowPayload */
         ScheduleWorkflowPayload: {
             /** Cron Expression */
@@ -2189,6 +2694,11 @@ export interface components {
             /** Trigger Config */
             trigger_config?: Record<string, never>;
         };
+        /** StartExecutionPayload */
+        StartExecutionPayload: {
+            /** Inputs */
+            inputs?: Record<string, never> | null;
+        };
         /**
          * SuperAdminStatusResponse
          * @description Response model for super admin status check.
@@ -2199,6 +2709,22 @@ export interface components {
             /** Requires Password */
             requires_password: boolean;
         };
+        /** TagOut */
+        TagOut: {
+            /** Name */
+            name: string;
+            /** Agent Count */
+            agent_count: number;
+            /** Workflow Count */
+            workflow_count: number;
+        };
+        /** TagRename */
+        TagRename: {
+            /** Tag */
+            tag: string;
+            /** Name */
+            name: string;
+        };
         /** TemplateDeployRequest */
         TemplateDeployRequest: {
             /** Template Id */
@@ -2208,6 +2734,42 @@ export interface components {
             /** Description */
             description?: string | null;
         };
+        /** TemplateFavoriteRequest */
+        TemplateFavoriteRequest: {
+            /** Favorite */
+            favorite: boolean;
+        };
+        /**
+         * TemplatePublishRequest
+         * @description Publish one of the caller's workflows to the gallery.
+         */
+        TemplatePublishRequest: {
+            /** Workflow Id */
+            workflow_id: number;
+            /** Name */
+            name: string;
+            /** Description */
+            description?: string | null;
+            /** Category */
+            category: string;
+            /**
+             * Tags
+             * @default []
+             */
+            tags?: string[];
+            /** Preview Image Url */
+            preview_image_url?: string | null;
+            /**
+             * Is Public
+             * @default true
+             */
+            is_public?: boolean;
+        };
+        /** TemplateRatingRequest */
+        TemplateRatingRequest: {
+            /** Rating */
+            rating?: number | null;
+        };
         /** Thread */
         Thread: {
             /** Title */
@@ -2233,6 +2795,18 @@ export interface components {
             id: number;
             /** Agent Id */
             agent_id: number;
+            /**
+             * Pinned
+             * @default false
+             */
+            pinned: boolean;
+            /**
+             * Archived
+             * @default false
+             */
+            archived: boolean;
+            /** Model Override */
+            model_override?: string | null;
             /**
              * Created At
              * Format: date-time
@@ -2287,6 +2861,8 @@ export interface components {
             name?: string | null;
             /** Sent At */
             sent_at?: string | null;
+            /** Attachments */
+            attachments?: components["schemas"]["ChatAttachment"][] | null;
         };
         /** ThreadMessageResponse */
         ThreadMessageResponse: {
@@ -2320,6 +2896,23 @@ export interface components {
             message_type?: string | null;
             /** Tool Name */
             tool_name?: string | null;
+            /**
+             * Attachments
+             * @default []
+             */
+            attachments?: components["schemas"]["ChatAttachment"][];
+            author?: components["schemas"]["MessageAuthor"] | null;
+            usage?: components["schemas"]["MessageUsage"] | null;
+            /**
+             * Bookmarked
+             * @default false
+             */
+            bookmarked?: boolean;
+            /**
+             * Reactions
+             * @default []
+             */
+            reactions?: components["schemas"]["MessageReaction"][];
         };
         /** ThreadUpdate */
         ThreadUpdate: {
@@ -2333,6 +2926,12 @@ export interface components {
             active?: boolean | null;
             /** Thread Type */
             thread_type?: string | null;
+            /** Pinned */
+            pinned?: boolean | null;
+            /** Archived */
+            archived?: boolean | null;
+            /** Model Override */
+            model_override?: string | null;
         };
         /**
          * TimeSeriesResponse
@@ -2489,6 +3088,57 @@ export interface components {
              */
             updated_at: string;
             canvas: components["schemas"]["WorkflowData-Output"];
+            /** Tags */
+            tags?: string[] | null;
+            /** Deleted At */
+            deleted_at?: string | null;
+        };
+        /**
+         * WorkflowCommentCreate
+         * @description Start a thread on *node_id*, or reply to the thread rooted at *parent_id*.
+         */
+        WorkflowCommentCreate: {
+            /** Node Id */
+            node_id?: string | null;
+            /** Parent Id */
+            parent_id?: number | null;
+            /** Body */
+            body: string;
+        };
+        /** WorkflowCommentOut */
+        WorkflowCommentOut: {
+            /** Id */
+            id: number;
+            /** Workflow Id */
+            workflow_id: number;
+            /** Node Id */
+            node_id: string;
+            /** Parent Id */
+            parent_id?: number | null;
+            /** Author Id */
+            author_id: number;
+            /** Author Name */
+            author_name: string;
+            /** Author Avatar Url */
+            author_avatar_url?: string | null;
+            /** Body */
+            body: string;
+            /**
+             * Mentioned User Ids
+             * @default []
+             */
+            mentioned_user_ids: number[];
+            /** Resolved At */
+            resolved_at?: string | null;
+            /** Resolved By Id */
+            resolved_by_id?: number | null;
+            /** Created At */
+            created_at?: string | null;
+        };
+        /** WorkflowCommentUpdate */
+        WorkflowCommentUpdate: {
+            /** Resolved */
+            resolved: boolean;
         };
         /** WorkflowCreate */
         WorkflowCreate: {
@@ -2536,7 +3186,7 @@ export interface components {
         };
         /**
          * WorkflowNode
-         * @description A workflow node (agent, tool, trigger, or conditional).
+         * @description A workflow node: agent, tool, trigger, HTTP request, wait, workflow call or control flow (if/switch/loop).
          */
         WorkflowNode: {
             /** Id */
@@ -2545,7 +3195,7 @@ export interface components {
              * Type
              * @enum {string}
              */
-            type: "agent" | "tool" | "trigger" | "conditional";
+            type: "agent" | "tool" | "trigger" | "conditional" | "switch" | "loop" | "http" | "wait" | "subworkflow";
             position: components["schemas"]["Position"];
             /** Config */
             config?: Record<string, never>;
@@ -2582,6 +3232,25 @@ export interface components {
              * Format: date-time
              */
             updated_at: string;
+            /** Rating Avg */
+            rating_avg?: number | null;
+            /**
+             * Rating Count
+             * @default 0
+             */
+            rating_count: number;
+            /**
+             * Favorite Count
+             * @default 0
+             */
+            favorite_count: number;
+            /** My Rating */
+            my_rating?: number | null;
+            /**
+             * Is Favorite
+             * @default false
+             */
+            is_favorite: boolean;
         };
         /** WorkflowTemplateCreate */
         WorkflowTemplateCreate: {
@@ -2606,6 +3275,8 @@ export interface components {
             name?: string | null;
             /** Description */
             description?: string | null;
+            /** Tags */
+            tags?: string[] | null;
         };
     };
     responses: never;
@@ -2793,6 +3464,41 @@ export interface operations {
             };
         };
     };
+    read_agents_usage_api_agents_usage_get: {
+        parameters: {
+            query?: {
+                scope?: string;
+                days?: number;
+                skip?: number;
+                limit?: number;
+                session_factory?: unknown;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successful Response */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["AgentUsage"][];
+                };
+            };
+            /** @description Validation Error */
+            422: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["HTTPValidationError"];
+                };
+            };
+        };
+    };
     read_agent_api_agents__agent_id__get: {
         parameters: {
             query?: {
@@ -2863,6 +3569,43 @@ export interface operations {
             };
         };
     };
+    patch_agent_api_agents__agent_id__patch: {
+        parameters: {
+            query?: {
+                session_factory?: unknown;
+            };
+            header?: never;
+            path: {
+                agent_id: number;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["AgentPatch"];
+            };
+        };
+        responses: {
+            /** @description Successful Response */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["Agent"];
+                };
+            };
+            /** @description Validation Error */
+            422: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["HTTPValidationError"];
+                };
+            };
+        };
+    };
     delete_agent_api_agents__agent_id__delete: {
         parameters: {
             query?: {
@@ -3451,6 +4194,8 @@ export interface operations {
             query?: {
                 skip?: number;
                 limit?: number;
+                before_message_id?: number | null;
+                latest?: boolean;
                 session_factory?: unknown;
             };
             header?: never;
@@ -3986,6 +4731,7 @@ export interface operations {
         parameters: {
             query?: {
                 limit?: number;
+                before_id?: number | null;
                 session_factory?: unknown;
             };
             header?: never;
@@ -4060,7 +4806,7 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CanvasUpdate"];
+                "application/json": components["schemas"]["CanvasValidate"];
             };
         };
         responses: {
@@ -4156,6 +4902,8 @@ export interface operations {
         parameters: {
             query?: {
                 session_factory?: unknown;
+                /** @description Open this workflow instead of the default one */
+                workflow_id?: number | null;
             };
             header?: never;
             path?: never;
@@ -4187,6 +4935,8 @@ export interface operations {
         parameters: {
             query?: {
                 session_factory?: unknown;
+                /** @description Save to this workflow instead of the default one */
+                workflow_id?: number | null;
             };
             header?: never;
             path?: never;
@@ -4390,6 +5140,7 @@ export interface operations {
     start_workflow_execution_api_workflow_executions_by_workflow__workflow_id__start_post: {
         parameters: {
             query?: {
+                step_mode?: boolean;
                 session_factory?: unknown;
             };
             header?: never;
@@ -4398,7 +5149,11 @@ export interface operations {
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody?: {
+            content: {
+                "application/json": components["schemas"]["StartExecutionPayload"] | null;
+            };
+        };
         responses: {
             /** @description Successful Response */
             200: {
@@ -4453,6 +5208,41 @@ export interface operations {
             };
         };
     };
+    run_from_node_api_workflow_executions__execution_id__run_from__node_id__post: {
+        parameters: {
+            query?: {
+                step_mode?: boolean;
+                session_factory?: unknown;
+            };
+            header?: never;
+            path: {
+                execution_id: number;
+                node_id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successful Response */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ExecutionStatusResponse"];
+                };
+            };
+            /** @description Validation Error */
+            422: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["HTTPValidationError"];
+                };
+            };
+        };
+    };
     start_workflow_execution_deprecated_api_workflow_executions__workflow_id__start_post: {
         parameters: {
             query?: {
@@ -4589,6 +5379,7 @@ export interface operations {
     get_execution_history_api_workflow_executions_history__workflow_id__get: {
         parameters: {
             query?: {
+                limit?: number;
                 session_factory?: unknown;
             };
             header?: never;
@@ -4605,7 +5396,40 @@ export interface operations {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": unknown;
+                    "application/json": components["schemas"]["ExecutionHistoryItem"][];
+                };
+            };
+            /** @description Validation Error */
+            422: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["HTTPValidationError"];
+                };
+            };
+        };
+    };
+    get_node_output_cache_api_workflow_executions_history__workflow_id__node_cache_get: {
+        parameters: {
+            query?: {
+                session_factory?: unknown;
+            };
+            header?: never;
+            path: {
+                workflow_id: number;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successful Response */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["NodeOutputCache"];
                 };
             };
             /** @description Validation Error */
@@ -4959,6 +5783,37 @@ export interface operations {
             };
         };
     };
+    read_current_user_capabilities_api_users_me_capabilities_get: {
+        parameters: {
+            query?: {
+                session_factory?: unknown;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successful Response */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["CapabilitiesOut"];
+                };
+            };
+            /** @description Validation Error */
+            422: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["HTTPValidationError"];
+                };
+            };
+        };
+    };
     update_current_user_api_users_me_put: {
         parameters: {
             query?: {
@@ -5036,6 +5891,9 @@ export interface operations {
                 skip?: number;
                 limit?: number;
                 my_templates?: boolean;
+                sort?: ("recent" | "rating" | "popular") | null;
+                min_rating?: number | null;
+                favorites?: boolean;
                 session_factory?: unknown;
             };
             header?: never;
@@ -15,6 +15,8 @@ export interface Envelope<T = unknown> {
   topic: string;
   /** Optional request correlation ID */
   req_id?: string;
+  /** Server trace ID of the request that caused this event (matches the X-Request-ID response header) */
+  trace_id?: string;
   /** Timestamp in milliseconds since epoch */
   ts: number;
   /** Message payload - structure depends on type */
@@ -80,6 +82,13 @@ export interface UnsubscribeData {
   message_id?: string;
 }
 
+export interface UnsubscribeSuccessData {
+  /** Correlation ID matching the original unsubscribe request */
+  message_id?: string;
+  /** Topics the client was unsubscribed from */
+  topics: string[];
+}
+
 export interface SendMessageData {
   thread_id: number;
   content: string;
@@ -101,6 +110,8 @@ export interface ThreadEventData {
 
 export interface StreamStartData {
   thread_id: number;
+  started_by_id?: number;
+  started_by_name?: string;
 }
 
 export interface StreamChunkData {
@@ -114,6 +125,14 @@ export interface StreamChunkData {
 
 export interface StreamEndData {
   thread_id: number;
+  message_id?: number;
+  run_id?: number;
+  model?: string;
+  prompt_tokens?: number;
+  completion_tokens?: number;
+  total_tokens?: number;
+  duration_ms?: number;
+  cost_usd?: number;
 }
 
 export interface AssistantIdData {
@@ -129,18 +148,51 @@ export interface AgentEventData {
   last_error?: string;
   name?: string;
   description?: string;
+  owner_id?: number;
+  /** Set on user-topic deltas; the agent fields are the full row */
+  action?: "created" | "updated" | "deleted";
+  /** Per-owner delta counter; a gap means deltas were missed */
+  seq?: number;
 }
 
 export interface RunUpdateData {
   id: number;
   agent_id: number;
   thread_id?: number;
-  status: "queued" | "running" | "success" | "failed";
+  status: "queued" | "running" | "cancelling" | "success" | "failed" | "cancelled";
   trigger?: "manual" | "schedule" | "chat" | "webhook" | "api";
   started_at?: string;
   finished_at?: string;
   duration_ms?: number;
   error?: string;
+  trace_id?: string;
+}
+
+export interface QueuedRunData {
+  id: number;
+  position: number;
+  trigger: "manual" | "schedule" | "chat" | "webhook" | "api";
+  thread_id?: number;
+  queued_at?: string;
+}
+
+export interface RunQueueUpdateData {
+  agent_id: number;
+  /** Queued runs, next to start first */
+  queue: QueuedRunData[];
+}
+
+export interface RunEventData {
+  agent_id: number;
+  run_id: number;
+  kind: "tool_started" | "tool_finished" | "tool_failed" | "message" | "retry";
+  timestamp: string;
+  tool_name?: string;
+  tool_call_id?: string;
+  /** Truncated args, result, error or message text */
+  preview?: string;
+  duration_ms?: number;
+  attempt?: number;
 }
 
 export interface UserUpdateData {
@@ -150,6 +202,22 @@ export interface UserUpdateData {
   avatar_url?: string;
 }
 
+export interface CommentMentionData {
+  workflow_id: number;
+  workflow_name: string;
+  node_id: string;
+  comment_id: number;
+  author_name: string;
+  /** Start of the comment body */
+  excerpt: string;
+}
+
+export interface ExecutionStartedData {
+  execution_id: number;
+  /** ISO 8601 start time */
+  timestamp?: string;
+}
+
 export interface NodeStateData {
   execution_id: number;
   node_id: string;
@@ -166,6 +234,17 @@ export interface NodeStateData {
   output?: Record<string, any>;
 }
 
+export interface WorkflowProgressData {
+  execution_id: number;
+  /** IDs of the nodes that have finished so far */
+  completed_nodes: string[];
+  /** Outputs of the completed nodes, keyed by node ID */
+  node_outputs?: Record<string, any>;
+  /** Error raised by the last completed node, if any */
+  error?: string;
+  timestamp?: string;
+}
+
 export interface ExecutionFinishedData {
   execution_id: number;
   /** How the execution ended */
@@ -187,6 +266,30 @@ export interface NodeLogData {
   text: string;
 }
 
+export interface ExecutionPausedData {
+  execution_id: number;
+  node_id: string;
+  node_type: "agent" | "tool" | "trigger" | "conditional" | "switch" | "loop" | "http" | "wait" | "subworkflow";
+  /** Config the node will run with unless replaced on step */
+  config: Record<string, any>;
+  /** Output envelopes of the nodes that have finished so far */
+  node_outputs: Record<string, any>;
+  /** Why the execution stopped at this node */
+  reason: "step" | "breakpoint";
+}
+
+export interface NodeWaitingData {
+  execution_id: number;
+  node_id: string;
+  mode: "duration" | "until" | "event";
+  /** When the wait ends on its own (UTC); absent for event waits without a timeout */
+  resume_at?: string;
+  /** External event name for event waits */
+  event?: string;
+  /** Path that resumes an event wait when POSTed to */
+  resume_path?: string;
+}
+
 export interface OpsEventData {
   type: "run_started" | "run_success" | "run_failed" | "agent_created" | "agent_updated" | "thread_message_created" | "budget_denied";
   agent_id?: number;
@@ -196,13 +299,56 @@ export interface OpsEventData {
   error?: string;
   agent_name?: string;
   status?: string;
-  scope?: "user" | "global";
+  scope?: "user" | "global" | "agent";
   percent?: number;
   used_usd?: number;
   limit_cents?: number;
   user_email?: string;
 }
 
+export interface PresenceCursorData {
+  x: number;
+  y: number;
+}
+
+export interface PresenceUpdateData {
+  workflow_id: number;
+  /** Pointer position in flow coordinates; null when off the canvas */
+  cursor?: PresenceCursorData | null;
+  selected_node_ids?: string[];
+  /** Node open in the sender's inspector */
+  editing_node_id?: string | null;
+  /** Node the sender just changed */
+  edited_node_id?: string;
+}
+
+export interface PresenceEditorData {
+  workflow_id: number;
+  /** Connection id; one user may have several tabs open */
+  client_id: string;
+  user_id: number;
+  display_name?: string;
+  avatar_url?: string;
+  cursor?: PresenceCursorData | null;
+  selected_node_ids: string[];
+  editing_node_id?: string | null;
+  edited_node_id?: string | null;
+  edited_at?: string | null;
+  updated_at: string;
+}
+
+export interface PresenceStateData {
+  workflow_id: number;
+  /** The receiving connection's own client_id */
+  self_client_id: string;
+  editors: PresenceEditorData[];
+}
+
+export interface PresenceLeftData {
+  workflow_id: number;
+  client_id: string;
+}
+
 // Typed message definitions with envelopes
 
 /** Heartbeat ping from server */
@@ -240,6 +386,11 @@ export interface UnsubscribeMessage extends Envelope<UnsubscribeData> {
   type: 'unsubscribe';
 }
 
+/** Unsubscription confirmation (server to client) */
+export interface UnsubscribeSuccessMessage extends Envelope<UnsubscribeSuccessData> {
+  type: 'unsubscribe_success';
+}
+
 /** Client request to send a message to a thread */
 export interface SendMessageRequest extends Envelope<SendMessageData> {
   type: 'send_message';
@@ -285,16 +436,41 @@ export interface RunUpdate extends Envelope<RunUpdateData> {
   type: 'run_update';
 }
 
+/** Runs waiting behind an agent's active run, in order */
+export interface RunQueueUpdate extends Envelope<RunQueueUpdateData> {
+  type: 'run_queue_update';
+}
+
+/** Tool call, intermediate message or retry inside an active run */
+export interface RunEvent extends Envelope<RunEventData> {
+  type: 'run_event';
+}
+
 /** User profile update */
 export interface UserUpdate extends Envelope<UserUpdateData> {
   type: 'user_update';
 }
 
+/** Someone mentioned the user in a canvas node comment */
+export interface CommentMention extends Envelope<CommentMentionData> {
+  type: 'comment_mention';
+}
+
+/** Workflow execution began */
+export interface ExecutionStarted extends Envelope<ExecutionStartedData> {
+  type: 'execution_started';
+}
+
 /** Workflow node state change */
 export interface NodeState extends Envelope<NodeStateData> {
   type: 'node_state';
 }
 
+/** Nodes completed so far in a workflow execution */
+export interface WorkflowProgress extends Envelope<WorkflowProgressData> {
+  type: 'workflow_progress';
+}
+
 /** Workflow execution completed */
 export interface ExecutionFinished extends Envelope<ExecutionFinishedData> {
   type: 'execution_finished';
@@ -305,11 +481,41 @@ export interface NodeLog extends Envelope<NodeLogData> {
   type: 'node_log';
 }
 
+/** Execution parked before a node (step mode or breakpoint) */
+export interface ExecutionPaused extends Envelope<ExecutionPausedData> {
+  type: 'execution_paused';
+}
+
+/** Wait node parked until its deadline, external event or a manual skip */
+export interface NodeWaiting extends Envelope<NodeWaitingData> {
+  type: 'node_waiting';
+}
+
 /** Normalized operational ticker event for admin dashboard */
 export interface OpsEvent extends Envelope<OpsEventData> {
   type: 'ops_event';
 }
 
+/** Client reports its cursor, selection or the node it is editing */
+export interface UpdatePresence extends Envelope<PresenceUpdateData> {
+  type: 'update_presence';
+}
+
+/** Editors already on a workflow, sent to a client when it joins */
+export interface PresenceState extends Envelope<PresenceStateData> {
+  type: 'presence_state';
+}
+
+/** An editor joined or changed its cursor, selection or editing node */
+export interface PresenceUpdate extends Envelope<PresenceEditorData> {
+  type: 'presence_update';
+}
+
+/** An editor closed the workflow or disconnected */
+export interface PresenceLeft extends Envelope<PresenceLeftData> {
+  type: 'presence_left';
+}
+
 // Discriminated union of all WebSocket messages
 export type WebSocketMessage =
   | PingMessage
@@ -319,6 +525,7 @@ export type WebSocketMessage =
   | SubscribeAckMessage
   | SubscribeErrorMessage
   | UnsubscribeMessage
+  | UnsubscribeSuccessMessage
   | SendMessageRequest
   | ThreadMessage
   | ThreadEvent
@@ -328,8 +535,19 @@ export type WebSocketMessage =
   | AssistantId
   | AgentEvent
   | RunUpdate
+  | RunQueueUpdate
+  | RunEvent
   | UserUpdate
+  | CommentMention
+  | ExecutionStarted
   | NodeState
+  | WorkflowProgress
   | ExecutionFinished
   | NodeLog
+  | ExecutionPaused
+  | NodeWaiting
   | OpsEvent
+  | UpdatePresence
+  | PresenceState
+  | PresenceUpdate
+  | PresenceLeft
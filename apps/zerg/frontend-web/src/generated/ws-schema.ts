@@ -0,0 +1,1181 @@
+// AUTO-GENERATED FILE - DO NOT EDIT
+// Generated from ws-protocol-asyncapi.yml at 2026-10-14T14:54:13.112039Z
+// Using AsyncAPI 3.0 + TypeScript Code Generation
+//
+// This file contains runtime schemas for validating WebSocket envelopes.
+// To update, modify the schema file and run: python scripts/generate-ws-types-modern.py
+
+export interface FieldSchema {
+  type?: "string" | "integer" | "number" | "boolean" | "object" | "array";
+  enum?: readonly (string | number)[];
+  const?: string | number | boolean;
+  minimum?: number;
+  required?: readonly string[];
+  properties?: Readonly<Record<string, FieldSchema>>;
+  items?: FieldSchema;
+  /** Name of another entry in WS_SCHEMAS */
+  ref?: string;
+}
+
+export const WS_SCHEMAS: Readonly<Record<string, FieldSchema>> = {
+  "Envelope": {
+    "type": "object",
+    "required": [
+      "v",
+      "type",
+      "topic",
+      "ts",
+      "data"
+    ],
+    "properties": {
+      "v": {
+        "type": "integer",
+        "const": 1
+      },
+      "type": {
+        "type": "string"
+      },
+      "topic": {
+        "type": "string"
+      },
+      "req_id": {
+        "type": "string"
+      },
+      "trace_id": {
+        "type": "string"
+      },
+      "ts": {
+        "type": "integer"
+      },
+      "data": {
+        "type": "object"
+      }
+    }
+  },
+  "AgentRef": {
+    "type": "object",
+    "required": [
+      "id"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "ThreadRef": {
+    "type": "object",
+    "required": [
+      "thread_id"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "UserRef": {
+    "type": "object",
+    "required": [
+      "id"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "ExecutionRef": {
+    "type": "object",
+    "required": [
+      "execution_id"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "PingData": {
+    "type": "object",
+    "properties": {
+      "timestamp": {
+        "type": "integer",
+        "minimum": 0
+      }
+    }
+  },
+  "PongData": {
+    "type": "object",
+    "properties": {
+      "timestamp": {
+        "type": "integer",
+        "minimum": 0
+      }
+    }
+  },
+  "ErrorData": {
+    "type": "object",
+    "required": [
+      "error"
+    ],
+    "properties": {
+      "error": {
+        "type": "string"
+      },
+      "details": {
+        "type": "object"
+      }
+    }
+  },
+  "SubscribeData": {
+    "type": "object",
+    "required": [
+      "topics"
+    ],
+    "properties": {
+      "topics": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "message_id": {
+        "type": "string"
+      }
+    }
+  },
+  "SubscribeAckData": {
+    "type": "object",
+    "required": [
+      "message_id",
+      "topics"
+    ],
+    "properties": {
+      "message_id": {
+        "type": "string"
+      },
+      "topics": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      }
+    }
+  },
+  "SubscribeErrorData": {
+    "type": "object",
+    "required": [
+      "message_id",
+      "error"
+    ],
+    "properties": {
+      "message_id": {
+        "type": "string"
+      },
+      "topics": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "error": {
+        "type": "string"
+      },
+      "error_code": {
+        "type": "string"
+      }
+    }
+  },
+  "UnsubscribeData": {
+    "type": "object",
+    "required": [
+      "topics"
+    ],
+    "properties": {
+      "topics": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "message_id": {
+        "type": "string"
+      }
+    }
+  },
+  "UnsubscribeSuccessData": {
+    "type": "object",
+    "required": [
+      "topics"
+    ],
+    "properties": {
+      "message_id": {
+        "type": "string"
+      },
+      "topics": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      }
+    }
+  },
+  "SendMessageData": {
+    "type": "object",
+    "required": [
+      "thread_id",
+      "content"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "content": {
+        "type": "string"
+      },
+      "metadata": {
+        "type": "object"
+      }
+    }
+  },
+  "ThreadMessageData": {
+    "type": "object",
+    "required": [
+      "thread_id",
+      "message"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "message": {
+        "type": "object",
+        "required": [
+          "id",
+          "role",
+          "content"
+        ],
+        "properties": {
+          "id": {
+            "type": "integer",
+            "minimum": 1
+          },
+          "role": {
+            "type": "string",
+            "enum": [
+              "user",
+              "assistant",
+              "system",
+              "tool"
+            ]
+          },
+          "content": {
+            "type": "string"
+          },
+          "metadata": {
+            "type": "object"
+          },
+          "created_at": {
+            "type": "string"
+          }
+        }
+      }
+    }
+  },
+  "ThreadEventData": {
+    "type": "object",
+    "required": [
+      "thread_id"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "agent_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "title": {
+        "type": "string"
+      },
+      "created_at": {
+        "type": "string"
+      },
+      "updated_at": {
+        "type": "string"
+      }
+    }
+  },
+  "StreamStartData": {
+    "type": "object",
+    "required": [
+      "thread_id"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "started_by_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "started_by_name": {
+        "type": "string"
+      }
+    }
+  },
+  "StreamChunkData": {
+    "type": "object",
+    "required": [
+      "thread_id",
+      "chunk_type"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "chunk_type": {
+        "type": "string",
+        "enum": [
+          "assistant_token",
+          "assistant_message",
+          "tool_output"
+        ]
+      },
+      "content": {
+        "type": "string"
+      },
+      "tool_name": {
+        "type": "string"
+      },
+      "tool_call_id": {
+        "type": "string"
+      },
+      "message_id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "StreamEndData": {
+    "type": "object",
+    "required": [
+      "thread_id"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "message_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "run_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "model": {
+        "type": "string"
+      },
+      "prompt_tokens": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "completion_tokens": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "total_tokens": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "duration_ms": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "cost_usd": {
+        "type": "number",
+        "minimum": 0
+      }
+    }
+  },
+  "AssistantIdData": {
+    "type": "object",
+    "required": [
+      "thread_id",
+      "message_id"
+    ],
+    "properties": {
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "message_id": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "AgentEventData": {
+    "type": "object",
+    "required": [
+      "id"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "status": {
+        "type": "string"
+      },
+      "last_run_at": {
+        "type": "string"
+      },
+      "next_run_at": {
+        "type": "string"
+      },
+      "last_error": {
+        "type": "string"
+      },
+      "name": {
+        "type": "string"
+      },
+      "description": {
+        "type": "string"
+      },
+      "owner_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "action": {
+        "type": "string",
+        "enum": [
+          "created",
+          "updated",
+          "deleted"
+        ]
+      },
+      "seq": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "RunUpdateData": {
+    "type": "object",
+    "required": [
+      "id",
+      "agent_id",
+      "status"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "agent_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "status": {
+        "type": "string",
+        "enum": [
+          "queued",
+          "running",
+          "cancelling",
+          "success",
+          "failed",
+          "cancelled"
+        ]
+      },
+      "trigger": {
+        "type": "string",
+        "enum": [
+          "manual",
+          "schedule",
+          "chat",
+          "webhook",
+          "api"
+        ]
+      },
+      "started_at": {
+        "type": "string"
+      },
+      "finished_at": {
+        "type": "string"
+      },
+      "duration_ms": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "error": {
+        "type": "string"
+      },
+      "trace_id": {
+        "type": "string"
+      }
+    }
+  },
+  "RunQueueUpdateData": {
+    "type": "object",
+    "required": [
+      "agent_id",
+      "queue"
+    ],
+    "properties": {
+      "agent_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "queue": {
+        "type": "array",
+        "items": {
+          "ref": "QueuedRunData"
+        }
+      }
+    }
+  },
+  "QueuedRunData": {
+    "type": "object",
+    "required": [
+      "id",
+      "position",
+      "trigger"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "position": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "trigger": {
+        "type": "string",
+        "enum": [
+          "manual",
+          "schedule",
+          "chat",
+          "webhook",
+          "api"
+        ]
+      },
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "queued_at": {
+        "type": "string"
+      }
+    }
+  },
+  "RunEventData": {
+    "type": "object",
+    "required": [
+      "agent_id",
+      "run_id",
+      "kind",
+      "timestamp"
+    ],
+    "properties": {
+      "agent_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "run_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "kind": {
+        "type": "string",
+        "enum": [
+          "tool_started",
+          "tool_finished",
+          "tool_failed",
+          "message",
+          "retry"
+        ]
+      },
+      "timestamp": {
+        "type": "string"
+      },
+      "tool_name": {
+        "type": "string"
+      },
+      "tool_call_id": {
+        "type": "string"
+      },
+      "preview": {
+        "type": "string"
+      },
+      "duration_ms": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "attempt": {
+        "type": "integer",
+        "minimum": 1
+      }
+    }
+  },
+  "UserUpdateData": {
+    "type": "object",
+    "required": [
+      "id"
+    ],
+    "properties": {
+      "id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "email": {
+        "type": "string"
+      },
+      "display_name": {
+        "type": "string"
+      },
+      "avatar_url": {
+        "type": "string"
+      }
+    }
+  },
+  "CommentMentionData": {
+    "type": "object",
+    "required": [
+      "workflow_id",
+      "workflow_name",
+      "node_id",
+      "comment_id",
+      "author_name",
+      "excerpt"
+    ],
+    "properties": {
+      "workflow_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "workflow_name": {
+        "type": "string"
+      },
+      "node_id": {
+        "type": "string"
+      },
+      "comment_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "author_name": {
+        "type": "string"
+      },
+      "excerpt": {
+        "type": "string"
+      }
+    }
+  },
+  "ExecutionStartedData": {
+    "type": "object",
+    "required": [
+      "execution_id"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "timestamp": {
+        "type": "string"
+      }
+    }
+  },
+  "NodeStateData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "node_id",
+      "phase"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "node_id": {
+        "type": "string"
+      },
+      "phase": {
+        "type": "string",
+        "enum": [
+          "waiting",
+          "running",
+          "finished"
+        ]
+      },
+      "result": {
+        "type": "string",
+        "enum": [
+          "success",
+          "failure",
+          "cancelled"
+        ]
+      },
+      "attempt_no": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "failure_kind": {
+        "type": "string",
+        "enum": [
+          "user",
+          "system",
+          "timeout",
+          "external",
+          "unknown"
+        ]
+      },
+      "error_message": {
+        "type": "string"
+      },
+      "output": {
+        "type": "object"
+      }
+    }
+  },
+  "WorkflowProgressData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "completed_nodes"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "completed_nodes": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "node_outputs": {
+        "type": "object"
+      },
+      "error": {
+        "type": "string"
+      },
+      "timestamp": {
+        "type": "string"
+      }
+    }
+  },
+  "ExecutionFinishedData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "result"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "result": {
+        "type": "string",
+        "enum": [
+          "success",
+          "failure",
+          "cancelled"
+        ]
+      },
+      "attempt_no": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "failure_kind": {
+        "type": "string",
+        "enum": [
+          "user",
+          "system",
+          "timeout",
+          "external",
+          "unknown"
+        ]
+      },
+      "error_message": {
+        "type": "string"
+      },
+      "duration_ms": {
+        "type": "integer",
+        "minimum": 0
+      }
+    }
+  },
+  "NodeLogData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "node_id",
+      "stream",
+      "text"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "node_id": {
+        "type": "string"
+      },
+      "stream": {
+        "type": "string",
+        "enum": [
+          "stdout",
+          "stderr"
+        ]
+      },
+      "text": {
+        "type": "string"
+      }
+    }
+  },
+  "ExecutionPausedData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "node_id",
+      "node_type",
+      "config",
+      "node_outputs",
+      "reason"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "node_id": {
+        "type": "string"
+      },
+      "node_type": {
+        "type": "string",
+        "enum": [
+          "agent",
+          "tool",
+          "trigger",
+          "conditional",
+          "switch",
+          "loop",
+          "http",
+          "wait",
+          "subworkflow"
+        ]
+      },
+      "config": {
+        "type": "object"
+      },
+      "node_outputs": {
+        "type": "object"
+      },
+      "reason": {
+        "type": "string",
+        "enum": [
+          "step",
+          "breakpoint"
+        ]
+      }
+    }
+  },
+  "NodeWaitingData": {
+    "type": "object",
+    "required": [
+      "execution_id",
+      "node_id",
+      "mode"
+    ],
+    "properties": {
+      "execution_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "node_id": {
+        "type": "string"
+      },
+      "mode": {
+        "type": "string",
+        "enum": [
+          "duration",
+          "until",
+          "event"
+        ]
+      },
+      "resume_at": {
+        "type": "string"
+      },
+      "event": {
+        "type": "string"
+      },
+      "resume_path": {
+        "type": "string"
+      }
+    }
+  },
+  "PresenceCursorData": {
+    "type": "object",
+    "required": [
+      "x",
+      "y"
+    ],
+    "properties": {
+      "x": {
+        "type": "number"
+      },
+      "y": {
+        "type": "number"
+      }
+    }
+  },
+  "PresenceUpdateData": {
+    "type": "object",
+    "required": [
+      "workflow_id"
+    ],
+    "properties": {
+      "workflow_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "cursor": {
+        "ref": "PresenceCursorData"
+      },
+      "selected_node_ids": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "editing_node_id": {
+        "type": "string"
+      },
+      "edited_node_id": {
+        "type": "string"
+      }
+    }
+  },
+  "PresenceEditorData": {
+    "type": "object",
+    "required": [
+      "workflow_id",
+      "client_id",
+      "user_id",
+      "selected_node_ids",
+      "updated_at"
+    ],
+    "properties": {
+      "workflow_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "client_id": {
+        "type": "string"
+      },
+      "user_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "display_name": {
+        "type": "string"
+      },
+      "avatar_url": {
+        "type": "string"
+      },
+      "cursor": {
+        "ref": "PresenceCursorData"
+      },
+      "selected_node_ids": {
+        "type": "array",
+        "items": {
+          "type": "string"
+        }
+      },
+      "editing_node_id": {
+        "type": "string"
+      },
+      "edited_node_id": {
+        "type": "string"
+      },
+      "edited_at": {
+        "type": "string"
+      },
+      "updated_at": {
+        "type": "string"
+      }
+    }
+  },
+  "PresenceStateData": {
+    "type": "object",
+    "required": [
+      "workflow_id",
+      "self_client_id",
+      "editors"
+    ],
+    "properties": {
+      "workflow_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "self_client_id": {
+        "type": "string"
+      },
+      "editors": {
+        "type": "array",
+        "items": {
+          "ref": "PresenceEditorData"
+        }
+      }
+    }
+  },
+  "PresenceLeftData": {
+    "type": "object",
+    "required": [
+      "workflow_id",
+      "client_id"
+    ],
+    "properties": {
+      "workflow_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "client_id": {
+        "type": "string"
+      }
+    }
+  },
+  "OpsEventData": {
+    "type": "object",
+    "required": [
+      "type"
+    ],
+    "properties": {
+      "type": {
+        "type": "string",
+        "enum": [
+          "run_started",
+          "run_success",
+          "run_failed",
+          "agent_created",
+          "agent_updated",
+          "thread_message_created",
+          "budget_denied"
+        ]
+      },
+      "agent_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "run_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "thread_id": {
+        "type": "integer",
+        "minimum": 1
+      },
+      "duration_ms": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "error": {
+        "type": "string"
+      },
+      "agent_name": {
+        "type": "string"
+      },
+      "status": {
+        "type": "string"
+      },
+      "scope": {
+        "type": "string",
+        "enum": [
+          "user",
+          "global",
+          "agent"
+        ]
+      },
+      "percent": {
+        "type": "number"
+      },
+      "used_usd": {
+        "type": "number"
+      },
+      "limit_cents": {
+        "type": "integer",
+        "minimum": 0
+      },
+      "user_email": {
+        "type": "string"
+      }
+    }
+  }
+};
+
+/** Message type (including aliases) -> payload schema name */
+export const WS_MESSAGE_PAYLOADS: Readonly<Record<string, string>> = {
+  "ping": "PingData",
+  "pong": "PongData",
+  "error": "ErrorData",
+  "subscribe": "SubscribeData",
+  "subscribe_ack": "SubscribeAckData",
+  "subscribe_error": "SubscribeErrorData",
+  "unsubscribe": "UnsubscribeData",
+  "unsubscribe_success": "UnsubscribeSuccessData",
+  "send_message": "SendMessageData",
+  "thread_message": "ThreadMessageData",
+  "thread_message_created": "ThreadMessageData",
+  "thread_event": "ThreadEventData",
+  "thread_created": "ThreadEventData",
+  "thread_updated": "ThreadEventData",
+  "thread_deleted": "ThreadEventData",
+  "stream_start": "StreamStartData",
+  "stream_chunk": "StreamChunkData",
+  "stream_end": "StreamEndData",
+  "assistant_id": "AssistantIdData",
+  "agent_event": "AgentEventData",
+  "agent_created": "AgentEventData",
+  "agent_updated": "AgentEventData",
+  "agent_deleted": "AgentEventData",
+  "agent_state": "AgentEventData",
+  "run_update": "RunUpdateData",
+  "run_queue_update": "RunQueueUpdateData",
+  "run_event": "RunEventData",
+  "user_update": "UserUpdateData",
+  "comment_mention": "CommentMentionData",
+  "execution_started": "ExecutionStartedData",
+  "node_state": "NodeStateData",
+  "workflow_progress": "WorkflowProgressData",
+  "execution_finished": "ExecutionFinishedData",
+  "node_log": "NodeLogData",
+  "execution_paused": "ExecutionPausedData",
+  "node_waiting": "NodeWaitingData",
+  "ops_event": "OpsEventData",
+  "update_presence": "PresenceUpdateData",
+  "presence_state": "PresenceStateData",
+  "presence_update": "PresenceEditorData",
+  "presence_left": "PresenceLeftData"
+};
@@ -0,0 +1,88 @@
+import { describe, expect, it } from "vitest";
+import { QueryClient } from "@tanstack/react-query";
+import { buildAgentQuickStats, selectCachedAgentData } from "../useAgentQuickStats";
+import type { AgentRun, AgentSummary, DashboardSnapshot } from "../../services/api";
+
+function makeAgent(overrides: Partial<AgentSummary> = {}): AgentSummary {
+  return {
+    id: 1,
+    name: "Alpha",
+    status: "idle",
+    owner_id: 7,
+    owner: {
+      id: 7,
+      email: "owner@example.com",
+      display_name: "Owner",
+      is_active: true,
+      created_at: "2025-01-01T00:00:00Z",
+    } as AgentSummary["owner"],
+    system_instructions: "",
+    task_instructions: "",
+    model: "gpt-4o",
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    messages: [],
+    last_run_at: "2025-01-02T10:00:00Z",
+    ...overrides,
+  } as AgentSummary;
+}
+
+function makeRun(id: number, status: AgentRun["status"]): AgentRun {
+  return {
+    id,
+    agent_id: 1,
+    thread_id: 1,
+    status,
+    trigger: "manual",
+    started_at: `2025-01-0${id}T00:00:00Z`,
+  } as AgentRun;
+}
+
+describe("buildAgentQuickStats", () => {
+  it("computes success rate and chronological sparkline from finished runs", () => {
+    // Newest first, as returned by the API
+    const runs = [makeRun(4, "running"), makeRun(3, "failed"), makeRun(2, "success"), makeRun(1, "success")];
+    const stats = buildAgentQuickStats(makeAgent(), runs);
+
+    expect(stats.sparkline).toEqual([true, true, false]);
+    expect(stats.successRate).toBeCloseTo(66.67, 1);
+    expect(stats.ownerLabel).toBe("Owner");
+    expect(stats.lastRunAt).toBe("2025-01-02T10:00:00Z");
+  });
+
+  it("returns empty stats when nothing is known yet", () => {
+    const stats = buildAgentQuickStats(null, null);
+    expect(stats.successRate).toBeNull();
+    expect(stats.sparkline).toEqual([]);
+    expect(stats.status).toBeNull();
+  });
+});
+
+describe("selectCachedAgentData", () => {
+  it("prefers dashboard snapshots which include run bundles", () => {
+    const queryClient = new QueryClient();
+    const snapshot: DashboardSnapshot = {
+      scope: "my",
+      fetchedAt: "2025-01-05T00:00:00Z",
+      runsLimit: 50,
+      agents: [makeAgent()],
+      runs: [{ agentId: 1, runs: [makeRun(1, "success")] }],
+      budgets: [],
+      stats: [],
+    };
+    queryClient.setQueryData(["dashboard", "my", 50], snapshot);
+
+    const cached = selectCachedAgentData(queryClient, 1);
+    expect(cached.agent?.name).toBe("Alpha");
+    expect(cached.runs).toHaveLength(1);
+  });
+
+  it("falls back to agent lists without runs", () => {
+    const queryClient = new QueryClient();
+    queryClient.setQueryData(["agents", { scope: "my" }], [makeAgent({ id: 2, name: "Beta" })]);
+
+    const cached = selectCachedAgentData(queryClient, 2);
+    expect(cached.agent?.name).toBe("Beta");
+    expect(cached.runs).toBeNull();
+  });
+});
@@ -1,37 +1,89 @@
+import { useCallback, useState } from "react";
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import { toast } from "react-hot-toast";
 import {
+  ApiError,
+  isApiError,
+  ChatAttachment,
+  forkThread,
   postThreadMessage,
+  regenerateThread,
   runThread,
   startWorkflowExecution,
   Thread,
   ThreadMessage,
+  updateMessageMetadata,
   updateThread,
+  type MessageMetadataUpdate,
 } from "../../services/api";
 
 interface UseChatActionsParams {
   agentId: number | null;
   effectiveThreadId: number | null;
+  // Called once an edited message has been forked into a new thread
+  onThreadForked?: (thread: Thread) => void;
 }
 
-export function useChatActions({ agentId, effectiveThreadId }: UseChatActionsParams) {
+/**
+ * A user message that didn't get an answer. `messageId` is set when the
+ * message reached the server but the run failed, so a retry only re-runs the
+ * thread; otherwise the content is posted again.
+ */
+export interface FailedSend {
+  key: number;
+  threadId: number;
+  content: string;
+  attachments: ChatAttachment[];
+  messageId: number | null;
+}
+
+// Carries the persisted message when posting succeeded but the run did not
+class ChatRunError extends Error {
+  constructor(
+    message: string,
+    readonly postedMessage: ThreadMessage,
+    readonly status?: number
+  ) {
+    super(message);
+  }
+}
+
+// 409 from /run: another participant's message is still being answered
+function isReplyInProgress(error: unknown) {
+  return (
+    (error instanceof ChatRunError && error.status === 409) || isApiError(error, "conflict")
+  );
+}
+
+const REPLY_IN_PROGRESS_MESSAGE = "The agent is already replying in this thread. Retry once it finishes.";
+
+export function useChatActions({ agentId, effectiveThreadId, onThreadForked }: UseChatActionsParams) {
   const queryClient = useQueryClient();
+  const [failedSends, setFailedSends] = useState<FailedSend[]>([]);
 
   const sendMutation = useMutation<
     ThreadMessage,
     Error,
-    { threadId: number; content: string },
+    { threadId: number; content: string; attachments?: ChatAttachment[] },
     number
   >({
-    mutationFn: async ({ threadId, content }) => {
+    mutationFn: async ({ threadId, content, attachments = [] }) => {
       console.log('[CHAT] 📤 Sending message to thread:', threadId);
-      const message = await postThreadMessage(threadId, content);
+      const message = await postThreadMessage(threadId, content, attachments);
       console.log('[CHAT] 🚀 Triggering thread run:', threadId, '(tokens will stream via WebSocket)');
-      await runThread(threadId);
+      try {
+        await runThread(threadId);
+      } catch (error) {
+        throw new ChatRunError(
+          error instanceof Error ? error.message : String(error),
+          message,
+          error instanceof ApiError ? error.status : undefined
+        );
+      }
       console.log('[CHAT] ✅ Run completed');
       return message;
     },
-    onMutate: async ({ threadId, content }) => {
+    onMutate: async ({ threadId, content, attachments = [] }) => {
       await queryClient.cancelQueries({ queryKey: ["thread-messages", threadId] });
 
       const optimisticId = -Date.now();
@@ -42,6 +94,7 @@ export function useChatActions({ agentId, effectiveThreadId }: UseChatActionsPar
         thread_id: threadId,
         role: "user",
         content,
+        attachments,
         sent_at: new Date().toISOString(),
         processed: true,
       } as unknown as ThreadMessage;
@@ -52,12 +105,33 @@ export function useChatActions({ agentId, effectiveThreadId }: UseChatActionsPar
 
       return optimisticId;
     },
-    onError: (_error, variables, optimisticId) => {
+    onError: (error, variables, optimisticId) => {
+      const postedMessage = error instanceof ChatRunError ? error.postedMessage : null;
       queryClient.setQueryData<ThreadMessage[]>(
         ["thread-messages", variables.threadId],
-        (current) => current?.filter((msg) => msg.id !== optimisticId) ?? []
+        (current) =>
+          postedMessage
+            ? current?.map((msg) => (msg.id === optimisticId ? postedMessage : msg)) ?? [postedMessage]
+            : current?.filter((msg) => msg.id !== optimisticId) ?? []
+      );
+      setFailedSends((prev) => [
+        ...prev,
+        {
+          key: optimisticId ?? -Date.now(),
+          threadId: variables.threadId,
+          content: variables.content,
+          attachments: variables.attachments ?? [],
+          messageId: postedMessage?.id ?? null,
+        },
+      ]);
+      toast.error(
+        isReplyInProgress(error)
+          ? REPLY_IN_PROGRESS_MESSAGE
+          : postedMessage
+            ? "Agent failed to respond"
+            : "Failed to send message",
+        { duration: 6000 }
       );
-      toast.error("Failed to send message", { duration: 6000 });
     },
     onSuccess: (data, variables, optimisticId) => {
       queryClient.setQueryData<ThreadMessage[]>(
@@ -75,6 +149,83 @@ export function useChatActions({ agentId, effectiveThreadId }: UseChatActionsPar
     },
   });
 
+  const invalidateThread = useCallback(
+    (threadId: number) => {
+      queryClient.invalidateQueries({ queryKey: ["thread-messages", threadId] });
+      if (agentId != null) {
+        queryClient.invalidateQueries({ queryKey: ["threads", agentId, "chat"] });
+      }
+    },
+    [agentId, queryClient]
+  );
+
+  // Re-run a thread whose user message was stored but never answered
+  const rerunMutation = useMutation<void, Error, FailedSend>({
+    mutationFn: (failure) => runThread(failure.threadId),
+    onError: (error, failure) => {
+      setFailedSends((prev) => [...prev, failure]);
+      toast.error(isReplyInProgress(error) ? REPLY_IN_PROGRESS_MESSAGE : "Agent failed to respond", {
+        duration: 6000,
+      });
+    },
+    onSettled: (_data, _error, failure) => invalidateThread(failure.threadId),
+  });
+
+  const retryFailedSend = useCallback(
+    (key: number) => {
+      const failure = failedSends.find((item) => item.key === key);
+      if (!failure) return;
+      setFailedSends((prev) => prev.filter((item) => item.key !== key));
+      if (failure.messageId != null) {
+        rerunMutation.mutate(failure);
+      } else {
+        sendMutation.mutate({
+          threadId: failure.threadId,
+          content: failure.content,
+          attachments: failure.attachments,
+        });
+      }
+    },
+    [failedSends, rerunMutation, sendMutation]
+  );
+
+  const discardFailedSend = useCallback((key: number) => {
+    setFailedSends((prev) => prev.filter((item) => item.key !== key));
+  }, []);
+
+  // Edit-and-resend forks the thread so the original conversation is preserved
+  const editMessageMutation = useMutation<Thread, Error, { threadId: number; messageId: number; content: string }>({
+    mutationFn: ({ threadId, messageId, content }) => forkThread(threadId, messageId, content),
+    onSuccess: (thread) => {
+      if (agentId != null) {
+        queryClient.invalidateQueries({ queryKey: ["threads", agentId, "chat"] });
+      }
+      onThreadForked?.(thread);
+      // Run after switching so the new thread's stream is the one on screen
+      runThread(thread.id)
+        .catch(() => toast.error("Agent failed to respond", { duration: 6000 }))
+        .finally(() => invalidateThread(thread.id));
+    },
+    onError: (error) => {
+      toast.error(`Failed to edit message: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const regenerateMutation = useMutation<void, Error, { threadId: number }>({
+    mutationFn: async ({ threadId }) => {
+      const userMessage = await regenerateThread(threadId);
+      // Drop the discarded turn right away instead of waiting for the refetch
+      queryClient.setQueryData<ThreadMessage[]>(["thread-messages", threadId], (current) =>
+        current?.filter((msg) => msg.id <= userMessage.id)
+      );
+      await runThread(threadId);
+    },
+    onError: (error) => {
+      toast.error(`Failed to regenerate response: ${error.message}`, { duration: 6000 });
+    },
+    onSettled: (_data, _error, variables) => invalidateThread(variables.threadId),
+  });
+
   // Workflow execution mutation
   const executeWorkflowMutation = useMutation({
     mutationFn: ({ workflowId }: { workflowId: number }) => startWorkflowExecution(workflowId),
@@ -140,9 +291,117 @@ export function useChatActions({ agentId, effectiveThreadId }: UseChatActionsPar
     },
   });
 
+  // Pin/archive toggles from the sidebar, applied optimistically like renames
+  const updateThreadFlagsMutation = useMutation<
+    Thread,
+    Error,
+    { threadId: number; pinned?: boolean; archived?: boolean },
+    { previousThreads?: Thread[] }
+  >({
+    mutationFn: ({ threadId, pinned, archived }) => updateThread(threadId, { pinned, archived }),
+    onMutate: async ({ threadId, pinned, archived }) => {
+      if (agentId == null) {
+        return {};
+      }
+      const queryKey = ["threads", agentId, "chat"] as const;
+      await queryClient.cancelQueries({ queryKey });
+      const previousThreads = queryClient.getQueryData<Thread[]>(queryKey);
+      queryClient.setQueryData<Thread[]>(queryKey, (old) =>
+        old
+          ? old.map((thread) => {
+              if (thread.id !== threadId) return thread;
+              const next = { ...thread };
+              if (pinned !== undefined) next.pinned = pinned;
+              if (archived !== undefined) {
+                next.archived = archived;
+                if (archived) next.pinned = false;
+              }
+              return next;
+            })
+          : old
+      );
+      return { previousThreads };
+    },
+    onError: (_error, variables, context) => {
+      if (agentId != null && context?.previousThreads) {
+        queryClient.setQueryData(["threads", agentId, "chat"], context.previousThreads);
+      }
+      const action =
+        variables.archived !== undefined
+          ? variables.archived
+            ? "archive"
+            : "unarchive"
+          : variables.pinned
+            ? "pin"
+            : "unpin";
+      toast.error(`Failed to ${action} thread`, {
+        duration: 6000,
+      });
+    },
+    onSuccess: (updatedThread) => {
+      if (agentId != null) {
+        queryClient.setQueryData<Thread[]>(["threads", agentId, "chat"], (old) =>
+          old ? old.map((thread) => (thread.id === updatedThread.id ? updatedThread : thread)) : old
+        );
+      }
+    },
+    onSettled: () => {
+      if (agentId != null) {
+        queryClient.invalidateQueries({ queryKey: ["threads", agentId, "chat"] });
+      }
+    },
+  });
+
+  // "" clears the override; the next reply in the thread uses the new model
+  const updateThreadModelMutation = useMutation<Thread, Error, { threadId: number; model: string }>({
+    mutationFn: ({ threadId, model }) => updateThread(threadId, { model_override: model }),
+    onSuccess: (updatedThread) => {
+      if (agentId != null) {
+        queryClient.setQueryData<Thread[]>(["threads", agentId, "chat"], (old) =>
+          old ? old.map((thread) => (thread.id === updatedThread.id ? updatedThread : thread)) : old
+        );
+      }
+    },
+    onError: (error) => {
+      toast.error(`Failed to change the thread's model: ${error.message}`, { duration: 6000 });
+    },
+    onSettled: () => {
+      if (agentId != null) {
+        queryClient.invalidateQueries({ queryKey: ["threads", agentId] });
+      }
+    },
+  });
+
+  const updateMessageMetadataMutation = useMutation<
+    ThreadMessage,
+    Error,
+    { threadId: number; messageId: number } & MessageMetadataUpdate
+  >({
+    mutationFn: ({ threadId, messageId, ...update }) => updateMessageMetadata(threadId, messageId, update),
+    onSuccess: (updated, { threadId }) => {
+      queryClient.setQueryData<ThreadMessage[]>(["thread-messages", threadId], (old) =>
+        old ? old.map((message) => (message.id === updated.id ? { ...message, ...updated } : message)) : old
+      );
+      if (agentId != null) {
+        queryClient.invalidateQueries({ queryKey: ["message-bookmarks", agentId] });
+      }
+    },
+    onError: (error) => {
+      toast.error(`Failed to update message: ${error.message}`, { duration: 6000 });
+    },
+  });
+
   return {
     sendMutation,
     executeWorkflowMutation,
     renameThreadMutation,
+    updateThreadFlagsMutation,
+    updateThreadModelMutation,
+    updateMessageMetadataMutation,
+    editMessageMutation,
+    regenerateMutation,
+    failedSends,
+    retryFailedSend,
+    discardFailedSend,
   };
 }
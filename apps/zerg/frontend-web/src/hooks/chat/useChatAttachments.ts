@@ -0,0 +1,125 @@
+import { useCallback, useEffect, useRef, useState } from "react";
+import { toast } from "react-hot-toast";
+import { uploadAttachment, type ChatAttachment } from "../../services/api";
+import { isImageType } from "../../components/chat/chatUtils";
+
+// Mirrors the backend limit so oversized files fail before uploading
+export const MAX_ATTACHMENT_BYTES = 10 * 1024 * 1024;
+export const MAX_ATTACHMENTS_PER_MESSAGE = 5;
+
+export interface PendingAttachment {
+  localId: string;
+  file: File;
+  // Object URL for image thumbnails while (and after) uploading
+  previewUrl: string | null;
+  progress: number;
+  status: "uploading" | "ready" | "error";
+  attachment?: ChatAttachment;
+  error?: string;
+}
+
+let nextLocalId = 0;
+
+/**
+ * Files staged in the composer. Uploads start as soon as files are added so
+ * that sending only has to reference the stored attachments.
+ */
+export function useChatAttachments(threadId: number | null) {
+  const [pending, setPending] = useState<PendingAttachment[]>([]);
+  const controllersRef = useRef(new Map<string, AbortController>());
+  const pendingRef = useRef(pending);
+  pendingRef.current = pending;
+
+  const update = useCallback((localId: string, patch: Partial<PendingAttachment>) => {
+    setPending((prev) => prev.map((item) => (item.localId === localId ? { ...item, ...patch } : item)));
+  }, []);
+
+  const releaseAll = useCallback(() => {
+    controllersRef.current.forEach((controller) => controller.abort());
+    controllersRef.current.clear();
+    pendingRef.current.forEach((item) => {
+      if (item.previewUrl) URL.revokeObjectURL(item.previewUrl);
+    });
+  }, []);
+
+  // Staged files belong to the thread they were uploaded to
+  useEffect(() => {
+    return () => {
+      releaseAll();
+      setPending([]);
+    };
+  }, [threadId, releaseAll]);
+
+  const addFiles = useCallback(
+    (files: File[]) => {
+      if (threadId == null || files.length === 0) return;
+
+      const room = MAX_ATTACHMENTS_PER_MESSAGE - pendingRef.current.length;
+      if (files.length > room) {
+        toast.error(`You can attach up to ${MAX_ATTACHMENTS_PER_MESSAGE} files per message`, { duration: 6000 });
+      }
+
+      const accepted = files.slice(0, Math.max(room, 0)).filter((file) => {
+        if (file.size > MAX_ATTACHMENT_BYTES) {
+          toast.error(`${file.name} is larger than 10 MB`, { duration: 6000 });
+          return false;
+        }
+        return true;
+      });
+
+      const staged = accepted.map<PendingAttachment>((file) => ({
+        localId: `att-${++nextLocalId}`,
+        file,
+        previewUrl: isImageType(file.type) ? URL.createObjectURL(file) : null,
+        progress: 0,
+        status: "uploading",
+      }));
+      setPending((prev) => [...prev, ...staged]);
+
+      staged.forEach(({ localId, file }) => {
+        const controller = new AbortController();
+        controllersRef.current.set(localId, controller);
+        uploadAttachment(threadId, file, {
+          signal: controller.signal,
+          onProgress: (progress) => update(localId, { progress }),
+        })
+          .then((attachment) => update(localId, { status: "ready", progress: 1, attachment }))
+          .catch((error: Error) => {
+            if (error.name === "AbortError") return;
+            update(localId, { status: "error", error: error.message });
+          })
+          .finally(() => controllersRef.current.delete(localId));
+      });
+    },
+    [threadId, update]
+  );
+
+  const removeAttachment = useCallback((localId: string) => {
+    controllersRef.current.get(localId)?.abort();
+    controllersRef.current.delete(localId);
+    setPending((prev) => {
+      const removed = prev.find((item) => item.localId === localId);
+      if (removed?.previewUrl) URL.revokeObjectURL(removed.previewUrl);
+      return prev.filter((item) => item.localId !== localId);
+    });
+  }, []);
+
+  // After a send the uploaded files live on the message; drop the local staging
+  const clearAttachments = useCallback(() => {
+    releaseAll();
+    setPending([]);
+  }, [releaseAll]);
+
+  const readyAttachments = pending
+    .filter((item) => item.status === "ready" && item.attachment)
+    .map((item) => item.attachment as ChatAttachment);
+
+  return {
+    pendingAttachments: pending,
+    readyAttachments,
+    isUploading: pending.some((item) => item.status === "uploading"),
+    addFiles,
+    removeAttachment,
+    clearAttachments,
+  };
+}
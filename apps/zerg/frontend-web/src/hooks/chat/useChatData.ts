@@ -1,15 +1,18 @@
-import { useMemo } from "react";
-import { useQuery } from "@tanstack/react-query";
+import { useCallback, useMemo, useState } from "react";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
 import {
   Agent,
   fetchAgent,
-  fetchThreadMessages,
+  fetchThreadMessagesPage,
   fetchThreads,
   fetchWorkflows,
   Thread,
+  THREAD_MESSAGES_PAGE_SIZE,
   ThreadMessage,
   Workflow,
 } from "../../services/api";
+import { mergeLatestPage, oldestMessageId, prependOlderPage } from "../../lib/threadHistory";
 
 interface UseChatDataParams {
   agentId: number | null;
@@ -17,6 +20,10 @@ interface UseChatDataParams {
 }
 
 export function useChatData({ agentId, effectiveThreadId }: UseChatDataParams) {
+  const queryClient = useQueryClient();
+  // Whether the server has messages older than the ones loaded, per thread
+  const [hasOlderByThread, setHasOlderByThread] = useState<Record<number, boolean>>({});
+  const [isLoadingOlder, setIsLoadingOlder] = useState(false);
   const agentQuery = useQuery<Agent>({
     queryKey: ["agent", agentId],
     queryFn: () => {
@@ -59,29 +66,67 @@ export function useChatData({ agentId, effectiveThreadId }: UseChatDataParams) {
   // Fetch workflows for execution in chat
   const workflowsQuery = useQuery<Workflow[]>({
     queryKey: ["workflows"],
-    queryFn: fetchWorkflows,
+    queryFn: () => fetchWorkflows(),
     staleTime: 60000, // Cache for 1 minute
   });
 
+  // Only the newest page is fetched; refetches keep whatever older history was already paged in
   const messagesQuery = useQuery<ThreadMessage[]>({
     queryKey: ["thread-messages", effectiveThreadId],
-    queryFn: () => {
+    queryFn: async () => {
       if (effectiveThreadId == null) {
-        return Promise.resolve<ThreadMessage[]>([]);
+        return [];
+      }
+      const threadId = effectiveThreadId;
+      const page = await fetchThreadMessagesPage(threadId);
+      const merged = mergeLatestPage(queryClient.getQueryData<ThreadMessage[]>(["thread-messages", threadId]), page);
+      if (merged === page) {
+        setHasOlderByThread((prev) => ({ ...prev, [threadId]: page.length >= THREAD_MESSAGES_PAGE_SIZE }));
       }
-      return fetchThreadMessages(effectiveThreadId);
+      return merged;
     },
     enabled: effectiveThreadId != null,
   });
 
-  const chatThreads = useMemo(() => {
+  const hasOlderMessages = effectiveThreadId != null && (hasOlderByThread[effectiveThreadId] ?? false);
+
+  const loadOlderMessages = useCallback(async () => {
+    if (effectiveThreadId == null || isLoadingOlder) {
+      return;
+    }
+    const threadId = effectiveThreadId;
+    const queryKey = ["thread-messages", threadId];
+    const beforeMessageId = oldestMessageId(queryClient.getQueryData<ThreadMessage[]>(queryKey) ?? []);
+    if (beforeMessageId == null) {
+      return;
+    }
+    setIsLoadingOlder(true);
+    try {
+      const page = await fetchThreadMessagesPage(threadId, { beforeMessageId });
+      queryClient.setQueryData<ThreadMessage[]>(queryKey, (current) => prependOlderPage(current ?? [], page));
+      setHasOlderByThread((prev) => ({ ...prev, [threadId]: page.length >= THREAD_MESSAGES_PAGE_SIZE }));
+    } catch (error) {
+      console.error("Failed to load older messages:", error);
+      toast.error("Failed to load older messages");
+    } finally {
+      setIsLoadingOlder(false);
+    }
+  }, [effectiveThreadId, isLoadingOlder, queryClient]);
+
+  const { chatThreads, archivedThreads } = useMemo(() => {
     const list = chatThreadsQuery.data ?? [];
     // Sort threads by updated_at (newest first), falling back to created_at
-    return [...list].sort((a, b) => {
+    const sorted = [...list].sort((a, b) => {
       const aTime = a.updated_at || a.created_at;
       const bTime = b.updated_at || b.created_at;
       return bTime.localeCompare(aTime);
     });
+    const visible = sorted.filter((thread) => !thread.archived);
+    return {
+      // Pinned threads stay on top; the sort is stable so recency order holds within each group
+      chatThreads: [...visible.filter((thread) => thread.pinned), ...visible.filter((thread) => !thread.pinned)],
+      archivedThreads: sorted.filter((thread) => thread.archived),
+    };
   }, [chatThreadsQuery.data]);
 
   const automationThreads = useMemo(() => {
@@ -108,8 +153,12 @@ export function useChatData({ agentId, effectiveThreadId }: UseChatDataParams) {
     // Derived data
     agent: agentQuery.data,
     chatThreads,
+    archivedThreads,
     automationThreads,
     messages: messagesQuery.data ?? [],
+    hasOlderMessages,
+    isLoadingOlder,
+    loadOlderMessages,
 
     // State
     isLoading,
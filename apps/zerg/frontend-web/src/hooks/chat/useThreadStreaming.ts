@@ -1,13 +1,23 @@
 import { useCallback, useMemo, useRef, useState } from "react";
 import { useQueryClient } from "@tanstack/react-query";
 import { useWebSocket } from "../../lib/useWebSocket";
+import type { MessageUsage, ThreadMessage } from "../../services/api";
+import type { StreamedToolOutput } from "../../components/chat/toolCalls";
+
+// Who asked for the reply being streamed (shared threads have several users)
+export interface ReplyStarter {
+  id: number;
+  name: string | null;
+}
 
 interface StreamingState {
   streamingMessages: Map<number, string>;
   streamingMessageId: number | null;
   pendingTokenBuffer: string;
+  toolOutputs: StreamedToolOutput[];
   tokenCount: number;
   startTime: number;
+  startedBy: ReplyStarter | null;
 }
 
 interface UseThreadStreamingParams {
@@ -15,6 +25,8 @@ interface UseThreadStreamingParams {
   effectiveThreadId: number | null;
 }
 
+const EMPTY_TOOL_OUTPUTS: StreamedToolOutput[] = [];
+
 export function useThreadStreaming({ agentId, effectiveThreadId }: UseThreadStreamingParams) {
   const queryClient = useQueryClient();
 
@@ -49,8 +61,15 @@ export function useThreadStreaming({ agentId, effectiveThreadId }: UseThreadStre
         streamingMessages: new Map(),
         streamingMessageId: null,
         pendingTokenBuffer: "",
+        toolOutputs: [],
         tokenCount: 0,
         startTime: Date.now(),
+        startedBy: data.started_by_id != null ? { id: data.started_by_id, name: data.started_by_name ?? null } : null,
+      });
+
+      // Another participant's message may have triggered this reply
+      queryClient.invalidateQueries({
+        queryKey: ["thread-messages", threadId]
       });
 
       // Force re-render to show writing badge immediately
@@ -86,6 +105,19 @@ export function useThreadStreaming({ agentId, effectiveThreadId }: UseThreadStre
 
         // Force re-render to update UI (active thread tokens + badge indicators)
         forceUpdate();
+      } else if (data.chunk_type === "tool_output") {
+        // Shown as a tool-call bubble under the assistant message being streamed
+        stream.toolOutputs = [
+          ...stream.toolOutputs,
+          {
+            messageId: data.message_id ?? null,
+            parentId: stream.streamingMessageId,
+            toolCallId: data.tool_call_id ?? null,
+            toolName: data.tool_name ?? null,
+            content: data.content || "",
+          },
+        ];
+        forceUpdate();
       }
 
     } else if (type === "assistant_id") {
@@ -113,6 +145,22 @@ export function useThreadStreaming({ agentId, effectiveThreadId }: UseThreadStre
         console.log(`[CHAT] 🏁 STREAM_END - thread ${threadId}: ${stream.tokenCount} tokens in ${duration}ms`);
       }
 
+      // Show the reply's usage footer right away; the refetch below persists it
+      if (data.message_id != null) {
+        const usage: MessageUsage = {
+          run_id: data.run_id ?? null,
+          model: data.model ?? null,
+          prompt_tokens: data.prompt_tokens ?? null,
+          completion_tokens: data.completion_tokens ?? null,
+          total_tokens: data.total_tokens ?? null,
+          duration_ms: data.duration_ms ?? null,
+          cost_usd: data.cost_usd ?? null,
+        };
+        queryClient.setQueryData<ThreadMessage[]>(["thread-messages", threadId], (current) =>
+          current?.map((msg) => (msg.id === data.message_id ? { ...msg, usage } : msg))
+        );
+      }
+
       // Refresh messages from API for this thread
       queryClient.invalidateQueries({
         queryKey: ["thread-messages", threadId]
@@ -149,6 +197,10 @@ export function useThreadStreaming({ agentId, effectiveThreadId }: UseThreadStre
     streamingMessages: activeStream?.streamingMessages || new Map(),
     streamingMessageId: activeStream?.streamingMessageId || null,
     pendingTokenBuffer: activeStream?.pendingTokenBuffer || "",
+    streamingToolOutputs: activeStream?.toolOutputs ?? EMPTY_TOOL_OUTPUTS,
+    // The agent is answering in the active thread, whoever asked
+    isAgentReplying: activeStream != null,
+    replyStartedBy: activeStream?.startedBy ?? null,
     allStreamingThreadIds: Array.from(streamsByThread.current.keys()),
   };
 }
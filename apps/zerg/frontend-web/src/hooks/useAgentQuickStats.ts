@@ -0,0 +1,135 @@
+import { useMemo } from "react";
+import { useQuery, useQueryClient, type QueryClient } from "@tanstack/react-query";
+import {
+  fetchAgent,
+  fetchAgentRuns,
+  type AgentRun,
+  type AgentSummary,
+  type DashboardSnapshot,
+} from "../services/api";
+
+// Number of recent runs shown in the hover card sparkline
+export const QUICK_STATS_RUNS = 20;
+
+export interface AgentQuickStats {
+  agent: AgentSummary | null;
+  status: string | null;
+  lastRunAt: string | null;
+  ownerLabel: string | null;
+  runCount: number;
+  successRate: number | null;
+  // Oldest → newest, one entry per finished run (true = success)
+  sparkline: boolean[];
+}
+
+type CachedAgentData = {
+  agent: AgentSummary | null;
+  runs: AgentRun[] | null;
+};
+
+/**
+ * Look up an agent and its recent runs in data other screens already fetched.
+ *
+ * The dashboard snapshot carries both the agent row and its run bundle, the
+ * canvas shelf caches the plain agent list, and the chat view caches single
+ * agents. Reusing those avoids a network round-trip for most hovers.
+ */
+export function selectCachedAgentData(queryClient: QueryClient, agentId: number): CachedAgentData {
+  let agent: AgentSummary | null = null;
+  let runs: AgentRun[] | null = null;
+
+  for (const [, snapshot] of queryClient.getQueriesData<DashboardSnapshot>({ queryKey: ["dashboard"] })) {
+    if (!snapshot) continue;
+    const match = snapshot.agents.find((candidate) => candidate.id === agentId);
+    if (match) {
+      agent = match;
+      const bundle = snapshot.runs.find((candidate) => candidate.agentId === agentId);
+      runs = bundle ? bundle.runs : [];
+      break;
+    }
+  }
+
+  if (!agent) {
+    agent = queryClient.getQueryData<AgentSummary>(["agent", agentId]) ?? null;
+  }
+
+  if (!agent) {
+    for (const [, list] of queryClient.getQueriesData<AgentSummary[]>({ queryKey: ["agents"] })) {
+      const match = Array.isArray(list) ? list.find((candidate) => candidate.id === agentId) : undefined;
+      if (match) {
+        agent = match;
+        break;
+      }
+    }
+  }
+
+  if (!runs) {
+    runs = queryClient.getQueryData<AgentRun[]>(["agent-runs", agentId]) ?? null;
+  }
+
+  return { agent, runs };
+}
+
+export function buildAgentQuickStats(agent: AgentSummary | null, runs: AgentRun[] | null): AgentQuickStats {
+  // Runs arrive newest-first; the sparkline reads left-to-right in time
+  const finished = (runs ?? [])
+    .slice(0, QUICK_STATS_RUNS)
+    .filter((run) => run.status === "success" || run.status === "failed")
+    .reverse();
+  const successCount = finished.filter((run) => run.status === "success").length;
+
+  const ownerLabel = agent?.owner
+    ? agent.owner.display_name?.trim() || agent.owner.email || null
+    : null;
+
+  return {
+    agent,
+    status: agent?.status ?? null,
+    lastRunAt: agent?.last_run_at ?? runs?.[0]?.started_at ?? null,
+    ownerLabel,
+    runCount: runs?.length ?? 0,
+    successRate: finished.length > 0 ? (successCount / finished.length) * 100 : null,
+    sparkline: finished.map((run) => run.status === "success"),
+  };
+}
+
+/**
+ * Quick stats for the agent hover card.
+ *
+ * Cached data is used when present; otherwise the agent and its runs are
+ * fetched lazily, only once the card is actually open.
+ */
+export function useAgentQuickStats(agentId: number, enabled: boolean) {
+  const queryClient = useQueryClient();
+
+  // Re-evaluated each time the card opens so it picks up fresh cache entries
+  const cached = useMemo(
+    () => (enabled ? selectCachedAgentData(queryClient, agentId) : { agent: null, runs: null }),
+    [agentId, enabled, queryClient]
+  );
+
+  const agentQuery = useQuery<AgentSummary>({
+    queryKey: ["agent", agentId],
+    queryFn: () => fetchAgent(agentId),
+    enabled: enabled && cached.agent == null,
+    staleTime: 30_000,
+  });
+
+  const runsQuery = useQuery<AgentRun[]>({
+    queryKey: ["agent-runs", agentId],
+    queryFn: () => fetchAgentRuns(agentId, QUICK_STATS_RUNS),
+    enabled: enabled && cached.runs == null,
+    staleTime: 30_000,
+  });
+
+  const agent = cached.agent ?? agentQuery.data ?? null;
+  const runs = cached.runs ?? runsQuery.data ?? null;
+
+  const stats = useMemo(() => buildAgentQuickStats(agent, runs), [agent, runs]);
+
+  return {
+    stats,
+    isLoadingAgent: agent == null && agentQuery.isLoading,
+    isLoadingRuns: runs == null && runsQuery.isLoading,
+  };
+}
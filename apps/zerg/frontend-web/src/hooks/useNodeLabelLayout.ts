@@ -0,0 +1,49 @@
+import { useMemo, useSyncExternalStore } from "react";
+import {
+  getTextMetricsVersion,
+  layoutLabel,
+  subscribeToTextMetrics,
+  type LabelLayout,
+  type LabelLayoutOptions,
+} from "../lib/textMeasure";
+
+// Node labels use the .agent-node/.tool-node/.trigger-node typography
+const FALLBACK_FONT_SIZE = "14px";
+const FALLBACK_FONT_FAMILY = "Inter, system-ui, sans-serif";
+const NODE_LABEL_WEIGHT = 500;
+
+let cachedFont: { version: number; font: string } | null = null;
+
+function readNodeLabelFont(): string {
+  if (typeof window === "undefined" || typeof document === "undefined") {
+    return `${NODE_LABEL_WEIGHT} ${FALLBACK_FONT_SIZE} ${FALLBACK_FONT_FAMILY}`;
+  }
+  const styles = window.getComputedStyle(document.documentElement);
+  const size = styles.getPropertyValue("--font-size-sm").trim() || FALLBACK_FONT_SIZE;
+  const family = styles.getPropertyValue("--font-family-base").trim() || FALLBACK_FONT_FAMILY;
+  return `${NODE_LABEL_WEIGHT} ${size} ${family}`;
+}
+
+/** The node label font, re-read from CSS only after a metrics change (theme, fonts, zoom). */
+export function nodeLabelFont(): string {
+  const version = getTextMetricsVersion();
+  if (cachedFont?.version !== version) {
+    cachedFont = { version, font: readNodeLabelFont() };
+  }
+  return cachedFont.font;
+}
+
+/**
+ * Measured wrapping for a canvas node label. Re-computes when fonts load,
+ * the zoom level / pixel ratio changes or the theme swaps fonts.
+ */
+export function useNodeLabelLayout(text: string, options?: LabelLayoutOptions): LabelLayout {
+  const version = useSyncExternalStore(subscribeToTextMetrics, getTextMetricsVersion, getTextMetricsVersion);
+  const { minWidth, maxWidth, maxLines } = options ?? {};
+
+  return useMemo(
+    () => layoutLabel(text, nodeLabelFont(), { minWidth, maxWidth, maxLines }),
+    // version is the cache-busting signal, not an input to layoutLabel
+    [text, version, minWidth, maxWidth, maxLines]
+  );
+}
@@ -18,7 +18,7 @@ import { useCallback, useRef } from 'react';
  */
 
 export interface DragData {
-  type: 'agent' | 'tool';
+  type: 'agent' | 'tool' | 'control';
   id?: string;
   name: string;
   [key: string]: unknown;
@@ -0,0 +1,64 @@
+/**
+ * Per-agent spend caps. The cap lives in ``agent.config.budget`` and is
+ * enforced server-side (quota.assert_agent_within_budget); the dashboard
+ * snapshot reports current spend so the UI can block runs up front.
+ */
+
+import type { AgentBudgetStatus } from "../services/api";
+
+export type BudgetPeriod = AgentBudgetStatus["period"];
+
+export interface AgentBudgetConfig {
+  period: BudgetPeriod;
+  limit_usd: number;
+}
+
+// Key in agent.config; mirrors AGENT_BUDGET_KEY in services/quota.py
+export const AGENT_BUDGET_KEY = "budget";
+
+export const BUDGET_PERIOD_LABELS: Record<BudgetPeriod, string> = {
+  daily: "Per day",
+  monthly: "Per month",
+};
+
+// Meter turns amber from here on, like the backend's 80% warning
+export const BUDGET_WARNING_PERCENT = 80;
+
+export function budgetFromConfig(config: Record<string, unknown> | null | undefined): AgentBudgetConfig | null {
+  const budget = config?.[AGENT_BUDGET_KEY];
+  if (!budget || typeof budget !== "object") return null;
+  const { period, limit_usd: limit } = budget as Record<string, unknown>;
+  const limitUsd = Number(limit);
+  if ((period !== "daily" && period !== "monthly") || !Number.isFinite(limitUsd) || limitUsd <= 0) return null;
+  return { period, limit_usd: limitUsd };
+}
+
+/** Config with the budget set (or removed when ``budget`` is null), other keys kept. */
+export function withBudget(
+  config: Record<string, unknown> | null | undefined,
+  budget: AgentBudgetConfig | null
+): Record<string, unknown> {
+  const next = { ...(config ?? {}) };
+  if (budget) next[AGENT_BUDGET_KEY] = budget;
+  else delete next[AGENT_BUDGET_KEY];
+  return next;
+}
+
+export function isBudgetExhausted(status: AgentBudgetStatus | null | undefined): boolean {
+  return !!status && status.spent_usd >= status.limit_usd;
+}
+
+export function budgetTone(status: AgentBudgetStatus): "ok" | "warning" | "exhausted" {
+  if (isBudgetExhausted(status)) return "exhausted";
+  return status.percent >= BUDGET_WARNING_PERCENT ? "warning" : "ok";
+}
+
+export function formatBudget(status: AgentBudgetStatus): string {
+  const period = status.period === "daily" ? "today" : "this month";
+  return `$${status.spent_usd.toFixed(2)} of $${status.limit_usd.toFixed(2)} ${period}`;
+}
+
+export function budgetExhaustedMessage(status: AgentBudgetStatus): string {
+  const period = status.period === "daily" ? "daily" : "monthly";
+  return `Run blocked: ${period} budget used up (${formatBudget(status)}). Raise the cap in Agent Config.`;
+}
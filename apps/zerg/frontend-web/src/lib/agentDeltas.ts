@@ -0,0 +1,87 @@
+/**
+ * Agent list deltas from the `agent_event` messages on the user's personal
+ * topic. Each delta carries the full agent row plus the action and a
+ * per-owner sequence number; the dashboard patches just that agent's row
+ * and only refetches the whole snapshot when the sequence shows a gap.
+ */
+
+import type { AgentSummary, DashboardSnapshot } from "../services/api";
+
+export type AgentDeltaAction = "created" | "updated" | "deleted";
+
+export interface AgentDelta {
+  action: AgentDeltaAction;
+  seq: number | null;
+  agent: AgentSummary;
+}
+
+const ACTIONS: readonly AgentDeltaAction[] = ["created", "updated", "deleted"];
+
+/** Pull a delta out of an `agent_event` payload; `null` for per-agent status events. */
+export function parseAgentDelta(data: unknown): AgentDelta | null {
+  if (typeof data !== "object" || data === null) return null;
+  const { action, seq, ...agent } = data as Record<string, unknown>;
+  if (!ACTIONS.includes(action as AgentDeltaAction) || typeof agent.id !== "number") return null;
+  return {
+    action: action as AgentDeltaAction,
+    seq: typeof seq === "number" ? seq : null,
+    agent: agent as unknown as AgentSummary,
+  };
+}
+
+export function removeAgentFromSnapshot(snapshot: DashboardSnapshot, agentId: number): DashboardSnapshot {
+  return {
+    ...snapshot,
+    agents: snapshot.agents.filter((agent) => agent.id !== agentId),
+    runs: snapshot.runs.filter((bundle) => bundle.agentId !== agentId),
+    budgets: snapshot.budgets.filter((budget) => budget.agent_id !== agentId),
+    stats: snapshot.stats.filter((stats) => stats.agent_id !== agentId),
+  };
+}
+
+/** Insert, patch or drop the delta's agent. Returns *snapshot* itself when nothing changed. */
+export function applyAgentDelta(snapshot: DashboardSnapshot, delta: AgentDelta): DashboardSnapshot {
+  const index = snapshot.agents.findIndex((agent) => agent.id === delta.agent.id);
+
+  if (delta.action === "deleted") {
+    return index === -1 ? snapshot : removeAgentFromSnapshot(snapshot, delta.agent.id);
+  }
+
+  if (index === -1) {
+    // An update for an agent we never saw means we missed its creation; insert it either way
+    return { ...snapshot, agents: [...snapshot.agents, delta.agent] };
+  }
+
+  const current = snapshot.agents[index];
+  // The event row lacks API-only fields such as the nested owner; keep ours
+  const next = { ...current, ...delta.agent };
+  const changed = (Object.keys(delta.agent) as (keyof AgentSummary)[]).some(
+    (key) => JSON.stringify(current[key]) !== JSON.stringify(next[key])
+  );
+  if (!changed) return snapshot;
+
+  const agents = snapshot.agents.slice();
+  agents[index] = next;
+  return { ...snapshot, agents };
+}
+
+/**
+ * Tracks the delta sequence for one connection. The first delta after a
+ * reset is taken as the baseline; after that anything but the next number
+ * (including a restart's reset back to 1) is a gap.
+ */
+export class AgentDeltaSequence {
+  private last: number | null = null;
+
+  /** Returns false when deltas were missed and the list must be refetched. */
+  accept(seq: number | null): boolean {
+    if (seq === null) return true;
+    const inOrder = this.last === null || seq === this.last + 1;
+    this.last = seq;
+    return inOrder;
+  }
+
+  reset() {
+    this.last = null;
+  }
+}
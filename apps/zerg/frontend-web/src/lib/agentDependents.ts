@@ -0,0 +1,49 @@
+/**
+ * Pre-delete dependency check: what still relies on an agent (workflows,
+ * triggers, its schedule, queued runs) and how the delete dialog words it.
+ */
+
+import type { AgentDependents } from "../services/api";
+
+function plural(count: number, noun: string): string {
+  return `${count} ${noun}${count === 1 ? "" : "s"}`;
+}
+
+export function activeDependentTriggers(dependents: AgentDependents) {
+  return dependents.triggers.filter((trigger) => !trigger.disabled);
+}
+
+/** Nothing to warn about – the agent can go straight to the trash. */
+export function hasDependents(dependents: AgentDependents): boolean {
+  return (
+    dependents.workflows.length > 0 ||
+    activeDependentTriggers(dependents).length > 0 ||
+    Boolean(dependents.schedule) ||
+    dependents.queued_run_ids.length > 0
+  );
+}
+
+/** Whether "disable and delete" would change anything beyond a plain delete. */
+export function canDisableDependents(dependents: AgentDependents): boolean {
+  return activeDependentTriggers(dependents).length > 0 || dependents.queued_run_ids.length > 0;
+}
+
+/** One line per kind of dependent, for the confirmation dialog. */
+export function describeDependents(dependents: AgentDependents): string[] {
+  const lines: string[] = [];
+  const { workflows, schedule, queued_run_ids: queued } = dependents;
+  const triggers = activeDependentTriggers(dependents);
+
+  if (workflows.length) {
+    const names = workflows.map((workflow) => workflow.name).join(", ");
+    const count = plural(workflows.length, "workflow");
+    lines.push(`Used by ${count} (${names}); their agent steps fail while it is deleted.`);
+  }
+  if (triggers.length) {
+    const types = Array.from(new Set(triggers.map((trigger) => trigger.type))).join(", ");
+    lines.push(`${plural(triggers.length, "active trigger")} (${types}) will stop running it.`);
+  }
+  if (schedule) lines.push(`Its schedule (${schedule}) stops.`);
+  if (queued.length) lines.push(`${plural(queued.length, "queued run")} waiting to start.`);
+  return lines;
+}
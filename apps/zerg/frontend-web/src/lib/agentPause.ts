@@ -0,0 +1,32 @@
+/**
+ * Agent pause and maintenance mode. A paused agent (``paused_at`` set) skips
+ * scheduled runs and trigger firings server-side; maintenance mode does the
+ * same for every agent. Manual runs are still allowed, so the UI asks first.
+ */
+
+import type { AgentSummary } from "../services/api";
+
+type PausableAgent = Pick<AgentSummary, "name" | "paused_at">;
+
+export function isAgentPaused(agent: Pick<AgentSummary, "paused_at"> | null | undefined): boolean {
+  return !!agent?.paused_at;
+}
+
+export const PAUSED_BADGE_TITLE = "Paused: schedules and triggers are suspended";
+
+export const MAINTENANCE_BANNER_TEXT =
+  "Maintenance mode is on: scheduled runs and trigger firings are suspended for all agents.";
+
+export function pausedRunWarning(agents: PausableAgent[]): string | null {
+  const paused = agents.filter(isAgentPaused);
+  if (paused.length === 0) return null;
+  const names = paused.map((agent) => `"${agent.name}"`).join(", ");
+  const subject = paused.length === 1 ? `${names} is paused` : `${names} are paused`;
+  return `${subject}. Run anyway? Schedules and triggers stay suspended.`;
+}
+
+/** True when nothing is paused or the user agreed to run anyway. */
+export function confirmRunWhilePaused(agents: PausableAgent[]): boolean {
+  const warning = pausedRunWarning(agents);
+  return warning === null || window.confirm(warning);
+}
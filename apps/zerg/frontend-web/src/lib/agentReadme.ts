@@ -0,0 +1,57 @@
+// Agent READMEs are markdown written for people (what the agent does, its
+// inputs, known caveats); these helpers derive the one-line teaser shown
+// while the panel is collapsed.
+
+export const README_SUMMARY_CHARS = 160;
+
+const FENCE = /^ {0,3}(`{3,}|~{3,})/;
+
+function stripInlineMarkdown(text: string): string {
+  return text
+    .replace(/!\[([^\]]*)\]\([^)]*\)/g, "$1")
+    .replace(/\[([^\]]+)\]\([^)]*\)/g, "$1")
+    .replace(/`([^`]*)`/g, "$1")
+    .replace(/(\*\*|__)(.*?)\1/g, "$2")
+    .replace(/\*(.+?)\*/g, "$1")
+    // Leave snake_case identifiers alone
+    .replace(/(^|\W)_(.+?)_(?=\W|$)/g, "$1$2")
+    .replace(/~~(.*?)~~/g, "$1")
+    .replace(/\s+/g, " ")
+    .trim();
+}
+
+/**
+ * Plain text of the first paragraph of *markdown*, skipping headings and code
+ * blocks, cut to *maxChars*. Empty when there is nothing to show.
+ */
+export function readmeSummary(markdown: string | null | undefined, maxChars: number = README_SUMMARY_CHARS): string {
+  if (!markdown) return "";
+  const paragraph: string[] = [];
+  let inFence = false;
+
+  for (const rawLine of markdown.split("\n")) {
+    if (FENCE.test(rawLine)) {
+      inFence = !inFence;
+      if (paragraph.length > 0) break;
+      continue;
+    }
+    if (inFence) continue;
+    const line = rawLine.trim();
+    if (!line) {
+      if (paragraph.length > 0) break;
+      continue;
+    }
+    if (/^#{1,6}\s/.test(line) || /^(-{3,}|\*{3,}|_{3,})$/.test(line)) {
+      if (paragraph.length > 0) break;
+      continue;
+    }
+    paragraph.push(line.replace(/^(>\s*|[-*+]\s+|\d+[.)]\s+)/, ""));
+  }
+
+  const text = stripInlineMarkdown(paragraph.join(" "));
+  return text.length > maxChars ? `${text.slice(0, maxChars - 1).trimEnd()}…` : text;
+}
+
+export function hasReadme(description: string | null | undefined): description is string {
+  return Boolean(description && description.trim());
+}
@@ -0,0 +1,38 @@
+/**
+ * What the current user may do with an agent on the dashboard. Owners (and
+ * admins with manage_any_agent) have full control; everyone else gets the
+ * view/run/edit level the owner shared with them. The server enforces the
+ * same rules: deleting, pausing, duplicating, exporting, chatting and
+ * sharing stay owner-only.
+ */
+
+import type { AgentPermission, AgentSummary, SharePermission } from "../services/api";
+
+export type AgentAccess = "owner" | SharePermission;
+
+const ORDER: AgentAccess[] = ["view", "run", "edit", "owner"];
+
+export const SHARE_PERMISSION_LABELS: Record<SharePermission, string> = {
+  view: "Can view",
+  run: "Can run",
+  edit: "Can edit",
+};
+
+export function agentAccess(
+  agent: Pick<AgentSummary, "id" | "owner_id">,
+  userId: number | null | undefined,
+  shared: ReadonlyMap<number, SharePermission>,
+  canManageAnyAgent: boolean
+): AgentAccess | null {
+  if (canManageAnyAgent || (userId != null && agent.owner_id === userId)) return "owner";
+  return shared.get(agent.id) ?? null;
+}
+
+/** True when *access* includes *required*. */
+export function accessAllows(access: AgentAccess | null, required: AgentAccess): boolean {
+  return access !== null && ORDER.indexOf(access) >= ORDER.indexOf(required);
+}
+
+export function sharedPermissionMap(permissions: AgentPermission[] | undefined): Map<number, SharePermission> {
+  return new Map((permissions ?? []).map((entry) => [entry.agent_id, entry.permission]));
+}
@@ -0,0 +1,122 @@
+/**
+ * The canvas agent shelf: search, tag filtering, favorites pinned on top and
+ * collapsible groups. Favorites, the grouping and which groups are collapsed
+ * live in user.prefs so the shelf looks the same on every device.
+ */
+
+import type { AgentSummary } from "../services/api";
+import { matchesTags } from "./tags";
+
+// Keys in user.prefs
+export const SHELF_FAVORITES_PREF = "shelf_favorites";
+export const SHELF_GROUPING_PREF = "shelf_grouping";
+export const SHELF_COLLAPSED_PREF = "shelf_collapsed_groups";
+
+export const SHELF_GROUPINGS = ["none", "owner", "tag"] as const;
+export type ShelfGrouping = (typeof SHELF_GROUPINGS)[number];
+
+export const SHELF_GROUPING_LABELS: Record<ShelfGrouping, string> = {
+  none: "No grouping",
+  owner: "By owner",
+  tag: "By tag",
+};
+
+export const FAVORITES_GROUP_KEY = "favorites";
+export const UNTAGGED_GROUP_KEY = "untagged";
+
+export interface ShelfGroup {
+  key: string;
+  label: string;
+  agents: AgentSummary[];
+}
+
+type Prefs = Record<string, unknown> | null | undefined;
+
+export function favoritesFromPrefs(prefs: Prefs): number[] {
+  const value = prefs?.[SHELF_FAVORITES_PREF];
+  return Array.isArray(value) ? value.filter((id): id is number => Number.isInteger(id)) : [];
+}
+
+export function groupingFromPrefs(prefs: Prefs): ShelfGrouping {
+  const value = prefs?.[SHELF_GROUPING_PREF];
+  return (SHELF_GROUPINGS as readonly unknown[]).includes(value) ? (value as ShelfGrouping) : "none";
+}
+
+export function collapsedGroupsFromPrefs(prefs: Prefs): string[] {
+  const value = prefs?.[SHELF_COLLAPSED_PREF];
+  return Array.isArray(value) ? value.filter((key): key is string => typeof key === "string") : [];
+}
+
+/** *list* with *item* added, or removed if it was already there. */
+export function toggleInList<T>(list: readonly T[], item: T): T[] {
+  return list.includes(item) ? list.filter((entry) => entry !== item) : [...list, item];
+}
+
+function ownerGroup(agent: AgentSummary, currentUserId: number | null): { key: string; label: string } {
+  if (agent.owner_id === currentUserId) return { key: "owner:me", label: "Mine" };
+  const owner = agent.owner;
+  const name = owner?.display_name?.trim() || owner?.email || `User ${agent.owner_id}`;
+  return { key: `owner:${agent.owner_id}`, label: name };
+}
+
+// An agent with several tags is listed under each of them
+function tagGroups(agent: AgentSummary): { key: string; label: string }[] {
+  const tags = agent.tags ?? [];
+  if (tags.length === 0) return [{ key: UNTAGGED_GROUP_KEY, label: "Untagged" }];
+  return tags.map((tag) => ({ key: `tag:${tag.toLowerCase()}`, label: tag }));
+}
+
+interface GroupOptions {
+  search?: string;
+  // Only agents carrying every one of these tags
+  tags?: readonly string[];
+  grouping?: ShelfGrouping;
+  favorites?: readonly number[];
+  currentUserId?: number | null;
+}
+
+/**
+ * Agents matching *search* and *tags*, split into groups. Favorites come first
+ * in their own group (and are left out of the others); "Mine" leads the owner
+ * groups and "Untagged" trails the tag groups, the rest are alphabetical.
+ * Empty groups are dropped.
+ */
+export function groupShelfAgents(agents: readonly AgentSummary[], options: GroupOptions = {}): ShelfGroup[] {
+  const { search = "", tags = [], grouping = "none", favorites = [], currentUserId = null } = options;
+  const normalized = search.trim().toLowerCase();
+  const byName = (left: AgentSummary, right: AgentSummary) => left.name.localeCompare(right.name);
+  const matching = agents.filter(
+    (agent) => (!normalized || agent.name.toLowerCase().includes(normalized)) && matchesTags(agent, tags)
+  );
+
+  const favoriteSet = new Set(favorites);
+  const pinned = matching.filter((agent) => favoriteSet.has(agent.id)).sort(byName);
+  const rest = matching.filter((agent) => !favoriteSet.has(agent.id));
+
+  const groups: ShelfGroup[] = [];
+  if (pinned.length > 0) {
+    groups.push({ key: FAVORITES_GROUP_KEY, label: "Favorites", agents: pinned });
+  }
+
+  if (grouping === "owner" || grouping === "tag") {
+    const grouped = new Map<string, ShelfGroup>();
+    for (const agent of rest) {
+      const keys = grouping === "owner" ? [ownerGroup(agent, currentUserId)] : tagGroups(agent);
+      for (const { key, label } of keys) {
+        const group = grouped.get(key) ?? { key, label, agents: [] };
+        group.agents.push(agent);
+        grouped.set(key, group);
+      }
+    }
+    // "Mine" leads and "Untagged" trails
+    const rank = (group: ShelfGroup) => (group.key === "owner:me" ? -1 : group.key === UNTAGGED_GROUP_KEY ? 1 : 0);
+    const ordered = [...grouped.values()].sort(
+      (left, right) => rank(left) - rank(right) || left.label.localeCompare(right.label)
+    );
+    ordered.forEach((group) => groups.push({ ...group, agents: group.agents.sort(byName) }));
+  } else if (rest.length > 0) {
+    groups.push({ key: "all", label: pinned.length > 0 ? "All agents" : "Agents", agents: [...rest].sort(byName) });
+  }
+
+  return groups;
+}
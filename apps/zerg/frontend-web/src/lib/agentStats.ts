@@ -0,0 +1,61 @@
+/**
+ * Per-agent run stats for the dashboard row. The snapshot carries stats
+ * aggregated server-side over every run (services/agent_stats.py); the
+ * loaded run page is only a fallback while those are missing.
+ */
+
+import type { AgentRun, AgentStats } from "../services/api";
+
+export interface SuccessSummary {
+  // Percent of finished runs that succeeded; null when nothing has finished
+  rate: number | null;
+  count: number;
+  display: string;
+}
+
+export function summarizeStats(stats: AgentStats): SuccessSummary {
+  const rate = stats.success_rate ?? null;
+  return { rate, count: stats.run_count, display: formatSuccess(rate, stats.run_count) };
+}
+
+/** Same summary from a page of runs; queued and running runs don't count against the rate. */
+export function summarizeRuns(runs: AgentRun[] | undefined): SuccessSummary {
+  const all = runs ?? [];
+  const finished = all.filter((run) => run.status === "success" || run.status === "failed");
+  const succeeded = finished.filter((run) => run.status === "success").length;
+  const rate = finished.length > 0 ? (succeeded / finished.length) * 100 : null;
+  return { rate, count: all.length, display: formatSuccess(rate, all.length) };
+}
+
+function formatSuccess(rate: number | null, count: number): string {
+  return `${rate === null ? "–" : `${rate.toFixed(1)}%`} (${count})`;
+}
+
+/** Sort order for the Success Rate column: unknown rates first, then by rate, then by run count. */
+export function compareSuccess(left: SuccessSummary, right: SuccessSummary): number {
+  const leftRate = left.rate ?? -1;
+  const rightRate = right.rate ?? -1;
+  if (leftRate !== rightRate) return leftRate - rightRate;
+  return left.count - right.count;
+}
+
+export function formatAverageDuration(durationMs: number | null | undefined): string | null {
+  if (durationMs === null || durationMs === undefined) return null;
+  if (durationMs < 1000) return `${Math.round(durationMs)} ms`;
+  const seconds = durationMs / 1000;
+  if (seconds < 60) return `${seconds.toFixed(1)} s`;
+  const minutes = Math.floor(seconds / 60);
+  return `${minutes} m ${String(Math.round(seconds % 60)).padStart(2, "0")} s`;
+}
+
+/** Bar heights (0..1) for the sparkline; all zeros when there were no runs. */
+export function sparklineHeights(daily: AgentStats["daily_runs"]): number[] {
+  const max = Math.max(0, ...daily.map((day) => day.count));
+  return daily.map((day) => (max === 0 ? 0 : day.count / max));
+}
+
+/** Replace one agent's entry, as after refetching it with fetchAgentStats. */
+export function withAgentStats(list: AgentStats[], next: AgentStats): AgentStats[] {
+  const found = list.some((entry) => entry.agent_id === next.agent_id);
+  return found ? list.map((entry) => (entry.agent_id === next.agent_id ? next : entry)) : [...list, next];
+}
@@ -0,0 +1,60 @@
+/**
+ * Which `agent:{id}` topics the dashboard subscribes to. Only agents whose
+ * rows are on screen (or close to it) get a per-agent topic, so a workspace
+ * with hundreds of agents doesn't stream every status flip to every tab.
+ * List-level changes – creations, renames, deletions – keep arriving on the
+ * personal `user:{id}` topic, which the server subscribes every socket to and
+ * which acts as the dashboard's summary feed.
+ *
+ * Agents that are busy stay subscribed while off screen so their run
+ * finishing still reaches the "run finished" notification, and an agent
+ * that comes back into view after being dropped has missed events, so the
+ * dashboard refetches once its topic is acknowledged again.
+ */
+
+import type { AgentRun, AgentSummary } from "../services/api";
+
+const BUSY_RUN_STATUSES: ReadonlySet<AgentRun["status"]> = new Set(["queued", "running", "cancelling"]);
+
+export interface WantedAgentOptions {
+  // Row ids currently inside the viewport margin; null before the first report
+  visibleIds: ReadonlySet<number> | null;
+  expandedAgentId: number | null;
+  runsByAgent: Record<number, AgentRun[] | undefined>;
+}
+
+/** Ids that should have a live `agent:` subscription right now. */
+export function wantedAgentIds(
+  agents: AgentSummary[],
+  { visibleIds, expandedAgentId, runsByAgent }: WantedAgentOptions
+): Set<number> {
+  const wanted = new Set<number>();
+  agents.forEach((agent) => {
+    const busy =
+      agent.status === "running" || (runsByAgent[agent.id] ?? []).some((run) => BUSY_RUN_STATUSES.has(run.status));
+    // Until visibility is known every rendered agent counts as visible
+    if (visibleIds === null || visibleIds.has(agent.id) || agent.id === expandedAgentId || busy) {
+      wanted.add(agent.id);
+    }
+  });
+  return wanted;
+}
+
+export interface TopicChanges {
+  subscribe: number[];
+  unsubscribe: number[];
+}
+
+/** Agent ids to subscribe (not yet subscribed or pending) and to drop. */
+export function agentTopicChanges(
+  wanted: ReadonlySet<number>,
+  subscribed: ReadonlySet<number>,
+  pending: ReadonlySet<number>
+): TopicChanges {
+  return {
+    subscribe: [...wanted].filter((id) => !subscribed.has(id) && !pending.has(id)),
+    unsubscribe: [...subscribed].filter((id) => !wanted.has(id)),
+  };
+}
+
+export const agentTopic = (id: number) => `agent:${id}`;
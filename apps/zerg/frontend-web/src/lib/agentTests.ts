@@ -0,0 +1,42 @@
+import type { AgentTestAssertion, AgentTestAssertionType, AgentTestCase } from "../services/api";
+
+export const ASSERTION_TYPES: { type: AgentTestAssertionType; label: string; placeholder: string }[] = [
+  { type: "contains", label: "Contains", placeholder: "Text the reply must include" },
+  { type: "not_contains", label: "Does not contain", placeholder: "Text the reply must not include" },
+  { type: "equals", label: "Equals", placeholder: "The whole expected reply" },
+  { type: "regex", label: "Matches regex", placeholder: "^Ticket #\\d+" },
+  { type: "json_path", label: "JSON path", placeholder: "Expected value (leave empty to check it exists)" },
+];
+
+export function assertionLabel(type: AgentTestAssertionType): string {
+  return ASSERTION_TYPES.find((entry) => entry.type === type)?.label ?? type;
+}
+
+export function emptyAssertion(type: AgentTestAssertionType = "contains"): AgentTestAssertion {
+  return { type, value: "", path: type === "json_path" ? "$." : null };
+}
+
+/** Why the assertion can't be saved as is, or null. */
+export function assertionError(assertion: AgentTestAssertion): string | null {
+  if (assertion.type === "json_path") {
+    return assertion.path?.trim() ? null : "Path is required";
+  }
+  // Regexes are checked server-side: Python's syntax differs from RegExp
+  return assertion.value ? null : "Value is required";
+}
+
+export interface TestRunSummary {
+  passed: number;
+  failed: number;
+  notRun: number;
+}
+
+export function summarizeTestRun(cases: AgentTestCase[]): TestRunSummary {
+  const summary: TestRunSummary = { passed: 0, failed: 0, notRun: 0 };
+  for (const testCase of cases) {
+    if (!testCase.last_result) summary.notRun++;
+    else if (testCase.last_result.passed) summary.passed++;
+    else summary.failed++;
+  }
+  return summary;
+}
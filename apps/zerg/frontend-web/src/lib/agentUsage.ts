@@ -0,0 +1,76 @@
+/**
+ * The dashboard's optional "Tokens (7d)" and "Cost (7d)" columns. Totals come
+ * from /agents/usage (services/agent_stats.py) and are only fetched while one
+ * of the columns is shown; which ones are shown is remembered per browser,
+ * like the sort.
+ */
+
+import type { AgentUsage } from "../services/api";
+
+export const USAGE_DAYS = 7;
+
+export const USAGE_COLUMNS = ["tokens", "cost"] as const;
+export type UsageColumn = (typeof USAGE_COLUMNS)[number];
+
+export const USAGE_COLUMN_LABELS: Record<UsageColumn, string> = {
+  tokens: `Tokens (${USAGE_DAYS}d)`,
+  cost: `Cost (${USAGE_DAYS}d)`,
+};
+
+export const USAGE_COLUMNS_STORAGE_KEY = "dashboard_usage_columns";
+
+/** Shown columns from their stored form (comma separated), in table order; unknown names are dropped. */
+export function parseUsageColumns(stored: string | null): UsageColumn[] {
+  const names = (stored ?? "").split(",").map((name) => name.trim());
+  return USAGE_COLUMNS.filter((column) => names.includes(column));
+}
+
+export function toggleUsageColumn(shown: readonly UsageColumn[], column: UsageColumn): UsageColumn[] {
+  const next = shown.includes(column) ? shown.filter((candidate) => candidate !== column) : [...shown, column];
+  return USAGE_COLUMNS.filter((candidate) => next.includes(candidate));
+}
+
+/** 950, 12.3k, 4.1M */
+export function formatTokenCount(tokens: number | null | undefined): string {
+  if (tokens === null || tokens === undefined) return "—";
+  if (tokens < 1000) return String(tokens);
+  if (tokens < 1_000_000) return `${trimZero(tokens / 1000)}k`;
+  return `${trimZero(tokens / 1_000_000)}M`;
+}
+
+function trimZero(value: number): string {
+  return value.toFixed(1).replace(/\.0$/, "");
+}
+
+/** Dollars with enough decimals that small spends don't read as $0.00; "—" when not priced. */
+export function formatUsageCost(cost: number | null | undefined): string {
+  if (cost === null || cost === undefined) return "—";
+  if (cost === 0 || cost >= 1) return `$${cost.toFixed(2)}`;
+  if (cost >= 0.01) return `$${cost.toFixed(3)}`;
+  return `$${cost.toFixed(4)}`;
+}
+
+/** Tooltip for the cost cell: one line per model, most expensive first. */
+export function costBreakdownTitle(usage: AgentUsage | undefined): string | undefined {
+  if (!usage || usage.by_model.length === 0) return undefined;
+  const lines = usage.by_model.map((entry) => {
+    const runs = `${entry.run_count} run${entry.run_count === 1 ? "" : "s"}`;
+    const tokens = `${formatTokenCount(entry.total_tokens)} tokens`;
+    return `${entry.model}: ${formatUsageCost(entry.total_cost_usd)} · ${tokens} (${runs})`;
+  });
+  if (usage.by_model.some((entry) => entry.total_cost_usd === null || entry.total_cost_usd === undefined)) {
+    lines.push("— = no pricing for that model");
+  }
+  return lines.join("\n");
+}
+
+/** Sort order for the usage columns: agents without numbers (not loaded, unpriced) first, then ascending. */
+export function compareUsage(
+  left: AgentUsage | undefined,
+  right: AgentUsage | undefined,
+  column: UsageColumn
+): number {
+  const value = (usage: AgentUsage | undefined) =>
+    (column === "tokens" ? usage?.total_tokens : usage?.total_cost_usd) ?? -1;
+  return value(left) - value(right);
+}
@@ -0,0 +1,57 @@
+// Screen-reader announcements for agent status changes. Kept short: the
+// live region interrupts whatever the user is listening to.
+
+export type Politeness = "polite" | "assertive";
+
+export interface Announcement {
+  message: string;
+  politeness: Politeness;
+  // Announcements with the same key inside the dedupe window are dropped, so
+  // a failed run and the agent's switch to "error" are only read out once
+  key?: string;
+}
+
+export const ANNOUNCEMENT_DEDUPE_MS = 5000;
+
+type AgentStatus = "idle" | "running" | "processing" | "error";
+
+export function agentStatusAnnouncement(
+  agent: { id: number; name: string; last_error?: string | null },
+  previous: AgentStatus,
+  next: AgentStatus
+): Announcement | null {
+  if (previous === next) return null;
+  if (next === "running") {
+    return { message: `${agent.name} is running`, politeness: "polite", key: `agent-${agent.id}-running` };
+  }
+  if (next === "error") {
+    const detail = agent.last_error ? `: ${agent.last_error}` : "";
+    return { message: `${agent.name} error${detail}`, politeness: "assertive", key: `agent-${agent.id}-error` };
+  }
+  return null;
+}
+
+export function runFinishedAnnouncement(
+  agent: { id: number; name: string },
+  runId: number,
+  status: "success" | "failed"
+): Announcement {
+  if (status === "success") {
+    return { message: `${agent.name} run ${runId} completed`, politeness: "polite", key: `run-${runId}` };
+  }
+  return { message: `${agent.name} run ${runId} failed`, politeness: "assertive", key: `agent-${agent.id}-error` };
+}
+
+/** Records the key and reports whether the announcement should be read out. */
+export function shouldAnnounce(
+  recent: Map<string, number>,
+  announcement: Announcement,
+  now: number,
+  windowMs: number = ANNOUNCEMENT_DEDUPE_MS
+): boolean {
+  if (!announcement.key) return true;
+  const last = recent.get(announcement.key);
+  if (last !== undefined && now - last < windowMs) return false;
+  recent.set(announcement.key, now);
+  return true;
+}
@@ -0,0 +1,39 @@
+/**
+ * API credentials: a base URL, an auth header template and a write-only key
+ * (services/api_credentials.py). HTTP nodes pick one as their auth source and
+ * MCP servers reference one instead of an auth token.
+ */
+import type { ApiCredential } from "../services/api";
+
+export const KEY_PLACEHOLDER = "{{key}}";
+export const DEFAULT_AUTH_HEADER = `Authorization: Bearer ${KEY_PLACEHOLDER}`;
+
+export const TEST_STATUS_LABELS: Record<ApiCredential["test_status"], string> = {
+  untested: "Not tested",
+  success: "Connected",
+  failed: "Failed",
+};
+
+/** Why an auth header template is unusable, or null when it's fine. Mirrors parse_auth_header. */
+export function authHeaderError(template: string): string | null {
+  const separator = template.indexOf(":");
+  const name = separator >= 0 ? template.slice(0, separator).trim() : "";
+  const value = separator >= 0 ? template.slice(separator + 1).trim() : "";
+  if (!name || /\s/.test(name)) return "Write the header as Name: value, e.g. Authorization: Bearer {{key}}.";
+  if (!value.includes(KEY_PLACEHOLDER)) return "Put {{key}} where the API key goes.";
+  return null;
+}
+
+export function isBaseUrl(url: string): boolean {
+  return /^https?:\/\/\S+$/.test(url.trim());
+}
+
+/** The key as shown once saved: dots with the last four characters when the server kept them. */
+export function maskedKey(credential: Pick<ApiCredential, "key_hint">): string {
+  return credential.key_hint ? `••••${credential.key_hint}` : "••••••••";
+}
+
+/** The header as it will be sent, with the key masked. */
+export function previewAuthHeader(credential: Pick<ApiCredential, "auth_header" | "key_hint">): string {
+  return credential.auth_header.replace(KEY_PLACEHOLDER, maskedKey(credential));
+}
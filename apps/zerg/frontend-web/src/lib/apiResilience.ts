@@ -0,0 +1,237 @@
+/**
+ * Retry and circuit breaking for API requests. Transient gateway errors
+ * (502/503/504) and network failures are retried with exponential backoff,
+ * but only for reads (GET/HEAD/OPTIONS); a mutation is never sent twice
+ * automatically. Each endpoint group (the first path segment, e.g. "agents")
+ * has its own breaker: enough consecutive failures open it so requests fail
+ * fast instead of piling onto a sick backend, and after a cooldown the last
+ * failed read is replayed as a half-open probe to decide whether to close it
+ * again.
+ */
+
+export type CircuitState = "closed" | "open" | "half-open";
+
+export interface RetryPolicy {
+  maxAttempts: number;
+  baseDelayMs: number;
+  maxDelayMs: number;
+}
+
+export interface CircuitOptions {
+  // Consecutive transient failures that open a group's circuit
+  failureThreshold?: number;
+  cooldownMs?: number;
+}
+
+export interface CircuitStatus {
+  group: string;
+  state: CircuitState;
+}
+
+export const DEFAULT_RETRY_POLICY: RetryPolicy = { maxAttempts: 3, baseDelayMs: 300, maxDelayMs: 4000 };
+export const RETRYABLE_STATUSES = new Set([502, 503, 504]);
+const SAFE_METHODS = new Set(["GET", "HEAD", "OPTIONS"]);
+
+export class CircuitOpenError extends Error {
+  readonly group: string;
+
+  constructor(group: string) {
+    super(`The ${group} API is temporarily unavailable; retrying automatically`);
+    this.name = "CircuitOpenError";
+    this.group = group;
+  }
+}
+
+/** Endpoint group for a request path: `/agents/3/runs?x=1` -> `agents`. */
+export function endpointGroup(path: string): string {
+  return path.replace(/^\/+/, "").split(/[/?#]/)[0] || "root";
+}
+
+/** Reads only: a replayed PUT or DELETE could undo a change made since it failed. */
+export function isRetryableRequest(init?: RequestInit): boolean {
+  return SAFE_METHODS.has((init?.method ?? "GET").toUpperCase());
+}
+
+/** Exponential backoff with jitter, honouring a server Retry-After (in seconds) when given. */
+export function backoffDelay(
+  attempt: number,
+  policy: RetryPolicy = DEFAULT_RETRY_POLICY,
+  retryAfter: string | null = null,
+  random: () => number = Math.random
+): number {
+  const serverDelay = retryAfter !== null ? Number(retryAfter) * 1000 : NaN;
+  if (Number.isFinite(serverDelay) && serverDelay >= 0) return Math.min(policy.maxDelayMs, serverDelay);
+  const exponential = Math.min(policy.maxDelayMs, policy.baseDelayMs * 2 ** (attempt - 1));
+  return exponential / 2 + (random() * exponential) / 2;
+}
+
+interface Circuit {
+  state: CircuitState;
+  failures: number;
+  timer: ReturnType<typeof setTimeout> | null;
+  probe: (() => Promise<Response>) | null;
+  probing: boolean;
+}
+
+export class CircuitBreakers {
+  private circuits = new Map<string, Circuit>();
+  private listeners = new Set<() => void>();
+  private statuses: CircuitStatus[] = [];
+  private readonly failureThreshold: number;
+  private readonly cooldownMs: number;
+
+  constructor({ failureThreshold = 5, cooldownMs = 15_000 }: CircuitOptions = {}) {
+    this.failureThreshold = failureThreshold;
+    this.cooldownMs = cooldownMs;
+  }
+
+  subscribe = (listener: () => void): (() => void) => {
+    this.listeners.add(listener);
+    return () => this.listeners.delete(listener);
+  };
+
+  /** Groups whose circuit isn't closed; a stable array between changes. */
+  getStatuses = (): CircuitStatus[] => this.statuses;
+
+  state(group: string): CircuitState {
+    return this.circuits.get(group)?.state ?? "closed";
+  }
+
+  /** Throws CircuitOpenError when *group* is rejecting requests. */
+  beforeRequest(group: string) {
+    const circuit = this.circuits.get(group);
+    if (!circuit || circuit.state === "closed") return;
+    // Half-open lets exactly one request through as the probe
+    if (circuit.state === "open" || circuit.probing) throw new CircuitOpenError(group);
+    circuit.probing = true;
+  }
+
+  recordSuccess(group: string) {
+    const circuit = this.circuits.get(group);
+    if (!circuit) return;
+    const wasClosed = circuit.state === "closed";
+    if (circuit.timer) clearTimeout(circuit.timer);
+    this.circuits.delete(group);
+    if (!wasClosed) this.emit();
+  }
+
+  /** *replay* re-sends the failed request as the half-open probe; only reads may pass one. */
+  recordFailure(group: string, replay?: () => Promise<Response>) {
+    const circuit: Circuit = this.circuits.get(group) ?? {
+      state: "closed",
+      failures: 0,
+      timer: null,
+      probe: null,
+      probing: false,
+    };
+    this.circuits.set(group, circuit);
+    circuit.failures += 1;
+    if (replay) circuit.probe = replay;
+    if (circuit.state === "half-open" || circuit.failures >= this.failureThreshold) this.open(group, circuit);
+  }
+
+  /** A probe that was aborted tells us nothing; let the next request probe instead. */
+  releaseProbe(group: string) {
+    const circuit = this.circuits.get(group);
+    if (circuit?.state === "half-open") circuit.probing = false;
+  }
+
+  reset() {
+    this.circuits.forEach((circuit) => {
+      if (circuit.timer) clearTimeout(circuit.timer);
+    });
+    this.circuits.clear();
+    this.emit();
+  }
+
+  private open(group: string, circuit: Circuit) {
+    circuit.state = "open";
+    circuit.probing = false;
+    if (circuit.timer) clearTimeout(circuit.timer);
+    circuit.timer = setTimeout(() => this.halfOpen(group), this.cooldownMs);
+    this.emit();
+  }
+
+  private halfOpen(group: string) {
+    const circuit = this.circuits.get(group);
+    if (!circuit || circuit.state !== "open") return;
+    circuit.state = "half-open";
+    circuit.timer = null;
+    this.emit();
+
+    const probe = circuit.probe;
+    if (!probe) return; // The next real request is the probe
+    circuit.probing = true;
+    probe().then(
+      (response) => {
+        if (RETRYABLE_STATUSES.has(response.status)) {
+          this.recordFailure(group);
+        } else {
+          this.recordSuccess(group);
+        }
+      },
+      () => this.recordFailure(group)
+    );
+  }
+
+  private emit() {
+    this.statuses = [...this.circuits.entries()]
+      .filter(([, circuit]) => circuit.state !== "closed")
+      .map(([group, circuit]) => ({ group, state: circuit.state }));
+    this.listeners.forEach((listener) => listener());
+  }
+}
+
+export const apiCircuits = new CircuitBreakers();
+
+interface ResilientFetchOptions {
+  retry: boolean;
+  signal?: AbortSignal | null;
+  policy?: RetryPolicy;
+  circuits?: CircuitBreakers;
+  sleep?: (ms: number) => Promise<void>;
+}
+
+const defaultSleep = (ms: number) => new Promise<void>((resolve) => setTimeout(resolve, ms));
+
+/**
+ * Run *send* under *group*'s circuit, retrying transient failures when
+ * `retry` is set. Resolves with the last response (maybe still a 5xx) so
+ * the caller's normal error handling applies; network errors are rethrown.
+ */
+export async function resilientFetch(
+  group: string,
+  send: () => Promise<Response>,
+  { retry, signal, policy = DEFAULT_RETRY_POLICY, circuits = apiCircuits, sleep = defaultSleep }: ResilientFetchOptions
+): Promise<Response> {
+  circuits.beforeRequest(group);
+  const replay = retry ? send : undefined;
+
+  for (let attempt = 1; ; attempt += 1) {
+    let response: Response | null = null;
+    let failure: unknown = null;
+    try {
+      response = await send();
+    } catch (error) {
+      // The caller gave up; that says nothing about the backend
+      if (signal?.aborted) {
+        circuits.releaseProbe(group);
+        throw error;
+      }
+      failure = error;
+    }
+
+    if (response && !RETRYABLE_STATUSES.has(response.status)) {
+      circuits.recordSuccess(group);
+      return response;
+    }
+
+    circuits.recordFailure(group, replay);
+    const exhausted = !retry || attempt >= policy.maxAttempts || circuits.state(group) !== "closed";
+    if (exhausted) {
+      if (response) return response;
+      throw failure;
+    }
+    await sleep(backoffDelay(attempt, policy, response?.headers.get("Retry-After") ?? null));
+  }
+}
@@ -1,7 +1,19 @@
-import React, { createContext, useContext, useEffect, useState, type ReactNode } from 'react';
+import React, { createContext, useContext, useEffect, useState, useSyncExternalStore, type ReactNode } from 'react';
 import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { toast } from 'react-hot-toast';
 import config from './config';
+import {
+  clearSession,
+  getSession,
+  installActivityTracking,
+  refreshSession,
+  setSessionExpiry,
+  startSessionKeepAlive,
+  subscribeSession,
+} from './session';
+import { SessionExpiryWarning } from '../components/SessionExpiryWarning';
+import { fetchWorkspaceDefaults, type WorkspaceDefaults } from '../services/api';
+import { WORKSPACE_DEFAULTS_QUERY_KEY } from './preferences';
 
 // Types from our API
 interface User {
@@ -20,6 +32,8 @@ interface AuthContextType {
   user: User | null;
   isAuthenticated: boolean;
   isLoading: boolean;
+  // Admin-set prefs that apply where the user hasn't chosen (see lib/preferences)
+  workspaceDefaults?: WorkspaceDefaults | null;
   login: (idToken: string) => Promise<void>;
   logout: () => void;
   getToken: () => string | null;
@@ -111,11 +125,20 @@ export function AuthProvider({ children }: AuthProviderProps) {
     staleTime: 5 * 60 * 1000, // 5 minutes
   });
 
+  const { data: workspaceDefaults } = useQuery<WorkspaceDefaults>({
+    queryKey: WORKSPACE_DEFAULTS_QUERY_KEY,
+    queryFn: fetchWorkspaceDefaults,
+    enabled: isAuthenticated,
+    staleTime: 5 * 60 * 1000,
+  });
+
   const loginMutation = useMutation({
     mutationFn: loginWithGoogle,
-    onSuccess: () => {
-      // Cookie is set by server; refetch user data
+    onSuccess: (data) => {
+      setSessionExpiry(data.expires_in);
+      // Cookie is set by server; refetch user data, plus anything that failed while signed out
       refetch();
+      queryClient.invalidateQueries();
     },
     onError: (error: Error) => {
       toast.error(`Login failed: ${error.message}`);
@@ -134,12 +157,29 @@ export function AuthProvider({ children }: AuthProviderProps) {
     }
   }, [userData, error]);
 
+  // Keep the session alive while the user works; the expiry is only known after a login or refresh
+  useEffect(() => {
+    if (!isAuthenticated || !config.authEnabled) {
+      return;
+    }
+    if (getSession().expiresAt === null) {
+      void refreshSession();
+    }
+    const stopTracking = installActivityTracking();
+    const stopKeepAlive = startSessionKeepAlive();
+    return () => {
+      stopTracking();
+      stopKeepAlive();
+    };
+  }, [isAuthenticated]);
+
   const login = async (idToken: string) => {
     await loginMutation.mutateAsync(idToken);
   };
 
   const logout = async () => {
     await logoutFromServer(); // Clear server-side cookie
+    clearSession();
     setUser(null);
     setIsAuthenticated(false);
     queryClient.clear();
@@ -155,6 +195,7 @@ export function AuthProvider({ children }: AuthProviderProps) {
     user,
     isAuthenticated,
     isLoading,
+    workspaceDefaults: workspaceDefaults ?? null,
     login,
     logout,
     getToken,
@@ -190,6 +231,17 @@ declare global {
           initialize: (config: { client_id: string; callback: (response: { credential: string }) => void }) => void;
           renderButton: (element: HTMLElement, options: { theme: string; size: string }) => void;
         };
+        // Authorization-code flow, used to grant offline Gmail access (see lib/gmail)
+        oauth2?: {
+          initCodeClient: (config: {
+            client_id: string;
+            scope: string;
+            ux_mode: "popup";
+            prompt?: string;
+            callback: (response: { code?: string; error?: string; error_description?: string }) => void;
+            error_callback?: (error: { type: string; message?: string }) => void;
+          }) => { requestCode: () => void };
+        };
       };
     };
   }
@@ -269,11 +321,13 @@ async function loginWithDevAccount(): Promise<{ access_token: string; expires_in
 // Login overlay component
 interface LoginOverlayProps {
   clientId: string;
+  // The session lapsed mid-use: the page stays mounted underneath, so don't reload it
+  expired?: boolean;
 }
 
-export function LoginOverlay({ clientId }: LoginOverlayProps) {
+export function LoginOverlay({ clientId, expired = false }: LoginOverlayProps) {
   const [isDevLoginLoading, setIsDevLoginLoading] = useState(false);
-  const { login } = useAuth();
+  const queryClient = useQueryClient();
 
   const handleLoginSuccess = () => {
     // The AuthProvider will handle updating the authentication state
@@ -286,7 +340,12 @@ export function LoginOverlay({ clientId }: LoginOverlayProps) {
   const handleDevLogin = async () => {
     setIsDevLoginLoading(true);
     try {
-      await loginWithDevAccount();
+      const { expires_in } = await loginWithDevAccount();
+      if (expired) {
+        setSessionExpiry(expires_in);
+        queryClient.invalidateQueries();
+        return;
+      }
       // Cookie is set by server; reload to trigger auth state update
       window.location.reload();
     } catch (error) {
@@ -321,7 +380,14 @@ export function LoginOverlay({ clientId }: LoginOverlayProps) {
           textAlign: 'center',
         }}
       >
-        <h2 style={{ marginBottom: '1rem', color: '#333' }}>Sign in to Zerg</h2>
+        <h2 style={{ marginBottom: '1rem', color: '#333' }}>
+          {expired ? 'Your session expired' : 'Sign in to Zerg'}
+        </h2>
+        {expired && (
+          <p style={{ marginBottom: '1rem', color: '#666' }}>
+            Sign in again to pick up where you left off. Unsaved changes are kept.
+          </p>
+        )}
         <GoogleSignInButton
           clientId={clientId}
           onSuccess={handleLoginSuccess}
@@ -361,7 +427,8 @@ interface AuthGuardProps {
 }
 
 export function AuthGuard({ children, clientId }: AuthGuardProps) {
-  const { isAuthenticated, isLoading } = useAuth();
+  const { isAuthenticated, isLoading, logout } = useAuth();
+  const session = useSyncExternalStore(subscribeSession, getSession);
 
   // Skip auth guard if authentication is disabled (for demos/tests)
   if (!config.authEnabled) {
@@ -386,5 +453,17 @@ export function AuthGuard({ children, clientId }: AuthGuardProps) {
     return <LoginOverlay clientId={clientId} />;
   }
 
-  return <>{children}</>;
+  return (
+    <>
+      {children}
+      {session.expired ? (
+        <LoginOverlay clientId={clientId} expired />
+      ) : (
+        session.warning &&
+        session.expiresAt !== null && (
+          <SessionExpiryWarning expiresAt={session.expiresAt} onStay={refreshSession} onSignOut={logout} />
+        )
+      )}
+    </>
+  );
 }
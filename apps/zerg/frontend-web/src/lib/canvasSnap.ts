@@ -0,0 +1,178 @@
+/**
+ * Canvas layout geometry. Drop placement for shelf drags (grid snapping,
+ * alignment guides against nearby nodes and an edge suggestion when the
+ * drop lands next to an existing node's output handle), plus the
+ * align/distribute commands for the multi-selection. Everything is in flow
+ * coordinates.
+ */
+
+export interface Point {
+  x: number;
+  y: number;
+}
+
+export interface Box extends Point {
+  width: number;
+  height: number;
+}
+
+export interface PlacedBox extends Box {
+  id: string;
+}
+
+export interface GuideLine {
+  orientation: "vertical" | "horizontal";
+  // x for vertical guides, y for horizontal ones
+  position: number;
+  start: number;
+  end: number;
+}
+
+export interface DropPlacement {
+  position: Point;
+  guides: GuideLine[];
+  // Node whose output the dropped node should connect to, if any
+  suggestedSourceId: string | null;
+}
+
+// How close (flow px) an edge must be to another node's edge to align with it
+export const GUIDE_THRESHOLD = 8;
+// Input-to-output handle distance that still suggests an edge
+export const EDGE_SUGGESTION_RADIUS = 80;
+// Used until React Flow has measured a node
+export const DEFAULT_NODE_SIZE = { width: 160, height: 48 };
+
+export function snapToGrid(point: Point, grid: number): Point {
+  return { x: Math.round(point.x / grid) * grid, y: Math.round(point.y / grid) * grid };
+}
+
+type Axis = "x" | "y";
+
+function anchors(box: Box, axis: Axis): number[] {
+  const start = axis === "x" ? box.x : box.y;
+  const size = axis === "x" ? box.width : box.height;
+  return [start, start + size / 2, start + size];
+}
+
+function bestAlignment(box: Box, others: Box[], axis: Axis, threshold: number) {
+  let best: { delta: number; position: number; other: Box } | null = null;
+  const own = anchors(box, axis);
+  for (const other of others) {
+    for (const target of anchors(other, axis)) {
+      for (const anchor of own) {
+        const delta = target - anchor;
+        if (Math.abs(delta) <= threshold && (!best || Math.abs(delta) < Math.abs(best.delta))) {
+          best = { delta, position: target, other };
+        }
+      }
+    }
+  }
+  return best;
+}
+
+/** Nudge ``box`` onto the closest left/centre/right and top/middle/bottom lines of ``others``. */
+export function alignToNeighbours(
+  box: Box,
+  others: Box[],
+  threshold = GUIDE_THRESHOLD
+): { position: Point; guides: GuideLine[] } {
+  const guides: GuideLine[] = [];
+  const position = { x: box.x, y: box.y };
+
+  const alignX = bestAlignment(box, others, "x", threshold);
+  if (alignX) position.x += alignX.delta;
+  const alignY = bestAlignment({ ...box, ...position }, others, "y", threshold);
+  if (alignY) position.y += alignY.delta;
+
+  const placed = { ...box, ...position };
+  if (alignX) {
+    guides.push({
+      orientation: "vertical",
+      position: alignX.position,
+      start: Math.min(placed.y, alignX.other.y),
+      end: Math.max(placed.y + placed.height, alignX.other.y + alignX.other.height),
+    });
+  }
+  if (alignY) {
+    guides.push({
+      orientation: "horizontal",
+      position: alignY.position,
+      start: Math.min(placed.x, alignY.other.x),
+      end: Math.max(placed.x + placed.width, alignY.other.x + alignY.other.width),
+    });
+  }
+  return { position, guides };
+}
+
+export function inputHandle(box: Box): Point {
+  return { x: box.x, y: box.y + box.height / 2 };
+}
+
+export function outputHandle(box: Box): Point {
+  return { x: box.x + box.width, y: box.y + box.height / 2 };
+}
+
+/** Nearest node whose output handle sits within ``radius`` of the box's input handle. */
+export function suggestEdgeSource(box: Box, others: PlacedBox[], radius = EDGE_SUGGESTION_RADIUS): string | null {
+  const input = inputHandle(box);
+  let best: { id: string; distance: number } | null = null;
+  for (const other of others) {
+    const output = outputHandle(other);
+    const distance = Math.hypot(output.x - input.x, output.y - input.y);
+    if (distance <= radius && (!best || distance < best.distance)) {
+      best = { id: other.id, distance };
+    }
+  }
+  return best?.id ?? null;
+}
+
+export function resolveDropPlacement(
+  position: Point,
+  size: { width: number; height: number },
+  others: PlacedBox[],
+  options: { grid: number | null }
+): DropPlacement {
+  const start = options.grid ? snapToGrid(position, options.grid) : position;
+  // Alignment wins over the grid: lining up with a neighbour is what the user is aiming for
+  const aligned = alignToNeighbours({ ...start, ...size }, others);
+  const box = { ...aligned.position, ...size };
+  return {
+    position: aligned.position,
+    guides: aligned.guides,
+    suggestedSourceId: suggestEdgeSource(box, others),
+  };
+}
+
+export type AlignMode = "left" | "right" | "top";
+
+/** New positions lining the boxes up on the outermost edge for ``mode``. */
+export function alignBoxes(boxes: PlacedBox[], mode: AlignMode): Map<string, Point> {
+  const positions = new Map<string, Point>();
+  if (boxes.length < 2) return positions;
+  const left = Math.min(...boxes.map((box) => box.x));
+  const right = Math.max(...boxes.map((box) => box.x + box.width));
+  const top = Math.min(...boxes.map((box) => box.y));
+  for (const box of boxes) {
+    if (mode === "left") positions.set(box.id, { x: left, y: box.y });
+    else if (mode === "right") positions.set(box.id, { x: right - box.width, y: box.y });
+    else positions.set(box.id, { x: box.x, y: top });
+  }
+  return positions;
+}
+
+/** Equal horizontal gaps between boxes; the leftmost and rightmost stay put. */
+export function distributeHorizontally(boxes: PlacedBox[]): Map<string, Point> {
+  const positions = new Map<string, Point>();
+  if (boxes.length < 3) return positions;
+  const sorted = [...boxes].sort((a, b) => a.x - b.x);
+  const first = sorted[0];
+  const last = sorted[sorted.length - 1];
+  const occupied = sorted.reduce((total, box) => total + box.width, 0);
+  const gap = (last.x + last.width - first.x - occupied) / (sorted.length - 1);
+  let cursor = first.x;
+  for (const box of sorted) {
+    positions.set(box.id, { x: cursor, y: box.y });
+    cursor += box.width + gap;
+  }
+  return positions;
+}
@@ -0,0 +1,50 @@
+/**
+ * What the signed-in user may do, loaded once from
+ * `/users/me/capabilities`. Components gate visibility and disabled states
+ * with `can(Capability.X)` instead of checking roles or reacting to 403s. The
+ * server still enforces every rule; this only keeps the UI from offering
+ * actions that would be refused.
+ */
+
+import { useMemo } from "react";
+import { useQuery } from "@tanstack/react-query";
+import { fetchCapabilities } from "../services/api";
+
+// Mirrors zerg.auth.capabilities.Capability
+export const Capability = {
+  ViewAllAgents: "view_all_agents",
+  ManageAnyAgent: "manage_any_agent",
+  ViewOps: "view_ops",
+  ManageMaintenance: "manage_maintenance",
+  ManageWorkspaceDefaults: "manage_workspace_defaults",
+  ResetDatabase: "reset_database",
+  UseAnyModel: "use_any_model",
+} as const;
+
+export type Capability = (typeof Capability)[keyof typeof Capability];
+
+export const CAPABILITIES_QUERY_KEY = ["capabilities"] as const;
+
+const NONE: ReadonlySet<string> = new Set();
+
+export interface Capabilities {
+  can: (capability: Capability) => boolean;
+  // False until the set has loaded; `can` denies everything before then
+  isLoaded: boolean;
+}
+
+export function capabilityChecker(granted: Iterable<string> | null | undefined): Capabilities["can"] {
+  const set = granted ? new Set(granted) : NONE;
+  return (capability) => set.has(capability);
+}
+
+export function useCapabilities(): Capabilities {
+  const { data, isSuccess } = useQuery({
+    queryKey: CAPABILITIES_QUERY_KEY,
+    queryFn: fetchCapabilities,
+    staleTime: Infinity,
+  });
+  const granted = data?.capabilities;
+  const can = useMemo(() => capabilityChecker(granted), [granted]);
+  return { can, isLoaded: isSuccess };
+}
@@ -0,0 +1,127 @@
+/**
+ * Geometry for the SVG time-series charts (components/TimeSeriesChart):
+ * nice axis ticks, stacking, hover hit-testing and run bucketing.
+ *
+ * Like the sparklines, ``null`` values break a line instead of being drawn
+ * as zero; stacked series treat them as zero so the layers stay contiguous.
+ */
+
+export type ChartTone = "primary" | "success" | "error" | "warning" | "muted";
+
+export interface ChartSeries {
+  key: string;
+  label: string;
+  values: (number | null)[];
+  tone: ChartTone;
+}
+
+export interface ChartBox {
+  width: number;
+  height: number;
+  top: number;
+  right: number;
+  bottom: number;
+  left: number;
+}
+
+/** Evenly spaced ticks from 0 to a round number covering ``max``. */
+export function niceTicks(max: number, count = 4): number[] {
+  if (!Number.isFinite(max) || max <= 0) return [0, 1];
+  const raw = max / count;
+  const magnitude = 10 ** Math.floor(Math.log10(raw));
+  const residual = raw / magnitude;
+  const nice = residual <= 1 ? 1 : residual <= 2 ? 2 : residual <= 5 ? 5 : 10;
+  const step = nice * magnitude;
+  const ticks: number[] = [];
+  for (let tick = 0; tick < max + step / 2; tick += step) {
+    ticks.push(Number(tick.toPrecision(12)));
+  }
+  // max itself may sit exactly on a tick; make sure the top one covers it
+  if (ticks[ticks.length - 1] < max) ticks.push(Number((ticks[ticks.length - 1] + step).toPrecision(12)));
+  return ticks;
+}
+
+export interface StackedLayer {
+  lower: number[];
+  upper: number[];
+}
+
+/** Cumulative layers, bottom series first. */
+export function stackSeries(series: (number | null)[][]): StackedLayer[] {
+  const length = Math.max(0, ...series.map((values) => values.length));
+  let base = new Array<number>(length).fill(0);
+  return series.map((values) => {
+    const upper = base.map((lower, index) => lower + (values[index] ?? 0));
+    const layer = { lower: base, upper };
+    base = upper;
+    return layer;
+  });
+}
+
+export function seriesMax(series: ChartSeries[], stacked: boolean): number {
+  if (stacked) {
+    const layers = stackSeries(series.map((entry) => entry.values));
+    return Math.max(0, ...(layers[layers.length - 1]?.upper ?? []));
+  }
+  return Math.max(0, ...series.flatMap((entry) => entry.values.filter((value): value is number => value !== null)));
+}
+
+export function xPosition(box: ChartBox, index: number, count: number): number {
+  const plotWidth = box.width - box.left - box.right;
+  if (count <= 1) return box.left + plotWidth / 2;
+  return box.left + (index / (count - 1)) * plotWidth;
+}
+
+export function yPosition(box: ChartBox, value: number, max: number): number {
+  const plotHeight = box.height - box.top - box.bottom;
+  return box.top + plotHeight - (max > 0 ? (value / max) * plotHeight : 0);
+}
+
+/** Index of the point closest to an x coordinate inside the plot. */
+export function nearestIndex(box: ChartBox, x: number, count: number): number {
+  if (count <= 1) return 0;
+  const plotWidth = box.width - box.left - box.right;
+  const ratio = (x - box.left) / plotWidth;
+  return Math.min(count - 1, Math.max(0, Math.round(ratio * (count - 1))));
+}
+
+/** Show every n-th x label so at most ``maxLabels`` are drawn. */
+export function labelStride(count: number, maxLabels: number): number {
+  return Math.max(1, Math.ceil(count / Math.max(1, maxLabels)));
+}
+
+export interface RunBuckets {
+  labels: string[];
+  succeeded: number[];
+  failed: number[];
+}
+
+function dayKey(date: Date, timeZone: string): string {
+  // en-CA formats as YYYY-MM-DD
+  return new Intl.DateTimeFormat("en-CA", { timeZone, year: "numeric", month: "2-digit", day: "2-digit" }).format(
+    date
+  );
+}
+
+/** Daily counts of finished runs for the last ``days`` days, oldest first. */
+export function bucketRunsByDay(
+  runs: { started_at?: string | null; status: string }[],
+  days: number,
+  now: Date,
+  timeZone = "UTC"
+): RunBuckets {
+  const labels: string[] = [];
+  for (let offset = days - 1; offset >= 0; offset -= 1) {
+    labels.push(dayKey(new Date(now.getTime() - offset * 24 * 60 * 60 * 1000), timeZone));
+  }
+  const succeeded = new Array<number>(days).fill(0);
+  const failed = new Array<number>(days).fill(0);
+  for (const run of runs) {
+    if (!run.started_at || (run.status !== "success" && run.status !== "failed")) continue;
+    const index = labels.indexOf(dayKey(new Date(run.started_at), timeZone));
+    if (index === -1) continue;
+    if (run.status === "success") succeeded[index] += 1;
+    else failed[index] += 1;
+  }
+  return { labels, succeeded, failed };
+}
@@ -0,0 +1,121 @@
+/**
+ * Control-flow nodes for the canvas: If/Else ("conditional"), Switch and a
+ * bounded Loop. Each one routes along the outgoing edges of the branch it
+ * picks at run time; an edge's branch is its source handle on the canvas and
+ * ``config.branch`` in the saved workflow (see services/workflow_engine.py).
+ */
+
+export type ControlNodeType = "conditional" | "switch" | "loop";
+
+export type NodeConfig = Record<string, unknown>;
+
+export interface ControlPaletteItem {
+  type: ControlNodeType;
+  name: string;
+  icon: string;
+}
+
+export const CONTROL_ITEMS: ControlPaletteItem[] = [
+  { type: "conditional", name: "If / Else", icon: "⑂" },
+  { type: "switch", name: "Switch", icon: "☰" },
+  { type: "loop", name: "Loop / For each", icon: "↻" },
+];
+
+// Mirror DEFAULT_LOOP_ITERATIONS / MAX_LOOP_ITERATIONS in services/node_executors.py
+export const DEFAULT_LOOP_ITERATIONS = 10;
+export const MAX_LOOP_ITERATIONS = 100;
+
+// Branch an edge without ``config.branch`` belongs to; mirrors DEFAULT_BRANCHES in the engine
+const DEFAULT_BRANCHES: Record<ControlNodeType, string> = {
+  conditional: "true",
+  switch: "default",
+  loop: "done",
+};
+
+export interface BranchHandle {
+  id: string;
+  label: string;
+}
+
+export function isControlNodeType(type: unknown): type is ControlNodeType {
+  return typeof type === "string" && type in DEFAULT_BRANCHES;
+}
+
+export function defaultControlConfig(type: ControlNodeType): NodeConfig {
+  switch (type) {
+    case "conditional":
+      return { condition: "", condition_type: "expression" };
+    case "switch":
+      return { expression: "", cases: [] };
+    case "loop":
+      return { items: "", max_iterations: DEFAULT_LOOP_ITERATIONS };
+  }
+}
+
+export function switchCases(config: NodeConfig | undefined): string[] {
+  const cases = config?.cases;
+  return Array.isArray(cases) ? cases.map(String) : [];
+}
+
+/** One text line per case; blanks and duplicates dropped. "default" is reserved for the fallback branch. */
+export function parseSwitchCases(text: string): string[] {
+  const cases = text
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line && line !== "default");
+  return Array.from(new Set(cases));
+}
+
+/** Source handles of a node, top to bottom; a single unnamed handle for everything else. */
+export function branchHandles(type: string | undefined, config: NodeConfig | undefined): BranchHandle[] {
+  switch (type) {
+    case "conditional":
+      return [
+        { id: "true", label: "True" },
+        { id: "false", label: "False" },
+      ];
+    case "switch":
+      return [
+        ...switchCases(config).map((value) => ({ id: value, label: value })),
+        { id: "default", label: "Default" },
+      ];
+    case "loop":
+      return [
+        { id: "body", label: "Each" },
+        { id: "done", label: "Done" },
+      ];
+    default:
+      return [];
+  }
+}
+
+/** Branch of a saved edge leaving a node of *sourceType*; null for ordinary nodes. */
+export function edgeBranch(sourceType: string | undefined, edgeConfig: NodeConfig | undefined): string | null {
+  if (!isControlNodeType(sourceType)) return null;
+  const branch = edgeConfig?.branch;
+  return typeof branch === "string" && branch ? branch : DEFAULT_BRANCHES[sourceType];
+}
+
+export function branchLabel(type: string | undefined, config: NodeConfig | undefined, branch: string): string {
+  return branchHandles(type, config).find((handle) => handle.id === branch)?.label ?? branch;
+}
+
+export function loopMaxIterations(config: NodeConfig | undefined): number {
+  const requested = Number(config?.max_iterations);
+  if (!Number.isFinite(requested) || requested < 1) return DEFAULT_LOOP_ITERATIONS;
+  return Math.min(Math.floor(requested), MAX_LOOP_ITERATIONS);
+}
+
+/** One-line summary shown on the node under its label. */
+export function controlNodeSummary(type: ControlNodeType, config: NodeConfig | undefined): string {
+  switch (type) {
+    case "conditional":
+      return typeof config?.condition === "string" && config.condition ? config.condition : "No condition";
+    case "switch":
+      return typeof config?.expression === "string" && config.expression ? config.expression : "No expression";
+    case "loop": {
+      const bound = `max ${loopMaxIterations(config)}`;
+      return typeof config?.items === "string" && config.items ? `${config.items} (${bound})` : `Repeat, ${bound}`;
+    }
+  }
+}
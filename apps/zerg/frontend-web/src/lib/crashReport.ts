@@ -0,0 +1,188 @@
+/**
+ * Crash handling: records recent errors, holds the current crash for the
+ * overlay, builds the copyable diagnostic report and carries unsaved page
+ * state (e.g. the canvas) across a soft reload via sessionStorage.
+ */
+
+import { getLastTraceId } from "./traceId";
+import { wsFrameLog, type WsFrame } from "./wsFrameLog";
+
+export type CrashSource = "react" | "window" | "rejection";
+
+export interface Crash {
+  message: string;
+  stack?: string;
+  componentStack?: string;
+  source: CrashSource;
+  ts: number;
+}
+
+export interface RecentError {
+  ts: number;
+  source: CrashSource;
+  message: string;
+}
+
+export const RECOVERY_STORAGE_KEY = "zerg:crash-recovery";
+const MAX_RECENT_ERRORS = 10;
+const REPORT_FRAME_COUNT = 20;
+
+let currentCrash: Crash | null = null;
+let recentErrors: RecentError[] = [];
+const listeners = new Set<() => void>();
+const recoverable = new Map<string, unknown>();
+// Errors an ErrorBoundary already shows a fallback for
+const handledErrors = new WeakSet<object>();
+
+function emit() {
+  listeners.forEach((listener) => listener());
+}
+
+export function subscribeCrash(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => listeners.delete(listener);
+}
+
+export function getCrash(): Crash | null {
+  return currentCrash;
+}
+
+export function getRecentErrors(): RecentError[] {
+  return recentErrors;
+}
+
+/** Remember an error for the next report without interrupting the user. */
+export function noteError(source: CrashSource, message: string) {
+  recentErrors = [...recentErrors, { ts: Date.now(), source, message }].slice(-MAX_RECENT_ERRORS);
+}
+
+/** Called by ErrorBoundary so the same error doesn't also open the overlay. */
+export function markErrorHandled(error: unknown) {
+  if (error && typeof error === "object") handledErrors.add(error);
+}
+
+/** Record a crash and show the overlay. The first crash wins until it is dismissed. */
+export function reportCrash(source: CrashSource, error: unknown, componentStack?: string) {
+  const err = error instanceof Error ? error : new Error(String(error));
+  noteError(source, err.message);
+  if (currentCrash) return;
+  currentCrash = { message: err.message, stack: err.stack, componentStack, source, ts: Date.now() };
+  emit();
+}
+
+export function dismissCrash() {
+  currentCrash = null;
+  emit();
+}
+
+/** Uncaught exceptions open the overlay; unhandled rejections are only noted. */
+export function installCrashHandlers(target: Window = window): () => void {
+  const onError = (event: ErrorEvent) => {
+    // Browser noise that doesn't mean the app is broken
+    if (!event.error || event.message?.includes("ResizeObserver loop")) return;
+    const { error } = event;
+    // React dev builds rethrow boundary-caught render errors; let componentDidCatch claim them first
+    setTimeout(() => {
+      if (!(typeof error === "object" && handledErrors.has(error))) reportCrash("window", error);
+    }, 0);
+  };
+  const onRejection = (event: PromiseRejectionEvent) => {
+    noteError("rejection", event.reason?.message ?? String(event.reason));
+  };
+  target.addEventListener("error", onError);
+  target.addEventListener("unhandledrejection", onRejection);
+  return () => {
+    target.removeEventListener("error", onError);
+    target.removeEventListener("unhandledrejection", onRejection);
+  };
+}
+
+/** Keep the latest copy of state a soft reload should bring back; `null` forgets it. */
+export function setRecoverableState(key: string, value: unknown) {
+  if (value === null || value === undefined) {
+    recoverable.delete(key);
+  } else {
+    recoverable.set(key, value);
+  }
+}
+
+/** Read (and forget) state saved by the last soft reload. */
+export function takeRecoveredState<T>(key: string, storage: Storage = window.sessionStorage): T | null {
+  try {
+    const stored = JSON.parse(storage.getItem(RECOVERY_STORAGE_KEY) ?? "{}") as Record<string, T>;
+    if (!(key in stored)) return null;
+    const { [key]: value, ...rest } = stored;
+    if (Object.keys(rest).length) {
+      storage.setItem(RECOVERY_STORAGE_KEY, JSON.stringify(rest));
+    } else {
+      storage.removeItem(RECOVERY_STORAGE_KEY);
+    }
+    return value;
+  } catch {
+    return null;
+  }
+}
+
+export function persistRecoverableState(storage: Storage = window.sessionStorage) {
+  if (recoverable.size === 0) return;
+  try {
+    storage.setItem(RECOVERY_STORAGE_KEY, JSON.stringify(Object.fromEntries(recoverable)));
+  } catch {
+    // Storage full or disabled; reload anyway
+  }
+}
+
+/** Reload the page, keeping unsaved state for pages to pick back up. */
+export function softReload() {
+  persistRecoverableState();
+  window.location.reload();
+}
+
+interface ReportContext {
+  frames?: WsFrame[];
+  errors?: RecentError[];
+  recoverableKeys?: string[];
+  now?: Date;
+}
+
+function frameLine(frame: WsFrame): string {
+  const arrow = frame.direction === "in" ? "<-" : "->";
+  const topic = frame.topic ? ` [${frame.topic}]` : "";
+  return `  ${new Date(frame.ts).toISOString()} ${arrow} ${frame.type}${topic} ${frame.size}B`;
+}
+
+/** Plain-text report for bug tickets: environment, the error and what led up to it. */
+export function buildDiagnosticReport(crash: Crash | null, context: ReportContext = {}): string {
+  const frames = (context.frames ?? wsFrameLog.snapshot()).slice(-REPORT_FRAME_COUNT);
+  const errors = context.errors ?? recentErrors;
+  const keys = context.recoverableKeys ?? [...recoverable.keys()];
+  const lines = [
+    "Zerg diagnostic report",
+    `Generated: ${(context.now ?? new Date()).toISOString()}`,
+    `URL: ${window.location.href}`,
+    `User agent: ${navigator.userAgent}`,
+    `Viewport: ${window.innerWidth}x${window.innerHeight}`,
+    `Online: ${navigator.onLine ? "yes" : "no"}`,
+    `Last trace ID: ${getLastTraceId() ?? "none"}`,
+    `Unsaved state: ${keys.length ? keys.join(", ") : "none"}`,
+    "",
+  ];
+
+  if (crash) {
+    lines.push(`Error (${crash.source}): ${crash.message}`);
+    if (crash.stack) lines.push(crash.stack);
+    if (crash.componentStack) lines.push("Component stack:", crash.componentStack.trim());
+    lines.push("");
+  }
+
+  lines.push(`Recent errors (${errors.length}):`);
+  errors.forEach((entry) => lines.push(`  ${new Date(entry.ts).toISOString()} ${entry.source}: ${entry.message}`));
+  lines.push("");
+
+  if (frames.length) {
+    lines.push(`Last WebSocket frames (${frames.length}):`, ...frames.map(frameLine));
+  } else {
+    lines.push("Last WebSocket frames: none recorded (enable with localStorage zerg:debug:ws=1)");
+  }
+  return lines.join("\n");
+}
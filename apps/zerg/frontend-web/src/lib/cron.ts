@@ -0,0 +1,309 @@
+/**
+ * Client-side crontab helpers for the agent schedule builder.
+ *
+ * Mirrors APScheduler's ``CronTrigger.from_crontab`` (which the backend uses)
+ * closely enough to validate input, describe it in words and preview the next
+ * run times without a round trip. Two APScheduler quirks matter here:
+ *
+ * - numeric weekdays count from 0 = Monday (not Sunday as in classic cron),
+ *   so the builder always writes day names;
+ * - when both day-of-month and weekday are restricted, *both* must match.
+ *
+ * Times are evaluated in UTC, the scheduler's timezone.
+ */
+
+export type CronPreset = "hourly" | "daily" | "weekdays" | "weekly";
+
+export interface CronPresetOptions {
+  minute: number;
+  hour: number;
+  // 0 = Monday … 6 = Sunday
+  weekday: number;
+}
+
+export interface CronSchedule {
+  raw: string[];
+  minutes: number[];
+  hours: number[];
+  days: number[];
+  months: number[];
+  weekdays: number[];
+  restricted: { days: boolean; months: boolean; weekdays: boolean };
+}
+
+export type CronParseResult =
+  | { ok: true; schedule: CronSchedule; warnings: string[] }
+  | { ok: false; error: string };
+
+export const WEEKDAY_NAMES = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+const WEEKDAY_KEYS = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+const MONTH_NAMES = [
+  "January",
+  "February",
+  "March",
+  "April",
+  "May",
+  "June",
+  "July",
+  "August",
+  "September",
+  "October",
+  "November",
+  "December",
+];
+const MONTH_KEYS = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+interface FieldSpec {
+  label: string;
+  min: number;
+  max: number;
+  names?: string[];
+}
+
+const FIELDS: FieldSpec[] = [
+  { label: "Minute", min: 0, max: 59 },
+  { label: "Hour", min: 0, max: 23 },
+  { label: "Day of month", min: 1, max: 31 },
+  { label: "Month", min: 1, max: 12, names: MONTH_KEYS },
+  { label: "Weekday", min: 0, max: 6, names: WEEKDAY_KEYS },
+];
+
+// Search horizon for next runs; four years covers Feb 29 schedules
+const MAX_SEARCH_DAYS = 366 * 4;
+
+function parseValue(token: string, spec: FieldSpec): number | string {
+  if (/^\d+$/.test(token)) {
+    const value = Number(token);
+    if (value < spec.min || value > spec.max) {
+      return `${spec.label} must be between ${spec.min} and ${spec.max}`;
+    }
+    return value;
+  }
+  const index = spec.names?.indexOf(token.toLowerCase()) ?? -1;
+  if (index === -1) {
+    return `${spec.label}: unsupported value "${token}"`;
+  }
+  return spec.min + index;
+}
+
+function parseField(raw: string, spec: FieldSpec): number[] | string {
+  const values = new Set<number>();
+  for (const part of raw.split(",")) {
+    const [range, stepText, extra] = part.split("/");
+    if (extra !== undefined || !range) {
+      return `${spec.label}: invalid expression "${part}"`;
+    }
+
+    let step = 1;
+    if (stepText !== undefined) {
+      if (!/^\d+$/.test(stepText) || Number(stepText) === 0) {
+        return `${spec.label}: step must be a positive number`;
+      }
+      step = Number(stepText);
+    }
+
+    let low: number;
+    let high: number;
+    if (range === "*") {
+      low = spec.min;
+      high = spec.max;
+    } else if (range.includes("-")) {
+      const [start, end] = range.split("-");
+      const first = parseValue(start, spec);
+      const last = parseValue(end, spec);
+      if (typeof first === "string") return first;
+      if (typeof last === "string") return last;
+      if (first > last) {
+        return `${spec.label}: range "${range}" runs backwards`;
+      }
+      low = first;
+      high = last;
+    } else {
+      const value = parseValue(range, spec);
+      if (typeof value === "string") return value;
+      low = value;
+      high = stepText !== undefined ? spec.max : value;
+    }
+
+    for (let value = low; value <= high; value += step) {
+      values.add(value);
+    }
+  }
+  return [...values].sort((a, b) => a - b);
+}
+
+export function parseCron(expression: string): CronParseResult {
+  const raw = expression.trim().split(/\s+/).filter(Boolean);
+  if (raw.length !== 5) {
+    return { ok: false, error: "Expected 5 fields: minute hour day-of-month month weekday" };
+  }
+
+  const parsed: number[][] = [];
+  for (let index = 0; index < FIELDS.length; index++) {
+    const result = parseField(raw[index], FIELDS[index]);
+    if (typeof result === "string") {
+      return { ok: false, error: result };
+    }
+    parsed.push(result);
+  }
+
+  const warnings: string[] = [];
+  if (/\d/.test(raw[4])) {
+    warnings.push("Numeric weekdays count from 0 = Monday here; day names such as mon-fri are clearer.");
+  }
+
+  const [minutes, hours, days, months, weekdays] = parsed;
+  return {
+    ok: true,
+    warnings,
+    schedule: {
+      raw,
+      minutes,
+      hours,
+      days,
+      months,
+      weekdays,
+      restricted: { days: raw[2] !== "*", months: raw[3] !== "*", weekdays: raw[4] !== "*" },
+    },
+  };
+}
+
+// ---------------------------------------------------------------------------
+// Next run times
+// ---------------------------------------------------------------------------
+
+export function nextRunTimes(expression: string, from: Date = new Date(), count = 5): Date[] {
+  const parsed = parseCron(expression);
+  if (!parsed.ok) return [];
+  const { schedule } = parsed;
+
+  // Runs fire on whole minutes strictly after *from*
+  const start = Math.floor(from.getTime() / 60_000) * 60_000 + 60_000;
+  const day = new Date(start);
+  day.setUTCHours(0, 0, 0, 0);
+
+  const runs: Date[] = [];
+  for (let offset = 0; offset < MAX_SEARCH_DAYS && runs.length < count; offset++) {
+    const weekday = (day.getUTCDay() + 6) % 7;
+    if (
+      schedule.months.includes(day.getUTCMonth() + 1) &&
+      schedule.days.includes(day.getUTCDate()) &&
+      schedule.weekdays.includes(weekday)
+    ) {
+      for (const hour of schedule.hours) {
+        for (const minute of schedule.minutes) {
+          const time = Date.UTC(day.getUTCFullYear(), day.getUTCMonth(), day.getUTCDate(), hour, minute);
+          if (time >= start) {
+            runs.push(new Date(time));
+            if (runs.length === count) return runs;
+          }
+        }
+      }
+    }
+    day.setUTCDate(day.getUTCDate() + 1);
+  }
+  return runs;
+}
+
+// ---------------------------------------------------------------------------
+// Human-readable description
+// ---------------------------------------------------------------------------
+
+function pad(value: number) {
+  return String(value).padStart(2, "0");
+}
+
+function joinWords(items: string[]) {
+  if (items.length <= 1) return items.join("");
+  return `${items.slice(0, -1).join(", ")} and ${items[items.length - 1]}`;
+}
+
+function stepOf(raw: string): number | null {
+  const match = /^\*\/(\d+)$/.exec(raw);
+  return match ? Number(match[1]) : null;
+}
+
+function describeTime(schedule: CronSchedule): string {
+  const [rawMinute, rawHour] = schedule.raw;
+  const { minutes, hours } = schedule;
+  const everyHour = rawHour === "*";
+
+  if (rawMinute === "*" && everyHour) return "Every minute";
+  const minuteStep = stepOf(rawMinute);
+  if (minuteStep && everyHour) return `Every ${minuteStep} minutes`;
+
+  if (minutes.length === 1) {
+    const minute = minutes[0];
+    if (everyHour) return minute === 0 ? "Every hour" : `Every hour at ${pad(minute)} minutes past`;
+    const hourStep = stepOf(rawHour);
+    if (hourStep) return `Every ${hourStep} hours at ${pad(minute)} minutes past`;
+    if (hours.length <= 6) return `At ${joinWords(hours.map((hour) => `${pad(hour)}:${pad(minute)}`))}`;
+  }
+
+  const minuteText = minuteStep ? `every ${minuteStep} minutes` : `minute ${minutes.join(", ")}`;
+  return everyHour ? `At ${minuteText} of every hour` : `At ${minuteText} past hour ${hours.join(", ")}`;
+}
+
+function describeWeekdays(weekdays: number[]): string {
+  if (weekdays.join() === "0,1,2,3,4") return "on weekdays";
+  if (weekdays.join() === "5,6") return "on weekends";
+  return `on ${joinWords(weekdays.map((day) => WEEKDAY_NAMES[day]))}`;
+}
+
+export function describeCron(expression: string): string | null {
+  const parsed = parseCron(expression);
+  if (!parsed.ok) return null;
+  const { schedule } = parsed;
+
+  const parts = [describeTime(schedule)];
+  if (schedule.restricted.days) {
+    parts.push(`on day ${joinWords(schedule.days.map(String))} of the month`);
+  }
+  if (schedule.restricted.weekdays) {
+    const weekdays = describeWeekdays(schedule.weekdays);
+    // With a day-of-month restriction both must match (APScheduler semantics)
+    parts.push(schedule.restricted.days ? `when it falls ${weekdays}` : weekdays);
+  }
+  if (schedule.restricted.months) {
+    parts.push(`in ${joinWords(schedule.months.map((month) => MONTH_NAMES[month - 1]))}`);
+  }
+  return parts.join(" ");
+}
+
+// ---------------------------------------------------------------------------
+// Presets
+// ---------------------------------------------------------------------------
+
+export function buildCronExpression(preset: CronPreset, options: CronPresetOptions): string {
+  const { minute, hour, weekday } = options;
+  switch (preset) {
+    case "hourly":
+      return `${minute} * * * *`;
+    case "daily":
+      return `${minute} ${hour} * * *`;
+    case "weekdays":
+      return `${minute} ${hour} * * mon-fri`;
+    case "weekly":
+      return `${minute} ${hour} * * ${WEEKDAY_KEYS[weekday]}`;
+  }
+}
+
+/** The preset (and its options) that produces *expression*, if any. */
+export function detectCronPreset(expression: string): ({ preset: CronPreset } & CronPresetOptions) | null {
+  const fields = expression.trim().toLowerCase().split(/\s+/);
+  if (fields.length !== 5 || fields[2] !== "*" || fields[3] !== "*") return null;
+  const [minuteText, hourText, , , weekdayText] = fields;
+  if (!/^\d+$/.test(minuteText) || Number(minuteText) > 59) return null;
+  const minute = Number(minuteText);
+
+  if (hourText === "*" && weekdayText === "*") {
+    return { preset: "hourly", minute, hour: 9, weekday: 0 };
+  }
+  if (!/^\d+$/.test(hourText) || Number(hourText) > 23) return null;
+  const hour = Number(hourText);
+
+  if (weekdayText === "*") return { preset: "daily", minute, hour, weekday: 0 };
+  if (weekdayText === "mon-fri") return { preset: "weekdays", minute, hour, weekday: 0 };
+  const weekday = WEEKDAY_KEYS.indexOf(weekdayText);
+  return weekday === -1 ? null : { preset: "weekly", minute, hour, weekday };
+}
@@ -0,0 +1,145 @@
+/**
+ * Timestamp formatting honouring the user's timezone preference.
+ *
+ * The backend stores naive UTC timestamps, so ISO strings without an offset
+ * are read as UTC rather than browser-local time. Users pick a timezone
+ * (or follow the browser) and an absolute or relative ("3 min ago") display
+ * mode in their profile; both live in ``user.prefs``. Whatever the mode,
+ * the UTC time is available as a hover tooltip.
+ */
+
+export type TimeDisplayMode = "absolute" | "relative";
+
+export interface TimePreferences {
+  timeZone: string;
+  mode: TimeDisplayMode;
+}
+
+// Keys in user.prefs
+export const TIMEZONE_PREF = "timezone";
+export const TIME_DISPLAY_PREF = "time_display";
+
+// Stored preference meaning "use the browser's timezone"
+export const AUTO_TIMEZONE = "auto";
+
+const NBSP = "\u00A0";
+const HAS_OFFSET = /(Z|[+-]\d{2}:?\d{2})$/i;
+
+export function browserTimeZone(): string {
+  try {
+    return Intl.DateTimeFormat().resolvedOptions().timeZone || "UTC";
+  } catch {
+    return "UTC";
+  }
+}
+
+export function isValidTimeZone(timeZone: string): boolean {
+  try {
+    new Intl.DateTimeFormat("en-US", { timeZone });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+export function listTimeZones(): string[] {
+  const intl = Intl as typeof Intl & { supportedValuesOf?: (key: string) => string[] };
+  const zones = intl.supportedValuesOf?.("timeZone") ?? [];
+  return zones.includes("UTC") ? zones : ["UTC", ...zones];
+}
+
+export function timePreferencesFromPrefs(prefs: Record<string, unknown> | null | undefined): TimePreferences {
+  const storedZone = prefs?.[TIMEZONE_PREF];
+  const timeZone =
+    typeof storedZone === "string" && storedZone !== AUTO_TIMEZONE && isValidTimeZone(storedZone)
+      ? storedZone
+      : browserTimeZone();
+  const mode = prefs?.[TIME_DISPLAY_PREF] === "relative" ? "relative" : "absolute";
+  return { timeZone, mode };
+}
+
+export function parseTimestamp(value: string | Date | null | undefined): Date | null {
+  if (!value) return null;
+  if (value instanceof Date) return Number.isNaN(value.getTime()) ? null : value;
+  const normalized = value.includes("T") && !HAS_OFFSET.test(value) ? `${value}Z` : value;
+  const date = new Date(normalized);
+  return Number.isNaN(date.getTime()) ? null : date;
+}
+
+/** Sort helper: missing timestamps order before any real one. */
+export function compareTimestamps(left: string | null | undefined, right: string | null | undefined): number {
+  const a = parseTimestamp(left)?.getTime() ?? Number.NEGATIVE_INFINITY;
+  const b = parseTimestamp(right)?.getTime() ?? Number.NEGATIVE_INFINITY;
+  return a === b ? 0 : a < b ? -1 : 1;
+}
+
+const partsFormatters = new Map<string, Intl.DateTimeFormat>();
+
+function dateParts(date: Date, timeZone: string) {
+  let formatter = partsFormatters.get(timeZone);
+  if (!formatter) {
+    formatter = new Intl.DateTimeFormat("en-US", {
+      timeZone,
+      year: "numeric",
+      month: "2-digit",
+      day: "2-digit",
+      hour: "2-digit",
+      minute: "2-digit",
+      second: "2-digit",
+      hourCycle: "h23",
+    });
+    partsFormatters.set(timeZone, formatter);
+  }
+  const parts: Record<string, string> = {};
+  for (const part of formatter.formatToParts(date)) {
+    parts[part.type] = part.value;
+  }
+  return parts;
+}
+
+/** Minutes since local midnight in *timeZone* (0–1439). */
+export function minutesOfDay(date: Date, timeZone = "UTC"): number {
+  const { hour, minute } = dateParts(date, timeZone);
+  return Number(hour) * 60 + Number(minute);
+}
+
+export function formatDateTimeShort(value: string | Date | null | undefined, timeZone = "UTC"): string {
+  const date = parseTimestamp(value);
+  if (!date) return "-";
+  const { year, month, day, hour, minute } = dateParts(date, timeZone);
+  return `${year}-${month}-${day}${NBSP}${hour}:${minute}`;
+}
+
+export function formatTimeShort(value: string | Date | null | undefined, timeZone = "UTC"): string {
+  const date = parseTimestamp(value);
+  if (!date) return "";
+  const { hour, minute } = dateParts(date, timeZone);
+  return `${hour}:${minute}`;
+}
+
+export function formatUtcTooltip(value: string | Date | null | undefined): string {
+  const date = parseTimestamp(value);
+  if (!date) return "";
+  const { year, month, day, hour, minute, second } = dateParts(date, "UTC");
+  return `${year}-${month}-${day} ${hour}:${minute}:${second} UTC`;
+}
+
+export function formatRelativeTime(
+  value: string | Date | null | undefined,
+  { now = Date.now(), timeZone = "UTC" }: { now?: number; timeZone?: string } = {}
+): string {
+  const date = parseTimestamp(value);
+  if (!date) return "-";
+
+  const seconds = Math.round((date.getTime() - now) / 1000);
+  const abs = Math.abs(seconds);
+  const phrase = (amount: string) => (seconds < 0 ? `${amount} ago` : `in ${amount}`);
+
+  if (abs < 45) return "just now";
+  if (abs < 60 * 60) return phrase(`${Math.max(1, Math.floor(abs / 60))} min`);
+  if (abs < 60 * 60 * 24) return phrase(`${Math.floor(abs / 3600)} h`);
+  const days = Math.floor(abs / 86400);
+  if (days < 30) return phrase(days === 1 ? "1 day" : `${days} days`);
+  // Beyond a month relative phrasing stops being useful
+  return formatDateTimeShort(date, timeZone);
+}
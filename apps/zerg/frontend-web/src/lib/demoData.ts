@@ -0,0 +1,218 @@
+/**
+ * Seed data for demo mode (lib/demoMode.ts): a signed-in user, a handful of
+ * agents with run history, a chat thread and a small workflow. Everything is
+ * derived from *now* and a fixed pseudo-random sequence, so screenshots look
+ * the same on every load.
+ */
+import type { Agent, AgentRun, ModelConfig, Thread, ThreadMessage } from "../services/api";
+
+export const DEMO_USER = {
+  id: 1,
+  email: "demo@example.com",
+  display_name: "Demo User",
+  avatar_url: null,
+  is_active: true,
+  created_at: "2025-01-06T09:00:00Z",
+  last_login: null,
+  prefs: {},
+  role: "ADMIN",
+  gmail_connected: false,
+};
+
+export const DEMO_CAPABILITIES = ["view_all_agents", "view_ops", "use_any_model"];
+
+export const DEMO_MODELS: ModelConfig[] = [
+  { id: "gpt-5-mini", display_name: "GPT-5 Mini", provider: "openai", is_default: true },
+  { id: "gpt-5", display_name: "GPT-5", provider: "openai", is_default: false },
+  { id: "gpt-5-nano", display_name: "GPT-5 Nano", provider: "openai", is_default: false },
+];
+
+interface AgentSeed {
+  name: string;
+  description: string;
+  model: string;
+  schedule: string | null;
+  system: string;
+  task: string;
+  // Share of runs that fail
+  failureRate: number;
+}
+
+const AGENT_SEEDS: AgentSeed[] = [
+  {
+    name: "Morning Briefing",
+    description: "Summarizes overnight email and calendar changes",
+    model: "gpt-5-mini",
+    schedule: "0 7 * * 1-5",
+    system: "You are a concise executive assistant.",
+    task: "Summarize new email and today's calendar in five bullet points.",
+    failureRate: 0.05,
+  },
+  {
+    name: "Support Triage",
+    description: "Labels and routes incoming support tickets",
+    model: "gpt-5-nano",
+    schedule: "*/15 * * * *",
+    system: "You triage customer support tickets by urgency and product area.",
+    task: "Label every new ticket and assign it to the right queue.",
+    failureRate: 0.15,
+  },
+  {
+    name: "Release Notes Writer",
+    description: "Drafts release notes from merged pull requests",
+    model: "gpt-5",
+    schedule: null,
+    system: "You write friendly, accurate release notes for end users.",
+    task: "Draft release notes for the pull requests merged since the last tag.",
+    failureRate: 0,
+  },
+  {
+    name: "Price Watcher",
+    description: "Checks competitor pricing pages for changes",
+    model: "gpt-5-nano",
+    schedule: "0 */6 * * *",
+    system: "You compare pricing pages and report meaningful changes only.",
+    task: "Fetch the tracked pricing pages and report any change.",
+    failureRate: 0.3,
+  },
+];
+
+export const DEMO_REPLIES = [
+  "Here's a quick summary: three new customer emails need a reply today, the design review moved to 2pm, " +
+    "and the weekly metrics report is ready to share.",
+  "I checked the latest runs. Everything succeeded except one pricing page that timed out; " +
+    "I'll retry it on the next schedule.",
+  "This is demo mode, so nothing is sent anywhere – but in a real workspace I'd call my tools here " +
+    "and stream back the results.",
+];
+
+// Deterministic pseudo-random numbers (mulberry32) so the seed is identical on every load
+export function seededRandom(seed: number): () => number {
+  let state = seed;
+  return () => {
+    state = (state + 0x6d2b79f5) | 0;
+    let t = Math.imul(state ^ (state >>> 15), 1 | state);
+    t = (t + Math.imul(t ^ (t >>> 7), 61 | t)) ^ t;
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+export interface DemoSeed {
+  agents: Agent[];
+  runs: AgentRun[];
+  threads: Thread[];
+  messages: ThreadMessage[];
+}
+
+const HOUR_MS = 60 * 60 * 1000;
+const RUNS_PER_AGENT = 8;
+
+export function createDemoSeed(now: Date = new Date()): DemoSeed {
+  const random = seededRandom(42);
+  const iso = (ms: number) => new Date(ms).toISOString();
+  const created = now.getTime() - 30 * 24 * HOUR_MS;
+  const agents: Agent[] = [];
+  const runs: AgentRun[] = [];
+  const threads: Thread[] = [];
+  const messages: ThreadMessage[] = [];
+
+  AGENT_SEEDS.forEach((seed, index) => {
+    const agentId = index + 1;
+    const threadId = agentId;
+    let lastRunAt: number | null = null;
+    let lastError: string | null = null;
+
+    for (let n = RUNS_PER_AGENT; n >= 1; n -= 1) {
+      const startedAt = now.getTime() - n * (6 + index) * HOUR_MS - Math.round(random() * HOUR_MS);
+      const durationMs = 1500 + Math.round(random() * 9000);
+      const failed = random() < seed.failureRate;
+      const tokens = 400 + Math.round(random() * 3600);
+      runs.push({
+        id: runs.length + 1,
+        agent_id: agentId,
+        thread_id: threadId,
+        status: failed ? "failed" : "success",
+        trigger: seed.schedule ? "schedule" : "manual",
+        started_at: iso(startedAt),
+        finished_at: iso(startedAt + durationMs),
+        duration_ms: durationMs,
+        total_tokens: tokens,
+        total_cost_usd: Number((tokens * 0.000002).toFixed(6)),
+        error: failed ? "Timed out waiting for https://example.com/pricing" : null,
+        trace_id: null,
+      });
+      lastRunAt = startedAt;
+      lastError = failed ? "Timed out waiting for https://example.com/pricing" : null;
+    }
+
+    agents.push({
+      id: agentId,
+      owner_id: DEMO_USER.id,
+      name: seed.name,
+      description: seed.description,
+      system_instructions: seed.system,
+      task_instructions: seed.task,
+      model: seed.model,
+      schedule: seed.schedule,
+      status: lastError ? "error" : "idle",
+      last_error: lastError,
+      created_at: iso(created + index * HOUR_MS),
+      updated_at: iso(created + index * HOUR_MS),
+      last_run_at: lastRunAt === null ? null : iso(lastRunAt),
+      next_run_at: seed.schedule ? iso(now.getTime() + (index + 1) * HOUR_MS) : null,
+      messages: [],
+    });
+
+    threads.push({
+      id: threadId,
+      agent_id: agentId,
+      title: index === 0 ? "Daily planning" : `${seed.name} chat`,
+      thread_type: "chat",
+      memory_strategy: "buffer",
+      active: true,
+      pinned: index === 0,
+      archived: false,
+      created_at: iso(created),
+      updated_at: iso(now.getTime() - HOUR_MS),
+      messages: [],
+    });
+  });
+
+  const chat: [string, string][] = [
+    ["user", "What's on my plate today?"],
+    ["assistant", DEMO_REPLIES[0]],
+    ["user", "Did any of the scheduled agents fail overnight?"],
+    ["assistant", DEMO_REPLIES[1]],
+  ];
+  chat.forEach(([role, content], index) => {
+    messages.push({
+      id: index + 1,
+      thread_id: 1,
+      role,
+      content,
+      sent_at: iso(now.getTime() - HOUR_MS + index * 60_000),
+      processed: true,
+    });
+  });
+
+  return { agents, runs, threads, messages };
+}
+
+export function demoWorkflowCanvas() {
+  return {
+    nodes: [
+      { id: "trigger-1", type: "trigger" as const, position: { x: 80, y: 160 }, config: { text: "Manual trigger" } },
+      {
+        id: "agent-1",
+        type: "agent" as const,
+        position: { x: 340, y: 160 },
+        config: { text: "Morning Briefing", agent_id: 1 },
+      },
+      { id: "http-1", type: "http" as const, position: { x: 600, y: 160 }, config: { text: "Post summary" } },
+    ],
+    edges: [
+      { from_node_id: "trigger-1", to_node_id: "agent-1" },
+      { from_node_id: "agent-1", to_node_id: "http-1" },
+    ],
+  };
+}
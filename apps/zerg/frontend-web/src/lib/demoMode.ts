@@ -0,0 +1,578 @@
+/**
+ * Demo mode: open the app with `?demo=1` and it runs entirely in the browser.
+ *
+ * `window.fetch` and `window.WebSocket` are swapped for an in-memory backend
+ * seeded from lib/demoData, so every caller – services/api, auth, the ops
+ * page's direct fetches – gets canned agents, runs, threads and ops numbers
+ * without a server. Writes change the in-memory state for the rest of the
+ * visit; chat replies stream token by token and scheduled agents keep
+ * "running" in the background over the fake WebSocket. The choice sticks for
+ * the browser tab until `?demo=0`.
+ */
+import type { Agent, AgentRun, Thread, ThreadMessage } from "../services/api";
+import {
+  DEMO_CAPABILITIES,
+  DEMO_MODELS,
+  DEMO_REPLIES,
+  DEMO_USER,
+  createDemoSeed,
+  demoWorkflowCanvas,
+  type DemoSeed,
+} from "./demoData";
+
+export const DEMO_STORAGE_KEY = "zerg:demo";
+export const DEMO_RUN_MS = 2500;
+export const DEMO_AMBIENT_INTERVAL_MS = 15_000;
+const DEMO_TOKEN_INTERVAL_MS = 40;
+const NOT_IN_DEMO = { detail: "Not available in demo mode" };
+
+/** Whether this tab is in demo mode; `?demo=1` turns it on and `?demo=0` off. */
+export function isDemoMode(search = window.location.search, storage: Storage = window.sessionStorage): boolean {
+  const param = new URLSearchParams(search).get("demo");
+  try {
+    if (param === "1") storage.setItem(DEMO_STORAGE_KEY, "1");
+    if (param === "0") storage.removeItem(DEMO_STORAGE_KEY);
+    return storage.getItem(DEMO_STORAGE_KEY) === "1";
+  } catch {
+    return param === "1";
+  }
+}
+
+export interface DemoResponse {
+  status: number;
+  body?: unknown;
+}
+
+export interface DemoEvent {
+  type: string;
+  topic: string;
+  data: Record<string, unknown>;
+}
+
+type Schedule = (callback: () => void, ms: number) => unknown;
+
+// "/api/agents/3" -> "/agents/3"; null for anything that isn't an API call
+export function demoApiPath(pathname: string): string | null {
+  const match = /\/api(\/.*)?$/.exec(pathname);
+  return match ? match[1] ?? "/" : null;
+}
+
+function sum(values: number[]): number {
+  return values.reduce((total, value) => total + value, 0);
+}
+
+function percentile(values: number[], fraction: number): number {
+  if (values.length === 0) return 0;
+  const sorted = [...values].sort((a, b) => a - b);
+  return sorted[Math.min(sorted.length - 1, Math.floor(fraction * sorted.length))];
+}
+
+export class DemoBackend {
+  private state: DemoSeed;
+  private canvas = demoWorkflowCanvas();
+  private listeners = new Set<(event: DemoEvent) => void>();
+  private ambientTimer: unknown = null;
+  private ambientTurn = 0;
+  private lastMessageId: number;
+
+  constructor(
+    private readonly now: () => Date = () => new Date(),
+    private readonly schedule: Schedule = (callback, ms) => window.setTimeout(callback, ms)
+  ) {
+    this.state = createDemoSeed(now());
+    this.lastMessageId = this.nextId(this.state.messages) - 1;
+  }
+
+  /** Receive every event the fake server would publish; the first listener starts the background runs. */
+  subscribe(listener: (event: DemoEvent) => void): () => void {
+    this.listeners.add(listener);
+    if (this.ambientTimer === null) this.scheduleAmbientRun();
+    return () => {
+      this.listeners.delete(listener);
+    };
+  }
+
+  handle(method: string, path: string, query: URLSearchParams, body: unknown): DemoResponse {
+    const verb = method.toUpperCase();
+    const input = (body && typeof body === "object" ? body : {}) as Record<string, unknown>;
+    const id = (pattern: RegExp) => {
+      const match = pattern.exec(path);
+      return match ? Number(match[1]) : null;
+    };
+    const ok = (value: unknown, status = 200): DemoResponse => ({ status, body: value });
+    const notFound = (): DemoResponse => ({ status: 404, body: { detail: "Not found" } });
+    let agentId: number | null;
+    let threadId: number | null;
+
+    if (verb === "GET") {
+      if (path === "/users/me") return ok(DEMO_USER);
+      if (path === "/users/me/capabilities") return ok({ role: DEMO_USER.role, capabilities: DEMO_CAPABILITIES });
+      if (path === "/system/info") return ok({ auth_disabled: true, maintenance_mode: false });
+      if (path === "/models/") return ok(DEMO_MODELS);
+      if (path === "/agents") return ok(this.state.agents);
+      if (path === "/agents/dashboard") return ok(this.dashboard(query));
+      if ((agentId = id(/^\/agents\/(\d+)$/)) !== null) return this.withAgent(agentId, (agent) => ok(agent));
+      if ((agentId = id(/^\/agents\/(\d+)\/runs$/)) !== null) {
+        const limit = Number(query.get("limit") ?? 50);
+        return ok(this.runsFor(agentId).slice(0, limit));
+      }
+      if ((agentId = id(/^\/agents\/(\d+)\/stats$/)) !== null) return ok(this.stats(agentId));
+      if (path === "/threads") {
+        const forAgent = Number(query.get("agent_id"));
+        const type = query.get("thread_type");
+        return ok(this.state.threads.filter((t) => t.agent_id === forAgent && (!type || t.thread_type === type)));
+      }
+      if ((threadId = id(/^\/threads\/(\d+)\/messages$/)) !== null) {
+        return ok(this.state.messages.filter((message) => message.thread_id === threadId));
+      }
+      if (path === "/workflows") return ok([this.workflow()]);
+      if (path === "/workflows/current") return ok(this.workflow());
+      if (path === "/ops/summary") return ok(this.opsSummary());
+      if (path === "/ops/timeseries") return ok({ series: this.opsSeries(query.get("metric") ?? "", query) });
+      if (path === "/ops/status") return ok(this.systemStatus());
+      // FastAPI list endpoints end in a slash: templates, triggers, env vars, tools and so on start out empty
+      if (path.endsWith("/")) return ok([]);
+      return notFound();
+    }
+
+    if (path === "/auth/refresh") return ok({ expires_in: 3600 });
+    if (path === "/auth/logout" || path === "/ops/beacon") return { status: 204 };
+
+    if (verb === "POST" && path === "/agents") return ok(this.createAgent(input), 201);
+    if (verb === "PUT" && (agentId = id(/^\/agents\/(\d+)$/)) !== null) {
+      return this.withAgent(agentId, (agent) => {
+        Object.assign(agent, input, { id: agent.id, updated_at: this.now().toISOString() });
+        return ok(agent);
+      });
+    }
+    if (verb === "DELETE" && (agentId = id(/^\/agents\/(\d+)$/)) !== null) {
+      this.state.agents = this.state.agents.filter((agent) => agent.id !== agentId);
+      return { status: 204 };
+    }
+    if (verb === "POST" && (agentId = id(/^\/agents\/(\d+)\/task$/)) !== null) {
+      return this.withAgent(agentId, (agent) => ok({ thread_id: this.startRun(agent, "manual").thread_id }, 202));
+    }
+    if (verb === "POST" && path === "/threads") return ok(this.createThread(input), 201);
+    if (verb === "POST" && (threadId = id(/^\/threads\/(\d+)\/messages$/)) !== null) {
+      return ok(this.addMessage(threadId, "user", String(input.content ?? "")), 201);
+    }
+    if (verb === "POST" && (threadId = id(/^\/threads\/(\d+)\/run$/)) !== null) {
+      this.streamReply(threadId);
+      return { status: 202 };
+    }
+    if (verb === "PATCH" && path === "/workflows/current/canvas") {
+      if (input.canvas && typeof input.canvas === "object") {
+        this.canvas = input.canvas as ReturnType<typeof demoWorkflowCanvas>;
+      }
+      return ok(this.workflow());
+    }
+    return { status: 501, body: NOT_IN_DEMO };
+  }
+
+  // --- Reads ---
+
+  private withAgent(agentId: number, respond: (agent: Agent) => DemoResponse): DemoResponse {
+    const agent = this.state.agents.find((candidate) => candidate.id === agentId);
+    return agent ? respond(agent) : { status: 404, body: { detail: "Agent not found" } };
+  }
+
+  private runsFor(agentId: number): AgentRun[] {
+    return this.state.runs.filter((run) => run.agent_id === agentId).sort((a, b) => b.id - a.id);
+  }
+
+  private stats(agentId: number) {
+    const runs = this.runsFor(agentId);
+    const finished = runs.filter((run) => run.status === "success" || run.status === "failed");
+    const successes = finished.filter((run) => run.status === "success").length;
+    const durations = finished.map((run) => run.duration_ms ?? 0);
+    const today = this.now().getTime();
+    const daily_runs = Array.from({ length: 7 }, (_, offset) => {
+      const date = new Date(today - (6 - offset) * 86_400_000).toISOString().slice(0, 10);
+      return { date, count: runs.filter((run) => run.started_at?.startsWith(date)).length };
+    });
+    return {
+      agent_id: agentId,
+      run_count: runs.length,
+      success_count: successes,
+      failed_count: finished.length - successes,
+      success_rate: finished.length ? successes / finished.length : null,
+      avg_duration_ms: durations.length ? Math.round(sum(durations) / durations.length) : null,
+      daily_runs,
+    };
+  }
+
+  private dashboard(query: URLSearchParams) {
+    const runsLimit = Number(query.get("runs_limit") ?? 50);
+    return {
+      scope: query.get("scope") ?? "my",
+      fetched_at: this.now().toISOString(),
+      runs_limit: runsLimit,
+      agents: this.state.agents,
+      runs: this.state.agents.map((agent) => ({
+        agent_id: agent.id,
+        runs: this.runsFor(agent.id).slice(0, runsLimit),
+      })),
+      budgets: [],
+      stats: this.state.agents.map((agent) => this.stats(agent.id)),
+      permissions: this.state.agents.map((agent) => ({ agent_id: agent.id, permission: "edit" })),
+    };
+  }
+
+  private workflow() {
+    return {
+      id: 1,
+      owner_id: DEMO_USER.id,
+      name: "Morning routine",
+      description: "Demo workflow",
+      is_active: true,
+      created_at: DEMO_USER.created_at,
+      updated_at: this.now().toISOString(),
+      canvas: this.canvas,
+    };
+  }
+
+  private runsSince(ms: number): AgentRun[] {
+    const since = this.now().getTime() - ms;
+    return this.state.runs.filter((run) => run.started_at && Date.parse(run.started_at) >= since);
+  }
+
+  private opsSummary() {
+    const today = this.runsSince(24 * 3_600_000);
+    const cost = sum(today.map((run) => run.total_cost_usd ?? 0));
+    const top = this.state.agents.map((agent) => {
+      const runs = today.filter((run) => run.agent_id === agent.id);
+      return {
+        agent_id: agent.id,
+        name: agent.name,
+        owner_email: DEMO_USER.email,
+        runs: runs.length,
+        cost_usd: sum(runs.map((run) => run.total_cost_usd ?? 0)),
+        p95_ms: percentile(runs.map((run) => run.duration_ms ?? 0), 0.95),
+      };
+    });
+    return {
+      runs_today: today.length,
+      cost_today_usd: cost,
+      budget_user: { limit_cents: 0, used_usd: cost, percent: null },
+      budget_global: { limit_cents: 0, used_usd: cost, percent: null },
+      active_users_24h: 1,
+      agents_total: this.state.agents.length,
+      agents_scheduled: this.state.agents.filter((agent) => agent.schedule).length,
+      latency_ms: {
+        p50: percentile(today.map((run) => run.duration_ms ?? 0), 0.5),
+        p95: percentile(today.map((run) => run.duration_ms ?? 0), 0.95),
+      },
+      errors_last_hour: this.runsSince(3_600_000).filter((run) => run.status === "failed").length,
+      top_agents_today: top.filter((agent) => agent.runs > 0).sort((a, b) => b.runs - a.runs),
+    };
+  }
+
+  // Same bucket labels as the server: "HH:00Z" for today, ISO dates for 7d/30d
+  private opsSeries(metric: string, query: URLSearchParams) {
+    const [kind, grain] = metric.split("_by_");
+    const value = (runs: AgentRun[]) =>
+      kind === "errors"
+        ? runs.filter((run) => run.status === "failed").length
+        : kind === "cost"
+          ? sum(runs.map((run) => run.total_cost_usd ?? 0))
+          : runs.length;
+    const now = this.now();
+    if (grain === "hour") {
+      const day = now.toISOString().slice(0, 10);
+      return Array.from({ length: 24 }, (_, hour) => {
+        const prefix = `${day}T${String(hour).padStart(2, "0")}`;
+        return { hour_iso: `${String(hour).padStart(2, "0")}:00Z`, value: value(this.runsStartingWith(prefix)) };
+      });
+    }
+    const days = query.get("window") === "30d" ? 30 : 7;
+    return Array.from({ length: days }, (_, offset) => {
+      const date = new Date(now.getTime() - (days - 1 - offset) * 86_400_000).toISOString().slice(0, 10);
+      return { hour_iso: date, value: value(this.runsStartingWith(date)) };
+    });
+  }
+
+  private runsStartingWith(prefix: string): AgentRun[] {
+    return this.state.runs.filter((run) => run.started_at?.startsWith(prefix));
+  }
+
+  private systemStatus() {
+    const lastHour = this.runsSince(3_600_000);
+    const errors = lastHour.filter((run) => run.status === "failed").length;
+    const durations = lastHour.map((run) => run.duration_ms ?? 0);
+    return {
+      status: "ok",
+      checked_at: this.now().toISOString(),
+      database: "ok",
+      websocket: { available: true, active_connections: this.listeners.size },
+      runs_last_hour: lastHour.length,
+      errors_last_hour: errors,
+      errors_last_24h: this.runsSince(86_400_000).filter((run) => run.status === "failed").length,
+      error_rate_last_hour: lastHour.length ? errors / lastHour.length : null,
+      frontend_errors_last_hour: 0,
+      latency_ms: { p50: percentile(durations, 0.5), p95: percentile(durations, 0.95) },
+    };
+  }
+
+  // --- Writes ---
+
+  private nextId(items: { id: number }[]): number {
+    return items.reduce((max, item) => Math.max(max, item.id), 0) + 1;
+  }
+
+  private createAgent(input: Record<string, unknown>): Agent {
+    const now = this.now().toISOString();
+    const agent: Agent = {
+      id: this.nextId(this.state.agents),
+      owner_id: DEMO_USER.id,
+      name: String(input.name ?? "New agent"),
+      system_instructions: String(input.system_instructions ?? ""),
+      task_instructions: String(input.task_instructions ?? ""),
+      model: String(input.model ?? DEMO_MODELS[0].id),
+      schedule: null,
+      status: "idle",
+      created_at: now,
+      updated_at: now,
+      messages: [],
+    };
+    this.state.agents.push(agent);
+    return agent;
+  }
+
+  private createThread(input: Record<string, unknown>): Thread {
+    const now = this.now().toISOString();
+    const thread: Thread = {
+      id: this.nextId(this.state.threads),
+      agent_id: Number(input.agent_id),
+      title: String(input.title ?? "New thread"),
+      thread_type: String(input.thread_type ?? "chat"),
+      memory_strategy: "buffer",
+      active: true,
+      pinned: false,
+      archived: false,
+      created_at: now,
+      updated_at: now,
+      messages: [],
+    };
+    this.state.threads.push(thread);
+    return thread;
+  }
+
+  private addMessage(threadId: number, role: string, content: string, id = ++this.lastMessageId): ThreadMessage {
+    const message: ThreadMessage = {
+      id,
+      thread_id: threadId,
+      role,
+      content,
+      sent_at: this.now().toISOString(),
+      processed: true,
+    };
+    this.state.messages.push(message);
+    return message;
+  }
+
+  private emit(type: string, topic: string, data: Record<string, unknown>) {
+    const event = { type, topic, data };
+    this.listeners.forEach((listener) => listener(event));
+  }
+
+  /** Run *agent*: running now, success after DEMO_RUN_MS, with the matching WebSocket events. */
+  startRun(agent: Agent, trigger: AgentRun["trigger"]): AgentRun {
+    const startedAt = this.now();
+    const threadId = this.state.threads.find((thread) => thread.agent_id === agent.id)?.id ?? 0;
+    const run: AgentRun = {
+      id: this.nextId(this.state.runs),
+      agent_id: agent.id,
+      thread_id: threadId,
+      status: "running",
+      trigger,
+      started_at: startedAt.toISOString(),
+    };
+    this.state.runs.push(run);
+    const topic = `agent:${agent.id}`;
+    agent.status = "running";
+    this.emit("agent_state", topic, { id: agent.id, status: "running" });
+    this.emit("run_update", topic, { ...run });
+
+    this.schedule(() => {
+      const tokens = 600 + (run.id % 7) * 250;
+      Object.assign(run, {
+        status: "success",
+        finished_at: this.now().toISOString(),
+        duration_ms: DEMO_RUN_MS,
+        total_tokens: tokens,
+        total_cost_usd: Number((tokens * 0.000002).toFixed(6)),
+      });
+      agent.status = "idle";
+      agent.last_error = null;
+      agent.last_run_at = run.started_at;
+      this.emit("run_update", topic, { ...run });
+      this.emit("agent_state", topic, { id: agent.id, status: "idle", last_run_at: run.started_at, last_error: null });
+    }, DEMO_RUN_MS);
+    return run;
+  }
+
+  /** Stream a canned assistant reply into *threadId*, a word at a time like the real chat stream. */
+  private streamReply(threadId: number) {
+    const topic = `user:${DEMO_USER.id}`;
+    const replies = this.state.messages.filter((message) => message.role === "assistant").length;
+    const words = DEMO_REPLIES[replies % DEMO_REPLIES.length].split(/(?<= )/);
+    // Reserved now: the client learns the id from assistant_id before the message exists
+    const messageId = ++this.lastMessageId;
+    this.emit("stream_start", topic, { thread_id: threadId });
+    this.emit("assistant_id", topic, { thread_id: threadId, message_id: messageId });
+    words.forEach((word, index) => {
+      this.schedule(
+        () => this.emit("stream_chunk", topic, { thread_id: threadId, chunk_type: "assistant_token", content: word }),
+        (index + 1) * DEMO_TOKEN_INTERVAL_MS
+      );
+    });
+    this.schedule(() => {
+      const message = this.addMessage(threadId, "assistant", words.join(""), messageId);
+      message.usage = { model: DEMO_MODELS[0].id, total_tokens: words.length * 2, cost_usd: 0.0001 };
+      this.emit("stream_end", topic, {
+        thread_id: threadId,
+        message_id: messageId,
+        model: DEMO_MODELS[0].id,
+        total_tokens: words.length * 2,
+        cost_usd: 0.0001,
+      });
+    }, (words.length + 1) * DEMO_TOKEN_INTERVAL_MS);
+  }
+
+  // While anything listens, scheduled agents take turns running so the dashboard stays live
+  private scheduleAmbientRun() {
+    this.ambientTimer = this.schedule(() => {
+      this.ambientTimer = null;
+      if (this.listeners.size === 0) return;
+      const scheduled = this.state.agents.filter((agent) => agent.schedule && agent.status !== "running");
+      if (scheduled.length > 0) {
+        this.startRun(scheduled[this.ambientTurn % scheduled.length], "schedule");
+        this.ambientTurn += 1;
+      }
+      this.scheduleAmbientRun();
+    }, DEMO_AMBIENT_INTERVAL_MS);
+  }
+}
+
+// --- Browser shims ---
+
+function requestUrl(input: RequestInfo | URL): URL {
+  const href = typeof input === "string" ? input : input instanceof URL ? input.href : input.url;
+  return new URL(href, window.location.href);
+}
+
+async function requestBody(input: RequestInfo | URL, init?: RequestInit): Promise<unknown> {
+  const raw = init?.body ?? (input instanceof Request ? await input.clone().text() : null);
+  if (typeof raw !== "string" || !raw) return undefined;
+  try {
+    return JSON.parse(raw);
+  } catch {
+    return raw;
+  }
+}
+
+/** A fetch that answers API calls from *backend* and passes everything else (assets, config.js) through. */
+export function createDemoFetch(backend: DemoBackend, passthrough: typeof fetch): typeof fetch {
+  return async (input, init) => {
+    const url = requestUrl(input);
+    const path = demoApiPath(url.pathname);
+    if (path === null) return passthrough(input, init);
+    const method = init?.method ?? (input instanceof Request ? input.method : "GET");
+    const { status, body } = backend.handle(method, path, url.searchParams, await requestBody(input, init));
+    const hasBody = body !== undefined && status !== 204;
+    return new Response(hasBody ? JSON.stringify(body) : null, {
+      status,
+      headers: hasBody ? { "Content-Type": "application/json" } : {},
+    });
+  };
+}
+
+/** A WebSocket stand-in that delivers *backend*'s events for the topics it has subscribed to. */
+export function createDemoWebSocket(backend: DemoBackend): typeof WebSocket {
+  class DemoWebSocket extends EventTarget {
+    static readonly CONNECTING = 0;
+    static readonly OPEN = 1;
+    static readonly CLOSING = 2;
+    static readonly CLOSED = 3;
+
+    readonly url: string;
+    readyState = DemoWebSocket.CONNECTING;
+    onopen: ((event: Event) => void) | null = null;
+    onmessage: ((event: MessageEvent) => void) | null = null;
+    onclose: ((event: CloseEvent) => void) | null = null;
+    onerror: ((event: Event) => void) | null = null;
+    private topics = new Set([`user:${DEMO_USER.id}`]);
+    private unsubscribe: (() => void) | null = null;
+
+    constructor(url: string | URL) {
+      super();
+      this.url = String(url);
+      window.setTimeout(() => {
+        if (this.readyState !== DemoWebSocket.CONNECTING) return;
+        this.readyState = DemoWebSocket.OPEN;
+        this.unsubscribe = backend.subscribe((event) => {
+          if (this.topics.has(event.topic)) this.deliver(event.type, event.topic, event.data);
+        });
+        this.fire(new Event("open"));
+      }, 0);
+    }
+
+    send(frame: string) {
+      let message: { type?: string; topics?: unknown; message_id?: string; data?: { topics?: unknown } };
+      try {
+        message = JSON.parse(frame);
+      } catch {
+        return;
+      }
+      const topics = message.topics ?? message.data?.topics;
+      const list = Array.isArray(topics) ? topics.filter((topic): topic is string => typeof topic === "string") : [];
+      if (message.type === "subscribe") {
+        list.forEach((topic) => this.topics.add(topic));
+        this.deliver("subscribe_ack", "system", { message_id: message.message_id ?? "", topics: list });
+      } else if (message.type === "unsubscribe") {
+        list.forEach((topic) => this.topics.delete(topic));
+      } else if (message.type === "ping") {
+        this.deliver("pong", "system", { timestamp: Date.now() });
+      }
+    }
+
+    close() {
+      if (this.readyState === DemoWebSocket.CLOSED) return;
+      this.readyState = DemoWebSocket.CLOSED;
+      this.unsubscribe?.();
+      this.fire(new CloseEvent("close", { code: 1000, wasClean: true }));
+    }
+
+    private deliver(type: string, topic: string, data: Record<string, unknown>) {
+      const frame = JSON.stringify({ v: 1, type, topic, ts: Date.now(), data });
+      // Asynchronous, like a real frame arriving
+      window.setTimeout(() => {
+        if (this.readyState === DemoWebSocket.OPEN) this.fire(new MessageEvent("message", { data: frame }));
+      }, 0);
+    }
+
+    private fire(event: Event) {
+      this.dispatchEvent(event);
+      const handler = this[`on${event.type}` as "onopen" | "onmessage" | "onclose" | "onerror"];
+      (handler as ((event: Event) => void) | null)?.(event);
+    }
+  }
+  return DemoWebSocket as unknown as typeof WebSocket;
+}
+
+let demoActive = false;
+
+/** True once installDemoMode has swapped in the demo backend. */
+export function isDemoActive(): boolean {
+  return demoActive;
+}
+
+/** Swap in the demo backend when the tab is in demo mode. Must run before anything talks to the API. */
+export function installDemoMode(): boolean {
+  if (typeof window === "undefined" || !isDemoMode()) return false;
+  const backend = new DemoBackend();
+  window.fetch = createDemoFetch(backend, window.fetch.bind(window));
+  window.WebSocket = createDemoWebSocket(backend);
+  demoActive = true;
+  return true;
+}
@@ -0,0 +1,78 @@
+/**
+ * Opt-in desktop notifications for finished agent runs.
+ *
+ * Fired only while the tab is hidden, when the user enabled them in their
+ * profile and the browser granted permission. An optional quiet-hours window
+ * (in the user's timezone, may wrap past midnight) suppresses them.
+ */
+
+import { minutesOfDay } from "./datetime";
+
+// Keys in user.prefs
+export const DESKTOP_NOTIFICATIONS_PREF = "desktop_notifications";
+export const QUIET_HOURS_PREF = "quiet_hours";
+
+export interface QuietHours {
+  // "HH:MM", 24-hour clock
+  start: string;
+  end: string;
+}
+
+export interface DesktopNotificationPrefs {
+  enabled: boolean;
+  quietHours: QuietHours | null;
+}
+
+const TIME_OF_DAY = /^([01]\d|2[0-3]):([0-5]\d)$/;
+
+function parseTimeOfDay(value: unknown): number | null {
+  if (typeof value !== "string") return null;
+  const match = TIME_OF_DAY.exec(value);
+  return match ? Number(match[1]) * 60 + Number(match[2]) : null;
+}
+
+export function desktopNotificationPrefs(prefs: Record<string, unknown> | null | undefined): DesktopNotificationPrefs {
+  const raw = prefs?.[QUIET_HOURS_PREF] as Partial<QuietHours> | null | undefined;
+  const valid = raw && parseTimeOfDay(raw.start) !== null && parseTimeOfDay(raw.end) !== null;
+  return {
+    enabled: prefs?.[DESKTOP_NOTIFICATIONS_PREF] === true,
+    quietHours: valid ? { start: raw.start as string, end: raw.end as string } : null,
+  };
+}
+
+export function isWithinQuietHours(date: Date, quietHours: QuietHours | null, timeZone: string): boolean {
+  if (!quietHours) return false;
+  const start = parseTimeOfDay(quietHours.start);
+  const end = parseTimeOfDay(quietHours.end);
+  if (start === null || end === null || start === end) return false;
+  const now = minutesOfDay(date, timeZone);
+  // 22:00–07:00 wraps past midnight
+  return start < end ? now >= start && now < end : now >= start || now < end;
+}
+
+export interface DesktopNotificationContext {
+  prefs: DesktopNotificationPrefs;
+  permission: NotificationPermission | "unsupported";
+  hidden: boolean;
+  now: Date;
+  timeZone: string;
+}
+
+export function shouldNotifyDesktop({ prefs, permission, hidden, now, timeZone }: DesktopNotificationContext): boolean {
+  return prefs.enabled && permission === "granted" && hidden && !isWithinQuietHours(now, prefs.quietHours, timeZone);
+}
+
+export function runFinishedNotification(
+  agentName: string,
+  status: "success" | "failed",
+  error?: string | null
+): { title: string; body: string } {
+  if (status === "success") {
+    return { title: `${agentName} finished`, body: "The run completed successfully." };
+  }
+  return { title: `${agentName} failed`, body: error ? error : "The run ended with an error." };
+}
+
+export function browserNotificationPermission(): NotificationPermission | "unsupported" {
+  return typeof window !== "undefined" && "Notification" in window ? Notification.permission : "unsupported";
+}
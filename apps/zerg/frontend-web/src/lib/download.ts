@@ -0,0 +1,20 @@
+/**
+ * Trigger a browser download for an in-memory blob.
+ */
+export function downloadBlob(filename: string, blob: Blob) {
+  const url = URL.createObjectURL(blob);
+  const link = document.createElement("a");
+  link.href = url;
+  link.download = filename;
+  document.body.appendChild(link);
+  link.click();
+  document.body.removeChild(link);
+  URL.revokeObjectURL(url);
+}
+
+/**
+ * Trigger a browser download for in-memory text content.
+ */
+export function downloadTextFile(filename: string, content: string, mimeType = "text/plain") {
+  downloadBlob(filename, new Blob([content], { type: `${mimeType};charset=utf-8` }));
+}
@@ -0,0 +1,62 @@
+/**
+ * Unsent work kept in localStorage: the chat composer per thread and the
+ * agent instruction editor per agent. Drafts are written through the shared
+ * persistence queue (lib/persistence.ts), so typing is debounced and a
+ * pending draft is flushed when the tab is hidden or closed. A draft is
+ * removed once it is sent/saved or edited back to empty, and drafts older
+ * than a month are ignored.
+ */
+
+import type { SliceWriter } from "./persistence";
+
+export const DRAFT_STORAGE_PREFIX = "zerg:draft:";
+export const DRAFT_MAX_AGE_MS = 30 * 24 * 60 * 60 * 1000;
+
+export interface StoredDraft<T> {
+  value: T;
+  // Epoch milliseconds of the last write
+  savedAt: number;
+}
+
+export const chatDraftKey = (threadId: number) => `chat:${threadId}`;
+export const agentDraftKey = (agentId: number) => `agent:${agentId}`;
+
+/** Persistence slice that owns the draft stored under *key*. */
+export const draftSlice = (key: string) => `draft:${key}`;
+
+export function readDraft<T>(
+  key: string,
+  storage: Storage = window.localStorage,
+  now: number = Date.now()
+): StoredDraft<T> | null {
+  const storageKey = DRAFT_STORAGE_PREFIX + key;
+  try {
+    const raw = storage.getItem(storageKey);
+    if (!raw) return null;
+    const parsed = JSON.parse(raw) as Partial<StoredDraft<T>>;
+    if (typeof parsed.savedAt !== "number" || parsed.value === undefined) return null;
+    if (now - parsed.savedAt > DRAFT_MAX_AGE_MS) {
+      storage.removeItem(storageKey);
+      return null;
+    }
+    return { value: parsed.value, savedAt: parsed.savedAt };
+  } catch {
+    return null;
+  }
+}
+
+/** Writer for a draft slice; `null` removes the draft. */
+export function draftWriter<T>(
+  key: string,
+  storage: Storage = window.localStorage
+): SliceWriter<StoredDraft<T> | null> {
+  const storageKey = DRAFT_STORAGE_PREFIX + key;
+  return (draft) => {
+    try {
+      if (draft === null) storage.removeItem(storageKey);
+      else storage.setItem(storageKey, JSON.stringify(draft));
+    } catch (error) {
+      console.warn(`Failed to save draft ${key}:`, error);
+    }
+  };
+}
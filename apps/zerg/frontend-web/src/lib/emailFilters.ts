@@ -0,0 +1,71 @@
+/**
+ * Email trigger filters: the structured object stored on a trigger
+ * (``config.filters``) and the flat form the builder edits.
+ *
+ * Keys mirror the backend matcher in ``email_filtering.py``. Multiple
+ * "from" or "subject" terms are OR-ed; every populated field must match.
+ */
+
+export interface EmailTriggerFilters {
+  from_contains?: string[];
+  subject_contains?: string[];
+  label_include?: string[];
+  label_exclude?: string[];
+  has_attachment?: boolean;
+  query?: string;
+}
+
+export interface EmailFilterForm {
+  from: string;
+  subject: string;
+  label: string;
+  hasAttachment: boolean;
+}
+
+function splitTerms(value: string): string[] {
+  return value
+    .split(",")
+    .map((term) => term.trim())
+    .filter(Boolean);
+}
+
+export function filtersToForm(filters: EmailTriggerFilters | null | undefined): EmailFilterForm {
+  return {
+    from: (filters?.from_contains ?? []).join(", "),
+    subject: (filters?.subject_contains ?? []).join(", "),
+    label: (filters?.label_include ?? []).join(", "),
+    hasAttachment: Boolean(filters?.has_attachment),
+  };
+}
+
+/**
+ * Compose the stored filter from the form. Keys the builder does not edit
+ * (``label_exclude``, ``query``) are carried over from *previous*.
+ */
+export function formToFilters(form: EmailFilterForm, previous?: EmailTriggerFilters | null): EmailTriggerFilters {
+  const filters: EmailTriggerFilters = {};
+  const from = splitTerms(form.from);
+  const subject = splitTerms(form.subject);
+  const labels = splitTerms(form.label);
+
+  if (from.length) filters.from_contains = from;
+  if (subject.length) filters.subject_contains = subject;
+  if (labels.length) filters.label_include = labels;
+  if (form.hasAttachment) filters.has_attachment = true;
+  if (previous?.label_exclude?.length) filters.label_exclude = previous.label_exclude;
+  if (previous?.query) filters.query = previous.query;
+  return filters;
+}
+
+export function describeEmailFilters(filters: EmailTriggerFilters | null | undefined): string {
+  const parts: string[] = [];
+  if (filters?.from_contains?.length) parts.push(`from ${filters.from_contains.join(" or ")}`);
+  if (filters?.subject_contains?.length) {
+    parts.push(`subject contains ${filters.subject_contains.map((term) => `"${term}"`).join(" or ")}`);
+  }
+  if (filters?.label_include?.length) parts.push(`labelled ${filters.label_include.join(" and ")}`);
+  if (filters?.label_exclude?.length) parts.push(`not labelled ${filters.label_exclude.join(" or ")}`);
+  if (filters?.has_attachment) parts.push("with an attachment");
+  if (filters?.query) parts.push(`matching "${filters.query}"`);
+  return parts.length ? `Emails ${parts.join(", ")}` : "Every new email";
+}
@@ -0,0 +1,54 @@
+/**
+ * Environment variables and secrets, referenced from node config and agent
+ * instructions as {{env.NAME}}. Values scoped to a workflow override
+ * workspace-wide ones of the same name; agent instructions never get secret
+ * values (services/env_vars.py).
+ */
+import type { EnvVar } from "../services/api";
+
+const ENV_REFERENCE_PATTERN = /\{\{\s*env\.([A-Za-z_][A-Za-z0-9_]*)\s*\}\}/g;
+const ENV_NAME_PATTERN = /^[A-Za-z_][A-Za-z0-9_]*$/;
+
+export const MAX_ENV_NAME_LENGTH = 64;
+
+export type EnvScope = "workflow" | "workspace";
+
+export interface EnvResolution {
+  name: string;
+  // Where the value comes from; null when nothing defines it
+  scope: EnvScope | null;
+  secret: boolean;
+}
+
+export function isValidEnvName(name: string): boolean {
+  return ENV_NAME_PATTERN.test(name) && name.length <= MAX_ENV_NAME_LENGTH;
+}
+
+/** Distinct {{env.NAME}} names in a string, or anywhere in an object or array of them, in order. */
+export function findEnvReferences(value: unknown): string[] {
+  const names = new Set<string>();
+  const visit = (item: unknown) => {
+    if (typeof item === "string") {
+      for (const match of item.matchAll(ENV_REFERENCE_PATTERN)) names.add(match[1]);
+    } else if (Array.isArray(item)) {
+      item.forEach(visit);
+    } else if (item && typeof item === "object") {
+      Object.values(item).forEach(visit);
+    }
+  };
+  visit(value);
+  return [...names];
+}
+
+/** Which variable a reference resolves to for *workflowId*, mirroring the engine's precedence. */
+export function resolveEnvReference(name: string, vars: EnvVar[], workflowId: number | null): EnvResolution {
+  const scoped = workflowId != null ? vars.find((v) => v.name === name && v.workflow_id === workflowId) : undefined;
+  if (scoped) return { name, scope: "workflow", secret: scoped.is_secret };
+  const workspace = vars.find((v) => v.name === name && v.workflow_id == null);
+  if (workspace) return { name, scope: "workspace", secret: workspace.is_secret };
+  return { name, scope: null, secret: false };
+}
+
+export function envReferenceText(name: string): string {
+  return `{{env.${name}}}`;
+}
@@ -0,0 +1,132 @@
+import type { ExecutionNodeSummary } from "../services/api";
+
+export type HeatMetric = "failure_rate" | "avg_duration" | "cost";
+
+export const HEAT_METRICS: { value: HeatMetric; label: string }[] = [
+  { value: "failure_rate", label: "Failure rate" },
+  { value: "avg_duration", label: "Avg duration" },
+  { value: "cost", label: "Avg cost" },
+];
+
+export interface NodeHeatStats {
+  runs: number;
+  failures: number;
+  failureRate: number;
+  avgDurationMs: number | null;
+  avgCostUsd: number | null;
+}
+
+export interface NodeHeat {
+  // 0 = cold, 1 = hottest node for the chosen metric
+  intensity: number;
+  label: string;
+  stats: NodeHeatStats;
+}
+
+/**
+ * Fold per-execution node summaries into per-node aggregates.
+ * Only finished node runs count; in-flight nodes have no outcome yet.
+ */
+export function aggregateNodeStats(executions: ExecutionNodeSummary[]): Map<string, NodeHeatStats> {
+  const totals = new Map<
+    string,
+    { runs: number; failures: number; durationSum: number; durationCount: number; costSum: number; costCount: number }
+  >();
+
+  executions.forEach((execution) => {
+    execution.nodes.forEach((node) => {
+      if (node.phase !== "finished") return;
+      const entry = totals.get(node.node_id) ?? {
+        runs: 0,
+        failures: 0,
+        durationSum: 0,
+        durationCount: 0,
+        costSum: 0,
+        costCount: 0,
+      };
+      entry.runs += 1;
+      if (node.result === "failure") entry.failures += 1;
+      if (node.duration_ms != null) {
+        entry.durationSum += node.duration_ms;
+        entry.durationCount += 1;
+      }
+      if (node.cost_usd != null) {
+        entry.costSum += node.cost_usd;
+        entry.costCount += 1;
+      }
+      totals.set(node.node_id, entry);
+    });
+  });
+
+  const stats = new Map<string, NodeHeatStats>();
+  totals.forEach((entry, nodeId) => {
+    stats.set(nodeId, {
+      runs: entry.runs,
+      failures: entry.failures,
+      failureRate: entry.runs > 0 ? entry.failures / entry.runs : 0,
+      avgDurationMs: entry.durationCount > 0 ? entry.durationSum / entry.durationCount : null,
+      avgCostUsd: entry.costCount > 0 ? entry.costSum / entry.costCount : null,
+    });
+  });
+  return stats;
+}
+
+function metricValue(stats: NodeHeatStats, metric: HeatMetric): number | null {
+  switch (metric) {
+    case "failure_rate":
+      return stats.failureRate;
+    case "avg_duration":
+      return stats.avgDurationMs;
+    case "cost":
+      return stats.avgCostUsd;
+  }
+}
+
+export function formatHeatValue(value: number | null, metric: HeatMetric): string {
+  if (value === null) return "n/a";
+  switch (metric) {
+    case "failure_rate":
+      return `${Math.round(value * 100)}%`;
+    case "avg_duration":
+      return value >= 1000 ? `${(value / 1000).toFixed(1)}s` : `${Math.round(value)}ms`;
+    case "cost":
+      return `$${value < 0.01 ? value.toFixed(4) : value.toFixed(2)}`;
+  }
+}
+
+/**
+ * Scale the chosen metric to a 0..1 intensity per node. Failure rate is
+ * already a ratio; duration and cost are relative to the slowest or most
+ * expensive node so hotspots stand out regardless of absolute scale.
+ */
+export function computeNodeHeat(stats: Map<string, NodeHeatStats>, metric: HeatMetric): {
+  heat: Map<string, NodeHeat>;
+  max: number | null;
+} {
+  let max: number | null = null;
+  stats.forEach((nodeStats) => {
+    const value = metricValue(nodeStats, metric);
+    if (value !== null) max = max === null ? value : Math.max(max, value);
+  });
+
+  const heat = new Map<string, NodeHeat>();
+  stats.forEach((nodeStats, nodeId) => {
+    const value = metricValue(nodeStats, metric);
+    if (value === null) return;
+    const intensity = metric === "failure_rate" ? value : max ? value / max : 0;
+    heat.set(nodeId, {
+      intensity,
+      label: `${formatHeatValue(value, metric)} · ${nodeStats.runs} run${nodeStats.runs === 1 ? "" : "s"}`,
+      stats: nodeStats,
+    });
+  });
+
+  return { heat, max };
+}
+
+// Green → amber → red
+export function heatColor(intensity: number): string {
+  const clamped = Math.min(Math.max(intensity, 0), 1);
+  const hue = 120 - clamped * 120;
+  return `hsl(${hue.toFixed(0)} 80% 50%)`;
+}
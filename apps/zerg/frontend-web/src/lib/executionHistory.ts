@@ -0,0 +1,150 @@
+/**
+ * Canvas execution history: the workflow's recent runs, filtered by status,
+ * start date and id. Pinned runs are listed first and never pruned; the
+ * retention setting drops everything else past a count or age from the
+ * cached list, so long-lived workflows don't keep hundreds of runs in memory.
+ * Pins are kept per workflow and the retention setting once per browser.
+ */
+
+import { parseTimestamp } from "./datetime";
+import type { WorkflowExecution } from "../services/api";
+
+export type ExecutionOutcome = "waiting" | "running" | "success" | "failure" | "cancelled";
+export type ExecutionStatusFilter = "all" | ExecutionOutcome;
+
+export const EXECUTION_STATUS_LABELS: Record<ExecutionStatusFilter, string> = {
+  all: "All",
+  waiting: "Waiting",
+  running: "Running",
+  success: "Succeeded",
+  failure: "Failed",
+  cancelled: "Cancelled",
+};
+
+export interface ExecutionFilter {
+  status: ExecutionStatusFilter;
+  // Local dates as yyyy-mm-dd (date input values); "" leaves that end open
+  from: string;
+  to: string;
+  // Execution id, with or without a leading #
+  query: string;
+}
+
+export const EMPTY_EXECUTION_FILTER: ExecutionFilter = { status: "all", from: "", to: "", query: "" };
+
+export interface ExecutionRetention {
+  // null keeps any number / any age
+  maxCount: number | null;
+  maxAgeDays: number | null;
+}
+
+export const DEFAULT_EXECUTION_RETENTION: ExecutionRetention = { maxCount: 50, maxAgeDays: 30 };
+export const RETENTION_COUNT_OPTIONS = [20, 50, 100, null] as const;
+export const RETENTION_AGE_OPTIONS = [1, 7, 30, 90, null] as const;
+
+// Shared with the canvas, which refreshes the list as runs start and finish
+export const executionHistoryQueryKey = (workflowId: number) => ["execution-history", workflowId] as const;
+
+export const EXECUTION_RETENTION_STORAGE_KEY = "zerg:execution-retention";
+export const executionPinsStorageKey = (workflowId: number) => `zerg:execution-pins:${workflowId}`;
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export function executionOutcome(execution: WorkflowExecution): ExecutionOutcome {
+  if (execution.phase !== "finished") return execution.phase;
+  return execution.result ?? "failure";
+}
+
+export function executionDurationLabel(execution: WorkflowExecution): string | null {
+  const started = parseTimestamp(execution.started_at);
+  const finished = parseTimestamp(execution.finished_at);
+  if (!started || !finished) return null;
+  const ms = Math.max(0, finished.getTime() - started.getTime());
+  return ms < 1000 ? `${ms}ms` : `${(ms / 1000).toFixed(1)}s`;
+}
+
+function localDateKey(date: Date): string {
+  const month = String(date.getMonth() + 1).padStart(2, "0");
+  const day = String(date.getDate()).padStart(2, "0");
+  return `${date.getFullYear()}-${month}-${day}`;
+}
+
+export function matchesExecutionFilter(execution: WorkflowExecution, filter: ExecutionFilter): boolean {
+  if (filter.status !== "all" && executionOutcome(execution) !== filter.status) return false;
+  const query = filter.query.trim().replace(/^#/, "");
+  if (query && !String(execution.id).startsWith(query)) return false;
+  if (filter.from || filter.to) {
+    const started = parseTimestamp(execution.started_at);
+    if (!started) return false;
+    const day = localDateKey(started);
+    if (filter.from && day < filter.from) return false;
+    if (filter.to && day > filter.to) return false;
+  }
+  return true;
+}
+
+/** Runs matching *filter*, pinned first; each group keeps the newest-first order. */
+export function filterExecutions(
+  executions: WorkflowExecution[],
+  filter: ExecutionFilter,
+  pinned: ReadonlySet<number>
+): WorkflowExecution[] {
+  const matching = executions.filter((execution) => matchesExecutionFilter(execution, filter));
+  return [
+    ...matching.filter((execution) => pinned.has(execution.id)),
+    ...matching.filter((execution) => !pinned.has(execution.id)),
+  ];
+}
+
+/**
+ * *executions* (newest first) with runs past *retention* dropped. Pinned and
+ * unfinished runs are always kept and don't count towards the limit.
+ */
+export function pruneExecutions(
+  executions: WorkflowExecution[],
+  retention: ExecutionRetention,
+  pinned: ReadonlySet<number>,
+  now: number = Date.now()
+): WorkflowExecution[] {
+  let kept = 0;
+  return executions.filter((execution) => {
+    if (pinned.has(execution.id) || execution.phase !== "finished") return true;
+    const started = parseTimestamp(execution.started_at ?? execution.finished_at);
+    if (retention.maxAgeDays !== null && started && now - started.getTime() > retention.maxAgeDays * DAY_MS) {
+      return false;
+    }
+    kept += 1;
+    return retention.maxCount === null || kept <= retention.maxCount;
+  });
+}
+
+/** *ids* with *id* pinned, or unpinned if it already was. */
+export function togglePin(ids: ReadonlySet<number>, id: number): Set<number> {
+  const next = new Set(ids);
+  if (next.has(id)) next.delete(id);
+  else next.add(id);
+  return next;
+}
+
+export function readExecutionPins(workflowId: number, storage: Storage = window.localStorage): Set<number> {
+  try {
+    const parsed = JSON.parse(storage.getItem(executionPinsStorageKey(workflowId)) ?? "[]");
+    return new Set(Array.isArray(parsed) ? parsed.filter((id): id is number => Number.isInteger(id)) : []);
+  } catch {
+    return new Set();
+  }
+}
+
+const isLimit = (value: unknown) => value === null || (Number.isInteger(value) && (value as number) > 0);
+
+export function readExecutionRetention(storage: Storage = window.localStorage): ExecutionRetention {
+  try {
+    const parsed = JSON.parse(storage.getItem(EXECUTION_RETENTION_STORAGE_KEY) ?? "null");
+    if (parsed && isLimit(parsed.maxCount) && isLimit(parsed.maxAgeDays)) {
+      return { maxCount: parsed.maxCount, maxAgeDays: parsed.maxAgeDays };
+    }
+  } catch {
+    // Fall through to the default
+  }
+  return DEFAULT_EXECUTION_RETENTION;
+}
@@ -0,0 +1,100 @@
+/**
+ * Client state for paused executions. In step mode the backend parks the
+ * run before each node, otherwise only at nodes with `config.breakpoint`;
+ * either way it broadcasts `execution_paused` with the node's config and
+ * every output produced so far, and the canvas steps or continues via REST.
+ */
+
+import type { ExecutionPausedData } from "../generated/ws-messages";
+
+export interface PausedNode {
+  nodeId: string;
+  nodeType: ExecutionPausedData["node_type"];
+  config: Record<string, unknown>;
+  reason: ExecutionPausedData["reason"];
+}
+
+export interface StepState {
+  paused: PausedNode | null;
+  // Output envelopes of finished nodes, as of the latest pause
+  nodeOutputs: Record<string, unknown>;
+  // A step/continue request is in flight
+  resuming: boolean;
+}
+
+export type StepAction =
+  | { type: "paused"; data: ExecutionPausedData }
+  | { type: "resume_requested" }
+  // Carries the node that was released, so a late response can't clear the next pause
+  | { type: "resumed"; nodeId: string }
+  | { type: "resume_failed" }
+  | { type: "finished" }
+  | { type: "reset" };
+
+export const initialStepState: StepState = { paused: null, nodeOutputs: {}, resuming: false };
+
+export function stepReducer(state: StepState, action: StepAction): StepState {
+  switch (action.type) {
+    case "paused":
+      return {
+        paused: {
+          nodeId: action.data.node_id,
+          nodeType: action.data.node_type,
+          config: action.data.config ?? {},
+          reason: action.data.reason ?? "step",
+        },
+        nodeOutputs: action.data.node_outputs ?? {},
+        resuming: false,
+      };
+    case "resume_requested":
+      return { ...state, resuming: true };
+    case "resumed":
+      if (state.paused?.nodeId !== action.nodeId) return state;
+      return { ...state, paused: null, resuming: false };
+    case "resume_failed":
+      return { ...state, resuming: false };
+    case "finished":
+      return { ...state, paused: null, resuming: false };
+    case "reset":
+      return initialStepState;
+  }
+}
+
+export type ConfigDraftResult = { ok: true; config: Record<string, unknown> } | { ok: false; error: string };
+
+export function parseConfigDraft(text: string): ConfigDraftResult {
+  try {
+    const parsed = JSON.parse(text);
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+      return { ok: false, error: "Input must be a JSON object." };
+    }
+    return { ok: true, config: parsed as Record<string, unknown> };
+  } catch (error) {
+    return { ok: false, error: `Invalid JSON: ${(error as Error).message}` };
+  }
+}
+
+/** The edited config to send, or undefined when the draft matches what the node would run with. */
+export function configOverride(original: Record<string, unknown>, text: string): Record<string, unknown> | undefined {
+  const result = parseConfigDraft(text);
+  if (!result.ok) return undefined;
+  return JSON.stringify(result.config) === JSON.stringify(original) ? undefined : result.config;
+}
+
+export function hasBreakpoint(config: Record<string, unknown> | undefined): boolean {
+  return config?.breakpoint === true;
+}
+
+/** Node config with the breakpoint flag flipped; the key is dropped rather than stored as false. */
+export function toggleBreakpoint(config: Record<string, unknown> | undefined): Record<string, unknown> {
+  const { breakpoint: _breakpoint, ...rest } = config ?? {};
+  return hasBreakpoint(config) ? rest : { ...rest, breakpoint: true };
+}
+
+/** The `value` of a node output envelope, falling back to the raw output. */
+export function outputValue(output: unknown): unknown {
+  if (output && typeof output === "object" && "value" in output) {
+    return (output as { value: unknown }).value;
+  }
+  return output;
+}
@@ -0,0 +1,255 @@
+/**
+ * Report exports shared by the dashboard and the ops page: CSV building, file
+ * names, and the printable run report (saved as PDF from the browser's print
+ * dialog).
+ */
+import type { AgentRun, ThreadMessage } from "../services/api";
+import { downloadTextFile } from "./download";
+
+export interface CsvColumn<T> {
+  header: string;
+  value: (row: T) => unknown;
+}
+
+export function escapeCsvValue(value: unknown): string {
+  if (value === null || value === undefined) {
+    return "";
+  }
+  const text = typeof value === "object" ? JSON.stringify(value) : String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+/** RFC 4180 CSV with a header row and CRLF line endings. */
+export function toCsv<T>(columns: ReadonlyArray<CsvColumn<T>>, rows: ReadonlyArray<T>): string {
+  const header = columns.map((column) => escapeCsvValue(column.header)).join(",");
+  const lines = rows.map((row) => columns.map((column) => escapeCsvValue(column.value(row))).join(","));
+  return [header, ...lines].join("\r\n") + "\r\n";
+}
+
+export function slugify(value: string, fallback = "export"): string {
+  return value.toLowerCase().replace(/[^a-z0-9]+/g, "-").replace(/^-+|-+$/g, "") || fallback;
+}
+
+/** e.g. "nightly-digest-runs-2025-01-31.csv" */
+export function exportFilename(subject: string, kind: string, extension: string, date = new Date()): string {
+  return `${slugify(subject)}-${kind}-${date.toISOString().slice(0, 10)}.${extension}`;
+}
+
+export function downloadCsv(filename: string, csv: string) {
+  downloadTextFile(filename, csv, "text/csv");
+}
+
+// --- Ops aggregates ---
+
+export interface OpsActivityExport {
+  labels: string[];
+  runs: number[];
+  errors: number[];
+  cost: number[];
+}
+
+interface OpsBucket {
+  bucket: string;
+  runs: number;
+  errors: number;
+  cost: number;
+}
+
+const OPS_COLUMNS: CsvColumn<OpsBucket>[] = [
+  { header: "bucket", value: (row) => row.bucket },
+  { header: "runs", value: (row) => row.runs },
+  { header: "errors", value: (row) => row.errors },
+  { header: "error_rate", value: (row) => (row.runs > 0 ? (row.errors / row.runs).toFixed(4) : "") },
+  { header: "cost_usd", value: (row) => row.cost.toFixed(6) },
+];
+
+/** One row per hour ("today") or day bucket of the selected window, then a total row. */
+export function opsActivityToCsv(activity: OpsActivityExport): string {
+  const buckets: OpsBucket[] = activity.labels.map((label, index) => ({
+    bucket: label,
+    runs: activity.runs[index] ?? 0,
+    errors: activity.errors[index] ?? 0,
+    cost: activity.cost[index] ?? 0,
+  }));
+  const total = buckets.reduce(
+    (sum, row) => ({ ...sum, runs: sum.runs + row.runs, errors: sum.errors + row.errors, cost: sum.cost + row.cost }),
+    { bucket: "total", runs: 0, errors: 0, cost: 0 }
+  );
+  return toCsv(OPS_COLUMNS, [...buckets, total]);
+}
+
+// --- Printable run report ---
+
+// Messages a second either side of the run still belong to it (timestamps are rounded)
+const RUN_WINDOW_SLACK_MS = 1000;
+
+function timeOf(value: string | null | undefined): number | null {
+  if (!value) return null;
+  const time = Date.parse(value);
+  return Number.isNaN(time) ? null : time;
+}
+
+/**
+ * The thread messages produced by *run*: replies billed to it, plus anything
+ * sent while it ran. Chat threads hold many runs, so the rest are left out;
+ * without timestamps the whole thread is used.
+ */
+export function runReportMessages(run: AgentRun, messages: ThreadMessage[]): ThreadMessage[] {
+  const start = timeOf(run.started_at);
+  if (start === null) return messages;
+  const end = timeOf(run.finished_at) ?? Number.POSITIVE_INFINITY;
+  return messages.filter((message) => {
+    if (message.usage?.run_id != null) return message.usage.run_id === run.id;
+    const sent = timeOf(message.sent_at);
+    return sent !== null && sent >= start - RUN_WINDOW_SLACK_MS && sent <= end + RUN_WINDOW_SLACK_MS;
+  });
+}
+
+export function escapeHtml(value: string): string {
+  return value
+    .replace(/&/g, "&amp;")
+    .replace(/</g, "&lt;")
+    .replace(/>/g, "&gt;")
+    .replace(/"/g, "&quot;")
+    .replace(/'/g, "&#39;");
+}
+
+function formatTime(value: string | null | undefined): string {
+  const time = timeOf(value);
+  return time === null ? "—" : new Date(time).toLocaleString();
+}
+
+function formatCost(cost: number | null | undefined): string {
+  return cost == null ? "—" : `$${cost.toFixed(cost > 0 && cost < 0.01 ? 4 : 2)}`;
+}
+
+function formatDuration(ms: number | null | undefined): string {
+  if (ms == null) return "—";
+  return ms < 1000 ? `${ms} ms` : `${(ms / 1000).toFixed(1)} s`;
+}
+
+function messageLabel(message: ThreadMessage): string {
+  if (message.role === "tool") return `Tool result${message.name ? ` · ${message.name}` : ""}`;
+  if (message.role === "assistant" && message.tool_calls?.length) return "Assistant (tool calls)";
+  return message.role.charAt(0).toUpperCase() + message.role.slice(1);
+}
+
+function messageBody(message: ThreadMessage): string {
+  const calls = message.tool_calls?.length ? `\n\n${JSON.stringify(message.tool_calls, null, 2)}` : "";
+  return `${message.content}${calls}`.trim();
+}
+
+const REPORT_STYLES = `
+  body { font: 14px/1.5 system-ui, sans-serif; color: #111; margin: 2rem auto; max-width: 60rem; padding: 0 1rem; }
+  h1 { margin-bottom: 0; }
+  .subtitle { color: #555; margin-top: 0.25rem; }
+  table { border-collapse: collapse; width: 100%; margin: 1rem 0; }
+  th, td { border-bottom: 1px solid #ddd; padding: 0.35rem 0.5rem; text-align: left; vertical-align: top; }
+  th { background: #f5f5f5; }
+  pre { white-space: pre-wrap; overflow-wrap: anywhere; background: #f7f7f7; padding: 0.5rem; margin: 0.25rem 0 0; }
+  .message { break-inside: avoid; margin: 1rem 0; }
+  .message-meta { color: #555; font-size: 12px; }
+  .error { color: #b00020; }
+  @media print { body { margin: 0; max-width: none; } }
+`;
+
+const TIMELINE_COLUMNS = ["Time", "Step", "Model", "Tokens", "Cost", "Duration"];
+
+interface RunReportInput {
+  run: AgentRun;
+  agentName: string;
+  messages: ThreadMessage[];
+  // Shown in the footer
+  generatedAt?: Date;
+}
+
+/** A standalone HTML document with the run's summary, costs, timeline and every prompt and output. */
+export function runReportHtml({ run, agentName, messages, generatedAt = new Date() }: RunReportInput): string {
+  const summary: [string, string][] = [
+    ["Status", run.status],
+    ["Trigger", run.trigger],
+    ["Started", formatTime(run.started_at)],
+    ["Finished", formatTime(run.finished_at)],
+    ["Duration", formatDuration(run.duration_ms)],
+    ["Tokens", run.total_tokens != null ? String(run.total_tokens) : "—"],
+    ["Cost", formatCost(run.total_cost_usd)],
+    ["Trace ID", run.trace_id ?? "—"],
+  ];
+
+  const timeline = messages
+    .map(
+      (message) => `<tr>
+        <td>${escapeHtml(formatTime(message.sent_at))}</td>
+        <td>${escapeHtml(messageLabel(message))}</td>
+        <td>${escapeHtml(message.usage?.model ?? "")}</td>
+        <td>${message.usage?.total_tokens ?? ""}</td>
+        <td>${message.usage?.cost_usd != null ? escapeHtml(formatCost(message.usage.cost_usd)) : ""}</td>
+        <td>${message.usage?.duration_ms != null ? escapeHtml(formatDuration(message.usage.duration_ms)) : ""}</td>
+      </tr>`
+    )
+    .join("");
+
+  const transcript = messages
+    .map((message) => {
+      const meta = `${messageLabel(message)} · ${formatTime(message.sent_at)}`;
+      return `<div class="message">
+        <div class="message-meta">${escapeHtml(meta)}</div>
+        <pre>${escapeHtml(messageBody(message))}</pre>
+      </div>`;
+    })
+    .join("");
+  const summaryRows = summary.map(([label, value]) => `<tr><th>${label}</th><td>${escapeHtml(value)}</td></tr>`);
+  const timelineHeader = `<tr>${TIMELINE_COLUMNS.map((column) => `<th>${column}</th>`).join("")}</tr>`;
+
+  const sections = [
+    `<table>${summaryRows.join("")}</table>`,
+    run.error ? `<h2>Error</h2><pre class="error">${escapeHtml(run.error)}</pre>` : "",
+    run.schema_errors?.length
+      ? `<h2>Schema violations</h2><ul>${run.schema_errors.map((e) => `<li>${escapeHtml(e)}</li>`).join("")}</ul>`
+      : "",
+    run.output != null
+      ? `<h2>Structured output</h2><pre>${escapeHtml(JSON.stringify(run.output, null, 2))}</pre>`
+      : "",
+    `<h2>Timeline</h2>`,
+    messages.length
+      ? `<table>${timelineHeader}${timeline}</table>`
+      : `<p>No messages were recorded for this run.</p>`,
+    messages.length ? `<h2>Prompts and outputs</h2>${transcript}` : "",
+  ];
+
+  const title = `Run #${run.id} – ${agentName}`;
+  return `<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>${escapeHtml(title)}</title>
+<style>${REPORT_STYLES}</style>
+</head>
+<body>
+<h1>Run #${run.id}</h1>
+<p class="subtitle">${escapeHtml(agentName)}</p>
+${sections.join("\n")}
+<p class="subtitle">Generated ${escapeHtml(generatedAt.toLocaleString())}</p>
+</body>
+</html>
+`;
+}
+
+/**
+ * Open *html* in a new tab and bring up the print dialog (where it can be
+ * saved as PDF). When pop-ups are blocked the HTML is downloaded instead;
+ * returns whether the print view opened.
+ */
+export function openPrintableReport(html: string, filename: string): boolean {
+  const view = window.open("", "_blank");
+  if (!view) {
+    downloadTextFile(filename, html, "text/html");
+    return false;
+  }
+  view.document.open();
+  view.document.write(html);
+  view.document.close();
+  view.focus();
+  view.print();
+  return true;
+}
@@ -0,0 +1,80 @@
+/**
+ * "GitHub PR action" node: a tool node preset that comments on or labels an
+ * issue or pull request via github_add_comment / github_add_labels
+ * (tools/builtin/github_tools.py), using the account's GitHub connector.
+ * The number and text may reference upstream outputs with ${node_id.value...}.
+ */
+
+export const GITHUB_TOOL_TYPE = "github-pr-action";
+
+export type GitHubAction = "comment" | "label";
+
+export const GITHUB_ACTION_TOOLS: Record<GitHubAction, string> = {
+  comment: "github_add_comment",
+  label: "github_add_labels",
+};
+
+export const GITHUB_ITEM = { type: GITHUB_TOOL_TYPE, name: "GitHub PR action", icon: "🐙" } as const;
+
+export type NodeConfig = Record<string, unknown>;
+
+export interface GitHubParams {
+  action: GitHubAction;
+  repo: string;
+  number: string;
+  // Comment body, or labels as a comma-separated list
+  text: string;
+}
+
+export function defaultGitHubConfig(): NodeConfig {
+  return { tool_name: GITHUB_ACTION_TOOLS.comment, static_params: { owner: "", repo: "", issue_number: "", body: "" } };
+}
+
+export function githubActionFor(toolName: unknown): GitHubAction | null {
+  if (toolName === GITHUB_ACTION_TOOLS.comment) return "comment";
+  if (toolName === GITHUB_ACTION_TOOLS.label) return "label";
+  return null;
+}
+
+export function isGitHubNode(config: NodeConfig | undefined): boolean {
+  return githubActionFor(config?.tool_name) !== null || config?.tool_type === GITHUB_TOOL_TYPE;
+}
+
+export function githubParams(config: NodeConfig | undefined): GitHubParams {
+  const params = config?.static_params;
+  const record = params && typeof params === "object" && !Array.isArray(params) ? (params as NodeConfig) : {};
+  const action = githubActionFor(config?.tool_name) ?? "comment";
+  const owner = typeof record.owner === "string" ? record.owner : "";
+  const repo = typeof record.repo === "string" ? record.repo : "";
+  const labels = Array.isArray(record.labels) ? record.labels.filter((label) => typeof label === "string") : [];
+  return {
+    action,
+    repo: owner || repo ? `${owner}/${repo}` : "",
+    number: record.issue_number != null ? String(record.issue_number) : "",
+    text: action === "label" ? labels.join(", ") : typeof record.body === "string" ? record.body : "",
+  };
+}
+
+/** static_params for the chosen action; a plain number is sent as a number, a template as written. */
+export function githubStaticParams({ action, repo, number, text }: GitHubParams): NodeConfig {
+  const [owner = "", name = ""] = repo.trim().split("/");
+  const trimmed = number.trim();
+  const params: NodeConfig = { owner, repo: name, issue_number: /^\d+$/.test(trimmed) ? Number(trimmed) : trimmed };
+  if (action === "label") {
+    params.labels = text
+      .split(",")
+      .map((label) => label.trim())
+      .filter(Boolean);
+  } else {
+    params.body = text;
+  }
+  return params;
+}
+
+/** One-line summary shown on the node under its label. */
+export function githubNodeSummary(config: NodeConfig | undefined): string {
+  const { action, repo, number } = githubParams(config);
+  if (!repo) return "No repository";
+  const target = number ? `${repo}#${number}` : repo;
+  return action === "label" ? `Labels ${target}` : `Comments on ${target}`;
+}
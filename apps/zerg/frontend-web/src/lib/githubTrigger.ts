@@ -0,0 +1,52 @@
+/**
+ * GitHub repository triggers. Config mirrors services/github_events.py: a
+ * repository, the webhook events that run the agent and, optionally, the
+ * issue/PR actions (opened, labeled, …) to narrow them to.
+ */
+
+export type GitHubEventType = "issues" | "pull_request" | "push";
+
+export const GITHUB_EVENT_LABELS: Record<GitHubEventType, string> = {
+  issues: "Issues",
+  pull_request: "Pull requests",
+  push: "Pushes",
+};
+
+export interface GitHubTriggerConfig {
+  repo: string;
+  events: GitHubEventType[];
+  actions?: string[];
+}
+
+const REPO_PATTERN = /^[A-Za-z0-9_.-]+\/[A-Za-z0-9_.-]+$/;
+
+export function isGitHubRepo(value: string): boolean {
+  return REPO_PATTERN.test(value.trim());
+}
+
+/** "opened, labeled" → ["opened", "labeled"] */
+export function parseActions(text: string): string[] {
+  return text
+    .split(",")
+    .map((action) => action.trim())
+    .filter(Boolean);
+}
+
+function isGitHubEventType(value: unknown): value is GitHubEventType {
+  return typeof value === "string" && value in GITHUB_EVENT_LABELS;
+}
+
+/** One-line summary, e.g. "Pull requests (opened) in octocat/hello". */
+export function describeGitHubTrigger(config: Record<string, unknown> | null | undefined): string {
+  const repo = typeof config?.repo === "string" ? config.repo : "";
+  const events = Array.isArray(config?.events) ? config.events.filter(isGitHubEventType) : [];
+  if (!repo || events.length === 0) return "Unknown GitHub trigger";
+  const actions = Array.isArray(config?.actions) ? config.actions.filter((a) => typeof a === "string") : [];
+  const labels = events.map((event) => GITHUB_EVENT_LABELS[event]).join(", ");
+  return `${labels}${actions.length ? ` (${actions.join(", ")})` : ""} in ${repo}`;
+}
+
+/** Whether the account has a GitHub connector, via OAuth or a token. */
+export function isGitHubConnected(connectors: { type: string; configured: boolean }[]) {
+  return connectors.some((connector) => connector.type === "github" && connector.configured);
+}
@@ -0,0 +1,94 @@
+/**
+ * Gmail connection helpers for the profile page: the Google authorization-code
+ * popup that grants offline read access, and the text shown about the
+ * connection's scopes, health and dependent email triggers.
+ */
+
+import type { GmailConnection, GmailDependentTrigger, GmailHealth } from "../services/api";
+
+export const GMAIL_CONNECTION_QUERY_KEY = ["gmail-connection"] as const;
+
+// Read access is all email triggers need (history, metadata, filter previews)
+export const GMAIL_SCOPE = "https://www.googleapis.com/auth/gmail.readonly";
+
+const SCOPE_LABELS: Record<string, string> = {
+  [GMAIL_SCOPE]: "Read email and settings",
+  "https://www.googleapis.com/auth/gmail.modify": "Read, label and archive email",
+  "https://www.googleapis.com/auth/gmail.send": "Send email",
+  "https://www.googleapis.com/auth/userinfo.email": "See your email address",
+  email: "See your email address",
+  openid: "Sign in with Google",
+  profile: "See your basic profile",
+};
+
+export function scopeLabel(scope: string): string {
+  return SCOPE_LABELS[scope] ?? scope.replace("https://www.googleapis.com/auth/", "");
+}
+
+export const GMAIL_HEALTH_LABELS: Record<GmailHealth, string> = {
+  healthy: "Healthy",
+  watch_expired: "Notifications expired",
+  auth_failed: "Access revoked",
+  not_connected: "Not connected",
+};
+
+/** Connected but broken – the reconnect flow is the fix for both states. */
+export function needsReconnect(connection: GmailConnection | undefined): boolean {
+  return !!connection?.connected && (connection.health === "auth_failed" || connection.health === "watch_expired");
+}
+
+export function activeTriggers(triggers: readonly GmailDependentTrigger[]): GmailDependentTrigger[] {
+  return triggers.filter((trigger) => !trigger.disabled);
+}
+
+/** Confirmation text for disconnecting, naming the agents whose triggers stop. */
+export function disconnectWarning(triggers: readonly GmailDependentTrigger[]): string {
+  const active = activeTriggers(triggers);
+  if (active.length === 0) return "Disconnect Gmail? No email triggers use this connection.";
+  const agents = Array.from(new Set(active.map((trigger) => trigger.agent_name)));
+  const count = active.length === 1 ? "1 email trigger" : `${active.length} email triggers`;
+  return (
+    `Disconnect Gmail? ${count} will be disabled and stop running ${agents.join(", ")}. ` +
+    "They keep their filters and come back if you reconnect."
+  );
+}
+
+const GIS_SRC = "https://accounts.google.com/gsi/client";
+
+function loadGoogleIdentity(): Promise<void> {
+  if (window.google?.accounts?.oauth2) return Promise.resolve();
+  return new Promise((resolve, reject) => {
+    const script = document.createElement("script");
+    script.src = GIS_SRC;
+    script.async = true;
+    script.onload = () => resolve();
+    script.onerror = () => reject(new Error("Could not load Google sign-in"));
+    document.head.appendChild(script);
+  });
+}
+
+/**
+ * Open Google's consent popup and resolve with the authorization code the
+ * backend exchanges for a refresh token. ``prompt: "consent"`` makes Google
+ * issue a fresh refresh token on reconnect too.
+ */
+export async function requestGmailAuthCode(clientId: string): Promise<string> {
+  await loadGoogleIdentity();
+  const oauth2 = window.google?.accounts?.oauth2;
+  if (!oauth2) throw new Error("Google sign-in is unavailable");
+  return new Promise((resolve, reject) => {
+    oauth2
+      .initCodeClient({
+        client_id: clientId,
+        scope: GMAIL_SCOPE,
+        ux_mode: "popup",
+        prompt: "consent",
+        callback: (response) => {
+          if (response.code) resolve(response.code);
+          else reject(new Error(response.error_description || response.error || "Gmail access was not granted"));
+        },
+        error_callback: (error) => reject(new Error(error.message || "The Google popup was closed")),
+      })
+      .requestCode();
+  });
+}
@@ -0,0 +1,68 @@
+/**
+ * HTTP request node ("http"): method, URL, headers and a body template, each of
+ * which may reference upstream outputs with ${node_id.value...}. Rendering and
+ * sending happen server-side (services/http_node.py).
+ */
+
+// Mirrors HTTP_METHODS in services/http_node.py
+export const HTTP_METHODS = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD"] as const;
+
+export type HttpMethod = (typeof HTTP_METHODS)[number];
+
+// The shelf's "HTTP Request" entry drops an http node; older canvases keep their http_request tool nodes
+export const HTTP_TOOL_TYPE = "http-request";
+
+export type NodeConfig = Record<string, unknown>;
+
+export function defaultHttpConfig(): NodeConfig {
+  return { method: "GET", url: "", headers: {}, body: "" };
+}
+
+export function isHttpMethod(value: unknown): value is HttpMethod {
+  return typeof value === "string" && (HTTP_METHODS as readonly string[]).includes(value);
+}
+
+/** Absolute http(s) URL, or one that starts with a reference resolved at run time. */
+export function isRequestUrl(url: string): boolean {
+  return /^(https?:\/\/\S+|\$\{)/.test(url.trim());
+}
+
+export function headersFromConfig(config: NodeConfig | undefined): Record<string, string> {
+  const headers = config?.headers;
+  if (!headers || typeof headers !== "object" || Array.isArray(headers)) return {};
+  return Object.fromEntries(Object.entries(headers).map(([name, value]) => [name, String(value)]));
+}
+
+export function formatHeaderLines(headers: Record<string, string>): string {
+  return Object.entries(headers)
+    .map(([name, value]) => `${name}: ${value}`)
+    .join("\n");
+}
+
+/** "Name: value" per line; blank lines are skipped. Returns the 1-based line of the first bad entry. */
+export function parseHeaderLines(text: string): { headers: Record<string, string>; badLine: number | null } {
+  const headers: Record<string, string> = {};
+  const lines = text.split("\n");
+  for (let index = 0; index < lines.length; index++) {
+    const line = lines[index].trim();
+    if (!line) continue;
+    const separator = line.indexOf(":");
+    const name = separator > 0 ? line.slice(0, separator).trim() : "";
+    if (!name) return { headers, badLine: index + 1 };
+    headers[name] = line.slice(separator + 1).trim();
+  }
+  return { headers, badLine: null };
+}
+
+/** One-line summary shown on the node under its label. */
+export function httpNodeSummary(config: NodeConfig | undefined): string {
+  const method = isHttpMethod(config?.method) ? config.method : "GET";
+  const url = typeof config?.url === "string" && config.url ? config.url : "No URL";
+  return `${method} ${url}`;
+}
+
+export function statusTone(status: number): "success" | "warning" | "error" {
+  if (status >= 200 && status < 300) return "success";
+  if (status >= 300 && status < 400) return "warning";
+  return "error";
+}
@@ -0,0 +1,18 @@
+import type { KeyboardEvent as ReactKeyboardEvent } from "react";
+
+// Chrome/Firefox report key "Process" and Safari reports keyCode 229 for keys
+// consumed by an input method editor
+const IME_PROCESS_KEY_CODE = 229;
+
+/**
+ * True while an IME (Japanese, Chinese, Korean, …) composition is in
+ * progress. The Enter that confirms a candidate must not be treated as
+ * "send", and no global shortcut should fire for composition keystrokes.
+ *
+ * Safari fires `compositionend` *before* the confirming keydown, so
+ * `isComposing` is already false there; the 229 keyCode catches that case.
+ */
+export function isImeComposing(event: KeyboardEvent | ReactKeyboardEvent): boolean {
+  const nativeEvent = "nativeEvent" in event ? event.nativeEvent : event;
+  return nativeEvent.isComposing || nativeEvent.keyCode === IME_PROCESS_KEY_CODE || nativeEvent.key === "Process";
+}
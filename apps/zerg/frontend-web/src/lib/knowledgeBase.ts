@@ -0,0 +1,50 @@
+/**
+ * Client-side helpers for the per-agent knowledge base. Limits mirror
+ * services/knowledge_base.py so bad files fail before they are uploaded.
+ */
+
+import type { KnowledgeDocument, KnowledgeDocumentStatus } from "../services/api";
+import { formatFileSize } from "../components/chat/chatUtils";
+
+export const MAX_KNOWLEDGE_FILE_BYTES = 5 * 1024 * 1024;
+export const KNOWLEDGE_EXTENSIONS = [".txt", ".md", ".csv", ".json", ".html", ".htm"];
+// For the file input's ``accept`` attribute
+export const KNOWLEDGE_ACCEPT = [...KNOWLEDGE_EXTENSIONS, "text/*", "application/json"].join(",");
+
+export const KNOWLEDGE_STATUS_LABELS: Record<KnowledgeDocumentStatus, string> = {
+  indexing: "Indexing…",
+  ready: "Ready",
+  failed: "Failed",
+};
+
+export function knowledgeFileError(file: Pick<File, "name" | "size" | "type">): string | null {
+  const name = file.name.toLowerCase();
+  const supported = KNOWLEDGE_EXTENSIONS.some((ext) => name.endsWith(ext)) || file.type.startsWith("text/");
+  if (!supported && file.type !== "application/json") {
+    return `${file.name}: only text, Markdown, CSV, JSON and HTML files are supported`;
+  }
+  if (file.size > MAX_KNOWLEDGE_FILE_BYTES) return `${file.name} is larger than 5 MB`;
+  if (file.size === 0) return `${file.name} is empty`;
+  return null;
+}
+
+export function isKnowledgeIndexing(documents: KnowledgeDocument[] | undefined): boolean {
+  return !!documents?.some((doc) => doc.status === "indexing");
+}
+
+/** Size and chunk count, e.g. "12.3 KB · 4 chunks". */
+export function knowledgeDocumentMeta(doc: KnowledgeDocument): string {
+  const parts: string[] = [];
+  if (doc.size_bytes != null) parts.push(formatFileSize(doc.size_bytes));
+  if (doc.status === "ready") parts.push(`${doc.chunk_count} chunk${doc.chunk_count === 1 ? "" : "s"}`);
+  return parts.join(" · ");
+}
+
+export function isHttpUrl(value: string): boolean {
+  try {
+    const url = new URL(value.trim());
+    return url.protocol === "http:" || url.protocol === "https:";
+  } catch {
+    return false;
+  }
+}
@@ -0,0 +1,41 @@
+import type { ModelComparisonResult } from "../services/api";
+
+export type ComparisonMetric = "duration_ms" | "total_tokens" | "cost_usd";
+
+export const COMPARISON_METRICS: { key: ComparisonMetric; label: string }[] = [
+  { key: "duration_ms", label: "Latency" },
+  { key: "total_tokens", label: "Tokens" },
+  { key: "cost_usd", label: "Cost" },
+];
+
+function metricValue(result: ModelComparisonResult, metric: ComparisonMetric): number | null {
+  // A failed run's numbers say nothing about the model
+  if (result.error) return null;
+  const value = result[metric];
+  return typeof value === "number" ? value : null;
+}
+
+/** Index of the result with the lowest value for *metric*, or null when there is nothing to compare. */
+export function bestResultIndex(results: ModelComparisonResult[], metric: ComparisonMetric): number | null {
+  const values = results.map((result) => metricValue(result, metric));
+  const known = values.filter((value): value is number => value !== null);
+  if (known.length < 2) return null;
+  const lowest = Math.min(...known);
+  if (known.every((value) => value === lowest)) return null;
+  return values.indexOf(lowest);
+}
+
+/** How much higher *value* is than the best one, e.g. "+42%"; null for the best result itself. */
+export function relativeToBest(
+  results: ModelComparisonResult[],
+  index: number,
+  metric: ComparisonMetric
+): string | null {
+  const best = bestResultIndex(results, metric);
+  if (best === null || best === index) return null;
+  const value = metricValue(results[index], metric);
+  const bestValue = metricValue(results[best], metric);
+  if (value === null || bestValue === null) return null;
+  if (bestValue === 0) return "more";
+  return `+${Math.round(((value - bestValue) / bestValue) * 100)}%`;
+}
@@ -0,0 +1,106 @@
+/**
+ * Per-agent generation parameters. Overrides live in ``agent.config.model_params``
+ * and are validated server-side (services/model_params.py); anything left blank
+ * falls back to the model's defaults from config/models.json.
+ */
+
+import type { ModelParams } from "../services/api";
+
+// Key in agent.config; mirrors AGENT_MODEL_PARAMS_KEY in services/model_params.py
+export const AGENT_MODEL_PARAMS_KEY = "model_params";
+
+export type NumericModelParam = "temperature" | "top_p" | "max_tokens";
+
+export interface ModelParamField {
+  key: NumericModelParam;
+  label: string;
+  min: number;
+  max: number;
+  step: number;
+}
+
+// Same bounds as MODEL_PARAM_RANGES on the backend
+export const MODEL_PARAM_FIELDS: ModelParamField[] = [
+  { key: "temperature", label: "Temperature", min: 0, max: 2, step: 0.1 },
+  { key: "top_p", label: "Top P", min: 0, max: 1, step: 0.05 },
+  { key: "max_tokens", label: "Max tokens", min: 1, max: 128000, step: 1 },
+];
+
+export const MAX_STOP_SEQUENCES = 4;
+
+/** Form state: one string per input, blank meaning "use the model default". */
+export type ModelParamsDraft = Record<NumericModelParam, string> & { stop: string };
+
+export function modelParamsFromConfig(config: Record<string, unknown> | null | undefined): ModelParams {
+  const params = config?.[AGENT_MODEL_PARAMS_KEY];
+  return params && typeof params === "object" ? (params as ModelParams) : {};
+}
+
+export function draftFromParams(params: ModelParams): ModelParamsDraft {
+  const field = (value: number | null | undefined) => (value == null ? "" : String(value));
+  return {
+    temperature: field(params.temperature),
+    top_p: field(params.top_p),
+    max_tokens: field(params.max_tokens),
+    stop: (params.stop ?? []).join("\n"),
+  };
+}
+
+/** One stop sequence per line; blank lines are ignored. */
+export function parseStopSequences(text: string): string[] {
+  return Array.from(new Set(text.split("\n").filter((line) => line.trim() !== "")));
+}
+
+export function modelParamError(field: ModelParamField, raw: string): string | null {
+  if (raw.trim() === "") return null;
+  const value = Number(raw);
+  if (!Number.isFinite(value) || value < field.min || value > field.max) {
+    return `${field.label} must be between ${field.min} and ${field.max}`;
+  }
+  if (field.key === "max_tokens" && !Number.isInteger(value)) return "Max tokens must be a whole number";
+  return null;
+}
+
+export function stopSequencesError(text: string): string | null {
+  return parseStopSequences(text).length > MAX_STOP_SEQUENCES
+    ? `At most ${MAX_STOP_SEQUENCES} stop sequences are allowed`
+    : null;
+}
+
+export function draftErrors(draft: ModelParamsDraft): string[] {
+  const errors = MODEL_PARAM_FIELDS.map((field) => modelParamError(field, draft[field.key]));
+  return [...errors, stopSequencesError(draft.stop)].filter((error): error is string => error !== null);
+}
+
+/** The overrides a valid draft describes; blank fields are left out. */
+export function paramsFromDraft(draft: ModelParamsDraft): ModelParams {
+  const params: ModelParams = {};
+  for (const { key } of MODEL_PARAM_FIELDS) {
+    if (draft[key].trim() !== "") params[key] = Number(draft[key]);
+  }
+  const stop = parseStopSequences(draft.stop);
+  if (stop.length > 0) params.stop = stop;
+  return params;
+}
+
+/** Config with the overrides set (or removed when there are none), other keys kept. */
+export function withModelParams(
+  config: Record<string, unknown> | null | undefined,
+  params: ModelParams
+): Record<string, unknown> {
+  const next = { ...(config ?? {}) };
+  if (Object.keys(params).length > 0) next[AGENT_MODEL_PARAMS_KEY] = params;
+  else delete next[AGENT_MODEL_PARAMS_KEY];
+  return next;
+}
+
+export function formatModelParam(key: keyof ModelParams, params: ModelParams): string {
+  if (key === "stop") return params.stop?.length ? params.stop.map((item) => JSON.stringify(item)).join(", ") : "none";
+  const value = params[key];
+  return value == null ? "model default" : String(value);
+}
+
+/** Whether the agent sets *key* itself rather than inheriting the model default. */
+export function isOverridden(key: keyof ModelParams, overrides: ModelParams): boolean {
+  return key === "stop" ? (overrides.stop?.length ?? 0) > 0 : overrides[key] != null;
+}
@@ -0,0 +1,83 @@
+/**
+ * Comment threads on canvas nodes. The API returns a flat, oldest-first
+ * list; a comment without `parent_id` starts a thread and replies point at
+ * that root. Mentions are written as `@someone@example.com` – the server
+ * resolves them and notifies each mentioned user over their `user:` topic.
+ */
+
+import type { CommentMentionData } from "../generated/ws-messages";
+import type { WorkflowComment } from "../services/api";
+import type { NewNotification } from "./notifications";
+
+export interface CommentThread {
+  root: WorkflowComment;
+  replies: WorkflowComment[];
+  resolved: boolean;
+}
+
+export interface NodeCommentSummary {
+  open: number;
+  total: number;
+}
+
+// Must match the server's pattern so highlighted mentions are the ones that notify
+const MENTION_RE = /(?<![\w@])@([\w.+-]+@[\w-]+(?:\.[\w-]+)+)/g;
+
+/** node id -> its threads, open ones first, each group oldest first. */
+export function groupCommentThreads(comments: WorkflowComment[]): Map<string, CommentThread[]> {
+  const threads = new Map<number, CommentThread>();
+  for (const comment of comments) {
+    if (comment.parent_id == null) {
+      threads.set(comment.id, { root: comment, replies: [], resolved: comment.resolved_at != null });
+    }
+  }
+  for (const comment of comments) {
+    if (comment.parent_id != null) threads.get(comment.parent_id)?.replies.push(comment);
+  }
+
+  const byNode = new Map<string, CommentThread[]>();
+  for (const thread of threads.values()) {
+    byNode.set(thread.root.node_id, [...(byNode.get(thread.root.node_id) ?? []), thread]);
+  }
+  for (const list of byNode.values()) {
+    list.sort((a, b) => Number(a.resolved) - Number(b.resolved));
+  }
+  return byNode;
+}
+
+/** Badge counts per node: open threads, and all threads. */
+export function summarizeNodeComments(threadsByNode: Map<string, CommentThread[]>): Map<string, NodeCommentSummary> {
+  const summaries = new Map<string, NodeCommentSummary>();
+  for (const [nodeId, threads] of threadsByNode) {
+    summaries.set(nodeId, { open: threads.filter((thread) => !thread.resolved).length, total: threads.length });
+  }
+  return summaries;
+}
+
+export type CommentSegment = { text: string; mention?: string };
+
+/** Split *body* into plain text and `@email` mention segments for rendering. */
+export function splitMentions(body: string): CommentSegment[] {
+  const segments: CommentSegment[] = [];
+  let last = 0;
+  for (const match of body.matchAll(MENTION_RE)) {
+    const start = match.index ?? 0;
+    // Sentence punctuation after an address isn't part of it
+    const email = match[1].replace(/\.+$/, "");
+    if (start > last) segments.push({ text: body.slice(last, start) });
+    segments.push({ text: `@${email}`, mention: email });
+    last = start + email.length + 1;
+  }
+  if (last < body.length) segments.push({ text: body.slice(last) });
+  return segments;
+}
+
+export function mentionNotification(data: CommentMentionData): NewNotification {
+  return {
+    id: `comment-mention-${data.comment_id}`,
+    kind: "info",
+    source: "mention",
+    message: `${data.author_name} mentioned you on "${data.workflow_name}": ${data.excerpt}`,
+    href: "/canvas",
+  };
+}
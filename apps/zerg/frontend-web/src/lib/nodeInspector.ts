@@ -0,0 +1,351 @@
+/**
+ * Form model for the canvas node inspector. Node config is free-form JSON
+ * read by the workflow engine (services/node_executors.py); the inspector
+ * edits the keys each executor reads and leaves anything else untouched.
+ */
+
+import { MAX_LOOP_ITERATIONS, loopMaxIterations, parseSwitchCases, switchCases } from "./controlFlow";
+import { parseCron } from "./cron";
+import {
+  GITHUB_ACTION_TOOLS,
+  GITHUB_TOOL_TYPE,
+  githubActionFor,
+  githubParams,
+  githubStaticParams,
+  type GitHubAction,
+} from "./githubNode";
+import { isGitHubRepo } from "./githubTrigger";
+import {
+  formatHeaderLines,
+  headersFromConfig,
+  isHttpMethod,
+  isRequestUrl,
+  parseHeaderLines,
+  type HttpMethod,
+} from "./httpNode";
+import { SLACK_TOOL_NAME, SLACK_TOOL_TYPE, slackParams } from "./slackNode";
+import { formatMapping, parseMapping, subworkflowTarget } from "./subworkflowNode";
+import { fromLocalInput, toLocalInput, waitMode, type WaitMode } from "./waitNode";
+import { formatInputSpecs, inputSpecsFromConfig, parseInputSpecs } from "./workflowInputs";
+
+export type InspectableNodeType =
+  | "agent"
+  | "tool"
+  | "trigger"
+  | "conditional"
+  | "switch"
+  | "loop"
+  | "http"
+  | "wait"
+  | "subworkflow";
+
+export type TriggerType = "manual" | "schedule" | "webhook" | "email";
+
+export const TRIGGER_TYPE_LABELS: Record<TriggerType, string> = {
+  manual: "Manual",
+  schedule: "Schedule",
+  webhook: "Webhook",
+  email: "Email",
+};
+
+// Shelf tool types predate the engine's tool names
+const TOOL_NAME_FOR_TYPE: Record<string, string> = {
+  "http-request": "http_request",
+  "url-fetch": "http_request",
+  [SLACK_TOOL_TYPE]: SLACK_TOOL_NAME,
+  [GITHUB_TOOL_TYPE]: GITHUB_ACTION_TOOLS.comment,
+};
+
+export type NodeConfig = Record<string, unknown>;
+
+export interface NodeDraft {
+  label: string;
+  agentId: string;
+  message: string;
+  toolName: string;
+  // JSON text for static_params
+  params: string;
+  // Send to Slack nodes edit these static_params directly
+  slackChannel: string;
+  slackText: string;
+  // GitHub PR action nodes: the action picks the tool, text is the comment or the labels
+  githubAction: GitHubAction;
+  githubRepo: string;
+  githubNumber: string;
+  githubText: string;
+  triggerType: TriggerType;
+  enabled: boolean;
+  cron: string;
+  // Manual trigger run inputs, "name: type [required] [= default]" per line
+  runInputs: string;
+  // If/Else condition; for loops, the optional continue-while condition
+  condition: string;
+  expression: string;
+  // Switch cases, one per line
+  cases: string;
+  items: string;
+  maxIterations: string;
+  method: HttpMethod;
+  url: string;
+  // HTTP headers, "Name: value" per line
+  headers: string;
+  body: string;
+  // API credential whose auth header is added; "" for none
+  credentialId: string;
+  waitMode: WaitMode;
+  minutes: string;
+  // Wait-until time as a datetime-local value (local time)
+  until: string;
+  event: string;
+  // Optional deadline for event waits; blank waits indefinitely
+  timeoutMinutes: string;
+  workflowId: string;
+  // Call Workflow input and output maps, "name = template" per line
+  inputs: string;
+  outputs: string;
+}
+
+export type DraftErrors = Partial<Record<keyof NodeDraft, string>>;
+
+function asRecord(value: unknown): Record<string, unknown> {
+  return value && typeof value === "object" && !Array.isArray(value) ? (value as Record<string, unknown>) : {};
+}
+
+function triggerMeta(config: NodeConfig) {
+  const trigger = asRecord(config.trigger);
+  const triggerConfig = asRecord(trigger.config);
+  return { trigger, triggerConfig, params: asRecord(triggerConfig.params) };
+}
+
+function isTriggerType(value: unknown): value is TriggerType {
+  return typeof value === "string" && value in TRIGGER_TYPE_LABELS;
+}
+
+export function draftFromNode(label: string, config: NodeConfig): NodeDraft {
+  const { trigger, triggerConfig, params } = triggerMeta(config);
+  const toolType = typeof config.tool_type === "string" ? config.tool_type : "";
+  const staticParams = config.static_params;
+  return {
+    label,
+    agentId: config.agent_id != null ? String(config.agent_id) : "",
+    message: typeof config.message === "string" ? config.message : "",
+    toolName: typeof config.tool_name === "string" ? config.tool_name : (TOOL_NAME_FOR_TYPE[toolType] ?? ""),
+    params: staticParams && typeof staticParams === "object" ? JSON.stringify(staticParams, null, 2) : "{}",
+    slackChannel: slackParams(config).channel,
+    slackText: slackParams(config).text,
+    githubAction: githubParams(config).action,
+    githubRepo: githubParams(config).repo,
+    githubNumber: githubParams(config).number,
+    githubText: githubParams(config).text,
+    triggerType: isTriggerType(trigger.type) ? trigger.type : "manual",
+    enabled: triggerConfig.enabled !== false,
+    cron: typeof params.cron === "string" ? params.cron : "",
+    runInputs: formatInputSpecs(inputSpecsFromConfig(config)),
+    condition: typeof config.condition === "string" ? config.condition : "",
+    expression: typeof config.expression === "string" ? config.expression : "",
+    cases: switchCases(config).join("\n"),
+    items: typeof config.items === "string" ? config.items : "",
+    maxIterations: String(loopMaxIterations(config)),
+    method: isHttpMethod(config.method) ? config.method : "GET",
+    url: typeof config.url === "string" ? config.url : "",
+    headers: formatHeaderLines(headersFromConfig(config)),
+    body: typeof config.body === "string" ? config.body : "",
+    credentialId: config.credential_id != null ? String(config.credential_id) : "",
+    waitMode: waitMode(config),
+    minutes: config.minutes != null ? String(config.minutes) : "",
+    until: toLocalInput(config.until),
+    event: typeof config.event === "string" ? config.event : "",
+    timeoutMinutes: config.timeout_minutes != null ? String(config.timeout_minutes) : "",
+    workflowId: String(subworkflowTarget(config) ?? ""),
+    inputs: formatMapping(config.inputs),
+    outputs: formatMapping(config.outputs),
+  };
+}
+
+export function validateDraft(type: InspectableNodeType, draft: NodeDraft, agentIds: number[]): DraftErrors {
+  const errors: DraftErrors = {};
+  if (!draft.label.trim()) errors.label = "Give the node a label.";
+
+  switch (type) {
+    case "agent":
+      if (!draft.agentId) errors.agentId = "Choose an agent.";
+      else if (!agentIds.includes(Number(draft.agentId))) errors.agentId = "This agent no longer exists.";
+      break;
+    case "tool": {
+      if (draft.toolName === SLACK_TOOL_NAME) {
+        if (!draft.slackChannel.trim()) errors.slackChannel = "Enter a channel, e.g. #alerts.";
+        if (!draft.slackText.trim()) errors.slackText = "Enter the message to send.";
+      }
+      if (githubActionFor(draft.toolName)) {
+        if (!isGitHubRepo(draft.githubRepo)) errors.githubRepo = "Enter the repository as owner/name.";
+        if (!draft.githubNumber.trim()) errors.githubNumber = "Enter the issue or pull request number.";
+        if (!draft.githubText.trim()) {
+          errors.githubText = draft.githubAction === "label" ? "Enter at least one label." : "Enter the comment.";
+        }
+      }
+      if (!draft.toolName.trim()) errors.toolName = "Enter a tool name.";
+      try {
+        const parsed = JSON.parse(draft.params || "{}");
+        if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+          errors.params = "Parameters must be a JSON object.";
+        }
+      } catch (error) {
+        errors.params = `Invalid JSON: ${(error as Error).message}`;
+      }
+      break;
+    }
+    case "trigger":
+      if (draft.triggerType === "schedule") {
+        const result = parseCron(draft.cron);
+        if (!result.ok) errors.cron = result.error;
+      }
+      if (draft.triggerType === "manual") {
+        const inputs = parseInputSpecs(draft.runInputs);
+        if ("error" in inputs) errors.runInputs = inputs.error;
+      }
+      break;
+    case "conditional":
+      if (!draft.condition.trim()) errors.condition = "Enter a condition.";
+      break;
+    case "switch":
+      if (!draft.expression.trim()) errors.expression = "Enter the value to switch on.";
+      break;
+    case "loop": {
+      const max = Number(draft.maxIterations);
+      if (!Number.isInteger(max) || max < 1 || max > MAX_LOOP_ITERATIONS) {
+        errors.maxIterations = `Enter a whole number from 1 to ${MAX_LOOP_ITERATIONS}.`;
+      }
+      break;
+    }
+    case "http": {
+      if (!draft.url.trim()) errors.url = "Enter a URL.";
+      else if (!isRequestUrl(draft.url)) errors.url = "The URL must start with http:// or https://.";
+      const { badLine } = parseHeaderLines(draft.headers);
+      if (badLine !== null) errors.headers = `Line ${badLine}: write each header as Name: value.`;
+      break;
+    }
+    case "wait": {
+      const isMinutes = (value: string) => value.trim() !== "" && Number.isFinite(Number(value)) && Number(value) >= 0;
+      if (draft.waitMode === "duration" && !isMinutes(draft.minutes)) {
+        errors.minutes = "Enter the number of minutes to wait.";
+      }
+      if (draft.waitMode === "until" && !fromLocalInput(draft.until)) errors.until = "Choose when to resume.";
+      if (draft.waitMode === "event") {
+        if (!draft.event.trim()) errors.event = "Name the event to wait for.";
+        if (draft.timeoutMinutes.trim() && !isMinutes(draft.timeoutMinutes)) {
+          errors.timeoutMinutes = "Enter a number of minutes, or leave blank to wait indefinitely.";
+        }
+      }
+      break;
+    }
+    case "subworkflow": {
+      if (!draft.workflowId) errors.workflowId = "Choose a workflow to call.";
+      const inputs = parseMapping(draft.inputs);
+      if ("error" in inputs) errors.inputs = inputs.error;
+      const outputs = parseMapping(draft.outputs);
+      if ("error" in outputs) errors.outputs = outputs.error;
+      break;
+    }
+  }
+  return errors;
+}
+
+/** Config with the draft applied; assumes validateDraft passed. */
+export function applyDraft(type: InspectableNodeType, config: NodeConfig, draft: NodeDraft): NodeConfig {
+  const next: NodeConfig = { ...config, text: draft.label.trim() };
+  switch (type) {
+    case "agent":
+      next.agent_id = Number(draft.agentId);
+      next.message = draft.message;
+      break;
+    case "tool":
+      next.tool_name = draft.toolName.trim();
+      next.static_params = JSON.parse(draft.params || "{}");
+      if (next.tool_name === SLACK_TOOL_NAME) {
+        next.static_params = {
+          ...(next.static_params as NodeConfig),
+          channel: draft.slackChannel.trim(),
+          text: draft.slackText,
+        };
+      }
+      if (githubActionFor(next.tool_name)) {
+        next.tool_name = GITHUB_ACTION_TOOLS[draft.githubAction];
+        const { body: _body, labels: _labels, ...rest } = next.static_params as NodeConfig;
+        next.static_params = {
+          ...rest,
+          ...githubStaticParams({
+            action: draft.githubAction,
+            repo: draft.githubRepo,
+            number: draft.githubNumber,
+            text: draft.githubText,
+          }),
+        };
+      }
+      break;
+    case "trigger": {
+      const { trigger, triggerConfig, params } = triggerMeta(config);
+      const nextParams = { ...params };
+      if (draft.triggerType === "schedule") nextParams.cron = draft.cron.trim();
+      else delete nextParams.cron;
+      // Only runs started by hand are asked for inputs
+      const inputs = draft.triggerType === "manual" ? parseInputSpecs(draft.runInputs) : null;
+      if (inputs && "specs" in inputs && inputs.specs.length > 0) nextParams.inputs = inputs.specs;
+      else delete nextParams.inputs;
+      next.trigger = {
+        ...trigger,
+        type: draft.triggerType,
+        config: { ...triggerConfig, enabled: draft.enabled, params: nextParams },
+      };
+      break;
+    }
+    case "conditional":
+      next.condition = draft.condition.trim();
+      next.condition_type = typeof config.condition_type === "string" ? config.condition_type : "expression";
+      break;
+    case "switch":
+      next.expression = draft.expression.trim();
+      next.cases = parseSwitchCases(draft.cases);
+      break;
+    case "loop":
+      next.items = draft.items.trim();
+      next.max_iterations = Number(draft.maxIterations);
+      if (draft.condition.trim()) next.condition = draft.condition.trim();
+      else delete next.condition;
+      break;
+    case "http":
+      next.method = draft.method;
+      next.url = draft.url.trim();
+      next.headers = parseHeaderLines(draft.headers).headers;
+      next.body = draft.body;
+      if (draft.credentialId) next.credential_id = Number(draft.credentialId);
+      else delete next.credential_id;
+      break;
+    case "wait":
+      // Only the selected mode's settings are kept so the engine never sees a stale deadline
+      delete next.minutes;
+      delete next.until;
+      delete next.event;
+      delete next.timeout_minutes;
+      next.mode = draft.waitMode;
+      if (draft.waitMode === "duration") next.minutes = Number(draft.minutes);
+      if (draft.waitMode === "until") next.until = fromLocalInput(draft.until);
+      if (draft.waitMode === "event") {
+        next.event = draft.event.trim();
+        if (draft.timeoutMinutes.trim()) next.timeout_minutes = Number(draft.timeoutMinutes);
+      }
+      break;
+    case "subworkflow": {
+      const inputs = parseMapping(draft.inputs);
+      const outputs = parseMapping(draft.outputs);
+      next.workflow_id = Number(draft.workflowId);
+      next.inputs = "mapping" in inputs ? inputs.mapping : {};
+      next.outputs = "mapping" in outputs ? outputs.mapping : {};
+      break;
+    }
+  }
+  return next;
+}
+
+export function isDraftDirty(original: NodeDraft, draft: NodeDraft): boolean {
+  return (Object.keys(original) as (keyof NodeDraft)[]).some((key) => original[key] !== draft[key]);
+}
@@ -0,0 +1,56 @@
+/**
+ * Run from a node. Every execution keeps each node's output, so the latest
+ * finished run doubles as a cache: a new run can start at any node whose
+ * inputs all have a cached output, re-running that node and everything
+ * downstream of it while the rest reuse what they produced last time.
+ * Mirrors the server's plan in zerg/services/partial_runs.py.
+ */
+
+import { executionHistoryQueryKey } from "./executionHistory";
+import type { NodeOutputCache } from "../services/api";
+
+type EdgeLike = { source: string; target: string };
+
+// Under the history key, so the invalidations on run start and finish refresh it too
+export const nodeOutputCacheQueryKey = (workflowId: number) =>
+  [...executionHistoryQueryKey(workflowId), "node-cache"] as const;
+
+/** *nodeId* and every node reachable from it. */
+export function downstreamNodeIds(edges: EdgeLike[], nodeId: string): Set<string> {
+  const reached = new Set([nodeId]);
+  const pending = [nodeId];
+  while (pending.length > 0) {
+    const current = pending.pop();
+    for (const edge of edges) {
+      if (edge.source === current && !reached.has(edge.target)) {
+        reached.add(edge.target);
+        pending.push(edge.target);
+      }
+    }
+  }
+  return reached;
+}
+
+/** Nodes outside the re-run part that feed it but have no cached output, sorted. */
+export function uncachedInputs(edges: EdgeLike[], nodeId: string, cached: ReadonlySet<string>): string[] {
+  const rerun = downstreamNodeIds(edges, nodeId);
+  const missing = new Set(
+    edges
+      .filter((edge) => rerun.has(edge.target) && !rerun.has(edge.source) && !cached.has(edge.source))
+      .map((edge) => edge.source)
+  );
+  return [...missing].sort();
+}
+
+/** Why the canvas can't run from *nodeId* right now, or null when it can. */
+export function runFromBlocker(
+  cache: NodeOutputCache | undefined,
+  edges: EdgeLike[],
+  nodeId: string,
+  labelOf: (nodeId: string) => string = (id) => id
+): string | null {
+  if (!cache || cache.execution_id === null) return "Run the whole workflow once first";
+  const missing = uncachedInputs(edges, nodeId, new Set(cache.node_ids));
+  if (missing.length > 0) return `No cached output for ${missing.map(labelOf).join(", ")}`;
+  return null;
+}
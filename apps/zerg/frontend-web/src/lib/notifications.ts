@@ -0,0 +1,124 @@
+// Notification center model: toasts and important live events kept in a
+// capped, newest-first list so they can be reviewed after they disappear.
+
+export type NotificationKind = "success" | "error" | "info";
+export type NotificationSource = "toast" | "run" | "budget" | "mention" | "trash";
+export type NotificationFilter = "all" | "unread" | "errors" | "runs";
+
+export interface AppNotification {
+  id: string;
+  kind: NotificationKind;
+  source: NotificationSource;
+  message: string;
+  createdAt: string;
+  read: boolean;
+  // Where clicking the entry should take the user, if anywhere
+  href?: string;
+  // Offered as an Undo button until used; plain data so it survives reloads
+  undo?: NotificationUndo;
+}
+
+export interface NotificationUndo {
+  kind: "restore-agent" | "restore-workflow";
+  id: number;
+}
+
+export type NewNotification = Omit<AppNotification, "id" | "createdAt" | "read"> & {
+  id?: string;
+  createdAt?: string;
+};
+
+export const MAX_NOTIFICATIONS = 200;
+export const NOTIFICATIONS_STORAGE_KEY = "zerg:notifications";
+
+let sequence = 0;
+
+export function createNotification(input: NewNotification, now: Date = new Date()): AppNotification {
+  sequence += 1;
+  return {
+    ...input,
+    id: input.id ?? `${now.getTime()}-${sequence}`,
+    createdAt: input.createdAt ?? now.toISOString(),
+    read: false,
+  };
+}
+
+export function addNotification(
+  list: AppNotification[],
+  entry: AppNotification,
+  max: number = MAX_NOTIFICATIONS
+): AppNotification[] {
+  // The same toast id can be reported more than once (e.g. loading -> success)
+  const rest = list.filter((existing) => existing.id !== entry.id);
+  return [entry, ...rest].slice(0, max);
+}
+
+export function markRead(list: AppNotification[], id: string): AppNotification[] {
+  return list.map((entry) => (entry.id === id && !entry.read ? { ...entry, read: true } : entry));
+}
+
+export function markAllRead(list: AppNotification[]): AppNotification[] {
+  if (!list.some((entry) => !entry.read)) return list;
+  return list.map((entry) => (entry.read ? entry : { ...entry, read: true }));
+}
+
+/** Drop an entry's undo once it has been used (or can no longer apply). */
+export function withoutUndo(list: AppNotification[], id: string): AppNotification[] {
+  return list.map((entry) => {
+    if (entry.id !== id || !entry.undo) return entry;
+    const { undo: _undo, ...rest } = entry;
+    return rest;
+  });
+}
+
+export function unreadCount(list: AppNotification[]): number {
+  return list.reduce((count, entry) => count + (entry.read ? 0 : 1), 0);
+}
+
+export function filterNotifications(list: AppNotification[], filter: NotificationFilter): AppNotification[] {
+  switch (filter) {
+    case "unread":
+      return list.filter((entry) => !entry.read);
+    case "errors":
+      return list.filter((entry) => entry.kind === "error");
+    case "runs":
+      return list.filter((entry) => entry.source === "run" || entry.source === "budget");
+    default:
+      return list;
+  }
+}
+
+type ToastLike = { id: string; type: string; message: unknown };
+
+// Only plain-text toasts are recorded; loading spinners are not events yet
+export function notificationFromToast(toast: ToastLike): NewNotification | null {
+  if (typeof toast.message !== "string" || !toast.message.trim()) return null;
+  let kind: NotificationKind;
+  switch (toast.type) {
+    case "success":
+      kind = "success";
+      break;
+    case "error":
+      kind = "error";
+      break;
+    case "blank":
+      kind = "info";
+      break;
+    default:
+      return null;
+  }
+  return { id: `toast-${toast.id}`, kind, source: "toast", message: toast.message };
+}
+
+export function loadNotifications(storage: Pick<Storage, "getItem"> | undefined): AppNotification[] {
+  if (!storage) return [];
+  try {
+    const parsed = JSON.parse(storage.getItem(NOTIFICATIONS_STORAGE_KEY) ?? "[]");
+    if (!Array.isArray(parsed)) return [];
+    return parsed
+      .filter((entry): entry is AppNotification => typeof entry?.id === "string" && typeof entry?.message === "string")
+      .slice(0, MAX_NOTIFICATIONS);
+  } catch {
+    return [];
+  }
+}
@@ -0,0 +1,72 @@
+/**
+ * Helpers for the admin ops ticker: the `ops:events` WebSocket topic
+ * streams compact OpsEventData frames (see services/ops_events.py).
+ */
+
+import type { OpsEventData } from "../generated/ws-messages";
+
+export type OpsEventKind = OpsEventData["type"];
+
+export type OpsEventTone = "info" | "success" | "error" | "warning" | "accent" | "neutral";
+
+export const OPS_EVENT_KINDS: readonly { kind: OpsEventKind; label: string; tone: OpsEventTone }[] = [
+  { kind: "run_started", label: "Run started", tone: "info" },
+  { kind: "run_success", label: "Run succeeded", tone: "success" },
+  { kind: "run_failed", label: "Run failed", tone: "error" },
+  { kind: "budget_denied", label: "Budget denied", tone: "warning" },
+  { kind: "agent_created", label: "Agent created", tone: "accent" },
+  { kind: "agent_updated", label: "Agent updated", tone: "accent" },
+  { kind: "thread_message_created", label: "Thread message", tone: "neutral" },
+];
+
+// Oldest entries fall off the end once the ticker holds this many
+export const TICKER_LIMIT = 100;
+
+export interface TickerEntry {
+  id: number;
+  receivedAt: string;
+  event: OpsEventData;
+}
+
+export function opsEventTone(kind: OpsEventKind): OpsEventTone {
+  return OPS_EVENT_KINDS.find((entry) => entry.kind === kind)?.tone ?? "neutral";
+}
+
+export function describeOpsEvent(event: OpsEventData): string {
+  const agent = event.agent_name ?? (event.agent_id !== undefined ? `Agent #${event.agent_id}` : "Agent");
+  switch (event.type) {
+    case "run_started":
+      return `${agent} started run #${event.run_id}`;
+    case "run_success":
+      return `${agent} finished run #${event.run_id}${event.duration_ms ? ` in ${event.duration_ms}ms` : ""}`;
+    case "run_failed":
+      return `${agent} failed run #${event.run_id}${event.error ? `: ${event.error}` : ""}`;
+    case "agent_created":
+      return `${agent} created`;
+    case "agent_updated":
+      return `${agent} updated${event.status ? ` (${event.status})` : ""}`;
+    case "thread_message_created":
+      return `New message in thread #${event.thread_id}`;
+    case "budget_denied": {
+      const who = event.scope === "global" ? "Global budget" : `Budget for ${event.user_email ?? "user"}`;
+      return `${who} exhausted${event.percent !== undefined ? ` (${event.percent.toFixed(0)}%)` : ""}`;
+    }
+    default:
+      return event.type;
+  }
+}
+
+/** Where clicking an entry should go, or null when there's nothing to open. */
+export function opsEventLink(event: OpsEventData): string | null {
+  if (event.agent_id === undefined) return null;
+  const params = new URLSearchParams({ agent: String(event.agent_id) });
+  if (event.run_id !== undefined && event.type.startsWith("run_")) {
+    params.set("run", String(event.run_id));
+  }
+  return `/dashboard?${params}`;
+}
+
+/** Newest first, capped at ``limit``. */
+export function prependEntries(entries: TickerEntry[], incoming: TickerEntry[], limit = TICKER_LIMIT): TickerEntry[] {
+  return [...incoming, ...entries].slice(0, limit);
+}
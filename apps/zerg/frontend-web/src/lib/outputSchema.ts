@@ -0,0 +1,96 @@
+/**
+ * Expected JSON output of an agent. The schema lives in ``agent.config.output_schema``
+ * and is validated server-side (services/schema_validation.py), which also checks
+ * each finished run and stores the parsed ``output`` plus any ``schema_errors``.
+ */
+
+import type { AgentRun } from "../services/api";
+
+// Key in agent.config; mirrors AGENT_OUTPUT_SCHEMA_KEY in services/schema_validation.py
+export const AGENT_OUTPUT_SCHEMA_KEY = "output_schema";
+
+const SCHEMA_TYPES = ["object", "array", "string", "number", "integer", "boolean", "null"];
+
+export const EXAMPLE_OUTPUT_SCHEMA = JSON.stringify(
+  {
+    type: "object",
+    properties: {
+      summary: { type: "string" },
+      items: { type: "array", items: { type: "object", properties: { name: { type: "string" } } } },
+    },
+    required: ["summary"],
+  },
+  null,
+  2
+);
+
+export function outputSchemaFromConfig(config: Record<string, unknown> | null | undefined): string {
+  const schema = config?.[AGENT_OUTPUT_SCHEMA_KEY];
+  return schema && typeof schema === "object" ? JSON.stringify(schema, null, 2) : "";
+}
+
+export type SchemaDraftResult = { schema: Record<string, unknown> | null; error: string | null };
+
+/**
+ * Parse the editor text. Blank means "no schema". Only the obvious mistakes are
+ * caught here; the backend runs the full JSON Schema meta-validation on save.
+ */
+export function parseSchemaDraft(text: string): SchemaDraftResult {
+  if (!text.trim()) return { schema: null, error: null };
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(text);
+  } catch (error) {
+    return { schema: null, error: `Invalid JSON: ${(error as Error).message}` };
+  }
+  if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+    return { schema: null, error: "The schema must be a JSON object" };
+  }
+  const schema = parsed as Record<string, unknown>;
+  const types = Array.isArray(schema.type) ? schema.type : schema.type === undefined ? [] : [schema.type];
+  const unknown = types.find((type) => typeof type !== "string" || !SCHEMA_TYPES.includes(type));
+  if (unknown !== undefined) {
+    return { schema: null, error: `Unknown type ${JSON.stringify(unknown)}` };
+  }
+  return { schema, error: null };
+}
+
+export function withOutputSchema(
+  config: Record<string, unknown> | null | undefined,
+  schema: Record<string, unknown> | null
+): Record<string, unknown> {
+  const next = { ...(config ?? {}) };
+  if (schema) next[AGENT_OUTPUT_SCHEMA_KEY] = schema;
+  else delete next[AGENT_OUTPUT_SCHEMA_KEY];
+  return next;
+}
+
+export function hasSchemaViolations(run: Pick<AgentRun, "schema_errors">): boolean {
+  return (run.schema_errors?.length ?? 0) > 0;
+}
+
+export function schemaViolationTitle(run: Pick<AgentRun, "schema_errors">): string {
+  return ["Output doesn't match the schema:", ...(run.schema_errors ?? [])].join("\n");
+}
+
+export function isPlainObject(value: unknown): value is Record<string, unknown> {
+  return value !== null && typeof value === "object" && !Array.isArray(value);
+}
+
+/** Arrays of objects render as a table: columns are the union of keys in first-seen order. */
+export function tableColumns(value: unknown): string[] | null {
+  if (!Array.isArray(value) || value.length === 0 || !value.every(isPlainObject)) return null;
+  const columns: string[] = [];
+  for (const row of value) {
+    for (const key of Object.keys(row)) {
+      if (!columns.includes(key)) columns.push(key);
+    }
+  }
+  return columns;
+}
+
+export function formatScalar(value: unknown): string {
+  if (value === null || value === undefined) return "—";
+  if (typeof value === "string") return value;
+  return JSON.stringify(value);
+}
@@ -0,0 +1,146 @@
+/**
+ * Opt-in performance sampling for the perf HUD. Timings are kept in small
+ * rolling windows per metric and summarised as p50/p95; nothing is recorded
+ * unless the HUD is enabled (localStorage "zerg:perf-hud" = "1", toggled by
+ * the global shortcut).
+ */
+
+export type PerfMetric = "frame" | "reducer" | "ws" | "api";
+
+export interface MetricSummary {
+  count: number;
+  p50: number;
+  p95: number;
+  max: number;
+}
+
+export const PERF_HUD_STORAGE_KEY = "zerg:perf-hud";
+export const PERF_WINDOW_SIZE = 120;
+// One frame at 60 Hz
+export const FRAME_BUDGET_MS = 1000 / 60;
+
+export const PERF_METRIC_LABELS: Record<PerfMetric, string> = {
+  frame: "Frame",
+  reducer: "Reducer",
+  ws: "WS handling",
+  api: "API round-trip",
+};
+
+// Nearest-rank percentile of an ascending list
+export function percentile(sorted: number[], p: number): number {
+  if (sorted.length === 0) return 0;
+  return sorted[Math.min(sorted.length - 1, Math.floor(p * sorted.length))];
+}
+
+/** Fixed-size window of the most recent samples. */
+export class RollingSamples {
+  private samples: number[] = [];
+
+  constructor(private readonly capacity: number = PERF_WINDOW_SIZE) {}
+
+  add(ms: number) {
+    this.samples.push(ms);
+    if (this.samples.length > this.capacity) this.samples.shift();
+  }
+
+  summary(): MetricSummary {
+    const sorted = [...this.samples].sort((a, b) => a - b);
+    return {
+      count: sorted.length,
+      p50: percentile(sorted, 0.5),
+      p95: percentile(sorted, 0.95),
+      max: sorted[sorted.length - 1] ?? 0,
+    };
+  }
+
+  clear() {
+    this.samples = [];
+  }
+}
+
+const windows: Record<PerfMetric, RollingSamples> = {
+  frame: new RollingSamples(),
+  reducer: new RollingSamples(),
+  ws: new RollingSamples(),
+  api: new RollingSamples(),
+};
+let overBudgetFrames = 0;
+let enabled = readEnabled();
+
+function readEnabled(): boolean {
+  try {
+    return typeof window !== "undefined" && window.localStorage.getItem(PERF_HUD_STORAGE_KEY) === "1";
+  } catch {
+    return false;
+  }
+}
+
+export function isPerfHudEnabled(): boolean {
+  return enabled;
+}
+
+export function setPerfHudEnabled(next: boolean) {
+  enabled = next;
+  try {
+    if (next) {
+      window.localStorage.setItem(PERF_HUD_STORAGE_KEY, "1");
+    } else {
+      window.localStorage.removeItem(PERF_HUD_STORAGE_KEY);
+    }
+  } catch {
+    // Private mode; the toggle still holds for this page load
+  }
+  if (!next) resetPerfMetrics();
+}
+
+export function recordTiming(metric: PerfMetric, ms: number) {
+  if (!enabled) return;
+  windows[metric].add(ms);
+  if (metric === "frame" && ms > FRAME_BUDGET_MS) overBudgetFrames += 1;
+}
+
+/** Time a synchronous call, e.g. a reducer or a message handler. */
+export function timed<T>(metric: PerfMetric, fn: () => T): T {
+  if (!enabled) return fn();
+  const start = performance.now();
+  try {
+    return fn();
+  } finally {
+    recordTiming(metric, performance.now() - start);
+  }
+}
+
+/** Wrap a useReducer reducer so each dispatch is timed. */
+export function measuredReducer<S, A>(reducer: (state: S, action: A) => S): (state: S, action: A) => S {
+  return (state, action) => timed("reducer", () => reducer(state, action));
+}
+
+export function perfSummary(): Record<PerfMetric, MetricSummary> & { overBudgetFrames: number } {
+  return {
+    frame: windows.frame.summary(),
+    reducer: windows.reducer.summary(),
+    ws: windows.ws.summary(),
+    api: windows.api.summary(),
+    overBudgetFrames,
+  };
+}
+
+export function resetPerfMetrics() {
+  Object.values(windows).forEach((samples) => samples.clear());
+  overBudgetFrames = 0;
+}
+
+/**
+ * Record the time between animation frames, i.e. how long each tick's
+ * script, layout and paint took. Returns a stop function.
+ */
+export function startFrameSampler(): () => void {
+  let last = performance.now();
+  let handle = requestAnimationFrame(function tick(now) {
+    // Background tabs stop ticking; the gap on return isn't a slow frame
+    if (now - last < 1000) recordTiming("frame", now - last);
+    last = now;
+    handle = requestAnimationFrame(tick);
+  });
+  return () => cancelAnimationFrame(handle);
+}
@@ -0,0 +1,144 @@
+/**
+ * Debounced, per-slice persistence. Each kind of state (the workflow canvas,
+ * profile prefs, local UI state) is its own slice with its own writer, so a
+ * burst of edits to one slice turns into a single write of that slice only,
+ * and values identical to the last write are skipped. Pending writes are
+ * flushed when the page is hidden or unloaded (with `keepalive` so the
+ * request survives the page) and when a slice's owner unmounts.
+ */
+
+export interface WriteOptions {
+  // The page is going away: use fetch keepalive / don't wait for a response
+  keepalive: boolean;
+}
+
+export type SliceWriter<T> = (value: T, options: WriteOptions) => unknown;
+
+export interface PersistenceOptions {
+  // Quiet period after the last change before writing
+  delayMs?: number;
+  // Upper bound on how long a steady stream of edits can postpone a write
+  maxWaitMs?: number;
+}
+
+interface Slice {
+  writer: SliceWriter<unknown>;
+  lastWritten: string | null;
+  pending: { value: unknown; serialized: string } | null;
+  timer: ReturnType<typeof setTimeout> | null;
+  firstChangeAt: number | null;
+}
+
+export class PersistenceQueue {
+  private slices = new Map<string, Slice>();
+  private readonly delayMs: number;
+  private readonly maxWaitMs: number;
+
+  constructor({ delayMs = 800, maxWaitMs = 5000 }: PersistenceOptions = {}) {
+    this.delayMs = delayMs;
+    this.maxWaitMs = maxWaitMs;
+  }
+
+  /** Attach the writer for *key*. Returns an unregister function that flushes first. */
+  register<T>(key: string, writer: SliceWriter<T>): () => void {
+    const existing = this.slices.get(key);
+    const slice: Slice = existing
+      ? { ...existing, writer: writer as SliceWriter<unknown> }
+      : { writer: writer as SliceWriter<unknown>, lastWritten: null, pending: null, timer: null, firstChangeAt: null };
+    this.slices.set(key, slice);
+    return () => {
+      if (this.slices.get(key) !== slice) return;
+      this.flushSlice(key, { keepalive: false });
+      this.slices.delete(key);
+    };
+  }
+
+  /** Record *value* as already persisted, e.g. what was just loaded from the server. */
+  markPersisted(key: string, value: unknown) {
+    const slice = this.slices.get(key);
+    if (!slice) return;
+    slice.lastWritten = JSON.stringify(value);
+    if (slice.pending?.serialized === slice.lastWritten) this.cancel(slice);
+  }
+
+  schedule(key: string, value: unknown) {
+    const slice = this.slices.get(key);
+    if (!slice) return;
+    const serialized = JSON.stringify(value);
+    if (serialized === slice.lastWritten) {
+      // Edited back to what's saved
+      this.cancel(slice);
+      return;
+    }
+    slice.pending = { value, serialized };
+
+    const now = Date.now();
+    slice.firstChangeAt ??= now;
+    const wait = Math.max(0, Math.min(this.delayMs, slice.firstChangeAt + this.maxWaitMs - now));
+    if (slice.timer) clearTimeout(slice.timer);
+    slice.timer = setTimeout(() => this.flushSlice(key, { keepalive: false }), wait);
+  }
+
+  hasPending(key?: string): boolean {
+    if (key) return !!this.slices.get(key)?.pending;
+    return [...this.slices.values()].some((slice) => slice.pending);
+  }
+
+  /** Write pending changes now: one slice, or every slice when *key* is omitted. */
+  flush(key?: string, options: WriteOptions = { keepalive: false }) {
+    const keys = key ? [key] : [...this.slices.keys()];
+    keys.forEach((sliceKey) => this.flushSlice(sliceKey, options));
+  }
+
+  private flushSlice(key: string, options: WriteOptions) {
+    const slice = this.slices.get(key);
+    if (!slice?.pending) return;
+    const { value, serialized } = slice.pending;
+    const previous = slice.lastWritten;
+    this.cancel(slice);
+    slice.lastWritten = serialized;
+    const result = slice.writer(value, options);
+    if (result instanceof Promise) {
+      // A failed write mustn't make the next identical change look already saved
+      result.catch(() => {
+        if (slice.lastWritten === serialized) slice.lastWritten = previous;
+      });
+    }
+  }
+
+  private cancel(slice: Slice) {
+    if (slice.timer) clearTimeout(slice.timer);
+    slice.timer = null;
+    slice.pending = null;
+    slice.firstChangeAt = null;
+  }
+}
+
+export const persistence = new PersistenceQueue();
+
+/** Flush pending writes when the tab is hidden or closed. Returns a cleanup. */
+export function installPersistenceFlush(queue: PersistenceQueue = persistence, target: Window = window): () => void {
+  const flushOnExit = () => queue.flush(undefined, { keepalive: true });
+  const onVisibility = () => {
+    if (target.document.visibilityState === "hidden") flushOnExit();
+  };
+  target.addEventListener("beforeunload", flushOnExit);
+  target.addEventListener("pagehide", flushOnExit);
+  target.document.addEventListener("visibilitychange", onVisibility);
+  return () => {
+    target.removeEventListener("beforeunload", flushOnExit);
+    target.removeEventListener("pagehide", flushOnExit);
+    target.document.removeEventListener("visibilitychange", onVisibility);
+  };
+}
+
+/** Writer for slices kept in localStorage. */
+export function localStorageWriter(storageKey: string): SliceWriter<unknown> {
+  return (value) => {
+    try {
+      window.localStorage.setItem(storageKey, typeof value === "string" ? value : JSON.stringify(value));
+    } catch (error) {
+      console.warn(`Failed to persist ${storageKey}:`, error);
+    }
+  };
+}
@@ -0,0 +1,154 @@
+/**
+ * The settings page's view of user.prefs. Each preference is stored under its
+ * own key (owned by the module that uses it: theme.ts, datetime.ts, …); an
+ * unset key falls back to the workspace default an admin picked, then to the
+ * built-in default. Known keys are validated again server-side
+ * (services/preferences.py).
+ */
+
+import { AUTO_TIMEZONE, TIME_DISPLAY_PREF, TIMEZONE_PREF, isValidTimeZone } from "./datetime";
+import {
+  DESKTOP_NOTIFICATIONS_PREF,
+  QUIET_HOURS_PREF,
+  desktopNotificationPrefs,
+  type QuietHours,
+} from "./desktopNotifications";
+import {
+  SHORTCUTS_PREF,
+  bindingOverrides,
+  bindingsFromPrefs,
+  findConflicts,
+  type ShortcutBindings,
+} from "./shortcuts";
+import { THEME_PREF } from "./theme";
+import type { WorkspaceDefaults } from "../services/api";
+
+// Keys in user.prefs
+export const DEFAULT_MODEL_PREF = "default_model";
+export const DASHBOARD_SCOPE_PREF = "dashboard_scope";
+export const DASHBOARD_SORT_PREF = "dashboard_sort";
+
+export const WORKSPACE_DEFAULTS_QUERY_KEY = ["workspace-defaults"] as const;
+
+export const DASHBOARD_SORT_KEYS = ["name", "status", "created_at", "last_run", "next_run", "success"] as const;
+export type DashboardSortKey = (typeof DASHBOARD_SORT_KEYS)[number];
+
+export const DASHBOARD_SORT_LABELS: Record<DashboardSortKey, string> = {
+  name: "Name",
+  status: "Status",
+  created_at: "Created",
+  last_run: "Last run",
+  next_run: "Next run",
+  success: "Success rate",
+};
+
+export const DEFAULT_QUIET_HOURS: QuietHours = { start: "22:00", end: "07:00" };
+
+type Prefs = Record<string, unknown> | null | undefined;
+
+/** Stored prefs over the workspace defaults; null or missing values fall through. */
+export function effectivePrefs(prefs: Prefs, defaults: WorkspaceDefaults | null | undefined): Record<string, unknown> {
+  const merged: Record<string, unknown> = {};
+  for (const source of [defaults, prefs]) {
+    for (const [key, value] of Object.entries(source ?? {})) {
+      if (value !== null && value !== undefined) merged[key] = value;
+    }
+  }
+  return merged;
+}
+
+export function dashboardSortFromPrefs(prefs: Prefs): { key: DashboardSortKey; ascending: boolean } | null {
+  const value = prefs?.[DASHBOARD_SORT_PREF] as { key?: unknown; ascending?: unknown } | null | undefined;
+  if (!value || !(DASHBOARD_SORT_KEYS as readonly unknown[]).includes(value.key)) return null;
+  return { key: value.key as DashboardSortKey, ascending: value.ascending !== false };
+}
+
+/**
+ * Form state for the settings page. Empty strings mean "not set": the
+ * workspace default (or the built-in one) applies.
+ */
+export interface PreferencesDraft {
+  theme: string;
+  timeZone: string;
+  timeDisplay: string;
+  desktopEnabled: boolean;
+  quietHoursEnabled: boolean;
+  quietHours: QuietHours;
+  defaultModel: string;
+  dashboardScope: string;
+  dashboardSort: string;
+  dashboardAscending: boolean;
+  shortcuts: ShortcutBindings;
+}
+
+export type PreferencesDraftErrors = Partial<Record<keyof PreferencesDraft, string>>;
+
+const asString = (value: unknown) => (typeof value === "string" ? value : "");
+
+export function draftFromPrefs(prefs: Prefs): PreferencesDraft {
+  const desktop = desktopNotificationPrefs(prefs);
+  const sort = dashboardSortFromPrefs(prefs);
+  return {
+    theme: asString(prefs?.[THEME_PREF]),
+    timeZone: asString(prefs?.[TIMEZONE_PREF]),
+    timeDisplay: asString(prefs?.[TIME_DISPLAY_PREF]),
+    desktopEnabled: desktop.enabled,
+    quietHoursEnabled: desktop.quietHours !== null,
+    quietHours: desktop.quietHours ?? DEFAULT_QUIET_HOURS,
+    defaultModel: asString(prefs?.[DEFAULT_MODEL_PREF]),
+    dashboardScope: asString(prefs?.[DASHBOARD_SCOPE_PREF]),
+    dashboardSort: sort?.key ?? "",
+    dashboardAscending: sort?.ascending ?? true,
+    shortcuts: bindingsFromPrefs(prefs),
+  };
+}
+
+export function validatePreferencesDraft(draft: PreferencesDraft, modelIds: readonly string[]): PreferencesDraftErrors {
+  const errors: PreferencesDraftErrors = {};
+  if (draft.timeZone && draft.timeZone !== AUTO_TIMEZONE && !isValidTimeZone(draft.timeZone)) {
+    errors.timeZone = `Unknown timezone "${draft.timeZone}"`;
+  }
+  if (draft.quietHoursEnabled && draft.quietHours.start === draft.quietHours.end) {
+    errors.quietHours = "Quiet hours need different start and end times";
+  }
+  if (draft.defaultModel && modelIds.length > 0 && !modelIds.includes(draft.defaultModel)) {
+    errors.defaultModel = `${draft.defaultModel} is no longer available`;
+  }
+  if (Object.keys(findConflicts(draft.shortcuts)).length > 0) {
+    errors.shortcuts = "Two shortcuts share the same keys. Rebind one of them before saving.";
+  }
+  return errors;
+}
+
+const orNull = (value: string) => value || null;
+
+const sortFromDraft = (draft: PreferencesDraft) =>
+  draft.dashboardSort ? { key: draft.dashboardSort, ascending: draft.dashboardAscending } : null;
+
+/** The prefs to store: *stored* with every settings-page key replaced from *draft*. */
+export function prefsFromDraft(stored: Prefs, draft: PreferencesDraft): Record<string, unknown> {
+  return {
+    ...(stored ?? {}),
+    [THEME_PREF]: orNull(draft.theme),
+    [TIMEZONE_PREF]: orNull(draft.timeZone),
+    [TIME_DISPLAY_PREF]: orNull(draft.timeDisplay),
+    [DESKTOP_NOTIFICATIONS_PREF]: draft.desktopEnabled,
+    [QUIET_HOURS_PREF]: draft.quietHoursEnabled ? draft.quietHours : null,
+    [DEFAULT_MODEL_PREF]: orNull(draft.defaultModel),
+    [DASHBOARD_SCOPE_PREF]: orNull(draft.dashboardScope),
+    [DASHBOARD_SORT_PREF]: sortFromDraft(draft),
+    [SHORTCUTS_PREF]: bindingOverrides(draft.shortcuts),
+  };
+}
+
+/** Workspace defaults from the admin section's draft (same field names as the user draft). */
+export function workspaceDefaultsFromDraft(draft: PreferencesDraft): WorkspaceDefaults {
+  return {
+    theme: orNull(draft.theme),
+    timezone: orNull(draft.timeZone),
+    time_display: orNull(draft.timeDisplay),
+    default_model: orNull(draft.defaultModel),
+    dashboard_scope: orNull(draft.dashboardScope),
+    dashboard_sort: sortFromDraft(draft),
+  };
+}
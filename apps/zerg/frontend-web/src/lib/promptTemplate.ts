@@ -0,0 +1,43 @@
+/**
+ * ``{{variable}}`` placeholders in agent system/task instructions.
+ *
+ * Mirrors backend ``zerg/prompts/variables.py`` so the config drawer can
+ * preview exactly what the agent will see: defined placeholders are
+ * substituted, undefined ones are left as-is.
+ */
+
+export type PromptVariables = Record<string, string>;
+
+const VARIABLE_PATTERN = /\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}/g;
+const NAME_PATTERN = /^[A-Za-z_][A-Za-z0-9_]*$/;
+
+export function isValidVariableName(name: string): boolean {
+  return NAME_PATTERN.test(name);
+}
+
+// Distinct variable names referenced in *text*, in order of first use
+export function findTemplateVariables(text: string | null | undefined): string[] {
+  if (!text) return [];
+  const names = new Set<string>();
+  for (const match of text.matchAll(VARIABLE_PATTERN)) {
+    names.add(match[1]);
+  }
+  return [...names];
+}
+
+export function renderPromptTemplate(text: string | null | undefined, variables: PromptVariables | null | undefined): string {
+  if (!text) return "";
+  if (!variables) return text;
+  return text.replace(VARIABLE_PATTERN, (placeholder, name: string) =>
+    Object.prototype.hasOwnProperty.call(variables, name) ? variables[name] : placeholder
+  );
+}
+
+export function undefinedTemplateVariables(
+  texts: Array<string | null | undefined>,
+  variables: PromptVariables | null | undefined
+): string[] {
+  const defined = variables ?? {};
+  const referenced = new Set(texts.flatMap((text) => findTemplateVariables(text)));
+  return [...referenced].filter((name) => !Object.prototype.hasOwnProperty.call(defined, name));
+}
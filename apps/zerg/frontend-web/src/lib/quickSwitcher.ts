@@ -0,0 +1,164 @@
+/**
+ * Quick switcher (Ctrl+P): fuzzy-jump to an agent's chat or settings, or to a
+ * workflow's canvas. Built to stay well inside one frame over thousands of
+ * names: the index lowercases every name once, scoring is a single pass over
+ * each name's char codes, and only the best few results are kept and sorted.
+ * Match positions for highlighting are computed for those alone. ``#word``
+ * in the query keeps only entries with a tag starting with that word.
+ */
+
+export type QuickSwitchKind = "agent" | "workflow";
+export type QuickSwitchTarget = "chat" | "config" | "canvas";
+
+export interface QuickSwitchEntry {
+  kind: QuickSwitchKind;
+  id: number;
+  name: string;
+  // Lowercased name, precomputed so typing never re-lowercases the index
+  key: string;
+  tags: string[];
+  // Lowercased tags, for the same reason
+  tagKeys: string[];
+}
+
+export interface QuickSwitchMatch {
+  entry: QuickSwitchEntry;
+  score: number;
+}
+
+export const QUICK_SWITCH_LIMIT = 50;
+
+export const QUICK_SWITCH_TARGET_LABELS: Record<QuickSwitchTarget, string> = {
+  chat: "Chat",
+  config: "Settings",
+  canvas: "Canvas",
+};
+
+type Named = { id: number; name: string; tags?: readonly string[] | null };
+
+export function buildQuickSwitchIndex(agents: readonly Named[], workflows: readonly Named[]): QuickSwitchEntry[] {
+  const entry = (kind: QuickSwitchKind, item: Named): QuickSwitchEntry => ({
+    kind,
+    id: item.id,
+    name: item.name,
+    key: item.name.toLowerCase(),
+    tags: [...(item.tags ?? [])],
+    tagKeys: (item.tags ?? []).map((tag) => tag.toLowerCase()),
+  });
+  return [...agents.map((agent) => entry("agent", agent)), ...workflows.map((wf) => entry("workflow", wf))];
+}
+
+function isBoundary(key: string, index: number): boolean {
+  if (index === 0) return true;
+  const previous = key.charCodeAt(index - 1);
+  // space - _ . / :
+  return previous === 32 || previous === 45 || previous === 95 || previous === 46 || previous === 47 || previous === 58;
+}
+
+/**
+ * How well *query* (already lowercased) matches *key* as a subsequence, or -1
+ * when it doesn't. Consecutive characters and word starts score higher; gaps
+ * and long names cost a little.
+ */
+export function fuzzyScore(query: string, key: string): number {
+  if (query.length === 0) return 0;
+  if (query.length > key.length) return -1;
+  let score = 0;
+  let keyIndex = 0;
+  let previousMatch = -2;
+  for (let queryIndex = 0; queryIndex < query.length; queryIndex++) {
+    const code = query.charCodeAt(queryIndex);
+    while (keyIndex < key.length && key.charCodeAt(keyIndex) !== code) keyIndex++;
+    if (keyIndex === key.length) return -1;
+    if (keyIndex === previousMatch + 1) {
+      score += 5;
+    } else if (previousMatch >= 0) {
+      score -= Math.min(keyIndex - previousMatch - 1, 3);
+    }
+    if (isBoundary(key, keyIndex)) score += keyIndex === 0 ? 8 : 6;
+    score += 1;
+    previousMatch = keyIndex;
+    keyIndex++;
+  }
+  return score - key.length * 0.01;
+}
+
+/** Indices of *key* matched by a normalized *query*, for highlighting a shown result. */
+export function matchPositions(query: string, key: string): number[] {
+  const positions: number[] = [];
+  let keyIndex = 0;
+  for (let queryIndex = 0; queryIndex < query.length && keyIndex < key.length; queryIndex++) {
+    const code = query.charCodeAt(queryIndex);
+    while (keyIndex < key.length && key.charCodeAt(keyIndex) !== code) keyIndex++;
+    if (keyIndex < key.length) positions.push(keyIndex++);
+  }
+  return positions.length === query.length ? positions : [];
+}
+
+export function normalizeQuickSwitchQuery(query: string): string {
+  return query.trim().toLowerCase().replace(/\s+/g, " ");
+}
+
+/** The ``#tag`` filters in *query* (lowercased, without the #) and the name query left over. */
+export function splitTagQuery(query: string): { text: string; tags: string[] } {
+  const tags: string[] = [];
+  const rest: string[] = [];
+  for (const word of query.split(/\s+/)) {
+    if (word.startsWith("#") && word.length > 1) tags.push(word.slice(1).toLowerCase());
+    else rest.push(word);
+  }
+  return { text: rest.join(" "), tags };
+}
+
+function hasTagsStartingWith(entry: QuickSwitchEntry, tags: readonly string[]): boolean {
+  return tags.every((tag) => entry.tagKeys.some((key) => key.startsWith(tag)));
+}
+
+function ranksBefore(a: QuickSwitchMatch, b: QuickSwitchMatch): boolean {
+  if (a.score !== b.score) return a.score > b.score;
+  return a.entry.key < b.entry.key;
+}
+
+/**
+ * The best *limit* matches, best first. An empty query lists the index (or
+ * the entries carrying the ``#tag`` filters) in order. Keeps a small sorted
+ * window instead of scoring, sorting and slicing the whole index.
+ */
+export function searchQuickSwitch(
+  index: readonly QuickSwitchEntry[],
+  query: string,
+  limit: number = QUICK_SWITCH_LIMIT
+): QuickSwitchMatch[] {
+  const { text, tags } = splitTagQuery(query);
+  const needle = normalizeQuickSwitchQuery(text);
+  const candidates = tags.length > 0 ? index.filter((entry) => hasTagsStartingWith(entry, tags)) : index;
+  if (!needle) return candidates.slice(0, limit).map((entry) => ({ entry, score: 0 }));
+
+  const best: QuickSwitchMatch[] = [];
+  for (const entry of candidates) {
+    const score = fuzzyScore(needle, entry.key);
+    if (score < 0) continue;
+    const match = { entry, score };
+    if (best.length === limit && !ranksBefore(match, best[best.length - 1])) continue;
+    let at = best.length;
+    while (at > 0 && ranksBefore(match, best[at - 1])) at--;
+    best.splice(at, 0, match);
+    if (best.length > limit) best.pop();
+  }
+  return best;
+}
+
+export function quickSwitchTargets(entry: QuickSwitchEntry): QuickSwitchTarget[] {
+  return entry.kind === "agent" ? ["chat", "config"] : ["canvas"];
+}
+
+export function quickSwitchPath(entry: QuickSwitchEntry, target: QuickSwitchTarget): string {
+  switch (target) {
+    case "chat":
+      return `/agent/${entry.id}/thread/?name=${encodeURIComponent(entry.name)}`;
+    case "config":
+      return `/dashboard?agent=${entry.id}&settings=1`;
+    case "canvas":
+      return `/canvas?workflow=${entry.id}`;
+  }
+}
@@ -0,0 +1,73 @@
+/**
+ * Raw JSON editor for an agent: an escape hatch for fields the settings
+ * forms don't expose yet. The editor shows every field of the agent contract
+ * (``AgentUpdate``); only the fields that changed are sent, with the values
+ * they had when the editor loaded them so the server can refuse an edit made
+ * from a stale copy (``PATCH /agents/{id}``). The contract itself is checked
+ * server-side by services/schema_validation.py.
+ */
+
+import type { Agent, AgentPatch } from "../services/api";
+
+// Fields of AgentUpdate, in the order the editor lists them
+export const AGENT_CONTRACT_FIELDS = [
+  "name",
+  "description",
+  "tags",
+  "model",
+  "status",
+  "schedule",
+  "system_instructions",
+  "task_instructions",
+  "prompt_variables",
+  "allowed_tools",
+  "config",
+  "last_error",
+] as const;
+
+export type RawDraftResult = { value: Record<string, unknown> | null; error: string | null };
+
+/** The agent's contract fields as the editor text; missing fields show as null. */
+export function agentContractJson(agent: Agent): string {
+  const source = agent as unknown as Record<string, unknown>;
+  const fields = Object.fromEntries(AGENT_CONTRACT_FIELDS.map((field) => [field, source[field] ?? null]));
+  return JSON.stringify(fields, null, 2);
+}
+
+export function parseRawAgentDraft(text: string): RawDraftResult {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(text);
+  } catch (error) {
+    return { value: null, error: `Invalid JSON: ${(error as Error).message}` };
+  }
+  if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+    return { value: null, error: "The agent must be a JSON object" };
+  }
+  return { value: parsed as Record<string, unknown>, error: null };
+}
+
+/** JSON with object keys sorted, so reordering keys isn't a change. */
+export function stableStringify(value: unknown): string {
+  if (Array.isArray(value)) return `[${value.map(stableStringify).join(",")}]`;
+  if (value && typeof value === "object") {
+    const entries = Object.entries(value as Record<string, unknown>).sort(([a], [b]) => a.localeCompare(b));
+    return `{${entries.map(([key, item]) => `${JSON.stringify(key)}:${stableStringify(item)}`).join(",")}}`;
+  }
+  return JSON.stringify(value) ?? "null";
+}
+
+/**
+ * The PATCH body for *edited* against *original*: changed or added keys with
+ * their original values as the base. Removing a key leaves the field as is.
+ */
+export function agentEditPatch(original: Record<string, unknown>, edited: Record<string, unknown>): AgentPatch {
+  const changes: Record<string, unknown> = {};
+  const base: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(edited)) {
+    if (stableStringify(value) === stableStringify(original[key] ?? null)) continue;
+    changes[key] = value;
+    if (key in original) base[key] = original[key];
+  }
+  return { changes, base };
+}
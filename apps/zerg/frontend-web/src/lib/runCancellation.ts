@@ -0,0 +1,23 @@
+/**
+ * Run cancellation. ``POST /runs/{id}/cancel`` answers with the run in
+ * ``cancelling`` while the in-flight task is interrupted; a ``run_update``
+ * with ``cancelled`` follows once the agent has stopped.
+ */
+
+import type { AgentRun } from "../services/api";
+
+type RunLike = Pick<AgentRun, "id" | "status">;
+
+export function isRunCancellable(run: Pick<AgentRun, "status"> | null | undefined): boolean {
+  return run?.status === "queued" || run?.status === "running";
+}
+
+/** The run to cancel: the running one, else the newest queued run; runs arrive newest first. */
+export function activeRun<T extends RunLike>(runs: T[] | undefined): T | null {
+  return runs?.find((run) => run.status === "running") ?? runs?.find(isRunCancellable) ?? null;
+}
+
+/** Runs with *runId* moved to ``cancelling``, for the optimistic update. */
+export function markRunCancelling<T extends RunLike>(runs: T[], runId: number): T[] {
+  return runs.map((run) => (run.id === runId && isRunCancellable(run) ? { ...run, status: "cancelling" } : run));
+}
@@ -0,0 +1,82 @@
+/**
+ * Live run inspector: while a run is active the server streams its tool
+ * calls, intermediate messages and retries to the ``agent:{id}`` topic as
+ * ``run_event`` frames (see services/run_events.py). Nothing is stored, so
+ * the tab only shows what happens after it opens.
+ */
+
+import type { AgentRun } from "../services/api";
+import type { RunEventData } from "../generated/ws-messages";
+
+export type RunEventKind = RunEventData["kind"];
+
+export type RunEventTone = "info" | "success" | "error" | "warning" | "neutral";
+
+export const RUN_EVENT_KINDS: readonly { kind: RunEventKind; label: string; tone: RunEventTone }[] = [
+  { kind: "tool_started", label: "Tool call", tone: "info" },
+  { kind: "tool_finished", label: "Tool result", tone: "success" },
+  { kind: "tool_failed", label: "Tool error", tone: "error" },
+  { kind: "message", label: "Message", tone: "neutral" },
+  { kind: "retry", label: "Retry", tone: "warning" },
+];
+
+// Oldest events fall off the top once the log holds this many
+export const RUN_EVENT_LIMIT = 200;
+
+export interface RunEventEntry {
+  id: number;
+  event: RunEventData;
+}
+
+/** Runs that can still produce events. */
+export function isRunLive(run: Pick<AgentRun, "status">): boolean {
+  return run.status === "queued" || run.status === "running" || run.status === "cancelling";
+}
+
+/** The event payload when *message* is a ``run_event`` for *runId*, else null. */
+export function runEventFor(message: { type: string; data?: unknown }, runId: number): RunEventData | null {
+  if (message.type !== "run_event" || !message.data) return null;
+  const event = message.data as RunEventData;
+  return event.run_id === runId ? event : null;
+}
+
+export function runEventLabel(kind: RunEventKind): string {
+  return RUN_EVENT_KINDS.find((entry) => entry.kind === kind)?.label ?? kind;
+}
+
+export function runEventTone(kind: RunEventKind): RunEventTone {
+  return RUN_EVENT_KINDS.find((entry) => entry.kind === kind)?.tone ?? "neutral";
+}
+
+export function describeRunEvent(event: RunEventData): string {
+  const tool = event.tool_name ?? "tool";
+  const took = event.duration_ms != null ? ` in ${event.duration_ms}ms` : "";
+  switch (event.kind) {
+    case "tool_started":
+      return `Calling ${tool}`;
+    case "tool_finished":
+      return `${tool} returned${took}`;
+    case "tool_failed":
+      return `${tool} failed${took}`;
+    case "retry":
+      return `Retrying${event.attempt != null ? ` (attempt ${event.attempt})` : ""}`;
+    case "message":
+      return "Assistant";
+    default:
+      return event.kind;
+  }
+}
+
+/** Pretty-printed payload for "Copy JSON". */
+export function runEventJson(event: RunEventData): string {
+  return JSON.stringify(event, null, 2);
+}
+
+/** Oldest first, keeping the newest ``limit``. */
+export function appendRunEvents(
+  entries: RunEventEntry[],
+  incoming: RunEventEntry[],
+  limit = RUN_EVENT_LIMIT
+): RunEventEntry[] {
+  return [...entries, ...incoming].slice(-limit);
+}
@@ -0,0 +1,77 @@
+import { fetchAgentRuns, type AgentRun } from "../services/api";
+import { toCsv } from "./export";
+
+export type RunExportFormat = "csv" | "jsonl";
+
+export const RUN_EXPORT_PAGE_SIZE = 100;
+
+export const RUN_EXPORT_COLUMNS = [
+  "id",
+  "agent_id",
+  "thread_id",
+  "status",
+  "trigger",
+  "started_at",
+  "finished_at",
+  "duration_ms",
+  "total_tokens",
+  "total_cost_usd",
+  "error",
+] as const satisfies ReadonlyArray<keyof AgentRun>;
+
+interface FetchAllRunsOptions {
+  pageSize?: number;
+  signal?: AbortSignal;
+  // Called after every page with the running total
+  onProgress?: (fetched: number) => void;
+}
+
+/**
+ * Page through an agent's full run history, newest first.
+ *
+ * Uses the `before_id` cursor so runs created mid-export don't shift pages.
+ */
+export async function fetchAllAgentRuns(agentId: number, options: FetchAllRunsOptions = {}): Promise<AgentRun[]> {
+  const pageSize = options.pageSize ?? RUN_EXPORT_PAGE_SIZE;
+  const runs: AgentRun[] = [];
+  let beforeId: number | undefined;
+
+  for (;;) {
+    if (options.signal?.aborted) {
+      throw new DOMException("Export cancelled", "AbortError");
+    }
+    const page = await fetchAgentRuns(agentId, pageSize, beforeId);
+    runs.push(...page);
+    options.onProgress?.(runs.length);
+    if (page.length < pageSize) {
+      break;
+    }
+    beforeId = page[page.length - 1].id;
+  }
+
+  return runs;
+}
+
+export function runsToCsv(runs: AgentRun[]): string {
+  return toCsv(
+    RUN_EXPORT_COLUMNS.map((column) => ({ header: column, value: (run: AgentRun) => run[column] })),
+    runs
+  );
+}
+
+export function runsToJsonl(runs: AgentRun[]): string {
+  return runs
+    .map((run) => {
+      const record: Record<string, unknown> = {};
+      RUN_EXPORT_COLUMNS.forEach((column) => {
+        record[column] = run[column] ?? null;
+      });
+      return JSON.stringify(record);
+    })
+    .map((line) => `${line}\n`)
+    .join("");
+}
+
+export function serializeRuns(runs: AgentRun[], format: RunExportFormat): string {
+  return format === "csv" ? runsToCsv(runs) : runsToJsonl(runs);
+}
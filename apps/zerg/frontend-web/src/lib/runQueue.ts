@@ -0,0 +1,50 @@
+/**
+ * Runs waiting behind an agent's active run. The backend queues schedule and
+ * trigger firings that arrive while the agent is busy (``queue_position`` set,
+ * status ``queued``) and broadcasts the whole queue as ``run_queue_update``
+ * whenever it changes.
+ */
+
+import type { QueuedRunData } from "../generated/ws-messages";
+import type { AgentRun } from "../services/api";
+
+type QueueableRun = Pick<AgentRun, "id" | "status" | "queue_position">;
+
+/** Queued runs, next to start first. */
+export function queuedRuns<T extends QueueableRun>(runs: T[] | undefined): T[] {
+  return (runs ?? [])
+    .filter((run) => run.status === "queued" && run.queue_position != null)
+    .sort((a, b) => (a.queue_position ?? 0) - (b.queue_position ?? 0));
+}
+
+/** Runs with positions taken from a ``run_queue_update``; runs no longer listed leave the queue. */
+export function applyQueueUpdate<T extends QueueableRun>(
+  runs: T[],
+  update: { queue: Pick<QueuedRunData, "id" | "position">[] }
+): T[] {
+  const positions = new Map(update.queue.map((entry) => [entry.id, entry.position]));
+  let changed = false;
+  const next = runs.map((run) => {
+    const position = positions.get(run.id) ?? null;
+    if ((run.queue_position ?? null) === position) return run;
+    changed = true;
+    return { ...run, queue_position: position };
+  });
+  return changed ? next : runs;
+}
+
+/** Queue order with *runId* moved *offset* places (negative = sooner), clamped to the ends. */
+export function moveInQueue(runIds: number[], runId: number, offset: number): number[] {
+  const from = runIds.indexOf(runId);
+  if (from === -1) return runIds;
+  const to = Math.min(Math.max(from + offset, 0), runIds.length - 1);
+  if (to === from) return runIds;
+  const next = runIds.slice();
+  next.splice(from, 1);
+  next.splice(to, 0, runId);
+  return next;
+}
+
+export function queueBadgeLabel(count: number): string {
+  return count === 1 ? "1 queued run" : `${count} queued runs`;
+}
@@ -0,0 +1,136 @@
+/**
+ * Session lifetime on the client. The session cookie is HttpOnly, so the
+ * expiry comes from the `expires_in` of login/refresh responses. Shortly
+ * before it lapses an active user is refreshed silently; an idle user gets
+ * a warning with a countdown instead. Any 401 from the API marks the
+ * session expired, which shows a sign-in overlay on top of the still-mounted
+ * page so unsaved canvas and chat drafts survive signing back in.
+ */
+
+import config from "./config";
+
+export interface SessionState {
+  // Epoch ms the session cookie expires at; null until a login/refresh reports it
+  expiresAt: number | null;
+  // Idle user is being asked whether to stay signed in
+  warning: boolean;
+  // The server rejected the session; the user must sign in again
+  expired: boolean;
+}
+
+export type SessionAction = "refresh" | "warn" | "expire" | "wait";
+
+// Refresh this long before expiry when the user has been active
+export const SESSION_REFRESH_LEAD_MS = 5 * 60 * 1000;
+// Idle users are warned this long before expiry
+export const SESSION_WARNING_LEAD_MS = 2 * 60 * 1000;
+// Input within this window counts as "active"
+export const SESSION_ACTIVE_WINDOW_MS = 10 * 60 * 1000;
+
+let state: SessionState = { expiresAt: null, warning: false, expired: false };
+let lastActivityAt = Date.now();
+const listeners = new Set<() => void>();
+
+function update(patch: Partial<SessionState>) {
+  state = { ...state, ...patch };
+  listeners.forEach((listener) => listener());
+}
+
+export function subscribeSession(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => listeners.delete(listener);
+}
+
+export function getSession(): SessionState {
+  return state;
+}
+
+/** Record a fresh session from a login or refresh response. */
+export function setSessionExpiry(expiresInSeconds: number, now: number = Date.now()) {
+  update({ expiresAt: now + expiresInSeconds * 1000, warning: false, expired: false });
+}
+
+/** Called on any 401 from the API. */
+export function markSessionExpired() {
+  if (state.expired) return;
+  update({ warning: false, expired: true });
+}
+
+export function clearSession() {
+  update({ expiresAt: null, warning: false, expired: false });
+}
+
+export function noteActivity(now: number = Date.now()) {
+  lastActivityAt = now;
+}
+
+export function getLastActivityAt(): number {
+  return lastActivityAt;
+}
+
+/** What the keep-alive loop should do at *now*. */
+export function sessionAction(now: number, expiresAt: number | null, lastActivity: number): SessionAction {
+  if (expiresAt === null) return "wait";
+  const remaining = expiresAt - now;
+  if (remaining <= 0) return "expire";
+  if (remaining > SESSION_REFRESH_LEAD_MS) return "wait";
+  if (now - lastActivity <= SESSION_ACTIVE_WINDOW_MS) return "refresh";
+  return remaining <= SESSION_WARNING_LEAD_MS ? "warn" : "wait";
+}
+
+let refreshing: Promise<boolean> | null = null;
+
+/** Extend the session. Resolves false (and marks it expired on 401) when the server refuses. */
+export function refreshSession(): Promise<boolean> {
+  refreshing ??= (async () => {
+    try {
+      const response = await fetch(`${config.apiBaseUrl}/auth/refresh`, {
+        method: "POST",
+        credentials: "include",
+      });
+      if (response.status === 401) {
+        markSessionExpired();
+        return false;
+      }
+      if (!response.ok) return false;
+      const { expires_in } = (await response.json()) as { expires_in: number };
+      setSessionExpiry(expires_in);
+      return true;
+    } catch {
+      // Offline; the next tick tries again
+      return false;
+    } finally {
+      refreshing = null;
+    }
+  })();
+  return refreshing;
+}
+
+/** Track user input for the active/idle decision. Returns a cleanup. */
+export function installActivityTracking(target: Window = window): () => void {
+  const onActivity = () => noteActivity();
+  const events = ["pointerdown", "keydown", "wheel"] as const;
+  events.forEach((name) => target.addEventListener(name, onActivity, { passive: true }));
+  return () => events.forEach((name) => target.removeEventListener(name, onActivity));
+}
+
+/**
+ * Check the session every *intervalMs*: refresh active users, warn idle
+ * ones, and mark it expired once the time is up. Returns a stop function.
+ */
+export function startSessionKeepAlive(intervalMs = 15_000): () => void {
+  const tick = () => {
+    const { expiresAt, expired, warning } = state;
+    if (expired) return;
+    const action = sessionAction(Date.now(), expiresAt, lastActivityAt);
+    if (action === "refresh") {
+      void refreshSession();
+    } else if (action === "warn" && !warning) {
+      update({ warning: true });
+    } else if (action === "expire") {
+      markSessionExpired();
+    }
+  };
+  const handle = setInterval(tick, intervalMs);
+  return () => clearInterval(handle);
+}
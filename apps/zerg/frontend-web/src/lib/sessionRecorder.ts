@@ -0,0 +1,214 @@
+/**
+ * Opt-in session recording for bug reports.
+ *
+ * While recording, every change to the react-query cache (fetch results and
+ * the setQueryData calls WebSocket handlers make), every mutation and every
+ * WebSocket message is appended to a trace with its time offset. Values are
+ * sanitized before they're kept: credentials are redacted and long strings
+ * cut short. The trace downloads as JSON; in debug builds
+ * `window.__zergReplay` re-applies one to a fresh cache so the reported state
+ * can be reproduced step by step.
+ */
+import type { QueryClient, QueryKey } from "@tanstack/react-query";
+
+export const SESSION_TRACE_VERSION = 1;
+export const MAX_SESSION_EVENTS = 5000;
+const MAX_STRING_LENGTH = 2000;
+const MAX_DEPTH = 8;
+const REDACTED = "[redacted]";
+const SENSITIVE_KEY = /pass(word)?|secret|token|authorization|api[_-]?key|cookie|credential/i;
+
+export type SessionEventKind = "query" | "mutation" | "ws-in" | "ws-out" | "navigation";
+
+export interface SessionEvent {
+  seq: number;
+  // Milliseconds since recording started
+  t: number;
+  kind: SessionEventKind;
+  // Query or mutation key
+  key?: unknown;
+  // WebSocket message type
+  type?: string;
+  payload: unknown;
+}
+
+export interface SessionTrace {
+  version: number;
+  started_at: string;
+  url: string;
+  user_agent: string;
+  events: SessionEvent[];
+  // Events dropped once MAX_SESSION_EVENTS was reached
+  dropped: number;
+}
+
+/** A copy of *value* that's safe to attach to a bug report. */
+export function sanitize(value: unknown, depth = 0): unknown {
+  if (typeof value === "string") {
+    return value.length > MAX_STRING_LENGTH ? `${value.slice(0, MAX_STRING_LENGTH)}… [${value.length} chars]` : value;
+  }
+  if (value === null || typeof value !== "object") {
+    return typeof value === "function" || typeof value === "symbol" ? undefined : value;
+  }
+  if (depth >= MAX_DEPTH) return "[truncated]";
+  if (value instanceof Date) return value.toISOString();
+  if (Array.isArray(value)) return value.map((item) => sanitize(item, depth + 1));
+  const result: Record<string, unknown> = {};
+  for (const [key, item] of Object.entries(value)) {
+    result[key] = SENSITIVE_KEY.test(key) && item != null && item !== "" ? REDACTED : sanitize(item, depth + 1);
+  }
+  return result;
+}
+
+interface Recording {
+  startedAt: number;
+  trace: SessionTrace;
+  unsubscribe: () => void;
+}
+
+let recording: Recording | null = null;
+const listeners = new Set<() => void>();
+
+function emit() {
+  listeners.forEach((listener) => listener());
+}
+
+export function subscribeSessionRecording(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => listeners.delete(listener);
+}
+
+export function isRecordingSession(): boolean {
+  return recording !== null;
+}
+
+export function recordedEventCount(): number {
+  return recording?.trace.events.length ?? 0;
+}
+
+function push(kind: SessionEventKind, event: Omit<SessionEvent, "seq" | "t" | "kind">) {
+  if (!recording) return;
+  const { trace, startedAt } = recording;
+  if (trace.events.length >= MAX_SESSION_EVENTS) {
+    trace.dropped += 1;
+    return;
+  }
+  trace.events.push({ seq: trace.events.length + 1, t: Math.round(performance.now() - startedAt), kind, ...event });
+  emit();
+}
+
+/** Start recording *queryClient*'s cache and mutations; a no-op if already recording. */
+export function startSessionRecording(queryClient: QueryClient) {
+  if (recording) return;
+  const unsubscribeQueries = queryClient.getQueryCache().subscribe((event) => {
+    if (event.type === "updated" && event.action.type === "success") {
+      push("query", { key: sanitize(event.query.queryKey), payload: sanitize(event.query.state.data) });
+    }
+  });
+  const unsubscribeMutations = queryClient.getMutationCache().subscribe((event) => {
+    if (event.type === "updated" && (event.action.type === "success" || event.action.type === "error")) {
+      const { state, options } = event.mutation;
+      push("mutation", {
+        key: sanitize(options.mutationKey ?? null),
+        payload: sanitize({ status: state.status, variables: state.variables, error: state.error?.message ?? null }),
+      });
+    }
+  });
+  recording = {
+    startedAt: performance.now(),
+    trace: {
+      version: SESSION_TRACE_VERSION,
+      started_at: new Date().toISOString(),
+      url: typeof window !== "undefined" ? window.location.pathname : "",
+      user_agent: typeof navigator !== "undefined" ? navigator.userAgent : "",
+      events: [],
+      dropped: 0,
+    },
+    unsubscribe: () => {
+      unsubscribeQueries();
+      unsubscribeMutations();
+    },
+  };
+  emit();
+}
+
+/** Stop recording and return the trace, or null if nothing was being recorded. */
+export function stopSessionRecording(): SessionTrace | null {
+  if (!recording) return null;
+  const { trace, unsubscribe } = recording;
+  unsubscribe();
+  recording = null;
+  emit();
+  return trace;
+}
+
+/** Called for every WebSocket message; a no-op unless recording. */
+export function recordSessionMessage(direction: "in" | "out", message: { type?: unknown }) {
+  if (!recording) return;
+  push(direction === "in" ? "ws-in" : "ws-out", {
+    type: typeof message.type === "string" ? message.type : "unknown",
+    payload: sanitize(message),
+  });
+}
+
+export function recordSessionNavigation(pathname: string) {
+  push("navigation", { payload: pathname });
+}
+
+export function traceToJson(trace: SessionTrace): string {
+  return JSON.stringify(trace, null, 2);
+}
+
+export function parseTrace(json: string): SessionTrace {
+  const trace = JSON.parse(json) as SessionTrace;
+  if (trace?.version !== SESSION_TRACE_VERSION || !Array.isArray(trace.events)) {
+    throw new Error(`Not a version ${SESSION_TRACE_VERSION} session trace`);
+  }
+  return trace;
+}
+
+// --- Replay (debug builds) ---
+
+export interface ReplayOptions {
+  // Stop after the event with this seq; defaults to the whole trace
+  upTo?: number;
+}
+
+/** Empty *queryClient* and stop it refetching, so state loaded into it afterwards stays put. */
+export function resetForReplay(queryClient: QueryClient) {
+  queryClient.cancelQueries();
+  queryClient.clear();
+  queryClient.setDefaultOptions({
+    queries: { staleTime: Infinity, refetchOnMount: false, refetchOnWindowFocus: false, refetchOnReconnect: false },
+  });
+}
+
+/**
+ * Reset *queryClient* and re-apply the trace's cache updates in order.
+ * Refetching is switched off first so server responses don't overwrite the
+ * replayed state. Returns the number of cache updates applied.
+ */
+export function replaySession(trace: SessionTrace, queryClient: QueryClient, options: ReplayOptions = {}): number {
+  resetForReplay(queryClient);
+  let applied = 0;
+  for (const event of trace.events) {
+    if (options.upTo !== undefined && event.seq > options.upTo) break;
+    if (event.kind !== "query") continue;
+    queryClient.setQueryData(event.key as QueryKey, event.payload);
+    applied += 1;
+  }
+  return applied;
+}
+
+export interface ReplayHarness {
+  replay: (trace: SessionTrace | string, upTo?: number) => number;
+}
+
+/** Expose `window.__zergReplay` in debug builds; production bundles never install it. */
+export function installReplayHarness(queryClient: QueryClient) {
+  if (typeof window === "undefined" || !import.meta.env.DEV) return;
+  window.__zergReplay = {
+    replay: (trace, upTo) =>
+      replaySession(typeof trace === "string" ? parseTrace(trace) : trace, queryClient, { upTo }),
+  };
+}
@@ -0,0 +1,173 @@
+/**
+ * Keyboard shortcut registry. Every shortcut the app handles is an action
+ * here with a default key chord; users can rebind them from their profile
+ * (stored as overrides in user.prefs) and help overlays list them from this
+ * table rather than hard-coding key names.
+ *
+ * Chords are strings like "N", "Shift+/" or "Ctrl+Alt+ArrowDown": modifiers
+ * in a fixed order, then one key. Letters, digits and "/" come from the
+ * physical key (event.code) so they work the same on every keyboard layout.
+ */
+
+export type ShortcutScope = "global" | "dashboard" | "canvas";
+
+export type ShortcutActionId =
+  | "dashboard.createAgent"
+  | "dashboard.runAgent"
+  | "dashboard.nextRow"
+  | "dashboard.previousRow"
+  | "dashboard.showHelp"
+  | "canvas.toggleSnap"
+  | "canvas.toggleGuides"
+  | "canvas.showHelp"
+  | "global.togglePerfHud"
+  | "global.quickSwitcher";
+
+export interface ShortcutAction {
+  id: ShortcutActionId;
+  label: string;
+  scope: ShortcutScope;
+  defaultChord: string;
+}
+
+export const SHORTCUT_ACTIONS: readonly ShortcutAction[] = [
+  { id: "dashboard.createAgent", label: "Create agent", scope: "dashboard", defaultChord: "N" },
+  { id: "dashboard.runAgent", label: "Run focused agent", scope: "dashboard", defaultChord: "R" },
+  { id: "dashboard.nextRow", label: "Next agent row", scope: "dashboard", defaultChord: "ArrowDown" },
+  { id: "dashboard.previousRow", label: "Previous agent row", scope: "dashboard", defaultChord: "ArrowUp" },
+  { id: "dashboard.showHelp", label: "Show shortcuts", scope: "dashboard", defaultChord: "Shift+/" },
+  { id: "canvas.toggleSnap", label: "Toggle snap to grid", scope: "canvas", defaultChord: "Shift+S" },
+  { id: "canvas.toggleGuides", label: "Toggle guides", scope: "canvas", defaultChord: "Shift+G" },
+  { id: "canvas.showHelp", label: "Show shortcuts", scope: "canvas", defaultChord: "Shift+/" },
+  { id: "global.togglePerfHud", label: "Toggle performance HUD", scope: "global", defaultChord: "Alt+Shift+P" },
+  { id: "global.quickSwitcher", label: "Jump to agent or workflow", scope: "global", defaultChord: "Ctrl+P" },
+];
+
+export const SCOPE_LABELS: Record<ShortcutScope, string> = {
+  global: "Everywhere",
+  dashboard: "Dashboard",
+  canvas: "Canvas",
+};
+
+// Key in user.prefs; holds only the chords that differ from the defaults
+export const SHORTCUTS_PREF = "shortcuts";
+
+export type ShortcutBindings = Record<ShortcutActionId, string>;
+
+const MODIFIERS = ["Ctrl", "Alt", "Shift", "Meta"] as const;
+type Modifier = (typeof MODIFIERS)[number];
+
+const MODIFIER_KEYS = new Set(["Control", "Alt", "Shift", "Meta", "AltGraph", "CapsLock", "OS"]);
+
+type ChordEvent = Pick<KeyboardEvent, "key" | "code" | "ctrlKey" | "altKey" | "shiftKey" | "metaKey">;
+
+function keyName(event: Pick<KeyboardEvent, "key" | "code">): string {
+  if (/^Key[A-Z]$/.test(event.code)) return event.code.slice(3);
+  if (/^Digit\d$/.test(event.code)) return event.code.slice(5);
+  if (event.code === "Slash") return "/";
+  if (event.key === " ") return "Space";
+  return event.key.length === 1 ? event.key.toUpperCase() : event.key;
+}
+
+/** The chord for a keydown, or null while only modifiers are held. */
+export function chordFromEvent(event: ChordEvent): string | null {
+  if (MODIFIER_KEYS.has(event.key)) return null;
+  const pressed: Record<Modifier, boolean> = {
+    Ctrl: event.ctrlKey,
+    Alt: event.altKey,
+    Shift: event.shiftKey,
+    Meta: event.metaKey,
+  };
+  return [...MODIFIERS.filter((modifier) => pressed[modifier]), keyName(event)].join("+");
+}
+
+// Modifier prefix, then the key; the key may itself be "+" ("Shift++")
+const CHORD_PATTERN = /^((?:(?:ctrl|alt|shift|meta)\+)*)(.+)$/i;
+
+function parseChord(value: string): { modifiers: Modifier[]; key: string } | null {
+  const match = CHORD_PATTERN.exec(value.trim());
+  if (!match || (match[2].length > 1 && match[2].includes("+"))) return null;
+  const named = match[1].split("+").filter(Boolean).map((part) => part.toLowerCase());
+  const modifiers = MODIFIERS.filter((modifier) => named.includes(modifier.toLowerCase()));
+  const key = match[2].length === 1 ? match[2].toUpperCase() : match[2];
+  return { modifiers, key };
+}
+
+/** Canonical form of a stored chord, or null if it isn't one. */
+export function normalizeChord(value: unknown): string | null {
+  if (typeof value !== "string") return null;
+  const parsed = parseChord(value);
+  return parsed ? [...parsed.modifiers, parsed.key].join("+") : null;
+}
+
+export function defaultBindings(): ShortcutBindings {
+  return Object.fromEntries(SHORTCUT_ACTIONS.map((action) => [action.id, action.defaultChord])) as ShortcutBindings;
+}
+
+export function bindingsFromPrefs(prefs: Record<string, unknown> | null | undefined): ShortcutBindings {
+  const bindings = defaultBindings();
+  const overrides = prefs?.[SHORTCUTS_PREF];
+  if (!overrides || typeof overrides !== "object") return bindings;
+  for (const action of SHORTCUT_ACTIONS) {
+    const chord = normalizeChord((overrides as Record<string, unknown>)[action.id]);
+    if (chord) bindings[action.id] = chord;
+  }
+  return bindings;
+}
+
+/** What to store in prefs: just the rebound actions. */
+export function bindingOverrides(bindings: ShortcutBindings): Partial<ShortcutBindings> {
+  const overrides: Partial<ShortcutBindings> = {};
+  for (const action of SHORTCUT_ACTIONS) {
+    if (bindings[action.id] !== action.defaultChord) overrides[action.id] = bindings[action.id];
+  }
+  return overrides;
+}
+
+function scopesOverlap(a: ShortcutScope, b: ShortcutScope): boolean {
+  return a === b || a === "global" || b === "global";
+}
+
+/** Actions sharing a chord in a scope where both could fire, keyed by action. */
+export function findConflicts(bindings: ShortcutBindings): Partial<Record<ShortcutActionId, ShortcutActionId[]>> {
+  const conflicts: Partial<Record<ShortcutActionId, ShortcutActionId[]>> = {};
+  for (const action of SHORTCUT_ACTIONS) {
+    const clashing = SHORTCUT_ACTIONS.filter(
+      (other) =>
+        other.id !== action.id &&
+        bindings[other.id] === bindings[action.id] &&
+        scopesOverlap(other.scope, action.scope)
+    ).map((other) => other.id);
+    if (clashing.length > 0) conflicts[action.id] = clashing;
+  }
+  return conflicts;
+}
+
+export function shortcutsForScope(scope: ShortcutScope): ShortcutAction[] {
+  return SHORTCUT_ACTIONS.filter((action) => scopesOverlap(action.scope, scope));
+}
+
+export function actionForEvent(
+  event: ChordEvent,
+  bindings: ShortcutBindings,
+  scope: ShortcutScope
+): ShortcutActionId | null {
+  const chord = chordFromEvent(event);
+  if (!chord) return null;
+  return shortcutsForScope(scope).find((action) => bindings[action.id] === chord)?.id ?? null;
+}
+
+const KEY_LABELS: Record<string, string> = {
+  ArrowDown: "↓",
+  ArrowUp: "↑",
+  ArrowLeft: "←",
+  ArrowRight: "→",
+  Meta: "⌘",
+};
+
+/** Individual keys of a chord, ready to render as <kbd> elements. */
+export function chordKeys(chord: string): string[] {
+  const parsed = parseChord(chord);
+  if (!parsed) return [chord];
+  return [...parsed.modifiers, parsed.key].map((part) => KEY_LABELS[part] ?? part);
+}
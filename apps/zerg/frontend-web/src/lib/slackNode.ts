@@ -0,0 +1,35 @@
+/**
+ * "Send to Slack" node: a tool node preset for send_slack_message
+ * (tools/builtin/slack_tools.py), which posts as the connected Slack app.
+ * Channel and text may reference upstream outputs with ${node_id.value...}.
+ */
+
+export const SLACK_TOOL_TYPE = "slack-message";
+export const SLACK_TOOL_NAME = "send_slack_message";
+
+export const SLACK_ITEM = { type: SLACK_TOOL_TYPE, name: "Send to Slack", icon: "💬" } as const;
+
+export type NodeConfig = Record<string, unknown>;
+
+export function defaultSlackConfig(): NodeConfig {
+  return { tool_name: SLACK_TOOL_NAME, static_params: { channel: "", text: "" } };
+}
+
+export function isSlackNode(config: NodeConfig | undefined): boolean {
+  return config?.tool_name === SLACK_TOOL_NAME || config?.tool_type === SLACK_TOOL_TYPE;
+}
+
+export function slackParams(config: NodeConfig | undefined): { channel: string; text: string } {
+  const params = config?.static_params;
+  const record = params && typeof params === "object" && !Array.isArray(params) ? (params as NodeConfig) : {};
+  return {
+    channel: typeof record.channel === "string" ? record.channel : "",
+    text: typeof record.text === "string" ? record.text : "",
+  };
+}
+
+/** One-line summary shown on the node under its label. */
+export function slackNodeSummary(config: NodeConfig | undefined): string {
+  const { channel } = slackParams(config);
+  return channel ? `Posts to ${channel}` : "No channel";
+}
@@ -0,0 +1,40 @@
+/**
+ * Slack-event triggers. Config mirrors services/slack_events.py: an event
+ * type plus an optional channel ID and keyword the event must contain.
+ */
+
+export type SlackEventType = "message" | "app_mention" | "reaction_added";
+
+export const SLACK_EVENT_LABELS: Record<SlackEventType, string> = {
+  app_mention: "The app is @mentioned",
+  message: "A message is posted",
+  reaction_added: "A reaction is added",
+};
+
+export interface SlackTriggerConfig {
+  event_type: SlackEventType;
+  channel?: string | null;
+  keyword?: string | null;
+}
+
+export function isSlackEventType(value: unknown): value is SlackEventType {
+  return typeof value === "string" && value in SLACK_EVENT_LABELS;
+}
+
+/** One-line summary, e.g. "A message is posted in C0123 containing “deploy”". */
+export function describeSlackTrigger(config: Record<string, unknown> | null | undefined): string {
+  const eventType = config?.event_type;
+  if (!isSlackEventType(eventType)) return "Unknown Slack event";
+  const channel = typeof config?.channel === "string" && config.channel ? config.channel : null;
+  const keyword = typeof config?.keyword === "string" && config.keyword ? config.keyword : null;
+  let summary = SLACK_EVENT_LABELS[eventType];
+  summary += channel ? ` in ${channel}` : " in any channel";
+  if (keyword) summary += eventType === "reaction_added" ? ` with :${keyword}:` : ` containing “${keyword}”`;
+  return summary;
+}
+
+/** Whether the account's Slack credential came from the app install (needed for events). */
+export function isSlackAppConnected(connectors: { type: string; metadata?: Record<string, unknown> | null }[]) {
+  const slack = connectors.find((connector) => connector.type === "slack");
+  return Boolean(slack?.metadata && slack.metadata.connected_via === "oauth" && slack.metadata.team_id);
+}
@@ -0,0 +1,48 @@
+/**
+ * Geometry for small inline line charts.
+ *
+ * ``null`` values (failed probes, missing samples) break the line so a gap
+ * shows instead of a misleading interpolation.
+ */
+
+export interface SparklineGeometry {
+  // One SVG ``points`` string per unbroken run of values
+  segments: string[];
+  // x positions of the null values, for gap markers
+  gaps: number[];
+  max: number;
+}
+
+export function sparklineGeometry(
+  values: (number | null | undefined)[],
+  width: number,
+  height: number,
+  padding = 1
+): SparklineGeometry {
+  const numeric = values.filter((value): value is number => typeof value === "number" && Number.isFinite(value));
+  const max = numeric.length ? Math.max(...numeric) : 0;
+  const min = numeric.length ? Math.min(...numeric) : 0;
+  const span = max - min || 1;
+  const step = values.length > 1 ? (width - padding * 2) / (values.length - 1) : 0;
+  const x = (index: number) => Number((padding + index * step).toFixed(2));
+  // A flat series sits in the middle rather than on the floor
+  const y = (value: number) =>
+    max === min ? height / 2 : Number((height - padding - ((value - min) / span) * (height - padding * 2)).toFixed(2));
+
+  const segments: string[] = [];
+  const gaps: number[] = [];
+  let current: string[] = [];
+
+  values.forEach((value, index) => {
+    if (typeof value === "number" && Number.isFinite(value)) {
+      current.push(`${x(index)},${y(value)}`);
+      return;
+    }
+    gaps.push(x(index));
+    if (current.length) segments.push(current.join(" "));
+    current = [];
+  });
+  if (current.length) segments.push(current.join(" "));
+
+  return { segments, gaps, max };
+}
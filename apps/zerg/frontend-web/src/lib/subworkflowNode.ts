@@ -0,0 +1,54 @@
+/**
+ * Call Workflow node ("subworkflow"): runs another of your workflows as a
+ * child execution. Its inputs map is resolved against upstream outputs and
+ * read by the child as ${input.value.<name>}; its outputs map is resolved
+ * against the child's node outputs when the child finishes
+ * (services/subworkflows.py).
+ */
+export type NodeConfig = Record<string, unknown>;
+
+// Shelf entry, listed with the control-flow nodes under Logic
+export const SUBWORKFLOW_ITEM = { type: "subworkflow", name: "Call Workflow", icon: "🧩" } as const;
+
+export function defaultSubworkflowConfig(): NodeConfig {
+  return { workflow_id: null, inputs: {}, outputs: {} };
+}
+
+export function subworkflowTarget(config: NodeConfig | undefined): number | null {
+  const target = Number(config?.workflow_id);
+  return Number.isInteger(target) && target > 0 ? target : null;
+}
+
+/** One-line summary shown on the node under its label. */
+export function subworkflowNodeSummary(config: NodeConfig | undefined, workflowName?: string): string {
+  const target = subworkflowTarget(config);
+  if (target == null) return "No workflow chosen";
+  return `Calls ${workflowName ?? `workflow #${target}`}`;
+}
+
+/** A mapping as editable "name = template" lines, one per key. */
+export function formatMapping(value: unknown): string {
+  if (!value || typeof value !== "object" || Array.isArray(value)) return "";
+  return Object.entries(value as Record<string, unknown>)
+    .map(([name, template]) => `${name} = ${typeof template === "string" ? template : JSON.stringify(template)}`)
+    .join("\n");
+}
+
+/** "name = template" lines back into a mapping; returns the first bad line's error instead when one doesn't parse. */
+export function parseMapping(text: string): { mapping: Record<string, string> } | { error: string } {
+  const mapping: Record<string, string> = {};
+  const lines = text.split("\n").map((line) => line.trim());
+  for (const [index, line] of lines.entries()) {
+    if (!line) continue;
+    const separator = line.indexOf("=");
+    const name = separator > 0 ? line.slice(0, separator).trim() : "";
+    if (!/^[A-Za-z_][\w-]*$/.test(name)) {
+      return { error: `Line ${index + 1}: write each mapping as name = value.` };
+    }
+    if (name in mapping) {
+      return { error: `Line ${index + 1}: ${name} is mapped twice.` };
+    }
+    mapping[name] = line.slice(separator + 1).trim();
+  }
+  return { mapping };
+}
@@ -0,0 +1,36 @@
+/**
+ * Status page rollup: the API's own health summary (GET /ops/status) combined
+ * with what this browser sees – whether the API answered, how fast, and the
+ * state of its WebSocket connection.
+ */
+import type { SystemStatus } from "../services/api";
+import { ConnectionStatus } from "./useWebSocket";
+
+export const STATUS_REFRESH_MS = 30_000;
+
+export type OverallStatus = "operational" | "degraded" | "down";
+
+export const OVERALL_STATUS_LABELS: Record<OverallStatus, string> = {
+  operational: "All systems operational",
+  degraded: "Degraded performance",
+  down: "API unreachable",
+};
+
+export interface StatusInputs {
+  // Latest /ops/status response; undefined until the first one arrives
+  status?: SystemStatus;
+  apiReachable: boolean;
+  connection: ConnectionStatus;
+}
+
+export function overallStatus({ status, apiReachable, connection }: StatusInputs): OverallStatus {
+  if (!apiReachable) return "down";
+  if (!status) return "operational";
+  if (status.status !== "ok" || connection !== ConnectionStatus.CONNECTED) return "degraded";
+  return "operational";
+}
+
+export function formatErrorRate(rate: number | null | undefined): string {
+  if (rate == null) return "No runs";
+  return `${(rate * 100).toFixed(rate > 0 && rate < 0.1 ? 1 : 0)}%`;
+}
@@ -0,0 +1,52 @@
+/**
+ * Free-form tags on agents and workflows. Mirrors zerg/services/tags.py: a
+ * tag is trimmed with inner whitespace collapsed, and tags compare
+ * case-insensitively, keeping the first spelling.
+ */
+
+// Mirror MAX_TAG_LENGTH / MAX_TAGS on the backend
+export const MAX_TAG_LENGTH = 40;
+export const MAX_TAGS = 20;
+
+export const TAGS_QUERY_KEY = ["tags"] as const;
+
+type Tagged = { tags?: readonly string[] | null };
+
+export function normalizeTag(raw: string): string {
+  return raw.replace(/\s+/g, " ").trim();
+}
+
+export function sameTag(left: string, right: string): boolean {
+  return left.toLowerCase() === right.toLowerCase();
+}
+
+export function hasTag(tags: readonly string[] | null | undefined, tag: string): boolean {
+  return (tags ?? []).some((existing) => sameTag(existing, tag));
+}
+
+/** *tags* with *raw* added, unchanged when it's blank, too long or already there. */
+export function addTag(tags: readonly string[], raw: string): string[] {
+  const tag = normalizeTag(raw);
+  if (!tag || tag.length > MAX_TAG_LENGTH || tags.length >= MAX_TAGS || hasTag(tags, tag)) return [...tags];
+  return [...tags, tag];
+}
+
+export function removeTag(tags: readonly string[], tag: string): string[] {
+  return tags.filter((existing) => !sameTag(existing, tag));
+}
+
+/** Every tag carried by *items*, once each, alphabetically. */
+export function collectTags(items: readonly Tagged[]): string[] {
+  const seen = new Map<string, string>();
+  for (const item of items) {
+    for (const tag of item.tags ?? []) {
+      if (!seen.has(tag.toLowerCase())) seen.set(tag.toLowerCase(), tag);
+    }
+  }
+  return [...seen.values()].sort((left, right) => left.localeCompare(right, undefined, { sensitivity: "base" }));
+}
+
+/** Whether *item* carries every one of *selected*; nothing selected matches everything. */
+export function matchesTags(item: Tagged, selected: readonly string[]): boolean {
+  return selected.every((tag) => hasTag(item.tags, tag));
+}
@@ -0,0 +1,116 @@
+/**
+ * Helpers for the workflow template gallery: canvas thumbnails for
+ * publishing, and client-side bookkeeping after rating/favoriting.
+ */
+
+import type { TemplateSort, WorkflowDataInput, WorkflowTemplate } from "../services/api";
+
+export const TEMPLATE_SORT_LABELS: Record<TemplateSort, string> = {
+  recent: "Newest",
+  rating: "Top rated",
+  popular: "Most favorited",
+};
+
+export const MAX_RATING = 5;
+
+const PREVIEW_WIDTH = 240;
+const PREVIEW_HEIGHT = 140;
+const PREVIEW_PADDING = 16;
+const PREVIEW_NODE = { width: 36, height: 20 };
+
+// The thumbnail is a standalone image, so it can't read theme variables
+const PREVIEW_COLORS: Record<string, string> = {
+  agent: "#6366f1",
+  tool: "#10b981",
+  trigger: "#f59e0b",
+  conditional: "#ec4899",
+  switch: "#ec4899",
+  loop: "#ec4899",
+  http: "#10b981",
+  wait: "#f59e0b",
+  subworkflow: "#8b5cf6",
+};
+const PREVIEW_BACKGROUND = "#0f172a";
+const PREVIEW_EDGE = "#64748b";
+
+type PreviewCanvas = Pick<WorkflowDataInput, "nodes" | "edges">;
+
+/** SVG thumbnail of the node layout, as a data: URL for ``preview_image_url``. */
+export function canvasPreviewDataUrl(canvas: PreviewCanvas): string {
+  const { nodes, edges } = canvas;
+  const xs = nodes.map((node) => node.position.x);
+  const ys = nodes.map((node) => node.position.y);
+  const minX = nodes.length > 0 ? Math.min(...xs) : 0;
+  const minY = nodes.length > 0 ? Math.min(...ys) : 0;
+  const spanX = Math.max(...xs, minX + 1) - minX;
+  const spanY = Math.max(...ys, minY + 1) - minY;
+  const scale = Math.min(
+    (PREVIEW_WIDTH - 2 * PREVIEW_PADDING - PREVIEW_NODE.width) / spanX,
+    (PREVIEW_HEIGHT - 2 * PREVIEW_PADDING - PREVIEW_NODE.height) / spanY,
+    1
+  );
+
+  const placed = new Map(
+    nodes.map((node) => [
+      node.id,
+      {
+        x: PREVIEW_PADDING + (node.position.x - minX) * scale,
+        y: PREVIEW_PADDING + (node.position.y - minY) * scale,
+        fill: PREVIEW_COLORS[node.type] ?? PREVIEW_EDGE,
+      },
+    ])
+  );
+  const centre = (id: string) => {
+    const node = placed.get(id);
+    return node ? { x: node.x + PREVIEW_NODE.width / 2, y: node.y + PREVIEW_NODE.height / 2 } : null;
+  };
+
+  const lines = edges
+    .map((edge) => [centre(edge.from_node_id), centre(edge.to_node_id)] as const)
+    .filter(([from, to]) => from && to)
+    .map(
+      ([from, to]) =>
+        `<line x1="${from!.x.toFixed(1)}" y1="${from!.y.toFixed(1)}" x2="${to!.x.toFixed(1)}" ` +
+        `y2="${to!.y.toFixed(1)}" stroke="${PREVIEW_EDGE}" stroke-width="2"/>`
+    );
+  const rects = [...placed.values()].map(
+    (node) =>
+      `<rect x="${node.x.toFixed(1)}" y="${node.y.toFixed(1)}" width="${PREVIEW_NODE.width}" ` +
+      `height="${PREVIEW_NODE.height}" rx="4" fill="${node.fill}"/>`
+  );
+
+  const svg =
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${PREVIEW_WIDTH}" height="${PREVIEW_HEIGHT}" ` +
+    `viewBox="0 0 ${PREVIEW_WIDTH} ${PREVIEW_HEIGHT}">` +
+    `<rect width="100%" height="100%" fill="${PREVIEW_BACKGROUND}"/>${lines.join("")}${rects.join("")}</svg>`;
+  // Markup is ASCII-only (numbers and fixed colours), so btoa is safe
+  return `data:image/svg+xml;base64,${btoa(svg)}`;
+}
+
+export function formatRating(template: WorkflowTemplate): string {
+  if (template.rating_avg === null || template.rating_avg === undefined || template.rating_count === 0) {
+    return "No ratings yet";
+  }
+  const count = `${template.rating_count} rating${template.rating_count === 1 ? "" : "s"}`;
+  return `${template.rating_avg.toFixed(1)} ★ (${count})`;
+}
+
+/** Swap in the server's copy of one template, dropping it when it no longer matches the favorites filter. */
+export function replaceTemplate(
+  list: WorkflowTemplate[],
+  updated: WorkflowTemplate,
+  favoritesOnly = false
+): WorkflowTemplate[] {
+  if (favoritesOnly && !updated.is_favorite) {
+    return list.filter((template) => template.id !== updated.id);
+  }
+  return list.map((template) => (template.id === updated.id ? updated : template));
+}
+
+export function parseTags(value: string): string[] {
+  const tags = value
+    .split(",")
+    .map((tag) => tag.trim())
+    .filter(Boolean);
+  return [...new Set(tags)];
+}
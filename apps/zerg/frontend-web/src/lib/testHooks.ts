@@ -0,0 +1,154 @@
+/**
+ * Deterministic hooks for E2E tests, installed as `window.__zergTest` in
+ * development and under Playwright (__TEST_WORKER_ID__).
+ *
+ * `snapshot()` serializes the app state – every cached query plus the current
+ * route – to JSON and `restore()` loads one back with refetching switched off,
+ * so a spec can start from a known state instead of clicking its way there.
+ * `waitForQuiescence()` resolves once nothing is fetching, mutating or waiting
+ * to be saved, replacing fixed sleeps and DOM polling.
+ */
+import type { QueryClient, QueryKey } from "@tanstack/react-query";
+import { pendingRequestCount } from "../services/api";
+import { persistence, type PersistenceQueue } from "./persistence";
+import { resetForReplay } from "./sessionRecorder";
+
+export const APP_SNAPSHOT_VERSION = 1;
+const DEFAULT_QUIESCENCE_TIMEOUT_MS = 10_000;
+// How long things must stay idle: a finished fetch often starts a dependent one
+const DEFAULT_SETTLE_MS = 100;
+const POLL_INTERVAL_MS = 25;
+
+export interface AppSnapshot {
+  version: number;
+  path: string;
+  queries: { key: QueryKey; data: unknown }[];
+}
+
+export interface BusyState {
+  fetching: number;
+  mutating: number;
+  requests: number;
+  // Canvas or UI changes not yet written (lib/persistence)
+  unsaved: boolean;
+}
+
+export interface QuiescenceOptions {
+  timeoutMs?: number;
+  settleMs?: number;
+}
+
+export interface TestHooks {
+  snapshot: () => AppSnapshot;
+  restore: (snapshot: AppSnapshot | string) => number;
+  busyState: () => BusyState;
+  waitForQuiescence: (options?: QuiescenceOptions) => Promise<void>;
+}
+
+export function snapshotAppState(queryClient: QueryClient, path = window.location.pathname): AppSnapshot {
+  const queries = queryClient
+    .getQueryCache()
+    .getAll()
+    .filter((query) => query.state.status === "success" && query.state.data !== undefined)
+    .map((query) => ({ key: query.queryKey, data: query.state.data }));
+  // Round-trip so the snapshot holds exactly what restore() will see
+  return JSON.parse(JSON.stringify({ version: APP_SNAPSHOT_VERSION, path, queries }));
+}
+
+export function parseSnapshot(json: string): AppSnapshot {
+  const snapshot = JSON.parse(json) as AppSnapshot;
+  if (snapshot?.version !== APP_SNAPSHOT_VERSION || !Array.isArray(snapshot.queries)) {
+    throw new Error(`Not a version ${APP_SNAPSHOT_VERSION} app snapshot`);
+  }
+  return snapshot;
+}
+
+/**
+ * Replace the cache with *snapshot*'s queries and navigate to its route.
+ * Returns the number of queries loaded.
+ */
+export function restoreAppState(snapshot: AppSnapshot, queryClient: QueryClient): number {
+  resetForReplay(queryClient);
+  for (const { key, data } of snapshot.queries) {
+    queryClient.setQueryData(key, data);
+  }
+  if (snapshot.path && snapshot.path !== window.location.pathname) {
+    // BrowserRouter follows popstate, so this is a client-side navigation
+    window.history.pushState(null, "", snapshot.path);
+    window.dispatchEvent(new PopStateEvent("popstate"));
+  }
+  return snapshot.queries.length;
+}
+
+export function busyState(queryClient: QueryClient, queue: PersistenceQueue = persistence): BusyState {
+  return {
+    fetching: queryClient.isFetching(),
+    mutating: queryClient.isMutating(),
+    requests: pendingRequestCount(),
+    unsaved: queue.hasPending(),
+  };
+}
+
+export function isQuiescent(state: BusyState): boolean {
+  return state.fetching === 0 && state.mutating === 0 && state.requests === 0 && !state.unsaved;
+}
+
+function describeBusy(state: BusyState): string {
+  const parts = [
+    state.fetching && `${state.fetching} fetching`,
+    state.mutating && `${state.mutating} mutating`,
+    state.requests && `${state.requests} requests`,
+    state.unsaved && "unsaved changes",
+  ].filter(Boolean);
+  return parts.join(", ");
+}
+
+/**
+ * Resolve once *getState* has reported idle for *settleMs* in a row; reject
+ * with whatever is still busy after *timeoutMs*.
+ */
+export function waitForQuiescence(
+  getState: () => BusyState,
+  { timeoutMs = DEFAULT_QUIESCENCE_TIMEOUT_MS, settleMs = DEFAULT_SETTLE_MS }: QuiescenceOptions = {}
+): Promise<void> {
+  const startedAt = Date.now();
+  return new Promise((resolve, reject) => {
+    let idleSince: number | null = null;
+    const check = () => {
+      const now = Date.now();
+      const state = getState();
+      if (isQuiescent(state)) {
+        idleSince ??= now;
+        if (now - idleSince >= settleMs) {
+          resolve();
+          return;
+        }
+      } else {
+        idleSince = null;
+        if (now - startedAt >= timeoutMs) {
+          reject(new Error(`Still busy after ${timeoutMs} ms: ${describeBusy(state)}`));
+          return;
+        }
+      }
+      setTimeout(check, POLL_INTERVAL_MS);
+    };
+    check();
+  });
+}
+
+export function isTestHooksEnabled(): boolean {
+  if (typeof window === "undefined") return false;
+  return import.meta.env.MODE === "development" || window.__TEST_WORKER_ID__ !== undefined;
+}
+
+/** Expose `window.__zergTest`; production bundles outside Playwright never install it. */
+export function installTestHooks(queryClient: QueryClient) {
+  if (!isTestHooksEnabled()) return;
+  window.__zergTest = {
+    snapshot: () => snapshotAppState(queryClient),
+    restore: (snapshot) =>
+      restoreAppState(typeof snapshot === "string" ? parseSnapshot(snapshot) : snapshot, queryClient),
+    busyState: () => busyState(queryClient),
+    waitForQuiescence: (options) => waitForQuiescence(() => busyState(queryClient), options),
+  };
+}
@@ -0,0 +1,235 @@
+/**
+ * Canvas-based text measurement for sizing canvas node labels.
+ *
+ * Widths come from CanvasRenderingContext2D.measureText, cached per
+ * (font, text) pair, and finished label layouts are cached per (font, text,
+ * options) so re-renders and drag frames never re-wrap. The caches are
+ * dropped whenever metrics may have shifted – web fonts finishing loading,
+ * the browser zoom / device pixel ratio changing, or the theme swapping
+ * fonts – and subscribers are notified so nodes can re-layout.
+ */
+
+export interface LabelLayout {
+  lines: string[];
+  // Width of the widest line, clamped to the min/max the caller asked for
+  width: number;
+}
+
+export interface LabelLayoutOptions {
+  minWidth?: number;
+  maxWidth?: number;
+  maxLines?: number;
+}
+
+// Matches the padding/icon-free text box of the canvas nodes
+const DEFAULT_MIN_WIDTH = 96;
+const DEFAULT_MAX_WIDTH = 220;
+const DEFAULT_MAX_LINES = 3;
+const ELLIPSIS = "…";
+// .node-label line-height
+export const LABEL_LINE_HEIGHT = 1.3;
+// Node chrome around the label: borders, padding and the 20px icon plus its margin
+const NODE_CHROME = { width: 64, height: 26, iconHeight: 20, minWidth: 140 };
+
+const widthCache = new Map<string, number>();
+const layoutCache = new Map<string, LabelLayout>();
+const listeners = new Set<() => void>();
+let metricsVersion = 0;
+let context: CanvasRenderingContext2D | OffscreenCanvasRenderingContext2D | null | undefined;
+
+function getContext() {
+  if (context !== undefined) return context;
+  context = null;
+  try {
+    if (typeof OffscreenCanvas !== "undefined") {
+      context = new OffscreenCanvas(1, 1).getContext("2d");
+    } else if (typeof document !== "undefined") {
+      context = document.createElement("canvas").getContext("2d");
+    }
+  } catch {
+    context = null;
+  }
+  return context;
+}
+
+function fontSizePx(font: string): number {
+  const match = /(\d+(?:\.\d+)?)px/.exec(font);
+  return match ? Number(match[1]) : 14;
+}
+
+export function measureTextWidth(text: string, font: string): number {
+  const key = `${font}\u0000${text}`;
+  const cached = widthCache.get(key);
+  if (cached !== undefined) return cached;
+
+  const ctx = getContext();
+  let width: number;
+  if (ctx) {
+    ctx.font = font;
+    width = ctx.measureText(text).width;
+  } else {
+    // No canvas (e.g. jsdom): fall back to an average glyph width
+    width = text.length * fontSizePx(font) * 0.6;
+  }
+  widthCache.set(key, width);
+  return width;
+}
+
+// Split a word that is wider than the line on its own
+function breakWord(word: string, font: string, maxWidth: number): string[] {
+  const parts: string[] = [];
+  let current = "";
+  for (const char of word) {
+    if (current && measureTextWidth(current + char, font) > maxWidth) {
+      parts.push(current);
+      current = char;
+    } else {
+      current += char;
+    }
+  }
+  if (current) parts.push(current);
+  return parts;
+}
+
+export function wrapText(text: string, font: string, maxWidth: number): string[] {
+  const words = text.trim().split(/\s+/).filter(Boolean);
+  if (words.length === 0) return [""];
+
+  const lines: string[] = [];
+  let current = "";
+  for (const word of words) {
+    const candidate = current ? `${current} ${word}` : word;
+    if (measureTextWidth(candidate, font) <= maxWidth) {
+      current = candidate;
+      continue;
+    }
+    if (current) lines.push(current);
+    if (measureTextWidth(word, font) > maxWidth) {
+      const pieces = breakWord(word, font, maxWidth);
+      lines.push(...pieces.slice(0, -1));
+      current = pieces[pieces.length - 1] ?? "";
+    } else {
+      current = word;
+    }
+  }
+  if (current) lines.push(current);
+  return lines;
+}
+
+function truncateLine(line: string, font: string, maxWidth: number): string {
+  if (measureTextWidth(line + ELLIPSIS, font) <= maxWidth) return line + ELLIPSIS;
+  let end = line.length;
+  while (end > 0 && measureTextWidth(line.slice(0, end) + ELLIPSIS, font) > maxWidth) {
+    end -= 1;
+  }
+  return line.slice(0, end).trimEnd() + ELLIPSIS;
+}
+
+/**
+ * Wrap *text* into at most ``maxLines`` lines no wider than ``maxWidth`` and
+ * report the box width needed to show them without clipping.
+ */
+export function layoutLabel(text: string, font: string, options: LabelLayoutOptions = {}): LabelLayout {
+  const minWidth = options.minWidth ?? DEFAULT_MIN_WIDTH;
+  const maxWidth = options.maxWidth ?? DEFAULT_MAX_WIDTH;
+  const maxLines = options.maxLines ?? DEFAULT_MAX_LINES;
+  const key = `${font}\u0000${minWidth}|${maxWidth}|${maxLines}\u0000${text}`;
+  const cached = layoutCache.get(key);
+  if (cached) return cached;
+
+  const layout = computeLabelLayout(text, font, minWidth, maxWidth, maxLines);
+  layoutCache.set(key, layout);
+  return layout;
+}
+
+function computeLabelLayout(
+  text: string,
+  font: string,
+  minWidth: number,
+  maxWidth: number,
+  maxLines: number
+): LabelLayout {
+  let lines = wrapText(text, font, maxWidth);
+  if (lines.length > maxLines) {
+    const kept = lines.slice(0, maxLines);
+    kept[maxLines - 1] = truncateLine(kept[maxLines - 1], font, maxWidth);
+    lines = kept;
+  }
+
+  const widest = Math.max(...lines.map((line) => measureTextWidth(line, font)));
+  // Round up so sub-pixel widths never wrap again in the DOM
+  return { lines, width: Math.ceil(Math.min(Math.max(widest, minWidth), maxWidth)) };
+}
+
+/**
+ * Size of a canvas node showing *text*, for layout math on nodes React Flow
+ * hasn't measured yet (fresh loads, off-screen nodes).
+ */
+export function estimateNodeSize(text: string, font: string): { width: number; height: number } {
+  const layout = layoutLabel(text, font);
+  const lineHeight = fontSizePx(font) * LABEL_LINE_HEIGHT;
+  return {
+    width: Math.max(layout.width + NODE_CHROME.width, NODE_CHROME.minWidth),
+    height: Math.ceil(Math.max(layout.lines.length * lineHeight, NODE_CHROME.iconHeight) + NODE_CHROME.height),
+  };
+}
+
+export function clearTextMeasureCache() {
+  widthCache.clear();
+  layoutCache.clear();
+  metricsVersion += 1;
+  listeners.forEach((listener) => listener());
+}
+
+export function getTextMetricsVersion() {
+  return metricsVersion;
+}
+
+let stopWatching: (() => void) | null = null;
+
+function watchMetricChanges() {
+  if (typeof window === "undefined") return () => undefined;
+  const cleanups: Array<() => void> = [];
+
+  const fonts = typeof document !== "undefined" ? document.fonts : undefined;
+  if (fonts && typeof fonts.addEventListener === "function") {
+    fonts.addEventListener("loadingdone", clearTextMeasureCache);
+    cleanups.push(() => fonts.removeEventListener("loadingdone", clearTextMeasureCache));
+  }
+
+  // Browser zoom changes the device pixel ratio, which changes glyph hinting
+  let dprQuery: MediaQueryList | null = null;
+  const onDprChange = () => {
+    dprQuery?.removeEventListener("change", onDprChange);
+    clearTextMeasureCache();
+    listenForDpr();
+  };
+  const listenForDpr = () => {
+    if (typeof window.matchMedia !== "function") return;
+    dprQuery = window.matchMedia(`(resolution: ${window.devicePixelRatio || 1}dppx)`);
+    dprQuery.addEventListener?.("change", onDprChange);
+  };
+  listenForDpr();
+  cleanups.push(() => dprQuery?.removeEventListener?.("change", onDprChange));
+
+  // Themes are applied as attributes/classes on <html> and may swap fonts
+  if (typeof MutationObserver !== "undefined") {
+    const observer = new MutationObserver(clearTextMeasureCache);
+    observer.observe(document.documentElement, { attributes: true, attributeFilter: ["class", "data-theme"] });
+    cleanups.push(() => observer.disconnect());
+  }
+
+  return () => cleanups.forEach((cleanup) => cleanup());
+}
+
+export function subscribeToTextMetrics(listener: () => void) {
+  listeners.add(listener);
+  if (!stopWatching) stopWatching = watchMetricChanges();
+  return () => {
+    listeners.delete(listener);
+    if (listeners.size === 0 && stopWatching) {
+      stopWatching();
+      stopWatching = null;
+    }
+  };
+}
@@ -0,0 +1,49 @@
+/**
+ * Color themes. Each theme is a set of overrides for the color custom
+ * properties from design-tokens (see styles/css/themes.css), selected with a
+ * `data-theme` attribute on <html>. Dark is the token default.
+ *
+ * The choice is stored in user.prefs and cached in localStorage so the right
+ * theme is applied before the profile has loaded.
+ */
+
+// Key in user.prefs
+export const THEME_PREF = "theme";
+export const THEME_STORAGE_KEY = "zerg:theme";
+
+export const THEMES = ["dark", "light", "high-contrast"] as const;
+export type Theme = (typeof THEMES)[number];
+export const DEFAULT_THEME: Theme = "dark";
+
+export const THEME_LABELS: Record<Theme, string> = {
+  dark: "Dark",
+  light: "Light",
+  "high-contrast": "High contrast",
+};
+
+export function isTheme(value: unknown): value is Theme {
+  return typeof value === "string" && (THEMES as readonly string[]).includes(value);
+}
+
+export function themeFromPrefs(prefs: Record<string, unknown> | null | undefined): Theme | null {
+  const value = prefs?.[THEME_PREF];
+  return isTheme(value) ? value : null;
+}
+
+export function cachedTheme(storage: Pick<Storage, "getItem"> | undefined): Theme {
+  try {
+    const value = storage?.getItem(THEME_STORAGE_KEY);
+    return isTheme(value) ? value : DEFAULT_THEME;
+  } catch {
+    return DEFAULT_THEME;
+  }
+}
+
+export function applyTheme(theme: Theme, root: HTMLElement = document.documentElement): void {
+  root.dataset.theme = theme;
+  try {
+    window.localStorage.setItem(THEME_STORAGE_KEY, theme);
+  } catch {
+    // Storage may be unavailable (private mode); the attribute is what matters
+  }
+}
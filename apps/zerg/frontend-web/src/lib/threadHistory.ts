@@ -0,0 +1,41 @@
+/**
+ * Paged thread history. A chat opens with the newest page of messages and
+ * pages backwards (`before_message_id`) as the user scrolls up; both kinds
+ * of page land in the same `["thread-messages", id]` cache entry, so the
+ * optimistic updates and streaming patches elsewhere keep working on a
+ * plain message array.
+ */
+
+import type { ThreadMessage } from "../services/api";
+
+// Optimistic messages use negative ids until the server assigns one
+const isPersisted = (message: ThreadMessage) => message.id > 0;
+
+/**
+ * Replace the newest part of *cached* with a freshly fetched latest *page*.
+ * Older messages already paged in are kept as long as they connect to the
+ * page; if the page doesn't overlap them (many new messages since) they are
+ * dropped and paged in again on demand.
+ */
+export function mergeLatestPage(cached: ThreadMessage[] | undefined, page: ThreadMessage[]): ThreadMessage[] {
+  const firstId = page[0]?.id;
+  if (!cached || firstId === undefined) {
+    return page;
+  }
+  const persisted = cached.filter(isPersisted);
+  const older = persisted.filter((message) => message.id < firstId);
+  const overlaps = persisted.some((message) => message.id >= firstId);
+  return older.length > 0 && overlaps ? [...older, ...page] : page;
+}
+
+/** Put an older *page* in front of *current*, skipping anything already there. */
+export function prependOlderPage(current: ThreadMessage[], page: ThreadMessage[]): ThreadMessage[] {
+  const oldestId = current.find(isPersisted)?.id;
+  const older = oldestId === undefined ? page : page.filter((message) => message.id < oldestId);
+  return older.length > 0 ? [...older, ...current] : current;
+}
+
+/** Id to page back from: the oldest message the client already has. */
+export function oldestMessageId(messages: ThreadMessage[]): number | null {
+  return messages.find(isPersisted)?.id ?? null;
+}
@@ -0,0 +1,59 @@
+/**
+ * Helpers for an agent's ``allowed_tools`` list.
+ *
+ * Mirrors the backend resolver: an empty list allows every tool, and an
+ * entry ending in ``*`` allows every tool with that prefix.
+ */
+
+export function isToolAllowed(name: string, allowed: readonly string[]): boolean {
+  if (allowed.length === 0) return true;
+  return allowed.some((pattern) => (pattern.endsWith("*") ? name.startsWith(pattern.slice(0, -1)) : pattern === name));
+}
+
+/**
+ * Allowlist after switching one tool on or off. Turning a tool off while
+ * everything is allowed pins the list to the remaining *available* tools;
+ * tools matched only by a wildcard are expanded so the others stay allowed.
+ */
+export function setToolAllowed(
+  name: string,
+  enabled: boolean,
+  allowed: readonly string[],
+  available: readonly string[]
+): string[] {
+  if (enabled) {
+    return isToolAllowed(name, allowed) && allowed.length > 0 ? [...allowed] : [...allowed, name];
+  }
+  if (allowed.length === 0) {
+    return available.filter((tool) => tool !== name);
+  }
+  const next = allowed.filter((pattern) => pattern !== name);
+  const wildcards = next.filter((pattern) => pattern.endsWith("*") && name.startsWith(pattern.slice(0, -1)));
+  if (wildcards.length === 0) return next;
+  const expanded = available.filter(
+    (tool) => tool !== name && wildcards.some((pattern) => tool.startsWith(pattern.slice(0, -1)))
+  );
+  return Array.from(new Set([...next.filter((pattern) => !wildcards.includes(pattern)), ...expanded]));
+}
+
+/** Allowed tools that have never been called. */
+export function unusedAllowedTools(
+  allowed: readonly string[],
+  available: readonly string[],
+  used: ReadonlySet<string>
+): string[] {
+  return available.filter((tool) => isToolAllowed(tool, allowed) && !used.has(tool));
+}
+
+/**
+ * Allowlist keeping only the tools that have been used. Returns ``null``
+ * when nothing has been used, since an empty list would allow everything.
+ */
+export function withoutUnusedTools(
+  allowed: readonly string[],
+  available: readonly string[],
+  used: ReadonlySet<string>
+): string[] | null {
+  const kept = available.filter((tool) => isToolAllowed(tool, allowed) && used.has(tool));
+  return kept.length > 0 ? kept : null;
+}
@@ -0,0 +1,154 @@
+// Builds playground form fields from a tool's JSON parameter schema and turns
+// the entered strings back into the arguments object the tool expects.
+
+export type ToolFieldKind = "string" | "number" | "integer" | "boolean" | "json";
+
+export interface ToolField {
+  name: string;
+  label: string;
+  kind: ToolFieldKind;
+  required: boolean;
+  description?: string;
+  options?: string[];
+  placeholder?: string;
+}
+
+type JsonSchema = {
+  type?: string | string[];
+  title?: string;
+  description?: string;
+  enum?: unknown[];
+  default?: unknown;
+  anyOf?: JsonSchema[];
+  oneOf?: JsonSchema[];
+  properties?: Record<string, JsonSchema>;
+  required?: string[];
+};
+
+// Optional[X] arrives as anyOf [X, null]; the form only cares about X
+function unwrapNullable(schema: JsonSchema): JsonSchema {
+  const variants = schema.anyOf ?? schema.oneOf;
+  if (!variants) return schema;
+  const concrete = variants.filter((variant) => variant.type !== "null");
+  if (concrete.length !== 1) return schema;
+  return {
+    ...concrete[0],
+    title: schema.title ?? concrete[0].title,
+    description: schema.description ?? concrete[0].description,
+    default: schema.default ?? concrete[0].default,
+  };
+}
+
+function fieldKind(schema: JsonSchema): ToolFieldKind {
+  const type = Array.isArray(schema.type) ? schema.type.find((t) => t !== "null") : schema.type;
+  switch (type) {
+    case "string":
+    case "number":
+    case "integer":
+    case "boolean":
+      return type;
+    default:
+      return "json";
+  }
+}
+
+export function schemaFields(schema: Record<string, unknown> | null | undefined): ToolField[] {
+  const root = (schema ?? {}) as JsonSchema;
+  const required = new Set(root.required ?? []);
+  return Object.entries(root.properties ?? {}).map(([name, raw]) => {
+    const property = unwrapNullable(raw);
+    const kind = fieldKind(property);
+    return {
+      name,
+      label: property.title ?? name,
+      kind,
+      required: required.has(name),
+      description: property.description,
+      options: property.enum?.map(String),
+      placeholder: kind === "json" ? (property.type === "array" ? "[]" : "{}") : undefined,
+    };
+  });
+}
+
+export function initialValues(schema: Record<string, unknown> | null | undefined): Record<string, string> {
+  const properties = ((schema ?? {}) as JsonSchema).properties ?? {};
+  const values: Record<string, string> = {};
+  for (const [name, raw] of Object.entries(properties)) {
+    const fallback = unwrapNullable(raw).default;
+    if (fallback === undefined || fallback === null) {
+      values[name] = "";
+    } else {
+      values[name] = typeof fallback === "string" ? fallback : JSON.stringify(fallback);
+    }
+  }
+  return values;
+}
+
+export type ArgsResult =
+  | { ok: true; args: Record<string, unknown> }
+  | { ok: false; errors: Record<string, string> };
+
+export function buildArgs(fields: ToolField[], values: Record<string, string>): ArgsResult {
+  const args: Record<string, unknown> = {};
+  const errors: Record<string, string> = {};
+
+  for (const field of fields) {
+    const text = (values[field.name] ?? "").trim();
+    if (text === "") {
+      // An untouched required checkbox means false; anything else left blank is omitted
+      if (field.required) {
+        if (field.kind === "boolean") args[field.name] = false;
+        else errors[field.name] = "Required";
+      }
+      continue;
+    }
+    switch (field.kind) {
+      case "string":
+        args[field.name] = values[field.name];
+        break;
+      case "boolean":
+        args[field.name] = text === "true";
+        break;
+      case "number":
+      case "integer": {
+        const parsed = Number(text);
+        if (!Number.isFinite(parsed) || (field.kind === "integer" && !Number.isInteger(parsed))) {
+          errors[field.name] = field.kind === "integer" ? "Must be a whole number" : "Must be a number";
+        } else {
+          args[field.name] = parsed;
+        }
+        break;
+      }
+      case "json":
+        try {
+          args[field.name] = JSON.parse(text);
+        } catch {
+          errors[field.name] = "Must be valid JSON";
+        }
+        break;
+    }
+  }
+
+  return Object.keys(errors).length > 0 ? { ok: false, errors } : { ok: true, args };
+}
+
+type ValidationIssue = { loc?: unknown[]; msg?: string };
+
+// Maps a 422 detail (pydantic error list) onto form field names
+export function argErrorsFromDetail(detail: unknown): Record<string, string> {
+  if (!Array.isArray(detail)) return {};
+  const errors: Record<string, string> = {};
+  for (const issue of detail as ValidationIssue[]) {
+    const name = issue.loc?.find((part) => typeof part === "string" && part !== "body" && part !== "args");
+    if (typeof name === "string" && !errors[name]) {
+      errors[name] = issue.msg ?? "Invalid value";
+    }
+  }
+  return errors;
+}
+
+// Pretty view of a result: JSON gets indented, anything else is shown as-is
+export function formatToolResult(result: unknown): string {
+  if (typeof result === "string") return result;
+  return JSON.stringify(result, null, 2) ?? "";
+}
@@ -0,0 +1,38 @@
+/**
+ * Backend trace IDs.
+ *
+ * Every API response carries an ``X-Request-ID`` header and WebSocket events
+ * caused by a request carry the same id as ``trace_id``. The most recent one
+ * is remembered so telemetry reports can be matched to backend logs, and
+ * API errors append theirs to the message so it shows up in error toasts.
+ */
+
+export const TRACE_ID_HEADER = "X-Request-ID";
+
+const TRACE_ID_IN_MESSAGE = /\(trace ID: ([A-Za-z0-9._-]+)\)/;
+
+let lastTraceId: string | null = null;
+
+export function rememberTraceId(traceId: string | null | undefined) {
+  if (traceId) lastTraceId = traceId;
+}
+
+export function getLastTraceId(): string | null {
+  return lastTraceId;
+}
+
+export function traceIdFromResponse(response: Pick<Response, "headers">): string | null {
+  // Test doubles for fetch don't always provide headers
+  const traceId = response.headers?.get(TRACE_ID_HEADER) ?? null;
+  rememberTraceId(traceId);
+  return traceId;
+}
+
+export function appendTraceId(message: string, traceId: string | null | undefined): string {
+  return traceId ? `${message} (trace ID: ${traceId})` : message;
+}
+
+export function extractTraceId(message: unknown): string | null {
+  if (typeof message !== "string") return null;
+  return TRACE_ID_IN_MESSAGE.exec(message)?.[1] ?? null;
+}
@@ -0,0 +1,77 @@
+/**
+ * Trash shared by agents and workflows. Both stay restorable for the same
+ * retention window; the trash page and the "Recently deleted" lists show
+ * them merged, most recently deleted first.
+ */
+
+import type { QueryClient } from "@tanstack/react-query";
+import {
+  AGENT_TRASH_RETENTION_DAYS,
+  restoreAgent,
+  restoreWorkflow,
+  type AgentSummary,
+  type Workflow,
+} from "../services/api";
+
+export type TrashedKind = "agent" | "workflow";
+
+export interface TrashedItem {
+  kind: TrashedKind;
+  id: number;
+  name: string;
+  deletedAt: string | null;
+}
+
+export const TRASHED_KIND_LABELS: Record<TrashedKind, string> = {
+  agent: "Agent",
+  workflow: "Workflow",
+};
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export function daysUntilPurge(deletedAt: string | null | undefined, now: number = Date.now()): number {
+  if (!deletedAt) return AGENT_TRASH_RETENTION_DAYS;
+  const elapsedDays = (now - new Date(deletedAt).getTime()) / DAY_MS;
+  return Math.max(0, Math.ceil(AGENT_TRASH_RETENTION_DAYS - elapsedDays));
+}
+
+type Trashed = { id: number; name: string; deleted_at?: string | null };
+
+export function toTrashedItem(kind: TrashedKind, item: Trashed): TrashedItem {
+  return { kind, id: item.id, name: item.name, deletedAt: item.deleted_at ?? null };
+}
+
+/** Agents and workflows in one list, newest deletion first, capped at *limit*. */
+export function mergeTrashed(
+  agents: readonly Pick<AgentSummary, "id" | "name" | "deleted_at">[],
+  workflows: readonly Pick<Workflow, "id" | "name" | "deleted_at">[],
+  limit: number = Infinity
+): TrashedItem[] {
+  const time = (item: TrashedItem) => (item.deletedAt ? new Date(item.deletedAt).getTime() : 0);
+  return [
+    ...agents.map((agent) => toTrashedItem("agent", agent)),
+    ...workflows.map((workflow) => toTrashedItem("workflow", workflow)),
+  ]
+    .sort((left, right) => time(right) - time(left))
+    .slice(0, limit);
+}
+
+export function restoreTrashed(item: Pick<TrashedItem, "kind" | "id">): Promise<{ name: string }> {
+  return item.kind === "workflow" ? restoreWorkflow(item.id) : restoreAgent(item.id);
+}
+
+// Every list a restore or delete can change
+const TRASH_AFFECTED_QUERIES = [
+  "agents-trash",
+  "workflows-trash",
+  "dashboard",
+  "agents",
+  "workflows",
+  "quick-switcher",
+];
+
+export function invalidateTrashLists(queryClient: QueryClient) {
+  for (const key of TRASH_AFFECTED_QUERIES) {
+    queryClient.invalidateQueries({ queryKey: [key] });
+  }
+}
@@ -0,0 +1,206 @@
+/**
+ * "Why didn't it fire?" – evaluate a trigger's conditions against a sample
+ * payload in the browser, one check per condition, so a filter that never
+ * matches can be debugged without waiting for a real event.
+ *
+ * The checks mirror the backend matchers: ``email_filtering.matches``,
+ * ``slack_events.is_actionable`` / ``trigger_matches`` and
+ * ``github_events.skip_reason``. Keep them in step when those change.
+ */
+
+import type { Trigger } from "../services/api";
+import type { EmailTriggerFilters } from "./emailFilters";
+
+export interface DiagnosticCheck {
+  label: string;
+  passed: boolean;
+  detail?: string;
+}
+
+export interface TriggerDiagnosis {
+  fires: boolean;
+  checks: DiagnosticCheck[];
+}
+
+type Payload = Record<string, unknown>;
+
+function asObject(value: unknown): Payload {
+  return value && typeof value === "object" && !Array.isArray(value) ? (value as Payload) : {};
+}
+
+function asStrings(value: unknown): string[] {
+  return Array.isArray(value) ? value.filter((item): item is string => typeof item === "string") : [];
+}
+
+function text(value: unknown): string {
+  return typeof value === "string" ? value : "";
+}
+
+/** Parse the pasted sample; it must be a JSON object like a real delivery. */
+export function parseSamplePayload(raw: string): { sample: Payload } | { error: string } {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(raw);
+  } catch (error) {
+    return { error: `Invalid JSON: ${(error as Error).message}` };
+  }
+  if (parsed === null || typeof parsed !== "object" || Array.isArray(parsed)) {
+    return { error: "Sample payload must be a JSON object" };
+  }
+  return { sample: parsed as Payload };
+}
+
+/** A starting point for the paste box, in the shape each matcher reads. */
+export const SAMPLE_PAYLOADS: Record<string, Payload> = {
+  email: {
+    labelIds: ["INBOX"],
+    headers: { From: "alice@example.com", Subject: "Invoice #42" },
+    hasAttachment: false,
+  },
+  slack: { type: "app_mention", channel: "C0123456", user: "U0123", text: "<@U0APP> deploy the api" },
+  github: { action: "opened", repository: { full_name: "octocat/hello" }, sender: { login: "octocat" } },
+  webhook: { event: "test", message: "Hello from Zerg" },
+};
+
+function diagnoseEmail(config: Payload, sample: Payload): DiagnosticCheck[] {
+  const filters = asObject(config.filters) as EmailTriggerFilters;
+  const labels = asStrings(sample.labelIds);
+  const headers = asObject(sample.headers);
+  const from = text(headers.From);
+  const subject = text(headers.Subject);
+  const checks: DiagnosticCheck[] = [];
+
+  const containsAny = (haystack: string, needles: string[]) =>
+    needles.some((needle) => haystack.toLowerCase().includes(needle.toLowerCase()));
+
+  if (filters.label_include?.length) {
+    const missing = filters.label_include.filter((label) => !labels.includes(label));
+    checks.push({
+      label: `Labelled ${filters.label_include.join(" and ")}`,
+      passed: missing.length === 0,
+      detail: missing.length ? `Missing ${missing.join(", ")}` : undefined,
+    });
+  }
+  if (filters.label_exclude?.length) {
+    const present = filters.label_exclude.filter((label) => labels.includes(label));
+    checks.push({
+      label: `Not labelled ${filters.label_exclude.join(" or ")}`,
+      passed: present.length === 0,
+      detail: present.length ? `Has ${present.join(", ")}` : undefined,
+    });
+  }
+  if (filters.from_contains?.length) {
+    checks.push({
+      label: `From contains ${filters.from_contains.join(" or ")}`,
+      passed: containsAny(from, filters.from_contains),
+      detail: from ? `From is “${from}”` : "No From header",
+    });
+  }
+  if (filters.subject_contains?.length) {
+    checks.push({
+      label: `Subject contains ${filters.subject_contains.join(" or ")}`,
+      passed: containsAny(subject, filters.subject_contains),
+      detail: subject ? `Subject is “${subject}”` : "No Subject header",
+    });
+  }
+  if (filters.has_attachment) {
+    checks.push({ label: "Has an attachment", passed: sample.hasAttachment === true });
+  }
+  const tokens = (filters.query ?? "").split(/\s+/).filter(Boolean);
+  if (tokens.length) {
+    const haystack = `${from} ${subject}`.toLowerCase();
+    const missing = tokens.filter((token) => !haystack.includes(token.toLowerCase()));
+    checks.push({
+      label: `From or subject contains every word of “${filters.query}”`,
+      passed: missing.length === 0,
+      detail: missing.length ? `Missing ${missing.join(", ")}` : undefined,
+    });
+  }
+  if (checks.length === 0) checks.push({ label: "No filters – every new email fires", passed: true });
+  return checks;
+}
+
+function diagnoseSlack(config: Payload, sample: Payload): DiagnosticCheck[] {
+  // Accept either the bare event or Slack's event_callback envelope
+  const event = sample.type === "event_callback" ? asObject(sample.event) : sample;
+  const type = text(event.type);
+  const eventType = text(config.event_type);
+  const channel = text(config.channel);
+  const keyword = text(config.keyword).trim().toLowerCase();
+  let ignored: string | undefined;
+  if (event.bot_id) ignored = "Bot posts are ignored";
+  else if (event.subtype) ignored = `Subtype “${text(event.subtype)}” is ignored`;
+  const checks: DiagnosticCheck[] = [
+    { label: "Posted by a person", passed: !ignored, detail: ignored },
+    { label: `Event is ${eventType || "(not set)"}`, passed: type === eventType, detail: `Got ${type || "(none)"}` },
+  ];
+  if (channel) {
+    const eventChannel = type === "reaction_added" ? text(asObject(event.item).channel) : text(event.channel);
+    checks.push({
+      label: `In channel ${channel}`,
+      passed: eventChannel === channel,
+      detail: `Channel is ${eventChannel || "(none)"}`,
+    });
+  }
+  if (keyword) {
+    const haystack = type === "reaction_added" ? text(event.reaction) : text(event.text);
+    checks.push({
+      label: type === "reaction_added" ? `Reaction is :${keyword}:` : `Text contains “${keyword}”`,
+      passed: haystack.toLowerCase().includes(keyword),
+    });
+  }
+  return checks;
+}
+
+function diagnoseGitHub(config: Payload, sample: Payload, githubEvent: string): DiagnosticCheck[] {
+  const events = asStrings(config.events);
+  const repo = text(config.repo);
+  const actions = asStrings(config.actions);
+  const payloadRepo = text(asObject(sample.repository).full_name);
+  const checks: DiagnosticCheck[] = [
+    { label: `Event is one of ${events.join(", ") || "(none)"}`, passed: events.includes(githubEvent) },
+    {
+      label: `Repository is ${repo}`,
+      passed: payloadRepo.toLowerCase() === repo.toLowerCase(),
+      detail: `Payload is for ${payloadRepo || "an unknown repository"}`,
+    },
+  ];
+  if (actions.length && githubEvent !== "push") {
+    const action = text(sample.action);
+    checks.push({
+      label: `Action is one of ${actions.join(", ")}`,
+      passed: actions.includes(action),
+      detail: `Action is ${action || "(none)"}`,
+    });
+  }
+  return checks;
+}
+
+/**
+ * Evaluate *trigger* against *sample*. GitHub sends the event name in a
+ * header rather than the body, so it is passed separately.
+ */
+export function diagnoseTrigger(
+  trigger: Pick<Trigger, "type" | "config">,
+  sample: Payload,
+  options: { githubEvent?: string } = {},
+): TriggerDiagnosis {
+  const config = asObject(trigger.config);
+  const checks: DiagnosticCheck[] = [];
+  if (config.disabled === true) {
+    checks.push({ label: "Trigger is enabled", passed: false, detail: text(config.disabled_reason) || undefined });
+  }
+
+  if (trigger.type === "email") checks.push(...diagnoseEmail(config, sample));
+  else if (trigger.type === "slack") checks.push(...diagnoseSlack(config, sample));
+  else if (trigger.type === "github") checks.push(...diagnoseGitHub(config, sample, options.githubEvent ?? ""));
+  else {
+    checks.push({
+      label: "Any signed JSON object fires",
+      passed: true,
+      detail: "The signature and timestamp are checked on delivery; see the history for rejections",
+    });
+  }
+
+  return { fires: checks.every((check) => check.passed), checks };
+}
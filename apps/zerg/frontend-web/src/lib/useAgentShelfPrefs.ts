@@ -0,0 +1,89 @@
+import { useCallback, useEffect, useRef, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { useAuth } from "./auth";
+import { updateUserPrefs } from "../services/api";
+import {
+  SHELF_COLLAPSED_PREF,
+  SHELF_FAVORITES_PREF,
+  SHELF_GROUPING_PREF,
+  collapsedGroupsFromPrefs,
+  favoritesFromPrefs,
+  groupingFromPrefs,
+  toggleInList,
+  type ShelfGrouping,
+} from "./agentShelf";
+
+interface ShelfPrefs {
+  favorites: number[];
+  grouping: ShelfGrouping;
+  collapsed: string[];
+}
+
+function shelfPrefsFrom(prefs: Record<string, unknown> | null | undefined): ShelfPrefs {
+  return {
+    favorites: favoritesFromPrefs(prefs),
+    grouping: groupingFromPrefs(prefs),
+    collapsed: collapsedGroupsFromPrefs(prefs),
+  };
+}
+
+/**
+ * The agent shelf's favorites, grouping and collapsed groups. Changes apply
+ * at once and are saved to the user's profile prefs in the background.
+ */
+export function useAgentShelfPrefs() {
+  const { user } = useAuth();
+  const queryClient = useQueryClient();
+  const [shelf, setShelf] = useState<ShelfPrefs>(() => shelfPrefsFrom(user?.prefs));
+  // Latest local state, so quick successive toggles build on each other
+  const shelfRef = useRef(shelf);
+  shelfRef.current = shelf;
+
+  useEffect(() => {
+    setShelf(shelfPrefsFrom(user?.prefs));
+  }, [user?.prefs]);
+
+  const save = useMutation({
+    mutationFn: (next: ShelfPrefs) =>
+      updateUserPrefs({
+        ...(user?.prefs ?? {}),
+        [SHELF_FAVORITES_PREF]: next.favorites,
+        [SHELF_GROUPING_PREF]: next.grouping,
+        [SHELF_COLLAPSED_PREF]: next.collapsed,
+      }),
+    onSuccess: (updated) => {
+      queryClient.setQueryData(["current-user"], (prev: Record<string, unknown> | undefined) =>
+        prev ? { ...prev, prefs: updated.prefs } : prev
+      );
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save shelf settings: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const update = useCallback(
+    (change: (current: ShelfPrefs) => ShelfPrefs) => {
+      const next = change(shelfRef.current);
+      shelfRef.current = next;
+      setShelf(next);
+      if (user) save.mutate(next);
+    },
+    [user, save]
+  );
+
+  const toggleFavorite = useCallback(
+    (agentId: number) => update((current) => ({ ...current, favorites: toggleInList(current.favorites, agentId) })),
+    [update]
+  );
+  const toggleGroup = useCallback(
+    (groupKey: string) => update((current) => ({ ...current, collapsed: toggleInList(current.collapsed, groupKey) })),
+    [update]
+  );
+  const setGrouping = useCallback(
+    (grouping: ShelfGrouping) => update((current) => ({ ...current, grouping })),
+    [update]
+  );
+
+  return { ...shelf, toggleFavorite, toggleGroup, setGrouping };
+}
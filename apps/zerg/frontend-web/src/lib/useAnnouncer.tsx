@@ -0,0 +1,53 @@
+import { createContext, useCallback, useContext, useRef, useState, type ReactNode } from "react";
+import { shouldAnnounce, type Announcement } from "./announcements";
+
+type Announce = (announcement: Announcement) => void;
+
+const AnnouncerContext = createContext<Announce | null>(null);
+
+// Screen readers only speak a live region when its text changes; clearing it
+// first lets an identical message be read again
+const CLEAR_DELAY_MS = 50;
+
+/**
+ * Visually hidden aria-live regions (polite and assertive) that components
+ * push short status messages into through useAnnounce().
+ */
+export function AnnouncerProvider({ children }: { children: ReactNode }) {
+  const [messages, setMessages] = useState({ polite: "", assertive: "" });
+  const recent = useRef(new Map<string, number>());
+
+  const announce = useCallback<Announce>((announcement) => {
+    if (!shouldAnnounce(recent.current, announcement, Date.now())) return;
+    const { politeness, message } = announcement;
+    setMessages((current) => ({ ...current, [politeness]: "" }));
+    window.setTimeout(() => {
+      setMessages((current) => ({ ...current, [politeness]: message }));
+    }, CLEAR_DELAY_MS);
+  }, []);
+
+  return (
+    <AnnouncerContext.Provider value={announce}>
+      {children}
+      <div className="sr-only" role="status" aria-live="polite" aria-atomic="true" data-testid="live-region-polite">
+        {messages.polite}
+      </div>
+      <div
+        className="sr-only"
+        role="alert"
+        aria-live="assertive"
+        aria-atomic="true"
+        data-testid="live-region-assertive"
+      >
+        {messages.assertive}
+      </div>
+    </AnnouncerContext.Provider>
+  );
+}
+
+const noop: Announce = () => undefined;
+
+/** announce() for components that may render outside the provider (e.g. in tests). */
+export function useAnnounce(): Announce {
+  return useContext(AnnouncerContext) ?? noop;
+}
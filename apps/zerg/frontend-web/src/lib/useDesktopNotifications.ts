@@ -0,0 +1,64 @@
+import { useCallback, useMemo, useRef } from "react";
+import { useNavigate } from "react-router-dom";
+import { useAuth } from "./auth";
+import { useTimePreferences } from "./useTimePreferences";
+import {
+  browserNotificationPermission,
+  desktopNotificationPrefs,
+  runFinishedNotification,
+  shouldNotifyDesktop,
+} from "./desktopNotifications";
+
+const NOTIFICATION_ICON = "/Gemini_Generated_Image_klhmhfklhmhfklhm-removebg-preview.png";
+
+export interface FinishedRun {
+  runId: number;
+  agentId: number;
+  agentName: string;
+  status: "success" | "failed";
+  error?: string | null;
+  threadId?: number;
+}
+
+/**
+ * Returns a callback that shows a desktop notification for a finished run
+ * if the user's preferences allow it right now. Each run notifies once.
+ */
+export function useRunFinishedNotifier(): (run: FinishedRun) => void {
+  const { user } = useAuth();
+  const { timeZone } = useTimePreferences();
+  const navigate = useNavigate();
+  const prefs = useMemo(() => desktopNotificationPrefs(user?.prefs), [user?.prefs]);
+  const notified = useRef(new Set<number>());
+
+  return useCallback(
+    (run: FinishedRun) => {
+      if (notified.current.has(run.runId)) return;
+      const allowed = shouldNotifyDesktop({
+        prefs,
+        permission: browserNotificationPermission(),
+        hidden: document.visibilityState === "hidden",
+        now: new Date(),
+        timeZone,
+      });
+      if (!allowed) return;
+      notified.current.add(run.runId);
+
+      const { title, body } = runFinishedNotification(run.agentName, run.status, run.error);
+      try {
+        const notification = new Notification(title, { body, tag: `run-${run.runId}`, icon: NOTIFICATION_ICON });
+        notification.onclick = () => {
+          window.focus();
+          if (run.threadId !== undefined) {
+            navigate(`/agent/${run.agentId}/thread/${run.threadId}`);
+          }
+          notification.close();
+        };
+      } catch (error) {
+        // Some browsers (Android Chrome) only allow notifications from a service worker
+        console.warn("Desktop notification failed:", error);
+      }
+    },
+    [prefs, timeZone, navigate]
+  );
+}
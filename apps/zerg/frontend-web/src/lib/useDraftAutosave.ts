@@ -0,0 +1,29 @@
+import { useCallback, useEffect } from "react";
+import { draftSlice, draftWriter } from "./drafts";
+import { persistence, type PersistenceQueue } from "./persistence";
+
+/**
+ * Autosave *value* as the draft stored under *key* (see lib/drafts.ts);
+ * `null` means there is nothing worth keeping and removes the draft. Switching
+ * *key* flushes the previous draft first, so the caller must change key and
+ * value together. Returns `discard`, which drops the draft right away.
+ */
+export function useDraftAutosave<T>(key: string | null, value: T | null, queue: PersistenceQueue = persistence) {
+  useEffect(() => {
+    if (key === null) return;
+    return queue.register(draftSlice(key), draftWriter<T>(key));
+  }, [key, queue]);
+
+  useEffect(() => {
+    if (key === null) return;
+    queue.schedule(draftSlice(key), value === null ? null : { value, savedAt: Date.now() });
+  }, [key, queue, value]);
+
+  return {
+    discard: useCallback(() => {
+      if (key === null) return;
+      queue.schedule(draftSlice(key), null);
+      queue.flush(draftSlice(key));
+    }, [key, queue]),
+  };
+}
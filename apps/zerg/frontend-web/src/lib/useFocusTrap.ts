@@ -0,0 +1,124 @@
+import { useEffect, useRef } from "react";
+import { isImeComposing } from "./ime";
+
+/**
+ * Focus management for modal dialogs and drawers.
+ *
+ * While active, Tab/Shift+Tab cycle inside the container, Escape calls
+ * `onEscape`, and focus moves into the dialog. On close, focus returns to the
+ * element that opened it. Traps stack: when a modal opens another modal only
+ * the topmost one handles keys, so Escape closes one layer at a time.
+ */
+
+const FOCUSABLE_SELECTOR = [
+  "a[href]",
+  "area[href]",
+  "button:not([disabled])",
+  'input:not([disabled]):not([type="hidden"])',
+  "select:not([disabled])",
+  "textarea:not([disabled])",
+  "iframe",
+  '[contenteditable="true"]',
+  '[tabindex]:not([tabindex="-1"])',
+].join(",");
+
+type TrapEntry = { id: symbol; container: HTMLElement };
+
+// Topmost (innermost) trap last
+const trapStack: TrapEntry[] = [];
+
+function pushTrap(entry: TrapEntry) {
+  // Child effects run before their parent's, so a dialog rendered inside
+  // another in the same commit is registered first; keep it on top anyway
+  const nestedIndex = trapStack.findIndex((other) => entry.container.contains(other.container));
+  if (nestedIndex === -1) {
+    trapStack.push(entry);
+  } else {
+    trapStack.splice(nestedIndex, 0, entry);
+  }
+}
+
+export function getFocusableElements(container: HTMLElement): HTMLElement[] {
+  return Array.from(container.querySelectorAll<HTMLElement>(FOCUSABLE_SELECTOR)).filter(
+    (element) => !element.closest("[hidden], [inert], [aria-hidden='true']")
+  );
+}
+
+/**
+ * Where Tab should land when it has to wrap, or null to let the browser move
+ * focus normally.
+ */
+export function nextTrapFocus(
+  elements: HTMLElement[],
+  current: Element | null,
+  backwards: boolean
+): HTMLElement | null {
+  if (elements.length === 0) return null;
+  const first = elements[0];
+  const last = elements[elements.length - 1];
+  const index = current ? elements.indexOf(current as HTMLElement) : -1;
+  if (index === -1) return backwards ? last : first;
+  if (backwards && index === 0) return last;
+  if (!backwards && index === elements.length - 1) return first;
+  return null;
+}
+
+export function useFocusTrap<T extends HTMLElement = HTMLDivElement>(active: boolean, onEscape?: () => void) {
+  const containerRef = useRef<T>(null);
+  const onEscapeRef = useRef(onEscape);
+
+  useEffect(() => {
+    onEscapeRef.current = onEscape;
+  }, [onEscape]);
+
+  useEffect(() => {
+    const container = containerRef.current;
+    if (!active || !container) return;
+
+    const id = Symbol("focus-trap");
+    pushTrap({ id, container });
+    const previouslyFocused = document.activeElement instanceof HTMLElement ? document.activeElement : null;
+
+    if (!container.contains(document.activeElement)) {
+      const preferred = container.querySelector<HTMLElement>("[data-autofocus]");
+      (preferred ?? getFocusableElements(container)[0] ?? container).focus();
+    }
+
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (trapStack[trapStack.length - 1]?.id !== id) return;
+
+      // Escape during IME composition cancels the candidate, not the dialog
+      if (event.key === "Escape" && !isImeComposing(event) && onEscapeRef.current) {
+        event.preventDefault();
+        event.stopPropagation();
+        onEscapeRef.current();
+        return;
+      }
+      if (event.key !== "Tab") return;
+
+      const elements = getFocusableElements(container);
+      if (elements.length === 0) {
+        event.preventDefault();
+        container.focus();
+        return;
+      }
+      const next = nextTrapFocus(elements, document.activeElement, event.shiftKey);
+      if (next) {
+        event.preventDefault();
+        next.focus();
+      }
+    };
+
+    document.addEventListener("keydown", handleKeyDown);
+    return () => {
+      document.removeEventListener("keydown", handleKeyDown);
+      trapStack.splice(
+        trapStack.findIndex((entry) => entry.id === id),
+        1
+      );
+      if (previouslyFocused?.isConnected) previouslyFocused.focus();
+    };
+  }, [active]);
+
+  return containerRef;
+}
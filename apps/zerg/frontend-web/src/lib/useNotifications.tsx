@@ -0,0 +1,132 @@
+import { createContext, useCallback, useContext, useEffect, useMemo, useRef, useState, type ReactNode } from "react";
+import { useToasterStore } from "react-hot-toast";
+import {
+  addNotification,
+  createNotification,
+  loadNotifications,
+  markAllRead as markAllReadIn,
+  markRead as markReadIn,
+  notificationFromToast,
+  unreadCount,
+  withoutUndo,
+  NOTIFICATIONS_STORAGE_KEY,
+  type AppNotification,
+  type NewNotification,
+} from "./notifications";
+
+interface NotificationsContextType {
+  notifications: AppNotification[];
+  unread: number;
+  persist: boolean;
+  notify: (input: NewNotification) => void;
+  markRead: (id: string) => void;
+  markAllRead: () => void;
+  clearUndo: (id: string) => void;
+  clear: () => void;
+  setPersist: (persist: boolean) => void;
+}
+
+const NotificationsContext = createContext<NotificationsContextType | null>(null);
+
+const PERSIST_STORAGE_KEY = "zerg:notifications:persist";
+
+function browserStorage(): Storage | undefined {
+  return typeof window === "undefined" || typeof localStorage === "undefined" ? undefined : localStorage;
+}
+
+// History survives reloads unless the user turned that off
+function readPersistPreference(): boolean {
+  try {
+    return browserStorage()?.getItem(PERSIST_STORAGE_KEY) !== "false";
+  } catch {
+    return true;
+  }
+}
+
+/**
+ * Records every toast plus whatever callers report through notify(), so the
+ * header bell can list what happened while the user looked elsewhere.
+ */
+export function NotificationsProvider({ children }: { children: ReactNode }) {
+  const [persist, setPersistState] = useState(readPersistPreference);
+  const [notifications, setNotifications] = useState<AppNotification[]>(() =>
+    persist ? loadNotifications(browserStorage()) : []
+  );
+
+  useEffect(() => {
+    const storage = browserStorage();
+    if (!storage) return;
+    try {
+      if (persist) {
+        storage.setItem(NOTIFICATIONS_STORAGE_KEY, JSON.stringify(notifications));
+      } else {
+        storage.removeItem(NOTIFICATIONS_STORAGE_KEY);
+      }
+    } catch (error) {
+      console.warn("Failed to persist notifications:", error);
+    }
+  }, [notifications, persist]);
+
+  const notify = useCallback((input: NewNotification) => {
+    setNotifications((current) => addNotification(current, createNotification(input)));
+  }, []);
+
+  // react-hot-toast keeps its own store; pick up each toast once it settles
+  const { toasts } = useToasterStore();
+  const recorded = useRef(new Set<string>());
+  useEffect(() => {
+    for (const toast of toasts) {
+      const key = `${toast.id}:${toast.type}`;
+      if (recorded.current.has(key)) continue;
+      const entry = notificationFromToast(toast);
+      if (!entry) continue;
+      recorded.current.add(key);
+      notify(entry);
+    }
+  }, [toasts, notify]);
+
+  const markRead = useCallback((id: string) => setNotifications((current) => markReadIn(current, id)), []);
+  const markAllRead = useCallback(() => setNotifications(markAllReadIn), []);
+  const clearUndo = useCallback((id: string) => setNotifications((current) => withoutUndo(current, id)), []);
+  const clear = useCallback(() => setNotifications([]), []);
+  const setPersist = useCallback((value: boolean) => {
+    setPersistState(value);
+    try {
+      browserStorage()?.setItem(PERSIST_STORAGE_KEY, String(value));
+    } catch {
+      // Preference just won't stick across reloads
+    }
+  }, []);
+
+  const value = useMemo(
+    () => ({
+      notifications,
+      unread: unreadCount(notifications),
+      persist,
+      notify,
+      markRead,
+      markAllRead,
+      clearUndo,
+      clear,
+      setPersist,
+    }),
+    [notifications, persist, notify, markRead, markAllRead, clearUndo, clear, setPersist]
+  );
+
+  return <NotificationsContext.Provider value={value}>{children}</NotificationsContext.Provider>;
+}
+
+const noop = () => undefined;
+
+/** notify() for components that may render outside the provider (e.g. in tests). */
+export function useNotify(): (input: NewNotification) => void {
+  return useContext(NotificationsContext)?.notify ?? noop;
+}
+
+export function useNotifications() {
+  const context = useContext(NotificationsContext);
+  if (!context) {
+    throw new Error("useNotifications must be used within NotificationsProvider");
+  }
+  return context;
+}
@@ -0,0 +1,10 @@
+import { useMemo } from "react";
+import { useAuth } from "./auth";
+import { effectivePrefs } from "./preferences";
+
+/** The user's prefs over the workspace defaults: what the app should apply. */
+export function useEffectivePrefs(): Record<string, unknown> {
+  const { user, workspaceDefaults } = useAuth();
+  const prefs = user?.prefs;
+  return useMemo(() => effectivePrefs(prefs, workspaceDefaults), [prefs, workspaceDefaults]);
+}
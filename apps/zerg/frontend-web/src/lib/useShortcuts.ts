@@ -0,0 +1,55 @@
+import { useEffect, useMemo, useRef } from "react";
+import { useAuth } from "./auth";
+import { isImeComposing } from "./ime";
+import {
+  actionForEvent,
+  bindingsFromPrefs,
+  type ShortcutActionId,
+  type ShortcutBindings,
+  type ShortcutScope,
+} from "./shortcuts";
+
+export type ShortcutHandlers = Partial<Record<ShortcutActionId, (event: KeyboardEvent) => void>>;
+
+/** The current user's key bindings (defaults plus their overrides). */
+export function useShortcutBindings(): ShortcutBindings {
+  const { user } = useAuth();
+  return useMemo(() => bindingsFromPrefs(user?.prefs), [user?.prefs]);
+}
+
+function isTypingTarget(target: EventTarget | null): boolean {
+  return (
+    target instanceof HTMLInputElement ||
+    target instanceof HTMLTextAreaElement ||
+    target instanceof HTMLSelectElement ||
+    (target instanceof HTMLElement && target.isContentEditable)
+  );
+}
+
+/**
+ * Window-level shortcuts for one page. Keys typed into form fields and IME
+ * composition keystrokes are ignored; only actions with a handler are
+ * consumed, so unbound keys keep their default behaviour.
+ */
+export function useShortcuts(scope: ShortcutScope, handlers: ShortcutHandlers, enabled: boolean = true): void {
+  const bindings = useShortcutBindings();
+  const handlersRef = useRef(handlers);
+
+  useEffect(() => {
+    handlersRef.current = handlers;
+  }, [handlers]);
+
+  useEffect(() => {
+    if (!enabled) return;
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.defaultPrevented || isImeComposing(event) || isTypingTarget(event.target)) return;
+      const action = actionForEvent(event, bindings, scope);
+      const handler = action ? handlersRef.current[action] : undefined;
+      if (!handler) return;
+      event.preventDefault();
+      handler(event);
+    };
+    window.addEventListener("keydown", handleKeyDown);
+    return () => window.removeEventListener("keydown", handleKeyDown);
+  }, [bindings, enabled, scope]);
+}
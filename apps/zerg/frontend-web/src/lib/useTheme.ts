@@ -0,0 +1,49 @@
+import { useCallback, useEffect, useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { useAuth } from "./auth";
+import { useEffectivePrefs } from "./usePreferences";
+import { updateUserPrefs } from "../services/api";
+import { THEME_PREF, applyTheme, cachedTheme, themeFromPrefs, type Theme } from "./theme";
+
+/**
+ * Current theme plus a setter that applies it immediately and saves it to the
+ * user's profile prefs. The stored preference wins once the profile loads.
+ */
+export function useTheme(): { theme: Theme; setTheme: (theme: Theme) => void } {
+  const { user } = useAuth();
+  const queryClient = useQueryClient();
+  // The user's choice, else the workspace default
+  const storedTheme = themeFromPrefs(useEffectivePrefs());
+  const [theme, setLocalTheme] = useState<Theme>(() => storedTheme ?? cachedTheme(window.localStorage));
+
+  useEffect(() => {
+    if (storedTheme) setLocalTheme(storedTheme);
+  }, [storedTheme]);
+
+  useEffect(() => {
+    applyTheme(theme);
+  }, [theme]);
+
+  const saveTheme = useMutation({
+    mutationFn: (next: Theme) => updateUserPrefs({ ...(user?.prefs ?? {}), [THEME_PREF]: next }),
+    onSuccess: (updated) => {
+      queryClient.setQueryData(["current-user"], (prev: Record<string, unknown> | undefined) =>
+        prev ? { ...prev, prefs: updated.prefs } : prev
+      );
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save theme: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const setTheme = useCallback(
+    (next: Theme) => {
+      setLocalTheme(next);
+      if (user) saveTheme.mutate(next);
+    },
+    [user, saveTheme]
+  );
+
+  return { theme, setTheme };
+}
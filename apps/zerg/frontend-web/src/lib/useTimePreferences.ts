@@ -0,0 +1,50 @@
+import { useMemo, useSyncExternalStore } from "react";
+import { timePreferencesFromPrefs, type TimePreferences } from "./datetime";
+import { useEffectivePrefs } from "./usePreferences";
+
+export function useTimePreferences(): TimePreferences {
+  const prefs = useEffectivePrefs();
+  return useMemo(() => timePreferencesFromPrefs(prefs), [prefs]);
+}
+
+// One shared clock for every relative timestamp on the page instead of an
+// interval per element
+const TICK_MS = 30_000;
+const listeners = new Set<() => void>();
+let now = Date.now();
+let timer: ReturnType<typeof setInterval> | null = null;
+
+function subscribe(listener: () => void) {
+  listeners.add(listener);
+  if (timer === null) {
+    now = Date.now();
+    timer = setInterval(() => {
+      now = Date.now();
+      listeners.forEach((notify) => notify());
+    }, TICK_MS);
+  }
+  return () => {
+    listeners.delete(listener);
+    if (listeners.size === 0 && timer !== null) {
+      clearInterval(timer);
+      timer = null;
+    }
+  };
+}
+
+function getSnapshot() {
+  // Catch up after the clock was idle; the value must stay stable between
+  // consecutive reads, so only refresh once it is a full tick old
+  if (timer === null && Date.now() - now >= TICK_MS) {
+    now = Date.now();
+  }
+  return now;
+}
+
+const noopSubscribe = () => () => undefined;
+const idleSnapshot = () => 0;
+
+/** Current time, refreshed every 30 seconds while *live* is set (0 otherwise). */
+export function useNow(live: boolean): number {
+  return useSyncExternalStore(live ? subscribe : noopSubscribe, live ? getSnapshot : idleSnapshot);
+}
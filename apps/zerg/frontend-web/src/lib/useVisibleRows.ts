@@ -0,0 +1,88 @@
+import { useEffect, useState } from "react";
+
+/**
+ * Ids of the rows inside a container that are on screen, read from a
+ * `data-*` attribute on each row. Rows added or removed by sorting and
+ * filtering are picked up through a MutationObserver, and reports are
+ * debounced so a fast scroll settles into one update.
+ *
+ * Returns a ref callback for the container and the visible ids – `null`
+ * until the first report, and forever where IntersectionObserver isn't
+ * available, so callers can treat "unknown" as "everything is visible".
+ */
+
+export interface VisibleRowsOptions {
+  // Attribute holding the numeric row id, e.g. "data-agent-id"
+  attribute: string;
+  // Rows this close to the viewport count as visible, so they are live before they scroll in
+  rootMargin?: string;
+  settleMs?: number;
+}
+
+function sameIds(a: ReadonlySet<number> | null, b: ReadonlySet<number>): boolean {
+  return a !== null && a.size === b.size && [...b].every((id) => a.has(id));
+}
+
+export function useVisibleRows({ attribute, rootMargin = "200px 0px", settleMs = 150 }: VisibleRowsOptions) {
+  const [container, setContainer] = useState<HTMLElement | null>(null);
+  const [visibleIds, setVisibleIds] = useState<ReadonlySet<number> | null>(null);
+
+  useEffect(() => {
+    if (!container || typeof IntersectionObserver === "undefined") {
+      return;
+    }
+
+    const visible = new Set<number>();
+    const observed = new Map<Element, number>();
+    let timer: number | undefined;
+    const publish = () => {
+      window.clearTimeout(timer);
+      timer = window.setTimeout(() => {
+        setVisibleIds((previous) => (sameIds(previous, visible) ? previous : new Set(visible)));
+      }, settleMs);
+    };
+
+    const intersections = new IntersectionObserver(
+      (entries) => {
+        entries.forEach((entry) => {
+          const id = observed.get(entry.target);
+          if (id === undefined) return;
+          if (entry.isIntersecting) visible.add(id);
+          else visible.delete(id);
+        });
+        publish();
+      },
+      { rootMargin }
+    );
+
+    const sync = () => {
+      const rows = new Set(container.querySelectorAll(`:scope > [${attribute}]`));
+      observed.forEach((id, row) => {
+        if (rows.has(row)) return;
+        intersections.unobserve(row);
+        observed.delete(row);
+        visible.delete(id);
+      });
+      rows.forEach((row) => {
+        const id = Number(row.getAttribute(attribute));
+        if (observed.has(row) || !Number.isFinite(id)) return;
+        observed.set(row, id);
+        intersections.observe(row);
+      });
+      publish();
+    };
+
+    const mutations = new MutationObserver(sync);
+    // Rows are direct children (table body, list), so cell re-renders don't trigger a resync
+    mutations.observe(container, { childList: true });
+    sync();
+
+    return () => {
+      window.clearTimeout(timer);
+      mutations.disconnect();
+      intersections.disconnect();
+    };
+  }, [attribute, container, rootMargin, settleMs]);
+
+  return { ref: setContainer, visibleIds };
+}
@@ -1,7 +1,12 @@
 import { useEffect, useRef, useCallback, useState } from 'react';
 import { useQueryClient } from '@tanstack/react-query';
 import { toast } from 'react-hot-toast';
+import { rememberTraceId } from './traceId';
 import { getWebSocketConfig } from './config';
+import { recordFrame } from './wsFrameLog';
+import { recordSessionMessage } from './sessionRecorder';
+import { checkIncomingEnvelope } from './wsValidation';
+import { recordTiming } from './perfMetrics';
 
 // Maximum number of messages to queue when disconnected
 // Prevents memory leak if user performs many actions while offline
@@ -147,20 +152,30 @@ export function useWebSocket(
   }, []);
 
   const handleMessage = useCallback((event: MessageEvent) => {
+    const receivedAt = performance.now();
     let message: WebSocketMessage;
 
     try {
       message = JSON.parse(event.data);
+      // Reports contract drift (diagnostics panel, dev crash overlay); the message is handled regardless
+      checkIncomingEnvelope(message);
     } catch {
       // If not JSON, treat as simple message
       message = { type: 'message', data: event.data };
     }
+    recordFrame('in', String(event.data), message);
+    recordSessionMessage('in', message);
+
+    if (typeof message.trace_id === 'string') {
+      rememberTraceId(message.trace_id);
+    }
 
     // Check if this is a streaming message
     const streamingTypes = [
       'stream_start', 'stream_chunk', 'stream_end', 'assistant_id',
       // Workflow execution events
-      'execution_started', 'node_state', 'workflow_progress', 'execution_finished'
+      'execution_started', 'node_state', 'node_log', 'workflow_progress', 'execution_paused', 'node_waiting',
+      'execution_finished'
     ];
     if (streamingTypes.includes(message.type)) {
       // Only log non-chunk messages to avoid noise (chunks logged with sampling in ChatPage)
@@ -180,6 +195,7 @@ export function useWebSocket(
         queryClient.invalidateQueries({ queryKey });
       });
     }
+    recordTiming('ws', performance.now() - receivedAt);
   }, [queryClient]);
 
   const handleConnect = useCallback(() => {
@@ -191,7 +207,10 @@ export function useWebSocket(
     if (wsRef.current && messageQueueRef.current.length > 0) {
       // console.log('[WS] 📬 Sending', messageQueueRef.current.length, 'queued messages');
       messageQueueRef.current.forEach(message => {
-        wsRef.current?.send(JSON.stringify(message));
+        const frame = JSON.stringify(message);
+        recordFrame('out', frame, message);
+        recordSessionMessage('out', message);
+        wsRef.current?.send(frame);
       });
       messageQueueRef.current = [];
     }
@@ -324,7 +343,10 @@ export function useWebSocket(
 
   const sendMessage = useCallback((message: WebSocketMessage) => {
     if (wsRef.current?.readyState === WebSocket.OPEN) {
-      wsRef.current.send(JSON.stringify(message));
+      const frame = JSON.stringify(message);
+      recordFrame('out', frame, message);
+      recordSessionMessage('out', message);
+      wsRef.current.send(frame);
     } else {
       // Queue message if not connected, but enforce bounds to prevent memory leak
       if (messageQueueRef.current.length >= MAX_QUEUED_MESSAGES) {
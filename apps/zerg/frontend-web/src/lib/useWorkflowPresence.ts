@@ -0,0 +1,116 @@
+import { useCallback, useEffect, useReducer, useRef } from "react";
+import type { PresenceEditorData, PresenceStateData, PresenceUpdateData } from "../generated/ws-messages";
+import { EMPTY_PRESENCE, applyPresenceMessage, presenceTopic, type PresenceSnapshot } from "./workflowPresence";
+
+// Cursor moves are sent at most this often
+const CURSOR_THROTTLE_MS = 50;
+
+type SendMessage = (message: { type: string; [key: string]: unknown }) => void;
+type PresenceFrame = { type: string; data?: unknown };
+type PresenceChanges = Omit<PresenceUpdateData, "workflow_id">;
+
+// A null frame resets to empty (leaving the workflow)
+function presenceReducer(state: PresenceSnapshot, frame: PresenceFrame | null): PresenceSnapshot {
+  return frame ? applyPresenceMessage(state, frame) : EMPTY_PRESENCE;
+}
+
+interface UseWorkflowPresenceOptions {
+  // Rejoin after the socket reconnects; the server forgets us on disconnect
+  connected: boolean;
+  // A remote editor reported changing a node
+  onRemoteEdit?: (editor: PresenceEditorData) => void;
+}
+
+/**
+ * Join `workflow:{id}:presence` over an existing socket and report this
+ * tab's cursor, selection and editing state. Feed every inbound frame to
+ * `handleMessage`. Nothing is queued while disconnected – presence is
+ * only meaningful live.
+ */
+export function useWorkflowPresence(
+  workflowId: number | null | undefined,
+  sendMessage: SendMessage,
+  { connected, onRemoteEdit }: UseWorkflowPresenceOptions
+) {
+  const [presence, dispatch] = useReducer(presenceReducer, EMPTY_PRESENCE);
+  const sendRef = useRef(sendMessage);
+  const onRemoteEditRef = useRef(onRemoteEdit);
+  const cursorTimerRef = useRef<number | null>(null);
+  const pendingCursorRef = useRef<PresenceChanges["cursor"]>(undefined);
+  const lastEditSeenRef = useRef(new Map<string, string>());
+  const selfClientIdRef = useRef<string | null>(null);
+
+  useEffect(() => {
+    sendRef.current = sendMessage;
+    onRemoteEditRef.current = onRemoteEdit;
+  });
+
+  useEffect(() => {
+    if (workflowId == null || !connected) return;
+    const topic = presenceTopic(workflowId);
+    sendRef.current({ type: "subscribe", topics: [topic] });
+    return () => {
+      if (cursorTimerRef.current !== null) window.clearTimeout(cursorTimerRef.current);
+      cursorTimerRef.current = null;
+      sendRef.current({ type: "unsubscribe", topics: [topic] });
+      lastEditSeenRef.current.clear();
+      selfClientIdRef.current = null;
+      dispatch(null);
+    };
+  }, [workflowId, connected]);
+
+  const send = useCallback(
+    (changes: PresenceChanges) => {
+      if (workflowId == null || !connected) return;
+      sendRef.current({ type: "update_presence", workflow_id: workflowId, ...changes });
+    },
+    [workflowId, connected]
+  );
+
+  const handleMessage = useCallback(
+    (frame: PresenceFrame) => {
+      if (!frame.type.startsWith("presence_")) return;
+      const data = frame.data as { workflow_id?: number } | undefined;
+      if (data?.workflow_id !== workflowId) return;
+      dispatch(frame);
+
+      const seen = lastEditSeenRef.current;
+      if (frame.type === "presence_state") {
+        const state = frame.data as PresenceStateData;
+        selfClientIdRef.current = state.self_client_id;
+        // Edits made before we joined are not news
+        for (const editor of state.editors) {
+          if (editor.edited_at) seen.set(editor.client_id, editor.edited_at);
+        }
+        return;
+      }
+      if (frame.type !== "presence_update") return;
+      const editor = frame.data as PresenceEditorData;
+      if (editor.client_id === selfClientIdRef.current) return;
+      // Cursor updates repeat the last edit; only report each edit once
+      if (!editor.edited_node_id || !editor.edited_at || seen.get(editor.client_id) === editor.edited_at) return;
+      seen.set(editor.client_id, editor.edited_at);
+      onRemoteEditRef.current?.(editor);
+    },
+    [workflowId]
+  );
+
+  /** Pointer position in flow coordinates, or null when it leaves the canvas. */
+  const reportCursor = useCallback(
+    (cursor: { x: number; y: number } | null) => {
+      pendingCursorRef.current = cursor;
+      if (cursorTimerRef.current !== null) return;
+      cursorTimerRef.current = window.setTimeout(() => {
+        cursorTimerRef.current = null;
+        send({ cursor: pendingCursorRef.current });
+      }, CURSOR_THROTTLE_MS);
+    },
+    [send]
+  );
+
+  const reportSelection = useCallback((nodeIds: string[]) => send({ selected_node_ids: nodeIds }), [send]);
+  const reportEditing = useCallback((nodeId: string | null) => send({ editing_node_id: nodeId }), [send]);
+  const reportEdit = useCallback((nodeId: string) => send({ edited_node_id: nodeId }), [send]);
+
+  return { presence, handleMessage, reportCursor, reportSelection, reportEditing, reportEdit };
+}
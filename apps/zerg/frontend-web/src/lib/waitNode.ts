@@ -0,0 +1,103 @@
+/**
+ * Wait node ("wait"): parks its branch for a number of minutes, until a time,
+ * or until an external system POSTs to the wait's resume URL. Waiting happens
+ * server-side (services/workflow_waits.py); while a wait is pending it can be
+ * skipped from the execution sidebar.
+ */
+import type { NodeWaitingData } from "../generated/ws-messages";
+
+export type WaitMode = "duration" | "until" | "event";
+
+export type NodeConfig = Record<string, unknown>;
+
+export const WAIT_MODES: { value: WaitMode; label: string }[] = [
+  { value: "duration", label: "Wait for a duration" },
+  { value: "until", label: "Wait until a time" },
+  { value: "event", label: "Wait for an external event" },
+];
+
+// Shelf entry, listed with the control-flow nodes under Logic
+export const WAIT_ITEM = { type: "wait", name: "Wait / Delay", icon: "⏳" } as const;
+
+export const DEFAULT_WAIT_MINUTES = 5;
+
+export function defaultWaitConfig(): NodeConfig {
+  return { mode: "duration", minutes: DEFAULT_WAIT_MINUTES };
+}
+
+export function waitMode(config: NodeConfig | undefined): WaitMode {
+  const mode = config?.mode;
+  return mode === "until" || mode === "event" ? mode : "duration";
+}
+
+/** One-line summary shown on the node under its label. */
+export function waitNodeSummary(config: NodeConfig | undefined): string {
+  switch (waitMode(config)) {
+    case "duration": {
+      const minutes = Number(config?.minutes);
+      return Number.isFinite(minutes) && minutes >= 0 ? `For ${minutes} min` : "No duration";
+    }
+    case "until": {
+      const until = typeof config?.until === "string" ? Date.parse(config.until) : NaN;
+      return Number.isNaN(until) ? "No time set" : `Until ${new Date(until).toLocaleString()}`;
+    }
+    case "event": {
+      const event = typeof config?.event === "string" ? config.event.trim() : "";
+      return event ? `On event “${event}”` : "No event name";
+    }
+  }
+}
+
+export interface PendingWait {
+  nodeId: string;
+  mode: WaitMode;
+  resumeAt: number | null;
+  event: string | null;
+  resumePath: string | null;
+}
+
+export function pendingWaitFromMessage(data: NodeWaitingData): PendingWait {
+  const resumeAt = data.resume_at ? Date.parse(data.resume_at) : NaN;
+  return {
+    nodeId: data.node_id,
+    mode: data.mode,
+    resumeAt: Number.isNaN(resumeAt) ? null : resumeAt,
+    event: data.event ?? null,
+    resumePath: data.resume_path ?? null,
+  };
+}
+
+/** Countdown to the wait's deadline, e.g. "1h 4m left"; event waits without a timeout never time out. */
+export function formatRemaining(resumeAt: number | null, now: number): string {
+  if (resumeAt == null) return "Until the event arrives";
+  const seconds = Math.ceil((resumeAt - now) / 1000);
+  if (seconds <= 0) return "Resuming…";
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  if (hours > 0) return `${hours}h ${minutes}m left`;
+  if (minutes > 0) return `${minutes}m ${seconds % 60}s left`;
+  return `${seconds}s left`;
+}
+
+/** Absolute URL an external system POSTs to (optionally with a JSON body) to resume an event wait. */
+export function resumeUrl(path: string, origin: string): string {
+  return new URL(path, origin).toString();
+}
+
+/** "2026-10-14T09:30" for a datetime-local input, in local time; blank when unset or invalid. */
+export function toLocalInput(iso: unknown): string {
+  const time = typeof iso === "string" ? Date.parse(iso) : NaN;
+  if (Number.isNaN(time)) return "";
+  const date = new Date(time);
+  const pad = (value: number) => String(value).padStart(2, "0");
+  return (
+    `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}` +
+    `T${pad(date.getHours())}:${pad(date.getMinutes())}`
+  );
+}
+
+/** datetime-local value back to an ISO UTC timestamp; blank when empty or invalid. */
+export function fromLocalInput(value: string): string {
+  const time = value ? new Date(value).getTime() : NaN;
+  return Number.isNaN(time) ? "" : new Date(time).toISOString();
+}
@@ -0,0 +1,86 @@
+// Word-level diff for comparing instruction versions. Whitespace runs are
+// tokens of their own so the output reassembles to the exact input text.
+
+export type DiffSegment = { type: "equal" | "added" | "removed"; text: string };
+
+// Above this many LCS cells the texts are shown as a whole replacement
+const MAX_DIFF_CELLS = 4_000_000;
+
+function tokenize(text: string): string[] {
+  return text.match(/\s+|[^\s]+/g) ?? [];
+}
+
+function push(segments: DiffSegment[], type: DiffSegment["type"], text: string) {
+  const last = segments[segments.length - 1];
+  if (last && last.type === type) last.text += text;
+  else segments.push({ type, text });
+}
+
+/** Segments that turn *before* into *after*. */
+export function diffWords(before: string, after: string): DiffSegment[] {
+  if (before === after) return before ? [{ type: "equal", text: before }] : [];
+  const a = tokenize(before);
+  const b = tokenize(after);
+
+  // Common prefix and suffix keep the LCS table small for typical edits
+  let start = 0;
+  while (start < a.length && start < b.length && a[start] === b[start]) start++;
+  let endA = a.length;
+  let endB = b.length;
+  while (endA > start && endB > start && a[endA - 1] === b[endB - 1]) {
+    endA--;
+    endB--;
+  }
+
+  const segments: DiffSegment[] = [];
+  if (start > 0) push(segments, "equal", a.slice(0, start).join(""));
+
+  const midA = a.slice(start, endA);
+  const midB = b.slice(start, endB);
+  if ((midA.length + 1) * (midB.length + 1) > MAX_DIFF_CELLS) {
+    if (midA.length) push(segments, "removed", midA.join(""));
+    if (midB.length) push(segments, "added", midB.join(""));
+  } else {
+    // lcs[i][j] = LCS length of midA[i:] and midB[j:]
+    const width = midB.length + 1;
+    const lcs = new Uint32Array((midA.length + 1) * width);
+    for (let i = midA.length - 1; i >= 0; i--) {
+      for (let j = midB.length - 1; j >= 0; j--) {
+        lcs[i * width + j] =
+          midA[i] === midB[j]
+            ? lcs[(i + 1) * width + j + 1] + 1
+            : Math.max(lcs[(i + 1) * width + j], lcs[i * width + j + 1]);
+      }
+    }
+    let i = 0;
+    let j = 0;
+    while (i < midA.length && j < midB.length) {
+      if (midA[i] === midB[j]) {
+        push(segments, "equal", midA[i]);
+        i++;
+        j++;
+      } else if (lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+        push(segments, "removed", midA[i++]);
+      } else {
+        push(segments, "added", midB[j++]);
+      }
+    }
+    while (i < midA.length) push(segments, "removed", midA[i++]);
+    while (j < midB.length) push(segments, "added", midB[j++]);
+  }
+
+  if (endA < a.length) push(segments, "equal", a.slice(endA).join(""));
+  return segments;
+}
+
+/** Changed word counts, ignoring whitespace-only tokens. */
+export function diffStats(segments: DiffSegment[]): { added: number; removed: number } {
+  const words = (text: string) => tokenize(text).filter((token) => token.trim()).length;
+  let added = 0;
+  let removed = 0;
+  for (const segment of segments) {
+    if (segment.type === "added") added += words(segment.text);
+    else if (segment.type === "removed") removed += words(segment.text);
+  }
+  return { added, removed };
+}
@@ -0,0 +1,92 @@
+/**
+ * Runs workflow computations (auto-layout, validation, large JSON parsing)
+ * in a shared Web Worker so they don't block rendering. A single worker is
+ * spawned lazily and requests are matched to replies by id. Where workers
+ * are unavailable (tests, old browsers, a worker that failed to load) the
+ * same task runs inline instead, so callers never need a second code path.
+ */
+
+import { runTask, type TaskInput, type TaskOutput, type TaskResponse, type WorkflowTask } from "./workflowTasks";
+
+export interface TaskOptions {
+  onProgress?: (fraction: number) => void;
+  signal?: AbortSignal;
+}
+
+interface Pending {
+  resolve: (output: unknown) => void;
+  reject: (error: Error) => void;
+  onProgress?: (fraction: number) => void;
+}
+
+let worker: Worker | null | undefined;
+let nextId = 1;
+const pending = new Map<number, Pending>();
+
+function settle(response: TaskResponse) {
+  const entry = pending.get(response.id);
+  if (!entry) return;
+  if (response.kind === "progress") {
+    entry.onProgress?.(response.fraction);
+    return;
+  }
+  pending.delete(response.id);
+  if (response.kind === "result") {
+    entry.resolve(response.output);
+  } else {
+    entry.reject(new Error(response.message));
+  }
+}
+
+function getWorker(): Worker | null {
+  if (worker !== undefined) return worker;
+  worker = null;
+  if (typeof Worker === "undefined") return worker;
+  try {
+    worker = new Worker(new URL("../workers/workflow.worker.ts", import.meta.url), { type: "module" });
+    worker.onmessage = (event: MessageEvent<TaskResponse>) => settle(event.data);
+    worker.onerror = (event) => {
+      // The worker script itself failed; fail what's in flight and run inline from now on
+      event.preventDefault();
+      worker?.terminate();
+      worker = null;
+      pending.forEach((entry) => entry.reject(new Error("Workflow worker crashed")));
+      pending.clear();
+    };
+  } catch {
+    worker = null;
+  }
+  return worker;
+}
+
+export function runWorkflowTask<K extends WorkflowTask>(
+  task: K,
+  input: TaskInput<K>,
+  options: TaskOptions = {}
+): Promise<TaskOutput<K>> {
+  const { onProgress, signal } = options;
+  if (signal?.aborted) return Promise.reject(new DOMException("Aborted", "AbortError"));
+
+  return new Promise<TaskOutput<K>>((resolve, reject) => {
+    const id = nextId++;
+    pending.set(id, { resolve: resolve as (output: unknown) => void, reject, onProgress });
+    // The worker finishes the job regardless; an aborted caller just stops listening
+    signal?.addEventListener("abort", () => {
+      if (pending.delete(id)) reject(new DOMException("Aborted", "AbortError"));
+    });
+
+    const target = getWorker();
+    if (target) {
+      target.postMessage({ id, task, input });
+    } else {
+      runTask({ id, task, input }, settle);
+    }
+  });
+}
+
+/** Test hook: drop the worker so the next task spawns (or falls back) afresh. */
+export function resetWorkflowWorker() {
+  worker?.terminate();
+  worker = undefined;
+  pending.clear();
+}
@@ -0,0 +1,158 @@
+/**
+ * Workflow graph algorithms that are too heavy for the UI thread on large
+ * canvases: layered auto-layout and structural validation. Pure and
+ * DOM-free so they run unchanged inside the workflow worker
+ * (workers/workflow.worker.ts) or inline as its fallback.
+ */
+
+export interface GraphNode {
+  id: string;
+  type?: string;
+  width: number;
+  height: number;
+}
+
+export interface GraphEdge {
+  source: string;
+  target: string;
+}
+
+export type ProgressCallback = (fraction: number) => void;
+
+export interface GraphIssue {
+  severity: "error" | "warning";
+  message: string;
+  nodeIds: string[];
+}
+
+export const LAYOUT_COLUMN_GAP = 80;
+export const LAYOUT_ROW_GAP = 32;
+
+/**
+ * Kahn's algorithm over the nodes that exist. Returns each node's layer
+ * (longest path from a source) and the ids left over because they sit on,
+ * or downstream of, a cycle.
+ */
+function layerNodes(nodes: GraphNode[], edges: GraphEdge[]) {
+  const ids = new Set(nodes.map((node) => node.id));
+  const outgoing = new Map<string, string[]>(nodes.map((node) => [node.id, []]));
+  const indegree = new Map<string, number>(nodes.map((node) => [node.id, 0]));
+  for (const edge of edges) {
+    if (!ids.has(edge.source) || !ids.has(edge.target) || edge.source === edge.target) continue;
+    outgoing.get(edge.source)!.push(edge.target);
+    indegree.set(edge.target, indegree.get(edge.target)! + 1);
+  }
+
+  const layer = new Map<string, number>();
+  const queue = nodes.filter((node) => indegree.get(node.id) === 0).map((node) => node.id);
+  queue.forEach((id) => layer.set(id, 0));
+  for (let head = 0; head < queue.length; head += 1) {
+    const id = queue[head];
+    for (const next of outgoing.get(id)!) {
+      layer.set(next, Math.max(layer.get(next) ?? 0, layer.get(id)! + 1));
+      indegree.set(next, indegree.get(next)! - 1);
+      if (indegree.get(next) === 0) queue.push(next);
+    }
+  }
+
+  const cyclic = nodes.filter((node) => !layer.has(node.id)).map((node) => node.id);
+  return { layer, cyclic };
+}
+
+/**
+ * Left-to-right layered layout: each node goes in the column after its
+ * furthest predecessor, and nodes within a column are ordered by the mean
+ * row of their predecessors to cut down on crossing edges. Nodes caught in
+ * a cycle are placed in a final column. Returns new top-left positions.
+ */
+export function layeredLayout(
+  nodes: GraphNode[],
+  edges: GraphEdge[],
+  onProgress?: ProgressCallback
+): Record<string, { x: number; y: number }> {
+  const { layer, cyclic } = layerNodes(nodes, edges);
+  const lastLayer = Math.max(-1, ...layer.values());
+  cyclic.forEach((id) => layer.set(id, lastLayer + 1));
+  onProgress?.(0.25);
+
+  const columns: GraphNode[][] = [];
+  for (const node of nodes) {
+    (columns[layer.get(node.id)!] ??= []).push(node);
+  }
+
+  const predecessors = new Map<string, string[]>();
+  for (const edge of edges) {
+    if (edge.source === edge.target) continue;
+    const list = predecessors.get(edge.target) ?? [];
+    list.push(edge.source);
+    predecessors.set(edge.target, list);
+  }
+
+  const row = new Map<string, number>();
+  const positions: Record<string, { x: number; y: number }> = {};
+  let x = 0;
+  columns.forEach((column, index) => {
+    const barycenter = (node: GraphNode) => {
+      const rows = (predecessors.get(node.id) ?? [])
+        .map((id) => row.get(id))
+        .filter((r): r is number => r !== undefined);
+      // Sources keep their canvas order, after nodes that have predecessors
+      return rows.length ? rows.reduce((sum, r) => sum + r, 0) / rows.length : Number.MAX_SAFE_INTEGER;
+    };
+    const keys = new Map(column.map((node) => [node.id, barycenter(node)]));
+    const ordered = [...column].sort((a, b) => keys.get(a.id)! - keys.get(b.id)!);
+    let y = 0;
+    ordered.forEach((node, position) => {
+      row.set(node.id, position);
+      positions[node.id] = { x, y };
+      y += node.height + LAYOUT_ROW_GAP;
+    });
+    x += Math.max(0, ...column.map((node) => node.width)) + LAYOUT_COLUMN_GAP;
+    onProgress?.(0.25 + (0.75 * (index + 1)) / columns.length);
+  });
+  return positions;
+}
+
+/** Structural problems that would make a run fail or do nothing useful. */
+export function validateGraph(nodes: GraphNode[], edges: GraphEdge[], onProgress?: ProgressCallback): GraphIssue[] {
+  const issues: GraphIssue[] = [];
+  const seen = new Set<string>();
+  const duplicates = new Set<string>();
+  for (const node of nodes) {
+    if (seen.has(node.id)) duplicates.add(node.id);
+    seen.add(node.id);
+  }
+  if (duplicates.size) {
+    issues.push({ severity: "error", message: "Duplicate node ids", nodeIds: [...duplicates] });
+  }
+
+  const dangling = edges.filter((edge) => !seen.has(edge.source) || !seen.has(edge.target));
+  if (dangling.length) {
+    const ids = dangling.flatMap((edge) => [edge.source, edge.target]).filter((id) => seen.has(id));
+    issues.push({ severity: "error", message: `${dangling.length} edge(s) point at missing nodes`, nodeIds: ids });
+  }
+  const selfLoops = edges.filter((edge) => edge.source === edge.target).map((edge) => edge.source);
+  if (selfLoops.length) {
+    issues.push({ severity: "error", message: "Node connected to itself", nodeIds: selfLoops });
+  }
+  onProgress?.(0.4);
+
+  const { cyclic } = layerNodes(nodes, edges);
+  if (cyclic.length) {
+    issues.push({ severity: "error", message: "Workflow contains a cycle", nodeIds: cyclic });
+  }
+  onProgress?.(0.8);
+
+  if (nodes.length && !nodes.some((node) => node.type === "trigger")) {
+    issues.push({ severity: "warning", message: "No trigger node; the workflow only runs manually", nodeIds: [] });
+  }
+  if (nodes.length > 1) {
+    const connected = new Set(edges.flatMap((edge) => [edge.source, edge.target]));
+    const isolated = nodes.filter((node) => !connected.has(node.id)).map((node) => node.id);
+    if (isolated.length) {
+      issues.push({ severity: "warning", message: `${isolated.length} node(s) not connected`, nodeIds: isolated });
+    }
+  }
+  onProgress?.(1);
+  return issues;
+}
@@ -0,0 +1,178 @@
+/**
+ * Run inputs a workflow's manual trigger declares in
+ * `config.trigger.config.params.inputs`. Run from the canvas, such a
+ * workflow first asks for them in a generated form; the start endpoint
+ * checks them again (services/workflow_inputs.py) and nodes read them as
+ * ${input.value.<name>}.
+ *
+ * The trigger inspector edits the declaration as one line per input:
+ * `name: type [required] [= default] [# description]`.
+ */
+
+import type { ApiFieldError } from "../services/api";
+
+export type NodeConfig = Record<string, unknown>;
+
+export const RUN_INPUT_TYPES = ["string", "number", "integer", "boolean", "json"] as const;
+export type RunInputType = (typeof RUN_INPUT_TYPES)[number];
+
+export interface RunInputSpec {
+  name: string;
+  type: RunInputType;
+  required?: boolean;
+  default?: unknown;
+  description?: string;
+}
+
+// Form state: checkboxes for booleans, text for everything else
+export type RunInputValues = Record<string, string | boolean>;
+
+function asRecord(value: unknown): Record<string, unknown> {
+  return value && typeof value === "object" && !Array.isArray(value) ? (value as Record<string, unknown>) : {};
+}
+
+function isRunInputType(value: unknown): value is RunInputType {
+  return typeof value === "string" && (RUN_INPUT_TYPES as readonly string[]).includes(value);
+}
+
+/** Declarations stored in a trigger node's config. */
+export function inputSpecsFromConfig(config: NodeConfig | undefined): RunInputSpec[] {
+  const params = asRecord(asRecord(asRecord(config?.trigger).config).params);
+  if (!Array.isArray(params.inputs)) return [];
+  return params.inputs.flatMap((item): RunInputSpec[] => {
+    const spec = asRecord(item);
+    if (typeof spec.name !== "string" || !spec.name) return [];
+    return [
+      {
+        name: spec.name,
+        type: isRunInputType(spec.type) ? spec.type : "string",
+        ...(spec.required === true ? { required: true } : {}),
+        ...(spec.default !== undefined && spec.default !== null ? { default: spec.default } : {}),
+        ...(typeof spec.description === "string" && spec.description ? { description: spec.description } : {}),
+      },
+    ];
+  });
+}
+
+/** Inputs a manual run asks for, from the workflow's manual trigger. */
+export function declaredRunInputs(nodes: { type?: string; config?: NodeConfig }[]): RunInputSpec[] {
+  const manual = nodes.find((node) => node.type === "trigger" && asRecord(node.config?.trigger).type === "manual");
+  return inputSpecsFromConfig(manual?.config);
+}
+
+function formatDefault(value: unknown): string {
+  return typeof value === "string" ? value : JSON.stringify(value);
+}
+
+/** Declarations as inspector lines. */
+export function formatInputSpecs(specs: RunInputSpec[]): string {
+  return specs
+    .map((spec) => {
+      let line = `${spec.name}: ${spec.type}`;
+      if (spec.required) line += " required";
+      if (spec.default !== undefined) line += ` = ${formatDefault(spec.default)}`;
+      if (spec.description) line += ` # ${spec.description}`;
+      return line;
+    })
+    .join("\n");
+}
+
+const SPEC_LINE = /^([A-Za-z_][\w-]*)\s*:\s*(\w+)(\s+required)?(?:\s*=\s*(.*?))?$/;
+
+/** Inspector lines back into declarations; returns the first bad line's error instead when one doesn't parse. */
+export function parseInputSpecs(text: string): { specs: RunInputSpec[] } | { error: string } {
+  const specs: RunInputSpec[] = [];
+  const lines = text.split("\n");
+  for (const [index, raw] of lines.entries()) {
+    const hash = raw.indexOf(" #");
+    const description = hash >= 0 ? raw.slice(hash + 2).trim() : "";
+    const line = (hash >= 0 ? raw.slice(0, hash) : raw).trim();
+    if (!line) continue;
+    const match = SPEC_LINE.exec(line);
+    if (!match) {
+      return { error: `Line ${index + 1}: write each input as name: type, e.g. email: string required.` };
+    }
+    const [, name, type, required, defaultText] = match;
+    if (!isRunInputType(type)) {
+      return { error: `Line ${index + 1}: the type must be one of ${RUN_INPUT_TYPES.join(", ")}.` };
+    }
+    if (specs.some((spec) => spec.name === name)) {
+      return { error: `Line ${index + 1}: ${name} is declared twice.` };
+    }
+    const spec: RunInputSpec = { name, type };
+    if (required) spec.required = true;
+    if (defaultText) {
+      const parsed = coerceRunInput(type, defaultText);
+      if ("error" in parsed) return { error: `Line ${index + 1}: the default ${parsed.error}.` };
+      spec.default = parsed.value;
+    }
+    if (description) spec.description = description;
+    specs.push(spec);
+  }
+  return { specs };
+}
+
+/** Value of *text* as *type*, or why it doesn't fit. */
+export function coerceRunInput(type: RunInputType, text: string | boolean): { value: unknown } | { error: string } {
+  if (type === "boolean") {
+    if (typeof text === "boolean") return { value: text };
+    if (text === "true" || text === "false") return { value: text === "true" };
+    return { error: "must be true or false" };
+  }
+  const raw = String(text).trim();
+  if (type === "number" || type === "integer") {
+    const number = Number(raw);
+    const error = type === "integer" ? "must be an integer" : "must be a number";
+    if (raw === "" || !Number.isFinite(number)) return { error };
+    if (type === "integer" && !Number.isInteger(number)) return { error };
+    return { value: number };
+  }
+  if (type === "json") {
+    try {
+      return { value: JSON.parse(raw) };
+    } catch {
+      return { error: "must be valid JSON" };
+    }
+  }
+  return { value: String(text) };
+}
+
+/** Form state prefilled from *inputs* (an earlier run's), falling back to each input's default. */
+export function initialRunInputValues(specs: RunInputSpec[], inputs: Record<string, unknown> = {}): RunInputValues {
+  return Object.fromEntries(
+    specs.map((spec) => {
+      const value = inputs[spec.name] ?? spec.default;
+      if (spec.type === "boolean") return [spec.name, value === true];
+      return [spec.name, value === undefined || value === null ? "" : formatDefault(value)];
+    })
+  );
+}
+
+/** Submitted form values as run inputs, or an error per input name. */
+export function validateRunInputs(
+  specs: RunInputSpec[],
+  values: RunInputValues
+): { inputs: Record<string, unknown>; errors: Record<string, string> } {
+  const inputs: Record<string, unknown> = {};
+  const errors: Record<string, string> = {};
+  for (const spec of specs) {
+    const value = values[spec.name] ?? "";
+    if (typeof value === "string" && value.trim() === "") {
+      if (spec.required) errors[spec.name] = "required";
+      continue;
+    }
+    const parsed = coerceRunInput(spec.type, value);
+    if ("error" in parsed) errors[spec.name] = parsed.error;
+    else inputs[spec.name] = parsed.value;
+  }
+  return { inputs, errors };
+}
+
+/** Per-input messages from a rejected start (`inputs.<name>` fields), or null when none are about inputs. */
+export function runInputFieldErrors(fieldErrors: ApiFieldError[]): Record<string, string> | null {
+  const errors: Record<string, string> = {};
+  for (const { field, message } of fieldErrors) {
+    if (field.startsWith("inputs.")) errors[field.slice("inputs.".length)] = message;
+  }
+  return Object.keys(errors).length > 0 ? errors : null;
+}
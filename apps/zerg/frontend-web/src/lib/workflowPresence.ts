@@ -0,0 +1,107 @@
+/**
+ * Other editors on the open workflow, fed by the `workflow:{id}:presence`
+ * topic. Entries are per connection, so the same user in two tabs shows up
+ * twice; our own connection is filtered out using the `self_client_id` the
+ * server sends on join. Canvas saves stay last-write-wins – presence only
+ * lets us warn before overwriting someone else's change to the same node.
+ */
+
+import type { PresenceEditorData, PresenceLeftData, PresenceStateData } from "../generated/ws-messages";
+
+export interface PresenceSnapshot {
+  selfClientId: string | null;
+  // Remote editors by client_id
+  editors: ReadonlyMap<string, PresenceEditorData>;
+}
+
+export const EMPTY_PRESENCE: PresenceSnapshot = { selfClientId: null, editors: new Map() };
+
+// A remote edit this recent to the node we touch counts as a conflict
+export const CONFLICT_WINDOW_MS = 2 * 60 * 1000;
+
+const PRESENCE_COLORS = ["#e5484d", "#0091ff", "#30a46c", "#f76b15", "#8e4ec6", "#d6409f", "#12a594", "#ffb224"];
+
+export function presenceTopic(workflowId: number): string {
+  return `workflow:${workflowId}:presence`;
+}
+
+/** Stable per-user colour for avatars, cursors and selection outlines. */
+export function presenceColor(userId: number): string {
+  return PRESENCE_COLORS[Math.abs(userId) % PRESENCE_COLORS.length];
+}
+
+export function presenceName(editor: Pick<PresenceEditorData, "display_name" | "user_id">): string {
+  return editor.display_name || `User ${editor.user_id}`;
+}
+
+export function presenceInitials(name: string): string {
+  const local = name.includes("@") ? name.split("@")[0] : name;
+  const words = local.split(/[\s._-]+/).filter(Boolean);
+  const letters = words.length > 1 ? words[0][0] + words[1][0] : local.slice(0, 2);
+  return letters.toUpperCase();
+}
+
+/** Fold one presence frame into *state*; unrelated frames return *state* unchanged. */
+export function applyPresenceMessage(
+  state: PresenceSnapshot,
+  message: { type: string; data?: unknown }
+): PresenceSnapshot {
+  switch (message.type) {
+    case "presence_state": {
+      const data = message.data as PresenceStateData;
+      const editors = new Map(
+        data.editors.filter((editor) => editor.client_id !== data.self_client_id).map((e) => [e.client_id, e])
+      );
+      return { selfClientId: data.self_client_id, editors };
+    }
+    case "presence_update": {
+      const editor = message.data as PresenceEditorData;
+      if (editor.client_id === state.selfClientId) return state;
+      const editors = new Map(state.editors);
+      editors.set(editor.client_id, editor);
+      return { ...state, editors };
+    }
+    case "presence_left": {
+      const { client_id } = message.data as PresenceLeftData;
+      if (!state.editors.has(client_id)) return state;
+      const editors = new Map(state.editors);
+      editors.delete(client_id);
+      return { ...state, editors };
+    }
+    default:
+      return state;
+  }
+}
+
+/** Remote editors that have *nodeId* open or changed it within the conflict window. */
+export function conflictingEditors(
+  editors: Iterable<PresenceEditorData>,
+  nodeId: string,
+  now: number = Date.now()
+): PresenceEditorData[] {
+  return [...editors].filter((editor) => {
+    if (editor.editing_node_id === nodeId) return true;
+    if (editor.edited_node_id !== nodeId || !editor.edited_at) return false;
+    return now - Date.parse(editor.edited_at) <= CONFLICT_WINDOW_MS;
+  });
+}
+
+/** node id -> remote editors that have it selected, for selection outlines. */
+export function remoteSelections(editors: Iterable<PresenceEditorData>): Map<string, PresenceEditorData[]> {
+  const byNode = new Map<string, PresenceEditorData[]>();
+  for (const editor of editors) {
+    for (const nodeId of editor.selected_node_ids) {
+      byNode.set(nodeId, [...(byNode.get(nodeId) ?? []), editor]);
+    }
+  }
+  return byNode;
+}
+
+/** One avatar per user even when they have several tabs open. */
+export function uniqueEditors(editors: Iterable<PresenceEditorData>): PresenceEditorData[] {
+  const byUser = new Map<number, PresenceEditorData>();
+  for (const editor of editors) {
+    if (!byUser.has(editor.user_id)) byUser.set(editor.user_id, editor);
+  }
+  return [...byUser.values()];
+}
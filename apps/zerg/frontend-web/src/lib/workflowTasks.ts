@@ -0,0 +1,49 @@
+/**
+ * The jobs the workflow worker knows how to run, keyed by name. Shared by
+ * the worker itself and by workerBridge's inline fallback so both paths run
+ * identical code.
+ */
+
+import { layeredLayout, validateGraph, type GraphEdge, type GraphIssue, type GraphNode } from "./workflowGraph";
+
+export interface WorkflowTaskTypes {
+  layout: { input: { nodes: GraphNode[]; edges: GraphEdge[] }; output: Record<string, { x: number; y: number }> };
+  validate: { input: { nodes: GraphNode[]; edges: GraphEdge[] }; output: GraphIssue[] };
+  // Big documents such as execution exports or agent import files
+  parseJson: { input: string; output: unknown };
+}
+
+export type WorkflowTask = keyof WorkflowTaskTypes;
+export type TaskInput<K extends WorkflowTask> = WorkflowTaskTypes[K]["input"];
+export type TaskOutput<K extends WorkflowTask> = WorkflowTaskTypes[K]["output"];
+
+type TaskFn<K extends WorkflowTask> = (input: TaskInput<K>, onProgress: (fraction: number) => void) => TaskOutput<K>;
+
+export const WORKFLOW_TASKS: { [K in WorkflowTask]: TaskFn<K> } = {
+  layout: ({ nodes, edges }, onProgress) => layeredLayout(nodes, edges, onProgress),
+  validate: ({ nodes, edges }, onProgress) => validateGraph(nodes, edges, onProgress),
+  parseJson: (text) => JSON.parse(text),
+};
+
+// Messages between workerBridge and workers/workflow.worker.ts
+export interface TaskRequest {
+  id: number;
+  task: WorkflowTask;
+  input: unknown;
+}
+
+export type TaskResponse =
+  | { id: number; kind: "progress"; fraction: number }
+  | { id: number; kind: "result"; output: unknown }
+  | { id: number; kind: "error"; message: string };
+
+export function runTask(request: TaskRequest, post: (response: TaskResponse) => void) {
+  const { id, task, input } = request;
+  try {
+    const run = WORKFLOW_TASKS[task] as TaskFn<WorkflowTask>;
+    const output = run(input as never, (fraction) => post({ id, kind: "progress", fraction }));
+    post({ id, kind: "result", output });
+  } catch (error) {
+    post({ id, kind: "error", message: error instanceof Error ? error.message : String(error) });
+  }
+}
@@ -0,0 +1,126 @@
+/**
+ * Debug-only record of recent WebSocket frames, shared by every
+ * useWebSocket connection. Recording is on in development, under Playwright
+ * (__TEST_WORKER_ID__) or when localStorage "zerg:debug:ws" is "1"; frames are
+ * readable from the hidden panel in the status footer and from
+ * `window.__zergWsDebug` so E2E tests can assert on message flow.
+ */
+
+export type FrameDirection = "in" | "out";
+
+export interface WsFrame {
+  seq: number;
+  // Epoch milliseconds
+  ts: number;
+  direction: FrameDirection;
+  type: string;
+  topic: string | null;
+  // Length of the serialized frame
+  size: number;
+  payload: unknown;
+}
+
+export interface WsDebugApi {
+  getFrames: () => WsFrame[];
+  getFrameCount: () => number;
+  clearFrames: () => void;
+}
+
+export const WS_FRAME_LOG_CAPACITY = 200;
+export const WS_DEBUG_STORAGE_KEY = "zerg:debug:ws";
+
+/** Fixed-size buffer that overwrites the oldest frame once full. */
+export class FrameRingBuffer {
+  private frames: (WsFrame | undefined)[];
+  private start = 0;
+  private count = 0;
+  private nextSeq = 1;
+  private listeners = new Set<() => void>();
+  private cached: WsFrame[] | null = null;
+
+  constructor(private readonly capacity: number) {
+    this.frames = new Array(capacity);
+  }
+
+  push(frame: Omit<WsFrame, "seq">): WsFrame {
+    const entry = { ...frame, seq: this.nextSeq++ };
+    if (this.count < this.capacity) {
+      this.frames[(this.start + this.count) % this.capacity] = entry;
+      this.count += 1;
+    } else {
+      this.frames[this.start] = entry;
+      this.start = (this.start + 1) % this.capacity;
+    }
+    this.changed();
+    return entry;
+  }
+
+  /** Oldest first. Stable between changes so it can back useSyncExternalStore. */
+  snapshot(): WsFrame[] {
+    if (!this.cached) {
+      this.cached = Array.from({ length: this.count }, (_, i) => this.frames[(this.start + i) % this.capacity]!);
+    }
+    return this.cached;
+  }
+
+  get size(): number {
+    return this.count;
+  }
+
+  clear(): void {
+    this.frames = new Array(this.capacity);
+    this.start = 0;
+    this.count = 0;
+    this.changed();
+  }
+
+  subscribe(listener: () => void): () => void {
+    this.listeners.add(listener);
+    return () => this.listeners.delete(listener);
+  }
+
+  private changed() {
+    this.cached = null;
+    this.listeners.forEach((listener) => listener());
+  }
+}
+
+export const wsFrameLog = new FrameRingBuffer(WS_FRAME_LOG_CAPACITY);
+
+export function isWsDebugEnabled(): boolean {
+  if (typeof window === "undefined") return false;
+  if (import.meta.env.MODE === "development" || window.__TEST_WORKER_ID__ !== undefined) return true;
+  try {
+    return window.localStorage.getItem(WS_DEBUG_STORAGE_KEY) === "1";
+  } catch {
+    return false;
+  }
+}
+
+/** Record one frame; a no-op unless debugging is enabled. */
+export function recordFrame(direction: FrameDirection, raw: string, message?: { type?: unknown; topic?: unknown }) {
+  if (!isWsDebugEnabled()) return;
+  wsFrameLog.push({
+    ts: Date.now(),
+    direction,
+    type: typeof message?.type === "string" ? message.type : "unknown",
+    topic: typeof message?.topic === "string" ? message.topic : null,
+    size: raw.length,
+    payload: message ?? raw,
+  });
+  installWsDebugGlobals();
+}
+
+export function installWsDebugGlobals(): void {
+  if (typeof window === "undefined" || window.__zergWsDebug) return;
+  window.__zergWsDebug = {
+    getFrames: () => wsFrameLog.snapshot(),
+    getFrameCount: () => wsFrameLog.size,
+    clearFrames: () => wsFrameLog.clear(),
+  };
+}
+
+/** The buffer as pretty JSON, for pasting into bug reports. */
+export function framesToJson(frames: WsFrame[]): string {
+  return JSON.stringify(frames, null, 2);
+}
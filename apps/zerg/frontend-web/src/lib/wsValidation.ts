@@ -0,0 +1,206 @@
+/**
+ * Contract checks for incoming WebSocket envelopes.
+ *
+ * Every parsed frame is validated against the schemas generated from
+ * schemas/ws-protocol-asyncapi.yml (generated/ws-schema.ts): the envelope
+ * itself, then the payload for its message type. Mismatches are counted per
+ * type for the WebSocket debug panel, and in development the first mismatch
+ * of each type is also thrown as an uncaught error so contract drift between
+ * backend and frontend shows up in the crash overlay right away. Messages
+ * are still delivered either way – this layer reports, it doesn't filter.
+ */
+import { WS_MESSAGE_PAYLOADS, WS_SCHEMAS, type FieldSchema } from "../generated/ws-schema";
+
+export interface SchemaIssue {
+  // Dotted path into the envelope, e.g. "data.status"
+  path: string;
+  message: string;
+}
+
+export interface SchemaMismatch {
+  type: string;
+  count: number;
+  // Epoch milliseconds
+  lastSeen: number;
+  lastIssues: SchemaIssue[];
+  lastMessage: unknown;
+}
+
+export class WsContractError extends Error {
+  constructor(
+    readonly messageType: string,
+    readonly issues: SchemaIssue[]
+  ) {
+    super(
+      `WebSocket "${messageType}" message doesn't match the protocol schema: ` +
+        issues.map((issue) => `${issue.path} ${issue.message}`).join("; ")
+    );
+    this.name = "WsContractError";
+  }
+}
+
+function describeValue(value: unknown): string {
+  if (value === null) return "null";
+  if (Array.isArray(value)) return "array";
+  return typeof value;
+}
+
+function checkType(value: unknown, type: NonNullable<FieldSchema["type"]>): boolean {
+  switch (type) {
+    case "integer":
+      return Number.isInteger(value);
+    case "number":
+      return typeof value === "number" && Number.isFinite(value);
+    case "object":
+      return typeof value === "object" && value !== null && !Array.isArray(value);
+    case "array":
+      return Array.isArray(value);
+    default:
+      return typeof value === type;
+  }
+}
+
+/**
+ * Append *value*'s violations of *schema* to *issues*. Optional fields may be
+ * null as well as absent: the backend serializes unset values as null.
+ */
+export function validateValue(value: unknown, schema: FieldSchema, path: string, issues: SchemaIssue[]): void {
+  if (schema.ref) {
+    const target = WS_SCHEMAS[schema.ref];
+    if (target) validateValue(value, target, path, issues);
+    return;
+  }
+  if (schema.type && !checkType(value, schema.type)) {
+    issues.push({ path, message: `should be ${schema.type}, got ${describeValue(value)}` });
+    return;
+  }
+  if (schema.const !== undefined && value !== schema.const) {
+    issues.push({ path, message: `should be ${JSON.stringify(schema.const)}` });
+  }
+  if (schema.enum && !schema.enum.includes(value as string | number)) {
+    issues.push({ path, message: `should be one of ${schema.enum.join(", ")}, got ${JSON.stringify(value)}` });
+  }
+  if (schema.minimum !== undefined && typeof value === "number" && value < schema.minimum) {
+    issues.push({ path, message: `should be at least ${schema.minimum}` });
+  }
+  if (schema.items && Array.isArray(value)) {
+    value.forEach((item, index) => validateValue(item, schema.items!, `${path}[${index}]`, issues));
+  }
+  if (schema.properties && checkType(value, "object")) {
+    const record = value as Record<string, unknown>;
+    const required = new Set(schema.required ?? []);
+    required.forEach((name) => {
+      if (record[name] === undefined) issues.push({ path: `${path}.${name}`, message: "is required" });
+    });
+    Object.entries(schema.properties).forEach(([name, field]) => {
+      const item = record[name];
+      if (item === undefined || (item === null && !required.has(name))) return;
+      validateValue(item, field, `${path}.${name}`, issues);
+    });
+  }
+}
+
+/** Everything wrong with *message* as an incoming envelope; empty when it matches the protocol. */
+export function validateEnvelope(message: unknown): SchemaIssue[] {
+  const issues: SchemaIssue[] = [];
+  const envelope = WS_SCHEMAS.Envelope;
+  if (envelope) {
+    // Payloads are checked against their own schema below
+    const envelopeFields = Object.fromEntries(
+      Object.entries(envelope.properties ?? {}).filter(([name]) => name !== "data")
+    );
+    validateValue(message, { ...envelope, properties: envelopeFields }, "envelope", issues);
+  }
+  if (!checkType(message, "object")) return issues;
+  const { type, data } = message as { type?: unknown; data?: unknown };
+  if (typeof type !== "string") return issues;
+  const payloadSchema = WS_MESSAGE_PAYLOADS[type];
+  if (!payloadSchema) {
+    issues.push({ path: "envelope.type", message: `"${type}" is not a message type in the protocol` });
+    return issues;
+  }
+  validateValue(data, WS_SCHEMAS[payloadSchema] ?? {}, "data", issues);
+  return issues;
+}
+
+// --- Mismatch counters ---
+
+export class SchemaMismatchLog {
+  private byType = new Map<string, SchemaMismatch>();
+  private listeners = new Set<() => void>();
+  private cached: SchemaMismatch[] | null = null;
+  private count = 0;
+
+  record(type: string, issues: SchemaIssue[], message: unknown): SchemaMismatch {
+    const existing = this.byType.get(type);
+    const entry: SchemaMismatch = {
+      type,
+      count: (existing?.count ?? 0) + 1,
+      lastSeen: Date.now(),
+      lastIssues: issues,
+      lastMessage: message,
+    };
+    this.byType.set(type, entry);
+    this.count += 1;
+    this.changed();
+    return entry;
+  }
+
+  /** Most frequent first. Stable between changes so it can back useSyncExternalStore. */
+  snapshot(): SchemaMismatch[] {
+    this.cached ??= [...this.byType.values()].sort((a, b) => b.count - a.count || a.type.localeCompare(b.type));
+    return this.cached;
+  }
+
+  get total(): number {
+    return this.count;
+  }
+
+  clear(): void {
+    this.byType.clear();
+    this.count = 0;
+    this.changed();
+  }
+
+  subscribe(listener: () => void): () => void {
+    this.listeners.add(listener);
+    return () => this.listeners.delete(listener);
+  }
+
+  private changed() {
+    this.cached = null;
+    this.listeners.forEach((listener) => listener());
+  }
+}
+
+export const wsSchemaMismatches = new SchemaMismatchLog();
+
+export interface CheckOptions {
+  log?: SchemaMismatchLog;
+  // Throw the first mismatch of each type asynchronously; defaults to development builds
+  panic?: boolean;
+}
+
+const panicked = new Set<string>();
+
+/** Validate one incoming message and report it when it doesn't match. Returns whether it matched. */
+export function checkIncomingEnvelope(
+  message: unknown,
+  { log = wsSchemaMismatches, panic = import.meta.env.DEV }: CheckOptions = {}
+): boolean {
+  const issues = validateEnvelope(message);
+  if (issues.length === 0) return true;
+  const rawType = checkType(message, "object") ? (message as { type?: unknown }).type : undefined;
+  const type = typeof rawType === "string" ? rawType : "unknown";
+  log.record(type, issues, message);
+  console.warn(`[WS] Schema mismatch in "${type}" message`, issues, message);
+  if (panic && !panicked.has(type)) {
+    panicked.add(type);
+    const error = new WsContractError(type, issues);
+    // Outside the socket handler, so it reaches the crash overlay without dropping the message
+    setTimeout(() => {
+      throw error;
+    }, 0);
+  }
+  return false;
+}
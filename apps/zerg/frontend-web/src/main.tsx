@@ -2,8 +2,17 @@ import React from "react";
 import ReactDOM from "react-dom/client";
 import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
 import { BrowserRouter } from "react-router-dom";
-import { Toaster } from "react-hot-toast";
+import { ToastBar, Toaster } from "react-hot-toast";
 import { AuthProvider } from "./lib/auth";
+import { extractTraceId, getLastTraceId } from "./lib/traceId";
+import { TraceId } from "./components/TraceId";
+import { CrashOverlay } from "./components/CrashOverlay";
+import { installCrashHandlers } from "./lib/crashReport";
+import { installDemoMode } from "./lib/demoMode";
+import { installPersistenceFlush } from "./lib/persistence";
+import { installReplayHarness } from "./lib/sessionRecorder";
+import { installTestHooks } from "./lib/testHooks";
+import { applyTheme, cachedTheme } from "./lib/theme";
 import "./styles/legacy.css";
 import "./styles/chat.css";
 import "./styles/profile-admin.css";
@@ -11,12 +20,15 @@ import "./styles/settings.css";
 import "./styles/css/agent-settings.css";
 import App from "./routes/App";
 
+// `?demo=1`: API and WebSocket calls are answered by an in-memory backend (before anything fetches)
+installDemoMode();
+
 // Global error beacon - captures JS errors from all users (including anonymous)
 window.onerror = (msg, src, line, col, err) => {
   fetch("/api/ops/beacon", {
     method: "POST",
     headers: { "Content-Type": "application/json" },
-    body: JSON.stringify({ msg, src, line, col, stack: err?.stack, url: location.href }),
+    body: JSON.stringify({ msg, src, line, col, stack: err?.stack, url: location.href, trace_id: getLastTraceId() }),
     keepalive: true,
   }).catch(() => {}); // Silent fail
 };
@@ -30,18 +42,32 @@ window.onunhandledrejection = (event) => {
       stack: event.reason?.stack,
       url: location.href,
       type: "unhandled_rejection",
+      // Rejections from API calls carry their own trace id
+      trace_id: event.reason?.traceId ?? getLastTraceId(),
     }),
     keepalive: true,
   }).catch(() => {});
 };
 
+// Uncaught exceptions outside a route's ErrorBoundary open the crash overlay
+installCrashHandlers();
+// Write pending canvas/UI changes before the tab is hidden or closed
+installPersistenceFlush();
+
 const container = document.getElementById("react-root");
 
 if (!container) {
   throw new Error("React root container not found");
 }
 
+// Apply the last-used theme before first paint; the profile pref takes over once loaded
+applyTheme(cachedTheme(window.localStorage));
+
 const queryClient = new QueryClient();
+// Debug builds: window.__zergReplay re-applies a recorded session trace
+installReplayHarness(queryClient);
+// Development and Playwright: window.__zergTest snapshots/restores state and waits for quiescence
+installTestHooks(queryClient);
 
 ReactDOM.createRoot(container).render(
   <React.StrictMode>
@@ -54,14 +80,15 @@ ReactDOM.createRoot(container).render(
           }}
         >
           <App />
+          <CrashOverlay />
           <Toaster
             position="top-right"
             toastOptions={{
               duration: 4000,
               style: {
-                background: '#27272a',
-                color: '#fafafa',
-                border: '1px solid #3f3f46',
+                background: 'var(--color-surface-card)',
+                color: 'var(--color-text-primary)',
+                border: '1px solid var(--color-border-primary)',
                 borderRadius: '8px',
                 fontSize: '14px',
                 fontFamily: "'Inter', -apple-system, BlinkMacSystemFont, sans-serif",
@@ -69,19 +96,34 @@ ReactDOM.createRoot(container).render(
               success: {
                 duration: 3000,
                 iconTheme: {
-                  primary: '#10b981',
+                  primary: 'var(--color-intent-success)',
                   secondary: '#fafafa',
                 },
               },
               error: {
                 duration: 6000,
                 iconTheme: {
-                  primary: '#ef4444',
+                  primary: 'var(--color-intent-error)',
                   secondary: '#fafafa',
                 },
               },
             }}
-          />
+          >
+            {(t) => (
+              <ToastBar toast={t}>
+                {({ icon, message }) => {
+                  const traceId = t.type === "error" ? extractTraceId(t.message) : null;
+                  return (
+                    <>
+                      {icon}
+                      {message}
+                      {traceId && <TraceId traceId={traceId} compact />}
+                    </>
+                  );
+                }}
+              </ToastBar>
+            )}
+          </Toaster>
         </BrowserRouter>
       </AuthProvider>
     </QueryClientProvider>
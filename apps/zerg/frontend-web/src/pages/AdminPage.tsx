@@ -1,8 +1,15 @@
-import React, { useState } from "react";
-import { useQuery, useMutation } from "@tanstack/react-query";
+import { useState } from "react";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
 import { toast } from "react-hot-toast";
 import { useAuth } from "../lib/auth";
 import config from "../lib/config";
+import { ApiError } from "../services/api";
+import { Capability, useCapabilities } from "../lib/capabilities";
+import { useFocusTrap } from "../lib/useFocusTrap";
+import AgentHoverCard from "../components/AgentHoverCard";
+import { TimeSeriesChart } from "../components/TimeSeriesChart";
+import OpsTicker from "../components/OpsTicker";
+import { downloadCsv, exportFilename, opsActivityToCsv } from "../lib/export";
 
 // Types for ops data - matching actual backend contract
 interface OpsSummary {
@@ -45,20 +52,68 @@ interface OpsTopAgent {
 
 // API functions (top agents are included in summary)
 async function fetchOpsSummary(): Promise<OpsSummary> {
-  const response = await fetch(`${config.apiBaseUrl}/ops/summary`, {
+  const url = `${config.apiBaseUrl}/ops/summary`;
+  const response = await fetch(url, {
     credentials: 'include', // Cookie auth
   });
 
   if (!response.ok) {
-    if (response.status === 403) {
-      throw new Error("Admin access required");
+    let body: unknown;
+    try {
+      body = await response.json();
+    } catch {
+      body = undefined;
     }
-    throw new Error("Failed to fetch ops summary");
+    throw new ApiError({ url, status: response.status, body });
   }
 
   return response.json();
 }
 
+type OpsWindow = "today" | "7d" | "30d";
+
+async function fetchOpsTimeseries(metric: string, window: OpsWindow): Promise<OpsSeriesPoint[]> {
+  const params = new URLSearchParams({ metric, window });
+  const response = await fetch(`${config.apiBaseUrl}/ops/timeseries?${params}`, {
+    credentials: 'include',
+  });
+
+  if (!response.ok) {
+    throw new Error(`Failed to fetch ${metric}`);
+  }
+
+  const data: { series: OpsSeriesPoint[] } = await response.json();
+  return data.series;
+}
+
+interface OpsActivity {
+  labels: string[];
+  runs: number[];
+  errors: number[];
+  cost: number[];
+}
+
+// Hourly buckets only exist for "today"; longer windows are bucketed by day
+async function fetchOpsActivity(window: OpsWindow): Promise<OpsActivity> {
+  const grain = window === "today" ? "hour" : "day";
+  const [runs, errors, cost] = await Promise.all([
+    fetchOpsTimeseries(`runs_by_${grain}`, window),
+    fetchOpsTimeseries(`errors_by_${grain}`, window),
+    fetchOpsTimeseries(`cost_by_${grain}`, window),
+  ]);
+  const valueAt = (points: OpsSeriesPoint[]) => {
+    const byLabel = new Map(points.map((point) => [point.hour_iso, point.value]));
+    return (label: string) => byLabel.get(label) ?? 0;
+  };
+  const labels = runs.map((point) => point.hour_iso);
+  return {
+    labels,
+    runs: labels.map(valueAt(runs)),
+    errors: labels.map(valueAt(errors)),
+    cost: labels.map(valueAt(cost)),
+  };
+}
+
 // Database management types and functions
 interface DatabaseResetRequest {
   confirmation_password?: string;
@@ -100,22 +155,66 @@ async function resetDatabase(request: DatabaseResetRequest): Promise<any> {
   return response.json();
 }
 
+// Maintenance mode suspends scheduled runs and trigger firings for every agent
+interface MaintenanceMode {
+  enabled: boolean;
+}
+
+async function fetchMaintenanceMode(): Promise<MaintenanceMode> {
+  const response = await fetch(`${config.apiBaseUrl}/admin/maintenance`, {
+    credentials: 'include',
+  });
+
+  if (!response.ok) {
+    throw new Error("Failed to fetch maintenance mode");
+  }
+
+  return response.json();
+}
+
+async function setMaintenanceMode(enabled: boolean): Promise<MaintenanceMode> {
+  const response = await fetch(`${config.apiBaseUrl}/admin/maintenance`, {
+    method: "PUT",
+    headers: {
+      "Content-Type": "application/json",
+    },
+    credentials: 'include',
+    body: JSON.stringify({ enabled }),
+  });
+
+  if (!response.ok) {
+    throw new Error("Failed to update maintenance mode");
+  }
+
+  return response.json();
+}
+
+// Title accent per metric; colors come from the active theme (see .metric-tone-*)
+type MetricTone = "info" | "success" | "warning" | "error" | "accent" | "neutral";
+
+function budgetTone(percent: number | null): MetricTone {
+  if (percent === null) return "neutral";
+  if (percent > 80) return "error";
+  if (percent > 60) return "warning";
+  return "success";
+}
+
 // Metric card component
 function MetricCard({
   title,
   value,
   subtitle,
-  color = "#10b981"
+  tone = "success"
 }: {
   title: string;
   value: string | number;
   subtitle?: string;
-  color?: string;
+  tone?: MetricTone;
 }) {
   return (
     <div className="metric-card">
       <div className="metric-header">
-        <h4 style={{ color }}>{title}</h4>
+        <h4 className={`metric-tone-${tone}`}>{title}</h4>
       </div>
       <div className="metric-value">{value}</div>
       {subtitle && <div className="metric-subtitle">{subtitle}</div>}
@@ -144,6 +243,7 @@ function ConfirmationModal({
   requirePassword?: boolean;
 }) {
   const [password, setPassword] = useState("");
+  const dialogRef = useFocusTrap(isOpen, onClose);
 
   if (!isOpen) return null;
 
@@ -154,15 +254,25 @@ function ConfirmationModal({
 
   return (
     <div className="modal-overlay" onClick={onClose}>
-      <div className="modal-content" onClick={(e) => e.stopPropagation()}>
-        <h3>{title}</h3>
-        <p>{message}</p>
+      <div
+        ref={dialogRef}
+        className="modal-content"
+        onClick={(e) => e.stopPropagation()}
+        role={isDangerous ? "alertdialog" : "dialog"}
+        aria-modal="true"
+        aria-labelledby="confirmation-modal-title"
+        aria-describedby="confirmation-modal-message"
+        tabIndex={-1}
+      >
+        <h3 id="confirmation-modal-title">{title}</h3>
+        <p id="confirmation-modal-message">{message}</p>
         {requirePassword && (
-          <div className="form-group" style={{ marginTop: "16px" }}>
+          <div className="form-group modal-password">
             <input
               type="password"
               className="form-input"
               placeholder="Confirmation password"
+              aria-label="Confirmation password"
               value={password}
               onChange={(e) => setPassword(e.target.value)}
             />
@@ -210,7 +320,11 @@ function TopAgentsTable({ agents }: { agents: OpsTopAgent[] }) {
         <tbody>
           {agents.map((agent) => (
             <tr key={agent.agent_id}>
-              <td className="agent-name">{agent.name}</td>
+              <td className="agent-name">
+                <AgentHoverCard agentId={agent.agent_id} agentName={agent.name}>
+                  {agent.name}
+                </AgentHoverCard>
+              </td>
               <td className="owner-email">{agent.owner_email}</td>
               <td className="runs-count">{agent.runs}</td>
               <td className="cost">
@@ -229,7 +343,7 @@ function TopAgentsTable({ agents }: { agents: OpsTopAgent[] }) {
 
 function AdminPage() {
   const { user } = useAuth();
-  const [selectedWindow, setSelectedWindow] = useState<"today" | "7d" | "30d">("today");
+  const [selectedWindow, setSelectedWindow] = useState<OpsWindow>("today");
   const [modalState, setModalState] = useState<{
     isOpen: boolean;
     type: "clear_data" | "full_rebuild" | null;
@@ -248,6 +362,13 @@ function AdminPage() {
     enabled: !!user, // Only run query when user is available
   });
 
+  const { data: activity, isLoading: activityLoading } = useQuery({
+    queryKey: ["ops-activity", selectedWindow],
+    queryFn: () => fetchOpsActivity(selectedWindow),
+    refetchInterval: 60000,
+    enabled: !!user,
+  });
+
   // Super admin status query
   const { data: adminStatus } = useQuery({
     queryKey: ["super-admin-status"],
@@ -255,6 +376,25 @@ function AdminPage() {
     enabled: !!user,
   });
 
+  const queryClient = useQueryClient();
+  const { data: maintenance } = useQuery({
+    queryKey: ["maintenance-mode"],
+    queryFn: fetchMaintenanceMode,
+    enabled: !!user,
+  });
+
+  const maintenanceMutation = useMutation({
+    mutationFn: setMaintenanceMode,
+    onSuccess: (data) => {
+      queryClient.setQueryData(["maintenance-mode"], data);
+      queryClient.invalidateQueries({ queryKey: ["system-info"] });
+      toast.success(data.enabled ? "Maintenance mode enabled" : "Maintenance mode disabled");
+    },
+    onError: (error: Error) => {
+      toast.error(error.message || "Failed to update maintenance mode");
+    },
+  });
+
   // Database reset mutation
   const resetMutation = useMutation({
     mutationFn: resetDatabase,
@@ -267,18 +407,23 @@ function AdminPage() {
     },
   });
 
-  // Handle permission errors - FIXED: Move ALL hooks before conditional logic
-  React.useEffect(() => {
-    if (summaryError instanceof Error && summaryError.message.includes("Admin access required")) {
-      toast.error("Admin access required to view this page");
-    }
-  }, [summaryError]);
+  const { can, isLoaded: capabilitiesLoaded } = useCapabilities();
 
-  // Check if user is admin (this should be checked by the router, but let's be safe)
   if (!user) {
     return <div>Loading...</div>;
   }
 
+  if (capabilitiesLoaded && !can(Capability.ViewOps)) {
+    return (
+      <div className="admin-page">
+        <div className="error-state" data-testid="admin-access-required">
+          <p>Admin access required to view this page</p>
+        </div>
+      </div>
+    );
+  }
+
+  const canResetDatabase = can(Capability.ResetDatabase);
   const formatCurrency = (value: number) => `$${value.toFixed(4)}`;
   const formatPercent = (value: number) => `${value.toFixed(1)}%`;
 
@@ -316,12 +461,23 @@ function AdminPage() {
           <label>Time Window:</label>
           <select
             value={selectedWindow}
-            onChange={(e) => setSelectedWindow(e.target.value as "today" | "7d" | "30d")}
+            onChange={(e) => setSelectedWindow(e.target.value as OpsWindow)}
           >
             <option value="today">Today</option>
             <option value="7d">Last 7 Days</option>
             <option value="30d">Last 30 Days</option>
           </select>
+          <button
+            type="button"
+            className="btn-secondary"
+            onClick={() =>
+              activity && downloadCsv(exportFilename("ops", selectedWindow, "csv"), opsActivityToCsv(activity))
+            }
+            disabled={!activity}
+            data-testid="ops-export-csv"
+          >
+            Export CSV
+          </button>
         </div>
       </div>
 
@@ -340,19 +496,19 @@ function AdminPage() {
               title="Runs Today"
               value={summary.runs_today}
               subtitle="Total executions"
-              color="#3b82f6"
+              tone="info"
             />
             <MetricCard
               title="Errors (1h)"
               value={summary.errors_last_hour}
               subtitle="Failed runs"
-              color="#ef4444"
+              tone="error"
             />
             <MetricCard
               title="Cost Today"
               value={summary.cost_today_usd !== null ? formatCurrency(summary.cost_today_usd) : "N/A"}
               subtitle="USD spent"
-              color="#10b981"
+              tone="success"
             />
             <MetricCard
               title="User Budget"
@@ -366,11 +522,7 @@ function AdminPage() {
                   ? `of $${(summary.budget_user.limit_cents / 100).toFixed(2)}`
                   : "Unlimited"
               }
-              color={
-                summary.budget_user.percent === null ? "#6b7280" :
-                summary.budget_user.percent > 80 ? "#ef4444" :
-                summary.budget_user.percent > 60 ? "#f59e0b" : "#10b981"
-              }
+              tone={budgetTone(summary.budget_user.percent)}
             />
             <MetricCard
               title="Global Budget"
@@ -384,20 +536,52 @@ function AdminPage() {
                   ? `of $${(summary.budget_global.limit_cents / 100).toFixed(2)}`
                   : "Unlimited"
               }
-              color={
-                summary.budget_global.percent === null ? "#6b7280" :
-                summary.budget_global.percent > 80 ? "#ef4444" :
-                summary.budget_global.percent > 60 ? "#f59e0b" : "#10b981"
-              }
+              tone={budgetTone(summary.budget_global.percent)}
             />
             <MetricCard
               title="Latency P95"
               value={`${summary.latency_ms.p95}ms`}
               subtitle={`P50: ${summary.latency_ms.p50}ms`}
-              color="#8b5cf6"
+              tone="accent"
             />
           </div>
 
+          <div className="admin-section">
+            <h3>Activity</h3>
+            {activityLoading ? (
+              <div className="loading-state">Loading activity...</div>
+            ) : activity ? (
+              <div className="activity-charts">
+                <TimeSeriesChart
+                  ariaLabel="Runs and errors over time"
+                  labels={activity.labels}
+                  stacked
+                  series={[
+                    {
+                      key: "ok",
+                      label: "Runs without errors",
+                      tone: "success",
+                      values: activity.runs.map((runs, index) => Math.max(runs - activity.errors[index], 0)),
+                    },
+                    { key: "errors", label: "Errors", tone: "error", values: activity.errors },
+                  ]}
+                />
+                <TimeSeriesChart
+                  ariaLabel="Cost over time"
+                  labels={activity.labels}
+                  height={140}
+                  formatValue={(value) => `$${value.toFixed(value > 0 && value < 1 ? 4 : 2)}`}
+                  series={[{ key: "cost", label: "Cost (USD)", tone: "primary", values: activity.cost }]}
+                />
+              </div>
+            ) : null}
+          </div>
+
+          <div className="admin-section">
+            <h3>Live Events</h3>
+            <OpsTicker />
+          </div>
+
           {/* Top Agents Section - using data from summary */}
           <div className="admin-section">
             <h3>Top Performing Agents (Today)</h3>
@@ -447,6 +631,27 @@ function AdminPage() {
             </div>
           </div>
 
+          <div className="admin-section">
+            <h3>Maintenance Mode</h3>
+            <div className="admin-actions">
+              <div className="action-group">
+                <button
+                  className={maintenance?.enabled ? "btn-primary" : "btn-warning"}
+                  onClick={() => maintenanceMutation.mutate(!maintenance?.enabled)}
+                  disabled={!maintenance || maintenanceMutation.isPending || !can(Capability.ManageMaintenance)}
+                  aria-pressed={maintenance?.enabled ?? false}
+                >
+                  {maintenance?.enabled ? "Disable Maintenance Mode" : "Enable Maintenance Mode"}
+                </button>
+                <p className="action-description">
+                  {maintenance?.enabled
+                    ? "Scheduled runs and trigger firings are suspended for all agents"
+                    : "Suspend scheduled runs and trigger firings for all agents; manual runs still work"}
+                </p>
+              </div>
+            </div>
+          </div>
+
           {/* Admin Actions */}
           <div className="admin-section">
             <h3>Database Management</h3>
@@ -455,7 +660,8 @@ function AdminPage() {
                 <button
                   className="btn-warning"
                   onClick={handleClearData}
-                  disabled={resetMutation.isPending}
+                  disabled={resetMutation.isPending || !canResetDatabase}
+                  title={canResetDatabase ? undefined : "Requires super admin access"}
                 >
                   Clear User Data
                 </button>
@@ -467,7 +673,8 @@ function AdminPage() {
                 <button
                   className="btn-danger"
                   onClick={handleFullReset}
-                  disabled={resetMutation.isPending}
+                  disabled={resetMutation.isPending || !canResetDatabase}
+                  title={canResetDatabase ? undefined : "Requires super admin access"}
                 >
                   Full Database Reset
                 </button>
@@ -0,0 +1,279 @@
+/**
+ * API credentials.
+ *
+ * Keys for third-party REST APIs, each with the base URL it belongs to and the
+ * header it's sent in. HTTP Request nodes choose one as their auth source and
+ * MCP servers can use one instead of an auth token. Keys are write-only: once
+ * saved only their last four characters are shown.
+ */
+
+import { useState, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import toast from "react-hot-toast";
+import clsx from "clsx";
+import {
+  createApiCredential,
+  deleteApiCredential,
+  fetchApiCredentials,
+  testApiCredential,
+  updateApiCredential,
+  type ApiCredential,
+  type ApiCredentialTestResult,
+} from "../services/api";
+import {
+  DEFAULT_AUTH_HEADER,
+  TEST_STATUS_LABELS,
+  authHeaderError,
+  isBaseUrl,
+  previewAuthHeader,
+} from "../lib/apiCredentials";
+import { Timestamp } from "../components/Timestamp";
+
+export const API_CREDENTIALS_QUERY_KEY = ["api-credentials"];
+
+interface CredentialForm {
+  // Credential being edited; null when adding
+  id: number | null;
+  name: string;
+  baseUrl: string;
+  authHeader: string;
+  key: string;
+}
+
+const EMPTY_FORM: CredentialForm = { id: null, name: "", baseUrl: "", authHeader: DEFAULT_AUTH_HEADER, key: "" };
+
+function formFor(credential: ApiCredential): CredentialForm {
+  return {
+    id: credential.id,
+    name: credential.name,
+    baseUrl: credential.base_url,
+    authHeader: credential.auth_header,
+    key: "",
+  };
+}
+
+/** Test connection for one credential: GETs the base URL plus an optional path with the key. */
+function CredentialTest({ credential }: { credential: ApiCredential }) {
+  const queryClient = useQueryClient();
+  const [path, setPath] = useState("");
+  const test = useMutation<ApiCredentialTestResult, Error>({
+    mutationFn: () => testApiCredential(credential.id, path.trim()),
+    onSettled: () => queryClient.invalidateQueries({ queryKey: API_CREDENTIALS_QUERY_KEY }),
+  });
+
+  return (
+    <div className="api-credential-test">
+      <input
+        value={path}
+        onChange={(event) => setPath(event.target.value)}
+        placeholder="/path (optional)"
+        aria-label={`Path to test ${credential.name} against`}
+        spellCheck={false}
+      />
+      <button type="button" className="btn-secondary" onClick={() => test.mutate()} disabled={test.isPending}>
+        {test.isPending ? "Testing…" : "Test connection"}
+      </button>
+      {test.isError && (
+        <span className="error-message" role="alert">
+          {test.error.message}
+        </span>
+      )}
+      {test.data && (
+        <span
+          className={clsx("api-credential-test-result", { "api-credential-test-result--failed": !test.data.success })}
+          aria-live="polite"
+        >
+          {test.data.message}
+        </span>
+      )}
+    </div>
+  );
+}
+
+export default function ApiCredentialsPage() {
+  const queryClient = useQueryClient();
+  const [form, setForm] = useState<CredentialForm>(EMPTY_FORM);
+
+  const credentialsQuery = useQuery({ queryKey: API_CREDENTIALS_QUERY_KEY, queryFn: fetchApiCredentials });
+  const credentials = credentialsQuery.data ?? [];
+  const editing = form.id != null ? credentials.find((credential) => credential.id === form.id) ?? null : null;
+
+  const invalidate = () => queryClient.invalidateQueries({ queryKey: API_CREDENTIALS_QUERY_KEY });
+
+  const saveCredential = useMutation({
+    mutationFn: (next: CredentialForm) => {
+      const fields = { name: next.name.trim(), base_url: next.baseUrl.trim(), auth_header: next.authHeader.trim() };
+      if (next.id == null) return createApiCredential({ ...fields, key: next.key.trim() });
+      // A blank key keeps the saved one
+      return updateApiCredential(next.id, { ...fields, ...(next.key.trim() ? { key: next.key.trim() } : {}) });
+    },
+    onSuccess: (saved) => {
+      toast.success(`Saved ${saved.name}`);
+      setForm(EMPTY_FORM);
+      invalidate();
+    },
+    onError: (error: Error) => toast.error(`Could not save: ${error.message}`),
+  });
+
+  const removeCredential = useMutation({
+    mutationFn: (credential: ApiCredential) => deleteApiCredential(credential.id),
+    onSuccess: (_, credential) => {
+      if (form.id === credential.id) setForm(EMPTY_FORM);
+      invalidate();
+    },
+    onError: (error: Error) => toast.error(`Could not delete: ${error.message}`),
+  });
+
+  const urlError = form.baseUrl.trim() && !isBaseUrl(form.baseUrl) ? "Start the URL with http:// or https://." : null;
+  const headerError = authHeaderError(form.authHeader);
+  const canSave =
+    Boolean(form.name.trim() && form.baseUrl.trim()) &&
+    !urlError &&
+    !headerError &&
+    (form.id != null || Boolean(form.key.trim())) &&
+    !saveCredential.isPending;
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    if (canSave) saveCredential.mutate(form);
+  };
+
+  const handleDelete = (credential: ApiCredential) => {
+    if (window.confirm(`Delete ${credential.name}? HTTP nodes and MCP servers that use it will stop authenticating.`)) {
+      removeCredential.mutate(credential);
+    }
+  };
+
+  const update = <K extends keyof CredentialForm>(key: K, value: CredentialForm[K]) =>
+    setForm((current) => ({ ...current, [key]: value }));
+
+  return (
+    <div className="integrations-container">
+      <div className="integrations-content">
+        <div className="integrations-header">
+          <h2>API Credentials</h2>
+          <p className="integrations-description">
+            Keys for REST APIs your workflows call. Choose one as the auth source of an HTTP Request node or when
+            adding an MCP server, and its header is added to each request. Keys can be replaced but never shown
+            again. <Link to="/settings/integrations">Back to integrations</Link>
+          </p>
+        </div>
+
+        <form className="env-var-form" onSubmit={handleSubmit} data-testid="api-credential-form">
+          <h3>{form.id == null ? "Add a credential" : `Edit ${editing?.name ?? form.name}`}</h3>
+          <div className="env-var-form-grid">
+            <label>
+              Name
+              <input value={form.name} onChange={(event) => update("name", event.target.value)} placeholder="Weather" />
+            </label>
+            <label>
+              Base URL
+              <input
+                value={form.baseUrl}
+                onChange={(event) => update("baseUrl", event.target.value)}
+                placeholder="https://api.example.com/v1"
+                aria-invalid={urlError ? true : undefined}
+                spellCheck={false}
+              />
+              {urlError && <span className="error-message">{urlError}</span>}
+            </label>
+            <label>
+              Auth header
+              <input
+                value={form.authHeader}
+                onChange={(event) => update("authHeader", event.target.value)}
+                aria-invalid={headerError ? true : undefined}
+                spellCheck={false}
+              />
+              {headerError && <span className="error-message">{headerError}</span>}
+            </label>
+            <label>
+              API key
+              <input
+                type="password"
+                value={form.key}
+                onChange={(event) => update("key", event.target.value)}
+                placeholder={form.id != null ? "Leave blank to keep the saved key" : ""}
+                autoComplete="off"
+                spellCheck={false}
+              />
+            </label>
+          </div>
+          <div className="env-var-form-actions">
+            {form.id != null && (
+              <button type="button" className="btn-secondary" onClick={() => setForm(EMPTY_FORM)}>
+                Cancel
+              </button>
+            )}
+            <button type="submit" className="btn-primary" disabled={!canSave}>
+              {saveCredential.isPending ? "Saving…" : form.id == null ? "Add credential" : "Save changes"}
+            </button>
+          </div>
+        </form>
+
+        {credentialsQuery.isLoading ? (
+          <p className="muted">Loading credentials…</p>
+        ) : credentialsQuery.error ? (
+          <p className="error-message">Failed to load credentials: {String(credentialsQuery.error)}</p>
+        ) : credentials.length === 0 ? (
+          <p className="muted">No API credentials yet.</p>
+        ) : (
+          <table className="env-var-table" data-testid="api-credential-table">
+            <thead>
+              <tr>
+                <th>Name</th>
+                <th>Header</th>
+                <th>Status</th>
+                <th>Updated</th>
+                <th aria-label="Actions" />
+              </tr>
+            </thead>
+            <tbody>
+              {credentials.map((credential) => (
+                <tr key={credential.id} data-testid={`api-credential-row-${credential.id}`}>
+                  <td>
+                    {credential.name}
+                    <div className="muted">
+                      <code>{credential.base_url}</code>
+                    </div>
+                  </td>
+                  <td>
+                    <code className="env-var-value">{previewAuthHeader(credential)}</code>
+                  </td>
+                  <td>
+                    <span className={`api-credential-status api-credential-status--${credential.test_status}`}>
+                      {TEST_STATUS_LABELS[credential.test_status]}
+                    </span>
+                    {credential.last_tested_at && (
+                      <div className="muted">
+                        <Timestamp value={credential.last_tested_at} />
+                      </div>
+                    )}
+                    <CredentialTest credential={credential} />
+                  </td>
+                  <td>
+                    <Timestamp value={credential.updated_at} fallback="—" />
+                  </td>
+                  <td className="env-var-actions">
+                    <button type="button" className="btn-secondary" onClick={() => setForm(formFor(credential))}>
+                      Edit
+                    </button>
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      onClick={() => handleDelete(credential)}
+                      disabled={removeCredential.isPending && removeCredential.variables?.id === credential.id}
+                    >
+                      Delete
+                    </button>
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -1,21 +1,40 @@
-import React, { useCallback, useEffect, useRef, useState } from "react";
+import React, { useCallback, useContext, useEffect, useRef, useState } from "react";
 import clsx from "clsx";
 import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
+import { useNavigate, useSearchParams } from "react-router-dom";
 import { useShelf } from "../lib/useShelfState";
-import { useWebSocket } from "../lib/useWebSocket";
+import { ConnectionStatus, useWebSocket } from "../lib/useWebSocket";
+import { useWorkflowPresence } from "../lib/useWorkflowPresence";
+import { conflictingEditors, presenceColor, presenceName, remoteSelections } from "../lib/workflowPresence";
+import { useShortcuts, useShortcutBindings } from "../lib/useShortcuts";
+import { useNotify } from "../lib/useNotifications";
+import { invalidateTrashLists } from "../lib/trash";
+import { useAuth } from "../lib/auth";
+import { SHELF_GROUPINGS, SHELF_GROUPING_LABELS, groupShelfAgents, type ShelfGrouping } from "../lib/agentShelf";
+import { useAgentShelfPrefs } from "../lib/useAgentShelfPrefs";
+import { collectTags, hasTag } from "../lib/tags";
+import { ShortcutHelpOverlay } from "../components/ShortcutHelpOverlay";
+import { WorkflowTagsButton } from "../components/WorkflowTagsButton";
 import { usePointerDrag } from "../hooks/usePointerDrag";
+import { nodeLabelFont, useNodeLabelLayout } from "../hooks/useNodeLabelLayout";
+import { estimateNodeSize } from "../lib/textMeasure";
 import {
   ReactFlow,
   ReactFlowProvider,
   Background,
   Controls,
   MiniMap,
+  Handle,
+  Position,
   addEdge,
   ViewportPortal,
   useNodesState,
   useEdgesState,
   useReactFlow,
   useStore,
+  useNodeId,
+  useUpdateNodeInternals,
+  type HandleProps,
   type Node as FlowNode,
   type Edge,
   type Connection,
@@ -26,15 +45,104 @@ import "@xyflow/react/dist/style.css";
 import "../styles/canvas-react.css";
 import toast from "react-hot-toast";
 import { ExecutionLogStream, type LogEntry } from "../components/ExecutionLogStream";
+import { ExecutionStepPanel } from "../components/ExecutionStepPanel";
+import { PendingWaitsPanel } from "../components/PendingWaitsPanel";
+import AgentHoverCard from "../components/AgentHoverCard";
+import { PresenceAvatars, RemoteCursors } from "../components/CanvasPresence";
+import NodeCommentsPanel, {
+  CommentBadge,
+  NodeCommentsContext,
+  workflowCommentsKey,
+} from "../components/NodeComments";
+import NodeInspector, { type InspectedNode } from "../components/NodeInspector";
+import AgentChatPane, { type AgentChatOption } from "../components/chat/AgentChatPane";
+import ExecutionHistorySidebar from "../components/ExecutionHistorySidebar";
+import { SubworkflowRunContext, SubworkflowRunModal } from "../components/SubworkflowRunModal";
+import { RunInputsModal } from "../components/RunInputsModal";
+import { TemplateGalleryModal } from "../components/templates/TemplateGalleryModal";
+import { PublishTemplateModal } from "../components/templates/PublishTemplateModal";
+import {
+  HEAT_METRICS,
+  aggregateNodeStats,
+  computeNodeHeat,
+  formatHeatValue,
+  heatColor,
+  type HeatMetric,
+} from "../lib/executionHeat";
+import { groupCommentThreads, summarizeNodeComments, type NodeCommentSummary } from "../lib/nodeComments";
+import { hasBreakpoint, initialStepState, stepReducer, toggleBreakpoint } from "../lib/executionStepper";
+import { PAUSED_BADGE_TITLE, confirmRunWhilePaused, isAgentPaused } from "../lib/agentPause";
+import { setRecoverableState, takeRecoveredState } from "../lib/crashReport";
+import { measuredReducer } from "../lib/perfMetrics";
+import { localStorageWriter, persistence } from "../lib/persistence";
+import { runWorkflowTask } from "../lib/workerBridge";
+import type { GraphEdge, GraphNode } from "../lib/workflowGraph";
+import { declaredRunInputs, runInputFieldErrors, type RunInputSpec } from "../lib/workflowInputs";
+import { executionHistoryQueryKey } from "../lib/executionHistory";
+import { nodeOutputCacheQueryKey, runFromBlocker } from "../lib/nodeOutputCache";
+import type { PresenceEditorData } from "../generated/ws-messages";
+import {
+  DEFAULT_NODE_SIZE,
+  alignBoxes,
+  distributeHorizontally,
+  inputHandle,
+  outputHandle,
+  resolveDropPlacement,
+  snapToGrid,
+  type AlignMode,
+  type DropPlacement,
+  type PlacedBox,
+} from "../lib/canvasSnap";
+import {
+  CONTROL_ITEMS,
+  branchHandles,
+  branchLabel,
+  controlNodeSummary,
+  defaultControlConfig,
+  edgeBranch,
+  isControlNodeType,
+  type ControlNodeType,
+} from "../lib/controlFlow";
+import { HTTP_TOOL_TYPE, defaultHttpConfig, httpNodeSummary } from "../lib/httpNode";
+import {
+  SUBWORKFLOW_ITEM,
+  defaultSubworkflowConfig,
+  subworkflowNodeSummary,
+  subworkflowTarget,
+} from "../lib/subworkflowNode";
+import {
+  WAIT_ITEM,
+  defaultWaitConfig,
+  pendingWaitFromMessage,
+  waitNodeSummary,
+  type PendingWait,
+} from "../lib/waitNode";
+import { SLACK_ITEM, SLACK_TOOL_TYPE, defaultSlackConfig, isSlackNode, slackNodeSummary } from "../lib/slackNode";
+import { GITHUB_ITEM, GITHUB_TOOL_TYPE, defaultGitHubConfig, isGitHubNode, githubNodeSummary } from "../lib/githubNode";
 import { AgentIcon, GlobeIcon, SignalIcon, WrenchIcon, ZapIcon } from "../components/icons";
 import {
   fetchAgents,
   fetchCurrentWorkflow,
+  fetchWorkflows,
+  deleteWorkflow,
+  restoreWorkflow,
+  WORKFLOW_TRASH_RETENTION_DAYS,
+  fetchEnvVars,
+  fetchApiCredentials,
   updateWorkflowCanvas,
+  validateWorkflowCanvas,
   startWorkflowExecution,
+  runFromNode,
+  stepExecution,
+  continueExecution,
+  skipWait,
   getExecutionStatus,
   getExecutionLogs,
   cancelExecution,
+  getExecutionNodeSummaries,
+  getNodeOutputCache,
+  fetchWorkflowComments,
+  ApiError,
   type AgentSummary,
   type Workflow,
   type WorkflowData,
@@ -42,6 +150,8 @@ import {
   type WorkflowNode,
   type WorkflowEdge,
   type ExecutionStatus,
+  type WorkflowExecution,
+  type WorkflowTemplate,
 } from "../services/api";
 
 // Type for node config data - properly typed to match backend schema
@@ -52,54 +162,319 @@ interface NodeConfig {
   [key: string]: unknown; // Allow additional properties
 }
 
+// Flow node data keeps the full config so keys edited in the inspector
+// (message, tool params, trigger settings...) survive a save
+type FlowNodeData = { label: string; agentId?: number; toolType?: string; config?: NodeConfig };
+
 type ToolPaletteItem = {
   type: string;
   name: string;
   icon: string;
 };
 
-type ShelfSection = "agents" | "tools";
+type ShelfSection = "agents" | "tools" | "logic";
+
+type RunOptions = {
+  stepMode?: boolean;
+  inputs?: Record<string, unknown>;
+  // Start at this node, reusing the finished execution's outputs for the rest
+  fromNode?: { executionId: number; nodeId: string };
+};
+// Run waiting on the manual trigger's inputs form
+type RunInputsPrompt = {
+  specs: RunInputSpec[];
+  stepMode: boolean;
+  // Prefill from the run being repeated
+  initialInputs?: Record<string, unknown>;
+  serverErrors?: Record<string, string>;
+};
+
+// Logic shelf: control-flow nodes plus the wait and Call Workflow nodes
+type LogicNodeType = ControlNodeType | typeof WAIT_ITEM.type | typeof SUBWORKFLOW_ITEM.type;
+const LOGIC_ITEMS: { type: LogicNodeType; name: string; icon: string }[] = [
+  ...CONTROL_ITEMS,
+  WAIT_ITEM,
+  SUBWORKFLOW_ITEM,
+];
+
+function isLogicNodeType(value: unknown): value is LogicNodeType {
+  return LOGIC_ITEMS.some((item) => item.type === value);
+}
+
+function defaultLogicConfig(type: LogicNodeType): NodeConfig {
+  if (type === WAIT_ITEM.type) return defaultWaitConfig();
+  if (type === SUBWORKFLOW_ITEM.type) return defaultSubworkflowConfig();
+  return defaultControlConfig(type);
+}
 
 const TOOL_ITEMS: ToolPaletteItem[] = [
   { type: "http-request", name: "HTTP Request", icon: "🌐" },
   { type: "url-fetch", name: "URL Fetch", icon: "📡" },
+  SLACK_ITEM,
+  GITHUB_ITEM,
 ] ;
 
 const SECTION_STATE_STORAGE_KEY = "canvas_section_state";
 const DEFAULT_SECTION_STATE: Record<ShelfSection, boolean> = {
   agents: false,
   tools: false,
+  logic: false,
 };
-const SNAP_GRID_SIZE = 24;
+const GRID_SIZE_STORAGE_KEY = "canvas_grid_size";
+const SPLIT_VIEW_STORAGE_KEY = "canvas_split_chat";
+// Step-through reducer, timed for the perf HUD
+const timedStepReducer = measuredReducer(stepReducer);
+
+// Persistence slices owned by the canvas (see lib/persistence.ts)
+const WORKFLOW_SLICE = "workflow";
+const SECTION_STATE_SLICE = "ui:canvas-sections";
+const GRID_SIZE_SLICE = "ui:canvas-grid";
+const SPLIT_VIEW_SLICE = "ui:canvas-split";
+
+// Key for the canvas carried across a soft reload from the crash overlay
+const CANVAS_RECOVERY_KEY = "canvas";
+const GRID_SIZE_OPTIONS = [12, 24, 48];
+const DEFAULT_GRID_SIZE = 24;
+type ArrangeMode = AlignMode | "distribute";
+// Formatting toolbar shown for a multi-selection
+const ARRANGE_COMMANDS: { mode: ArrangeMode; label: string; icon: string; minNodes: number }[] = [
+  { mode: "left", label: "Align left", icon: "⇤", minNodes: 2 },
+  { mode: "right", label: "Align right", icon: "⇥", minNodes: 2 },
+  { mode: "top", label: "Align top", icon: "⤒", minNodes: 2 },
+  { mode: "distribute", label: "Distribute horizontally", icon: "⇹", minNodes: 3 },
+];
+// Executions aggregated by the heat overlay
+const HEAT_EXECUTIONS_LIMIT = 20;
+
+type NodeHeatData = { color: string; label: string };
+
+function HeatBadge({ heat }: { heat?: NodeHeatData }) {
+  if (!heat) return null;
+  return (
+    <div className="node-heat-badge" style={{ borderColor: heat.color }} title={heat.label}>
+      {heat.label}
+    </div>
+  );
+}
+
+// Label box sized from measured text so long names wrap instead of clipping
+function NodeLabel({ text, className }: { text: string; className: string }) {
+  const layout = useNodeLabelLayout(text);
+  return (
+    <span className={clsx("node-label", className)} style={{ width: layout.width }} title={text}>
+      {layout.lines.map((line, index) => (
+        <span key={index} className="node-label-line">
+          {line}
+        </span>
+      ))}
+    </span>
+  );
+}
+
+// Handles only exist inside a React Flow node; the MiniMap renders node bodies without them
+function NodeHandle(props: HandleProps) {
+  return useNodeId() ? <Handle {...props} /> : null;
+}
+
+// Overlays layered on at render time; never saved with the canvas
+type NodeMarkers = { heat?: NodeHeatData; comments?: NodeCommentSummary };
+type AgentNodeData = { label: string; agentId?: number; paused?: boolean } & NodeMarkers;
 
 // Custom node component for agents
-function AgentNode({ data }: { data: { label: string; agentId?: number } }) {
+function AgentNode({ id, data }: { id: string; data: AgentNodeData }) {
   return (
     <div className="agent-node">
+      <NodeHandle type="target" position={Position.Left} />
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
+      {data.paused && (
+        <span className="paused-badge" title={PAUSED_BADGE_TITLE}>
+          Paused
+        </span>
+      )}
       <div className="agent-icon"><AgentIcon width={20} height={20} /></div>
-      <div className="agent-name">{data.label}</div>
+      {data.agentId != null ? (
+        <AgentHoverCard agentId={data.agentId} agentName={data.label}>
+          <NodeLabel text={data.label} className="agent-name" />
+        </AgentHoverCard>
+      ) : (
+        <NodeLabel text={data.label} className="agent-name" />
+      )}
     </div>
   );
 }
 
 // Custom node component for tools
-function ToolNode({ data }: { data: { label: string; toolType?: string } }) {
+function ToolNode({
+  id,
+  data,
+}: {
+  id: string;
+  data: { label: string; toolType?: string; config?: NodeConfig } & NodeMarkers;
+}) {
   const IconComponent = data.toolType === 'http-request' ? GlobeIcon : data.toolType === 'url-fetch' ? SignalIcon : WrenchIcon;
 
+  if (isSlackNode({ ...data.config, tool_type: data.toolType })) {
+    return (
+      <div className="tool-node http-node">
+        <NodeHandle type="target" position={Position.Left} />
+        <NodeHandle type="source" position={Position.Right} />
+        <HeatBadge heat={data.heat} />
+        <CommentBadge nodeId={id} summary={data.comments} />
+        <div className="tool-icon">{SLACK_ITEM.icon}</div>
+        <div className="http-node-body">
+          <NodeLabel text={data.label} className="tool-name" />
+          <div className="http-node-request">{slackNodeSummary(data.config)}</div>
+        </div>
+      </div>
+    );
+  }
+
+  if (isGitHubNode({ ...data.config, tool_type: data.toolType })) {
+    return (
+      <div className="tool-node http-node">
+        <NodeHandle type="target" position={Position.Left} />
+        <NodeHandle type="source" position={Position.Right} />
+        <HeatBadge heat={data.heat} />
+        <CommentBadge nodeId={id} summary={data.comments} />
+        <div className="tool-icon">{GITHUB_ITEM.icon}</div>
+        <div className="http-node-body">
+          <NodeLabel text={data.label} className="tool-name" />
+          <div className="http-node-request">{githubNodeSummary(data.config)}</div>
+        </div>
+      </div>
+    );
+  }
+
   return (
     <div className="tool-node">
+      <NodeHandle type="target" position={Position.Left} />
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
       <div className="tool-icon"><IconComponent width={20} height={20} /></div>
-      <div className="tool-name">{data.label}</div>
+      <NodeLabel text={data.label} className="tool-name" />
+    </div>
+  );
+}
+
+// Custom node component for HTTP requests
+function HttpNode({ id, data }: { id: string; data: { label: string; config?: NodeConfig } & NodeMarkers }) {
+  return (
+    <div className="tool-node http-node">
+      <NodeHandle type="target" position={Position.Left} />
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
+      <div className="tool-icon"><GlobeIcon width={20} height={20} /></div>
+      <div className="http-node-body">
+        <NodeLabel text={data.label} className="tool-name" />
+        <div className="http-node-request">{httpNodeSummary(data.config)}</div>
+      </div>
     </div>
   );
 }
 
 // Custom node component for triggers
-function TriggerNode({ data }: { data: { label: string } }) {
+function TriggerNode({ id, data }: { id: string; data: { label: string } & NodeMarkers }) {
   return (
     <div className="trigger-node">
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
       <div className="trigger-icon"><ZapIcon width={20} height={20} /></div>
-      <div className="trigger-name">{data.label}</div>
+      <NodeLabel text={data.label} className="trigger-name" />
+    </div>
+  );
+}
+
+type WaitNodeData = { label: string; config?: NodeConfig; waiting?: boolean } & NodeMarkers;
+
+// Custom node component for waits; flagged while the current run is parked on it
+function WaitNode({ id, data }: { id: string; data: WaitNodeData }) {
+  return (
+    <div className="control-node wait-node" data-testid="wait-node">
+      <NodeHandle type="target" position={Position.Left} />
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
+      {data.waiting && <span className="wait-node-badge">Waiting</span>}
+      <div className="control-node-header">
+        <span className="control-icon">{WAIT_ITEM.icon}</span>
+        <NodeLabel text={data.label} className="control-name" />
+      </div>
+      <div className="control-node-summary">{waitNodeSummary(data.config)}</div>
+    </div>
+  );
+}
+
+type SubworkflowNodeData = {
+  label: string;
+  config?: NodeConfig;
+  // Name of the called workflow, and whether the current run has reached this node
+  calleeName?: string;
+  started?: boolean;
+} & NodeMarkers;
+
+// Custom node component for Call Workflow nodes; opens the child run once the current run reaches it
+function SubworkflowNode({ id, data }: { id: string; data: SubworkflowNodeData }) {
+  const openRun = useContext(SubworkflowRunContext);
+  return (
+    <div className="control-node subworkflow-node" data-testid="subworkflow-node">
+      <NodeHandle type="target" position={Position.Left} />
+      <NodeHandle type="source" position={Position.Right} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
+      <div className="control-node-header">
+        <span className="control-icon">{SUBWORKFLOW_ITEM.icon}</span>
+        <NodeLabel text={data.label} className="control-name" />
+      </div>
+      <div className="control-node-summary">{subworkflowNodeSummary(data.config, data.calleeName)}</div>
+      {data.started && openRun && (
+        <button
+          type="button"
+          className="subworkflow-node-open nodrag"
+          onClick={(event) => {
+            event.stopPropagation();
+            openRun(id);
+          }}
+        >
+          Open run
+        </button>
+      )}
+    </div>
+  );
+}
+
+type ControlNodeData = { label: string; config?: NodeConfig } & NodeMarkers;
+
+// Custom node component for If/Else, Switch and Loop: one output handle per branch
+function ControlNode({ id, type, data }: { id: string; type: ControlNodeType; data: ControlNodeData }) {
+  const updateNodeInternals = useUpdateNodeInternals();
+  const handles = branchHandles(type, data.config);
+  const handleKey = handles.map((handle) => handle.id).join("\n");
+  // Switch cases add and remove handles without necessarily resizing the node
+  useEffect(() => updateNodeInternals(id), [handleKey, id, updateNodeInternals]);
+
+  return (
+    <div className={clsx("control-node", `control-node--${type}`)} data-testid={`control-node-${type}`}>
+      <NodeHandle type="target" position={Position.Left} />
+      <HeatBadge heat={data.heat} />
+      <CommentBadge nodeId={id} summary={data.comments} />
+      <div className="control-node-header">
+        <span className="control-icon">{CONTROL_ITEMS.find((item) => item.type === type)?.icon}</span>
+        <NodeLabel text={data.label} className="control-name" />
+      </div>
+      <div className="control-node-summary">{controlNodeSummary(type, data.config)}</div>
+      <div className="control-node-branches">
+        {handles.map((handle) => (
+          <div key={handle.id} className="control-node-branch">
+            {handle.label}
+            <NodeHandle type="source" id={handle.id} position={Position.Right} />
+          </div>
+        ))}
+      </div>
     </div>
   );
 }
@@ -122,9 +497,13 @@ function MiniMapNode(props: any) {
     <foreignObject x={x} y={y} width={width} height={height}>
       {/* We use a div with 100% size to contain the node component */}
       <div className="minimap-node-content" style={{ width: '100%', height: '100%' }}>
-        {type === 'agent' && <AgentNode data={data as { label: string; agentId?: number }} />}
-        {type === 'tool' && <ToolNode data={data as { label: string; toolType?: string }} />}
-        {type === 'trigger' && <TriggerNode data={data as { label: string }} />}
+        {type === 'agent' && <AgentNode id={id} data={data as { label: string; agentId?: number }} />}
+        {type === 'tool' && <ToolNode id={id} data={data as FlowNodeData} />}
+        {type === 'trigger' && <TriggerNode id={id} data={data as { label: string }} />}
+        {type === 'http' && <HttpNode id={id} data={data as { label: string; config?: NodeConfig }} />}
+        {isControlNodeType(type) && <ControlNode id={id} type={type} data={data as ControlNodeData} />}
+        {type === 'wait' && <WaitNode id={id} data={data as WaitNodeData} />}
+        {type === 'subworkflow' && <SubworkflowNode id={id} data={data as SubworkflowNodeData} />}
       </div>
     </foreignObject>
   );
@@ -134,6 +513,12 @@ const nodeTypes: NodeTypes = {
   agent: AgentNode,
   tool: ToolNode,
   trigger: TriggerNode,
+  http: HttpNode,
+  conditional: ControlNode,
+  switch: ControlNode,
+  loop: ControlNode,
+  wait: WaitNode,
+  subworkflow: SubworkflowNode,
 };
 
 const clamp = (value: number, min: number, max: number) => Math.min(Math.max(value, min), max);
@@ -148,30 +533,114 @@ function convertToReactFlowData(workflowData: WorkflowData): { nodes: FlowNode[]
       label: (node.config as NodeConfig)?.text || `${node.type} node`,
       agentId: (node.config as NodeConfig)?.agent_id,
       toolType: (node.config as NodeConfig)?.tool_type,
+      config: node.config as NodeConfig,
     },
   }));
 
-  const edges: Edge[] = workflowData.edges.map((edge: WorkflowEdge) => ({
-    id: `${edge.from_node_id}-${edge.to_node_id}`,
-    source: edge.from_node_id,
-    target: edge.to_node_id,
-  }));
+  const nodesById = new Map(workflowData.nodes.map((node) => [node.id, node]));
+  const edges: Edge[] = workflowData.edges.map((edge: WorkflowEdge) => {
+    const source = nodesById.get(edge.from_node_id);
+    const branch = (edge.config as NodeConfig | undefined)?.branch;
+    return flowEdge(edge.from_node_id, edge.to_node_id, source?.type, source?.config as NodeConfig, branch);
+  });
 
   return { nodes, edges };
 }
 
+// Edges leaving a control-flow node start at their branch's handle (its default branch when not given)
+function flowEdge(
+  sourceId: string,
+  targetId: string,
+  sourceType: string | undefined,
+  sourceConfig: NodeConfig | undefined,
+  branch?: unknown
+): Edge {
+  const resolved = edgeBranch(sourceType, { branch });
+  if (!resolved) {
+    return { id: `${sourceId}-${targetId}`, source: sourceId, target: targetId };
+  }
+  return {
+    id: `${sourceId}:${resolved}-${targetId}`,
+    source: sourceId,
+    target: targetId,
+    sourceHandle: resolved,
+    label: branchLabel(sourceType, sourceConfig, resolved),
+    className: "branch-edge",
+  };
+}
+
+type DropPayload =
+  | { type: "agent"; agentId: number; label: string }
+  | { type: "tool"; toolType: string; label: string }
+  | { type: "control"; controlType: LogicNodeType; label: string };
+
+// New canvas node for a shelf item dropped at ``position``
+function nodeFromDrop(payload: DropPayload, position: { x: number; y: number }): FlowNode {
+  switch (payload.type) {
+    case "agent":
+      return {
+        id: `agent-${Date.now()}`,
+        type: "agent",
+        position,
+        data: { label: payload.label, agentId: payload.agentId },
+      };
+    case "control":
+      return {
+        id: `${payload.controlType}-${Date.now()}`,
+        type: payload.controlType,
+        position,
+        data: {
+          label: payload.label,
+          config: defaultLogicConfig(payload.controlType),
+        },
+      };
+    case "tool":
+      if (payload.toolType === HTTP_TOOL_TYPE) {
+        return {
+          id: `http-${Date.now()}`,
+          type: "http",
+          position,
+          data: { label: payload.label, config: defaultHttpConfig() },
+        };
+      }
+      if (payload.toolType === SLACK_TOOL_TYPE) {
+        return {
+          id: `slack-${Date.now()}`,
+          type: "tool",
+          position,
+          data: { label: payload.label, toolType: payload.toolType, config: defaultSlackConfig() },
+        };
+      }
+      if (payload.toolType === GITHUB_TOOL_TYPE) {
+        return {
+          id: `github-${Date.now()}`,
+          type: "tool",
+          position,
+          data: { label: payload.label, toolType: payload.toolType, config: defaultGitHubConfig() },
+        };
+      }
+      return {
+        id: `tool-${Date.now()}`,
+        type: "tool",
+        position,
+        data: { label: payload.label, toolType: payload.toolType },
+      };
+  }
+}
+
 // Normalize workflow data to eliminate float drift and ordering differences
 function normalizeWorkflow(nodes: FlowNode[], edges: Edge[]): WorkflowDataInput {
   const sortedNodes = [...nodes]
     .sort((a, b) => a.id.localeCompare(b.id))
     .map((node) => ({
       id: node.id,
-      type: node.type as "agent" | "tool" | "trigger" | "conditional",
+      type: node.type as WorkflowNode["type"],
       position: {
         x: Math.round(node.position.x * 2) / 2, // 0.5px quantization
         y: Math.round(node.position.y * 2) / 2,
       },
       config: {
+        ...(node.data as FlowNodeData).config,
         text: node.data.label,
         agent_id: node.data.agentId,
         tool_type: node.data.toolType,
@@ -183,7 +652,8 @@ function normalizeWorkflow(nodes: FlowNode[], edges: Edge[]): WorkflowDataInput
     .map((edge) => ({
       from_node_id: edge.source,
       to_node_id: edge.target,
-      config: {}, // Edges typically don't have config data
+      // Only edges out of control-flow nodes carry config: the branch they belong to
+      config: edge.sourceHandle ? { branch: edge.sourceHandle } : {},
     }));
 
   return { nodes: sortedNodes, edges: sortedEdges };
@@ -204,8 +674,52 @@ async function hashWorkflow(data: WorkflowDataInput): Promise<string> {
   return hashArray.map((b) => b.toString(16).padStart(2, "0")).join("");
 }
 
-function CanvasPageContent() {
+function nodeBox(node: FlowNode): PlacedBox {
+  let width = node.measured?.width ?? node.width;
+  let height = node.measured?.height ?? node.height;
+  if (!width || !height) {
+    // Not rendered by React Flow yet: size it from the label text instead
+    const estimate = estimateNodeSize(node.data.label ?? "", nodeLabelFont());
+    width ||= estimate.width;
+    height ||= estimate.height;
+  }
+  return { id: node.id, x: node.position.x, y: node.position.y, width, height };
+}
+
+// Worker input: drop React Flow state the graph algorithms don't need
+function graphInput(nodes: FlowNode[], edges: Edge[]): { nodes: GraphNode[]; edges: GraphEdge[] } {
+  return {
+    nodes: nodes.map((node) => {
+      const { id, width, height } = nodeBox(node);
+      return { id, type: node.type, width, height };
+    }),
+    edges: edges.map((edge) => ({ source: edge.source, target: edge.target })),
+  };
+}
+
+function readStoredSplitView(): boolean {
+  if (typeof window === "undefined") return false;
+  try {
+    return window.localStorage.getItem(SPLIT_VIEW_STORAGE_KEY) === "true";
+  } catch {
+    return false;
+  }
+}
+
+function readStoredGridSize(): number {
+  if (typeof window === "undefined") return DEFAULT_GRID_SIZE;
+  try {
+    const stored = Number(window.localStorage.getItem(GRID_SIZE_STORAGE_KEY));
+    return GRID_SIZE_OPTIONS.includes(stored) ? stored : DEFAULT_GRID_SIZE;
+  } catch {
+    return DEFAULT_GRID_SIZE;
+  }
+}
+
+function CanvasPageContent({ workflowId }: { workflowId?: number }) {
   const queryClient = useQueryClient();
+  const navigate = useNavigate();
+  const notify = useNotify();
   const { isShelfOpen } = useShelf();
   const reactFlowInstance = useReactFlow();
   const zoom = useStore((state) => state.transform[2]);
@@ -224,10 +738,20 @@ function CanvasPageContent() {
 
   // Execution state
   const [currentExecution, setCurrentExecution] = useState<ExecutionStatus | null>(null);
+  // Wait nodes the current run is parked on, keyed by node id
+  const [pendingWaits, setPendingWaits] = useState<Record<string, PendingWait>>({});
+  // Nodes the current run has reached, so Call Workflow nodes can open their child run
+  const [startedNodeIds, setStartedNodeIds] = useState<Record<string, true>>({});
+  const [runModalNodeId, setRunModalNodeId] = useState<string | null>(null);
+  const [runInputsPrompt, setRunInputsPrompt] = useState<RunInputsPrompt | null>(null);
+  const [showHistory, setShowHistory] = useState(false);
   const [executionLogs, setExecutionLogs] = useState<LogEntry[]>([]);
+  const [stepState, dispatchStep] = React.useReducer(timedStepReducer, initialStepState);
   const [isDragActive, setIsDragActive] = useState(false);
   const [isDragging, setIsDragging] = useState(false);
   const [showLogs, setShowLogs] = useState(false);
+  const [heatOverlayEnabled, setHeatOverlayEnabled] = useState(false);
+  const [heatMetric, setHeatMetric] = useState<HeatMetric>("failure_rate");
 
   // Draggable logs panel state
   const [logsPanelPosition, setLogsPanelPosition] = useState<{ x: number; y: number } | null>(null);
@@ -238,6 +762,13 @@ function CanvasPageContent() {
   const [dragPreviewPosition, setDragPreviewPosition] = useState<{ x: number; y: number } | null>(
     null
   );
+  const [dropHints, setDropHints] = useState<Omit<DropPlacement, "position"> | null>(null);
+  const [snapToGridEnabled, setSnapToGridEnabled] = useState(true);
+  const [gridSize, setGridSize] = useState(readStoredGridSize);
+  const [guidesVisible, setGuidesVisible] = useState(true);
+  // Split view: the chat of the selected agent node sits beside the canvas
+  const [splitViewEnabled, setSplitViewEnabled] = useState(readStoredSplitView);
+  const [chatAgentId, setChatAgentId] = useState<number | null>(null);
   const transparentDragImage = React.useMemo(() => {
     const img = new Image();
     img.src = "data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///ywAAAAAAQABAAACAUwAOw==";
@@ -246,8 +777,22 @@ function CanvasPageContent() {
   const resetDragPreview = useCallback(() => {
     setDragPreviewData(null);
     setDragPreviewPosition(null);
+    setDropHints(null);
   }, []);
 
+  // Grid snap, alignment with neighbours and edge suggestion for a node dropped at ``position``
+  const resolvePlacement = useCallback(
+    (position: { x: number; y: number }, preview: DragPreviewData | null): DropPlacement => {
+      const others = reactFlowInstance.getNodes().map(nodeBox);
+      const size = {
+        width: preview?.baseSize.width || DEFAULT_NODE_SIZE.width,
+        height: preview?.baseSize.height || DEFAULT_NODE_SIZE.height,
+      };
+      return resolveDropPlacement(position, size, others, { grid: snapToGridEnabled ? gridSize : null });
+    },
+    [gridSize, reactFlowInstance, snapToGridEnabled]
+  );
+
   const updatePreviewPositionFromClientPoint = useCallback(
     (clientPoint: { x: number; y: number }, overridePreview?: DragPreviewData | null) => {
       const preview = overridePreview ?? dragPreviewData;
@@ -267,17 +812,21 @@ function CanvasPageContent() {
         x: clientPoint.x - offsetX,
         y: clientPoint.y - offsetY,
       });
-      setDragPreviewPosition(flowPosition);
+      const { position, ...hints } = resolvePlacement(flowPosition, preview);
+      setDragPreviewPosition(position);
+      setDropHints(hints);
     },
-    [dragPreviewData, reactFlowInstance, zoom]
+    [dragPreviewData, reactFlowInstance, resolvePlacement, zoom]
   );
 
-  type DropPayload =
-    | { type: "agent"; agentId: number; label: string }
-    | { type: "tool"; toolType: string; label: string };
-
   const toDropPayload = useCallback(
-    (raw: { type: "agent" | "tool"; id?: string; name: string; tool_type?: string }): DropPayload | null => {
+    (raw: {
+      type: "agent" | "tool" | "control";
+      id?: string;
+      name: string;
+      tool_type?: string;
+      control_type?: string;
+    }): DropPayload | null => {
       if (!raw?.type || !raw.name) {
         return null;
       }
@@ -297,6 +846,13 @@ function CanvasPageContent() {
         return { type: "tool", toolType: raw.tool_type, label: raw.name };
       }
 
+      if (raw.type === "control") {
+        if (!isLogicNodeType(raw.control_type)) {
+          return null;
+        }
+        return { type: "control", controlType: raw.control_type, label: raw.name };
+      }
+
       return null;
     },
     []
@@ -312,40 +868,32 @@ function CanvasPageContent() {
           }
         : { x: 0, y: 0 };
 
-      const position = reactFlowInstance.screenToFlowPosition({
-        x: clientPoint.x - pointerAdjustment.x,
-        y: clientPoint.y - pointerAdjustment.y,
-      });
+      const { position, suggestedSourceId } = resolvePlacement(
+        reactFlowInstance.screenToFlowPosition({
+          x: clientPoint.x - pointerAdjustment.x,
+          y: clientPoint.y - pointerAdjustment.y,
+        }),
+        preview
+      );
 
-      const newNode: FlowNode =
-        payload.type === "agent"
-          ? {
-              id: `agent-${Date.now()}`,
-              type: "agent",
-              position,
-              data: {
-                label: payload.label,
-                agentId: payload.agentId,
-              },
-            }
-          : {
-              id: `tool-${Date.now()}`,
-              type: "tool",
-              position,
-              data: {
-                label: payload.label,
-                toolType: payload.toolType,
-              },
-            };
+      const newNode = nodeFromDrop(payload, position);
 
       setNodes((nodes) => [...nodes, newNode]);
+      const suggestedSource = suggestedSourceId ? reactFlowInstance.getNode(suggestedSourceId) : undefined;
+      if (suggestedSource) {
+        const sourceConfig = (suggestedSource.data as FlowNodeData).config;
+        setEdges((edges) => [...edges, flowEdge(suggestedSource.id, newNode.id, suggestedSource.type, sourceConfig)]);
+      }
       setIsDragActive(false);
       resetDragPreview();
     },
-    [dragPreviewData, reactFlowInstance, resetDragPreview, setNodes, zoom]
+    [dragPreviewData, reactFlowInstance, resetDragPreview, resolvePlacement, setEdges, setNodes, zoom]
   );
 
   const [searchTerm, setSearchTerm] = useState("");
+  const { user } = useAuth();
+  const shelfPrefs = useAgentShelfPrefs();
+  const [shelfTag, setShelfTag] = useState("");
   const [collapsedSections, setCollapsedSections] = useState<Record<ShelfSection, boolean>>(() => {
     if (typeof window === "undefined") {
       return { ...DEFAULT_SECTION_STATE };
@@ -362,20 +910,35 @@ function CanvasPageContent() {
       return { ...DEFAULT_SECTION_STATE };
     }
   });
-  const [snapToGridEnabled, setSnapToGridEnabled] = useState(true);
-  const [guidesVisible, setGuidesVisible] = useState(true);
   const [contextMenu, setContextMenu] = useState<{ nodeId: string; x: number; y: number } | null>(null);
+  const [commentNodeId, setCommentNodeId] = useState<string | null>(null);
   const [showShortcutHelp, setShowShortcutHelp] = useState(false);
+  const [showTemplateGallery, setShowTemplateGallery] = useState(false);
+  const [showPublishTemplate, setShowPublishTemplate] = useState(false);
 
   React.useEffect(() => {
-    if (typeof window === "undefined") return;
-    try {
-      window.localStorage.setItem(SECTION_STATE_STORAGE_KEY, JSON.stringify(collapsedSections));
-    } catch (error) {
-      console.warn("Failed to persist shelf section state:", error);
-    }
+    const unregisterSections = persistence.register(SECTION_STATE_SLICE, localStorageWriter(SECTION_STATE_STORAGE_KEY));
+    const unregisterGrid = persistence.register(GRID_SIZE_SLICE, localStorageWriter(GRID_SIZE_STORAGE_KEY));
+    const unregisterSplit = persistence.register(SPLIT_VIEW_SLICE, localStorageWriter(SPLIT_VIEW_STORAGE_KEY));
+    return () => {
+      unregisterSections();
+      unregisterGrid();
+      unregisterSplit();
+    };
+  }, []);
+
+  React.useEffect(() => {
+    persistence.schedule(SECTION_STATE_SLICE, collapsedSections);
   }, [collapsedSections]);
 
+  React.useEffect(() => {
+    persistence.schedule(GRID_SIZE_SLICE, gridSize);
+  }, [gridSize]);
+
+  React.useEffect(() => {
+    persistence.schedule(SPLIT_VIEW_SLICE, splitViewEnabled);
+  }, [splitViewEnabled]);
+
   const toggleSection = useCallback((section: ShelfSection) => {
     setCollapsedSections((prev) => ({
       ...prev,
@@ -383,47 +946,16 @@ function CanvasPageContent() {
     }));
   }, []);
 
-  useEffect(() => {
-    const handleKeyDown = (event: KeyboardEvent) => {
-      const target = event.target as HTMLElement | null;
-      const isFormField =
-        target instanceof HTMLInputElement ||
-        target instanceof HTMLTextAreaElement ||
-        target instanceof HTMLSelectElement ||
-        target?.isContentEditable;
-
-      if (isFormField) {
-        return;
-      }
-
-      if (event.shiftKey) {
-        const key = event.key.toLowerCase();
-        if (key === "s") {
-          event.preventDefault();
-          setSnapToGridEnabled((prev) => !prev);
-          return;
-        }
-        if (key === "g") {
-          event.preventDefault();
-          setGuidesVisible((prev) => !prev);
-          return;
-        }
-        if (event.code === "Slash") {
-          event.preventDefault();
-          setShowShortcutHelp((prev) => !prev);
-          return;
-        }
-      }
-
-      if (event.key === "Escape" && showShortcutHelp) {
-        event.preventDefault();
-        setShowShortcutHelp(false);
-      }
-    };
+  const shortcutBindings = useShortcutBindings();
+  const snapChord = shortcutBindings["canvas.toggleSnap"];
+  const guidesChord = shortcutBindings["canvas.toggleGuides"];
+  useShortcuts("canvas", {
+    "canvas.toggleSnap": () => setSnapToGridEnabled((prev) => !prev),
+    "canvas.toggleGuides": () => setGuidesVisible((prev) => !prev),
+    "canvas.showHelp": () => setShowShortcutHelp((prev) => !prev),
+  });
 
-    window.addEventListener("keydown", handleKeyDown);
-    return () => window.removeEventListener("keydown", handleKeyDown);
-  }, [showShortcutHelp]);
+  const closeShortcutHelp = useCallback(() => setShowShortcutHelp(false), []);
 
   useEffect(() => {
     if (!contextMenu) {
@@ -482,14 +1014,27 @@ function CanvasPageContent() {
         ...sourceNode,
         id: `${sourceNode.id}-copy-${Date.now()}`,
         position: {
-          x: sourceNode.position.x + SNAP_GRID_SIZE,
-          y: sourceNode.position.y + SNAP_GRID_SIZE,
+          x: sourceNode.position.x + gridSize,
+          y: sourceNode.position.y + gridSize,
         },
         selected: false,
       };
       return [...currentNodes, duplicatedNode];
     });
     setContextMenu(null);
+  }, [contextMenu, gridSize, setNodes]);
+
+  const handleToggleBreakpoint = useCallback(() => {
+    if (!contextMenu) return;
+    const { nodeId } = contextMenu;
+    setNodes((currentNodes) =>
+      currentNodes.map((node) =>
+        node.id === nodeId
+          ? { ...node, data: { ...node.data, config: toggleBreakpoint(node.data.config) } }
+          : node
+      )
+    );
+    setContextMenu(null);
   }, [contextMenu, setNodes]);
 
   const handleDeleteNode = useCallback(() => {
@@ -502,14 +1047,182 @@ function CanvasPageContent() {
     setContextMenu(null);
   }, [contextMenu, setEdges, setNodes]);
 
+  const handleOpenComments = useCallback(() => {
+    if (!contextMenu) return;
+    setCommentNodeId(contextMenu.nodeId);
+    setContextMenu(null);
+  }, [contextMenu]);
+
   const handlePaneClick = useCallback(() => {
     setContextMenu(null);
   }, []);
 
+  // The inspector follows a single selected node; multi-selection hides it
+  const selectedNodes = nodes.filter((node) => node.selected);
+  const selectedNode = selectedNodes.length === 1 ? selectedNodes[0] : null;
+  const inspectedNode: InspectedNode | null = React.useMemo(() => {
+    if (!selectedNode) return null;
+    const data = selectedNode.data as FlowNodeData;
+    return {
+      id: selectedNode.id,
+      type: selectedNode.type as InspectedNode["type"],
+      label: data.label,
+      config: { ...data.config, text: data.label, agent_id: data.agentId, tool_type: data.toolType },
+    };
+    // Position changes produce new node objects but keep `data`
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [selectedNode?.id, selectedNode?.type, selectedNode?.data]);
+
+  // Agents placed on the canvas, once each, for the split view's agent picker
+  const canvasAgents: AgentChatOption[] = React.useMemo(() => {
+    const seen = new Map<number, AgentChatOption>();
+    nodes.forEach((node) => {
+      const data = node.data as FlowNodeData;
+      if (node.type === "agent" && data.agentId != null && !seen.has(data.agentId)) {
+        seen.set(data.agentId, { agentId: data.agentId, label: data.label });
+      }
+    });
+    return [...seen.values()];
+  }, [nodes]);
+
+  // The chat follows the selected agent node; other selections keep the last agent
+  const selectedAgentId = selectedNode?.type === "agent" ? (selectedNode.data as FlowNodeData).agentId : undefined;
+  React.useEffect(() => {
+    if (selectedAgentId != null) setChatAgentId(selectedAgentId);
+  }, [selectedAgentId]);
+  const splitChatAgentId = chatAgentId ?? canvasAgents[0]?.agentId ?? null;
+
+  // Picking an agent in the chat selects its (first) node on the canvas
+  const handleSelectChatAgent = useCallback(
+    (agentId: number) => {
+      setChatAgentId(agentId);
+      setNodes((currentNodes) => {
+        const target = currentNodes.find((node) => (node.data as FlowNodeData).agentId === agentId);
+        return currentNodes.map((node) => {
+          const selected = node === target;
+          return selected === Boolean(node.selected) ? node : { ...node, selected };
+        });
+      });
+    },
+    [setNodes]
+  );
+
+  // Dashed line from the suggested source's output to the ghost's input while dragging
+  const suggestedEdge = React.useMemo(() => {
+    const sourceId = dropHints?.suggestedSourceId;
+    const source = sourceId ? nodes.find((node) => node.id === sourceId) : undefined;
+    if (!source || !dragPreviewPosition) return null;
+    return {
+      from: outputHandle(nodeBox(source)),
+      to: inputHandle({
+        ...dragPreviewPosition,
+        width: dragPreviewData?.baseSize.width || DEFAULT_NODE_SIZE.width,
+        height: dragPreviewData?.baseSize.height || DEFAULT_NODE_SIZE.height,
+      }),
+    };
+  }, [dragPreviewData, dragPreviewPosition, dropHints, nodes]);
+
+  const handleArrangeSelection = useCallback(
+    (mode: ArrangeMode) => {
+      setNodes((currentNodes) => {
+        const boxes = currentNodes.filter((node) => node.selected).map(nodeBox);
+        const positions = mode === "distribute" ? distributeHorizontally(boxes) : alignBoxes(boxes, mode);
+        if (positions.size === 0) return currentNodes;
+        return currentNodes.map((node) => {
+          const position = positions.get(node.id);
+          return position ? { ...node, position } : node;
+        });
+      });
+    },
+    [setNodes]
+  );
+
+  // Layout and validation run in the workflow worker so large canvases stay responsive
+  const [graphTaskRunning, setGraphTaskRunning] = useState(false);
+
+  const handleAutoLayout = useCallback(async () => {
+    if (nodes.length === 0 || graphTaskRunning) return;
+    setGraphTaskRunning(true);
+    const toastId = toast.loading("Arranging nodes…");
+    try {
+      const positions = await runWorkflowTask("layout", graphInput(nodes, edges), {
+        onProgress: (fraction) => toast.loading(`Arranging nodes… ${Math.round(fraction * 100)}%`, { id: toastId }),
+      });
+      // Keep the arrangement where the graph already sits
+      const originX = Math.min(...nodes.map((node) => node.position.x));
+      const originY = Math.min(...nodes.map((node) => node.position.y));
+      setNodes((currentNodes) =>
+        currentNodes.map((node) => {
+          const offset = positions[node.id];
+          if (!offset) return node;
+          const position = { x: originX + offset.x, y: originY + offset.y };
+          return { ...node, position: snapToGridEnabled ? snapToGrid(position, gridSize) : position };
+        })
+      );
+      toast.success("Nodes arranged", { id: toastId });
+    } catch (error) {
+      toast.error(`Auto layout failed: ${error instanceof Error ? error.message : "Unknown error"}`, { id: toastId });
+    } finally {
+      setGraphTaskRunning(false);
+    }
+  }, [edges, graphTaskRunning, gridSize, nodes, setNodes, snapToGridEnabled]);
+
+  const handleValidateWorkflow = useCallback(async () => {
+    if (graphTaskRunning) return;
+    setGraphTaskRunning(true);
+    try {
+      const issues = await runWorkflowTask("validate", graphInput(nodes, edges));
+      if (issues.length === 0) {
+        toast.success("No problems found");
+        return;
+      }
+      // Select the offending nodes so they're easy to find
+      const flagged = new Set(issues.flatMap((issue) => issue.nodeIds));
+      setNodes((currentNodes) => currentNodes.map((node) => ({ ...node, selected: flagged.has(node.id) })));
+      const summary = issues.map((issue) => issue.message).join("\n");
+      if (issues.some((issue) => issue.severity === "error")) {
+        toast.error(summary);
+      } else {
+        toast(summary, { icon: "⚠️" });
+      }
+    } catch (error) {
+      toast.error(`Validation failed: ${error instanceof Error ? error.message : "Unknown error"}`);
+    } finally {
+      setGraphTaskRunning(false);
+    }
+  }, [edges, graphTaskRunning, nodes, setNodes]);
+
+  const handleApplyNodeConfig = useCallback(
+    (nodeId: string, update: { label: string; config: NodeConfig }) => {
+      setNodes((currentNodes) =>
+        currentNodes.map((node) =>
+          node.id === nodeId
+            ? {
+                ...node,
+                data: {
+                  ...node.data,
+                  label: update.label,
+                  agentId: update.config.agent_id,
+                  toolType: update.config.tool_type,
+                  config: update.config,
+                },
+              }
+            : node
+        )
+      );
+    },
+    [setNodes]
+  );
+
+  const handleCloseInspector = useCallback(() => {
+    setNodes((currentNodes) => currentNodes.map((node) => (node.selected ? { ...node, selected: false } : node)));
+  }, [setNodes]);
+
   type DraggableAgent = { id: number; name: string };
-  type DraggableTool = { type: string; name: string };
+  // Logic items are dragged like tools but drop as control-flow nodes
+  type DraggableTool = { type: string; name: string; kind?: "tool" | "control" };
 
-  type DragPreviewKind = "agent" | "tool";
+  type DragPreviewKind = "agent" | "tool" | "control";
 
   interface DragPreviewData {
     kind: DragPreviewKind;
@@ -522,7 +1235,7 @@ function CanvasPageContent() {
   }
 
   const resolveToolIcon = useCallback((toolType: string) => {
-    return TOOL_ITEMS.find((tool) => tool.type === toolType)?.icon ?? "🔧";
+    return [...TOOL_ITEMS, ...LOGIC_ITEMS].find((tool) => tool.type === toolType)?.icon ?? "🔧";
   }, []);
 
   const beginAgentDrag = useCallback(
@@ -579,8 +1292,9 @@ function CanvasPageContent() {
       event.stopPropagation();
       // NOTE: Do NOT call preventDefault() - it cancels HTML5 drag on desktop
 
-      event.dataTransfer.setData("tool-type", tool.type);
-      event.dataTransfer.setData("tool-name", tool.name);
+      const kind = tool.kind ?? "tool";
+      event.dataTransfer.setData(`${kind}-type`, tool.type);
+      event.dataTransfer.setData(`${kind}-name`, tool.name);
       event.dataTransfer.effectAllowed = "move";
       if (event.dataTransfer.setDragImage) {
         event.dataTransfer.setDragImage(transparentDragImage, 0, 0);
@@ -595,7 +1309,7 @@ function CanvasPageContent() {
         const pointerRatioX = rect.width ? clamp(pointerOffsetX / rect.width, 0, 1) : 0;
         const pointerRatioY = rect.height ? clamp(pointerOffsetY / rect.height, 0, 1) : 0;
         const preview: DragPreviewData = {
-          kind: "tool",
+          kind,
           label: tool.name,
           icon: resolveToolIcon(tool.type),
           baseSize: { width: rect.width || 160, height: rect.height || 48 },
@@ -606,7 +1320,7 @@ function CanvasPageContent() {
         updatePreviewPositionFromClientPoint({ x: clientX, y: clientY }, preview);
       } else {
         const preview: DragPreviewData = {
-          kind: "tool",
+          kind,
           label: tool.name,
           icon: resolveToolIcon(tool.type),
           baseSize: { width: 160, height: 48 },
@@ -718,6 +1432,11 @@ function CanvasPageContent() {
     refetchInterval: 2000, // Poll every 2 seconds
   });
 
+  const pausedAgentIds = React.useMemo(
+    () => new Set(agents.filter(isAgentPaused).map((agent) => agent.id)),
+    [agents]
+  );
+
   const filteredAgents = React.useMemo(() => {
     const normalized = searchTerm.trim().toLowerCase();
     if (!normalized) {
@@ -726,6 +1445,22 @@ function CanvasPageContent() {
     return agents.filter((agent) => agent.name.toLowerCase().includes(normalized));
   }, [agents, searchTerm]);
 
+  const shelfTags = React.useMemo(() => collectTags(agents), [agents]);
+  // Falls back to every tag once no agent carries the chosen one
+  const activeShelfTag = hasTag(shelfTags, shelfTag) ? shelfTag : "";
+
+  const shelfGroups = React.useMemo(
+    () =>
+      groupShelfAgents(agents, {
+        search: searchTerm,
+        tags: activeShelfTag ? [activeShelfTag] : [],
+        grouping: shelfPrefs.grouping,
+        favorites: shelfPrefs.favorites,
+        currentUserId: user?.id ?? null,
+      }),
+    [agents, searchTerm, activeShelfTag, shelfPrefs.grouping, shelfPrefs.favorites, user?.id]
+  );
+
   const filteredTools = React.useMemo(() => {
     const normalized = searchTerm.trim().toLowerCase();
     if (!normalized) {
@@ -734,30 +1469,223 @@ function CanvasPageContent() {
     return TOOL_ITEMS.filter((tool) => tool.name.toLowerCase().includes(normalized));
   }, [searchTerm]);
 
+  const filteredControls = React.useMemo(() => {
+    const normalized = searchTerm.trim().toLowerCase();
+    return LOGIC_ITEMS.filter((item) => item.name.toLowerCase().includes(normalized));
+  }, [searchTerm]);
+
   // Fetch current workflow
   const { data: workflow } = useQuery<Workflow>({
-    queryKey: ["workflow", "current"],
-    queryFn: fetchCurrentWorkflow,
+    queryKey: ["workflow", "current", workflowId ?? null],
+    queryFn: () => fetchCurrentWorkflow(workflowId),
     staleTime: 30000, // Consider data fresh for 30 seconds
   });
 
+  // Other workflows, as Call Workflow targets
+  const { data: allWorkflows = [] } = useQuery<Workflow[]>({
+    queryKey: ["workflows"],
+    queryFn: () => fetchWorkflows(),
+    staleTime: 60000,
+  });
+
+  // Delete moves the workflow to the trash and opens another one; the toast
+  // offers an undo until it expires, the notification center after
+  const deleteWorkflowMutation = useMutation({
+    mutationFn: (target: Workflow) => deleteWorkflow(target.id),
+    onSuccess: (_data, target) => {
+      invalidateTrashLists(queryClient);
+      queryClient.removeQueries({ queryKey: ["workflow", "current"] });
+      notify({
+        kind: "info",
+        source: "trash",
+        message: `Moved ${target.name} to trash`,
+        undo: { kind: "restore-workflow", id: target.id },
+      });
+      toast(
+        (t) => (
+          <span className="undo-toast">
+            Moved {target.name} to trash
+            <button
+              type="button"
+              className="undo-toast-button"
+              onClick={() => {
+                toast.dismiss(t.id);
+                restoreWorkflowMutation.mutate(target);
+              }}
+            >
+              Undo
+            </button>
+          </span>
+        ),
+        { duration: 8000 }
+      );
+      const next = allWorkflows.find((candidate) => candidate.id !== target.id);
+      navigate(next ? `/canvas?workflow=${next.id}` : "/dashboard");
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to delete workflow: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const restoreWorkflowMutation = useMutation({
+    mutationFn: (target: Workflow) => restoreWorkflow(target.id),
+    onSuccess: (restored) => {
+      invalidateTrashLists(queryClient);
+      navigate(`/canvas?workflow=${restored.id}`);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to restore workflow: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleDeleteWorkflow = () => {
+    if (!workflow) return;
+    const confirmed =
+      typeof window === "undefined" ||
+      window.confirm(
+        `Move ${workflow.name} to the trash? It can be restored for ${WORKFLOW_TRASH_RETENTION_DAYS} days.`
+      );
+    if (confirmed) deleteWorkflowMutation.mutate(workflow);
+  };
+  const { data: envVars = [] } = useQuery({
+    queryKey: ["env-vars"],
+    queryFn: fetchEnvVars,
+    staleTime: 60000,
+  });
+  const { data: apiCredentials = [] } = useQuery({
+    queryKey: ["api-credentials"],
+    queryFn: fetchApiCredentials,
+    staleTime: 60000,
+  });
+  const callableWorkflows = React.useMemo(
+    () => allWorkflows.filter((candidate) => candidate.id !== workflow?.id),
+    [allWorkflows, workflow?.id]
+  );
+  const workflowNames = React.useMemo(
+    () => new Map(allWorkflows.map((candidate) => [candidate.id, candidate.name])),
+    [allWorkflows]
+  );
+
+  const { data: nodeSummaries, isFetching: isFetchingHeat } = useQuery({
+    queryKey: ["workflow-node-summaries", workflow?.id, HEAT_EXECUTIONS_LIMIT],
+    queryFn: () => getExecutionNodeSummaries(workflow!.id, HEAT_EXECUTIONS_LIMIT),
+    enabled: heatOverlayEnabled && workflow?.id != null,
+    staleTime: 30000,
+  });
+
+  const nodeHeat = React.useMemo(() => {
+    if (!heatOverlayEnabled || !nodeSummaries) return null;
+    return computeNodeHeat(aggregateNodeStats(nodeSummaries), heatMetric);
+  }, [heatOverlayEnabled, nodeSummaries, heatMetric]);
+
+  // Latest finished run's node outputs: marked on the canvas and reused by "Run from this node"
+  const { data: nodeOutputCache } = useQuery({
+    queryKey: nodeOutputCacheQueryKey(workflow?.id ?? 0),
+    queryFn: () => getNodeOutputCache(workflow!.id),
+    enabled: workflow?.id != null,
+  });
+  const cachedNodeIds = React.useMemo(() => new Set(nodeOutputCache?.node_ids ?? []), [nodeOutputCache]);
+
+  // Heat and run-control markers are layered onto a derived node list so they never reach the saved canvas
+  const pausedNodeId = stepState.paused?.nodeId ?? null;
+  const displayNodes = React.useMemo(() => {
+    const withCallees = nodes.map((node) => {
+      if (node.type !== SUBWORKFLOW_ITEM.type) return node;
+      const callee = subworkflowTarget(node.data.config);
+      return {
+        ...node,
+        data: {
+          ...node.data,
+          calleeName: callee != null ? workflowNames.get(callee) : undefined,
+          started: node.id in startedNodeIds,
+        },
+      };
+    });
+    const withMarkers = withCallees.map((node) => {
+      const breakpoint = hasBreakpoint(node.data.config);
+      const paused = node.id === pausedNodeId;
+      const agentPaused = node.data.agentId != null && pausedAgentIds.has(node.data.agentId);
+      const waiting = node.id in pendingWaits;
+      const cached = cachedNodeIds.has(node.id);
+      if (!breakpoint && !paused && !agentPaused && !waiting && !cached) return node;
+      return {
+        ...node,
+        className: clsx(
+          node.className,
+          breakpoint && "node-breakpoint",
+          paused && "node-paused",
+          waiting && "node-waiting",
+          cached && "node-cached"
+        ),
+        data: agentPaused || waiting ? { ...node.data, paused: agentPaused, waiting } : node.data,
+      };
+    });
+    if (!nodeHeat) return withMarkers;
+    return withMarkers.map((node) => {
+      const heat = nodeHeat.heat.get(node.id);
+      const color = heat ? heatColor(heat.intensity) : undefined;
+      return {
+        ...node,
+        className: clsx(node.className, "heat-node", !heat && "heat-node--no-data"),
+        style: color ? { ...node.style, ["--heat-color" as string]: color } : node.style,
+        data: { ...node.data, heat: heat && color ? { color, label: heat.label } : undefined },
+      };
+    });
+  }, [nodes, nodeHeat, pausedNodeId, pausedAgentIds, pendingWaits, workflowNames, startedNodeIds, cachedNodeIds]);
+
+  // Canvas edits are saved through the persistence queue: bursts coalesce, unchanged canvases are
+  // skipped, and whatever is pending is flushed on unmount or (with keepalive) when the tab closes
+  const saveCanvasRef = useRef<(canvas: WorkflowDataInput) => Promise<unknown>>(() => Promise.resolve());
+  React.useEffect(
+    () =>
+      persistence.register<WorkflowDataInput>(WORKFLOW_SLICE, (canvas, { keepalive }) =>
+        keepalive ? updateWorkflowCanvas(canvas, { keepalive: true, workflowId }) : saveCanvasRef.current(canvas)
+      ),
+    [workflowId]
+  );
+
   // Initialize nodes and edges from workflow data ONLY on first load
   // This prevents flickering when server state updates after user drags nodes
   React.useEffect(() => {
     if (workflow?.canvas && !canvasInitializedRef.current) {
       const { nodes: flowNodes, edges: flowEdges } = convertToReactFlowData(workflow.canvas);
-      setNodes(flowNodes);
-      setEdges(flowEdges);
+      const normalized = normalizeWorkflow(flowNodes, flowEdges);
+      // After a crash, a soft reload hands back the canvas as it was; autosave then persists it
+      const recovered = takeRecoveredState<WorkflowDataInput>(CANVAS_RECOVERY_KEY);
+      const restore = recovered !== null && JSON.stringify(recovered) !== JSON.stringify(normalized);
+      const initial = restore
+        ? convertToReactFlowData(recovered as unknown as WorkflowData)
+        : { nodes: flowNodes, edges: flowEdges };
+      setNodes(initial.nodes);
+      setEdges(initial.edges);
       canvasInitializedRef.current = true;
+      if (restore) {
+        toast.success("Restored unsaved canvas changes");
+      }
 
       // Initialize hash from loaded workflow
-      const normalized = normalizeWorkflow(flowNodes, flowEdges);
+      persistence.markPersisted(WORKFLOW_SLICE, normalized);
       hashWorkflow(normalized).then((hash) => {
         lastSavedHashRef.current = hash;
       });
     }
   }, [workflow, setNodes, setEdges]);
 
+  // Templates replace the canvas locally; autosave then persists them
+  const handleUseTemplate = useCallback(
+    (template: WorkflowTemplate) => {
+      if (nodes.length > 0 && !window.confirm(`Replace the current canvas with "${template.name}"?`)) {
+        return;
+      }
+      const { nodes: flowNodes, edges: flowEdges } = convertToReactFlowData(template.canvas);
+      setNodes(flowNodes);
+      setEdges(flowEdges);
+      setShowTemplateGallery(false);
+      toast.success(`Loaded template "${template.name}"`);
+    },
+    [nodes.length, setNodes, setEdges]
+  );
+
   useEffect(() => {
     if (!canvasInitializedRef.current || initialFitDoneRef.current) {
       return;
@@ -880,7 +1808,7 @@ function CanvasPageContent() {
       }
 
       pendingHashesRef.current.add(hash);
-      const result = await updateWorkflowCanvas(data);
+      const result = await updateWorkflowCanvas(data, { workflowId });
       return result;
     },
     onSuccess: (result, _variables, context) => {
@@ -888,6 +1816,7 @@ function CanvasPageContent() {
 
       lastSavedHashRef.current = context.hash;
       pendingHashesRef.current.delete(context.hash);
+      setRecoverableState(CANVAS_RECOVERY_KEY, null);
 
       // Reuse single toast ID to avoid stacking
       if (toastIdRef.current) {
@@ -896,7 +1825,7 @@ function CanvasPageContent() {
         toastIdRef.current = toast.success("Workflow saved");
       }
 
-      queryClient.setQueryData(["workflow", "current"], result);
+      queryClient.setQueryData(["workflow", "current", workflowId ?? null], result);
     },
     onError: (error: Error, _variables, context) => {
       if (context?.hash) {
@@ -907,45 +1836,117 @@ function CanvasPageContent() {
     },
   });
 
+  React.useEffect(() => {
+    saveCanvasRef.current = saveWorkflowMutation.mutateAsync;
+  }, [saveWorkflowMutation.mutateAsync]);
+
   // Auto-save workflow when nodes or edges change (skip during drag)
-  const debouncedSave = React.useMemo(() => {
-    return debounce((nodes: FlowNode[], edges: Edge[]) => {
-      if (nodes.length > 0 || edges.length > 0) {
-        const workflowData = normalizeWorkflow(nodes, edges);
-        saveWorkflowMutation.mutate(workflowData);
-      }
-    }, 1000);
-  }, [saveWorkflowMutation]);
+  React.useEffect(() => {
+    if (!isDragging && (nodes.length > 0 || edges.length > 0)) {
+      persistence.schedule(WORKFLOW_SLICE, normalizeWorkflow(nodes, edges));
+    }
+  }, [nodes, edges, isDragging]);
 
+  // Keep the latest canvas for a soft reload after a crash; a successful save clears it
   React.useEffect(() => {
-    if (!isDragging) {
-      debouncedSave(nodes, edges);
+    if (canvasInitializedRef.current) {
+      setRecoverableState(CANVAS_RECOVERY_KEY, normalizeWorkflow(nodes, edges));
     }
-  }, [nodes, edges, isDragging, debouncedSave]);
+  }, [nodes, edges]);
 
   // Workflow execution mutations
   const executeWorkflowMutation = useMutation({
-    mutationFn: async () => {
+    mutationFn: async (options: RunOptions) => {
       if (!workflow?.id) {
         throw new Error("No workflow loaded");
       }
+      // The server checks run inputs against its stored canvas, so save pending edits first
+      if (persistence.hasPending(WORKFLOW_SLICE)) {
+        const canvas = normalizeWorkflow(nodes, edges);
+        await saveCanvasRef.current(canvas);
+        persistence.markPersisted(WORKFLOW_SLICE, canvas);
+      }
       console.log('[CanvasPage] 🚀 Starting workflow execution, workflow_id:', workflow.id);
       // Clear previous logs before starting
       setExecutionLogs([]);
-      return startWorkflowExecution(workflow.id);
+      dispatchStep({ type: "reset" });
+      setPendingWaits({});
+      setStartedNodeIds({});
+      if (options.fromNode) {
+        const { executionId, nodeId } = options.fromNode;
+        return runFromNode(executionId, nodeId, { stepMode: options.stepMode });
+      }
+      return startWorkflowExecution(workflow.id, options);
     },
-    onSuccess: (execution) => {
+    onSuccess: (execution, options) => {
       console.log('[CanvasPage] 🎯 Workflow started, execution_id:', execution.execution_id);
       setCurrentExecution(execution);
-      toast.success("Workflow execution started! Watch the logs panel for real-time updates.");
+      if (execution.step_mode) {
+        toast.success("Step mode: the run pauses before each node. Use Step or Continue in the logs panel.");
+      } else if (options.fromNode) {
+        const label = nodeLabels[options.fromNode.nodeId] ?? options.fromNode.nodeId;
+        toast.success(`Running from ${label}; the nodes before it reuse their cached outputs.`);
+      } else {
+        toast.success("Workflow execution started! Watch the logs panel for real-time updates.");
+      }
       // Auto-open logs panel to show real-time stream
       setShowLogs(true);
+      setRunInputsPrompt(null);
+      if (workflow?.id) queryClient.invalidateQueries({ queryKey: executionHistoryQueryKey(workflow.id) });
     },
-    onError: (error: Error) => {
+    onError: (error: Error, options) => {
+      // Inputs the server rejected are shown on their fields, in the form if it's open and
+      // otherwise (a re-run whose inputs no longer fit) in a form prefilled with them
+      const inputErrors =
+        error instanceof ApiError && error.kind === "validation" ? runInputFieldErrors(error.fieldErrors) : null;
+      if (inputErrors) {
+        setRunInputsPrompt(
+          runInputsPrompt
+            ? { ...runInputsPrompt, serverErrors: inputErrors }
+            : {
+                specs: runInputSpecs(),
+                stepMode: !!options.stepMode,
+                initialInputs: options.inputs,
+                serverErrors: inputErrors,
+              }
+        );
+        return;
+      }
       toast.error(`Failed to start workflow: ${error.message || "Unknown error"}`);
     },
   });
 
+  const runInputSpecs = () => declaredRunInputs(nodes.map((node) => ({ type: node.type, config: node.data.config })));
+  // Pausing an agent only suspends its schedules and triggers, so a manual run asks first
+  const confirmCanvasRun = () =>
+    confirmRunWhilePaused(agents.filter((agent) => nodes.some((node) => node.data.agentId === agent.id)));
+
+  // A manual trigger that declares run inputs asks for those through a form
+  const runWorkflow = (options: RunOptions) => {
+    if (!confirmCanvasRun()) return;
+    const specs = runInputSpecs();
+    if (specs.length > 0) setRunInputsPrompt({ specs, stepMode: !!options.stepMode });
+    else executeWorkflowMutation.mutate(options);
+  };
+
+  // Runs from before inputs were kept (or declared) go through the form instead
+  const rerunExecution = (execution: WorkflowExecution) => {
+    if (!execution.inputs && runInputSpecs().length > 0) {
+      runWorkflow({});
+    } else if (confirmCanvasRun()) {
+      executeWorkflowMutation.mutate({ inputs: execution.inputs ?? undefined });
+    }
+  };
+
+  // Re-runs the node and everything after it; the rest reuse the latest run's outputs
+  const runFromContextNode = () => {
+    if (!contextMenu || nodeOutputCache?.execution_id == null) return;
+    const { nodeId } = contextMenu;
+    setContextMenu(null);
+    if (!confirmCanvasRun()) return;
+    executeWorkflowMutation.mutate({ fromNode: { executionId: nodeOutputCache.execution_id, nodeId } });
+  };
+
   const cancelExecutionMutation = useMutation({
     mutationFn: async () => {
       if (!currentExecution?.execution_id) {
@@ -956,12 +1957,53 @@ function CanvasPageContent() {
     onSuccess: () => {
       toast.success("Workflow execution cancelled");
       setCurrentExecution(null);
+      setPendingWaits({});
     },
     onError: (error: Error) => {
       toast.error(`Failed to cancel execution: ${error.message || "Unknown error"}`);
     },
   });
 
+  const skipWaitMutation = useMutation({
+    mutationFn: async (nodeId: string) => {
+      if (!currentExecution?.execution_id) {
+        throw new Error("No execution to resume");
+      }
+      return skipWait(currentExecution.execution_id, nodeId);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to skip wait: ${error.message || "Unknown error"}`);
+    },
+  });
+
+  const resumeExecutionMutation = useMutation({
+    mutationFn: async ({ mode, config }: { mode: "step" | "continue"; config?: Record<string, unknown> }) => {
+      if (!currentExecution?.execution_id) {
+        throw new Error("No execution to resume");
+      }
+      const executionId = currentExecution.execution_id;
+      return mode === "step" ? stepExecution(executionId, config) : continueExecution(executionId, config);
+    },
+    onMutate: () => {
+      const nodeId = stepState.paused?.nodeId;
+      dispatchStep({ type: "resume_requested" });
+      return { nodeId };
+    },
+    onSuccess: (status, _variables, context) => {
+      if (context?.nodeId) dispatchStep({ type: "resumed", nodeId: context.nodeId });
+      setCurrentExecution((prev) => (prev ? { ...prev, step_mode: status.step_mode } : prev));
+    },
+    onError: (error: Error) => {
+      dispatchStep({ type: "resume_failed" });
+      toast.error(`Failed to resume execution: ${error.message || "Unknown error"}`);
+    },
+  });
+
+  const nodeLabels = React.useMemo(
+    () => Object.fromEntries(nodes.map((node) => [node.id, String((node.data as FlowNodeData).label ?? node.id)])),
+    [nodes]
+  );
+
   const isSaving = saveWorkflowMutation.isPending;
 
   // WebSocket for real-time execution updates
@@ -978,6 +2020,8 @@ function CanvasPageContent() {
     switch (message_type) {
       case 'execution_started': {
         console.log('[CanvasPage] ✅ Execution started:', data.execution_id);
+        setPendingWaits({});
+        setStartedNodeIds({});
         setExecutionLogs([{
           timestamp: Date.now(),
           type: 'execution',
@@ -996,10 +2040,21 @@ function CanvasPageContent() {
 
         console.log('[CanvasPage] 📍 Node:', node_id, '→', phase, result || '');
 
+        setStartedNodeIds(prev => (node_id in prev ? prev : { ...prev, [node_id]: true }));
+
+        if (phase === 'finished') {
+          setPendingWaits(prev => {
+            if (!(node_id in prev)) return prev;
+            const { [node_id]: _finished, ...rest } = prev;
+            return rest;
+          });
+        }
+
         setExecutionLogs(prev => [...prev, {
           timestamp: Date.now(),
           type: logType,
           message: logMessage,
+          nodeId: node_id,
           metadata: data
         }]);
 
@@ -1014,6 +2069,48 @@ function CanvasPageContent() {
         break;
       }
 
+      case 'node_log': {
+        const { node_id, stream, text } = data;
+        setExecutionLogs(prev => [...prev, {
+          timestamp: Date.now(),
+          type: 'output',
+          level: stream === 'stderr' ? 'stderr' : 'stdout',
+          message: text,
+          nodeId: node_id,
+          metadata: data
+        }]);
+        break;
+      }
+
+      case 'execution_paused': {
+        dispatchStep({ type: 'paused', data });
+        // Breakpoints can stop a normal run, so make sure the controls are on screen
+        setShowLogs(true);
+        setExecutionLogs(prev => [...prev, {
+          timestamp: Date.now(),
+          type: 'execution',
+          message: data.reason === 'breakpoint'
+            ? `BREAKPOINT at NODE ${data.node_id}`
+            : `PAUSED before NODE ${data.node_id}`,
+          nodeId: data.node_id,
+          metadata: data
+        }]);
+        break;
+      }
+
+      case 'node_waiting': {
+        const wait = pendingWaitFromMessage(data);
+        setPendingWaits(prev => ({ ...prev, [wait.nodeId]: wait }));
+        setExecutionLogs(prev => [...prev, {
+          timestamp: Date.now(),
+          type: 'node',
+          message: `NODE ${data.node_id} WAITING${wait.event ? ` for event "${wait.event}"` : ''}`,
+          nodeId: data.node_id,
+          metadata: data
+        }]);
+        break;
+      }
+
       case 'workflow_progress': {
         const { completed_nodes } = data;
         // console.log('[CanvasPage] Workflow progress:', { completed: completed_nodes.length });
@@ -1031,6 +2128,8 @@ function CanvasPageContent() {
         }]);
 
         console.log('[CanvasPage] 🏁 Execution finished:', result);
+        dispatchStep({ type: 'finished' });
+        setPendingWaits({});
 
         // Refresh execution status via REST (to sync DB state)
         if (currentExecutionRef.current?.execution_id) {
@@ -1040,6 +2139,10 @@ function CanvasPageContent() {
             console.error('[CanvasPage] Failed to fetch final execution status:', err);
           });
         }
+
+        // New outcome changes the heat overlay aggregates and the history sidebar
+        queryClient.invalidateQueries({ queryKey: ["workflow-node-summaries"] });
+        queryClient.invalidateQueries({ queryKey: ["execution-history"] });
         break;
       }
 
@@ -1047,13 +2150,131 @@ function CanvasPageContent() {
         // console.log('[CanvasPage] Unknown message type:', message_type);
         break;
     }
+  }, [queryClient]);
+
+  // Presence frames are handled by useWorkflowPresence, which needs sendMessage from the socket below
+  const presenceFrameRef = useRef<(frame: { type: string; data?: unknown }) => void>(() => {});
+  const handleSocketMessage = useCallback((frame: { type: string; data?: unknown }) => {
+    presenceFrameRef.current(frame);
   }, []);
 
-  const { sendMessage } = useWebSocket(currentExecution?.execution_id != null, {
-    includeAuth: true,
-    invalidateQueries: [],
-    onStreamingMessage: handleStreamingMessage,
+  const { sendMessage, connectionStatus } = useWebSocket(
+    workflow?.id != null || currentExecution?.execution_id != null,
+    {
+      includeAuth: true,
+      invalidateQueries: [],
+      onStreamingMessage: handleStreamingMessage,
+      onMessage: handleSocketMessage,
+    }
+  );
+
+  // Other editors on this workflow: avatars, cursors, selection outlines and edit-conflict warnings
+  const inspectedNodeIdRef = useRef<string | null>(null);
+  const handleRemoteEdit = useCallback((editor: PresenceEditorData) => {
+    if (editor.edited_node_id !== inspectedNodeIdRef.current) return;
+    toast(`${presenceName(editor)} just changed this node. Applying your edits will overwrite theirs.`, {
+      icon: "⚠️",
+      id: `presence-remote-edit-${editor.edited_node_id}`,
+    });
+  }, []);
+  const {
+    presence,
+    handleMessage: handlePresenceFrame,
+    reportCursor,
+    reportSelection,
+    reportEditing,
+    reportEdit,
+  } = useWorkflowPresence(workflow?.id, sendMessage, {
+    connected: connectionStatus === ConnectionStatus.CONNECTED,
+    onRemoteEdit: handleRemoteEdit,
   });
+  useEffect(() => {
+    presenceFrameRef.current = handlePresenceFrame;
+  }, [handlePresenceFrame]);
+
+  const selectionKey = selectedNodes.map((node) => node.id).join(",");
+  useEffect(() => {
+    reportSelection(selectionKey ? selectionKey.split(",") : []);
+  }, [selectionKey, reportSelection]);
+  useEffect(() => {
+    inspectedNodeIdRef.current = inspectedNode?.id ?? null;
+    reportEditing(inspectedNode?.id ?? null);
+  }, [inspectedNode?.id, reportEditing]);
+
+  const handlePresencePointerMove = useCallback(
+    (event: React.PointerEvent) => {
+      if (presence.editors.size === 0) return;
+      reportCursor(reactFlowInstance.screenToFlowPosition({ x: event.clientX, y: event.clientY }));
+    },
+    [presence.editors.size, reactFlowInstance, reportCursor]
+  );
+  const handlePresencePointerLeave = useCallback(() => reportCursor(null), [reportCursor]);
+
+  // Saves stay last-write-wins; warn when someone else has the node open or just changed it
+  // Call Workflow targets are checked against the saved call graph as soon as they're applied
+  const checkCallNode = useCallback(
+    (nodeId: string, config: NodeConfig) => {
+      const canvas: WorkflowDataInput = {
+        nodes: [{ id: nodeId, type: "subworkflow", position: { x: 0, y: 0 }, config }] as unknown as WorkflowNode[],
+        edges: [],
+      };
+      validateWorkflowCanvas(canvas, workflow?.id)
+        .then((validation) => {
+          const problem = validation.errors.find((error) => error.node_id === nodeId);
+          if (problem) toast.error(String(problem.message), { id: `call-check-${nodeId}` });
+        })
+        // The check is advisory; the engine refuses bad calls at run time
+        .catch(() => undefined);
+    },
+    [workflow?.id]
+  );
+
+  const handleInspectorApply = useCallback(
+    (nodeId: string, update: { label: string; config: NodeConfig }) => {
+      const others = conflictingEditors(presence.editors.values(), nodeId);
+      if (others.length > 0) {
+        const names = [...new Set(others.map(presenceName))].join(", ");
+        toast(`${names} is also editing this node. The last save wins.`, {
+          icon: "⚠️",
+          id: `presence-conflict-${nodeId}`,
+        });
+      }
+      handleApplyNodeConfig(nodeId, update);
+      reportEdit(nodeId);
+      if (inspectedNode?.id === nodeId && inspectedNode.type === SUBWORKFLOW_ITEM.type) {
+        checkCallNode(nodeId, update.config);
+      }
+    },
+    [handleApplyNodeConfig, presence.editors, reportEdit, inspectedNode, checkCallNode]
+  );
+
+  const { data: workflowComments } = useQuery({
+    queryKey: workflowCommentsKey(workflow?.id ?? 0),
+    queryFn: () => fetchWorkflowComments(workflow!.id),
+    enabled: workflow?.id != null,
+    staleTime: 30000,
+  });
+  const commentThreads = React.useMemo(() => groupCommentThreads(workflowComments ?? []), [workflowComments]);
+  const commentSummaries = React.useMemo(() => summarizeNodeComments(commentThreads), [commentThreads]);
+  const commentNode = commentNodeId ? nodes.find((node) => node.id === commentNodeId) ?? null : null;
+
+  const remoteSelectionByNode = React.useMemo(() => remoteSelections(presence.editors.values()), [presence.editors]);
+  const flowNodes = React.useMemo(() => {
+    if (remoteSelectionByNode.size === 0 && commentSummaries.size === 0) return displayNodes;
+    return displayNodes.map((node) => {
+      const selectors = remoteSelectionByNode.get(node.id);
+      const comments = commentSummaries.get(node.id);
+      if (!selectors && !comments) return node;
+      return {
+        ...node,
+        className: selectors ? clsx(node.className, "node-remote-selected") : node.className,
+        style: selectors
+          ? { ...node.style, ["--presence-color" as string]: presenceColor(selectors[0].user_id) }
+          : node.style,
+        data: comments ? { ...node.data, comments } : node.data,
+      };
+    });
+  }, [commentSummaries, displayNodes, remoteSelectionByNode]);
 
   // Subscribe to workflow execution topic when execution starts
   useEffect(() => {
@@ -1083,9 +2304,12 @@ function CanvasPageContent() {
   // Handle connection creation
   const onConnect: OnConnect = useCallback(
     (connection: Connection) => {
-      setEdges((eds: Edge[]) => addEdge(connection, eds));
+      const source = reactFlowInstance.getNode(connection.source);
+      const sourceConfig = (source?.data as FlowNodeData | undefined)?.config;
+      const edge = flowEdge(connection.source, connection.target, source?.type, sourceConfig, connection.sourceHandle);
+      setEdges((eds: Edge[]) => addEdge({ ...connection, ...edge }, eds));
     },
-    [setEdges]
+    [reactFlowInstance, setEdges]
   );
 
   // Drag lifecycle handlers
@@ -1097,10 +2321,10 @@ function CanvasPageContent() {
     setIsDragging(false);
     // Trigger immediate save after drag completes
     if (nodes.length > 0 || edges.length > 0) {
-      const workflowData = normalizeWorkflow(nodes, edges);
-      saveWorkflowMutation.mutate(workflowData);
+      persistence.schedule(WORKFLOW_SLICE, normalizeWorkflow(nodes, edges));
+      persistence.flush(WORKFLOW_SLICE);
     }
-  }, [nodes, edges, saveWorkflowMutation]);
+  }, [nodes, edges]);
 
   // E2E Test Compatibility: Add legacy CSS classes to React Flow nodes
   useEffect(() => {
@@ -1136,6 +2360,8 @@ function CanvasPageContent() {
       const agentName = event.dataTransfer.getData("agent-name");
       const toolType = event.dataTransfer.getData("tool-type");
       const toolName = event.dataTransfer.getData("tool-name");
+      const controlType = event.dataTransfer.getData("control-type");
+      const controlName = event.dataTransfer.getData("control-name");
 
       let payload: DropPayload | null = null;
 
@@ -1143,6 +2369,8 @@ function CanvasPageContent() {
         payload = toDropPayload({ type: "agent", id: agentId, name: agentName });
       } else if (toolType && toolName) {
         payload = toDropPayload({ type: "tool", name: toolName, tool_type: toolType });
+      } else if (controlType && controlName) {
+        payload = toDropPayload({ type: "control", name: controlName, control_type: controlType });
       }
 
       if (!payload) {
@@ -1157,6 +2385,136 @@ function CanvasPageContent() {
     [dragPreviewData, finalizeDrop, resetDragPreview, setIsDragActive, toDropPayload, updatePreviewPositionFromClientPoint]
   );
 
+  // Shelf entry for an agent; the star pins it to the Favorites group
+  const renderShelfAgent = (agent: AgentSummary) => (
+    <div
+      key={agent.id}
+      className="agent-shelf-item agent-pill"
+      data-testid={`shelf-agent-${agent.id}`}
+      draggable={true}
+      role="button"
+      tabIndex={0}
+      aria-grabbed="false"
+      aria-label={`Drag agent ${agent.name} onto the canvas`}
+      onDragStart={(event) => beginAgentDrag(event, { id: agent.id, name: agent.name })}
+      onDragEnd={(event) => {
+        if (event.currentTarget instanceof HTMLElement) {
+          event.currentTarget.setAttribute('aria-grabbed', 'false');
+        }
+      }}
+      onPointerDown={(event) => {
+        // Only use Pointer API for touch/pen; let HTML5 drag handle mouse
+        if (event.isPrimary && event.pointerType !== 'mouse') {
+          // Start pointer drag tracking
+          startDrag(event as unknown as React.PointerEvent, {
+            type: 'agent',
+            id: agent.id.toString(),
+            name: agent.name
+          });
+
+          // Set drag preview data for visual feedback
+          const rect = event.currentTarget.getBoundingClientRect();
+          const pointerOffsetX = event.clientX - rect.left;
+          const pointerOffsetY = event.clientY - rect.top;
+          const preview: DragPreviewData = {
+            kind: 'agent',
+            label: agent.name,
+            icon: '🤖',
+            baseSize: { width: rect.width || 160, height: rect.height || 48 },
+            pointerRatio: {
+              x: rect.width ? pointerOffsetX / rect.width : 0,
+              y: rect.height ? pointerOffsetY / rect.height : 0
+            },
+            agentId: agent.id,
+          };
+          setDragPreviewData(preview);
+          updatePreviewPositionFromClientPoint({ x: event.clientX, y: event.clientY }, preview);
+          setIsDragActive(true);
+
+          event.currentTarget.setAttribute('aria-grabbed', 'true');
+        }
+      }}
+    >
+      {/* Icon added via CSS ::before pseudo-element */}
+      <div className="agent-name">{agent.name}</div>
+      {isAgentPaused(agent) && (
+        <span className="paused-badge" title={PAUSED_BADGE_TITLE}>
+          Paused
+        </span>
+      )}
+      <button
+        type="button"
+        className={clsx("shelf-favorite-btn", { active: shelfPrefs.favorites.includes(agent.id) })}
+        draggable={false}
+        onPointerDown={(event) => event.stopPropagation()}
+        onClick={(event) => {
+          event.stopPropagation();
+          shelfPrefs.toggleFavorite(agent.id);
+        }}
+        aria-pressed={shelfPrefs.favorites.includes(agent.id)}
+        aria-label={`${shelfPrefs.favorites.includes(agent.id) ? "Unfavorite" : "Favorite"} ${agent.name}`}
+        data-testid={`shelf-favorite-${agent.id}`}
+      >
+        {shelfPrefs.favorites.includes(agent.id) ? "★" : "☆"}
+      </button>
+    </div>
+  );
+
+  // Shelf entry for a tool or a logic node; logic nodes drop as control-flow nodes
+  const renderPaletteItem = (tool: ToolPaletteItem, kind: "tool" | "control") => (
+    <div
+      key={tool.type}
+      className="tool-palette-item"
+      data-testid={`${kind}-${tool.type}`}
+      draggable={true}
+      role="button"
+      tabIndex={0}
+      aria-grabbed="false"
+      aria-label={`Drag ${kind === "control" ? "logic node" : "tool"} ${tool.name} onto the canvas`}
+      onDragStart={(event) => beginToolDrag(event, { ...tool, kind })}
+      onDragEnd={(event) => {
+        if (event.currentTarget instanceof HTMLElement) {
+          event.currentTarget.setAttribute('aria-grabbed', 'false');
+        }
+      }}
+      onPointerDown={(event) => {
+        // Only use Pointer API for touch/pen; let HTML5 drag handle mouse
+        if (event.isPrimary && event.pointerType !== 'mouse') {
+          // Start pointer drag tracking
+          startDrag(event as unknown as React.PointerEvent, {
+            type: kind,
+            name: tool.name,
+            [`${kind}_type`]: tool.type,
+          });
+
+          // Set drag preview data for visual feedback
+          const rect = event.currentTarget.getBoundingClientRect();
+          const pointerOffsetX = event.clientX - rect.left;
+          const pointerOffsetY = event.clientY - rect.top;
+          const preview: DragPreviewData = {
+            kind,
+            label: tool.name,
+            icon: tool.icon,
+            baseSize: { width: rect.width || 160, height: rect.height || 48 },
+            pointerRatio: {
+              x: rect.width ? pointerOffsetX / rect.width : 0,
+              y: rect.height ? pointerOffsetY / rect.height : 0
+            },
+            toolType: tool.type,
+          };
+          setDragPreviewData(preview);
+          updatePreviewPositionFromClientPoint({ x: event.clientX, y: event.clientY }, preview);
+          setIsDragActive(true);
+
+          event.currentTarget.setAttribute('aria-grabbed', 'true');
+        }
+      }}
+    >
+      <div className="tool-icon">{tool.icon}</div>
+      <div className="tool-name">{tool.name}</div>
+    </div>
+  );
+
   const onDragOver = useCallback((event: React.DragEvent) => {
     event.preventDefault();
     event.dataTransfer.dropEffect = 'move';
@@ -1223,66 +2581,67 @@ function CanvasPageContent() {
             <span>Agents</span>
             <span className="count">{filteredAgents.length}</span>
           </button>
+          {!collapsedSections.agents && (
+            <select
+              className="shelf-grouping-select"
+              value={shelfPrefs.grouping}
+              onChange={(event) => shelfPrefs.setGrouping(event.target.value as ShelfGrouping)}
+              aria-label="Group agents"
+            >
+              {SHELF_GROUPINGS.map((grouping) => (
+                <option key={grouping} value={grouping}>
+                  {SHELF_GROUPING_LABELS[grouping]}
+                </option>
+              ))}
+            </select>
+          )}
+          {!collapsedSections.agents && shelfTags.length > 0 && (
+            <select
+              className="shelf-grouping-select"
+              value={activeShelfTag}
+              onChange={(event) => setShelfTag(event.target.value)}
+              aria-label="Filter agents by tag"
+              data-testid="shelf-tag-filter"
+            >
+              <option value="">All tags</option>
+              {shelfTags.map((tag) => (
+                <option key={tag} value={tag}>
+                  {tag}
+                </option>
+              ))}
+            </select>
+          )}
           {!collapsedSections.agents &&
-            (filteredAgents.length > 0 ? (
+            (shelfGroups.length > 0 ? (
               <div id="shelf-agent-list" className="agent-shelf-content">
-                {filteredAgents.map((agent) => (
-                  <div
-                    key={agent.id}
-                    className="agent-shelf-item agent-pill"
-                    data-testid={`shelf-agent-${agent.id}`}
-                    draggable={true}
-                    role="button"
-                    tabIndex={0}
-                    aria-grabbed="false"
-                    aria-label={`Drag agent ${agent.name} onto the canvas`}
-                    onDragStart={(event) => beginAgentDrag(event, { id: agent.id, name: agent.name })}
-                    onDragEnd={(event) => {
-                      if (event.currentTarget instanceof HTMLElement) {
-                        event.currentTarget.setAttribute('aria-grabbed', 'false');
-                      }
-                    }}
-                    onPointerDown={(event) => {
-                      // Only use Pointer API for touch/pen; let HTML5 drag handle mouse
-                      if (event.isPrimary && event.pointerType !== 'mouse') {
-                        // Start pointer drag tracking
-                        startDrag(event as unknown as React.PointerEvent, {
-                          type: 'agent',
-                          id: agent.id.toString(),
-                          name: agent.name
-                        });
-
-                        // Set drag preview data for visual feedback
-                        const rect = event.currentTarget.getBoundingClientRect();
-                        const pointerOffsetX = event.clientX - rect.left;
-                        const pointerOffsetY = event.clientY - rect.top;
-                        const preview: DragPreviewData = {
-                          kind: 'agent',
-                          label: agent.name,
-                          icon: '🤖',
-                          baseSize: { width: rect.width || 160, height: rect.height || 48 },
-                          pointerRatio: {
-                            x: rect.width ? pointerOffsetX / rect.width : 0,
-                            y: rect.height ? pointerOffsetY / rect.height : 0
-                          },
-                          agentId: agent.id,
-                        };
-                        setDragPreviewData(preview);
-                        updatePreviewPositionFromClientPoint({ x: event.clientX, y: event.clientY }, preview);
-                        setIsDragActive(true);
-
-                        event.currentTarget.setAttribute('aria-grabbed', 'true');
-                      }
-                    }}
-                  >
-                    {/* Icon added via CSS ::before pseudo-element */}
-                    <div className="agent-name">{agent.name}</div>
-                  </div>
-                ))}
+                {shelfGroups.length === 1 && shelfGroups[0].key === "all"
+                  ? shelfGroups[0].agents.map(renderShelfAgent)
+                  : shelfGroups.map((group) => {
+                      const collapsed = shelfPrefs.collapsed.includes(group.key);
+                      return (
+                        <div key={group.key} className="shelf-agent-group" data-testid={`shelf-group-${group.key}`}>
+                          <button
+                            type="button"
+                            className="shelf-group-toggle"
+                            onClick={() => shelfPrefs.toggleGroup(group.key)}
+                            aria-expanded={!collapsed}
+                          >
+                            <span className="caret">{collapsed ? "▸" : "▾"}</span>
+                            <span>{group.label}</span>
+                            <span className="count">{group.agents.length}</span>
+                          </button>
+                          {!collapsed && group.agents.map(renderShelfAgent)}
+                        </div>
+                      );
+                    })}
               </div>
             ) : (
               <p className="shelf-empty">
-                {searchTerm ? `No agents found for "${searchTerm}".` : "No agents available."}
+                {searchTerm
+                  ? `No agents found for "${searchTerm}".`
+                  : activeShelfTag
+                    ? `No agents tagged "${activeShelfTag}".`
+                    : "No agents available."}
               </p>
             ))}
         </section>
@@ -1306,59 +2665,7 @@ function CanvasPageContent() {
           {!collapsedSections.tools &&
             (filteredTools.length > 0 ? (
               <div id="shelf-tool-list" className="tool-palette-content">
-                {filteredTools.map((tool) => (
-                  <div
-                    key={tool.type}
-                    className="tool-palette-item"
-                    data-testid={`tool-${tool.type}`}
-                    draggable={true}
-                    role="button"
-                    tabIndex={0}
-                    aria-grabbed="false"
-                    aria-label={`Drag tool ${tool.name} onto the canvas`}
-                    onDragStart={(event) => beginToolDrag(event, tool)}
-                    onDragEnd={(event) => {
-                      if (event.currentTarget instanceof HTMLElement) {
-                        event.currentTarget.setAttribute('aria-grabbed', 'false');
-                      }
-                    }}
-                    onPointerDown={(event) => {
-                      // Only use Pointer API for touch/pen; let HTML5 drag handle mouse
-                      if (event.isPrimary && event.pointerType !== 'mouse') {
-                        // Start pointer drag tracking
-                        startDrag(event as unknown as React.PointerEvent, {
-                          type: 'tool',
-                          name: tool.name,
-                          tool_type: tool.type
-                        });
-
-                        // Set drag preview data for visual feedback
-                        const rect = event.currentTarget.getBoundingClientRect();
-                        const pointerOffsetX = event.clientX - rect.left;
-                        const pointerOffsetY = event.clientY - rect.top;
-                        const preview: DragPreviewData = {
-                          kind: 'tool',
-                          label: tool.name,
-                          icon: tool.icon,
-                          baseSize: { width: rect.width || 160, height: rect.height || 48 },
-                          pointerRatio: {
-                            x: rect.width ? pointerOffsetX / rect.width : 0,
-                            y: rect.height ? pointerOffsetY / rect.height : 0
-                          },
-                          toolType: tool.type,
-                        };
-                        setDragPreviewData(preview);
-                        updatePreviewPositionFromClientPoint({ x: event.clientX, y: event.clientY }, preview);
-                        setIsDragActive(true);
-
-                        event.currentTarget.setAttribute('aria-grabbed', 'true');
-                      }
-                    }}
-                  >
-                    <div className="tool-icon">{tool.icon}</div>
-                    <div className="tool-name">{tool.name}</div>
-                  </div>
-                ))}
+                {filteredTools.map((tool) => renderPaletteItem(tool, "tool"))}
               </div>
             ) : (
               <p className="shelf-empty">
@@ -1366,6 +2673,28 @@ function CanvasPageContent() {
               </p>
             ))}
         </section>
+
+        <section id="logic-palette" data-testid="logic-palette" className="agent-shelf-section">
+          <button
+            type="button"
+            className="shelf-section-toggle"
+            onClick={() => toggleSection("logic")}
+            aria-expanded={!collapsedSections.logic}
+            aria-controls="shelf-logic-list"
+          >
+            <span className="caret">{collapsedSections.logic ? "▸" : "▾"}</span>
+            <span>Logic</span>
+            <span className="count">{filteredControls.length}</span>
+          </button>
+          {!collapsedSections.logic &&
+            (filteredControls.length > 0 ? (
+              <div id="shelf-logic-list" className="tool-palette-content">
+                {filteredControls.map((item) => renderPaletteItem(item, "control"))}
+              </div>
+            ) : (
+              <p className="shelf-empty">{`No logic nodes found for "${searchTerm}".`}</p>
+            ))}
+        </section>
       </div>
 
       <div
@@ -1376,7 +2705,7 @@ function CanvasPageContent() {
         <div className="main-content-area">
           {/* Execution Controls */}
           <div className="execution-controls">
-            <div className="execution-buttons">
+            <div className="execution-buttons" role="toolbar" aria-label="Workflow execution">
               {(() => {
                 const hasNodes = nodes.length > 0;
                 const isRunning = currentExecution?.phase === 'running';
@@ -1392,14 +2721,26 @@ function CanvasPageContent() {
                 else if (!hasNodes) tooltip = "Add nodes to the canvas before running";
 
                 return (
-                  <button
-                    className={`run-button ${isPending ? 'loading' : ''}`}
-                    onClick={() => executeWorkflowMutation.mutate()}
-                    disabled={isDisabled}
-                    title={tooltip}
-                  >
-                    {isPending ? '⏳' : '▶️'} Run
-                  </button>
+                  <>
+                    <button
+                      className={`run-button ${isPending ? 'loading' : ''}`}
+                      onClick={() => runWorkflow({})}
+                      disabled={isDisabled}
+                      title={tooltip}
+                      aria-label={tooltip}
+                    >
+                      {isPending ? '⏳' : '▶️'} Run
+                    </button>
+                    <button
+                      className="step-run-button"
+                      onClick={() => runWorkflow({ stepMode: true })}
+                      disabled={isDisabled}
+                      title={isDisabled ? tooltip : "Run step by step, pausing before each node"}
+                      aria-label="Run workflow step by step"
+                    >
+                      ⏭️ Step
+                    </button>
+                  </>
                 );
               })()}
 
@@ -1409,16 +2750,29 @@ function CanvasPageContent() {
                   onClick={() => cancelExecutionMutation.mutate()}
                   disabled={cancelExecutionMutation.isPending}
                   title="Cancel Execution"
+                  aria-label="Cancel execution"
                 >
                   ⏹️ Cancel
                 </button>
               )}
 
+              <button
+                className="history-button"
+                onClick={() => setShowHistory((open) => !open)}
+                disabled={!workflow?.id}
+                title="Toggle Execution History"
+                aria-label="Toggle execution history"
+                aria-pressed={showHistory}
+              >
+                🕘 History
+              </button>
+
               {currentExecution && (
                 <button
                   className="logs-button"
                   onClick={() => setShowLogs(!showLogs)}
                   title="Toggle Execution Logs"
+                  aria-label="Toggle execution logs"
                   aria-expanded={showLogs}
                   aria-controls="execution-logs-drawer"
                 >
@@ -1432,8 +2786,8 @@ function CanvasPageContent() {
                   className="canvas-toggle-btn"
                   onClick={() => setSnapToGridEnabled((prev) => !prev)}
                   aria-pressed={snapToGridEnabled}
-                  aria-label={snapToGridEnabled ? 'Disable snap to grid (Shift+S)' : 'Enable snap to grid (Shift+S)'}
-                  title={`Snap to grid ${snapToGridEnabled ? 'enabled' : 'disabled'} (Shift+S)`}
+                  aria-label={`${snapToGridEnabled ? 'Disable' : 'Enable'} snap to grid (${snapChord})`}
+                  title={`Snap to grid ${snapToGridEnabled ? 'enabled' : 'disabled'} (${snapChord})`}
                 >
                   ⬛
                 </button>
@@ -1442,12 +2796,107 @@ function CanvasPageContent() {
                   className="canvas-toggle-btn"
                   onClick={() => setGuidesVisible((prev) => !prev)}
                   aria-pressed={guidesVisible}
-                  aria-label={guidesVisible ? 'Hide guides (Shift+G)' : 'Show guides (Shift+G)'}
-                  title={`Guides ${guidesVisible ? 'visible' : 'hidden'} (Shift+G)`}
+                  aria-label={`${guidesVisible ? 'Hide' : 'Show'} guides (${guidesChord})`}
+                  title={`Guides ${guidesVisible ? 'visible' : 'hidden'} (${guidesChord})`}
                 >
                   #️⃣
                 </button>
+                <select
+                  className="canvas-grid-size"
+                  value={gridSize}
+                  onChange={(event) => setGridSize(Number(event.target.value))}
+                  aria-label="Grid spacing"
+                  title="Grid spacing"
+                >
+                  {GRID_SIZE_OPTIONS.map((size) => (
+                    <option key={size} value={size}>
+                      {size}px
+                    </option>
+                  ))}
+                </select>
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={() => setHeatOverlayEnabled((prev) => !prev)}
+                  aria-pressed={heatOverlayEnabled}
+                  aria-label={heatOverlayEnabled ? 'Hide execution heat overlay' : 'Show execution heat overlay'}
+                  title={`Execution heat overlay ${heatOverlayEnabled ? 'on' : 'off'}`}
+                  disabled={!workflow?.id}
+                >
+                  🔥
+                </button>
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={() => setSplitViewEnabled((prev) => !prev)}
+                  aria-pressed={splitViewEnabled}
+                  aria-label={splitViewEnabled ? "Hide agent chat" : "Show agent chat beside the canvas"}
+                  title={
+                    canvasAgents.length > 0 ? "Split view: chat with the selected agent" : "Add an agent node to chat"
+                  }
+                  data-testid="canvas-split-toggle"
+                >
+                  💬
+                </button>
               </div>
+
+              <div className="canvas-mode-toggles" role="group" aria-label="Workflow tools">
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={handleAutoLayout}
+                  aria-label="Auto-arrange nodes"
+                  title="Auto layout"
+                  disabled={nodes.length === 0 || graphTaskRunning}
+                >
+                  🪄
+                </button>
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={handleValidateWorkflow}
+                  aria-label="Check workflow for problems"
+                  title="Validate workflow"
+                  disabled={nodes.length === 0 || graphTaskRunning}
+                >
+                  ✔️
+                </button>
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={handleDeleteWorkflow}
+                  aria-label="Delete workflow"
+                  title="Move workflow to trash"
+                  disabled={!workflow?.id || deleteWorkflowMutation.isPending}
+                >
+                  🗑️
+                </button>
+                <WorkflowTagsButton key={workflow?.id} workflow={workflow} />
+              </div>
+
+              <div className="canvas-mode-toggles" role="group" aria-label="Templates">
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={() => setShowTemplateGallery(true)}
+                  aria-label="Browse workflow templates"
+                  title="Template gallery"
+                >
+                  🗂️
+                </button>
+                <button
+                  type="button"
+                  className="canvas-toggle-btn"
+                  onClick={() => setShowPublishTemplate(true)}
+                  aria-label="Publish workflow as template"
+                  title={nodes.length > 0 ? "Publish as template" : "Add nodes before publishing"}
+                  disabled={!workflow?.id || nodes.length === 0}
+                >
+                  📤
+                </button>
+              </div>
+
+              <PresenceAvatars editors={presence.editors.values()} />
             </div>
 
             {/* Execution Status */}
@@ -1455,6 +2904,16 @@ function CanvasPageContent() {
               <div
                 className={`execution-status execution-status--${currentExecution.phase}`}
                 onClick={() => setShowLogs(!showLogs)}
+                onKeyDown={(event) => {
+                  if (event.key === 'Enter' || event.key === ' ') {
+                    event.preventDefault();
+                    setShowLogs(!showLogs);
+                  }
+                }}
+                role="button"
+                tabIndex={0}
+                aria-expanded={showLogs}
+                aria-controls="execution-logs-drawer"
                 style={{ cursor: 'pointer' }}
                 title={showLogs ? "Click to hide execution details" : "Click to show execution details"}
               >
@@ -1473,10 +2932,36 @@ function CanvasPageContent() {
           </div>
 
           <div
-            className={`canvas-workspace${showLogs && currentExecution ? ' logs-open' : ''}`}
+            className={clsx("canvas-workspace", {
+              "logs-open": showLogs && currentExecution,
+              "canvas-workspace--split": splitViewEnabled,
+            })}
             data-testid="canvas-workspace"
           >
-            <div className="canvas-stage">
+            <div
+              className="canvas-stage"
+              onPointerMove={handlePresencePointerMove}
+              onPointerLeave={handlePresencePointerLeave}
+            >
+              {selectedNodes.length > 1 && (
+                <div className="canvas-format-toolbar" role="toolbar" aria-label="Arrange selected nodes">
+                  {ARRANGE_COMMANDS.map((command) => {
+                    const disabled = selectedNodes.length < command.minNodes;
+                    return (
+                      <button
+                        key={command.mode}
+                        type="button"
+                        onClick={() => handleArrangeSelection(command.mode)}
+                        aria-label={command.label}
+                        title={disabled ? `Select at least ${command.minNodes} nodes` : command.label}
+                        disabled={disabled}
+                      >
+                        {command.icon}
+                      </button>
+                    );
+                  })}
+                </div>
+              )}
               {isSaving && (
                 <div className="canvas-save-banner" role="status" aria-live="polite">
                   {saveWorkflowMutation.isPending ? 'Saving changes...' : 'Syncing workflow...'}
@@ -1499,66 +2984,200 @@ function CanvasPageContent() {
                   onDragOver={onDragOver}
                 />
               )}
-              <ReactFlow
-                nodes={nodes}
-                edges={edges}
-                onNodesChange={onNodesChange}
-                onEdgesChange={onEdgesChange}
-                onConnect={onConnect}
-                onNodeDragStart={onNodeDragStart}
-                onNodeDragStop={onNodeDragStop}
-                onDrop={onDrop}
-                onDragOver={onDragOver}
-                nodeTypes={nodeTypes}
-                snapToGrid={snapToGridEnabled}
-                snapGrid={[SNAP_GRID_SIZE, SNAP_GRID_SIZE]}
-                selectionOnDrag
-                panOnScroll
-                multiSelectionKeyCode="Shift"
-                onPaneClick={handlePaneClick}
-                onNodeContextMenu={handleNodeContextMenu}
-              >
-                {dragPreviewData && dragPreviewPosition && (
-                  <ViewportPortal>
-                    <div
-                      className="canvas-drag-preview"
-                      style={{
-                        position: "absolute",
-                        transform: `translate(${dragPreviewPosition.x}px, ${dragPreviewPosition.y}px)`,
-                        pointerEvents: "none",
-                        width: `${dragPreviewData.baseSize.width || 160}px`,
-                        height: `${dragPreviewData.baseSize.height || 48}px`,
-                      }}
+              {heatOverlayEnabled && (
+                <div className="heat-legend" data-testid="heat-legend">
+                  <label className="heat-legend__metric">
+                    <span>Heat</span>
+                    <select
+                      value={heatMetric}
+                      onChange={(event) => setHeatMetric(event.target.value as HeatMetric)}
+                      aria-label="Heat metric"
                     >
-                      {dragPreviewData.kind === "agent" ? (
-                        <div className="agent-node drag-preview-node">
-                          <div className="agent-icon">{dragPreviewData.icon}</div>
-                          <div className="agent-name">{dragPreviewData.label}</div>
-                        </div>
-                      ) : (
-                        <div className="tool-node drag-preview-node">
-                          <div className="tool-icon">{dragPreviewData.icon}</div>
-                          <div className="tool-name">{dragPreviewData.label}</div>
+                      {HEAT_METRICS.map((option) => (
+                        <option key={option.value} value={option.value}>
+                          {option.label}
+                        </option>
+                      ))}
+                    </select>
+                  </label>
+                  <div className="heat-legend__scale" aria-hidden="true" />
+                  <div className="heat-legend__labels">
+                    <span>{heatMetric === 'failure_rate' ? '0%' : formatHeatValue(0, heatMetric)}</span>
+                    <span>
+                      {heatMetric === 'failure_rate'
+                        ? '100%'
+                        : formatHeatValue(nodeHeat?.max ?? null, heatMetric)}
+                    </span>
+                  </div>
+                  <div className="heat-legend__footer">
+                    {isFetchingHeat && !nodeSummaries
+                      ? 'Loading executions…'
+                      : `Last ${nodeSummaries?.length ?? 0} execution${nodeSummaries?.length === 1 ? '' : 's'}`}
+                  </div>
+                </div>
+              )}
+              <NodeCommentsContext.Provider value={setCommentNodeId}>
+                <SubworkflowRunContext.Provider value={currentExecution ? setRunModalNodeId : null}>
+                  <ReactFlow
+                    nodes={flowNodes}
+                    edges={edges}
+                    onNodesChange={onNodesChange}
+                    onEdgesChange={onEdgesChange}
+                    onConnect={onConnect}
+                    onNodeDragStart={onNodeDragStart}
+                    onNodeDragStop={onNodeDragStop}
+                    onDrop={onDrop}
+                    onDragOver={onDragOver}
+                    nodeTypes={nodeTypes}
+                    snapToGrid={snapToGridEnabled}
+                    snapGrid={[gridSize, gridSize]}
+                    selectionOnDrag
+                    panOnScroll
+                    multiSelectionKeyCode="Shift"
+                    onPaneClick={handlePaneClick}
+                    onNodeContextMenu={handleNodeContextMenu}
+                  >
+                    {dragPreviewData && dragPreviewPosition && (
+                      <ViewportPortal>
+                        <div
+                          className="canvas-drag-preview"
+                          style={{
+                            position: "absolute",
+                            transform: `translate(${dragPreviewPosition.x}px, ${dragPreviewPosition.y}px)`,
+                            pointerEvents: "none",
+                            width: `${dragPreviewData.baseSize.width || 160}px`,
+                            height: `${dragPreviewData.baseSize.height || 48}px`,
+                          }}
+                        >
+                          {dragPreviewData.kind === "agent" ? (
+                            <div className="agent-node drag-preview-node">
+                              <div className="agent-icon">{dragPreviewData.icon}</div>
+                              <div className="agent-name">{dragPreviewData.label}</div>
+                            </div>
+                          ) : dragPreviewData.kind === "control" ? (
+                            <div className="control-node drag-preview-node">
+                              <div className="control-node-header">
+                                <span className="control-icon">{dragPreviewData.icon}</span>
+                                <span className="control-name">{dragPreviewData.label}</span>
+                              </div>
+                            </div>
+                          ) : (
+                            <div className="tool-node drag-preview-node">
+                              <div className="tool-icon">{dragPreviewData.icon}</div>
+                              <div className="tool-name">{dragPreviewData.label}</div>
+                            </div>
+                          )}
                         </div>
-                      )}
-                    </div>
-                  </ViewportPortal>
-                )}
-                {guidesVisible && <Background gap={SNAP_GRID_SIZE} />}
-                <Controls />
-                <MiniMap
-                  nodeComponent={MiniMapNode}
-                  maskColor="rgba(20, 20, 35, 0.6)"
-                  style={{
-                    backgroundColor: '#2a2a3a', // Match card background
-                    height: 120,
-                    width: 160,
-                    border: '1px solid #3d3d5c',
-                    borderRadius: '4px'
-                  }}
-                />
-              </ReactFlow>
+                        {guidesVisible &&
+                          dropHints?.guides.map((guide) => (
+                            <div
+                              key={guide.orientation}
+                              className={`canvas-guide-line ${guide.orientation}`}
+                              data-testid="canvas-guide-line"
+                              style={
+                                guide.orientation === "vertical"
+                                  ? { left: guide.position, top: guide.start, height: guide.end - guide.start }
+                                  : { top: guide.position, left: guide.start, width: guide.end - guide.start }
+                              }
+                            />
+                          ))}
+                        {suggestedEdge && (
+                          <svg className="canvas-edge-suggestion" data-testid="canvas-edge-suggestion">
+                            <line
+                              x1={suggestedEdge.from.x}
+                              y1={suggestedEdge.from.y}
+                              x2={suggestedEdge.to.x}
+                              y2={suggestedEdge.to.y}
+                            />
+                          </svg>
+                        )}
+                      </ViewportPortal>
+                    )}
+                    {presence.editors.size > 0 && (
+                      <ViewportPortal>
+                        <RemoteCursors editors={presence.editors.values()} />
+                      </ViewportPortal>
+                    )}
+                    {guidesVisible && <Background gap={gridSize} />}
+                    <Controls />
+                    <MiniMap
+                      nodeComponent={MiniMapNode}
+                      maskColor="rgba(20, 20, 35, 0.6)"
+                      style={{
+                        backgroundColor: '#2a2a3a', // Match card background
+                        height: 120,
+                        width: 160,
+                        border: '1px solid #3d3d5c',
+                        borderRadius: '4px'
+                      }}
+                    />
+                  </ReactFlow>
+                </SubworkflowRunContext.Provider>
+              </NodeCommentsContext.Provider>
             </div>
+            {splitViewEnabled &&
+              (splitChatAgentId != null ? (
+                <AgentChatPane
+                  agentId={splitChatAgentId}
+                  agentOptions={canvasAgents}
+                  onSelectAgent={handleSelectChatAgent}
+                  onClose={() => setSplitViewEnabled(false)}
+                />
+              ) : (
+                <aside className="agent-chat-pane agent-chat-pane--empty" aria-label="Agent chat">
+                  <p className="muted">Add an agent node to the canvas to chat with it here.</p>
+                </aside>
+              ))}
+            {inspectedNode && (
+              <NodeInspector
+                node={inspectedNode}
+                agents={agents}
+                workflows={callableWorkflows}
+                onApply={handleInspectorApply}
+                onClose={handleCloseInspector}
+                executionId={currentExecution?.execution_id}
+                envVars={envVars}
+                workflowId={workflow?.id ?? null}
+                apiCredentials={apiCredentials}
+              />
+            )}
+            {runInputsPrompt && (
+              <RunInputsModal
+                specs={runInputsPrompt.specs}
+                stepMode={runInputsPrompt.stepMode}
+                initialInputs={runInputsPrompt.initialInputs}
+                serverErrors={runInputsPrompt.serverErrors}
+                isStarting={executeWorkflowMutation.isPending}
+                onRun={(inputs) => executeWorkflowMutation.mutate({ stepMode: runInputsPrompt.stepMode, inputs })}
+                onClose={() => setRunInputsPrompt(null)}
+              />
+            )}
+            {runModalNodeId && currentExecution && (
+              <SubworkflowRunModal
+                executionId={currentExecution.execution_id}
+                nodeId={runModalNodeId}
+                onClose={() => setRunModalNodeId(null)}
+              />
+            )}
+            {showHistory && workflow && (
+              <ExecutionHistorySidebar
+                key={workflow.id}
+                workflowId={workflow.id}
+                activeExecutionId={currentExecution?.execution_id ?? null}
+                canRerun={currentExecution?.phase !== "running" && !executeWorkflowMutation.isPending}
+                onRerun={rerunExecution}
+                onClose={() => setShowHistory(false)}
+              />
+            )}
+            {commentNode && workflow && (
+              <NodeCommentsPanel
+                workflowId={workflow.id}
+                nodeId={commentNode.id}
+                nodeLabel={(commentNode.data as FlowNodeData).label}
+                threads={commentThreads.get(commentNode.id) ?? []}
+                onClose={() => setCommentNodeId(null)}
+              />
+            )}
             {showLogs && currentExecution && (
               <aside
                 ref={logsPanelRef}
@@ -1578,6 +3197,17 @@ function CanvasPageContent() {
                   style={{ cursor: isDraggingLogsPanel ? 'grabbing' : 'grab' }}
                 >
                   <h4>Execution Logs</h4>
+                  {currentExecution.phase === 'running' && (
+                    <button
+                      type="button"
+                      className="logs-cancel-run"
+                      onMouseDown={(e) => e.stopPropagation()}
+                      onClick={() => cancelExecutionMutation.mutate()}
+                      disabled={cancelExecutionMutation.isPending}
+                    >
+                      {cancelExecutionMutation.isPending ? 'Cancelling…' : 'Cancel run'}
+                    </button>
+                  )}
                   <button
                     className="close-logs"
                     onClick={() => setShowLogs(false)}
@@ -1587,9 +3217,27 @@ function CanvasPageContent() {
                   </button>
                 </div>
                 <div className="logs-content">
+                  {(currentExecution.step_mode || stepState.paused) && currentExecution.phase === 'running' && (
+                    <ExecutionStepPanel
+                      state={stepState}
+                      nodeLabels={nodeLabels}
+                      onStep={(config) => resumeExecutionMutation.mutate({ mode: 'step', config })}
+                      onContinue={(config) => resumeExecutionMutation.mutate({ mode: 'continue', config })}
+                      onAbort={() => cancelExecutionMutation.mutate()}
+                    />
+                  )}
+                  {currentExecution.phase === 'running' && Object.keys(pendingWaits).length > 0 && (
+                    <PendingWaitsPanel
+                      waits={Object.values(pendingWaits)}
+                      nodeLabels={nodeLabels}
+                      onSkip={(nodeId) => skipWaitMutation.mutate(nodeId)}
+                      skippingNodeId={skipWaitMutation.isPending ? (skipWaitMutation.variables ?? null) : null}
+                    />
+                  )}
                   <ExecutionLogStream
                     logs={executionLogs}
                     isRunning={currentExecution.phase === 'running'}
+                    executionId={currentExecution.execution_id}
                   />
                 </div>
               </aside>
@@ -1598,28 +3246,19 @@ function CanvasPageContent() {
         </div>
       </div>
 
-      {showShortcutHelp && (
-        <div className="shortcut-help-overlay" role="dialog" aria-modal="true" aria-labelledby="shortcut-help-title">
-          <div className="shortcut-help-panel">
-            <div className="shortcut-help-header">
-              <h3 id="shortcut-help-title">Canvas Shortcuts</h3>
-              <button
-                type="button"
-                className="close-logs"
-                onClick={() => setShowShortcutHelp(false)}
-                title="Close shortcuts"
-              >
-                ✕
-              </button>
-            </div>
-            <ul className="shortcut-help-list">
-              <li><kbd>Shift</kbd> + <kbd>S</kbd> Toggle snap to grid</li>
-              <li><kbd>Shift</kbd> + <kbd>G</kbd> Toggle guides</li>
-              <li><kbd>Shift</kbd> + <kbd>/</kbd> Show this panel</li>
-            </ul>
-            <p className="shortcut-help-hint">Press Esc to close.</p>
-          </div>
-        </div>
+      {showShortcutHelp && <ShortcutHelpOverlay scope="canvas" onClose={closeShortcutHelp} />}
+      <TemplateGalleryModal
+        isOpen={showTemplateGallery}
+        onClose={() => setShowTemplateGallery(false)}
+        onUseTemplate={handleUseTemplate}
+      />
+      {showPublishTemplate && workflow && (
+        <PublishTemplateModal
+          isOpen
+          onClose={() => setShowPublishTemplate(false)}
+          workflow={workflow}
+          canvas={normalizeWorkflow(nodes, edges)}
+        />
       )}
 
       {contextMenu && (
@@ -1633,6 +3272,31 @@ function CanvasPageContent() {
           <button type="button" role="menuitem" onClick={handleDuplicateNode}>
             Duplicate node
           </button>
+          <button type="button" role="menuitem" onClick={handleToggleBreakpoint}>
+            {hasBreakpoint(nodes.find((node) => node.id === contextMenu.nodeId)?.data.config)
+              ? "Remove breakpoint"
+              : "Add breakpoint"}
+          </button>
+          <button type="button" role="menuitem" onClick={handleOpenComments}>
+            Comments
+          </button>
+          {(() => {
+            const blocker =
+              currentExecution?.phase === "running" || executeWorkflowMutation.isPending
+                ? "Wait for the current run to finish"
+                : runFromBlocker(nodeOutputCache, edges, contextMenu.nodeId, (id) => nodeLabels[id] ?? id);
+            return (
+              <button
+                type="button"
+                role="menuitem"
+                onClick={runFromContextNode}
+                disabled={blocker !== null}
+                title={blocker ?? "Re-run this node and everything after it, reusing cached outputs before it"}
+              >
+                Run from this node
+              </button>
+            );
+          })()}
           <button type="button" role="menuitem" onClick={handleDeleteNode}>
             Delete node
           </button>
@@ -1648,22 +3312,14 @@ function CanvasPageContent() {
 }
 
 // Wrapper component that provides ReactFlow context
+// ?workflow=<id> opens that workflow (the quick switcher links here); switching remounts the canvas
 export default function CanvasPage() {
+  const [searchParams] = useSearchParams();
+  const requested = Number(searchParams.get("workflow"));
+  const workflowId = Number.isInteger(requested) && requested > 0 ? requested : undefined;
   return (
-    <ReactFlowProvider>
-      <CanvasPageContent />
+    <ReactFlowProvider key={workflowId ?? "current"}>
+      <CanvasPageContent workflowId={workflowId} />
     </ReactFlowProvider>
   );
 }
-
-// Simple debounce utility function
-function debounce<T extends unknown[]>(
-  func: (...args: T) => void,
-  wait: number
-): (...args: T) => void {
-  let timeout: number | undefined;
-  return (...args: T) => {
-    clearTimeout(timeout);
-    timeout = window.setTimeout(() => func(...args), wait);
-  };
-}
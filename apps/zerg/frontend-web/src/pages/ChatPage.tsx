@@ -1,18 +1,27 @@
-import { useEffect, useRef, useState, type FormEvent } from "react";
-import { useNavigate, useParams } from "react-router-dom";
-import { useQueryClient } from "@tanstack/react-query";
+import { useEffect, useMemo, useRef, useState, type FormEvent } from "react";
+import { useNavigate, useParams, useSearchParams } from "react-router-dom";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
 import { toast } from "react-hot-toast";
 import clsx from "clsx";
 import { useShelf } from "../lib/useShelfState";
+import { chatDraftKey, readDraft } from "../lib/drafts";
+import { useDraftAutosave } from "../lib/useDraftAutosave";
 import { SettingsIcon } from "../components/icons";
 import AgentSettingsDrawer from "../components/agent-settings/AgentSettingsDrawer";
 import { ChatThreadList } from "../components/chat/ChatThreadList";
 import { ChatMessageList } from "../components/chat/ChatMessageList";
 import { ChatComposer } from "../components/chat/ChatComposer";
+import { ThreadModelPicker } from "../components/chat/ThreadModelPicker";
+import { SavedMessagesPanel } from "../components/chat/SavedMessagesPanel";
 import { useChatData } from "../hooks/chat/useChatData";
+import { AgentReadme } from "../components/AgentReadme";
 import { useChatActions } from "../hooks/chat/useChatActions";
+import { useChatAttachments } from "../hooks/chat/useChatAttachments";
 import { useThreadStreaming } from "../hooks/chat/useThreadStreaming";
-import { createThread } from "../services/api";
+import { createThread, fetchModels, type Thread, type ThreadMessage } from "../services/api";
+import { useAuth } from "../lib/auth";
+import { formatCostUsd, formatTokenCount, summarizeThreadUsage } from "../components/chat/chatUtils";
+import { downloadTranscript, fetchAllThreadMessages, type TranscriptFormat } from "../components/chat/transcriptExport";
 
 function useRequiredNumber(param?: string): number | null {
   if (!param) return null;
@@ -22,10 +31,12 @@ function useRequiredNumber(param?: string): number | null {
 
 export default function ChatPage() {
   const params = useParams();
+  const [searchParams] = useSearchParams();
   const navigate = useNavigate();
   const queryClient = useQueryClient();
   const { isShelfOpen, closeShelf } = useShelf();
   const creatingThreadRef = useRef(false);
+  const { user } = useAuth();
 
   const agentId = useRequiredNumber(params.agentId);
   const threadIdParam = useRequiredNumber(params.threadId ?? undefined);
@@ -33,11 +44,38 @@ export default function ChatPage() {
   const [editingThreadId, setEditingThreadId] = useState<number | null>(null);
   const [editingTitle, setEditingTitle] = useState("");
   const [isSettingsDrawerOpen, setIsSettingsDrawerOpen] = useState(false);
+  const [showSavedMessages, setShowSavedMessages] = useState(false);
+  // Message to scroll to once its thread is open; links may carry it as ?message=<id>
+  const [focusMessageId, setFocusMessageId] = useState<number | null>(
+    () => Number(searchParams.get("message")) || null
+  );
 
   // Advanced features state
   const [showWorkflowPanel, setShowWorkflowPanel] = useState(false);
   const [selectedWorkflow, setSelectedWorkflow] = useState<number | null>(null);
   const [draft, setDraft] = useState("");
+  // Thread the composer text belongs to; always changes together with the draft
+  const [draftThreadId, setDraftThreadId] = useState<number | null>(null);
+  const [draftRestored, setDraftRestored] = useState(false);
+  const { discard: discardStoredDraft } = useDraftAutosave(
+    draftThreadId === null ? null : chatDraftKey(draftThreadId),
+    draft.trim() ? draft : null
+  );
+
+  // Each thread has its own draft; switching threads saves the old one and restores the new one
+  useEffect(() => {
+    const stored = selectedThreadId === null ? null : readDraft<string>(chatDraftKey(selectedThreadId));
+    const restored = typeof stored?.value === "string" && stored.value.trim() ? stored.value : "";
+    setDraft(restored);
+    setDraftRestored(restored !== "");
+    setDraftThreadId(selectedThreadId);
+  }, [selectedThreadId]);
+
+  const handleDiscardDraft = () => {
+    setDraft("");
+    setDraftRestored(false);
+    discardStoredDraft();
+  };
 
   // Sync selectedThreadId from URL parameter - one-way only (URL → state)
   // This ensures state stays consistent with URL, but doesn't override when
@@ -47,10 +85,28 @@ export default function ChatPage() {
   }, [threadIdParam]);
 
   // Use chat data hook - strict URL state (no fallback)
-  const { agent, chatThreads, automationThreads, messages, isLoading, hasError, workflowsQuery, chatThreadsQuery } = useChatData({
+  const {
+    agent,
+    chatThreads,
+    archivedThreads,
+    automationThreads,
+    messages,
+    hasOlderMessages,
+    isLoadingOlder,
+    loadOlderMessages,
+    isLoading,
+    hasError,
+    workflowsQuery,
+    chatThreadsQuery,
+  } = useChatData({
     agentId,
     effectiveThreadId: selectedThreadId,
   });
+  const threadUsage = useMemo(() => summarizeThreadUsage(messages), [messages]);
+  const { data: models = [] } = useQuery({ queryKey: ["models"], queryFn: fetchModels, staleTime: 60 * 60 * 1000 });
+  const currentThread = [...chatThreads, ...archivedThreads, ...automationThreads].find(
+    (thread) => thread.id === selectedThreadId
+  );
 
   // Strict URL model: effectiveThreadId is just selectedThreadId
   // If no thread is selected, we handle it explicitly below
@@ -120,17 +176,51 @@ export default function ChatPage() {
   }, [agentId, selectedThreadId, chatThreads.length, chatThreadsQuery.isLoading, queryClient, navigate]);
 
   // Use chat actions hook
-  const { sendMutation, executeWorkflowMutation, renameThreadMutation } = useChatActions({
+  const {
+    sendMutation,
+    executeWorkflowMutation,
+    renameThreadMutation,
+    updateThreadFlagsMutation,
+    updateThreadModelMutation,
+    updateMessageMetadataMutation,
+    editMessageMutation,
+    regenerateMutation,
+    failedSends,
+    retryFailedSend,
+    discardFailedSend,
+  } = useChatActions({
     agentId,
     effectiveThreadId,
+    onThreadForked: (thread) => {
+      setSelectedThreadId(thread.id);
+      navigate(`/agent/${thread.agent_id}/thread/${thread.id}`, { replace: true });
+      toast.success("Edited message sent in a new thread");
+    },
   });
 
   // Use streaming hook - no subscriptions needed, user:{user_id} is auto-subscribed
-  const { streamingMessages, streamingMessageId, pendingTokenBuffer, allStreamingThreadIds } = useThreadStreaming({
+  const { pendingAttachments, readyAttachments, isUploading, addFiles, removeAttachment, clearAttachments } =
+    useChatAttachments(effectiveThreadId);
+
+  const {
+    streamingMessages,
+    streamingMessageId,
+    pendingTokenBuffer,
+    streamingToolOutputs,
+    allStreamingThreadIds,
+    isAgentReplying,
+    replyStartedBy,
+  } = useThreadStreaming({
     agentId,
     effectiveThreadId,
   });
 
+  // Shared threads: another participant's message is being answered
+  const replyPendingNotice =
+    isAgentReplying && replyStartedBy && replyStartedBy.id !== user?.id
+      ? `The agent is replying to ${replyStartedBy.name ?? "another participant"}. You can send once it finishes.`
+      : null;
+
   // Event handlers
   const handleSelectThread = (thread: any) => {
     setSelectedThreadId(thread.id);
@@ -174,6 +264,30 @@ export default function ChatPage() {
     setEditingTitle("");
   };
 
+  const handleTogglePin = (thread: Thread) => {
+    updateThreadFlagsMutation.mutate({ threadId: thread.id, pinned: !thread.pinned });
+  };
+
+  const handleToggleArchive = (thread: Thread) => {
+    updateThreadFlagsMutation.mutate({ threadId: thread.id, archived: !thread.archived });
+  };
+
+  const handleToggleBookmark = (message: ThreadMessage) => {
+    updateMessageMetadataMutation.mutate({
+      threadId: message.thread_id,
+      messageId: message.id,
+      bookmarked: !message.bookmarked,
+    });
+  };
+
+  const handleJumpToSaved = (message: ThreadMessage) => {
+    setFocusMessageId(message.id);
+    if (message.thread_id !== selectedThreadId) {
+      setSelectedThreadId(message.thread_id);
+      navigate(`/agent/${agentId}/thread/${message.thread_id}`, { replace: true });
+    }
+  };
+
   // Event handlers
   const handleSend = async (evt: FormEvent) => {
     evt.preventDefault();
@@ -182,12 +296,25 @@ export default function ChatPage() {
       return;
     }
     const trimmed = draft.trim();
-    if (!trimmed) {
+    if (isUploading) {
+      toast.error("Wait for attachments to finish uploading");
       return;
     }
+    if (!trimmed && readyAttachments.length === 0) {
+      return;
+    }
+    if (isAgentReplying) {
+      toast.error("Wait for the agent to finish replying");
+      return;
+    }
+    const attachments = readyAttachments;
     setDraft("");
+    // The text now lives in the thread (or in the failed-send card with its retry)
+    setDraftRestored(false);
+    discardStoredDraft();
+    clearAttachments();
     try {
-      await sendMutation.mutateAsync({ threadId: effectiveThreadId, content: trimmed });
+      await sendMutation.mutateAsync({ threadId: effectiveThreadId, content: trimmed, attachments });
     } catch (error) {
       // Error handling is done in the mutation's onError callback
     }
@@ -202,31 +329,28 @@ export default function ChatPage() {
     });
   };
 
+  // The open thread is exported from what is loaded unless older pages are still on the server
+  const handleExportThread = async (thread: Thread, format: TranscriptFormat) => {
+    try {
+      const threadMessages =
+        thread.id === effectiveThreadId && !hasOlderMessages ? messages : await fetchAllThreadMessages(thread.id);
+      if (!threadMessages.some((msg) => msg.role !== "system")) {
+        toast.error("No messages to export");
+        return;
+      }
+      downloadTranscript({ threadId: thread.id, title: thread.title, agentName: agent?.name }, threadMessages, format);
+      toast.success("Transcript exported");
+    } catch (error) {
+      toast.error(`Failed to export thread: ${(error as Error).message}`, { duration: 6000 });
+    }
+  };
+
   const handleExportChat = () => {
-    if (messages.length === 0) {
+    if (!currentThread) {
       toast.error("No messages to export");
       return;
     }
-
-    const chatHistory = messages
-      .filter(msg => msg.role !== "system")
-      .map(msg => {
-        const timestamp = new Date(msg.created_at || "").toLocaleString();
-        return `[${timestamp}] ${msg.role.toUpperCase()}: ${msg.content}`;
-      })
-      .join("\n\n");
-
-    const blob = new Blob([chatHistory], { type: "text/plain" });
-    const url = URL.createObjectURL(blob);
-    const link = document.createElement("a");
-    link.href = url;
-    link.download = `chat-history-${effectiveThreadId || 'unknown'}.txt`;
-    document.body.appendChild(link);
-    link.click();
-    document.body.removeChild(link);
-    URL.revokeObjectURL(url);
-
-    toast.success("Chat history exported");
+    void handleExportThread(currentThread, "markdown");
   };
 
   // Workflow execution handler
@@ -256,7 +380,7 @@ export default function ChatPage() {
   const handleCreateThread = async () => {
     if (agentId == null) return;
     // Auto-generate thread name based on the count of existing threads
-    const threadCount = chatThreads.length + 1;
+    const threadCount = chatThreads.length + archivedThreads.length + 1;
     const title = `Thread ${threadCount}`;
     try {
       const thread = await createThread(agentId, title);
@@ -320,9 +444,35 @@ export default function ChatPage() {
                 {effectiveThreadId != null ? `#${effectiveThreadId}` : "None"}
               </span>
             </div>
+            {threadUsage.replies > 0 && (
+              <div className="thread-usage-stats" data-testid="thread-usage-stats">
+                {formatTokenCount(threadUsage.totalTokens)} tokens
+                {threadUsage.costUsd != null && ` · ${formatCostUsd(threadUsage.costUsd)}`}
+                {` · ${threadUsage.replies} ${threadUsage.replies === 1 ? "reply" : "replies"}`}
+              </div>
+            )}
           </div>
           {agentId != null && (
             <div className="chat-actions">
+              {currentThread && (
+                <ThreadModelPicker
+                  models={models}
+                  agentModel={agent?.model}
+                  value={currentThread.model_override}
+                  onChange={(model) => updateThreadModelMutation.mutate({ threadId: currentThread.id, model })}
+                  disabled={updateThreadModelMutation.isPending}
+                />
+              )}
+              <button
+                type="button"
+                className={clsx("chat-saved-btn", { "chat-saved-btn--active": showSavedMessages })}
+                onClick={() => setShowSavedMessages((open) => !open)}
+                aria-expanded={showSavedMessages}
+                title="Saved messages"
+                data-testid="saved-messages-toggle"
+              >
+                ★ <span>Saved</span>
+              </button>
               <button
                 type="button"
                 className="chat-settings-btn"
@@ -335,10 +485,12 @@ export default function ChatPage() {
             </div>
           )}
         </header>
+        <AgentReadme description={agent?.description} className="chat-agent-readme" />
 
         <div className="chat-body">
           <ChatThreadList
             chatThreads={chatThreads}
+            archivedThreads={archivedThreads}
             automationThreads={automationThreads}
             effectiveThreadId={effectiveThreadId}
             editingThreadId={editingThreadId}
@@ -348,19 +500,63 @@ export default function ChatPage() {
             onSaveThreadTitle={handleSaveThreadTitle}
             onCancelEdit={handleCancelEdit}
             onTitleChange={setEditingTitle}
+            onTogglePin={handleTogglePin}
+            onToggleArchive={handleToggleArchive}
+            onExportThread={handleExportThread}
             isRenamingPending={renameThreadMutation.isPending}
             onCreateThread={handleCreateThread}
             isShelfOpen={isShelfOpen}
             streamingThreadIds={allStreamingThreadIds}
           />
 
+          {/* Keyed by thread so each one opens with a fresh window at its newest message */}
           <ChatMessageList
+            key={effectiveThreadId ?? "new"}
             messages={messages}
+            hasOlderMessages={hasOlderMessages}
+            isLoadingOlder={isLoadingOlder}
+            onLoadOlder={loadOlderMessages}
             streamingMessages={streamingMessages}
             streamingMessageId={streamingMessageId}
             pendingTokenBuffer={pendingTokenBuffer}
+            streamingToolOutputs={streamingToolOutputs}
             onCopyMessage={handleCopyMessage}
+            currentUserId={user?.id ?? null}
+            failedSends={failedSends.filter((failure) => failure.threadId === effectiveThreadId)}
+            onRetryFailed={retryFailedSend}
+            onDiscardFailed={discardFailedSend}
+            onEditMessage={(message, content) => {
+              if (effectiveThreadId == null) return;
+              editMessageMutation.mutate({ threadId: effectiveThreadId, messageId: message.id, content });
+            }}
+            onRegenerate={() => {
+              if (effectiveThreadId == null) return;
+              regenerateMutation.mutate({ threadId: effectiveThreadId });
+            }}
+            isBusy={
+              sendMutation.isPending || editMessageMutation.isPending || regenerateMutation.isPending || isAgentReplying
+            }
+            onToggleBookmark={handleToggleBookmark}
+            onToggleReaction={(message, emoji) =>
+              updateMessageMetadataMutation.mutate({
+                threadId: message.thread_id,
+                messageId: message.id,
+                reaction: emoji,
+              })
+            }
+            focusMessageId={focusMessageId}
+            onFocusHandled={() => setFocusMessageId(null)}
           />
+
+          {showSavedMessages && (
+            <SavedMessagesPanel
+              agentId={agentId}
+              threads={[...chatThreads, ...archivedThreads, ...automationThreads]}
+              onJump={handleJumpToSaved}
+              onRemove={handleToggleBookmark}
+              onClose={() => setShowSavedMessages(false)}
+            />
+          )}
         </div>
 
         {/* Scrim overlay when thread sidebar is open on mobile */}
@@ -386,6 +582,14 @@ export default function ChatPage() {
             isExecutingWorkflow={executeWorkflowMutation.isPending}
             messagesCount={messages.length}
             onExportChat={handleExportChat}
+            attachments={pendingAttachments}
+            isUploading={isUploading}
+            onAddFiles={addFiles}
+            onRemoveAttachment={removeAttachment}
+            replyPendingNotice={replyPendingNotice}
+            isAgentReplying={isAgentReplying}
+            draftRestored={draftRestored}
+            onDiscardDraft={handleDiscardDraft}
           />
         </div>
       </div>
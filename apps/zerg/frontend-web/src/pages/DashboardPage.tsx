@@ -1,28 +1,106 @@
 import { Fragment, useCallback, useEffect, useMemo, useRef, useState, type KeyboardEvent as ReactKeyboardEvent, type MouseEvent as ReactMouseEvent } from "react";
 import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
-import { useNavigate } from "react-router-dom";
+import { Link, useNavigate, useSearchParams } from "react-router-dom";
+import { toast } from "react-hot-toast";
 import {
+  isApiError,
+  cancelRun,
+  deleteAgent,
+  dropQueuedRun,
+  duplicateAgent,
+  exportAgent,
+  fetchAgentDependents,
+  fetchAgentStats,
+  fetchAgentUsage,
   fetchDashboardSnapshot,
+  fetchSystemInfo,
+  pauseAgent,
+  reorderRunQueue,
+  restoreAgent,
+  resumeAgent,
   runAgent,
   updateAgent,
   fetchModels,
+  type AgentDependents,
   type AgentRun,
+  type AgentStats,
+  type AgentScope,
+  type AgentUsage,
   type AgentSummary,
   type DashboardSnapshot,
   type ModelConfig,
 } from "../services/api";
 import { buildUrl } from "../services/api";
 import { ConnectionStatus, useWebSocket } from "../lib/useWebSocket";
+import { useVisibleRows } from "../lib/useVisibleRows";
+import { agentTopic, agentTopicChanges, wantedAgentIds } from "../lib/agentSubscriptions";
 import { useAuth } from "../lib/auth";
-import { MessageCircleIcon, PlayIcon, SettingsIcon, TrashIcon } from "../components/icons";
+import { DASHBOARD_SCOPE_PREF, DEFAULT_MODEL_PREF, dashboardSortFromPrefs } from "../lib/preferences";
+import { useEffectivePrefs } from "../lib/usePreferences";
+import { Capability, useCapabilities } from "../lib/capabilities";
+import { useNotify } from "../lib/useNotifications";
+import { useRunFinishedNotifier } from "../lib/useDesktopNotifications";
+import { useAnnounce } from "../lib/useAnnouncer";
+import { agentStatusAnnouncement, runFinishedAnnouncement } from "../lib/announcements";
+import {
+  CopyIcon,
+  DownloadIcon,
+  MessageCircleIcon,
+  PauseIcon,
+  StopIcon,
+  PlayIcon,
+  SettingsIcon,
+  ShareIcon,
+  TrashIcon,
+} from "../components/icons";
 import AgentSettingsDrawer from "../components/agent-settings/AgentSettingsDrawer";
-import type { WebSocketMessage } from "../generated/ws-messages";
+import AgentHoverCard from "../components/AgentHoverCard";
+import RunHistoryExport from "../components/RunHistoryExport";
+import AgentRunChart from "../components/AgentRunChart";
+import BudgetMeter from "../components/BudgetMeter";
+import AgentStatsCell from "../components/AgentStatsCell";
+import RunDetailDrawer from "../components/RunDetailDrawer";
+import Timestamp from "../components/Timestamp";
+import AgentImportWizard from "../components/AgentImportWizard";
+import AgentShareDialog from "../components/AgentShareDialog";
+import DeleteAgentDialog from "../components/DeleteAgentDialog";
+import RecentlyDeleted from "../components/RecentlyDeleted";
+import { ShortcutHelpOverlay } from "../components/ShortcutHelpOverlay";
+import { AgentReadme } from "../components/AgentReadme";
+import { useShortcutBindings, useShortcuts } from "../lib/useShortcuts";
+import { actionForEvent } from "../lib/shortcuts";
+import { downloadTextFile } from "../lib/download";
+import { budgetExhaustedMessage, isBudgetExhausted } from "../lib/agentBudget";
+import { MAINTENANCE_BANNER_TEXT, PAUSED_BADGE_TITLE, confirmRunWhilePaused, isAgentPaused } from "../lib/agentPause";
+import { activeRun, markRunCancelling } from "../lib/runCancellation";
+import { hasDependents } from "../lib/agentDependents";
+import { applyQueueUpdate, moveInQueue, queueBadgeLabel, queuedRuns } from "../lib/runQueue";
+import { AgentDeltaSequence, applyAgentDelta, parseAgentDelta, removeAgentFromSnapshot } from "../lib/agentDeltas";
+import { compareSuccess, summarizeRuns, summarizeStats, withAgentStats } from "../lib/agentStats";
+import { compareTimestamps } from "../lib/datetime";
+import { accessAllows, agentAccess, sharedPermissionMap } from "../lib/agentSharing";
+import { hasSchemaViolations, schemaViolationTitle } from "../lib/outputSchema";
+import { collectTags, hasTag, matchesTags, removeTag } from "../lib/tags";
+import {
+  USAGE_COLUMNS,
+  USAGE_COLUMNS_STORAGE_KEY,
+  USAGE_COLUMN_LABELS,
+  USAGE_DAYS,
+  compareUsage,
+  costBreakdownTitle,
+  formatTokenCount,
+  formatUsageCost,
+  parseUsageColumns,
+  toggleUsageColumn,
+  type UsageColumn,
+} from "../lib/agentUsage";
+import type { RunQueueUpdateData, WebSocketMessage } from "../generated/ws-messages";
 
 // App logo (served from public folder)
 const appLogo = "/Gemini_Generated_Image_klhmhfklhmhfklhm-removebg-preview.png";
 
-type Scope = "my" | "all";
-type SortKey = "name" | "status" | "created_at" | "last_run" | "next_run" | "success";
+type Scope = AgentScope;
+type SortKey = "name" | "status" | "created_at" | "last_run" | "next_run" | "success" | UsageColumn;
 
 type SortConfig = {
   key: SortKey;
@@ -31,11 +109,14 @@ type SortConfig = {
 
 type AgentRunsState = Record<number, AgentRun[]>;
 
+type PendingDelete = {
+  agent: AgentSummary;
+  // null when the dependency check failed
+  dependents: AgentDependents | null;
+};
+
 type LegacyAgentRow = {
   agent: AgentSummary;
-  createdDisplay: string;
-  lastRunDisplay: string;
-  nextRunDisplay: string;
 };
 
 const STATUS_ORDER: Record<string, number> = {
@@ -45,8 +126,6 @@ const STATUS_ORDER: Record<string, number> = {
   error: 3,
 };
 
-const NBSP = "\u00A0";
-
 const STORAGE_KEY_SORT = "dashboard_sort_key";
 const STORAGE_KEY_ASC = "dashboard_sort_asc";
 const RUNS_LIMIT = 50;
@@ -54,21 +133,41 @@ const RUNS_LIMIT = 50;
 export default function DashboardPage() {
   const navigate = useNavigate();
   const queryClient = useQueryClient();
-  const { isAuthenticated } = useAuth();
-  const [scope, setScope] = useState<Scope>("my");
-  const [sortConfig, setSortConfig] = useState<SortConfig>(() => loadSortConfig());
+  const { isAuthenticated, user } = useAuth();
+  const { can } = useCapabilities();
+  const notify = useNotify();
+  const notifyRunFinished = useRunFinishedNotifier();
+  const announce = useAnnounce();
+  const prefs = useEffectivePrefs();
+  const [scope, setScope] = useState<Scope>(() => (prefs[DASHBOARD_SCOPE_PREF] === "all" ? "all" : "my"));
+  const [sortConfig, setSortConfig] = useState<SortConfig>(() => loadSortConfig(dashboardSortFromPrefs(prefs)));
+  const [searchParams, setSearchParams] = useSearchParams();
   const [expandedAgentId, setExpandedAgentId] = useState<number | null>(null);
   const dashboardQueryKey = useMemo(() => ["dashboard", scope, RUNS_LIMIT] as const, [scope]);
   const [expandedRunHistory, setExpandedRunHistory] = useState<Set<number>>(new Set());
   const [settingsAgentId, setSettingsAgentId] = useState<number | null>(null);
+  const [selectedRun, setSelectedRun] = useState<{ agentId: number; runId: number } | null>(null);
+  const [isImportOpen, setIsImportOpen] = useState(false);
+  const [sharingAgent, setSharingAgent] = useState<AgentSummary | null>(null);
+  // Only agents carrying every selected tag are listed
+  const [tagFilter, setTagFilter] = useState<string[]>([]);
+  const [usageColumns, setUsageColumns] = useState<UsageColumn[]>(loadUsageColumns);
+  // Agent awaiting delete confirmation because something still relies on it
+  const [pendingDelete, setPendingDelete] = useState<PendingDelete | null>(null);
+  const [showShortcutHelp, setShowShortcutHelp] = useState(false);
   const [editingAgentId, setEditingAgentId] = useState<number | null>(null);
   const [editingName, setEditingName] = useState<string>("");
 
   // WebSocket state - must be declared before useQuery to avoid reference errors
   const subscribedAgentIdsRef = useRef<Set<number>>(new Set());
+  // Agents unsubscribed after scrolling out of view; their rows are stale until resubscribed
+  const droppedAgentIdsRef = useRef<Set<number>>(new Set());
+  const { ref: agentsBodyRef, visibleIds: visibleAgentIds } = useVisibleRows({ attribute: "data-agent-id" });
   const [wsReconnectToken, setWsReconnectToken] = useState(0);
   const sendMessageRef = useRef<((message: any) => void) | null>(null);
   const messageIdCounterRef = useRef(0);
+  const agentDeltaSeqRef = useRef(new AgentDeltaSequence());
+  const hasConnectedRef = useRef(false);
 
   // Track pending subscriptions to handle confirmations and timeouts
   // Don't mark as subscribed until we get subscribe_ack to enable automatic retry
@@ -92,6 +191,20 @@ export default function DashboardPage() {
     [dashboardQueryKey, queryClient]
   );
 
+  // A finished run shifts that agent's aggregates; refetch just its entry
+  const refreshAgentStats = useCallback(
+    (agentId: number) => {
+      fetchAgentStats(agentId)
+        .then((stats) =>
+          applyDashboardUpdate((current) => ({ ...current, stats: withAgentStats(current.stats, stats) }))
+        )
+        .catch(() => {
+          // Stale stats are harmless; the next snapshot fetch corrects them
+        });
+    },
+    [applyDashboardUpdate]
+  );
+
   // WebSocket message handler must be defined before useWebSocket hook
   const handleWebSocketMessage = useCallback(
     (message: WebSocketMessage | { type: string; topic?: string; data?: any; message_id?: string }) => {
@@ -109,9 +222,14 @@ export default function DashboardPage() {
             pendingSubscriptionsRef.current.delete(messageId);
 
             if (message.type === "subscribe_ack") {
+              let missedEvents = false;
               pending.agentIds.forEach((agentId) => {
                 subscribedAgentIdsRef.current.add(agentId);
+                missedEvents = droppedAgentIdsRef.current.delete(agentId) || missedEvents;
               });
+              if (missedEvents) {
+                queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+              }
             } else {
               console.error("[WS] Subscription failed for topics:", pending.topics);
               setWsReconnectToken((token) => token + 1);
@@ -122,6 +240,24 @@ export default function DashboardPage() {
       }
 
       const topic = typeof message.topic === "string" ? message.topic : "";
+
+      // Agent list deltas arrive on the personal topic and patch every cached scope except
+      // "shared", which never contains the user's own agents
+      if (message.type === "agent_event" && topic.startsWith("user:")) {
+        const delta = parseAgentDelta(message.data);
+        if (!delta) {
+          return;
+        }
+        if (!agentDeltaSeqRef.current.accept(delta.seq)) {
+          queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+          return;
+        }
+        queryClient.setQueriesData<DashboardSnapshot>({ queryKey: ["dashboard"] }, (current) =>
+          current && current.scope !== "shared" ? applyAgentDelta(current, delta) : current
+        );
+        return;
+      }
+
       if (!topic.startsWith("agent:")) {
         return;
       }
@@ -189,6 +325,25 @@ export default function DashboardPage() {
         return;
       }
 
+      if (eventType === "run_queue_update") {
+        if (!Array.isArray(dataPayload.queue)) {
+          return;
+        }
+        const update = { queue: dataPayload.queue as RunQueueUpdateData["queue"] };
+        applyDashboardUpdate((current) => {
+          let changed = false;
+          const runs = current.runs.map((bundle) => {
+            if (bundle.agentId !== agentId) return bundle;
+            const nextRuns = applyQueueUpdate(bundle.runs, update);
+            if (nextRuns === bundle.runs) return bundle;
+            changed = true;
+            return { ...bundle, runs: nextRuns };
+          });
+          return changed ? { ...current, runs } : current;
+        });
+        return;
+      }
+
       if (eventType === "run_update") {
         const runIdCandidate = dataPayload.id ?? dataPayload.run_id;
         const runId = typeof runIdCandidate === "number" ? runIdCandidate : null;
@@ -199,6 +354,32 @@ export default function DashboardPage() {
         const threadId =
           typeof dataPayload.thread_id === "number" ? (dataPayload.thread_id as number) : undefined;
 
+        if (dataPayload.status === "success" || dataPayload.status === "failed") {
+          const snapshot = queryClient.getQueryData<DashboardSnapshot>(dashboardQueryKey);
+          const agentName = snapshot?.agents.find((agent) => agent.id === agentId)?.name ?? `Agent ${agentId}`;
+          const runError = typeof dataPayload.error === "string" && dataPayload.error ? dataPayload.error : null;
+          notifyRunFinished({ runId, agentId, agentName, status: dataPayload.status, error: runError, threadId });
+          queryClient.invalidateQueries({ queryKey: ["dashboard-usage"] });
+          // Spend is only computed server-side; refresh so budget meters stay current
+          if (snapshot?.budgets.some((budget) => budget.agent_id === agentId)) {
+            queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+          } else {
+            refreshAgentStats(agentId);
+          }
+          announce(runFinishedAnnouncement({ id: agentId, name: agentName }, runId, dataPayload.status));
+          if (dataPayload.status === "failed") {
+            notify({
+              id: `run-${runId}-failed`,
+              kind: "error",
+              source: "run",
+              message: `${agentName} run #${runId} failed${runError ? `: ${runError}` : ""}`,
+              href: threadId !== undefined ? `/agent/${agentId}/thread/${threadId}` : undefined,
+            });
+          }
+        } else if (dataPayload.status === "cancelled") {
+          refreshAgentStats(agentId);
+        }
+
         applyDashboardUpdate((current) => {
           const runsBundles = current.runs.slice();
           let bundleIndex = runsBundles.findIndex((bundle) => bundle.agentId === agentId);
@@ -216,14 +397,11 @@ export default function DashboardPage() {
           let nextRuns = existingRuns;
 
           if (existingIndex === -1) {
-            if (threadId === undefined) {
-              return current;
-            }
-
+            // Queued runs have no thread until they start
             const newRun: AgentRun = {
               id: runId,
               agent_id: agentId,
-              thread_id: threadId,
+              thread_id: threadId ?? null,
               status:
                 typeof dataPayload.status === "string"
                   ? (dataPayload.status as AgentRun["status"])
@@ -242,6 +420,7 @@ export default function DashboardPage() {
                 dataPayload.error === undefined
                   ? null
                   : (dataPayload.error as string | null) ?? null,
+              trace_id: typeof dataPayload.trace_id === "string" ? (dataPayload.trace_id as string) : null,
             };
 
             nextRuns = [newRun, ...existingRuns];
@@ -253,6 +432,7 @@ export default function DashboardPage() {
             const previousRun = existingRuns[existingIndex];
             const updatedRun: AgentRun = {
               ...previousRun,
+              thread_id: threadId ?? previousRun.thread_id,
               status:
                 typeof dataPayload.status === "string"
                   ? (dataPayload.status as AgentRun["status"])
@@ -281,6 +461,10 @@ export default function DashboardPage() {
                 dataPayload.error === undefined
                   ? previousRun.error
                   : ((dataPayload.error as string | null) ?? null),
+              trace_id:
+                typeof dataPayload.trace_id === "string"
+                  ? (dataPayload.trace_id as string)
+                  : previousRun.trace_id,
             };
 
             const hasRunDiff =
@@ -290,7 +474,8 @@ export default function DashboardPage() {
               updatedRun.duration_ms !== previousRun.duration_ms ||
               updatedRun.total_tokens !== previousRun.total_tokens ||
               updatedRun.total_cost_usd !== previousRun.total_cost_usd ||
-              updatedRun.error !== previousRun.error;
+              updatedRun.error !== previousRun.error ||
+              updatedRun.trace_id !== previousRun.trace_id;
 
             if (hasRunDiff) {
               nextRuns = [...existingRuns];
@@ -312,8 +497,9 @@ export default function DashboardPage() {
               return agent;
             }
 
+            // A queued run waits behind the active one, and a cancelling run is still running
             const statusValue =
-              typeof dataPayload.status === "string"
+              typeof dataPayload.status === "string" && !["queued", "cancelling"].includes(dataPayload.status)
                 ? (dataPayload.status as AgentSummary["status"])
                 : agent.status;
             const lastRunValue =
@@ -343,13 +529,20 @@ export default function DashboardPage() {
         });
       }
     },
-    [applyDashboardUpdate]
+    [announce, applyDashboardUpdate, dashboardQueryKey, notify, notifyRunFinished, queryClient, refreshAgentStats]
   );
 
   const { connectionStatus, sendMessage } = useWebSocket(isAuthenticated, {
     onMessage: handleWebSocketMessage,
     onConnect: () => {
       subscribedAgentIdsRef.current.clear();
+      droppedAgentIdsRef.current.clear();
+      // Deltas sent while we were disconnected are gone; refetch once and start a new sequence
+      agentDeltaSeqRef.current.reset();
+      if (hasConnectedRef.current) {
+        queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+      }
+      hasConnectedRef.current = true;
       // Clear any pending subscriptions from previous connection
       pendingSubscriptionsRef.current.forEach((pending) => {
         clearTimeout(pending.timeoutId);
@@ -359,6 +552,13 @@ export default function DashboardPage() {
     },
   });
 
+  // With a live socket the agent_event delta updates the list; otherwise refetch
+  const refreshAgentsUnlessLive = () => {
+    if (connectionStatus !== ConnectionStatus.CONNECTED) {
+      queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+    }
+  };
+
   const {
     data: modelsData,
   } = useQuery<ModelConfig[]>({
@@ -367,9 +567,12 @@ export default function DashboardPage() {
     staleTime: 1000 * 60 * 60, // 1 hour
   });
 
+  // The user's (or workspace's) preferred model while it is still offered
+  const preferredModel = prefs[DEFAULT_MODEL_PREF];
   const defaultModel = useMemo(() => {
-    return modelsData?.find((m) => m.is_default)?.id || "gpt-5.1";
-  }, [modelsData]);
+    const preferred = modelsData?.find((m) => m.id === preferredModel);
+    return preferred?.id || modelsData?.find((m) => m.is_default)?.id || "gpt-5.1";
+  }, [modelsData, preferredModel]);
 
   const {
     data: dashboardData,
@@ -383,6 +586,41 @@ export default function DashboardPage() {
   });
 
   const agents: AgentSummary[] = useMemo(() => dashboardData?.agents ?? [], [dashboardData]);
+  const budgetsByAgent = useMemo(
+    () => new Map((dashboardData?.budgets ?? []).map((budget) => [budget.agent_id, budget])),
+    [dashboardData]
+  );
+  const statsByAgent = useMemo(
+    () => new Map((dashboardData?.stats ?? []).map((stats) => [stats.agent_id, stats])),
+    [dashboardData]
+  );
+
+  // Only fetched while a usage column is shown
+  const { data: usageData } = useQuery({
+    queryKey: ["dashboard-usage", scope],
+    queryFn: () => fetchAgentUsage(scope, USAGE_DAYS),
+    enabled: usageColumns.length > 0,
+    staleTime: 60000,
+  });
+  const usageByAgent = useMemo(
+    () => new Map((usageData ?? []).map((usage) => [usage.agent_id, usage])),
+    [usageData]
+  );
+
+  // Announce Running/Error transitions; agents seen for the first time (initial
+  // load, scope switch) are not announced
+  const previousStatuses = useRef(new Map<number, AgentSummary["status"]>());
+  useEffect(() => {
+    const seen = previousStatuses.current;
+    for (const agent of agents) {
+      const previous = seen.get(agent.id);
+      if (previous !== undefined) {
+        const announcement = agentStatusAnnouncement(agent, previous, agent.status);
+        if (announcement) announce(announcement);
+      }
+      seen.set(agent.id, agent.status);
+    }
+  }, [agents, announce]);
 
   const runsByAgent: AgentRunsState = useMemo(() => {
     if (!dashboardData) {
@@ -405,6 +643,16 @@ export default function DashboardPage() {
 
   const runsDataLoading = isLoading && !dashboardData;
 
+  // Derived from the cached runs so the drawer follows live run_update events
+  const selectedRunDetails = useMemo(() => {
+    if (!selectedRun) return null;
+    const run = runsByAgent[selectedRun.agentId]?.find((candidate) => candidate.id === selectedRun.runId);
+    const agent = agents.find((candidate) => candidate.id === selectedRun.agentId);
+    return run ? { run, agentName: agent?.name ?? "" } : null;
+  }, [selectedRun, runsByAgent, agents]);
+  const closeRunDetails = useCallback(() => setSelectedRun(null), []);
+  const closeImportWizard = useCallback(() => setIsImportOpen(false), []);
+
   // Keep sendMessage ref up-to-date for stable cleanup
   useEffect(() => {
     sendMessageRef.current = sendMessage;
@@ -438,36 +686,72 @@ export default function DashboardPage() {
         queryClient.setQueryData(dashboardQueryKey, context.previousSnapshot);
       }
       console.error("Failed to run agent:", err);
+      // The backend answers 429 once a daily budget is used up
+      if (isApiError(err, "rate_limited")) {
+        notify({
+          kind: "error",
+          source: "budget",
+          message: typeof err.detail === "string" ? err.detail : "Run refused: daily budget exhausted",
+        });
+      }
     },
     onSettled: (_, __, agentId) => {
       dispatchDashboardEvent("run", agentId);
     },
   });
 
-  useEffect(() => {
-    // Persist sort preferences whenever they change.
-    persistSortConfig(sortConfig);
-  }, [sortConfig]);
-
   useEffect(() => {
     if (!error) {
       return;
     }
 
-    if (error instanceof Error && error.message.includes("(403)")) {
+    if (isApiError(error, "forbidden")) {
       setScope("my");
     }
   }, [error]);
 
+  // Hover card "Open" links land here with ?agent=<id>; expand that row once loaded.
+  // Ops ticker links may add &run=<id> to open that run's details as well, and the
+  // quick switcher &settings=1 to open the agent's settings drawer.
+  const requestedAgentParam = searchParams.get("agent");
+  const requestedRunParam = searchParams.get("run");
+  const requestedSettingsParam = searchParams.get("settings");
+  useEffect(() => {
+    if (!requestedAgentParam || !dashboardData) {
+      return;
+    }
+    const requestedId = Number(requestedAgentParam);
+    if (agents.some((agent) => agent.id === requestedId)) {
+      setExpandedAgentId(requestedId);
+      const requestedRunId = Number(requestedRunParam);
+      if (requestedRunParam && Number.isInteger(requestedRunId)) {
+        setSelectedRun({ agentId: requestedId, runId: requestedRunId });
+      }
+      if (requestedSettingsParam === "1") {
+        setSettingsAgentId(requestedId);
+      }
+    }
+    setSearchParams(
+      (prev) => {
+        const next = new URLSearchParams(prev);
+        next.delete("agent");
+        next.delete("run");
+        next.delete("settings");
+        return next;
+      },
+      { replace: true }
+    );
+  }, [agents, dashboardData, requestedAgentParam, requestedRunParam, requestedSettingsParam, setSearchParams]);
+
   useEffect(() => {
-    if (expandedAgentId === null) {
+    if (expandedAgentId === null || !dashboardData) {
       return;
     }
     if (agents.some((agent) => agent.id === expandedAgentId)) {
       return;
     }
     setExpandedAgentId(null);
-  }, [agents, expandedAgentId]);
+  }, [agents, dashboardData, expandedAgentId]);
 
   // Use unified WebSocket hook for real-time updates
   // Only connect when authenticated to avoid auth failure spam
@@ -479,7 +763,8 @@ export default function DashboardPage() {
       return;
     }
 
-    const activeIds = new Set(agents.map((agent) => agent.id));
+    // Only rows on screen (plus busy and expanded agents) get a per-agent topic
+    const wantedIds = wantedAgentIds(agents, { visibleIds: visibleAgentIds, expandedAgentId, runsByAgent });
 
     // Find agents that need subscription (not currently subscribed AND not pending)
     const pendingAgentIds = new Set<number>();
@@ -487,22 +772,17 @@ export default function DashboardPage() {
       pending.agentIds.forEach((id) => pendingAgentIds.add(id));
     });
 
-    const topicsToSubscribe: string[] = [];
-    const agentIdsToSubscribe: number[] = [];
-    for (const id of activeIds) {
-      if (!subscribedAgentIdsRef.current.has(id) && !pendingAgentIds.has(id)) {
-        topicsToSubscribe.push(`agent:${id}`);
-        agentIdsToSubscribe.push(id);
-      }
-    }
+    const changes = agentTopicChanges(wantedIds, subscribedAgentIdsRef.current, pendingAgentIds);
+    const agentIdsToSubscribe = changes.subscribe;
+    const topicsToSubscribe = agentIdsToSubscribe.map(agentTopic);
 
-    const topicsToUnsubscribe: string[] = [];
-    for (const id of Array.from(subscribedAgentIdsRef.current)) {
-      if (!activeIds.has(id)) {
-        subscribedAgentIdsRef.current.delete(id);
-        topicsToUnsubscribe.push(`agent:${id}`);
-      }
-    }
+    const knownIds = new Set(agents.map((agent) => agent.id));
+    changes.unsubscribe.forEach((id) => {
+      subscribedAgentIdsRef.current.delete(id);
+      // Still listed, just off screen: it will need a refresh when it comes back
+      if (knownIds.has(id)) droppedAgentIdsRef.current.add(id);
+    });
+    const topicsToUnsubscribe = changes.unsubscribe.map(agentTopic);
 
     if (topicsToSubscribe.length > 0) {
       const messageId = generateMessageId();
@@ -539,7 +819,16 @@ export default function DashboardPage() {
         message_id: generateMessageId(),
       });
     }
-  }, [agents, connectionStatus, isAuthenticated, wsReconnectToken, generateMessageId]);
+  }, [
+    agents,
+    connectionStatus,
+    expandedAgentId,
+    isAuthenticated,
+    runsByAgent,
+    visibleAgentIds,
+    wsReconnectToken,
+    generateMessageId,
+  ]);
 
   useEffect(() => {
     if (isAuthenticated) {
@@ -614,26 +903,223 @@ export default function DashboardPage() {
     },
     onSuccess: () => {
       // WebSocket will deliver the agent with real name
-      queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+      refreshAgentsUnlessLive();
       idempotencyKeyRef.current = null; // Reset for next creation
     },
   });
 
-  // Delete agent mutation
+  // Delete moves the agent to the trash; the row disappears immediately and
+  // the toast offers an undo until it expires, the notification center after
   const deleteAgentMutation = useMutation({
-    mutationFn: async (agentId: number) => {
-      const response = await fetch(buildUrl(`/agents/${agentId}`), {
-        method: "DELETE",
-        credentials: 'include', // Cookie auth
+    mutationFn: ({ agent, disableDependents }: { agent: AgentSummary; disableDependents: boolean }) =>
+      disableDependents ? deleteAgent(agent.id, { disableDependents }) : deleteAgent(agent.id),
+    onMutate: async ({ agent }) => {
+      await queryClient.cancelQueries({ queryKey: dashboardQueryKey });
+      const previous = queryClient.getQueryData<DashboardSnapshot>(dashboardQueryKey);
+      queryClient.setQueryData<DashboardSnapshot>(dashboardQueryKey, (current) =>
+        current ? removeAgentFromSnapshot(current, agent.id) : current
+      );
+      return { previous };
+    },
+    onError: (error: Error, _agent, context) => {
+      if (context?.previous) {
+        queryClient.setQueryData(dashboardQueryKey, context.previous);
+      }
+      toast.error(`Failed to delete agent: ${error.message}`, { duration: 6000 });
+    },
+    onSuccess: (_data, { agent, disableDependents }) => {
+      queryClient.invalidateQueries({ queryKey: ["agents-trash"] });
+      notify({
+        kind: "info",
+        source: "trash",
+        message: disableDependents
+          ? `Moved ${agent.name} to trash and disabled its triggers`
+          : `Moved ${agent.name} to trash`,
+        undo: { kind: "restore-agent", id: agent.id },
       });
-      if (!response.ok) throw new Error("Delete failed");
-      return response;
+      toast(
+        (t) => (
+          <span className="undo-toast">
+            Moved {agent.name} to trash
+            <button
+              type="button"
+              className="undo-toast-button"
+              onClick={() => {
+                toast.dismiss(t.id);
+                restoreAgentMutation.mutate(agent);
+              }}
+            >
+              Undo
+            </button>
+          </span>
+        ),
+        { duration: 8000 }
+      );
+    },
+    onSettled: () => {
+      refreshAgentsUnlessLive();
     },
+  });
+
+  const restoreAgentMutation = useMutation({
+    mutationFn: (agent: AgentSummary) => restoreAgent(agent.id),
     onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["dashboard"] });
+      queryClient.invalidateQueries({ queryKey: ["agents-trash"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to restore agent: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const { data: systemInfo } = useQuery({
+    queryKey: ["system-info"],
+    queryFn: fetchSystemInfo,
+    staleTime: 60_000,
+  });
+
+  const togglePauseMutation = useMutation({
+    mutationFn: (agent: AgentSummary) => (isAgentPaused(agent) ? resumeAgent(agent.id) : pauseAgent(agent.id)),
+    onSuccess: (updated) => {
+      queryClient.setQueryData<DashboardSnapshot>(dashboardQueryKey, (current) =>
+        current
+          ? {
+              ...current,
+              agents: current.agents.map((agent) =>
+                agent.id === updated.id ? { ...agent, paused_at: updated.paused_at } : agent
+              ),
+            }
+          : current
+      );
+      toast.success(isAgentPaused(updated) ? `Paused ${updated.name}` : `Resumed ${updated.name}`);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to update agent: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  // Optimistically shows "cancelling"; the cancelled run_update settles it
+  const cancelRunMutation = useMutation({
+    mutationFn: ({ runId }: { agentId: number; runId: number }) => cancelRun(runId),
+    onMutate: async ({ agentId, runId }) => {
+      await queryClient.cancelQueries({ queryKey: dashboardQueryKey });
+
+      const previousSnapshot = queryClient.getQueryData<DashboardSnapshot>(dashboardQueryKey);
+
+      queryClient.setQueryData<DashboardSnapshot>(dashboardQueryKey, (current) =>
+        current
+          ? {
+              ...current,
+              runs: current.runs.map((bundle) =>
+                bundle.agentId === agentId ? { ...bundle, runs: markRunCancelling(bundle.runs, runId) } : bundle
+              ),
+            }
+          : current
+      );
+
+      return { previousSnapshot };
+    },
+    onSuccess: (run) => {
+      applyDashboardUpdate((current) => ({
+        ...current,
+        runs: current.runs.map((bundle) =>
+          bundle.agentId === run.agent_id
+            ? {
+                ...bundle,
+                // A cancelled run_update may already have landed; don't roll it back
+                runs: bundle.runs.map((candidate) =>
+                  candidate.id === run.id && candidate.status === "cancelling" ? run : candidate
+                ),
+              }
+            : bundle
+        ),
+        // Runs without a live task are cancelled on the spot and the agent is freed
+        agents:
+          run.status === "cancelled"
+            ? current.agents.map((agent) =>
+                agent.id === run.agent_id && agent.status === "running" ? { ...agent, status: "idle" as const } : agent
+              )
+            : current.agents,
+      }));
+      toast.success(run.status === "cancelled" ? `Run #${run.id} cancelled` : `Cancelling run #${run.id}…`);
+    },
+    onError: (error: Error, _variables, context) => {
+      if (context?.previousSnapshot) {
+        queryClient.setQueryData(dashboardQueryKey, context.previousSnapshot);
+      }
+      toast.error(`Failed to cancel run: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  // Queue edits apply optimistically; the run_queue_update broadcast confirms them
+  const reorderQueueMutation = useMutation({
+    mutationFn: ({ agentId, runIds }: { agentId: number; runIds: number[] }) => reorderRunQueue(agentId, runIds),
+    onMutate: async ({ agentId, runIds }) => {
+      await queryClient.cancelQueries({ queryKey: dashboardQueryKey });
+
+      const previousSnapshot = queryClient.getQueryData<DashboardSnapshot>(dashboardQueryKey);
+      const update = { queue: runIds.map((id, index) => ({ id, position: index + 1 })) };
+
+      applyDashboardUpdate((current) => ({
+        ...current,
+        runs: current.runs.map((bundle) =>
+          bundle.agentId === agentId ? { ...bundle, runs: applyQueueUpdate(bundle.runs, update) } : bundle
+        ),
+      }));
+
+      return { previousSnapshot };
+    },
+    onError: (error: Error, _variables, context) => {
+      if (context?.previousSnapshot) {
+        queryClient.setQueryData(dashboardQueryKey, context.previousSnapshot);
+      }
+      // 409 means the queue moved on (a run started or was dropped) since it was rendered
+      toast.error(`Failed to reorder queue: ${error.message}`, { duration: 6000 });
       queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
     },
   });
 
+  const dropQueuedRunMutation = useMutation({
+    mutationFn: ({ agentId, runId }: { agentId: number; runId: number }) => dropQueuedRun(agentId, runId),
+    onSuccess: (run) => {
+      applyDashboardUpdate((current) => ({
+        ...current,
+        runs: current.runs.map((bundle) =>
+          bundle.agentId === run.agent_id
+            ? { ...bundle, runs: bundle.runs.map((candidate) => (candidate.id === run.id ? run : candidate)) }
+            : bundle
+        ),
+      }));
+      toast.success(`Dropped run #${run.id} from the queue`);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to drop queued run: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const duplicateAgentMutation = useMutation({
+    mutationFn: (agent: AgentSummary) => duplicateAgent(agent.id),
+    onSuccess: (copy) => {
+      refreshAgentsUnlessLive();
+      toast.success(`Created ${copy.name}`);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to duplicate agent: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  async function handleExportAgent(event: ReactMouseEvent<HTMLButtonElement>, agent: AgentSummary) {
+    event.stopPropagation();
+    try {
+      const agentExport = await exportAgent(agent.id);
+      const slug = agent.name.toLowerCase().replace(/[^a-z0-9]+/g, "-").replace(/^-|-$/g, "") || `agent-${agent.id}`;
+      downloadTextFile(`${slug}.agent.json`, JSON.stringify(agentExport, null, 2), "application/json");
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to export agent: ${message}`, { duration: 6000 });
+    }
+  }
+
   // Inline name editing handlers
   function startEditingName(agentId: number, currentName: string) {
     setEditingAgentId(agentId);
@@ -648,7 +1134,7 @@ export default function DashboardPage() {
 
     try {
       await updateAgent(agentId, { name: editingName });
-      queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+      refreshAgentsUnlessLive();
     } catch (error) {
       console.error("Failed to rename:", error);
     }
@@ -662,14 +1148,43 @@ export default function DashboardPage() {
     setEditingName("");
   }
 
+  const agentTags = useMemo(() => collectTags(agents), [agents]);
+  // A tag that no listed agent carries any more (renamed, scope switched) stops filtering
+  const activeTagFilter = useMemo(() => tagFilter.filter((tag) => hasTag(agentTags, tag)), [tagFilter, agentTags]);
+
   const sortedRows: LegacyAgentRow[] = useMemo(() => {
-    return sortAgents(agents, runsByAgent, sortConfig).map((agent) => ({
-      agent,
-      createdDisplay: formatDateTimeShort(agent.created_at ?? null),
-      lastRunDisplay: formatDateTimeShort(agent.last_run_at ?? null),
-      nextRunDisplay: formatDateTimeShort(agent.next_run_at ?? null),
-    }));
-  }, [agents, runsByAgent, sortConfig]);
+    const tagged = agents.filter((agent) => matchesTags(agent, activeTagFilter));
+    return sortAgents(tagged, runsByAgent, statsByAgent, usageByAgent, sortConfig).map((agent) => ({ agent }));
+  }, [agents, activeTagFilter, runsByAgent, statsByAgent, usageByAgent, sortConfig]);
+
+  const toggleTagFilter = (tag: string) =>
+    setTagFilter((current) => (hasTag(current, tag) ? removeTag(current, tag) : [...current, tag]));
+
+  const shortcutBindings = useShortcutBindings();
+  // Page shortcuts stay quiet while a drawer or dialog owns the keyboard
+  const overlayOpen =
+    settingsAgentId !== null ||
+    selectedRun !== null ||
+    isImportOpen ||
+    sharingAgent !== null ||
+    pendingDelete !== null ||
+    showShortcutHelp;
+  useShortcuts(
+    "dashboard",
+    {
+      "dashboard.createAgent": () => {
+        if (!createAgentMutation.isPending) createAgentMutation.mutate();
+      },
+      "dashboard.runAgent": () => {
+        const row = document.activeElement?.closest<HTMLElement>("tr[data-agent-id]");
+        const agent = agents.find((candidate) => String(candidate.id) === row?.dataset.agentId);
+        if (!agent || agent.status === "running" || blockForBudget(agent.id)) return;
+        if (confirmRunWhilePaused([agent])) runAgentMutation.mutate(agent.id);
+      },
+      "dashboard.showHelp": () => setShowShortcutHelp(true),
+    },
+    !overlayOpen
+  );
 
   if (isLoading) {
     return (
@@ -693,31 +1208,72 @@ export default function DashboardPage() {
   }
 
   const includeOwner = scope === "all";
-  const emptyColspan = includeOwner ? 8 : 7;
+  const sharedPermissions = sharedPermissionMap(dashboardData?.permissions);
+  const accessFor = (agent: AgentSummary) =>
+    agentAccess(agent, user?.id, sharedPermissions, can(Capability.ManageAnyAgent));
+  const emptyColspan = (includeOwner ? 8 : 7) + usageColumns.length;
 
   return (
     <div id="dashboard-container" className="dashboard-container">
       <div id="dashboard" className="dashboard">
         <div className="dashboard-header">
-          <div className="scope-wrapper">
-            <span className="scope-text-label" id="scope-text">
-              {scope === "all" ? "All agents" : "My agents"}
-            </span>
-            <label className="scope-toggle">
-              <input
-                type="checkbox"
-                id="dashboard-scope-toggle"
-                data-testid="dashboard-scope-toggle"
-                checked={scope === "all"}
-                onChange={(e) => {
-                  const newScope = e.target.checked ? "all" : "my";
-                  setScope(newScope);
-                }}
-              />
-              <span className="slider"></span>
-            </label>
-          </div>
+          <button
+            type="button"
+            className={`shared-filter-button${scope === "shared" ? " active" : ""}`}
+            data-testid="dashboard-shared-filter"
+            aria-pressed={scope === "shared"}
+            onClick={() => setScope(scope === "shared" ? "my" : "shared")}
+          >
+            Shared with me
+          </button>
+          {can(Capability.ViewAllAgents) && scope !== "shared" && (
+            <div className="scope-wrapper">
+              <span className="scope-text-label" id="scope-text">
+                {scope === "all" ? "All agents" : "My agents"}
+              </span>
+              <label className="scope-toggle">
+                <input
+                  type="checkbox"
+                  id="dashboard-scope-toggle"
+                  data-testid="dashboard-scope-toggle"
+                  checked={scope === "all"}
+                  onChange={(e) => {
+                    const newScope = e.target.checked ? "all" : "my";
+                    setScope(newScope);
+                  }}
+                />
+                <span className="slider"></span>
+              </label>
+            </div>
+          )}
           <div className="button-container">
+            <details className="dashboard-columns-menu" data-testid="dashboard-columns-menu">
+              <summary>Columns</summary>
+              <div className="dashboard-columns-options">
+                {USAGE_COLUMNS.map((column) => (
+                  <label key={column}>
+                    <input
+                      type="checkbox"
+                      checked={usageColumns.includes(column)}
+                      onChange={() => handleToggleUsageColumn(column)}
+                      data-testid={`dashboard-column-${column}`}
+                    />
+                    {USAGE_COLUMN_LABELS[column]}
+                  </label>
+                ))}
+              </div>
+            </details>
+            <Link to="/trash" className="trash-link" data-testid="dashboard-trash-link">
+              <TrashIcon /> Trash
+            </Link>
+            <button
+              type="button"
+              className="import-agent-button"
+              data-testid="import-agent-btn"
+              onClick={() => setIsImportOpen(true)}
+            >
+              Import Agent
+            </button>
             <button
               id="create-agent-button"
               type="button"
@@ -731,6 +1287,34 @@ export default function DashboardPage() {
           </div>
         </div>
 
+        {systemInfo?.maintenance_mode && (
+          <div className="maintenance-banner" role="status" data-testid="maintenance-banner">
+            {MAINTENANCE_BANNER_TEXT}
+          </div>
+        )}
+
+        {agentTags.length > 0 && (
+          <div className="tag-filter" role="group" aria-label="Filter agents by tag" data-testid="dashboard-tag-filter">
+            <span className="tag-filter-label">Tags</span>
+            {agentTags.map((tag) => (
+              <button
+                key={tag}
+                type="button"
+                className={`tag-chip tag-filter-chip${hasTag(activeTagFilter, tag) ? " active" : ""}`}
+                aria-pressed={hasTag(activeTagFilter, tag)}
+                onClick={() => toggleTagFilter(tag)}
+              >
+                {tag}
+              </button>
+            ))}
+            {activeTagFilter.length > 0 && (
+              <button type="button" className="btn-tertiary" onClick={() => setTagFilter([])}>
+                Clear
+              </button>
+            )}
+          </div>
+        )}
+
         <table id="agents-table" className="agents-table">
           <thead>
             <tr>
@@ -741,6 +1325,11 @@ export default function DashboardPage() {
               {renderHeaderCell("Last Run", "last_run", sortConfig, handleSort)}
               {renderHeaderCell("Next Run", "next_run", sortConfig, handleSort)}
               {renderHeaderCell("Success Rate", "success", sortConfig, handleSort)}
+              {usageColumns.map((column) => (
+                <Fragment key={column}>
+                  {renderHeaderCell(USAGE_COLUMN_LABELS[column], column, sortConfig, handleSort)}
+                </Fragment>
+              ))}
               <th
                 scope="col"
                 className="actions-header"
@@ -753,16 +1342,34 @@ export default function DashboardPage() {
               </th>
             </tr>
           </thead>
-          <tbody id="agents-table-body">
-            {sortedRows.map(({ agent, createdDisplay, lastRunDisplay, nextRunDisplay }) => {
+          <tbody id="agents-table-body" ref={agentsBodyRef}>
+            {sortedRows.map(({ agent }) => {
               const runs = runsByAgent[agent.id];
               const isExpanded = expandedAgentId === agent.id;
               const isRunHistoryExpanded = expandedRunHistory.has(agent.id);
-              const successStats = computeSuccessStats(runs);
+              const agentStats = statsByAgent.get(agent.id);
+              const agentUsage = usageByAgent.get(agent.id);
+              const successSummary = agentStats ? summarizeStats(agentStats) : summarizeRuns(runs);
               const lastRunIndicator = determineLastRunIndicator(runs);
               const isRunning = agent.status === "running";
+              const isPaused = isAgentPaused(agent);
+              const cancellableRun = isRunning ? activeRun(runs) : null;
+              const isCancelling = runs?.some((run) => run.status === "cancelling") ?? false;
+              const queue = queuedRuns(runs);
+              const queueIds = queue.map((run) => run.id);
               // Check if this specific agent is being mutated
               const isPendingRun = runAgentMutation.isPending && runAgentMutation.variables === agent.id;
+              const budget = budgetsByAgent.get(agent.id);
+              const budgetBlocked = isBudgetExhausted(budget);
+              const runLooksDisabled = isRunning || isPendingRun || budgetBlocked;
+              const access = accessFor(agent);
+              const isOwner = accessAllows(access, "owner");
+              const canEdit = accessAllows(access, "edit");
+              const runLabel = isRunning
+                ? "Agent is already running"
+                : budgetBlocked && budget
+                  ? budgetExhaustedMessage(budget)
+                  : "Run Agent";
 
               return (
                 <Fragment key={agent.id}>
@@ -796,12 +1403,28 @@ export default function DashboardPage() {
                           autoFocus
                         />
                       ) : (
-                        <span
-                          className="editable-name"
-                          onClick={() => startEditingName(agent.id, agent.name)}
-                          title="Click to rename"
+                        <AgentHoverCard
+                          agentId={agent.id}
+                          agentName={agent.name}
+                          onOpen={(agentId) => setExpandedAgentId(agentId)}
                         >
-                          {agent.name}
+                          <span
+                            className="editable-name"
+                            onClick={() => canEdit && startEditingName(agent.id, agent.name)}
+                            title={canEdit ? "Click to rename" : undefined}
+                          >
+                            {agent.name}
+                          </span>
+                        </AgentHoverCard>
+                      )}
+                      {budget && <BudgetMeter status={budget} />}
+                      {agent.tags && agent.tags.length > 0 && (
+                        <span className="tag-list" data-testid={`agent-tags-${agent.id}`}>
+                          {agent.tags.map((tag) => (
+                            <span key={tag} className="tag-chip">
+                              {tag}
+                            </span>
+                          ))}
                         </span>
                       )}
                     </td>
@@ -814,6 +1437,24 @@ export default function DashboardPage() {
                       <span className={`status-indicator status-${agent.status.toLowerCase()}`}>
                         {formatStatus(agent.status)}
                       </span>
+                      {isPaused && (
+                        <span
+                          className="paused-badge"
+                          title={PAUSED_BADGE_TITLE}
+                          data-testid={`paused-badge-${agent.id}`}
+                        >
+                          Paused
+                        </span>
+                      )}
+                      {queue.length > 0 && (
+                        <span
+                          className="queue-badge"
+                          title={queueBadgeLabel(queue.length)}
+                          data-testid={`queue-badge-${agent.id}`}
+                        >
+                          {queue.length} queued
+                        </span>
+                      )}
                       {agent.last_error && agent.last_error.trim() && (
                         <span className="info-icon" title={agent.last_error}>
                           ℹ
@@ -827,33 +1468,93 @@ export default function DashboardPage() {
                         </span>
                       )}
                     </td>
-                    <td data-label="Created">{createdDisplay}</td>
-                    <td data-label="Last Run">{lastRunDisplay}</td>
-                    <td data-label="Next Run">{nextRunDisplay}</td>
-                    <td data-label="Success Rate">{successStats.display}</td>
+                    <td data-label="Created">
+                      <Timestamp value={agent.created_at} />
+                    </td>
+                    <td data-label="Last Run">
+                      <Timestamp value={agent.last_run_at} />
+                    </td>
+                    <td data-label="Next Run">
+                      <Timestamp value={agent.next_run_at} />
+                    </td>
+                    <td data-label="Success Rate">
+                      <AgentStatsCell summary={successSummary} stats={agentStats} />
+                    </td>
+                    {usageColumns.includes("tokens") && (
+                      <td
+                        data-label={USAGE_COLUMN_LABELS.tokens}
+                        className="usage-cell"
+                        data-testid={`agent-tokens-${agent.id}`}
+                      >
+                        {usageData ? formatTokenCount(agentUsage?.total_tokens ?? 0) : "…"}
+                      </td>
+                    )}
+                    {usageColumns.includes("cost") && (
+                      <td
+                        data-label={USAGE_COLUMN_LABELS.cost}
+                        className="usage-cell"
+                        title={costBreakdownTitle(agentUsage)}
+                        data-testid={`agent-cost-${agent.id}`}
+                      >
+                        {usageData ? formatUsageCost(agentUsage?.total_cost_usd) : "…"}
+                      </td>
+                    )}
                     <td className="actions-cell" data-label="Actions">
                       <div className="actions-cell-inner">
-                        <button
-                          type="button"
-                          className={`action-btn run-btn${isRunning || isPendingRun ? " disabled" : ""}`}
-                          data-testid={`run-agent-${agent.id}`}
-                          disabled={isRunning || isPendingRun}
-                          title={isRunning ? "Agent is already running" : "Run Agent"}
-                          aria-label={isRunning ? "Agent is already running" : "Run Agent"}
-                          onClick={(event) => handleRunAgent(event, agent.id, agent.status)}
-                        >
-                          <PlayIcon />
-                        </button>
-                        <button
-                          type="button"
-                          className="action-btn chat-btn"
-                          data-testid={`chat-agent-${agent.id}`}
-                          title="Chat with Agent"
-                          aria-label="Chat with Agent"
-                          onClick={(event) => handleChatAgent(event, agent.id, agent.name)}
-                        >
-                          <MessageCircleIcon />
-                        </button>
+                        {accessAllows(access, "run") && (
+                          <button
+                            type="button"
+                            className={`action-btn run-btn${runLooksDisabled ? " disabled" : ""}`}
+                            data-testid={`run-agent-${agent.id}`}
+                            disabled={isRunning || isPendingRun}
+                            // Stays clickable when over budget so the click can explain why
+                            aria-disabled={budgetBlocked || undefined}
+                            title={runLabel}
+                            aria-label={runLabel}
+                            onClick={(event) => handleRunAgent(event, agent)}
+                          >
+                            <PlayIcon />
+                          </button>
+                        )}
+                        {isRunning && (cancellableRun || isCancelling) && (
+                          <button
+                            type="button"
+                            className="action-btn cancel-run-btn"
+                            data-testid={`cancel-run-${agent.id}`}
+                            disabled={!cancellableRun}
+                            title={cancellableRun ? "Cancel run" : "Cancelling…"}
+                            aria-label={cancellableRun ? "Cancel run" : "Cancelling run"}
+                            onClick={(event) => cancellableRun && handleCancelRun(event, agent.id, cancellableRun.id)}
+                          >
+                            <StopIcon />
+                          </button>
+                        )}
+                        {isOwner && (
+                          <>
+                            <button
+                              type="button"
+                              className={`action-btn pause-btn${isPaused ? " active" : ""}`}
+                              data-testid={`pause-agent-${agent.id}`}
+                              disabled={togglePauseMutation.isPending && togglePauseMutation.variables?.id === agent.id}
+                              aria-pressed={isPaused}
+                              title={isPaused ? "Resume schedules and triggers" : "Pause schedules and triggers"}
+                              aria-label={isPaused ? "Resume agent" : "Pause agent"}
+                              onClick={(event) => handleTogglePause(event, agent)}
+                            >
+                              <PauseIcon />
+                            </button>
+                            <button
+                              type="button"
+                              className="action-btn chat-btn"
+                              data-testid={`chat-agent-${agent.id}`}
+                              title="Chat with Agent"
+                              aria-label="Chat with Agent"
+                              onClick={(event) => handleChatAgent(event, agent.id, agent.name)}
+                            >
+                              <MessageCircleIcon />
+                            </button>
+                          </>
+                        )}
                         <button
                           type="button"
                           className="action-btn debug-btn"
@@ -864,13 +1565,52 @@ export default function DashboardPage() {
                         >
                           <SettingsIcon />
                         </button>
+                        {isOwner && (
+                          <>
+                            <button
+                              type="button"
+                              className="action-btn duplicate-btn"
+                              data-testid={`duplicate-agent-${agent.id}`}
+                              disabled={duplicateAgentMutation.isPending}
+                              title="Duplicate agent"
+                              aria-label="Duplicate agent"
+                              onClick={(event) => handleDuplicateAgent(event, agent)}
+                            >
+                              <CopyIcon />
+                            </button>
+                            <button
+                              type="button"
+                              className="action-btn export-btn"
+                              data-testid={`export-agent-${agent.id}`}
+                              title="Export as JSON"
+                              aria-label="Export as JSON"
+                              onClick={(event) => handleExportAgent(event, agent)}
+                            >
+                              <DownloadIcon />
+                            </button>
+                            <button
+                              type="button"
+                              className="action-btn share-btn"
+                              data-testid={`share-agent-${agent.id}`}
+                              title="Share agent"
+                              aria-label="Share agent"
+                              onClick={(event) => {
+                                event.stopPropagation();
+                                setSharingAgent(agent);
+                              }}
+                            >
+                              <ShareIcon />
+                            </button>
+                          </>
+                        )}
                         <button
                           type="button"
                           className="action-btn delete-btn"
                           data-testid={`delete-agent-${agent.id}`}
-                          title="Delete Agent"
-                          aria-label="Delete Agent"
-                          onClick={(event) => handleDeleteAgent(event, agent.id, agent.name)}
+                          title={isOwner ? "Move to trash" : "Only the owner can delete this agent"}
+                          aria-label="Move to trash"
+                          disabled={!isOwner}
+                          onClick={(event) => handleDeleteAgent(event, agent)}
                         >
                           <TrashIcon />
                         </button>
@@ -881,12 +1621,61 @@ export default function DashboardPage() {
                     <tr className="agent-detail-row" key={`detail-${agent.id}`}>
                       <td colSpan={emptyColspan}>
                         <div className="agent-detail-container">
+                          <AgentReadme description={agent.description} />
                           {runsDataLoading && <span>Loading run history...</span>}
                           {!runsDataLoading && runs && runs.length === 0 && (
                             <span>No runs recorded yet.</span>
                           )}
                           {!runsDataLoading && runs && runs.length > 0 && (
                             <>
+                              {queue.length > 0 && (
+                                <section
+                                  className="run-queue"
+                                  aria-label="Queued runs"
+                                  data-testid={`run-queue-${agent.id}`}
+                                >
+                                  <h4>Queued runs</h4>
+                                  <ol className="run-queue-list">
+                                    {queue.map((run, index) => (
+                                      <li key={run.id} className="run-queue-item">
+                                        <span className="run-queue-label">
+                                          Run #{run.id} · {capitaliseFirst(run.trigger)}
+                                        </span>
+                                        <button
+                                          type="button"
+                                          className="run-queue-btn"
+                                          aria-label={`Move run ${run.id} up`}
+                                          disabled={index === 0 || reorderQueueMutation.isPending}
+                                          onClick={() => handleMoveQueuedRun(agent.id, queueIds, run.id, -1)}
+                                        >
+                                          ↑
+                                        </button>
+                                        <button
+                                          type="button"
+                                          className="run-queue-btn"
+                                          aria-label={`Move run ${run.id} down`}
+                                          disabled={index === queue.length - 1 || reorderQueueMutation.isPending}
+                                          onClick={() => handleMoveQueuedRun(agent.id, queueIds, run.id, 1)}
+                                        >
+                                          ↓
+                                        </button>
+                                        <button
+                                          type="button"
+                                          className="run-queue-btn run-queue-drop"
+                                          aria-label={`Drop run ${run.id}`}
+                                          data-testid={`drop-queued-run-${run.id}`}
+                                          disabled={dropQueuedRunMutation.isPending}
+                                          onClick={() => handleDropQueuedRun(agent.id, run.id)}
+                                        >
+                                          ✕
+                                        </button>
+                                      </li>
+                                    ))}
+                                  </ol>
+                                </section>
+                              )}
+                              <RunHistoryExport agentId={agent.id} agentName={agent.name} />
+                              <AgentRunChart runs={runs} />
                               <table className="run-history-table">
                                 <thead>
                                   <tr>
@@ -904,8 +1693,21 @@ export default function DashboardPage() {
                                     .slice(0, isRunHistoryExpanded ? runs.length : Math.min(runs.length, 5))
                                     .map((run) => (
                                       <tr key={run.id}>
-                                        <td>{formatRunStatusIcon(run.status)}</td>
-                                        <td>{formatDateTimeShort(run.started_at ?? null)}</td>
+                                        <td>
+                                          {formatRunStatusIcon(run.status)}
+                                          {hasSchemaViolations(run) && (
+                                            <span
+                                              className="run-schema-flag"
+                                              title={schemaViolationTitle(run)}
+                                              aria-label="Output doesn't match the schema"
+                                            >
+                                              ⚠
+                                            </span>
+                                          )}
+                                        </td>
+                                        <td>
+                                          <Timestamp value={run.started_at} />
+                                        </td>
                                         <td>{formatDuration(run.duration_ms)}</td>
                                         <td>{capitaliseFirst(run.trigger)}</td>
                                         <td>{formatTokens(run.total_tokens)}</td>
@@ -915,16 +1717,19 @@ export default function DashboardPage() {
                                             className="kebab-menu-btn"
                                             role="button"
                                             tabIndex={0}
+                                            aria-label="Run details"
                                             onClick={(event) => {
                                               event.preventDefault();
                                               event.stopPropagation();
                                               dispatchDashboardEvent("run-actions", agent.id, run.id);
+                                              setSelectedRun({ agentId: agent.id, runId: run.id });
                                             }}
                                             onKeyDown={(event) => {
                                               if (event.key === "Enter" || event.key === " ") {
                                                 event.preventDefault();
                                                 event.stopPropagation();
                                                 dispatchDashboardEvent("run-actions", agent.id, run.id);
+                                                setSelectedRun({ agentId: agent.id, runId: run.id });
                                               }
                                             }}
                                           >
@@ -967,7 +1772,9 @@ export default function DashboardPage() {
                       className="empty-state-illustration"
                     />
                     <p className="empty-state-text">
-                      No agents found. Click 'Create Agent' to get started.
+                      {activeTagFilter.length > 0
+                        ? "No agents have all the selected tags."
+                        : "No agents found. Click 'Create Agent' to get started."}
                     </p>
                   </div>
                 </td>
@@ -976,6 +1783,7 @@ export default function DashboardPage() {
           </tbody>
         </table>
       </div>
+      <RecentlyDeleted />
       {settingsAgentId != null && (
         <AgentSettingsDrawer
           agentId={settingsAgentId}
@@ -983,6 +1791,40 @@ export default function DashboardPage() {
           onClose={() => setSettingsAgentId(null)}
         />
       )}
+      {selectedRunDetails && (
+        <RunDetailDrawer
+          run={selectedRunDetails.run}
+          agentName={selectedRunDetails.agentName}
+          onClose={closeRunDetails}
+          onCancel={() =>
+            cancelRunMutation.mutate({ agentId: selectedRunDetails.run.agent_id, runId: selectedRunDetails.run.id })
+          }
+        />
+      )}
+      {showShortcutHelp && <ShortcutHelpOverlay scope="dashboard" onClose={() => setShowShortcutHelp(false)} />}
+      {sharingAgent && <AgentShareDialog agent={sharingAgent} onClose={() => setSharingAgent(null)} />}
+      {pendingDelete && (
+        <DeleteAgentDialog
+          agent={pendingDelete.agent}
+          dependents={pendingDelete.dependents}
+          onCancel={() => setPendingDelete(null)}
+          onConfirm={(disableDependents) => {
+            deleteAgentMutation.mutate({ agent: pendingDelete.agent, disableDependents });
+            setPendingDelete(null);
+          }}
+        />
+      )}
+      {isImportOpen && (
+        <AgentImportWizard
+          models={modelsData ?? []}
+          onClose={closeImportWizard}
+          onImported={(agent) => {
+            setIsImportOpen(false);
+            queryClient.invalidateQueries({ queryKey: dashboardQueryKey });
+            toast.success(`Imported ${agent.name}`);
+          }}
+        />
+      )}
     </div>
   );
 
@@ -1003,13 +1845,21 @@ export default function DashboardPage() {
     });
   }
 
+  function handleToggleUsageColumn(column: UsageColumn) {
+    const next = toggleUsageColumn(usageColumns, column);
+    setUsageColumns(next);
+    persistUsageColumns(next);
+    // Sorting by a hidden column would look random
+    if (!next.includes(column) && sortConfig.key === column) {
+      setSortConfig({ key: "name", ascending: true });
+    }
+  }
+
+  // Only a sort picked here is remembered, so the settings-page default applies until then
   function handleSort(key: SortKey) {
-    setSortConfig((prev) => {
-      if (prev.key === key) {
-        return { key, ascending: !prev.ascending };
-      }
-      return { key, ascending: true };
-    });
+    const next = sortConfig.key === key ? { key, ascending: !sortConfig.ascending } : { key, ascending: true };
+    setSortConfig(next);
+    persistSortConfig(next);
   }
 
   function handleRowKeyDown(event: ReactKeyboardEvent<HTMLTableRowElement>, agentId: number) {
@@ -1020,7 +1870,8 @@ export default function DashboardPage() {
       return;
     }
 
-    if (key !== "ArrowDown" && key !== "ArrowUp") {
+    const action = actionForEvent(event.nativeEvent, shortcutBindings, "dashboard");
+    if (action !== "dashboard.nextRow" && action !== "dashboard.previousRow") {
       return;
     }
 
@@ -1035,18 +1886,54 @@ export default function DashboardPage() {
     if (index === -1) {
       return;
     }
-    const nextIndex = key === "ArrowDown" ? Math.min(rows.length - 1, index + 1) : Math.max(0, index - 1);
+    const nextIndex = action === "dashboard.nextRow" ? Math.min(rows.length - 1, index + 1) : Math.max(0, index - 1);
     rows[nextIndex]?.focus();
   }
 
-  function handleRunAgent(event: ReactMouseEvent<HTMLButtonElement>, agentId: number, status: string) {
+  // Mirrors the backend's per-agent budget check so the user gets the reason
+  // without a round trip
+  function blockForBudget(agentId: number): boolean {
+    const budget = budgetsByAgent.get(agentId);
+    if (!budget || !isBudgetExhausted(budget)) {
+      return false;
+    }
+    toast.error(budgetExhaustedMessage(budget), { duration: 6000 });
+    return true;
+  }
+
+  function handleRunAgent(event: ReactMouseEvent<HTMLButtonElement>, agent: AgentSummary) {
     event.stopPropagation();
     // Don't run if already running
-    if (status === "running") {
+    if (agent.status === "running" || blockForBudget(agent.id)) {
+      return;
+    }
+    // Pausing only suspends automatic runs, so a manual run just needs a confirmation
+    if (!confirmRunWhilePaused([agent])) {
       return;
     }
     // Use the optimistic mutation
-    runAgentMutation.mutate(agentId);
+    runAgentMutation.mutate(agent.id);
+  }
+
+  function handleMoveQueuedRun(agentId: number, queueIds: number[], runId: number, offset: number) {
+    const runIds = moveInQueue(queueIds, runId, offset);
+    if (runIds !== queueIds) {
+      reorderQueueMutation.mutate({ agentId, runIds });
+    }
+  }
+
+  function handleDropQueuedRun(agentId: number, runId: number) {
+    dropQueuedRunMutation.mutate({ agentId, runId });
+  }
+
+  function handleCancelRun(event: ReactMouseEvent<HTMLElement>, agentId: number, runId: number) {
+    event.stopPropagation();
+    cancelRunMutation.mutate({ agentId, runId });
+  }
+
+  function handleTogglePause(event: ReactMouseEvent<HTMLButtonElement>, agent: AgentSummary) {
+    event.stopPropagation();
+    togglePauseMutation.mutate(agent);
   }
 
   function handleChatAgent(event: ReactMouseEvent<HTMLButtonElement>, agentId: number, agentName: string) {
@@ -1059,13 +1946,28 @@ export default function DashboardPage() {
     setSettingsAgentId(agentId);
   }
 
-  function handleDeleteAgent(event: ReactMouseEvent<HTMLButtonElement>, agentId: number, name: string) {
+  function handleDuplicateAgent(event: ReactMouseEvent<HTMLButtonElement>, agent: AgentSummary) {
     event.stopPropagation();
-    const confirmed = typeof window === "undefined" || window.confirm(`Delete agent ${name}?`);
-    if (!confirmed) {
-      return;
+    duplicateAgentMutation.mutate(agent);
+  }
+
+  // Agents nothing relies on go straight to the trash; the rest need confirming
+  async function handleDeleteAgent(event: ReactMouseEvent<HTMLButtonElement>, agent: AgentSummary) {
+    event.stopPropagation();
+    let dependents: AgentDependents | null = null;
+    try {
+      dependents = await queryClient.fetchQuery({
+        queryKey: ["agent-dependents", agent.id],
+        queryFn: () => fetchAgentDependents(agent.id),
+      });
+    } catch {
+      // Ask anyway; the dialog says the check failed
+    }
+    if (dependents && !hasDependents(dependents)) {
+      deleteAgentMutation.mutate({ agent, disableDependents: false });
+    } else {
+      setPendingDelete({ agent, dependents });
     }
-    deleteAgentMutation.mutate(agentId);
   }
 }
 
@@ -1078,7 +1980,7 @@ type HeaderRenderer = (
 ) => JSX.Element;
 
 const renderHeaderCell: HeaderRenderer = (label, sortKey, sortConfig, onSort, sortable = true) => {
-  const dataColumn = label.toLowerCase().replace(/\s+/g, "_");
+  const dataColumn = label.toLowerCase().replace(/\s+/g, "_").replace(/[()]/g, "");
   const effectiveKey = sortKey === "owner" ? "name" : sortKey;
   const isActive = sortable && sortConfig.key === effectiveKey;
   const arrow = sortConfig.ascending ? "▲" : "▼";
@@ -1115,10 +2017,16 @@ function renderOwnerCell(agent: AgentSummary) {
   );
 }
 
-function sortAgents(agents: AgentSummary[], runsByAgent: AgentRunsState, sortConfig: SortConfig): AgentSummary[] {
+function sortAgents(
+  agents: AgentSummary[],
+  runsByAgent: AgentRunsState,
+  statsByAgent: Map<number, AgentStats>,
+  usageByAgent: Map<number, AgentUsage>,
+  sortConfig: SortConfig
+): AgentSummary[] {
   const sorted = [...agents];
   sorted.sort((left, right) => {
-    const comparison = compareAgents(left, right, runsByAgent, sortConfig.key);
+    const comparison = compareAgents(left, right, runsByAgent, statsByAgent, usageByAgent, sortConfig.key);
     if (comparison !== 0) {
       return sortConfig.ascending ? comparison : -comparison;
     }
@@ -1132,6 +2040,8 @@ function compareAgents(
   left: AgentSummary,
   right: AgentSummary,
   runsByAgent: AgentRunsState,
+  statsByAgent: Map<number, AgentStats>,
+  usageByAgent: Map<number, AgentUsage>,
   sortKey: SortKey
 ): number {
   switch (sortKey) {
@@ -1140,44 +2050,26 @@ function compareAgents(
     case "status":
       return (STATUS_ORDER[left.status] ?? 99) - (STATUS_ORDER[right.status] ?? 99);
     case "created_at":
-      return formatDateTimeShort(left.created_at ?? null).localeCompare(
-        formatDateTimeShort(right.created_at ?? null)
-      );
+      return compareTimestamps(left.created_at, right.created_at);
     case "last_run":
-      return formatDateTimeShort(left.last_run_at ?? null).localeCompare(
-        formatDateTimeShort(right.last_run_at ?? null)
-      );
+      return compareTimestamps(left.last_run_at, right.last_run_at);
     case "next_run":
-      return formatDateTimeShort(left.next_run_at ?? null).localeCompare(
-        formatDateTimeShort(right.next_run_at ?? null)
-      );
+      return compareTimestamps(left.next_run_at, right.next_run_at);
     case "success": {
-      const leftStats = computeSuccessStats(runsByAgent[left.id]);
-      const rightStats = computeSuccessStats(runsByAgent[right.id]);
-      if (leftStats.rate === rightStats.rate) {
-        return leftStats.count - rightStats.count;
-      }
-      return leftStats.rate - rightStats.rate;
+      const summarize = (agentId: number) => {
+        const stats = statsByAgent.get(agentId);
+        return stats ? summarizeStats(stats) : summarizeRuns(runsByAgent[agentId]);
+      };
+      return compareSuccess(summarize(left.id), summarize(right.id));
     }
+    case "tokens":
+    case "cost":
+      return compareUsage(usageByAgent.get(left.id), usageByAgent.get(right.id), sortKey);
     default:
       return 0;
   }
 }
 
-function computeSuccessStats(runs?: AgentRun[]): { display: string; rate: number; count: number } {
-  if (!runs || runs.length === 0) {
-    return { display: "0.0% (0)", rate: 0, count: 0 };
-  }
-
-  const successCount = runs.filter((run) => run.status === "success").length;
-  const successRate = runs.length === 0 ? 0 : (successCount / runs.length) * 100;
-  return {
-    display: `${successRate.toFixed(1)}% (${runs.length})`,
-    rate: successRate,
-    count: runs.length,
-  };
-}
-
 function determineLastRunIndicator(runs?: AgentRun[]): boolean | null {
   if (!runs || runs.length === 0) {
     return null;
@@ -1192,24 +2084,6 @@ function determineLastRunIndicator(runs?: AgentRun[]): boolean | null {
   return null;
 }
 
-function formatDateTimeShort(iso: string | null | undefined): string {
-  if (!iso) {
-    return "-";
-  }
-
-  const date = new Date(iso);
-  if (Number.isNaN(date.getTime())) {
-    return "-";
-  }
-
-  const year = date.getUTCFullYear();
-  const month = String(date.getUTCMonth() + 1).padStart(2, "0");
-  const day = String(date.getUTCDate()).padStart(2, "0");
-  const hours = String(date.getUTCHours()).padStart(2, "0");
-  const minutes = String(date.getUTCMinutes()).padStart(2, "0");
-  return `${year}-${month}-${day}${NBSP}${hours}:${minutes}`;
-}
-
 function formatStatus(status: string): string {
   switch (status) {
     case "running":
@@ -1272,17 +2146,26 @@ function formatRunStatusIcon(status: AgentRun["status"]): string {
       return "✔";
     case "failed":
       return "✖";
+    case "cancelling":
+      return "◌";
+    case "cancelled":
+      return "⊘";
     default:
       return "●";
   }
 }
 
-function loadSortConfig(): SortConfig {
+// The last sort used in this browser, else the dashboard default from the settings page
+function loadSortConfig(preferred: SortConfig | null = null): SortConfig {
+  const fallback = preferred ?? { key: "name", ascending: true };
   if (typeof window === "undefined") {
-    return { key: "name", ascending: true };
+    return fallback;
   }
 
-  const storedKey = window.localStorage.getItem(STORAGE_KEY_SORT) ?? "name";
+  const storedKey = window.localStorage.getItem(STORAGE_KEY_SORT);
+  if (storedKey === null) {
+    return fallback;
+  }
   const storedAsc = window.localStorage.getItem(STORAGE_KEY_ASC);
 
   const keyMap: Record<string, SortKey> = {
@@ -1292,6 +2175,8 @@ function loadSortConfig(): SortConfig {
     last_run: "last_run",
     next_run: "next_run",
     success: "success",
+    tokens: "tokens",
+    cost: "cost",
   };
 
   const key = keyMap[storedKey] ?? "name";
@@ -1299,6 +2184,20 @@ function loadSortConfig(): SortConfig {
   return { key, ascending };
 }
 
+function loadUsageColumns(): UsageColumn[] {
+  if (typeof window === "undefined") {
+    return [];
+  }
+  return parseUsageColumns(window.localStorage.getItem(USAGE_COLUMNS_STORAGE_KEY));
+}
+
+function persistUsageColumns(columns: UsageColumn[]) {
+  if (typeof window === "undefined") {
+    return;
+  }
+  window.localStorage.setItem(USAGE_COLUMNS_STORAGE_KEY, columns.join(","));
+}
+
 function persistSortConfig(config: SortConfig) {
   if (typeof window === "undefined") {
     return;
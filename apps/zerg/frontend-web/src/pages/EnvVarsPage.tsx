@@ -0,0 +1,262 @@
+/**
+ * Secrets & variables.
+ *
+ * Named values that workflow nodes and agent instructions reference as
+ * {{env.NAME}}. Variables apply workspace-wide or to one workflow, where they
+ * override a workspace value of the same name. Secret values are write-only:
+ * once saved they can be replaced but never shown again.
+ */
+
+import { useState, type FormEvent } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import toast from "react-hot-toast";
+import {
+  createEnvVar,
+  deleteEnvVar,
+  fetchEnvVars,
+  fetchWorkflows,
+  updateEnvVar,
+  type EnvVar,
+  type Workflow,
+} from "../services/api";
+import { envReferenceText, isValidEnvName } from "../lib/envVars";
+import { Timestamp } from "../components/Timestamp";
+
+export const ENV_VARS_QUERY_KEY = ["env-vars"];
+
+interface EnvVarForm {
+  // Variable being edited; null when adding
+  id: number | null;
+  name: string;
+  value: string;
+  isSecret: boolean;
+  description: string;
+  // "" for workspace-wide
+  workflowId: string;
+}
+
+const EMPTY_FORM: EnvVarForm = { id: null, name: "", value: "", isSecret: false, description: "", workflowId: "" };
+
+function formFor(variable: EnvVar): EnvVarForm {
+  return {
+    id: variable.id,
+    name: variable.name,
+    value: variable.value ?? "",
+    isSecret: variable.is_secret,
+    description: variable.description ?? "",
+    workflowId: variable.workflow_id != null ? String(variable.workflow_id) : "",
+  };
+}
+
+export default function EnvVarsPage() {
+  const queryClient = useQueryClient();
+  const [form, setForm] = useState<EnvVarForm>(EMPTY_FORM);
+
+  const varsQuery = useQuery({ queryKey: ENV_VARS_QUERY_KEY, queryFn: fetchEnvVars });
+  const variables = varsQuery.data ?? [];
+  const editing = form.id != null ? variables.find((variable) => variable.id === form.id) ?? null : null;
+  const { data: workflows = [] } = useQuery<Workflow[]>({
+    queryKey: ["workflows"],
+    queryFn: () => fetchWorkflows(),
+    staleTime: 60000,
+  });
+
+  const invalidate = () => queryClient.invalidateQueries({ queryKey: ENV_VARS_QUERY_KEY });
+
+  const saveVar = useMutation({
+    mutationFn: (next: EnvVarForm) => {
+      const description = next.description.trim() || null;
+      if (next.id == null) {
+        return createEnvVar({
+          name: next.name.trim(),
+          value: next.value,
+          is_secret: next.isSecret,
+          description,
+          workflow_id: next.workflowId ? Number(next.workflowId) : null,
+        });
+      }
+      // A blank value on a secret keeps the stored one
+      const keepValue = editing?.is_secret && next.value === "";
+      return updateEnvVar(next.id, {
+        name: next.name.trim(),
+        is_secret: next.isSecret,
+        description: description ?? "",
+        ...(keepValue ? {} : { value: next.value }),
+      });
+    },
+    onSuccess: (saved) => {
+      toast.success(`Saved ${saved.name}`);
+      setForm(EMPTY_FORM);
+      invalidate();
+    },
+    onError: (error: Error) => toast.error(`Could not save: ${error.message}`),
+  });
+
+  const removeVar = useMutation({
+    mutationFn: (variable: EnvVar) => deleteEnvVar(variable.id),
+    onSuccess: (_, variable) => {
+      if (form.id === variable.id) setForm(EMPTY_FORM);
+      invalidate();
+    },
+    onError: (error: Error) => toast.error(`Could not delete: ${error.message}`),
+  });
+
+  const nameError = form.name.trim() && !isValidEnvName(form.name.trim())
+    ? "Use letters, digits and underscores, not starting with a digit."
+    : null;
+  // New variables need a value; so does a secret being turned into a plain value
+  const needsValue = form.id == null || (editing?.is_secret === true && !form.isSecret);
+  const canSave = Boolean(form.name.trim()) && !nameError && !(needsValue && form.value === "") && !saveVar.isPending;
+
+  const handleSubmit = (event: FormEvent) => {
+    event.preventDefault();
+    if (canSave) saveVar.mutate(form);
+  };
+
+  const handleDelete = (variable: EnvVar) => {
+    if (window.confirm(`Delete ${variable.name}? Nodes and agents that reference it will stop resolving it.`)) {
+      removeVar.mutate(variable);
+    }
+  };
+
+  const update = <K extends keyof EnvVarForm>(key: K, value: EnvVarForm[K]) =>
+    setForm((current) => ({ ...current, [key]: value }));
+
+  return (
+    <div className="integrations-container">
+      <div className="integrations-content">
+        <div className="integrations-header">
+          <h2>Secrets &amp; Variables</h2>
+          <p className="integrations-description">
+            Reference a value from node settings or agent instructions as <code>{envReferenceText("NAME")}</code>.
+            A variable scoped to a workflow overrides a workspace one with the same name; agents use workspace
+            variables other than secrets, which only workflow nodes can read.{" "}
+            <Link to="/settings/integrations">Back to integrations</Link>
+          </p>
+        </div>
+
+        <form className="env-var-form" onSubmit={handleSubmit} data-testid="env-var-form">
+          <h3>{form.id == null ? "Add a variable" : `Edit ${editing?.name ?? form.name}`}</h3>
+          <div className="env-var-form-grid">
+            <label>
+              Name
+              <input
+                value={form.name}
+                onChange={(event) => update("name", event.target.value)}
+                placeholder="API_TOKEN"
+                aria-invalid={nameError ? true : undefined}
+                spellCheck={false}
+              />
+              {nameError && <span className="error-message">{nameError}</span>}
+            </label>
+            <label>
+              Scope
+              <select
+                value={form.workflowId}
+                onChange={(event) => update("workflowId", event.target.value)}
+                disabled={form.id != null}
+              >
+                <option value="">Workspace (all workflows and agents)</option>
+                {workflows.map((workflow) => (
+                  <option key={workflow.id} value={String(workflow.id)}>
+                    Workflow: {workflow.name}
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="env-var-form-value">
+              Value
+              <input
+                type={form.isSecret ? "password" : "text"}
+                value={form.value}
+                onChange={(event) => update("value", event.target.value)}
+                placeholder={editing?.is_secret ? "Leave blank to keep the saved secret" : ""}
+                autoComplete="off"
+                spellCheck={false}
+              />
+            </label>
+            <label>
+              Description
+              <input value={form.description} onChange={(event) => update("description", event.target.value)} />
+            </label>
+          </div>
+          <label className="env-var-form-secret">
+            <input
+              type="checkbox"
+              checked={form.isSecret}
+              onChange={(event) => update("isSecret", event.target.checked)}
+            />
+            Secret – hide the value once saved
+          </label>
+          <div className="env-var-form-actions">
+            {form.id != null && (
+              <button type="button" className="btn-secondary" onClick={() => setForm(EMPTY_FORM)}>
+                Cancel
+              </button>
+            )}
+            <button type="submit" className="btn-primary" disabled={!canSave}>
+              {saveVar.isPending ? "Saving…" : form.id == null ? "Add variable" : "Save changes"}
+            </button>
+          </div>
+        </form>
+
+        {varsQuery.isLoading ? (
+          <p className="muted">Loading variables…</p>
+        ) : varsQuery.error ? (
+          <p className="error-message">Failed to load variables: {String(varsQuery.error)}</p>
+        ) : variables.length === 0 ? (
+          <p className="muted">No variables yet.</p>
+        ) : (
+          <table className="env-var-table" data-testid="env-var-table">
+            <thead>
+              <tr>
+                <th>Name</th>
+                <th>Scope</th>
+                <th>Value</th>
+                <th>Updated</th>
+                <th aria-label="Actions" />
+              </tr>
+            </thead>
+            <tbody>
+              {variables.map((variable) => (
+                <tr key={variable.id} data-testid={`env-var-row-${variable.id}`}>
+                  <td>
+                    <code>{variable.name}</code>
+                    {variable.description && <div className="muted">{variable.description}</div>}
+                  </td>
+                  <td>{variable.workflow_id != null ? variable.workflow_name ?? "Workflow" : "Workspace"}</td>
+                  <td>
+                    {variable.is_secret ? (
+                      <span className="env-var-secret" title="Secret values can't be shown">
+                        •••••• secret
+                      </span>
+                    ) : (
+                      <code className="env-var-value">{variable.value}</code>
+                    )}
+                  </td>
+                  <td>
+                    <Timestamp value={variable.updated_at} fallback="—" />
+                  </td>
+                  <td className="env-var-actions">
+                    <button type="button" className="btn-secondary" onClick={() => setForm(formFor(variable))}>
+                      Edit
+                    </button>
+                    <button
+                      type="button"
+                      className="btn-secondary"
+                      onClick={() => handleDelete(variable)}
+                      disabled={removeVar.isPending && removeVar.variables?.id === variable.id}
+                    >
+                      Delete
+                    </button>
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        )}
+      </div>
+    </div>
+  );
+}
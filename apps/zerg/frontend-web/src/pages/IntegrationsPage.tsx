@@ -6,6 +6,7 @@
  */
 
 import { useState, type FormEvent } from "react";
+import { Link } from "react-router-dom";
 import {
   useAccountConnectors,
   useConfigureAccountConnector,
@@ -125,6 +126,13 @@ export default function IntegrationsPage() {
             Configure credentials for external services. These integrations are shared across all your agents.
             Individual agents can override these settings if needed.
           </p>
+          <p className="integrations-description">
+            <Link to="/settings/mcp">Check the health of your MCP servers</Link>
+            {" · "}
+            <Link to="/settings/variables">Manage secrets &amp; variables</Link>
+            {" · "}
+            <Link to="/settings/api-credentials">Manage API credentials</Link>
+          </p>
         </div>
 
         {isLoading ? (
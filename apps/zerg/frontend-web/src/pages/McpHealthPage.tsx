@@ -0,0 +1,193 @@
+/**
+ * MCP server health dashboard.
+ *
+ * Lists every MCP server configured across the user's agents, probes them on
+ * an interval and offers a reconnect for servers that dropped. Replaces
+ * checking each agent's settings drawer one at a time.
+ */
+
+import { useState } from "react";
+import clsx from "clsx";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import toast from "react-hot-toast";
+import {
+  fetchMcpServerHealth,
+  reconnectMcpServer,
+  type McpHealthStatus,
+  type McpServerHealth,
+} from "../services/api";
+import { sparklineGeometry } from "../lib/sparkline";
+import { Timestamp } from "../components/Timestamp";
+
+// How often the dashboard re-probes while auto-refresh is on
+export const MCP_HEALTH_POLL_MS = 30_000;
+
+const STATUS_LABELS: Record<McpHealthStatus, string> = {
+  online: "Online",
+  offline: "Offline",
+  auth_error: "Auth failed",
+  config_error: "Misconfigured",
+  unknown: "Not checked",
+};
+
+const SPARK_WIDTH = 120;
+const SPARK_HEIGHT = 24;
+
+function LatencySparkline({ server }: { server: McpServerHealth }) {
+  if (server.history.length < 2) {
+    return <span className="muted">—</span>;
+  }
+  const latencies = server.history.map((sample) => (sample.status === "online" ? sample.latency_ms ?? null : null));
+  const { segments, gaps, max } = sparklineGeometry(latencies, SPARK_WIDTH, SPARK_HEIGHT, 2);
+  const failures = gaps.length;
+  return (
+    <svg
+      className="mcp-health-sparkline"
+      width={SPARK_WIDTH}
+      height={SPARK_HEIGHT}
+      viewBox={`0 0 ${SPARK_WIDTH} ${SPARK_HEIGHT}`}
+      role="img"
+      aria-label={`Latency over the last ${latencies.length} checks, peak ${Math.round(max)} ms, ${failures} failed`}
+    >
+      {segments.map((points) => (
+        <polyline key={points} points={points} className="spark-line" />
+      ))}
+      {gaps.map((x) => (
+        <line key={x} x1={x} x2={x} y1={SPARK_HEIGHT - 6} y2={SPARK_HEIGHT} className="spark-gap" />
+      ))}
+    </svg>
+  );
+}
+
+function serverKey(server: McpServerHealth) {
+  return `${server.agent_id}:${server.preset ?? server.name}`;
+}
+
+export default function McpHealthPage() {
+  const queryClient = useQueryClient();
+  const [autoRefresh, setAutoRefresh] = useState(true);
+
+  const healthQuery = useQuery({
+    queryKey: ["mcp-health"],
+    queryFn: () => fetchMcpServerHealth(true),
+    refetchInterval: autoRefresh ? MCP_HEALTH_POLL_MS : false,
+    refetchIntervalInBackground: false,
+  });
+
+  const reconnectMutation = useMutation({
+    mutationFn: (server: McpServerHealth) => reconnectMcpServer(server.agent_id, server.preset ?? server.name),
+    onSuccess: (result) => {
+      if (result.status === "online") {
+        toast.success(`Reconnected to ${result.name}`);
+      } else {
+        toast.error(`${result.name} is still ${STATUS_LABELS[result.status].toLowerCase()}`, { duration: 6000 });
+      }
+      queryClient.setQueryData<McpServerHealth[]>(["mcp-health"], (rows) =>
+        rows?.map((row) => (serverKey(row) === serverKey(result) ? result : row))
+      );
+      queryClient.invalidateQueries({ queryKey: ["agent", result.agent_id, "mcp-servers"] });
+      queryClient.invalidateQueries({ queryKey: ["agent", result.agent_id, "available-tools"] });
+    },
+    onError: (error: Error) => {
+      toast.error(`Reconnect failed: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const servers = healthQuery.data ?? [];
+  const unhealthy = servers.filter((server) => server.status !== "online" && server.status !== "unknown").length;
+
+  return (
+    <div className="integrations-container">
+      <div className="integrations-content">
+        <div className="integrations-header">
+          <h2>MCP Server Health</h2>
+          <p className="integrations-description">
+            Every MCP server configured on your agents. Servers shared by several agents are checked once per refresh.{" "}
+            <Link to="/settings/integrations">Back to integrations</Link>
+          </p>
+        </div>
+
+        <div className="mcp-health-toolbar">
+          <label>
+            <input type="checkbox" checked={autoRefresh} onChange={(event) => setAutoRefresh(event.target.checked)} />
+            <span>Check every {MCP_HEALTH_POLL_MS / 1000} s</span>
+          </label>
+          <button
+            type="button"
+            className="btn-secondary"
+            onClick={() => healthQuery.refetch()}
+            disabled={healthQuery.isFetching}
+          >
+            {healthQuery.isFetching ? "Checking…" : "Check now"}
+          </button>
+          {servers.length > 0 && (
+            <span className={clsx("mcp-health-summary", { degraded: unhealthy > 0 })} role="status">
+              {unhealthy === 0 ? "All servers healthy" : `${unhealthy} of ${servers.length} need attention`}
+            </span>
+          )}
+        </div>
+
+        {healthQuery.isLoading ? (
+          <p className="muted">Checking servers…</p>
+        ) : healthQuery.error ? (
+          <p className="error-message">Failed to load MCP servers: {String(healthQuery.error)}</p>
+        ) : servers.length === 0 ? (
+          <p className="muted">None of your agents use MCP servers yet. Add one from an agent's settings.</p>
+        ) : (
+          <table className="mcp-health-table" data-testid="mcp-health-table">
+            <thead>
+              <tr>
+                <th>Server</th>
+                <th>Agent</th>
+                <th>Status</th>
+                <th>Latency</th>
+                <th>Trend</th>
+                <th>Last checked</th>
+                <th aria-label="Actions" />
+              </tr>
+            </thead>
+            <tbody>
+              {servers.map((server) => {
+                const key = serverKey(server);
+                const reconnecting = reconnectMutation.isPending && reconnectMutation.variables === server;
+                return (
+                  <tr key={key} data-testid={`mcp-health-row-${key}`}>
+                    <td>
+                      <div className="server-name">{server.name}</div>
+                      <div className="server-url">{server.url}</div>
+                    </td>
+                    <td>{server.agent_name}</td>
+                    <td>
+                      <span className={clsx("status-pill", server.status)} title={server.error ?? undefined}>
+                        {STATUS_LABELS[server.status]}
+                      </span>
+                      {server.status === "online" && <div className="muted">{server.tool_count} tools</div>}
+                    </td>
+                    <td>{server.latency_ms != null ? `${Math.round(server.latency_ms)} ms` : "—"}</td>
+                    <td>
+                      <LatencySparkline server={server} />
+                    </td>
+                    <td>
+                      <Timestamp value={server.last_checked} fallback="Never" />
+                    </td>
+                    <td>
+                      <button
+                        type="button"
+                        className="btn-secondary"
+                        onClick={() => reconnectMutation.mutate(server)}
+                        disabled={reconnecting}
+                      >
+                        {reconnecting ? "Reconnecting…" : "Reconnect"}
+                      </button>
+                    </td>
+                  </tr>
+                );
+              })}
+            </tbody>
+          </table>
+        )}
+      </div>
+    </div>
+  );
+}
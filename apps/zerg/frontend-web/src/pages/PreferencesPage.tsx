@@ -0,0 +1,453 @@
+import React, { useMemo, useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { toast } from "react-hot-toast";
+import { useAuth } from "../lib/auth";
+import { Capability, useCapabilities } from "../lib/capabilities";
+import { AUTO_TIMEZONE, browserTimeZone, listTimeZones } from "../lib/datetime";
+import { browserNotificationPermission } from "../lib/desktopNotifications";
+import {
+  DASHBOARD_SORT_KEYS,
+  DASHBOARD_SORT_LABELS,
+  WORKSPACE_DEFAULTS_QUERY_KEY,
+  draftFromPrefs,
+  prefsFromDraft,
+  validatePreferencesDraft,
+  workspaceDefaultsFromDraft,
+  type PreferencesDraft,
+  type PreferencesDraftErrors,
+} from "../lib/preferences";
+import { THEMES, THEME_LABELS, isTheme } from "../lib/theme";
+import {
+  fetchModels,
+  updateUserPrefs,
+  updateWorkspaceDefaults,
+  type ModelConfig,
+  type WorkspaceDefaults,
+} from "../services/api";
+import { ShortcutSettings } from "../components/ShortcutSettings";
+import { TagManager } from "../components/TagManager";
+
+type DraftChange = <K extends keyof PreferencesDraft>(key: K, value: PreferencesDraft[K]) => void;
+
+interface DefaultableFieldsProps {
+  idPrefix: string;
+  draft: PreferencesDraft;
+  errors: PreferencesDraftErrors;
+  onChange: DraftChange;
+  models: ModelConfig[];
+  // Label for the empty option: what applies when the field is left unset
+  inheritedLabel: (value: string | null | undefined) => string;
+  inherited: WorkspaceDefaults | null;
+  canViewAll: boolean;
+}
+
+function FieldError({ message }: { message?: string }) {
+  return message ? <small className="form-error" role="alert">{message}</small> : null;
+}
+
+/** Fields a user sets for themselves and an admin sets for the workspace. */
+function DefaultableFields({
+  idPrefix,
+  draft,
+  errors,
+  onChange,
+  models,
+  inheritedLabel,
+  inherited,
+  canViewAll,
+}: DefaultableFieldsProps) {
+  const timeZones = useMemo(() => listTimeZones(), []);
+  const inheritedTheme = inherited?.theme;
+  const inheritedModel =
+    models.find((model) => model.id === inherited?.default_model) ?? models.find((model) => model.is_default);
+  const inheritedSort = inherited?.dashboard_sort?.key as keyof typeof DASHBOARD_SORT_LABELS | undefined;
+
+  return (
+    <>
+      <div className="form-section">
+        <h3>Appearance</h3>
+        <div className="form-group">
+          <label htmlFor={`${idPrefix}-theme`} className="form-label">Theme</label>
+          <select
+            id={`${idPrefix}-theme`}
+            value={draft.theme}
+            onChange={(e) => onChange("theme", e.target.value)}
+            className="form-input"
+          >
+            <option value="">{inheritedLabel(isTheme(inheritedTheme) ? THEME_LABELS[inheritedTheme] : "Dark")}</option>
+            {THEMES.map((theme) => (
+              <option key={theme} value={theme}>
+                {THEME_LABELS[theme]}
+              </option>
+            ))}
+          </select>
+        </div>
+      </div>
+
+      <div className="form-section">
+        <h3>Time Display</h3>
+        <div className="form-group">
+          <label htmlFor={`${idPrefix}-timezone`} className="form-label">Timezone</label>
+          <select
+            id={`${idPrefix}-timezone`}
+            value={draft.timeZone}
+            onChange={(e) => onChange("timeZone", e.target.value)}
+            className="form-input"
+            aria-invalid={errors.timeZone ? true : undefined}
+          >
+            <option value="">
+              {inheritedLabel(inherited?.timezone === AUTO_TIMEZONE ? null : inherited?.timezone)}
+            </option>
+            <option value={AUTO_TIMEZONE}>Browser default ({browserTimeZone()})</option>
+            {timeZones.map((zone) => (
+              <option key={zone} value={zone}>
+                {zone}
+              </option>
+            ))}
+          </select>
+          <FieldError message={errors.timeZone} />
+          <small>Run times and chat timestamps are shown in this timezone. Hover any time to see it in UTC.</small>
+        </div>
+
+        <div className="form-group">
+          <label htmlFor={`${idPrefix}-time-display`} className="form-label">Show times as</label>
+          <select
+            id={`${idPrefix}-time-display`}
+            value={draft.timeDisplay}
+            onChange={(e) => onChange("timeDisplay", e.target.value)}
+            className="form-input"
+          >
+            <option value="">
+              {inheritedLabel(inherited?.time_display === "relative" ? "Relative" : "Date and time")}
+            </option>
+            <option value="absolute">Date and time (2025-01-31 14:05)</option>
+            <option value="relative">Relative (3 min ago)</option>
+          </select>
+        </div>
+      </div>
+
+      <div className="form-section">
+        <h3>Agents</h3>
+        <div className="form-group">
+          <label htmlFor={`${idPrefix}-default-model`} className="form-label">Default model</label>
+          <select
+            id={`${idPrefix}-default-model`}
+            value={draft.defaultModel}
+            onChange={(e) => onChange("defaultModel", e.target.value)}
+            className="form-input"
+            aria-invalid={errors.defaultModel ? true : undefined}
+          >
+            <option value="">{inheritedLabel(inheritedModel?.display_name)}</option>
+            {models.map((model) => (
+              <option key={model.id} value={model.id}>
+                {model.display_name}
+              </option>
+            ))}
+          </select>
+          <FieldError message={errors.defaultModel} />
+          <small>New agents start with this model.</small>
+        </div>
+      </div>
+
+      <div className="form-section">
+        <h3>Dashboard</h3>
+        {canViewAll && (
+          <div className="form-group">
+            <label htmlFor={`${idPrefix}-dashboard-scope`} className="form-label">Show</label>
+            <select
+              id={`${idPrefix}-dashboard-scope`}
+              value={draft.dashboardScope}
+              onChange={(e) => onChange("dashboardScope", e.target.value)}
+              className="form-input"
+            >
+              <option value="">
+                {inheritedLabel(inherited?.dashboard_scope === "all" ? "All agents" : "My agents")}
+              </option>
+              <option value="my">My agents</option>
+              <option value="all">All agents</option>
+            </select>
+          </div>
+        )}
+        <div className="form-group">
+          <label htmlFor={`${idPrefix}-dashboard-sort`} className="form-label">Sort agents by</label>
+          <div className="quiet-hours-row">
+            <select
+              id={`${idPrefix}-dashboard-sort`}
+              value={draft.dashboardSort}
+              onChange={(e) => onChange("dashboardSort", e.target.value)}
+              className="form-input"
+            >
+              <option value="">{inheritedLabel(inheritedSort ? DASHBOARD_SORT_LABELS[inheritedSort] : "Name")}</option>
+              {DASHBOARD_SORT_KEYS.map((key) => (
+                <option key={key} value={key}>
+                  {DASHBOARD_SORT_LABELS[key]}
+                </option>
+              ))}
+            </select>
+            <select
+              aria-label="Sort direction"
+              value={draft.dashboardAscending ? "asc" : "desc"}
+              onChange={(e) => onChange("dashboardAscending", e.target.value === "asc")}
+              className="form-input"
+              disabled={!draft.dashboardSort}
+            >
+              <option value="asc">Ascending</option>
+              <option value="desc">Descending</option>
+            </select>
+          </div>
+          <small>Applies until you sort the table yourself in this browser.</small>
+        </div>
+      </div>
+    </>
+  );
+}
+
+function useModels() {
+  return useQuery<ModelConfig[]>({
+    queryKey: ["models"],
+    queryFn: fetchModels,
+    staleTime: 1000 * 60 * 60, // 1 hour
+  }).data ?? [];
+}
+
+function UserPreferencesForm({ prefs }: { prefs: Record<string, unknown> | null | undefined }) {
+  const queryClient = useQueryClient();
+  const { workspaceDefaults } = useAuth();
+  const { can } = useCapabilities();
+  const models = useModels();
+  const storedDraft = useMemo(() => draftFromPrefs(prefs), [prefs]);
+  const [draft, setDraft] = useState(storedDraft);
+  const [permission, setPermission] = useState(browserNotificationPermission);
+  const errors = validatePreferencesDraft(
+    draft,
+    models.map((model) => model.id)
+  );
+  const hasErrors = Object.keys(errors).length > 0;
+
+  const change: DraftChange = (key, value) => setDraft((current) => ({ ...current, [key]: value }));
+
+  const saveMutation = useMutation({
+    mutationFn: (next: PreferencesDraft) => updateUserPrefs(prefsFromDraft(prefs, next)),
+    onSuccess: (updated) => {
+      toast.success("Settings saved");
+      queryClient.setQueryData(["current-user"], (prev: Record<string, unknown> | undefined) =>
+        prev ? { ...prev, prefs: updated.prefs } : prev
+      );
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save settings: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (hasErrors) return;
+    if (JSON.stringify(draft) === JSON.stringify(storedDraft)) {
+      toast.success("No changes to save");
+      return;
+    }
+    saveMutation.mutate(draft);
+  };
+
+  // Ask for permission the moment the user opts in, while we have a user gesture
+  const handleDesktopToggle = async (enabled: boolean) => {
+    change("desktopEnabled", enabled);
+    if (enabled && permission === "default") {
+      setPermission(await Notification.requestPermission());
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} className="profile-form" aria-label="Your preferences">
+      <DefaultableFields
+        idPrefix="pref"
+        draft={draft}
+        errors={errors}
+        onChange={change}
+        models={models}
+        inherited={workspaceDefaults ?? null}
+        inheritedLabel={(value) => `Workspace default${value ? ` (${value})` : ""}`}
+        canViewAll={can(Capability.ViewAllAgents)}
+      />
+
+      <div className="form-section">
+        <h3>Desktop Notifications</h3>
+
+        <div className="form-group">
+          <label className="form-checkbox">
+            <input
+              type="checkbox"
+              checked={draft.desktopEnabled}
+              onChange={(e) => handleDesktopToggle(e.target.checked)}
+              disabled={permission === "unsupported"}
+              data-testid="desktop-notifications-toggle"
+            />
+            <span>Notify me when a run finishes while Swarmlet is in a background tab</span>
+          </label>
+          {permission === "unsupported" && <small>This browser does not support desktop notifications.</small>}
+          {permission === "denied" && (
+            <small>Notifications are blocked for this site. Allow them in your browser settings first.</small>
+          )}
+        </div>
+
+        <div className="form-group">
+          <label className="form-checkbox">
+            <input
+              type="checkbox"
+              checked={draft.quietHoursEnabled}
+              onChange={(e) => change("quietHoursEnabled", e.target.checked)}
+              disabled={!draft.desktopEnabled}
+            />
+            <span>Quiet hours</span>
+          </label>
+          {draft.quietHoursEnabled && (
+            <div className="quiet-hours-row">
+              <label className="form-label" htmlFor="quiet-hours-start">From</label>
+              <input
+                type="time"
+                id="quiet-hours-start"
+                value={draft.quietHours.start}
+                onChange={(e) => change("quietHours", { ...draft.quietHours, start: e.target.value })}
+                className="form-input"
+                disabled={!draft.desktopEnabled}
+              />
+              <label className="form-label" htmlFor="quiet-hours-end">to</label>
+              <input
+                type="time"
+                id="quiet-hours-end"
+                value={draft.quietHours.end}
+                onChange={(e) => change("quietHours", { ...draft.quietHours, end: e.target.value })}
+                className="form-input"
+                disabled={!draft.desktopEnabled}
+              />
+            </div>
+          )}
+          <FieldError message={errors.quietHours} />
+          <small>No desktop notifications are shown during these hours (in your timezone above).</small>
+        </div>
+      </div>
+
+      <div className="form-section">
+        <h3>Keyboard Shortcuts</h3>
+        <ShortcutSettings bindings={draft.shortcuts} onChange={(shortcuts) => change("shortcuts", shortcuts)} />
+        <FieldError message={errors.shortcuts} />
+        <small>Press Shift + / on the dashboard or canvas to see the shortcuts available there.</small>
+      </div>
+
+      <div className="form-actions">
+        <button
+          type="button"
+          onClick={() => setDraft(storedDraft)}
+          className="btn-secondary"
+          disabled={saveMutation.isPending}
+        >
+          Reset Changes
+        </button>
+        <button type="submit" className="btn-primary" disabled={saveMutation.isPending || hasErrors}>
+          {saveMutation.isPending ? "Saving..." : "Save Changes"}
+        </button>
+      </div>
+    </form>
+  );
+}
+
+function WorkspaceDefaultsForm({ defaults }: { defaults: WorkspaceDefaults | null }) {
+  const queryClient = useQueryClient();
+  const models = useModels();
+  const storedDraft = useMemo(() => draftFromPrefs(defaults as Record<string, unknown> | null), [defaults]);
+  const [draft, setDraft] = useState(storedDraft);
+  const errors = validatePreferencesDraft(
+    draft,
+    models.map((model) => model.id)
+  );
+  const hasErrors = Object.keys(errors).length > 0;
+
+  const change: DraftChange = (key, value) => setDraft((current) => ({ ...current, [key]: value }));
+
+  const saveMutation = useMutation({
+    mutationFn: (next: PreferencesDraft) => updateWorkspaceDefaults(workspaceDefaultsFromDraft(next)),
+    onSuccess: (updated) => {
+      toast.success("Workspace defaults saved");
+      queryClient.setQueryData(WORKSPACE_DEFAULTS_QUERY_KEY, updated);
+    },
+    onError: (error: Error) => {
+      toast.error(`Failed to save workspace defaults: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        if (!hasErrors) saveMutation.mutate(draft);
+      }}
+      className="profile-form"
+      aria-label="Workspace defaults"
+    >
+      <h2>Workspace Defaults</h2>
+      <p className="form-help">
+        Apply to every user who hasn&apos;t picked their own value. Changing a default doesn&apos;t touch anyone&apos;s
+        saved preferences.
+      </p>
+      <DefaultableFields
+        idPrefix="workspace"
+        draft={draft}
+        errors={errors}
+        onChange={change}
+        models={models}
+        inherited={null}
+        inheritedLabel={(value) => `Built-in default${value ? ` (${value})` : ""}`}
+        canViewAll
+      />
+      <div className="form-actions">
+        <button
+          type="button"
+          onClick={() => setDraft(storedDraft)}
+          className="btn-secondary"
+          disabled={saveMutation.isPending}
+        >
+          Reset Changes
+        </button>
+        <button type="submit" className="btn-primary" disabled={saveMutation.isPending || hasErrors}>
+          {saveMutation.isPending ? "Saving..." : "Save Workspace Defaults"}
+        </button>
+      </div>
+    </form>
+  );
+}
+
+/**
+ * Every per-user preference in one place, saved to user.prefs in one request,
+ * the user's tags, plus the workspace-wide defaults for admins.
+ */
+export default function PreferencesPage() {
+  const { user, workspaceDefaults } = useAuth();
+  const { can } = useCapabilities();
+
+  if (!user) {
+    return (
+      <div className="profile-container">
+        <div>Loading settings...</div>
+      </div>
+    );
+  }
+
+  return (
+    <div className="profile-container">
+      <div className="profile-content">
+        <h2>Settings</h2>
+        <UserPreferencesForm prefs={user.prefs} />
+        <section className="profile-form" aria-labelledby="tag-settings-title">
+          <h2 id="tag-settings-title">Tags</h2>
+          <p className="form-help">
+            Tags on your agents and workflows. Renaming a tag to one that already exists merges the two.
+          </p>
+          <TagManager />
+        </section>
+        {can(Capability.ManageWorkspaceDefaults) && (
+          <WorkspaceDefaultsForm key={JSON.stringify(workspaceDefaults)} defaults={workspaceDefaults} />
+        )}
+      </div>
+    </div>
+  );
+}
@@ -2,11 +2,12 @@ import React, { useState } from "react";
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import { toast } from "react-hot-toast";
 import { useAuth } from "../lib/auth";
-
+import DataExportSection from "../components/DataExportSection";
+import GmailConnectionSection from "../components/GmailConnectionSection";
+import Timestamp from "../components/Timestamp";
 interface UserUpdatePayload {
   display_name?: string | null;
   avatar_url?: string | null;
-  prefs?: Record<string, unknown> | null;
 }
 
 // API function for updating user profile
@@ -220,6 +221,15 @@ export default function ProfilePage() {
             </div>
           </div>
 
+          {/* Preferences moved to the settings page */}
+          <div className="form-section">
+            <h3>Preferences</h3>
+            <small>
+              Theme, timezone, notifications, default model, dashboard defaults and keyboard shortcuts live on the{" "}
+              <a href="/settings/preferences">Settings</a> page.
+            </small>
+          </div>
+
           {/* Account Information */}
           <div className="form-section">
             <h3>Account Information</h3>
@@ -238,10 +248,7 @@ export default function ProfilePage() {
               <div className="info-item">
                 <span className="info-label">Last Login:</span>
                 <span className="info-value">
-                  {user.last_login
-                    ? new Date(user.last_login).toLocaleString()
-                    : "Never"
-                  }
+                  <Timestamp value={user.last_login} fallback="Never" />
                 </span>
               </div>
             </div>
@@ -266,6 +273,10 @@ export default function ProfilePage() {
             </button>
           </div>
         </form>
+
+        <GmailConnectionSection />
+
+        <DataExportSection />
       </div>
     </div>
   );
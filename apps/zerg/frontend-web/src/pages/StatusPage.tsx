@@ -0,0 +1,141 @@
+/**
+ * System status.
+ *
+ * API reachability, the WebSocket connection, recent error counts and run
+ * latency at a glance, refreshed every 30 seconds. Open to every signed-in
+ * user and built to be left on a team wallboard – the admin ops dashboard
+ * covers budgets, costs and per-agent numbers.
+ */
+
+import { useQuery } from "@tanstack/react-query";
+import clsx from "clsx";
+import { fetchSystemStatus, type SystemStatus } from "../services/api";
+import { ConnectionStatus, useWebSocket } from "../lib/useWebSocket";
+import {
+  OVERALL_STATUS_LABELS,
+  STATUS_REFRESH_MS,
+  formatErrorRate,
+  overallStatus,
+} from "../lib/systemStatus";
+import { Timestamp } from "../components/Timestamp";
+
+const CONNECTION_LABELS: Record<ConnectionStatus, string> = {
+  [ConnectionStatus.CONNECTED]: "Connected",
+  [ConnectionStatus.CONNECTING]: "Connecting…",
+  [ConnectionStatus.RECONNECTING]: "Reconnecting…",
+  [ConnectionStatus.ERROR]: "Connection error",
+  [ConnectionStatus.DISCONNECTED]: "Disconnected",
+};
+
+interface MeasuredStatus {
+  status: SystemStatus;
+  roundTripMs: number;
+}
+
+async function measureStatus(): Promise<MeasuredStatus> {
+  const startedAt = performance.now();
+  const status = await fetchSystemStatus();
+  return { status, roundTripMs: Math.round(performance.now() - startedAt) };
+}
+
+type Tone = "ok" | "warn" | "error";
+
+function StatusTile({ title, value, detail, tone }: { title: string; value: string; detail?: string; tone: Tone }) {
+  return (
+    <div className={clsx("status-tile", `status-tile--${tone}`)}>
+      <span className="status-tile-title">{title}</span>
+      <span className="status-tile-value">{value}</span>
+      {detail && <span className="status-tile-detail">{detail}</span>}
+    </div>
+  );
+}
+
+export default function StatusPage() {
+  const { connectionStatus } = useWebSocket(true, { includeAuth: true, invalidateQueries: [] });
+  const statusQuery = useQuery({
+    queryKey: ["system-status"],
+    queryFn: measureStatus,
+    refetchInterval: STATUS_REFRESH_MS,
+    refetchIntervalInBackground: true,
+    retry: false,
+  });
+
+  // Keep showing the last numbers while the API is unreachable
+  const measured = statusQuery.data;
+  const status = measured?.status;
+  const apiReachable = !statusQuery.isError;
+  const overall = overallStatus({ status, apiReachable, connection: connectionStatus });
+
+  return (
+    <div className="status-page" data-testid="status-page">
+      <header className={clsx("status-page-banner", `status-page-banner--${overall}`)} role="status">
+        <h1>{OVERALL_STATUS_LABELS[overall]}</h1>
+        <span className="status-page-checked">
+          {statusQuery.dataUpdatedAt ? (
+            <>
+              Checked <Timestamp value={new Date(statusQuery.dataUpdatedAt).toISOString()} format="time" />
+            </>
+          ) : (
+            "Checking…"
+          )}
+        </span>
+      </header>
+
+      <div className="status-grid">
+        <StatusTile
+          title="API"
+          value={apiReachable ? (measured ? "Reachable" : "Checking…") : "Unreachable"}
+          detail={
+            apiReachable && measured
+              ? `${measured.roundTripMs} ms round trip`
+              : statusQuery.error
+                ? String(statusQuery.error)
+                : undefined
+          }
+          tone={apiReachable ? "ok" : "error"}
+        />
+        <StatusTile
+          title="Database"
+          value={status ? (status.database === "ok" ? "OK" : "Error") : "—"}
+          tone={!status || status.database === "ok" ? "ok" : "error"}
+        />
+        <StatusTile
+          title="Live updates"
+          value={CONNECTION_LABELS[connectionStatus]}
+          detail={status?.websocket.available ? `${status.websocket.active_connections} connected clients` : undefined}
+          tone={
+            connectionStatus === ConnectionStatus.CONNECTED
+              ? "ok"
+              : connectionStatus === ConnectionStatus.ERROR
+                ? "error"
+                : "warn"
+          }
+        />
+        <StatusTile
+          title="Failed runs (1h)"
+          value={status ? String(status.errors_last_hour) : "—"}
+          detail={
+            status ? `${formatErrorRate(status.error_rate_last_hour)} of ${status.runs_last_hour} runs` : undefined
+          }
+          tone={status?.status === "degraded" && status.errors_last_hour > 0 ? "error" : "ok"}
+        />
+        <StatusTile
+          title="Failed runs (24h)"
+          value={status ? String(status.errors_last_24h) : "—"}
+          tone="ok"
+        />
+        <StatusTile
+          title="Browser errors (1h)"
+          value={status ? String(status.frontend_errors_last_hour) : "—"}
+          tone={status && status.frontend_errors_last_hour > 0 ? "warn" : "ok"}
+        />
+        <StatusTile
+          title="Run latency (1h)"
+          value={status ? `${status.latency_ms.p50} ms` : "—"}
+          detail={status ? `p95 ${status.latency_ms.p95} ms` : undefined}
+          tone="ok"
+        />
+      </div>
+    </div>
+  );
+}
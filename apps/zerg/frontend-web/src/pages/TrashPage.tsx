@@ -0,0 +1,176 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Link } from "react-router-dom";
+import { toast } from "react-hot-toast";
+import {
+  AGENT_TRASH_RETENTION_DAYS,
+  fetchTrashedAgents,
+  fetchTrashedWorkflows,
+  permanentlyDeleteAgent,
+  permanentlyDeleteWorkflow,
+  type AgentSummary,
+  type Workflow,
+} from "../services/api";
+import {
+  daysUntilPurge,
+  invalidateTrashLists,
+  restoreTrashed,
+  toTrashedItem,
+  type TrashedItem,
+  type TrashedKind,
+} from "../lib/trash";
+
+export { daysUntilPurge };
+
+// What a permanent delete takes with it, for the confirmation prompt
+const PURGE_WARNINGS: Record<TrashedKind, string> = {
+  agent: "Its threads and run history will be removed.",
+  workflow: "Its canvas, layout and execution history will be removed.",
+};
+
+interface TrashTableProps {
+  title: string;
+  items: TrashedItem[];
+  busy: TrashedItem | undefined;
+  onRestore: (item: TrashedItem) => void;
+  onDelete: (item: TrashedItem) => void;
+}
+
+function TrashTable({ title, items, busy, onRestore, onDelete }: TrashTableProps) {
+  if (items.length === 0) return null;
+  return (
+    <section className="trash-section">
+      <h3>{title}</h3>
+      <table className="agents-table trash-table">
+        <thead>
+          <tr>
+            <th>Name</th>
+            <th>Deleted</th>
+            <th>Removed in</th>
+            <th aria-label="Actions" />
+          </tr>
+        </thead>
+        <tbody>
+          {items.map((item) => {
+            const daysLeft = daysUntilPurge(item.deletedAt);
+            const isBusy = busy?.kind === item.kind && busy.id === item.id;
+            return (
+              <tr key={item.id} data-testid={`trash-row-${item.kind}-${item.id}`}>
+                <td>{item.name}</td>
+                <td>{item.deletedAt ? new Date(item.deletedAt).toLocaleString() : "-"}</td>
+                <td>{daysLeft === 1 ? "1 day" : `${daysLeft} days`}</td>
+                <td className="trash-actions">
+                  <button type="button" className="btn-secondary" onClick={() => onRestore(item)} disabled={isBusy}>
+                    Restore
+                  </button>
+                  <button type="button" className="btn-danger" onClick={() => onDelete(item)} disabled={isBusy}>
+                    Delete forever
+                  </button>
+                </td>
+              </tr>
+            );
+          })}
+        </tbody>
+      </table>
+    </section>
+  );
+}
+
+/**
+ * Agents and workflows moved to the trash from the dashboard or canvas. They
+ * can be restored until the retention window runs out; deleting one here is
+ * permanent.
+ */
+export default function TrashPage() {
+  const queryClient = useQueryClient();
+
+  const agentsQuery = useQuery<AgentSummary[]>({
+    queryKey: ["agents-trash"],
+    queryFn: fetchTrashedAgents,
+  });
+  const workflowsQuery = useQuery<Workflow[]>({
+    queryKey: ["workflows-trash"],
+    queryFn: fetchTrashedWorkflows,
+  });
+
+  const restoreMutation = useMutation({
+    mutationFn: (item: TrashedItem) => restoreTrashed(item),
+    onSuccess: (_data, item) => {
+      invalidateTrashLists(queryClient);
+      toast.success(`Restored ${item.name}`);
+    },
+    onError: (error: Error, item) => {
+      toast.error(`Failed to restore ${item.kind}: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  const purgeMutation = useMutation({
+    mutationFn: (item: TrashedItem) =>
+      item.kind === "workflow" ? permanentlyDeleteWorkflow(item.id) : permanentlyDeleteAgent(item.id),
+    onSuccess: (_data, item) => {
+      invalidateTrashLists(queryClient);
+      toast.success(`Deleted ${item.name} permanently`);
+    },
+    onError: (error: Error, item) => {
+      toast.error(`Failed to delete ${item.kind}: ${error.message}`, { duration: 6000 });
+    },
+  });
+
+  function handlePermanentDelete(item: TrashedItem) {
+    const confirmed =
+      typeof window === "undefined" ||
+      window.confirm(`Permanently delete ${item.name}? ${PURGE_WARNINGS[item.kind]} This cannot be undone.`);
+    if (confirmed) {
+      purgeMutation.mutate(item);
+    }
+  }
+
+  const agents = (agentsQuery.data ?? []).map((agent) => toTrashedItem("agent", agent));
+  const workflows = (workflowsQuery.data ?? []).map((workflow) => toTrashedItem("workflow", workflow));
+  const busy = restoreMutation.isPending
+    ? restoreMutation.variables
+    : purgeMutation.isPending
+      ? purgeMutation.variables
+      : undefined;
+  const error = agentsQuery.error ?? workflowsQuery.error;
+
+  return (
+    <div className="trash-container">
+      <div className="trash-header">
+        <Link to="/dashboard" className="trash-back-link">
+          ← Back to dashboard
+        </Link>
+        <h2>Trash</h2>
+        <p className="trash-description">
+          Deleted agents and workflows are kept for {AGENT_TRASH_RETENTION_DAYS} days before they are removed for good.
+        </p>
+      </div>
+
+      {agentsQuery.isLoading || workflowsQuery.isLoading ? (
+        <p className="muted">Loading trash…</p>
+      ) : error ? (
+        <p className="error-message">Failed to load trash: {String(error)}</p>
+      ) : agents.length === 0 && workflows.length === 0 ? (
+        <p className="trash-empty" data-testid="trash-empty">
+          Trash is empty
+        </p>
+      ) : (
+        <>
+          <TrashTable
+            title="Agents"
+            items={agents}
+            busy={busy}
+            onRestore={(item) => restoreMutation.mutate(item)}
+            onDelete={handlePermanentDelete}
+          />
+          <TrashTable
+            title="Workflows"
+            items={workflows}
+            busy={busy}
+            onRestore={(item) => restoreMutation.mutate(item)}
+            onDelete={handlePermanentDelete}
+          />
+        </>
+      )}
+    </div>
+  );
+}
@@ -1,6 +1,7 @@
-import { render, screen, waitFor, within } from "@testing-library/react";
+import { cleanup, render, screen, waitFor, within } from "@testing-library/react";
 import userEvent from "@testing-library/user-event";
 import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
+import { MemoryRouter } from "react-router-dom";
 import { beforeEach, describe, expect, it, vi } from "vitest";
 import AdminPage from "../AdminPage";
 import config from "../../lib/config";
@@ -73,6 +74,27 @@ const mockOpsSummary = {
   ],
 };
 
+const adminCapabilities = {
+  role: "ADMIN",
+  capabilities: [
+    "manage_any_agent",
+    "manage_maintenance",
+    "reset_database",
+    "use_any_model",
+    "view_all_agents",
+    "view_ops",
+  ],
+};
+
+function capabilitiesResponse(body: typeof adminCapabilities) {
+  return Promise.resolve({
+    ok: true,
+    status: 200,
+    headers: new Headers({ "content-type": "application/json" }),
+    json: () => Promise.resolve(body),
+  });
+}
+
 function renderAdminPage() {
   // Mock localStorage.zerg_jwt that AdminPage expects
   Object.defineProperty(window, 'localStorage', {
@@ -96,7 +118,9 @@ function renderAdminPage() {
 
   return render(
     <QueryClientProvider client={queryClient}>
-      <AdminPage />
+      <MemoryRouter>
+        <AdminPage />
+      </MemoryRouter>
     </QueryClientProvider>
   );
 }
@@ -121,6 +145,15 @@ describe("AdminPage", () => {
           json: () => Promise.resolve({ is_super_admin: true, requires_password: false }),
         });
       }
+      if (url.includes(`${config.apiBaseUrl}/admin/maintenance`)) {
+        return Promise.resolve({
+          ok: true,
+          json: () => Promise.resolve({ enabled: false }),
+        });
+      }
+      if (url.includes(`${config.apiBaseUrl}/users/me/capabilities`)) {
+        return capabilitiesResponse(adminCapabilities);
+      }
       if (url.includes(`${config.apiBaseUrl}/admin/reset-database`)) {
         return Promise.resolve({
           ok: true,
@@ -180,6 +213,22 @@ describe("AdminPage", () => {
     expect(screen.getAllByText("300ms").length).toBeGreaterThan(0); // p95_ms
   });
 
+  it("toggles maintenance mode", async () => {
+    renderAdminPage();
+    const user = userEvent.setup();
+
+    const toggle = await screen.findByRole("button", { name: "Enable Maintenance Mode" });
+    await waitFor(() => expect(toggle).toBeEnabled());
+    await user.click(toggle);
+
+    await waitFor(() => {
+      expect(mockFetch).toHaveBeenCalledWith(
+        `${config.apiBaseUrl}/admin/maintenance`,
+        expect.objectContaining({ method: "PUT", body: JSON.stringify({ enabled: true }) })
+      );
+    });
+  });
+
   it("allows changing time window", async () => {
     renderAdminPage();
     const user = userEvent.setup();
@@ -195,6 +244,34 @@ describe("AdminPage", () => {
     expect(select.value).toBe("7d");
   });
 
+  it("charts runs and errors from the ops timeseries", async () => {
+    const defaultFetch = mockFetch.getMockImplementation()!;
+    mockFetch.mockImplementation((input: RequestInfo | URL) => {
+      const url = typeof input === "string" ? input : input.toString();
+      if (url.includes(`${config.apiBaseUrl}/ops/timeseries`)) {
+        const metric = new URL(url, "http://localhost").searchParams.get("metric");
+        const values = metric === "errors_by_hour" ? [0, 1] : metric === "runs_by_hour" ? [3, 4] : [0.01, 0.02];
+        return Promise.resolve({
+          ok: true,
+          json: () =>
+            Promise.resolve({ series: values.map((value, hour) => ({ hour_iso: `0${hour}:00Z`, value })) }),
+        });
+      }
+      return defaultFetch(input);
+    });
+
+    renderAdminPage();
+
+    const chart = await screen.findByRole("img", { name: "Runs and errors over time" });
+    expect(chart.querySelectorAll(".ts-chart__area")).toHaveLength(2);
+    expect(screen.getByText("Runs without errors")).toBeInTheDocument();
+    expect(screen.getByRole("img", { name: "Cost over time" })).toBeInTheDocument();
+    expect(mockFetch).toHaveBeenCalledWith(
+      expect.stringContaining("metric=errors_by_hour&window=today"),
+      expect.anything()
+    );
+  });
+
   it("handles API errors gracefully", async () => {
     // Mock API failure
     mockFetch.mockResolvedValueOnce({
@@ -212,6 +289,35 @@ describe("AdminPage", () => {
     expect(screen.getByText("Retry")).toBeInTheDocument();
   });
 
+  it("gates the page and destructive actions on capabilities", async () => {
+    const defaultFetch = mockFetch.getMockImplementation()!;
+    mockFetch.mockImplementation((input: RequestInfo | URL, init?: RequestInit) => {
+      const url = typeof input === "string" ? input : input.toString();
+      if (url.includes("/users/me/capabilities")) {
+        return capabilitiesResponse({ role: "ADMIN", capabilities: ["view_ops", "manage_maintenance"] });
+      }
+      return defaultFetch(input, init);
+    });
+
+    renderAdminPage();
+
+    await waitFor(() => expect(screen.getByRole("button", { name: "Full Database Reset" })).toBeDisabled());
+    expect(screen.getByRole("button", { name: "Clear User Data" })).toBeDisabled();
+
+    cleanup();
+    mockFetch.mockImplementation((input: RequestInfo | URL, init?: RequestInit) => {
+      const url = typeof input === "string" ? input : input.toString();
+      if (url.includes("/users/me/capabilities")) {
+        return capabilitiesResponse({ role: "USER", capabilities: [] });
+      }
+      return defaultFetch(input, init);
+    });
+
+    renderAdminPage();
+
+    expect(await screen.findByTestId("admin-access-required")).toBeInTheDocument();
+  });
+
   it("shows loading states", async () => {
     // Mock delayed response
     let resolvePromise: (value: any) => void;
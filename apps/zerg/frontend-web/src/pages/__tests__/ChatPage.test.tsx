@@ -3,15 +3,17 @@ import userEvent from "@testing-library/user-event";
 import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
 import { MemoryRouter, Route, Routes } from "react-router-dom";
 import { beforeEach, describe, expect, it, vi } from "vitest";
+import { toast } from "react-hot-toast";
 
 import ChatPage from "../ChatPage";
 import { ShelfProvider } from "../../lib/useShelfState";
-import type { Thread, ThreadMessage } from "../../services/api";
+import type { Thread, ThreadMessage, ThreadUpdatePayload } from "../../services/api";
+import { chatDraftKey, DRAFT_STORAGE_PREFIX } from "../../lib/drafts";
 
 const apiMocks = vi.hoisted(() => ({
   fetchAgent: vi.fn(),
   fetchThreads: vi.fn(),
-  fetchThreadMessages: vi.fn(),
+  fetchThreadMessagesPage: vi.fn(),
   postThreadMessage: vi.fn(),
   runThread: vi.fn(),
   createThread: vi.fn(),
@@ -20,6 +22,13 @@ const apiMocks = vi.hoisted(() => ({
   startWorkflowExecution: vi.fn(),
   fetchContainerPolicy: vi.fn(),
   fetchAccountConnectors: vi.fn().mockResolvedValue([]),
+  uploadAttachment: vi.fn(),
+  updateMessageMetadata: vi.fn(),
+  fetchBookmarkedMessages: vi.fn().mockResolvedValue([]),
+  fetchModels: vi.fn().mockResolvedValue([
+    { id: "gpt-5.1-chat-latest", display_name: "GPT-5.1", provider: "openai", is_default: true },
+    { id: "gpt-5-mini", display_name: "GPT-5 mini", provider: "openai", is_default: false },
+  ]),
 }));
 
 vi.mock("../../services/api", async (importOriginal) => {
@@ -33,7 +42,7 @@ vi.mock("../../services/api", async (importOriginal) => {
 const {
   fetchAgent: mockFetchAgent,
   fetchThreads: mockFetchThreads,
-  fetchThreadMessages: mockFetchThreadMessages,
+  fetchThreadMessagesPage: mockFetchThreadMessages,
   postThreadMessage: mockPostThreadMessage,
   runThread: mockRunThread,
   createThread: mockCreateThread,
@@ -41,6 +50,9 @@ const {
   fetchWorkflows: mockFetchWorkflows,
   startWorkflowExecution: mockStartWorkflowExecution,
   fetchContainerPolicy: mockFetchContainerPolicy,
+  uploadAttachment: mockUploadAttachment,
+  updateMessageMetadata: mockUpdateMessageMetadata,
+  fetchBookmarkedMessages: mockFetchBookmarkedMessages,
 } = apiMocks;
 
 function renderChatPage(initialEntry = "/agent/1/thread/42") {
@@ -68,6 +80,7 @@ describe("ChatPage", () => {
 
   beforeEach(() => {
     vi.clearAllMocks();
+    window.localStorage.clear();
 
     const now = new Date().toISOString();
     const thread: Thread = {
@@ -78,6 +91,8 @@ describe("ChatPage", () => {
       memory_strategy: "buffer",
       active: true,
       thread_type: "chat",
+      pinned: false,
+      archived: false,
       created_at: now,
       updated_at: now,
       messages: [],
@@ -120,12 +135,23 @@ describe("ChatPage", () => {
       id: 100,
       title: "Generated",
     });
-    mockUpdateThread.mockImplementation((_threadId: number, payload: { title?: string | null }) => {
-      if (typeof payload.title === "string" && payload.title.trim().length > 0) {
-        threadState = { ...threadState, title: payload.title };
+    mockUpdateThread.mockImplementation(
+      (_threadId: number, payload: ThreadUpdatePayload) => {
+        if (typeof payload.title === "string" && payload.title.trim().length > 0) {
+          threadState = { ...threadState, title: payload.title };
+        }
+        if (typeof payload.pinned === "boolean") {
+          threadState = { ...threadState, pinned: payload.pinned };
+        }
+        if (typeof payload.archived === "boolean") {
+          threadState = { ...threadState, archived: payload.archived, pinned: payload.archived ? false : threadState.pinned };
+        }
+        if (typeof payload.model_override === "string") {
+          threadState = { ...threadState, model_override: payload.model_override || null };
+        }
+        return Promise.resolve(threadState);
       }
-      return Promise.resolve(threadState);
-    });
+    );
 
     // Mock workflow functions
     mockFetchWorkflows.mockResolvedValue([
@@ -162,11 +188,26 @@ describe("ChatPage", () => {
     await user.click(sendButton);
 
     await waitFor(() => {
-      expect(mockPostThreadMessage).toHaveBeenCalledWith(42, "New human message");
+      expect(mockPostThreadMessage).toHaveBeenCalledWith(42, "New human message", []);
       expect(mockRunThread).toHaveBeenCalledWith(42);
     });
   });
 
+  it("restores an unsent draft for the thread and drops it once discarded", async () => {
+    const storageKey = `${DRAFT_STORAGE_PREFIX}${chatDraftKey(42)}`;
+    window.localStorage.setItem(storageKey, JSON.stringify({ value: "Half-written question", savedAt: Date.now() }));
+    renderChatPage();
+
+    expect(await screen.findByTestId("draft-restored")).toHaveTextContent("Draft restored");
+    expect(screen.getByTestId("chat-input")).toHaveValue("Half-written question");
+
+    const user = userEvent.setup();
+    await user.click(screen.getByRole("button", { name: "Discard" }));
+    expect(screen.getByTestId("chat-input")).toHaveValue("");
+    expect(screen.queryByTestId("draft-restored")).not.toBeInTheDocument();
+    expect(window.localStorage.getItem(storageKey)).toBeNull();
+  });
+
   it("renames a thread and persists via API", async () => {
     renderChatPage();
 
@@ -187,4 +228,209 @@ describe("ChatPage", () => {
       expect(screen.getByText("Renamed")).toBeInTheDocument();
     });
   });
+
+  it("pins and archives a thread from the sidebar", async () => {
+    renderChatPage();
+
+    const user = userEvent.setup();
+
+    await user.click(await screen.findByTestId("pin-thread-42"));
+    await waitFor(() => {
+      expect(mockUpdateThread).toHaveBeenCalledWith(42, { pinned: true, archived: undefined });
+    });
+    expect(await screen.findByLabelText("Pinned")).toBeInTheDocument();
+
+    await user.click(screen.getByTestId("archive-thread-42"));
+    await waitFor(() => {
+      expect(mockUpdateThread).toHaveBeenCalledWith(42, { pinned: undefined, archived: true });
+    });
+
+    // Archived threads move into the collapsed "Archived" section
+    const archivedHeader = await screen.findByTestId("archived-threads-header");
+    expect(screen.queryByTestId("archived-threads-list")).not.toBeInTheDocument();
+    await user.click(archivedHeader);
+    const archivedList = await screen.findByTestId("archived-threads-list");
+    expect(archivedList).toContainElement(screen.getByTestId("thread-row-42"));
+  });
+
+  it("overrides the model for the current thread from the header", async () => {
+    renderChatPage();
+
+    const user = userEvent.setup();
+    const picker = await screen.findByTestId("thread-model-picker");
+    await screen.findByRole("option", { name: "GPT-5 mini" });
+    expect(picker).toHaveValue("");
+    expect(screen.getByRole("option", { name: "Agent default (GPT-5.1)" })).toBeInTheDocument();
+
+    await user.selectOptions(picker, "gpt-5-mini");
+    await waitFor(() => {
+      expect(mockUpdateThread).toHaveBeenCalledWith(42, { model_override: "gpt-5-mini" });
+    });
+    await waitFor(() => expect(screen.getByTestId("thread-model-picker")).toHaveValue("gpt-5-mini"));
+
+    await user.selectOptions(screen.getByTestId("thread-model-picker"), "");
+    await waitFor(() => {
+      expect(mockUpdateThread).toHaveBeenCalledWith(42, { model_override: "" });
+    });
+  });
+
+  it("bookmarks and reacts to a message and lists it under saved messages", async () => {
+    const stored = { id: 99, thread_id: 42, role: "user", content: "Hello from storage", processed: true };
+    mockUpdateMessageMetadata.mockImplementation(
+      (_threadId: number, _messageId: number, update: { bookmarked?: boolean; reaction?: string }) =>
+        Promise.resolve({
+          ...stored,
+          bookmarked: update.bookmarked ?? true,
+          reactions: update.reaction ? [{ emoji: update.reaction, count: 1, reacted: true }] : [],
+        })
+    );
+    renderChatPage();
+
+    const user = userEvent.setup();
+    await user.click(await screen.findByTestId("message-bookmark"));
+    await waitFor(() => expect(mockUpdateMessageMetadata).toHaveBeenCalledWith(42, 99, { bookmarked: true }));
+    await waitFor(() => expect(screen.getByTestId("message-bookmark")).toHaveAttribute("aria-pressed", "true"));
+
+    await user.click(screen.getByTestId("message-react"));
+    await user.click(screen.getByRole("menuitem", { name: "React with 🎉" }));
+    await waitFor(() => expect(mockUpdateMessageMetadata).toHaveBeenCalledWith(42, 99, { reaction: "🎉" }));
+    expect(await screen.findByTestId("message-reactions")).toHaveTextContent("🎉 1");
+
+    mockFetchBookmarkedMessages.mockResolvedValue([{ ...stored, bookmarked: true }]);
+    await user.click(screen.getByTestId("saved-messages-toggle"));
+    const saved = await screen.findByTestId("saved-message-99");
+    expect(saved).toHaveTextContent("Primary");
+    expect(mockFetchBookmarkedMessages).toHaveBeenCalledWith(1);
+  });
+
+  it("offers retry when the agent run fails after the message posted", async () => {
+    mockRunThread.mockRejectedValueOnce(new Error("boom"));
+    renderChatPage();
+
+    const input = await screen.findByTestId("chat-input");
+    const user = userEvent.setup();
+    await user.type(input, "New human message");
+    await user.click(await screen.findByTestId("send-message-btn"));
+
+    const retryButton = await screen.findByRole("button", { name: "Retry" });
+    // The message was stored, so retry only re-runs the thread
+    await user.click(retryButton);
+
+    await waitFor(() => {
+      expect(mockRunThread).toHaveBeenCalledTimes(2);
+    });
+    expect(mockPostThreadMessage).toHaveBeenCalledTimes(1);
+  });
+
+  it("labels message authors once a thread has other participants", async () => {
+    const now = new Date().toISOString();
+    mockFetchThreadMessages.mockResolvedValue([
+      {
+        id: 99,
+        thread_id: 42,
+        role: "user",
+        content: "Mine",
+        sent_at: now,
+        processed: true,
+        author: { id: 1, display_name: "Test User", avatar_url: null },
+      },
+      {
+        id: 101,
+        thread_id: 42,
+        role: "user",
+        content: "Theirs",
+        sent_at: now,
+        processed: true,
+        author: { id: 7, display_name: "Sam Rivera", avatar_url: null },
+      },
+    ]);
+    renderChatPage();
+
+    await screen.findByText("Theirs");
+    expect(screen.getAllByTestId("message-author")).toHaveLength(2);
+    expect(screen.getByText("You")).toBeInTheDocument();
+    expect(screen.getByText("Sam Rivera")).toBeInTheDocument();
+    // Only the viewer's own message can be edited and resent
+    expect(screen.getAllByTitle("Edit and resend")).toHaveLength(1);
+  });
+
+  it("explains when another participant's reply is still running", async () => {
+    const { ApiError } = await import("../../services/api");
+    const toastError = vi.spyOn(toast, "error");
+    mockRunThread.mockRejectedValueOnce(
+      new ApiError({ url: "/threads/42/run", status: 409, body: { detail: "The agent is already replying in this thread" } })
+    );
+    renderChatPage();
+
+    const input = await screen.findByTestId("chat-input");
+    const user = userEvent.setup();
+    await user.type(input, "New human message");
+    await user.click(await screen.findByTestId("send-message-btn"));
+
+    expect(await screen.findByRole("button", { name: "Retry" })).toBeInTheDocument();
+    expect(toastError).toHaveBeenCalledWith(
+      "The agent is already replying in this thread. Retry once it finishes.",
+      { duration: 6000 }
+    );
+  });
+
+  it("shows reply usage footers and thread totals", async () => {
+    const now = new Date().toISOString();
+    const reply = (id: number, totalTokens: number, costUsd: number | null): ThreadMessage => ({
+      id,
+      thread_id: 42,
+      role: "assistant",
+      content: `Reply ${id}`,
+      sent_at: now,
+      processed: true,
+      usage: {
+        run_id: id,
+        model: "gpt-5.1-chat-latest",
+        prompt_tokens: totalTokens - 100,
+        completion_tokens: 100,
+        total_tokens: totalTokens,
+        duration_ms: 2300,
+        cost_usd: costUsd,
+      },
+    });
+    mockFetchThreadMessages.mockResolvedValue([reply(1, 1200, 0.004), reply(2, 800, 0.012)]);
+    renderChatPage();
+
+    await screen.findByText("Reply 2");
+    const footers = screen.getAllByTestId("message-usage");
+    expect(footers[0]).toHaveTextContent("1.2k tokens · gpt-5.1-chat-latest · 2.3s · $0.0040");
+    expect(footers[1]).toHaveTextContent("800 tokens · gpt-5.1-chat-latest · 2.3s · $0.01");
+    expect(screen.getByTestId("thread-usage-stats")).toHaveTextContent("2.0k tokens · $0.02 · 2 replies");
+  });
+
+  it("uploads picked files and sends them with the message", async () => {
+    const attachment = {
+      id: "abc",
+      filename: "notes.txt",
+      content_type: "text/plain",
+      size: 5,
+      url: "/static/attachments/abc.txt",
+    };
+    mockUploadAttachment.mockImplementation((_threadId: number, _file: File, options?: { onProgress?: (p: number) => void }) => {
+      options?.onProgress?.(0.5);
+      return Promise.resolve(attachment);
+    });
+    renderChatPage();
+
+    const user = userEvent.setup();
+    const fileInput = await screen.findByTestId("attach-file-input");
+    await user.upload(fileInput, new File(["hello"], "notes.txt", { type: "text/plain" }));
+
+    expect(await screen.findByText("notes.txt")).toBeInTheDocument();
+    expect(mockUploadAttachment).toHaveBeenCalledWith(42, expect.any(File), expect.any(Object));
+
+    // Attachments alone are enough to send
+    const sendButton = await screen.findByTestId("send-message-btn");
+    await waitFor(() => expect(sendButton).not.toBeDisabled());
+    await user.click(sendButton);
+
+    await waitFor(() => {
+      expect(mockPostThreadMessage).toHaveBeenCalledWith(42, "", [attachment]);
+    });
+  });
 });
@@ -6,8 +6,17 @@ import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
 import { MemoryRouter } from "react-router-dom";
 import DashboardPage from "../DashboardPage";
 import {
+  cancelRun,
   fetchDashboardSnapshot,
   createAgent,
+  deleteAgent,
+  dropQueuedRun,
+  duplicateAgent,
+  fetchAgentDependents,
+  fetchAgentUsage,
+  fetchSystemInfo,
+  pauseAgent,
+  reorderRunQueue,
   runAgent,
   type AgentSummary,
   type AgentRun,
@@ -22,6 +31,20 @@ vi.mock("../../services/api", async (importOriginal) => {
     createAgent: vi.fn(),
     resetAgent: vi.fn(),
     runAgent: vi.fn(),
+    deleteAgent: vi.fn(),
+    fetchAgentDependents: vi.fn(),
+    fetchAgentUsage: vi.fn(),
+    restoreAgent: vi.fn(),
+    fetchTrashedAgents: vi.fn().mockResolvedValue([]),
+    fetchTrashedWorkflows: vi.fn().mockResolvedValue([]),
+    duplicateAgent: vi.fn(),
+    pauseAgent: vi.fn(),
+    resumeAgent: vi.fn(),
+    fetchSystemInfo: vi.fn(),
+    cancelRun: vi.fn(),
+    reorderRunQueue: vi.fn(),
+    dropQueuedRun: vi.fn(),
+    fetchCapabilities: vi.fn().mockResolvedValue({ role: "USER", capabilities: [] }),
   };
 });
 
@@ -52,6 +75,7 @@ function buildAgent(
     messages: overrides.messages ?? [],
     next_run_at: overrides.next_run_at ?? null,
     last_run_at: overrides.last_run_at ?? null,
+    paused_at: overrides.paused_at ?? null,
   };
 }
 
@@ -59,6 +83,15 @@ describe("DashboardPage", () => {
   const fetchDashboardSnapshotMock = fetchDashboardSnapshot as unknown as vi.MockedFunction<typeof fetchDashboardSnapshot>;
   const createAgentMock = createAgent as unknown as vi.MockedFunction<typeof createAgent>;
   const runAgentMock = runAgent as unknown as vi.MockedFunction<typeof runAgent>;
+  const deleteAgentMock = deleteAgent as unknown as vi.MockedFunction<typeof deleteAgent>;
+  const fetchAgentDependentsMock = fetchAgentDependents as unknown as vi.MockedFunction<typeof fetchAgentDependents>;
+  const duplicateAgentMock = duplicateAgent as unknown as vi.MockedFunction<typeof duplicateAgent>;
+  const pauseAgentMock = pauseAgent as unknown as vi.MockedFunction<typeof pauseAgent>;
+  const fetchSystemInfoMock = fetchSystemInfo as unknown as vi.MockedFunction<typeof fetchSystemInfo>;
+  const cancelRunMock = cancelRun as unknown as vi.MockedFunction<typeof cancelRun>;
+  const reorderRunQueueMock = reorderRunQueue as unknown as vi.MockedFunction<typeof reorderRunQueue>;
+  const dropQueuedRunMock = dropQueuedRun as unknown as vi.MockedFunction<typeof dropQueuedRun>;
+  const fetchAgentUsageMock = fetchAgentUsage as unknown as vi.MockedFunction<typeof fetchAgentUsage>;
   const mockSockets: MockWebSocketInstance[] = [];
 
   beforeAll(() => {
@@ -93,6 +126,24 @@ describe("DashboardPage", () => {
     createAgentMock.mockReset();
     runAgentMock.mockReset();
     runAgentMock.mockResolvedValue(undefined);
+    deleteAgentMock.mockReset();
+    deleteAgentMock.mockResolvedValue(undefined);
+    fetchAgentDependentsMock.mockReset();
+    fetchAgentDependentsMock.mockImplementation(async (agentId) => ({
+      agent_id: agentId,
+      workflows: [],
+      triggers: [],
+      schedule: null,
+      queued_run_ids: [],
+    }));
+    duplicateAgentMock.mockReset();
+    pauseAgentMock.mockReset();
+    fetchSystemInfoMock.mockReset();
+    cancelRunMock.mockReset();
+    reorderRunQueueMock.mockReset();
+    dropQueuedRunMock.mockReset();
+    fetchAgentUsageMock.mockReset();
+    fetchSystemInfoMock.mockResolvedValue({ auth_disabled: true, maintenance_mode: false });
   });
 
   afterEach(() => {
@@ -100,7 +151,12 @@ describe("DashboardPage", () => {
     window.localStorage.clear();
   });
 
-  function renderDashboard(initialAgents: AgentSummary[], runsByAgent?: Record<number, AgentRun[]>) {
+  function renderDashboard(
+    initialAgents: AgentSummary[],
+    runsByAgent?: Record<number, AgentRun[]>,
+    budgets: DashboardSnapshot["budgets"] = [],
+    stats: DashboardSnapshot["stats"] = []
+  ) {
     const runsLookup = runsByAgent ?? {};
     const snapshot: DashboardSnapshot = {
       scope: "my",
@@ -111,6 +167,8 @@ describe("DashboardPage", () => {
         agentId: agent.id,
         runs: runsLookup[agent.id] ?? [],
       })),
+      budgets,
+      stats,
     };
 
     fetchDashboardSnapshotMock.mockResolvedValue(snapshot);
@@ -177,6 +235,267 @@ describe("DashboardPage", () => {
     expect(within(agentRows[1]).getByText("Beta")).toBeInTheDocument();
   });
 
+  test("moves an agent nothing relies on to the trash without confirmation", async () => {
+    const agents: AgentSummary[] = [
+      buildAgent({ id: 1, name: "Keep", status: "idle", owner_id: 1 }),
+      buildAgent({ id: 2, name: "Discard", status: "idle", owner_id: 1 }),
+    ];
+    const confirmSpy = vi.spyOn(window, "confirm");
+
+    renderDashboard(agents);
+    await screen.findByText("Discard");
+
+    await userEvent.click(screen.getByTestId("delete-agent-2"));
+
+    await waitFor(() => expect(deleteAgentMock).toHaveBeenCalledWith(2));
+    expect(confirmSpy).not.toHaveBeenCalled();
+    expect(screen.queryByTestId("delete-agent-dialog")).not.toBeInTheDocument();
+    await waitFor(() => expect(screen.queryByText("Discard")).not.toBeInTheDocument());
+    expect(screen.getByText("Keep")).toBeInTheDocument();
+    expect(screen.getByTestId("dashboard-trash-link")).toHaveAttribute("href", "/trash");
+    confirmSpy.mockRestore();
+  });
+
+  test("lists dependents before deleting and can disable them with the agent", async () => {
+    const agents: AgentSummary[] = [buildAgent({ id: 2, name: "Relied on", status: "idle", owner_id: 1 })];
+    fetchAgentDependentsMock.mockResolvedValue({
+      agent_id: 2,
+      workflows: [{ id: 7, name: "Nightly report" }],
+      triggers: [{ id: 3, type: "webhook", disabled: false }],
+      schedule: null,
+      queued_run_ids: [],
+    });
+
+    renderDashboard(agents);
+    await screen.findByText("Relied on");
+    await userEvent.click(screen.getByTestId("delete-agent-2"));
+
+    const dialog = await screen.findByTestId("delete-agent-dialog");
+    expect(within(dialog).getByText(/Nightly report/)).toBeInTheDocument();
+    expect(within(dialog).getByText(/1 active trigger \(webhook\)/)).toBeInTheDocument();
+    expect(deleteAgentMock).not.toHaveBeenCalled();
+
+    await userEvent.click(within(dialog).getByRole("button", { name: "Disable triggers and delete" }));
+    expect(deleteAgentMock).toHaveBeenCalledWith(2, { disableDependents: true });
+    expect(screen.queryByTestId("delete-agent-dialog")).not.toBeInTheDocument();
+  });
+
+  test("duplicates an agent and refreshes the list", async () => {
+    const original = buildAgent({ id: 1, name: "Original", status: "idle", owner_id: 1 });
+    duplicateAgentMock.mockResolvedValue(buildAgent({ id: 2, name: "Original (copy)", status: "idle", owner_id: 1 }));
+
+    renderDashboard([original]);
+    await screen.findByText("Original");
+    const fetchesBefore = fetchDashboardSnapshotMock.mock.calls.length;
+
+    await userEvent.click(screen.getByTestId("duplicate-agent-1"));
+
+    expect(duplicateAgentMock).toHaveBeenCalledWith(1);
+    await waitFor(() => expect(fetchDashboardSnapshotMock.mock.calls.length).toBeGreaterThan(fetchesBefore));
+  });
+
+  test("shows budget spend and blocks runs once the cap is reached", async () => {
+    const agents: AgentSummary[] = [
+      buildAgent({ id: 1, name: "Capped", status: "idle", owner_id: 1 }),
+      buildAgent({ id: 2, name: "Under", status: "idle", owner_id: 1 }),
+    ];
+    renderDashboard(agents, {}, [
+      { agent_id: 1, period: "daily", limit_usd: 1, spent_usd: 1.25, percent: 125 },
+      { agent_id: 2, period: "monthly", limit_usd: 10, spent_usd: 2.5, percent: 25 },
+    ]);
+    await screen.findByText("Capped");
+
+    expect(screen.getByText("$1.25 of $1.00 today")).toBeInTheDocument();
+    expect(screen.getByText("$2.50 of $10.00 this month")).toBeInTheDocument();
+
+    const blocked = screen.getByTestId("run-agent-1");
+    expect(blocked).toHaveAttribute("aria-disabled", "true");
+    await userEvent.click(blocked);
+    expect(runAgentMock).not.toHaveBeenCalled();
+
+    await userEvent.click(screen.getByTestId("run-agent-2"));
+    await waitFor(() => expect(runAgentMock).toHaveBeenCalledWith(2));
+  });
+
+  test("badges paused agents and confirms before running them manually", async () => {
+    const agents: AgentSummary[] = [
+      buildAgent({ id: 1, name: "Sleeper", status: "idle", owner_id: 1, paused_at: "2026-10-14T08:00:00Z" }),
+      buildAgent({ id: 2, name: "Awake", status: "idle", owner_id: 1 }),
+    ];
+    pauseAgentMock.mockResolvedValue({ ...agents[1], paused_at: "2026-10-14T09:00:00Z" });
+    fetchSystemInfoMock.mockResolvedValue({ auth_disabled: true, maintenance_mode: true });
+    const confirmSpy = vi.spyOn(window, "confirm").mockReturnValue(false);
+
+    renderDashboard(agents);
+    await screen.findByText("Sleeper");
+
+    expect(screen.getByTestId("paused-badge-1")).toBeInTheDocument();
+    expect(screen.queryByTestId("paused-badge-2")).not.toBeInTheDocument();
+    expect(await screen.findByTestId("maintenance-banner")).toBeInTheDocument();
+
+    await userEvent.click(screen.getByTestId("run-agent-1"));
+    expect(confirmSpy).toHaveBeenCalledOnce();
+    expect(runAgentMock).not.toHaveBeenCalled();
+
+    await userEvent.click(screen.getByTestId("pause-agent-2"));
+    expect(pauseAgentMock).toHaveBeenCalledWith(2);
+    expect(await screen.findByTestId("paused-badge-2")).toBeInTheDocument();
+    confirmSpy.mockRestore();
+  });
+
+  test("cancels the active run and shows it as cancelling", async () => {
+    const agents: AgentSummary[] = [buildAgent({ id: 1, name: "Runaway", status: "running", owner_id: 1 })];
+    const run: AgentRun = {
+      id: 77,
+      agent_id: 1,
+      thread_id: 5,
+      status: "running",
+      trigger: "manual",
+      started_at: "2026-10-14T09:00:00.000Z",
+      finished_at: null,
+      duration_ms: null,
+      total_tokens: null,
+      total_cost_usd: null,
+      error: null,
+    };
+    cancelRunMock.mockResolvedValue({ ...run, status: "cancelling" });
+
+    renderDashboard(agents, { 1: [run] });
+    await screen.findByText("Runaway");
+
+    await userEvent.click(screen.getByTestId("cancel-run-1"));
+
+    expect(cancelRunMock).toHaveBeenCalledWith(77);
+    await waitFor(() => expect(screen.getByTestId("cancel-run-1")).toBeDisabled());
+    expect(screen.getByTestId("cancel-run-1")).toHaveAttribute("aria-label", "Cancelling run");
+  });
+
+  test("shows queued runs and lets them be reordered or dropped", async () => {
+    const agents: AgentSummary[] = [buildAgent({ id: 1, name: "Busy", status: "running", owner_id: 1 })];
+    const baseRun: AgentRun = {
+      id: 10,
+      agent_id: 1,
+      thread_id: 20,
+      status: "running",
+      trigger: "manual",
+      started_at: "2026-10-14T09:00:00.000Z",
+      finished_at: null,
+      duration_ms: null,
+      total_tokens: null,
+      total_cost_usd: null,
+      error: null,
+    };
+    const runs: AgentRun[] = [
+      { ...baseRun, id: 12, thread_id: 22, status: "queued", trigger: "webhook", started_at: null, queue_position: 2 },
+      { ...baseRun, id: 11, thread_id: 21, status: "queued", trigger: "schedule", started_at: null, queue_position: 1 },
+      baseRun,
+    ];
+    reorderRunQueueMock.mockResolvedValue([runs[0], runs[1]]);
+    dropQueuedRunMock.mockResolvedValue({ ...runs[1], status: "cancelled", queue_position: null });
+
+    renderDashboard(agents, { 1: runs });
+    await screen.findByText("Busy");
+    expect(screen.getByTestId("queue-badge-1")).toHaveTextContent("2 queued");
+
+    await userEvent.click(screen.getByRole("row", { name: /Busy/ }));
+    const queue = await screen.findByTestId("run-queue-1");
+    const items = within(queue).getAllByRole("listitem");
+    expect(items.map((item) => item.textContent)).toEqual([
+      expect.stringContaining("Run #11"),
+      expect.stringContaining("Run #12"),
+    ]);
+    expect(within(queue).getByRole("button", { name: "Move run 11 up" })).toBeDisabled();
+
+    await userEvent.click(within(queue).getByRole("button", { name: "Move run 12 up" }));
+    expect(reorderRunQueueMock).toHaveBeenCalledWith(1, [12, 11]);
+
+    await userEvent.click(within(queue).getByTestId("drop-queued-run-11"));
+    expect(dropQueuedRunMock).toHaveBeenCalledWith(1, 11);
+    await waitFor(() => expect(screen.getByTestId("queue-badge-1")).toHaveTextContent("1 queued"));
+
+    // Cancelling targets the active run, not the newer queued one
+    cancelRunMock.mockResolvedValue({ ...baseRun, status: "cancelling" });
+    await userEvent.click(screen.getByTestId("cancel-run-1"));
+    expect(cancelRunMock).toHaveBeenCalledWith(10);
+  });
+
+  test("shows aggregated success rate, average duration and a weekly sparkline", async () => {
+    const agents: AgentSummary[] = [buildAgent({ id: 1, name: "Steady", status: "idle", owner_id: 1 })];
+    const daily = ["09-18", "09-19", "09-20", "09-21", "09-22", "09-23", "09-24"].map((day, index) => ({
+      date: `2025-${day}`,
+      count: index % 3,
+    }));
+    renderDashboard(agents, {}, [], [
+      {
+        agent_id: 1,
+        run_count: 40,
+        success_count: 30,
+        failed_count: 10,
+        success_rate: 75,
+        avg_duration_ms: 2500,
+        daily_runs: daily,
+      },
+    ]);
+    await screen.findByText("Steady");
+
+    expect(screen.getByText("75.0% (40)")).toBeInTheDocument();
+    expect(screen.getByText("avg 2.5 s")).toBeInTheDocument();
+    expect(screen.getByRole("img", { name: "6 runs in the last 7 days" })).toBeInTheDocument();
+  });
+
+  test("shows optional token and cost columns and sorts by cost", async () => {
+    const agents: AgentSummary[] = [
+      buildAgent({ id: 1, name: "Alpha", status: "idle", owner_id: 1 }),
+      buildAgent({ id: 2, name: "Beta", status: "idle", owner_id: 1 }),
+    ];
+    fetchAgentUsageMock.mockResolvedValue([
+      { agent_id: 1, days: 7, run_count: 2, total_tokens: 1200, total_cost_usd: 0.02, by_model: [] },
+      {
+        agent_id: 2,
+        days: 7,
+        run_count: 3,
+        total_tokens: 45000,
+        total_cost_usd: 1.5,
+        by_model: [
+          { model: "gpt-big", run_count: 2, total_tokens: 40000, total_cost_usd: 1.5 },
+          { model: "local-small", run_count: 1, total_tokens: 5000, total_cost_usd: null },
+        ],
+      },
+    ]);
+
+    renderDashboard(agents);
+    await screen.findByText("Alpha");
+    // Hidden by default, so nothing is fetched
+    expect(fetchAgentUsageMock).not.toHaveBeenCalled();
+    expect(document.querySelector('th[data-column="cost_7d"]')).toBeNull();
+
+    fireEvent.click(screen.getByTestId("dashboard-column-tokens"));
+    fireEvent.click(screen.getByTestId("dashboard-column-cost"));
+    expect(window.localStorage.getItem("dashboard_usage_columns")).toBe("tokens,cost");
+
+    expect(await screen.findByText("45k")).toBeInTheDocument();
+    expect(screen.getByTestId("agent-tokens-1")).toHaveTextContent("1.2k");
+    expect(screen.getByTestId("agent-cost-1")).toHaveTextContent("$0.020");
+    const cost = screen.getByTestId("agent-cost-2");
+    expect(cost).toHaveTextContent("$1.50");
+    expect(cost.getAttribute("title")).toContain("gpt-big: $1.50 · 40k tokens (2 runs)");
+    expect(cost.getAttribute("title")).toContain("local-small: —");
+    expect(fetchAgentUsageMock).toHaveBeenCalledWith("my", 7);
+
+    const costHeader = document.querySelector<HTMLElement>('th[data-column="cost_7d"]');
+    if (!costHeader) {
+      throw new Error("Cost header not found");
+    }
+    fireEvent.click(costHeader);
+    fireEvent.click(costHeader);
+    await waitFor(() => {
+      const rowOrder = Array.from(document.querySelectorAll<HTMLTableRowElement>("tr[data-agent-id]")).map((row) =>
+        row.getAttribute("data-agent-id")
+      );
+      expect(rowOrder).toEqual(["2", "1"]);
+    });
+  });
+
   test("expands an agent row and shows run history", async () => {
     const agent = buildAgent({
       id: 1,
@@ -283,6 +602,38 @@ describe("DashboardPage", () => {
     expect(screen.getByText("Show less")).toBeInTheDocument();
   });
 
+  test("opens run details with the trace id", async () => {
+    const agent = buildAgent({ id: 1, name: "Runner", status: "idle", owner_id: 1, owner: null });
+    const run: AgentRun = {
+      id: 42,
+      agent_id: 1,
+      thread_id: 9,
+      status: "failed",
+      trigger: "manual",
+      started_at: "2025-09-24T09:55:00.000Z",
+      finished_at: "2025-09-24T09:56:00.000Z",
+      duration_ms: 60000,
+      total_tokens: 120,
+      total_cost_usd: 0.12,
+      error: "Timed out",
+      trace_id: "3f9a1c2b7d4e8f60",
+    };
+
+    renderDashboard([agent], { 1: [run] });
+
+    await userEvent.click(await screen.findByRole("row", { name: /Runner/ }));
+    await userEvent.click(await screen.findByRole("button", { name: "Run details" }));
+
+    const drawer = screen.getByTestId("run-detail-drawer");
+    expect(within(drawer).getByText("Run #42")).toBeInTheDocument();
+    expect(within(drawer).getByText("Timed out")).toBeInTheDocument();
+    expect(within(drawer).getByText("3f9a1c2b7d4e8f60")).toBeInTheDocument();
+    expect(within(drawer).getByRole("button", { name: "Copy trace ID 3f9a1c2b7d4e8f60" })).toBeInTheDocument();
+
+    await userEvent.click(within(drawer).getByRole("button", { name: "Close run details" }));
+    expect(screen.queryByTestId("run-detail-drawer")).not.toBeInTheDocument();
+  });
+
   test("sorts agents by status and toggles sort direction", async () => {
     const agents: AgentSummary[] = [
       buildAgent({ id: 1, name: "Alpha", status: "idle", owner_id: 1 }),
@@ -365,4 +716,60 @@ describe("DashboardPage", () => {
       expect(statusCell.textContent).toContain("Running");
     });
   });
+
+  test("inserts and removes agents from user-topic deltas without refetching", async () => {
+    renderDashboard([buildAgent({ id: 1, name: "Existing", status: "idle", owner_id: 9 })]);
+
+    await screen.findByText("Existing");
+    const socket = mockSockets[0];
+    socket.onopen?.(new Event("open"));
+    const fetchesBefore = fetchDashboardSnapshotMock.mock.calls.length;
+
+    const send = (data: Record<string, unknown>) =>
+      socket.onmessage?.({ data: JSON.stringify({ type: "agent_event", topic: "user:9", data }) } as MessageEvent);
+
+    send({ ...buildAgent({ id: 2, name: "Fresh", status: "idle", owner_id: 9 }), action: "created", seq: 1 });
+    await screen.findByText("Fresh");
+
+    send({ ...buildAgent({ id: 1, name: "Existing", status: "idle", owner_id: 9 }), action: "deleted", seq: 2 });
+    await waitFor(() => expect(screen.queryByText("Existing")).not.toBeInTheDocument());
+    expect(fetchDashboardSnapshotMock.mock.calls.length).toBe(fetchesBefore);
+
+    // A skipped sequence number means a delta was lost
+    send({ ...buildAgent({ id: 2, name: "Fresh", status: "idle", owner_id: 9 }), action: "updated", seq: 5 });
+    await waitFor(() => expect(fetchDashboardSnapshotMock.mock.calls.length).toBeGreaterThan(fetchesBefore));
+  });
+
+  test("shows agents shared with the user and limits actions to the granted level", async () => {
+    renderDashboard([buildAgent({ id: 1, name: "Mine", status: "idle", owner_id: 1 })]);
+    await screen.findByText("Mine");
+    expect(screen.getByTestId("share-agent-1")).toBeInTheDocument();
+
+    fetchDashboardSnapshotMock.mockResolvedValue({
+      scope: "shared",
+      fetchedAt: new Date().toISOString(),
+      runsLimit: 50,
+      agents: [
+        buildAgent({ id: 5, name: "Viewable", status: "idle", owner_id: 9 }),
+        buildAgent({ id: 6, name: "Runnable", status: "idle", owner_id: 9 }),
+      ],
+      runs: [],
+      budgets: [],
+      stats: [],
+      permissions: [
+        { agent_id: 5, permission: "view" },
+        { agent_id: 6, permission: "run" },
+      ],
+    });
+    await userEvent.click(screen.getByTestId("dashboard-shared-filter"));
+
+    await screen.findByText("Viewable");
+    expect(fetchDashboardSnapshotMock).toHaveBeenLastCalledWith(expect.objectContaining({ scope: "shared" }));
+    expect(screen.getByTestId("dashboard-shared-filter")).toHaveAttribute("aria-pressed", "true");
+    expect(screen.queryByTestId("run-agent-5")).not.toBeInTheDocument();
+    expect(screen.getByTestId("run-agent-6")).toBeInTheDocument();
+    expect(screen.getByTestId("delete-agent-5")).toBeDisabled();
+    expect(screen.queryByTestId("pause-agent-6")).not.toBeInTheDocument();
+    expect(screen.queryByTestId("share-agent-6")).not.toBeInTheDocument();
+  });
 });
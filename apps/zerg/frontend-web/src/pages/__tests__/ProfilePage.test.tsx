@@ -54,6 +54,13 @@ describe("ProfilePage", () => {
     mockFetch.mockImplementation(async (input: RequestInfo | URL) => {
       const url = typeof input === "string" ? input : input.toString();
 
+      if (url.includes("/auth/google/gmail")) {
+        return {
+          ok: true,
+          json: () => Promise.resolve({ connected: false, health: "not_connected", scopes: [], triggers: [] }),
+        } as Response;
+      }
+
       if (url === "/api/users/me") {
         return {
           ok: true,
@@ -152,6 +159,12 @@ describe("ProfilePage", () => {
     });
   });
 
+  it("offers to connect Gmail when no account is connected", async () => {
+    renderProfilePage();
+
+    expect((await screen.findAllByRole("button", { name: "Connect Gmail" }))[0]).toBeEnabled();
+  });
+
   it("resets form to original values", async () => {
     renderProfilePage();
     const user = userEvent.setup();
@@ -0,0 +1,163 @@
+import { render, screen, waitFor, within } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
+import { MemoryRouter } from "react-router-dom";
+import { beforeEach, afterEach, describe, expect, it, vi } from "vitest";
+import TrashPage, { daysUntilPurge } from "../TrashPage";
+import {
+  fetchTrashedAgents,
+  fetchTrashedWorkflows,
+  permanentlyDeleteAgent,
+  restoreAgent,
+  restoreWorkflow,
+  type AgentSummary,
+  type Workflow,
+} from "../../services/api";
+
+vi.mock("../../services/api", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../../services/api")>();
+  return {
+    ...actual,
+    fetchTrashedAgents: vi.fn(),
+    restoreAgent: vi.fn(),
+    permanentlyDeleteAgent: vi.fn(),
+    fetchTrashedWorkflows: vi.fn(),
+    restoreWorkflow: vi.fn(),
+    permanentlyDeleteWorkflow: vi.fn(),
+  };
+});
+
+const fetchTrashedAgentsMock = fetchTrashedAgents as unknown as vi.MockedFunction<typeof fetchTrashedAgents>;
+const restoreAgentMock = restoreAgent as unknown as vi.MockedFunction<typeof restoreAgent>;
+const permanentlyDeleteAgentMock = permanentlyDeleteAgent as unknown as vi.MockedFunction<
+  typeof permanentlyDeleteAgent
+>;
+const fetchTrashedWorkflowsMock = fetchTrashedWorkflows as unknown as vi.MockedFunction<typeof fetchTrashedWorkflows>;
+const restoreWorkflowMock = restoreWorkflow as unknown as vi.MockedFunction<typeof restoreWorkflow>;
+
+function buildTrashedAgent(id: number, name: string, deletedAt: string): AgentSummary {
+  return {
+    id,
+    name,
+    status: "idle",
+    owner_id: 1,
+    owner: null,
+    system_instructions: "",
+    task_instructions: "",
+    model: "gpt-5.1-chat-latest",
+    schedule: null,
+    config: null,
+    last_error: null,
+    allowed_tools: [],
+    created_at: deletedAt,
+    updated_at: deletedAt,
+    messages: [],
+    next_run_at: null,
+    last_run_at: null,
+    deleted_at: deletedAt,
+  };
+}
+
+function buildTrashedWorkflow(id: number, name: string, deletedAt: string): Workflow {
+  return {
+    id,
+    name,
+    description: "",
+    owner_id: 1,
+    is_active: false,
+    created_at: deletedAt,
+    updated_at: deletedAt,
+    canvas: { nodes: [], edges: [] },
+    deleted_at: deletedAt,
+  };
+}
+
+function renderTrashPage() {
+  const queryClient = new QueryClient({
+    defaultOptions: {
+      queries: { retry: false },
+      mutations: { retry: false },
+    },
+  });
+
+  return render(
+    <QueryClientProvider client={queryClient}>
+      <MemoryRouter>
+        <TrashPage />
+      </MemoryRouter>
+    </QueryClientProvider>
+  );
+}
+
+describe("TrashPage", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    fetchTrashedWorkflowsMock.mockResolvedValue([]);
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it("shows an empty state", async () => {
+    fetchTrashedAgentsMock.mockResolvedValue([]);
+    renderTrashPage();
+    expect(await screen.findByTestId("trash-empty")).toHaveTextContent("Trash is empty");
+  });
+
+  it("restores an agent and refreshes the list", async () => {
+    const agent = buildTrashedAgent(5, "Old Agent", new Date().toISOString());
+    fetchTrashedAgentsMock.mockResolvedValueOnce([agent]).mockResolvedValue([]);
+    restoreAgentMock.mockResolvedValue({ ...agent, deleted_at: null });
+
+    renderTrashPage();
+    await screen.findByText("Old Agent");
+    expect(screen.getByText("30 days")).toBeInTheDocument();
+
+    await userEvent.click(screen.getByRole("button", { name: "Restore" }));
+
+    expect(restoreAgentMock).toHaveBeenCalledWith(5);
+    expect(await screen.findByTestId("trash-empty")).toBeInTheDocument();
+  });
+
+  it("only deletes permanently after confirmation", async () => {
+    const agent = buildTrashedAgent(6, "Doomed", new Date().toISOString());
+    fetchTrashedAgentsMock.mockResolvedValue([agent]);
+    permanentlyDeleteAgentMock.mockResolvedValue(undefined);
+    const confirmSpy = vi.spyOn(window, "confirm").mockReturnValueOnce(false).mockReturnValueOnce(true);
+
+    renderTrashPage();
+    await screen.findByText("Doomed");
+
+    await userEvent.click(screen.getByRole("button", { name: "Delete forever" }));
+    expect(permanentlyDeleteAgentMock).not.toHaveBeenCalled();
+
+    await userEvent.click(screen.getByRole("button", { name: "Delete forever" }));
+    await waitFor(() => expect(permanentlyDeleteAgentMock).toHaveBeenCalledWith(6));
+    expect(confirmSpy).toHaveBeenCalledTimes(2);
+  });
+
+  it("lists trashed workflows in their own section and restores them", async () => {
+    const workflow = buildTrashedWorkflow(8, "Nightly sync", new Date().toISOString());
+    fetchTrashedAgentsMock.mockResolvedValue([buildTrashedAgent(5, "Old Agent", new Date().toISOString())]);
+    fetchTrashedWorkflowsMock.mockResolvedValueOnce([workflow]).mockResolvedValue([]);
+    restoreWorkflowMock.mockResolvedValue({ ...workflow, is_active: true, deleted_at: null });
+
+    renderTrashPage();
+    const row = await screen.findByTestId("trash-row-workflow-8");
+    expect(screen.getByRole("heading", { name: "Workflows" })).toBeInTheDocument();
+    expect(screen.getByTestId("trash-row-agent-5")).toBeInTheDocument();
+
+    await userEvent.click(within(row).getByRole("button", { name: "Restore" }));
+
+    expect(restoreWorkflowMock).toHaveBeenCalledWith(8);
+    expect(restoreAgentMock).not.toHaveBeenCalled();
+    await waitFor(() => expect(screen.queryByTestId("trash-row-workflow-8")).not.toBeInTheDocument());
+  });
+
+  it("counts down the retention window", () => {
+    const now = Date.parse("2026-01-31T00:00:00Z");
+    expect(daysUntilPurge("2026-01-21T00:00:00Z", now)).toBe(20);
+    expect(daysUntilPurge("2025-12-01T00:00:00Z", now)).toBe(0);
+  });
+});
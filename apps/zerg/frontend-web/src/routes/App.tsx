@@ -10,14 +10,22 @@ import SecurityPage from "../pages/SecurityPage";
 import DashboardPage from "../pages/DashboardPage";
 import ProfilePage from "../pages/ProfilePage";
 import SettingsPage from "../pages/SettingsPage";
+import PreferencesPage from "../pages/PreferencesPage";
 import IntegrationsPage from "../pages/IntegrationsPage";
+import McpHealthPage from "../pages/McpHealthPage";
+import EnvVarsPage from "../pages/EnvVarsPage";
+import ApiCredentialsPage from "../pages/ApiCredentialsPage";
 import AdminPage from "../pages/AdminPage";
+import TrashPage from "../pages/TrashPage";
+import StatusPage from "../pages/StatusPage";
 import { AuthGuard } from "../lib/auth";
 
 // Lazy-loaded pages (heavy dependencies - reduces initial bundle by ~700KB)
 const ChatPage = lazy(() => import("../pages/ChatPage"));
 const CanvasPage = lazy(() => import("../pages/CanvasPage"));
 import { ShelfProvider } from "../lib/useShelfState";
+import { NotificationsProvider } from "../lib/useNotifications";
+import { AnnouncerProvider } from "../lib/useAnnouncer";
 import { ErrorBoundary } from "../components/ErrorBoundary";
 import { usePerformanceMonitoring, useBundleSizeWarning } from "../lib/usePerformance";
 import config from "../lib/config";
@@ -36,11 +44,15 @@ function PageLoader() {
 function AuthenticatedApp() {
   return (
     <AuthGuard clientId={config.googleClientId}>
-      <ShelfProvider>
-        <Layout>
-          <Outlet />
-        </Layout>
-      </ShelfProvider>
+      <NotificationsProvider>
+        <AnnouncerProvider>
+          <ShelfProvider>
+            <Layout>
+              <Outlet />
+            </Layout>
+          </ShelfProvider>
+        </AnnouncerProvider>
+      </NotificationsProvider>
     </AuthGuard>
   );
 }
@@ -120,6 +132,14 @@ export default function App() {
             </ErrorBoundary>
           )
         },
+        {
+          path: "/trash",
+          element: (
+            <ErrorBoundary>
+              <TrashPage />
+            </ErrorBoundary>
+          )
+        },
         {
           path: "/canvas",
           element: (
@@ -156,6 +176,14 @@ export default function App() {
             </ErrorBoundary>
           )
         },
+        {
+          path: "/settings/preferences",
+          element: (
+            <ErrorBoundary>
+              <PreferencesPage />
+            </ErrorBoundary>
+          )
+        },
         {
           path: "/settings/integrations",
           element: (
@@ -164,6 +192,38 @@ export default function App() {
             </ErrorBoundary>
           )
         },
+        {
+          path: "/settings/mcp",
+          element: (
+            <ErrorBoundary>
+              <McpHealthPage />
+            </ErrorBoundary>
+          )
+        },
+        {
+          path: "/settings/variables",
+          element: (
+            <ErrorBoundary>
+              <EnvVarsPage />
+            </ErrorBoundary>
+          )
+        },
+        {
+          path: "/settings/api-credentials",
+          element: (
+            <ErrorBoundary>
+              <ApiCredentialsPage />
+            </ErrorBoundary>
+          )
+        },
+        {
+          path: "/status",
+          element: (
+            <ErrorBoundary>
+              <StatusPage />
+            </ErrorBoundary>
+          )
+        },
         {
           path: "/admin",
           element: (
@@ -1,31 +1,121 @@
 import type { components, operations } from "../generated/openapi-types";
+import { appendTraceId, rememberTraceId, TRACE_ID_HEADER, traceIdFromResponse } from "../lib/traceId";
+import { recordTiming } from "../lib/perfMetrics";
+import { CircuitOpenError, endpointGroup, isRetryableRequest, resilientFetch } from "../lib/apiResilience";
+import { markSessionExpired } from "../lib/session";
+
+export type ApiErrorKind =
+  | "unauthorized"
+  | "forbidden"
+  | "not_found"
+  | "conflict"
+  | "validation"
+  | "rate_limited"
+  | "network"
+  | "server"
+  | "client";
+
+export interface ApiFieldError {
+  // Dotted location without the leading "body", e.g. "config.temperature"
+  field: string;
+  message: string;
+}
+
+/** Classify a response status; 0 means the request never got a response. */
+export function apiErrorKind(status: number): ApiErrorKind {
+  if (status === 0) return "network";
+  if (status === 401) return "unauthorized";
+  if (status === 403) return "forbidden";
+  if (status === 404) return "not_found";
+  if (status === 409) return "conflict";
+  if (status === 400 || status === 422) return "validation";
+  if (status === 429) return "rate_limited";
+  return status >= 500 ? "server" : "client";
+}
+
+// FastAPI validation errors: {"detail": [{"loc": ["body", "name"], "msg": "..."}]}
+function fieldErrorsFromDetail(detail: unknown): ApiFieldError[] {
+  if (!Array.isArray(detail)) return [];
+  return detail.flatMap((issue) => {
+    if (!issue || typeof issue !== "object") return [];
+    const { loc, msg } = issue as { loc?: unknown[]; msg?: unknown };
+    const path = Array.isArray(loc) ? loc.filter((part, index) => !(index === 0 && part === "body")) : [];
+    return [{ field: path.join("."), message: typeof msg === "string" ? msg : "Invalid value" }];
+  });
+}
 
 export class ApiError extends Error {
+  readonly kind: ApiErrorKind;
   readonly status: number;
   readonly url: string;
   readonly body: unknown;
-
-  constructor({ url, status, body }: { url: string; status: number; body: unknown }) {
+  // Backend trace id (X-Request-ID) for correlating with server logs
+  readonly traceId: string | null;
+  // The body's `detail`: a message string, or FastAPI's list of validation issues
+  readonly detail: unknown;
+  // Per-field messages for validation errors
+  readonly fieldErrors: ApiFieldError[];
+  // Seconds to wait before retrying a rate-limited request, from Retry-After
+  readonly retryAfter: number | null;
+
+  constructor({
+    url,
+    status,
+    body,
+    traceId = null,
+    retryAfter = null,
+  }: {
+    url: string;
+    status: number;
+    body: unknown;
+    traceId?: string | null;
+    retryAfter?: number | null;
+  }) {
+    const detail = body && typeof body === "object" && "detail" in body ? body.detail : undefined;
     // Extract detailed error message from body if available
-    let detailMessage = `Request to ${url} failed with status ${status}`;
-    if (body && typeof body === 'object' && 'detail' in body) {
-      detailMessage = `${detailMessage}: ${body.detail}`;
+    let detailMessage =
+      status === 0 ? `Request to ${url} failed: network unreachable` : `Request to ${url} failed with status ${status}`;
+    if (typeof detail === "string") {
+      detailMessage = `${detailMessage}: ${detail}`;
+    } else if (detail !== undefined) {
+      detailMessage = `${detailMessage}: ${JSON.stringify(detail)}`;
     }
 
-    super(detailMessage);
+    super(appendTraceId(detailMessage, traceId));
     this.name = "ApiError";
+    this.kind = apiErrorKind(status);
     this.status = status;
     this.url = url;
     this.body = body;
+    this.traceId = traceId;
+    this.detail = detail;
+    this.fieldErrors = this.kind === "validation" ? fieldErrorsFromDetail(detail) : [];
+    this.retryAfter = retryAfter;
 
     // Log full error details to console for debugging
     console.error('[API Error]', {
       url,
       status,
       body,
+      traceId,
       message: detailMessage,
     });
   }
+
+  /** The server's message when it sent one, else the generic message. */
+  get detailMessage(): string {
+    return typeof this.detail === "string" ? this.detail : this.message;
+  }
+}
+
+export function isApiError(error: unknown, kind?: ApiErrorKind): error is ApiError {
+  return error instanceof ApiError && (kind === undefined || error.kind === kind);
+}
+
+function retryAfterSeconds(response: Response): number | null {
+  const header = response.headers.get("Retry-After");
+  const seconds = header === null ? NaN : Number(header);
+  return Number.isFinite(seconds) ? seconds : null;
 }
 
 type Schemas = components["schemas"];
@@ -39,31 +129,82 @@ type JsonResponse<Op extends { responses: Record<number | string, unknown> }, Co
 export type Agent = Schemas["Agent"];
 export type AgentSummary = Agent;
 export type AgentRun = Schemas["AgentRunOut"];
+export type AgentBudgetStatus = Schemas["AgentBudgetStatus"];
+export type AgentStats = Schemas["AgentStats"];
+export type AgentUsage = Schemas["AgentUsage"];
 export type Thread = Schemas["Thread"];
 export type ThreadMessage = Schemas["ThreadMessageResponse"] & { created_at?: string };
 export type ThreadUpdatePayload = Schemas["ThreadUpdate"];
+export type ChatAttachment = Schemas["ChatAttachment"];
+export type MessageAuthor = Schemas["MessageAuthor"];
+export type MessageUsage = Schemas["MessageUsage"];
+export type MessageReaction = Schemas["MessageReaction"];
+export type MessageMetadataUpdate = Schemas["MessageMetadataUpdate"];
+
+// Reactions the backend accepts, in the order they are offered
+export const MESSAGE_REACTIONS = ["👍", "👎", "❤️", "🎉", "😄", "🤔"] as const;
 export type Workflow = Schemas["Workflow"];
 export type WorkflowData = Schemas["WorkflowData-Output"];
 export type WorkflowDataInput = Schemas["WorkflowData-Input"];
 export type WorkflowNode = Schemas["WorkflowNode"];
 export type WorkflowEdge = Schemas["WorkflowEdge"];
+export type WorkflowTemplate = Schemas["WorkflowTemplate"];
+export type WorkflowComment = Schemas["WorkflowCommentOut"];
+export type WorkflowUpdate = Schemas["WorkflowUpdate"];
+export type TagSummary = Schemas["TagOut"];
+export type TemplatePublishPayload = Schemas["TemplatePublishRequest"];
+export type UserCapabilities = Schemas["CapabilitiesOut"];
+export type SharePermission = Schemas["SharePermission"];
+export type AgentShare = Schemas["AgentShareOut"];
+export type AgentPermission = Schemas["AgentPermission"];
+export type AgentInstructionVersion = Schemas["AgentInstructionVersionOut"];
+export type ModelComparison = Schemas["ModelComparison"];
+export type ModelComparisonResult = Schemas["ModelComparisonResult"];
+export type ModelParams = Schemas["ModelParams"];
+export type AgentModelParams = Schemas["AgentModelParams"];
+// "shared" lists agents other users shared with the caller
+export type AgentScope = "my" | "all" | "shared";
+export type TemplateSort = "recent" | "rating" | "popular";
+
+export interface TemplateListParams {
+  category?: string | null;
+  sort?: TemplateSort;
+  minRating?: number | null;
+  favoritesOnly?: boolean;
+  mine?: boolean;
+}
 
 // Workflow Execution Types
 export interface WorkflowExecution {
   id: number;
   workflow_id: number;
-  phase: 'waiting' | 'running' | 'finished' | 'cancelled';
-  result?: unknown;
-  log?: string;
-  started_at?: string;
-  finished_at?: string;
-  triggered_by?: string;
+  phase: 'waiting' | 'running' | 'finished';
+  result?: 'success' | 'failure' | 'cancelled' | null;
+  error_message?: string | null;
+  started_at?: string | null;
+  finished_at?: string | null;
+  triggered_by?: string | null;
+  // What the run was started with, readable by nodes as ${input.value.<name>}
+  inputs?: Record<string, unknown> | null;
+  // Set on runs started from a node, which reused the source execution's other outputs
+  source_execution_id?: number | null;
+  start_node_id?: string | null;
+}
+
+// Nodes with a cached output in the workflow's latest finished execution
+export interface NodeOutputCache {
+  execution_id: number | null;
+  finished_at?: string | null;
+  node_ids: string[];
 }
 
 export interface ExecutionStatus {
   execution_id: number;
   phase: string;
   result?: unknown;
+  // Run control: step-mode runs pause before every node
+  step_mode?: boolean;
+  paused_node_id?: string | null;
 }
 
 export interface ExecutionLogs {
@@ -96,6 +237,8 @@ type ThreadCreate = Schemas["ThreadCreate"];
 type ThreadMessageCreate = Schemas["ThreadMessageCreate"];
 type WorkflowCreate = Schemas["WorkflowCreate"];
 type CanvasUpdate = Schemas["CanvasUpdate"];
+type CanvasValidate = Schemas["CanvasValidate"];
+export type CanvasValidation = Schemas["ValidationResponse"];
 
 export type AgentCreatePayload = Pick<AgentCreate, "system_instructions" | "task_instructions" | "model"> &
   Partial<Omit<AgentCreate, "system_instructions" | "task_instructions" | "model">>;
@@ -118,7 +261,7 @@ type CreatedWorkflowResponse = JsonResponse<Operations["create_workflow_api_work
 type UpdatedWorkflowCanvasResponse = JsonResponse<Operations["update_current_workflow_canvas_api_workflows_current_canvas_patch"], 200>;
 
 type FetchAgentsParams = {
-  scope?: "my" | "all";
+  scope?: AgentScope;
   limit?: number;
   skip?: number;
 };
@@ -129,11 +272,14 @@ type DashboardRunsBundleResponse = {
 };
 
 type DashboardSnapshotResponse = {
-  scope: "my" | "all";
+  scope: AgentScope;
   fetched_at: string;
   runs_limit: number;
   agents: AgentSummary[];
   runs: DashboardRunsBundleResponse[];
+  budgets?: AgentBudgetStatus[];
+  stats?: AgentStats[];
+  permissions?: AgentPermission[];
 };
 
 export type DashboardRunsBundle = {
@@ -142,15 +288,21 @@ export type DashboardRunsBundle = {
 };
 
 export type DashboardSnapshot = {
-  scope: "my" | "all";
+  scope: AgentScope;
   fetchedAt: string;
   runsLimit: number;
   agents: AgentSummary[];
   runs: DashboardRunsBundle[];
+  // Only agents with a spend cap configured
+  budgets: AgentBudgetStatus[];
+  // Aggregated over each agent's full run history
+  stats: AgentStats[];
+  // The caller's access to agents shared with them (scope "shared" only)
+  permissions: AgentPermission[];
 };
 
 type FetchDashboardParams = {
-  scope?: "my" | "all";
+  scope?: AgentScope;
   runsLimit?: number;
   skip?: number;
   limit?: number;
@@ -236,7 +388,23 @@ export function buildUrl(path: string): string {
   return `${prefix}/${normalizedPath}`;
 }
 
+// Requests still waiting on a response, retries included; E2E tests wait for zero (lib/testHooks)
+let inFlightRequests = 0;
+
+export function pendingRequestCount(): number {
+  return inFlightRequests;
+}
+
 async function request<T>(path: string, init?: RequestInit): Promise<T> {
+  inFlightRequests += 1;
+  try {
+    return await sendRequest<T>(path, init);
+  } finally {
+    inFlightRequests -= 1;
+  }
+}
+
+async function sendRequest<T>(path: string, init?: RequestInit): Promise<T> {
   const url = buildUrl(path);
   const headers = new Headers(init?.headers);
 
@@ -252,12 +420,39 @@ async function request<T>(path: string, init?: RequestInit): Promise<T> {
     headers.set("X-Test-Worker", String(testWorkerHeader));
   }
 
-  const response = await fetch(url, {
-    ...init,
-    headers,
-    credentials: 'include', // Required for cookie auth
-  });
+  const send = async () => {
+    const startedAt = performance.now();
+    try {
+      return await fetch(url, {
+        ...init,
+        headers,
+        credentials: 'include', // Required for cookie auth
+      });
+    } finally {
+      recordTiming("api", performance.now() - startedAt);
+    }
+  };
+  // Transient 502/503/504s are retried for idempotent requests; see lib/apiResilience
+  let response: Response;
+  try {
+    response = await resilientFetch(endpointGroup(path), send, {
+      retry: isRetryableRequest({ ...init, headers }),
+      signal: init?.signal,
+    });
+  } catch (error) {
+    if (error instanceof DOMException && error.name === "AbortError") throw error;
+    if (error instanceof CircuitOpenError) {
+      throw new ApiError({ url, status: 503, body: { detail: error.message } });
+    }
+    throw new ApiError({ url, status: 0, body: undefined });
+  }
+
+  // The session lapsed: AuthGuard overlays a sign-in prompt without unmounting the page
+  if (response.status === 401 && !path.startsWith("/auth/")) {
+    markSessionExpired();
+  }
 
+  const traceId = traceIdFromResponse(response);
   const hasBody = response.status !== 204 && response.status !== 205;
   const contentType = response.headers.get("content-type") ?? "";
   const expectsJson = contentType.includes("application/json");
@@ -273,14 +468,15 @@ async function request<T>(path: string, init?: RequestInit): Promise<T> {
       }
     } catch (error) {
       if (!response.ok) {
-        throw new ApiError({ url, status: response.status, body: data });
+        const retryAfter = retryAfterSeconds(response);
+        throw new ApiError({ url, status: response.status, body: data, traceId, retryAfter });
       }
       throw error instanceof Error ? error : new Error("Failed to parse response body");
     }
   }
 
   if (!response.ok) {
-    throw new ApiError({ url, status: response.status, body: data });
+    throw new ApiError({ url, status: response.status, body: data, traceId, retryAfter: retryAfterSeconds(response) });
   }
 
   return data as T;
@@ -327,9 +523,53 @@ export async function fetchDashboardSnapshot(params: FetchDashboardParams = {}):
       agentId: bundle.agent_id,
       runs: bundle.runs,
     })),
+    budgets: response.budgets ?? [],
+    stats: response.stats ?? [],
+    permissions: response.permissions ?? [],
   };
 }
 
+export async function fetchAgentShares(agentId: number): Promise<AgentShare[]> {
+  return request<AgentShare[]>(`/agents/${agentId}/shares`);
+}
+
+// Omit userEmail to share with the whole workspace
+export async function shareAgent(
+  agentId: number,
+  permission: SharePermission,
+  userEmail?: string | null
+): Promise<AgentShare> {
+  return request<AgentShare>(`/agents/${agentId}/shares`, {
+    method: "PUT",
+    body: JSON.stringify({ user_email: userEmail || null, permission }),
+  });
+}
+
+export async function removeAgentShare(agentId: number, shareId: number): Promise<void> {
+  await request<void>(`/agents/${agentId}/shares/${shareId}`, { method: "DELETE" });
+}
+
+export async function fetchAgentModelParams(agentId: number): Promise<AgentModelParams> {
+  return request<AgentModelParams>(`/agents/${agentId}/model-params`);
+}
+
+// Runs the models one after another; omit input to send the task instructions
+export async function compareModels(agentId: number, models: string[], input?: string): Promise<ModelComparison> {
+  return request<ModelComparison>(`/agents/${agentId}/compare`, {
+    method: "POST",
+    body: JSON.stringify({ models, input: input || null }),
+  });
+}
+
+// Newest first
+export async function fetchInstructionVersions(agentId: number): Promise<AgentInstructionVersion[]> {
+  return request<AgentInstructionVersion[]>(`/agents/${agentId}/instruction-versions`);
+}
+
+export async function restoreInstructionVersion(agentId: number, versionId: number): Promise<Agent> {
+  return request<Agent>(`/agents/${agentId}/instruction-versions/${versionId}/restore`, { method: "POST" });
+}
+
 export async function createAgent(payload: AgentCreatePayload): Promise<CreatedAgentResponse> {
   return request<CreatedAgentResponse>(`/agents`, {
     method: "POST",
@@ -353,6 +593,22 @@ export async function fetchThreadMessages(threadId: number): Promise<ThreadMessa
   return request<ThreadMessagesResponse>(`/threads/${threadId}/messages`);
 }
 
+export const THREAD_MESSAGES_PAGE_SIZE = 100;
+
+// Newest page of a thread, or the page just older than `beforeMessageId`; a short page means nothing older
+export async function fetchThreadMessagesPage(
+  threadId: number,
+  { beforeMessageId, limit = THREAD_MESSAGES_PAGE_SIZE }: { beforeMessageId?: number; limit?: number } = {}
+): Promise<ThreadMessagesResponse> {
+  const params = new URLSearchParams({ limit: String(limit) });
+  if (beforeMessageId != null) {
+    params.set("before_message_id", String(beforeMessageId));
+  } else {
+    params.set("latest", "true");
+  }
+  return request<ThreadMessagesResponse>(`/threads/${threadId}/messages?${params.toString()}`);
+}
+
 export async function createThread(agentId: number, title: string): Promise<CreatedThreadResponse> {
   const payload: ThreadCreate = {
     agent_id: agentId,
@@ -367,10 +623,15 @@ export async function createThread(agentId: number, title: string): Promise<Crea
   });
 }
 
-export async function postThreadMessage(threadId: number, content: string): Promise<CreatedThreadMessageResponse> {
+export async function postThreadMessage(
+  threadId: number,
+  content: string,
+  attachments: ChatAttachment[] = []
+): Promise<CreatedThreadMessageResponse> {
   const payload: ThreadMessageCreate = {
     role: "user",
     content,
+    ...(attachments.length > 0 ? { attachments } : {}),
   };
   return request<CreatedThreadMessageResponse>(`/threads/${threadId}/messages`, {
     method: "POST",
@@ -378,6 +639,92 @@ export async function postThreadMessage(threadId: number, content: string): Prom
   });
 }
 
+type UploadFileOptions = {
+  // Fraction in [0, 1]; only reported when the browser knows the total size
+  onProgress?: (fraction: number) => void;
+  signal?: AbortSignal;
+};
+
+// fetch() can't report upload progress, so file uploads go through XHR
+function uploadFile<T>(path: string, file: File, { onProgress, signal }: UploadFileOptions = {}): Promise<T> {
+  const url = buildUrl(path);
+  const body = new FormData();
+  body.append("file", file);
+
+  return new Promise((resolve, reject) => {
+    const xhr = new XMLHttpRequest();
+    xhr.open("POST", url);
+    xhr.withCredentials = true;
+    xhr.responseType = "json";
+
+    const testWorkerHeader = typeof window !== "undefined" ? window.__TEST_WORKER_ID__ : undefined;
+    if (testWorkerHeader !== undefined) {
+      xhr.setRequestHeader("X-Test-Worker", String(testWorkerHeader));
+    }
+
+    xhr.upload.onprogress = (event) => {
+      if (event.lengthComputable) {
+        onProgress?.(event.loaded / event.total);
+      }
+    };
+    xhr.onload = () => {
+      if (xhr.status >= 200 && xhr.status < 300) {
+        resolve(xhr.response as T);
+      } else {
+        const traceId = xhr.getResponseHeader(TRACE_ID_HEADER);
+        rememberTraceId(traceId);
+        reject(new ApiError({ url, status: xhr.status, body: xhr.response, traceId }));
+      }
+    };
+    xhr.onerror = () => reject(new ApiError({ url, status: 0, body: undefined }));
+    xhr.onabort = () => reject(new DOMException("Upload aborted", "AbortError"));
+
+    if (signal) {
+      if (signal.aborted) {
+        reject(new DOMException("Upload aborted", "AbortError"));
+        return;
+      }
+      signal.addEventListener("abort", () => xhr.abort(), { once: true });
+    }
+    xhr.send(body);
+  });
+}
+
+export function uploadAttachment(threadId: number, file: File, options?: UploadFileOptions): Promise<ChatAttachment> {
+  return uploadFile<ChatAttachment>(`/threads/${threadId}/attachments`, file, options);
+}
+
+// Edit-and-resend: copies history before `messageId` into a new thread ending with `content`
+export async function forkThread(threadId: number, messageId: number, content: string): Promise<Thread> {
+  return request<Thread>(`/threads/${threadId}/fork`, {
+    method: "POST",
+    body: JSON.stringify({ message_id: messageId, content }),
+  });
+}
+
+// Drops the latest assistant turn; returns the user message that will be answered again
+export async function regenerateThread(threadId: number): Promise<ThreadMessage> {
+  return request<ThreadMessage>(`/threads/${threadId}/regenerate`, {
+    method: "POST",
+  });
+}
+
+// Bookmarks and reactions are per user; the response reflects the caller's view
+export async function updateMessageMetadata(
+  threadId: number,
+  messageId: number,
+  update: MessageMetadataUpdate
+): Promise<ThreadMessage> {
+  return request<ThreadMessage>(`/threads/${threadId}/messages/${messageId}/metadata`, {
+    method: "PATCH",
+    body: JSON.stringify(update),
+  });
+}
+
+export async function fetchBookmarkedMessages(agentId: number): Promise<ThreadMessage[]> {
+  return request<ThreadMessage[]>(`/threads/bookmarks?agent_id=${agentId}`);
+}
+
 export async function runThread(threadId: number): Promise<void> {
   await request<void>(`/threads/${threadId}/run`, {
     method: "POST",
@@ -391,8 +738,22 @@ export async function updateThread(threadId: number, payload: ThreadUpdatePayloa
   });
 }
 
-export async function fetchAgentRuns(agentId: number, limit = 20): Promise<AgentRunsListResponse> {
-  return request<AgentRunsListResponse>(`/agents/${agentId}/runs?limit=${limit}`);
+export async function fetchAgentRuns(agentId: number, limit = 20, beforeId?: number): Promise<AgentRunsListResponse> {
+  const params = new URLSearchParams({ limit: String(limit) });
+  if (beforeId !== undefined) {
+    params.set("before_id", String(beforeId));
+  }
+  return request<AgentRunsListResponse>(`/agents/${agentId}/runs?${params.toString()}`);
+}
+
+export async function fetchAgentStats(agentId: number): Promise<AgentStats> {
+  return request<AgentStats>(`/agents/${agentId}/stats`);
+}
+
+// Token and cost totals for the dashboard's optional usage columns
+export async function fetchAgentUsage(scope: AgentScope = "my", days = 7): Promise<AgentUsage[]> {
+  const params = new URLSearchParams({ scope, days: String(days) });
+  return request<AgentUsage[]>(`/agents/usage?${params.toString()}`);
 }
 
 export async function updateAgent(agentId: number, payload: AgentUpdatePayload): Promise<UpdatedAgentResponse> {
@@ -402,10 +763,521 @@ export async function updateAgent(agentId: number, payload: AgentUpdatePayload):
   });
 }
 
+export type AgentPatch = Schemas["AgentPatch"];
+export type AgentPatchValidation = Schemas["AgentPatchValidation"];
+
+// Checks a raw edit against the agent contract (the AgentUpdate schema) without applying it
+export async function validateAgentEdit(edit: unknown): Promise<AgentPatchValidation> {
+  return request<AgentPatchValidation>(`/agents/contract/validate`, { method: "POST", body: JSON.stringify(edit) });
+}
+
+export async function patchAgent(agentId: number, patch: AgentPatch): Promise<UpdatedAgentResponse> {
+  return request<UpdatedAgentResponse>(`/agents/${agentId}`, { method: "PATCH", body: JSON.stringify(patch) });
+}
+
 export async function resetAgent(agentId: number): Promise<UpdatedAgentResponse> {
   return updateAgent(agentId, { status: "idle", last_error: "" });
 }
 
+// Deleting moves the agent to the trash; it stays restorable for this long
+export const AGENT_TRASH_RETENTION_DAYS = 30;
+
+export interface AgentDependents {
+  agent_id: number;
+  workflows: { id: number; name: string }[];
+  triggers: { id: number; type: string; disabled: boolean }[];
+  schedule?: string | null;
+  next_run_at?: string | null;
+  queued_run_ids: number[];
+}
+
+export async function fetchAgentDependents(agentId: number): Promise<AgentDependents> {
+  return request<AgentDependents>(`/agents/${agentId}/dependents`);
+}
+
+// disableDependents also switches off the agent's triggers and drops its queued runs
+export async function deleteAgent(agentId: number, options: { disableDependents?: boolean } = {}): Promise<void> {
+  const query = options.disableDependents ? "?disable_dependents=true" : "";
+  return request<void>(`/agents/${agentId}${query}`, {
+    method: "DELETE",
+  });
+}
+
+export async function fetchTrashedAgents(): Promise<AgentSummary[]> {
+  return request<AgentSummary[]>(`/agents/trash`);
+}
+
+export async function restoreAgent(agentId: number): Promise<AgentSummary> {
+  return request<AgentSummary>(`/agents/${agentId}/restore`, {
+    method: "POST",
+  });
+}
+
+export async function permanentlyDeleteAgent(agentId: number): Promise<void> {
+  return request<void>(`/agents/${agentId}/permanent`, {
+    method: "DELETE",
+  });
+}
+
+// Paused agents skip scheduled runs and trigger firings; manual runs still work
+export async function pauseAgent(agentId: number): Promise<AgentSummary> {
+  return request<AgentSummary>(`/agents/${agentId}/pause`, {
+    method: "POST",
+  });
+}
+
+export async function resumeAgent(agentId: number): Promise<AgentSummary> {
+  return request<AgentSummary>(`/agents/${agentId}/resume`, {
+    method: "POST",
+  });
+}
+
+export async function cancelRun(runId: number): Promise<AgentRun> {
+  return request<AgentRun>(`/runs/${runId}/cancel`, {
+    method: "POST",
+  });
+}
+
+export async function reorderRunQueue(agentId: number, runIds: number[]): Promise<AgentRun[]> {
+  return request<AgentRun[]>(`/agents/${agentId}/queue`, {
+    method: "PUT",
+    body: JSON.stringify({ run_ids: runIds }),
+  });
+}
+
+export async function dropQueuedRun(agentId: number, runId: number): Promise<AgentRun> {
+  return request<AgentRun>(`/agents/${agentId}/queue/${runId}`, {
+    method: "DELETE",
+  });
+}
+
+export interface SystemInfo {
+  auth_disabled: boolean;
+  google_client_id?: string | null;
+  app_public_url?: string | null;
+  // Admin switch: schedules and triggers are suspended for every agent
+  maintenance_mode: boolean;
+}
+
+export async function fetchSystemInfo(): Promise<SystemInfo> {
+  return request<SystemInfo>(`/system/info`);
+}
+
+// ---------------------------------------------------------------------------
+// Agent duplication and export/import
+// ---------------------------------------------------------------------------
+
+export interface AgentExport {
+  format: string;
+  version: number;
+  exported_at?: string | null;
+  agent: {
+    name: string;
+    system_instructions: string;
+    task_instructions: string;
+    model: string;
+    schedule?: string | null;
+    config?: Record<string, unknown> | null;
+    allowed_tools?: string[] | null;
+    prompt_variables?: Record<string, string> | null;
+  };
+  triggers: { type: string; config?: Record<string, unknown> | null }[];
+}
+
+export interface AgentImportConflict {
+  field: "name" | "model" | "mcp_servers" | "triggers" | string;
+  message: string;
+}
+
+export interface AgentImportPreview {
+  name: string;
+  suggested_name: string;
+  name_conflict: boolean;
+  model: string;
+  model_available: boolean;
+  has_schedule: boolean;
+  trigger_count: number;
+  conflicts: AgentImportConflict[];
+}
+
+export interface AgentImportOptions {
+  name?: string;
+  model?: string;
+  include_schedule?: boolean;
+  include_triggers?: boolean;
+}
+
+export async function duplicateAgent(agentId: number): Promise<AgentSummary> {
+  return request<AgentSummary>(`/agents/${agentId}/duplicate`, {
+    method: "POST",
+  });
+}
+
+export async function exportAgent(agentId: number): Promise<AgentExport> {
+  return request<AgentExport>(`/agents/${agentId}/export`);
+}
+
+export interface ToolUsageStat {
+  name: string;
+  calls: number;
+  last_used_at?: string | null;
+}
+
+export interface ToolUsageResponse {
+  agent_id: number;
+  tools: ToolUsageStat[];
+}
+
+export async function fetchToolUsage(agentId: number): Promise<ToolUsageResponse> {
+  return request<ToolUsageResponse>(`/agents/${agentId}/tool-usage`);
+}
+
+export interface ToolDefinition {
+  name: string;
+  description: string;
+  parameters: Record<string, unknown>;
+}
+
+export interface ToolInvokeResult {
+  tool: string;
+  ok: boolean;
+  result: unknown;
+  raw: string;
+  error?: string | null;
+  duration_ms: number;
+}
+
+export async function fetchAgentTools(agentId: number): Promise<ToolDefinition[]> {
+  return request<ToolDefinition[]>(`/agents/${agentId}/tools/`);
+}
+
+export async function invokeTool(
+  agentId: number,
+  toolName: string,
+  args: Record<string, unknown>
+): Promise<ToolInvokeResult> {
+  return request<ToolInvokeResult>(`/agents/${agentId}/tools/${encodeURIComponent(toolName)}/invoke`, {
+    method: "POST",
+    body: JSON.stringify({ args }),
+  });
+}
+
+export type AgentTestAssertionType = "contains" | "not_contains" | "equals" | "regex" | "json_path";
+
+export interface AgentTestAssertion {
+  type: AgentTestAssertionType;
+  value: string;
+  // Only used by json_path
+  path?: string | null;
+}
+
+export interface AgentTestAssertionResult extends AgentTestAssertion {
+  passed: boolean;
+  actual?: string | null;
+  message: string;
+}
+
+export interface AgentTestResult {
+  passed: boolean;
+  output: string;
+  error?: string | null;
+  assertions: AgentTestAssertionResult[];
+  duration_ms: number;
+  ran_at: string;
+}
+
+export interface AgentTestCase {
+  id: number;
+  agent_id: number;
+  name: string;
+  input: string;
+  assertions: AgentTestAssertion[];
+  last_result?: AgentTestResult | null;
+  last_run_at?: string | null;
+  created_at?: string | null;
+  updated_at?: string | null;
+}
+
+export type AgentTestCasePayload = Pick<AgentTestCase, "name" | "input" | "assertions">;
+
+export async function fetchAgentTests(agentId: number): Promise<AgentTestCase[]> {
+  return request<AgentTestCase[]>(`/agents/${agentId}/tests/`);
+}
+
+export async function createAgentTest(agentId: number, payload: AgentTestCasePayload): Promise<AgentTestCase> {
+  return request<AgentTestCase>(`/agents/${agentId}/tests/`, { method: "POST", body: JSON.stringify(payload) });
+}
+
+export async function updateAgentTest(
+  agentId: number,
+  caseId: number,
+  payload: Partial<AgentTestCasePayload>
+): Promise<AgentTestCase> {
+  return request<AgentTestCase>(`/agents/${agentId}/tests/${caseId}`, { method: "PUT", body: JSON.stringify(payload) });
+}
+
+export async function deleteAgentTest(agentId: number, caseId: number): Promise<void> {
+  await request<void>(`/agents/${agentId}/tests/${caseId}`, { method: "DELETE" });
+}
+
+export async function runAgentTest(agentId: number, caseId: number): Promise<AgentTestCase> {
+  return request<AgentTestCase>(`/agents/${agentId}/tests/${caseId}/run`, { method: "POST" });
+}
+
+// Runs every case in order; resolves once all have finished
+export async function runAllAgentTests(agentId: number): Promise<AgentTestCase[]> {
+  return request<AgentTestCase[]>(`/agents/${agentId}/tests/run`, { method: "POST" });
+}
+
+export type KnowledgeDocumentStatus = "indexing" | "ready" | "failed";
+
+export interface KnowledgeDocument {
+  id: number;
+  agent_id: number;
+  name: string;
+  // Set for linked pages
+  url?: string | null;
+  content_type?: string | null;
+  size_bytes?: number | null;
+  status: KnowledgeDocumentStatus;
+  error?: string | null;
+  chunk_count: number;
+  created_at?: string | null;
+  updated_at?: string | null;
+}
+
+export async function fetchKnowledgeDocuments(agentId: number): Promise<KnowledgeDocument[]> {
+  return request<KnowledgeDocument[]>(`/agents/${agentId}/knowledge/`);
+}
+
+// Resolves once the file is stored; indexing continues in the background
+export function uploadKnowledgeFile(
+  agentId: number,
+  file: File,
+  options?: UploadFileOptions
+): Promise<KnowledgeDocument> {
+  return uploadFile<KnowledgeDocument>(`/agents/${agentId}/knowledge/files`, file, options);
+}
+
+export async function addKnowledgeLink(agentId: number, url: string, name?: string): Promise<KnowledgeDocument> {
+  return request<KnowledgeDocument>(`/agents/${agentId}/knowledge/links`, {
+    method: "POST",
+    body: JSON.stringify({ url, name: name || null }),
+  });
+}
+
+export async function deleteKnowledgeDocument(agentId: number, documentId: number): Promise<void> {
+  await request<void>(`/agents/${agentId}/knowledge/${documentId}`, { method: "DELETE" });
+}
+
+export async function previewAgentImport(agentExport: AgentExport): Promise<AgentImportPreview> {
+  return request<AgentImportPreview>(`/agents/import/preview`, {
+    method: "POST",
+    body: JSON.stringify(agentExport),
+  });
+}
+
+export async function importAgent(agentExport: AgentExport, options: AgentImportOptions = {}): Promise<AgentSummary> {
+  return request<AgentSummary>(`/agents/import`, {
+    method: "POST",
+    body: JSON.stringify({ export: agentExport, ...options }),
+  });
+}
+
+// ---------------------------------------------------------------------------
+// Triggers
+// ---------------------------------------------------------------------------
+
+import type { EmailTriggerFilters } from "../lib/emailFilters";
+
+export interface Trigger {
+  id: number;
+  agent_id: number;
+  type: "webhook" | "email" | string;
+  config?: Record<string, unknown> | null;
+  secret: string;
+  created_at: string;
+}
+
+export interface TriggerDelivery {
+  id: number;
+  trigger_id: number;
+  status_code: number;
+  error?: string | null;
+  is_test: boolean;
+  payload_bytes?: number | null;
+  received_at: string;
+  // What the firing satisfied and the run it started, when it started one
+  matched?: string | null;
+  run_id?: number | null;
+  run_status?: string | null;
+}
+
+export interface TestTriggerResponse {
+  status: string;
+  delivery: TriggerDelivery;
+}
+
+export interface EmailConnector {
+  id: number;
+  owner_id: number;
+  type: string;
+  provider: string;
+  config?: Record<string, unknown> | null;
+}
+
+export interface EmailPreviewMessage {
+  id: string;
+  sender?: string | null;
+  subject?: string | null;
+  date?: string | null;
+  snippet?: string | null;
+  label_ids: string[];
+  has_attachment: boolean;
+}
+
+export interface EmailFilterPreview {
+  query: string;
+  scanned: number;
+  messages: EmailPreviewMessage[];
+}
+
+export function triggerWebhookUrl(triggerId: number): string {
+  return new URL(buildUrl(`/triggers/${triggerId}/events`), window.location.origin).toString();
+}
+
+/** Payload URL to add under the repository's Settings → Webhooks. */
+export function githubWebhookUrl(triggerId: number): string {
+  return new URL(buildUrl(`/github/webhooks/${triggerId}`), window.location.origin).toString();
+}
+
+export async function fetchTriggers(agentId: number): Promise<Trigger[]> {
+  return request<Trigger[]>(`/triggers/?agent_id=${agentId}`);
+}
+
+export async function createWebhookTrigger(agentId: number): Promise<Trigger> {
+  return request<Trigger>(`/triggers/`, {
+    method: "POST",
+    body: JSON.stringify({ agent_id: agentId, type: "webhook" }),
+  });
+}
+
+export async function createEmailTrigger(
+  agentId: number,
+  connectorId: number,
+  filters: EmailTriggerFilters = {}
+): Promise<Trigger> {
+  return request<Trigger>(`/triggers/`, {
+    method: "POST",
+    body: JSON.stringify({ agent_id: agentId, type: "email", config: { connector_id: connectorId, filters } }),
+  });
+}
+
+export async function createSlackTrigger(
+  agentId: number,
+  config: { event_type: string; channel?: string; keyword?: string }
+): Promise<Trigger> {
+  return request<Trigger>(`/triggers/`, {
+    method: "POST",
+    body: JSON.stringify({ agent_id: agentId, type: "slack", config }),
+  });
+}
+
+export async function createGitHubTrigger(
+  agentId: number,
+  config: { repo: string; events: string[]; actions?: string[] }
+): Promise<Trigger> {
+  return request<Trigger>(`/triggers/`, {
+    method: "POST",
+    body: JSON.stringify({ agent_id: agentId, type: "github", config }),
+  });
+}
+
+export async function updateTriggerFilters(triggerId: number, filters: EmailTriggerFilters | null): Promise<Trigger> {
+  return request<Trigger>(`/triggers/${triggerId}`, {
+    method: "PATCH",
+    body: JSON.stringify({ filters }),
+  });
+}
+
+export async function previewEmailFilter(
+  connectorId: number,
+  filters: EmailTriggerFilters,
+  limit = 10
+): Promise<EmailFilterPreview> {
+  return request<EmailFilterPreview>(`/triggers/email/preview`, {
+    method: "POST",
+    body: JSON.stringify({ connector_id: connectorId, filters, limit }),
+  });
+}
+
+export async function fetchEmailConnectors(): Promise<EmailConnector[]> {
+  const connectors = await request<EmailConnector[]>(`/connectors/`);
+  return connectors.filter((connector) => connector.type === "email");
+}
+
+export type GmailHealth = "healthy" | "watch_expired" | "auth_failed" | "not_connected";
+
+export interface GmailDependentTrigger {
+  id: number;
+  agent_id: number;
+  agent_name: string;
+  disabled: boolean;
+}
+
+export interface GmailConnection {
+  connected: boolean;
+  connector_id?: number | null;
+  email_address?: string | null;
+  scopes: string[];
+  connected_at?: string | null;
+  watch_expiry?: number | null;
+  health: GmailHealth;
+  health_detail?: string | null;
+  triggers: GmailDependentTrigger[];
+}
+
+/** With *check*, the backend refreshes an access token to verify the grant is still valid. */
+export async function fetchGmailConnection(check = false): Promise<GmailConnection> {
+  return request<GmailConnection>(`/auth/google/gmail${check ? "?check=true" : ""}`);
+}
+
+export async function connectGmail(authCode: string): Promise<{ status: string; connector_id: number }> {
+  return request<{ status: string; connector_id: number }>(`/auth/google/gmail`, {
+    method: "POST",
+    body: JSON.stringify({ auth_code: authCode, callback_url: `${window.location.origin}/api/email/webhook/google` }),
+  });
+}
+
+export async function disconnectGmail(): Promise<{ status: string; disabled_trigger_ids: number[] }> {
+  return request<{ status: string; disabled_trigger_ids: number[] }>(`/auth/google/gmail`, {
+    method: "DELETE",
+  });
+}
+
+export async function deleteTrigger(triggerId: number): Promise<void> {
+  await request<void>(`/triggers/${triggerId}`, {
+    method: "DELETE",
+  });
+}
+
+export async function rotateTriggerSecret(triggerId: number): Promise<Trigger> {
+  return request<Trigger>(`/triggers/${triggerId}/rotate-secret`, {
+    method: "POST",
+  });
+}
+
+export async function fetchTriggerDeliveries(triggerId: number, limit = 20): Promise<TriggerDelivery[]> {
+  return request<TriggerDelivery[]>(`/triggers/${triggerId}/deliveries?limit=${limit}`);
+}
+
+export async function testTrigger(triggerId: number, payload: Record<string, unknown>): Promise<TestTriggerResponse> {
+  return request<TestTriggerResponse>(`/triggers/${triggerId}/test`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
 type RunAgentResponse = {
   thread_id: number;
 };
@@ -448,13 +1320,209 @@ export async function testMcpServer(agentId: number, payload: McpServerAddReques
   });
 }
 
+export type McpHealthStatus = "online" | "offline" | "auth_error" | "config_error" | "unknown";
+
+export interface McpHealthSample {
+  status: McpHealthStatus;
+  checked_at: string;
+  latency_ms?: number | null;
+  error?: string | null;
+}
+
+export interface McpServerHealth {
+  agent_id: number;
+  agent_name: string;
+  name: string;
+  url: string;
+  preset?: string | null;
+  tool_count: number;
+  status: McpHealthStatus;
+  latency_ms?: number | null;
+  last_checked?: string | null;
+  error?: string | null;
+  history: McpHealthSample[];
+}
+
+export async function fetchMcpServerHealth(refresh = false): Promise<McpServerHealth[]> {
+  return request<McpServerHealth[]>(`/mcp-servers/health${refresh ? "?refresh=true" : ""}`);
+}
+
+export async function reconnectMcpServer(agentId: number, serverName: string): Promise<McpServerHealth> {
+  return request<McpServerHealth>(`/agents/${agentId}/mcp-servers/${encodeURIComponent(serverName)}/reconnect`, {
+    method: "POST",
+  });
+}
+
+// Status page: system-wide health any signed-in user can see (aggregate counts only)
+export interface SystemStatus {
+  status: "ok" | "degraded";
+  checked_at: string;
+  database: "ok" | "error";
+  websocket: { available: boolean; active_connections: number };
+  runs_last_hour: number;
+  errors_last_hour: number;
+  errors_last_24h: number;
+  // null when no runs finished in the last hour
+  error_rate_last_hour: number | null;
+  frontend_errors_last_hour: number;
+  latency_ms: { p50: number; p95: number };
+}
+
+export async function fetchSystemStatus(): Promise<SystemStatus> {
+  return request<SystemStatus>(`/ops/status`);
+}
+
+// Environment variables and secrets, referenced as {{env.NAME}}
+export interface EnvVar {
+  id: number;
+  name: string;
+  // null for workspace-wide variables
+  workflow_id?: number | null;
+  workflow_name?: string | null;
+  is_secret: boolean;
+  // Always null for secrets: their values are write-only
+  value?: string | null;
+  description?: string | null;
+  updated_at?: string | null;
+}
+
+export interface EnvVarInput {
+  name: string;
+  value: string;
+  is_secret: boolean;
+  description?: string | null;
+  workflow_id?: number | null;
+}
+
+// value omitted keeps the current value
+export type EnvVarUpdate = Partial<Omit<EnvVarInput, "workflow_id">>;
+
+export async function fetchEnvVars(): Promise<EnvVar[]> {
+  return request<EnvVar[]>(`/env-vars/`);
+}
+
+export async function createEnvVar(input: EnvVarInput): Promise<EnvVar> {
+  return request<EnvVar>(`/env-vars/`, { method: "POST", body: JSON.stringify(input) });
+}
+
+export async function updateEnvVar(id: number, update: EnvVarUpdate): Promise<EnvVar> {
+  return request<EnvVar>(`/env-vars/${id}`, { method: "PATCH", body: JSON.stringify(update) });
+}
+
+export async function deleteEnvVar(id: number): Promise<void> {
+  await request<void>(`/env-vars/${id}`, { method: "DELETE" });
+}
+
+// Tags across the caller's agents and workflows; set them through updateAgent / updateWorkflow
+export async function fetchTags(): Promise<TagSummary[]> {
+  return request<TagSummary[]>(`/tags/`);
+}
+
+// Renaming onto an existing tag merges the two
+export async function renameTag(tag: string, name: string): Promise<TagSummary[]> {
+  return request<TagSummary[]>(`/tags/rename`, { method: "POST", body: JSON.stringify({ tag, name }) });
+}
+
+export async function deleteTag(tag: string): Promise<TagSummary[]> {
+  return request<TagSummary[]>(`/tags/?tag=${encodeURIComponent(tag)}`, { method: "DELETE" });
+}
+
+// Third-party REST API keys, used as the auth source for HTTP nodes and MCP servers
+export interface ApiCredential {
+  id: number;
+  name: string;
+  base_url: string;
+  // "Name: value" with {{key}} where the key goes
+  auth_header: string;
+  // Last four characters of the key; "" for short keys
+  key_hint: string;
+  test_status: "untested" | "success" | "failed";
+  last_tested_at?: string | null;
+  updated_at?: string | null;
+}
+
+export interface ApiCredentialInput {
+  name: string;
+  base_url: string;
+  auth_header: string;
+  key: string;
+}
+
+// key omitted keeps the current key
+export type ApiCredentialUpdate = Partial<ApiCredentialInput>;
+
+export interface ApiCredentialTestResult {
+  success: boolean;
+  message: string;
+  status_code?: number | null;
+}
+
+export async function fetchApiCredentials(): Promise<ApiCredential[]> {
+  return request<ApiCredential[]>(`/api-credentials/`);
+}
+
+export async function createApiCredential(input: ApiCredentialInput): Promise<ApiCredential> {
+  return request<ApiCredential>(`/api-credentials/`, { method: "POST", body: JSON.stringify(input) });
+}
+
+export async function updateApiCredential(id: number, update: ApiCredentialUpdate): Promise<ApiCredential> {
+  return request<ApiCredential>(`/api-credentials/${id}`, { method: "PATCH", body: JSON.stringify(update) });
+}
+
+export async function deleteApiCredential(id: number): Promise<void> {
+  await request<void>(`/api-credentials/${id}`, { method: "DELETE" });
+}
+
+export async function testApiCredential(id: number, path = ""): Promise<ApiCredentialTestResult> {
+  return request<ApiCredentialTestResult>(`/api-credentials/${id}/test`, {
+    method: "POST",
+    body: JSON.stringify({ path }),
+  });
+}
+
 // Workflow API functions
-export async function fetchWorkflows(): Promise<WorkflowsResponse> {
-  return request<WorkflowsResponse>(`/workflows`);
+export async function fetchWorkflows(limit?: number): Promise<WorkflowsResponse> {
+  const query = limit != null ? `?limit=${limit}` : "";
+  return request<WorkflowsResponse>(`/workflows${query}`);
+}
+
+// Deleting moves the workflow to the trash; same retention as agents
+export const WORKFLOW_TRASH_RETENTION_DAYS = AGENT_TRASH_RETENTION_DAYS;
+
+export async function deleteWorkflow(workflowId: number): Promise<void> {
+  return request<void>(`/workflows/${workflowId}`, {
+    method: "DELETE",
+  });
+}
+
+// Rename a workflow or change its description or tags; tags replace the whole list
+export async function updateWorkflow(workflowId: number, update: WorkflowUpdate): Promise<Workflow> {
+  return request<Workflow>(`/workflows/${workflowId}`, {
+    method: "PATCH",
+    body: JSON.stringify(update),
+  });
+}
+
+export async function fetchTrashedWorkflows(): Promise<Workflow[]> {
+  return request<Workflow[]>(`/workflows/trash`);
+}
+
+export async function restoreWorkflow(workflowId: number): Promise<Workflow> {
+  return request<Workflow>(`/workflows/${workflowId}/restore`, {
+    method: "POST",
+  });
+}
+
+export async function permanentlyDeleteWorkflow(workflowId: number): Promise<void> {
+  return request<void>(`/workflows/${workflowId}/permanent`, {
+    method: "DELETE",
+  });
 }
 
-export async function fetchCurrentWorkflow(): Promise<WorkflowResponse> {
-  return request<WorkflowResponse>(`/workflows/current`);
+// Without an id the server picks the user's default workflow
+export async function fetchCurrentWorkflow(workflowId?: number): Promise<WorkflowResponse> {
+  const query = workflowId != null ? `?workflow_id=${workflowId}` : "";
+  return request<WorkflowResponse>(`/workflows/current${query}`);
 }
 
 export async function createWorkflow(name: string, description?: string, canvas?: WorkflowDataInput): Promise<CreatedWorkflowResponse> {
@@ -469,13 +1537,130 @@ export async function createWorkflow(name: string, description?: string, canvas?
   });
 }
 
-export async function updateWorkflowCanvas(canvas: WorkflowDataInput): Promise<UpdatedWorkflowCanvasResponse> {
+// keepalive lets the save finish while the page unloads
+export async function updateWorkflowCanvas(
+  canvas: WorkflowDataInput,
+  options: { keepalive?: boolean; workflowId?: number } = {}
+): Promise<UpdatedWorkflowCanvasResponse> {
   const payload: CanvasUpdate = {
     canvas,
   };
-  return request<UpdatedWorkflowCanvasResponse>(`/workflows/current/canvas`, {
+  const query = options.workflowId != null ? `?workflow_id=${options.workflowId}` : "";
+  return request<UpdatedWorkflowCanvasResponse>(`/workflows/current/canvas${query}`, {
     method: "PATCH",
     body: JSON.stringify(payload),
+    keepalive: options.keepalive,
+  });
+}
+
+// Check a canvas without saving it; workflowId lets Call Workflow nodes be checked for calls back into it
+export async function validateWorkflowCanvas(
+  canvas: WorkflowDataInput,
+  workflowId?: number
+): Promise<CanvasValidation> {
+  const payload: CanvasValidate = { canvas, workflow_id: workflowId ?? null };
+  return request<CanvasValidation>(`/workflows/validate`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
+// Node comment threads; omit nodeId when replying, the reply joins the parent's node
+export async function fetchWorkflowComments(workflowId: number): Promise<WorkflowComment[]> {
+  return request<WorkflowComment[]>(`/workflows/${workflowId}/comments`);
+}
+
+export async function createWorkflowComment(
+  workflowId: number,
+  payload: { body: string; nodeId?: string; parentId?: number }
+): Promise<WorkflowComment> {
+  return request<WorkflowComment>(`/workflows/${workflowId}/comments`, {
+    method: "POST",
+    body: JSON.stringify({ body: payload.body, node_id: payload.nodeId ?? null, parent_id: payload.parentId ?? null }),
+  });
+}
+
+export async function resolveWorkflowComment(
+  workflowId: number,
+  commentId: number,
+  resolved: boolean
+): Promise<WorkflowComment> {
+  return request<WorkflowComment>(`/workflows/${workflowId}/comments/${commentId}`, {
+    method: "PATCH",
+    body: JSON.stringify({ resolved }),
+  });
+}
+
+// HTTP node "Test request": sends what the node would send; references resolve against executionId's outputs
+export interface HttpRequestTestPayload {
+  method: string;
+  url: string;
+  headers: Record<string, string>;
+  body: string | null;
+  execution_id?: number | null;
+  // API credential whose auth header is added
+  credential_id?: number | null;
+}
+
+export interface HttpRequestResponse {
+  status_code: number;
+  ok: boolean;
+  headers: Record<string, string>;
+  body: unknown;
+  url: string;
+  elapsed_ms?: number;
+  truncated?: boolean;
+}
+
+export interface HttpRequestTestResult {
+  method: string;
+  url: string;
+  unresolved: string[];
+  response: HttpRequestResponse | null;
+  error: string | null;
+}
+
+export async function testHttpRequest(payload: HttpRequestTestPayload): Promise<HttpRequestTestResult> {
+  return request<HttpRequestTestResult>(`/workflows/http-request/test`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
+// Template gallery API functions
+export async function fetchTemplates(params: TemplateListParams = {}): Promise<WorkflowTemplate[]> {
+  const query = new URLSearchParams();
+  if (params.category) query.set("category", params.category);
+  if (params.sort) query.set("sort", params.sort);
+  if (params.minRating) query.set("min_rating", String(params.minRating));
+  if (params.favoritesOnly) query.set("favorites", "true");
+  if (params.mine) query.set("my_templates", "true");
+  const suffix = query.toString();
+  return request<WorkflowTemplate[]>(`/templates/${suffix ? `?${suffix}` : ""}`);
+}
+
+export async function fetchTemplateCategories(): Promise<string[]> {
+  return request<string[]>(`/templates/categories`);
+}
+
+export async function publishWorkflowTemplate(payload: TemplatePublishPayload): Promise<WorkflowTemplate> {
+  return request<WorkflowTemplate>(`/templates/publish`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
+export async function rateTemplate(templateId: number, rating: number | null): Promise<WorkflowTemplate> {
+  return request<WorkflowTemplate>(`/templates/${templateId}/rating`, {
+    method: "PUT",
+    body: JSON.stringify({ rating }),
+  });
+}
+
+export async function favoriteTemplate(templateId: number, favorite: boolean): Promise<WorkflowTemplate> {
+  return request<WorkflowTemplate>(`/templates/${templateId}/favorite`, {
+    method: "PUT",
+    body: JSON.stringify({ favorite }),
   });
 }
 
@@ -486,12 +1671,93 @@ export async function reserveWorkflowExecution(workflowId: number): Promise<Exec
   });
 }
 
-export async function startWorkflowExecution(workflowId: number): Promise<ExecutionStatus> {
-  return request<ExecutionStatus>(`/workflow-executions/by-workflow/${workflowId}/start`, {
+// `inputs` are checked against the manual trigger's declared run inputs (lib/workflowInputs.ts)
+export async function startWorkflowExecution(
+  workflowId: number,
+  options: { stepMode?: boolean; inputs?: Record<string, unknown> } = {}
+): Promise<ExecutionStatus> {
+  const query = options.stepMode ? "?step_mode=true" : "";
+  return request<ExecutionStatus>(`/workflow-executions/by-workflow/${workflowId}/start${query}`, {
+    method: "POST",
+    ...(options.inputs ? { body: JSON.stringify({ inputs: options.inputs }) } : {}),
+  });
+}
+
+// Start a new run at `nodeId`, reusing the finished execution's outputs for every other node
+export async function runFromNode(
+  executionId: number,
+  nodeId: string,
+  options: { stepMode?: boolean } = {}
+): Promise<ExecutionStatus> {
+  const query = options.stepMode ? "?step_mode=true" : "";
+  return request<ExecutionStatus>(
+    `/workflow-executions/${executionId}/run-from/${encodeURIComponent(nodeId)}${query}`,
+    { method: "POST" }
+  );
+}
+
+// Run the node a step-mode execution is paused at; `config` replaces its config for this run
+export async function stepExecution(executionId: number, config?: Record<string, unknown>): Promise<ExecutionStatus> {
+  return request<ExecutionStatus>(`/workflow-executions/${executionId}/step`, {
+    method: "POST",
+    body: JSON.stringify({ config: config ?? null }),
+  });
+}
+
+// Leave step mode and run the rest of the execution unattended
+export async function continueExecution(
+  executionId: number,
+  config?: Record<string, unknown>
+): Promise<ExecutionStatus> {
+  return request<ExecutionStatus>(`/workflow-executions/${executionId}/continue`, {
+    method: "POST",
+    body: JSON.stringify({ config: config ?? null }),
+  });
+}
+
+// End a wait node's wait now; the run carries on from that node
+export async function skipWait(executionId: number, nodeId: string): Promise<void> {
+  return request<void>(`/workflow-executions/${executionId}/waits/${encodeURIComponent(nodeId)}/skip`, {
     method: "POST",
   });
 }
 
+export interface ExecutionNodeDetail {
+  node_id: string;
+  phase: string;
+  result?: string | null;
+  error_message?: string | null;
+  output?: Record<string, unknown> | null;
+}
+
+// Execution started by a Call Workflow node
+export interface ChildExecutionSummary {
+  execution_id: number;
+  workflow_id: number;
+  node_id?: string | null;
+  phase: string;
+  result?: string | null;
+}
+
+export interface ExecutionDetail {
+  execution_id: number;
+  workflow_id: number;
+  workflow_name: string;
+  phase: string;
+  result?: string | null;
+  error_message?: string | null;
+  parent_execution_id?: number | null;
+  parent_node_id?: string | null;
+  canvas: WorkflowData;
+  nodes: ExecutionNodeDetail[];
+  children: ChildExecutionSummary[];
+}
+
+// Node states and child runs of an execution, for drilling into called workflows
+export async function getExecutionDetail(executionId: number): Promise<ExecutionDetail> {
+  return request<ExecutionDetail>(`/workflow-executions/${executionId}/detail`);
+}
+
 export async function startReservedExecution(executionId: number): Promise<ExecutionStatus> {
   return request<ExecutionStatus>(`/workflow-executions/executions/${executionId}/start`, {
     method: "POST",
@@ -513,8 +1779,34 @@ export async function cancelExecution(executionId: number, reason: string): Prom
   });
 }
 
-export async function getExecutionHistory(workflowId: number): Promise<WorkflowExecution[]> {
-  return request<WorkflowExecution[]>(`/workflow-executions/history/${workflowId}`);
+// Newest first
+export async function getExecutionHistory(workflowId: number, limit = 100): Promise<WorkflowExecution[]> {
+  return request<WorkflowExecution[]>(`/workflow-executions/history/${workflowId}?limit=${limit}`);
+}
+
+export async function getNodeOutputCache(workflowId: number): Promise<NodeOutputCache> {
+  return request<NodeOutputCache>(`/workflow-executions/history/${workflowId}/node-cache`);
+}
+
+export interface NodeExecutionSummary {
+  node_id: string;
+  phase: string;
+  result: string | null;
+  duration_ms: number | null;
+  cost_usd: number | null;
+}
+
+export interface ExecutionNodeSummary {
+  execution_id: number;
+  phase: string;
+  result: string | null;
+  started_at: string | null;
+  finished_at: string | null;
+  nodes: NodeExecutionSummary[];
+}
+
+export async function getExecutionNodeSummaries(workflowId: number, limit = 20): Promise<ExecutionNodeSummary[]> {
+  return request<ExecutionNodeSummary[]>(`/workflow-executions/history/${workflowId}/node-summaries?limit=${limit}`);
 }
 
 export interface ModelConfig {
@@ -522,6 +1814,8 @@ export interface ModelConfig {
   display_name: string;
   provider: string;
   is_default: boolean;
+  // Generation parameters from config/models.json an agent starts from
+  default_params?: ModelParams;
 }
 
 export async function fetchModels(): Promise<ModelConfig[]> {
@@ -686,3 +1980,71 @@ export async function replaceUserContext(context: UserContext): Promise<UserCont
     body: JSON.stringify({ context }),
   });
 }
+
+export async function fetchCapabilities(): Promise<UserCapabilities> {
+  return request<UserCapabilities>(`/users/me/capabilities`);
+}
+
+// Replaces user.prefs wholesale; callers merge into the stored prefs first
+export async function updateUserPrefs(
+  prefs: Record<string, unknown>
+): Promise<{ id: number; prefs?: Record<string, unknown> | null }> {
+  return request<{ id: number; prefs?: Record<string, unknown> | null }>(`/users/me`, {
+    method: "PUT",
+    body: JSON.stringify({ prefs }),
+  });
+}
+
+// Admin-set prefs that apply until a user picks their own value
+export interface WorkspaceDefaults {
+  theme?: string | null;
+  timezone?: string | null;
+  time_display?: string | null;
+  default_model?: string | null;
+  dashboard_scope?: string | null;
+  dashboard_sort?: { key: string; ascending: boolean } | null;
+}
+
+export async function fetchWorkspaceDefaults(): Promise<WorkspaceDefaults> {
+  return request<WorkspaceDefaults>(`/workspace/defaults`);
+}
+
+export async function updateWorkspaceDefaults(defaults: WorkspaceDefaults): Promise<WorkspaceDefaults> {
+  return request<WorkspaceDefaults>(`/admin/workspace-defaults`, {
+    method: "PUT",
+    body: JSON.stringify(defaults),
+  });
+}
+
+// ---------------------------------------------------------------------------
+// Account data export
+// ---------------------------------------------------------------------------
+
+export interface DataExport {
+  id: number;
+  status: "queued" | "running" | "success" | "failed";
+  size_bytes: number | null;
+  error: string | null;
+  created_at: string;
+  finished_at: string | null;
+}
+
+export async function requestDataExport(): Promise<DataExport> {
+  return request<DataExport>(`/users/me/exports`, { method: "POST" });
+}
+
+export async function fetchDataExports(): Promise<DataExport[]> {
+  return request<DataExport[]>(`/users/me/exports`);
+}
+
+// The archive is binary, so this bypasses request()'s JSON/text handling
+export async function downloadDataExport(exportId: number): Promise<Blob> {
+  const url = buildUrl(`/users/me/exports/${exportId}/download`);
+  const response = await fetch(url, { credentials: "include" });
+  if (!response.ok) {
+    const traceId = traceIdFromResponse(response);
+    const body = await response.json().catch(() => undefined);
+    throw new ApiError({ url, status: response.status, body, traceId });
+  }
+  return response.blob();
+}
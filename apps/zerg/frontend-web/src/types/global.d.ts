@@ -1,6 +1,9 @@
 declare global {
   interface Window {
     __TEST_WORKER_ID__?: string | number;
+    __zergWsDebug?: import("../lib/wsFrameLog").WsDebugApi;
+    __zergReplay?: import("../lib/sessionRecorder").ReplayHarness;
+    __zergTest?: import("../lib/testHooks").TestHooks;
   }
 }
 
@@ -0,0 +1,15 @@
+/**
+ * Worker entry for workflow computations (see lib/workerBridge.ts).
+ */
+
+import { runTask, type TaskRequest, type TaskResponse } from "../lib/workflowTasks";
+
+// Typed by hand: the app compiles against the DOM lib, not "webworker"
+const scope = self as unknown as {
+  onmessage: ((event: MessageEvent<TaskRequest>) => void) | null;
+  postMessage: (response: TaskResponse) => void;
+};
+
+scope.onmessage = (event) => {
+  runTask(event.data, (response) => scope.postMessage(response));
+};
@@ -102,6 +102,70 @@ class ModernProtocolGenerator:
 
         print(f"✅ TypeScript types: {output_path}")
 
+        self._generate_typescript_validation_schema()
+
+    def _generate_typescript_validation_schema(self):
+        """Generate the runtime payload schemas the frontend validates incoming envelopes against."""
+        output_path = self.output_dir / "apps" / "zerg" / "frontend-web" / "src" / "generated" / "ws-schema.ts"
+        output_path.parent.mkdir(parents=True, exist_ok=True)
+
+        components = self.schema.get("components", {})
+        schemas = {
+            name: self._reduce_validation_schema(schema_def)
+            for name, schema_def in components.get("schemas", {}).items()
+        }
+        payloads: Dict[str, str] = {}
+        for msg_data in components.get("messages", {}).values():
+            payload_ref = msg_data.get("payload", {}).get("$ref", "")
+            if not payload_ref:
+                continue
+            payload_name = payload_ref.split("/")[-1]
+            for type_name in [msg_data.get("name", "")] + msg_data.get("x-aliases", []):
+                if type_name:
+                    payloads[type_name] = payload_name
+
+        code = self._generate_typescript_header().replace(
+            "strongly-typed WebSocket message definitions", "runtime schemas for validating WebSocket envelopes"
+        )
+        code += '''export interface FieldSchema {
+  type?: "string" | "integer" | "number" | "boolean" | "object" | "array";
+  enum?: readonly (string | number)[];
+  const?: string | number | boolean;
+  minimum?: number;
+  required?: readonly string[];
+  properties?: Readonly<Record<string, FieldSchema>>;
+  items?: FieldSchema;
+  /** Name of another entry in WS_SCHEMAS */
+  ref?: string;
+}
+
+'''
+        code += f"export const WS_SCHEMAS: Readonly<Record<string, FieldSchema>> = {json.dumps(schemas, indent=2)};\n\n"
+        code += "/** Message type (including aliases) -> payload schema name */\n"
+        code += "export const WS_MESSAGE_PAYLOADS: Readonly<Record<string, string>> = "
+        code += f"{json.dumps(payloads, indent=2)};\n"
+
+        with open(output_path, 'w') as f:
+            f.write(code)
+
+        print(f"✅ TypeScript validation schema: {output_path}")
+
+    def _reduce_validation_schema(self, schema: Dict[str, Any]) -> Dict[str, Any]:
+        """Keep only the keywords the runtime validator checks."""
+        if "$ref" in schema:
+            return {"ref": schema["$ref"].split("/")[-1]}
+        reduced: Dict[str, Any] = {}
+        for key in ("type", "enum", "const", "minimum", "required"):
+            if key in schema:
+                reduced[key] = schema[key]
+        if "properties" in schema:
+            reduced["properties"] = {
+                name: self._reduce_validation_schema(prop) for name, prop in schema["properties"].items()
+            }
+        if "items" in schema:
+            reduced["items"] = self._reduce_validation_schema(schema["items"])
+        return reduced
+
     def _generate_typescript_header(self) -> str:
         """Generate TypeScript file header."""
         return f'''// AUTO-GENERATED FILE - DO NOT EDIT